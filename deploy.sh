@@ -13,12 +13,25 @@ NC='\033[0m'
 
 app=$1 # should be the controller's name
 registry=$2 # should be either remote or local
+mode=${3:-cluster} # should be either cluster (watch all namespaces) or namespaced (watch only the controller's namespace)
+name=${app//_/-} # Kubernetes object names cannot contain underscores, e.g., rabbitmq_topology runs in namespace rabbitmq-topology
+
+if [ "$mode" = "namespaced" ]; then
+    rbac=rbac_namespaced.yaml
+else
+    rbac=rbac.yaml
+fi
 
 ## use imperative management for CRDs since metadata for PodTemplateSpec is too long.
-if cd deploy/$1 && kubectl create -f crd.yaml && kubectl apply -f rbac.yaml && kubectl apply -f deploy_$registry.yaml; then
+if cd deploy/$1 && kubectl create -f crd.yaml && kubectl apply -f $rbac && kubectl apply -f deploy_$registry.yaml; then
+    if [ "$mode" = "namespaced" ]; then
+        ## the entrypoint is "controller run", so the flags are appended as args.
+        kubectl patch deployment $name-controller -n $name --type=json \
+            -p '[{"op": "add", "path": "/spec/template/spec/containers/0/args", "value": ["--namespaces", "'$name'"]}]'
+    fi
     echo ""
-    echo -e "${GREEN}The $app controller is deployed in your Kubernetes cluster in namespace \"$app\".${NC}"
-    echo -e "${GREEN}Run \"kubectl get pod -n $app\" to check the controller pod.${NC}"
+    echo -e "${GREEN}The $app controller is deployed in your Kubernetes cluster in namespace \"$name\".${NC}"
+    echo -e "${GREEN}Run \"kubectl get pod -n $name\" to check the controller pod.${NC}"
     echo -e "${GREEN}Run \"kubectl apply -f deploy/$app/$app.yaml\" to deploy the cluster custom resource(s).${NC}"
 else
     echo ""
@@ -7,13 +7,14 @@
 set -xeu
 
 app=$1
+name=${app//_/-} # Kubernetes object names cannot contain underscores
 
 # Copy the Dockerfile and build the docker image of the controller
 cp docker/controller/Dockerfile .
-docker build -t local/$app-controller:v0.1.0 --build-arg APP=$app .
+docker build -t local/$name-controller:v0.1.0 --build-arg APP=$app .
 rm Dockerfile
 # Set up the kind cluster and load the image into the cluster
 kind create cluster --config deploy/kind.yaml
-kind load docker-image local/$app-controller:v0.1.0
+kind load docker-image local/$name-controller:v0.1.0
 # Deploy the controller as a pod to the kind cluster, using the image just loaded
 ./deploy.sh $app local
@@ -5,7 +5,9 @@ use crate::external_api::exec::*;
 use crate::fluent_controller::fluentbit::exec::resource::*;
 use crate::fluent_controller::fluentbit::model::reconciler as model_reconciler;
 use crate::fluent_controller::fluentbit::model::resource as model_resource;
-use crate::fluent_controller::fluentbit::trusted::{exec_types::*, spec_types, step::*};
+use crate::fluent_controller::fluentbit::trusted::{
+    exec_types::*, fluentbit_api_exec::*, spec_types, step::*,
+};
 use crate::kubernetes_api_objects::exec::prelude::*;
 use crate::kubernetes_api_objects::exec::resource::ResourceWrapper;
 use crate::reconciler::exec::{io::*, reconciler::*, resource_builder::*};
@@ -20,7 +22,7 @@ pub struct FluentBitReconciler {}
 impl Reconciler for FluentBitReconciler {
     type R = FluentBit;
     type T = FluentBitReconcileState;
-    type ExternalAPIType = EmptyAPIShimLayer;
+    type ExternalAPIType = FluentBitAPIShimLayer;
 
     open spec fn well_formed(fb: &FluentBit) -> bool { fb@.well_formed() }
 
@@ -28,8 +30,8 @@ impl Reconciler for FluentBitReconciler {
         reconcile_init_state()
     }
 
-    fn reconcile_core(fb: &FluentBit, resp_o: Option<Response<EmptyType>>, state: FluentBitReconcileState)
-    -> (FluentBitReconcileState, Option<Request<EmptyType>>) {
+    fn reconcile_core(fb: &FluentBit, resp_o: Option<Response<FluentBitAPIOutput>>, state: FluentBitReconcileState)
+    -> (FluentBitReconcileState, Option<Request<FluentBitAPIInput>>) {
         reconcile_core(fb, resp_o, state)
     }
 
@@ -45,7 +47,11 @@ impl Reconciler for FluentBitReconciler {
 pub fn reconcile_init_state() -> (state: FluentBitReconcileState)
     ensures state@ == model_reconciler::reconcile_init_state(),
 {
-    FluentBitReconcileState { reconcile_step: FluentBitReconcileStep::Init }
+    FluentBitReconcileState {
+        reconcile_step: FluentBitReconcileStep::Init,
+        latest_config_hash_opt: None,
+        latest_daemon_set_opt: None,
+    }
 }
 
 pub fn reconcile_done(state: &FluentBitReconcileState) -> (res: bool)
@@ -66,7 +72,7 @@ pub fn reconcile_error(state: &FluentBitReconcileState) -> (res: bool)
     }
 }
 
-pub fn reconcile_core(fb: &FluentBit, resp_o: Option<Response<EmptyType>>, state: FluentBitReconcileState) -> (res: (FluentBitReconcileState, Option<Request<EmptyType>>))
+pub fn reconcile_core(fb: &FluentBit, resp_o: Option<Response<FluentBitAPIOutput>>, state: FluentBitReconcileState) -> (res: (FluentBitReconcileState, Option<Request<FluentBitAPIInput>>))
     requires fb@.well_formed(),
     ensures (res.0@, opt_request_to_view(&res.1)) == model_reconciler::reconcile_core(fb@, opt_response_to_view(&resp_o), state@),
         // resource_version_check(opt_response_to_view(&resp_o), opt_request_to_view(&res.1)),
@@ -88,35 +94,123 @@ pub fn reconcile_core(fb: &FluentBit, resp_o: Option<Response<EmptyType>>, state
         FluentBitReconcileStep::AfterGetSecret => {
             if resp_o.is_some() && resp_o.as_ref().unwrap().is_k_response()
             && resp_o.as_ref().unwrap().as_k_response_ref().is_get_response() {
-                let get_sts_resp = resp_o.unwrap().into_k_response().into_get_response().res;
-                if get_sts_resp.is_ok() {
+                let get_secret_resp = resp_o.unwrap().into_k_response().into_get_response().res;
+                if get_secret_resp.is_ok() {
+                    let secret = Secret::unmarshal(get_secret_resp.unwrap());
+                    if secret.is_ok() {
+                        let req_o = KubeAPIRequest::GetRequest(KubeGetRequest {
+                            api_resource: ServiceAccount::api_resource(),
+                            name: make_service_account_name(fb),
+                            namespace: fb.metadata().namespace().unwrap(),
+                        });
+                        let state_prime = FluentBitReconcileState {
+                            reconcile_step: FluentBitReconcileStep::AfterKRequestStep(ActionKind::Get, SubResource::ServiceAccount),
+                            latest_config_hash_opt: Some(config_hash(&secret.unwrap())),
+                            ..state
+                        };
+                        return (state_prime, Some(Request::KRequest(req_o)));
+                    }
+                }
+            }
+            return report_error(fb, state);
+        },
+        FluentBitReconcileStep::AfterKRequestStep(_, resource) => {
+            match resource {
+                SubResource::ServiceAccount => reconcile_helper::<model_resource::ServiceAccountBuilder, ServiceAccountBuilder>(fb, resp_o, state),
+                SubResource::Role => reconcile_helper::<model_resource::RoleBuilder, RoleBuilder>(fb, resp_o, state),
+                SubResource::RoleBinding => reconcile_helper::<model_resource::RoleBindingBuilder, RoleBindingBuilder>(fb, resp_o, state),
+                SubResource::Service => reconcile_helper::<model_resource::ServiceBuilder, ServiceBuilder>(fb, resp_o, state),
+                SubResource::DaemonSet => reconcile_helper::<model_resource::DaemonSetBuilder, DaemonSetBuilder>(fb, resp_o, state),
+            }
+        },
+        FluentBitReconcileStep::AfterGetDaemonSetStatus => {
+            if resp_o.is_some() && resp_o.as_ref().unwrap().is_k_response()
+            && resp_o.as_ref().unwrap().as_k_response_ref().is_get_response() {
+                let get_ds_resp = resp_o.unwrap().into_k_response().into_get_response().res;
+                if get_ds_resp.is_ok() {
+                    let ds = DaemonSet::unmarshal(get_ds_resp.unwrap());
+                    if ds.is_ok() && state.latest_config_hash_opt.is_some() {
+                        let ext_req = FluentBitAPIInput::ReloadConfigRequest(
+                            make_daemon_set_name(fb), fb.metadata().namespace().unwrap(), state.latest_config_hash_opt.as_ref().unwrap().clone()
+                        );
+                        let state_prime = FluentBitReconcileState {
+                            reconcile_step: FluentBitReconcileStep::AfterReloadConfig,
+                            latest_daemon_set_opt: Some(ds.unwrap()),
+                            ..state
+                        };
+                        return (state_prime, Some(Request::ExternalRequest(ext_req)));
+                    }
+                }
+            }
+            return report_error(fb, state);
+        },
+        FluentBitReconcileStep::AfterReloadConfig => {
+            if resp_o.is_some() && resp_o.as_ref().unwrap().is_external_response()
+            && resp_o.as_ref().unwrap().as_external_response_ref().is_reload_config_response() {
+                let reload_config_resp = resp_o.unwrap().into_external_response().unwrap_reload_config_response().res;
+                if reload_config_resp.is_ok() {
                     let req_o = KubeAPIRequest::GetRequest(KubeGetRequest {
-                        api_resource: ServiceAccount::api_resource(),
-                        name: make_service_account_name(fb),
+                        api_resource: FluentBit::api_resource(),
+                        name: fb.metadata().name().unwrap(),
                         namespace: fb.metadata().namespace().unwrap(),
                     });
                     let state_prime = FluentBitReconcileState {
-                        reconcile_step: FluentBitReconcileStep::AfterKRequestStep(ActionKind::Get, SubResource::ServiceAccount),
+                        reconcile_step: FluentBitReconcileStep::AfterGetFluentBit,
                         ..state
                     };
                     return (state_prime, Some(Request::KRequest(req_o)));
                 }
             }
-            // return error state
+            return report_error(fb, state);
+        },
+        FluentBitReconcileStep::AfterGetFluentBit => {
+            if resp_o.is_some() && resp_o.as_ref().unwrap().is_k_response()
+            && resp_o.as_ref().unwrap().as_k_response_ref().is_get_response() {
+                let get_fb_resp = resp_o.unwrap().into_k_response().into_get_response().res;
+                if get_fb_resp.is_ok() {
+                    let latest_fb = FluentBit::unmarshal(get_fb_resp.unwrap());
+                    if latest_fb.is_ok() && state.latest_daemon_set_opt.is_some() && state.latest_config_hash_opt.is_some() {
+                        let updated_fb = update_fb_status(&latest_fb.unwrap(), state.latest_daemon_set_opt.as_ref().unwrap(), state.latest_config_hash_opt.as_ref().unwrap());
+                        let req_o = KubeAPIRequest::UpdateStatusRequest(KubeUpdateStatusRequest {
+                            api_resource: FluentBit::api_resource(),
+                            name: fb.metadata().name().unwrap(),
+                            namespace: fb.metadata().namespace().unwrap(),
+                            obj: updated_fb.marshal(),
+                        });
+                        let state_prime = FluentBitReconcileState {
+                            reconcile_step: FluentBitReconcileStep::AfterUpdateStatus,
+                            ..state
+                        };
+                        return (state_prime, Some(Request::KRequest(req_o)));
+                    }
+                }
+            }
+            return report_error(fb, state);
+        },
+        FluentBitReconcileStep::AfterUpdateStatus => {
+            if resp_o.is_some() && resp_o.as_ref().unwrap().is_k_response()
+            && resp_o.as_ref().unwrap().as_k_response_ref().is_update_status_response() {
+                let update_status_resp = resp_o.unwrap().into_k_response().into_update_status_response().res;
+                if update_status_resp.is_ok() {
+                    let state_prime = FluentBitReconcileState {
+                        reconcile_step: FluentBitReconcileStep::Done,
+                        ..state
+                    };
+                    return (state_prime, None);
+                }
+            }
             let state_prime = FluentBitReconcileState {
                 reconcile_step: FluentBitReconcileStep::Error,
                 ..state
             };
             return (state_prime, None);
         },
-        FluentBitReconcileStep::AfterKRequestStep(_, resource) => {
-            match resource {
-                SubResource::ServiceAccount => reconcile_helper::<model_resource::ServiceAccountBuilder, ServiceAccountBuilder>(fb, resp_o, state),
-                SubResource::Role => reconcile_helper::<model_resource::RoleBuilder, RoleBuilder>(fb, resp_o, state),
-                SubResource::RoleBinding => reconcile_helper::<model_resource::RoleBindingBuilder, RoleBindingBuilder>(fb, resp_o, state),
-                SubResource::Service => reconcile_helper::<model_resource::ServiceBuilder, ServiceBuilder>(fb, resp_o, state),
-                SubResource::DaemonSet => reconcile_helper::<model_resource::DaemonSetBuilder, DaemonSetBuilder>(fb, resp_o, state),
-            }
+        FluentBitReconcileStep::AfterUpdateDegradedStatus => {
+            let state_prime = FluentBitReconcileState {
+                reconcile_step: FluentBitReconcileStep::Error,
+                ..state
+            };
+            return (state_prime, None);
         },
         _ => {
             let state_prime = FluentBitReconcileState {
@@ -129,12 +223,29 @@ pub fn reconcile_core(fb: &FluentBit, resp_o: Option<Response<EmptyType>>, state
     }
 }
 
+pub fn report_error(fb: &FluentBit, state: FluentBitReconcileState) -> (res: (FluentBitReconcileState, Option<Request<FluentBitAPIInput>>))
+    requires fb@.well_formed(),
+    ensures (res.0@, opt_request_to_view(&res.1)) == model_reconciler::report_error(fb@, state@),
+{
+    let req_o = KubeAPIRequest::UpdateStatusRequest(KubeUpdateStatusRequest {
+        api_resource: FluentBit::api_resource(),
+        name: fb.metadata().name().unwrap(),
+        namespace: fb.metadata().namespace().unwrap(),
+        obj: update_fb_status_to_degraded(fb).marshal(),
+    });
+    let state_prime = FluentBitReconcileState {
+        reconcile_step: FluentBitReconcileStep::AfterUpdateDegradedStatus,
+        ..state
+    };
+    (state_prime, Some(Request::KRequest(req_o)))
+}
+
 pub fn reconcile_helper<
     SpecBuilder: SpecResourceBuilder<spec_types::FluentBitView, spec_types::FluentBitReconcileState>,
     Builder: ResourceBuilder<FluentBit, FluentBitReconcileState, SpecBuilder>
 >(
-    fb: &FluentBit, resp_o: Option<Response<EmptyType>>, state: FluentBitReconcileState
-) -> (res: (FluentBitReconcileState, Option<Request<EmptyType>>))
+    fb: &FluentBit, resp_o: Option<Response<FluentBitAPIOutput>>, state: FluentBitReconcileState
+) -> (res: (FluentBitReconcileState, Option<Request<FluentBitAPIInput>>))
     requires
         fb@.well_formed(),
         Builder::requirements(fb@),
@@ -183,13 +294,7 @@ pub fn reconcile_helper<
                             }
                         }
                     }
-                    // return error state
-                    let state_prime = FluentBitReconcileState {
-                        reconcile_step: FluentBitReconcileStep::Error,
-                        ..state
-                    };
-                    let req_o = None;
-                    return (state_prime, req_o);
+                    return report_error(fb, state);
                 },
                 ActionKind::Create => {
                     if resp_o.is_some() && resp_o.as_ref().unwrap().is_k_response()
@@ -206,12 +311,7 @@ pub fn reconcile_helper<
                             return (state_prime, req_o);
                         }
                     }
-                    let state_prime = FluentBitReconcileState {
-                        reconcile_step: FluentBitReconcileStep::Error,
-                        ..state
-                    };
-                    let req_o = None;
-                    return (state_prime, req_o);
+                    return report_error(fb, state);
                 },
                 ActionKind::Update => {
                     if resp_o.is_some() && resp_o.as_ref().unwrap().is_k_response()
@@ -228,12 +328,7 @@ pub fn reconcile_helper<
                             return (state_prime, req_o);
                         }
                     }
-                    let state_prime = FluentBitReconcileState {
-                        reconcile_step: FluentBitReconcileStep::Error,
-                        ..state
-                    };
-                    let req_o = None;
-                    return (state_prime, req_o);
+                    return report_error(fb, state);
                 },
             }
         },
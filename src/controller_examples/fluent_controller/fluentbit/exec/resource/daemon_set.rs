@@ -9,8 +9,8 @@ use crate::fluent_controller::fluentbit::trusted::{
 };
 use crate::kubernetes_api_objects::exec::resource::ResourceWrapper;
 use crate::kubernetes_api_objects::exec::{
-    container::*, label_selector::*, pod_template_spec::*, prelude::*, resource_requirements::*,
-    volume::*,
+    condition::*, container::*, label_selector::*, pod_template_spec::*, prelude::*,
+    resource_requirements::*, volume::*,
 };
 use crate::reconciler::exec::{io::*, reconciler::*, resource_builder::*};
 use crate::vstd_ext::string_map::StringMap;
@@ -54,11 +54,17 @@ impl ResourceBuilder<FluentBit, FluentBitReconcileState, model_resource::DaemonS
     fn state_after_create(fb: &FluentBit, obj: DynamicObject, state: FluentBitReconcileState) -> (res: Result<(FluentBitReconcileState, Option<KubeAPIRequest>), ()>) {
         let ds = DaemonSet::unmarshal(obj);
         if ds.is_ok() {
+            // Read the daemon set again so that the status reflects the latest rollout progress
+            let req = KubeAPIRequest::GetRequest(KubeGetRequest {
+                api_resource: DaemonSet::api_resource(),
+                name: make_daemon_set_name(fb),
+                namespace: fb.metadata().namespace().unwrap(),
+            });
             let state_prime = FluentBitReconcileState {
-                reconcile_step: FluentBitReconcileStep::Done,
+                reconcile_step: FluentBitReconcileStep::AfterGetDaemonSetStatus,
                 ..state
             };
-            Ok((state_prime, None))
+            Ok((state_prime, Some(req)))
         } else {
             Err(())
         }
@@ -67,11 +73,17 @@ impl ResourceBuilder<FluentBit, FluentBitReconcileState, model_resource::DaemonS
     fn state_after_update(fb: &FluentBit, obj: DynamicObject, state: FluentBitReconcileState) -> (res: Result<(FluentBitReconcileState, Option<KubeAPIRequest>), ()>) {
         let ds = DaemonSet::unmarshal(obj);
         if ds.is_ok() {
+            // Read the daemon set again so that the status reflects the latest rollout progress
+            let req = KubeAPIRequest::GetRequest(KubeGetRequest {
+                api_resource: DaemonSet::api_resource(),
+                name: make_daemon_set_name(fb),
+                namespace: fb.metadata().namespace().unwrap(),
+            });
             let state_prime = FluentBitReconcileState {
-                reconcile_step: FluentBitReconcileStep::Done,
+                reconcile_step: FluentBitReconcileStep::AfterGetDaemonSetStatus,
                 ..state
             };
-            Ok((state_prime, None))
+            Ok((state_prime, Some(req)))
         } else {
             Err(())
         }
@@ -432,4 +444,36 @@ fn make_env(fb: &FluentBit) -> (env_vars: Vec<EnvVar>)
     env_vars
 }
 
+pub fn update_fb_status(fb: &FluentBit, ds: &DaemonSet, config_hash: &String) -> (updated_fb: FluentBit)
+    ensures updated_fb@ == model_resource::update_fb_status(fb@, ds@, config_hash@),
+{
+    let mut status = FluentBitStatus::default();
+    let ready = match ds.status() {
+        Some(ds_status) => {
+            status.set_desired_number_scheduled(ds_status.desired_number_scheduled());
+            status.set_number_ready(ds_status.number_ready());
+            status.set_updated_number_scheduled(ds_status.updated_number_scheduled());
+            ds_status.number_ready() == ds_status.desired_number_scheduled()
+            && ds_status.updated_number_scheduled() == ds_status.desired_number_scheduled()
+        },
+        None => false,
+    };
+    status.set_config_hash(config_hash.clone());
+    status.set_conditions(make_conditions(ready, false, fb.metadata().generation()));
+    let mut updated_fb = fb.clone();
+    updated_fb.set_status(status);
+    updated_fb
+}
+
+pub fn update_fb_status_to_degraded(fb: &FluentBit) -> (updated_fb: FluentBit)
+    ensures updated_fb@ == model_resource::update_fb_status_to_degraded(fb@),
+{
+    let status_o = fb.status();
+    let mut status = if status_o.is_some() { status_o.unwrap() } else { FluentBitStatus::default() };
+    status.set_conditions(make_conditions(false, true, fb.metadata().generation()));
+    let mut updated_fb = fb.clone();
+    updated_fb.set_status(status);
+    updated_fb
+}
+
 }
@@ -4,7 +4,7 @@
 use crate::external_api::spec::*;
 use crate::fluent_controller::fluentbit::model::resource::*;
 use crate::fluent_controller::fluentbit::trusted::{
-    liveness_theorem::desired_secret_key, maker::*, spec_types::*, step::*,
+    fluentbit_api_spec::*, liveness_theorem::desired_secret_key, maker::*, spec_types::*, step::*,
 };
 use crate::kubernetes_api_objects::spec::prelude::*;
 use crate::reconciler::spec::{io::*, reconciler::*, resource_builder::*};
@@ -12,15 +12,15 @@ use vstd::{prelude::*, string::*};
 
 verus! {
 
-impl Reconciler<FluentBitView, EmptyAPI> for FluentBitReconciler {
+impl Reconciler<FluentBitView, FluentBitAPI> for FluentBitReconciler {
     type T = FluentBitReconcileState;
 
     open spec fn reconcile_init_state() -> FluentBitReconcileState {
         reconcile_init_state()
     }
 
-    open spec fn reconcile_core(fb: FluentBitView, resp_o: Option<ResponseView<EmptyTypeView>>, state: FluentBitReconcileState)
-    -> (FluentBitReconcileState, Option<RequestView<EmptyTypeView>>) {
+    open spec fn reconcile_core(fb: FluentBitView, resp_o: Option<ResponseView<FluentBitAPIOutputView>>, state: FluentBitReconcileState)
+    -> (FluentBitReconcileState, Option<RequestView<FluentBitAPIInputView>>) {
         reconcile_core(fb, resp_o, state)
     }
 
@@ -35,7 +35,13 @@ impl Reconciler<FluentBitView, EmptyAPI> for FluentBitReconciler {
     open spec fn expect_from_user(obj: DynamicObjectView) -> bool { obj.kind == SecretView::kind() /* expect the user might create some secret object */ }
 }
 
-pub open spec fn reconcile_init_state() -> FluentBitReconcileState { FluentBitReconcileState { reconcile_step: FluentBitReconcileStep::Init } }
+pub open spec fn reconcile_init_state() -> FluentBitReconcileState {
+    FluentBitReconcileState {
+        reconcile_step: FluentBitReconcileStep::Init,
+        latest_config_hash_opt: None,
+        latest_daemon_set_opt: None,
+    }
+}
 
 pub open spec fn reconcile_done(state: FluentBitReconcileState) -> bool {
     match state.reconcile_step {
@@ -52,8 +58,8 @@ pub open spec fn reconcile_error(state: FluentBitReconcileState) -> bool {
 }
 
 pub open spec fn reconcile_core(
-    fb: FluentBitView, resp_o: Option<ResponseView<EmptyTypeView>>, state: FluentBitReconcileState
-) -> (FluentBitReconcileState, Option<RequestView<EmptyTypeView>>) {
+    fb: FluentBitView, resp_o: Option<ResponseView<FluentBitAPIOutputView>>, state: FluentBitReconcileState
+) -> (FluentBitReconcileState, Option<RequestView<FluentBitAPIInputView>>) {
     let step = state.reconcile_step;
     let resp = resp_o.get_Some_0();
     let fb_name = fb.metadata.name.get_Some_0();
@@ -69,23 +75,21 @@ pub open spec fn reconcile_core(
         },
         FluentBitReconcileStep::AfterGetSecret => {
             let get_secret_resp = resp_o.get_Some_0().get_KResponse_0().get_GetResponse_0().res;
+            let secret = SecretView::unmarshal(get_secret_resp.get_Ok_0());
             if resp_o.is_Some() && resp_o.get_Some_0().is_KResponse()
             && resp_o.get_Some_0().get_KResponse_0().is_GetResponse()
-            && get_secret_resp.is_Ok() {
+            && get_secret_resp.is_Ok() && secret.is_Ok() {
                 let req_o = APIRequest::GetRequest(GetRequest {
                     key: make_service_account_key(fb),
                 });
                 let state_prime = FluentBitReconcileState {
                     reconcile_step: FluentBitReconcileStep::AfterKRequestStep(ActionKind::Get, SubResource::ServiceAccount),
+                    latest_config_hash_opt: Some(config_hash(secret.get_Ok_0().data)),
                     ..state
                 };
                 (state_prime, Some(RequestView::KRequest(req_o)))
             } else {
-                let state_prime = FluentBitReconcileState {
-                    reconcile_step: FluentBitReconcileStep::Error,
-                    ..state
-                };
-                (state_prime, None)
+                report_error(fb, state)
             }
         },
         FluentBitReconcileStep::AfterKRequestStep(_, resource) => {
@@ -97,6 +101,87 @@ pub open spec fn reconcile_core(
                 SubResource::DaemonSet => { reconcile_helper::<DaemonSetBuilder>(fb, resp_o, state) },
             }
         },
+        FluentBitReconcileStep::AfterGetDaemonSetStatus => {
+            let get_ds_resp = resp.get_KResponse_0().get_GetResponse_0().res;
+            let ds = DaemonSetView::unmarshal(get_ds_resp.get_Ok_0());
+            if resp_o.is_Some() && resp.is_KResponse() && resp.get_KResponse_0().is_GetResponse()
+            && get_ds_resp.is_Ok() && ds.is_Ok() && state.latest_config_hash_opt.is_Some() {
+                // The pods of the daemon set reload the config from the mounted secret,
+                // so a config change does not roll out the daemon set.
+                let req_o = FluentBitAPIInputView::ReloadConfigRequest(
+                    make_daemon_set_name(fb), fb_namespace, state.latest_config_hash_opt.get_Some_0()
+                );
+                let state_prime = FluentBitReconcileState {
+                    reconcile_step: FluentBitReconcileStep::AfterReloadConfig,
+                    latest_daemon_set_opt: Some(ds.get_Ok_0()),
+                    ..state
+                };
+                (state_prime, Some(RequestView::ExternalRequest(req_o)))
+            } else {
+                report_error(fb, state)
+            }
+        },
+        FluentBitReconcileStep::AfterReloadConfig => {
+            if resp_o.is_Some() && resp.is_ExternalResponse() && resp.get_ExternalResponse_0().is_ReloadConfigResponse()
+            && resp.get_ExternalResponse_0().get_ReloadConfigResponse_0().res.is_Ok() {
+                let req_o = APIRequest::GetRequest(GetRequest {
+                    key: fb.object_ref(),
+                });
+                let state_prime = FluentBitReconcileState {
+                    reconcile_step: FluentBitReconcileStep::AfterGetFluentBit,
+                    ..state
+                };
+                (state_prime, Some(RequestView::KRequest(req_o)))
+            } else {
+                report_error(fb, state)
+            }
+        },
+        FluentBitReconcileStep::AfterGetFluentBit => {
+            let get_fb_resp = resp.get_KResponse_0().get_GetResponse_0().res;
+            let latest_fb = FluentBitView::unmarshal(get_fb_resp.get_Ok_0());
+            if resp_o.is_Some() && resp.is_KResponse() && resp.get_KResponse_0().is_GetResponse()
+            && get_fb_resp.is_Ok() && latest_fb.is_Ok()
+            && state.latest_daemon_set_opt.is_Some() && state.latest_config_hash_opt.is_Some() {
+                // The status is written on top of the fluentbit just read from the API server (instead of the triggering one)
+                // so that the request carries the latest resource version and does not conflict with an earlier status update.
+                let req_o = APIRequest::UpdateStatusRequest(UpdateStatusRequest {
+                    namespace: fb_namespace,
+                    name: fb_name,
+                    obj: update_fb_status(latest_fb.get_Ok_0(), state.latest_daemon_set_opt.get_Some_0(), state.latest_config_hash_opt.get_Some_0()).marshal(),
+                });
+                let state_prime = FluentBitReconcileState {
+                    reconcile_step: FluentBitReconcileStep::AfterUpdateStatus,
+                    ..state
+                };
+                (state_prime, Some(RequestView::KRequest(req_o)))
+            } else {
+                report_error(fb, state)
+            }
+        },
+        FluentBitReconcileStep::AfterUpdateStatus => {
+            let update_status_resp = resp.get_KResponse_0().get_UpdateStatusResponse_0().res;
+            if resp_o.is_Some() && resp.is_KResponse() && resp.get_KResponse_0().is_UpdateStatusResponse()
+            && update_status_resp.is_Ok() {
+                let state_prime = FluentBitReconcileState {
+                    reconcile_step: FluentBitReconcileStep::Done,
+                    ..state
+                };
+                (state_prime, None)
+            } else {
+                let state_prime = FluentBitReconcileState {
+                    reconcile_step: FluentBitReconcileStep::Error,
+                    ..state
+                };
+                (state_prime, None)
+            }
+        },
+        FluentBitReconcileStep::AfterUpdateDegradedStatus => {
+            let state_prime = FluentBitReconcileState {
+                reconcile_step: FluentBitReconcileStep::Error,
+                ..state
+            };
+            (state_prime, None)
+        },
         _ => {
             let state_prime = FluentBitReconcileState {
                 reconcile_step: step,
@@ -107,13 +192,27 @@ pub open spec fn reconcile_core(
     }
 }
 
+// report_error marks the fluentbit as degraded before the reconcile ends in error.
+pub open spec fn report_error(fb: FluentBitView, state: FluentBitReconcileState) -> (FluentBitReconcileState, Option<RequestView<FluentBitAPIInputView>>) {
+    let req_o = APIRequest::UpdateStatusRequest(UpdateStatusRequest {
+        namespace: fb.metadata.namespace.get_Some_0(),
+        name: fb.metadata.name.get_Some_0(),
+        obj: update_fb_status_to_degraded(fb).marshal(),
+    });
+    let state_prime = FluentBitReconcileState {
+        reconcile_step: FluentBitReconcileStep::AfterUpdateDegradedStatus,
+        ..state
+    };
+    (state_prime, Some(RequestView::KRequest(req_o)))
+}
+
 pub open spec fn get_secret_req(fb: FluentBitView) -> GetRequest {
     GetRequest{ key: desired_secret_key(fb) }
 }
 
 pub open spec fn reconcile_helper<Builder: ResourceBuilder<FluentBitView, FluentBitReconcileState>>(
-    fb: FluentBitView, resp_o: Option<ResponseView<EmptyTypeView>>, state: FluentBitReconcileState
-) -> (FluentBitReconcileState, Option<RequestView<EmptyTypeView>>) {
+    fb: FluentBitView, resp_o: Option<ResponseView<FluentBitAPIOutputView>>, state: FluentBitReconcileState
+) -> (FluentBitReconcileState, Option<RequestView<FluentBitAPIInputView>>) {
     let step = state.reconcile_step;
     match step {
         FluentBitReconcileStep::AfterKRequestStep(action, resource) => {
@@ -137,11 +236,7 @@ pub open spec fn reconcile_helper<Builder: ResourceBuilder<FluentBitView, Fluent
                                 };
                                 (state_prime, Some(RequestView::KRequest(req_o)))
                             } else {
-                                let state_prime = FluentBitReconcileState {
-                                    reconcile_step: FluentBitReconcileStep::Error,
-                                    ..state
-                                };
-                                (state_prime, None)
+                                report_error(fb, state)
                             }
                         } else if get_resp.get_Err_0().is_ObjectNotFound() {
                             let new_obj = Builder::make(fb, state);
@@ -156,26 +251,14 @@ pub open spec fn reconcile_helper<Builder: ResourceBuilder<FluentBitView, Fluent
                                 };
                                 (state_prime, Some(RequestView::KRequest(req_o)))
                             } else {
-                                let state_prime = FluentBitReconcileState {
-                                    reconcile_step: FluentBitReconcileStep::Error,
-                                    ..state
-                                };
-                                (state_prime, None)
+                                report_error(fb, state)
                             }
                         } else {
-                            let state_prime = FluentBitReconcileState {
-                                reconcile_step: FluentBitReconcileStep::Error,
-                                ..state
-                            };
-                            (state_prime, None)
+                            report_error(fb, state)
                         }
                     } else {
                         // return error state
-                        let state_prime = FluentBitReconcileState {
-                            reconcile_step: FluentBitReconcileStep::Error,
-                            ..state
-                        };
-                        (state_prime, None)
+                        report_error(fb, state)
                     }
                 },
                 ActionKind::Create => {
@@ -188,19 +271,11 @@ pub open spec fn reconcile_helper<Builder: ResourceBuilder<FluentBitView, Fluent
                             let req_o = if req.is_Some() { Some(RequestView::KRequest(req.get_Some_0())) } else { None };
                             (state_prime, req_o)
                         } else {
-                            let state_prime = FluentBitReconcileState {
-                                reconcile_step: FluentBitReconcileStep::Error,
-                                ..state
-                            };
-                            (state_prime, None)
+                            report_error(fb, state)
                         }
                     } else {
                         // return error state
-                        let state_prime = FluentBitReconcileState {
-                            reconcile_step: FluentBitReconcileStep::Error,
-                            ..state
-                        };
-                        (state_prime, None)
+                        report_error(fb, state)
                     }
                 },
                 ActionKind::Update => {
@@ -213,19 +288,11 @@ pub open spec fn reconcile_helper<Builder: ResourceBuilder<FluentBitView, Fluent
                             let req_o = if req.is_Some() { Some(RequestView::KRequest(req.get_Some_0())) } else { None };
                             (state_prime, req_o)
                         } else {
-                            let state_prime = FluentBitReconcileState {
-                                reconcile_step: FluentBitReconcileStep::Error,
-                                ..state
-                            };
-                            (state_prime, None)
+                            report_error(fb, state)
                         }
                     } else {
                         // return error state
-                        let state_prime = FluentBitReconcileState {
-                            reconcile_step: FluentBitReconcileStep::Error,
-                            ..state
-                        };
-                        (state_prime, None)
+                        report_error(fb, state)
                     }
                 },
             }
@@ -7,8 +7,8 @@ use crate::fluent_controller::fluentbit::model::resource::{
 };
 use crate::fluent_controller::fluentbit::trusted::{spec_types::*, step::*};
 use crate::kubernetes_api_objects::spec::{
-    container::*, label_selector::*, pod_template_spec::*, prelude::*, resource_requirements::*,
-    volume::*,
+    condition::*, container::*, label_selector::*, pod_template_spec::*, prelude::*,
+    resource_requirements::*, volume::*,
 };
 use crate::reconciler::spec::{io::*, reconciler::*, resource_builder::*};
 use crate::vstd_ext::string_view::*;
@@ -41,11 +41,12 @@ impl ResourceBuilder<FluentBitView, FluentBitReconcileState> for DaemonSetBuilde
     open spec fn state_after_create(fb: FluentBitView, obj: DynamicObjectView, state: FluentBitReconcileState) -> (res: Result<(FluentBitReconcileState, Option<APIRequest>), ()>) {
         let ds = DaemonSetView::unmarshal(obj);
         if ds.is_Ok() {
+            let req = APIRequest::GetRequest(GetRequest { key: make_daemon_set_key(fb) });
             let state_prime = FluentBitReconcileState {
-                reconcile_step: FluentBitReconcileStep::Done,
+                reconcile_step: FluentBitReconcileStep::AfterGetDaemonSetStatus,
                 ..state
             };
-            Ok((state_prime, None))
+            Ok((state_prime, Some(req)))
         } else {
             Err(())
         }
@@ -54,11 +55,12 @@ impl ResourceBuilder<FluentBitView, FluentBitReconcileState> for DaemonSetBuilde
     open spec fn state_after_update(fb: FluentBitView, obj: DynamicObjectView, state: FluentBitReconcileState) -> (res: Result<(FluentBitReconcileState, Option<APIRequest>), ()>) {
         let ds = DaemonSetView::unmarshal(obj);
         if ds.is_Ok() {
+            let req = APIRequest::GetRequest(GetRequest { key: make_daemon_set_key(fb) });
             let state_prime = FluentBitReconcileState {
-                reconcile_step: FluentBitReconcileStep::Done,
+                reconcile_step: FluentBitReconcileStep::AfterGetDaemonSetStatus,
                 ..state
             };
-            Ok((state_prime, None))
+            Ok((state_prime, Some(req)))
         } else {
             Err(())
         }
@@ -314,5 +316,26 @@ pub open spec fn make_env(fluentbit: FluentBitView) -> Seq<EnvVarView> {
     ]
 }
 
+pub open spec fn update_fb_status(fb: FluentBitView, ds: DaemonSetView, config_hash: StringView) -> FluentBitView {
+    let ds_status = ds.status.get_Some_0();
+    let ready = ds.status.is_Some()
+        && ds_status.number_ready == ds_status.desired_number_scheduled
+        && ds_status.updated_number_scheduled == ds_status.desired_number_scheduled;
+    let status = if ds.status.is_Some() {
+        FluentBitStatusView::default()
+            .set_desired_number_scheduled(ds_status.desired_number_scheduled)
+            .set_number_ready(ds_status.number_ready)
+            .set_updated_number_scheduled(ds_status.updated_number_scheduled)
+    } else {
+        FluentBitStatusView::default()
+    };
+    fb.set_status(status.set_config_hash(config_hash).set_conditions(make_conditions(ready, false, fb.metadata.generation)))
+}
+
+// The degraded status keeps the daemon set counters reported by the last successful reconcile.
+pub open spec fn update_fb_status_to_degraded(fb: FluentBitView) -> FluentBitView {
+    let status = if fb.status.is_Some() { fb.status.get_Some_0() } else { FluentBitStatusView::default() };
+    fb.set_status(status.set_conditions(make_conditions(false, true, fb.metadata.generation)))
+}
 
 }
@@ -0,0 +1,80 @@
+// Copyright 2022 VMware, Inc.
+// SPDX-License-Identifier: MIT
+#![allow(unused_imports)]
+use super::predicate::*;
+use crate::fluent_controller::fluentbit::{
+    model::{reconciler::*, resource::*},
+    proof::{predicate::*, resource::*},
+    trusted::{fluentbit_api_spec::*, liveness_theorem::*, spec_types::*, step::*},
+};
+use crate::kubernetes_api_objects::spec::{
+    api_method::*, common::*, dynamic::*, owner_reference::*, prelude::*, resource::*,
+};
+use crate::kubernetes_cluster::spec::{
+    cluster::*,
+    cluster_state_machine::Step,
+    controller::types::{ControllerActionInput, ControllerStep},
+    message::*,
+};
+use crate::temporal_logic::{defs::*, rules::*};
+use vstd::{prelude::*, string::*};
+
+verus! {
+
+#[verifier(spinoff_prover)]
+pub proof fn lemma_eventually_always_every_reload_config_request_carries_the_desired_config_hash(spec: TempPred<FBCluster>, fb: FluentBitView)
+    requires
+        spec.entails(always(lift_action(FBCluster::next()))),
+        spec.entails(tla_forall(|i| FBCluster::kubernetes_api_next().weak_fairness(i))),
+        spec.entails(tla_forall(|i| FBCluster::external_api_next().weak_fairness(i))),
+        spec.entails(always(lift_state(FBCluster::every_in_flight_msg_has_lower_id_than_allocator()))),
+        spec.entails(always(lift_state(FBCluster::each_object_in_reconcile_has_consistent_key_and_valid_metadata()))),
+        spec.entails(always(lift_state(config_hash_is_the_same_as_etcd_server_secret_if_secret_fetched(fb)))),
+        spec.entails(always(lift_action(desired_config_is_stable(fb)))),
+    ensures spec.entails(true_pred().leads_to(always(lift_state(every_reload_config_request_carries_the_desired_config_hash(fb))))),
+{
+    let key = fb.object_ref();
+    let requirements = |msg: FBMessage, s: FBCluster| {
+        reload_config_request_msg(fb)(msg)
+        ==> msg.content.get_ExternalAPIRequest_0().get_ReloadConfigRequest_2() == desired_config_hash(fb, s.resources())
+    };
+    let stronger_next = |s: FBCluster, s_prime: FBCluster| {
+        &&& FBCluster::next()(s, s_prime)
+        &&& FBCluster::each_object_in_reconcile_has_consistent_key_and_valid_metadata()(s)
+        &&& config_hash_is_the_same_as_etcd_server_secret_if_secret_fetched(fb)(s)
+        &&& desired_config_is_stable(fb)(s, s_prime)
+    };
+    assert forall |s, s_prime| #[trigger] stronger_next(s, s_prime)
+    implies FBCluster::every_new_req_msg_if_in_flight_then_satisfies(requirements)(s, s_prime) by {
+        assert(desired_config_hash(fb, s.resources()) == desired_config_hash(fb, s_prime.resources()));
+        assert forall |msg: FBMessage| (!s.in_flight().contains(msg) || requirements(msg, s)) && #[trigger] s_prime.in_flight().contains(msg)
+        implies requirements(msg, s_prime) by {
+            if reload_config_request_msg(fb)(msg) && !s.in_flight().contains(msg) {
+                // Only the reconcile of fb at the step after reading the daemon set status sends a reload request
+                // for the daemon set of fb, and it sends the config hash it keeps.
+                let step = choose |step| FBCluster::next_step(s, s_prime, step);
+                assert(step.is_ControllerStep());
+                let cr_key = step.get_ControllerStep_0().1.get_Some_0();
+                assert(s.ongoing_reconciles().contains_key(cr_key));
+                assert(s.ongoing_reconciles()[cr_key].local_state.reconcile_step == FluentBitReconcileStep::AfterGetDaemonSetStatus);
+                assert(cr_key == key);
+            }
+        }
+    }
+    invariant_n!(
+        spec, lift_action(stronger_next), lift_action(FBCluster::every_new_req_msg_if_in_flight_then_satisfies(requirements)),
+        lift_action(FBCluster::next()),
+        lift_state(FBCluster::each_object_in_reconcile_has_consistent_key_and_valid_metadata()),
+        lift_state(config_hash_is_the_same_as_etcd_server_secret_if_secret_fetched(fb)),
+        lift_action(desired_config_is_stable(fb))
+    );
+
+    FBCluster::lemma_true_leads_to_always_every_in_flight_req_msg_satisfies(spec, requirements);
+
+    temp_pred_equality(
+        lift_state(every_reload_config_request_carries_the_desired_config_hash(fb)),
+        lift_state(FBCluster::every_in_flight_req_msg_satisfies(requirements))
+    );
+}
+
+}
@@ -1,11 +1,13 @@
 // Copyright 2022 VMware, Inc.
 // SPDX-License-Identifier: MIT
+pub mod fluentbit_api;
 pub mod owner_ref;
 pub mod predicate;
 pub mod proof;
 pub mod unchangeable;
 pub mod validation;
 
+pub use fluentbit_api::*;
 pub use owner_ref::*;
 pub use predicate::*;
 pub use proof::*;
@@ -1,14 +1,14 @@
 // Copyright 2022 VMware, Inc.
 // SPDX-License-Identifier: MIT
 #![allow(unused_imports)]
-use crate::external_api::spec::{EmptyAPI, EmptyTypeView};
 use crate::fluent_controller::fluentbit::{
     model::reconciler::*,
     proof::{predicate::*, resource::*},
-    trusted::{spec_types::*, step::*},
+    trusted::{liveness_theorem::*, spec_types::*, step::*},
 };
 use crate::kubernetes_api_objects::spec::{
     api_method::*, common::*, config_map::*, daemon_set::*, owner_reference::*, resource::*,
+    secret::*,
 };
 use crate::kubernetes_cluster::spec::{
     cluster::*,
@@ -251,4 +251,62 @@ pub open spec fn daemon_set_not_exists_or_matches_or_no_more_status_update(fb: F
     }
 }
 
+// Once the config secret is fetched, the reconcile keeps the hash of the config in the secret, which stays the same
+// as long as the data of the secret does not change.
+pub open spec fn config_hash_is_the_same_as_etcd_server_secret_if_secret_fetched(fb: FluentBitView) -> StatePred<FBCluster> {
+    |s: FBCluster| {
+        let key = fb.object_ref();
+        let secret_key = desired_secret_key(fb);
+        let local_state = s.ongoing_reconciles()[key].local_state;
+        let pending_req = s.ongoing_reconciles()[key].pending_req_msg.get_Some_0();
+        s.ongoing_reconciles().contains_key(key)
+        ==> match local_state.reconcile_step {
+            FluentBitReconcileStep::AfterGetSecret => {
+                &&& s.ongoing_reconciles()[key].pending_req_msg.is_Some()
+                &&& resource_get_request_msg(secret_key)(pending_req)
+                &&& forall |msg: FBMessage|
+                    #[trigger] s.in_flight().contains(msg)
+                    && Message::resp_msg_matches_req_msg(msg, pending_req)
+                    && msg.content.is_get_response()
+                    && msg.content.get_get_response().res.is_Ok()
+                    ==> {
+                        &&& s.resources().contains_key(secret_key)
+                        &&& SecretView::unmarshal(s.resources()[secret_key]).is_Ok()
+                        &&& SecretView::unmarshal(msg.content.get_get_response().res.get_Ok_0()).is_Ok()
+                        &&& SecretView::unmarshal(msg.content.get_get_response().res.get_Ok_0()).get_Ok_0().data
+                            == SecretView::unmarshal(s.resources()[secret_key]).get_Ok_0().data
+                    }
+            },
+            FluentBitReconcileStep::AfterKRequestStep(_, _) | FluentBitReconcileStep::AfterGetDaemonSetStatus => {
+                latest_config_hash_is_the_same_as_etcd_server_secret(fb)(s)
+            },
+            _ => true,
+        }
+    }
+}
+
+// Every reload request for the daemon set of fb carries the hash of the config currently in the secret,
+// so handling a pending reload request never makes the pods go back to an older config.
+pub open spec fn every_reload_config_request_carries_the_desired_config_hash(fb: FluentBitView) -> StatePred<FBCluster> {
+    |s: FBCluster| {
+        forall |msg: FBMessage| {
+            &&& #[trigger] s.in_flight().contains(msg)
+            &&& reload_config_request_msg(fb)(msg)
+        } ==> msg.content.get_ExternalAPIRequest_0().get_ReloadConfigRequest_2() == desired_config_hash(fb, s.resources())
+    }
+}
+
+pub open spec fn every_update_status_request_of_fb_is_the_pending_req(fb: FluentBitView) -> StatePred<FBCluster> {
+    |s: FBCluster| {
+        let key = fb.object_ref();
+        forall |msg: FBMessage| {
+            &&& s.in_flight().contains(msg)
+            &&& #[trigger] resource_update_status_request_msg(key)(msg)
+        } ==> {
+            &&& at_fb_step(key, FluentBitReconcileStep::AfterUpdateStatus)(s) || at_fb_step(key, FluentBitReconcileStep::AfterUpdateDegradedStatus)(s)
+            &&& FBCluster::pending_req_msg_is(s, key, msg)
+        }
+    }
+}
+
 }
@@ -981,6 +981,83 @@ pub proof fn lemma_eventually_always_resource_object_only_has_owner_reference_po
     );
 }
 
+#[verifier(spinoff_prover)]
+pub proof fn lemma_eventually_always_config_hash_is_the_same_as_etcd_server_secret_if_secret_fetched(spec: TempPred<FBCluster>, fb: FluentBitView)
+    requires
+        spec.entails(always(lift_action(FBCluster::next()))),
+        spec.entails(always(lift_state(FBCluster::each_object_in_reconcile_has_consistent_key_and_valid_metadata()))),
+        spec.entails(always(lift_state(FBCluster::every_in_flight_msg_has_unique_id()))),
+        spec.entails(always(lift_state(FBCluster::every_in_flight_msg_has_lower_id_than_allocator()))),
+        spec.entails(always(lift_state(FBCluster::the_object_in_reconcile_has_spec_and_uid_as(fb)))),
+        spec.entails(always(lift_action(desired_config_is_stable(fb)))),
+        spec.entails(true_pred().leads_to(lift_state(|s: FBCluster| !s.ongoing_reconciles().contains_key(fb.object_ref())))),
+    ensures spec.entails(true_pred().leads_to(always(lift_state(config_hash_is_the_same_as_etcd_server_secret_if_secret_fetched(fb))))),
+{
+    let key = fb.object_ref();
+    let inv = config_hash_is_the_same_as_etcd_server_secret_if_secret_fetched(fb);
+    let secret_key = desired_secret_key(fb);
+    let next = |s: FBCluster, s_prime: FBCluster| {
+        &&& FBCluster::next()(s, s_prime)
+        &&& FBCluster::each_object_in_reconcile_has_consistent_key_and_valid_metadata()(s)
+        &&& FBCluster::every_in_flight_msg_has_unique_id()(s)
+        &&& FBCluster::every_in_flight_msg_has_lower_id_than_allocator()(s)
+        &&& FBCluster::the_object_in_reconcile_has_spec_and_uid_as(fb)(s)
+        &&& desired_config_is_stable(fb)(s, s_prime)
+    };
+    combine_spec_entails_always_n!(
+        spec, lift_action(next), lift_action(FBCluster::next()),
+        lift_state(FBCluster::each_object_in_reconcile_has_consistent_key_and_valid_metadata()),
+        lift_state(FBCluster::every_in_flight_msg_has_unique_id()),
+        lift_state(FBCluster::every_in_flight_msg_has_lower_id_than_allocator()),
+        lift_state(FBCluster::the_object_in_reconcile_has_spec_and_uid_as(fb)),
+        lift_action(desired_config_is_stable(fb))
+    );
+    leads_to_weaken(
+        spec, true_pred(), lift_state(|s: FBCluster| !s.ongoing_reconciles().contains_key(fb.object_ref())),
+        true_pred(), lift_state(inv)
+    );
+    assert forall |s, s_prime| inv(s) && #[trigger] next(s, s_prime) implies inv(s_prime) by {
+        // The data of the secret, and thus the hash of the config, stays the same across every step.
+        assert(desired_config_hash(fb, s.resources()) == desired_config_hash(fb, s_prime.resources()));
+        if s_prime.ongoing_reconciles().contains_key(key) {
+            let step = choose |step| FBCluster::next_step(s, s_prime, step);
+            match step {
+                Step::ApiServerStep(input) => {
+                    let req = input.get_Some_0();
+                    if at_fb_step(key, FluentBitReconcileStep::AfterGetSecret)(s) {
+                        let pending_req = s.ongoing_reconciles()[key].pending_req_msg.get_Some_0();
+                        assert forall |msg: FBMessage|
+                            #[trigger] s_prime.in_flight().contains(msg)
+                            && Message::resp_msg_matches_req_msg(msg, pending_req)
+                            && msg.content.is_get_response()
+                            && msg.content.get_get_response().res.is_Ok()
+                        implies SecretView::unmarshal(msg.content.get_get_response().res.get_Ok_0()).get_Ok_0().data
+                            == SecretView::unmarshal(s_prime.resources()[secret_key]).get_Ok_0().data by {
+                            if !s.in_flight().contains(msg) {
+                                assert(req.content.get_rest_id() == pending_req.content.get_rest_id());
+                                assert(req == pending_req);
+                            }
+                        }
+                    }
+                },
+                Step::ControllerStep(input) => {
+                    if input.1.get_Some_0() == key && at_fb_step(key, FluentBitReconcileStep::Init)(s) {
+                        assert forall |msg: FBMessage| #[trigger] s_prime.in_flight().contains(msg)
+                        implies !Message::resp_msg_matches_req_msg(msg, s_prime.ongoing_reconciles()[key].pending_req_msg.get_Some_0()) by {
+                            assert(msg.content.get_rest_id() < s.rest_id_allocator.rest_id_counter);
+                        }
+                    } else if input.1.get_Some_0() == key && at_fb_step(key, FluentBitReconcileStep::AfterGetSecret)(s) {
+                        let resp = input.0.get_Some_0();
+                        assert(s.in_flight().contains(resp));
+                    }
+                },
+                _ => {},
+            }
+        }
+    }
+    leads_to_stable(spec, lift_action(next), true_pred(), lift_state(inv));
+}
+
 pub proof fn leads_to_always_tla_forall_subresource(spec: TempPred<FBCluster>, p: TempPred<FBCluster>, a_to_p: spec_fn(SubResource)->TempPred<FBCluster>)
     requires forall |a: SubResource| spec.entails(p.leads_to(always(#[trigger] a_to_p(a)))),
     ensures spec.entails(p.leads_to(always(tla_forall(a_to_p)))),
@@ -1108,5 +1185,67 @@ pub proof fn lemma_always_no_create_resource_request_msg_without_name_in_flight(
     init_invariant(spec, FBCluster::init(), FBCluster::next(), inv);
 }
 
+#[verifier(spinoff_prover)]
+pub proof fn lemma_eventually_always_every_update_status_request_of_fb_is_the_pending_req(spec: TempPred<FBCluster>, fb: FluentBitView)
+    requires
+        spec.entails(always(lift_action(FBCluster::next()))),
+        spec.entails(tla_forall(|i| FBCluster::kubernetes_api_next().weak_fairness(i))),
+        spec.entails(tla_forall(|i| FBCluster::external_api_next().weak_fairness(i))),
+        spec.entails(always(lift_state(FBCluster::every_in_flight_msg_has_lower_id_than_allocator()))),
+        spec.entails(always(lift_state(FBCluster::crash_disabled()))),
+        spec.entails(always(lift_state(FBCluster::busy_disabled()))),
+        spec.entails(always(lift_state(FBCluster::each_object_in_reconcile_has_consistent_key_and_valid_metadata()))),
+        spec.entails(always(lift_state(FBCluster::every_in_flight_msg_has_unique_id()))),
+    ensures spec.entails(true_pred().leads_to(always(lift_state(every_update_status_request_of_fb_is_the_pending_req(fb))))),
+{
+    let key = fb.object_ref();
+    let requirements = |msg: FBMessage, s: FBCluster| {
+        resource_update_status_request_msg(key)(msg) ==> {
+            &&& at_fb_step(key, FluentBitReconcileStep::AfterUpdateStatus)(s) || at_fb_step(key, FluentBitReconcileStep::AfterUpdateDegradedStatus)(s)
+            &&& FBCluster::pending_req_msg_is(s, key, msg)
+        }
+    };
+    let stronger_next = |s: FBCluster, s_prime: FBCluster| {
+        &&& FBCluster::next()(s, s_prime)
+        &&& FBCluster::crash_disabled()(s)
+        &&& FBCluster::busy_disabled()(s)
+        &&& FBCluster::each_object_in_reconcile_has_consistent_key_and_valid_metadata()(s)
+        &&& FBCluster::every_in_flight_msg_has_unique_id()(s)
+    };
+    assert forall |s, s_prime| #[trigger] stronger_next(s, s_prime)
+    implies FBCluster::every_new_req_msg_if_in_flight_then_satisfies(requirements)(s, s_prime) by {
+        assert forall |msg: FBMessage| (!s.in_flight().contains(msg) || requirements(msg, s)) && #[trigger] s_prime.in_flight().contains(msg)
+        implies requirements(msg, s_prime) by {
+            if resource_update_status_request_msg(key)(msg) {
+                let step = choose |step| FBCluster::next_step(s, s_prime, step);
+                if !s.in_flight().contains(msg) {
+                    // Only the reconcile of the fluentbit object sends update status requests for it, and it always waits for
+                    // the response at AfterUpdateStatus or AfterUpdateDegradedStatus.
+                    assert(step.is_ControllerStep());
+                    let cr_key = step.get_ControllerStep_0().1.get_Some_0();
+                    assert(s.ongoing_reconciles().contains_key(cr_key));
+                    assert(s.ongoing_reconciles()[cr_key].triggering_cr.object_ref() == cr_key);
+                    assert(cr_key == key);
+                    assert(FBCluster::pending_req_msg_is(s_prime, key, msg));
+                } else {
+                    assert(requirements(msg, s));
+                    assert(s.ongoing_reconciles()[key] == s_prime.ongoing_reconciles()[key]);
+                }
+            }
+        }
+    }
+    invariant_n!(
+        spec, lift_action(stronger_next), lift_action(FBCluster::every_new_req_msg_if_in_flight_then_satisfies(requirements)),
+        lift_action(FBCluster::next()), lift_state(FBCluster::crash_disabled()), lift_state(FBCluster::busy_disabled()),
+        lift_state(FBCluster::each_object_in_reconcile_has_consistent_key_and_valid_metadata()),
+        lift_state(FBCluster::every_in_flight_msg_has_unique_id())
+    );
+
+    FBCluster::lemma_true_leads_to_always_every_in_flight_req_msg_satisfies(spec, requirements);
+
+    temp_pred_equality(
+        lift_state(every_update_status_request_of_fb_is_the_pending_req(fb)),
+        lift_state(FBCluster::every_in_flight_req_msg_satisfies(requirements)));
+}
 
 }
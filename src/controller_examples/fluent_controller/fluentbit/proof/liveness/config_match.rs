@@ -0,0 +1,184 @@
+// Copyright 2022 VMware, Inc.
+// SPDX-License-Identifier: MIT
+#![allow(unused_imports)]
+use crate::external_api::spec::*;
+use crate::fluent_controller::fluentbit::{
+    model::{reconciler::*, resource::*},
+    proof::{helper_invariants, liveness::status_match::*, predicate::*, resource::*},
+    trusted::{fluentbit_api_spec::*, liveness_theorem::*, maker::*, spec_types::*, step::*},
+};
+use crate::kubernetes_api_objects::spec::{
+    api_method::*, common::*, dynamic::*, owner_reference::*, prelude::*, resource::*,
+};
+use crate::kubernetes_cluster::spec::{
+    cluster::*,
+    cluster_state_machine::Step,
+    controller::types::{ControllerActionInput, ControllerStep},
+    message::*,
+};
+use crate::temporal_logic::{defs::*, rules::*};
+use vstd::{prelude::*, string::*};
+
+verus! {
+
+// The reconcile that reaches the daemon set goes on to send the reload request with the hash of the current config,
+// and the fluent-bit pods reload the config once the request is handled.
+pub proof fn lemma_from_after_get_daemon_set_step_to_config_is_reloaded(spec: TempPred<FBCluster>, fb: FluentBitView)
+    requires
+        spec.entails(always(lift_action(FBCluster::next()))),
+        spec.entails(tla_forall(|i| FBCluster::controller_next().weak_fairness(i))),
+        spec.entails(tla_forall(|i| FBCluster::kubernetes_api_next().weak_fairness(i))),
+        spec.entails(tla_forall(|i| FBCluster::external_api_next().weak_fairness(i))),
+        spec.entails(always(lift_state(FBCluster::crash_disabled()))),
+        spec.entails(always(lift_state(FBCluster::busy_disabled()))),
+        spec.entails(always(lift_state(FBCluster::pending_req_of_key_is_unique_with_unique_id(fb.object_ref())))),
+        spec.entails(always(lift_state(FBCluster::each_object_in_etcd_is_well_formed()))),
+        spec.entails(always(lift_state(FBCluster::every_in_flight_msg_has_unique_id()))),
+        spec.entails(always(lift_state(desired_state_is(fb)))),
+        spec.entails(always(lift_state(helper_invariants::the_object_in_reconcile_satisfies_state_validation(fb.object_ref())))),
+        spec.entails(always(lift_state(helper_invariants::every_resource_update_request_implies_at_after_update_resource_step(SubResource::DaemonSet, fb)))),
+        spec.entails(always(lift_state(helper_invariants::no_delete_resource_request_msg_in_flight(SubResource::DaemonSet, fb)))),
+        spec.entails(always(lift_state(helper_invariants::resource_object_only_has_owner_reference_pointing_to_current_cr(SubResource::DaemonSet, fb)))),
+        spec.entails(always(lift_state(helper_invariants::resource_object_has_no_finalizers_or_timestamp_and_only_has_controller_owner_ref(SubResource::DaemonSet, fb)))),
+        spec.entails(always(lift_state(helper_invariants::daemon_set_in_etcd_satisfies_unchangeable(fb)))),
+        spec.entails(always(lift_state(helper_invariants::config_hash_is_the_same_as_etcd_server_secret_if_secret_fetched(fb)))),
+        spec.entails(always(lift_state(sub_resource_state_matches(SubResource::DaemonSet, fb)))),
+        spec.entails(always(lift_action(desired_config_is_stable(fb)))),
+        spec.entails(always(lift_action(daemon_set_status_is_stable::<FluentBitMaker>(fb)))),
+    ensures
+        spec.entails(lift_state(pending_req_in_flight_at_after_get_resource_step(SubResource::DaemonSet, fb)).leads_to(lift_state(config_is_reloaded::<FluentBitMaker>(fb)))),
+{
+    let p0 = lift_state(pending_req_in_flight_at_after_get_resource_step(SubResource::DaemonSet, fb));
+    let p1 = lift_state(pending_req_in_flight_at_after_reload_config_step(fb));
+    let post = lift_state(config_is_reloaded::<FluentBitMaker>(fb));
+
+    lemma_from_after_get_daemon_set_step_to_after_reload_config_step(spec, fb);
+
+    assert_by(spec.entails(p1.leads_to(post)), {
+        let pre = |req_msg| lift_state(req_msg_is_the_in_flight_pending_req_at_after_reload_config_step(fb, req_msg));
+        assert forall |req_msg| spec.entails(#[trigger] pre(req_msg).leads_to(post)) by {
+            lemma_config_is_reloaded_at_after_reload_config_step(spec, fb, req_msg);
+        }
+        leads_to_exists_intro(spec, pre, post);
+        assert_by(tla_exists(pre) == p1, {
+            assert forall |ex| #[trigger] p1.satisfied_by(ex) implies tla_exists(pre).satisfied_by(ex) by {
+                let req_msg = ex.head().ongoing_reconciles()[fb.object_ref()].pending_req_msg.get_Some_0();
+                assert(pre(req_msg).satisfied_by(ex));
+            }
+            temp_pred_equality(tla_exists(pre), p1);
+        });
+    });
+
+    leads_to_trans(spec, p0, p1, post);
+}
+
+#[verifier(spinoff_prover)]
+proof fn lemma_config_is_reloaded_at_after_reload_config_step(spec: TempPred<FBCluster>, fb: FluentBitView, req_msg: FBMessage)
+    requires
+        spec.entails(always(lift_action(FBCluster::next()))),
+        spec.entails(tla_forall(|i| FBCluster::external_api_next().weak_fairness(i))),
+        spec.entails(always(lift_state(FBCluster::crash_disabled()))),
+        spec.entails(always(lift_state(FBCluster::busy_disabled()))),
+        spec.entails(always(lift_state(FBCluster::every_in_flight_msg_has_unique_id()))),
+        spec.entails(always(lift_state(sub_resource_state_matches(SubResource::DaemonSet, fb)))),
+        spec.entails(always(lift_action(desired_config_is_stable(fb)))),
+        spec.entails(always(lift_action(daemon_set_status_is_stable::<FluentBitMaker>(fb)))),
+    ensures
+        spec.entails(lift_state(req_msg_is_the_in_flight_pending_req_at_after_reload_config_step(fb, req_msg))
+            .leads_to(lift_state(config_is_reloaded::<FluentBitMaker>(fb)))),
+{
+    let pre = req_msg_is_the_in_flight_pending_req_at_after_reload_config_step(fb, req_msg);
+    let post = config_is_reloaded::<FluentBitMaker>(fb);
+    let input = Some(req_msg);
+    let stronger_next = |s, s_prime: FBCluster| {
+        &&& FBCluster::next()(s, s_prime)
+        &&& FBCluster::crash_disabled()(s)
+        &&& FBCluster::busy_disabled()(s)
+        &&& FBCluster::every_in_flight_msg_has_unique_id()(s)
+        &&& sub_resource_state_matches(SubResource::DaemonSet, fb)(s)
+        &&& desired_config_is_stable(fb)(s, s_prime)
+        &&& daemon_set_status_is_stable::<FluentBitMaker>(fb)(s, s_prime)
+    };
+    combine_spec_entails_always_n!(
+        spec, lift_action(stronger_next),
+        lift_action(FBCluster::next()),
+        lift_state(FBCluster::crash_disabled()),
+        lift_state(FBCluster::busy_disabled()),
+        lift_state(FBCluster::every_in_flight_msg_has_unique_id()),
+        lift_state(sub_resource_state_matches(SubResource::DaemonSet, fb)),
+        lift_action(desired_config_is_stable(fb)),
+        lift_action(daemon_set_status_is_stable::<FluentBitMaker>(fb))
+    );
+
+    assert forall |s, s_prime| pre(s) && #[trigger] stronger_next(s, s_prime) && FBCluster::external_api_next().forward(input)(s, s_prime)
+    implies post(s_prime) by {
+        assert(daemon_set_key(make_daemon_set_name(fb), fb.metadata.namespace.get_Some_0()) == make_daemon_set_key(fb));
+        assert(s.resources().contains_key(make_daemon_set_key(fb)));
+        assert(s_prime.resources() == s.resources());
+    }
+
+    assert forall |s, s_prime| pre(s) && #[trigger] stronger_next(s, s_prime) implies pre(s_prime) || post(s_prime) by {
+        let step = choose |step| FBCluster::next_step(s, s_prime, step);
+        match step {
+            Step::ExternalAPIStep(input) => {
+                if input.get_Some_0() == req_msg {
+                    assert(daemon_set_key(make_daemon_set_name(fb), fb.metadata.namespace.get_Some_0()) == make_daemon_set_key(fb));
+                    assert(s.resources().contains_key(make_daemon_set_key(fb)));
+                    assert(post(s_prime));
+                }
+            },
+            _ => {}
+        }
+    }
+
+    FBCluster::lemma_pre_leads_to_post_by_external_api(
+        spec, input, stronger_next, FBCluster::handle_external_request(), pre, post
+    );
+}
+
+// Every reload request for the daemon set of fb carries the hash of the current config, and the config stays the same,
+// so the reloaded config hash of the daemon set never goes stale once it matches.
+#[verifier(spinoff_prover)]
+pub proof fn lemma_config_is_reloaded_is_stable(spec: TempPred<FBCluster>, fb: FluentBitView, p: TempPred<FBCluster>)
+    requires
+        spec.entails(p.leads_to(lift_state(config_is_reloaded::<FluentBitMaker>(fb)))),
+        spec.entails(always(lift_action(FBCluster::next()))),
+        spec.entails(always(lift_state(helper_invariants::every_reload_config_request_carries_the_desired_config_hash(fb)))),
+        spec.entails(always(lift_action(desired_config_is_stable(fb)))),
+    ensures spec.entails(p.leads_to(always(lift_state(config_is_reloaded::<FluentBitMaker>(fb))))),
+{
+    let post = config_is_reloaded::<FluentBitMaker>(fb);
+    let ds_key = make_daemon_set_key(fb);
+    let stronger_next = |s, s_prime: FBCluster| {
+        &&& FBCluster::next()(s, s_prime)
+        &&& helper_invariants::every_reload_config_request_carries_the_desired_config_hash(fb)(s)
+        &&& desired_config_is_stable(fb)(s, s_prime)
+    };
+    combine_spec_entails_always_n!(
+        spec, lift_action(stronger_next),
+        lift_action(FBCluster::next()),
+        lift_state(helper_invariants::every_reload_config_request_carries_the_desired_config_hash(fb)),
+        lift_action(desired_config_is_stable(fb))
+    );
+
+    assert forall |s, s_prime: FBCluster| post(s) && #[trigger] stronger_next(s, s_prime) implies post(s_prime) by {
+        assert(desired_config_hash(fb, s.resources()) == desired_config_hash(fb, s_prime.resources()));
+        let step = choose |step| FBCluster::next_step(s, s_prime, step);
+        match step {
+            Step::ExternalAPIStep(input) => {
+                let req = input.get_Some_0();
+                let content = req.content.get_ExternalAPIRequest_0();
+                if daemon_set_key(content.get_ReloadConfigRequest_0(), content.get_ReloadConfigRequest_1()) == ds_key {
+                    assert(reload_config_request_msg(fb)(req));
+                } else {
+                    assert(s_prime.external_state().reloaded_config_hash[ds_key] == s.external_state().reloaded_config_hash[ds_key]);
+                }
+            },
+            _ => {}
+        }
+    }
+
+    leads_to_stable(spec, lift_action(stronger_next), p, lift_state(post));
+}
+
+}
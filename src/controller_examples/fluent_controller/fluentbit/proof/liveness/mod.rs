@@ -1,7 +1,9 @@
 // Copyright 2022 VMware, Inc.
 // SPDX-License-Identifier: MIT
+pub mod config_match;
 pub mod daemon_set_match;
 pub mod proof;
 pub mod resource_match;
+pub mod status_match;
 pub mod spec;
 pub mod terminate;
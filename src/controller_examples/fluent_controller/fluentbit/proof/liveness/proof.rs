@@ -13,6 +13,8 @@ use crate::fluent_controller::fluentbit::{
             },
             resource_match::*,
             spec::*,
+            config_match::*,
+            status_match::lemma_from_after_get_daemon_set_step_to_status_reflects_daemon_set,
             terminate,
         },
         predicate::*,
@@ -87,6 +89,230 @@ proof fn spec_before_phase_n_entails_true_leads_to_current_state_matches(i: nat,
     leads_to_trans(spec_before_phase_n(i, fb), true_pred(), invariants_since_phase_n(i, fb), always(lift_state(current_state_matches::<FluentBitMaker>(fb))));
 }
 
+// We prove init /\ []next /\ []wf |= []desired_state_is(fb) /\ []desired_config_is_stable(fb) /\ []daemon_set_status_is_stable(fb)
+// ~> []config_is_reloaded(fb) holds for each fb.
+proof fn config_liveness_proof_forall_fb()
+    ensures config_liveness_theorem::<FluentBitMaker>(),
+{
+    assert forall |fb: FluentBitView| #[trigger] cluster_spec().entails(config_liveness::<FluentBitMaker>(fb)) by {
+        config_liveness_proof(fb);
+    };
+    spec_entails_tla_forall(cluster_spec(), |fb: FluentBitView| config_liveness::<FluentBitMaker>(fb));
+}
+
+proof fn config_liveness_proof(fb: FluentBitView)
+    ensures cluster_spec().entails(config_liveness::<FluentBitMaker>(fb)),
+{
+    let stability = config_stability(fb);
+    let post = always(lift_state(config_is_reloaded::<FluentBitMaker>(fb)));
+    assumption_and_invariants_of_all_phases_is_stable(fb);
+    lemma_true_leads_to_always_config_is_reloaded(fb);
+    reveal_with_fuel(spec_before_phase_n, 8);
+    spec_before_phase_n_entails_true_leads_to_config_is_reloaded(7, fb);
+    spec_before_phase_n_entails_true_leads_to_config_is_reloaded(6, fb);
+    spec_before_phase_n_entails_true_leads_to_config_is_reloaded(5, fb);
+    spec_before_phase_n_entails_true_leads_to_config_is_reloaded(4, fb);
+    spec_before_phase_n_entails_true_leads_to_config_is_reloaded(3, fb);
+    spec_before_phase_n_entails_true_leads_to_config_is_reloaded(2, fb);
+    spec_before_phase_n_entails_true_leads_to_config_is_reloaded(1, fb);
+
+    let assumption = always(lift_state(desired_state_is(fb))).and(always(lift_action(desired_config_is_stable(fb))))
+        .and(always(lift_action(daemon_set_status_is_stable::<FluentBitMaker>(fb))));
+    temp_pred_equality(invariants(fb).and(assumption), spec_before_phase_n(1, fb).and(stability));
+    unpack_conditions_from_spec(invariants(fb), assumption, true_pred(), post);
+    temp_pred_equality(true_pred().and(assumption), assumption);
+
+    entails_trans(
+        cluster_spec().and(derived_invariants_since_beginning(fb)), invariants(fb),
+        assumption.leads_to(post)
+    );
+    sm_spec_entails_all_invariants(fb);
+    simplify_predicate(cluster_spec(), derived_invariants_since_beginning(fb));
+}
+
+spec fn config_stability(fb: FluentBitView) -> TempPred<FBCluster> {
+    always(lift_action(desired_config_is_stable(fb))).and(always(lift_action(daemon_set_status_is_stable::<FluentBitMaker>(fb))))
+}
+
+proof fn spec_before_phase_n_entails_true_leads_to_config_is_reloaded(i: nat, fb: FluentBitView)
+    requires
+        1 <= i <= 7,
+        valid(stable(spec_before_phase_n(i, fb))),
+        spec_before_phase_n(i + 1, fb).and(config_stability(fb)).entails(true_pred().leads_to(always(lift_state(config_is_reloaded::<FluentBitMaker>(fb))))),
+    ensures spec_before_phase_n(i, fb).and(config_stability(fb)).entails(true_pred().leads_to(always(lift_state(config_is_reloaded::<FluentBitMaker>(fb))))),
+{
+    let stability = config_stability(fb);
+    let spec = spec_before_phase_n(i, fb).and(stability);
+    let post = always(lift_state(config_is_reloaded::<FluentBitMaker>(fb)));
+    reveal_with_fuel(spec_before_phase_n, 8);
+    stable_and_always_n!(lift_action(desired_config_is_stable(fb)), lift_action(daemon_set_status_is_stable::<FluentBitMaker>(fb)));
+    stable_and_temp(spec_before_phase_n(i, fb), stability);
+    temp_pred_equality(spec_before_phase_n(i + 1, fb).and(stability), spec.and(invariants_since_phase_n(i, fb)));
+    spec_of_previous_phases_entails_eventually_new_invariants(i, fb);
+    entails_trans(spec, spec_before_phase_n(i, fb), true_pred().leads_to(invariants_since_phase_n(i, fb)));
+    unpack_conditions_from_spec(spec, invariants_since_phase_n(i, fb), true_pred(), post);
+    temp_pred_equality(true_pred().and(invariants_since_phase_n(i, fb)), invariants_since_phase_n(i, fb));
+    leads_to_trans(spec, true_pred(), invariants_since_phase_n(i, fb), post);
+}
+
+// Once the daemon set always matches and the config hash kept by the reconcile always matches the secret, the next round
+// of reconcile asks the fluent-bit pods to reload the current config. After that, every reload request in flight carries
+// the same hash, so the pods keep running the current config.
+proof fn lemma_true_leads_to_always_config_is_reloaded(fb: FluentBitView)
+    ensures assumption_and_invariants_of_all_phases(fb).and(config_stability(fb)).entails(true_pred().leads_to(always(lift_state(config_is_reloaded::<FluentBitMaker>(fb))))),
+{
+    let invs = assumption_and_invariants_of_all_phases(fb);
+    let stability = config_stability(fb);
+    let spec = invs.and(stability);
+    let ds_matches = lift_state(sub_resource_state_matches(SubResource::DaemonSet, fb));
+    let config_hash_matches = lift_state(helper_invariants::config_hash_is_the_same_as_etcd_server_secret_if_secret_fetched(fb));
+    let reload_carries_hash = lift_state(helper_invariants::every_reload_config_request_carries_the_desired_config_hash(fb));
+    let cond = always(ds_matches).and(always(config_hash_matches));
+    let post = lift_state(config_is_reloaded::<FluentBitMaker>(fb));
+
+    lemma_true_leads_to_always_state_matches_for_all_resources(fb);
+    entails_trans(spec, invs, true_pred().leads_to(always(ds_matches)));
+    terminate::reconcile_eventually_terminates(spec, fb);
+    helper_invariants::lemma_eventually_always_config_hash_is_the_same_as_etcd_server_secret_if_secret_fetched(spec, fb);
+    leads_to_always_combine(spec, true_pred(), ds_matches, config_hash_matches);
+
+    assumption_and_invariants_of_all_phases_is_stable(fb);
+    stable_and_always_n!(lift_action(desired_config_is_stable(fb)), lift_action(daemon_set_status_is_stable::<FluentBitMaker>(fb)));
+    stable_and_temp(invs, stability);
+    stable_and_always_n!(ds_matches, config_hash_matches);
+    stable_and_temp(spec, cond);
+
+    // The reload requests only carry the current config hash after the config hash kept by the reconcile matches the secret.
+    let spec_with_cond = spec.and(cond);
+    helper_invariants::lemma_eventually_always_every_reload_config_request_carries_the_desired_config_hash(spec_with_cond, fb);
+
+    let spec_with_all_conds = spec_with_cond.and(always(reload_carries_hash));
+    lemma_true_leads_to_after_get_resource_step_for_all_resources(fb);
+    entails_trans(spec_with_all_conds, invs, true_pred().leads_to(lift_state(pending_req_in_flight_at_after_get_resource_step(SubResource::DaemonSet, fb))));
+    always_tla_forall_apply_for_sub_resource(spec_with_all_conds, SubResource::DaemonSet, fb);
+    lemma_from_after_get_daemon_set_step_to_config_is_reloaded(spec_with_all_conds, fb);
+    leads_to_trans(spec_with_all_conds, true_pred(), lift_state(pending_req_in_flight_at_after_get_resource_step(SubResource::DaemonSet, fb)), post);
+    lemma_config_is_reloaded_is_stable(spec_with_all_conds, fb, true_pred());
+
+    unpack_conditions_from_spec(spec_with_cond, always(reload_carries_hash), true_pred(), always(post));
+    temp_pred_equality(true_pred().and(always(reload_carries_hash)), always(reload_carries_hash));
+    leads_to_trans(spec_with_cond, true_pred(), always(reload_carries_hash), always(post));
+
+    unpack_conditions_from_spec(spec, cond, true_pred(), always(post));
+    temp_pred_equality(true_pred().and(cond), cond);
+    leads_to_trans(spec, true_pred(), cond, always(post));
+}
+
+// We prove init /\ []next /\ []wf |= []desired_state_is(fb) /\ []desired_config_is_stable(fb) /\ []daemon_set_status_is_stable(fb)
+// /\ []fluentbit_is_stable_except_status(fb) ~> status_reflects_daemon_set(fb) holds for each fb.
+proof fn status_liveness_proof_forall_fb()
+    ensures status_liveness_theorem::<FluentBitMaker>(),
+{
+    assert forall |fb: FluentBitView| #[trigger] cluster_spec().entails(status_liveness::<FluentBitMaker>(fb)) by {
+        status_liveness_proof(fb);
+    };
+    spec_entails_tla_forall(cluster_spec(), |fb: FluentBitView| status_liveness::<FluentBitMaker>(fb));
+}
+
+proof fn status_liveness_proof(fb: FluentBitView)
+    ensures cluster_spec().entails(status_liveness::<FluentBitMaker>(fb)),
+{
+    let stability = status_stability(fb);
+    let post = lift_state(status_reflects_daemon_set::<FluentBitMaker>(fb));
+    assumption_and_invariants_of_all_phases_is_stable(fb);
+    lemma_true_leads_to_status_reflects_daemon_set(fb);
+    reveal_with_fuel(spec_before_phase_n, 8);
+    spec_before_phase_n_entails_true_leads_to_status_reflects_daemon_set(7, fb);
+    spec_before_phase_n_entails_true_leads_to_status_reflects_daemon_set(6, fb);
+    spec_before_phase_n_entails_true_leads_to_status_reflects_daemon_set(5, fb);
+    spec_before_phase_n_entails_true_leads_to_status_reflects_daemon_set(4, fb);
+    spec_before_phase_n_entails_true_leads_to_status_reflects_daemon_set(3, fb);
+    spec_before_phase_n_entails_true_leads_to_status_reflects_daemon_set(2, fb);
+    spec_before_phase_n_entails_true_leads_to_status_reflects_daemon_set(1, fb);
+
+    let assumption = always(lift_state(desired_state_is(fb))).and(always(lift_action(desired_config_is_stable(fb))))
+        .and(always(lift_action(daemon_set_status_is_stable::<FluentBitMaker>(fb)))).and(always(lift_action(fluentbit_is_stable_except_status(fb))));
+    temp_pred_equality(invariants(fb).and(assumption), spec_before_phase_n(1, fb).and(stability));
+    unpack_conditions_from_spec(invariants(fb), assumption, true_pred(), post);
+    temp_pred_equality(true_pred().and(assumption), assumption);
+
+    entails_trans(
+        cluster_spec().and(derived_invariants_since_beginning(fb)), invariants(fb),
+        assumption.leads_to(post)
+    );
+    sm_spec_entails_all_invariants(fb);
+    simplify_predicate(cluster_spec(), derived_invariants_since_beginning(fb));
+}
+
+spec fn status_stability(fb: FluentBitView) -> TempPred<FBCluster> {
+    always(lift_action(desired_config_is_stable(fb))).and(always(lift_action(daemon_set_status_is_stable::<FluentBitMaker>(fb))))
+        .and(always(lift_action(fluentbit_is_stable_except_status(fb))))
+}
+
+proof fn spec_before_phase_n_entails_true_leads_to_status_reflects_daemon_set(i: nat, fb: FluentBitView)
+    requires
+        1 <= i <= 7,
+        valid(stable(spec_before_phase_n(i, fb))),
+        spec_before_phase_n(i + 1, fb).and(status_stability(fb)).entails(true_pred().leads_to(lift_state(status_reflects_daemon_set::<FluentBitMaker>(fb)))),
+    ensures spec_before_phase_n(i, fb).and(status_stability(fb)).entails(true_pred().leads_to(lift_state(status_reflects_daemon_set::<FluentBitMaker>(fb)))),
+{
+    let stability = status_stability(fb);
+    let spec = spec_before_phase_n(i, fb).and(stability);
+    let post = lift_state(status_reflects_daemon_set::<FluentBitMaker>(fb));
+    reveal_with_fuel(spec_before_phase_n, 8);
+    stable_and_always_n!(
+        lift_action(desired_config_is_stable(fb)), lift_action(daemon_set_status_is_stable::<FluentBitMaker>(fb)),
+        lift_action(fluentbit_is_stable_except_status(fb))
+    );
+    stable_and_temp(spec_before_phase_n(i, fb), stability);
+    temp_pred_equality(spec_before_phase_n(i + 1, fb).and(stability), spec.and(invariants_since_phase_n(i, fb)));
+    spec_of_previous_phases_entails_eventually_new_invariants(i, fb);
+    entails_trans(spec, spec_before_phase_n(i, fb), true_pred().leads_to(invariants_since_phase_n(i, fb)));
+    unpack_conditions_from_spec(spec, invariants_since_phase_n(i, fb), true_pred(), post);
+    temp_pred_equality(true_pred().and(invariants_since_phase_n(i, fb)), invariants_since_phase_n(i, fb));
+    leads_to_trans(spec, true_pred(), invariants_since_phase_n(i, fb), post);
+}
+
+// Once the daemon set always matches, the config hash kept by the reconcile always matches the secret and no one else
+// sends status update requests for the fluentbit, the next round of reconcile writes the status that reflects the daemon set.
+proof fn lemma_true_leads_to_status_reflects_daemon_set(fb: FluentBitView)
+    ensures assumption_and_invariants_of_all_phases(fb).and(status_stability(fb)).entails(true_pred().leads_to(lift_state(status_reflects_daemon_set::<FluentBitMaker>(fb)))),
+{
+    let invs = assumption_and_invariants_of_all_phases(fb);
+    let stability = status_stability(fb);
+    let spec = invs.and(stability);
+    let ds_matches = lift_state(sub_resource_state_matches(SubResource::DaemonSet, fb));
+    let no_other_status_update = lift_state(helper_invariants::every_update_status_request_of_fb_is_the_pending_req(fb));
+    let config_hash_matches = lift_state(helper_invariants::config_hash_is_the_same_as_etcd_server_secret_if_secret_fetched(fb));
+    let cond = always(ds_matches).and(always(no_other_status_update)).and(always(config_hash_matches));
+    let post = lift_state(status_reflects_daemon_set::<FluentBitMaker>(fb));
+
+    lemma_true_leads_to_always_state_matches_for_all_resources(fb);
+    entails_trans(spec, invs, true_pred().leads_to(always(ds_matches)));
+    helper_invariants::lemma_eventually_always_every_update_status_request_of_fb_is_the_pending_req(spec, fb);
+    terminate::reconcile_eventually_terminates(spec, fb);
+    helper_invariants::lemma_eventually_always_config_hash_is_the_same_as_etcd_server_secret_if_secret_fetched(spec, fb);
+    leads_to_always_combine_n!(spec, true_pred(), ds_matches, no_other_status_update, config_hash_matches);
+
+    assumption_and_invariants_of_all_phases_is_stable(fb);
+    stable_and_always_n!(
+        lift_action(desired_config_is_stable(fb)), lift_action(daemon_set_status_is_stable::<FluentBitMaker>(fb)),
+        lift_action(fluentbit_is_stable_except_status(fb))
+    );
+    stable_and_temp(invs, stability);
+
+    let spec_with_cond = spec.and(cond);
+    lemma_true_leads_to_after_get_resource_step_for_all_resources(fb);
+    entails_trans(spec_with_cond, invs, true_pred().leads_to(lift_state(pending_req_in_flight_at_after_get_resource_step(SubResource::DaemonSet, fb))));
+    always_tla_forall_apply_for_sub_resource(spec_with_cond, SubResource::DaemonSet, fb);
+    lemma_from_after_get_daemon_set_step_to_status_reflects_daemon_set(spec_with_cond, fb);
+    leads_to_trans(spec_with_cond, true_pred(), lift_state(pending_req_in_flight_at_after_get_resource_step(SubResource::DaemonSet, fb)), post);
+
+    unpack_conditions_from_spec(spec, cond, true_pred(), post);
+    temp_pred_equality(true_pred().and(cond), cond);
+    leads_to_trans(spec, true_pred(), cond, post);
+}
+
 proof fn lemma_true_leads_to_always_current_state_matches(fb: FluentBitView)
     ensures assumption_and_invariants_of_all_phases(fb).entails(true_pred().leads_to(always(lift_state(current_state_matches::<FluentBitMaker>(fb))))),
 {
@@ -105,8 +331,8 @@ proof fn lemma_true_leads_to_always_current_state_matches(fb: FluentBitView)
     temp_pred_equality(tla_forall(|res: SubResource| lift_state(sub_resource_state_matches(res, fb))), lift_state(current_state_matches::<FluentBitMaker>(fb)));
 }
 
-proof fn lemma_true_leads_to_always_state_matches_for_all_resources(fb: FluentBitView)
-    ensures forall |sub_resource: SubResource| assumption_and_invariants_of_all_phases(fb).entails(true_pred().leads_to(always(lift_state(#[trigger] sub_resource_state_matches(sub_resource, fb))))),
+proof fn lemma_true_leads_to_after_get_resource_step_for_all_resources(fb: FluentBitView)
+    ensures forall |sub_resource: SubResource| assumption_and_invariants_of_all_phases(fb).entails(true_pred().leads_to(lift_state(#[trigger] pending_req_in_flight_at_after_get_resource_step(sub_resource, fb)))),
 {
     let spec = assumption_and_invariants_of_all_phases(fb);
 
@@ -116,7 +342,7 @@ proof fn lemma_true_leads_to_always_state_matches_for_all_resources(fb: FluentBi
 
     // The use of termination property ensures spec |= true ~> reconcile_idle.
     terminate::reconcile_eventually_terminates(spec, fb);
-    // Then we can continue to show that spec |= reconcile_idle ~> []current_state_matches(fb).
+    // Then we can continue to show that spec |= reconcile_idle ~> at_after_get_resource_step(sub_resource).
 
     // The following two lemmas show that spec |= reconcile_idle ~> init /\ no_pending_req.
     lemma_from_reconcile_idle_to_scheduled(spec, fb);
@@ -149,6 +375,25 @@ proof fn lemma_true_leads_to_always_state_matches_for_all_resources(fb: FluentBi
         lift_state(pending_req_in_flight_at_after_get_resource_step(SubResource::Service, fb)),
         lift_state(pending_req_in_flight_at_after_get_resource_step(SubResource::DaemonSet, fb))
     );
+    assert forall |sub_resource: SubResource| spec.entails(
+        true_pred().leads_to(lift_state(#[trigger] pending_req_in_flight_at_after_get_resource_step(sub_resource, fb)))
+    ) by {
+        match sub_resource {
+            SubResource::ServiceAccount => {},
+            SubResource::Role => {},
+            SubResource::RoleBinding => {},
+            SubResource::Service => {},
+            SubResource::DaemonSet => {},
+        }
+    }
+}
+
+proof fn lemma_true_leads_to_always_state_matches_for_all_resources(fb: FluentBitView)
+    ensures forall |sub_resource: SubResource| assumption_and_invariants_of_all_phases(fb).entails(true_pred().leads_to(always(lift_state(#[trigger] sub_resource_state_matches(sub_resource, fb))))),
+{
+    let spec = assumption_and_invariants_of_all_phases(fb);
+
+    lemma_true_leads_to_after_get_resource_step_for_all_resources(fb);
 
     // Since we already have true ~> at_after_get_resource_step(sub_resource), and we can get at_after_get_resource_step(sub_resource)
     // ~> sub_resource_state_matches(sub_resource, fb) by applying lemma lemma_from_after_get_resource_step_to_resource_matches,
@@ -0,0 +1,1112 @@
+// Copyright 2022 VMware, Inc.
+// SPDX-License-Identifier: MIT
+#![allow(unused_imports)]
+use crate::external_api::spec::*;
+use crate::fluent_controller::fluentbit::{
+    model::{reconciler::*, resource::*},
+    proof::{helper_invariants, predicate::*, resource::*},
+    trusted::{fluentbit_api_spec::*, liveness_theorem::*, maker::*, spec_types::*, step::*},
+};
+use crate::kubernetes_api_objects::spec::{
+    api_method::*, common::*, dynamic::*, owner_reference::*, prelude::*, resource::*,
+};
+use crate::kubernetes_cluster::spec::{
+    builtin_controllers::types::BuiltinControllerChoice,
+    cluster::*,
+    cluster_state_machine::Step,
+    controller::types::{ControllerActionInput, ControllerStep},
+    message::*,
+};
+use crate::temporal_logic::{defs::*, rules::*};
+use crate::vstd_ext::{map_lib::*, string_view::*};
+use vstd::{prelude::*, string::*};
+
+verus! {
+
+// Once the daemon set always matches, the reconcile that reaches the daemon set updates it again (which changes nothing
+// the status depends on), reads its status and asks the fluent-bit pods to reload the config it read from the secret.
+// The daemon set status must stay the same during this; otherwise the update may conflict with a status write of the
+// built-in daemon set controller and the reconcile ends in error before it reaches the reload.
+pub proof fn lemma_from_after_get_daemon_set_step_to_after_reload_config_step(spec: TempPred<FBCluster>, fb: FluentBitView)
+    requires
+        spec.entails(always(lift_action(FBCluster::next()))),
+        spec.entails(tla_forall(|i| FBCluster::controller_next().weak_fairness(i))),
+        spec.entails(tla_forall(|i| FBCluster::kubernetes_api_next().weak_fairness(i))),
+        spec.entails(always(lift_state(FBCluster::crash_disabled()))),
+        spec.entails(always(lift_state(FBCluster::busy_disabled()))),
+        spec.entails(always(lift_state(FBCluster::pending_req_of_key_is_unique_with_unique_id(fb.object_ref())))),
+        spec.entails(always(lift_state(FBCluster::each_object_in_etcd_is_well_formed()))),
+        spec.entails(always(lift_state(FBCluster::every_in_flight_msg_has_unique_id()))),
+        spec.entails(always(lift_state(desired_state_is(fb)))),
+        spec.entails(always(lift_state(helper_invariants::the_object_in_reconcile_satisfies_state_validation(fb.object_ref())))),
+        spec.entails(always(lift_state(helper_invariants::every_resource_update_request_implies_at_after_update_resource_step(SubResource::DaemonSet, fb)))),
+        spec.entails(always(lift_state(helper_invariants::no_delete_resource_request_msg_in_flight(SubResource::DaemonSet, fb)))),
+        spec.entails(always(lift_state(helper_invariants::resource_object_only_has_owner_reference_pointing_to_current_cr(SubResource::DaemonSet, fb)))),
+        spec.entails(always(lift_state(helper_invariants::resource_object_has_no_finalizers_or_timestamp_and_only_has_controller_owner_ref(SubResource::DaemonSet, fb)))),
+        spec.entails(always(lift_state(helper_invariants::daemon_set_in_etcd_satisfies_unchangeable(fb)))),
+        spec.entails(always(lift_state(helper_invariants::config_hash_is_the_same_as_etcd_server_secret_if_secret_fetched(fb)))),
+        spec.entails(always(lift_state(sub_resource_state_matches(SubResource::DaemonSet, fb)))),
+        spec.entails(always(lift_action(desired_config_is_stable(fb)))),
+        spec.entails(always(lift_action(daemon_set_status_is_stable::<FluentBitMaker>(fb)))),
+    ensures
+        spec.entails(lift_state(pending_req_in_flight_at_after_get_resource_step(SubResource::DaemonSet, fb)).leads_to(lift_state(pending_req_in_flight_at_after_reload_config_step(fb)))),
+{
+    let ds_key = get_request(SubResource::DaemonSet, fb).key;
+    let p0 = lift_state(pending_req_in_flight_at_after_get_resource_step(SubResource::DaemonSet, fb));
+    let p1 = lift_state(at_after_get_resource_step_and_exists_ok_resp_in_flight(SubResource::DaemonSet, fb));
+    let p2 = lift_state(pending_req_in_flight_at_after_update_resource_step(SubResource::DaemonSet, fb));
+    let p3 = lift_state(at_after_update_resource_step_and_exists_ok_resp_in_flight(SubResource::DaemonSet, fb));
+    let p4 = lift_state(pending_req_in_flight_at_after_get_daemon_set_status_step(fb));
+    let p5 = lift_state(at_after_get_daemon_set_status_step_and_exists_ok_resp_in_flight(fb));
+    let p6 = lift_state(pending_req_in_flight_at_after_reload_config_step(fb));
+
+    assert_by(spec.entails(p0.leads_to(p1)), {
+        let pre = |req_msg| lift_state(req_msg_is_the_in_flight_pending_req_at_after_get_resource_step(SubResource::DaemonSet, fb, req_msg));
+        assert forall |req_msg| spec.entails(#[trigger] pre(req_msg).leads_to(p1)) by {
+            lemma_receives_ok_resp_at_after_get_daemon_set_step(spec, fb, req_msg);
+        }
+        leads_to_exists_intro(spec, pre, p1);
+        assert_by(tla_exists(pre) == p0, {
+            assert forall |ex| #[trigger] p0.satisfied_by(ex) implies tla_exists(pre).satisfied_by(ex) by {
+                let req_msg = ex.head().ongoing_reconciles()[fb.object_ref()].pending_req_msg.get_Some_0();
+                assert(pre(req_msg).satisfied_by(ex));
+            }
+            temp_pred_equality(tla_exists(pre), p0);
+        });
+    });
+
+    assert_by(spec.entails(p1.leads_to(p2)), {
+        let pre = |resp_msg| lift_state(resp_msg_is_the_in_flight_ok_resp_at_after_get_resource_step(SubResource::DaemonSet, fb, resp_msg));
+        assert forall |resp_msg| spec.entails(#[trigger] pre(resp_msg).leads_to(p2)) by {
+            lemma_from_after_get_daemon_set_step_to_after_update_daemon_set_step(spec, fb, resp_msg);
+        }
+        leads_to_exists_intro(spec, pre, p2);
+        assert_by(tla_exists(pre) == p1, {
+            assert forall |ex| #[trigger] p1.satisfied_by(ex) implies tla_exists(pre).satisfied_by(ex) by {
+                let resp_msg = choose |resp_msg| {
+                    &&& #[trigger] ex.head().in_flight().contains(resp_msg)
+                    &&& Message::resp_msg_matches_req_msg(resp_msg, ex.head().ongoing_reconciles()[fb.object_ref()].pending_req_msg.get_Some_0())
+                    &&& resp_msg.content.get_get_response().res.is_Ok()
+                    &&& resp_msg.content.get_get_response().res.get_Ok_0() == ex.head().resources()[ds_key]
+                };
+                assert(pre(resp_msg).satisfied_by(ex));
+            }
+            temp_pred_equality(tla_exists(pre), p1);
+        });
+    });
+
+    assert_by(spec.entails(p2.leads_to(p3)), {
+        let pre = |req_msg| lift_state(req_msg_is_the_in_flight_pending_req_at_after_update_resource_step(SubResource::DaemonSet, fb, req_msg));
+        assert forall |req_msg| spec.entails(#[trigger] pre(req_msg).leads_to(p3)) by {
+            lemma_receives_ok_resp_at_after_update_daemon_set_step(spec, fb, req_msg);
+        }
+        leads_to_exists_intro(spec, pre, p3);
+        assert_by(tla_exists(pre) == p2, {
+            assert forall |ex| #[trigger] p2.satisfied_by(ex) implies tla_exists(pre).satisfied_by(ex) by {
+                let req_msg = ex.head().ongoing_reconciles()[fb.object_ref()].pending_req_msg.get_Some_0();
+                assert(pre(req_msg).satisfied_by(ex));
+            }
+            temp_pred_equality(tla_exists(pre), p2);
+        });
+    });
+
+    assert_by(spec.entails(p3.leads_to(p4)), {
+        let pre = |resp_msg| lift_state(resp_msg_is_the_in_flight_ok_resp_at_after_update_resource_step(SubResource::DaemonSet, fb, resp_msg));
+        assert forall |resp_msg| spec.entails(#[trigger] pre(resp_msg).leads_to(p4)) by {
+            lemma_from_after_update_daemon_set_step_to_after_get_daemon_set_status_step(spec, fb, resp_msg);
+        }
+        leads_to_exists_intro(spec, pre, p4);
+        assert_by(tla_exists(pre) == p3, {
+            assert forall |ex| #[trigger] p3.satisfied_by(ex) implies tla_exists(pre).satisfied_by(ex) by {
+                let s = ex.head();
+                let resp_msg = choose |resp_msg| {
+                    &&& #[trigger] s.in_flight().contains(resp_msg)
+                    &&& Message::resp_msg_matches_req_msg(resp_msg, s.ongoing_reconciles()[fb.object_ref()].pending_req_msg.get_Some_0())
+                    &&& resp_msg.content.get_update_response().res.is_Ok()
+                    &&& state_after_update(SubResource::DaemonSet, fb, resp_msg.content.get_update_response().res.get_Ok_0(), s.ongoing_reconciles()[fb.object_ref()].local_state).is_Ok()
+                };
+                assert(pre(resp_msg).satisfied_by(ex));
+            }
+            temp_pred_equality(tla_exists(pre), p3);
+        });
+    });
+
+    assert_by(spec.entails(p4.leads_to(p5)), {
+        let pre = |req_msg| lift_state(req_msg_is_the_in_flight_pending_req_at_after_get_daemon_set_status_step(fb, req_msg));
+        assert forall |req_msg| spec.entails(#[trigger] pre(req_msg).leads_to(p5)) by {
+            lemma_receives_ok_resp_at_after_get_daemon_set_status_step(spec, fb, req_msg);
+        }
+        leads_to_exists_intro(spec, pre, p5);
+        assert_by(tla_exists(pre) == p4, {
+            assert forall |ex| #[trigger] p4.satisfied_by(ex) implies tla_exists(pre).satisfied_by(ex) by {
+                let req_msg = ex.head().ongoing_reconciles()[fb.object_ref()].pending_req_msg.get_Some_0();
+                assert(pre(req_msg).satisfied_by(ex));
+            }
+            temp_pred_equality(tla_exists(pre), p4);
+        });
+    });
+
+    assert_by(spec.entails(p5.leads_to(p6)), {
+        let pre = |resp_msg| lift_state(resp_msg_is_the_in_flight_ok_resp_at_after_get_daemon_set_status_step(fb, resp_msg));
+        assert forall |resp_msg| spec.entails(#[trigger] pre(resp_msg).leads_to(p6)) by {
+            lemma_from_after_get_daemon_set_status_step_to_after_reload_config_step(spec, fb, resp_msg);
+        }
+        leads_to_exists_intro(spec, pre, p6);
+        assert_by(tla_exists(pre) == p5, {
+            assert forall |ex| #[trigger] p5.satisfied_by(ex) implies tla_exists(pre).satisfied_by(ex) by {
+                let resp_msg = choose |resp_msg| #[trigger] resp_msg_is_the_in_flight_ok_resp_at_after_get_daemon_set_status_step(fb, resp_msg)(ex.head());
+                assert(pre(resp_msg).satisfied_by(ex));
+            }
+            temp_pred_equality(tla_exists(pre), p5);
+        });
+    });
+
+    leads_to_trans_n!(spec, p0, p1, p2, p3, p4, p5, p6);
+}
+
+// After the reload, the reconcile reads the latest fluentbit and writes the status on top of it.
+// Since the daemon set status, the config secret and the fluentbit (except its status) all stay the same, the status
+// written by the controller reflects the daemon set.
+pub proof fn lemma_from_after_get_daemon_set_step_to_status_reflects_daemon_set(spec: TempPred<FBCluster>, fb: FluentBitView)
+    requires
+        spec.entails(always(lift_action(FBCluster::next()))),
+        spec.entails(tla_forall(|i| FBCluster::controller_next().weak_fairness(i))),
+        spec.entails(tla_forall(|i| FBCluster::kubernetes_api_next().weak_fairness(i))),
+        spec.entails(always(lift_state(FBCluster::crash_disabled()))),
+        spec.entails(always(lift_state(FBCluster::busy_disabled()))),
+        spec.entails(always(lift_state(FBCluster::pending_req_of_key_is_unique_with_unique_id(fb.object_ref())))),
+        spec.entails(always(lift_state(FBCluster::each_object_in_etcd_is_well_formed()))),
+        spec.entails(always(lift_state(FBCluster::every_in_flight_msg_has_unique_id()))),
+        spec.entails(always(lift_state(desired_state_is(fb)))),
+        spec.entails(always(lift_state(helper_invariants::the_object_in_reconcile_satisfies_state_validation(fb.object_ref())))),
+        spec.entails(always(lift_state(helper_invariants::every_resource_update_request_implies_at_after_update_resource_step(SubResource::DaemonSet, fb)))),
+        spec.entails(always(lift_state(helper_invariants::no_delete_resource_request_msg_in_flight(SubResource::DaemonSet, fb)))),
+        spec.entails(always(lift_state(helper_invariants::resource_object_only_has_owner_reference_pointing_to_current_cr(SubResource::DaemonSet, fb)))),
+        spec.entails(always(lift_state(helper_invariants::resource_object_has_no_finalizers_or_timestamp_and_only_has_controller_owner_ref(SubResource::DaemonSet, fb)))),
+        spec.entails(always(lift_state(helper_invariants::daemon_set_in_etcd_satisfies_unchangeable(fb)))),
+        spec.entails(always(lift_state(helper_invariants::config_hash_is_the_same_as_etcd_server_secret_if_secret_fetched(fb)))),
+        spec.entails(always(lift_state(helper_invariants::every_update_status_request_of_fb_is_the_pending_req(fb)))),
+        spec.entails(always(lift_state(sub_resource_state_matches(SubResource::DaemonSet, fb)))),
+        spec.entails(tla_forall(|i| FBCluster::external_api_next().weak_fairness(i))),
+        spec.entails(always(lift_action(desired_config_is_stable(fb)))),
+        spec.entails(always(lift_action(daemon_set_status_is_stable::<FluentBitMaker>(fb)))),
+        spec.entails(always(lift_action(fluentbit_is_stable_except_status(fb)))),
+    ensures
+        spec.entails(lift_state(pending_req_in_flight_at_after_get_resource_step(SubResource::DaemonSet, fb)).leads_to(lift_state(status_reflects_daemon_set::<FluentBitMaker>(fb)))),
+{
+    let p0 = lift_state(pending_req_in_flight_at_after_get_resource_step(SubResource::DaemonSet, fb));
+    let p_reload = lift_state(pending_req_in_flight_at_after_reload_config_step(fb));
+    let p_reload_resp = lift_state(at_after_reload_config_step_and_exists_ok_resp_in_flight(fb));
+    let p6 = lift_state(pending_req_in_flight_at_after_get_fluentbit_step(fb));
+    let p7 = lift_state(at_after_get_fluentbit_step_and_exists_ok_resp_in_flight(fb));
+    let p8 = lift_state(pending_req_in_flight_at_after_update_status_step(fb));
+    let post = lift_state(status_reflects_daemon_set::<FluentBitMaker>(fb));
+
+    lemma_from_after_get_daemon_set_step_to_after_reload_config_step(spec, fb);
+
+    assert_by(spec.entails(p_reload.leads_to(p_reload_resp)), {
+        let pre = |req_msg| lift_state(req_msg_is_the_in_flight_pending_req_at_after_reload_config_step(fb, req_msg));
+        assert forall |req_msg| spec.entails(#[trigger] pre(req_msg).leads_to(p_reload_resp)) by {
+            lemma_receives_ok_resp_at_after_reload_config_step(spec, fb, req_msg);
+        }
+        leads_to_exists_intro(spec, pre, p_reload_resp);
+        assert_by(tla_exists(pre) == p_reload, {
+            assert forall |ex| #[trigger] p_reload.satisfied_by(ex) implies tla_exists(pre).satisfied_by(ex) by {
+                let req_msg = ex.head().ongoing_reconciles()[fb.object_ref()].pending_req_msg.get_Some_0();
+                assert(pre(req_msg).satisfied_by(ex));
+            }
+            temp_pred_equality(tla_exists(pre), p_reload);
+        });
+    });
+
+    assert_by(spec.entails(p_reload_resp.leads_to(p6)), {
+        let pre = |resp_msg| lift_state(resp_msg_is_the_in_flight_ok_resp_at_after_reload_config_step(fb, resp_msg));
+        assert forall |resp_msg| spec.entails(#[trigger] pre(resp_msg).leads_to(p6)) by {
+            lemma_from_after_reload_config_step_to_after_get_fluentbit_step(spec, fb, resp_msg);
+        }
+        leads_to_exists_intro(spec, pre, p6);
+        assert_by(tla_exists(pre) == p_reload_resp, {
+            assert forall |ex| #[trigger] p_reload_resp.satisfied_by(ex) implies tla_exists(pre).satisfied_by(ex) by {
+                let resp_msg = choose |resp_msg| #[trigger] resp_msg_is_the_in_flight_ok_resp_at_after_reload_config_step(fb, resp_msg)(ex.head());
+                assert(pre(resp_msg).satisfied_by(ex));
+            }
+            temp_pred_equality(tla_exists(pre), p_reload_resp);
+        });
+    });
+
+    assert_by(spec.entails(p6.leads_to(p7)), {
+        let pre = |req_msg| lift_state(req_msg_is_the_in_flight_pending_req_at_after_get_fluentbit_step(fb, req_msg));
+        assert forall |req_msg| spec.entails(#[trigger] pre(req_msg).leads_to(p7)) by {
+            lemma_receives_ok_resp_at_after_get_fluentbit_step(spec, fb, req_msg);
+        }
+        leads_to_exists_intro(spec, pre, p7);
+        assert_by(tla_exists(pre) == p6, {
+            assert forall |ex| #[trigger] p6.satisfied_by(ex) implies tla_exists(pre).satisfied_by(ex) by {
+                let req_msg = ex.head().ongoing_reconciles()[fb.object_ref()].pending_req_msg.get_Some_0();
+                assert(pre(req_msg).satisfied_by(ex));
+            }
+            temp_pred_equality(tla_exists(pre), p6);
+        });
+    });
+
+    assert_by(spec.entails(p7.leads_to(p8)), {
+        let pre = |resp_msg| lift_state(resp_msg_is_the_in_flight_ok_resp_at_after_get_fluentbit_step(fb, resp_msg));
+        assert forall |resp_msg| spec.entails(#[trigger] pre(resp_msg).leads_to(p8)) by {
+            lemma_from_after_get_fluentbit_step_to_after_update_status_step(spec, fb, resp_msg);
+        }
+        leads_to_exists_intro(spec, pre, p8);
+        assert_by(tla_exists(pre) == p7, {
+            assert forall |ex| #[trigger] p7.satisfied_by(ex) implies tla_exists(pre).satisfied_by(ex) by {
+                let resp_msg = choose |resp_msg| #[trigger] resp_msg_is_the_in_flight_ok_resp_at_after_get_fluentbit_step(fb, resp_msg)(ex.head());
+                assert(pre(resp_msg).satisfied_by(ex));
+            }
+            temp_pred_equality(tla_exists(pre), p7);
+        });
+    });
+
+    assert_by(spec.entails(p8.leads_to(post)), {
+        let pre = |req_msg| lift_state(req_msg_is_the_in_flight_pending_req_at_after_update_status_step(fb, req_msg));
+        assert forall |req_msg| spec.entails(#[trigger] pre(req_msg).leads_to(post)) by {
+            lemma_status_reflects_daemon_set_at_after_update_status_step(spec, fb, req_msg);
+        }
+        leads_to_exists_intro(spec, pre, post);
+        assert_by(tla_exists(pre) == p8, {
+            assert forall |ex| #[trigger] p8.satisfied_by(ex) implies tla_exists(pre).satisfied_by(ex) by {
+                let req_msg = ex.head().ongoing_reconciles()[fb.object_ref()].pending_req_msg.get_Some_0();
+                assert(pre(req_msg).satisfied_by(ex));
+            }
+            temp_pred_equality(tla_exists(pre), p8);
+        });
+    });
+
+    leads_to_trans_n!(spec, p0, p_reload, p_reload_resp, p6, p7, p8, post);
+}
+
+// The daemon set is only written by the update request sent at AfterKRequestStep(Update, DaemonSet) and by the status
+// updates of the built-in daemon set controller, which are noops as long as the daemon set status is stable.
+proof fn daemon_set_is_unchanged_unless_updated_by_controller(s: FBCluster, s_prime: FBCluster, fb: FluentBitView, req: FBMessage)
+    requires
+        FBCluster::each_object_in_etcd_is_well_formed()(s),
+        helper_invariants::no_delete_resource_request_msg_in_flight(SubResource::DaemonSet, fb)(s),
+        daemon_set_status_is_stable::<FluentBitMaker>(fb)(s, s_prime),
+        s.resources().contains_key(get_request(SubResource::DaemonSet, fb).key),
+        s.in_flight().contains(req),
+        FBCluster::next_step(s, s_prime, Step::ApiServerStep(Some(req))),
+        !resource_update_request_msg(get_request(SubResource::DaemonSet, fb).key)(req),
+    ensures s_prime.resources()[get_request(SubResource::DaemonSet, fb).key] == s.resources()[get_request(SubResource::DaemonSet, fb).key],
+{
+    let ds_key = get_request(SubResource::DaemonSet, fb).key;
+    assert(!resource_delete_request_msg(ds_key)(req));
+    if resource_update_status_request_msg(ds_key)(req) {
+        let old_obj = s.resources()[ds_key];
+        let updated_obj = status_updated_object(req.content.get_update_status_request(), old_obj);
+        assert(old_obj.object_ref() == ds_key);
+        if updated_obj != old_obj {
+            assert(updated_obj.status != old_obj.status);
+        }
+    }
+}
+
+#[verifier(spinoff_prover)]
+proof fn lemma_receives_ok_resp_at_after_get_daemon_set_step(spec: TempPred<FBCluster>, fb: FluentBitView, req_msg: FBMessage)
+    requires
+        spec.entails(always(lift_action(FBCluster::next()))),
+        spec.entails(tla_forall(|i| FBCluster::kubernetes_api_next().weak_fairness(i))),
+        spec.entails(always(lift_state(FBCluster::crash_disabled()))),
+        spec.entails(always(lift_state(FBCluster::busy_disabled()))),
+        spec.entails(always(lift_state(FBCluster::every_in_flight_msg_has_unique_id()))),
+        spec.entails(always(lift_state(FBCluster::each_object_in_etcd_is_well_formed()))),
+        spec.entails(always(lift_state(helper_invariants::every_resource_update_request_implies_at_after_update_resource_step(SubResource::DaemonSet, fb)))),
+        spec.entails(always(lift_state(helper_invariants::no_delete_resource_request_msg_in_flight(SubResource::DaemonSet, fb)))),
+        spec.entails(always(lift_state(sub_resource_state_matches(SubResource::DaemonSet, fb)))),
+        spec.entails(always(lift_action(daemon_set_status_is_stable::<FluentBitMaker>(fb)))),
+    ensures
+        spec.entails(lift_state(req_msg_is_the_in_flight_pending_req_at_after_get_resource_step(SubResource::DaemonSet, fb, req_msg))
+            .leads_to(lift_state(at_after_get_resource_step_and_exists_ok_resp_in_flight(SubResource::DaemonSet, fb)))),
+{
+    let pre = req_msg_is_the_in_flight_pending_req_at_after_get_resource_step(SubResource::DaemonSet, fb, req_msg);
+    let post = at_after_get_resource_step_and_exists_ok_resp_in_flight(SubResource::DaemonSet, fb);
+    let resource_key = get_request(SubResource::DaemonSet, fb).key;
+    let input = Some(req_msg);
+    let stronger_next = |s, s_prime: FBCluster| {
+        &&& FBCluster::next()(s, s_prime)
+        &&& FBCluster::crash_disabled()(s)
+        &&& FBCluster::busy_disabled()(s)
+        &&& FBCluster::every_in_flight_msg_has_unique_id()(s)
+        &&& FBCluster::each_object_in_etcd_is_well_formed()(s)
+        &&& helper_invariants::every_resource_update_request_implies_at_after_update_resource_step(SubResource::DaemonSet, fb)(s)
+        &&& helper_invariants::no_delete_resource_request_msg_in_flight(SubResource::DaemonSet, fb)(s)
+        &&& sub_resource_state_matches(SubResource::DaemonSet, fb)(s)
+        &&& sub_resource_state_matches(SubResource::DaemonSet, fb)(s_prime)
+        &&& daemon_set_status_is_stable::<FluentBitMaker>(fb)(s, s_prime)
+    };
+    always_to_always_later(spec, lift_state(sub_resource_state_matches(SubResource::DaemonSet, fb)));
+    combine_spec_entails_always_n!(
+        spec, lift_action(stronger_next),
+        lift_action(FBCluster::next()),
+        lift_state(FBCluster::crash_disabled()),
+        lift_state(FBCluster::busy_disabled()),
+        lift_state(FBCluster::every_in_flight_msg_has_unique_id()),
+        lift_state(FBCluster::each_object_in_etcd_is_well_formed()),
+        lift_state(helper_invariants::every_resource_update_request_implies_at_after_update_resource_step(SubResource::DaemonSet, fb)),
+        lift_state(helper_invariants::no_delete_resource_request_msg_in_flight(SubResource::DaemonSet, fb)),
+        lift_state(sub_resource_state_matches(SubResource::DaemonSet, fb)),
+        later(lift_state(sub_resource_state_matches(SubResource::DaemonSet, fb))),
+        lift_action(daemon_set_status_is_stable::<FluentBitMaker>(fb))
+    );
+
+    assert forall |s, s_prime| pre(s) && #[trigger] stronger_next(s, s_prime) implies pre(s_prime) || post(s_prime) by {
+        let step = choose |step| FBCluster::next_step(s, s_prime, step);
+        match step {
+            Step::ApiServerStep(input) => {
+                let req = input.get_Some_0();
+                assert(!resource_update_request_msg(resource_key)(req));
+                daemon_set_is_unchanged_unless_updated_by_controller(s, s_prime, fb, req);
+                if req == req_msg {
+                    let resp_msg = FBCluster::handle_get_request_msg(req_msg, s.kubernetes_api_state).1;
+                    assert({
+                        &&& s_prime.in_flight().contains(resp_msg)
+                        &&& Message::resp_msg_matches_req_msg(resp_msg, req_msg)
+                        &&& resp_msg.content.get_get_response().res.is_Ok()
+                        &&& resp_msg.content.get_get_response().res.get_Ok_0() == s_prime.resources()[resource_key]
+                    });
+                    assert(post(s_prime));
+                }
+            },
+            _ => {}
+        }
+    }
+
+    assert forall |s, s_prime| pre(s) && #[trigger] stronger_next(s, s_prime) && FBCluster::kubernetes_api_next().forward(input)(s, s_prime)
+    implies post(s_prime) by {
+        let resp_msg = FBCluster::handle_get_request_msg(req_msg, s.kubernetes_api_state).1;
+        assert({
+            &&& s_prime.in_flight().contains(resp_msg)
+            &&& Message::resp_msg_matches_req_msg(resp_msg, req_msg)
+            &&& resp_msg.content.get_get_response().res.is_Ok()
+            &&& resp_msg.content.get_get_response().res.get_Ok_0() == s_prime.resources()[resource_key]
+        });
+    }
+
+    FBCluster::lemma_pre_leads_to_post_by_kubernetes_api(
+        spec, input, stronger_next, FBCluster::handle_request(), pre, post
+    );
+}
+
+#[verifier(spinoff_prover)]
+proof fn lemma_from_after_get_daemon_set_step_to_after_update_daemon_set_step(spec: TempPred<FBCluster>, fb: FluentBitView, resp_msg: FBMessage)
+    requires
+        spec.entails(always(lift_action(FBCluster::next()))),
+        spec.entails(tla_forall(|i| FBCluster::controller_next().weak_fairness(i))),
+        spec.entails(always(lift_state(FBCluster::crash_disabled()))),
+        spec.entails(always(lift_state(FBCluster::busy_disabled()))),
+        spec.entails(always(lift_state(FBCluster::pending_req_of_key_is_unique_with_unique_id(fb.object_ref())))),
+        spec.entails(always(lift_state(FBCluster::every_in_flight_msg_has_unique_id()))),
+        spec.entails(always(lift_state(FBCluster::each_object_in_etcd_is_well_formed()))),
+        spec.entails(always(lift_state(desired_state_is(fb)))),
+        spec.entails(always(lift_state(helper_invariants::every_resource_update_request_implies_at_after_update_resource_step(SubResource::DaemonSet, fb)))),
+        spec.entails(always(lift_state(helper_invariants::no_delete_resource_request_msg_in_flight(SubResource::DaemonSet, fb)))),
+        spec.entails(always(lift_state(helper_invariants::resource_object_only_has_owner_reference_pointing_to_current_cr(SubResource::DaemonSet, fb)))),
+        spec.entails(always(lift_state(helper_invariants::daemon_set_in_etcd_satisfies_unchangeable(fb)))),
+        spec.entails(always(lift_state(helper_invariants::config_hash_is_the_same_as_etcd_server_secret_if_secret_fetched(fb)))),
+        spec.entails(always(lift_state(sub_resource_state_matches(SubResource::DaemonSet, fb)))),
+        spec.entails(always(lift_action(desired_config_is_stable(fb)))),
+        spec.entails(always(lift_action(daemon_set_status_is_stable::<FluentBitMaker>(fb)))),
+    ensures
+        spec.entails(lift_state(resp_msg_is_the_in_flight_ok_resp_at_after_get_resource_step(SubResource::DaemonSet, fb, resp_msg))
+            .leads_to(lift_state(pending_req_in_flight_at_after_update_resource_step(SubResource::DaemonSet, fb)))),
+{
+    let pre = resp_msg_is_the_in_flight_ok_resp_at_after_get_resource_step(SubResource::DaemonSet, fb, resp_msg);
+    let post = pending_req_in_flight_at_after_update_resource_step(SubResource::DaemonSet, fb);
+    let resource_key = get_request(SubResource::DaemonSet, fb).key;
+    let input = (Some(resp_msg), Some(fb.object_ref()));
+    let stronger_next = |s, s_prime: FBCluster| {
+        &&& FBCluster::next()(s, s_prime)
+        &&& FBCluster::crash_disabled()(s)
+        &&& FBCluster::busy_disabled()(s)
+        &&& FBCluster::pending_req_of_key_is_unique_with_unique_id(fb.object_ref())(s)
+        &&& FBCluster::every_in_flight_msg_has_unique_id()(s)
+        &&& FBCluster::each_object_in_etcd_is_well_formed()(s)
+        &&& desired_state_is(fb)(s)
+        &&& helper_invariants::every_resource_update_request_implies_at_after_update_resource_step(SubResource::DaemonSet, fb)(s)
+        &&& helper_invariants::no_delete_resource_request_msg_in_flight(SubResource::DaemonSet, fb)(s)
+        &&& helper_invariants::resource_object_only_has_owner_reference_pointing_to_current_cr(SubResource::DaemonSet, fb)(s)
+        &&& helper_invariants::daemon_set_in_etcd_satisfies_unchangeable(fb)(s)
+        &&& helper_invariants::config_hash_is_the_same_as_etcd_server_secret_if_secret_fetched(fb)(s)
+        &&& sub_resource_state_matches(SubResource::DaemonSet, fb)(s)
+        &&& desired_config_is_stable(fb)(s, s_prime)
+        &&& daemon_set_status_is_stable::<FluentBitMaker>(fb)(s, s_prime)
+    };
+
+    combine_spec_entails_always_n!(
+        spec, lift_action(stronger_next),
+        lift_action(FBCluster::next()),
+        lift_state(FBCluster::crash_disabled()),
+        lift_state(FBCluster::busy_disabled()),
+        lift_state(FBCluster::pending_req_of_key_is_unique_with_unique_id(fb.object_ref())),
+        lift_state(FBCluster::every_in_flight_msg_has_unique_id()),
+        lift_state(FBCluster::each_object_in_etcd_is_well_formed()),
+        lift_state(desired_state_is(fb)),
+        lift_state(helper_invariants::every_resource_update_request_implies_at_after_update_resource_step(SubResource::DaemonSet, fb)),
+        lift_state(helper_invariants::no_delete_resource_request_msg_in_flight(SubResource::DaemonSet, fb)),
+        lift_state(helper_invariants::resource_object_only_has_owner_reference_pointing_to_current_cr(SubResource::DaemonSet, fb)),
+        lift_state(helper_invariants::daemon_set_in_etcd_satisfies_unchangeable(fb)),
+        lift_state(helper_invariants::config_hash_is_the_same_as_etcd_server_secret_if_secret_fetched(fb)),
+        lift_state(sub_resource_state_matches(SubResource::DaemonSet, fb)),
+        lift_action(desired_config_is_stable(fb)),
+        lift_action(daemon_set_status_is_stable::<FluentBitMaker>(fb))
+    );
+
+    assert forall |s, s_prime: FBCluster| pre(s) && #[trigger] stronger_next(s, s_prime) implies pre(s_prime) || post(s_prime) by {
+        let step = choose |step| FBCluster::next_step(s, s_prime, step);
+        match step {
+            Step::ApiServerStep(input) => {
+                let req = input.get_Some_0();
+                assert(!resource_update_request_msg(resource_key)(req));
+                daemon_set_is_unchanged_unless_updated_by_controller(s, s_prime, fb, req);
+            },
+            _ => {}
+        }
+    }
+    FBCluster::lemma_pre_leads_to_post_by_controller(spec, input, stronger_next, FBCluster::continue_reconcile(), pre, post);
+}
+
+#[verifier(spinoff_prover)]
+proof fn lemma_receives_ok_resp_at_after_update_daemon_set_step(spec: TempPred<FBCluster>, fb: FluentBitView, req_msg: FBMessage)
+    requires
+        spec.entails(always(lift_action(FBCluster::next()))),
+        spec.entails(tla_forall(|i| FBCluster::kubernetes_api_next().weak_fairness(i))),
+        spec.entails(always(lift_state(FBCluster::crash_disabled()))),
+        spec.entails(always(lift_state(FBCluster::busy_disabled()))),
+        spec.entails(always(lift_state(FBCluster::every_in_flight_msg_has_unique_id()))),
+        spec.entails(always(lift_state(FBCluster::each_object_in_etcd_is_well_formed()))),
+        spec.entails(always(lift_state(desired_state_is(fb)))),
+        spec.entails(always(lift_state(helper_invariants::the_object_in_reconcile_satisfies_state_validation(fb.object_ref())))),
+        spec.entails(always(lift_state(helper_invariants::every_resource_update_request_implies_at_after_update_resource_step(SubResource::DaemonSet, fb)))),
+        spec.entails(always(lift_state(helper_invariants::no_delete_resource_request_msg_in_flight(SubResource::DaemonSet, fb)))),
+        spec.entails(always(lift_state(helper_invariants::resource_object_has_no_finalizers_or_timestamp_and_only_has_controller_owner_ref(SubResource::DaemonSet, fb)))),
+        spec.entails(always(lift_state(helper_invariants::resource_object_only_has_owner_reference_pointing_to_current_cr(SubResource::DaemonSet, fb)))),
+        spec.entails(always(lift_state(helper_invariants::daemon_set_in_etcd_satisfies_unchangeable(fb)))),
+        spec.entails(always(lift_state(helper_invariants::config_hash_is_the_same_as_etcd_server_secret_if_secret_fetched(fb)))),
+        spec.entails(always(lift_action(desired_config_is_stable(fb)))),
+        spec.entails(always(lift_action(daemon_set_status_is_stable::<FluentBitMaker>(fb)))),
+    ensures
+        spec.entails(lift_state(req_msg_is_the_in_flight_pending_req_at_after_update_resource_step(SubResource::DaemonSet, fb, req_msg))
+            .leads_to(lift_state(at_after_update_resource_step_and_exists_ok_resp_in_flight(SubResource::DaemonSet, fb)))),
+{
+    let pre = req_msg_is_the_in_flight_pending_req_at_after_update_resource_step(SubResource::DaemonSet, fb, req_msg);
+    let post = at_after_update_resource_step_and_exists_ok_resp_in_flight(SubResource::DaemonSet, fb);
+    let resource_key = get_request(SubResource::DaemonSet, fb).key;
+    let resource_well_formed = |s: FBCluster| {
+        s.resources().contains_key(resource_key)
+        ==> FBCluster::etcd_object_is_well_formed(resource_key)(s)
+    };
+    let input = Some(req_msg);
+    let stronger_next = |s, s_prime: FBCluster| {
+        &&& FBCluster::next()(s, s_prime)
+        &&& FBCluster::crash_disabled()(s)
+        &&& FBCluster::busy_disabled()(s)
+        &&& FBCluster::every_in_flight_msg_has_unique_id()(s)
+        &&& FBCluster::each_object_in_etcd_is_well_formed()(s)
+        &&& resource_well_formed(s_prime)
+        &&& desired_state_is(fb)(s)
+        &&& helper_invariants::the_object_in_reconcile_satisfies_state_validation(fb.object_ref())(s)
+        &&& helper_invariants::every_resource_update_request_implies_at_after_update_resource_step(SubResource::DaemonSet, fb)(s)
+        &&& helper_invariants::no_delete_resource_request_msg_in_flight(SubResource::DaemonSet, fb)(s)
+        &&& helper_invariants::resource_object_has_no_finalizers_or_timestamp_and_only_has_controller_owner_ref(SubResource::DaemonSet, fb)(s)
+        &&& helper_invariants::resource_object_only_has_owner_reference_pointing_to_current_cr(SubResource::DaemonSet, fb)(s)
+        &&& helper_invariants::daemon_set_in_etcd_satisfies_unchangeable(fb)(s)
+        &&& helper_invariants::config_hash_is_the_same_as_etcd_server_secret_if_secret_fetched(fb)(s)
+        &&& desired_config_is_stable(fb)(s, s_prime)
+        &&& daemon_set_status_is_stable::<FluentBitMaker>(fb)(s, s_prime)
+    };
+    always_weaken(spec, lift_state(FBCluster::each_object_in_etcd_is_well_formed()), lift_state(resource_well_formed));
+    always_to_always_later(spec, lift_state(resource_well_formed));
+    combine_spec_entails_always_n!(
+        spec, lift_action(stronger_next),
+        lift_action(FBCluster::next()),
+        lift_state(FBCluster::crash_disabled()),
+        lift_state(FBCluster::busy_disabled()),
+        lift_state(FBCluster::every_in_flight_msg_has_unique_id()),
+        lift_state(FBCluster::each_object_in_etcd_is_well_formed()),
+        later(lift_state(resource_well_formed)),
+        lift_state(desired_state_is(fb)),
+        lift_state(helper_invariants::the_object_in_reconcile_satisfies_state_validation(fb.object_ref())),
+        lift_state(helper_invariants::every_resource_update_request_implies_at_after_update_resource_step(SubResource::DaemonSet, fb)),
+        lift_state(helper_invariants::no_delete_resource_request_msg_in_flight(SubResource::DaemonSet, fb)),
+        lift_state(helper_invariants::resource_object_has_no_finalizers_or_timestamp_and_only_has_controller_owner_ref(SubResource::DaemonSet, fb)),
+        lift_state(helper_invariants::resource_object_only_has_owner_reference_pointing_to_current_cr(SubResource::DaemonSet, fb)),
+        lift_state(helper_invariants::daemon_set_in_etcd_satisfies_unchangeable(fb)),
+        lift_state(helper_invariants::config_hash_is_the_same_as_etcd_server_secret_if_secret_fetched(fb)),
+        lift_action(desired_config_is_stable(fb)),
+        lift_action(daemon_set_status_is_stable::<FluentBitMaker>(fb))
+    );
+
+    assert forall |s, s_prime: FBCluster| pre(s) && #[trigger] stronger_next(s, s_prime) && FBCluster::kubernetes_api_next().forward(input)(s, s_prime)
+    implies post(s_prime) by {
+        let resp_msg = FBCluster::handle_update_request_msg(req_msg, s.kubernetes_api_state).1;
+        assert(s_prime.in_flight().contains(resp_msg));
+        DaemonSetView::marshal_preserves_integrity();
+        assert({
+            &&& Message::resp_msg_matches_req_msg(resp_msg, req_msg)
+            &&& resp_msg.content.get_update_response().res.is_Ok()
+            &&& resp_msg.content.get_update_response().res.get_Ok_0() == s_prime.resources()[resource_key]
+        });
+    }
+
+    assert forall |s, s_prime: FBCluster| pre(s) && #[trigger] stronger_next(s, s_prime) implies pre(s_prime) || post(s_prime) by {
+        let step = choose |step| FBCluster::next_step(s, s_prime, step);
+        match step {
+            Step::ApiServerStep(input) => {
+                let req = input.get_Some_0();
+                if req == req_msg {
+                    let resp_msg = FBCluster::handle_update_request_msg(req_msg, s.kubernetes_api_state).1;
+                    assert(s_prime.in_flight().contains(resp_msg));
+                    DaemonSetView::marshal_preserves_integrity();
+                    assert(post(s_prime));
+                } else if resource_update_request_msg(resource_key)(req) {
+                    // The only update request of the daemon set is the pending request itself.
+                    assert(FBCluster::pending_req_msg_is(s, fb.object_ref(), req));
+                    assert(false);
+                } else {
+                    daemon_set_is_unchanged_unless_updated_by_controller(s, s_prime, fb, req);
+                }
+            },
+            _ => {}
+        }
+    }
+
+    FBCluster::lemma_pre_leads_to_post_by_kubernetes_api(spec, input, stronger_next, FBCluster::handle_request(), pre, post);
+}
+
+#[verifier(spinoff_prover)]
+proof fn lemma_from_after_update_daemon_set_step_to_after_get_daemon_set_status_step(spec: TempPred<FBCluster>, fb: FluentBitView, resp_msg: FBMessage)
+    requires
+        spec.entails(always(lift_action(FBCluster::next()))),
+        spec.entails(tla_forall(|i| FBCluster::controller_next().weak_fairness(i))),
+        spec.entails(always(lift_state(FBCluster::crash_disabled()))),
+        spec.entails(always(lift_state(FBCluster::busy_disabled()))),
+        spec.entails(always(lift_state(FBCluster::pending_req_of_key_is_unique_with_unique_id(fb.object_ref())))),
+        spec.entails(always(lift_state(FBCluster::every_in_flight_msg_has_unique_id()))),
+        spec.entails(always(lift_state(helper_invariants::config_hash_is_the_same_as_etcd_server_secret_if_secret_fetched(fb)))),
+        spec.entails(always(lift_action(desired_config_is_stable(fb)))),
+    ensures
+        spec.entails(lift_state(resp_msg_is_the_in_flight_ok_resp_at_after_update_resource_step(SubResource::DaemonSet, fb, resp_msg))
+            .leads_to(lift_state(pending_req_in_flight_at_after_get_daemon_set_status_step(fb)))),
+{
+    let pre = resp_msg_is_the_in_flight_ok_resp_at_after_update_resource_step(SubResource::DaemonSet, fb, resp_msg);
+    let post = pending_req_in_flight_at_after_get_daemon_set_status_step(fb);
+    let input = (Some(resp_msg), Some(fb.object_ref()));
+    let stronger_next = |s, s_prime: FBCluster| {
+        &&& FBCluster::next()(s, s_prime)
+        &&& FBCluster::crash_disabled()(s)
+        &&& FBCluster::busy_disabled()(s)
+        &&& FBCluster::pending_req_of_key_is_unique_with_unique_id(fb.object_ref())(s)
+        &&& FBCluster::every_in_flight_msg_has_unique_id()(s)
+        &&& helper_invariants::config_hash_is_the_same_as_etcd_server_secret_if_secret_fetched(fb)(s)
+        &&& desired_config_is_stable(fb)(s, s_prime)
+    };
+
+    combine_spec_entails_always_n!(
+        spec, lift_action(stronger_next),
+        lift_action(FBCluster::next()),
+        lift_state(FBCluster::crash_disabled()),
+        lift_state(FBCluster::busy_disabled()),
+        lift_state(FBCluster::pending_req_of_key_is_unique_with_unique_id(fb.object_ref())),
+        lift_state(FBCluster::every_in_flight_msg_has_unique_id()),
+        lift_state(helper_invariants::config_hash_is_the_same_as_etcd_server_secret_if_secret_fetched(fb)),
+        lift_action(desired_config_is_stable(fb))
+    );
+
+    FBCluster::lemma_pre_leads_to_post_by_controller(spec, input, stronger_next, FBCluster::continue_reconcile(), pre, post);
+}
+
+#[verifier(spinoff_prover)]
+proof fn lemma_receives_ok_resp_at_after_get_daemon_set_status_step(spec: TempPred<FBCluster>, fb: FluentBitView, req_msg: FBMessage)
+    requires
+        spec.entails(always(lift_action(FBCluster::next()))),
+        spec.entails(tla_forall(|i| FBCluster::kubernetes_api_next().weak_fairness(i))),
+        spec.entails(always(lift_state(FBCluster::crash_disabled()))),
+        spec.entails(always(lift_state(FBCluster::busy_disabled()))),
+        spec.entails(always(lift_state(FBCluster::every_in_flight_msg_has_unique_id()))),
+        spec.entails(always(lift_state(FBCluster::each_object_in_etcd_is_well_formed()))),
+        spec.entails(always(lift_state(sub_resource_state_matches(SubResource::DaemonSet, fb)))),
+        spec.entails(always(lift_action(desired_config_is_stable(fb)))),
+        spec.entails(always(lift_action(daemon_set_status_is_stable::<FluentBitMaker>(fb)))),
+    ensures
+        spec.entails(lift_state(req_msg_is_the_in_flight_pending_req_at_after_get_daemon_set_status_step(fb, req_msg))
+            .leads_to(lift_state(at_after_get_daemon_set_status_step_and_exists_ok_resp_in_flight(fb)))),
+{
+    let pre = req_msg_is_the_in_flight_pending_req_at_after_get_daemon_set_status_step(fb, req_msg);
+    let post = at_after_get_daemon_set_status_step_and_exists_ok_resp_in_flight(fb);
+    let resource_key = get_request(SubResource::DaemonSet, fb).key;
+    let input = Some(req_msg);
+    let stronger_next = |s, s_prime: FBCluster| {
+        &&& FBCluster::next()(s, s_prime)
+        &&& FBCluster::crash_disabled()(s)
+        &&& FBCluster::busy_disabled()(s)
+        &&& FBCluster::every_in_flight_msg_has_unique_id()(s)
+        &&& FBCluster::each_object_in_etcd_is_well_formed()(s_prime)
+        &&& sub_resource_state_matches(SubResource::DaemonSet, fb)(s_prime)
+        &&& desired_config_is_stable(fb)(s, s_prime)
+    };
+    always_to_always_later(spec, lift_state(FBCluster::each_object_in_etcd_is_well_formed()));
+    always_to_always_later(spec, lift_state(sub_resource_state_matches(SubResource::DaemonSet, fb)));
+    combine_spec_entails_always_n!(
+        spec, lift_action(stronger_next),
+        lift_action(FBCluster::next()),
+        lift_state(FBCluster::crash_disabled()),
+        lift_state(FBCluster::busy_disabled()),
+        lift_state(FBCluster::every_in_flight_msg_has_unique_id()),
+        later(lift_state(FBCluster::each_object_in_etcd_is_well_formed())),
+        later(lift_state(sub_resource_state_matches(SubResource::DaemonSet, fb))),
+        lift_action(desired_config_is_stable(fb))
+    );
+
+    assert forall |s, s_prime| pre(s) && #[trigger] stronger_next(s, s_prime) && FBCluster::kubernetes_api_next().forward(input)(s, s_prime)
+    implies post(s_prime) by {
+        let resp_msg = FBCluster::handle_get_request_msg(req_msg, s.kubernetes_api_state).1;
+        assert(s_prime.resources()[resource_key] == s.resources()[resource_key]);
+        assert(resp_msg_is_the_in_flight_ok_resp_at_after_get_daemon_set_status_step(fb, resp_msg)(s_prime));
+    }
+
+    assert forall |s, s_prime| pre(s) && #[trigger] stronger_next(s, s_prime) implies pre(s_prime) || post(s_prime) by {
+        let step = choose |step| FBCluster::next_step(s, s_prime, step);
+        match step {
+            Step::ApiServerStep(input) => {
+                if input.get_Some_0() == req_msg {
+                    let resp_msg = FBCluster::handle_get_request_msg(req_msg, s.kubernetes_api_state).1;
+                    assert(resp_msg_is_the_in_flight_ok_resp_at_after_get_daemon_set_status_step(fb, resp_msg)(s_prime));
+                }
+            },
+            _ => {}
+        }
+    }
+
+    FBCluster::lemma_pre_leads_to_post_by_kubernetes_api(
+        spec, input, stronger_next, FBCluster::handle_request(), pre, post
+    );
+}
+
+#[verifier(spinoff_prover)]
+proof fn lemma_from_after_get_daemon_set_status_step_to_after_reload_config_step(spec: TempPred<FBCluster>, fb: FluentBitView, resp_msg: FBMessage)
+    requires
+        spec.entails(always(lift_action(FBCluster::next()))),
+        spec.entails(tla_forall(|i| FBCluster::controller_next().weak_fairness(i))),
+        spec.entails(always(lift_state(FBCluster::crash_disabled()))),
+        spec.entails(always(lift_state(FBCluster::busy_disabled()))),
+        spec.entails(always(lift_state(FBCluster::pending_req_of_key_is_unique_with_unique_id(fb.object_ref())))),
+        spec.entails(always(lift_state(FBCluster::every_in_flight_msg_has_unique_id()))),
+        spec.entails(always(lift_state(FBCluster::each_object_in_etcd_is_well_formed()))),
+        spec.entails(always(lift_state(sub_resource_state_matches(SubResource::DaemonSet, fb)))),
+        spec.entails(always(lift_action(desired_config_is_stable(fb)))),
+        spec.entails(always(lift_action(daemon_set_status_is_stable::<FluentBitMaker>(fb)))),
+    ensures
+        spec.entails(lift_state(resp_msg_is_the_in_flight_ok_resp_at_after_get_daemon_set_status_step(fb, resp_msg))
+            .leads_to(lift_state(pending_req_in_flight_at_after_reload_config_step(fb)))),
+{
+    let pre = resp_msg_is_the_in_flight_ok_resp_at_after_get_daemon_set_status_step(fb, resp_msg);
+    let post = pending_req_in_flight_at_after_reload_config_step(fb);
+    let input = (Some(resp_msg), Some(fb.object_ref()));
+    let stronger_next = |s, s_prime: FBCluster| {
+        &&& FBCluster::next()(s, s_prime)
+        &&& FBCluster::crash_disabled()(s)
+        &&& FBCluster::busy_disabled()(s)
+        &&& FBCluster::pending_req_of_key_is_unique_with_unique_id(fb.object_ref())(s)
+        &&& FBCluster::every_in_flight_msg_has_unique_id()(s)
+        &&& FBCluster::each_object_in_etcd_is_well_formed()(s_prime)
+        &&& sub_resource_state_matches(SubResource::DaemonSet, fb)(s_prime)
+        &&& desired_config_is_stable(fb)(s, s_prime)
+        &&& daemon_set_status_is_stable::<FluentBitMaker>(fb)(s, s_prime)
+    };
+    always_to_always_later(spec, lift_state(FBCluster::each_object_in_etcd_is_well_formed()));
+    always_to_always_later(spec, lift_state(sub_resource_state_matches(SubResource::DaemonSet, fb)));
+    combine_spec_entails_always_n!(
+        spec, lift_action(stronger_next),
+        lift_action(FBCluster::next()),
+        lift_state(FBCluster::crash_disabled()),
+        lift_state(FBCluster::busy_disabled()),
+        lift_state(FBCluster::pending_req_of_key_is_unique_with_unique_id(fb.object_ref())),
+        lift_state(FBCluster::every_in_flight_msg_has_unique_id()),
+        later(lift_state(FBCluster::each_object_in_etcd_is_well_formed())),
+        later(lift_state(sub_resource_state_matches(SubResource::DaemonSet, fb))),
+        lift_action(desired_config_is_stable(fb)),
+        lift_action(daemon_set_status_is_stable::<FluentBitMaker>(fb))
+    );
+
+    FBCluster::lemma_pre_leads_to_post_by_controller(spec, input, stronger_next, FBCluster::continue_reconcile(), pre, post);
+}
+
+// The reload request is handled by the fluent-bit pods of the daemon set, which exists since the daemon set matches.
+#[verifier(spinoff_prover)]
+proof fn lemma_receives_ok_resp_at_after_reload_config_step(spec: TempPred<FBCluster>, fb: FluentBitView, req_msg: FBMessage)
+    requires
+        spec.entails(always(lift_action(FBCluster::next()))),
+        spec.entails(tla_forall(|i| FBCluster::external_api_next().weak_fairness(i))),
+        spec.entails(always(lift_state(FBCluster::crash_disabled()))),
+        spec.entails(always(lift_state(FBCluster::busy_disabled()))),
+        spec.entails(always(lift_state(FBCluster::every_in_flight_msg_has_unique_id()))),
+        spec.entails(always(lift_state(FBCluster::each_object_in_etcd_is_well_formed()))),
+        spec.entails(always(lift_state(sub_resource_state_matches(SubResource::DaemonSet, fb)))),
+        spec.entails(always(lift_action(desired_config_is_stable(fb)))),
+        spec.entails(always(lift_action(daemon_set_status_is_stable::<FluentBitMaker>(fb)))),
+    ensures
+        spec.entails(lift_state(req_msg_is_the_in_flight_pending_req_at_after_reload_config_step(fb, req_msg))
+            .leads_to(lift_state(at_after_reload_config_step_and_exists_ok_resp_in_flight(fb)))),
+{
+    let pre = req_msg_is_the_in_flight_pending_req_at_after_reload_config_step(fb, req_msg);
+    let post = at_after_reload_config_step_and_exists_ok_resp_in_flight(fb);
+    let input = Some(req_msg);
+    let stronger_next = |s, s_prime: FBCluster| {
+        &&& FBCluster::next()(s, s_prime)
+        &&& FBCluster::crash_disabled()(s)
+        &&& FBCluster::busy_disabled()(s)
+        &&& FBCluster::every_in_flight_msg_has_unique_id()(s)
+        &&& FBCluster::each_object_in_etcd_is_well_formed()(s_prime)
+        &&& sub_resource_state_matches(SubResource::DaemonSet, fb)(s)
+        &&& desired_config_is_stable(fb)(s, s_prime)
+        &&& daemon_set_status_is_stable::<FluentBitMaker>(fb)(s, s_prime)
+    };
+    always_to_always_later(spec, lift_state(FBCluster::each_object_in_etcd_is_well_formed()));
+    combine_spec_entails_always_n!(
+        spec, lift_action(stronger_next),
+        lift_action(FBCluster::next()),
+        lift_state(FBCluster::crash_disabled()),
+        lift_state(FBCluster::busy_disabled()),
+        lift_state(FBCluster::every_in_flight_msg_has_unique_id()),
+        later(lift_state(FBCluster::each_object_in_etcd_is_well_formed())),
+        lift_state(sub_resource_state_matches(SubResource::DaemonSet, fb)),
+        lift_action(desired_config_is_stable(fb)),
+        lift_action(daemon_set_status_is_stable::<FluentBitMaker>(fb))
+    );
+
+    assert forall |s, s_prime| pre(s) && #[trigger] stronger_next(s, s_prime) && FBCluster::external_api_next().forward(input)(s, s_prime)
+    implies post(s_prime) by {
+        let resp_msg = FBCluster::handle_external_request_helper(req_msg, s.external_api_state, s.resources()).1;
+        assert(daemon_set_key(make_daemon_set_name(fb), fb.metadata.namespace.get_Some_0()) == make_daemon_set_key(fb));
+        assert(s.resources().contains_key(make_daemon_set_key(fb)));
+        assert(resp_msg_is_the_in_flight_ok_resp_at_after_reload_config_step(fb, resp_msg)(s_prime));
+    }
+
+    assert forall |s, s_prime| pre(s) && #[trigger] stronger_next(s, s_prime) implies pre(s_prime) || post(s_prime) by {
+        let step = choose |step| FBCluster::next_step(s, s_prime, step);
+        match step {
+            Step::ExternalAPIStep(input) => {
+                if input.get_Some_0() == req_msg {
+                    let resp_msg = FBCluster::handle_external_request_helper(req_msg, s.external_api_state, s.resources()).1;
+                    assert(daemon_set_key(make_daemon_set_name(fb), fb.metadata.namespace.get_Some_0()) == make_daemon_set_key(fb));
+                    assert(s.resources().contains_key(make_daemon_set_key(fb)));
+                    assert(resp_msg_is_the_in_flight_ok_resp_at_after_reload_config_step(fb, resp_msg)(s_prime));
+                }
+            },
+            _ => {}
+        }
+    }
+
+    FBCluster::lemma_pre_leads_to_post_by_external_api(
+        spec, input, stronger_next, FBCluster::handle_external_request(), pre, post
+    );
+}
+
+#[verifier(spinoff_prover)]
+proof fn lemma_from_after_reload_config_step_to_after_get_fluentbit_step(spec: TempPred<FBCluster>, fb: FluentBitView, resp_msg: FBMessage)
+    requires
+        spec.entails(always(lift_action(FBCluster::next()))),
+        spec.entails(tla_forall(|i| FBCluster::controller_next().weak_fairness(i))),
+        spec.entails(always(lift_state(FBCluster::crash_disabled()))),
+        spec.entails(always(lift_state(FBCluster::busy_disabled()))),
+        spec.entails(always(lift_state(FBCluster::pending_req_of_key_is_unique_with_unique_id(fb.object_ref())))),
+        spec.entails(always(lift_state(FBCluster::every_in_flight_msg_has_unique_id()))),
+        spec.entails(always(lift_state(FBCluster::each_object_in_etcd_is_well_formed()))),
+        spec.entails(always(lift_state(sub_resource_state_matches(SubResource::DaemonSet, fb)))),
+        spec.entails(always(lift_action(desired_config_is_stable(fb)))),
+        spec.entails(always(lift_action(daemon_set_status_is_stable::<FluentBitMaker>(fb)))),
+    ensures
+        spec.entails(lift_state(resp_msg_is_the_in_flight_ok_resp_at_after_reload_config_step(fb, resp_msg))
+            .leads_to(lift_state(pending_req_in_flight_at_after_get_fluentbit_step(fb)))),
+{
+    let pre = resp_msg_is_the_in_flight_ok_resp_at_after_reload_config_step(fb, resp_msg);
+    let post = pending_req_in_flight_at_after_get_fluentbit_step(fb);
+    let input = (Some(resp_msg), Some(fb.object_ref()));
+    let stronger_next = |s, s_prime: FBCluster| {
+        &&& FBCluster::next()(s, s_prime)
+        &&& FBCluster::crash_disabled()(s)
+        &&& FBCluster::busy_disabled()(s)
+        &&& FBCluster::pending_req_of_key_is_unique_with_unique_id(fb.object_ref())(s)
+        &&& FBCluster::every_in_flight_msg_has_unique_id()(s)
+        &&& FBCluster::each_object_in_etcd_is_well_formed()(s_prime)
+        &&& sub_resource_state_matches(SubResource::DaemonSet, fb)(s_prime)
+        &&& desired_config_is_stable(fb)(s, s_prime)
+        &&& daemon_set_status_is_stable::<FluentBitMaker>(fb)(s, s_prime)
+    };
+    always_to_always_later(spec, lift_state(FBCluster::each_object_in_etcd_is_well_formed()));
+    always_to_always_later(spec, lift_state(sub_resource_state_matches(SubResource::DaemonSet, fb)));
+    combine_spec_entails_always_n!(
+        spec, lift_action(stronger_next),
+        lift_action(FBCluster::next()),
+        lift_state(FBCluster::crash_disabled()),
+        lift_state(FBCluster::busy_disabled()),
+        lift_state(FBCluster::pending_req_of_key_is_unique_with_unique_id(fb.object_ref())),
+        lift_state(FBCluster::every_in_flight_msg_has_unique_id()),
+        later(lift_state(FBCluster::each_object_in_etcd_is_well_formed())),
+        later(lift_state(sub_resource_state_matches(SubResource::DaemonSet, fb))),
+        lift_action(desired_config_is_stable(fb)),
+        lift_action(daemon_set_status_is_stable::<FluentBitMaker>(fb))
+    );
+
+    FBCluster::lemma_pre_leads_to_post_by_controller(spec, input, stronger_next, FBCluster::continue_reconcile(), pre, post);
+}
+
+#[verifier(spinoff_prover)]
+proof fn lemma_receives_ok_resp_at_after_get_fluentbit_step(spec: TempPred<FBCluster>, fb: FluentBitView, req_msg: FBMessage)
+    requires
+        spec.entails(always(lift_action(FBCluster::next()))),
+        spec.entails(tla_forall(|i| FBCluster::kubernetes_api_next().weak_fairness(i))),
+        spec.entails(always(lift_state(FBCluster::crash_disabled()))),
+        spec.entails(always(lift_state(FBCluster::busy_disabled()))),
+        spec.entails(always(lift_state(FBCluster::every_in_flight_msg_has_unique_id()))),
+        spec.entails(always(lift_state(FBCluster::each_object_in_etcd_is_well_formed()))),
+        spec.entails(always(lift_state(desired_state_is(fb)))),
+        spec.entails(always(lift_state(sub_resource_state_matches(SubResource::DaemonSet, fb)))),
+        spec.entails(always(lift_action(desired_config_is_stable(fb)))),
+        spec.entails(always(lift_action(daemon_set_status_is_stable::<FluentBitMaker>(fb)))),
+    ensures
+        spec.entails(lift_state(req_msg_is_the_in_flight_pending_req_at_after_get_fluentbit_step(fb, req_msg))
+            .leads_to(lift_state(at_after_get_fluentbit_step_and_exists_ok_resp_in_flight(fb)))),
+{
+    let pre = req_msg_is_the_in_flight_pending_req_at_after_get_fluentbit_step(fb, req_msg);
+    let post = at_after_get_fluentbit_step_and_exists_ok_resp_in_flight(fb);
+    let input = Some(req_msg);
+    let stronger_next = |s, s_prime: FBCluster| {
+        &&& FBCluster::next()(s, s_prime)
+        &&& FBCluster::crash_disabled()(s)
+        &&& FBCluster::busy_disabled()(s)
+        &&& FBCluster::every_in_flight_msg_has_unique_id()(s)
+        &&& FBCluster::each_object_in_etcd_is_well_formed()(s_prime)
+        &&& desired_state_is(fb)(s_prime)
+        &&& sub_resource_state_matches(SubResource::DaemonSet, fb)(s_prime)
+        &&& desired_config_is_stable(fb)(s, s_prime)
+        &&& daemon_set_status_is_stable::<FluentBitMaker>(fb)(s, s_prime)
+    };
+    always_to_always_later(spec, lift_state(FBCluster::each_object_in_etcd_is_well_formed()));
+    always_to_always_later(spec, lift_state(desired_state_is(fb)));
+    always_to_always_later(spec, lift_state(sub_resource_state_matches(SubResource::DaemonSet, fb)));
+    combine_spec_entails_always_n!(
+        spec, lift_action(stronger_next),
+        lift_action(FBCluster::next()),
+        lift_state(FBCluster::crash_disabled()),
+        lift_state(FBCluster::busy_disabled()),
+        lift_state(FBCluster::every_in_flight_msg_has_unique_id()),
+        later(lift_state(FBCluster::each_object_in_etcd_is_well_formed())),
+        later(lift_state(desired_state_is(fb))),
+        later(lift_state(sub_resource_state_matches(SubResource::DaemonSet, fb))),
+        lift_action(desired_config_is_stable(fb)),
+        lift_action(daemon_set_status_is_stable::<FluentBitMaker>(fb))
+    );
+
+    assert forall |s, s_prime| pre(s) && #[trigger] stronger_next(s, s_prime) && FBCluster::kubernetes_api_next().forward(input)(s, s_prime)
+    implies post(s_prime) by {
+        let resp_msg = FBCluster::handle_get_request_msg(req_msg, s.kubernetes_api_state).1;
+        assert(resp_msg_is_the_in_flight_ok_resp_at_after_get_fluentbit_step(fb, resp_msg)(s_prime));
+    }
+
+    assert forall |s, s_prime| pre(s) && #[trigger] stronger_next(s, s_prime) implies pre(s_prime) || post(s_prime) by {
+        let step = choose |step| FBCluster::next_step(s, s_prime, step);
+        match step {
+            Step::ApiServerStep(input) => {
+                if input.get_Some_0() == req_msg {
+                    let resp_msg = FBCluster::handle_get_request_msg(req_msg, s.kubernetes_api_state).1;
+                    assert(resp_msg_is_the_in_flight_ok_resp_at_after_get_fluentbit_step(fb, resp_msg)(s_prime));
+                }
+            },
+            _ => {}
+        }
+    }
+
+    FBCluster::lemma_pre_leads_to_post_by_kubernetes_api(
+        spec, input, stronger_next, FBCluster::handle_request(), pre, post
+    );
+}
+
+// The fluentbit object only changes when its status changes, and its status only changes by the status update request of
+// the controller. So the fluentbit stays the same before the controller sends the status update request.
+proof fn fluentbit_is_unchanged_unless_updated_by_controller(s: FBCluster, s_prime: FBCluster, fb: FluentBitView, req: FBMessage)
+    requires
+        helper_invariants::every_update_status_request_of_fb_is_the_pending_req(fb)(s),
+        fluentbit_is_stable_except_status(fb)(s, s_prime),
+        desired_state_is(fb)(s),
+        desired_state_is(fb)(s_prime),
+        s.in_flight().contains(req),
+        FBCluster::next_step(s, s_prime, Step::ApiServerStep(Some(req))),
+        at_fb_step(fb.object_ref(), FluentBitReconcileStep::AfterGetFluentBit)(s) || !FBCluster::pending_req_msg_is(s, fb.object_ref(), req),
+    ensures s_prime.resources()[fb.object_ref()] == s.resources()[fb.object_ref()],
+{
+    let key = fb.object_ref();
+    assert(!resource_update_status_request_msg(key)(req));
+    if req.content.is_update_request() && req.content.get_update_request().key() == key {
+        // An update request never changes the status, so it cannot change the fluentbit object either.
+        assert(s_prime.resources()[key].status == s.resources()[key].status);
+    }
+}
+
+#[verifier(spinoff_prover)]
+proof fn lemma_from_after_get_fluentbit_step_to_after_update_status_step(spec: TempPred<FBCluster>, fb: FluentBitView, resp_msg: FBMessage)
+    requires
+        spec.entails(always(lift_action(FBCluster::next()))),
+        spec.entails(tla_forall(|i| FBCluster::controller_next().weak_fairness(i))),
+        spec.entails(always(lift_state(FBCluster::crash_disabled()))),
+        spec.entails(always(lift_state(FBCluster::busy_disabled()))),
+        spec.entails(always(lift_state(FBCluster::pending_req_of_key_is_unique_with_unique_id(fb.object_ref())))),
+        spec.entails(always(lift_state(FBCluster::every_in_flight_msg_has_unique_id()))),
+        spec.entails(always(lift_state(FBCluster::each_object_in_etcd_is_well_formed()))),
+        spec.entails(always(lift_state(desired_state_is(fb)))),
+        spec.entails(always(lift_state(helper_invariants::every_update_status_request_of_fb_is_the_pending_req(fb)))),
+        spec.entails(always(lift_state(sub_resource_state_matches(SubResource::DaemonSet, fb)))),
+        spec.entails(always(lift_action(desired_config_is_stable(fb)))),
+        spec.entails(always(lift_action(daemon_set_status_is_stable::<FluentBitMaker>(fb)))),
+        spec.entails(always(lift_action(fluentbit_is_stable_except_status(fb)))),
+    ensures
+        spec.entails(lift_state(resp_msg_is_the_in_flight_ok_resp_at_after_get_fluentbit_step(fb, resp_msg))
+            .leads_to(lift_state(pending_req_in_flight_at_after_update_status_step(fb)))),
+{
+    let pre = resp_msg_is_the_in_flight_ok_resp_at_after_get_fluentbit_step(fb, resp_msg);
+    let post = pending_req_in_flight_at_after_update_status_step(fb);
+    let input = (Some(resp_msg), Some(fb.object_ref()));
+    let stronger_next = |s, s_prime: FBCluster| {
+        &&& FBCluster::next()(s, s_prime)
+        &&& FBCluster::crash_disabled()(s)
+        &&& FBCluster::busy_disabled()(s)
+        &&& FBCluster::pending_req_of_key_is_unique_with_unique_id(fb.object_ref())(s)
+        &&& FBCluster::every_in_flight_msg_has_unique_id()(s)
+        &&& FBCluster::each_object_in_etcd_is_well_formed()(s)
+        &&& FBCluster::each_object_in_etcd_is_well_formed()(s_prime)
+        &&& desired_state_is(fb)(s)
+        &&& desired_state_is(fb)(s_prime)
+        &&& helper_invariants::every_update_status_request_of_fb_is_the_pending_req(fb)(s)
+        &&& sub_resource_state_matches(SubResource::DaemonSet, fb)(s_prime)
+        &&& desired_config_is_stable(fb)(s, s_prime)
+        &&& daemon_set_status_is_stable::<FluentBitMaker>(fb)(s, s_prime)
+        &&& fluentbit_is_stable_except_status(fb)(s, s_prime)
+    };
+    always_to_always_later(spec, lift_state(FBCluster::each_object_in_etcd_is_well_formed()));
+    always_to_always_later(spec, lift_state(desired_state_is(fb)));
+    always_to_always_later(spec, lift_state(sub_resource_state_matches(SubResource::DaemonSet, fb)));
+    combine_spec_entails_always_n!(
+        spec, lift_action(stronger_next),
+        lift_action(FBCluster::next()),
+        lift_state(FBCluster::crash_disabled()),
+        lift_state(FBCluster::busy_disabled()),
+        lift_state(FBCluster::pending_req_of_key_is_unique_with_unique_id(fb.object_ref())),
+        lift_state(FBCluster::every_in_flight_msg_has_unique_id()),
+        lift_state(FBCluster::each_object_in_etcd_is_well_formed()),
+        later(lift_state(FBCluster::each_object_in_etcd_is_well_formed())),
+        lift_state(desired_state_is(fb)),
+        later(lift_state(desired_state_is(fb))),
+        lift_state(helper_invariants::every_update_status_request_of_fb_is_the_pending_req(fb)),
+        later(lift_state(sub_resource_state_matches(SubResource::DaemonSet, fb))),
+        lift_action(desired_config_is_stable(fb)),
+        lift_action(daemon_set_status_is_stable::<FluentBitMaker>(fb)),
+        lift_action(fluentbit_is_stable_except_status(fb))
+    );
+
+    assert forall |s, s_prime: FBCluster| pre(s) && #[trigger] stronger_next(s, s_prime) implies pre(s_prime) || post(s_prime) by {
+        let step = choose |step| FBCluster::next_step(s, s_prime, step);
+        match step {
+            Step::ApiServerStep(input) => {
+                fluentbit_is_unchanged_unless_updated_by_controller(s, s_prime, fb, input.get_Some_0());
+            },
+            _ => {}
+        }
+    }
+    FBCluster::lemma_pre_leads_to_post_by_controller(spec, input, stronger_next, FBCluster::continue_reconcile(), pre, post);
+}
+
+#[verifier(spinoff_prover)]
+proof fn lemma_status_reflects_daemon_set_at_after_update_status_step(spec: TempPred<FBCluster>, fb: FluentBitView, req_msg: FBMessage)
+    requires
+        spec.entails(always(lift_action(FBCluster::next()))),
+        spec.entails(tla_forall(|i| FBCluster::kubernetes_api_next().weak_fairness(i))),
+        spec.entails(always(lift_state(FBCluster::crash_disabled()))),
+        spec.entails(always(lift_state(FBCluster::busy_disabled()))),
+        spec.entails(always(lift_state(FBCluster::every_in_flight_msg_has_unique_id()))),
+        spec.entails(always(lift_state(FBCluster::each_object_in_etcd_is_well_formed()))),
+        spec.entails(always(lift_state(desired_state_is(fb)))),
+        spec.entails(always(lift_state(helper_invariants::every_update_status_request_of_fb_is_the_pending_req(fb)))),
+        spec.entails(always(lift_state(sub_resource_state_matches(SubResource::DaemonSet, fb)))),
+        spec.entails(always(lift_action(desired_config_is_stable(fb)))),
+        spec.entails(always(lift_action(daemon_set_status_is_stable::<FluentBitMaker>(fb)))),
+        spec.entails(always(lift_action(fluentbit_is_stable_except_status(fb)))),
+    ensures
+        spec.entails(lift_state(req_msg_is_the_in_flight_pending_req_at_after_update_status_step(fb, req_msg))
+            .leads_to(lift_state(status_reflects_daemon_set::<FluentBitMaker>(fb)))),
+{
+    let pre = req_msg_is_the_in_flight_pending_req_at_after_update_status_step(fb, req_msg);
+    let post = status_reflects_daemon_set::<FluentBitMaker>(fb);
+    let input = Some(req_msg);
+    let stronger_next = |s, s_prime: FBCluster| {
+        &&& FBCluster::next()(s, s_prime)
+        &&& FBCluster::crash_disabled()(s)
+        &&& FBCluster::busy_disabled()(s)
+        &&& FBCluster::every_in_flight_msg_has_unique_id()(s)
+        &&& FBCluster::each_object_in_etcd_is_well_formed()(s)
+        &&& FBCluster::each_object_in_etcd_is_well_formed()(s_prime)
+        &&& desired_state_is(fb)(s)
+        &&& desired_state_is(fb)(s_prime)
+        &&& helper_invariants::every_update_status_request_of_fb_is_the_pending_req(fb)(s)
+        &&& sub_resource_state_matches(SubResource::DaemonSet, fb)(s_prime)
+        &&& desired_config_is_stable(fb)(s, s_prime)
+        &&& daemon_set_status_is_stable::<FluentBitMaker>(fb)(s, s_prime)
+        &&& fluentbit_is_stable_except_status(fb)(s, s_prime)
+    };
+    always_to_always_later(spec, lift_state(FBCluster::each_object_in_etcd_is_well_formed()));
+    always_to_always_later(spec, lift_state(desired_state_is(fb)));
+    always_to_always_later(spec, lift_state(sub_resource_state_matches(SubResource::DaemonSet, fb)));
+    combine_spec_entails_always_n!(
+        spec, lift_action(stronger_next),
+        lift_action(FBCluster::next()),
+        lift_state(FBCluster::crash_disabled()),
+        lift_state(FBCluster::busy_disabled()),
+        lift_state(FBCluster::every_in_flight_msg_has_unique_id()),
+        lift_state(FBCluster::each_object_in_etcd_is_well_formed()),
+        later(lift_state(FBCluster::each_object_in_etcd_is_well_formed())),
+        lift_state(desired_state_is(fb)),
+        later(lift_state(desired_state_is(fb))),
+        lift_state(helper_invariants::every_update_status_request_of_fb_is_the_pending_req(fb)),
+        later(lift_state(sub_resource_state_matches(SubResource::DaemonSet, fb))),
+        lift_action(desired_config_is_stable(fb)),
+        lift_action(daemon_set_status_is_stable::<FluentBitMaker>(fb)),
+        lift_action(fluentbit_is_stable_except_status(fb))
+    );
+
+    assert forall |s, s_prime: FBCluster| pre(s) && #[trigger] stronger_next(s, s_prime) && FBCluster::kubernetes_api_next().forward(input)(s, s_prime)
+    implies post(s_prime) by {
+        let key = fb.object_ref();
+        let local_state = s.ongoing_reconciles()[key].local_state;
+        let etcd_fb = FluentBitView::unmarshal(s.resources()[key]).get_Ok_0();
+        let new_fb = update_fb_status(etcd_fb, local_state.latest_daemon_set_opt.get_Some_0(), local_state.latest_config_hash_opt.get_Some_0());
+        FluentBitView::marshal_status_preserves_integrity();
+        FluentBitView::marshal_preserves_integrity();
+        // The request carries the metadata (and hence the resource version) of the fluentbit in etcd, so it passes the
+        // admission check and the fluentbit in etcd ends up with the new status.
+        assert(req_msg.content.get_update_status_request().obj.metadata == s.resources()[key].metadata);
+        assert(s_prime.resources()[key].status == new_fb.marshal().status);
+        assert(FluentBitView::unmarshal(s_prime.resources()[key]).get_Ok_0().status == new_fb.status);
+    }
+
+    assert forall |s, s_prime: FBCluster| pre(s) && #[trigger] stronger_next(s, s_prime) implies pre(s_prime) || post(s_prime) by {
+        let step = choose |step| FBCluster::next_step(s, s_prime, step);
+        match step {
+            Step::ApiServerStep(input) => {
+                let req = input.get_Some_0();
+                if req != req_msg {
+                    fluentbit_is_unchanged_unless_updated_by_controller(s, s_prime, fb, req);
+                }
+            },
+            _ => {}
+        }
+    }
+
+    FBCluster::lemma_pre_leads_to_post_by_kubernetes_api(spec, input, stronger_next, FBCluster::handle_request(), pre, post);
+}
+
+}
@@ -1,7 +1,6 @@
 // Copyright 2022 VMware, Inc.
 // SPDX-License-Identifier: MIT
 #![allow(unused_imports)]
-use crate::external_api::spec::EmptyAPI;
 use crate::fluent_controller::fluentbit::{
     model::{reconciler::*, resource::*},
     proof::predicate::*,
@@ -33,6 +32,11 @@ pub proof fn reconcile_eventually_terminates(spec: TempPred<FBCluster>, fb: Flue
         spec.entails(always(lift_state(FBCluster::pending_req_of_key_is_unique_with_unique_id(fb.object_ref())))),
         spec.entails(always(lift_state(FBCluster::no_pending_req_msg_at_reconcile_state(fb.object_ref(), at_step_closure(FluentBitReconcileStep::Init))))),
         spec.entails(always(lift_state(FBCluster::pending_req_in_flight_or_resp_in_flight_at_reconcile_state(fb.object_ref(), at_step_closure(FluentBitReconcileStep::AfterGetSecret))))),
+        spec.entails(always(lift_state(FBCluster::pending_req_in_flight_or_resp_in_flight_at_reconcile_state(fb.object_ref(), at_step_closure(FluentBitReconcileStep::AfterGetDaemonSetStatus))))),
+        spec.entails(always(lift_state(FBCluster::pending_req_in_flight_or_resp_in_flight_at_reconcile_state(fb.object_ref(), at_step_closure(FluentBitReconcileStep::AfterReloadConfig))))),
+        spec.entails(always(lift_state(FBCluster::pending_req_in_flight_or_resp_in_flight_at_reconcile_state(fb.object_ref(), at_step_closure(FluentBitReconcileStep::AfterGetFluentBit))))),
+        spec.entails(always(lift_state(FBCluster::pending_req_in_flight_or_resp_in_flight_at_reconcile_state(fb.object_ref(), at_step_closure(FluentBitReconcileStep::AfterUpdateStatus))))),
+        spec.entails(always(lift_state(FBCluster::pending_req_in_flight_or_resp_in_flight_at_reconcile_state(fb.object_ref(), at_step_closure(FluentBitReconcileStep::AfterUpdateDegradedStatus))))),
         spec.entails(always(tla_forall(|step: (ActionKind, SubResource)| lift_state(FBCluster::pending_req_in_flight_or_resp_in_flight_at_reconcile_state(
             fb.object_ref(), at_step_closure(FluentBitReconcileStep::AfterKRequestStep(step.0, step.1))
         ))))),
@@ -58,8 +62,57 @@ pub proof fn reconcile_eventually_terminates(spec: TempPred<FBCluster>, fb: Flue
     temp_pred_equality(lift_state(at_step_state_pred(fb, FluentBitReconcileStep::Error)), lift_state(FBCluster::reconciler_reconcile_error(fb.object_ref())));
     entails_implies_leads_to(spec, lift_state(reconcile_idle), lift_state(reconcile_idle));
 
+    // Then, prove that after_update_degraded_status ~> reconcile_idle, since it always ends in error.
+    FBCluster::lemma_from_some_state_to_arbitrary_next_state_to_reconcile_idle(
+        spec, fb, at_step_closure(FluentBitReconcileStep::AfterUpdateDegradedStatus), at_step_closure(FluentBitReconcileStep::Error)
+    );
+
     // Second, prove that the sub resource that every intermediate steps can lead to reconcile idle.
-    lemma_from_after_get_resource_step_to_after_get_next_resource_step_to_reconcile_idle(spec, fb, SubResource::DaemonSet, FluentBitReconcileStep::Done);
+    or_leads_to_combine_and_equality!(
+        spec,
+        lift_state(at_step1_or_step2_state_pred(fb, FluentBitReconcileStep::Done, FluentBitReconcileStep::Error)),
+        lift_state(at_step_state_pred(fb, FluentBitReconcileStep::Done)),
+        lift_state(at_step_state_pred(fb, FluentBitReconcileStep::Error));
+        lift_state(reconcile_idle)
+    );
+    FBCluster::lemma_from_some_state_to_arbitrary_next_state_to_reconcile_idle(
+        spec, fb, at_step_closure(FluentBitReconcileStep::AfterUpdateStatus),
+        at_step1_or_step2_closure(FluentBitReconcileStep::Done, FluentBitReconcileStep::Error)
+    );
+    or_leads_to_combine_and_equality!(
+        spec,
+        lift_state(at_step1_or_step2_state_pred(fb, FluentBitReconcileStep::AfterUpdateStatus, FluentBitReconcileStep::AfterUpdateDegradedStatus)),
+        lift_state(at_step_state_pred(fb, FluentBitReconcileStep::AfterUpdateStatus)),
+        lift_state(at_step_state_pred(fb, FluentBitReconcileStep::AfterUpdateDegradedStatus));
+        lift_state(reconcile_idle)
+    );
+    FBCluster::lemma_from_some_state_to_arbitrary_next_state_to_reconcile_idle(
+        spec, fb, at_step_closure(FluentBitReconcileStep::AfterGetFluentBit),
+        at_step1_or_step2_closure(FluentBitReconcileStep::AfterUpdateStatus, FluentBitReconcileStep::AfterUpdateDegradedStatus)
+    );
+    or_leads_to_combine_and_equality!(
+        spec,
+        lift_state(at_step1_or_step2_state_pred(fb, FluentBitReconcileStep::AfterGetFluentBit, FluentBitReconcileStep::AfterUpdateDegradedStatus)),
+        lift_state(at_step_state_pred(fb, FluentBitReconcileStep::AfterGetFluentBit)),
+        lift_state(at_step_state_pred(fb, FluentBitReconcileStep::AfterUpdateDegradedStatus));
+        lift_state(reconcile_idle)
+    );
+    FBCluster::lemma_from_some_state_to_arbitrary_next_state_to_reconcile_idle(
+        spec, fb, at_step_closure(FluentBitReconcileStep::AfterReloadConfig),
+        at_step1_or_step2_closure(FluentBitReconcileStep::AfterGetFluentBit, FluentBitReconcileStep::AfterUpdateDegradedStatus)
+    );
+    or_leads_to_combine_and_equality!(
+        spec,
+        lift_state(at_step1_or_step2_state_pred(fb, FluentBitReconcileStep::AfterReloadConfig, FluentBitReconcileStep::AfterUpdateDegradedStatus)),
+        lift_state(at_step_state_pred(fb, FluentBitReconcileStep::AfterReloadConfig)),
+        lift_state(at_step_state_pred(fb, FluentBitReconcileStep::AfterUpdateDegradedStatus));
+        lift_state(reconcile_idle)
+    );
+    FBCluster::lemma_from_some_state_to_arbitrary_next_state_to_reconcile_idle(
+        spec, fb, at_step_closure(FluentBitReconcileStep::AfterGetDaemonSetStatus),
+        at_step1_or_step2_closure(FluentBitReconcileStep::AfterReloadConfig, FluentBitReconcileStep::AfterUpdateDegradedStatus)
+    );
+    lemma_from_after_get_resource_step_to_after_get_next_resource_step_to_reconcile_idle(spec, fb, SubResource::DaemonSet, FluentBitReconcileStep::AfterGetDaemonSetStatus);
     lemma_from_after_get_resource_step_to_after_get_next_resource_step_to_reconcile_idle(spec, fb, SubResource::Service, after_get_k_request_step(SubResource::DaemonSet));
     lemma_from_after_get_resource_step_to_after_get_next_resource_step_to_reconcile_idle(spec, fb, SubResource::RoleBinding, after_get_k_request_step(SubResource::Service));
     lemma_from_after_get_resource_step_to_after_get_next_resource_step_to_reconcile_idle(spec, fb, SubResource::Role, after_get_k_request_step(SubResource::RoleBinding));
@@ -68,14 +121,14 @@ pub proof fn reconcile_eventually_terminates(spec: TempPred<FBCluster>, fb: Flue
     // Third, prove that after get secret step can reach the state of handling the first sub resource (service account).
     or_leads_to_combine_and_equality!(
         spec,
-        lift_state(at_step1_or_step2_state_pred(fb, after_get_k_request_step(SubResource::ServiceAccount), FluentBitReconcileStep::Error)),
+        lift_state(at_step1_or_step2_state_pred(fb, after_get_k_request_step(SubResource::ServiceAccount), FluentBitReconcileStep::AfterUpdateDegradedStatus)),
         lift_state(at_step_state_pred(fb, after_get_k_request_step(SubResource::ServiceAccount))),
-        lift_state(at_step_state_pred(fb, FluentBitReconcileStep::Error));
+        lift_state(at_step_state_pred(fb, FluentBitReconcileStep::AfterUpdateDegradedStatus));
         lift_state(reconcile_idle)
     );
     FBCluster::lemma_from_some_state_to_arbitrary_next_state_to_reconcile_idle(
         spec, fb, at_step_closure(FluentBitReconcileStep::AfterGetSecret),
-        at_step1_or_step2_closure(after_get_k_request_step(SubResource::ServiceAccount), FluentBitReconcileStep::Error)
+        at_step1_or_step2_closure(after_get_k_request_step(SubResource::ServiceAccount), FluentBitReconcileStep::AfterUpdateDegradedStatus)
     );
 
     // Fourth, prove that reconcile init state can reach the state after get secret.
@@ -93,6 +146,11 @@ pub proof fn reconcile_eventually_terminates(spec: TempPred<FBCluster>, fb: Flue
         lift_state(state_pred_regarding_sub_resource(fb, SubResource::RoleBinding)),
         lift_state(state_pred_regarding_sub_resource(fb, SubResource::Service)),
         lift_state(state_pred_regarding_sub_resource(fb, SubResource::DaemonSet)),
+        lift_state(at_step_state_pred(fb, FluentBitReconcileStep::AfterGetDaemonSetStatus)),
+        lift_state(at_step_state_pred(fb, FluentBitReconcileStep::AfterReloadConfig)),
+        lift_state(at_step_state_pred(fb, FluentBitReconcileStep::AfterGetFluentBit)),
+        lift_state(at_step_state_pred(fb, FluentBitReconcileStep::AfterUpdateStatus)),
+        lift_state(at_step_state_pred(fb, FluentBitReconcileStep::AfterUpdateDegradedStatus)),
         lift_state(at_step_state_pred(fb, FluentBitReconcileStep::Done)),
         lift_state(at_step_state_pred(fb, FluentBitReconcileStep::Error));
         lift_state(reconcile_idle)
@@ -137,7 +195,7 @@ proof fn lemma_from_after_get_resource_step_to_after_get_next_resource_step_to_r
         next_resource_after(sub_resource) == next_step,
         spec.entails(lift_state(at_step_state_pred(fb, next_step))
             .leads_to(lift_state(|s: FBCluster| !s.ongoing_reconciles().contains_key(fb.object_ref())))),
-        spec.entails(lift_state(at_step_state_pred(fb, FluentBitReconcileStep::Error))
+        spec.entails(lift_state(at_step_state_pred(fb, FluentBitReconcileStep::AfterUpdateDegradedStatus))
             .leads_to(lift_state(|s: FBCluster| !s.ongoing_reconciles().contains_key(fb.object_ref())))),
     ensures
         spec.entails(lift_state(at_step_state_pred(fb, after_get_k_request_step(sub_resource))).leads_to(lift_state(|s: FBCluster| !s.ongoing_reconciles().contains_key(fb.object_ref())))),
@@ -146,12 +204,12 @@ proof fn lemma_from_after_get_resource_step_to_after_get_next_resource_step_to_r
     hide(make_daemon_set);
     let state_after_create_or_update = |s: FluentBitReconcileState| {
         s.reconcile_step == next_step
-        || s.reconcile_step == FluentBitReconcileStep::Error
+        || s.reconcile_step == FluentBitReconcileStep::AfterUpdateDegradedStatus
     };
     or_leads_to_combine_and_equality!(
         spec, lift_state(FBCluster::at_expected_reconcile_states(fb.object_ref(), state_after_create_or_update)),
         lift_state(at_step_state_pred(fb, next_step)),
-        lift_state(at_step_state_pred(fb, FluentBitReconcileStep::Error));
+        lift_state(at_step_state_pred(fb, FluentBitReconcileStep::AfterUpdateDegradedStatus));
         lift_state(|s: FBCluster| { !s.ongoing_reconciles().contains_key(fb.object_ref()) })
     );
     FBCluster::lemma_from_some_state_to_arbitrary_next_state_to_reconcile_idle(spec, fb, at_step_closure(after_create_k_request_step(sub_resource)), state_after_create_or_update);
@@ -160,13 +218,13 @@ proof fn lemma_from_after_get_resource_step_to_after_get_next_resource_step_to_r
     let state_after_get = |s: FluentBitReconcileState| {
         s.reconcile_step == after_create_k_request_step(sub_resource)
         || s.reconcile_step == after_update_k_request_step(sub_resource)
-        || s.reconcile_step == FluentBitReconcileStep::Error
+        || s.reconcile_step == FluentBitReconcileStep::AfterUpdateDegradedStatus
     };
     or_leads_to_combine_and_equality!(
         spec, lift_state(FBCluster::at_expected_reconcile_states(fb.object_ref(), state_after_get)),
         lift_state(at_step_state_pred(fb, after_create_k_request_step(sub_resource))),
         lift_state(at_step_state_pred(fb, after_update_k_request_step(sub_resource))),
-        lift_state(at_step_state_pred(fb, FluentBitReconcileStep::Error));
+        lift_state(at_step_state_pred(fb, FluentBitReconcileStep::AfterUpdateDegradedStatus));
         lift_state(|s: FBCluster| { !s.ongoing_reconciles().contains_key(fb.object_ref()) })
     );
     FBCluster::lemma_from_some_state_to_arbitrary_next_state_to_reconcile_idle(spec, fb, at_step_closure(after_get_k_request_step(sub_resource)), state_after_get);
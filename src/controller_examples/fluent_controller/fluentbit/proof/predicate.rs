@@ -1,10 +1,11 @@
 // Copyright 2022 VMware, Inc.
 // SPDX-License-Identifier: MIT
 #![allow(unused_imports)]
-use crate::external_api::spec::{EmptyAPI, EmptyTypeView};
 use crate::fluent_controller::fluentbit::model::{reconciler::*, resource::*};
 use crate::fluent_controller::fluentbit::proof::resource::*;
-use crate::fluent_controller::fluentbit::trusted::{liveness_theorem::*, spec_types::*, step::*};
+use crate::fluent_controller::fluentbit::trusted::{
+    fluentbit_api_spec::*, liveness_theorem::*, spec_types::*, step::*,
+};
 use crate::kubernetes_api_objects::spec::{
     api_method::*, common::*, prelude::*, resource::*, stateful_set::*,
 };
@@ -16,7 +17,7 @@ use crate::kubernetes_cluster::spec::{
     message::*,
 };
 use crate::temporal_logic::defs::*;
-use vstd::prelude::*;
+use vstd::{prelude::*, string::*};
 
 verus! {
 
@@ -150,7 +151,7 @@ pub open spec fn next_resource_after(sub_resource: SubResource) -> FluentBitReco
         SubResource::Role => after_get_k_request_step(SubResource::RoleBinding),
         SubResource::RoleBinding => after_get_k_request_step(SubResource::Service),
         SubResource::Service => after_get_k_request_step(SubResource::DaemonSet),
-        _ => FluentBitReconcileStep::Done,
+        SubResource::DaemonSet => FluentBitReconcileStep::AfterGetDaemonSetStatus,
     }
 }
 
@@ -430,4 +431,212 @@ pub open spec fn resp_msg_is_the_in_flight_ok_resp_at_after_update_resource_step
     }
 }
 
+pub open spec fn latest_config_hash_is_the_same_as_etcd_server_secret(fb: FluentBitView) -> StatePred<FBCluster> {
+    |s: FBCluster| {
+        let secret_key = desired_secret_key(fb);
+        let local_state = s.ongoing_reconciles()[fb.object_ref()].local_state;
+        &&& s.resources().contains_key(secret_key)
+        &&& SecretView::unmarshal(s.resources()[secret_key]).is_Ok()
+        &&& local_state.latest_config_hash_opt == Some(desired_config_hash(fb, s.resources()))
+    }
+}
+
+pub open spec fn latest_daemon_set_status_is_the_same_as_etcd_server_daemon_set(fb: FluentBitView) -> StatePred<FBCluster> {
+    |s: FBCluster| {
+        let ds_key = get_request(SubResource::DaemonSet, fb).key;
+        let local_state = s.ongoing_reconciles()[fb.object_ref()].local_state;
+        &&& s.resources().contains_key(ds_key)
+        &&& DaemonSetView::unmarshal(s.resources()[ds_key]).is_Ok()
+        &&& local_state.latest_daemon_set_opt.is_Some()
+        &&& local_state.latest_daemon_set_opt.get_Some_0().status == DaemonSetView::unmarshal(s.resources()[ds_key]).get_Ok_0().status
+    }
+}
+
+pub open spec fn pending_req_in_flight_at_after_get_daemon_set_status_step(fb: FluentBitView) -> StatePred<FBCluster> {
+    |s: FBCluster| {
+        let msg = s.ongoing_reconciles()[fb.object_ref()].pending_req_msg.get_Some_0();
+        &&& req_msg_is_the_in_flight_pending_req_at_after_get_daemon_set_status_step(fb, msg)(s)
+    }
+}
+
+pub open spec fn req_msg_is_the_in_flight_pending_req_at_after_get_daemon_set_status_step(fb: FluentBitView, req_msg: FBMessage) -> StatePred<FBCluster> {
+    |s: FBCluster| {
+        let request = req_msg.content.get_APIRequest_0();
+        &&& at_fb_step_with_fb(fb, FluentBitReconcileStep::AfterGetDaemonSetStatus)(s)
+        &&& FBCluster::pending_req_msg_is(s, fb.object_ref(), req_msg)
+        &&& s.in_flight().contains(req_msg)
+        &&& req_msg.src == HostId::CustomController
+        &&& req_msg.dst == HostId::ApiServer
+        &&& req_msg.content.is_APIRequest()
+        &&& request.is_GetRequest()
+        &&& request.get_GetRequest_0() == get_request(SubResource::DaemonSet, fb)
+        &&& latest_config_hash_is_the_same_as_etcd_server_secret(fb)(s)
+    }
+}
+
+pub open spec fn at_after_get_daemon_set_status_step_and_exists_ok_resp_in_flight(fb: FluentBitView) -> StatePred<FBCluster> {
+    |s: FBCluster| {
+        exists |resp_msg| #[trigger] resp_msg_is_the_in_flight_ok_resp_at_after_get_daemon_set_status_step(fb, resp_msg)(s)
+    }
+}
+
+// Only the status of the daemon set matters from here on, and the status stays the same even if the daemon set gets updated.
+pub open spec fn resp_msg_is_the_in_flight_ok_resp_at_after_get_daemon_set_status_step(fb: FluentBitView, resp_msg: FBMessage) -> StatePred<FBCluster> {
+    |s: FBCluster| {
+        let msg = s.ongoing_reconciles()[fb.object_ref()].pending_req_msg.get_Some_0();
+        let request = msg.content.get_APIRequest_0();
+        let key = get_request(SubResource::DaemonSet, fb).key;
+        &&& at_fb_step_with_fb(fb, FluentBitReconcileStep::AfterGetDaemonSetStatus)(s)
+        &&& FBCluster::has_pending_k8s_api_req_msg(s, fb.object_ref())
+        &&& msg.src == HostId::CustomController
+        &&& msg.dst == HostId::ApiServer
+        &&& msg.content.is_APIRequest()
+        &&& request.is_GetRequest()
+        &&& request.get_GetRequest_0() == get_request(SubResource::DaemonSet, fb)
+        &&& s.resources().contains_key(key)
+        &&& DaemonSetView::unmarshal(s.resources()[key]).is_Ok()
+        &&& s.in_flight().contains(resp_msg)
+        &&& Message::resp_msg_matches_req_msg(resp_msg, msg)
+        &&& resp_msg.content.get_get_response().res.is_Ok()
+        &&& DaemonSetView::unmarshal(resp_msg.content.get_get_response().res.get_Ok_0()).is_Ok()
+        &&& DaemonSetView::unmarshal(resp_msg.content.get_get_response().res.get_Ok_0()).get_Ok_0().status
+            == DaemonSetView::unmarshal(s.resources()[key]).get_Ok_0().status
+        &&& latest_config_hash_is_the_same_as_etcd_server_secret(fb)(s)
+    }
+}
+
+pub open spec fn reload_config_request(fb: FluentBitView, config_hash: StringView) -> FluentBitAPIInputView {
+    FluentBitAPIInputView::ReloadConfigRequest(make_daemon_set_name(fb), fb.metadata.namespace.get_Some_0(), config_hash)
+}
+
+pub open spec fn reload_config_request_msg(fb: FluentBitView) -> spec_fn(FBMessage) -> bool {
+    |msg: FBMessage|
+        msg.dst.is_ExternalAPI()
+        && msg.content.is_ExternalAPIRequest()
+        && msg.content.get_ExternalAPIRequest_0().is_ReloadConfigRequest()
+        && msg.content.get_ExternalAPIRequest_0().get_ReloadConfigRequest_0() == make_daemon_set_name(fb)
+        && msg.content.get_ExternalAPIRequest_0().get_ReloadConfigRequest_1() == fb.metadata.namespace.get_Some_0()
+}
+
+pub open spec fn pending_req_in_flight_at_after_reload_config_step(fb: FluentBitView) -> StatePred<FBCluster> {
+    |s: FBCluster| {
+        let msg = s.ongoing_reconciles()[fb.object_ref()].pending_req_msg.get_Some_0();
+        &&& req_msg_is_the_in_flight_pending_req_at_after_reload_config_step(fb, msg)(s)
+    }
+}
+
+// The reload request carries the hash of the config currently in the secret.
+pub open spec fn req_msg_is_the_in_flight_pending_req_at_after_reload_config_step(fb: FluentBitView, req_msg: FBMessage) -> StatePred<FBCluster> {
+    |s: FBCluster| {
+        &&& at_fb_step_with_fb(fb, FluentBitReconcileStep::AfterReloadConfig)(s)
+        &&& FBCluster::pending_req_msg_is(s, fb.object_ref(), req_msg)
+        &&& s.in_flight().contains(req_msg)
+        &&& req_msg.src == HostId::CustomController
+        &&& req_msg.dst == HostId::ExternalAPI
+        &&& req_msg.content.is_ExternalAPIRequest()
+        &&& req_msg.content.get_ExternalAPIRequest_0() == reload_config_request(fb, desired_config_hash(fb, s.resources()))
+        &&& latest_config_hash_is_the_same_as_etcd_server_secret(fb)(s)
+        &&& latest_daemon_set_status_is_the_same_as_etcd_server_daemon_set(fb)(s)
+    }
+}
+
+pub open spec fn at_after_reload_config_step_and_exists_ok_resp_in_flight(fb: FluentBitView) -> StatePred<FBCluster> {
+    |s: FBCluster| {
+        exists |resp_msg| #[trigger] resp_msg_is_the_in_flight_ok_resp_at_after_reload_config_step(fb, resp_msg)(s)
+    }
+}
+
+pub open spec fn resp_msg_is_the_in_flight_ok_resp_at_after_reload_config_step(fb: FluentBitView, resp_msg: FBMessage) -> StatePred<FBCluster> {
+    |s: FBCluster| {
+        let msg = s.ongoing_reconciles()[fb.object_ref()].pending_req_msg.get_Some_0();
+        &&& at_fb_step_with_fb(fb, FluentBitReconcileStep::AfterReloadConfig)(s)
+        &&& s.ongoing_reconciles()[fb.object_ref()].pending_req_msg.is_Some()
+        &&& msg.src == HostId::CustomController
+        &&& msg.dst == HostId::ExternalAPI
+        &&& msg.content.is_ExternalAPIRequest()
+        &&& s.in_flight().contains(resp_msg)
+        &&& Message::resp_msg_matches_req_msg(resp_msg, msg)
+        &&& resp_msg.content.get_ExternalAPIResponse_0() == FluentBitAPIOutputView::ReloadConfigResponse(FluentBitAPIReloadConfigResultView{res: Ok(())})
+        &&& latest_config_hash_is_the_same_as_etcd_server_secret(fb)(s)
+        &&& latest_daemon_set_status_is_the_same_as_etcd_server_daemon_set(fb)(s)
+    }
+}
+
+pub open spec fn pending_req_in_flight_at_after_get_fluentbit_step(fb: FluentBitView) -> StatePred<FBCluster> {
+    |s: FBCluster| {
+        let msg = s.ongoing_reconciles()[fb.object_ref()].pending_req_msg.get_Some_0();
+        &&& req_msg_is_the_in_flight_pending_req_at_after_get_fluentbit_step(fb, msg)(s)
+    }
+}
+
+pub open spec fn req_msg_is_the_in_flight_pending_req_at_after_get_fluentbit_step(fb: FluentBitView, req_msg: FBMessage) -> StatePred<FBCluster> {
+    |s: FBCluster| {
+        let request = req_msg.content.get_APIRequest_0();
+        &&& at_fb_step_with_fb(fb, FluentBitReconcileStep::AfterGetFluentBit)(s)
+        &&& FBCluster::pending_req_msg_is(s, fb.object_ref(), req_msg)
+        &&& s.in_flight().contains(req_msg)
+        &&& req_msg.src == HostId::CustomController
+        &&& req_msg.dst == HostId::ApiServer
+        &&& req_msg.content.is_APIRequest()
+        &&& request.is_GetRequest()
+        &&& request.get_GetRequest_0() == GetRequest { key: fb.object_ref() }
+        &&& latest_config_hash_is_the_same_as_etcd_server_secret(fb)(s)
+        &&& latest_daemon_set_status_is_the_same_as_etcd_server_daemon_set(fb)(s)
+    }
+}
+
+pub open spec fn at_after_get_fluentbit_step_and_exists_ok_resp_in_flight(fb: FluentBitView) -> StatePred<FBCluster> {
+    |s: FBCluster| {
+        exists |resp_msg| #[trigger] resp_msg_is_the_in_flight_ok_resp_at_after_get_fluentbit_step(fb, resp_msg)(s)
+    }
+}
+
+pub open spec fn resp_msg_is_the_in_flight_ok_resp_at_after_get_fluentbit_step(fb: FluentBitView, resp_msg: FBMessage) -> StatePred<FBCluster> {
+    |s: FBCluster| {
+        let msg = s.ongoing_reconciles()[fb.object_ref()].pending_req_msg.get_Some_0();
+        let request = msg.content.get_APIRequest_0();
+        &&& at_fb_step_with_fb(fb, FluentBitReconcileStep::AfterGetFluentBit)(s)
+        &&& FBCluster::has_pending_k8s_api_req_msg(s, fb.object_ref())
+        &&& msg.src == HostId::CustomController
+        &&& msg.dst == HostId::ApiServer
+        &&& msg.content.is_APIRequest()
+        &&& request.is_GetRequest()
+        &&& request.get_GetRequest_0() == GetRequest { key: fb.object_ref() }
+        &&& s.resources().contains_key(fb.object_ref())
+        &&& s.in_flight().contains(resp_msg)
+        &&& Message::resp_msg_matches_req_msg(resp_msg, msg)
+        &&& resp_msg.content.get_get_response().res.is_Ok()
+        &&& resp_msg.content.get_get_response().res.get_Ok_0() == s.resources()[fb.object_ref()]
+        &&& latest_config_hash_is_the_same_as_etcd_server_secret(fb)(s)
+        &&& latest_daemon_set_status_is_the_same_as_etcd_server_daemon_set(fb)(s)
+    }
+}
+
+pub open spec fn pending_req_in_flight_at_after_update_status_step(fb: FluentBitView) -> StatePred<FBCluster> {
+    |s: FBCluster| {
+        let msg = s.ongoing_reconciles()[fb.object_ref()].pending_req_msg.get_Some_0();
+        &&& req_msg_is_the_in_flight_pending_req_at_after_update_status_step(fb, msg)(s)
+    }
+}
+
+// The status update request is built on top of the fluentbit object currently in etcd, so it carries its resource version.
+pub open spec fn req_msg_is_the_in_flight_pending_req_at_after_update_status_step(fb: FluentBitView, req_msg: FBMessage) -> StatePred<FBCluster> {
+    |s: FBCluster| {
+        let local_state = s.ongoing_reconciles()[fb.object_ref()].local_state;
+        let etcd_fb = FluentBitView::unmarshal(s.resources()[fb.object_ref()]).get_Ok_0();
+        &&& at_fb_step_with_fb(fb, FluentBitReconcileStep::AfterUpdateStatus)(s)
+        &&& FBCluster::pending_req_msg_is(s, fb.object_ref(), req_msg)
+        &&& s.in_flight().contains(req_msg)
+        &&& req_msg.src == HostId::CustomController
+        &&& resource_update_status_request_msg(fb.object_ref())(req_msg)
+        &&& s.resources().contains_key(fb.object_ref())
+        &&& FluentBitView::unmarshal(s.resources()[fb.object_ref()]).is_Ok()
+        &&& req_msg.content.get_update_status_request().obj == update_fb_status(
+            etcd_fb, local_state.latest_daemon_set_opt.get_Some_0(), local_state.latest_config_hash_opt.get_Some_0()
+        ).marshal()
+        &&& latest_config_hash_is_the_same_as_etcd_server_secret(fb)(s)
+        &&& latest_daemon_set_status_is_the_same_as_etcd_server_daemon_set(fb)(s)
+    }
+}
+
 }
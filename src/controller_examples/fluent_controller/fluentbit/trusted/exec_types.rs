@@ -5,8 +5,8 @@ use crate::fluent_controller::fluentbit::trusted::{
 };
 use crate::kubernetes_api_objects::error::UnmarshalError;
 use crate::kubernetes_api_objects::exec::{
-    affinity::*, api_resource::*, container::*, dynamic::*, object_meta::*, owner_reference::*,
-    prelude::*, resource::*, resource_requirements::*, toleration::*, volume::*,
+    affinity::*, api_resource::*, condition::*, container::*, dynamic::*, object_meta::*,
+    owner_reference::*, prelude::*, resource::*, resource_requirements::*, toleration::*, volume::*,
 };
 use crate::kubernetes_api_objects::spec::resource::*;
 use crate::vstd_ext::{string_map::*, string_view::*};
@@ -17,6 +17,8 @@ verus! {
 
 pub struct FluentBitReconcileState {
     pub reconcile_step: FluentBitReconcileStep,
+    pub latest_config_hash_opt: Option<String>,
+    pub latest_daemon_set_opt: Option<DaemonSet>,
 }
 
 impl std::clone::Clone for FluentBitReconcileState {
@@ -26,13 +28,60 @@ impl std::clone::Clone for FluentBitReconcileState {
     {
         FluentBitReconcileState {
             reconcile_step: self.reconcile_step,
+            latest_config_hash_opt:
+                match &self.latest_config_hash_opt {
+                    Some(n) => Some(n.clone()),
+                    None => None,
+                },
+            latest_daemon_set_opt:
+                match &self.latest_daemon_set_opt {
+                    Some(ds) => Some(ds.clone()),
+                    None => None,
+                },
         }
     }
 }
 
 impl View for FluentBitReconcileState {
     type V = spec_types::FluentBitReconcileState;
-    open spec fn view(&self) -> spec_types::FluentBitReconcileState { spec_types::FluentBitReconcileState { reconcile_step: self.reconcile_step } }
+    open spec fn view(&self) -> spec_types::FluentBitReconcileState {
+        spec_types::FluentBitReconcileState {
+            reconcile_step: self.reconcile_step,
+            latest_config_hash_opt: match &self.latest_config_hash_opt {
+                Some(s) => Some(s@),
+                None => None,
+            },
+            latest_daemon_set_opt: match &self.latest_daemon_set_opt {
+                Some(ds) => Some(ds@),
+                None => None,
+            },
+        }
+    }
+}
+
+// config_hash hashes the data of the config secret with 64-bit FNV-1a, which (unlike the std hasher)
+// gives the same hash across controller restarts and builds. The entries are visited in key order.
+#[verifier(external_body)]
+pub fn config_hash(secret: &Secret) -> (hash: String)
+    ensures hash@ == spec_types::config_hash(secret@.data),
+{
+    let mut hash: u64 = 0xcbf29ce484222325;
+    let mut write = |bytes: &[u8]| {
+        for b in bytes {
+            hash ^= *b as u64;
+            hash = hash.wrapping_mul(0x100000001b3);
+        }
+    };
+    if let Some(data) = secret.clone().into_kube().data {
+        for (key, value) in data.iter() {
+            // The separator keeps ("ab", "c") and ("a", "bc") apart
+            write(key.as_bytes());
+            write(&[0]);
+            write(&value.0);
+            write(&[0]);
+        }
+    }
+    format!("{:016x}", hash)
 }
 
 #[verifier(external_body)]
@@ -47,6 +96,13 @@ impl View for FluentBit {
 }
 
 impl FluentBit {
+    #[verifier(external_body)]
+    pub fn clone(&self) -> (fb: Self)
+        ensures fb@ == self@,
+    {
+        FluentBit { inner: self.inner.clone() }
+    }
+
     #[verifier(external_body)]
     pub fn metadata(&self) -> (metadata: ObjectMeta)
         ensures metadata@ == self@.metadata,
@@ -61,6 +117,31 @@ impl FluentBit {
         FluentBitSpec { inner: self.inner.spec.clone() }
     }
 
+    #[verifier(external_body)]
+    pub fn status(&self) -> (status: Option<FluentBitStatus>)
+        ensures
+            self@.status.is_Some() == status.is_Some(),
+            status.is_Some() ==> status.get_Some_0()@ == self@.status.get_Some_0(),
+    {
+        if self.inner.status.is_none() {
+            None
+        } else {
+            Some(FluentBitStatus { inner: self.inner.status.as_ref().unwrap().clone() })
+        }
+    }
+
+    #[verifier(external_body)]
+    pub fn set_status(&mut self, status: FluentBitStatus)
+        ensures self@ == old(self)@.set_status(status@),
+    {
+        let mut new_status = status.into_kube();
+        match (&mut new_status.conditions, self.inner.status.as_ref().and_then(|s| s.conditions.as_ref())) {
+            (Some(conditions), Some(old_conditions)) => preserve_transition_times(conditions, old_conditions),
+            _ => {},
+        }
+        self.inner.status = Some(new_status);
+    }
+
     #[verifier(external_body)]
     pub fn api_resource() -> (res: ApiResource)
         ensures res@.kind == FluentBitView::kind(),
@@ -445,4 +526,58 @@ impl FluentBitSpec {
     }
 }
 
+#[verifier(external_body)]
+pub struct FluentBitStatus {
+    inner: deps_hack::FluentBitStatus,
+}
+
+impl FluentBitStatus {
+    pub spec fn view(&self) -> spec_types::FluentBitStatusView;
+
+    #[verifier(external_body)]
+    pub fn default() -> (status: FluentBitStatus)
+        ensures status@ == spec_types::FluentBitStatusView::default(),
+    {
+        FluentBitStatus { inner: deps_hack::FluentBitStatus::default() }
+    }
+
+    #[verifier(external_body)]
+    pub fn set_desired_number_scheduled(&mut self, desired_number_scheduled: i32)
+        ensures self@ == old(self)@.set_desired_number_scheduled(desired_number_scheduled as int),
+    {
+        self.inner.desired_number_scheduled = desired_number_scheduled;
+    }
+
+    #[verifier(external_body)]
+    pub fn set_number_ready(&mut self, number_ready: i32)
+        ensures self@ == old(self)@.set_number_ready(number_ready as int),
+    {
+        self.inner.number_ready = number_ready;
+    }
+
+    #[verifier(external_body)]
+    pub fn set_updated_number_scheduled(&mut self, updated_number_scheduled: i32)
+        ensures self@ == old(self)@.set_updated_number_scheduled(updated_number_scheduled as int),
+    {
+        self.inner.updated_number_scheduled = updated_number_scheduled;
+    }
+
+    #[verifier(external_body)]
+    pub fn set_config_hash(&mut self, config_hash: String)
+        ensures self@ == old(self)@.set_config_hash(config_hash@),
+    {
+        self.inner.config_hash = Some(config_hash);
+    }
+
+    #[verifier(external_body)]
+    pub fn set_conditions(&mut self, conditions: Vec<Condition>)
+        ensures self@ == old(self)@.set_conditions(conditions@.map_values(|c: Condition| c@)),
+    {
+        self.inner.conditions = Some(into_kube_conditions(conditions))
+    }
+
+    #[verifier(external)]
+    fn into_kube(self) -> deps_hack::FluentBitStatus { self.inner }
+}
+
 }
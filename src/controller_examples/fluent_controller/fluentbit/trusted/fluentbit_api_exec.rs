@@ -0,0 +1,320 @@
+// Copyright 2022 VMware, Inc.
+// SPDX-License-Identifier: MIT
+use crate::external_api::exec::*;
+use crate::external_api::http::{http_request_without_auth, is_success};
+use crate::fluent_controller::fluentbit::trusted::{
+    fluentbit_api_spec::{
+        FluentBitAPIInputView, FluentBitAPIOutputView, FluentBitAPIReloadConfigResultView,
+    },
+    step::*,
+};
+use deps_hack::futures::future::BoxFuture;
+use deps_hack::k8s_openapi::api::apps::v1::DaemonSet;
+use deps_hack::k8s_openapi::api::core::v1::Pod;
+use deps_hack::kube::{api::ListParams, Api, Client};
+use deps_hack::tracing::info;
+use vstd::{prelude::*, string::*, view::*};
+
+verus! {
+
+pub struct FluentBitAPIReloadConfigResult {
+    pub res: Result<(), FluentBitAPIError>,
+}
+
+impl View for FluentBitAPIReloadConfigResult {
+    type V = FluentBitAPIReloadConfigResultView;
+    open spec fn view(&self) -> FluentBitAPIReloadConfigResultView { FluentBitAPIReloadConfigResultView {res: self.res} }
+}
+
+#[is_variant]
+pub enum FluentBitAPIInput {
+    ReloadConfigRequest(String, String, String),
+}
+
+#[is_variant]
+pub enum FluentBitAPIOutput {
+    ReloadConfigResponse(FluentBitAPIReloadConfigResult),
+}
+
+impl View for FluentBitAPIInput {
+    type V = FluentBitAPIInputView;
+    open spec fn view(&self) -> FluentBitAPIInputView {
+        match self {
+            FluentBitAPIInput::ReloadConfigRequest(name, namespace, config_hash)
+                => FluentBitAPIInputView::ReloadConfigRequest(name@, namespace@, config_hash@),
+        }
+    }
+}
+
+impl View for FluentBitAPIOutput {
+    type V = FluentBitAPIOutputView;
+    open spec fn view(&self) -> FluentBitAPIOutputView {
+        match self {
+            FluentBitAPIOutput::ReloadConfigResponse(result) => FluentBitAPIOutputView::ReloadConfigResponse(result@),
+        }
+    }
+}
+
+impl FluentBitAPIOutput {
+    pub fn is_reload_config_response(&self) -> (res: bool)
+        ensures res == self.is_ReloadConfigResponse(),
+    {
+        match self {
+            FluentBitAPIOutput::ReloadConfigResponse(_) => true,
+        }
+    }
+
+    pub fn unwrap_reload_config_response(self) -> (result: FluentBitAPIReloadConfigResult)
+        requires self.is_ReloadConfigResponse(),
+        ensures result == self.get_ReloadConfigResponse_0(),
+    {
+        match self {
+            FluentBitAPIOutput::ReloadConfigResponse(result) => result,
+        }
+    }
+}
+
+pub struct FluentBitAPIShimLayer {}
+
+}
+
+impl ExternalAPIShimLayer for FluentBitAPIShimLayer {
+    type Input = FluentBitAPIInput;
+    type Output = FluentBitAPIOutput;
+    fn call_external_api(input: FluentBitAPIInput) -> FluentBitAPIOutput {
+        match input {
+            FluentBitAPIInput::ReloadConfigRequest(ds_name, ds_namespace, config_hash) => {
+                FluentBitAPIOutput::ReloadConfigResponse(fluentbit_reload_config(
+                    ds_name,
+                    ds_namespace,
+                    config_hash,
+                ))
+            }
+        }
+    }
+}
+
+// The reload calls use blocking sockets, so they run on the blocking thread pool.
+impl AsyncExternalAPIShimLayer for FluentBitAPIShimLayer {
+    fn call_external_api_async(input: FluentBitAPIInput) -> BoxFuture<'static, FluentBitAPIOutput> {
+        call_external_api_blocking::<Self>(input)
+    }
+
+    fn error_of(output: &FluentBitAPIOutput) -> Option<String> {
+        match output {
+            FluentBitAPIOutput::ReloadConfigResponse(result) => result.res.as_ref().err(),
+        }
+        .map(|err| format!("{:?}", err))
+    }
+}
+
+// The port of the fluent-bit HTTP server when the fluentbit object does not set metricsPort
+// (see make_fluentbit_pod_spec).
+const DEFAULT_HTTP_PORT: i32 = 2020;
+
+// The name of the container port that exposes the fluent-bit HTTP server.
+const HTTP_PORT_NAME: &str = "metrics";
+
+pub fn fluentbit_reload_config(
+    ds_name: String,
+    ds_namespace: String,
+    config_hash: String,
+) -> FluentBitAPIReloadConfigResult {
+    let result = FluentBitAPIReloadConfigResult {
+        res: fluentbit_reload_config_internal(ds_name, ds_namespace, &config_hash),
+    };
+    match result.res {
+        Err(_) => info!("Reload fluentbit config {} failed", config_hash),
+        Ok(_) => info!("Reload fluentbit config {} successfully", config_hash),
+    }
+    result
+}
+
+// fluentbit_reload_config_internal asks every running fluent-bit pod of the daemon set to reload its config.
+// The kubelet refreshes the mounted secret some time after the secret changes, so a pod can reload the old config.
+// The reconciler sends the reload again in every round, which makes the pods pick up the new config eventually.
+// The reload endpoint only exists when the user config enables HTTP_Server and Hot_Reload in the service section.
+pub fn fluentbit_reload_config_internal(
+    ds_name: String,
+    ds_namespace: String,
+    config_hash: &String,
+) -> Result<(), FluentBitAPIError> {
+    let (ds, pods) = block_on(get_daemon_set_and_pods(&ds_name, &ds_namespace)).map_err(|e| {
+        info!("Reading the daemon set and its pods failed: {}", e);
+        FluentBitAPIError::ReloadConfigFailed
+    })?;
+    let port = http_port(&ds);
+    for addr in pods.iter().filter_map(|pod| running_pod_ip(pod)) {
+        let addr = format!("{}:{}", addr, port);
+        info!("Reloading config {} on {} ...", config_hash, &addr);
+        reload_config_on_pod(&addr)?;
+    }
+    Ok(())
+}
+
+// get_daemon_set_and_pods reads the daemon set (see make_daemon_set) and the pods its selector matches.
+async fn get_daemon_set_and_pods(
+    name: &String,
+    namespace: &String,
+) -> Result<(DaemonSet, Vec<Pod>), String> {
+    let client = Client::try_default().await.map_err(|e| e.to_string())?;
+    let ds = Api::<DaemonSet>::namespaced(client.clone(), namespace)
+        .get(name)
+        .await
+        .map_err(|e| e.to_string())?;
+    let selector = ds
+        .spec
+        .as_ref()
+        .and_then(|spec| spec.selector.match_labels.as_ref())
+        .map(|labels| {
+            labels
+                .iter()
+                .map(|(k, v)| format!("{}={}", k, v))
+                .collect::<Vec<String>>()
+                .join(",")
+        })
+        .ok_or("the daemon set has no label selector".to_string())?;
+    let pods = Api::<Pod>::namespaced(client, namespace)
+        .list(&ListParams::default().labels(&selector))
+        .await
+        .map_err(|e| e.to_string())?;
+    Ok((ds, pods.items))
+}
+
+// http_port finds the port of the fluent-bit HTTP server from the container port named metrics.
+pub fn http_port(ds: &DaemonSet) -> i32 {
+    ds.spec
+        .as_ref()
+        .and_then(|spec| spec.template.spec.as_ref())
+        .and_then(|pod_spec| {
+            pod_spec
+                .containers
+                .iter()
+                .filter_map(|container| container.ports.as_ref())
+                .flatten()
+                .find(|port| port.name.as_deref() == Some(HTTP_PORT_NAME))
+                .map(|port| port.container_port)
+        })
+        .unwrap_or(DEFAULT_HTTP_PORT)
+}
+
+// running_pod_ip returns the ip of the pod if it is running; the other pods read the current config when they start.
+pub fn running_pod_ip(pod: &Pod) -> Option<String> {
+    let status = pod.status.as_ref()?;
+    if status.phase.as_deref() != Some("Running") {
+        return None;
+    }
+    status.pod_ip.clone()
+}
+
+pub fn reload_config_on_pod(addr: &str) -> Result<(), FluentBitAPIError> {
+    let (status, _) = http_request_without_auth("POST", addr, "/api/v2/reload", "")
+        .map_err(|e| {
+            info!("Reload request to {} failed: {}", addr, e);
+            FluentBitAPIError::ReloadConfigFailed
+        })?;
+    if is_success(status) {
+        Ok(())
+    } else {
+        info!("Reload endpoint on {} returned status {}", addr, status);
+        Err(FluentBitAPIError::ReloadConfigFailed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use deps_hack::k8s_openapi::api::apps::v1::DaemonSetSpec;
+    use deps_hack::k8s_openapi::api::core::v1::{
+        Container, ContainerPort, PodSpec, PodStatus, PodTemplateSpec,
+    };
+    use std::io::{Read, Write};
+    use std::net::TcpListener;
+    use std::thread;
+
+    // Serves one request with the given status line and returns the raw request it received.
+    fn serve_once(status: &'static str) -> (String, thread::JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap().to_string();
+        let handle = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut buf = [0; 4096];
+            let n = stream.read(&mut buf).unwrap();
+            let response = format!("HTTP/1.1 {}\r\nContent-Length: 0\r\n\r\n", status);
+            stream.write_all(response.as_bytes()).unwrap();
+            String::from_utf8_lossy(&buf[..n]).to_string()
+        });
+        (addr, handle)
+    }
+
+    fn daemon_set(ports: Option<Vec<ContainerPort>>) -> DaemonSet {
+        DaemonSet {
+            spec: Some(DaemonSetSpec {
+                template: PodTemplateSpec {
+                    spec: Some(PodSpec {
+                        containers: vec![Container {
+                            name: "fluent-bit".to_string(),
+                            ports: ports,
+                            ..Default::default()
+                        }],
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    fn pod(phase: &str, pod_ip: Option<&str>) -> Pod {
+        Pod {
+            status: Some(PodStatus {
+                phase: Some(phase.to_string()),
+                pod_ip: pod_ip.map(|ip| ip.to_string()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_http_port() {
+        let ds = daemon_set(Some(vec![ContainerPort {
+            name: Some("metrics".to_string()),
+            container_port: 2021,
+            ..Default::default()
+        }]));
+        assert_eq!(http_port(&ds), 2021);
+        assert_eq!(http_port(&daemon_set(None)), 2020);
+    }
+
+    #[test]
+    fn test_running_pod_ip() {
+        assert_eq!(
+            running_pod_ip(&pod("Running", Some("10.0.0.1"))),
+            Some("10.0.0.1".to_string())
+        );
+        assert_eq!(running_pod_ip(&pod("Pending", Some("10.0.0.1"))), None);
+        assert_eq!(running_pod_ip(&pod("Running", None)), None);
+    }
+
+    #[test]
+    fn test_reload_config_on_pod() {
+        let (addr, handle) = serve_once("200 OK");
+        assert!(reload_config_on_pod(&addr).is_ok());
+        let request = handle.join().unwrap();
+        assert!(request.starts_with("POST /api/v2/reload HTTP/1.1\r\n"));
+        assert!(!request.contains("Authorization"));
+    }
+
+    #[test]
+    fn test_reload_config_on_pod_fails() {
+        let (addr, handle) = serve_once("404 Not Found");
+        assert!(matches!(
+            reload_config_on_pod(&addr),
+            Err(FluentBitAPIError::ReloadConfigFailed)
+        ));
+        handle.join().unwrap();
+    }
+}
@@ -0,0 +1,83 @@
+// Copyright 2022 VMware, Inc.
+// SPDX-License-Identifier: MIT
+use crate::external_api::spec::*;
+use crate::fluent_controller::fluentbit::trusted::step::*;
+use crate::kubernetes_api_objects::spec::{common::*, daemon_set::*, dynamic::*, resource::*};
+use vstd::{prelude::*, string::*};
+
+verus! {
+
+pub struct FluentBitAPI {}
+
+// FluentBitAPIState maps each daemon set object to the hash of the config that the fluent-bit pods it runs
+// were last told to reload.
+// The pods read the config from the mounted secret when they start, so a pod created by a new daemon set object
+// does not need a reload to pick up the config either.
+pub struct FluentBitAPIState {
+    pub reloaded_config_hash: Map<ObjectRef, StringView>,
+}
+
+impl FluentBitAPIState {
+    pub open spec fn init() -> FluentBitAPIState {
+        FluentBitAPIState {
+            reloaded_config_hash: Map::empty(),
+        }
+    }
+}
+
+pub struct FluentBitAPIReloadConfigResultView {
+    pub res: Result<(), FluentBitAPIError>,
+}
+
+#[is_variant]
+pub enum FluentBitAPIInputView {
+    ReloadConfigRequest(StringView, StringView, StringView),
+}
+
+#[is_variant]
+pub enum FluentBitAPIOutputView {
+    ReloadConfigResponse(FluentBitAPIReloadConfigResultView),
+}
+
+impl ExternalAPI for FluentBitAPI {
+
+    type Input = FluentBitAPIInputView;
+    type Output = FluentBitAPIOutputView;
+    type State = FluentBitAPIState;
+
+    open spec fn transition(input: FluentBitAPIInputView, resources: StoredState, state: FluentBitAPIState) -> (FluentBitAPIState, FluentBitAPIOutputView) {
+        match input {
+            FluentBitAPIInputView::ReloadConfigRequest(name, namespace, config_hash) => {
+                let (s_prime, res) = handle_reload_config(name, namespace, config_hash, resources, state);
+                (s_prime, FluentBitAPIOutputView::ReloadConfigResponse(res))
+            },
+        }
+    }
+
+    open spec fn init_state() -> FluentBitAPIState { FluentBitAPIState::init() }
+}
+
+pub open spec fn daemon_set_key(name: StringView, namespace: StringView) -> ObjectRef {
+    ObjectRef {
+        kind: DaemonSetView::kind(),
+        namespace: namespace,
+        name: name,
+    }
+}
+
+// handle_reload_config models calling the /api/v2/reload endpoint of every fluent-bit pod run by the daemon set object,
+// which makes fluent-bit re-read the config from the mounted secret without restarting the pod.
+// It fails if the daemon set object does not exist, and otherwise records the hash of the config the pods now run with.
+pub open spec fn handle_reload_config(name: StringView, namespace: StringView, config_hash: StringView, resources: StoredState, state: FluentBitAPIState) -> (FluentBitAPIState, FluentBitAPIReloadConfigResultView) {
+    let key = daemon_set_key(name, namespace);
+    if !resources.contains_key(key) {
+        (state, FluentBitAPIReloadConfigResultView{res: Err(FluentBitAPIError::ReloadConfigFailed)})
+    } else {
+        let state_prime = FluentBitAPIState {
+            reloaded_config_hash: state.reloaded_config_hash.insert(key, config_hash),
+        };
+        (state_prime, FluentBitAPIReloadConfigResultView{res: Ok(())})
+    }
+}
+
+}
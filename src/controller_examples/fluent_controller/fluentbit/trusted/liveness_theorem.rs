@@ -1,12 +1,13 @@
 // Copyright 2022 VMware, Inc.
 // SPDX-License-Identifier: MIT
 #![allow(unused_imports)]
-use crate::fluent_controller::fluentbit::trusted::{maker::*, spec_types::*, step::*};
+use crate::fluent_controller::fluentbit::trusted::{
+    fluentbit_api_spec::*, maker::*, spec_types::*, step::*,
+};
 use crate::kubernetes_api_objects::spec::prelude::*;
 use crate::kubernetes_cluster::spec::{cluster::*, cluster_state_machine::Step, message::*};
 use crate::temporal_logic::defs::*;
-use crate::vstd_ext::string_view::int_to_string_view;
-use vstd::prelude::*;
+use vstd::{prelude::*, string::*};
 
 verus! {
 
@@ -18,6 +19,26 @@ pub open spec fn liveness<M: Maker>(fb:FluentBitView) -> TempPred<FBCluster> {
     always(lift_state(desired_state_is(fb))).leads_to(always(lift_state(current_state_matches::<M>(fb))))
 }
 
+pub open spec fn config_liveness_theorem<M: Maker>() -> bool { cluster_spec().entails(tla_forall(|fb: FluentBitView| config_liveness::<M>(fb))) }
+
+// config_liveness says that once the config secret stops changing, the fluent-bit pods eventually reload the latest config
+// and keep running it, so every change of the config is applied after the changes settle.
+// The reconcile only reaches the reload after its daemon set update stops conflicting with the status writes of the
+// built-in daemon set controller, so the daemon set status is also assumed to settle.
+pub open spec fn config_liveness<M: Maker>(fb: FluentBitView) -> TempPred<FBCluster> {
+    always(lift_state(desired_state_is(fb))).and(always(lift_action(desired_config_is_stable(fb))))
+        .and(always(lift_action(daemon_set_status_is_stable::<M>(fb))))
+        .leads_to(always(lift_state(config_is_reloaded::<M>(fb))))
+}
+
+pub open spec fn status_liveness_theorem<M: Maker>() -> bool { cluster_spec().entails(tla_forall(|fb: FluentBitView| status_liveness::<M>(fb))) }
+
+pub open spec fn status_liveness<M: Maker>(fb: FluentBitView) -> TempPred<FBCluster> {
+    always(lift_state(desired_state_is(fb))).and(always(lift_action(desired_config_is_stable(fb))))
+        .and(always(lift_action(daemon_set_status_is_stable::<M>(fb)))).and(always(lift_action(fluentbit_is_stable_except_status(fb))))
+        .leads_to(lift_state(status_reflects_daemon_set::<M>(fb)))
+}
+
 pub open spec fn desired_secret_key(fb: FluentBitView) -> ObjectRef {
     ObjectRef {
         kind: SecretView::kind(),
@@ -33,6 +54,56 @@ pub open spec fn desired_state_is(fb: FluentBitView) -> StatePred<FBCluster> {
     }
 }
 
+// The config secret is written by the FluentBitConfig controller or by the user, not by the fluentbit controller,
+// so the pods can only settle on the latest config after the config stops changing.
+// Writes that keep the data of the secret (e.g., to its labels) are still allowed.
+pub open spec fn desired_config_is_stable(fb: FluentBitView) -> ActionPred<FBCluster> {
+    |s: FBCluster, s_prime: FBCluster| {
+        let key = desired_secret_key(fb);
+        &&& s.resources().contains_key(key)
+        &&& s_prime.resources().contains_key(key)
+        &&& SecretView::unmarshal(s.resources()[key]).is_Ok()
+        &&& SecretView::unmarshal(s_prime.resources()[key]).is_Ok()
+        &&& SecretView::unmarshal(s.resources()[key]).get_Ok_0().data == SecretView::unmarshal(s_prime.resources()[key]).get_Ok_0().data
+    }
+}
+
+pub open spec fn desired_config_hash(fb: FluentBitView, resources: StoredState) -> StringView {
+    config_hash(SecretView::unmarshal(resources[desired_secret_key(fb)]).get_Ok_0().data)
+}
+
+// config_is_reloaded says the fluent-bit pods run by the daemon set object have reloaded the current config.
+pub open spec fn config_is_reloaded<M: Maker>(fb: FluentBitView) -> StatePred<FBCluster> {
+    |s: FBCluster| {
+        let key = M::make_daemon_set_key(fb);
+        &&& s.resources().contains_key(desired_secret_key(fb))
+        &&& SecretView::unmarshal(s.resources()[desired_secret_key(fb)]).is_Ok()
+        &&& s.external_state().reloaded_config_hash.contains_key(key)
+        &&& s.external_state().reloaded_config_hash[key] == desired_config_hash(fb, s.resources())
+    }
+}
+
+// The daemon set status is written by the built-in daemon set controller as the pods get scheduled and become ready.
+// The fluentbit status can only catch up with it after it stops changing.
+pub open spec fn daemon_set_status_is_stable<M: Maker>(fb: FluentBitView) -> ActionPred<FBCluster> {
+    |s: FBCluster, s_prime: FBCluster| {
+        let key = M::make_daemon_set_key(fb);
+        s.resources().contains_key(key) && s_prime.resources().contains_key(key)
+        ==> s.resources()[key].status == s_prime.resources()[key].status
+    }
+}
+
+// Nobody other than the fluentbit controller touches the fluentbit object (whose spec is already fixed by desired_state_is),
+// so the only writes to it are the status updates of the controller.
+pub open spec fn fluentbit_is_stable_except_status(fb: FluentBitView) -> ActionPred<FBCluster> {
+    |s: FBCluster, s_prime: FBCluster| {
+        let key = fb.object_ref();
+        s.resources().contains_key(key) && s_prime.resources().contains_key(key)
+        && s.resources()[key].status == s_prime.resources()[key].status
+        ==> s.resources()[key] == s_prime.resources()[key]
+    }
+}
+
 pub open spec fn current_state_matches<M: Maker>(fb:FluentBitView) -> StatePred<FBCluster> {
     |s: FBCluster| {
         forall |sub_resource: SubResource| #[trigger] resource_state_matches::<M>(sub_resource, fb, s.resources())
@@ -97,4 +168,29 @@ pub open spec fn resource_state_matches<M: Maker>(sub_resource: SubResource, fb:
     }
 }
 
+// The fluentbit status reports the counters of the daemon set status and the hash of the config
+// that the fluent-bit pods have reloaded.
+pub open spec fn status_reflects_daemon_set<M: Maker>(fb: FluentBitView) -> StatePred<FBCluster> {
+    |s: FBCluster| {
+        let fb_obj = s.resources()[fb.object_ref()];
+        let ds_obj = s.resources()[M::make_daemon_set_key(fb)];
+        let secret_obj = s.resources()[desired_secret_key(fb)];
+        let fb_status = FluentBitView::unmarshal(fb_obj).get_Ok_0().status.get_Some_0();
+        let ds_status = DaemonSetView::unmarshal(ds_obj).get_Ok_0().status;
+        &&& s.resources().contains_key(fb.object_ref())
+        &&& s.resources().contains_key(M::make_daemon_set_key(fb))
+        &&& s.resources().contains_key(desired_secret_key(fb))
+        &&& SecretView::unmarshal(secret_obj).is_Ok()
+        &&& FluentBitView::unmarshal(fb_obj).is_Ok()
+        &&& FluentBitView::unmarshal(fb_obj).get_Ok_0().status.is_Some()
+        &&& DaemonSetView::unmarshal(ds_obj).is_Ok()
+        &&& ds_status.is_Some() ==> {
+            &&& fb_status.desired_number_scheduled == ds_status.get_Some_0().desired_number_scheduled
+            &&& fb_status.number_ready == ds_status.get_Some_0().number_ready
+            &&& fb_status.updated_number_scheduled == ds_status.get_Some_0().updated_number_scheduled
+        }
+        &&& fb_status.config_hash == Some(desired_config_hash(fb, s.resources()))
+    }
+}
+
 }
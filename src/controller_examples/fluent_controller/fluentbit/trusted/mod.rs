@@ -1,6 +1,8 @@
 // Copyright 2022 VMware, Inc.
 // SPDX-License-Identifier: MIT
 pub mod exec_types;
+pub mod fluentbit_api_exec;
+pub mod fluentbit_api_spec;
 pub mod liveness_theorem;
 pub mod maker;
 pub mod spec_types;
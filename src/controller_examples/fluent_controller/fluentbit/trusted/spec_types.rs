@@ -1,12 +1,10 @@
 // Copyright 2022 VMware, Inc.
 // SPDX-License-Identifier: MIT
-use crate::external_api::spec::{EmptyAPI, EmptyTypeView};
-use crate::fluent_controller::fluentbit::trusted::step::*;
+use crate::fluent_controller::fluentbit::trusted::{fluentbit_api_spec::*, step::*};
 use crate::kubernetes_api_objects::error::UnmarshalError;
 use crate::kubernetes_api_objects::spec::{
-    affinity::*, api_resource::*, common::*, container::*, dynamic::*, object_meta::*,
-    owner_reference::*, prelude::*, resource::*, resource_requirements::*, toleration::*,
-    volume::*,
+    affinity::*, api_resource::*, common::*, condition::*, container::*, dynamic::*, object_meta::*,
+    owner_reference::*, prelude::*, resource::*, resource_requirements::*, toleration::*, volume::*,
 };
 use crate::kubernetes_cluster::spec::{cluster::*, cluster_state_machine::*, message::*};
 use crate::vstd_ext::string_view::*;
@@ -16,24 +14,28 @@ verus! {
 
 pub type FBStep = Step<FBMessage>;
 
-pub type FBCluster = Cluster<FluentBitView, EmptyAPI, FluentBitReconciler>;
+pub type FBCluster = Cluster<FluentBitView, FluentBitAPI, FluentBitReconciler>;
 
-pub type FBMessage = Message<EmptyTypeView, EmptyTypeView>;
+pub type FBMessage = Message<FluentBitAPIInputView, FluentBitAPIOutputView>;
 
 pub struct FluentBitReconciler {}
 
 pub struct FluentBitReconcileState {
     pub reconcile_step: FluentBitReconcileStep,
+    pub latest_config_hash_opt: Option<StringView>,
+    pub latest_daemon_set_opt: Option<DaemonSetView>,
 }
 
+// config_hash is the content hash of the config secret data. Unlike the resource version, it stays the same
+// when only the metadata of the secret changes, so such writes do not make the fluent-bit pods reload the config.
+pub closed spec fn config_hash(data: Option<Map<StringView, ByteStringView>>) -> StringView;
+
 pub struct FluentBitView {
     pub metadata: ObjectMetaView,
     pub spec: FluentBitSpecView,
     pub status: Option<FluentBitStatusView>,
 }
 
-pub type FluentBitStatusView = EmptyStatusView;
-
 impl FluentBitView {
     pub open spec fn well_formed(self) -> bool {
         &&& self.metadata.name.is_Some()
@@ -50,6 +52,13 @@ impl FluentBitView {
             uid: self.metadata.uid.get_Some_0(),
         }
     }
+
+    pub open spec fn set_status(self, status: FluentBitStatusView) -> FluentBitView {
+        FluentBitView {
+            status: Some(status),
+            ..self
+        }
+    }
 }
 
 impl ResourceView for FluentBitView {
@@ -140,6 +149,8 @@ impl ResourceView for FluentBitView {
 impl CustomResourceView for FluentBitView {
     proof fn kind_is_custom_resource() {}
 
+    open spec fn is_namespaced() -> bool { true }
+
     open spec fn spec_status_validation(obj_spec: Self::Spec, obj_status: Self::Status) -> bool { true }
 
     proof fn validation_result_determined_by_spec_and_status()
@@ -185,4 +196,59 @@ pub struct FluentBitSpecView {
     pub ports: Option<Seq<ContainerPortView>>,
 }
 
+pub struct FluentBitStatusView {
+    pub desired_number_scheduled: int,
+    pub number_ready: int,
+    pub updated_number_scheduled: int,
+    pub config_hash: Option<StringView>,
+    pub conditions: Option<Seq<ConditionView>>,
+}
+
+impl FluentBitStatusView {
+    pub open spec fn default() -> FluentBitStatusView {
+        FluentBitStatusView {
+            desired_number_scheduled: 0,
+            number_ready: 0,
+            updated_number_scheduled: 0,
+            config_hash: None,
+            conditions: None,
+        }
+    }
+
+    pub open spec fn set_desired_number_scheduled(self, desired_number_scheduled: int) -> FluentBitStatusView {
+        FluentBitStatusView {
+            desired_number_scheduled: desired_number_scheduled,
+            ..self
+        }
+    }
+
+    pub open spec fn set_number_ready(self, number_ready: int) -> FluentBitStatusView {
+        FluentBitStatusView {
+            number_ready: number_ready,
+            ..self
+        }
+    }
+
+    pub open spec fn set_updated_number_scheduled(self, updated_number_scheduled: int) -> FluentBitStatusView {
+        FluentBitStatusView {
+            updated_number_scheduled: updated_number_scheduled,
+            ..self
+        }
+    }
+
+    pub open spec fn set_config_hash(self, config_hash: StringView) -> FluentBitStatusView {
+        FluentBitStatusView {
+            config_hash: Some(config_hash),
+            ..self
+        }
+    }
+
+    pub open spec fn set_conditions(self, conditions: Seq<ConditionView>) -> FluentBitStatusView {
+        FluentBitStatusView {
+            conditions: Some(conditions),
+            ..self
+        }
+    }
+}
+
 }
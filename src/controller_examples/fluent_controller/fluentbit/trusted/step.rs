@@ -10,6 +10,11 @@ pub enum FluentBitReconcileStep {
     Init,
     AfterGetSecret,
     AfterKRequestStep(ActionKind, SubResource),
+    AfterGetDaemonSetStatus,
+    AfterReloadConfig,
+    AfterGetFluentBit,
+    AfterUpdateStatus,
+    AfterUpdateDegradedStatus,
     Done,
     Error,
 }
@@ -40,6 +45,20 @@ impl std::clone::Clone for SubResource {
     { *self }
 }
 
+#[is_variant]
+pub enum FluentBitAPIError {
+    ReloadConfigFailed,
+}
+
+#[verifier(external)]
+impl std::fmt::Debug for FluentBitAPIError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            FluentBitAPIError::ReloadConfigFailed => write!(f, "ReloadConfigFailed"),
+        }
+    }
+}
+
 #[is_variant]
 pub enum ActionKind {
     Get,
@@ -85,6 +85,31 @@ pub fn reconcile_core(fbc: &FluentBitConfig, resp_o: Option<Response<EmptyType>>
                 SubResource::Secret => reconcile_helper::<model_resource::SecretBuilder, SecretBuilder>(fbc, resp_o, state),
             }
         },
+        FluentBitConfigReconcileStep::AfterUpdateStatus => {
+            if resp_o.is_some() && resp_o.as_ref().unwrap().is_k_response()
+            && resp_o.as_ref().unwrap().as_k_response_ref().is_update_status_response() {
+                let update_status_resp = resp_o.unwrap().into_k_response().into_update_status_response().res;
+                if update_status_resp.is_ok() {
+                    let state_prime = FluentBitConfigReconcileState {
+                        reconcile_step: FluentBitConfigReconcileStep::Done,
+                        ..state
+                    };
+                    return (state_prime, None);
+                }
+            }
+            let state_prime = FluentBitConfigReconcileState {
+                reconcile_step: FluentBitConfigReconcileStep::Error,
+                ..state
+            };
+            return (state_prime, None);
+        },
+        FluentBitConfigReconcileStep::AfterUpdateDegradedStatus => {
+            let state_prime = FluentBitConfigReconcileState {
+                reconcile_step: FluentBitConfigReconcileStep::Error,
+                ..state
+            };
+            return (state_prime, None);
+        },
         _ => {
             let state_prime =FluentBitConfigReconcileState {
                 reconcile_step: step,
@@ -96,6 +121,23 @@ pub fn reconcile_core(fbc: &FluentBitConfig, resp_o: Option<Response<EmptyType>>
     }
 }
 
+pub fn report_error(fbc: &FluentBitConfig, state: FluentBitConfigReconcileState) -> (res: (FluentBitConfigReconcileState, Option<Request<EmptyType>>))
+    requires fbc@.well_formed(),
+    ensures (res.0@, opt_request_to_view(&res.1)) == model_reconciler::report_error(fbc@, state@),
+{
+    let req_o = KubeAPIRequest::UpdateStatusRequest(KubeUpdateStatusRequest {
+        api_resource: FluentBitConfig::api_resource(),
+        name: fbc.metadata().name().unwrap(),
+        namespace: fbc.metadata().namespace().unwrap(),
+        obj: update_fbc_status_to_degraded(fbc).marshal(),
+    });
+    let state_prime = FluentBitConfigReconcileState {
+        reconcile_step: FluentBitConfigReconcileStep::AfterUpdateDegradedStatus,
+        ..state
+    };
+    (state_prime, Some(Request::KRequest(req_o)))
+}
+
 pub fn reconcile_helper<
     SpecBuilder: SpecResourceBuilder<spec_types::FluentBitConfigView, spec_types::FluentBitConfigReconcileState>,
     Builder: ResourceBuilder<FluentBitConfig, FluentBitConfigReconcileState, SpecBuilder>
@@ -150,13 +192,7 @@ pub fn reconcile_helper<
                             }
                         }
                     }
-                    // return error state
-                    let state_prime = FluentBitConfigReconcileState {
-                        reconcile_step: FluentBitConfigReconcileStep::Error,
-                        ..state
-                    };
-                    let req_o = None;
-                    return (state_prime, req_o);
+                    return report_error(fbc, state);
                 },
                 ActionKind::Create => {
                     if resp_o.is_some() && resp_o.as_ref().unwrap().is_k_response()
@@ -173,12 +209,7 @@ pub fn reconcile_helper<
                             return (state_prime, req_o);
                         }
                     }
-                    let state_prime = FluentBitConfigReconcileState {
-                        reconcile_step: FluentBitConfigReconcileStep::Error,
-                        ..state
-                    };
-                    let req_o = None;
-                    return (state_prime, req_o);
+                    return report_error(fbc, state);
                 },
                 ActionKind::Update => {
                     if resp_o.is_some() && resp_o.as_ref().unwrap().is_k_response()
@@ -195,12 +226,7 @@ pub fn reconcile_helper<
                             return (state_prime, req_o);
                         }
                     }
-                    let state_prime = FluentBitConfigReconcileState {
-                        reconcile_step: FluentBitConfigReconcileStep::Error,
-                        ..state
-                    };
-                    let req_o = None;
-                    return (state_prime, req_o);
+                    return report_error(fbc, state);
                 },
             }
         },
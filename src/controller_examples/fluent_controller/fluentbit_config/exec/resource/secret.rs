@@ -9,8 +9,8 @@ use crate::fluent_controller::fluentbit_config::trusted::{
 };
 use crate::kubernetes_api_objects::exec::resource::ResourceWrapper;
 use crate::kubernetes_api_objects::exec::{
-    container::*, label_selector::*, pod_template_spec::*, prelude::*, resource_requirements::*,
-    volume::*,
+    condition::*, container::*, label_selector::*, pod_template_spec::*, prelude::*,
+    resource_requirements::*, volume::*,
 };
 use crate::reconciler::exec::{io::*, reconciler::*, resource_builder::*};
 use crate::vstd_ext::string_map::StringMap;
@@ -50,11 +50,18 @@ impl ResourceBuilder<FluentBitConfig, FluentBitConfigReconcileState, model_resou
     fn state_after_create(fbc: &FluentBitConfig, obj: DynamicObject, state: FluentBitConfigReconcileState) -> (res: Result<(FluentBitConfigReconcileState, Option<KubeAPIRequest>), ()>) {
         let secret = Secret::unmarshal(obj);
         if secret.is_ok() {
+            let updated_fbc = update_fbc_status(fbc);
+            let req = KubeAPIRequest::UpdateStatusRequest(KubeUpdateStatusRequest {
+                api_resource: FluentBitConfig::api_resource(),
+                name: fbc.metadata().name().unwrap(),
+                namespace: fbc.metadata().namespace().unwrap(),
+                obj: updated_fbc.marshal(),
+            });
             let state_prime = FluentBitConfigReconcileState {
-                reconcile_step: FluentBitConfigReconcileStep::Done,
+                reconcile_step: FluentBitConfigReconcileStep::AfterUpdateStatus,
                 ..state
             };
-            Ok((state_prime, None))
+            Ok((state_prime, Some(req)))
         } else {
             Err(())
         }
@@ -63,11 +70,18 @@ impl ResourceBuilder<FluentBitConfig, FluentBitConfigReconcileState, model_resou
     fn state_after_update(fbc: &FluentBitConfig, obj: DynamicObject, state: FluentBitConfigReconcileState) -> (res: Result<(FluentBitConfigReconcileState, Option<KubeAPIRequest>), ()>) {
         let secret = Secret::unmarshal(obj);
         if secret.is_ok() {
+            let updated_fbc = update_fbc_status(fbc);
+            let req = KubeAPIRequest::UpdateStatusRequest(KubeUpdateStatusRequest {
+                api_resource: FluentBitConfig::api_resource(),
+                name: fbc.metadata().name().unwrap(),
+                namespace: fbc.metadata().namespace().unwrap(),
+                obj: updated_fbc.marshal(),
+            });
             let state_prime = FluentBitConfigReconcileState {
-                reconcile_step: FluentBitConfigReconcileStep::Done,
+                reconcile_step: FluentBitConfigReconcileStep::AfterUpdateStatus,
                 ..state
             };
-            Ok((state_prime, None))
+            Ok((state_prime, Some(req)))
         } else {
             Err(())
         }
@@ -86,7 +100,8 @@ pub fn update_secret(fbc: &FluentBitConfig, found_secret: Secret) -> (secret: Se
         metadata.unset_finalizers();
         metadata
     });
-    secret.set_data(made_secret.data().unwrap());
+    secret.set_binary_data(made_secret.data().unwrap());
+    secret.unset_string_data();
     secret
 }
 
@@ -117,4 +132,28 @@ pub fn make_secret(fbc: &FluentBitConfig) -> (secret: Secret)
     secret
 }
 
+pub fn update_fbc_status(fbc: &FluentBitConfig) -> (updated_fbc: FluentBitConfig)
+    ensures updated_fbc@ == model_resource::update_fbc_status(fbc@),
+{
+    let mut updated_fbc = fbc.clone();
+    updated_fbc.set_status({
+        let mut status = FluentBitConfigStatus::default();
+        status.set_conditions(make_conditions(true, false, fbc.metadata().generation()));
+        status
+    });
+    updated_fbc
+}
+
+pub fn update_fbc_status_to_degraded(fbc: &FluentBitConfig) -> (updated_fbc: FluentBitConfig)
+    ensures updated_fbc@ == model_resource::update_fbc_status_to_degraded(fbc@),
+{
+    let mut updated_fbc = fbc.clone();
+    updated_fbc.set_status({
+        let mut status = FluentBitConfigStatus::default();
+        status.set_conditions(make_conditions(false, true, fbc.metadata().generation()));
+        status
+    });
+    updated_fbc
+}
+
 }
@@ -72,6 +72,30 @@ pub open spec fn reconcile_core(
                 SubResource::Secret => { reconcile_helper::<SecretBuilder>(fbc, resp_o, state) },
             }
         },
+        FluentBitConfigReconcileStep::AfterUpdateStatus => {
+            let update_status_resp = resp.get_KResponse_0().get_UpdateStatusResponse_0().res;
+            if resp_o.is_Some() && resp.is_KResponse() && resp.get_KResponse_0().is_UpdateStatusResponse()
+            && update_status_resp.is_Ok() {
+                let state_prime = FluentBitConfigReconcileState {
+                    reconcile_step: FluentBitConfigReconcileStep::Done,
+                    ..state
+                };
+                (state_prime, None)
+            } else {
+                let state_prime = FluentBitConfigReconcileState {
+                    reconcile_step: FluentBitConfigReconcileStep::Error,
+                    ..state
+                };
+                (state_prime, None)
+            }
+        },
+        FluentBitConfigReconcileStep::AfterUpdateDegradedStatus => {
+            let state_prime = FluentBitConfigReconcileState {
+                reconcile_step: FluentBitConfigReconcileStep::Error,
+                ..state
+            };
+            (state_prime, None)
+        },
         _ => {
             let state_prime = FluentBitConfigReconcileState {
                 reconcile_step: step,
@@ -91,6 +115,20 @@ pub open spec fn reconcile_error_result(state: FluentBitConfigReconcileState) ->
     (state_prime, req_o)
 }
 
+// report_error marks the fluentbit config as degraded before the reconcile ends in error.
+pub open spec fn report_error(fbc: FluentBitConfigView, state: FluentBitConfigReconcileState) -> (FluentBitConfigReconcileState, Option<RequestView<EmptyTypeView>>) {
+    let req_o = APIRequest::UpdateStatusRequest(UpdateStatusRequest {
+        namespace: fbc.metadata.namespace.get_Some_0(),
+        name: fbc.metadata.name.get_Some_0(),
+        obj: update_fbc_status_to_degraded(fbc).marshal(),
+    });
+    let state_prime = FluentBitConfigReconcileState {
+        reconcile_step: FluentBitConfigReconcileStep::AfterUpdateDegradedStatus,
+        ..state
+    };
+    (state_prime, Some(RequestView::KRequest(req_o)))
+}
+
 pub open spec fn reconcile_helper<Builder: ResourceBuilder<FluentBitConfigView, FluentBitConfigReconcileState>>(
     fbc: FluentBitConfigView, resp_o: Option<ResponseView<EmptyTypeView>>, state: FluentBitConfigReconcileState
 ) -> (FluentBitConfigReconcileState, Option<RequestView<EmptyTypeView>>) {
@@ -117,11 +155,7 @@ pub open spec fn reconcile_helper<Builder: ResourceBuilder<FluentBitConfigView,
                                 };
                                 (state_prime, Some(RequestView::KRequest(req_o)))
                             } else {
-                                let state_prime = FluentBitConfigReconcileState {
-                                    reconcile_step: FluentBitConfigReconcileStep::Error,
-                                    ..state
-                                };
-                                (state_prime, None)
+                                report_error(fbc, state)
                             }
                         } else if get_resp.get_Err_0().is_ObjectNotFound() {
                             let new_obj = Builder::make(fbc, state);
@@ -136,26 +170,14 @@ pub open spec fn reconcile_helper<Builder: ResourceBuilder<FluentBitConfigView,
                                 };
                                 (state_prime, Some(RequestView::KRequest(req_o)))
                             } else {
-                                let state_prime = FluentBitConfigReconcileState {
-                                    reconcile_step: FluentBitConfigReconcileStep::Error,
-                                    ..state
-                                };
-                                (state_prime, None)
+                                report_error(fbc, state)
                             }
                         } else {
-                            let state_prime = FluentBitConfigReconcileState {
-                                reconcile_step: FluentBitConfigReconcileStep::Error,
-                                ..state
-                            };
-                            (state_prime, None)
+                            report_error(fbc, state)
                         }
                     } else {
                         // return error state
-                        let state_prime = FluentBitConfigReconcileState {
-                            reconcile_step: FluentBitConfigReconcileStep::Error,
-                            ..state
-                        };
-                        (state_prime, None)
+                        report_error(fbc, state)
                     }
                 },
                 ActionKind::Create => {
@@ -168,19 +190,11 @@ pub open spec fn reconcile_helper<Builder: ResourceBuilder<FluentBitConfigView,
                             let req_o = if req.is_Some() { Some(RequestView::KRequest(req.get_Some_0())) } else { None };
                             (state_prime, req_o)
                         } else {
-                            let state_prime = FluentBitConfigReconcileState {
-                                reconcile_step: FluentBitConfigReconcileStep::Error,
-                                ..state
-                            };
-                            (state_prime, None)
+                            report_error(fbc, state)
                         }
                     } else {
                         // return error state
-                        let state_prime = FluentBitConfigReconcileState {
-                            reconcile_step: FluentBitConfigReconcileStep::Error,
-                            ..state
-                        };
-                        (state_prime, None)
+                        report_error(fbc, state)
                     }
                 },
                 ActionKind::Update => {
@@ -193,19 +207,11 @@ pub open spec fn reconcile_helper<Builder: ResourceBuilder<FluentBitConfigView,
                             let req_o = if req.is_Some() { Some(RequestView::KRequest(req.get_Some_0())) } else { None };
                             (state_prime, req_o)
                         } else {
-                            let state_prime = FluentBitConfigReconcileState {
-                                reconcile_step: FluentBitConfigReconcileStep::Error,
-                                ..state
-                            };
-                            (state_prime, None)
+                            report_error(fbc, state)
                         }
                     } else {
                         // return error state
-                        let state_prime = FluentBitConfigReconcileState {
-                            reconcile_step: FluentBitConfigReconcileStep::Error,
-                            ..state
-                        };
-                        (state_prime, None)
+                        report_error(fbc, state)
                     }
                 },
             }
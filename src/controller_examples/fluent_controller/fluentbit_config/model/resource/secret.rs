@@ -5,8 +5,8 @@ use super::common::*;
 use crate::external_api::spec::*;
 use crate::fluent_controller::fluentbit_config::trusted::{spec_types::*, step::*};
 use crate::kubernetes_api_objects::spec::{
-    container::*, label_selector::*, pod_template_spec::*, prelude::*, resource_requirements::*,
-    volume::*,
+    condition::*, container::*, label_selector::*, pod_template_spec::*, prelude::*,
+    resource_requirements::*, volume::*,
 };
 use crate::kubernetes_cluster::spec::message::*;
 use crate::reconciler::spec::{io::*, reconciler::*, resource_builder::*};
@@ -41,11 +41,16 @@ impl ResourceBuilder<FluentBitConfigView, FluentBitConfigReconcileState> for Sec
     open spec fn state_after_create(fbc: FluentBitConfigView, obj: DynamicObjectView, state: FluentBitConfigReconcileState) -> (res: Result<(FluentBitConfigReconcileState, Option<APIRequest>), ()>) {
         let sts = SecretView::unmarshal(obj);
         if sts.is_Ok() {
+            let req = APIRequest::UpdateStatusRequest(UpdateStatusRequest {
+                namespace: fbc.metadata.namespace.get_Some_0(),
+                name: fbc.metadata.name.get_Some_0(),
+                obj: update_fbc_status(fbc).marshal(),
+            });
             let state_prime = FluentBitConfigReconcileState {
-                reconcile_step: FluentBitConfigReconcileStep::Done,
+                reconcile_step: FluentBitConfigReconcileStep::AfterUpdateStatus,
                 ..state
             };
-            Ok((state_prime, None))
+            Ok((state_prime, Some(req)))
         } else {
             Err(())
         }
@@ -54,11 +59,16 @@ impl ResourceBuilder<FluentBitConfigView, FluentBitConfigReconcileState> for Sec
     open spec fn state_after_update(fbc: FluentBitConfigView, obj: DynamicObjectView, state: FluentBitConfigReconcileState) -> (res: Result<(FluentBitConfigReconcileState, Option<APIRequest>), ()>) {
         let sts = SecretView::unmarshal(obj);
         if sts.is_Ok() {
+            let req = APIRequest::UpdateStatusRequest(UpdateStatusRequest {
+                namespace: fbc.metadata.namespace.get_Some_0(),
+                name: fbc.metadata.name.get_Some_0(),
+                obj: update_fbc_status(fbc).marshal(),
+            });
             let state_prime = FluentBitConfigReconcileState {
-                reconcile_step: FluentBitConfigReconcileStep::Done,
+                reconcile_step: FluentBitConfigReconcileStep::AfterUpdateStatus,
                 ..state
             };
-            Ok((state_prime, None))
+            Ok((state_prime, Some(req)))
         } else {
             Err(())
         }
@@ -96,12 +106,22 @@ pub open spec fn update_secret(fbc: FluentBitConfigView, found_secret: SecretVie
             finalizers: None,
             ..found_secret.metadata
         },
-        data: Some(Map::empty()
+        data: Some(string_map_to_byte_string_map(Map::empty()
             .insert("fluent-bit.conf"@, fbc.spec.fluentbit_config)
             .insert("parsers.conf"@, fbc.spec.parsers_config)
-        ),
+        )),
+        string_data: None,
         ..found_secret
     }
 }
 
+// The config is ready as soon as the secret holding the rendered config files is in place.
+pub open spec fn update_fbc_status(fbc: FluentBitConfigView) -> FluentBitConfigView {
+    fbc.set_status(FluentBitConfigStatusView::default().set_conditions(make_conditions(true, false, fbc.metadata.generation)))
+}
+
+pub open spec fn update_fbc_status_to_degraded(fbc: FluentBitConfigView) -> FluentBitConfigView {
+    fbc.set_status(FluentBitConfigStatusView::default().set_conditions(make_conditions(false, true, fbc.metadata.generation)))
+}
+
 }
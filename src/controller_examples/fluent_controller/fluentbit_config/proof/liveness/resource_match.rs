@@ -458,7 +458,10 @@ proof fn lemma_resource_state_matches_at_after_create_resource_step(spec: TempPr
         let resp = FBCCluster::handle_create_request_msg(pending_msg, s.kubernetes_api_state).1;
         assert(s_prime.in_flight().contains(resp));
         match sub_resource {
-            SubResource::Secret => SecretView::marshal_preserves_integrity(),
+            SubResource::Secret => {
+                SecretView::marshal_preserves_integrity();
+                SecretView::lemma_merge_string_data_in_spec_preserves_spec_without_string_data(SecretView::unmarshal(pending_msg.content.get_create_request().obj).get_Ok_0());
+            },
         }
     }
 
@@ -612,7 +615,10 @@ proof fn lemma_resource_state_matches_at_after_update_resource_step(spec: TempPr
         let resp = FBCCluster::handle_update_request_msg(pending_msg, s.kubernetes_api_state).1;
         assert(s_prime.in_flight().contains(resp));
         match sub_resource {
-            SubResource::Secret => SecretView::marshal_preserves_integrity(),
+            SubResource::Secret => {
+                SecretView::marshal_preserves_integrity();
+                SecretView::lemma_merge_string_data_in_spec_preserves_spec_without_string_data(SecretView::unmarshal(pending_msg.content.get_update_request().obj).get_Ok_0());
+            },
         }
     }
 
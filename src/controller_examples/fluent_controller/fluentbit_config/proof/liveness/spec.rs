@@ -195,6 +195,8 @@ pub open spec fn derived_invariants_since_beginning(fbc: FluentBitConfigView) ->
     .and(always(lift_state(FBCCluster::each_object_in_reconcile_has_consistent_key_and_valid_metadata())))
     .and(always(tla_forall(|sub_resource: SubResource| lift_state(helper_invariants::resource_object_has_no_finalizers_or_timestamp_and_only_has_controller_owner_ref(sub_resource, fbc)))))
     .and(always(lift_state(FBCCluster::no_pending_req_msg_at_reconcile_state(fbc.object_ref(), at_step_closure(FluentBitConfigReconcileStep::Init)))))
+    .and(always(lift_state(FBCCluster::pending_req_in_flight_or_resp_in_flight_at_reconcile_state(fbc.object_ref(), at_step_closure(FluentBitConfigReconcileStep::AfterUpdateStatus)))))
+    .and(always(lift_state(FBCCluster::pending_req_in_flight_or_resp_in_flight_at_reconcile_state(fbc.object_ref(), at_step_closure(FluentBitConfigReconcileStep::AfterUpdateDegradedStatus)))))
     .and(always(tla_forall(|step: (ActionKind, SubResource)| lift_state(FBCCluster::pending_req_in_flight_or_resp_in_flight_at_reconcile_state(fbc.object_ref(), at_step_closure(FluentBitConfigReconcileStep::AfterKRequestStep(step.0, step.1)))))))
     .and(always(tla_forall(|res: SubResource| lift_state(helper_invariants::no_update_status_request_msg_in_flight(res, fbc)))))
     .and(always(lift_state(helper_invariants::the_object_in_reconcile_satisfies_state_validation(fbc.object_ref()))))
@@ -226,6 +228,8 @@ pub proof fn derived_invariants_since_beginning_is_stable(fbc: FluentBitConfigVi
         lift_state(FBCCluster::each_object_in_reconcile_has_consistent_key_and_valid_metadata()),
         tla_forall(a_to_p_1),
         lift_state(FBCCluster::no_pending_req_msg_at_reconcile_state(fbc.object_ref(), at_step_closure(FluentBitConfigReconcileStep::Init))),
+        lift_state(FBCCluster::pending_req_in_flight_or_resp_in_flight_at_reconcile_state(fbc.object_ref(), at_step_closure(FluentBitConfigReconcileStep::AfterUpdateStatus))),
+        lift_state(FBCCluster::pending_req_in_flight_or_resp_in_flight_at_reconcile_state(fbc.object_ref(), at_step_closure(FluentBitConfigReconcileStep::AfterUpdateDegradedStatus))),
         tla_forall(a_to_p_2),
         tla_forall(a_to_p_3),
         lift_state(helper_invariants::the_object_in_reconcile_satisfies_state_validation(fbc.object_ref())),
@@ -366,6 +370,8 @@ pub proof fn sm_spec_entails_all_invariants(fbc: FluentBitConfigView)
         spec_entails_always_tla_forall(spec, a_to_p_1);
     });
     FBCCluster::lemma_always_no_pending_req_msg_at_reconcile_state(spec, fbc.object_ref(), at_step_closure(FluentBitConfigReconcileStep::Init));
+    FBCCluster::lemma_always_pending_req_in_flight_or_resp_in_flight_at_reconcile_state(spec, fbc.object_ref(), at_step_closure(FluentBitConfigReconcileStep::AfterUpdateStatus));
+    FBCCluster::lemma_always_pending_req_in_flight_or_resp_in_flight_at_reconcile_state(spec, fbc.object_ref(), at_step_closure(FluentBitConfigReconcileStep::AfterUpdateDegradedStatus));
 
     let a_to_p_2 = |step: (ActionKind, SubResource)| lift_state(FBCCluster::pending_req_in_flight_or_resp_in_flight_at_reconcile_state(fbc.object_ref(), at_step_closure(FluentBitConfigReconcileStep::AfterKRequestStep(step.0, step.1))));
     lemma_always_for_all_step_pending_req_in_flight_or_resp_in_flight_at_reconcile_state(spec, fbc);
@@ -415,6 +421,8 @@ pub proof fn sm_spec_entails_all_invariants(fbc: FluentBitConfigView)
         lift_state(FBCCluster::each_object_in_reconcile_has_consistent_key_and_valid_metadata()),
         tla_forall(a_to_p_1),
         lift_state(FBCCluster::no_pending_req_msg_at_reconcile_state(fbc.object_ref(), at_step_closure(FluentBitConfigReconcileStep::Init))),
+        lift_state(FBCCluster::pending_req_in_flight_or_resp_in_flight_at_reconcile_state(fbc.object_ref(), at_step_closure(FluentBitConfigReconcileStep::AfterUpdateStatus))),
+        lift_state(FBCCluster::pending_req_in_flight_or_resp_in_flight_at_reconcile_state(fbc.object_ref(), at_step_closure(FluentBitConfigReconcileStep::AfterUpdateDegradedStatus))),
         tla_forall(a_to_p_2),
         tla_forall(a_to_p_3),
         lift_state(helper_invariants::the_object_in_reconcile_satisfies_state_validation(fbc.object_ref())),
@@ -32,6 +32,8 @@ pub proof fn reconcile_eventually_terminates(spec: TempPred<FBCCluster>, fbc: Fl
         spec.entails(always(lift_state(FBCCluster::every_in_flight_msg_has_unique_id()))),
         spec.entails(always(lift_state(FBCCluster::pending_req_of_key_is_unique_with_unique_id(fbc.object_ref())))),
         spec.entails(always(lift_state(FBCCluster::no_pending_req_msg_at_reconcile_state(fbc.object_ref(), at_step_closure(FluentBitConfigReconcileStep::Init))))),
+        spec.entails(always(lift_state(FBCCluster::pending_req_in_flight_or_resp_in_flight_at_reconcile_state(fbc.object_ref(), at_step_closure(FluentBitConfigReconcileStep::AfterUpdateStatus))))),
+        spec.entails(always(lift_state(FBCCluster::pending_req_in_flight_or_resp_in_flight_at_reconcile_state(fbc.object_ref(), at_step_closure(FluentBitConfigReconcileStep::AfterUpdateDegradedStatus))))),
         spec.entails(always(tla_forall(|step: (ActionKind, SubResource)| lift_state(FBCCluster::pending_req_in_flight_or_resp_in_flight_at_reconcile_state(
             fbc.object_ref(), at_step_closure(FluentBitConfigReconcileStep::AfterKRequestStep(step.0, step.1))
         ))))),
@@ -57,8 +59,24 @@ pub proof fn reconcile_eventually_terminates(spec: TempPred<FBCCluster>, fbc: Fl
     temp_pred_equality(lift_state(at_step_state_pred(fbc, FluentBitConfigReconcileStep::Error)), lift_state(FBCCluster::reconciler_reconcile_error(fbc.object_ref())));
     entails_implies_leads_to(spec, lift_state(reconcile_idle), lift_state(reconcile_idle));
 
+    // Then, prove that after_update_degraded_status ~> reconcile_idle, since it always ends in error.
+    FBCCluster::lemma_from_some_state_to_arbitrary_next_state_to_reconcile_idle(
+        spec, fbc, at_step_closure(FluentBitConfigReconcileStep::AfterUpdateDegradedStatus), at_step_closure(FluentBitConfigReconcileStep::Error)
+    );
+
     // Second, prove that the sub resource that every intermediate steps can lead to reconcile idle.
-    lemma_from_after_get_resource_step_to_after_get_next_resource_step_to_reconcile_idle(spec, fbc, SubResource::Secret, FluentBitConfigReconcileStep::Done);
+    or_leads_to_combine_and_equality!(
+        spec,
+        lift_state(at_step1_or_step2_state_pred(fbc, FluentBitConfigReconcileStep::Done, FluentBitConfigReconcileStep::Error)),
+        lift_state(at_step_state_pred(fbc, FluentBitConfigReconcileStep::Done)),
+        lift_state(at_step_state_pred(fbc, FluentBitConfigReconcileStep::Error));
+        lift_state(reconcile_idle)
+    );
+    FBCCluster::lemma_from_some_state_to_arbitrary_next_state_to_reconcile_idle(
+        spec, fbc, at_step_closure(FluentBitConfigReconcileStep::AfterUpdateStatus),
+        at_step1_or_step2_closure(FluentBitConfigReconcileStep::Done, FluentBitConfigReconcileStep::Error)
+    );
+    lemma_from_after_get_resource_step_to_after_get_next_resource_step_to_reconcile_idle(spec, fbc, SubResource::Secret, FluentBitConfigReconcileStep::AfterUpdateStatus);
 
     // Third, prove that reconcile init state can reach the state after get secret.
     FBCCluster::lemma_from_init_state_to_next_state_to_reconcile_idle(spec, fbc, at_step_closure(FluentBitConfigReconcileStep::Init), at_step_closure(after_get_k_request_step(SubResource::Secret)));
@@ -70,6 +88,8 @@ pub proof fn reconcile_eventually_terminates(spec: TempPred<FBCCluster>, fbc: Fl
         lift_state(reconcile_idle),
         lift_state(at_step_state_pred(fbc, FluentBitConfigReconcileStep::Init)),
         lift_state(state_pred_regarding_sub_resource(fbc, SubResource::Secret)),
+        lift_state(at_step_state_pred(fbc, FluentBitConfigReconcileStep::AfterUpdateStatus)),
+        lift_state(at_step_state_pred(fbc, FluentBitConfigReconcileStep::AfterUpdateDegradedStatus)),
         lift_state(at_step_state_pred(fbc, FluentBitConfigReconcileStep::Done)),
         lift_state(at_step_state_pred(fbc, FluentBitConfigReconcileStep::Error));
         lift_state(reconcile_idle)
@@ -80,6 +100,10 @@ pub open spec fn at_step_state_pred(fbc: FluentBitConfigView, step: FluentBitCon
     FBCCluster::at_expected_reconcile_states(fbc.object_ref(), |s: FluentBitConfigReconcileState| s.reconcile_step == step)
 }
 
+pub open spec fn at_step1_or_step2_state_pred(fbc: FluentBitConfigView, step1: FluentBitConfigReconcileStep, step2: FluentBitConfigReconcileStep) -> StatePred<FBCCluster> {
+    FBCCluster::at_expected_reconcile_states(fbc.object_ref(), at_step1_or_step2_closure(step1, step2))
+}
+
 pub open spec fn state_pred_regarding_sub_resource(fbc: FluentBitConfigView, sub_resource: SubResource) -> StatePred<FBCCluster> {
     FBCCluster::at_expected_reconcile_states(
         fbc.object_ref(),
@@ -109,19 +133,19 @@ proof fn lemma_from_after_get_resource_step_to_after_get_next_resource_step_to_r
         // sub resource step.
         next_resource_after(sub_resource) == next_step,
         spec.entails(lift_state(at_step_state_pred(fbc, next_step)).leads_to(lift_state(|s: FBCCluster| !s.ongoing_reconciles().contains_key(fbc.object_ref())))),
-        spec.entails(lift_state(at_step_state_pred(fbc, FluentBitConfigReconcileStep::Error)).leads_to(lift_state(|s: FBCCluster| !s.ongoing_reconciles().contains_key(fbc.object_ref())))),
+        spec.entails(lift_state(at_step_state_pred(fbc, FluentBitConfigReconcileStep::AfterUpdateDegradedStatus)).leads_to(lift_state(|s: FBCCluster| !s.ongoing_reconciles().contains_key(fbc.object_ref())))),
     ensures
         spec.entails(lift_state(at_step_state_pred(fbc, after_get_k_request_step(sub_resource))).leads_to(lift_state(|s: FBCCluster| !s.ongoing_reconciles().contains_key(fbc.object_ref())))),
         spec.entails(lift_state(state_pred_regarding_sub_resource(fbc, sub_resource)).leads_to(lift_state(|s: FBCCluster| !s.ongoing_reconciles().contains_key(fbc.object_ref())))),
 {
     let state_after_create_or_update = |s: FluentBitConfigReconcileState| {
         s.reconcile_step == next_step
-        || s.reconcile_step == FluentBitConfigReconcileStep::Error
+        || s.reconcile_step == FluentBitConfigReconcileStep::AfterUpdateDegradedStatus
     };
     or_leads_to_combine_and_equality!(
         spec, lift_state(FBCCluster::at_expected_reconcile_states(fbc.object_ref(), state_after_create_or_update)),
         lift_state(at_step_state_pred(fbc, next_step)),
-        lift_state(at_step_state_pred(fbc, FluentBitConfigReconcileStep::Error));
+        lift_state(at_step_state_pred(fbc, FluentBitConfigReconcileStep::AfterUpdateDegradedStatus));
         lift_state(|s: FBCCluster| { !s.ongoing_reconciles().contains_key(fbc.object_ref()) })
     );
     FBCCluster::lemma_from_some_state_to_arbitrary_next_state_to_reconcile_idle(spec, fbc, at_step_closure(after_create_k_request_step(sub_resource)), state_after_create_or_update);
@@ -130,13 +154,13 @@ proof fn lemma_from_after_get_resource_step_to_after_get_next_resource_step_to_r
     let state_after_get = |s: FluentBitConfigReconcileState| {
         s.reconcile_step == after_create_k_request_step(sub_resource)
         || s.reconcile_step == after_update_k_request_step(sub_resource)
-        || s.reconcile_step == FluentBitConfigReconcileStep::Error
+        || s.reconcile_step == FluentBitConfigReconcileStep::AfterUpdateDegradedStatus
     };
     or_leads_to_combine_and_equality!(
         spec, lift_state(FBCCluster::at_expected_reconcile_states(fbc.object_ref(), state_after_get)),
         lift_state(at_step_state_pred(fbc, after_create_k_request_step(sub_resource))),
         lift_state(at_step_state_pred(fbc, after_update_k_request_step(sub_resource))),
-        lift_state(at_step_state_pred(fbc, FluentBitConfigReconcileStep::Error));
+        lift_state(at_step_state_pred(fbc, FluentBitConfigReconcileStep::AfterUpdateDegradedStatus));
         lift_state(|s: FBCCluster| { !s.ongoing_reconciles().contains_key(fbc.object_ref()) })
     );
     FBCCluster::lemma_from_some_state_to_arbitrary_next_state_to_reconcile_idle(spec, fbc, at_step_closure(after_get_k_request_step(sub_resource)), state_after_get);
@@ -77,7 +77,7 @@ pub open spec fn after_update_k_request_step(sub_resource: SubResource) -> Fluen
 
 pub open spec fn next_resource_after(sub_resource: SubResource) -> FluentBitConfigReconcileStep {
     match sub_resource {
-        SubResource::Secret => FluentBitConfigReconcileStep::Done,
+        SubResource::Secret => FluentBitConfigReconcileStep::AfterUpdateStatus,
     }
 }
 
@@ -5,7 +5,7 @@ use crate::fluent_controller::fluentbit_config::trusted::{
 };
 use crate::kubernetes_api_objects::error::UnmarshalError;
 use crate::kubernetes_api_objects::exec::{
-    api_resource::*, dynamic::*, object_meta::*, owner_reference::*, resource::*,
+    api_resource::*, condition::*, dynamic::*, object_meta::*, owner_reference::*, resource::*,
     resource_requirements::*,
 };
 use crate::kubernetes_api_objects::spec::resource::*;
@@ -51,6 +51,13 @@ impl View for FluentBitConfig {
 }
 
 impl FluentBitConfig {
+    #[verifier(external_body)]
+    pub fn clone(&self) -> (fbc: Self)
+        ensures fbc@ == self@,
+    {
+        FluentBitConfig { inner: self.inner.clone() }
+    }
+
     #[verifier(external_body)]
     pub fn metadata(&self) -> (metadata: ObjectMeta)
         ensures metadata@ == self@.metadata,
@@ -65,6 +72,18 @@ impl FluentBitConfig {
         FluentBitConfigSpec { inner: self.inner.spec.clone() }
     }
 
+    #[verifier(external_body)]
+    pub fn set_status(&mut self, status: FluentBitConfigStatus)
+        ensures self@ == old(self)@.set_status(status@),
+    {
+        let mut new_status = status.into_kube();
+        match (&mut new_status.conditions, self.inner.status.as_ref().and_then(|s| s.conditions.as_ref())) {
+            (Some(conditions), Some(old_conditions)) => preserve_transition_times(conditions, old_conditions),
+            _ => {},
+        }
+        self.inner.status = Some(new_status);
+    }
+
     #[verifier(external_body)]
     pub fn api_resource() -> (res: ApiResource)
         ensures res@.kind == FluentBitConfigView::kind(),
@@ -137,4 +156,30 @@ impl FluentBitConfigSpec {
     }
 }
 
+#[verifier(external_body)]
+pub struct FluentBitConfigStatus {
+    inner: deps_hack::FluentBitConfigStatus,
+}
+
+impl FluentBitConfigStatus {
+    pub spec fn view(&self) -> spec_types::FluentBitConfigStatusView;
+
+    #[verifier(external_body)]
+    pub fn default() -> (status: FluentBitConfigStatus)
+        ensures status@ == spec_types::FluentBitConfigStatusView::default(),
+    {
+        FluentBitConfigStatus { inner: deps_hack::FluentBitConfigStatus::default() }
+    }
+
+    #[verifier(external_body)]
+    pub fn set_conditions(&mut self, conditions: Vec<Condition>)
+        ensures self@ == old(self)@.set_conditions(conditions@.map_values(|c: Condition| c@)),
+    {
+        self.inner.conditions = Some(into_kube_conditions(conditions))
+    }
+
+    #[verifier(external)]
+    fn into_kube(self) -> deps_hack::FluentBitConfigStatus { self.inner }
+}
+
 }
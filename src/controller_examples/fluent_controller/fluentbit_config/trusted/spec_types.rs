@@ -4,7 +4,7 @@ use crate::external_api::spec::{EmptyAPI, EmptyTypeView};
 use crate::fluent_controller::fluentbit_config::trusted::step::*;
 use crate::kubernetes_api_objects::error::UnmarshalError;
 use crate::kubernetes_api_objects::spec::{
-    common::*, dynamic::*, object_meta::*, owner_reference::*, resource::*,
+    common::*, condition::*, dynamic::*, object_meta::*, owner_reference::*, resource::*,
     resource_requirements::*,
 };
 use crate::kubernetes_cluster::spec::{cluster::*, cluster_state_machine::*, message::*};
@@ -31,8 +31,6 @@ pub struct FluentBitConfigView {
     pub status: Option<FluentBitConfigStatusView>,
 }
 
-pub type FluentBitConfigStatusView = EmptyStatusView;
-
 impl FluentBitConfigView {
     pub open spec fn well_formed(self) -> bool {
         &&& self.metadata.name.is_Some()
@@ -49,6 +47,13 @@ impl FluentBitConfigView {
             uid: self.metadata.uid.get_Some_0(),
         }
     }
+
+    pub open spec fn set_status(self, status: FluentBitConfigStatusView) -> FluentBitConfigView {
+        FluentBitConfigView {
+            status: Some(status),
+            ..self
+        }
+    }
 }
 
 impl ResourceView for FluentBitConfigView {
@@ -139,6 +144,8 @@ impl ResourceView for FluentBitConfigView {
 impl CustomResourceView for FluentBitConfigView {
     proof fn kind_is_custom_resource() {}
 
+    open spec fn is_namespaced() -> bool { true }
+
     open spec fn spec_status_validation(obj_spec: Self::Spec, obj_status: Self::Status) -> bool { true }
 
     proof fn validation_result_determined_by_spec_and_status()
@@ -151,4 +158,23 @@ pub struct FluentBitConfigSpecView {
     pub parsers_config: StringView,
 }
 
+pub struct FluentBitConfigStatusView {
+    pub conditions: Option<Seq<ConditionView>>,
+}
+
+impl FluentBitConfigStatusView {
+    pub open spec fn default() -> FluentBitConfigStatusView {
+        FluentBitConfigStatusView {
+            conditions: None,
+        }
+    }
+
+    pub open spec fn set_conditions(self, conditions: Seq<ConditionView>) -> FluentBitConfigStatusView {
+        FluentBitConfigStatusView {
+            conditions: Some(conditions),
+            ..self
+        }
+    }
+}
+
 }
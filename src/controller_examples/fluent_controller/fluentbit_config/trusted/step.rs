@@ -9,6 +9,8 @@ verus! {
 pub enum FluentBitConfigReconcileStep {
     Init,
     AfterKRequestStep(ActionKind, SubResource),
+    AfterUpdateStatus,
+    AfterUpdateDegradedStatus,
     Done,
     Error,
 }
@@ -11,6 +11,7 @@ use crate::rabbitmq_controller::exec::resource::*;
 use crate::rabbitmq_controller::model::reconciler as model_reconciler;
 use crate::rabbitmq_controller::model::resource as model_resource;
 use crate::rabbitmq_controller::trusted::exec_types::*;
+use crate::rabbitmq_controller::trusted::rabbitmq_api_exec::*;
 use crate::rabbitmq_controller::trusted::spec_types;
 use crate::rabbitmq_controller::trusted::step::*;
 use crate::reconciler::exec::{io::*, reconciler::*, resource_builder::*};
@@ -27,7 +28,7 @@ pub struct RabbitmqReconciler {}
 impl Reconciler for RabbitmqReconciler {
     type R = RabbitmqCluster;
     type T = RabbitmqReconcileState;
-    type ExternalAPIType = EmptyAPIShimLayer;
+    type ExternalAPIType = RabbitmqAPIShimLayer;
 
     open spec fn well_formed(rabbitmq: &RabbitmqCluster) -> bool { rabbitmq@.well_formed() }
 
@@ -35,7 +36,7 @@ impl Reconciler for RabbitmqReconciler {
         reconcile_init_state()
     }
 
-    fn reconcile_core(rabbitmq: &RabbitmqCluster, resp_o: Option<Response<EmptyType>>, state: RabbitmqReconcileState) -> (RabbitmqReconcileState, Option<Request<EmptyType>>) {
+    fn reconcile_core(rabbitmq: &RabbitmqCluster, resp_o: Option<Response<RabbitmqAPIOutput>>, state: RabbitmqReconcileState) -> (RabbitmqReconcileState, Option<Request<RabbitmqAPIInput>>) {
         reconcile_core(rabbitmq, resp_o, state)
     }
 
@@ -54,6 +55,7 @@ pub fn reconcile_init_state() -> (state: RabbitmqReconcileState)
     RabbitmqReconcileState {
         reconcile_step: RabbitmqReconcileStep::Init,
         latest_config_map_rv_opt: None,
+        drained_replicas_opt: None,
     }
 }
 
@@ -75,7 +77,7 @@ pub fn reconcile_error(state: &RabbitmqReconcileState) -> (res: bool)
     }
 }
 
-pub fn reconcile_core(rabbitmq: &RabbitmqCluster, resp_o: Option<Response<EmptyType>>, state: RabbitmqReconcileState) -> (res: (RabbitmqReconcileState, Option<Request<EmptyType>>))
+pub fn reconcile_core(rabbitmq: &RabbitmqCluster, resp_o: Option<Response<RabbitmqAPIOutput>>, state: RabbitmqReconcileState) -> (res: (RabbitmqReconcileState, Option<Request<RabbitmqAPIInput>>))
     requires rabbitmq@.well_formed(),
     ensures (res.0@, opt_request_to_view(&res.1)) == model_reconciler::reconcile_core(rabbitmq@, opt_response_to_view(&resp_o), state@),
         // resource_version_check(opt_response_to_view(&resp_o), opt_request_to_view(&res.1)),
@@ -89,7 +91,7 @@ pub fn reconcile_core(rabbitmq: &RabbitmqCluster, resp_o: Option<Response<EmptyT
             };
             return (state_prime, Some(Request::KRequest(req_o)));
         },
-        RabbitmqReconcileStep::AfterKRequestStep(_, resource) => {
+        RabbitmqReconcileStep::AfterKRequestStep(action, resource) => {
             match resource {
                 SubResource::HeadlessService => reconcile_helper::<model_resource::HeadlessServiceBuilder, HeadlessServiceBuilder>(rabbitmq, resp_o, state),
                 SubResource::Service => reconcile_helper::<model_resource::ServiceBuilder, ServiceBuilder>(rabbitmq, resp_o, state),
@@ -100,8 +102,89 @@ pub fn reconcile_core(rabbitmq: &RabbitmqCluster, resp_o: Option<Response<EmptyT
                 SubResource::ServiceAccount => reconcile_helper::<model_resource::ServiceAccountBuilder, ServiceAccountBuilder>(rabbitmq, resp_o, state),
                 SubResource::Role => reconcile_helper::<model_resource::RoleBuilder, RoleBuilder>(rabbitmq, resp_o, state),
                 SubResource::RoleBinding => reconcile_helper::<model_resource::RoleBindingBuilder, RoleBindingBuilder>(rabbitmq, resp_o, state),
-                SubResource::StatefulSet => reconcile_helper::<model_resource::StatefulSetBuilder, StatefulSetBuilder>(rabbitmq, resp_o, state),
+                SubResource::PodDisruptionBudget => reconcile_helper::<model_resource::PodDisruptionBudgetBuilder, PodDisruptionBudgetBuilder>(rabbitmq, resp_o, state),
+                SubResource::StatefulSet => {
+                    let is_get = match action {
+                        ActionKind::Get => true,
+                        _ => false,
+                    };
+                    let found_replicas = found_stateful_set_replicas(rabbitmq, &resp_o);
+                    if is_get && state.drained_replicas_opt.is_none()
+                    && found_replicas.is_some() && found_replicas.unwrap() > rabbitmq.spec().replicas() {
+                        if rabbitmq.spec().allow_scale_down() {
+                            let ext_req = RabbitmqAPIInput::DrainNodesRequest(
+                                rabbitmq.metadata().name().unwrap(), rabbitmq.metadata().namespace().unwrap(), found_replicas.unwrap(), rabbitmq.spec().replicas(),
+                                rabbitmq.controller_owner_ref()
+                            );
+                            let state_prime = RabbitmqReconcileState {
+                                reconcile_step: RabbitmqReconcileStep::AfterDrainNodes,
+                                ..state
+                            };
+                            return (state_prime, Some(Request::ExternalRequest(ext_req)));
+                        }
+                        return report_error(rabbitmq, state);
+                    }
+                    reconcile_helper::<model_resource::StatefulSetBuilder, StatefulSetBuilder>(rabbitmq, resp_o, state)
+                },
+            }
+        },
+        RabbitmqReconcileStep::AfterUpdateStatus => {
+            if resp_o.is_some() && resp_o.as_ref().unwrap().is_k_response()
+            && resp_o.as_ref().unwrap().as_k_response_ref().is_update_status_response() {
+                let update_status_resp = resp_o.unwrap().into_k_response().into_update_status_response().res;
+                if update_status_resp.is_ok() {
+                    let ext_req = RabbitmqAPIInput::SetPluginsRequest(
+                        rabbitmq.metadata().name().unwrap(), rabbitmq.metadata().namespace().unwrap(), rabbitmq.spec().replicas(), make_enabled_plugins(rabbitmq)
+                    );
+                    let state_prime = RabbitmqReconcileState {
+                        reconcile_step: RabbitmqReconcileStep::AfterSetPlugins,
+                        ..state
+                    };
+                    return (state_prime, Some(Request::ExternalRequest(ext_req)));
+                }
+            }
+            let state_prime = RabbitmqReconcileState {
+                reconcile_step: RabbitmqReconcileStep::Error,
+                ..state
+            };
+            return (state_prime, None);
+        },
+        RabbitmqReconcileStep::AfterDrainNodes => {
+            if resp_o.is_some() && resp_o.as_ref().unwrap().is_external_response()
+            && resp_o.as_ref().unwrap().as_external_response_ref().is_drain_nodes_response() {
+                let drain_nodes_resp = resp_o.unwrap().into_external_response().unwrap_drain_nodes_response().res;
+                if drain_nodes_resp.is_ok() {
+                    let req_o = KubeAPIRequest::GetRequest(StatefulSetBuilder::get_request(rabbitmq));
+                    let state_prime = RabbitmqReconcileState {
+                        reconcile_step: RabbitmqReconcileStep::AfterKRequestStep(ActionKind::Get, SubResource::StatefulSet),
+                        drained_replicas_opt: Some(rabbitmq.spec().replicas()),
+                        ..state
+                    };
+                    return (state_prime, Some(Request::KRequest(req_o)));
+                }
             }
+            return report_error(rabbitmq, state);
+        },
+        RabbitmqReconcileStep::AfterSetPlugins => {
+            if resp_o.is_some() && resp_o.as_ref().unwrap().is_external_response()
+            && resp_o.as_ref().unwrap().as_external_response_ref().is_set_plugins_response() {
+                let set_plugins_resp = resp_o.unwrap().into_external_response().unwrap_set_plugins_response().res;
+                if set_plugins_resp.is_ok() {
+                    let state_prime = RabbitmqReconcileState {
+                        reconcile_step: RabbitmqReconcileStep::Done,
+                        ..state
+                    };
+                    return (state_prime, None);
+                }
+            }
+            return report_error(rabbitmq, state);
+        },
+        RabbitmqReconcileStep::AfterUpdateDegradedStatus => {
+            let state_prime = RabbitmqReconcileState {
+                reconcile_step: RabbitmqReconcileStep::Error,
+                ..state
+            };
+            return (state_prime, None);
         },
         _ => {
             return (state, None);
@@ -109,12 +192,50 @@ pub fn reconcile_core(rabbitmq: &RabbitmqCluster, resp_o: Option<Response<EmptyT
     }
 }
 
+pub fn found_stateful_set_replicas(rabbitmq: &RabbitmqCluster, resp_o: &Option<Response<RabbitmqAPIOutput>>) -> (replicas: Option<i32>)
+    requires rabbitmq@.well_formed(),
+    ensures
+        replicas.is_Some() == model_reconciler::found_stateful_set_replicas(rabbitmq@, opt_response_to_view(resp_o)).is_Some(),
+        replicas.is_Some() ==> replicas.get_Some_0() as int == model_reconciler::found_stateful_set_replicas(rabbitmq@, opt_response_to_view(resp_o)).get_Some_0(),
+{
+    if resp_o.is_some() && resp_o.as_ref().unwrap().is_k_response()
+    && resp_o.as_ref().unwrap().as_k_response_ref().is_get_response()
+    && resp_o.as_ref().unwrap().as_k_response_ref().as_get_response_ref().res.is_ok() {
+        let sts = StatefulSet::unmarshal(resp_o.as_ref().unwrap().as_k_response_ref().as_get_response_ref().res.as_ref().unwrap().clone());
+        if sts.is_ok() {
+            let found_sts = sts.unwrap();
+            if found_sts.metadata().owner_references_only_contains(rabbitmq.controller_owner_ref())
+            && found_sts.spec().is_some() {
+                return found_sts.spec().unwrap().replicas();
+            }
+        }
+    }
+    None
+}
+
+pub fn report_error(rabbitmq: &RabbitmqCluster, state: RabbitmqReconcileState) -> (res: (RabbitmqReconcileState, Option<Request<RabbitmqAPIInput>>))
+    requires rabbitmq@.well_formed(),
+    ensures (res.0@, opt_request_to_view(&res.1)) == model_reconciler::report_error(rabbitmq@, state@),
+{
+    let req_o = KubeAPIRequest::UpdateStatusRequest(KubeUpdateStatusRequest {
+        api_resource: RabbitmqCluster::api_resource(),
+        name: rabbitmq.metadata().name().unwrap(),
+        namespace: rabbitmq.metadata().namespace().unwrap(),
+        obj: update_rabbitmq_status_to_degraded(rabbitmq).marshal(),
+    });
+    let state_prime = RabbitmqReconcileState {
+        reconcile_step: RabbitmqReconcileStep::AfterUpdateDegradedStatus,
+        ..state
+    };
+    (state_prime, Some(Request::KRequest(req_o)))
+}
+
 pub fn reconcile_helper<
     SpecBuilder: SpecResourceBuilder<spec_types::RabbitmqClusterView, spec_types::RabbitmqReconcileState>,
     Builder: ResourceBuilder<RabbitmqCluster, RabbitmqReconcileState, SpecBuilder>
 >(
-    rabbitmq: &RabbitmqCluster, resp_o: Option<Response<EmptyType>>, state: RabbitmqReconcileState
-) -> (res: (RabbitmqReconcileState, Option<Request<EmptyType>>))
+    rabbitmq: &RabbitmqCluster, resp_o: Option<Response<RabbitmqAPIOutput>>, state: RabbitmqReconcileState
+) -> (res: (RabbitmqReconcileState, Option<Request<RabbitmqAPIInput>>))
     requires
         rabbitmq@.well_formed(),
         Builder::requirements(rabbitmq@),
@@ -164,12 +285,7 @@ pub fn reconcile_helper<
                         }
                     }
                     // return error state
-                    let state_prime = RabbitmqReconcileState {
-                        reconcile_step: RabbitmqReconcileStep::Error,
-                        ..state
-                    };
-                    let req_o = None;
-                    return (state_prime, req_o);
+                    return report_error(rabbitmq, state);
                 },
                 ActionKind::Create => {
                     if resp_o.is_some() && resp_o.as_ref().unwrap().is_k_response()
@@ -186,11 +302,7 @@ pub fn reconcile_helper<
                             return (state_prime, req_o);
                         }
                     }
-                    let state_prime = RabbitmqReconcileState {
-                        reconcile_step: RabbitmqReconcileStep::Error,
-                        ..state
-                    };
-                    return (state_prime, None);
+                    return report_error(rabbitmq, state);
                 },
                 ActionKind::Update => {
                     if resp_o.is_some() && resp_o.as_ref().unwrap().is_k_response()
@@ -207,11 +319,7 @@ pub fn reconcile_helper<
                             return (state_prime, req_o);
                         }
                     }
-                    let state_prime = RabbitmqReconcileState {
-                        reconcile_step: RabbitmqReconcileStep::Error,
-                        ..state
-                    };
-                    return (state_prime, None);
+                    return report_error(rabbitmq, state);
                 },
             }
         },
@@ -163,6 +163,35 @@ pub fn default_rbmq_config(rabbitmq: &RabbitmqCluster) -> (s: String)
     .concat("cluster_name = ")
     .concat(rabbitmq.metadata().name().unwrap().as_str())
     .concat("\n")
+    .concat(make_tls_config(rabbitmq).as_str())
+}
+
+pub fn make_tls_config(rabbitmq: &RabbitmqCluster) -> (s: String)
+    requires rabbitmq@.well_formed(),
+    ensures s@ == model_resource::make_tls_config(rabbitmq@),
+{
+    if rabbitmq.spec().tls().is_none() {
+        return "".to_string();
+    }
+    let tls = rabbitmq.spec().tls().unwrap();
+    let mut tls_config = "ssl_options.certfile = /etc/rabbitmq-tls/tls.crt\n\
+    ssl_options.keyfile = /etc/rabbitmq-tls/tls.key\n\
+    listeners.ssl.default = 5671\n\
+    management.ssl.certfile = /etc/rabbitmq-tls/tls.crt\n\
+    management.ssl.keyfile = /etc/rabbitmq-tls/tls.key\n\
+    management.ssl.port = 15671\n".to_string();
+    if tls.ca_secret_name().is_some() {
+        tls_config.append("ssl_options.cacertfile = /etc/rabbitmq-tls-ca/ca.crt\n\
+        ssl_options.verify = verify_peer\n\
+        management.ssl.cacertfile = /etc/rabbitmq-tls-ca/ca.crt\n");
+    }
+    if tls.disable_non_tls_listeners() {
+        tls_config.append("listeners.tcp = none\n\
+        management.tcp.port = 15672\n");
+    } else {
+        tls_config.append("management.tcp.port = 15672\n");
+    }
+    tls_config
 }
 
 }
@@ -92,6 +92,7 @@ pub fn update_default_user_secret(rabbitmq: &RabbitmqCluster, found_secret: Secr
         metadata
     });
     user_secret.set_data(make_default_user_secret_data(rabbitmq));
+    user_secret.unset_string_data();
     user_secret
 }
 
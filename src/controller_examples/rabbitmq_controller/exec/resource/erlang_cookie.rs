@@ -98,6 +98,7 @@ pub fn update_erlang_secret(rabbitmq: &RabbitmqCluster, found_erlang_secret: Sec
         metadata.set_annotations(made_secret.metadata().annotations().unwrap());
         metadata
     });
+    erlang_secret.unset_string_data();
     erlang_secret
 }
 
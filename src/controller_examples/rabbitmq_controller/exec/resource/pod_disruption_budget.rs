@@ -0,0 +1,148 @@
+// Copyright 2022 VMware, Inc.
+// SPDX-License-Identifier: MIT
+#![allow(unused_imports)]
+use super::common::*;
+use crate::external_api::exec::*;
+use crate::kubernetes_api_objects::exec::{label_selector::*, prelude::*};
+use crate::rabbitmq_controller::exec::resource::stateful_set::StatefulSetBuilder;
+use crate::rabbitmq_controller::model::resource as model_resource;
+use crate::rabbitmq_controller::trusted::exec_types::*;
+use crate::rabbitmq_controller::trusted::spec_types::RabbitmqClusterView;
+use crate::rabbitmq_controller::trusted::step::*;
+use crate::reconciler::exec::{io::*, reconciler::*, resource_builder::*};
+use crate::vstd_ext::string_map::StringMap;
+use crate::vstd_ext::string_view::*;
+use vstd::prelude::*;
+use vstd::seq_lib::*;
+use vstd::string::*;
+
+verus! {
+
+pub struct PodDisruptionBudgetBuilder {}
+
+impl ResourceBuilder<RabbitmqCluster, RabbitmqReconcileState, model_resource::PodDisruptionBudgetBuilder> for PodDisruptionBudgetBuilder {
+    open spec fn requirements(rabbitmq: RabbitmqClusterView) -> bool { rabbitmq.well_formed() }
+
+    fn get_request(rabbitmq: &RabbitmqCluster) -> KubeGetRequest {
+        KubeGetRequest {
+            api_resource: PodDisruptionBudget::api_resource(),
+            name: make_pod_disruption_budget_name(rabbitmq),
+            namespace: rabbitmq.metadata().namespace().unwrap(),
+        }
+    }
+
+    fn make(rabbitmq: &RabbitmqCluster, state: &RabbitmqReconcileState) -> Result<DynamicObject, ()> {
+        Ok(make_pod_disruption_budget(rabbitmq).marshal())
+    }
+
+    fn update(rabbitmq: &RabbitmqCluster, state: &RabbitmqReconcileState, obj: DynamicObject) -> Result<DynamicObject, ()> {
+        let pdb = PodDisruptionBudget::unmarshal(obj);
+        if pdb.is_ok() {
+            Ok(update_pod_disruption_budget(rabbitmq, pdb.unwrap()).marshal())
+        } else {
+            Err(())
+        }
+    }
+
+    fn state_after_create(rabbitmq: &RabbitmqCluster, obj: DynamicObject, state: RabbitmqReconcileState) -> (res: Result<(RabbitmqReconcileState, Option<KubeAPIRequest>), ()>) {
+        let pdb = PodDisruptionBudget::unmarshal(obj);
+        if pdb.is_ok() {
+            let state_prime = RabbitmqReconcileState {
+                reconcile_step: RabbitmqReconcileStep::AfterKRequestStep(ActionKind::Get, SubResource::StatefulSet),
+                ..state
+            };
+            let req = KubeAPIRequest::GetRequest(StatefulSetBuilder::get_request(rabbitmq));
+            Ok((state_prime, Some(req)))
+        } else {
+            Err(())
+        }
+    }
+
+    fn state_after_update(rabbitmq: &RabbitmqCluster, obj: DynamicObject, state: RabbitmqReconcileState) -> (res: Result<(RabbitmqReconcileState, Option<KubeAPIRequest>), ()>) {
+        let pdb = PodDisruptionBudget::unmarshal(obj);
+        if pdb.is_ok() {
+            let state_prime = RabbitmqReconcileState {
+                reconcile_step: RabbitmqReconcileStep::AfterKRequestStep(ActionKind::Get, SubResource::StatefulSet),
+                ..state
+            };
+            let req = KubeAPIRequest::GetRequest(StatefulSetBuilder::get_request(rabbitmq));
+            Ok((state_prime, Some(req)))
+        } else {
+            Err(())
+        }
+    }
+}
+
+pub fn update_pod_disruption_budget(rabbitmq: &RabbitmqCluster, found_pdb: PodDisruptionBudget) -> (pdb: PodDisruptionBudget)
+    requires rabbitmq@.well_formed(),
+    ensures pdb@ == model_resource::update_pod_disruption_budget(rabbitmq@, found_pdb@),
+{
+    let mut pdb = found_pdb.clone();
+    let made_pdb = make_pod_disruption_budget(rabbitmq);
+    pdb.set_metadata({
+        let mut metadata = found_pdb.metadata();
+        metadata.set_owner_references(make_owner_references(rabbitmq));
+        metadata.unset_finalizers();
+        metadata.set_labels(made_pdb.metadata().labels().unwrap());
+        metadata.set_annotations(made_pdb.metadata().annotations().unwrap());
+        metadata
+    });
+    pdb.set_spec(made_pdb.spec().unwrap());
+    pdb
+}
+
+pub fn make_pod_disruption_budget_name(rabbitmq: &RabbitmqCluster) -> (name: String)
+    requires rabbitmq@.well_formed(),
+    ensures name@ == model_resource::make_pod_disruption_budget_name(rabbitmq@),
+{
+    rabbitmq.metadata().name().unwrap().concat("-pdb")
+}
+
+pub fn make_quorum_size(rabbitmq: &RabbitmqCluster) -> (quorum_size: i32)
+    ensures quorum_size as int == model_resource::make_quorum_size(rabbitmq@),
+{
+    let replicas = rabbitmq.spec().replicas();
+    if replicas > 0 { replicas / 2 + 1 } else { 0 }
+}
+
+pub fn make_min_available(rabbitmq: &RabbitmqCluster) -> (min_available: i32)
+    ensures min_available as int == model_resource::make_min_available(rabbitmq@),
+{
+    match rabbitmq.spec().pod_disruption_budget() {
+        Some(pdb_spec) => pdb_spec.min_available(),
+        None => make_quorum_size(rabbitmq),
+    }
+}
+
+pub fn make_pod_disruption_budget(rabbitmq: &RabbitmqCluster) -> (pdb: PodDisruptionBudget)
+    requires rabbitmq@.well_formed(),
+    ensures pdb@ == model_resource::make_pod_disruption_budget(rabbitmq@),
+{
+    let mut pdb = PodDisruptionBudget::default();
+    pdb.set_metadata({
+        let mut metadata = ObjectMeta::default();
+        metadata.set_name(make_pod_disruption_budget_name(rabbitmq));
+        metadata.set_namespace(rabbitmq.metadata().namespace().unwrap());
+        metadata.set_owner_references(make_owner_references(rabbitmq));
+        metadata.set_labels(make_labels(rabbitmq));
+        metadata.set_annotations(rabbitmq.spec().annotations());
+        metadata
+    });
+    pdb.set_spec({
+        let mut pdb_spec = PodDisruptionBudgetSpec::default();
+        pdb_spec.set_min_available(make_min_available(rabbitmq));
+        pdb_spec.set_selector({
+            let mut selector = LabelSelector::default();
+            selector.set_match_labels({
+                let mut match_labels = StringMap::empty();
+                match_labels.insert("app".to_string(), rabbitmq.metadata().name().unwrap());
+                match_labels
+            });
+            selector
+        });
+        pdb_spec
+    });
+    pdb
+}
+
+}
@@ -13,6 +13,7 @@ use crate::rabbitmq_controller::trusted::exec_types::*;
 use crate::rabbitmq_controller::trusted::spec_types::RabbitmqClusterView;
 use crate::rabbitmq_controller::trusted::step::*;
 use crate::reconciler::exec::{io::*, reconciler::*, resource_builder::*};
+use crate::vstd_ext::seq_lib::*;
 use crate::vstd_ext::string_map::StringMap;
 use crate::vstd_ext::string_view::*;
 use vstd::prelude::*;
@@ -117,9 +118,122 @@ pub fn make_plugins_config_map(rabbitmq: &RabbitmqCluster) -> (config_map: Confi
     });
     let mut data = StringMap::empty();
     data.insert("enabled_plugins".to_string(),
-                "[rabbitmq_peer_discovery_k8s,rabbitmq_prometheus,rabbitmq_management].".to_string());
+                "[".to_string().concat(join_plugins(&make_enabled_plugins(rabbitmq)).as_str()).concat("]."));
     config_map.set_data(data);
     config_map
 }
 
+pub fn make_default_plugins() -> (plugins: Vec<String>)
+    ensures plugins@.map_values(|p: String| p@) == model_resource::default_plugins(),
+{
+    let mut plugins = Vec::new();
+    plugins.push("rabbitmq_peer_discovery_k8s".to_string());
+    plugins.push("rabbitmq_prometheus".to_string());
+    plugins.push("rabbitmq_management".to_string());
+    proof {
+        assert_seqs_equal!(plugins@.map_values(|p: String| p@), model_resource::default_plugins());
+    }
+    plugins
+}
+
+pub fn make_enabled_plugins(rabbitmq: &RabbitmqCluster) -> (plugins: Vec<String>)
+    requires rabbitmq@.well_formed(),
+    ensures plugins@.map_values(|p: String| p@) == model_resource::make_enabled_plugins(rabbitmq@),
+{
+    let default_plugins = make_default_plugins();
+    let additional_plugins = rabbitmq.spec().additional_plugins();
+    let ghost not_default = |p: StringView| !model_resource::default_plugins().contains(p);
+    let mut plugins = make_default_plugins();
+    let mut idx = 0;
+
+    proof {
+        assert_seqs_equal!(
+            plugins@.map_values(|p: String| p@),
+            model_resource::default_plugins() + additional_plugins@.map_values(|p: String| p@).take(0).filter(not_default)
+        );
+    }
+
+    while idx < additional_plugins.len()
+        invariant
+            idx <= additional_plugins.len(),
+            default_plugins@.map_values(|p: String| p@) == model_resource::default_plugins(),
+            additional_plugins@.map_values(|p: String| p@) == rabbitmq@.spec.additional_plugins,
+            plugins@.map_values(|p: String| p@)
+                == model_resource::default_plugins() + additional_plugins@.map_values(|p: String| p@).take(idx as int).filter(not_default),
+    {
+        let plugin = &additional_plugins[idx];
+        let ghost old_plugins = plugins@.map_values(|p: String| p@);
+        if !contains_plugin(&default_plugins, plugin) {
+            plugins.push(plugin.clone());
+        }
+
+        proof {
+            let additional = additional_plugins@.map_values(|p: String| p@);
+            seq_filter_push(additional.take(idx as int), not_default, plugin@);
+            assert(additional.take(idx as int).push(plugin@) == additional.take((idx + 1) as int));
+            if not_default(plugin@) {
+                assert_seqs_equal!(
+                    plugins@.map_values(|p: String| p@),
+                    old_plugins.push(plugin@)
+                );
+            }
+        }
+
+        idx = idx + 1;
+    }
+    assert(additional_plugins@.map_values(|p: String| p@) == additional_plugins@.map_values(|p: String| p@).take(additional_plugins.len() as int));
+    plugins
+}
+
+fn contains_plugin(plugins: &Vec<String>, plugin: &String) -> (b: bool)
+    ensures b == plugins@.map_values(|p: String| p@).contains(plugin@),
+{
+    let mut idx = 0;
+    while idx < plugins.len()
+        invariant
+            idx <= plugins.len(),
+            forall |i| 0 <= i < idx ==> plugins@.map_values(|p: String| p@)[i] != plugin@,
+    {
+        if plugins[idx].eq(plugin) {
+            assert(plugins@.map_values(|p: String| p@)[idx as int] == plugin@);
+            return true;
+        }
+        idx = idx + 1;
+    }
+    false
+}
+
+pub fn join_plugins(plugins: &Vec<String>) -> (s: String)
+    ensures s@ == model_resource::join_plugins(plugins@.map_values(|p: String| p@)),
+{
+    let mut s = "".to_string();
+    let mut idx = 0;
+
+    proof {
+        assert_seqs_equal!(plugins@.map_values(|p: String| p@).take(0), Seq::<StringView>::empty());
+    }
+
+    while idx < plugins.len()
+        invariant
+            idx <= plugins.len(),
+            s@ == model_resource::join_plugins(plugins@.map_values(|p: String| p@).take(idx as int)),
+    {
+        if idx == 0 {
+            s = plugins[0].clone();
+        } else {
+            s = s.concat(",").concat(plugins[idx].as_str());
+        }
+
+        proof {
+            let joined = plugins@.map_values(|p: String| p@);
+            assert(joined.take((idx + 1) as int).drop_last() == joined.take(idx as int));
+            assert(joined.take((idx + 1) as int).last() == joined[idx as int]);
+        }
+
+        idx = idx + 1;
+    }
+    assert(plugins@.map_values(|p: String| p@) == plugins@.map_values(|p: String| p@).take(plugins.len() as int));
+    s
+}
+
 }
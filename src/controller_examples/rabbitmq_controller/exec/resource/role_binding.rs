@@ -7,7 +7,7 @@ use crate::kubernetes_api_objects::exec::{
     container::*, label_selector::*, pod_template_spec::*, prelude::*, resource_requirements::*,
     volume::*,
 };
-use crate::rabbitmq_controller::exec::resource::stateful_set::StatefulSetBuilder;
+use crate::rabbitmq_controller::exec::resource::pod_disruption_budget::PodDisruptionBudgetBuilder;
 use crate::rabbitmq_controller::model::resource as model_resource;
 use crate::rabbitmq_controller::trusted::exec_types::*;
 use crate::rabbitmq_controller::trusted::spec_types::RabbitmqClusterView;
@@ -51,10 +51,10 @@ impl ResourceBuilder<RabbitmqCluster, RabbitmqReconcileState, model_resource::Ro
         let rb = RoleBinding::unmarshal(obj);
         if rb.is_ok() {
             let state_prime = RabbitmqReconcileState {
-                reconcile_step: RabbitmqReconcileStep::AfterKRequestStep(ActionKind::Get, SubResource::StatefulSet),
+                reconcile_step: RabbitmqReconcileStep::AfterKRequestStep(ActionKind::Get, SubResource::PodDisruptionBudget),
                 ..state
             };
-            let req = KubeAPIRequest::GetRequest(StatefulSetBuilder::get_request(rabbitmq));
+            let req = KubeAPIRequest::GetRequest(PodDisruptionBudgetBuilder::get_request(rabbitmq));
             Ok((state_prime, Some(req)))
         } else {
             Err(())
@@ -65,10 +65,10 @@ impl ResourceBuilder<RabbitmqCluster, RabbitmqReconcileState, model_resource::Ro
         let rb = RoleBinding::unmarshal(obj);
         if rb.is_ok() {
             let state_prime = RabbitmqReconcileState {
-                reconcile_step: RabbitmqReconcileStep::AfterKRequestStep(ActionKind::Get, SubResource::StatefulSet),
+                reconcile_step: RabbitmqReconcileStep::AfterKRequestStep(ActionKind::Get, SubResource::PodDisruptionBudget),
                 ..state
             };
-            let req = KubeAPIRequest::GetRequest(StatefulSetBuilder::get_request(rabbitmq));
+            let req = KubeAPIRequest::GetRequest(PodDisruptionBudgetBuilder::get_request(rabbitmq));
             Ok((state_prime, Some(req)))
         } else {
             Err(())
@@ -103,12 +103,20 @@ pub fn make_role_binding_name(rabbitmq: &RabbitmqCluster) -> (name: String)
 
 pub fn make_role_ref(rabbitmq: &RabbitmqCluster) -> (role_ref: RoleRef)
     requires rabbitmq@.well_formed(),
-    ensures role_ref@ == model_resource::make_role_binding(rabbitmq@).role_ref
+    ensures role_ref@ == model_resource::make_role_ref(rabbitmq@),
 {
     let mut role_ref = RoleRef::default();
     role_ref.set_api_group("rbac.authorization.k8s.io".to_string());
-    role_ref.set_kind("Role".to_string());
-    role_ref.set_name(rabbitmq.metadata().name().unwrap().concat("-peer-discovery"));
+    match rabbitmq.spec().cluster_role_name() {
+        Some(cluster_role_name) => {
+            role_ref.set_kind("ClusterRole".to_string());
+            role_ref.set_name(cluster_role_name);
+        },
+        None => {
+            role_ref.set_kind("Role".to_string());
+            role_ref.set_name(rabbitmq.metadata().name().unwrap().concat("-peer-discovery"));
+        },
+    }
     role_ref
 }
 
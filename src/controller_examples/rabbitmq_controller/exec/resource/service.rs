@@ -137,6 +137,18 @@ pub fn make_main_service(rabbitmq: &RabbitmqCluster) -> (service: Service)
         port.set_app_protocol("prometheus.io/metrics".to_string());
         port
     });
+    if rabbitmq.spec().tls().is_some() {
+        ports.push({
+            let mut port = ServicePort::new_with("amqps".to_string(), 5671);
+            port.set_app_protocol("amqps".to_string());
+            port
+        });
+        ports.push({
+            let mut port = ServicePort::new_with("management-tls".to_string(), 15671);
+            port.set_app_protocol("https".to_string());
+            port
+        });
+    }
     proof {
         assert_seqs_equal!(
             ports@.map_values(|port: ServicePort| port@),
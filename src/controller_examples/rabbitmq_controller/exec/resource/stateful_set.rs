@@ -4,8 +4,8 @@
 use super::common::*;
 use crate::external_api::exec::*;
 use crate::kubernetes_api_objects::exec::{
-    container::*, label_selector::*, pod_template_spec::*, prelude::*, resource_requirements::*,
-    volume::*, volume_resource_requirements::*,
+    condition::*, container::*, label_selector::*, pod_template_spec::*, prelude::*,
+    resource_requirements::*, volume::*, volume_resource_requirements::*,
 };
 use crate::rabbitmq_controller::model::resource as model_resource;
 use crate::rabbitmq_controller::trusted::exec_types::*;
@@ -53,7 +53,8 @@ impl ResourceBuilder<RabbitmqCluster, RabbitmqReconcileState, model_resource::St
         if sts.is_ok() {
             let found_sts = sts.unwrap();
             if found_sts.metadata().owner_references_only_contains(rabbitmq.controller_owner_ref())
-            && state.latest_config_map_rv_opt.is_some() && found_sts.spec().is_some() {
+            && state.latest_config_map_rv_opt.is_some() && found_sts.spec().is_some()
+            && scale_down_is_drained(rabbitmq, &found_sts, state) {
                 return Ok(update_stateful_set(rabbitmq, found_sts, state.latest_config_map_rv_opt.as_ref().unwrap()).marshal());
             }
         }
@@ -63,11 +64,18 @@ impl ResourceBuilder<RabbitmqCluster, RabbitmqReconcileState, model_resource::St
     fn state_after_create(rabbitmq: &RabbitmqCluster, obj: DynamicObject, state: RabbitmqReconcileState) -> (res: Result<(RabbitmqReconcileState, Option<KubeAPIRequest>), ()>) {
         let sts = StatefulSet::unmarshal(obj);
         if sts.is_ok() {
+            let updated_rabbitmq = update_rabbitmq_status(rabbitmq, 0);
+            let req = KubeAPIRequest::UpdateStatusRequest(KubeUpdateStatusRequest {
+                api_resource: RabbitmqCluster::api_resource(),
+                name: rabbitmq.metadata().name().unwrap(),
+                namespace: rabbitmq.metadata().namespace().unwrap(),
+                obj: updated_rabbitmq.marshal(),
+            });
             let state_prime = RabbitmqReconcileState {
-                reconcile_step: RabbitmqReconcileStep::Done,
+                reconcile_step: RabbitmqReconcileStep::AfterUpdateStatus,
                 ..state
             };
-            Ok((state_prime, None))
+            Ok((state_prime, Some(req)))
         } else {
             Err(())
         }
@@ -76,11 +84,24 @@ impl ResourceBuilder<RabbitmqCluster, RabbitmqReconcileState, model_resource::St
     fn state_after_update(rabbitmq: &RabbitmqCluster, obj: DynamicObject, state: RabbitmqReconcileState) -> (res: Result<(RabbitmqReconcileState, Option<KubeAPIRequest>), ()>) {
         let sts = StatefulSet::unmarshal(obj);
         if sts.is_ok() {
+            let stateful_set = sts.unwrap();
+            let ready_replicas = if stateful_set.status().is_some() && stateful_set.status().as_ref().unwrap().ready_replicas().is_some() {
+                stateful_set.status().as_ref().unwrap().ready_replicas().unwrap()
+            } else {
+                0
+            };
+            let updated_rabbitmq = update_rabbitmq_status(rabbitmq, ready_replicas);
+            let req = KubeAPIRequest::UpdateStatusRequest(KubeUpdateStatusRequest {
+                api_resource: RabbitmqCluster::api_resource(),
+                name: rabbitmq.metadata().name().unwrap(),
+                namespace: rabbitmq.metadata().namespace().unwrap(),
+                obj: updated_rabbitmq.marshal(),
+            });
             let state_prime = RabbitmqReconcileState {
-                reconcile_step: RabbitmqReconcileStep::Done,
+                reconcile_step: RabbitmqReconcileStep::AfterUpdateStatus,
                 ..state
             };
-            Ok((state_prime, None))
+            Ok((state_prime, Some(req)))
         } else {
             Err(())
         }
@@ -126,6 +147,16 @@ pub fn update_stateful_set(rabbitmq: &RabbitmqCluster, found_stateful_set: State
     stateful_set
 }
 
+pub fn scale_down_is_drained(rabbitmq: &RabbitmqCluster, found_stateful_set: &StatefulSet, state: &RabbitmqReconcileState) -> (res: bool)
+    requires found_stateful_set@.spec.is_Some(),
+    ensures res == model_resource::scale_down_is_drained(rabbitmq@, found_stateful_set@, state@),
+{
+    let found_replicas = found_stateful_set.spec().unwrap().replicas();
+    found_replicas.is_none()
+    || found_replicas.unwrap() <= rabbitmq.spec().replicas()
+    || (state.drained_replicas_opt.is_some() && state.drained_replicas_opt.unwrap() == rabbitmq.spec().replicas())
+}
+
 pub fn sts_restart_annotation() -> (anno: String)
     ensures anno@ == model_resource::sts_restart_annotation(),
 {
@@ -419,6 +450,31 @@ pub fn make_rabbitmq_pod_spec(rabbitmq: &RabbitmqCluster) -> (pod_spec: PodSpec)
             volume
         });
     }
+    if rabbitmq.spec().tls().is_some() {
+        let tls = rabbitmq.spec().tls().unwrap();
+        volumes.push({
+            let mut volume = Volume::default();
+            volume.set_name("rabbitmq-tls".to_string());
+            volume.set_secret({
+                let mut secret = SecretVolumeSource::default();
+                secret.set_secret_name(tls.secret_name());
+                secret
+            });
+            volume
+        });
+        if tls.ca_secret_name().is_some() {
+            volumes.push({
+                let mut volume = Volume::default();
+                volume.set_name("rabbitmq-tls-ca".to_string());
+                volume.set_secret({
+                    let mut secret = SecretVolumeSource::default();
+                    secret.set_secret_name(tls.ca_secret_name().unwrap());
+                    secret
+                });
+                volume
+            });
+        }
+    }
     proof {
         assert_seqs_equal!(
             volumes@.map_values(|vol: Volume| vol@),
@@ -636,6 +692,25 @@ pub fn make_rabbitmq_pod_spec(rabbitmq: &RabbitmqCluster) -> (pod_spec: PodSpec)
                     });
                 }
 
+                if rabbitmq.spec().tls().is_some() {
+                    volume_mounts.push({
+                        let mut volume_mount = VolumeMount::default();
+                        volume_mount.set_name("rabbitmq-tls".to_string());
+                        volume_mount.set_mount_path("/etc/rabbitmq-tls/".to_string());
+                        volume_mount.set_read_only(true);
+                        volume_mount
+                    });
+                    if rabbitmq.spec().tls().unwrap().ca_secret_name().is_some() {
+                        volume_mounts.push({
+                            let mut volume_mount = VolumeMount::default();
+                            volume_mount.set_name("rabbitmq-tls-ca".to_string());
+                            volume_mount.set_mount_path("/etc/rabbitmq-tls-ca/".to_string());
+                            volume_mount.set_read_only(true);
+                            volume_mount
+                        });
+                    }
+                }
+
                 proof {
                     assert_seqs_equal!(
                         volume_mounts@.map_values(|volume_mount: VolumeMount| volume_mount@),
@@ -649,6 +724,10 @@ pub fn make_rabbitmq_pod_spec(rabbitmq: &RabbitmqCluster) -> (pod_spec: PodSpec)
                 ports.push(ContainerPort::new_with("epmd".to_string(), 4369));
                 ports.push(ContainerPort::new_with("amqp".to_string(), 5672));
                 ports.push(ContainerPort::new_with("management".to_string(), 15672));
+                if rabbitmq.spec().tls().is_some() {
+                    ports.push(ContainerPort::new_with("amqps".to_string(), 5671));
+                    ports.push(ContainerPort::new_with("management-tls".to_string(), 15671));
+                }
 
                 proof {
                     assert_seqs_equal!(
@@ -668,7 +747,11 @@ pub fn make_rabbitmq_pod_spec(rabbitmq: &RabbitmqCluster) -> (pod_spec: PodSpec)
                 probe.set_timeout_seconds(5);
                 probe.set_tcp_socket({
                     let mut tcp_socket_action = TCPSocketAction::default();
-                    tcp_socket_action.set_port(5672);
+                    if rabbitmq.spec().tls().is_some() && rabbitmq.spec().tls().unwrap().disable_non_tls_listeners() {
+                        tcp_socket_action.set_port(5671);
+                    } else {
+                        tcp_socket_action.set_port(5672);
+                    }
                     tcp_socket_action
                 });
                 probe
@@ -692,6 +775,14 @@ pub fn make_rabbitmq_pod_spec(rabbitmq: &RabbitmqCluster) -> (pod_spec: PodSpec)
     if rabbitmq_tolerations.is_some() {
         pod_spec.set_tolerations(rabbitmq_tolerations.unwrap());
     }
+    let rabbitmq_topology_spread_constraints = rabbitmq.spec().topology_spread_constraints();
+    if rabbitmq_topology_spread_constraints.is_some() {
+        pod_spec.set_topology_spread_constraints(rabbitmq_topology_spread_constraints.unwrap());
+    }
+    let rabbitmq_priority_class_name = rabbitmq.spec().priority_class_name();
+    if rabbitmq_priority_class_name.is_some() {
+        pod_spec.set_priority_class_name(rabbitmq_priority_class_name.unwrap());
+    }
     pod_spec.set_termination_grace_period_seconds(604800);
     pod_spec
 }
@@ -735,4 +826,28 @@ pub fn make_env_vars(rabbitmq: &RabbitmqCluster) -> (env_vars: Vec<EnvVar>)
     env_vars
 }
 
+pub fn update_rabbitmq_status(rabbitmq: &RabbitmqCluster, ready_replicas: i32) -> (updated_rabbitmq: RabbitmqCluster)
+    ensures updated_rabbitmq@ == model_resource::update_rabbitmq_status(rabbitmq@, ready_replicas as int),
+{
+    let mut updated_rabbitmq = rabbitmq.clone();
+    updated_rabbitmq.set_status({
+        let mut status = RabbitmqClusterStatus::default();
+        status.set_conditions(make_conditions(ready_replicas == rabbitmq.spec().replicas(), false, rabbitmq.metadata().generation()));
+        status
+    });
+    updated_rabbitmq
+}
+
+pub fn update_rabbitmq_status_to_degraded(rabbitmq: &RabbitmqCluster) -> (updated_rabbitmq: RabbitmqCluster)
+    ensures updated_rabbitmq@ == model_resource::update_rabbitmq_status_to_degraded(rabbitmq@),
+{
+    let mut updated_rabbitmq = rabbitmq.clone();
+    updated_rabbitmq.set_status({
+        let mut status = RabbitmqClusterStatus::default();
+        status.set_conditions(make_conditions(false, true, rabbitmq.metadata().generation()));
+        status
+    });
+    updated_rabbitmq
+}
+
 }
@@ -9,6 +9,7 @@ use crate::kubernetes_api_objects::spec::{
 use crate::kubernetes_cluster::spec::message::*;
 use crate::rabbitmq_controller::model::resource::*;
 use crate::rabbitmq_controller::trusted::maker::*;
+use crate::rabbitmq_controller::trusted::rabbitmq_api_spec::*;
 use crate::rabbitmq_controller::trusted::spec_types::*;
 use crate::rabbitmq_controller::trusted::step::*;
 use crate::reconciler::spec::{io::*, reconciler::*, resource_builder::*};
@@ -20,15 +21,15 @@ use vstd::string::*;
 
 verus! {
 
-impl Reconciler<RabbitmqClusterView, EmptyAPI> for RabbitmqReconciler {
+impl Reconciler<RabbitmqClusterView, RabbitmqAPI> for RabbitmqReconciler {
     type T = RabbitmqReconcileState;
 
     open spec fn reconcile_init_state() -> RabbitmqReconcileState {
         reconcile_init_state()
     }
 
-    open spec fn reconcile_core(rabbitmq: RabbitmqClusterView, resp_o: Option<ResponseView<EmptyTypeView>>, state: RabbitmqReconcileState)
-    -> (RabbitmqReconcileState, Option<RequestView<EmptyTypeView>>) {
+    open spec fn reconcile_core(rabbitmq: RabbitmqClusterView, resp_o: Option<ResponseView<RabbitmqAPIOutputView>>, state: RabbitmqReconcileState)
+    -> (RabbitmqReconcileState, Option<RequestView<RabbitmqAPIInputView>>) {
         reconcile_core(rabbitmq, resp_o, state)
     }
 
@@ -47,6 +48,7 @@ pub open spec fn reconcile_init_state() -> RabbitmqReconcileState {
     RabbitmqReconcileState {
         reconcile_step: RabbitmqReconcileStep::Init,
         latest_config_map_rv_opt: None,
+        drained_replicas_opt: None,
     }
 }
 
@@ -65,8 +67,8 @@ pub open spec fn reconcile_error(state: RabbitmqReconcileState) -> bool {
 }
 
 pub open spec fn reconcile_core(
-    rabbitmq: RabbitmqClusterView, resp_o: Option<ResponseView<EmptyTypeView>>, state: RabbitmqReconcileState
-) -> (RabbitmqReconcileState, Option<RequestView<EmptyTypeView>>)
+    rabbitmq: RabbitmqClusterView, resp_o: Option<ResponseView<RabbitmqAPIOutputView>>, state: RabbitmqReconcileState
+) -> (RabbitmqReconcileState, Option<RequestView<RabbitmqAPIInputView>>)
     recommends
         rabbitmq.metadata.name.is_Some(),
         rabbitmq.metadata.namespace.is_Some(),
@@ -82,7 +84,7 @@ pub open spec fn reconcile_core(
             };
             (state_prime, Some(RequestView::KRequest(req_o)))
         },
-        RabbitmqReconcileStep::AfterKRequestStep(_, resource) => {
+        RabbitmqReconcileStep::AfterKRequestStep(action, resource) => {
             match resource {
                 SubResource::HeadlessService => { reconcile_helper::<HeadlessServiceBuilder>(rabbitmq, resp_o, state) },
                 SubResource::Service => { reconcile_helper::<ServiceBuilder>(rabbitmq, resp_o, state) },
@@ -93,9 +95,83 @@ pub open spec fn reconcile_core(
                 SubResource::ServiceAccount => { reconcile_helper::<ServiceAccountBuilder>(rabbitmq, resp_o, state) },
                 SubResource::Role => { reconcile_helper::<RoleBuilder>(rabbitmq, resp_o, state) },
                 SubResource::RoleBinding => { reconcile_helper::<RoleBindingBuilder>(rabbitmq, resp_o, state) },
-                SubResource::StatefulSet => { reconcile_helper::<StatefulSetBuilder>(rabbitmq, resp_o, state) },
+                SubResource::PodDisruptionBudget => { reconcile_helper::<PodDisruptionBudgetBuilder>(rabbitmq, resp_o, state) },
+                SubResource::StatefulSet => {
+                    let found_replicas = found_stateful_set_replicas(rabbitmq, resp_o);
+                    if action == ActionKind::Get && state.drained_replicas_opt.is_None()
+                    && found_replicas.is_Some() && found_replicas.get_Some_0() > rabbitmq.spec.replicas {
+                        // Before scaling down, check that no quorum queue loses its majority and drain the nodes to be removed
+                        if rabbitmq.spec.allow_scale_down {
+                            let state_prime = RabbitmqReconcileState {
+                                reconcile_step: RabbitmqReconcileStep::AfterDrainNodes,
+                                ..state
+                            };
+                            (state_prime, Some(RequestView::ExternalRequest(drain_nodes_request(rabbitmq, found_replicas.get_Some_0()))))
+                        } else {
+                            report_error(rabbitmq, state)
+                        }
+                    } else {
+                        reconcile_helper::<StatefulSetBuilder>(rabbitmq, resp_o, state)
+                    }
+                },
+            }
+        },
+        RabbitmqReconcileStep::AfterUpdateStatus => {
+            let resp = resp_o.get_Some_0();
+            let update_status_resp = resp.get_KResponse_0().get_UpdateStatusResponse_0().res;
+            if resp_o.is_Some() && resp.is_KResponse() && resp.get_KResponse_0().is_UpdateStatusResponse()
+            && update_status_resp.is_Ok() {
+                // The plugins config map only takes effect when a node (re)starts,
+                // so the plugin list is also pushed to the running nodes to avoid a rolling restart.
+                let state_prime = RabbitmqReconcileState {
+                    reconcile_step: RabbitmqReconcileStep::AfterSetPlugins,
+                    ..state
+                };
+                (state_prime, Some(RequestView::ExternalRequest(set_plugins_request(rabbitmq))))
+            } else {
+                let state_prime = RabbitmqReconcileState {
+                    reconcile_step: RabbitmqReconcileStep::Error,
+                    ..state
+                };
+                (state_prime, None)
+            }
+        },
+        RabbitmqReconcileStep::AfterDrainNodes => {
+            let resp = resp_o.get_Some_0();
+            if resp_o.is_Some() && resp.is_ExternalResponse() && resp.get_ExternalResponse_0().is_DrainNodesResponse()
+            && resp.get_ExternalResponse_0().get_DrainNodesResponse_0().res.is_Ok() {
+                // Get the stateful set again and scale it down to the drained replicas
+                let req_o = APIRequest::GetRequest(StatefulSetBuilder::get_request(rabbitmq));
+                let state_prime = RabbitmqReconcileState {
+                    reconcile_step: RabbitmqReconcileStep::AfterKRequestStep(ActionKind::Get, SubResource::StatefulSet),
+                    drained_replicas_opt: Some(rabbitmq.spec.replicas),
+                    ..state
+                };
+                (state_prime, Some(RequestView::KRequest(req_o)))
+            } else {
+                report_error(rabbitmq, state)
             }
         },
+        RabbitmqReconcileStep::AfterSetPlugins => {
+            let resp = resp_o.get_Some_0();
+            if resp_o.is_Some() && resp.is_ExternalResponse() && resp.get_ExternalResponse_0().is_SetPluginsResponse()
+            && resp.get_ExternalResponse_0().get_SetPluginsResponse_0().res.is_Ok() {
+                let state_prime = RabbitmqReconcileState {
+                    reconcile_step: RabbitmqReconcileStep::Done,
+                    ..state
+                };
+                (state_prime, None)
+            } else {
+                report_error(rabbitmq, state)
+            }
+        },
+        RabbitmqReconcileStep::AfterUpdateDegradedStatus => {
+            let state_prime = RabbitmqReconcileState {
+                reconcile_step: RabbitmqReconcileStep::Error,
+                ..state
+            };
+            (state_prime, None)
+        },
         _ => {
             let state_prime = RabbitmqReconcileState {
                 reconcile_step: step,
@@ -106,6 +182,34 @@ pub open spec fn reconcile_core(
     }
 }
 
+pub open spec fn set_plugins_request(rabbitmq: RabbitmqClusterView) -> RabbitmqAPIInputView {
+    RabbitmqAPIInputView::SetPluginsRequest(
+        rabbitmq.metadata.name.get_Some_0(), rabbitmq.metadata.namespace.get_Some_0(), rabbitmq.spec.replicas, make_enabled_plugins(rabbitmq)
+    )
+}
+
+pub open spec fn drain_nodes_request(rabbitmq: RabbitmqClusterView, replicas: int) -> RabbitmqAPIInputView {
+    RabbitmqAPIInputView::DrainNodesRequest(
+        rabbitmq.metadata.name.get_Some_0(), rabbitmq.metadata.namespace.get_Some_0(), replicas, rabbitmq.spec.replicas,
+        rabbitmq.controller_owner_ref()
+    )
+}
+
+// found_stateful_set_replicas returns the replicas of the stateful set in the get response
+// if the stateful set belongs to the current cr.
+pub open spec fn found_stateful_set_replicas(rabbitmq: RabbitmqClusterView, resp_o: Option<ResponseView<RabbitmqAPIOutputView>>) -> Option<int> {
+    let get_resp = resp_o.get_Some_0().get_KResponse_0().get_GetResponse_0().res;
+    let sts = StatefulSetView::unmarshal(get_resp.get_Ok_0());
+    let found_sts = sts.get_Ok_0();
+    if resp_o.is_Some() && resp_o.get_Some_0().is_KResponse() && resp_o.get_Some_0().get_KResponse_0().is_GetResponse()
+    && get_resp.is_Ok() && sts.is_Ok() && found_sts.metadata.owner_references_only_contains(rabbitmq.controller_owner_ref())
+    && found_sts.spec.is_Some() && found_sts.spec.get_Some_0().replicas.is_Some() {
+        found_sts.spec.get_Some_0().replicas
+    } else {
+        None
+    }
+}
+
 pub open spec fn reconcile_error_result(state: RabbitmqReconcileState) -> (RabbitmqReconcileState, Option<APIRequest>) {
     let state_prime = RabbitmqReconcileState {
         reconcile_step: RabbitmqReconcileStep::Error,
@@ -115,9 +219,23 @@ pub open spec fn reconcile_error_result(state: RabbitmqReconcileState) -> (Rabbi
     (state_prime, req_o)
 }
 
+// report_error marks the rabbitmq cluster as degraded before the reconcile ends in error.
+pub open spec fn report_error(rabbitmq: RabbitmqClusterView, state: RabbitmqReconcileState) -> (RabbitmqReconcileState, Option<RequestView<RabbitmqAPIInputView>>) {
+    let req_o = APIRequest::UpdateStatusRequest(UpdateStatusRequest {
+        namespace: rabbitmq.metadata.namespace.get_Some_0(),
+        name: rabbitmq.metadata.name.get_Some_0(),
+        obj: update_rabbitmq_status_to_degraded(rabbitmq).marshal(),
+    });
+    let state_prime = RabbitmqReconcileState {
+        reconcile_step: RabbitmqReconcileStep::AfterUpdateDegradedStatus,
+        ..state
+    };
+    (state_prime, Some(RequestView::KRequest(req_o)))
+}
+
 pub open spec fn reconcile_helper<Builder: ResourceBuilder<RabbitmqClusterView, RabbitmqReconcileState>>(
-    rabbitmq: RabbitmqClusterView, resp_o: Option<ResponseView<EmptyTypeView>>, state: RabbitmqReconcileState
-) -> (RabbitmqReconcileState, Option<RequestView<EmptyTypeView>>)
+    rabbitmq: RabbitmqClusterView, resp_o: Option<ResponseView<RabbitmqAPIOutputView>>, state: RabbitmqReconcileState
+) -> (RabbitmqReconcileState, Option<RequestView<RabbitmqAPIInputView>>)
     recommends
         rabbitmq.metadata.name.is_Some(),
         rabbitmq.metadata.namespace.is_Some(),
@@ -146,11 +264,7 @@ pub open spec fn reconcile_helper<Builder: ResourceBuilder<RabbitmqClusterView,
                                 };
                                 (state_prime, Some(RequestView::KRequest(req_o)))
                             } else {
-                                let state_prime = RabbitmqReconcileState {
-                                    reconcile_step: RabbitmqReconcileStep::Error,
-                                    ..state
-                                };
-                                (state_prime, None)
+                                report_error(rabbitmq, state)
                             }
                         } else if get_resp.get_Err_0().is_ObjectNotFound() {
                             let new_obj = Builder::make(rabbitmq, state);
@@ -165,26 +279,14 @@ pub open spec fn reconcile_helper<Builder: ResourceBuilder<RabbitmqClusterView,
                                 };
                                 (state_prime, Some(RequestView::KRequest(req_o)))
                             } else {
-                                let state_prime = RabbitmqReconcileState {
-                                    reconcile_step: RabbitmqReconcileStep::Error,
-                                    ..state
-                                };
-                                (state_prime, None)
+                                report_error(rabbitmq, state)
                             }
                         } else {
-                            let state_prime = RabbitmqReconcileState {
-                                reconcile_step: RabbitmqReconcileStep::Error,
-                                ..state
-                            };
-                            (state_prime, None)
+                            report_error(rabbitmq, state)
                         }
                     } else {
                         // return error state
-                        let state_prime = RabbitmqReconcileState {
-                            reconcile_step: RabbitmqReconcileStep::Error,
-                            ..state
-                        };
-                        (state_prime, None)
+                        report_error(rabbitmq, state)
                     }
                 },
                 ActionKind::Create => {
@@ -197,19 +299,11 @@ pub open spec fn reconcile_helper<Builder: ResourceBuilder<RabbitmqClusterView,
                             let req_o = if req.is_Some() { Some(RequestView::KRequest(req.get_Some_0())) } else { None };
                             (state_prime, req_o)
                         } else {
-                            let state_prime = RabbitmqReconcileState {
-                                reconcile_step: RabbitmqReconcileStep::Error,
-                                ..state
-                            };
-                            (state_prime, None)
+                            report_error(rabbitmq, state)
                         }
                     } else {
                         // return error state
-                        let state_prime = RabbitmqReconcileState {
-                            reconcile_step: RabbitmqReconcileStep::Error,
-                            ..state
-                        };
-                        (state_prime, None)
+                        report_error(rabbitmq, state)
                     }
                 },
                 ActionKind::Update => {
@@ -222,19 +316,11 @@ pub open spec fn reconcile_helper<Builder: ResourceBuilder<RabbitmqClusterView,
                             let req_o = if req.is_Some() { Some(RequestView::KRequest(req.get_Some_0())) } else { None };
                             (state_prime, req_o)
                         } else {
-                            let state_prime = RabbitmqReconcileState {
-                                reconcile_step: RabbitmqReconcileStep::Error,
-                                ..state
-                            };
-                            (state_prime, None)
+                            report_error(rabbitmq, state)
                         }
                     } else {
                         // return error state
-                        let state_prime = RabbitmqReconcileState {
-                            reconcile_step: RabbitmqReconcileStep::Error,
-                            ..state
-                        };
-                        (state_prime, None)
+                        report_error(rabbitmq, state)
                     }
                 },
             }
@@ -270,6 +356,8 @@ impl Maker for RabbitmqMaker {
 
     open spec fn make_role_binding_key(rabbitmq: RabbitmqClusterView) -> ObjectRef { make_role_binding_key(rabbitmq) }
 
+    open spec fn make_pod_disruption_budget_key(rabbitmq: RabbitmqClusterView) -> ObjectRef { make_pod_disruption_budget_key(rabbitmq) }
+
     open spec fn make_stateful_set_key(rabbitmq: RabbitmqClusterView) -> ObjectRef { make_stateful_set_key(rabbitmq) }
 
     open spec fn make_headless_service(rabbitmq: RabbitmqClusterView) -> ServiceView { make_headless_service(rabbitmq) }
@@ -290,6 +378,8 @@ impl Maker for RabbitmqMaker {
 
     open spec fn make_role_binding(rabbitmq: RabbitmqClusterView) -> RoleBindingView { make_role_binding(rabbitmq) }
 
+    open spec fn make_pod_disruption_budget(rabbitmq: RabbitmqClusterView) -> PodDisruptionBudgetView { make_pod_disruption_budget(rabbitmq) }
+
     open spec fn make_stateful_set(rabbitmq: RabbitmqClusterView, config_map_rv: StringView) -> StatefulSetView { make_stateful_set(rabbitmq, config_map_rv) }
 }
 
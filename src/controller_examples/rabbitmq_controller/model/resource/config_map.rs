@@ -149,6 +149,41 @@ pub open spec fn default_rbmq_config(rabbitmq: RabbitmqClusterView) -> StringVie
     cluster_formation.k8s.address_type = hostname\n"@
     + "cluster_formation.target_cluster_size_hint = "@ + int_to_string_view(rabbitmq.spec.replicas) + "\n"@
     + "cluster_name = "@ + name + "\n"@
+    + make_tls_config(rabbitmq)
+}
+
+// The TLS secrets are mounted by the stateful set at the paths below (see make_tls_volume_mounts).
+pub open spec fn make_tls_config(rabbitmq: RabbitmqClusterView) -> StringView {
+    if rabbitmq.spec.tls.is_None() {
+        ""@
+    } else {
+        let tls = rabbitmq.spec.tls.get_Some_0();
+        "ssl_options.certfile = /etc/rabbitmq-tls/tls.crt\n\
+        ssl_options.keyfile = /etc/rabbitmq-tls/tls.key\n\
+        listeners.ssl.default = 5671\n\
+        management.ssl.certfile = /etc/rabbitmq-tls/tls.crt\n\
+        management.ssl.keyfile = /etc/rabbitmq-tls/tls.key\n\
+        management.ssl.port = 15671\n"@
+        + {
+            if tls.ca_secret_name.is_Some() {
+                "ssl_options.cacertfile = /etc/rabbitmq-tls-ca/ca.crt\n\
+                ssl_options.verify = verify_peer\n\
+                management.ssl.cacertfile = /etc/rabbitmq-tls-ca/ca.crt\n"@
+            } else {
+                ""@
+            }
+        }
+        // The controller calls the management API over plain http (see rabbitmq_api_exec.rs),
+        // so the management tcp listener stays even when the other non-tls listeners are disabled.
+        + {
+            if tls.disable_non_tls_listeners {
+                "listeners.tcp = none\n\
+                management.tcp.port = 15672\n"@
+            } else {
+                "management.tcp.port = 15672\n"@
+            }
+        }
+    }
 }
 
 }
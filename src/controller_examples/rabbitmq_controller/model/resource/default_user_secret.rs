@@ -89,7 +89,8 @@ pub open spec fn update_default_user_secret(rabbitmq: RabbitmqClusterView, found
             annotations: made_secret.metadata.annotations,
             ..found_secret.metadata
         },
-        data: Some(make_default_user_secret_data(rabbitmq)),
+        data: Some(string_map_to_byte_string_map(make_default_user_secret_data(rabbitmq))),
+        string_data: None,
         ..found_secret
     }
 }
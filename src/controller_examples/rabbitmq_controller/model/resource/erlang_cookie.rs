@@ -89,12 +89,13 @@ pub open spec fn update_erlang_secret(rabbitmq: RabbitmqClusterView, found_erlan
             annotations: made_erlang_secret.metadata.annotations,
             ..found_erlang_secret.metadata
         },
+        string_data: None,
         ..found_erlang_secret
     }
 }
 
 pub open spec fn make_erlang_secret(rabbitmq: RabbitmqClusterView) -> SecretView {
-    let cookie = random_encoded_string(24);
+    let cookie = random_encoded_string_view(24);
     let data = Map::empty()
         .insert(".erlang.cookie"@, cookie);
     make_secret(rabbitmq, make_erlang_secret_name(rabbitmq), data)
@@ -5,6 +5,7 @@ pub mod config_map;
 pub mod default_user_secret;
 pub mod erlang_cookie;
 pub mod headless_service;
+pub mod pod_disruption_budget;
 pub mod rabbitmq_plugins;
 pub mod role;
 pub mod role_binding;
@@ -17,6 +18,7 @@ pub use config_map::*;
 pub use default_user_secret::*;
 pub use erlang_cookie::*;
 pub use headless_service::*;
+pub use pod_disruption_budget::*;
 pub use rabbitmq_plugins::*;
 pub use role::*;
 pub use role_binding::*;
@@ -0,0 +1,124 @@
+// Copyright 2022 VMware, Inc.
+// SPDX-License-Identifier: MIT
+#![allow(unused_imports)]
+use super::common::*;
+use crate::external_api::spec::*;
+use crate::kubernetes_api_objects::spec::{label_selector::*, prelude::*};
+use crate::kubernetes_cluster::spec::message::*;
+use crate::rabbitmq_controller::model::resource::StatefulSetBuilder;
+use crate::rabbitmq_controller::trusted::spec_types::*;
+use crate::rabbitmq_controller::trusted::step::*;
+use crate::reconciler::spec::{io::*, reconciler::*, resource_builder::*};
+use crate::state_machine::{action::*, state_machine::*};
+use crate::temporal_logic::defs::*;
+use crate::vstd_ext::string_view::*;
+use vstd::prelude::*;
+use vstd::string::*;
+
+verus! {
+
+pub struct PodDisruptionBudgetBuilder {}
+
+impl ResourceBuilder<RabbitmqClusterView, RabbitmqReconcileState> for PodDisruptionBudgetBuilder {
+    open spec fn get_request(rabbitmq: RabbitmqClusterView) -> GetRequest {
+        GetRequest { key: make_pod_disruption_budget_key(rabbitmq) }
+    }
+
+    open spec fn make(rabbitmq: RabbitmqClusterView, state: RabbitmqReconcileState) -> Result<DynamicObjectView, ()> {
+        Ok(make_pod_disruption_budget(rabbitmq).marshal())
+    }
+
+    open spec fn update(rabbitmq: RabbitmqClusterView, state: RabbitmqReconcileState, obj: DynamicObjectView) -> Result<DynamicObjectView, ()> {
+        let pdb = PodDisruptionBudgetView::unmarshal(obj);
+        if pdb.is_Ok() {
+            Ok(update_pod_disruption_budget(rabbitmq, pdb.get_Ok_0()).marshal())
+        } else {
+            Err(())
+        }
+    }
+
+    open spec fn state_after_create(rabbitmq: RabbitmqClusterView, obj: DynamicObjectView, state: RabbitmqReconcileState) -> (res: Result<(RabbitmqReconcileState, Option<APIRequest>), ()>) {
+        let pdb = PodDisruptionBudgetView::unmarshal(obj);
+        if pdb.is_Ok() {
+            let state_prime = RabbitmqReconcileState {
+                reconcile_step: RabbitmqReconcileStep::AfterKRequestStep(ActionKind::Get, SubResource::StatefulSet),
+                ..state
+            };
+            let req = APIRequest::GetRequest(StatefulSetBuilder::get_request(rabbitmq));
+            Ok((state_prime, Some(req)))
+        } else {
+            Err(())
+        }
+    }
+
+    open spec fn state_after_update(rabbitmq: RabbitmqClusterView, obj: DynamicObjectView, state: RabbitmqReconcileState) -> (res: Result<(RabbitmqReconcileState, Option<APIRequest>), ()>) {
+        let pdb = PodDisruptionBudgetView::unmarshal(obj);
+        if pdb.is_Ok() {
+            let state_prime = RabbitmqReconcileState {
+                reconcile_step: RabbitmqReconcileStep::AfterKRequestStep(ActionKind::Get, SubResource::StatefulSet),
+                ..state
+            };
+            let req = APIRequest::GetRequest(StatefulSetBuilder::get_request(rabbitmq));
+            Ok((state_prime, Some(req)))
+        } else {
+            Err(())
+        }
+    }
+}
+
+pub open spec fn make_pod_disruption_budget_name(rabbitmq: RabbitmqClusterView) -> StringView { rabbitmq.metadata.name.get_Some_0() + "-pdb"@ }
+
+pub open spec fn make_pod_disruption_budget_key(rabbitmq: RabbitmqClusterView) -> ObjectRef {
+    ObjectRef {
+        kind: PodDisruptionBudgetView::kind(),
+        name: make_pod_disruption_budget_name(rabbitmq),
+        namespace: rabbitmq.metadata.namespace.get_Some_0(),
+    }
+}
+
+// Quorum queues stay available as long as a majority of the nodes is running,
+// so a voluntary disruption (e.g., a node drain) may only evict a node if a majority remains.
+pub open spec fn make_quorum_size(rabbitmq: RabbitmqClusterView) -> int {
+    if rabbitmq.spec.replicas > 0 { rabbitmq.spec.replicas / 2 + 1 } else { 0 }
+}
+
+// With a single replica the quorum is the only node, so the default budget blocks every node drain.
+// The quorum size can be overridden through spec.podDisruptionBudget; setting minAvailable to 0 disables the budget.
+pub open spec fn make_min_available(rabbitmq: RabbitmqClusterView) -> int {
+    if rabbitmq.spec.pod_disruption_budget.is_Some() {
+        rabbitmq.spec.pod_disruption_budget.get_Some_0().min_available
+    } else {
+        make_quorum_size(rabbitmq)
+    }
+}
+
+pub open spec fn update_pod_disruption_budget(rabbitmq: RabbitmqClusterView, found_pdb: PodDisruptionBudgetView) -> PodDisruptionBudgetView {
+    let made_pdb = make_pod_disruption_budget(rabbitmq);
+    PodDisruptionBudgetView {
+        metadata: ObjectMetaView {
+            owner_references: Some(make_owner_references(rabbitmq)),
+            finalizers: None,
+            labels: made_pdb.metadata.labels,
+            annotations: made_pdb.metadata.annotations,
+            ..found_pdb.metadata
+        },
+        spec: made_pdb.spec,
+        ..found_pdb
+    }
+}
+
+pub open spec fn make_pod_disruption_budget(rabbitmq: RabbitmqClusterView) -> PodDisruptionBudgetView {
+    PodDisruptionBudgetView::default()
+        .set_metadata(ObjectMetaView::default()
+            .set_name(make_pod_disruption_budget_name(rabbitmq))
+            .set_namespace(rabbitmq.metadata.namespace.get_Some_0())
+            .set_owner_references(make_owner_references(rabbitmq))
+            .set_labels(make_labels(rabbitmq))
+            .set_annotations(rabbitmq.spec.annotations)
+        ).set_spec(PodDisruptionBudgetSpecView::default()
+            .set_min_available(make_min_available(rabbitmq))
+            .set_selector(LabelSelectorView::default().set_match_labels(Map::empty().insert("app"@, rabbitmq.metadata.name.get_Some_0())))
+        )
+}
+
+}
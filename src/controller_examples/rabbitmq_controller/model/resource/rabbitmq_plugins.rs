@@ -104,8 +104,29 @@ pub open spec fn make_plugins_config_map(rabbitmq: RabbitmqClusterView) -> Confi
             .set_annotations(rabbitmq.spec.annotations)
         )
         .set_data(Map::empty()
-            .insert("enabled_plugins"@, "[rabbitmq_peer_discovery_k8s,rabbitmq_prometheus,rabbitmq_management]."@)
+            .insert("enabled_plugins"@, "["@ + join_plugins(make_enabled_plugins(rabbitmq)) + "]."@)
         )
 }
 
+pub open spec fn default_plugins() -> Seq<StringView> {
+    seq!["rabbitmq_peer_discovery_k8s"@, "rabbitmq_prometheus"@, "rabbitmq_management"@]
+}
+
+// The default plugins are always enabled; the additional ones are appended unless they are already in the list.
+pub open spec fn make_enabled_plugins(rabbitmq: RabbitmqClusterView) -> Seq<StringView> {
+    default_plugins() + rabbitmq.spec.additional_plugins.filter(|p: StringView| !default_plugins().contains(p))
+}
+
+pub open spec fn join_plugins(plugins: Seq<StringView>) -> StringView
+    decreases plugins.len(),
+{
+    if plugins.len() == 0 {
+        ""@
+    } else if plugins.len() == 1 {
+        plugins[0]
+    } else {
+        join_plugins(plugins.drop_last()) + ","@ + plugins.last()
+    }
+}
+
 }
@@ -8,7 +8,7 @@ use crate::kubernetes_api_objects::spec::{
     volume::*,
 };
 use crate::kubernetes_cluster::spec::message::*;
-use crate::rabbitmq_controller::model::resource::StatefulSetBuilder;
+use crate::rabbitmq_controller::model::resource::PodDisruptionBudgetBuilder;
 use crate::rabbitmq_controller::trusted::spec_types::*;
 use crate::rabbitmq_controller::trusted::step::*;
 use crate::reconciler::spec::{io::*, reconciler::*, resource_builder::*};
@@ -44,10 +44,10 @@ impl ResourceBuilder<RabbitmqClusterView, RabbitmqReconcileState> for RoleBindin
         let rb = RoleBindingView::unmarshal(obj);
         if rb.is_Ok() {
             let state_prime = RabbitmqReconcileState {
-                reconcile_step: RabbitmqReconcileStep::AfterKRequestStep(ActionKind::Get, SubResource::StatefulSet),
+                reconcile_step: RabbitmqReconcileStep::AfterKRequestStep(ActionKind::Get, SubResource::PodDisruptionBudget),
                 ..state
             };
-            let req = APIRequest::GetRequest(StatefulSetBuilder::get_request(rabbitmq));
+            let req = APIRequest::GetRequest(PodDisruptionBudgetBuilder::get_request(rabbitmq));
             Ok((state_prime, Some(req)))
         } else {
             Err(())
@@ -58,10 +58,10 @@ impl ResourceBuilder<RabbitmqClusterView, RabbitmqReconcileState> for RoleBindin
         let rb = RoleBindingView::unmarshal(obj);
         if rb.is_Ok() {
             let state_prime = RabbitmqReconcileState {
-                reconcile_step: RabbitmqReconcileStep::AfterKRequestStep(ActionKind::Get, SubResource::StatefulSet),
+                reconcile_step: RabbitmqReconcileStep::AfterKRequestStep(ActionKind::Get, SubResource::PodDisruptionBudget),
                 ..state
             };
-            let req = APIRequest::GetRequest(StatefulSetBuilder::get_request(rabbitmq));
+            let req = APIRequest::GetRequest(PodDisruptionBudgetBuilder::get_request(rabbitmq));
             Ok((state_prime, Some(req)))
         } else {
             Err(())
@@ -94,6 +94,21 @@ pub open spec fn update_role_binding(rabbitmq: RabbitmqClusterView, found_role_b
     }
 }
 
+// The role binding refers to the role created by the controller,
+// unless the user asks to bind to a pre-existing cluster role.
+pub open spec fn make_role_ref(rabbitmq: RabbitmqClusterView) -> RoleRefView {
+    match rabbitmq.spec.cluster_role_name {
+        Some(cluster_role_name) => RoleRefView::default()
+            .set_api_group("rbac.authorization.k8s.io"@)
+            .set_kind("ClusterRole"@)
+            .set_name(cluster_role_name),
+        None => RoleRefView::default()
+            .set_api_group("rbac.authorization.k8s.io"@)
+            .set_kind("Role"@)
+            .set_name(rabbitmq.metadata.name.get_Some_0() + "-peer-discovery"@),
+    }
+}
+
 pub open spec fn make_role_binding(rabbitmq: RabbitmqClusterView) -> RoleBindingView {
     RoleBindingView::default()
         .set_metadata(ObjectMetaView::default()
@@ -102,11 +117,8 @@ pub open spec fn make_role_binding(rabbitmq: RabbitmqClusterView) -> RoleBinding
             .set_owner_references(make_owner_references(rabbitmq))
             .set_labels(make_labels(rabbitmq))
             .set_annotations(rabbitmq.spec.annotations)
-        ).set_role_ref(RoleRefView::default()
-            .set_api_group("rbac.authorization.k8s.io"@)
-            .set_kind("Role"@)
-            .set_name(rabbitmq.metadata.name.get_Some_0() + "-peer-discovery"@)
-        ).set_subjects(seq![SubjectView::default()
+        ).set_role_ref(make_role_ref(rabbitmq))
+        .set_subjects(seq![SubjectView::default()
             .set_kind("ServiceAccount"@)
             .set_name(rabbitmq.metadata.name.get_Some_0() + "-server"@)
             .set_namespace(rabbitmq.metadata.namespace.get_Some_0())
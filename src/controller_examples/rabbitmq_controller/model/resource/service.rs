@@ -108,6 +108,12 @@ pub open spec fn make_main_service(rabbitmq: RabbitmqClusterView) -> ServiceView
         ServicePortView::default().set_name("management"@).set_port(15672).set_app_protocol("http"@),
         ServicePortView::default().set_name("prometheus"@).set_port(15692).set_app_protocol("prometheus.io/metrics"@),
     ];
+    let ports = if rabbitmq.spec.tls.is_Some() {
+        ports.push(ServicePortView::default().set_name("amqps"@).set_port(5671).set_app_protocol("amqps"@))
+            .push(ServicePortView::default().set_name("management-tls"@).set_port(15671).set_app_protocol("https"@))
+    } else {
+        ports
+    };
     make_service(rabbitmq, make_main_service_name(rabbitmq), ports, true)
 }
 
@@ -4,8 +4,8 @@
 use super::common::*;
 use crate::external_api::spec::*;
 use crate::kubernetes_api_objects::spec::{
-    container::*, label_selector::*, pod_template_spec::*, prelude::*, resource_requirements::*,
-    volume::*, volume_resource_requirements::*,
+    condition::*, container::*, label_selector::*, pod_template_spec::*, prelude::*,
+    resource_requirements::*, volume::*, volume_resource_requirements::*,
 };
 use crate::kubernetes_cluster::spec::message::*;
 use crate::rabbitmq_controller::model::resource::config_map::make_server_config_map_key;
@@ -39,7 +39,8 @@ impl ResourceBuilder<RabbitmqClusterView, RabbitmqReconcileState> for StatefulSe
         let sts = StatefulSetView::unmarshal(obj);
         let found_sts = sts.get_Ok_0();
         if sts.is_Ok() && found_sts.metadata.owner_references_only_contains(rabbitmq.controller_owner_ref())
-        && state.latest_config_map_rv_opt.is_Some() && found_sts.spec.is_Some() {
+        && state.latest_config_map_rv_opt.is_Some() && found_sts.spec.is_Some()
+        && scale_down_is_drained(rabbitmq, found_sts, state) {
             Ok(update_stateful_set(rabbitmq, found_sts, state.latest_config_map_rv_opt.get_Some_0()).marshal())
         } else {
             Err(())
@@ -49,11 +50,16 @@ impl ResourceBuilder<RabbitmqClusterView, RabbitmqReconcileState> for StatefulSe
     open spec fn state_after_create(rabbitmq: RabbitmqClusterView, obj: DynamicObjectView, state: RabbitmqReconcileState) -> (res: Result<(RabbitmqReconcileState, Option<APIRequest>), ()>) {
         let sts = StatefulSetView::unmarshal(obj);
         if sts.is_Ok() {
+            let req = APIRequest::UpdateStatusRequest(UpdateStatusRequest {
+                namespace: rabbitmq.metadata.namespace.get_Some_0(),
+                name: rabbitmq.metadata.name.get_Some_0(),
+                obj: update_rabbitmq_status(rabbitmq, 0).marshal(),
+            });
             let state_prime = RabbitmqReconcileState {
-                reconcile_step: RabbitmqReconcileStep::Done,
+                reconcile_step: RabbitmqReconcileStep::AfterUpdateStatus,
                 ..state
             };
-            Ok((state_prime, None))
+            Ok((state_prime, Some(req)))
         } else {
             Err(())
         }
@@ -62,17 +68,36 @@ impl ResourceBuilder<RabbitmqClusterView, RabbitmqReconcileState> for StatefulSe
     open spec fn state_after_update(rabbitmq: RabbitmqClusterView, obj: DynamicObjectView, state: RabbitmqReconcileState) -> (res: Result<(RabbitmqReconcileState, Option<APIRequest>), ()>) {
         let sts = StatefulSetView::unmarshal(obj);
         if sts.is_Ok() {
+            let stateful_set = sts.get_Ok_0();
+            let ready_replicas = if stateful_set.status.is_Some() && stateful_set.status.get_Some_0().ready_replicas.is_Some() {
+                stateful_set.status.get_Some_0().ready_replicas.get_Some_0()
+            } else {
+                0
+            };
+            let req = APIRequest::UpdateStatusRequest(UpdateStatusRequest {
+                namespace: rabbitmq.metadata.namespace.get_Some_0(),
+                name: rabbitmq.metadata.name.get_Some_0(),
+                obj: update_rabbitmq_status(rabbitmq, ready_replicas).marshal(),
+            });
             let state_prime = RabbitmqReconcileState {
-                reconcile_step: RabbitmqReconcileStep::Done,
+                reconcile_step: RabbitmqReconcileStep::AfterUpdateStatus,
                 ..state
             };
-            Ok((state_prime, None))
+            Ok((state_prime, Some(req)))
         } else {
             Err(())
         }
     }
 }
 
+// The stateful set can only be scaled down after the nodes beyond the desired replicas have been drained.
+pub open spec fn scale_down_is_drained(rabbitmq: RabbitmqClusterView, found_stateful_set: StatefulSetView, state: RabbitmqReconcileState) -> bool {
+    let found_replicas = found_stateful_set.spec.get_Some_0().replicas;
+    ||| found_replicas.is_None()
+    ||| found_replicas.get_Some_0() <= rabbitmq.spec.replicas
+    ||| state.drained_replicas_opt == Some(rabbitmq.spec.replicas)
+}
+
 pub open spec fn make_stateful_set_key(rabbitmq: RabbitmqClusterView) -> ObjectRef {
     ObjectRef {
         kind: StatefulSetView::kind(),
@@ -341,12 +366,20 @@ pub open spec fn make_rabbitmq_pod_spec(rabbitmq: RabbitmqClusterView) -> PodSpe
                     } else {
                         volume_mounts
                     }
+                } + make_tls_volume_mounts(rabbitmq)),
+                ports: Some({
+                    let ports = seq![
+                        ContainerPortView::default().set_name("epmd"@).set_container_port(4369),
+                        ContainerPortView::default().set_name("amqp"@).set_container_port(5672),
+                        ContainerPortView::default().set_name("management"@).set_container_port(15672),
+                    ];
+                    if rabbitmq.spec.tls.is_Some() {
+                        ports.push(ContainerPortView::default().set_name("amqps"@).set_container_port(5671))
+                            .push(ContainerPortView::default().set_name("management-tls"@).set_container_port(15671))
+                    } else {
+                        ports
+                    }
                 }),
-                ports: Some(seq![
-                    ContainerPortView::default().set_name("epmd"@).set_container_port(4369),
-                    ContainerPortView::default().set_name("amqp"@).set_container_port(5672),
-                    ContainerPortView::default().set_name("management"@).set_container_port(15672),
-                ]),
                 readiness_probe: Some(
                     ProbeView::default()
                         .set_failure_threshold(3)
@@ -354,7 +387,7 @@ pub open spec fn make_rabbitmq_pod_spec(rabbitmq: RabbitmqClusterView) -> PodSpe
                         .set_period_seconds(10)
                         .set_success_threshold(1)
                         .set_timeout_seconds(5)
-                        .set_tcp_socket(TCPSocketActionView::default().set_port(5672))
+                        .set_tcp_socket(TCPSocketActionView::default().set_port(make_amqp_probe_port(rabbitmq)))
                 ),
                 resources: rabbitmq.spec.resources,
                 ..ContainerView::default()
@@ -366,15 +399,71 @@ pub open spec fn make_rabbitmq_pod_spec(rabbitmq: RabbitmqClusterView) -> PodSpe
             } else {
                 volumes
             }
-        }),
+        } + make_tls_volumes(rabbitmq)),
         affinity: rabbitmq.spec.affinity,
         tolerations: rabbitmq.spec.tolerations,
+        topology_spread_constraints: rabbitmq.spec.topology_spread_constraints,
+        priority_class_name: rabbitmq.spec.priority_class_name,
         // TODO: do not hardcode this value
         termination_grace_period_seconds: Some(604800),
         ..PodSpecView::default()
     }
 }
 
+pub open spec fn make_tls_volumes(rabbitmq: RabbitmqClusterView) -> Seq<VolumeView> {
+    if rabbitmq.spec.tls.is_None() {
+        seq![]
+    } else {
+        let tls = rabbitmq.spec.tls.get_Some_0();
+        let volumes = seq![
+            VolumeView::default()
+                .set_name("rabbitmq-tls"@)
+                .set_secret(SecretVolumeSourceView::default().set_secret_name(tls.secret_name)),
+        ];
+        if tls.ca_secret_name.is_Some() {
+            volumes.push(
+                VolumeView::default()
+                    .set_name("rabbitmq-tls-ca"@)
+                    .set_secret(SecretVolumeSourceView::default().set_secret_name(tls.ca_secret_name.get_Some_0()))
+            )
+        } else {
+            volumes
+        }
+    }
+}
+
+pub open spec fn make_tls_volume_mounts(rabbitmq: RabbitmqClusterView) -> Seq<VolumeMountView> {
+    if rabbitmq.spec.tls.is_None() {
+        seq![]
+    } else {
+        let volume_mounts = seq![
+            VolumeMountView::default()
+                .set_name("rabbitmq-tls"@)
+                .set_mount_path("/etc/rabbitmq-tls/"@)
+                .set_read_only(true),
+        ];
+        if rabbitmq.spec.tls.get_Some_0().ca_secret_name.is_Some() {
+            volume_mounts.push(
+                VolumeMountView::default()
+                    .set_name("rabbitmq-tls-ca"@)
+                    .set_mount_path("/etc/rabbitmq-tls-ca/"@)
+                    .set_read_only(true)
+            )
+        } else {
+            volume_mounts
+        }
+    }
+}
+
+// The plain AMQP listener is closed when only TLS listeners are allowed, so the probe checks the AMQPS port instead.
+pub open spec fn make_amqp_probe_port(rabbitmq: RabbitmqClusterView) -> int {
+    if rabbitmq.spec.tls.is_Some() && rabbitmq.spec.tls.get_Some_0().disable_non_tls_listeners {
+        5671
+    } else {
+        5672
+    }
+}
+
 pub open spec fn make_env_vars(rabbitmq: RabbitmqClusterView) -> Seq<EnvVarView> {
     seq![
         EnvVarView {
@@ -429,4 +518,18 @@ pub open spec fn make_env_vars(rabbitmq: RabbitmqClusterView) -> Seq<EnvVarView>
     ]
 }
 
+pub open spec fn update_rabbitmq_status(rabbitmq: RabbitmqClusterView, ready_replicas: int) -> RabbitmqClusterView {
+    rabbitmq.set_status(
+        RabbitmqClusterStatusView::default().set_conditions(
+            make_conditions(ready_replicas == rabbitmq.spec.replicas, false, rabbitmq.metadata.generation)
+        )
+    )
+}
+
+pub open spec fn update_rabbitmq_status_to_degraded(rabbitmq: RabbitmqClusterView) -> RabbitmqClusterView {
+    rabbitmq.set_status(
+        RabbitmqClusterStatusView::default().set_conditions(make_conditions(false, true, rabbitmq.metadata.generation))
+    )
+}
+
 }
@@ -3,11 +3,13 @@
 pub mod owner_ref;
 pub mod predicate;
 pub mod proof;
+pub mod replicas;
 pub mod unchangeable;
 pub mod validation;
 
 pub use owner_ref::*;
 pub use predicate::*;
 pub use proof::*;
+pub use replicas::*;
 pub use unchangeable::*;
 pub use validation::*;
@@ -36,6 +36,17 @@ pub open spec fn the_object_in_reconcile_satisfies_state_validation(key: ObjectR
     }
 }
 
+// The reconciler only records in its local state that the nodes were drained to the replicas of the triggering cr.
+pub open spec fn drained_replicas_in_reconcile_state_is_none_or_desired_replicas(key: ObjectRef) -> StatePred<RMQCluster>
+{
+    |s: RMQCluster| {
+        let local_state = s.ongoing_reconciles()[key].local_state;
+        s.ongoing_reconciles().contains_key(key)
+        ==> local_state.drained_replicas_opt.is_None()
+            || local_state.drained_replicas_opt == Some(s.ongoing_reconciles()[key].triggering_cr.spec.replicas)
+    }
+}
+
 pub open spec fn the_object_in_schedule_satisfies_state_validation() -> StatePred<RMQCluster>
 {
     |s: RMQCluster| {
@@ -292,7 +303,7 @@ pub open spec fn cm_rv_is_the_same_as_etcd_server_cm_if_cm_updated(rabbitmq: Rab
         ==> match local_state.reconcile_step {
             RabbitmqReconcileStep::AfterKRequestStep(_, sub_resource) => {
                 match sub_resource {
-                    SubResource::ServiceAccount | SubResource::Role | SubResource::RoleBinding | SubResource::StatefulSet => {
+                    SubResource::ServiceAccount | SubResource::Role | SubResource::RoleBinding | SubResource::PodDisruptionBudget | SubResource::StatefulSet => {
                         let cm_key = get_request(SubResource::ServerConfigMap, rabbitmq).key;
                         &&& s.resources().contains_key(cm_key)
                         &&& s.resources()[cm_key].metadata.resource_version.is_Some()
@@ -94,6 +94,31 @@ pub proof fn lemma_always_the_object_in_reconcile_satisfies_state_validation(spe
     init_invariant(spec, RMQCluster::init(), stronger_next, inv);
 }
 
+pub proof fn lemma_always_drained_replicas_in_reconcile_state_is_none_or_desired_replicas(spec: TempPred<RMQCluster>, key: ObjectRef)
+    requires
+        spec.entails(lift_state(RMQCluster::init())),
+        spec.entails(always(lift_action(RMQCluster::next()))),
+    ensures spec.entails(always(lift_state(drained_replicas_in_reconcile_state_is_none_or_desired_replicas(key)))),
+{
+    let inv = drained_replicas_in_reconcile_state_is_none_or_desired_replicas(key);
+    assert forall |s, s_prime| inv(s) && #[trigger] RMQCluster::next()(s, s_prime) implies inv(s_prime) by {
+        if s_prime.ongoing_reconciles().contains_key(key) {
+            let step = choose |step| RMQCluster::next_step(s, s_prime, step);
+            match step {
+                Step::ControllerStep(input) => {
+                    if input.1.get_Some_0() == key && s.ongoing_reconciles().contains_key(key) {
+                        // The triggering cr stays the same during the reconcile, and the only transition that sets
+                        // drained_replicas_opt sets it to the replicas of the triggering cr
+                        assert(s_prime.ongoing_reconciles()[key].triggering_cr == s.ongoing_reconciles()[key].triggering_cr);
+                    }
+                },
+                _ => {},
+            }
+        }
+    }
+    init_invariant(spec, RMQCluster::init(), RMQCluster::next(), inv);
+}
+
 pub proof fn lemma_eventually_always_cm_rv_is_the_same_as_etcd_server_cm_if_cm_updated_forall(spec: TempPred<RMQCluster>, rabbitmq: RabbitmqClusterView)
     requires
         spec.entails(always(lift_action(RMQCluster::next()))),
@@ -164,7 +189,7 @@ proof fn lemma_eventually_always_cm_rv_is_the_same_as_etcd_server_cm_if_cm_updat
             match s_prime.ongoing_reconciles()[key].local_state.reconcile_step {
                 RabbitmqReconcileStep::AfterKRequestStep(_, sub_resource) => {
                     match sub_resource {
-                        SubResource::ServiceAccount | SubResource::Role | SubResource::RoleBinding | SubResource::StatefulSet => {
+                        SubResource::ServiceAccount | SubResource::Role | SubResource::RoleBinding | SubResource::PodDisruptionBudget | SubResource::StatefulSet => {
                             let step = choose |step| RMQCluster::next_step(s, s_prime, step);
                             match step {
                                 Step::ApiServerStep(input) => {
@@ -972,6 +997,7 @@ pub proof fn lemma_always_no_update_status_request_msg_in_flight_of_except_state
                                                 SubResource::ServiceAccount => {},
                                                 SubResource::Role => {},
                                                 SubResource::RoleBinding => {},
+                                                SubResource::PodDisruptionBudget => {},
                                                 SubResource::StatefulSet => {},
                                             }
                                         },
@@ -1043,6 +1069,7 @@ pub proof fn lemma_always_no_update_status_request_msg_not_from_bc_in_flight_of_
                                             SubResource::ServiceAccount => {},
                                             SubResource::Role => {},
                                             SubResource::RoleBinding => {},
+                                            SubResource::PodDisruptionBudget => {},
                                             SubResource::StatefulSet => {},
                                         }
                                     },
@@ -1360,6 +1387,9 @@ pub proof fn lemma_resource_update_request_msg_implies_key_in_reconcile_equals(s
         SubResource::Role => {
             seq_lib::seq_equal_preserved_by_add(key.name, cr_key.name, "-peer-discovery"@);
         },
+        SubResource::PodDisruptionBudget => {
+            seq_lib::seq_equal_preserved_by_add(key.name, cr_key.name, "-pdb"@);
+        },
     }
 }
 
@@ -1487,6 +1517,9 @@ pub proof fn lemma_resource_create_request_msg_implies_key_in_reconcile_equals(s
         SubResource::Role => {
             seq_lib::seq_equal_preserved_by_add(key.name, cr_key.name, "-peer-discovery"@);
         },
+        SubResource::PodDisruptionBudget => {
+            seq_lib::seq_equal_preserved_by_add(key.name, cr_key.name, "-pdb"@);
+        },
     }
 }
 
@@ -1676,7 +1709,7 @@ pub proof fn leads_to_always_tla_forall_subresource(spec: TempPred<RMQCluster>,
         spec, p, a_to_p,
         set![SubResource::HeadlessService, SubResource::Service, SubResource::ErlangCookieSecret, SubResource::DefaultUserSecret,
         SubResource::PluginsConfigMap, SubResource::ServerConfigMap, SubResource::ServiceAccount, SubResource::Role,
-        SubResource::RoleBinding, SubResource::StatefulSet]
+        SubResource::RoleBinding, SubResource::PodDisruptionBudget, SubResource::StatefulSet]
     );
 }
 
@@ -0,0 +1,420 @@
+// Copyright 2022 VMware, Inc.
+// SPDX-License-Identifier: MIT
+#![allow(unused_imports)]
+use crate::external_api::spec::*;
+use crate::kubernetes_api_objects::spec::{
+    api_method::*, common::*, dynamic::*, owner_reference::*, resource::*, stateful_set::*,
+};
+use crate::kubernetes_cluster::spec::{
+    cluster::*,
+    cluster_state_machine::Step,
+    controller::types::{ControllerActionInput, ControllerStep},
+    message::*,
+};
+use crate::rabbitmq_controller::{
+    model::{reconciler::*, resource::*},
+    proof::{
+        helper_invariants::{owner_ref::*, predicate::*, proof::*},
+        predicate::*,
+        resource::*,
+    },
+    trusted::{
+        liveness_theorem::*, rabbitmq_api_spec::*, safety_theorem::*, spec_types::*, step::*,
+    },
+};
+use crate::temporal_logic::{defs::*, rules::*};
+use vstd::prelude::*;
+
+verus! {
+
+// The stateful set has more replicas than desired only if the cr allows scaling down and the drain check succeeds.
+// With allow_scale_down, this is exactly what drain_nodes_succeeds assumes. Without it, the stateful set never has
+// more replicas than desired (see replicas_of_etcd_stateful_set_satisfies_order).
+pub open spec fn scale_down_is_drainable(rabbitmq: RabbitmqClusterView) -> StatePred<RMQCluster> {
+    |s: RMQCluster| {
+        let key = make_stateful_set_key(rabbitmq);
+        let replicas = replicas_of_stateful_set(s.resources()[key]);
+        s.resources().contains_key(key)
+        && replicas > rabbitmq.spec.replicas
+        ==> rabbitmq.spec.allow_scale_down
+            && quorum_queues_keep_majority(rabbitmq.metadata.name.get_Some_0(), rabbitmq.metadata.namespace.get_Some_0(), replicas, rabbitmq.spec.replicas)
+    }
+}
+
+pub proof fn lemma_always_scale_down_is_drainable(spec: TempPred<RMQCluster>, rabbitmq: RabbitmqClusterView)
+    requires
+        spec.entails(always(lift_state(RMQCluster::desired_state_is(rabbitmq)))),
+        spec.entails(always(lift_state(replicas_of_etcd_stateful_set_satisfies_order(rabbitmq)))),
+        spec.entails(always(lift_state(resource_object_only_has_owner_reference_pointing_to_current_cr(SubResource::StatefulSet, rabbitmq)))),
+        rabbitmq.spec.allow_scale_down ==> spec.entails(always(lift_state(drain_nodes_succeeds::<RabbitmqMaker>(rabbitmq)))),
+    ensures spec.entails(always(lift_state(scale_down_is_drainable(rabbitmq)))),
+{
+    if rabbitmq.spec.allow_scale_down {
+        always_weaken(spec, lift_state(drain_nodes_succeeds::<RabbitmqMaker>(rabbitmq)), lift_state(scale_down_is_drainable(rabbitmq)));
+    } else {
+        let inv = |s: RMQCluster| {
+            &&& RMQCluster::desired_state_is(rabbitmq)(s)
+            &&& replicas_of_etcd_stateful_set_satisfies_order(rabbitmq)(s)
+            &&& resource_object_only_has_owner_reference_pointing_to_current_cr(SubResource::StatefulSet, rabbitmq)(s)
+        };
+        combine_spec_entails_always_n!(
+            spec, lift_state(inv),
+            lift_state(RMQCluster::desired_state_is(rabbitmq)),
+            lift_state(replicas_of_etcd_stateful_set_satisfies_order(rabbitmq)),
+            lift_state(resource_object_only_has_owner_reference_pointing_to_current_cr(SubResource::StatefulSet, rabbitmq))
+        );
+        assert forall |s: RMQCluster| #[trigger] inv(s) implies scale_down_is_drainable(rabbitmq)(s) by {
+            let key = rabbitmq.object_ref();
+            let sts_key = make_stateful_set_key(rabbitmq);
+            if s.resources().contains_key(sts_key) {
+                let cr = RabbitmqClusterView::unmarshal(s.resources()[key]).get_Ok_0();
+                assert(cr.controller_owner_ref() == rabbitmq.controller_owner_ref());
+                assert(!cr.spec.allow_scale_down);
+                assert(replicas_of_stateful_set(s.resources()[sts_key]) <= rabbitmq.spec.replicas);
+            }
+        }
+        always_weaken(spec, lift_state(inv), lift_state(scale_down_is_drainable(rabbitmq)));
+    }
+}
+
+// This function defines a replicas order for stateful set object. Here, obj can be the etcd statful set object, the object
+// in create/update stateful set object. We define this order because, the replicas in the update request is derived from
+// the triggering cr; so, in order to show the updated replicas is no larger than that of the cr, we need to show that
+// the replicas of the cr never decrease as long as allow_scale_down is unset, which transition_validation guarantees.
+// obj.metadata.owner_references_only_contains(s.ongoing_reconciles()[key].triggering_cr.controller_owner_ref()) here is to ensure that the cr
+// is still the one that creates the stateful set object. The left two comparison is to assist the last one because when the state moves
+// to the next state, the triggering_cr may be assigned (inserted or updated).
+//
+// A cr with allow_scale_down can reduce its replicas, so the order only covers the crs without it.
+pub open spec fn replicas_satisfies_order(obj: DynamicObjectView, rabbitmq: RabbitmqClusterView) -> StatePred<RMQCluster>
+    recommends
+        obj.kind.is_StatefulSetKind(),
+{
+    |s: RMQCluster| {
+        let key = rabbitmq.object_ref();
+        let sts_replicas = replicas_of_stateful_set(obj);
+        &&& s.resources().contains_key(key)
+            && !RabbitmqClusterView::unmarshal(s.resources()[key]).get_Ok_0().spec.allow_scale_down
+            && obj.metadata.owner_references_only_contains(RabbitmqClusterView::unmarshal(s.resources()[key]).get_Ok_0().controller_owner_ref())
+            ==> sts_replicas <= replicas_of_rabbitmq(s.resources()[key])
+        &&& s.scheduled_reconciles().contains_key(key)
+            && !s.scheduled_reconciles()[key].spec.allow_scale_down
+            && obj.metadata.owner_references_only_contains(s.scheduled_reconciles()[key].controller_owner_ref())
+            ==> sts_replicas <= s.scheduled_reconciles()[key].spec.replicas
+        &&& s.ongoing_reconciles().contains_key(key)
+            && !s.ongoing_reconciles()[key].triggering_cr.spec.allow_scale_down
+            && obj.metadata.owner_references_only_contains(s.ongoing_reconciles()[key].triggering_cr.controller_owner_ref())
+            ==> sts_replicas <= s.ongoing_reconciles()[key].triggering_cr.spec.replicas
+    }
+}
+
+pub open spec fn replicas_of_rabbitmq(obj: DynamicObjectView) -> int
+    recommends
+        obj.kind.is_CustomResourceKind(),
+{
+    RabbitmqClusterView::unmarshal(obj).get_Ok_0().spec.replicas
+}
+
+pub open spec fn replicas_of_etcd_stateful_set_satisfies_order(rabbitmq: RabbitmqClusterView) -> StatePred<RMQCluster> {
+    |s: RMQCluster| {
+        let sts_key = make_stateful_set_key(rabbitmq);
+        s.resources().contains_key(sts_key) ==> replicas_satisfies_order(s.resources()[sts_key], rabbitmq)(s)
+    }
+}
+
+#[verifier(spinoff_prover)]
+pub proof fn lemma_always_replicas_of_etcd_stateful_set_satisfies_order(spec: TempPred<RMQCluster>, rabbitmq: RabbitmqClusterView)
+    requires
+        spec.entails(lift_state(RMQCluster::init())),
+        spec.entails(always(lift_action(RMQCluster::next()))),
+    ensures spec.entails(always(lift_state(replicas_of_etcd_stateful_set_satisfies_order(rabbitmq)))),
+{
+    let inv = replicas_of_etcd_stateful_set_satisfies_order(rabbitmq);
+    let next = |s, s_prime| {
+        &&& RMQCluster::next()(s, s_prime)
+        &&& RMQCluster::each_object_in_etcd_is_well_formed()(s)
+        &&& RMQCluster::each_object_in_etcd_is_well_formed()(s_prime)
+        &&& every_owner_ref_of_every_object_in_etcd_has_different_uid_from_uid_counter(SubResource::StatefulSet, rabbitmq)(s)
+        &&& replicas_of_stateful_set_create_or_update_request_msg_satisfies_order(rabbitmq)(s)
+        &&& no_create_resource_request_msg_without_name_in_flight(SubResource::StatefulSet, rabbitmq)(s)
+    };
+    RMQCluster::lemma_always_each_object_in_etcd_is_well_formed(spec);
+    always_to_always_later(spec, lift_state(RMQCluster::each_object_in_etcd_is_well_formed()));
+    lemma_always_every_owner_ref_of_every_object_in_etcd_has_different_uid_from_uid_counter(spec, SubResource::StatefulSet, rabbitmq);
+    lemma_always_replicas_of_stateful_set_create_or_update_request_msg_satisfies_order(spec, rabbitmq);
+    lemma_always_no_create_resource_request_msg_without_name_in_flight(spec, SubResource::StatefulSet, rabbitmq);
+    combine_spec_entails_always_n!(
+        spec, lift_action(next), lift_action(RMQCluster::next()), lift_state(RMQCluster::each_object_in_etcd_is_well_formed()),
+        later(lift_state(RMQCluster::each_object_in_etcd_is_well_formed())),
+        lift_state(every_owner_ref_of_every_object_in_etcd_has_different_uid_from_uid_counter(SubResource::StatefulSet, rabbitmq)),
+        lift_state(replicas_of_stateful_set_create_or_update_request_msg_satisfies_order(rabbitmq)),
+        lift_state(no_create_resource_request_msg_without_name_in_flight(SubResource::StatefulSet, rabbitmq))
+    );
+    assert forall |s, s_prime| inv(s) && #[trigger] next(s, s_prime) implies inv(s_prime) by {
+        let key = rabbitmq.object_ref();
+        let sts_key = make_stateful_set_key(rabbitmq);
+        if s_prime.resources().contains_key(sts_key) {
+            if s.resources().contains_key(sts_key) && s.resources()[sts_key] == s_prime.resources()[sts_key] {
+                if s_prime.resources().contains_key(key) {
+                    if !s.resources().contains_key(key) {
+                        assert(s_prime.resources()[key].metadata.uid.get_Some_0() == s.kubernetes_api_state.uid_counter);
+                        let owner_refs = s.resources()[sts_key].metadata.owner_references;
+                        assert(owner_refs.get_Some_0()[0].uid != s.kubernetes_api_state.uid_counter);
+                        assert(owner_refs.get_Some_0()[0] != RabbitmqClusterView::unmarshal(s_prime.resources()[key]).get_Ok_0().controller_owner_ref());
+                    } else if s.resources()[key] != s_prime.resources()[key] {
+                        lemma_cr_without_allow_scale_down_never_reduces_replicas(rabbitmq, s, s_prime);
+                    }
+                }
+            } else {
+                let step = choose |step| RMQCluster::next_step(s, s_prime, step);
+                match step {
+                    Step::ApiServerStep(input) => {
+                        let req = input.get_Some_0();
+                        assert(!resource_create_request_msg_without_name(sts_key.kind, sts_key.namespace)(req));
+                    },
+                    _ => {},
+                }
+            }
+        }
+    }
+    init_invariant(spec, RMQCluster::init(), next, inv);
+}
+
+// When the cr in etcd is updated and the new one does not allow scaling down, neither did the old one (allow_scale_down
+// cannot be unset), so the replicas did not decrease.
+proof fn lemma_cr_without_allow_scale_down_never_reduces_replicas(rabbitmq: RabbitmqClusterView, s: RMQCluster, s_prime: RMQCluster)
+    requires
+        RMQCluster::next()(s, s_prime),
+        RMQCluster::each_object_in_etcd_is_well_formed()(s),
+        RMQCluster::each_object_in_etcd_is_well_formed()(s_prime),
+        s.resources().contains_key(rabbitmq.object_ref()),
+        s_prime.resources().contains_key(rabbitmq.object_ref()),
+        s.resources()[rabbitmq.object_ref()] != s_prime.resources()[rabbitmq.object_ref()],
+    ensures
+        s.resources()[rabbitmq.object_ref()].metadata.uid == s_prime.resources()[rabbitmq.object_ref()].metadata.uid,
+        !RabbitmqClusterView::unmarshal(s_prime.resources()[rabbitmq.object_ref()]).get_Ok_0().spec.allow_scale_down
+        ==> !RabbitmqClusterView::unmarshal(s.resources()[rabbitmq.object_ref()]).get_Ok_0().spec.allow_scale_down
+            && replicas_of_rabbitmq(s.resources()[rabbitmq.object_ref()]) <= replicas_of_rabbitmq(s_prime.resources()[rabbitmq.object_ref()]),
+{
+    let key = rabbitmq.object_ref();
+    let old_cr = RabbitmqClusterView::unmarshal(s.resources()[key]).get_Ok_0();
+    let new_cr = RabbitmqClusterView::unmarshal(s_prime.resources()[key]).get_Ok_0();
+    assert(s.resources()[key].metadata.uid == s_prime.resources()[key].metadata.uid);
+    assert(new_cr.transition_validation(old_cr));
+    assert(RabbitmqClusterView::unmarshal(s.resources()[key]).get_Ok_0().controller_owner_ref() == RabbitmqClusterView::unmarshal(s_prime.resources()[key]).get_Ok_0().controller_owner_ref());
+}
+
+pub open spec fn replicas_of_stateful_set_create_or_update_request_msg_satisfies_order(rabbitmq: RabbitmqClusterView) -> StatePred<RMQCluster> {
+    |s: RMQCluster| {
+        let sts_key = make_stateful_set_key(rabbitmq);
+        forall |msg: RMQMessage|
+            #[trigger] s.in_flight().contains(msg)
+            ==> (
+                resource_create_request_msg(sts_key)(msg)
+                ==> replicas_satisfies_order(msg.content.get_create_request().obj, rabbitmq)(s)
+            ) && (
+                resource_update_request_msg(sts_key)(msg)
+                ==> replicas_satisfies_order(msg.content.get_update_request().obj, rabbitmq)(s)
+            )
+    }
+}
+
+pub proof fn lemma_always_replicas_of_stateful_set_create_or_update_request_msg_satisfies_order(spec: TempPred<RMQCluster>, rabbitmq: RabbitmqClusterView)
+    requires
+        spec.entails(lift_state(RMQCluster::init())),
+        spec.entails(always(lift_action(RMQCluster::next()))),
+    ensures spec.entails(always(lift_state(replicas_of_stateful_set_create_or_update_request_msg_satisfies_order(rabbitmq)))),
+{
+    let inv = replicas_of_stateful_set_create_or_update_request_msg_satisfies_order(rabbitmq);
+    let sts_key = make_stateful_set_key(rabbitmq);
+    let next = |s, s_prime| {
+        &&& RMQCluster::next()(s, s_prime)
+        &&& RMQCluster::each_object_in_etcd_is_well_formed()(s)
+        &&& RMQCluster::each_object_in_etcd_is_well_formed()(s_prime)
+        &&& RMQCluster::each_object_in_reconcile_has_consistent_key_and_valid_metadata()(s)
+        &&& RMQCluster::transition_rule_applies_to_etcd_and_scheduled_and_triggering_cr(rabbitmq)(s)
+        &&& object_in_every_resource_create_or_update_request_msg_only_has_valid_owner_references(SubResource::StatefulSet, rabbitmq)(s)
+    };
+    RMQCluster::lemma_always_each_object_in_etcd_is_well_formed(spec);
+    always_to_always_later(spec, lift_state(RMQCluster::each_object_in_etcd_is_well_formed()));
+    RMQCluster::lemma_always_each_object_in_reconcile_has_consistent_key_and_valid_metadata(spec);
+    RMQCluster::lemma_always_transition_rule_applies_to_etcd_and_scheduled_and_triggering_cr(spec, rabbitmq);
+    lemma_always_object_in_every_resource_create_or_update_request_msg_only_has_valid_owner_references(spec, SubResource::StatefulSet, rabbitmq);
+    combine_spec_entails_always_n!(
+        spec, lift_action(next),
+        lift_action(RMQCluster::next()),
+        lift_state(RMQCluster::each_object_in_etcd_is_well_formed()),
+        later(lift_state(RMQCluster::each_object_in_etcd_is_well_formed())),
+        lift_state(RMQCluster::each_object_in_reconcile_has_consistent_key_and_valid_metadata()),
+        lift_state(RMQCluster::transition_rule_applies_to_etcd_and_scheduled_and_triggering_cr(rabbitmq)),
+        lift_state(object_in_every_resource_create_or_update_request_msg_only_has_valid_owner_references(SubResource::StatefulSet, rabbitmq))
+    );
+    assert forall |s, s_prime| inv(s) && #[trigger] next(s, s_prime) implies inv(s_prime) by {
+        assert forall |msg| #[trigger] s_prime.in_flight().contains(msg) implies (resource_create_request_msg(sts_key)(msg)
+        ==> replicas_satisfies_order(msg.content.get_create_request().obj, rabbitmq)(s_prime)) && (resource_update_request_msg(sts_key)(msg)
+        ==> replicas_satisfies_order(msg.content.get_update_request().obj, rabbitmq)(s_prime)) by {
+            if resource_create_request_msg(sts_key)(msg) {
+                replicas_of_stateful_set_create_request_msg_satisfies_order_induction(rabbitmq, s, s_prime, msg);
+            }
+            if resource_update_request_msg(sts_key)(msg) {
+                replicas_of_stateful_set_update_request_msg_satisfies_order_induction(rabbitmq, s, s_prime, msg);
+            }
+        }
+    }
+    init_invariant(spec, RMQCluster::init(), next, inv);
+}
+
+proof fn replicas_of_stateful_set_create_request_msg_satisfies_order_induction(
+    rabbitmq: RabbitmqClusterView, s: RMQCluster, s_prime: RMQCluster, msg: RMQMessage
+)
+    requires
+        RMQCluster::next()(s, s_prime),
+        RMQCluster::each_object_in_etcd_is_well_formed()(s),
+        RMQCluster::each_object_in_etcd_is_well_formed()(s_prime),
+        RMQCluster::each_object_in_reconcile_has_consistent_key_and_valid_metadata()(s),
+        RMQCluster::transition_rule_applies_to_etcd_and_scheduled_and_triggering_cr(rabbitmq)(s),
+        object_in_every_resource_create_or_update_request_msg_only_has_valid_owner_references(SubResource::StatefulSet, rabbitmq)(s),
+        replicas_of_stateful_set_create_or_update_request_msg_satisfies_order(rabbitmq)(s),
+        s_prime.in_flight().contains(msg),
+        resource_create_request_msg(make_stateful_set_key(rabbitmq))(msg),
+    ensures replicas_satisfies_order(msg.content.get_create_request().obj, rabbitmq)(s_prime),
+{
+    let step = choose |step| RMQCluster::next_step(s, s_prime, step);
+    let key = rabbitmq.object_ref();
+    let sts_key = make_stateful_set_key(rabbitmq);
+    match step {
+        Step::ApiServerStep(input) => {
+            assert(s.controller_state == s_prime.controller_state);
+            assert(s.in_flight().contains(msg));
+            if s_prime.resources().contains_key(key) {
+                if s.resources().contains_key(key) {
+                    if s.resources()[key] != s_prime.resources()[key] {
+                        lemma_cr_without_allow_scale_down_never_reduces_replicas(rabbitmq, s, s_prime);
+                    }
+                } else {
+                    let owner_refs = msg.content.get_create_request().obj.metadata.owner_references;
+                    assert(owner_refs.is_Some() && owner_refs.get_Some_0().len() == 1);
+                    assert(owner_refs.get_Some_0()[0].uid < s.kubernetes_api_state.uid_counter);
+                    assert(owner_refs.get_Some_0()[0] != RabbitmqClusterView::unmarshal(s_prime.resources()[key]).get_Ok_0().controller_owner_ref());
+                    assert(!msg.content.get_create_request().obj.metadata.owner_references_only_contains(RabbitmqClusterView::unmarshal(s_prime.resources()[key]).get_Ok_0().controller_owner_ref()));
+                }
+            }
+        },
+        Step::ControllerStep(input) => {
+            if !s.in_flight().contains(msg) {
+                StatefulSetView::marshal_preserves_integrity();
+                StatefulSetView::marshal_spec_preserves_integrity();
+                lemma_resource_create_request_msg_implies_key_in_reconcile_equals(SubResource::StatefulSet, rabbitmq, s, s_prime, msg, step);
+                lemma_triggering_cr_bounds_replicas_of_the_other_crs(rabbitmq, s_prime);
+            }
+        },
+        _ => {
+            assert(s.in_flight().contains(msg));
+            assert(s.resources().contains_key(key) == s_prime.resources().contains_key(key));
+            if s.resources().contains_key(key) {
+                assert(s.resources()[key] == s_prime.resources()[key]);
+            }
+        },
+    }
+}
+
+proof fn replicas_of_stateful_set_update_request_msg_satisfies_order_induction(
+    rabbitmq: RabbitmqClusterView, s: RMQCluster, s_prime: RMQCluster, msg: RMQMessage
+)
+    requires
+        RMQCluster::next()(s, s_prime),
+        RMQCluster::each_object_in_etcd_is_well_formed()(s),
+        RMQCluster::each_object_in_etcd_is_well_formed()(s_prime),
+        RMQCluster::each_object_in_reconcile_has_consistent_key_and_valid_metadata()(s),
+        RMQCluster::transition_rule_applies_to_etcd_and_scheduled_and_triggering_cr(rabbitmq)(s),
+        object_in_every_resource_create_or_update_request_msg_only_has_valid_owner_references(SubResource::StatefulSet, rabbitmq)(s),
+        replicas_of_stateful_set_create_or_update_request_msg_satisfies_order(rabbitmq)(s),
+        s_prime.in_flight().contains(msg),
+        resource_update_request_msg(make_stateful_set_key(rabbitmq))(msg),
+    ensures replicas_satisfies_order(msg.content.get_update_request().obj, rabbitmq)(s_prime),
+{
+    let step = choose |step| RMQCluster::next_step(s, s_prime, step);
+    let key = rabbitmq.object_ref();
+    let sts_key = make_stateful_set_key(rabbitmq);
+    match step {
+        Step::ApiServerStep(input) => {
+            assert(s.in_flight().contains(msg));
+            assert(s.controller_state == s_prime.controller_state);
+            if s_prime.resources().contains_key(key) {
+                if s.resources().contains_key(key) {
+                    if s.resources()[key] != s_prime.resources()[key] {
+                        lemma_cr_without_allow_scale_down_never_reduces_replicas(rabbitmq, s, s_prime);
+                    }
+                } else {
+                    let owner_refs = msg.content.get_update_request().obj.metadata.owner_references;
+                    assert(owner_refs.is_Some() && owner_refs.get_Some_0().len() == 1);
+                    assert(owner_refs.get_Some_0()[0].uid < s.kubernetes_api_state.uid_counter);
+                    assert(owner_refs.get_Some_0()[0] != RabbitmqClusterView::unmarshal(s_prime.resources()[key]).get_Ok_0().controller_owner_ref());
+                    assert(!msg.content.get_update_request().obj.metadata.owner_references_only_contains(RabbitmqClusterView::unmarshal(s_prime.resources()[key]).get_Ok_0().controller_owner_ref()));
+                }
+            }
+        },
+        Step::ControllerStep(input) => {
+            if !s.in_flight().contains(msg) {
+                StatefulSetView::marshal_preserves_integrity();
+                StatefulSetView::marshal_spec_preserves_integrity();
+                lemma_resource_update_request_msg_implies_key_in_reconcile_equals(SubResource::StatefulSet, rabbitmq, s, s_prime, msg, step);
+                lemma_triggering_cr_bounds_replicas_of_the_other_crs(rabbitmq, s_prime);
+            }
+        },
+        _ => {
+            assert(s.in_flight().contains(msg));
+            assert(s.resources().contains_key(key) == s_prime.resources().contains_key(key));
+            if s.resources().contains_key(key) {
+                assert(s.resources()[key] == s_prime.resources()[key]);
+            }
+        },
+    }
+}
+
+// A new create/update request has the replicas of the triggering cr. The cr in etcd and the scheduled cr with the same uid
+// are newer than the triggering cr, so if they do not allow scaling down, neither does the triggering cr and they have no
+// fewer replicas than it.
+proof fn lemma_triggering_cr_bounds_replicas_of_the_other_crs(rabbitmq: RabbitmqClusterView, s: RMQCluster)
+    requires
+        RMQCluster::transition_rule_applies_to_etcd_and_scheduled_and_triggering_cr(rabbitmq)(s),
+        RMQCluster::each_object_in_etcd_is_well_formed()(s),
+        s.ongoing_reconciles().contains_key(rabbitmq.object_ref()),
+    ensures
+        ({
+            let key = rabbitmq.object_ref();
+            let triggering_cr = s.ongoing_reconciles()[key].triggering_cr;
+            let etcd_cr = RabbitmqClusterView::unmarshal(s.resources()[key]).get_Ok_0();
+            s.resources().contains_key(key)
+            && etcd_cr.controller_owner_ref() == triggering_cr.controller_owner_ref()
+            && !etcd_cr.spec.allow_scale_down
+            ==> !triggering_cr.spec.allow_scale_down && triggering_cr.spec.replicas <= etcd_cr.spec.replicas
+        }),
+        ({
+            let key = rabbitmq.object_ref();
+            let triggering_cr = s.ongoing_reconciles()[key].triggering_cr;
+            let scheduled_cr = s.scheduled_reconciles()[key];
+            s.scheduled_reconciles().contains_key(key)
+            && scheduled_cr.controller_owner_ref() == triggering_cr.controller_owner_ref()
+            && !scheduled_cr.spec.allow_scale_down
+            ==> !triggering_cr.spec.allow_scale_down && triggering_cr.spec.replicas <= scheduled_cr.spec.replicas
+        }),
+{
+    let key = rabbitmq.object_ref();
+    let triggering_cr = s.ongoing_reconciles()[key].triggering_cr;
+    if s.resources().contains_key(key) {
+        let etcd_cr = RabbitmqClusterView::unmarshal(s.resources()[key]).get_Ok_0();
+        if etcd_cr.controller_owner_ref() == triggering_cr.controller_owner_ref() {
+            assert(s.resources()[key].metadata.uid.get_Some_0() == triggering_cr.metadata.uid.get_Some_0());
+            assert(etcd_cr.transition_validation(triggering_cr));
+        }
+    }
+    if s.scheduled_reconciles().contains_key(key) {
+        let scheduled_cr = s.scheduled_reconciles()[key];
+        if scheduled_cr.controller_owner_ref() == triggering_cr.controller_owner_ref() {
+            assert(scheduled_cr.transition_validation(triggering_cr));
+        }
+    }
+}
+
+}
@@ -36,7 +36,8 @@ use vstd::{prelude::*, string::*};
 
 verus! {
 
-// We prove init /\ []next /\ []wf |= []RMQCluster::desired_state_is(rabbitmq) ~> []current_state_matches(rabbitmq) holds for each rabbitmq.
+// We prove init /\ []next /\ []wf |= []RMQCluster::desired_state_is(rabbitmq) ~> []current_state_matches(rabbitmq) holds for each rabbitmq,
+// with the additional premise []drain_nodes_succeeds(rabbitmq) if rabbitmq allows scaling down.
 proof fn liveness_proof_forall_rabbitmq()
     ensures liveness_theorem::<RabbitmqMaker>(),
 {
@@ -60,13 +61,13 @@ proof fn liveness_proof(rabbitmq: RabbitmqClusterView)
     spec_before_phase_n_entails_true_leads_to_current_state_matches(2, rabbitmq);
     spec_before_phase_n_entails_true_leads_to_current_state_matches(1, rabbitmq);
 
-    let assumption = always(lift_state(RMQCluster::desired_state_is(rabbitmq)));
+    let assumption = assumptions(rabbitmq);
     unpack_conditions_from_spec(invariants(rabbitmq), assumption, true_pred(), always(lift_state(current_state_matches::<RabbitmqMaker>(rabbitmq))));
     temp_pred_equality(true_pred().and(assumption), assumption);
 
     entails_trans(
         cluster_spec().and(derived_invariants_since_beginning(rabbitmq)), invariants(rabbitmq),
-        always(lift_state(RMQCluster::desired_state_is(rabbitmq))).leads_to(always(lift_state(current_state_matches::<RabbitmqMaker>(rabbitmq))))
+        assumptions(rabbitmq).leads_to(always(lift_state(current_state_matches::<RabbitmqMaker>(rabbitmq))))
     );
     sm_spec_entails_all_invariants(rabbitmq);
     simplify_predicate(cluster_spec(), derived_invariants_since_beginning(rabbitmq));
@@ -121,6 +122,7 @@ proof fn lemma_true_leads_to_always_state_matches_for_all_but_stateful_set(rabbi
         ==> assumption_and_invariants_of_all_phases(rabbitmq).entails(true_pred().leads_to(always(lift_state(#[trigger] sub_resource_state_matches(sub_resource, rabbitmq))))),
 {
     let spec = assumption_and_invariants_of_all_phases(rabbitmq);
+    unpack_assumptions(spec, rabbitmq);
 
     assert forall |action: ActionKind, sub_resource: SubResource| #![auto] spec.entails(always(lift_state(RMQCluster::pending_req_in_flight_or_resp_in_flight_at_reconcile_state(rabbitmq.object_ref(), at_step_closure(RabbitmqReconcileStep::AfterKRequestStep(action, sub_resource)))))) by {
         always_tla_forall_apply(spec, |step: (ActionKind, SubResource)| lift_state(RMQCluster::pending_req_in_flight_or_resp_in_flight_at_reconcile_state(rabbitmq.object_ref(), at_step_closure(RabbitmqReconcileStep::AfterKRequestStep(step.0, step.1)))), (action, sub_resource));
@@ -165,7 +167,8 @@ proof fn lemma_true_leads_to_always_state_matches_for_all_but_stateful_set(rabbi
         lift_state(pending_req_in_flight_at_after_get_resource_step(SubResource::ServerConfigMap, rabbitmq)),
         lift_state(pending_req_in_flight_at_after_get_resource_step(SubResource::ServiceAccount, rabbitmq)),
         lift_state(pending_req_in_flight_at_after_get_resource_step(SubResource::Role, rabbitmq)),
-        lift_state(pending_req_in_flight_at_after_get_resource_step(SubResource::RoleBinding, rabbitmq))
+        lift_state(pending_req_in_flight_at_after_get_resource_step(SubResource::RoleBinding, rabbitmq)),
+        lift_state(pending_req_in_flight_at_after_get_resource_step(SubResource::PodDisruptionBudget, rabbitmq))
     );
 
     // Since we already have true ~> at_after_get_resource_step(sub_resource), and we can get at_after_get_resource_step(sub_resource)
@@ -211,6 +214,10 @@ proof fn lemma_true_leads_to_always_state_matches_for_stateful_set(rabbitmq: Rab
     let spec = spec2.and(always(lift_state(helper_invariants::stateful_set_not_exists_or_matches_or_no_more_status_update(rabbitmq))));
     always_tla_forall_apply_for_sub_resource(spec, SubResource::ServerConfigMap, rabbitmq);
     always_tla_forall_apply_for_sub_resource(spec, SubResource::StatefulSet, rabbitmq);
+    // Without allow_scale_down, the stateful set never has more replicas than desired, otherwise drain_nodes_succeeds
+    // tells that the drain succeeds; either way, the stateful set can always be scaled to the desired replicas.
+    unpack_assumptions(spec, rabbitmq);
+    helper_invariants::lemma_always_scale_down_is_drainable(spec, rabbitmq);
 
     assert_by(valid(stable(spec1)), {
         assumption_and_invariants_of_all_phases_is_stable(rabbitmq);
@@ -255,6 +262,7 @@ proof fn lemma_true_leads_to_always_state_matches_for_stateful_set(rabbitmq: Rab
             lift_state(pending_req_in_flight_at_after_get_resource_step(SubResource::ServiceAccount, rabbitmq)),
             lift_state(pending_req_in_flight_at_after_get_resource_step(SubResource::Role, rabbitmq)),
             lift_state(pending_req_in_flight_at_after_get_resource_step(SubResource::RoleBinding, rabbitmq)),
+            lift_state(pending_req_in_flight_at_after_get_resource_step(SubResource::PodDisruptionBudget, rabbitmq)),
             lift_state(pending_req_in_flight_at_after_get_resource_step(SubResource::StatefulSet, rabbitmq))
         );
         // We then prove pending_req_in_flight_at_after_get_resource_step(SubResource::StatefulSet, rabbitmq) ~> sub_resource_state_matches(SubResource::StatefulSet, rabbitmq)
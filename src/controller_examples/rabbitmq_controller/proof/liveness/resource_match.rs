@@ -659,15 +659,28 @@ proof fn lemma_resource_state_matches_at_after_create_resource_step(
         assert(s_prime.in_flight().contains(resp));
         match sub_resource {
             SubResource::HeadlessService => ServiceView::marshal_preserves_integrity(),
-            SubResource::Service => ServiceView::marshal_preserves_integrity(),
-            SubResource::ErlangCookieSecret => SecretView::marshal_preserves_integrity(),
-            SubResource::DefaultUserSecret => SecretView::marshal_preserves_integrity(),
+            SubResource::Service => {
+                ServiceView::marshal_preserves_integrity();
+                lemma_made_main_service_exposes_tls_ports(rabbitmq);
+            },
+            SubResource::ErlangCookieSecret => {
+                SecretView::marshal_preserves_integrity();
+                SecretView::lemma_merge_string_data_in_spec_preserves_spec_without_string_data(SecretView::unmarshal(pending_msg.content.get_create_request().obj).get_Ok_0());
+            },
+            SubResource::DefaultUserSecret => {
+                SecretView::marshal_preserves_integrity();
+                SecretView::lemma_merge_string_data_in_spec_preserves_spec_without_string_data(SecretView::unmarshal(pending_msg.content.get_create_request().obj).get_Ok_0());
+            },
             SubResource::PluginsConfigMap => ConfigMapView::marshal_preserves_integrity(),
             SubResource::ServerConfigMap => ConfigMapView::marshal_preserves_integrity(),
             SubResource::ServiceAccount => ServiceAccountView::marshal_preserves_integrity(),
             SubResource::Role => RoleView::marshal_preserves_integrity(),
             SubResource::RoleBinding => RoleBindingView::marshal_preserves_integrity(),
-            SubResource::StatefulSet => StatefulSetView::marshal_preserves_integrity(),
+            SubResource::PodDisruptionBudget => PodDisruptionBudgetView::marshal_preserves_integrity(),
+            SubResource::StatefulSet => {
+                StatefulSetView::marshal_preserves_integrity();
+                lemma_made_stateful_set_mounts_tls_secrets(rabbitmq);
+            },
         }
     }
 
@@ -845,15 +858,28 @@ proof fn lemma_resource_state_matches_at_after_update_resource_step(
         assert(s_prime.in_flight().contains(resp));
         match sub_resource {
             SubResource::HeadlessService => ServiceView::marshal_preserves_integrity(),
-            SubResource::Service => ServiceView::marshal_preserves_integrity(),
-            SubResource::ErlangCookieSecret => SecretView::marshal_preserves_integrity(),
-            SubResource::DefaultUserSecret => SecretView::marshal_preserves_integrity(),
+            SubResource::Service => {
+                ServiceView::marshal_preserves_integrity();
+                lemma_made_main_service_exposes_tls_ports(rabbitmq);
+            },
+            SubResource::ErlangCookieSecret => {
+                SecretView::marshal_preserves_integrity();
+                SecretView::lemma_merge_string_data_in_spec_preserves_spec_without_string_data(SecretView::unmarshal(pending_msg.content.get_update_request().obj).get_Ok_0());
+            },
+            SubResource::DefaultUserSecret => {
+                SecretView::marshal_preserves_integrity();
+                SecretView::lemma_merge_string_data_in_spec_preserves_spec_without_string_data(SecretView::unmarshal(pending_msg.content.get_update_request().obj).get_Ok_0());
+            },
             SubResource::PluginsConfigMap => ConfigMapView::marshal_preserves_integrity(),
             SubResource::ServerConfigMap => ConfigMapView::marshal_preserves_integrity(),
             SubResource::ServiceAccount => ServiceAccountView::marshal_preserves_integrity(),
             SubResource::Role => RoleView::marshal_preserves_integrity(),
             SubResource::RoleBinding => RoleBindingView::marshal_preserves_integrity(),
-            SubResource::StatefulSet => StatefulSetView::marshal_preserves_integrity(),
+            SubResource::PodDisruptionBudget => PodDisruptionBudgetView::marshal_preserves_integrity(),
+            SubResource::StatefulSet => {
+                StatefulSetView::marshal_preserves_integrity();
+                lemma_made_stateful_set_mounts_tls_secrets(rabbitmq);
+            },
         }
     }
 
@@ -1009,6 +1035,7 @@ pub proof fn lemma_resource_object_is_stable(
             SubResource::ServiceAccount => ServiceAccountView::marshal_preserves_integrity(),
             SubResource::Role => RoleView::marshal_preserves_integrity(),
             SubResource::RoleBinding => RoleBindingView::marshal_preserves_integrity(),
+            SubResource::PodDisruptionBudget => PodDisruptionBudgetView::marshal_preserves_integrity(),
             _ => {}
         }
     }
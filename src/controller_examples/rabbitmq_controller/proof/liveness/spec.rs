@@ -25,7 +25,7 @@ verus! {
 
 pub open spec fn assumption_and_invariants_of_all_phases(rabbitmq: RabbitmqClusterView) -> TempPred<RMQCluster> {
     invariants(rabbitmq)
-    .and(always(lift_state(desired_state_is(rabbitmq))))
+    .and(assumptions(rabbitmq))
     .and(invariants_since_phase_i(rabbitmq))
     .and(invariants_since_phase_ii(rabbitmq))
     .and(invariants_since_phase_iii(rabbitmq))
@@ -35,9 +35,45 @@ pub open spec fn assumption_and_invariants_of_all_phases(rabbitmq: RabbitmqClust
     .and(invariants_since_phase_vii(rabbitmq))
 }
 
+// The assumptions of the liveness property: the desired state stays unchanged and, if the cr allows scaling down,
+// the drain for scaling down succeeds. They are the premises of liveness.
+pub open spec fn assumptions(rabbitmq: RabbitmqClusterView) -> TempPred<RMQCluster> {
+    if rabbitmq.spec.allow_scale_down {
+        always(lift_state(desired_state_is(rabbitmq))).and(always(lift_state(drain_nodes_succeeds::<RabbitmqMaker>(rabbitmq))))
+    } else {
+        always(lift_state(desired_state_is(rabbitmq)))
+    }
+}
+
+pub proof fn assumptions_is_stable(rabbitmq: RabbitmqClusterView)
+    ensures valid(stable(assumptions(rabbitmq))),
+{
+    always_p_is_stable(lift_state(desired_state_is(rabbitmq)));
+    if rabbitmq.spec.allow_scale_down {
+        always_p_is_stable(lift_state(drain_nodes_succeeds::<RabbitmqMaker>(rabbitmq)));
+        stable_and_n!(always(lift_state(desired_state_is(rabbitmq))), always(lift_state(drain_nodes_succeeds::<RabbitmqMaker>(rabbitmq))));
+    }
+}
+
+pub proof fn unpack_assumptions(spec: TempPred<RMQCluster>, rabbitmq: RabbitmqClusterView)
+    requires spec.entails(assumptions(rabbitmq)),
+    ensures
+        spec.entails(always(lift_state(desired_state_is(rabbitmq)))),
+        rabbitmq.spec.allow_scale_down ==> spec.entails(always(lift_state(drain_nodes_succeeds::<RabbitmqMaker>(rabbitmq)))),
+{
+    assert forall |ex| #[trigger] spec.satisfied_by(ex) implies always(lift_state(desired_state_is(rabbitmq))).satisfied_by(ex) by {
+        implies_apply(ex, spec, assumptions(rabbitmq));
+    }
+    if rabbitmq.spec.allow_scale_down {
+        assert forall |ex| #[trigger] spec.satisfied_by(ex) implies always(lift_state(drain_nodes_succeeds::<RabbitmqMaker>(rabbitmq))).satisfied_by(ex) by {
+            implies_apply(ex, spec, assumptions(rabbitmq));
+        }
+    }
+}
+
 pub open spec fn invariants_since_phase_n(n: nat, rabbitmq: RabbitmqClusterView) -> TempPred<RMQCluster> {
     if n == 0 {
-        invariants(rabbitmq).and(always(lift_state(desired_state_is(rabbitmq))))
+        invariants(rabbitmq).and(assumptions(rabbitmq))
     } else if n == 1 {
         invariants_since_phase_i(rabbitmq)
     } else if n == 2 {
@@ -61,7 +97,7 @@ pub open spec fn spec_before_phase_n(n: nat, rabbitmq: RabbitmqClusterView) -> T
     decreases n,
 {
     if n == 1 {
-        invariants(rabbitmq).and(always(lift_state(desired_state_is(rabbitmq))))
+        invariants(rabbitmq).and(assumptions(rabbitmq))
     } else if 2 <= n <= 8 {
         spec_before_phase_n((n-1) as nat, rabbitmq).and(invariants_since_phase_n((n-1) as nat, rabbitmq))
     } else {
@@ -75,6 +111,7 @@ pub proof fn spec_of_previous_phases_entails_eventually_new_invariants(i: nat, r
 {
     let spec = spec_before_phase_n(i, rabbitmq);
     reveal_with_fuel(spec_before_phase_n, 8);
+    unpack_assumptions(spec, rabbitmq);
     if i == 1 {
         RMQCluster::lemma_true_leads_to_crash_always_disabled(spec);
         RMQCluster::lemma_true_leads_to_busy_always_disabled(spec);
@@ -126,7 +163,7 @@ pub proof fn assumption_and_invariants_of_all_phases_is_stable(rabbitmq: Rabbitm
 {
     reveal_with_fuel(spec_before_phase_n, 8);
     invariants_is_stable(rabbitmq);
-    always_p_is_stable(lift_state(desired_state_is(rabbitmq)));
+    assumptions_is_stable(rabbitmq);
     invariants_since_phase_i_is_stable(rabbitmq);
     invariants_since_phase_ii_is_stable(rabbitmq);
     invariants_since_phase_iii_is_stable(rabbitmq);
@@ -135,7 +172,7 @@ pub proof fn assumption_and_invariants_of_all_phases_is_stable(rabbitmq: Rabbitm
     invariants_since_phase_vi_is_stable(rabbitmq);
     invariants_since_phase_vii_is_stable(rabbitmq);
     stable_and_n!(
-        invariants(rabbitmq), always(lift_state(desired_state_is(rabbitmq))),
+        invariants(rabbitmq), assumptions(rabbitmq),
         invariants_since_phase_i(rabbitmq), invariants_since_phase_ii(rabbitmq), invariants_since_phase_iii(rabbitmq),
         invariants_since_phase_iv(rabbitmq), invariants_since_phase_v(rabbitmq), invariants_since_phase_vi(rabbitmq),
         invariants_since_phase_vii(rabbitmq)
@@ -180,8 +217,8 @@ pub proof fn next_with_wf_is_stable()
 // This predicate combines all the possible actions (next), weak fairness and invariants that hold throughout the execution.
 // We name it invariants here because these predicates are never violated, thus they can all be seen as some kind of invariants.
 //
-// The final goal of our proof is to show init /\ invariants |= []desired_state_is(cr) ~> []current_state_matches(cr).
-// init /\ invariants is equivalent to init /\ next /\ weak_fairness, so we get cluster_spec() |= []desired_state_is(cr) ~> []current_state_matches(cr).
+// The final goal of our proof is to show init /\ invariants |= assumptions(cr) ~> []current_state_matches(cr).
+// init /\ invariants is equivalent to init /\ next /\ weak_fairness, so we get cluster_spec() |= assumptions(cr) ~> []current_state_matches(cr).
 pub open spec fn invariants(rabbitmq: RabbitmqClusterView) -> TempPred<RMQCluster> {
     next_with_wf().and(derived_invariants_since_beginning(rabbitmq))
 }
@@ -209,10 +246,15 @@ pub open spec fn derived_invariants_since_beginning(rabbitmq: RabbitmqClusterVie
     .and(always(lift_state(RMQCluster::each_object_in_reconcile_has_consistent_key_and_valid_metadata())))
     .and(always(tla_forall(|sub_resource: SubResource| lift_state(helper_invariants::resource_object_has_no_finalizers_or_timestamp_and_only_has_controller_owner_ref(sub_resource, rabbitmq)))))
     .and(always(lift_state(RMQCluster::no_pending_req_msg_at_reconcile_state(rabbitmq.object_ref(), at_step_closure(RabbitmqReconcileStep::Init)))))
+    .and(always(lift_state(RMQCluster::pending_req_in_flight_or_resp_in_flight_at_reconcile_state(rabbitmq.object_ref(), at_step_closure(RabbitmqReconcileStep::AfterUpdateStatus)))))
+    .and(always(lift_state(RMQCluster::pending_req_in_flight_or_resp_in_flight_at_reconcile_state(rabbitmq.object_ref(), at_step_closure(RabbitmqReconcileStep::AfterUpdateDegradedStatus)))))
+    .and(always(lift_state(RMQCluster::pending_req_in_flight_or_resp_in_flight_at_reconcile_state(rabbitmq.object_ref(), at_step_closure(RabbitmqReconcileStep::AfterSetPlugins)))))
+    .and(always(lift_state(RMQCluster::pending_req_in_flight_or_resp_in_flight_at_reconcile_state(rabbitmq.object_ref(), at_step_closure(RabbitmqReconcileStep::AfterDrainNodes)))))
     .and(always(tla_forall(|step: (ActionKind, SubResource)| lift_state(RMQCluster::pending_req_in_flight_or_resp_in_flight_at_reconcile_state(rabbitmq.object_ref(), at_step_closure(RabbitmqReconcileStep::AfterKRequestStep(step.0, step.1)))))))
     .and(always(tla_forall(|res: SubResource| lift_state(helper_invariants::no_update_status_request_msg_in_flight_of_except_stateful_set(res, rabbitmq)))))
     .and(always(lift_state(helper_invariants::no_update_status_request_msg_not_from_bc_in_flight_of_stateful_set(rabbitmq))))
     .and(always(lift_state(helper_invariants::the_object_in_reconcile_satisfies_state_validation(rabbitmq.object_ref()))))
+    .and(always(lift_state(helper_invariants::drained_replicas_in_reconcile_state_is_none_or_desired_replicas(rabbitmq.object_ref()))))
     .and(always(lift_state(RMQCluster::key_of_object_in_matched_ok_get_resp_message_is_same_as_key_of_pending_req(rabbitmq.object_ref()))))
     .and(always(lift_state(RMQCluster::key_of_object_in_matched_ok_create_resp_message_is_same_as_key_of_pending_req(rabbitmq.object_ref()))))
     .and(always(lift_state(RMQCluster::key_of_object_in_matched_ok_update_resp_message_is_same_as_key_of_pending_req(rabbitmq.object_ref()))))
@@ -221,6 +263,7 @@ pub open spec fn derived_invariants_since_beginning(rabbitmq: RabbitmqClusterVie
     .and(always(lift_state(helper_invariants::stateful_set_in_etcd_satisfies_unchangeable(rabbitmq))))
     .and(always(tla_forall(|sub_resource: SubResource| lift_state(helper_invariants::object_in_etcd_satisfies_unchangeable(sub_resource, rabbitmq)))))
     .and(always(tla_forall(|sub_resource: SubResource| lift_state(helper_invariants::no_create_resource_request_msg_without_name_in_flight(sub_resource, rabbitmq)))))
+    .and(always(lift_state(helper_invariants::replicas_of_etcd_stateful_set_satisfies_order(rabbitmq))))
 }
 
 pub proof fn derived_invariants_since_beginning_is_stable(rabbitmq: RabbitmqClusterView)
@@ -244,10 +287,15 @@ pub proof fn derived_invariants_since_beginning_is_stable(rabbitmq: RabbitmqClus
         lift_state(RMQCluster::each_object_in_reconcile_has_consistent_key_and_valid_metadata()),
         tla_forall(a_to_p_1),
         lift_state(RMQCluster::no_pending_req_msg_at_reconcile_state(rabbitmq.object_ref(), at_step_closure(RabbitmqReconcileStep::Init))),
+        lift_state(RMQCluster::pending_req_in_flight_or_resp_in_flight_at_reconcile_state(rabbitmq.object_ref(), at_step_closure(RabbitmqReconcileStep::AfterUpdateStatus))),
+        lift_state(RMQCluster::pending_req_in_flight_or_resp_in_flight_at_reconcile_state(rabbitmq.object_ref(), at_step_closure(RabbitmqReconcileStep::AfterUpdateDegradedStatus))),
+        lift_state(RMQCluster::pending_req_in_flight_or_resp_in_flight_at_reconcile_state(rabbitmq.object_ref(), at_step_closure(RabbitmqReconcileStep::AfterSetPlugins))),
+        lift_state(RMQCluster::pending_req_in_flight_or_resp_in_flight_at_reconcile_state(rabbitmq.object_ref(), at_step_closure(RabbitmqReconcileStep::AfterDrainNodes))),
         tla_forall(a_to_p_2),
         tla_forall(a_to_p_3),
         lift_state(helper_invariants::no_update_status_request_msg_not_from_bc_in_flight_of_stateful_set(rabbitmq)),
         lift_state(helper_invariants::the_object_in_reconcile_satisfies_state_validation(rabbitmq.object_ref())),
+        lift_state(helper_invariants::drained_replicas_in_reconcile_state_is_none_or_desired_replicas(rabbitmq.object_ref())),
         lift_state(RMQCluster::key_of_object_in_matched_ok_get_resp_message_is_same_as_key_of_pending_req(rabbitmq.object_ref())),
         lift_state(RMQCluster::key_of_object_in_matched_ok_create_resp_message_is_same_as_key_of_pending_req(rabbitmq.object_ref())),
         lift_state(RMQCluster::key_of_object_in_matched_ok_update_resp_message_is_same_as_key_of_pending_req(rabbitmq.object_ref())),
@@ -255,7 +303,8 @@ pub proof fn derived_invariants_since_beginning_is_stable(rabbitmq: RabbitmqClus
         tla_forall(a_to_p_5),
         lift_state(helper_invariants::stateful_set_in_etcd_satisfies_unchangeable(rabbitmq)),
         tla_forall(a_to_p_6),
-        tla_forall(a_to_p_7)
+        tla_forall(a_to_p_7),
+        lift_state(helper_invariants::replicas_of_etcd_stateful_set_satisfies_order(rabbitmq))
     );
 }
 
@@ -362,6 +411,46 @@ pub proof fn invariants_since_phase_vii_is_stable(rabbitmq: RabbitmqClusterView)
     always_p_is_stable(lift_state(helper_invariants::cm_rv_is_the_same_as_etcd_server_cm_if_cm_updated(rabbitmq)));
 }
 
+pub proof fn lemma_always_for_after_update_status_step_pending_req_in_flight_or_resp_in_flight_at_reconcile_state(spec: TempPred<RMQCluster>, rabbitmq: RabbitmqClusterView)
+    requires
+        spec.entails(lift_state(RMQCluster::init())),
+        spec.entails(always(lift_action(RMQCluster::next()))),
+        spec.entails(always(lift_state(RMQCluster::pending_req_of_key_is_unique_with_unique_id(rabbitmq.object_ref())))),
+    ensures spec.entails(always(lift_state(RMQCluster::pending_req_in_flight_or_resp_in_flight_at_reconcile_state(rabbitmq.object_ref(), at_step_closure(RabbitmqReconcileStep::AfterUpdateStatus))))),
+{
+    RMQCluster::lemma_always_pending_req_in_flight_or_resp_in_flight_at_reconcile_state(spec, rabbitmq.object_ref(), at_step_closure(RabbitmqReconcileStep::AfterUpdateStatus));
+}
+
+pub proof fn lemma_always_for_after_update_degraded_status_step_pending_req_in_flight_or_resp_in_flight_at_reconcile_state(spec: TempPred<RMQCluster>, rabbitmq: RabbitmqClusterView)
+    requires
+        spec.entails(lift_state(RMQCluster::init())),
+        spec.entails(always(lift_action(RMQCluster::next()))),
+        spec.entails(always(lift_state(RMQCluster::pending_req_of_key_is_unique_with_unique_id(rabbitmq.object_ref())))),
+    ensures spec.entails(always(lift_state(RMQCluster::pending_req_in_flight_or_resp_in_flight_at_reconcile_state(rabbitmq.object_ref(), at_step_closure(RabbitmqReconcileStep::AfterUpdateDegradedStatus))))),
+{
+    RMQCluster::lemma_always_pending_req_in_flight_or_resp_in_flight_at_reconcile_state(spec, rabbitmq.object_ref(), at_step_closure(RabbitmqReconcileStep::AfterUpdateDegradedStatus));
+}
+
+pub proof fn lemma_always_for_after_set_plugins_step_pending_req_in_flight_or_resp_in_flight_at_reconcile_state(spec: TempPred<RMQCluster>, rabbitmq: RabbitmqClusterView)
+    requires
+        spec.entails(lift_state(RMQCluster::init())),
+        spec.entails(always(lift_action(RMQCluster::next()))),
+        spec.entails(always(lift_state(RMQCluster::pending_req_of_key_is_unique_with_unique_id(rabbitmq.object_ref())))),
+    ensures spec.entails(always(lift_state(RMQCluster::pending_req_in_flight_or_resp_in_flight_at_reconcile_state(rabbitmq.object_ref(), at_step_closure(RabbitmqReconcileStep::AfterSetPlugins))))),
+{
+    RMQCluster::lemma_always_pending_req_in_flight_or_resp_in_flight_at_reconcile_state(spec, rabbitmq.object_ref(), at_step_closure(RabbitmqReconcileStep::AfterSetPlugins));
+}
+
+pub proof fn lemma_always_for_after_drain_nodes_step_pending_req_in_flight_or_resp_in_flight_at_reconcile_state(spec: TempPred<RMQCluster>, rabbitmq: RabbitmqClusterView)
+    requires
+        spec.entails(lift_state(RMQCluster::init())),
+        spec.entails(always(lift_action(RMQCluster::next()))),
+        spec.entails(always(lift_state(RMQCluster::pending_req_of_key_is_unique_with_unique_id(rabbitmq.object_ref())))),
+    ensures spec.entails(always(lift_state(RMQCluster::pending_req_in_flight_or_resp_in_flight_at_reconcile_state(rabbitmq.object_ref(), at_step_closure(RabbitmqReconcileStep::AfterDrainNodes))))),
+{
+    RMQCluster::lemma_always_pending_req_in_flight_or_resp_in_flight_at_reconcile_state(spec, rabbitmq.object_ref(), at_step_closure(RabbitmqReconcileStep::AfterDrainNodes));
+}
+
 pub proof fn lemma_always_for_all_step_pending_req_in_flight_or_resp_in_flight_at_reconcile_state(spec: TempPred<RMQCluster>, rabbitmq: RabbitmqClusterView)
     requires
         spec.entails(lift_state(RMQCluster::init())),
@@ -408,6 +497,10 @@ pub proof fn sm_spec_entails_all_invariants(rabbitmq: RabbitmqClusterView)
         spec_entails_always_tla_forall(spec, a_to_p_1);
     });
     RMQCluster::lemma_always_no_pending_req_msg_at_reconcile_state(spec, rabbitmq.object_ref(), at_step_closure(RabbitmqReconcileStep::Init));
+    lemma_always_for_after_update_status_step_pending_req_in_flight_or_resp_in_flight_at_reconcile_state(spec, rabbitmq);
+    lemma_always_for_after_update_degraded_status_step_pending_req_in_flight_or_resp_in_flight_at_reconcile_state(spec, rabbitmq);
+    lemma_always_for_after_set_plugins_step_pending_req_in_flight_or_resp_in_flight_at_reconcile_state(spec, rabbitmq);
+    lemma_always_for_after_drain_nodes_step_pending_req_in_flight_or_resp_in_flight_at_reconcile_state(spec, rabbitmq);
 
     // Different from other a_to_p_x, we encapsulate a_to_p_2 inside the lemma below because we find its reasoning is
     // surprisingly slow in this context. Encapsulating the reasoning reduces the verification time of this function
@@ -424,6 +517,7 @@ pub proof fn sm_spec_entails_all_invariants(rabbitmq: RabbitmqClusterView)
     });
     helper_invariants::lemma_always_no_update_status_request_msg_not_from_bc_in_flight_of_stateful_set(spec, rabbitmq);
     helper_invariants::lemma_always_the_object_in_reconcile_satisfies_state_validation(spec, rabbitmq.object_ref());
+    helper_invariants::lemma_always_drained_replicas_in_reconcile_state_is_none_or_desired_replicas(spec, rabbitmq.object_ref());
     RMQCluster::lemma_always_key_of_object_in_matched_ok_get_resp_message_is_same_as_key_of_pending_req(spec, rabbitmq.object_ref());
     RMQCluster::lemma_always_key_of_object_in_matched_ok_create_resp_message_is_same_as_key_of_pending_req(spec, rabbitmq.object_ref());
     RMQCluster::lemma_always_key_of_object_in_matched_ok_update_resp_message_is_same_as_key_of_pending_req(spec, rabbitmq.object_ref());
@@ -456,6 +550,7 @@ pub proof fn sm_spec_entails_all_invariants(rabbitmq: RabbitmqClusterView)
         }
         spec_entails_always_tla_forall(spec, a_to_p_7);
     });
+    helper_invariants::lemma_always_replicas_of_etcd_stateful_set_satisfies_order(spec, rabbitmq);
 
     entails_always_and_n!(
         spec,
@@ -469,10 +564,15 @@ pub proof fn sm_spec_entails_all_invariants(rabbitmq: RabbitmqClusterView)
         lift_state(RMQCluster::each_object_in_reconcile_has_consistent_key_and_valid_metadata()),
         tla_forall(a_to_p_1),
         lift_state(RMQCluster::no_pending_req_msg_at_reconcile_state(rabbitmq.object_ref(), at_step_closure(RabbitmqReconcileStep::Init))),
+        lift_state(RMQCluster::pending_req_in_flight_or_resp_in_flight_at_reconcile_state(rabbitmq.object_ref(), at_step_closure(RabbitmqReconcileStep::AfterUpdateStatus))),
+        lift_state(RMQCluster::pending_req_in_flight_or_resp_in_flight_at_reconcile_state(rabbitmq.object_ref(), at_step_closure(RabbitmqReconcileStep::AfterUpdateDegradedStatus))),
+        lift_state(RMQCluster::pending_req_in_flight_or_resp_in_flight_at_reconcile_state(rabbitmq.object_ref(), at_step_closure(RabbitmqReconcileStep::AfterSetPlugins))),
+        lift_state(RMQCluster::pending_req_in_flight_or_resp_in_flight_at_reconcile_state(rabbitmq.object_ref(), at_step_closure(RabbitmqReconcileStep::AfterDrainNodes))),
         tla_forall(a_to_p_2),
         tla_forall(a_to_p_3),
         lift_state(helper_invariants::no_update_status_request_msg_not_from_bc_in_flight_of_stateful_set(rabbitmq)),
         lift_state(helper_invariants::the_object_in_reconcile_satisfies_state_validation(rabbitmq.object_ref())),
+        lift_state(helper_invariants::drained_replicas_in_reconcile_state_is_none_or_desired_replicas(rabbitmq.object_ref())),
         lift_state(RMQCluster::key_of_object_in_matched_ok_get_resp_message_is_same_as_key_of_pending_req(rabbitmq.object_ref())),
         lift_state(RMQCluster::key_of_object_in_matched_ok_create_resp_message_is_same_as_key_of_pending_req(rabbitmq.object_ref())),
         lift_state(RMQCluster::key_of_object_in_matched_ok_update_resp_message_is_same_as_key_of_pending_req(rabbitmq.object_ref())),
@@ -480,7 +580,8 @@ pub proof fn sm_spec_entails_all_invariants(rabbitmq: RabbitmqClusterView)
         tla_forall(a_to_p_5),
         lift_state(helper_invariants::stateful_set_in_etcd_satisfies_unchangeable(rabbitmq)),
         tla_forall(a_to_p_6),
-        tla_forall(a_to_p_7)
+        tla_forall(a_to_p_7),
+        lift_state(helper_invariants::replicas_of_etcd_stateful_set_satisfies_order(rabbitmq))
     );
 }
 
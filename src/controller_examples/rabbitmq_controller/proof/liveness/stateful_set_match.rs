@@ -20,8 +20,12 @@ use crate::rabbitmq_controller::{
         predicate::*,
         resource::*,
     },
-    trusted::{liveness_theorem::*, spec_types::*, step::*},
+    trusted::{
+        liveness_theorem::*, rabbitmq_api_spec::*, safety_theorem::replicas_of_stateful_set,
+        spec_types::*, step::*,
+    },
 };
+use crate::reconciler::spec::io::*;
 use crate::temporal_logic::{defs::*, rules::*};
 use crate::vstd_ext::{map_lib::*, string_view::*};
 use vstd::{prelude::*, string::*};
@@ -35,6 +39,7 @@ pub proof fn lemma_from_after_get_stateful_set_step_to_stateful_set_matches(
         spec.entails(always(lift_action(RMQCluster::next()))),
         spec.entails(tla_forall(|i| RMQCluster::controller_next().weak_fairness(i))),
         spec.entails(tla_forall(|i| RMQCluster::kubernetes_api_next().weak_fairness(i))),
+        spec.entails(tla_forall(|i| RMQCluster::external_api_next().weak_fairness(i))),
         spec.entails(always(lift_state(RMQCluster::crash_disabled()))),
         spec.entails(always(lift_state(RMQCluster::busy_disabled()))),
         spec.entails(always(lift_state(RMQCluster::pending_req_of_key_is_unique_with_unique_id(rabbitmq.object_ref())))),
@@ -42,6 +47,7 @@ pub proof fn lemma_from_after_get_stateful_set_step_to_stateful_set_matches(
         spec.entails(always(lift_state(RMQCluster::every_in_flight_msg_has_unique_id()))),
         spec.entails(always(lift_state(RMQCluster::each_object_in_reconcile_has_consistent_key_and_valid_metadata()))),
         spec.entails(always(lift_state(RMQCluster::desired_state_is(rabbitmq)))),
+        spec.entails(always(lift_state(helper_invariants::scale_down_is_drainable(rabbitmq)))),
         spec.entails(always(lift_state(helper_invariants::the_object_in_reconcile_satisfies_state_validation(rabbitmq.object_ref())))),
         spec.entails(always(lift_state(helper_invariants::every_resource_update_request_implies_at_after_update_resource_step(SubResource::StatefulSet, rabbitmq)))),
         spec.entails(always(lift_state(helper_invariants::stateful_set_not_exists_or_matches_or_no_more_status_update(rabbitmq)))),
@@ -53,6 +59,7 @@ pub proof fn lemma_from_after_get_stateful_set_step_to_stateful_set_matches(
         spec.entails(always(lift_state(helper_invariants::every_resource_create_request_implies_at_after_create_resource_step(SubResource::StatefulSet, rabbitmq)))),
         spec.entails(always(lift_state(helper_invariants::every_resource_create_request_implies_at_after_create_resource_step(SubResource::ServerConfigMap, rabbitmq)))),
         spec.entails(always(lift_state(helper_invariants::stateful_set_in_etcd_satisfies_unchangeable(rabbitmq)))),
+        spec.entails(always(lift_state(helper_invariants::drained_replicas_in_reconcile_state_is_none_or_desired_replicas(rabbitmq.object_ref())))),
         spec.entails(always(lift_action(helper_invariants::cm_rv_stays_unchanged(rabbitmq)))),
     ensures spec.entails(lift_state(pending_req_in_flight_at_after_get_resource_step(SubResource::StatefulSet, rabbitmq)).leads_to(lift_state(sub_resource_state_matches(SubResource::StatefulSet, rabbitmq)))),
 {
@@ -80,12 +87,14 @@ proof fn lemma_from_after_get_stateful_set_step_and_key_exists_to_stateful_set_m
         spec.entails(always(lift_action(RMQCluster::next()))),
         spec.entails(tla_forall(|i| RMQCluster::controller_next().weak_fairness(i))),
         spec.entails(tla_forall(|i| RMQCluster::kubernetes_api_next().weak_fairness(i))),
+        spec.entails(tla_forall(|i| RMQCluster::external_api_next().weak_fairness(i))),
         spec.entails(always(lift_state(RMQCluster::crash_disabled()))),
         spec.entails(always(lift_state(RMQCluster::busy_disabled()))),
         spec.entails(always(lift_state(RMQCluster::pending_req_of_key_is_unique_with_unique_id(rabbitmq.object_ref())))),
         spec.entails(always(lift_state(RMQCluster::each_object_in_etcd_is_well_formed()))),
         spec.entails(always(lift_state(RMQCluster::every_in_flight_msg_has_unique_id()))),
         spec.entails(always(lift_state(RMQCluster::desired_state_is(rabbitmq)))),
+        spec.entails(always(lift_state(helper_invariants::scale_down_is_drainable(rabbitmq)))),
         spec.entails(always(lift_state(helper_invariants::the_object_in_reconcile_satisfies_state_validation(rabbitmq.object_ref())))),
         spec.entails(always(lift_state(helper_invariants::every_resource_update_request_implies_at_after_update_resource_step(SubResource::StatefulSet, rabbitmq)))),
         spec.entails(always(lift_state(helper_invariants::stateful_set_not_exists_or_matches_or_no_more_status_update(rabbitmq)))),
@@ -94,6 +103,7 @@ proof fn lemma_from_after_get_stateful_set_step_and_key_exists_to_stateful_set_m
         spec.entails(always(lift_state(helper_invariants::resource_object_only_has_owner_reference_pointing_to_current_cr(SubResource::StatefulSet, rabbitmq)))),
         spec.entails(always(lift_state(helper_invariants::resource_object_has_no_finalizers_or_timestamp_and_only_has_controller_owner_ref(SubResource::StatefulSet, rabbitmq)))),
         spec.entails(always(lift_state(helper_invariants::stateful_set_in_etcd_satisfies_unchangeable(rabbitmq)))),
+        spec.entails(always(lift_state(helper_invariants::drained_replicas_in_reconcile_state_is_none_or_desired_replicas(rabbitmq.object_ref())))),
         spec.entails(always(lift_action(helper_invariants::cm_rv_stays_unchanged(rabbitmq)))),
     ensures
         spec.entails(lift_state(|s: RMQCluster| {
@@ -153,7 +163,7 @@ proof fn lemma_from_after_get_stateful_set_step_and_key_exists_to_stateful_set_m
             &&& !sub_resource_state_matches(SubResource::StatefulSet, rabbitmq)(s)
         });
         assert forall |resp_msg| spec.entails(#[trigger] pre2(resp_msg).leads_to(post2)) by {
-            lemma_from_after_get_stateful_set_step_to_after_update_stateful_set_step(spec, rabbitmq, resp_msg);
+            lemma_from_after_get_stateful_set_step_to_after_update_stateful_set_step_with_drain(spec, rabbitmq, resp_msg);
         }
         leads_to_exists_intro(spec, pre2, post2);
         assert_by(tla_exists(pre2) == post1, {
@@ -291,6 +301,583 @@ proof fn lemma_from_key_exists_to_receives_ok_resp_at_after_get_stateful_set_ste
     );
 }
 
+// drain_is_needed tells whether the reconciler has to drain the nodes before scaling down the stateful set in the get response.
+spec fn drain_is_needed(rabbitmq: RabbitmqClusterView, resp_msg: RMQMessage) -> StatePred<RMQCluster> {
+    |s: RMQCluster| {
+        let found_replicas = found_stateful_set_replicas(rabbitmq, Some(ResponseView::KResponse(resp_msg.content.get_APIResponse_0())));
+        &&& s.ongoing_reconciles()[rabbitmq.object_ref()].local_state.drained_replicas_opt.is_None()
+        &&& found_replicas.is_Some()
+        &&& found_replicas.get_Some_0() > rabbitmq.spec.replicas
+    }
+}
+
+// When the stateful set in etcd has more replicas than desired, the reconciler first drains the nodes to be removed,
+// gets the stateful set again and then updates it. Otherwise it updates the stateful set right away.
+proof fn lemma_from_after_get_stateful_set_step_to_after_update_stateful_set_step_with_drain(
+    spec: TempPred<RMQCluster>, rabbitmq: RabbitmqClusterView, resp_msg: RMQMessage
+)
+    requires
+        spec.entails(always(lift_action(RMQCluster::next()))),
+        spec.entails(tla_forall(|i| RMQCluster::controller_next().weak_fairness(i))),
+        spec.entails(tla_forall(|i| RMQCluster::kubernetes_api_next().weak_fairness(i))),
+        spec.entails(tla_forall(|i| RMQCluster::external_api_next().weak_fairness(i))),
+        spec.entails(always(lift_state(RMQCluster::crash_disabled()))),
+        spec.entails(always(lift_state(RMQCluster::busy_disabled()))),
+        spec.entails(always(lift_state(RMQCluster::pending_req_of_key_is_unique_with_unique_id(rabbitmq.object_ref())))),
+        spec.entails(always(lift_state(RMQCluster::every_in_flight_msg_has_unique_id()))),
+        spec.entails(always(lift_state(RMQCluster::each_object_in_etcd_is_well_formed()))),
+        spec.entails(always(lift_state(RMQCluster::desired_state_is(rabbitmq)))),
+        spec.entails(always(lift_state(helper_invariants::scale_down_is_drainable(rabbitmq)))),
+        spec.entails(always(lift_state(helper_invariants::every_resource_update_request_implies_at_after_update_resource_step(SubResource::StatefulSet, rabbitmq)))),
+        spec.entails(always(lift_state(helper_invariants::stateful_set_not_exists_or_matches_or_no_more_status_update(rabbitmq)))),
+        spec.entails(always(lift_state(helper_invariants::no_delete_resource_request_msg_in_flight(SubResource::StatefulSet, rabbitmq)))),
+        spec.entails(always(lift_state(helper_invariants::cm_rv_is_the_same_as_etcd_server_cm_if_cm_updated(rabbitmq)))),
+        spec.entails(always(lift_state(helper_invariants::resource_object_only_has_owner_reference_pointing_to_current_cr(SubResource::StatefulSet, rabbitmq)))),
+        spec.entails(always(lift_state(helper_invariants::stateful_set_in_etcd_satisfies_unchangeable(rabbitmq)))),
+        spec.entails(always(lift_state(helper_invariants::drained_replicas_in_reconcile_state_is_none_or_desired_replicas(rabbitmq.object_ref())))),
+        spec.entails(always(lift_action(helper_invariants::cm_rv_stays_unchanged(rabbitmq)))),
+    ensures
+        spec.entails(
+            lift_state(|s: RMQCluster| {
+                &&& resp_msg_is_the_in_flight_ok_resp_at_after_get_resource_step(SubResource::StatefulSet, rabbitmq, resp_msg)(s)
+                &&& !sub_resource_state_matches(SubResource::StatefulSet, rabbitmq)(s)
+            })
+            .leads_to(lift_state(|s: RMQCluster| {
+                &&& pending_req_in_flight_at_after_update_resource_step(SubResource::StatefulSet, rabbitmq)(s)
+                &&& !sub_resource_state_matches(SubResource::StatefulSet, rabbitmq)(s)
+            }))
+        ),
+{
+    let pre = lift_state(|s: RMQCluster| {
+        &&& resp_msg_is_the_in_flight_ok_resp_at_after_get_resource_step(SubResource::StatefulSet, rabbitmq, resp_msg)(s)
+        &&& !sub_resource_state_matches(SubResource::StatefulSet, rabbitmq)(s)
+    });
+    let no_drain = lift_state(|s: RMQCluster| {
+        &&& resp_msg_is_the_in_flight_ok_resp_at_after_get_resource_step(SubResource::StatefulSet, rabbitmq, resp_msg)(s)
+        &&& !sub_resource_state_matches(SubResource::StatefulSet, rabbitmq)(s)
+        &&& !drain_is_needed(rabbitmq, resp_msg)(s)
+    });
+    let drain = lift_state(|s: RMQCluster| {
+        &&& resp_msg_is_the_in_flight_ok_resp_at_after_get_resource_step(SubResource::StatefulSet, rabbitmq, resp_msg)(s)
+        &&& !sub_resource_state_matches(SubResource::StatefulSet, rabbitmq)(s)
+        &&& drain_is_needed(rabbitmq, resp_msg)(s)
+    });
+    let post = lift_state(|s: RMQCluster| {
+        &&& pending_req_in_flight_at_after_update_resource_step(SubResource::StatefulSet, rabbitmq)(s)
+        &&& !sub_resource_state_matches(SubResource::StatefulSet, rabbitmq)(s)
+    });
+
+    lemma_from_after_get_stateful_set_step_to_after_update_stateful_set_step(spec, rabbitmq, resp_msg);
+
+    assert_by(spec.entails(drain.leads_to(post)), {
+        let after_drain_nodes = lift_state(|s: RMQCluster| {
+            &&& pending_req_in_flight_at_after_drain_nodes_step(rabbitmq)(s)
+            &&& !sub_resource_state_matches(SubResource::StatefulSet, rabbitmq)(s)
+        });
+        let drain_ok = lift_state(|s: RMQCluster| {
+            &&& at_after_drain_nodes_step_and_exists_ok_resp_in_flight(rabbitmq)(s)
+            &&& !sub_resource_state_matches(SubResource::StatefulSet, rabbitmq)(s)
+        });
+        let after_get_again = lift_state(|s: RMQCluster| {
+            &&& pending_req_in_flight_at_after_get_stateful_set_step_after_drain(rabbitmq)(s)
+            &&& !sub_resource_state_matches(SubResource::StatefulSet, rabbitmq)(s)
+        });
+        let get_ok_after_drain = lift_state(|s: RMQCluster| {
+            &&& at_after_get_resource_step_and_exists_ok_resp_in_flight(SubResource::StatefulSet, rabbitmq)(s)
+            &&& s.ongoing_reconciles()[rabbitmq.object_ref()].local_state.drained_replicas_opt == Some(rabbitmq.spec.replicas)
+            &&& !sub_resource_state_matches(SubResource::StatefulSet, rabbitmq)(s)
+        });
+
+        lemma_from_after_get_stateful_set_step_to_after_drain_nodes_step(spec, rabbitmq, resp_msg);
+
+        let pre1 = |req_msg| lift_state(|s: RMQCluster| {
+            &&& req_msg_is_the_in_flight_pending_req_at_after_drain_nodes_step(rabbitmq, req_msg)(s)
+            &&& !sub_resource_state_matches(SubResource::StatefulSet, rabbitmq)(s)
+        });
+        assert forall |req_msg| spec.entails(#[trigger] pre1(req_msg).leads_to(drain_ok)) by {
+            lemma_receives_ok_resp_at_after_drain_nodes_step(spec, rabbitmq, req_msg);
+        }
+        leads_to_exists_intro(spec, pre1, drain_ok);
+        assert_by(tla_exists(pre1) == after_drain_nodes, {
+            assert forall |ex| #[trigger] after_drain_nodes.satisfied_by(ex)
+            implies tla_exists(pre1).satisfied_by(ex) by {
+                let req_msg = ex.head().ongoing_reconciles()[rabbitmq.object_ref()].pending_req_msg.get_Some_0();
+                assert(pre1(req_msg).satisfied_by(ex));
+            }
+            temp_pred_equality(tla_exists(pre1), after_drain_nodes);
+        });
+
+        let pre2 = |drain_resp_msg| lift_state(|s: RMQCluster| {
+            &&& resp_msg_is_the_in_flight_ok_resp_at_after_drain_nodes_step(rabbitmq, drain_resp_msg)(s)
+            &&& !sub_resource_state_matches(SubResource::StatefulSet, rabbitmq)(s)
+        });
+        assert forall |drain_resp_msg| spec.entails(#[trigger] pre2(drain_resp_msg).leads_to(after_get_again)) by {
+            lemma_from_after_drain_nodes_step_to_after_get_stateful_set_step(spec, rabbitmq, drain_resp_msg);
+        }
+        leads_to_exists_intro(spec, pre2, after_get_again);
+        assert_by(tla_exists(pre2) == drain_ok, {
+            assert forall |ex| #[trigger] drain_ok.satisfied_by(ex)
+            implies tla_exists(pre2).satisfied_by(ex) by {
+                let drain_resp_msg = choose |drain_resp_msg: RMQMessage| {
+                    let resp = drain_resp_msg.content.get_ExternalAPIResponse_0();
+                    &&& #[trigger] ex.head().in_flight().contains(drain_resp_msg)
+                    &&& Message::resp_msg_matches_req_msg(drain_resp_msg, ex.head().ongoing_reconciles()[rabbitmq.object_ref()].pending_req_msg.get_Some_0())
+                    &&& resp == RabbitmqAPIOutputView::DrainNodesResponse(RabbitmqAPIDrainNodesResultView{res: Ok(())})
+                };
+                assert(pre2(drain_resp_msg).satisfied_by(ex));
+            }
+            temp_pred_equality(tla_exists(pre2), drain_ok);
+        });
+
+        let pre3 = |req_msg| lift_state(|s: RMQCluster| {
+            &&& req_msg_is_the_in_flight_pending_req_at_after_get_resource_step_and_key_exists(SubResource::StatefulSet, rabbitmq, req_msg)(s)
+            &&& s.ongoing_reconciles()[rabbitmq.object_ref()].local_state.drained_replicas_opt == Some(rabbitmq.spec.replicas)
+            &&& !sub_resource_state_matches(SubResource::StatefulSet, rabbitmq)(s)
+        });
+        assert forall |req_msg| spec.entails(#[trigger] pre3(req_msg).leads_to(get_ok_after_drain)) by {
+            lemma_from_key_exists_to_receives_ok_resp_at_after_get_stateful_set_step_after_drain(spec, rabbitmq, req_msg);
+        }
+        leads_to_exists_intro(spec, pre3, get_ok_after_drain);
+        assert_by(tla_exists(pre3) == after_get_again, {
+            assert forall |ex| #[trigger] after_get_again.satisfied_by(ex)
+            implies tla_exists(pre3).satisfied_by(ex) by {
+                let req_msg = ex.head().ongoing_reconciles()[rabbitmq.object_ref()].pending_req_msg.get_Some_0();
+                assert(pre3(req_msg).satisfied_by(ex));
+            }
+            temp_pred_equality(tla_exists(pre3), after_get_again);
+        });
+
+        // After the drain, drained_replicas_opt is set so the reconciler goes on to update the stateful set
+        let pre4 = |get_resp_msg| lift_state(|s: RMQCluster| {
+            &&& resp_msg_is_the_in_flight_ok_resp_at_after_get_resource_step(SubResource::StatefulSet, rabbitmq, get_resp_msg)(s)
+            &&& !sub_resource_state_matches(SubResource::StatefulSet, rabbitmq)(s)
+            &&& !drain_is_needed(rabbitmq, get_resp_msg)(s)
+        });
+        assert forall |get_resp_msg| spec.entails(#[trigger] pre4(get_resp_msg).leads_to(post)) by {
+            lemma_from_after_get_stateful_set_step_to_after_update_stateful_set_step(spec, rabbitmq, get_resp_msg);
+        }
+        leads_to_exists_intro(spec, pre4, post);
+        assert forall |ex| #[trigger] get_ok_after_drain.satisfied_by(ex) implies tla_exists(pre4).satisfied_by(ex) by {
+            let sts_key = get_request(SubResource::StatefulSet, rabbitmq).key;
+            let get_resp_msg = choose |get_resp_msg| {
+                &&& #[trigger] ex.head().in_flight().contains(get_resp_msg)
+                &&& Message::resp_msg_matches_req_msg(get_resp_msg, ex.head().ongoing_reconciles()[rabbitmq.object_ref()].pending_req_msg.get_Some_0())
+                &&& get_resp_msg.content.get_get_response().res.is_Ok()
+                &&& get_resp_msg.content.get_get_response().res.get_Ok_0() == ex.head().resources()[sts_key]
+            };
+            assert(pre4(get_resp_msg).satisfied_by(ex));
+        }
+        entails_implies_leads_to(spec, get_ok_after_drain, tla_exists(pre4));
+        leads_to_trans_n!(spec, drain, after_drain_nodes, drain_ok, after_get_again, get_ok_after_drain, tla_exists(pre4), post);
+    });
+
+    or_leads_to_combine(spec, no_drain, drain, post);
+    temp_pred_equality(no_drain.or(drain), pre);
+}
+
+#[verifier(spinoff_prover)]
+proof fn lemma_from_after_get_stateful_set_step_to_after_drain_nodes_step(
+    spec: TempPred<RMQCluster>, rabbitmq: RabbitmqClusterView, resp_msg: RMQMessage
+)
+    requires
+        spec.entails(always(lift_action(RMQCluster::next()))),
+        spec.entails(tla_forall(|i| RMQCluster::controller_next().weak_fairness(i))),
+        spec.entails(tla_forall(|i| RMQCluster::kubernetes_api_next().weak_fairness(i))),
+        spec.entails(tla_forall(|i| RMQCluster::external_api_next().weak_fairness(i))),
+        spec.entails(always(lift_state(RMQCluster::crash_disabled()))),
+        spec.entails(always(lift_state(RMQCluster::busy_disabled()))),
+        spec.entails(always(lift_state(RMQCluster::pending_req_of_key_is_unique_with_unique_id(rabbitmq.object_ref())))),
+        spec.entails(always(lift_state(RMQCluster::every_in_flight_msg_has_unique_id()))),
+        spec.entails(always(lift_state(RMQCluster::each_object_in_etcd_is_well_formed()))),
+        spec.entails(always(lift_state(RMQCluster::desired_state_is(rabbitmq)))),
+        spec.entails(always(lift_state(helper_invariants::scale_down_is_drainable(rabbitmq)))),
+        spec.entails(always(lift_state(helper_invariants::every_resource_update_request_implies_at_after_update_resource_step(SubResource::StatefulSet, rabbitmq)))),
+        spec.entails(always(lift_state(helper_invariants::stateful_set_not_exists_or_matches_or_no_more_status_update(rabbitmq)))),
+        spec.entails(always(lift_state(helper_invariants::no_delete_resource_request_msg_in_flight(SubResource::StatefulSet, rabbitmq)))),
+        spec.entails(always(lift_state(helper_invariants::cm_rv_is_the_same_as_etcd_server_cm_if_cm_updated(rabbitmq)))),
+        spec.entails(always(lift_state(helper_invariants::resource_object_only_has_owner_reference_pointing_to_current_cr(SubResource::StatefulSet, rabbitmq)))),
+        spec.entails(always(lift_state(helper_invariants::stateful_set_in_etcd_satisfies_unchangeable(rabbitmq)))),
+        spec.entails(always(lift_state(helper_invariants::drained_replicas_in_reconcile_state_is_none_or_desired_replicas(rabbitmq.object_ref())))),
+        spec.entails(always(lift_action(helper_invariants::cm_rv_stays_unchanged(rabbitmq)))),
+    ensures
+        spec.entails(
+            lift_state(|s: RMQCluster| {
+                &&& resp_msg_is_the_in_flight_ok_resp_at_after_get_resource_step(SubResource::StatefulSet, rabbitmq, resp_msg)(s)
+                &&& !sub_resource_state_matches(SubResource::StatefulSet, rabbitmq)(s)
+                &&& drain_is_needed(rabbitmq, resp_msg)(s)
+            })
+            .leads_to(lift_state(|s: RMQCluster| {
+                &&& pending_req_in_flight_at_after_drain_nodes_step(rabbitmq)(s)
+                &&& !sub_resource_state_matches(SubResource::StatefulSet, rabbitmq)(s)
+            }))
+        ),
+{
+    let pre = |s: RMQCluster| {
+        &&& resp_msg_is_the_in_flight_ok_resp_at_after_get_resource_step(SubResource::StatefulSet, rabbitmq, resp_msg)(s)
+        &&& !sub_resource_state_matches(SubResource::StatefulSet, rabbitmq)(s)
+        &&& drain_is_needed(rabbitmq, resp_msg)(s)
+    };
+    let post = |s: RMQCluster| {
+        &&& pending_req_in_flight_at_after_drain_nodes_step(rabbitmq)(s)
+        &&& !sub_resource_state_matches(SubResource::StatefulSet, rabbitmq)(s)
+    };
+    let input = (Some(resp_msg), Some(rabbitmq.object_ref()));
+    let stronger_next = |s, s_prime: RMQCluster| {
+        &&& RMQCluster::next()(s, s_prime)
+        &&& RMQCluster::crash_disabled()(s)
+        &&& RMQCluster::busy_disabled()(s)
+        &&& RMQCluster::pending_req_of_key_is_unique_with_unique_id(rabbitmq.object_ref())(s)
+        &&& RMQCluster::every_in_flight_msg_has_unique_id()(s)
+        &&& RMQCluster::each_object_in_etcd_is_well_formed()(s)
+        &&& RMQCluster::desired_state_is(rabbitmq)(s)
+        &&& helper_invariants::scale_down_is_drainable(rabbitmq)(s)
+        &&& helper_invariants::every_resource_update_request_implies_at_after_update_resource_step(SubResource::StatefulSet, rabbitmq)(s)
+        &&& helper_invariants::stateful_set_not_exists_or_matches_or_no_more_status_update(rabbitmq)(s)
+        &&& helper_invariants::no_delete_resource_request_msg_in_flight(SubResource::StatefulSet, rabbitmq)(s)
+        &&& helper_invariants::resource_object_only_has_owner_reference_pointing_to_current_cr(SubResource::StatefulSet, rabbitmq)(s)
+    };
+    combine_spec_entails_always_n!(
+        spec, lift_action(stronger_next),
+        lift_action(RMQCluster::next()),
+        lift_state(RMQCluster::crash_disabled()),
+        lift_state(RMQCluster::busy_disabled()),
+        lift_state(RMQCluster::pending_req_of_key_is_unique_with_unique_id(rabbitmq.object_ref())),
+        lift_state(RMQCluster::every_in_flight_msg_has_unique_id()),
+        lift_state(RMQCluster::each_object_in_etcd_is_well_formed()),
+        lift_state(RMQCluster::desired_state_is(rabbitmq)),
+        lift_state(helper_invariants::scale_down_is_drainable(rabbitmq)),
+        lift_state(helper_invariants::every_resource_update_request_implies_at_after_update_resource_step(SubResource::StatefulSet, rabbitmq)),
+        lift_state(helper_invariants::stateful_set_not_exists_or_matches_or_no_more_status_update(rabbitmq)),
+        lift_state(helper_invariants::no_delete_resource_request_msg_in_flight(SubResource::StatefulSet, rabbitmq)),
+        lift_state(helper_invariants::resource_object_only_has_owner_reference_pointing_to_current_cr(SubResource::StatefulSet, rabbitmq))
+    );
+
+    assert forall |s, s_prime: RMQCluster| pre(s) && #[trigger] stronger_next(s, s_prime) implies pre(s_prime) || post(s_prime) by {
+        let step = choose |step| RMQCluster::next_step(s, s_prime, step);
+        let resource_key = get_request(SubResource::StatefulSet, rabbitmq).key;
+        match step {
+            Step::ApiServerStep(input) => {
+                let req = input.get_Some_0();
+                assert(!resource_delete_request_msg(resource_key)(req));
+                assert(!resource_update_request_msg(resource_key)(req));
+                assert(!resource_update_status_request_msg(resource_key)(req));
+            },
+            _ => {}
+        }
+    }
+
+    assert forall |s, s_prime: RMQCluster| pre(s) && #[trigger] stronger_next(s, s_prime) && RMQCluster::controller_next().forward(input)(s, s_prime)
+    implies post(s_prime) by {
+        let resource_key = get_request(SubResource::StatefulSet, rabbitmq).key;
+        // The drain nodes request is made from the stateful set in the get response, which is the one in etcd
+        StatefulSetView::marshal_preserves_integrity();
+        assert(found_stateful_set_replicas(rabbitmq, Some(ResponseView::KResponse(resp_msg.content.get_APIResponse_0()))) == Some(replicas_of_stateful_set(s.resources()[resource_key])));
+        assert(rabbitmq.controller_owner_ref() == s.ongoing_reconciles()[rabbitmq.object_ref()].triggering_cr.controller_owner_ref());
+    }
+
+    RMQCluster::lemma_pre_leads_to_post_by_controller(spec, input, stronger_next, RMQCluster::continue_reconcile(), pre, post);
+}
+
+// The drain succeeds as the stateful set hosting the nodes is owned by the current cr
+// and scale_down_is_drainable tells that no quorum queue loses its majority.
+#[verifier(spinoff_prover)]
+proof fn lemma_receives_ok_resp_at_after_drain_nodes_step(
+    spec: TempPred<RMQCluster>, rabbitmq: RabbitmqClusterView, req_msg: RMQMessage
+)
+    requires
+        spec.entails(always(lift_action(RMQCluster::next()))),
+        spec.entails(tla_forall(|i| RMQCluster::controller_next().weak_fairness(i))),
+        spec.entails(tla_forall(|i| RMQCluster::kubernetes_api_next().weak_fairness(i))),
+        spec.entails(tla_forall(|i| RMQCluster::external_api_next().weak_fairness(i))),
+        spec.entails(always(lift_state(RMQCluster::crash_disabled()))),
+        spec.entails(always(lift_state(RMQCluster::busy_disabled()))),
+        spec.entails(always(lift_state(RMQCluster::pending_req_of_key_is_unique_with_unique_id(rabbitmq.object_ref())))),
+        spec.entails(always(lift_state(RMQCluster::every_in_flight_msg_has_unique_id()))),
+        spec.entails(always(lift_state(RMQCluster::each_object_in_etcd_is_well_formed()))),
+        spec.entails(always(lift_state(RMQCluster::desired_state_is(rabbitmq)))),
+        spec.entails(always(lift_state(helper_invariants::scale_down_is_drainable(rabbitmq)))),
+        spec.entails(always(lift_state(helper_invariants::every_resource_update_request_implies_at_after_update_resource_step(SubResource::StatefulSet, rabbitmq)))),
+        spec.entails(always(lift_state(helper_invariants::stateful_set_not_exists_or_matches_or_no_more_status_update(rabbitmq)))),
+        spec.entails(always(lift_state(helper_invariants::no_delete_resource_request_msg_in_flight(SubResource::StatefulSet, rabbitmq)))),
+        spec.entails(always(lift_state(helper_invariants::cm_rv_is_the_same_as_etcd_server_cm_if_cm_updated(rabbitmq)))),
+        spec.entails(always(lift_state(helper_invariants::resource_object_only_has_owner_reference_pointing_to_current_cr(SubResource::StatefulSet, rabbitmq)))),
+        spec.entails(always(lift_state(helper_invariants::stateful_set_in_etcd_satisfies_unchangeable(rabbitmq)))),
+        spec.entails(always(lift_state(helper_invariants::drained_replicas_in_reconcile_state_is_none_or_desired_replicas(rabbitmq.object_ref())))),
+        spec.entails(always(lift_action(helper_invariants::cm_rv_stays_unchanged(rabbitmq)))),
+    ensures
+        spec.entails(
+            lift_state(|s: RMQCluster| {
+                &&& req_msg_is_the_in_flight_pending_req_at_after_drain_nodes_step(rabbitmq, req_msg)(s)
+                &&& !sub_resource_state_matches(SubResource::StatefulSet, rabbitmq)(s)
+            })
+            .leads_to(lift_state(|s: RMQCluster| {
+                &&& at_after_drain_nodes_step_and_exists_ok_resp_in_flight(rabbitmq)(s)
+                &&& !sub_resource_state_matches(SubResource::StatefulSet, rabbitmq)(s)
+            }))
+        ),
+{
+    let pre = |s: RMQCluster| {
+        &&& req_msg_is_the_in_flight_pending_req_at_after_drain_nodes_step(rabbitmq, req_msg)(s)
+        &&& !sub_resource_state_matches(SubResource::StatefulSet, rabbitmq)(s)
+    };
+    let post = |s: RMQCluster| {
+        &&& at_after_drain_nodes_step_and_exists_ok_resp_in_flight(rabbitmq)(s)
+        &&& !sub_resource_state_matches(SubResource::StatefulSet, rabbitmq)(s)
+    };
+    let resource_key = get_request(SubResource::StatefulSet, rabbitmq).key;
+    let input = Some(req_msg);
+    let stronger_next = |s, s_prime: RMQCluster| {
+        &&& RMQCluster::next()(s, s_prime)
+        &&& RMQCluster::crash_disabled()(s)
+        &&& RMQCluster::busy_disabled()(s)
+        &&& RMQCluster::every_in_flight_msg_has_unique_id()(s)
+        &&& RMQCluster::each_object_in_etcd_is_well_formed()(s)
+        &&& helper_invariants::scale_down_is_drainable(rabbitmq)(s)
+        &&& helper_invariants::every_resource_update_request_implies_at_after_update_resource_step(SubResource::StatefulSet, rabbitmq)(s)
+        &&& helper_invariants::no_delete_resource_request_msg_in_flight(SubResource::StatefulSet, rabbitmq)(s)
+        &&& helper_invariants::stateful_set_not_exists_or_matches_or_no_more_status_update(rabbitmq)(s)
+        &&& helper_invariants::resource_object_only_has_owner_reference_pointing_to_current_cr(SubResource::StatefulSet, rabbitmq)(s)
+    };
+    combine_spec_entails_always_n!(
+        spec, lift_action(stronger_next),
+        lift_action(RMQCluster::next()),
+        lift_state(RMQCluster::crash_disabled()),
+        lift_state(RMQCluster::busy_disabled()),
+        lift_state(RMQCluster::every_in_flight_msg_has_unique_id()),
+        lift_state(RMQCluster::each_object_in_etcd_is_well_formed()),
+        lift_state(helper_invariants::scale_down_is_drainable(rabbitmq)),
+        lift_state(helper_invariants::every_resource_update_request_implies_at_after_update_resource_step(SubResource::StatefulSet, rabbitmq)),
+        lift_state(helper_invariants::no_delete_resource_request_msg_in_flight(SubResource::StatefulSet, rabbitmq)),
+        lift_state(helper_invariants::stateful_set_not_exists_or_matches_or_no_more_status_update(rabbitmq)),
+        lift_state(helper_invariants::resource_object_only_has_owner_reference_pointing_to_current_cr(SubResource::StatefulSet, rabbitmq))
+    );
+
+    assert forall |s, s_prime| pre(s) && #[trigger] stronger_next(s, s_prime) implies pre(s_prime) || post(s_prime) by {
+        let step = choose |step| RMQCluster::next_step(s, s_prime, step);
+        match step {
+            Step::ExternalAPIStep(input) => {
+                if input.get_Some_0() == req_msg {
+                    let resp_msg = RMQCluster::handle_external_request_helper(req_msg, s.external_api_state, s.resources()).1;
+                    assert({
+                        &&& s_prime.in_flight().contains(resp_msg)
+                        &&& Message::resp_msg_matches_req_msg(resp_msg, req_msg)
+                        &&& resp_msg.content.get_ExternalAPIResponse_0() == RabbitmqAPIOutputView::DrainNodesResponse(RabbitmqAPIDrainNodesResultView{res: Ok(())})
+                    });
+                    assert(post(s_prime));
+                }
+            },
+            Step::ApiServerStep(input) => {
+                // The stateful set keeps its replicas, so the drain nodes request still matches the one in etcd
+                assert(!resource_delete_request_msg(resource_key)(input.get_Some_0()));
+                assert(!resource_update_request_msg(resource_key)(input.get_Some_0()));
+                assert(!resource_update_status_request_msg(resource_key)(input.get_Some_0()));
+            },
+            _ => {}
+        }
+    }
+
+    assert forall |s, s_prime| pre(s) && #[trigger] stronger_next(s, s_prime) && RMQCluster::external_api_next().forward(input)(s, s_prime)
+    implies post(s_prime) by {
+        let resp_msg = RMQCluster::handle_external_request_helper(req_msg, s.external_api_state, s.resources()).1;
+        StatefulSetView::marshal_preserves_integrity();
+        assert(validate(rabbitmq.metadata.name.get_Some_0(), rabbitmq.metadata.namespace.get_Some_0(), replicas_of_stateful_set(s.resources()[resource_key]), s.resources()));
+        assert(s.resources()[resource_key].metadata.owner_references_only_contains(rabbitmq.controller_owner_ref()));
+        assert({
+            &&& s_prime.in_flight().contains(resp_msg)
+            &&& Message::resp_msg_matches_req_msg(resp_msg, req_msg)
+            &&& resp_msg.content.get_ExternalAPIResponse_0() == RabbitmqAPIOutputView::DrainNodesResponse(RabbitmqAPIDrainNodesResultView{res: Ok(())})
+        });
+    }
+
+    RMQCluster::lemma_pre_leads_to_post_by_external_api(spec, input, stronger_next, RMQCluster::handle_external_request(), pre, post);
+}
+
+#[verifier(spinoff_prover)]
+proof fn lemma_from_after_drain_nodes_step_to_after_get_stateful_set_step(
+    spec: TempPred<RMQCluster>, rabbitmq: RabbitmqClusterView, resp_msg: RMQMessage
+)
+    requires
+        spec.entails(always(lift_action(RMQCluster::next()))),
+        spec.entails(tla_forall(|i| RMQCluster::controller_next().weak_fairness(i))),
+        spec.entails(tla_forall(|i| RMQCluster::kubernetes_api_next().weak_fairness(i))),
+        spec.entails(tla_forall(|i| RMQCluster::external_api_next().weak_fairness(i))),
+        spec.entails(always(lift_state(RMQCluster::crash_disabled()))),
+        spec.entails(always(lift_state(RMQCluster::busy_disabled()))),
+        spec.entails(always(lift_state(RMQCluster::pending_req_of_key_is_unique_with_unique_id(rabbitmq.object_ref())))),
+        spec.entails(always(lift_state(RMQCluster::every_in_flight_msg_has_unique_id()))),
+        spec.entails(always(lift_state(RMQCluster::each_object_in_etcd_is_well_formed()))),
+        spec.entails(always(lift_state(RMQCluster::desired_state_is(rabbitmq)))),
+        spec.entails(always(lift_state(helper_invariants::scale_down_is_drainable(rabbitmq)))),
+        spec.entails(always(lift_state(helper_invariants::every_resource_update_request_implies_at_after_update_resource_step(SubResource::StatefulSet, rabbitmq)))),
+        spec.entails(always(lift_state(helper_invariants::stateful_set_not_exists_or_matches_or_no_more_status_update(rabbitmq)))),
+        spec.entails(always(lift_state(helper_invariants::no_delete_resource_request_msg_in_flight(SubResource::StatefulSet, rabbitmq)))),
+        spec.entails(always(lift_state(helper_invariants::cm_rv_is_the_same_as_etcd_server_cm_if_cm_updated(rabbitmq)))),
+        spec.entails(always(lift_state(helper_invariants::resource_object_only_has_owner_reference_pointing_to_current_cr(SubResource::StatefulSet, rabbitmq)))),
+        spec.entails(always(lift_state(helper_invariants::stateful_set_in_etcd_satisfies_unchangeable(rabbitmq)))),
+        spec.entails(always(lift_state(helper_invariants::drained_replicas_in_reconcile_state_is_none_or_desired_replicas(rabbitmq.object_ref())))),
+        spec.entails(always(lift_action(helper_invariants::cm_rv_stays_unchanged(rabbitmq)))),
+    ensures
+        spec.entails(
+            lift_state(|s: RMQCluster| {
+                &&& resp_msg_is_the_in_flight_ok_resp_at_after_drain_nodes_step(rabbitmq, resp_msg)(s)
+                &&& !sub_resource_state_matches(SubResource::StatefulSet, rabbitmq)(s)
+            })
+            .leads_to(lift_state(|s: RMQCluster| {
+                &&& pending_req_in_flight_at_after_get_stateful_set_step_after_drain(rabbitmq)(s)
+                &&& !sub_resource_state_matches(SubResource::StatefulSet, rabbitmq)(s)
+            }))
+        ),
+{
+    let pre = |s: RMQCluster| {
+        &&& resp_msg_is_the_in_flight_ok_resp_at_after_drain_nodes_step(rabbitmq, resp_msg)(s)
+        &&& !sub_resource_state_matches(SubResource::StatefulSet, rabbitmq)(s)
+    };
+    let post = |s: RMQCluster| {
+        &&& pending_req_in_flight_at_after_get_stateful_set_step_after_drain(rabbitmq)(s)
+        &&& !sub_resource_state_matches(SubResource::StatefulSet, rabbitmq)(s)
+    };
+    let input = (Some(resp_msg), Some(rabbitmq.object_ref()));
+    let stronger_next = |s, s_prime: RMQCluster| {
+        &&& RMQCluster::next()(s, s_prime)
+        &&& RMQCluster::crash_disabled()(s)
+        &&& RMQCluster::busy_disabled()(s)
+        &&& RMQCluster::pending_req_of_key_is_unique_with_unique_id(rabbitmq.object_ref())(s)
+        &&& RMQCluster::every_in_flight_msg_has_unique_id()(s)
+        &&& helper_invariants::every_resource_update_request_implies_at_after_update_resource_step(SubResource::StatefulSet, rabbitmq)(s)
+        &&& helper_invariants::no_delete_resource_request_msg_in_flight(SubResource::StatefulSet, rabbitmq)(s)
+        &&& helper_invariants::stateful_set_not_exists_or_matches_or_no_more_status_update(rabbitmq)(s)
+    };
+    combine_spec_entails_always_n!(
+        spec, lift_action(stronger_next),
+        lift_action(RMQCluster::next()),
+        lift_state(RMQCluster::crash_disabled()),
+        lift_state(RMQCluster::busy_disabled()),
+        lift_state(RMQCluster::pending_req_of_key_is_unique_with_unique_id(rabbitmq.object_ref())),
+        lift_state(RMQCluster::every_in_flight_msg_has_unique_id()),
+        lift_state(helper_invariants::every_resource_update_request_implies_at_after_update_resource_step(SubResource::StatefulSet, rabbitmq)),
+        lift_state(helper_invariants::no_delete_resource_request_msg_in_flight(SubResource::StatefulSet, rabbitmq)),
+        lift_state(helper_invariants::stateful_set_not_exists_or_matches_or_no_more_status_update(rabbitmq))
+    );
+
+    assert forall |s, s_prime: RMQCluster| pre(s) && #[trigger] stronger_next(s, s_prime) implies pre(s_prime) || post(s_prime) by {
+        let step = choose |step| RMQCluster::next_step(s, s_prime, step);
+        let resource_key = get_request(SubResource::StatefulSet, rabbitmq).key;
+        match step {
+            Step::ApiServerStep(input) => {
+                let req = input.get_Some_0();
+                assert(!resource_delete_request_msg(resource_key)(req));
+                assert(!resource_update_request_msg(resource_key)(req));
+                assert(!resource_update_status_request_msg(resource_key)(req));
+            },
+            _ => {}
+        }
+    }
+    RMQCluster::lemma_pre_leads_to_post_by_controller(spec, input, stronger_next, RMQCluster::continue_reconcile(), pre, post);
+}
+
+// This is the same as lemma_from_key_exists_to_receives_ok_resp_at_after_get_stateful_set_step
+// except that it also keeps track of drained_replicas_opt, which the kubernetes API never changes.
+#[verifier(spinoff_prover)]
+proof fn lemma_from_key_exists_to_receives_ok_resp_at_after_get_stateful_set_step_after_drain(
+    spec: TempPred<RMQCluster>, rabbitmq: RabbitmqClusterView, req_msg: RMQMessage
+)
+    requires
+        spec.entails(always(lift_action(RMQCluster::next()))),
+        spec.entails(tla_forall(|i| RMQCluster::kubernetes_api_next().weak_fairness(i))),
+        spec.entails(always(lift_state(RMQCluster::crash_disabled()))),
+        spec.entails(always(lift_state(RMQCluster::busy_disabled()))),
+        spec.entails(always(lift_state(RMQCluster::every_in_flight_msg_has_unique_id()))),
+        spec.entails(always(lift_state(helper_invariants::every_resource_update_request_implies_at_after_update_resource_step(SubResource::StatefulSet, rabbitmq)))),
+        spec.entails(always(lift_state(helper_invariants::no_delete_resource_request_msg_in_flight(SubResource::StatefulSet, rabbitmq)))),
+        spec.entails(always(lift_state(helper_invariants::stateful_set_not_exists_or_matches_or_no_more_status_update(rabbitmq)))),
+        spec.entails(always(lift_action(helper_invariants::cm_rv_stays_unchanged(rabbitmq)))),
+    ensures
+        spec.entails(
+            lift_state(|s: RMQCluster| {
+                &&& req_msg_is_the_in_flight_pending_req_at_after_get_resource_step_and_key_exists(SubResource::StatefulSet, rabbitmq, req_msg)(s)
+                &&& s.ongoing_reconciles()[rabbitmq.object_ref()].local_state.drained_replicas_opt == Some(rabbitmq.spec.replicas)
+                &&& !sub_resource_state_matches(SubResource::StatefulSet, rabbitmq)(s)
+            })
+            .leads_to(lift_state(|s: RMQCluster| {
+                &&& at_after_get_resource_step_and_exists_ok_resp_in_flight(SubResource::StatefulSet, rabbitmq)(s)
+                &&& s.ongoing_reconciles()[rabbitmq.object_ref()].local_state.drained_replicas_opt == Some(rabbitmq.spec.replicas)
+                &&& !sub_resource_state_matches(SubResource::StatefulSet, rabbitmq)(s)
+            }))
+        ),
+{
+    let pre = |s: RMQCluster| {
+        &&& req_msg_is_the_in_flight_pending_req_at_after_get_resource_step_and_key_exists(SubResource::StatefulSet, rabbitmq, req_msg)(s)
+        &&& s.ongoing_reconciles()[rabbitmq.object_ref()].local_state.drained_replicas_opt == Some(rabbitmq.spec.replicas)
+        &&& !sub_resource_state_matches(SubResource::StatefulSet, rabbitmq)(s)
+    };
+    let post = |s: RMQCluster| {
+        &&& at_after_get_resource_step_and_exists_ok_resp_in_flight(SubResource::StatefulSet, rabbitmq)(s)
+        &&& s.ongoing_reconciles()[rabbitmq.object_ref()].local_state.drained_replicas_opt == Some(rabbitmq.spec.replicas)
+        &&& !sub_resource_state_matches(SubResource::StatefulSet, rabbitmq)(s)
+    };
+    let resource_key = get_request(SubResource::StatefulSet, rabbitmq).key;
+    let input = Some(req_msg);
+    let stronger_next = |s, s_prime: RMQCluster| {
+        &&& RMQCluster::next()(s, s_prime)
+        &&& RMQCluster::crash_disabled()(s)
+        &&& RMQCluster::busy_disabled()(s)
+        &&& RMQCluster::every_in_flight_msg_has_unique_id()(s)
+        &&& helper_invariants::every_resource_update_request_implies_at_after_update_resource_step(SubResource::StatefulSet, rabbitmq)(s)
+        &&& helper_invariants::no_delete_resource_request_msg_in_flight(SubResource::StatefulSet, rabbitmq)(s)
+        &&& helper_invariants::stateful_set_not_exists_or_matches_or_no_more_status_update(rabbitmq)(s)
+        &&& helper_invariants::cm_rv_stays_unchanged(rabbitmq)(s, s_prime)
+    };
+    combine_spec_entails_always_n!(
+        spec, lift_action(stronger_next),
+        lift_action(RMQCluster::next()),
+        lift_state(RMQCluster::crash_disabled()),
+        lift_state(RMQCluster::busy_disabled()),
+        lift_state(RMQCluster::every_in_flight_msg_has_unique_id()),
+        lift_state(helper_invariants::every_resource_update_request_implies_at_after_update_resource_step(SubResource::StatefulSet, rabbitmq)),
+        lift_state(helper_invariants::no_delete_resource_request_msg_in_flight(SubResource::StatefulSet, rabbitmq)),
+        lift_state(helper_invariants::stateful_set_not_exists_or_matches_or_no_more_status_update(rabbitmq)),
+        lift_action(helper_invariants::cm_rv_stays_unchanged(rabbitmq))
+    );
+
+    assert forall |s, s_prime| pre(s) && #[trigger] stronger_next(s, s_prime) implies pre(s_prime) || post(s_prime) by {
+        let step = choose |step| RMQCluster::next_step(s, s_prime, step);
+        match step {
+            Step::ApiServerStep(input) => {
+                let req = input.get_Some_0();
+                assert(!resource_delete_request_msg(resource_key)(req));
+                assert(!resource_update_request_msg(resource_key)(req));
+                assert(!resource_update_status_request_msg(resource_key)(req));
+                if req == req_msg {
+                    let resp_msg = RMQCluster::handle_get_request_msg(req_msg, s.kubernetes_api_state).1;
+                    assert({
+                        &&& s_prime.in_flight().contains(resp_msg)
+                        &&& Message::resp_msg_matches_req_msg(resp_msg, req_msg)
+                        &&& resp_msg.content.get_get_response().res.is_Ok()
+                        &&& resp_msg.content.get_get_response().res.get_Ok_0() == s_prime.resources()[resource_key]
+                    });
+                    assert(post(s_prime));
+                }
+            },
+            _ => {}
+        }
+    }
+
+    assert forall |s, s_prime| pre(s) && #[trigger] stronger_next(s, s_prime) && RMQCluster::kubernetes_api_next().forward(input)(s, s_prime)
+    implies post(s_prime) by {
+        let resp_msg = RMQCluster::handle_get_request_msg(req_msg, s.kubernetes_api_state).1;
+        assert({
+            &&& s_prime.in_flight().contains(resp_msg)
+            &&& Message::resp_msg_matches_req_msg(resp_msg, req_msg)
+            &&& resp_msg.content.get_get_response().res.is_Ok()
+            &&& resp_msg.content.get_get_response().res.get_Ok_0() == s_prime.resources()[resource_key]
+        });
+    }
+
+    RMQCluster::lemma_pre_leads_to_post_by_kubernetes_api(
+        spec, input, stronger_next, RMQCluster::handle_request(), pre, post
+    );
+}
+
 #[verifier(spinoff_prover)]
 proof fn lemma_from_after_get_stateful_set_step_to_after_update_stateful_set_step(
     spec: TempPred<RMQCluster>, rabbitmq: RabbitmqClusterView, resp_msg: RMQMessage
@@ -310,12 +897,14 @@ proof fn lemma_from_after_get_stateful_set_step_to_after_update_stateful_set_ste
         spec.entails(always(lift_state(helper_invariants::cm_rv_is_the_same_as_etcd_server_cm_if_cm_updated(rabbitmq)))),
         spec.entails(always(lift_state(helper_invariants::resource_object_only_has_owner_reference_pointing_to_current_cr(SubResource::StatefulSet, rabbitmq)))),
         spec.entails(always(lift_state(helper_invariants::stateful_set_in_etcd_satisfies_unchangeable(rabbitmq)))),
+        spec.entails(always(lift_state(helper_invariants::drained_replicas_in_reconcile_state_is_none_or_desired_replicas(rabbitmq.object_ref())))),
         spec.entails(always(lift_action(helper_invariants::cm_rv_stays_unchanged(rabbitmq)))),
     ensures
         spec.entails(
             lift_state(|s: RMQCluster| {
                 &&& resp_msg_is_the_in_flight_ok_resp_at_after_get_resource_step(SubResource::StatefulSet, rabbitmq, resp_msg)(s)
                 &&& !sub_resource_state_matches(SubResource::StatefulSet, rabbitmq)(s)
+                &&& !drain_is_needed(rabbitmq, resp_msg)(s)
             })
             .leads_to(lift_state(|s: RMQCluster| {
                 &&& pending_req_in_flight_at_after_update_resource_step(SubResource::StatefulSet, rabbitmq)(s)
@@ -326,6 +915,7 @@ proof fn lemma_from_after_get_stateful_set_step_to_after_update_stateful_set_ste
     let pre = |s: RMQCluster| {
         &&& resp_msg_is_the_in_flight_ok_resp_at_after_get_resource_step(SubResource::StatefulSet, rabbitmq, resp_msg)(s)
         &&& !sub_resource_state_matches(SubResource::StatefulSet, rabbitmq)(s)
+        &&& !drain_is_needed(rabbitmq, resp_msg)(s)
     };
     let post = |s: RMQCluster| {
         &&& pending_req_in_flight_at_after_update_resource_step(SubResource::StatefulSet, rabbitmq)(s)
@@ -346,6 +936,7 @@ proof fn lemma_from_after_get_stateful_set_step_to_after_update_stateful_set_ste
         &&& helper_invariants::cm_rv_is_the_same_as_etcd_server_cm_if_cm_updated(rabbitmq)(s)
         &&& helper_invariants::resource_object_only_has_owner_reference_pointing_to_current_cr(SubResource::StatefulSet, rabbitmq)(s)
         &&& helper_invariants::stateful_set_in_etcd_satisfies_unchangeable(rabbitmq)(s)
+        &&& helper_invariants::drained_replicas_in_reconcile_state_is_none_or_desired_replicas(rabbitmq.object_ref())(s)
         &&& helper_invariants::cm_rv_stays_unchanged(rabbitmq)(s, s_prime)
     };
 
@@ -364,6 +955,7 @@ proof fn lemma_from_after_get_stateful_set_step_to_after_update_stateful_set_ste
         lift_state(helper_invariants::cm_rv_is_the_same_as_etcd_server_cm_if_cm_updated(rabbitmq)),
         lift_state(helper_invariants::resource_object_only_has_owner_reference_pointing_to_current_cr(SubResource::StatefulSet, rabbitmq)),
         lift_state(helper_invariants::stateful_set_in_etcd_satisfies_unchangeable(rabbitmq)),
+        lift_state(helper_invariants::drained_replicas_in_reconcile_state_is_none_or_desired_replicas(rabbitmq.object_ref())),
         lift_action(helper_invariants::cm_rv_stays_unchanged(rabbitmq))
     );
 
@@ -463,6 +1055,7 @@ proof fn lemma_stateful_set_state_matches_at_after_update_stateful_set_step(spec
         let resp = RMQCluster::handle_update_request_msg(pending_msg, s.kubernetes_api_state).1;
         assert(s_prime.in_flight().contains(resp));
         StatefulSetView::marshal_preserves_integrity();
+        lemma_made_stateful_set_mounts_tls_secrets(rabbitmq);
     }
 
     assert forall |s, s_prime: RMQCluster| pre(s) && #[trigger] stronger_next(s, s_prime) implies pre(s_prime) || post(s_prime) by {
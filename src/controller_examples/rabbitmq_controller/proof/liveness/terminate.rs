@@ -1,7 +1,6 @@
 // Copyright 2022 VMware, Inc.
 // SPDX-License-Identifier: MIT
 #![allow(unused_imports)]
-use crate::external_api::spec::EmptyAPI;
 use crate::kubernetes_api_objects::spec::{
     api_method::*, common::*, dynamic::*, resource::*, stateful_set::*,
 };
@@ -33,6 +32,14 @@ pub proof fn reconcile_eventually_terminates(spec: TempPred<RMQCluster>, rabbitm
         spec.entails(always(lift_state(RMQCluster::pending_req_of_key_is_unique_with_unique_id(rabbitmq.object_ref())))),
         spec.entails(always(lift_state(RMQCluster::no_pending_req_msg_at_reconcile_state(
             rabbitmq.object_ref(), |s: RabbitmqReconcileState| s.reconcile_step == RabbitmqReconcileStep::Init)))),
+        spec.entails(always(lift_state(RMQCluster::pending_req_in_flight_or_resp_in_flight_at_reconcile_state(
+            rabbitmq.object_ref(), at_step_closure(RabbitmqReconcileStep::AfterUpdateStatus))))),
+        spec.entails(always(lift_state(RMQCluster::pending_req_in_flight_or_resp_in_flight_at_reconcile_state(
+            rabbitmq.object_ref(), at_step_closure(RabbitmqReconcileStep::AfterUpdateDegradedStatus))))),
+        spec.entails(always(lift_state(RMQCluster::pending_req_in_flight_or_resp_in_flight_at_reconcile_state(
+            rabbitmq.object_ref(), at_step_closure(RabbitmqReconcileStep::AfterSetPlugins))))),
+        spec.entails(always(lift_state(RMQCluster::pending_req_in_flight_or_resp_in_flight_at_reconcile_state(
+            rabbitmq.object_ref(), at_step_closure(RabbitmqReconcileStep::AfterDrainNodes))))),
         spec.entails(always(tla_forall(|step: (ActionKind, SubResource)| lift_state(RMQCluster::pending_req_in_flight_or_resp_in_flight_at_reconcile_state(
                 rabbitmq.object_ref(), at_step_closure(RabbitmqReconcileStep::AfterKRequestStep(step.0, step.1))
             ))))),
@@ -58,9 +65,33 @@ pub proof fn reconcile_eventually_terminates(spec: TempPred<RMQCluster>, rabbitm
     temp_pred_equality(lift_state(at_step_state_pred(rabbitmq, RabbitmqReconcileStep::Error)), lift_state(RMQCluster::reconciler_reconcile_error(rabbitmq.object_ref())));
     entails_implies_leads_to(spec, lift_state(reconcile_idle), lift_state(reconcile_idle));
 
+    // Then, prove that after_update_degraded_status ~> reconcile_idle, since it always ends in error.
+    RMQCluster::lemma_from_some_state_to_arbitrary_next_state_to_reconcile_idle(
+        spec, rabbitmq, at_step_closure(RabbitmqReconcileStep::AfterUpdateDegradedStatus), at_step_closure(RabbitmqReconcileStep::Error)
+    );
+
     // Second, prove that the sub resource that every intermediate steps can lead to reconcile idle.
-    lemma_from_after_get_resource_step_to_after_get_next_resource_step_to_reconcile_idle(spec, rabbitmq, SubResource::StatefulSet, RabbitmqReconcileStep::Done);
-    lemma_from_after_get_resource_step_to_after_get_next_resource_step_to_reconcile_idle(spec, rabbitmq, SubResource::RoleBinding, after_get_k_request_step(SubResource::StatefulSet));
+    or_leads_to_combine_and_equality!(spec,
+        lift_state(at_step1_or_step2_state_pred(rabbitmq, RabbitmqReconcileStep::Done, RabbitmqReconcileStep::AfterUpdateDegradedStatus)),
+        lift_state(at_step_state_pred(rabbitmq, RabbitmqReconcileStep::Done)), lift_state(at_step_state_pred(rabbitmq, RabbitmqReconcileStep::AfterUpdateDegradedStatus));
+        lift_state(reconcile_idle)
+    );
+    RMQCluster::lemma_from_some_state_to_arbitrary_next_state_to_reconcile_idle(
+        spec, rabbitmq, at_step_closure(RabbitmqReconcileStep::AfterSetPlugins),
+        at_step1_or_step2_closure(RabbitmqReconcileStep::Done, RabbitmqReconcileStep::AfterUpdateDegradedStatus)
+    );
+    or_leads_to_combine_and_equality!(spec,
+        lift_state(at_step1_or_step2_state_pred(rabbitmq, RabbitmqReconcileStep::AfterSetPlugins, RabbitmqReconcileStep::Error)),
+        lift_state(at_step_state_pred(rabbitmq, RabbitmqReconcileStep::AfterSetPlugins)), lift_state(at_step_state_pred(rabbitmq, RabbitmqReconcileStep::Error));
+        lift_state(reconcile_idle)
+    );
+    RMQCluster::lemma_from_some_state_to_arbitrary_next_state_to_reconcile_idle(
+        spec, rabbitmq, at_step_closure(RabbitmqReconcileStep::AfterUpdateStatus),
+        at_step1_or_step2_closure(RabbitmqReconcileStep::AfterSetPlugins, RabbitmqReconcileStep::Error)
+    );
+    lemma_from_after_get_stateful_set_step_to_after_update_status_step_to_reconcile_idle(spec, rabbitmq);
+    lemma_from_after_get_resource_step_to_after_get_next_resource_step_to_reconcile_idle(spec, rabbitmq, SubResource::PodDisruptionBudget, after_get_k_request_step(SubResource::StatefulSet));
+    lemma_from_after_get_resource_step_to_after_get_next_resource_step_to_reconcile_idle(spec, rabbitmq, SubResource::RoleBinding, after_get_k_request_step(SubResource::PodDisruptionBudget));
     lemma_from_after_get_resource_step_to_after_get_next_resource_step_to_reconcile_idle(spec, rabbitmq, SubResource::Role, after_get_k_request_step(SubResource::RoleBinding));
     lemma_from_after_get_resource_step_to_after_get_next_resource_step_to_reconcile_idle(spec, rabbitmq, SubResource::ServiceAccount, after_get_k_request_step(SubResource::Role));
     lemma_from_after_get_resource_step_to_after_get_next_resource_step_to_reconcile_idle(spec, rabbitmq, SubResource::ServerConfigMap, after_get_k_request_step(SubResource::ServiceAccount));
@@ -88,7 +119,11 @@ pub proof fn reconcile_eventually_terminates(spec: TempPred<RMQCluster>, rabbitm
         lift_state(state_pred_regarding_sub_resource(rabbitmq, SubResource::ServiceAccount)),
         lift_state(state_pred_regarding_sub_resource(rabbitmq, SubResource::Role)),
         lift_state(state_pred_regarding_sub_resource(rabbitmq, SubResource::RoleBinding)),
+        lift_state(state_pred_regarding_sub_resource(rabbitmq, SubResource::PodDisruptionBudget)),
         lift_state(state_pred_regarding_sub_resource(rabbitmq, SubResource::StatefulSet)),
+        lift_state(at_step_state_pred(rabbitmq, RabbitmqReconcileStep::AfterUpdateStatus)),
+        lift_state(at_step_state_pred(rabbitmq, RabbitmqReconcileStep::AfterSetPlugins)),
+        lift_state(at_step_state_pred(rabbitmq, RabbitmqReconcileStep::AfterUpdateDegradedStatus)),
         lift_state(at_step_state_pred(rabbitmq, RabbitmqReconcileStep::Done)),
         lift_state(at_step_state_pred(rabbitmq, RabbitmqReconcileStep::Error));
         lift_state(reconcile_idle)
@@ -99,6 +134,14 @@ pub open spec fn at_step_state_pred(rabbitmq: RabbitmqClusterView, step: Rabbitm
     RMQCluster::at_expected_reconcile_states(rabbitmq.object_ref(), |s: RabbitmqReconcileState| s.reconcile_step == step)
 }
 
+pub open spec fn at_step1_or_step2_closure(step1: RabbitmqReconcileStep, step2: RabbitmqReconcileStep) -> spec_fn(RabbitmqReconcileState) -> bool {
+    |s: RabbitmqReconcileState| s.reconcile_step == step1 || s.reconcile_step == step2
+}
+
+pub open spec fn at_step1_or_step2_state_pred(rabbitmq: RabbitmqClusterView, step1: RabbitmqReconcileStep, step2: RabbitmqReconcileStep) -> StatePred<RMQCluster> {
+    RMQCluster::at_expected_reconcile_states(rabbitmq.object_ref(), at_step1_or_step2_closure(step1, step2))
+}
+
 pub open spec fn state_pred_regarding_sub_resource(rabbitmq: RabbitmqClusterView, sub_resource: SubResource) -> StatePred<RMQCluster> {
     RMQCluster::at_expected_reconcile_states(
         rabbitmq.object_ref(),
@@ -129,7 +172,7 @@ proof fn lemma_from_after_get_resource_step_to_after_get_next_resource_step_to_r
         next_resource_after(sub_resource) == next_step,
         spec.entails(lift_state(at_step_state_pred(rabbitmq, next_step))
             .leads_to(lift_state(|s: RMQCluster| !s.ongoing_reconciles().contains_key(rabbitmq.object_ref())))),
-        spec.entails(lift_state(at_step_state_pred(rabbitmq, RabbitmqReconcileStep::Error))
+        spec.entails(lift_state(at_step_state_pred(rabbitmq, RabbitmqReconcileStep::AfterUpdateDegradedStatus))
             .leads_to(lift_state(|s: RMQCluster| !s.ongoing_reconciles().contains_key(rabbitmq.object_ref())))),
     ensures
         spec.entails(lift_state(at_step_state_pred(rabbitmq, after_get_k_request_step(sub_resource)))
@@ -139,12 +182,12 @@ proof fn lemma_from_after_get_resource_step_to_after_get_next_resource_step_to_r
 {
     let state_after_create_or_update = |s: RabbitmqReconcileState| {
         s.reconcile_step == next_step
-        || s.reconcile_step == RabbitmqReconcileStep::Error
+        || s.reconcile_step == RabbitmqReconcileStep::AfterUpdateDegradedStatus
     };
     or_leads_to_combine_and_equality!(
         spec, lift_state(RMQCluster::at_expected_reconcile_states(rabbitmq.object_ref(), state_after_create_or_update)),
         lift_state(at_step_state_pred(rabbitmq, next_step)),
-        lift_state(at_step_state_pred(rabbitmq, RabbitmqReconcileStep::Error));
+        lift_state(at_step_state_pred(rabbitmq, RabbitmqReconcileStep::AfterUpdateDegradedStatus));
         lift_state(|s: RMQCluster| { !s.ongoing_reconciles().contains_key(rabbitmq.object_ref()) })
     );
     RMQCluster::lemma_from_some_state_to_arbitrary_next_state_to_reconcile_idle(spec, rabbitmq, at_step_closure(after_create_k_request_step(sub_resource)), state_after_create_or_update);
@@ -153,13 +196,13 @@ proof fn lemma_from_after_get_resource_step_to_after_get_next_resource_step_to_r
     let state_after_get = |s: RabbitmqReconcileState| {
         s.reconcile_step == after_create_k_request_step(sub_resource)
         || s.reconcile_step == after_update_k_request_step(sub_resource)
-        || s.reconcile_step == RabbitmqReconcileStep::Error
+        || s.reconcile_step == RabbitmqReconcileStep::AfterUpdateDegradedStatus
     };
     or_leads_to_combine_and_equality!(
         spec, lift_state(RMQCluster::at_expected_reconcile_states(rabbitmq.object_ref(), state_after_get)),
         lift_state(at_step_state_pred(rabbitmq, after_create_k_request_step(sub_resource))),
         lift_state(at_step_state_pred(rabbitmq, after_update_k_request_step(sub_resource))),
-        lift_state(at_step_state_pred(rabbitmq, RabbitmqReconcileStep::Error));
+        lift_state(at_step_state_pred(rabbitmq, RabbitmqReconcileStep::AfterUpdateDegradedStatus));
         lift_state(|s: RMQCluster| { !s.ongoing_reconciles().contains_key(rabbitmq.object_ref()) })
     );
     RMQCluster::lemma_from_some_state_to_arbitrary_next_state_to_reconcile_idle(spec, rabbitmq, at_step_closure(after_get_k_request_step(sub_resource)), state_after_get);
@@ -172,4 +215,125 @@ proof fn lemma_from_after_get_resource_step_to_after_get_next_resource_step_to_r
     );
 }
 
+// The stateful set step differs from the other sub resources because a scale-down first goes through AfterDrainNodes
+// and then gets the stateful set again. The second get always carries drained_replicas_opt, which rules out another
+// drain, so we split the get step on drained_replicas_opt to show that this detour happens at most once.
+proof fn lemma_from_after_get_stateful_set_step_to_after_update_status_step_to_reconcile_idle(spec: TempPred<RMQCluster>, rabbitmq: RabbitmqClusterView)
+    requires
+        spec.entails(always(lift_action(RMQCluster::next()))),
+        spec.entails(tla_forall(|i| RMQCluster::kubernetes_api_next().weak_fairness(i))),
+        spec.entails(tla_forall(|i| RMQCluster::external_api_next().weak_fairness(i))),
+        spec.entails(tla_forall(|i| RMQCluster::controller_next().weak_fairness(i))),
+        spec.entails(always(lift_state(RMQCluster::crash_disabled()))),
+        spec.entails(always(lift_state(RMQCluster::busy_disabled()))),
+        spec.entails(always(lift_state(RMQCluster::every_in_flight_msg_has_unique_id()))),
+        spec.entails(always(lift_state(RMQCluster::pending_req_of_key_is_unique_with_unique_id(rabbitmq.object_ref())))),
+        forall |action: ActionKind| #![auto]
+            spec.entails(always(lift_state(RMQCluster::pending_req_in_flight_or_resp_in_flight_at_reconcile_state(
+                rabbitmq.object_ref(), at_step_closure(RabbitmqReconcileStep::AfterKRequestStep(action, SubResource::StatefulSet)
+            ))))),
+        spec.entails(always(lift_state(RMQCluster::pending_req_in_flight_or_resp_in_flight_at_reconcile_state(
+            rabbitmq.object_ref(), at_step_closure(RabbitmqReconcileStep::AfterDrainNodes))))),
+        spec.entails(lift_state(at_step_state_pred(rabbitmq, RabbitmqReconcileStep::AfterUpdateStatus))
+            .leads_to(lift_state(|s: RMQCluster| !s.ongoing_reconciles().contains_key(rabbitmq.object_ref())))),
+        spec.entails(lift_state(at_step_state_pred(rabbitmq, RabbitmqReconcileStep::AfterUpdateDegradedStatus))
+            .leads_to(lift_state(|s: RMQCluster| !s.ongoing_reconciles().contains_key(rabbitmq.object_ref())))),
+    ensures
+        spec.entails(lift_state(at_step_state_pred(rabbitmq, after_get_k_request_step(SubResource::StatefulSet)))
+            .leads_to(lift_state(|s: RMQCluster| !s.ongoing_reconciles().contains_key(rabbitmq.object_ref())))),
+        spec.entails(lift_state(state_pred_regarding_sub_resource(rabbitmq, SubResource::StatefulSet))
+            .leads_to(lift_state(|s: RMQCluster| !s.ongoing_reconciles().contains_key(rabbitmq.object_ref())))),
+{
+    let sub_resource = SubResource::StatefulSet;
+    let reconcile_idle = |s: RMQCluster| { !s.ongoing_reconciles().contains_key(rabbitmq.object_ref()) };
+    let state_after_create_or_update = |s: RabbitmqReconcileState| {
+        s.reconcile_step == RabbitmqReconcileStep::AfterUpdateStatus
+        || s.reconcile_step == RabbitmqReconcileStep::AfterUpdateDegradedStatus
+    };
+    or_leads_to_combine_and_equality!(
+        spec, lift_state(RMQCluster::at_expected_reconcile_states(rabbitmq.object_ref(), state_after_create_or_update)),
+        lift_state(at_step_state_pred(rabbitmq, RabbitmqReconcileStep::AfterUpdateStatus)),
+        lift_state(at_step_state_pred(rabbitmq, RabbitmqReconcileStep::AfterUpdateDegradedStatus));
+        lift_state(reconcile_idle)
+    );
+    RMQCluster::lemma_from_some_state_to_arbitrary_next_state_to_reconcile_idle(spec, rabbitmq, at_step_closure(after_create_k_request_step(sub_resource)), state_after_create_or_update);
+    RMQCluster::lemma_from_some_state_to_arbitrary_next_state_to_reconcile_idle(spec, rabbitmq, at_step_closure(after_update_k_request_step(sub_resource)), state_after_create_or_update);
+
+    let state_after_get = |s: RabbitmqReconcileState| {
+        s.reconcile_step == after_create_k_request_step(sub_resource)
+        || s.reconcile_step == after_update_k_request_step(sub_resource)
+        || s.reconcile_step == RabbitmqReconcileStep::AfterUpdateDegradedStatus
+    };
+    or_leads_to_combine_and_equality!(
+        spec, lift_state(RMQCluster::at_expected_reconcile_states(rabbitmq.object_ref(), state_after_get)),
+        lift_state(at_step_state_pred(rabbitmq, after_create_k_request_step(sub_resource))),
+        lift_state(at_step_state_pred(rabbitmq, after_update_k_request_step(sub_resource))),
+        lift_state(at_step_state_pred(rabbitmq, RabbitmqReconcileStep::AfterUpdateDegradedStatus));
+        lift_state(reconcile_idle)
+    );
+
+    // The get step after draining the nodes can only go to create, update or error.
+    let at_get_after_drain = |s: RabbitmqReconcileState| {
+        s.reconcile_step == after_get_k_request_step(sub_resource) && s.drained_replicas_opt.is_Some()
+    };
+    let at_get_before_drain = |s: RabbitmqReconcileState| {
+        s.reconcile_step == after_get_k_request_step(sub_resource) && s.drained_replicas_opt.is_None()
+    };
+    lemma_pending_req_in_flight_or_resp_in_flight_at_get_stateful_set_step_split(spec, rabbitmq, at_get_after_drain);
+    lemma_pending_req_in_flight_or_resp_in_flight_at_get_stateful_set_step_split(spec, rabbitmq, at_get_before_drain);
+    RMQCluster::lemma_from_some_state_to_arbitrary_next_state_to_reconcile_idle(spec, rabbitmq, at_get_after_drain, state_after_get);
+
+    // Draining the nodes goes back to the get step with drained_replicas_opt set.
+    let state_after_drain = |s: RabbitmqReconcileState| at_get_after_drain(s) || s.reconcile_step == RabbitmqReconcileStep::AfterUpdateDegradedStatus;
+    or_leads_to_combine_and_equality!(
+        spec, lift_state(RMQCluster::at_expected_reconcile_states(rabbitmq.object_ref(), state_after_drain)),
+        lift_state(RMQCluster::at_expected_reconcile_states(rabbitmq.object_ref(), at_get_after_drain)),
+        lift_state(at_step_state_pred(rabbitmq, RabbitmqReconcileStep::AfterUpdateDegradedStatus));
+        lift_state(reconcile_idle)
+    );
+    RMQCluster::lemma_from_some_state_to_arbitrary_next_state_to_reconcile_idle(spec, rabbitmq, at_step_closure(RabbitmqReconcileStep::AfterDrainNodes), state_after_drain);
+
+    // The get step before draining the nodes can go to create, update, drain or error.
+    let state_after_get_before_drain = |s: RabbitmqReconcileState| state_after_get(s) || s.reconcile_step == RabbitmqReconcileStep::AfterDrainNodes;
+    or_leads_to_combine_and_equality!(
+        spec, lift_state(RMQCluster::at_expected_reconcile_states(rabbitmq.object_ref(), state_after_get_before_drain)),
+        lift_state(RMQCluster::at_expected_reconcile_states(rabbitmq.object_ref(), state_after_get)),
+        lift_state(at_step_state_pred(rabbitmq, RabbitmqReconcileStep::AfterDrainNodes));
+        lift_state(reconcile_idle)
+    );
+    RMQCluster::lemma_from_some_state_to_arbitrary_next_state_to_reconcile_idle(spec, rabbitmq, at_get_before_drain, state_after_get_before_drain);
+
+    or_leads_to_combine_and_equality!(
+        spec, lift_state(at_step_state_pred(rabbitmq, after_get_k_request_step(sub_resource))),
+        lift_state(RMQCluster::at_expected_reconcile_states(rabbitmq.object_ref(), at_get_after_drain)),
+        lift_state(RMQCluster::at_expected_reconcile_states(rabbitmq.object_ref(), at_get_before_drain));
+        lift_state(reconcile_idle)
+    );
+    or_leads_to_combine_and_equality!(
+        spec, lift_state(state_pred_regarding_sub_resource(rabbitmq, sub_resource)),
+        lift_state(at_step_state_pred(rabbitmq, after_get_k_request_step(sub_resource))),
+        lift_state(at_step_state_pred(rabbitmq, after_create_k_request_step(sub_resource))),
+        lift_state(at_step_state_pred(rabbitmq, after_update_k_request_step(sub_resource)));
+        lift_state(reconcile_idle)
+    );
+}
+
+proof fn lemma_pending_req_in_flight_or_resp_in_flight_at_get_stateful_set_step_split(
+    spec: TempPred<RMQCluster>, rabbitmq: RabbitmqClusterView, state: spec_fn(RabbitmqReconcileState) -> bool
+)
+    requires
+        spec.entails(always(lift_state(RMQCluster::pending_req_in_flight_or_resp_in_flight_at_reconcile_state(
+            rabbitmq.object_ref(), at_step_closure(after_get_k_request_step(SubResource::StatefulSet))
+        )))),
+        forall |s: RabbitmqReconcileState| #[trigger] state(s) ==> s.reconcile_step == after_get_k_request_step(SubResource::StatefulSet),
+    ensures
+        spec.entails(always(lift_state(RMQCluster::pending_req_in_flight_or_resp_in_flight_at_reconcile_state(rabbitmq.object_ref(), state)))),
+{
+    always_weaken(
+        spec,
+        lift_state(RMQCluster::pending_req_in_flight_or_resp_in_flight_at_reconcile_state(rabbitmq.object_ref(), at_step_closure(after_get_k_request_step(SubResource::StatefulSet)))),
+        lift_state(RMQCluster::pending_req_in_flight_or_resp_in_flight_at_reconcile_state(rabbitmq.object_ref(), state))
+    );
+}
+
 }
@@ -14,7 +14,8 @@ use crate::kubernetes_cluster::spec::{
 use crate::rabbitmq_controller::model::{reconciler::*, resource::*};
 use crate::rabbitmq_controller::proof::resource::*;
 use crate::rabbitmq_controller::trusted::{
-    liveness_theorem::resource_state_matches, spec_types::*, step::*,
+    liveness_theorem::resource_state_matches, rabbitmq_api_spec::*,
+    safety_theorem::replicas_of_stateful_set, spec_types::*, step::*,
 };
 use crate::temporal_logic::defs::*;
 use vstd::prelude::*;
@@ -80,8 +81,9 @@ pub open spec fn next_resource_after(sub_resource: SubResource) -> RabbitmqRecon
         SubResource::ServerConfigMap => after_get_k_request_step(SubResource::ServiceAccount),
         SubResource::ServiceAccount => after_get_k_request_step(SubResource::Role),
         SubResource::Role => after_get_k_request_step(SubResource::RoleBinding),
-        SubResource::RoleBinding => after_get_k_request_step(SubResource::StatefulSet),
-        _ => RabbitmqReconcileStep::Done,
+        SubResource::RoleBinding => after_get_k_request_step(SubResource::PodDisruptionBudget),
+        SubResource::PodDisruptionBudget => after_get_k_request_step(SubResource::StatefulSet),
+        SubResource::StatefulSet => RabbitmqReconcileStep::AfterUpdateStatus,
     }
 }
 
@@ -361,4 +363,80 @@ pub open spec fn resp_msg_is_the_in_flight_ok_resp_at_after_update_resource_step
     }
 }
 
+// The drain nodes request is made from the stateful set in etcd, which has more replicas than desired.
+pub open spec fn pending_req_in_flight_at_after_drain_nodes_step(rabbitmq: RabbitmqClusterView) -> StatePred<RMQCluster> {
+    |s: RMQCluster| {
+        let step = RabbitmqReconcileStep::AfterDrainNodes;
+        let msg = s.ongoing_reconciles()[rabbitmq.object_ref()].pending_req_msg.get_Some_0();
+        let sts_key = get_request(SubResource::StatefulSet, rabbitmq).key;
+        &&& at_rabbitmq_step_with_rabbitmq(rabbitmq, step)(s)
+        &&& s.ongoing_reconciles()[rabbitmq.object_ref()].pending_req_msg.is_Some()
+        &&& s.in_flight().contains(msg)
+        &&& msg.src == HostId::CustomController
+        &&& msg.dst == HostId::ExternalAPI
+        &&& msg.content.is_ExternalAPIRequest()
+        &&& s.resources().contains_key(sts_key)
+        &&& replicas_of_stateful_set(s.resources()[sts_key]) > rabbitmq.spec.replicas
+        &&& msg.content.get_ExternalAPIRequest_0() == drain_nodes_request(rabbitmq, replicas_of_stateful_set(s.resources()[sts_key]))
+    }
+}
+
+pub open spec fn req_msg_is_the_in_flight_pending_req_at_after_drain_nodes_step(rabbitmq: RabbitmqClusterView, req_msg: RMQMessage) -> StatePred<RMQCluster> {
+    |s: RMQCluster| {
+        let step = RabbitmqReconcileStep::AfterDrainNodes;
+        let sts_key = get_request(SubResource::StatefulSet, rabbitmq).key;
+        &&& at_rabbitmq_step_with_rabbitmq(rabbitmq, step)(s)
+        &&& RMQCluster::pending_req_msg_is(s, rabbitmq.object_ref(), req_msg)
+        &&& s.in_flight().contains(req_msg)
+        &&& req_msg.src == HostId::CustomController
+        &&& req_msg.dst == HostId::ExternalAPI
+        &&& req_msg.content.is_ExternalAPIRequest()
+        &&& s.resources().contains_key(sts_key)
+        &&& replicas_of_stateful_set(s.resources()[sts_key]) > rabbitmq.spec.replicas
+        &&& req_msg.content.get_ExternalAPIRequest_0() == drain_nodes_request(rabbitmq, replicas_of_stateful_set(s.resources()[sts_key]))
+    }
+}
+
+pub open spec fn at_after_drain_nodes_step_and_exists_ok_resp_in_flight(rabbitmq: RabbitmqClusterView) -> StatePred<RMQCluster> {
+    |s: RMQCluster| {
+        let step = RabbitmqReconcileStep::AfterDrainNodes;
+        let msg = s.ongoing_reconciles()[rabbitmq.object_ref()].pending_req_msg.get_Some_0();
+        &&& at_rabbitmq_step_with_rabbitmq(rabbitmq, step)(s)
+        &&& s.ongoing_reconciles()[rabbitmq.object_ref()].pending_req_msg.is_Some()
+        &&& msg.src == HostId::CustomController
+        &&& msg.dst == HostId::ExternalAPI
+        &&& msg.content.is_ExternalAPIRequest()
+        &&& exists |resp_msg: RMQMessage| {
+            let resp = resp_msg.content.get_ExternalAPIResponse_0();
+            &&& #[trigger] s.in_flight().contains(resp_msg)
+            &&& Message::resp_msg_matches_req_msg(resp_msg, msg)
+            &&& resp == RabbitmqAPIOutputView::DrainNodesResponse(RabbitmqAPIDrainNodesResultView{res: Ok(())})
+        }
+    }
+}
+
+pub open spec fn resp_msg_is_the_in_flight_ok_resp_at_after_drain_nodes_step(rabbitmq: RabbitmqClusterView, resp_msg: RMQMessage) -> StatePred<RMQCluster> {
+    |s: RMQCluster| {
+        let step = RabbitmqReconcileStep::AfterDrainNodes;
+        let msg = s.ongoing_reconciles()[rabbitmq.object_ref()].pending_req_msg.get_Some_0();
+        let resp = resp_msg.content.get_ExternalAPIResponse_0();
+        &&& at_rabbitmq_step_with_rabbitmq(rabbitmq, step)(s)
+        &&& s.ongoing_reconciles()[rabbitmq.object_ref()].pending_req_msg.is_Some()
+        &&& msg.src == HostId::CustomController
+        &&& msg.dst == HostId::ExternalAPI
+        &&& msg.content.is_ExternalAPIRequest()
+        &&& s.in_flight().contains(resp_msg)
+        &&& Message::resp_msg_matches_req_msg(resp_msg, msg)
+        &&& resp == RabbitmqAPIOutputView::DrainNodesResponse(RabbitmqAPIDrainNodesResultView{res: Ok(())})
+    }
+}
+
+// After the drain succeeds, the reconciler gets the stateful set again and is allowed to scale it down.
+pub open spec fn pending_req_in_flight_at_after_get_stateful_set_step_after_drain(rabbitmq: RabbitmqClusterView) -> StatePred<RMQCluster> {
+    |s: RMQCluster| {
+        &&& pending_req_in_flight_at_after_get_resource_step(SubResource::StatefulSet, rabbitmq)(s)
+        &&& s.ongoing_reconciles()[rabbitmq.object_ref()].local_state.drained_replicas_opt == Some(rabbitmq.spec.replicas)
+    }
+}
+
 }
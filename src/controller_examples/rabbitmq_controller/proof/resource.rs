@@ -7,6 +7,9 @@ use crate::kubernetes_api_objects::spec::{
     volume::*,
 };
 use crate::rabbitmq_controller::model::resource::*;
+use crate::rabbitmq_controller::trusted::liveness_theorem::{
+    tls_ports_are_exposed, tls_secrets_are_mounted,
+};
 use crate::rabbitmq_controller::trusted::spec_types::{
     RabbitmqClusterView, RabbitmqReconcileState,
 };
@@ -31,6 +34,7 @@ pub open spec fn get_request(sub_resource: SubResource, rabbitmq: RabbitmqCluste
         SubResource::ServiceAccount => ServiceAccountBuilder::get_request(rabbitmq),
         SubResource::Role => RoleBuilder::get_request(rabbitmq),
         SubResource::RoleBinding => RoleBindingBuilder::get_request(rabbitmq),
+        SubResource::PodDisruptionBudget => PodDisruptionBudgetBuilder::get_request(rabbitmq),
         SubResource::StatefulSet => StatefulSetBuilder::get_request(rabbitmq),
     }
 }
@@ -46,6 +50,7 @@ pub open spec fn make(sub_resource: SubResource, rabbitmq: RabbitmqClusterView,
         SubResource::ServiceAccount => ServiceAccountBuilder::make(rabbitmq, state),
         SubResource::Role => RoleBuilder::make(rabbitmq, state),
         SubResource::RoleBinding => RoleBindingBuilder::make(rabbitmq, state),
+        SubResource::PodDisruptionBudget => PodDisruptionBudgetBuilder::make(rabbitmq, state),
         SubResource::StatefulSet => StatefulSetBuilder::make(rabbitmq, state),
     }
 }
@@ -61,6 +66,7 @@ pub open spec fn update(sub_resource: SubResource, rabbitmq: RabbitmqClusterView
         SubResource::ServiceAccount => ServiceAccountBuilder::update(rabbitmq, state, obj),
         SubResource::Role => RoleBuilder::update(rabbitmq, state, obj),
         SubResource::RoleBinding => RoleBindingBuilder::update(rabbitmq, state, obj),
+        SubResource::PodDisruptionBudget => PodDisruptionBudgetBuilder::update(rabbitmq, state, obj),
         SubResource::StatefulSet => StatefulSetBuilder::update(rabbitmq, state, obj),
     }
 }
@@ -78,6 +84,7 @@ pub open spec fn state_after_create(
         SubResource::ServiceAccount => ServiceAccountBuilder::state_after_create(rabbitmq, obj, state),
         SubResource::Role => RoleBuilder::state_after_create(rabbitmq, obj, state),
         SubResource::RoleBinding => RoleBindingBuilder::state_after_create(rabbitmq, obj, state),
+        SubResource::PodDisruptionBudget => PodDisruptionBudgetBuilder::state_after_create(rabbitmq, obj, state),
         SubResource::StatefulSet => StatefulSetBuilder::state_after_create(rabbitmq, obj, state),
     }
 }
@@ -95,6 +102,7 @@ pub open spec fn state_after_update(
         SubResource::ServiceAccount => ServiceAccountBuilder::state_after_update(rabbitmq, obj, state),
         SubResource::Role => RoleBuilder::state_after_update(rabbitmq, obj, state),
         SubResource::RoleBinding => RoleBindingBuilder::state_after_update(rabbitmq, obj, state),
+        SubResource::PodDisruptionBudget => PodDisruptionBudgetBuilder::state_after_update(rabbitmq, obj, state),
         SubResource::StatefulSet => StatefulSetBuilder::state_after_update(rabbitmq, obj, state),
     }
 }
@@ -118,4 +126,32 @@ pub open spec fn unchangeable(sub_resource: SubResource, object: DynamicObjectVi
     }
 }
 
+pub proof fn lemma_made_main_service_exposes_tls_ports(rabbitmq: RabbitmqClusterView)
+    ensures tls_ports_are_exposed(rabbitmq, make_main_service(rabbitmq).spec.get_Some_0()),
+{
+    if rabbitmq.spec.tls.is_Some() {
+        let ports = make_main_service(rabbitmq).spec.get_Some_0().ports.get_Some_0();
+        assert(ports[3].port == 5671);
+        assert(ports[4].port == 15671);
+    }
+}
+
+pub proof fn lemma_made_stateful_set_mounts_tls_secrets(rabbitmq: RabbitmqClusterView)
+    ensures forall |config_map_rv: StringView| tls_secrets_are_mounted(rabbitmq, #[trigger] make_stateful_set(rabbitmq, config_map_rv).spec),
+{
+    assert forall |config_map_rv: StringView| tls_secrets_are_mounted(rabbitmq, #[trigger] make_stateful_set(rabbitmq, config_map_rv).spec) by {
+        if rabbitmq.spec.tls.is_Some() {
+            // The tls volumes are appended to the other volumes of the pod
+            let volumes = make_rabbitmq_pod_spec(rabbitmq).volumes.get_Some_0();
+            let tls_volumes = make_tls_volumes(rabbitmq);
+            let offset = volumes.len() - tls_volumes.len();
+            assert(make_stateful_set(rabbitmq, config_map_rv).spec.get_Some_0().template.spec == Some(make_rabbitmq_pod_spec(rabbitmq)));
+            assert(volumes[offset] == tls_volumes[0]);
+            if rabbitmq.spec.tls.get_Some_0().ca_secret_name.is_Some() {
+                assert(volumes[offset + 1] == tls_volumes[1]);
+            }
+        }
+    }
+}
+
 }
@@ -3,7 +3,7 @@
 #![allow(unused_imports)]
 use crate::external_api::spec::*;
 use crate::kubernetes_api_objects::spec::{
-    api_method::*, common::*, dynamic::*, resource::*, stateful_set::*,
+    api_method::*, common::*, dynamic::*, owner_reference::*, resource::*, stateful_set::*,
 };
 use crate::kubernetes_cluster::spec::{
     cluster::*,
@@ -14,7 +14,7 @@ use crate::kubernetes_cluster::spec::{
 use crate::rabbitmq_controller::{
     model::{reconciler::*, resource::*},
     proof::{helper_invariants::*, predicate::*, resource::*},
-    trusted::{safety_theorem::*, spec_types::*, step::*},
+    trusted::{rabbitmq_api_spec::*, safety_theorem::*, spec_types::*, step::*},
 };
 use crate::temporal_logic::{defs::*, rules::*};
 use vstd::prelude::*;
@@ -33,33 +33,33 @@ proof fn safety_proof_forall_rabbitmq()
 proof fn safety_proof(rabbitmq: RabbitmqClusterView)
     ensures cluster_spec_without_wf().entails(safety::<RabbitmqMaker>(rabbitmq)),
 {
-    lemma_stateful_set_never_scaled_down_for_rabbitmq(cluster_spec_without_wf(), rabbitmq);
+    lemma_stateful_set_scaled_down_only_after_drain_for_rabbitmq(cluster_spec_without_wf(), rabbitmq);
 }
 
 // This invariant is exactly the high-level property. The proof of this invariant is where we talk about update Message. It requires another two invariants to hold all the time:
-// - replicas_of_stateful_set_update_request_msg_is_no_smaller_than_etcd
+// - replicas_of_stateful_set_update_request_msg_is_no_smaller_than_etcd_or_drained
 // - object_in_sts_update_request_has_smaller_rv_than_etcd
 //
 // Invariant 2 is to show that every stateful set update request must specify the resource version because stateful set is allowed to update unconditionally. If resource version can be none, we can't rule out invalid update request through resource version. Invariant 3 is quite obvious.
-proof fn lemma_stateful_set_never_scaled_down_for_rabbitmq(spec: TempPred<RMQCluster>, rabbitmq: RabbitmqClusterView)
+proof fn lemma_stateful_set_scaled_down_only_after_drain_for_rabbitmq(spec: TempPred<RMQCluster>, rabbitmq: RabbitmqClusterView)
     requires
         spec.entails(lift_state(RMQCluster::init())),
         spec.entails(always(lift_action(RMQCluster::next()))),
-    ensures spec.entails(always(lift_action(stateful_set_not_scaled_down::<RabbitmqMaker>(rabbitmq)))),
+    ensures spec.entails(always(lift_action(stateful_set_scaled_down_only_after_drain::<RabbitmqMaker>(rabbitmq)))),
 {
-    let inv = stateful_set_not_scaled_down::<RabbitmqMaker>(rabbitmq);
+    let inv = stateful_set_scaled_down_only_after_drain::<RabbitmqMaker>(rabbitmq);
     let next = |s, s_prime| {
         &&& RMQCluster::next()(s, s_prime)
-        &&& replicas_of_stateful_set_update_request_msg_is_no_smaller_than_etcd(rabbitmq)(s)
+        &&& replicas_of_stateful_set_update_request_msg_is_no_smaller_than_etcd_or_drained(rabbitmq)(s)
         &&& object_in_resource_update_request_msg_has_smaller_rv_than_etcd(SubResource::StatefulSet, rabbitmq)(s)
         &&& RMQCluster::each_object_in_etcd_is_well_formed()(s)
         &&& RMQCluster::each_object_in_etcd_is_well_formed()(s_prime)
     };
-    lemma_always_replicas_of_stateful_set_update_request_msg_is_no_smaller_than_etcd(spec, rabbitmq);
+    lemma_always_replicas_of_stateful_set_update_request_msg_is_no_smaller_than_etcd_or_drained(spec, rabbitmq);
     lemma_always_object_in_resource_update_request_msg_has_smaller_rv_than_etcd(spec, SubResource::StatefulSet, rabbitmq);
     RMQCluster::lemma_always_each_object_in_etcd_is_well_formed(spec);
     always_to_always_later(spec, lift_state(RMQCluster::each_object_in_etcd_is_well_formed()));
-    assert forall |s, s_prime| #[trigger] next(s, s_prime) implies stateful_set_not_scaled_down::<RabbitmqMaker>(rabbitmq)(s, s_prime) by {
+    assert forall |s, s_prime| #[trigger] next(s, s_prime) implies stateful_set_scaled_down_only_after_drain::<RabbitmqMaker>(rabbitmq)(s, s_prime) by {
         let sts_key = make_stateful_set_key(rabbitmq);
         if s.resources().contains_key(sts_key) && s_prime.resources().contains_key(sts_key) {
             if s.resources()[sts_key].spec != s_prime.resources()[sts_key].spec {
@@ -71,20 +71,26 @@ proof fn lemma_stateful_set_never_scaled_down_for_rabbitmq(spec: TempPred<RMQClu
                     if resource_update_request_msg(sts_key)(input) {} else {}
                     assert(s.resources()[sts_key].metadata.resource_version.get_Some_0() == input.content.get_update_request().obj.metadata.resource_version.get_Some_0());
                     assert(replicas_of_stateful_set(s_prime.resources()[sts_key]) == replicas_of_stateful_set(input.content.get_update_request().obj));
-                    assert(replicas_of_stateful_set(s_prime.resources()[sts_key]) >= replicas_of_stateful_set(s.resources()[sts_key]));
+                    assert(s_prime.resources()[sts_key].metadata.owner_references == input.content.get_update_request().obj.metadata.owner_references);
                 }
             }
         }
     }
     invariant_n!(
         spec, lift_action(next), lift_action(inv),
-        lift_action(RMQCluster::next()), lift_state(replicas_of_stateful_set_update_request_msg_is_no_smaller_than_etcd(rabbitmq)),
+        lift_action(RMQCluster::next()), lift_state(replicas_of_stateful_set_update_request_msg_is_no_smaller_than_etcd_or_drained(rabbitmq)),
         lift_state(object_in_resource_update_request_msg_has_smaller_rv_than_etcd(SubResource::StatefulSet, rabbitmq)),
         lift_state(RMQCluster::each_object_in_etcd_is_well_formed()), later(lift_state(RMQCluster::each_object_in_etcd_is_well_formed()))
     );
 }
 
-spec fn replicas_of_stateful_set_update_request_msg_is_no_smaller_than_etcd(rabbitmq: RabbitmqClusterView) -> StatePred<RMQCluster> {
+pub open spec fn drained(owner_ref: OwnerReferenceView, replicas: int) -> StatePred<RMQCluster> {
+    |s: RMQCluster| s.external_state().drained_replicas.contains((owner_ref.uid, replicas))
+}
+
+// The update request either does not reduce the replicas of the stateful set it is going to overwrite,
+// or reduces them to replicas for which the drain check has already succeeded.
+spec fn replicas_of_stateful_set_update_request_msg_is_no_smaller_than_etcd_or_drained(rabbitmq: RabbitmqClusterView) -> StatePred<RMQCluster> {
     |s: RMQCluster| {
         let sts_key = make_stateful_set_key(rabbitmq);
         forall |msg: RMQMessage|
@@ -93,330 +99,253 @@ spec fn replicas_of_stateful_set_update_request_msg_is_no_smaller_than_etcd(rabb
             && s.resources().contains_key(sts_key)
             && s.resources()[sts_key].metadata.resource_version.get_Some_0() == msg.content.get_update_request().obj.metadata.resource_version.get_Some_0()
             ==> replicas_of_stateful_set(s.resources()[sts_key]) <= replicas_of_stateful_set(msg.content.get_update_request().obj)
+                || drained_for_owner(s, msg.content.get_update_request().obj, replicas_of_stateful_set(msg.content.get_update_request().obj))
     }
 }
 
-proof fn lemma_always_replicas_of_stateful_set_update_request_msg_is_no_smaller_than_etcd(spec: TempPred<RMQCluster>, rabbitmq: RabbitmqClusterView)
+proof fn lemma_always_replicas_of_stateful_set_update_request_msg_is_no_smaller_than_etcd_or_drained(spec: TempPred<RMQCluster>, rabbitmq: RabbitmqClusterView)
     requires
         spec.entails(lift_state(RMQCluster::init())),
         spec.entails(always(lift_action(RMQCluster::next()))),
-    ensures spec.entails(always(lift_state(replicas_of_stateful_set_update_request_msg_is_no_smaller_than_etcd(rabbitmq)))),
+    ensures spec.entails(always(lift_state(replicas_of_stateful_set_update_request_msg_is_no_smaller_than_etcd_or_drained(rabbitmq)))),
 {
-    let inv = replicas_of_stateful_set_update_request_msg_is_no_smaller_than_etcd(rabbitmq);
-    // let key = rabbitmq.object_ref();
+    let inv = replicas_of_stateful_set_update_request_msg_is_no_smaller_than_etcd_or_drained(rabbitmq);
     let sts_key = make_stateful_set_key(rabbitmq);
     let next = |s, s_prime| {
         &&& RMQCluster::next()(s, s_prime)
         &&& RMQCluster::each_object_in_etcd_is_well_formed()(s)
         &&& RMQCluster::each_object_in_etcd_is_well_formed()(s_prime)
-        &&& replicas_of_etcd_stateful_set_satisfies_order(rabbitmq)(s_prime)
         &&& object_in_resource_update_request_msg_has_smaller_rv_than_etcd(SubResource::StatefulSet, rabbitmq)(s)
         &&& RMQCluster::each_object_in_reconcile_has_consistent_key_and_valid_metadata()(s)
         &&& RMQCluster::object_in_ok_get_resp_is_same_as_etcd_with_same_rv(sts_key)(s)
         &&& response_at_after_get_resource_step_is_resource_get_response(SubResource::StatefulSet, rabbitmq)(s)
+        &&& drained_replicas_in_reconcile_state_is_recorded(rabbitmq)(s)
     };
     RMQCluster::lemma_always_each_object_in_etcd_is_well_formed(spec);
     always_to_always_later(spec, lift_state(RMQCluster::each_object_in_etcd_is_well_formed()));
-    lemma_always_replicas_of_etcd_stateful_set_satisfies_order(spec, rabbitmq);
-    always_to_always_later(spec, lift_state(replicas_of_etcd_stateful_set_satisfies_order(rabbitmq)));
     lemma_always_object_in_resource_update_request_msg_has_smaller_rv_than_etcd(spec, SubResource::StatefulSet, rabbitmq);
     RMQCluster::lemma_always_each_object_in_reconcile_has_consistent_key_and_valid_metadata(spec);
     RMQCluster::lemma_always_object_in_ok_get_resp_is_same_as_etcd_with_same_rv(spec, sts_key);
     lemma_always_response_at_after_get_resource_step_is_resource_get_response(spec, SubResource::StatefulSet, rabbitmq);
+    lemma_always_drained_replicas_in_reconcile_state_is_recorded(spec, rabbitmq);
     combine_spec_entails_always_n!(
         spec, lift_action(next), lift_action(RMQCluster::next()),
         lift_state(RMQCluster::each_object_in_etcd_is_well_formed()), later(lift_state(RMQCluster::each_object_in_etcd_is_well_formed())),
-        later(lift_state(replicas_of_etcd_stateful_set_satisfies_order(rabbitmq))),
         lift_state(object_in_resource_update_request_msg_has_smaller_rv_than_etcd(SubResource::StatefulSet, rabbitmq)),
         lift_state(RMQCluster::each_object_in_reconcile_has_consistent_key_and_valid_metadata()),
         lift_state(RMQCluster::object_in_ok_get_resp_is_same_as_etcd_with_same_rv(sts_key)),
-        lift_state(response_at_after_get_resource_step_is_resource_get_response(SubResource::StatefulSet, rabbitmq))
+        lift_state(response_at_after_get_resource_step_is_resource_get_response(SubResource::StatefulSet, rabbitmq)),
+        lift_state(drained_replicas_in_reconcile_state_is_recorded(rabbitmq))
     );
     assert forall |s, s_prime| inv(s) && #[trigger] next(s, s_prime) implies inv(s_prime) by {
         assert forall |msg| #[trigger] s_prime.in_flight().contains(msg) && resource_update_request_msg(sts_key)(msg) && s_prime.resources().contains_key(sts_key)
         && s_prime.resources()[sts_key].metadata.resource_version.get_Some_0() == msg.content.get_update_request().obj.metadata.resource_version.get_Some_0()
-        implies StatefulSetView::unmarshal(s_prime.resources()[sts_key]).get_Ok_0().spec.get_Some_0().replicas.get_Some_0()
-        <= replicas_of_stateful_set(msg.content.get_update_request().obj) by {
+        implies replicas_of_stateful_set(s_prime.resources()[sts_key]) <= replicas_of_stateful_set(msg.content.get_update_request().obj)
+        || drained_for_owner(s_prime, msg.content.get_update_request().obj, replicas_of_stateful_set(msg.content.get_update_request().obj)) by {
             let step = choose |step| RMQCluster::next_step(s, s_prime, step);
+            lemma_drained_replicas_never_shrink(s, s_prime);
             if s.in_flight().contains(msg) {
                 if !s.resources().contains_key(sts_key) || s.resources()[sts_key] != s_prime.resources()[sts_key] {
                     assert(s_prime.resources()[sts_key].metadata.resource_version.get_Some_0() == s.kubernetes_api_state.resource_version_counter);
                     assert(msg.content.get_update_request().obj.metadata.resource_version.get_Some_0() < s.kubernetes_api_state.resource_version_counter);
                     assert(false);
-                } else {
-                    assert(StatefulSetView::unmarshal(s_prime.resources()[sts_key]).get_Ok_0().spec.get_Some_0().replicas.get_Some_0() <= replicas_of_stateful_set(msg.content.get_update_request().obj));
+                }
+                let obj = msg.content.get_update_request().obj;
+                if drained_for_owner(s, obj, replicas_of_stateful_set(obj)) {
+                    let owner_ref = choose |owner_ref: OwnerReferenceView| {
+                        &&& #[trigger] obj.metadata.owner_references_only_contains(owner_ref)
+                        &&& s.external_state().drained_replicas.contains((owner_ref.uid, replicas_of_stateful_set(obj)))
+                    };
+                    assert(obj.metadata.owner_references_only_contains(owner_ref));
                 }
             } else {
                 StatefulSetView::marshal_preserves_integrity();
                 StatefulSetView::marshal_spec_preserves_integrity();
                 lemma_resource_update_request_msg_implies_key_in_reconcile_equals(SubResource::StatefulSet, rabbitmq, s, s_prime, msg, step);
+                let key = rabbitmq.object_ref();
+                let triggering_cr = s.ongoing_reconciles()[key].triggering_cr;
+                let local_state = s.ongoing_reconciles()[key].local_state;
+                let resp = step.get_ControllerStep_0().0.get_Some_0();
+                let found_sts = StatefulSetView::unmarshal(resp.content.get_get_response().res.get_Ok_0()).get_Ok_0();
+                // The update request is made from the stateful set in the get response, which is the one in etcd if the resource versions are the same
+                assert(found_sts.metadata.resource_version == msg.content.get_update_request().obj.metadata.resource_version);
+                assert(replicas_of_stateful_set(msg.content.get_update_request().obj) == triggering_cr.spec.replicas);
+                assert(scale_down_is_drained(triggering_cr, found_sts, local_state));
+                if local_state.drained_replicas_opt == Some(triggering_cr.spec.replicas) {
+                    // The update request is owned by the triggering cr, on whose behalf the nodes were drained
+                    assert(msg.content.get_update_request().obj.metadata.owner_references == Some(make_owner_references(triggering_cr)));
+                    assert(msg.content.get_update_request().obj.metadata.owner_references_only_contains(triggering_cr.controller_owner_ref()));
+                    assert(drained(triggering_cr.controller_owner_ref(), triggering_cr.spec.replicas)(s_prime));
+                } else {
+                    assert(s.resources()[sts_key] == resp.content.get_get_response().res.get_Ok_0());
+                    assert(replicas_of_stateful_set(s_prime.resources()[sts_key]) <= triggering_cr.spec.replicas);
+                }
             }
         }
     }
     init_invariant(spec, RMQCluster::init(), next, inv);
 }
 
-// This function defined a replicas order for stateful set object. Here, obj can be the etcd statful set object, the object
-// in create/update stateful set object. We define this order because, the replicas in the update request is derived from
-// the triggering cr; so, in order to show the updated replicas is no smaller than the original one, we need to show that
-// the original one (the one stored in etcd)'s replicas is no larger than that of triggering cr. obj.metadata.owner_references_only_contains
-// (s.ongoing_reconciles()[key].triggering_cr.controller_owner_ref()) here is to ensure that the cr is still the one that creates the stateful
-// set object. The left two comparison is to assist the last one because when the state moves to the next state, the triggering_cr
-// may be assigned (inserted or updated).
-spec fn replicas_satisfies_order(obj: DynamicObjectView, rabbitmq: RabbitmqClusterView) -> StatePred<RMQCluster>
-    recommends
-        obj.kind.is_StatefulSetKind(),
+// The drain checks that succeeded are never forgotten by the external API.
+proof fn lemma_drained_replicas_never_shrink(s: RMQCluster, s_prime: RMQCluster)
+    requires RMQCluster::next()(s, s_prime),
+    ensures s.external_state().drained_replicas.subset_of(s_prime.external_state().drained_replicas),
 {
-    |s: RMQCluster| {
-        let key = rabbitmq.object_ref();
-        let sts_replicas = replicas_of_stateful_set(obj);
-        &&& s.resources().contains_key(key)
-            && obj.metadata.owner_references_only_contains(RabbitmqClusterView::unmarshal(s.resources()[key]).get_Ok_0().controller_owner_ref())
-            ==> sts_replicas <= replicas_of_rabbitmq(s.resources()[key])
-        &&& s.scheduled_reconciles().contains_key(key)
-            && obj.metadata.owner_references_only_contains(s.scheduled_reconciles()[key].controller_owner_ref())
-            ==> sts_replicas <= s.scheduled_reconciles()[key].spec.replicas
-        &&& s.ongoing_reconciles().contains_key(key)
-            && obj.metadata.owner_references_only_contains(s.ongoing_reconciles()[key].triggering_cr.controller_owner_ref())
-            ==> sts_replicas <= s.ongoing_reconciles()[key].triggering_cr.spec.replicas
+    let step = choose |step| RMQCluster::next_step(s, s_prime, step);
+    match step {
+        Step::ExternalAPIStep(input) => {
+            let req = input.get_Some_0().content.get_ExternalAPIRequest_0();
+            match req {
+                RabbitmqAPIInputView::SetPluginsRequest(..) => {},
+                RabbitmqAPIInputView::DrainNodesRequest(..) => {},
+            }
+        },
+        _ => {},
     }
 }
 
-spec fn replicas_of_rabbitmq(obj: DynamicObjectView) -> int
-    recommends
-        obj.kind.is_CustomResourceKind(),
-{
-    RabbitmqClusterView::unmarshal(obj).get_Ok_0().spec.replicas
-}
-
-spec fn replicas_of_etcd_stateful_set_satisfies_order(rabbitmq: RabbitmqClusterView) -> StatePred<RMQCluster> {
+// Once the reconciler records in its local state that the nodes were drained to some replicas,
+// the drain check for these replicas has succeeded in the external API.
+spec fn drained_replicas_in_reconcile_state_is_recorded(rabbitmq: RabbitmqClusterView) -> StatePred<RMQCluster> {
     |s: RMQCluster| {
-        let sts_key = make_stateful_set_key(rabbitmq);
-        s.resources().contains_key(sts_key) ==> replicas_satisfies_order(s.resources()[sts_key], rabbitmq)(s)
+        let key = rabbitmq.object_ref();
+        let triggering_cr = s.ongoing_reconciles()[key].triggering_cr;
+        let local_state = s.ongoing_reconciles()[key].local_state;
+        s.ongoing_reconciles().contains_key(key)
+        && local_state.drained_replicas_opt.is_Some()
+        ==> drained(triggering_cr.controller_owner_ref(), local_state.drained_replicas_opt.get_Some_0())(s)
     }
 }
 
-#[verifier(spinoff_prover)]
-proof fn lemma_always_replicas_of_etcd_stateful_set_satisfies_order(spec: TempPred<RMQCluster>, rabbitmq: RabbitmqClusterView)
+proof fn lemma_always_drained_replicas_in_reconcile_state_is_recorded(spec: TempPred<RMQCluster>, rabbitmq: RabbitmqClusterView)
     requires
         spec.entails(lift_state(RMQCluster::init())),
         spec.entails(always(lift_action(RMQCluster::next()))),
-    ensures spec.entails(always(lift_state(replicas_of_etcd_stateful_set_satisfies_order(rabbitmq)))),
+    ensures spec.entails(always(lift_state(drained_replicas_in_reconcile_state_is_recorded(rabbitmq)))),
 {
-    let inv = replicas_of_etcd_stateful_set_satisfies_order(rabbitmq);
+    let inv = drained_replicas_in_reconcile_state_is_recorded(rabbitmq);
     let next = |s, s_prime| {
         &&& RMQCluster::next()(s, s_prime)
-        &&& RMQCluster::each_object_in_etcd_is_well_formed()(s)
-        &&& RMQCluster::each_object_in_etcd_is_well_formed()(s_prime)
-        &&& every_owner_ref_of_every_object_in_etcd_has_different_uid_from_uid_counter(SubResource::StatefulSet, rabbitmq)(s)
-        &&& replicas_of_stateful_set_create_or_update_request_msg_satisfies_order(rabbitmq)(s)
-        &&& no_create_resource_request_msg_without_name_in_flight(SubResource::StatefulSet, rabbitmq)(s)
+        &&& RMQCluster::each_object_in_reconcile_has_consistent_key_and_valid_metadata()(s)
+        &&& ok_drain_nodes_response_is_recorded(rabbitmq)(s)
     };
-    RMQCluster::lemma_always_each_object_in_etcd_is_well_formed(spec);
-    always_to_always_later(spec, lift_state(RMQCluster::each_object_in_etcd_is_well_formed()));
-    lemma_always_every_owner_ref_of_every_object_in_etcd_has_different_uid_from_uid_counter(spec, SubResource::StatefulSet, rabbitmq);
-    lemma_always_replicas_of_stateful_set_create_or_update_request_msg_satisfies_order(spec, rabbitmq);
-    lemma_always_no_create_resource_request_msg_without_name_in_flight(spec, SubResource::StatefulSet, rabbitmq);
+    RMQCluster::lemma_always_each_object_in_reconcile_has_consistent_key_and_valid_metadata(spec);
+    lemma_always_ok_drain_nodes_response_is_recorded(spec, rabbitmq);
     combine_spec_entails_always_n!(
-        spec, lift_action(next), lift_action(RMQCluster::next()), lift_state(RMQCluster::each_object_in_etcd_is_well_formed()),
-        later(lift_state(RMQCluster::each_object_in_etcd_is_well_formed())),
-        lift_state(every_owner_ref_of_every_object_in_etcd_has_different_uid_from_uid_counter(SubResource::StatefulSet, rabbitmq)),
-        lift_state(replicas_of_stateful_set_create_or_update_request_msg_satisfies_order(rabbitmq)),
-        lift_state(no_create_resource_request_msg_without_name_in_flight(SubResource::StatefulSet, rabbitmq))
+        spec, lift_action(next), lift_action(RMQCluster::next()),
+        lift_state(RMQCluster::each_object_in_reconcile_has_consistent_key_and_valid_metadata()),
+        lift_state(ok_drain_nodes_response_is_recorded(rabbitmq))
     );
     assert forall |s, s_prime| inv(s) && #[trigger] next(s, s_prime) implies inv(s_prime) by {
         let key = rabbitmq.object_ref();
-        let sts_key = make_stateful_set_key(rabbitmq);
-        if s_prime.resources().contains_key(sts_key) {
-            if s.resources().contains_key(sts_key) && s.resources()[sts_key] == s_prime.resources()[sts_key] {
-                if s_prime.resources().contains_key(key) {
-                    if !s.resources().contains_key(key) {
-                        assert(s_prime.resources()[key].metadata.uid.get_Some_0() == s.kubernetes_api_state.uid_counter);
-                        let owner_refs = s.resources()[sts_key].metadata.owner_references;
-                        assert(owner_refs.get_Some_0()[0].uid != s.kubernetes_api_state.uid_counter);
-                        assert(owner_refs.get_Some_0()[0] != RabbitmqClusterView::unmarshal(s_prime.resources()[key]).get_Ok_0().controller_owner_ref());
-                    } else if s.resources()[key] != s_prime.resources()[key] {
-                        assert(s.resources()[key].metadata.uid == s_prime.resources()[key].metadata.uid);
-                        assert(RabbitmqClusterView::unmarshal(s.resources()[key]).get_Ok_0().controller_owner_ref() == RabbitmqClusterView::unmarshal(s_prime.resources()[key]).get_Ok_0().controller_owner_ref());
-                        assert(replicas_of_rabbitmq(s.resources()[key]) <= replicas_of_rabbitmq(s_prime.resources()[key]));
+        lemma_drained_replicas_never_shrink(s, s_prime);
+        if s_prime.ongoing_reconciles().contains_key(key) && s_prime.ongoing_reconciles()[key].local_state.drained_replicas_opt.is_Some() {
+            let step = choose |step| RMQCluster::next_step(s, s_prime, step);
+            match step {
+                Step::ControllerStep(input) => {
+                    if input.1.get_Some_0() == key && s.ongoing_reconciles().contains_key(key) {
+                        let local_state = s.ongoing_reconciles()[key].local_state;
+                        if local_state.drained_replicas_opt != s_prime.ongoing_reconciles()[key].local_state.drained_replicas_opt {
+                            // The only transition that sets drained_replicas_opt is the one receiving the ok drain nodes response
+                            assert(local_state.reconcile_step == RabbitmqReconcileStep::AfterDrainNodes);
+                            let triggering_cr = s.ongoing_reconciles()[key].triggering_cr;
+                            assert(s_prime.ongoing_reconciles()[key].local_state.drained_replicas_opt == Some(triggering_cr.spec.replicas));
+                            assert(s_prime.ongoing_reconciles()[key].triggering_cr == triggering_cr);
+                        }
                     }
-                }
-            } else {
-                let step = choose |step| RMQCluster::next_step(s, s_prime, step);
-                match step {
-                    Step::ApiServerStep(input) => {
-                        let req = input.get_Some_0();
-                        assert(!resource_create_request_msg_without_name(sts_key.kind, sts_key.namespace)(req));
-                    },
-                    _ => {},
-                }
+                },
+                _ => {},
             }
         }
     }
     init_invariant(spec, RMQCluster::init(), next, inv);
 }
 
-spec fn replicas_of_stateful_set_create_or_update_request_msg_satisfies_order(rabbitmq: RabbitmqClusterView) -> StatePred<RMQCluster> {
+// At the AfterDrainNodes step, the pending request is the drain nodes request made from the triggering cr,
+// and any ok response to it means the external API has recorded the drain check for the desired replicas.
+spec fn ok_drain_nodes_response_is_recorded(rabbitmq: RabbitmqClusterView) -> StatePred<RMQCluster> {
     |s: RMQCluster| {
-        let sts_key = make_stateful_set_key(rabbitmq);
-        forall |msg: RMQMessage|
-            #[trigger] s.in_flight().contains(msg)
-            ==> (
-                resource_create_request_msg(sts_key)(msg)
-                ==> replicas_satisfies_order(msg.content.get_create_request().obj, rabbitmq)(s)
-            ) && (
-                resource_update_request_msg(sts_key)(msg)
-                ==> replicas_satisfies_order(msg.content.get_update_request().obj, rabbitmq)(s)
-            )
+        let key = rabbitmq.object_ref();
+        let triggering_cr = s.ongoing_reconciles()[key].triggering_cr;
+        let pending_req = s.ongoing_reconciles()[key].pending_req_msg.get_Some_0();
+        at_rabbitmq_step(key, RabbitmqReconcileStep::AfterDrainNodes)(s)
+        ==> {
+            &&& s.ongoing_reconciles()[key].pending_req_msg.is_Some()
+            &&& pending_req.content.is_ExternalAPIRequest()
+            &&& pending_req.content.get_ExternalAPIRequest_0().is_DrainNodesRequest()
+            &&& pending_req.content.get_ExternalAPIRequest_0() == drain_nodes_request(triggering_cr, pending_req.content.get_ExternalAPIRequest_0().get_DrainNodesRequest_2())
+            &&& forall |msg: RMQMessage|
+                #[trigger] s.in_flight().contains(msg)
+                && msg.content.is_ExternalAPIRequest()
+                && msg.content.get_rest_id() == pending_req.content.get_rest_id()
+                ==> msg == pending_req
+            &&& forall |msg: RMQMessage|
+                #[trigger] s.in_flight().contains(msg)
+                && Message::resp_msg_matches_req_msg(msg, pending_req)
+                && msg.content.get_ExternalAPIResponse_0().is_DrainNodesResponse()
+                && msg.content.get_ExternalAPIResponse_0().get_DrainNodesResponse_0().res.is_Ok()
+                ==> drained(triggering_cr.controller_owner_ref(), triggering_cr.spec.replicas)(s)
+        }
     }
 }
 
-proof fn lemma_always_replicas_of_stateful_set_create_or_update_request_msg_satisfies_order(spec: TempPred<RMQCluster>, rabbitmq: RabbitmqClusterView)
+proof fn lemma_always_ok_drain_nodes_response_is_recorded(spec: TempPred<RMQCluster>, rabbitmq: RabbitmqClusterView)
     requires
         spec.entails(lift_state(RMQCluster::init())),
         spec.entails(always(lift_action(RMQCluster::next()))),
-    ensures spec.entails(always(lift_state(replicas_of_stateful_set_create_or_update_request_msg_satisfies_order(rabbitmq)))),
+    ensures spec.entails(always(lift_state(ok_drain_nodes_response_is_recorded(rabbitmq)))),
 {
-    let inv = replicas_of_stateful_set_create_or_update_request_msg_satisfies_order(rabbitmq);
-    let sts_key = make_stateful_set_key(rabbitmq);
+    let inv = ok_drain_nodes_response_is_recorded(rabbitmq);
+    let key = rabbitmq.object_ref();
     let next = |s, s_prime| {
         &&& RMQCluster::next()(s, s_prime)
-        &&& RMQCluster::each_object_in_etcd_is_well_formed()(s)
-        &&& RMQCluster::each_object_in_etcd_is_well_formed()(s_prime)
         &&& RMQCluster::each_object_in_reconcile_has_consistent_key_and_valid_metadata()(s)
-        &&& RMQCluster::transition_rule_applies_to_etcd_and_scheduled_and_triggering_cr(rabbitmq)(s)
-        &&& object_in_every_resource_create_or_update_request_msg_only_has_valid_owner_references(SubResource::StatefulSet, rabbitmq)(s)
+        &&& RMQCluster::every_in_flight_msg_has_lower_id_than_allocator()(s)
+        &&& RMQCluster::every_pending_req_msg_has_lower_id_than_allocator()(s)
     };
-    RMQCluster::lemma_always_each_object_in_etcd_is_well_formed(spec);
-    always_to_always_later(spec, lift_state(RMQCluster::each_object_in_etcd_is_well_formed()));
     RMQCluster::lemma_always_each_object_in_reconcile_has_consistent_key_and_valid_metadata(spec);
-    RMQCluster::lemma_always_transition_rule_applies_to_etcd_and_scheduled_and_triggering_cr(spec, rabbitmq);
-    lemma_always_object_in_every_resource_create_or_update_request_msg_only_has_valid_owner_references(spec, SubResource::StatefulSet, rabbitmq);
+    RMQCluster::lemma_always_every_in_flight_msg_has_lower_id_than_allocator(spec);
+    RMQCluster::lemma_always_every_pending_req_msg_has_lower_id_than_allocator(spec);
     combine_spec_entails_always_n!(
-        spec, lift_action(next),
-        lift_action(RMQCluster::next()),
-        lift_state(RMQCluster::each_object_in_etcd_is_well_formed()),
-        later(lift_state(RMQCluster::each_object_in_etcd_is_well_formed())),
+        spec, lift_action(next), lift_action(RMQCluster::next()),
         lift_state(RMQCluster::each_object_in_reconcile_has_consistent_key_and_valid_metadata()),
-        lift_state(RMQCluster::transition_rule_applies_to_etcd_and_scheduled_and_triggering_cr(rabbitmq)),
-        lift_state(object_in_every_resource_create_or_update_request_msg_only_has_valid_owner_references(SubResource::StatefulSet, rabbitmq))
+        lift_state(RMQCluster::every_in_flight_msg_has_lower_id_than_allocator()),
+        lift_state(RMQCluster::every_pending_req_msg_has_lower_id_than_allocator())
     );
     assert forall |s, s_prime| inv(s) && #[trigger] next(s, s_prime) implies inv(s_prime) by {
-        assert forall |msg| #[trigger] s_prime.in_flight().contains(msg) implies (resource_create_request_msg(sts_key)(msg)
-        ==> replicas_satisfies_order(msg.content.get_create_request().obj, rabbitmq)(s_prime)) && (resource_update_request_msg(sts_key)(msg)
-        ==> replicas_satisfies_order(msg.content.get_update_request().obj, rabbitmq)(s_prime)) by {
-            if resource_create_request_msg(sts_key)(msg) {
-                replicas_of_stateful_set_create_request_msg_satisfies_order_induction(rabbitmq, s, s_prime, msg);
-            }
-            if resource_update_request_msg(sts_key)(msg) {
-                replicas_of_stateful_set_update_request_msg_satisfies_order_induction(rabbitmq, s, s_prime, msg);
-            }
-        }
-    }
-    init_invariant(spec, RMQCluster::init(), next, inv);
-}
-
-proof fn replicas_of_stateful_set_create_request_msg_satisfies_order_induction(
-    rabbitmq: RabbitmqClusterView, s: RMQCluster, s_prime: RMQCluster, msg: RMQMessage
-)
-    requires
-        RMQCluster::next()(s, s_prime),
-        RMQCluster::each_object_in_etcd_is_well_formed()(s),
-        RMQCluster::each_object_in_etcd_is_well_formed()(s_prime),
-        RMQCluster::each_object_in_reconcile_has_consistent_key_and_valid_metadata()(s),
-        RMQCluster::transition_rule_applies_to_etcd_and_scheduled_and_triggering_cr(rabbitmq)(s),
-        object_in_every_resource_create_or_update_request_msg_only_has_valid_owner_references(SubResource::StatefulSet, rabbitmq)(s),
-        replicas_of_stateful_set_create_or_update_request_msg_satisfies_order(rabbitmq)(s),
-        s_prime.in_flight().contains(msg),
-        resource_create_request_msg(make_stateful_set_key(rabbitmq))(msg),
-    ensures replicas_satisfies_order(msg.content.get_create_request().obj, rabbitmq)(s_prime),
-{
-    let step = choose |step| RMQCluster::next_step(s, s_prime, step);
-    let key = rabbitmq.object_ref();
-    let sts_key = make_stateful_set_key(rabbitmq);
-    match step {
-        Step::ApiServerStep(input) => {
-            assert(s.controller_state == s_prime.controller_state);
-            assert(s.in_flight().contains(msg));
-            if s_prime.resources().contains_key(key) {
-                if s.resources().contains_key(key) {
-                    assert(replicas_of_rabbitmq(s.resources()[key]) <= replicas_of_rabbitmq(s_prime.resources()[key]));
-                } else {
-                    let owner_refs = msg.content.get_create_request().obj.metadata.owner_references;
-                    assert(owner_refs.is_Some() && owner_refs.get_Some_0().len() == 1);
-                    assert(owner_refs.get_Some_0()[0].uid < s.kubernetes_api_state.uid_counter);
-                    assert(owner_refs.get_Some_0()[0] != RabbitmqClusterView::unmarshal(s_prime.resources()[key]).get_Ok_0().controller_owner_ref());
-                    assert(!msg.content.get_create_request().obj.metadata.owner_references_only_contains(RabbitmqClusterView::unmarshal(s_prime.resources()[key]).get_Ok_0().controller_owner_ref()));
+        if at_rabbitmq_step(key, RabbitmqReconcileStep::AfterDrainNodes)(s_prime) {
+            lemma_drained_replicas_never_shrink(s, s_prime);
+            let step = choose |step| RMQCluster::next_step(s, s_prime, step);
+            let pending_req = s_prime.ongoing_reconciles()[key].pending_req_msg.get_Some_0();
+            if at_rabbitmq_step(key, RabbitmqReconcileStep::AfterDrainNodes)(s)
+            && s.ongoing_reconciles()[key] == s_prime.ongoing_reconciles()[key] {
+                match step {
+                    Step::ExternalAPIStep(input) => {
+                        // The external API only handles the pending request, which is the drain nodes request made from the triggering cr
+                        let req_msg = input.get_Some_0();
+                        if req_msg.content.get_rest_id() == pending_req.content.get_rest_id() {
+                            assert(req_msg == pending_req);
+                        }
+                    },
+                    _ => {
+                        assert forall |msg: RMQMessage| #[trigger] s_prime.in_flight().contains(msg) && msg.content.get_rest_id() == pending_req.content.get_rest_id()
+                        implies s.in_flight().contains(msg) by {
+                            assert(pending_req.content.get_rest_id() < s.rest_id_allocator.rest_id_counter);
+                        }
+                    },
                 }
-            }
-        },
-        Step::ControllerStep(input) => {
-            if !s.in_flight().contains(msg) {
-                StatefulSetView::marshal_preserves_integrity();
-                StatefulSetView::marshal_spec_preserves_integrity();
-                lemma_resource_create_request_msg_implies_key_in_reconcile_equals(SubResource::StatefulSet, rabbitmq, s, s_prime, msg, step);
-            }
-        },
-        _ => {
-            assert(s.in_flight().contains(msg));
-            assert(s.resources().contains_key(key) == s_prime.resources().contains_key(key));
-            if s.resources().contains_key(key) {
-                assert(s.resources()[key] == s_prime.resources()[key]);
-            }
-        },
-    }
-}
-
-proof fn replicas_of_stateful_set_update_request_msg_satisfies_order_induction(
-    rabbitmq: RabbitmqClusterView, s: RMQCluster, s_prime: RMQCluster, msg: RMQMessage
-)
-    requires
-        RMQCluster::next()(s, s_prime),
-        RMQCluster::each_object_in_etcd_is_well_formed()(s),
-        RMQCluster::each_object_in_etcd_is_well_formed()(s_prime),
-        RMQCluster::each_object_in_reconcile_has_consistent_key_and_valid_metadata()(s),
-        RMQCluster::transition_rule_applies_to_etcd_and_scheduled_and_triggering_cr(rabbitmq)(s),
-        object_in_every_resource_create_or_update_request_msg_only_has_valid_owner_references(SubResource::StatefulSet, rabbitmq)(s),
-        // no_create_resource_request_msg_without_name_in_flight(SubResource::StatefulSet, rabbitmq)(s),
-        replicas_of_stateful_set_create_or_update_request_msg_satisfies_order(rabbitmq)(s),
-        s_prime.in_flight().contains(msg),
-        resource_update_request_msg(make_stateful_set_key(rabbitmq))(msg),
-    ensures replicas_satisfies_order(msg.content.get_update_request().obj, rabbitmq)(s_prime),
-{
-    let step = choose |step| RMQCluster::next_step(s, s_prime, step);
-    let key = rabbitmq.object_ref();
-    let sts_key = make_stateful_set_key(rabbitmq);
-    match step {
-        Step::ApiServerStep(input) => {
-            assert(s.in_flight().contains(msg));
-            assert(s.controller_state == s_prime.controller_state);
-            if s_prime.resources().contains_key(key) {
-                if s.resources().contains_key(key) {
-                    assert(replicas_of_rabbitmq(s.resources()[key]) <= replicas_of_rabbitmq(s_prime.resources()[key]));
-                } else {
-                    let owner_refs = msg.content.get_update_request().obj.metadata.owner_references;
-                    assert(owner_refs.is_Some() && owner_refs.get_Some_0().len() == 1);
-                    assert(owner_refs.get_Some_0()[0].uid < s.kubernetes_api_state.uid_counter);
-                    assert(owner_refs.get_Some_0()[0] != RabbitmqClusterView::unmarshal(s_prime.resources()[key]).get_Ok_0().controller_owner_ref());
-                    assert(!msg.content.get_update_request().obj.metadata.owner_references_only_contains(RabbitmqClusterView::unmarshal(s_prime.resources()[key]).get_Ok_0().controller_owner_ref()));
+            } else {
+                // The reconciler just sent the drain nodes request with a fresh id, so nothing in flight shares the id yet
+                assert(step.is_ControllerStep());
+                assert(pending_req.content.get_rest_id() == s.rest_id_allocator.rest_id_counter);
+                assert forall |msg: RMQMessage| #[trigger] s_prime.in_flight().contains(msg) && msg != pending_req
+                implies msg.content.get_rest_id() != pending_req.content.get_rest_id() by {
+                    assert(s.in_flight().contains(msg));
                 }
             }
-        },
-        Step::ControllerStep(input) => {
-            if !s.in_flight().contains(msg) {
-                StatefulSetView::marshal_preserves_integrity();
-                StatefulSetView::marshal_spec_preserves_integrity();
-                lemma_resource_update_request_msg_implies_key_in_reconcile_equals(SubResource::StatefulSet, rabbitmq, s, s_prime, msg, step);
-            }
-        },
-        _ => {
-            assert(s.in_flight().contains(msg));
-            assert(s.resources().contains_key(key) == s_prime.resources().contains_key(key));
-            if s.resources().contains_key(key) {
-                assert(s.resources()[key] == s_prime.resources()[key]);
-            }
-        },
+        }
     }
+    init_invariant(spec, RMQCluster::init(), next, inv);
 }
 
 }
@@ -2,8 +2,9 @@
 // SPDX-License-Identifier: MIT
 use crate::kubernetes_api_objects::error::UnmarshalError;
 use crate::kubernetes_api_objects::exec::{
-    affinity::*, api_resource::*, dynamic::*, object_meta::*, owner_reference::*, resource::*,
-    resource_requirements::*, stateful_set::*, toleration::*,
+    affinity::*, api_resource::*, condition::*, dynamic::*, object_meta::*, owner_reference::*,
+    resource::*, resource_requirements::*, stateful_set::*, toleration::*,
+    topology_spread_constraint::*,
 };
 use crate::kubernetes_api_objects::spec::resource::*;
 use crate::rabbitmq_controller::trusted::{spec_types, step::*};
@@ -19,6 +20,9 @@ pub struct RabbitmqReconcileState {
     // since reconcile_core is frequently "trapped" into the controller_runtime spec.
     pub reconcile_step: RabbitmqReconcileStep,
     pub latest_config_map_rv_opt: Option<String>,
+    // drained_replicas_opt records the replicas that the nodes have been drained to in this reconcile,
+    // so the stateful set can be scaled down to it.
+    pub drained_replicas_opt: Option<i32>,
 }
 
 impl std::clone::Clone for RabbitmqReconcileState {
@@ -33,7 +37,8 @@ impl std::clone::Clone for RabbitmqReconcileState {
                 match &self.latest_config_map_rv_opt {
                     Some(n) => Some(n.clone()),
                     None => None,
-                }
+                },
+            drained_replicas_opt: self.drained_replicas_opt,
         }
     }
 }
@@ -49,6 +54,11 @@ impl View for RabbitmqReconcileState {
                     Some(s) => Some(s@),
                     None => None,
                 },
+            drained_replicas_opt:
+                match self.drained_replicas_opt {
+                    Some(r) => Some(r as int),
+                    None => None,
+                },
         }
     }
 }
@@ -65,6 +75,13 @@ impl View for RabbitmqCluster {
 }
 
 impl RabbitmqCluster {
+    #[verifier(external_body)]
+    pub fn clone(&self) -> (rabbitmq: Self)
+        ensures rabbitmq@ == self@,
+    {
+        RabbitmqCluster { inner: self.inner.clone() }
+    }
+
     #[verifier(external_body)]
     pub fn metadata(&self) -> (metadata: ObjectMeta)
         ensures metadata@ == self@.metadata,
@@ -79,6 +96,18 @@ impl RabbitmqCluster {
         RabbitmqClusterSpec { inner: self.inner.spec.clone() }
     }
 
+    #[verifier(external_body)]
+    pub fn set_status(&mut self, status: RabbitmqClusterStatus)
+        ensures self@ == old(self)@.set_status(status@),
+    {
+        let mut new_status = status.into_kube();
+        match (&mut new_status.conditions, self.inner.status.as_ref().and_then(|s| s.conditions.as_ref())) {
+            (Some(conditions), Some(old_conditions)) => preserve_transition_times(conditions, old_conditions),
+            _ => {},
+        }
+        self.inner.status = Some(new_status);
+    }
+
     #[verifier(external_body)]
     pub fn api_resource() -> (res: ApiResource)
         ensures res@.kind == spec_types::RabbitmqClusterView::kind(),
@@ -191,6 +220,27 @@ impl RabbitmqClusterSpec {
         }
     }
 
+    #[verifier(external_body)]
+    pub fn topology_spread_constraints(&self) -> (topology_spread_constraints: Option<Vec<TopologySpreadConstraint>>)
+        ensures
+            self@.topology_spread_constraints.is_Some() == topology_spread_constraints.is_Some(),
+            topology_spread_constraints.is_Some() ==> topology_spread_constraints.get_Some_0()@.map_values(|c: TopologySpreadConstraint| c@) == self@.topology_spread_constraints.get_Some_0(),
+    {
+        match &self.inner.topology_spread_constraints {
+            Some(cons) => Some(cons.clone().into_iter().map(|c: deps_hack::k8s_openapi::api::core::v1::TopologySpreadConstraint| TopologySpreadConstraint::from_kube(c)).collect()),
+            None => None,
+        }
+    }
+
+    #[verifier(external_body)]
+    pub fn priority_class_name(&self) -> (priority_class_name: Option<String>)
+        ensures
+            self@.priority_class_name.is_Some() == priority_class_name.is_Some(),
+            priority_class_name.is_Some() ==> priority_class_name.get_Some_0()@ == self@.priority_class_name.get_Some_0(),
+    {
+        self.inner.priority_class_name.clone()
+    }
+
     #[verifier(external_body)]
     pub fn labels(&self) -> (labels: StringMap)
         ensures labels@ == self@.labels,
@@ -235,6 +285,101 @@ impl RabbitmqClusterSpec {
             None => None,
         }
     }
+
+    #[verifier(external_body)]
+    pub fn additional_plugins(&self) -> (plugins: Vec<String>)
+        ensures plugins@.map_values(|p: String| p@) == self@.additional_plugins,
+    {
+        self.inner.additional_plugins.clone()
+    }
+
+    #[verifier(external_body)]
+    pub fn tls(&self) -> (tls: Option<RabbitmqTLSSpec>)
+        ensures
+            self@.tls.is_Some() == tls.is_Some(),
+            tls.is_Some() ==> tls.get_Some_0()@ == self@.tls.get_Some_0(),
+    {
+        match &self.inner.tls {
+            Some(t) => Some(RabbitmqTLSSpec { inner: t.clone() }),
+            None => None,
+        }
+    }
+
+    #[verifier(external_body)]
+    pub fn allow_scale_down(&self) -> (allow_scale_down: bool)
+        ensures allow_scale_down == self@.allow_scale_down,
+    {
+        self.inner.allow_scale_down
+    }
+
+    #[verifier(external_body)]
+    pub fn cluster_role_name(&self) -> (cluster_role_name: Option<String>)
+        ensures
+            self@.cluster_role_name.is_Some() == cluster_role_name.is_Some(),
+            cluster_role_name.is_Some() ==> cluster_role_name.get_Some_0()@ == self@.cluster_role_name.get_Some_0(),
+    {
+        self.inner.cluster_role_name.clone()
+    }
+
+    #[verifier(external_body)]
+    pub fn pod_disruption_budget(&self) -> (pod_disruption_budget: Option<RabbitmqPodDisruptionBudgetSpec>)
+        ensures
+            self@.pod_disruption_budget.is_Some() == pod_disruption_budget.is_Some(),
+            pod_disruption_budget.is_Some() ==> pod_disruption_budget.get_Some_0()@ == self@.pod_disruption_budget.get_Some_0(),
+    {
+        match &self.inner.pod_disruption_budget {
+            Some(p) => Some(RabbitmqPodDisruptionBudgetSpec { inner: p.clone() }),
+            None => None,
+        }
+    }
+}
+
+#[verifier(external_body)]
+pub struct RabbitmqTLSSpec {
+    inner: deps_hack::RabbitmqTLSSpec,
+}
+
+impl RabbitmqTLSSpec {
+    pub spec fn view(&self) -> spec_types::RabbitmqTLSSpecView;
+
+    #[verifier(external_body)]
+    pub fn secret_name(&self) -> (secret_name: String)
+        ensures secret_name@ == self@.secret_name,
+    {
+        self.inner.secret_name.clone()
+    }
+
+    #[verifier(external_body)]
+    pub fn ca_secret_name(&self) -> (ca_secret_name: Option<String>)
+        ensures
+            self@.ca_secret_name.is_Some() == ca_secret_name.is_Some(),
+            ca_secret_name.is_Some() ==> ca_secret_name.get_Some_0()@ == self@.ca_secret_name.get_Some_0(),
+    {
+        self.inner.ca_secret_name.clone()
+    }
+
+    #[verifier(external_body)]
+    pub fn disable_non_tls_listeners(&self) -> (disable_non_tls_listeners: bool)
+        ensures disable_non_tls_listeners == self@.disable_non_tls_listeners,
+    {
+        self.inner.disable_non_tls_listeners
+    }
+}
+
+#[verifier(external_body)]
+pub struct RabbitmqPodDisruptionBudgetSpec {
+    inner: deps_hack::RabbitmqPodDisruptionBudgetSpec,
+}
+
+impl RabbitmqPodDisruptionBudgetSpec {
+    pub spec fn view(&self) -> spec_types::RabbitmqPodDisruptionBudgetSpecView;
+
+    #[verifier(external_body)]
+    pub fn min_available(&self) -> (min_available: i32)
+        ensures min_available as int == self@.min_available,
+    {
+        self.inner.min_available
+    }
 }
 
 #[verifier(external_body)]
@@ -297,12 +442,29 @@ impl RabbitmqClusterPersistenceSpec {
 }
 
 #[verifier(external_body)]
-pub fn random_encoded_string(data_len: usize) -> (cookie: String)
-    ensures
-        cookie@ == spec_types::random_encoded_string(data_len),
-{
-    let random_bytes: std::vec::Vec<std::primitive::u8> = (0..data_len).map(|_| deps_hack::rand::random::<std::primitive::u8>()).collect();
-    deps_hack::base64::encode(random_bytes)
+pub struct RabbitmqClusterStatus {
+    inner: deps_hack::RabbitmqClusterStatus,
+}
+
+impl RabbitmqClusterStatus {
+    pub spec fn view(&self) -> spec_types::RabbitmqClusterStatusView;
+
+    #[verifier(external_body)]
+    pub fn default() -> (status: RabbitmqClusterStatus)
+        ensures status@ == spec_types::RabbitmqClusterStatusView::default(),
+    {
+        RabbitmqClusterStatus { inner: deps_hack::RabbitmqClusterStatus::default() }
+    }
+
+    #[verifier(external_body)]
+    pub fn set_conditions(&mut self, conditions: Vec<Condition>)
+        ensures self@ == old(self)@.set_conditions(conditions@.map_values(|c: Condition| c@)),
+    {
+        self.inner.conditions = Some(into_kube_conditions(conditions))
+    }
+
+    #[verifier(external)]
+    fn into_kube(self) -> deps_hack::RabbitmqClusterStatus { self.inner }
 }
 
 }
@@ -3,9 +3,12 @@
 #![allow(unused_imports)]
 use crate::kubernetes_api_objects::spec::prelude::*;
 use crate::kubernetes_cluster::spec::{cluster::*, cluster_state_machine::Step, message::*};
-use crate::rabbitmq_controller::trusted::{maker::*, spec_types::*, step::*};
+use crate::rabbitmq_controller::trusted::{
+    maker::*, rabbitmq_api_spec::quorum_queues_keep_majority, safety_theorem::replicas_of_stateful_set,
+    spec_types::*, step::*,
+};
 use crate::temporal_logic::defs::*;
-use crate::vstd_ext::string_view::int_to_string_view;
+use crate::vstd_ext::string_view::{int_to_string_view, StringView};
 use vstd::prelude::*;
 
 verus! {
@@ -14,12 +17,34 @@ pub open spec fn liveness_theorem<M: Maker>() -> bool { cluster_spec().entails(t
 
 pub open spec fn cluster_spec() -> TempPred<RMQCluster> { RMQCluster::sm_spec() }
 
+// Without allow_scale_down, the replicas of the cr never decrease, so the stateful set never needs to be scaled down
+// and the theorem is the original one: if the desired state stays unchanged, the current state eventually matches it forever.
+// With allow_scale_down, it additionally assumes that the drain needed for scaling down succeeds.
 pub open spec fn liveness<M: Maker>(rabbitmq: RabbitmqClusterView) -> TempPred<RMQCluster> {
-    always(lift_state(desired_state_is(rabbitmq))).leads_to(always(lift_state(current_state_matches::<M>(rabbitmq))))
+    if rabbitmq.spec.allow_scale_down {
+        always(lift_state(desired_state_is(rabbitmq))).and(always(lift_state(drain_nodes_succeeds::<M>(rabbitmq))))
+            .leads_to(always(lift_state(current_state_matches::<M>(rabbitmq))))
+    } else {
+        always(lift_state(desired_state_is(rabbitmq))).leads_to(always(lift_state(current_state_matches::<M>(rabbitmq))))
+    }
 }
 
 pub open spec fn desired_state_is(rabbitmq: RabbitmqClusterView) -> StatePred<RMQCluster> { RMQCluster::desired_state_is(rabbitmq) }
 
+// Scaling down the stateful set requires draining the nodes to be removed first, and the drain is refused as long as
+// some quorum queue would lose its majority, in which case the stateful set keeps more replicas than desired and never matches.
+// We therefore assume that in the states where the stateful set has more replicas than desired, the drain check
+// on the management API passes. The assumption says nothing about the other states.
+pub open spec fn drain_nodes_succeeds<M: Maker>(rabbitmq: RabbitmqClusterView) -> StatePred<RMQCluster> {
+    |s: RMQCluster| {
+        let key = M::make_stateful_set_key(rabbitmq);
+        let replicas = replicas_of_stateful_set(s.resources()[key]);
+        s.resources().contains_key(key)
+        && replicas > rabbitmq.spec.replicas
+        ==> quorum_queues_keep_majority(rabbitmq.metadata.name.get_Some_0(), rabbitmq.metadata.namespace.get_Some_0(), replicas, rabbitmq.spec.replicas)
+    }
+}
+
 pub open spec fn current_state_matches<M: Maker>(rabbitmq: RabbitmqClusterView) -> StatePred<RMQCluster> {
     |s: RMQCluster| {
         forall |sub_resource: SubResource| #[trigger] resource_state_matches::<M>(sub_resource, rabbitmq, s.resources())
@@ -55,6 +80,7 @@ pub open spec fn resource_state_matches<M: Maker>(sub_resource: SubResource, rab
                 cluster_ip: made_spec.cluster_ip,
                 ..spec
             }
+            &&& tls_ports_are_exposed(rabbitmq, spec)
             &&& obj.metadata.labels == M::make_main_service(rabbitmq).metadata.labels
             &&& obj.metadata.annotations == M::make_main_service(rabbitmq).metadata.annotations
         },
@@ -123,6 +149,15 @@ pub open spec fn resource_state_matches<M: Maker>(sub_resource: SubResource, rab
             &&& obj.metadata.labels == M::make_role_binding(rabbitmq).metadata.labels
             &&& obj.metadata.annotations == M::make_role_binding(rabbitmq).metadata.annotations
         },
+        SubResource::PodDisruptionBudget => {
+            let key = M::make_pod_disruption_budget_key(rabbitmq);
+            let obj = resources[key];
+            &&& resources.contains_key(key)
+            &&& PodDisruptionBudgetView::unmarshal(obj).is_Ok()
+            &&& PodDisruptionBudgetView::unmarshal(obj).get_Ok_0().spec == M::make_pod_disruption_budget(rabbitmq).spec
+            &&& obj.metadata.labels == M::make_pod_disruption_budget(rabbitmq).metadata.labels
+            &&& obj.metadata.annotations == M::make_pod_disruption_budget(rabbitmq).metadata.annotations
+        },
         SubResource::StatefulSet => {
             let key = M::make_stateful_set_key(rabbitmq);
             let obj = resources[key];
@@ -134,10 +169,41 @@ pub open spec fn resource_state_matches<M: Maker>(sub_resource: SubResource, rab
             &&& cm_obj.metadata.resource_version.is_Some()
             &&& StatefulSetView::unmarshal(obj).is_Ok()
             &&& StatefulSetView::unmarshal(obj).get_Ok_0().spec == made_sts.spec
+            &&& tls_secrets_are_mounted(rabbitmq, StatefulSetView::unmarshal(obj).get_Ok_0().spec)
             &&& obj.metadata.labels == made_sts.metadata.labels
             &&& obj.metadata.annotations == made_sts.metadata.annotations
         },
     }
 }
 
+// The tls settings are checked against the cr rather than the Maker, so that a Maker dropping them from the made
+// objects does not satisfy the theorem: with tls, the server pods mount the certificate secret (and the ca secret
+// if any) named in the cr, and the client service exposes the amqps and management tls ports.
+pub open spec fn tls_secrets_are_mounted(rabbitmq: RabbitmqClusterView, sts_spec: Option<StatefulSetSpecView>) -> bool {
+    rabbitmq.spec.tls.is_Some() ==> {
+        let tls = rabbitmq.spec.tls.get_Some_0();
+        let pod_spec = sts_spec.get_Some_0().template.spec;
+        &&& sts_spec.is_Some()
+        &&& pod_spec.is_Some()
+        &&& pod_spec_mounts_secret(pod_spec.get_Some_0(), tls.secret_name)
+        &&& tls.ca_secret_name.is_Some() ==> pod_spec_mounts_secret(pod_spec.get_Some_0(), tls.ca_secret_name.get_Some_0())
+    }
+}
+
+pub open spec fn pod_spec_mounts_secret(pod_spec: PodSpecView, secret_name: StringView) -> bool {
+    let volumes = pod_spec.volumes.get_Some_0();
+    &&& pod_spec.volumes.is_Some()
+    &&& exists |i: int| 0 <= i < volumes.len() && #[trigger] volumes[i].secret.is_Some()
+        && volumes[i].secret.get_Some_0().secret_name == Some(secret_name)
+}
+
+pub open spec fn tls_ports_are_exposed(rabbitmq: RabbitmqClusterView, service_spec: ServiceSpecView) -> bool {
+    rabbitmq.spec.tls.is_Some() ==> {
+        let ports = service_spec.ports.get_Some_0();
+        &&& service_spec.ports.is_Some()
+        &&& exists |i: int| 0 <= i < ports.len() && #[trigger] ports[i].port == 5671
+        &&& exists |i: int| 0 <= i < ports.len() && #[trigger] ports[i].port == 15671
+    }
+}
+
 }
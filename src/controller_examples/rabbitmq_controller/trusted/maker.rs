@@ -19,6 +19,7 @@ pub trait Maker {
     spec fn make_service_account_key(rabbitmq: RabbitmqClusterView) -> ObjectRef;
     spec fn make_role_key(rabbitmq: RabbitmqClusterView) -> ObjectRef;
     spec fn make_role_binding_key(rabbitmq: RabbitmqClusterView) -> ObjectRef;
+    spec fn make_pod_disruption_budget_key(rabbitmq: RabbitmqClusterView) -> ObjectRef;
     spec fn make_stateful_set_key(rabbitmq: RabbitmqClusterView) -> ObjectRef;
 
     spec fn make_headless_service(rabbitmq: RabbitmqClusterView) -> ServiceView;
@@ -30,6 +31,7 @@ pub trait Maker {
     spec fn make_service_account(rabbitmq: RabbitmqClusterView) -> ServiceAccountView;
     spec fn make_role(rabbitmq: RabbitmqClusterView) -> RoleView;
     spec fn make_role_binding(rabbitmq: RabbitmqClusterView) -> RoleBindingView;
+    spec fn make_pod_disruption_budget(rabbitmq: RabbitmqClusterView) -> PodDisruptionBudgetView;
     spec fn make_stateful_set(rabbitmq: RabbitmqClusterView, config_map_rv: StringView) -> StatefulSetView;
 }
 
@@ -3,6 +3,8 @@
 pub mod exec_types;
 pub mod liveness_theorem;
 pub mod maker;
+pub mod rabbitmq_api_exec;
+pub mod rabbitmq_api_spec;
 pub mod safety_theorem;
 pub mod spec_types;
 pub mod step;
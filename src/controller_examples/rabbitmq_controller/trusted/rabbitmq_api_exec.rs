@@ -0,0 +1,732 @@
+// Copyright 2022 VMware, Inc.
+// SPDX-License-Identifier: MIT
+use crate::external_api::exec::*;
+use crate::external_api::http::{
+    get_basic_auth_credentials, http_request, is_success, percent_encode,
+};
+use crate::kubernetes_api_objects::exec::{owner_reference::*, resource::ResourceWrapper};
+use crate::rabbitmq_controller::trusted::{
+    rabbitmq_api_spec::{
+        RabbitmqAPIDrainNodesResultView, RabbitmqAPIInputView, RabbitmqAPIOutputView,
+        RabbitmqAPISetPluginsResultView,
+    },
+    step::*,
+};
+use deps_hack::futures::future::BoxFuture;
+use deps_hack::k8s_openapi::api::apps::v1::StatefulSet;
+use deps_hack::k8s_openapi::api::core::v1::Pod;
+use deps_hack::k8s_openapi::apimachinery::pkg::apis::meta::v1::{
+    OwnerReference as KubeOwnerReference, Status,
+};
+use deps_hack::kube::{api::AttachParams, Api, Client};
+use deps_hack::tokio::io::AsyncReadExt;
+use deps_hack::tracing::info;
+use vstd::{pervasive::unreached, prelude::*, string::*, view::*};
+
+verus! {
+
+pub struct RabbitmqAPISetPluginsResult {
+    pub res: Result<(), RabbitmqAPIError>,
+}
+
+impl View for RabbitmqAPISetPluginsResult {
+    type V = RabbitmqAPISetPluginsResultView;
+    open spec fn view(&self) -> RabbitmqAPISetPluginsResultView { RabbitmqAPISetPluginsResultView {res: self.res} }
+}
+
+pub struct RabbitmqAPIDrainNodesResult {
+    pub res: Result<(), RabbitmqAPIError>,
+}
+
+impl View for RabbitmqAPIDrainNodesResult {
+    type V = RabbitmqAPIDrainNodesResultView;
+    open spec fn view(&self) -> RabbitmqAPIDrainNodesResultView { RabbitmqAPIDrainNodesResultView {res: self.res} }
+}
+
+#[is_variant]
+pub enum RabbitmqAPIInput {
+    SetPluginsRequest(String, String, i32, Vec<String>),
+    DrainNodesRequest(String, String, i32, i32, OwnerReference),
+}
+
+#[is_variant]
+pub enum RabbitmqAPIOutput {
+    SetPluginsResponse(RabbitmqAPISetPluginsResult),
+    DrainNodesResponse(RabbitmqAPIDrainNodesResult),
+}
+
+impl View for RabbitmqAPIInput {
+    type V = RabbitmqAPIInputView;
+    open spec fn view(&self) -> RabbitmqAPIInputView {
+        match self {
+            RabbitmqAPIInput::SetPluginsRequest(name, namespace, replicas, plugins)
+                => RabbitmqAPIInputView::SetPluginsRequest(name@, namespace@, *replicas as int, plugins@.map_values(|s: String| s@)),
+            RabbitmqAPIInput::DrainNodesRequest(name, namespace, replicas, target_replicas, owner_ref)
+                => RabbitmqAPIInputView::DrainNodesRequest(name@, namespace@, *replicas as int, *target_replicas as int, owner_ref@),
+        }
+    }
+}
+
+impl View for RabbitmqAPIOutput {
+    type V = RabbitmqAPIOutputView;
+    open spec fn view(&self) -> RabbitmqAPIOutputView {
+        match self {
+            RabbitmqAPIOutput::SetPluginsResponse(result) => RabbitmqAPIOutputView::SetPluginsResponse(result@),
+            RabbitmqAPIOutput::DrainNodesResponse(result) => RabbitmqAPIOutputView::DrainNodesResponse(result@),
+        }
+    }
+}
+
+impl RabbitmqAPIOutput {
+    pub fn is_set_plugins_response(&self) -> (res: bool)
+        ensures res == self.is_SetPluginsResponse(),
+    {
+        match self {
+            RabbitmqAPIOutput::SetPluginsResponse(_) => true,
+            _ => false,
+        }
+    }
+
+    pub fn unwrap_set_plugins_response(self) -> (result: RabbitmqAPISetPluginsResult)
+        requires self.is_SetPluginsResponse(),
+        ensures result == self.get_SetPluginsResponse_0(),
+    {
+        match self {
+            RabbitmqAPIOutput::SetPluginsResponse(result) => result,
+            _ => unreached(),
+        }
+    }
+
+    pub fn is_drain_nodes_response(&self) -> (res: bool)
+        ensures res == self.is_DrainNodesResponse(),
+    {
+        match self {
+            RabbitmqAPIOutput::DrainNodesResponse(_) => true,
+            _ => false,
+        }
+    }
+
+    pub fn unwrap_drain_nodes_response(self) -> (result: RabbitmqAPIDrainNodesResult)
+        requires self.is_DrainNodesResponse(),
+        ensures result == self.get_DrainNodesResponse_0(),
+    {
+        match self {
+            RabbitmqAPIOutput::DrainNodesResponse(result) => result,
+            _ => unreached(),
+        }
+    }
+}
+
+pub struct RabbitmqAPIShimLayer {}
+
+}
+
+impl ExternalAPIShimLayer for RabbitmqAPIShimLayer {
+    type Input = RabbitmqAPIInput;
+    type Output = RabbitmqAPIOutput;
+    fn call_external_api(input: RabbitmqAPIInput) -> RabbitmqAPIOutput {
+        match input {
+            RabbitmqAPIInput::SetPluginsRequest(
+                rabbitmq_name,
+                rabbitmq_namespace,
+                replicas,
+                plugins,
+            ) => RabbitmqAPIOutput::SetPluginsResponse(rabbitmq_set_plugins(
+                rabbitmq_name,
+                rabbitmq_namespace,
+                replicas,
+                plugins,
+            )),
+            RabbitmqAPIInput::DrainNodesRequest(
+                rabbitmq_name,
+                rabbitmq_namespace,
+                replicas,
+                target_replicas,
+                owner_ref,
+            ) => RabbitmqAPIOutput::DrainNodesResponse(rabbitmq_drain_nodes(
+                rabbitmq_name,
+                rabbitmq_namespace,
+                replicas,
+                target_replicas,
+                owner_ref.into_kube(),
+            )),
+        }
+    }
+}
+
+// The management API calls use blocking sockets, so they run on the blocking thread pool.
+impl AsyncExternalAPIShimLayer for RabbitmqAPIShimLayer {
+    fn call_external_api_async(input: RabbitmqAPIInput) -> BoxFuture<'static, RabbitmqAPIOutput> {
+        call_external_api_blocking::<Self>(input)
+    }
+
+    fn error_of(output: &RabbitmqAPIOutput) -> Option<String> {
+        match output {
+            RabbitmqAPIOutput::SetPluginsResponse(result) => result.res.as_ref().err(),
+            RabbitmqAPIOutput::DrainNodesResponse(result) => result.res.as_ref().err(),
+        }
+        .map(|err| format!("{:?}", err))
+    }
+}
+
+const MANAGEMENT_PORT: i32 = 15672;
+
+// The name of the rabbitmq container in the server pods (see make_rabbitmq_pod_spec).
+const RABBITMQ_CONTAINER: &str = "rabbitmq";
+
+pub fn rabbitmq_node_addr(name: &String, namespace: &String, ordinal: i32) -> String {
+    format!(
+        "{}-server-{}.{}-nodes.{}.svc.cluster.local:{}",
+        name, ordinal, name, namespace, MANAGEMENT_PORT
+    )
+}
+
+pub fn rabbitmq_pod_name(name: &String, ordinal: i32) -> String {
+    format!("{}-server-{}", name, ordinal)
+}
+
+// get_default_user_credentials reads the management credentials from the default user secret
+// (see make_default_user_secret).
+async fn get_default_user_credentials(
+    name: &String,
+    namespace: &String,
+) -> Result<(String, String), String> {
+    get_basic_auth_credentials(&format!("{}-default-user", name), namespace).await
+}
+
+pub fn rabbitmq_set_plugins(
+    name: String,
+    namespace: String,
+    replicas: i32,
+    plugins: Vec<String>,
+) -> RabbitmqAPISetPluginsResult {
+    let result = RabbitmqAPISetPluginsResult {
+        res: rabbitmq_set_plugins_internal(name, namespace, replicas, plugins),
+    };
+    match result.res {
+        Err(_) => info!("Set rabbitmq plugins failed"),
+        Ok(_) => info!("Set rabbitmq plugins successfully"),
+    }
+    result
+}
+
+pub fn rabbitmq_set_plugins_internal(
+    name: String,
+    namespace: String,
+    replicas: i32,
+    plugins: Vec<String>,
+) -> Result<(), RabbitmqAPIError> {
+    set_plugins_on_each_node(&name, replicas, &plugins, |pod_name, command| {
+        block_on(exec_in_rabbitmq_container(&namespace, pod_name, command))
+    })
+}
+
+// set_plugins_on_each_node runs the set plugins command on every running node with exec and stops at the first node
+// where it fails, so that the reconciler retries with all the nodes later.
+// Plugins are enabled per node, so every running node needs to receive the new list.
+pub fn set_plugins_on_each_node<F>(
+    name: &String,
+    replicas: i32,
+    plugins: &Vec<String>,
+    mut exec: F,
+) -> Result<(), RabbitmqAPIError>
+where
+    F: FnMut(&String, Vec<String>) -> Result<(), String>,
+{
+    for ordinal in 0..replicas {
+        let pod_name = rabbitmq_pod_name(name, ordinal);
+        info!("Setting plugins {:?} on {} ...", plugins, &pod_name);
+        exec(&pod_name, make_set_plugins_command(plugins)).map_err(|e| {
+            info!("Setting plugins on {} failed: {}", &pod_name, e);
+            RabbitmqAPIError::SetPluginsFailed
+        })?;
+    }
+    Ok(())
+}
+
+// make_set_plugins_command lists the full set of plugins, so "rabbitmq-plugins set" also disables
+// the plugins that are no longer listed.
+pub fn make_set_plugins_command(plugins: &Vec<String>) -> Vec<String> {
+    let mut command = vec!["rabbitmq-plugins".to_string(), "set".to_string()];
+    command.extend(plugins.iter().cloned());
+    command
+}
+
+// exec_in_rabbitmq_container runs the command inside the rabbitmq container of the pod.
+// "rabbitmq-plugins set" enables the listed plugins and disables the others on the running node without restarting it.
+async fn exec_in_rabbitmq_container(
+    namespace: &String,
+    pod_name: &String,
+    command: Vec<String>,
+) -> Result<(), String> {
+    let client = Client::try_default().await.map_err(|e| e.to_string())?;
+    let mut attached = Api::<Pod>::namespaced(client, namespace)
+        .exec(
+            pod_name,
+            command,
+            &AttachParams::default()
+                .container(RABBITMQ_CONTAINER)
+                .stderr(false),
+        )
+        .await
+        .map_err(|e| e.to_string())?;
+    let status = attached.take_status().ok_or("no status".to_string())?;
+    let mut output = String::new();
+    if let Some(mut stdout) = attached.stdout() {
+        stdout
+            .read_to_string(&mut output)
+            .await
+            .map_err(|e| e.to_string())?;
+    }
+    let status = status.await;
+    attached.join().await.map_err(|e| e.to_string())?;
+    check_exec_status(status, output)
+}
+
+// check_exec_status tells whether the command succeeded from the status the api server sends when the exec ends.
+// The output of the command is returned as the error otherwise; a missing status means the exec was cut off.
+pub fn check_exec_status(status: Option<Status>, output: String) -> Result<(), String> {
+    match status.and_then(|status| status.status) {
+        Some(status) if status == "Success" => Ok(()),
+        Some(_) => Err(output),
+        None => Err(format!("no status, output: {}", output)),
+    }
+}
+
+// The erlang node name of each rabbitmq node, which is how quorum queues refer to their members
+// (see RABBITMQ_NODENAME in make_env_vars).
+pub fn rabbitmq_node_name(name: &String, namespace: &String, ordinal: i32) -> String {
+    format!(
+        "rabbit@{}-server-{}.{}-nodes.{}",
+        name, ordinal, name, namespace
+    )
+}
+
+pub fn rabbitmq_drain_nodes(
+    name: String,
+    namespace: String,
+    replicas: i32,
+    target_replicas: i32,
+    owner_ref: KubeOwnerReference,
+) -> RabbitmqAPIDrainNodesResult {
+    let result = RabbitmqAPIDrainNodesResult {
+        res: rabbitmq_drain_nodes_internal(name, namespace, replicas, target_replicas, owner_ref),
+    };
+    match result.res {
+        Err(_) => info!("Drain rabbitmq nodes failed"),
+        Ok(_) => info!("Drain rabbitmq nodes successfully"),
+    }
+    result
+}
+
+pub fn rabbitmq_drain_nodes_internal(
+    name: String,
+    namespace: String,
+    replicas: i32,
+    target_replicas: i32,
+    owner_ref: KubeOwnerReference,
+) -> Result<(), RabbitmqAPIError> {
+    let sts = block_on(get_stateful_set(&name, &namespace)).map_err(|e| {
+        info!("Reading the stateful set failed: {}", e);
+        RabbitmqAPIError::DrainNodesFailed
+    })?;
+    check_drain_nodes(&sts, replicas, target_replicas, &owner_ref).map_err(|e| {
+        info!("Refusing to drain the nodes: {}", e);
+        RabbitmqAPIError::DrainNodesFailed
+    })?;
+    // The stateful set removes the pods with the largest ordinals first
+    let removed_nodes = (target_replicas..replicas)
+        .map(|ordinal| rabbitmq_node_name(&name, &namespace, ordinal))
+        .collect::<Vec<String>>();
+    let (username, password) =
+        block_on(get_default_user_credentials(&name, &namespace)).map_err(|e| {
+            info!("Reading the default user credentials failed: {}", e);
+            RabbitmqAPIError::DrainNodesFailed
+        })?;
+    let credentials = (username.as_str(), password.as_str());
+    // All the nodes are still running at this point, so the first one can answer for the cluster
+    let addr = rabbitmq_node_addr(&name, &namespace, 0);
+    let quorum_queues = list_quorum_queues(&addr, credentials)?;
+    for queue in quorum_queues.iter() {
+        if !queue.keeps_majority_without(&removed_nodes) {
+            info!(
+                "Quorum queue {} in vhost {} would lose its majority",
+                queue.name, queue.vhost
+            );
+            return Err(RabbitmqAPIError::DrainNodesFailed);
+        }
+    }
+    for node in removed_nodes.iter() {
+        info!("Draining {} ...", node);
+        shrink_quorum_queues_on_node(&addr, node, credentials)?;
+    }
+    Ok(())
+}
+
+// get_stateful_set reads the stateful set hosting the rabbitmq nodes (see make_stateful_set).
+async fn get_stateful_set(name: &String, namespace: &String) -> Result<StatefulSet, String> {
+    let client = Client::try_default().await.map_err(|e| e.to_string())?;
+    Api::<StatefulSet>::namespaced(client, namespace)
+        .get(&format!("{}-server", name))
+        .await
+        .map_err(|e| e.to_string())
+}
+
+// check_drain_nodes makes the same checks as handle_drain_nodes in rabbitmq_api_spec.rs before any node is drained:
+// the stateful set runs the replicas the request is addressed to, it is owned only by owner_ref,
+// and the request removes at least one node.
+pub fn check_drain_nodes(
+    sts: &StatefulSet,
+    replicas: i32,
+    target_replicas: i32,
+    owner_ref: &KubeOwnerReference,
+) -> Result<(), String> {
+    let sts_replicas = sts.spec.as_ref().and_then(|spec| spec.replicas);
+    if sts_replicas != Some(replicas) {
+        return Err(format!(
+            "the stateful set runs {:?} replicas instead of {}",
+            sts_replicas, replicas
+        ));
+    }
+    if sts.metadata.owner_references.as_ref() != Some(&vec![owner_ref.clone()]) {
+        return Err(format!(
+            "the stateful set is not owned only by {}",
+            owner_ref.uid
+        ));
+    }
+    if target_replicas >= replicas {
+        return Err(format!(
+            "{} replicas do not scale down from {}",
+            target_replicas, replicas
+        ));
+    }
+    Ok(())
+}
+
+pub struct QuorumQueue {
+    pub name: String,
+    pub vhost: String,
+    pub members: Vec<String>,
+    pub online: Vec<String>,
+}
+
+impl QuorumQueue {
+    // keeps_majority_without checks whether more than half of the members stay online
+    // after the removed nodes leave the cluster.
+    pub fn keeps_majority_without(&self, removed_nodes: &[String]) -> bool {
+        let remaining_online = self
+            .online
+            .iter()
+            .filter(|node| self.members.contains(node) && !removed_nodes.contains(node))
+            .count();
+        remaining_online * 2 > self.members.len()
+    }
+}
+
+pub fn list_quorum_queues(
+    addr: &str,
+    credentials: (&str, &str),
+) -> Result<Vec<QuorumQueue>, RabbitmqAPIError> {
+    let (status, body) = http_request("GET", addr, "/api/queues", credentials, "")
+        .map_err(|_e| RabbitmqAPIError::DrainNodesFailed)?;
+    if !is_success(status) {
+        info!("Management API on {} returned status {}", addr, status);
+        return Err(RabbitmqAPIError::DrainNodesFailed);
+    }
+    parse_quorum_queues(&body).ok_or(RabbitmqAPIError::DrainNodesFailed)
+}
+
+pub fn parse_quorum_queues(body: &str) -> Option<Vec<QuorumQueue>> {
+    let string_list = |value: &deps_hack::serde_json::Value| -> Vec<String> {
+        value
+            .as_array()
+            .map(|values| {
+                values
+                    .iter()
+                    .filter_map(|v| v.as_str().map(|s| s.to_string()))
+                    .collect()
+            })
+            .unwrap_or_default()
+    };
+    let queues = deps_hack::serde_json::from_str::<deps_hack::serde_json::Value>(body).ok()?;
+    Some(
+        queues
+            .as_array()?
+            .iter()
+            .filter(|queue| queue["type"] == "quorum")
+            .map(|queue| QuorumQueue {
+                name: queue["name"].as_str().unwrap_or_default().to_string(),
+                vhost: queue["vhost"].as_str().unwrap_or_default().to_string(),
+                members: string_list(&queue["members"]),
+                online: string_list(&queue["online"]),
+            })
+            .collect(),
+    )
+}
+
+// shrink_quorum_queues_on_node removes the replicas hosted by the node from all the quorum queues,
+// so the queues no longer count the node towards their quorum once it is gone.
+pub fn shrink_quorum_queues_on_node(
+    addr: &str,
+    node: &str,
+    credentials: (&str, &str),
+) -> Result<(), RabbitmqAPIError> {
+    let path = format!(
+        "/api/queues/quorum/replicas/on/{}/shrink",
+        percent_encode(node)
+    );
+    let (status, _) = http_request("DELETE", addr, &path, credentials, "")
+        .map_err(|_e| RabbitmqAPIError::DrainNodesFailed)?;
+    if is_success(status) {
+        Ok(())
+    } else {
+        info!("Management API on {} returned status {}", addr, status);
+        Err(RabbitmqAPIError::DrainNodesFailed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{Read, Write};
+    use std::net::TcpListener;
+    use std::thread;
+
+    // Serves one request with the given status line and returns the raw request it received.
+    fn serve_once(status: &'static str) -> (String, thread::JoinHandle<String>) {
+        serve_once_with_body(status, "")
+    }
+
+    fn serve_once_with_body(
+        status: &'static str,
+        body: &'static str,
+    ) -> (String, thread::JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap().to_string();
+        let handle = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut buf = [0; 4096];
+            let n = stream.read(&mut buf).unwrap();
+            let response = format!(
+                "HTTP/1.1 {}\r\nContent-Length: {}\r\n\r\n{}",
+                status,
+                body.len(),
+                body
+            );
+            stream.write_all(response.as_bytes()).unwrap();
+            String::from_utf8_lossy(&buf[..n]).to_string()
+        });
+        (addr, handle)
+    }
+
+    fn owner_ref(uid: &str) -> KubeOwnerReference {
+        KubeOwnerReference {
+            api_version: "anvil.dev/v1".to_string(),
+            kind: "RabbitmqCluster".to_string(),
+            name: "rabbitmq".to_string(),
+            uid: uid.to_string(),
+            controller: Some(true),
+            block_owner_deletion: None,
+        }
+    }
+
+    fn stateful_set(replicas: i32, owner_uid: &str) -> StatefulSet {
+        let mut sts = StatefulSet::default();
+        sts.metadata.owner_references = Some(vec![owner_ref(owner_uid)]);
+        sts.spec = Some(deps_hack::k8s_openapi::api::apps::v1::StatefulSetSpec {
+            replicas: Some(replicas),
+            ..Default::default()
+        });
+        sts
+    }
+
+    fn quorum_queue(members: Vec<&str>, online: Vec<&str>) -> QuorumQueue {
+        QuorumQueue {
+            name: "q".to_string(),
+            vhost: "/".to_string(),
+            members: members.into_iter().map(|m| m.to_string()).collect(),
+            online: online.into_iter().map(|m| m.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn test_make_set_plugins_command() {
+        let plugins = vec![
+            "rabbitmq_management".to_string(),
+            "rabbitmq_shovel".to_string(),
+        ];
+        assert_eq!(
+            make_set_plugins_command(&plugins),
+            vec![
+                "rabbitmq-plugins",
+                "set",
+                "rabbitmq_management",
+                "rabbitmq_shovel"
+            ]
+        );
+        assert_eq!(
+            make_set_plugins_command(&Vec::new()),
+            vec!["rabbitmq-plugins", "set"]
+        );
+    }
+
+    #[test]
+    fn test_set_plugins_on_each_node() {
+        let plugins = vec!["rabbitmq_management".to_string()];
+        let mut calls = Vec::new();
+        let res =
+            set_plugins_on_each_node(&"rabbitmq".to_string(), 3, &plugins, |pod_name, command| {
+                calls.push((pod_name.clone(), command));
+                Ok(())
+            });
+        assert!(res.is_ok());
+        let command = make_set_plugins_command(&plugins);
+        assert_eq!(
+            calls,
+            vec![
+                ("rabbitmq-server-0".to_string(), command.clone()),
+                ("rabbitmq-server-1".to_string(), command.clone()),
+                ("rabbitmq-server-2".to_string(), command),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_set_plugins_on_each_node_stops_at_failure() {
+        let mut pods = Vec::new();
+        let res =
+            set_plugins_on_each_node(&"rabbitmq".to_string(), 3, &Vec::new(), |pod_name, _| {
+                pods.push(pod_name.clone());
+                if pod_name == "rabbitmq-server-1" {
+                    Err("Error: unknown plugin".to_string())
+                } else {
+                    Ok(())
+                }
+            });
+        assert!(matches!(res, Err(RabbitmqAPIError::SetPluginsFailed)));
+        assert_eq!(pods, vec!["rabbitmq-server-0", "rabbitmq-server-1"]);
+    }
+
+    #[test]
+    fn test_check_exec_status() {
+        let status = |status: &str| {
+            Some(Status {
+                status: Some(status.to_string()),
+                ..Default::default()
+            })
+        };
+        assert!(check_exec_status(status("Success"), String::new()).is_ok());
+        assert_eq!(
+            check_exec_status(status("Failure"), "Error: unknown plugin".to_string()),
+            Err("Error: unknown plugin".to_string())
+        );
+        assert!(check_exec_status(None, String::new()).is_err());
+        assert!(check_exec_status(Some(Status::default()), String::new()).is_err());
+    }
+
+    #[test]
+    fn test_keeps_majority_without() {
+        let removed = vec!["rabbit@n2".to_string()];
+        let queue = quorum_queue(
+            vec!["rabbit@n0", "rabbit@n1", "rabbit@n2"],
+            vec!["rabbit@n0", "rabbit@n1", "rabbit@n2"],
+        );
+        assert!(queue.keeps_majority_without(&removed));
+        // n1 is already offline, so removing n2 leaves only one of three members
+        let queue = quorum_queue(
+            vec!["rabbit@n0", "rabbit@n1", "rabbit@n2"],
+            vec!["rabbit@n0", "rabbit@n2"],
+        );
+        assert!(!queue.keeps_majority_without(&removed));
+        let removed = vec!["rabbit@n1".to_string(), "rabbit@n2".to_string()];
+        let queue = quorum_queue(
+            vec!["rabbit@n0", "rabbit@n1", "rabbit@n2"],
+            vec!["rabbit@n0", "rabbit@n1", "rabbit@n2"],
+        );
+        assert!(!queue.keeps_majority_without(&removed));
+    }
+
+    #[test]
+    fn test_list_quorum_queues() {
+        let (addr, handle) = serve_once_with_body(
+            "200 OK",
+            r#"[{"name":"q1","vhost":"/","type":"quorum","members":["rabbit@n0","rabbit@n1"],"online":["rabbit@n0"]},{"name":"q2","vhost":"/","type":"classic"}]"#,
+        );
+        let queues = list_quorum_queues(&addr, ("admin", "secret")).unwrap();
+        let request = handle.join().unwrap();
+        assert!(request.starts_with("GET /api/queues HTTP/1.1\r\n"));
+        assert!(request.contains("Authorization: Basic YWRtaW46c2VjcmV0\r\n"));
+        assert_eq!(queues.len(), 1);
+        assert_eq!(queues[0].name, "q1");
+        assert_eq!(queues[0].members, vec!["rabbit@n0", "rabbit@n1"]);
+        assert_eq!(queues[0].online, vec!["rabbit@n0"]);
+    }
+
+    #[test]
+    fn test_shrink_quorum_queues_on_node() {
+        let (addr, handle) = serve_once("204 No Content");
+        assert!(shrink_quorum_queues_on_node(
+            &addr,
+            "rabbit@rabbitmq-server-2",
+            ("admin", "secret")
+        )
+        .is_ok());
+        let request = handle.join().unwrap();
+        assert!(request.starts_with(
+            "DELETE /api/queues/quorum/replicas/on/rabbit%40rabbitmq-server-2/shrink HTTP/1.1\r\n"
+        ));
+    }
+
+    #[test]
+    fn test_rabbitmq_node_name() {
+        assert_eq!(
+            rabbitmq_node_name(&"rabbitmq".to_string(), &"default".to_string(), 1),
+            "rabbit@rabbitmq-server-1.rabbitmq-nodes.default"
+        );
+    }
+
+    #[test]
+    fn test_rabbitmq_pod_name() {
+        assert_eq!(
+            rabbitmq_pod_name(&"rabbitmq".to_string(), 2),
+            "rabbitmq-server-2"
+        );
+    }
+
+    #[test]
+    fn test_rabbitmq_node_addr() {
+        assert_eq!(
+            rabbitmq_node_addr(&"rabbitmq".to_string(), &"default".to_string(), 2),
+            "rabbitmq-server-2.rabbitmq-nodes.default.svc.cluster.local:15672"
+        );
+    }
+
+    #[test]
+    fn test_error_of() {
+        let drain_failed = RabbitmqAPIOutput::DrainNodesResponse(RabbitmqAPIDrainNodesResult { res: Err(RabbitmqAPIError::DrainNodesFailed) });
+        assert_eq!(RabbitmqAPIShimLayer::error_of(&drain_failed), Some("DrainNodesFailed".to_string()));
+        let set_plugins_done = RabbitmqAPIOutput::SetPluginsResponse(RabbitmqAPISetPluginsResult { res: Ok(()) });
+        assert_eq!(RabbitmqAPIShimLayer::error_of(&set_plugins_done), None);
+    }
+
+    #[test]
+    fn test_check_drain_nodes() {
+        assert!(check_drain_nodes(&stateful_set(3, "uid-1"), 3, 1, &owner_ref("uid-1")).is_ok());
+    }
+
+    #[test]
+    fn test_check_drain_nodes_rejects_other_owner() {
+        assert!(check_drain_nodes(&stateful_set(3, "uid-1"), 3, 1, &owner_ref("uid-2")).is_err());
+    }
+
+    #[test]
+    fn test_check_drain_nodes_rejects_replicas_mismatch() {
+        assert!(check_drain_nodes(&stateful_set(5, "uid-1"), 3, 1, &owner_ref("uid-1")).is_err());
+        assert!(check_drain_nodes(&StatefulSet::default(), 3, 1, &owner_ref("uid-1")).is_err());
+    }
+
+    #[test]
+    fn test_check_drain_nodes_rejects_scale_up() {
+        assert!(check_drain_nodes(&stateful_set(3, "uid-1"), 3, 3, &owner_ref("uid-1")).is_err());
+    }
+}
@@ -0,0 +1,153 @@
+// Copyright 2022 VMware, Inc.
+// SPDX-License-Identifier: MIT
+use crate::external_api::spec::*;
+use crate::kubernetes_api_objects::spec::{common::*, dynamic::*, owner_reference::*, resource::*, stateful_set::*};
+use crate::rabbitmq_controller::trusted::step::*;
+use vstd::{prelude::*, string::*};
+
+verus! {
+
+// RabbitmqNodesAddr identifies the rabbitmq nodes that the plugin list is applied to.
+// Similar to the zookeeper API, the nodes are hosted by a stateful set object identified by name and namespace,
+// and the uid distinguishes stateful set objects that share the same name -- the new stateful set object
+// starts with the plugins listed in the config map, instead of inheriting the plugins enabled on the old one.
+pub struct RabbitmqNodesAddr {
+    pub name: StringView,
+    pub namespace: StringView,
+    pub uid: Uid,
+}
+
+impl RabbitmqNodesAddr {
+    pub open spec fn new(name: StringView, namespace: StringView, uid: Uid) -> Self {
+        RabbitmqNodesAddr {
+            name: name,
+            namespace: namespace,
+            uid: uid,
+        }
+    }
+}
+
+pub struct RabbitmqAPI {}
+
+// RabbitmqAPIState maps the rabbitmq nodes to the plugins enabled on the running nodes,
+// and records every (uid, replicas) for which draining the nodes beyond replicas succeeded.
+// The uid is the one of the rabbitmq cluster owning the nodes, so a cluster recreated with the same name
+// does not inherit the drain checks done for the old one.
+pub struct RabbitmqAPIState {
+    pub enabled_plugins: Map<RabbitmqNodesAddr, Seq<StringView>>,
+    pub drained_replicas: Set<(Uid, int)>,
+}
+
+impl RabbitmqAPIState {
+    pub open spec fn init() -> RabbitmqAPIState {
+        RabbitmqAPIState {
+            enabled_plugins: Map::empty(),
+            drained_replicas: Set::empty(),
+        }
+    }
+}
+
+pub struct RabbitmqAPISetPluginsResultView {
+    pub res: Result<(), RabbitmqAPIError>,
+}
+
+pub struct RabbitmqAPIDrainNodesResultView {
+    pub res: Result<(), RabbitmqAPIError>,
+}
+
+#[is_variant]
+pub enum RabbitmqAPIInputView {
+    SetPluginsRequest(StringView, StringView, int, Seq<StringView>),
+    DrainNodesRequest(StringView, StringView, int, int, OwnerReferenceView),
+}
+
+#[is_variant]
+pub enum RabbitmqAPIOutputView {
+    SetPluginsResponse(RabbitmqAPISetPluginsResultView),
+    DrainNodesResponse(RabbitmqAPIDrainNodesResultView),
+}
+
+impl ExternalAPI for RabbitmqAPI {
+
+    type Input = RabbitmqAPIInputView;
+    type Output = RabbitmqAPIOutputView;
+    type State = RabbitmqAPIState;
+
+    open spec fn transition(input: RabbitmqAPIInputView, resources: StoredState, state: RabbitmqAPIState) -> (RabbitmqAPIState, RabbitmqAPIOutputView) {
+        match input {
+            RabbitmqAPIInputView::SetPluginsRequest(name, namespace, replicas, plugins) => {
+                let (s_prime, res) = handle_set_plugins(name, namespace, replicas, plugins, resources, state);
+                (s_prime, RabbitmqAPIOutputView::SetPluginsResponse(res))
+            },
+            RabbitmqAPIInputView::DrainNodesRequest(name, namespace, replicas, target_replicas, owner_ref) => {
+                let (s_prime, res) = handle_drain_nodes(name, namespace, replicas, target_replicas, owner_ref, resources, state);
+                (s_prime, RabbitmqAPIOutputView::DrainNodesResponse(res))
+            },
+        }
+    }
+
+    open spec fn init_state() -> RabbitmqAPIState { RabbitmqAPIState::init() }
+}
+
+// validate checks that the stateful set object hosting the rabbitmq nodes exists
+// and runs the number of replicas the request is addressed to.
+//
+// TODO: more validation check could be implemented,
+// such as checking whether the pods are really ready.
+pub open spec fn validate(name: StringView, namespace: StringView, replicas: int, resources: StoredState) -> bool {
+    let sts_key = ObjectRef {
+        kind: Kind::StatefulSetKind,
+        namespace: namespace,
+        name: name + "-server"@,
+    };
+    let sts_spec = StatefulSetView::unmarshal(resources[sts_key]).get_Ok_0().spec;
+    &&& resources.contains_key(sts_key)
+    &&& StatefulSetView::unmarshal(resources[sts_key]).is_Ok()
+    &&& sts_spec.is_Some()
+    &&& sts_spec.get_Some_0().replicas.is_Some()
+    &&& sts_spec.get_Some_0().replicas.get_Some_0() == replicas
+}
+
+// handle_set_plugins models running "rabbitmq-plugins set" on each rabbitmq node with the set plugins request.
+// The plugin list replaces the enabled plugins on the running nodes, so plugins missing from the list get disabled.
+// Note that it uses the uid to avoid updating the plugins belonging to the old stateful set object.
+pub open spec fn handle_set_plugins(name: StringView, namespace: StringView, replicas: int, plugins: Seq<StringView>, resources: StoredState, state: RabbitmqAPIState) -> (RabbitmqAPIState, RabbitmqAPISetPluginsResultView) {
+    let key = ObjectRef { kind: Kind::StatefulSetKind, namespace: namespace, name: name + "-server"@ };
+    if !validate(name, namespace, replicas, resources) {
+        (state, RabbitmqAPISetPluginsResultView{res: Err(RabbitmqAPIError::SetPluginsFailed)})
+    } else {
+        let addr = RabbitmqNodesAddr::new(name, namespace, resources[key].metadata.uid.get_Some_0());
+        let state_prime = RabbitmqAPIState {
+            enabled_plugins: state.enabled_plugins.insert(addr, plugins),
+            ..state
+        };
+        (state_prime, RabbitmqAPISetPluginsResultView{res: Ok(())})
+    }
+}
+
+// quorum_queues_keep_majority tells whether every quorum queue still has a majority of its members online
+// after the nodes with ordinal no smaller than target_replicas are removed.
+// The queue membership lives in the rabbitmq nodes instead of the kubernetes objects,
+// so we leave it as a closed function instead of modeling the queues.
+pub closed spec fn quorum_queues_keep_majority(name: StringView, namespace: StringView, replicas: int, target_replicas: int) -> bool;
+
+// handle_drain_nodes models the behavior of the management API handling the drain nodes request.
+// It refuses to drain if any quorum queue would lose its majority, otherwise it moves the queue replicas
+// off the nodes with ordinal no smaller than target_replicas and records that these nodes can be removed.
+// Note that the nodes are only drained on behalf of the owner_ref that owns the stateful set object hosting them,
+// and the drain check is recorded with the uid of the owner.
+pub open spec fn handle_drain_nodes(name: StringView, namespace: StringView, replicas: int, target_replicas: int, owner_ref: OwnerReferenceView, resources: StoredState, state: RabbitmqAPIState) -> (RabbitmqAPIState, RabbitmqAPIDrainNodesResultView) {
+    let key = ObjectRef { kind: Kind::StatefulSetKind, namespace: namespace, name: name + "-server"@ };
+    if !validate(name, namespace, replicas, resources) || !resources[key].metadata.owner_references_only_contains(owner_ref)
+    || target_replicas >= replicas || !quorum_queues_keep_majority(name, namespace, replicas, target_replicas) {
+        (state, RabbitmqAPIDrainNodesResultView{res: Err(RabbitmqAPIError::DrainNodesFailed)})
+    } else {
+        let state_prime = RabbitmqAPIState {
+            drained_replicas: state.drained_replicas.insert((owner_ref.uid, target_replicas)),
+            ..state
+        };
+        (state_prime, RabbitmqAPIDrainNodesResultView{res: Ok(())})
+    }
+}
+
+}
@@ -19,7 +19,7 @@ pub open spec fn cluster_spec_without_wf() -> TempPred<RMQCluster> {
 }
 
 pub open spec fn safety<M: Maker>(rabbitmq: RabbitmqClusterView) -> TempPred<RMQCluster> {
-    always(lift_action(stateful_set_not_scaled_down::<M>(rabbitmq)))
+    always(lift_action(stateful_set_scaled_down_only_after_drain::<M>(rabbitmq)))
 }
 
 // To prove the safety property about stateful set, we need to first specify what the property is.
@@ -29,12 +29,27 @@ pub open spec fn safety<M: Maker>(rabbitmq: RabbitmqClusterView) -> TempPred<RMQ
 // because Message is just a tool and a detail of the system. For update action, one way to circumvent using Message is
 // to talk about the previous and current state: an object being updated means that it exists in both states but changes
 // in current state.
-pub open spec fn stateful_set_not_scaled_down<M: Maker>(rabbitmq: RabbitmqClusterView) -> ActionPred<RMQCluster> {
+//
+// Scaling down is allowed, but only to replicas for which the drain check succeeded, that is, the management API confirmed
+// that no quorum queue loses its majority without the removed nodes and moved the queue replicas off these nodes.
+// The drain check is recorded in the state of the external API, so we don't need to mention Message here either.
+// The drain check is recorded with the uid of the rabbitmq cluster that owns the stateful set, instead of the name
+// and namespace, so that a rabbitmq cluster recreated with the same name cannot scale down with the old drain checks.
+pub open spec fn stateful_set_scaled_down_only_after_drain<M: Maker>(rabbitmq: RabbitmqClusterView) -> ActionPred<RMQCluster> {
     |s: RMQCluster, s_prime: RMQCluster| {
         let sts_key = M::make_stateful_set_key(rabbitmq);
+        let replicas = replicas_of_stateful_set(s_prime.resources()[sts_key]);
         s.resources().contains_key(sts_key)
         && s_prime.resources().contains_key(sts_key)
-        ==> replicas_of_stateful_set(s_prime.resources()[sts_key]) >= replicas_of_stateful_set(s.resources()[sts_key])
+        && replicas < replicas_of_stateful_set(s.resources()[sts_key])
+        ==> drained_for_owner(s, s_prime.resources()[sts_key], replicas)
+    }
+}
+
+pub open spec fn drained_for_owner(s: RMQCluster, obj: DynamicObjectView, replicas: int) -> bool {
+    exists |owner_ref: OwnerReferenceView| {
+        &&& #[trigger] obj.metadata.owner_references_only_contains(owner_ref)
+        &&& s.external_state().drained_replicas.contains((owner_ref.uid, replicas))
     }
 }
 
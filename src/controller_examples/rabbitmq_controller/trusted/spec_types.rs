@@ -1,13 +1,13 @@
 // Copyright 2022 VMware, Inc.
 // SPDX-License-Identifier: MIT
-use crate::external_api::spec::{EmptyAPI, EmptyTypeView};
 use crate::kubernetes_api_objects::error::*;
 use crate::kubernetes_api_objects::spec::{
-    affinity::*, api_resource::*, common::*, dynamic::*, object_meta::*, owner_reference::*,
-    resource::*, resource_requirements::*, stateful_set::*, toleration::*,
+    affinity::*, api_resource::*, common::*, condition::*, dynamic::*, object_meta::*,
+    owner_reference::*, resource::*, resource_requirements::*, stateful_set::*, toleration::*,
+    topology_spread_constraint::*,
 };
 use crate::kubernetes_cluster::spec::{cluster::*, cluster_state_machine::*, message::*};
-use crate::rabbitmq_controller::trusted::step::*;
+use crate::rabbitmq_controller::trusted::{rabbitmq_api_spec::*, step::*};
 use crate::vstd_ext::string_view::*;
 use vstd::prelude::*;
 
@@ -15,15 +15,16 @@ verus! {
 
 pub type RMQStep = Step<RMQMessage>;
 
-pub type RMQCluster = Cluster<RabbitmqClusterView, EmptyAPI, RabbitmqReconciler>;
+pub type RMQCluster = Cluster<RabbitmqClusterView, RabbitmqAPI, RabbitmqReconciler>;
 
-pub type RMQMessage = Message<EmptyTypeView, EmptyTypeView>;
+pub type RMQMessage = Message<RabbitmqAPIInputView, RabbitmqAPIOutputView>;
 
 pub struct RabbitmqReconciler {}
 
 pub struct RabbitmqReconcileState {
     pub reconcile_step: RabbitmqReconcileStep,
     pub latest_config_map_rv_opt: Option<StringView>,
+    pub drained_replicas_opt: Option<int>,
 }
 
 pub struct RabbitmqClusterView {
@@ -32,8 +33,6 @@ pub struct RabbitmqClusterView {
     pub status: Option<RabbitmqClusterStatusView>,
 }
 
-pub type RabbitmqClusterStatusView = EmptyStatusView;
-
 impl RabbitmqClusterView {
     pub open spec fn well_formed(self) -> bool {
         &&& self.metadata.name.is_Some()
@@ -50,6 +49,13 @@ impl RabbitmqClusterView {
             uid: self.metadata.uid.get_Some_0(),
         }
     }
+
+    pub open spec fn set_status(self, status: RabbitmqClusterStatusView) -> RabbitmqClusterView {
+        RabbitmqClusterView {
+            status: Some(status),
+            ..self
+        }
+    }
 }
 
 impl ResourceView for RabbitmqClusterView {
@@ -134,6 +140,7 @@ impl ResourceView for RabbitmqClusterView {
 
     open spec fn state_validation(self) -> bool {
         &&& self.spec.replicas >= 0
+        &&& self.spec.pod_disruption_budget.is_Some() ==> self.spec.pod_disruption_budget.get_Some_0().min_available >= 0
         // &&& self.spec.pod_management_policy.is_Some() ==>
         //     (self.spec.pod_management_policy.get_Some_0() == "OrderedReady"@
         //         || self.spec.pod_management_policy.get_Some_0() == "Parallel"@)
@@ -142,10 +149,14 @@ impl ResourceView for RabbitmqClusterView {
     }
 
     open spec fn transition_validation(self, old_obj: RabbitmqClusterView) -> bool {
-        &&& self.spec.replicas >= old_obj.spec.replicas
+        // allow_scale_down cannot be unset once set, so a cr without it has never had its replicas reduced
+        // and the stateful set owned by it never has more replicas than it
+        &&& old_obj.spec.allow_scale_down ==> self.spec.allow_scale_down
+        &&& self.spec.allow_scale_down || self.spec.replicas >= old_obj.spec.replicas
         &&& self.spec.persistence.storage == old_obj.spec.persistence.storage
         &&& self.spec.persistence.storage_class_name == old_obj.spec.persistence.storage_class_name
         &&& self.spec.pod_management_policy == old_obj.spec.pod_management_policy
+        &&& self.spec.cluster_role_name == old_obj.spec.cluster_role_name // the role_ref of the role binding is immutable
     }
 
 }
@@ -153,8 +164,11 @@ impl ResourceView for RabbitmqClusterView {
 impl CustomResourceView for RabbitmqClusterView {
     proof fn kind_is_custom_resource() {}
 
+    open spec fn is_namespaced() -> bool { true }
+
     open spec fn spec_status_validation(obj_spec: Self::Spec, obj_status: Self::Status) -> bool {
         &&& obj_spec.replicas >= 0
+        &&& obj_spec.pod_disruption_budget.is_Some() ==> obj_spec.pod_disruption_budget.get_Some_0().min_available >= 0
     }
 
     proof fn validation_result_determined_by_spec_and_status()
@@ -169,11 +183,28 @@ pub struct RabbitmqClusterSpecView {
     pub rabbitmq_config: Option<RabbitmqConfigView>,
     pub affinity: Option<AffinityView>,
     pub tolerations: Option<Seq<TolerationView>>,
+    pub topology_spread_constraints: Option<Seq<TopologySpreadConstraintView>>,
+    pub priority_class_name: Option<StringView>,
     pub labels: Map<StringView, StringView>,
     pub annotations: Map<StringView, StringView>,
     pub resources: Option<ResourceRequirementsView>,
     pub pod_management_policy: StringView,
     pub persistent_volume_claim_retention_policy: Option<StatefulSetPersistentVolumeClaimRetentionPolicyView>,
+    pub additional_plugins: Seq<StringView>,
+    pub tls: Option<RabbitmqTLSSpecView>,
+    pub allow_scale_down: bool,
+    pub cluster_role_name: Option<StringView>,
+    pub pod_disruption_budget: Option<RabbitmqPodDisruptionBudgetSpecView>,
+}
+
+pub struct RabbitmqTLSSpecView {
+    pub secret_name: StringView,
+    pub ca_secret_name: Option<StringView>,
+    pub disable_non_tls_listeners: bool,
+}
+
+pub struct RabbitmqPodDisruptionBudgetSpecView {
+    pub min_available: int,
 }
 
 pub struct RabbitmqConfigView {
@@ -187,6 +218,23 @@ pub struct RabbitmqClusterPersistenceSpecView {
     pub storage: StringView,
 }
 
-pub closed spec fn random_encoded_string(length: usize) -> StringView;
+pub struct RabbitmqClusterStatusView {
+    pub conditions: Option<Seq<ConditionView>>,
+}
+
+impl RabbitmqClusterStatusView {
+    pub open spec fn default() -> RabbitmqClusterStatusView {
+        RabbitmqClusterStatusView {
+            conditions: None,
+        }
+    }
+
+    pub open spec fn set_conditions(self, conditions: Seq<ConditionView>) -> RabbitmqClusterStatusView {
+        RabbitmqClusterStatusView {
+            conditions: Some(conditions),
+            ..self
+        }
+    }
+}
 
 }
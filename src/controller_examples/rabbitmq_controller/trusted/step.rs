@@ -9,6 +9,10 @@ verus! {
 pub enum RabbitmqReconcileStep {
     Init,
     AfterKRequestStep(ActionKind, SubResource),
+    AfterUpdateStatus,
+    AfterUpdateDegradedStatus,
+    AfterSetPlugins,
+    AfterDrainNodes,
     Done,
     Error,
 }
@@ -32,9 +36,26 @@ pub enum SubResource {
     ServiceAccount,
     Role,
     RoleBinding,
+    PodDisruptionBudget,
     StatefulSet,
 }
 
+#[is_variant]
+pub enum RabbitmqAPIError {
+    SetPluginsFailed,
+    DrainNodesFailed,
+}
+
+#[verifier(external)]
+impl std::fmt::Debug for RabbitmqAPIError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            RabbitmqAPIError::SetPluginsFailed => write!(f, "SetPluginsFailed"),
+            RabbitmqAPIError::DrainNodesFailed => write!(f, "DrainNodesFailed"),
+        }
+    }
+}
+
 #[is_variant]
 pub enum ActionKind {
     Get,
@@ -128,15 +128,58 @@ pub proof fn lemma_from_diff_and_init_to_current_state_matches(
         list_resp(diff)
     );
 
-    // Now we've listed the pods, perform different behaviors accoding to the difference.
-    if diff < 0 {
-        let create_resp = |diff: int| lift_state(
-            |s: VRSCluster| {
-                &&& exists_ok_resp_in_flight_at_after_create_pod_step(vrs, abs(diff))(s)
-                &&& num_diff_pods_is(vrs, diff)(s)
+    let create_resp = |diff: int| lift_state(
+        |s: VRSCluster| {
+            &&& exists_ok_resp_in_flight_at_after_create_pod_step(vrs, abs(diff))(s)
+            &&& num_diff_pods_is(vrs, diff)(s)
+        }
+    );
+    let delete_resp = |diff: int| lift_state(
+        |s: VRSCluster| {
+            &&& exists_ok_resp_in_flight_at_after_delete_pod_step(vrs, abs(diff))(s)
+            &&& num_diff_pods_is(vrs, diff)(s)
+        }
+    );
+    let matches = lift_state(current_state_matches(vrs));
+
+    // Each round of reconcile either deletes or creates one pod, so we rank the states after the response by
+    // (pods to delete, pods to create) in the lexicographic order, and every round moves to a lower rank.
+    let resp_rank = |r: (nat, nat)| if r.1 == 0 { delete_resp(r.0 as int) } else { create_resp(-(r.1 as int)) };
+    assert forall |r: (nat, nat)| #[trigger] spec.entails(resp_rank(r).leads_to(matches.or(lower_rank(nat_pair_lt(), resp_rank, r)))) by {
+        let lower = lower_rank(nat_pair_lt(), resp_rank, r);
+        if r.1 == 0 && r.0 == 0 {
+            entails_implies_leads_to(spec, delete_resp(0), matches.or(lower));
+        } else if r.1 == 0 {
+            let n = r.0 as int;
+            let next_r = ((r.0 - 1) as nat, 0nat);
+            lemma_from_after_receive_delete_pod_resp_to_receive_delete_pod_resp(spec, vrs, n);
+            assert forall |ex| #[trigger] delete_resp(n - 1).satisfied_by(ex) implies matches.or(lower).satisfied_by(ex) by {
+                assert(nat_pair_lt()(next_r, r) && resp_rank(next_r).satisfied_by(ex));
+            };
+            entails_implies_leads_to(spec, delete_resp(n - 1), matches.or(lower));
+            assert(resp_rank(r) == delete_resp(n));
+            leads_to_trans(spec, delete_resp(n), delete_resp(n - 1), matches.or(lower));
+        } else {
+            let n = -(r.1 as int);
+            lemma_from_after_receive_create_pod_resp_to_receive_create_pod_resp(spec, vrs, n);
+            if r.1 == 1 {
+                entails_implies_leads_to(spec, create_resp(0), matches.or(lower));
+            } else {
+                let next_r = (r.0, (r.1 - 1) as nat);
+                assert forall |ex| #[trigger] create_resp(n + 1).satisfied_by(ex) implies matches.or(lower).satisfied_by(ex) by {
+                    assert(nat_pair_lt()(next_r, r) && resp_rank(next_r).satisfied_by(ex));
+                };
+                entails_implies_leads_to(spec, create_resp(n + 1), matches.or(lower));
             }
-        );
+            assert(resp_rank(r) == create_resp(n));
+            leads_to_trans(spec, create_resp(n), create_resp(n + 1), matches.or(lower));
+        }
+    };
+    nat_pair_lt_is_well_founded();
+    leads_to_rank_well_founded(spec, nat_pair_lt(), resp_rank, matches);
 
+    // Now we've listed the pods, perform different behaviors accoding to the difference.
+    if diff < 0 {
         // Add first create pod request after listing pods to leads-to chain.
         lemma_from_after_receive_list_pods_resp_to_receive_create_pod_resp(spec, vrs, diff);
         leads_to_trans_n!(
@@ -149,68 +192,27 @@ pub proof fn lemma_from_diff_and_init_to_current_state_matches(
         // Is this enough?
         if diff + 1 == 0 {
             // If so, pre(diff) ~> current_state_matches(vrs) trivially.
-            entails_implies_leads_to(spec, create_resp(diff + 1), lift_state(current_state_matches(vrs)));
+            entails_implies_leads_to(spec, create_resp(diff + 1), matches);
             leads_to_trans_n!(
                 spec,
                 pre(diff),
                 create_resp(diff + 1),
-                lift_state(current_state_matches(vrs))
+                matches
             );
             return;
         }
 
-        // In this branch, we need to convert negative integers to natural numbers for
-        // the predicate to fit the rule leads_to_rank_step_one.
-        let ranking_pred = |n: nat| create_resp(-n);
-
-        // Useful assertions to let us chain in and out of ranking_pred
-        assert forall |n: nat| #![trigger create_resp(-n)]
-                    spec.entails(create_resp(-n).leads_to(ranking_pred(n))) by {
-            entails_implies_leads_to(spec, create_resp(-n), ranking_pred(n));
-        };
-
-        assert forall |n: nat| #![trigger create_resp(-n)]
-                    spec.entails(ranking_pred(n).leads_to(create_resp(-n))) by {
-            entails_implies_leads_to(spec, ranking_pred(n), create_resp(-n));
-        };
-
-         // Proving n > 0 => ranking_pred(n) ~> ranking_pred(n - 1)
-        assert forall |n: nat| #![trigger ranking_pred(n)]
-                    n > 0 implies spec.entails(ranking_pred(n).leads_to(ranking_pred((n - 1) as nat))) by {
-            let diff = -n;
-            lemma_from_after_receive_create_pod_resp_to_receive_create_pod_resp(spec, vrs, diff);
-
-            leads_to_trans_n!(
-                spec,
-                ranking_pred(n),
-                create_resp(diff),
-                create_resp(diff + 1),
-                ranking_pred((n - 1) as nat)
-            );
-        };
-
-        // Apply ranking function lemma
-        leads_to_rank_step_one(spec, ranking_pred);
-
-        // Chain everything
-        entails_implies_leads_to(spec, create_resp(0), lift_state(current_state_matches(vrs)));
+        // Otherwise there are still -(diff + 1) pods to create and no pod to delete.
+        let r = (0nat, (-(diff + 1)) as nat);
+        assert(spec.entails(resp_rank(r).leads_to(matches)));
+        assert(resp_rank(r) == create_resp(diff + 1));
         leads_to_trans_n!(
             spec,
             pre(diff),
             create_resp(diff + 1),
-            ranking_pred(-(diff + 1) as nat),
-            ranking_pred(0),
-            create_resp(0),
-            lift_state(current_state_matches(vrs))
+            matches
         );
     } else if diff > 0 {
-        let delete_resp = |diff: int| lift_state(
-            |s: VRSCluster| {
-                &&& exists_ok_resp_in_flight_at_after_delete_pod_step(vrs, abs(diff))(s)
-                &&& num_diff_pods_is(vrs, diff)(s)
-            }
-        );
-
         // Add first delete pod request after listing pods to leads-to chain.
         lemma_from_after_receive_list_pods_resp_to_receive_delete_pod_resp(spec, vrs, diff);
         leads_to_trans_n!(
@@ -220,60 +222,15 @@ pub proof fn lemma_from_diff_and_init_to_current_state_matches(
             delete_resp(diff - 1)
         );
 
-        // Is this enough?
-        if diff - 1 == 0 {
-            // If so, pre(diff) ~> current_state_matches(vrs) trivially.
-            entails_implies_leads_to(spec, delete_resp(diff - 1), lift_state(current_state_matches(vrs)));
-            leads_to_trans_n!(
-                spec,
-                pre(diff),
-                delete_resp(diff - 1),
-                lift_state(current_state_matches(vrs))
-            );
-            return;
-        }
-
-        let ranking_pred = |n: nat| delete_resp(n as int);
-
-        // Useful assertions to let us chain in and out of ranking_pred
-        assert forall |n: nat| #![trigger ranking_pred(n)]
-                    spec.entails(delete_resp(n as int).leads_to(ranking_pred(n))) by {
-            entails_implies_leads_to(spec, delete_resp(n as int), ranking_pred(n));
-        };
-
-        assert forall |n: nat| #![trigger ranking_pred(n)]
-                    spec.entails(ranking_pred(n).leads_to(delete_resp(n as int))) by {
-            entails_implies_leads_to(spec, ranking_pred(n), delete_resp(n as int));
-        };
-
-         // Proving n > 0 => ranking_pred(n) ~> ranking_pred(n - 1)
-        assert forall |n: nat| #![trigger ranking_pred(n)]
-                    n > 0 implies spec.entails(ranking_pred(n).leads_to(ranking_pred((n - 1) as nat))) by {
-            let diff = n as int;
-            lemma_from_after_receive_delete_pod_resp_to_receive_delete_pod_resp(spec, vrs, diff);
-
-            leads_to_trans_n!(
-                spec,
-                ranking_pred(n),
-                delete_resp(diff),
-                delete_resp(diff - 1),
-                ranking_pred((n - 1) as nat)
-            );
-        };
-
-        // Apply ranking function lemma
-        leads_to_rank_step_one(spec, ranking_pred);
-
-        // Chain everything
-        entails_implies_leads_to(spec, delete_resp(0), lift_state(current_state_matches(vrs)));
+        // There are diff - 1 pods left to delete and no pod to create.
+        let r = ((diff - 1) as nat, 0nat);
+        assert(spec.entails(resp_rank(r).leads_to(matches)));
+        assert(resp_rank(r) == delete_resp(diff - 1));
         leads_to_trans_n!(
             spec,
             pre(diff),
             delete_resp(diff - 1),
-            ranking_pred((diff - 1) as nat),
-            ranking_pred(0),
-            delete_resp(0),
-            lift_state(current_state_matches(vrs))
+            matches
         );
     } else {
         // diff = 0
@@ -141,6 +141,8 @@ pub open spec fn invariants(vrs: VReplicaSetView) -> TempPred<VRSCluster> {
 pub open spec fn derived_invariants_since_beginning(vrs: VReplicaSetView) -> TempPred<VRSCluster> {
     always(lift_state(VRSCluster::every_in_flight_msg_has_unique_id()))
     .and(always(lift_state(VRSCluster::pending_req_in_flight_or_resp_in_flight_at_reconcile_state(vrs.object_ref(), at_step_closure(VReplicaSetReconcileStep::AfterListPods)))))
+    .and(always(lift_state(VRSCluster::pending_req_in_flight_or_resp_in_flight_at_reconcile_state(vrs.object_ref(), at_step_closure(VReplicaSetReconcileStep::AfterUpdateStatus)))))
+    .and(always(lift_state(VRSCluster::pending_req_in_flight_or_resp_in_flight_at_reconcile_state(vrs.object_ref(), at_step_closure(VReplicaSetReconcileStep::AfterUpdateDegradedStatus)))))
     .and(always(lift_state(VRSCluster::every_in_flight_req_msg_has_different_id_from_pending_req_msg_of(vrs.object_ref()))))
     .and(always(lift_state(VRSCluster::object_in_ok_get_response_has_smaller_rv_than_etcd())))
     .and(always(lift_state(VRSCluster::pending_req_of_key_is_unique_with_unique_id(vrs.object_ref()))))
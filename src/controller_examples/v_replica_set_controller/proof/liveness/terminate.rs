@@ -41,6 +41,10 @@ pub proof fn reconcile_eventually_terminates(spec: TempPred<VRSCluster>, vrs: VR
         spec.entails(always(tla_forall(|diff: usize| lift_state(VRSCluster::pending_req_in_flight_or_resp_in_flight_at_reconcile_state(
                 vrs.object_ref(), at_step_closure(VReplicaSetReconcileStep::AfterDeletePod(diff))
             ))))),
+        spec.entails(always(lift_state(VRSCluster::pending_req_in_flight_or_resp_in_flight_at_reconcile_state(
+            vrs.object_ref(), at_step_closure(VReplicaSetReconcileStep::AfterUpdateStatus))))),
+        spec.entails(always(lift_state(VRSCluster::pending_req_in_flight_or_resp_in_flight_at_reconcile_state(
+            vrs.object_ref(), at_step_closure(VReplicaSetReconcileStep::AfterUpdateDegradedStatus))))),
 
     ensures spec.entails(true_pred().leads_to(lift_state(|s: VRSCluster| !s.ongoing_reconciles().contains_key(vrs.object_ref())))),
 {
@@ -75,10 +79,26 @@ pub proof fn reconcile_eventually_terminates(spec: TempPred<VRSCluster>, vrs: VR
     temp_pred_equality(lift_state(at_step_state_pred(vrs, VReplicaSetReconcileStep::Error)), lift_state(VRSCluster::reconciler_reconcile_error(vrs.object_ref())));
     entails_implies_leads_to(spec, lift_state(reconcile_idle), lift_state(reconcile_idle));
 
-    // Second, prove that after_create_pod_rank(0) \/ after_delete_pod_rank(0) ~> reconcile_idle.
+    // Second, prove that after_update_status ~> reconcile_idle.
+    let state_after_update_status = |s: VReplicaSetReconcileState| {
+        s.reconcile_step == VReplicaSetReconcileStep::Done
+        || s.reconcile_step == VReplicaSetReconcileStep::Error
+    };
+    or_leads_to_combine_and_equality!(
+        spec, lift_state(VRSCluster::at_expected_reconcile_states(vrs.object_ref(), state_after_update_status)),
+        lift_state(at_step_state_pred(vrs, VReplicaSetReconcileStep::Done)),
+        lift_state(at_step_state_pred(vrs, VReplicaSetReconcileStep::Error));
+        lift_state(reconcile_idle)
+    );
+    VRSCluster::lemma_from_some_state_to_arbitrary_next_state_to_reconcile_idle(spec, vrs, at_step_closure(VReplicaSetReconcileStep::AfterUpdateStatus), state_after_update_status);
+
+    // Then, prove that after_update_degraded_status ~> reconcile_idle, since it always ends in error.
+    VRSCluster::lemma_from_some_state_to_arbitrary_next_state_to_reconcile_idle(spec, vrs, at_step_closure(VReplicaSetReconcileStep::AfterUpdateDegradedStatus), at_step_closure(VReplicaSetReconcileStep::Error));
+
+    // Third, prove that after_create_pod_rank(0) \/ after_delete_pod_rank(0) ~> reconcile_idle.
     lemma_from_after_create_or_delete_pod_rank_zero_to_reconcile_idle(spec, vrs);
 
-    // Third, prove for all n that AfterCreatePod(n) ~> reconcile_idle.
+    // Fourth, prove for all n that AfterCreatePod(n) ~> reconcile_idle.
     assert forall |n: usize| #![auto]
         spec.entails(lift_state(at_step_state_pred(vrs, VReplicaSetReconcileStep::AfterCreatePod(n)))
                     .leads_to(lift_state(reconcile_idle))) by {
@@ -132,10 +152,10 @@ pub proof fn reconcile_eventually_terminates(spec: TempPred<VRSCluster>, vrs: VR
         );
     }
 
-    // Fourth, prove that after_list_pods ~> reconcile_idle.
+    // Fifth, prove that after_list_pods ~> reconcile_idle.
     lemma_from_after_list_pods_to_reconcile_idle(spec, vrs);
 
-    // Fifth, prove that reconcile init state can reach AfterListPods.
+    // Sixth, prove that reconcile init state can reach AfterListPods.
     VRSCluster::lemma_from_init_state_to_next_state_to_reconcile_idle(
         spec, vrs, at_step_closure(VReplicaSetReconcileStep::Init), at_step_closure(VReplicaSetReconcileStep::AfterListPods));
 
@@ -167,6 +187,8 @@ pub proof fn reconcile_eventually_terminates(spec: TempPred<VRSCluster>, vrs: VR
         lift_state(at_step_state_pred(vrs, VReplicaSetReconcileStep::AfterListPods)),
         tla_exists(at_after_create_pod),
         tla_exists(at_after_delete_pod),
+        lift_state(at_step_state_pred(vrs, VReplicaSetReconcileStep::AfterUpdateStatus)),
+        lift_state(at_step_state_pred(vrs, VReplicaSetReconcileStep::AfterUpdateDegradedStatus)),
         lift_state(at_step_state_pred(vrs, VReplicaSetReconcileStep::Done)),
         lift_state(at_step_state_pred(vrs, VReplicaSetReconcileStep::Error));
         lift_state(reconcile_idle)
@@ -181,7 +203,7 @@ pub open spec fn after_create_pod_rank(vrs: VReplicaSetView, diff: usize) -> Sta
     |s: VRSCluster| {
         ||| at_step_state_pred(vrs, VReplicaSetReconcileStep::AfterCreatePod(diff))(s)
         // There may have been an error as well.
-        ||| at_step_state_pred(vrs, VReplicaSetReconcileStep::Error)(s)
+        ||| at_step_state_pred(vrs, VReplicaSetReconcileStep::AfterUpdateDegradedStatus)(s)
     }
 }
 
@@ -189,7 +211,7 @@ pub open spec fn after_delete_pod_rank(vrs: VReplicaSetView, diff: usize) -> Sta
     |s: VRSCluster| {
         ||| at_step_state_pred(vrs, VReplicaSetReconcileStep::AfterDeletePod(diff))(s)
         // There may have been an error as well.
-        ||| at_step_state_pred(vrs, VReplicaSetReconcileStep::Error)(s)
+        ||| at_step_state_pred(vrs, VReplicaSetReconcileStep::AfterUpdateDegradedStatus)(s)
     }
 }
 
@@ -211,9 +233,9 @@ proof fn lemma_from_after_create_or_delete_pod_rank_zero_to_reconcile_idle(
         spec.entails(always(lift_state(VRSCluster::pending_req_in_flight_or_resp_in_flight_at_reconcile_state(
             vrs.object_ref(), at_step_closure(VReplicaSetReconcileStep::AfterDeletePod(0)))))),
         // The next state will lead to reconcile_idle.
-        spec.entails(lift_state(at_step_state_pred(vrs, VReplicaSetReconcileStep::Done))
+        spec.entails(lift_state(at_step_state_pred(vrs, VReplicaSetReconcileStep::AfterUpdateStatus))
             .leads_to(lift_state(|s: VRSCluster| !s.ongoing_reconciles().contains_key(vrs.object_ref())))),
-        spec.entails(lift_state(at_step_state_pred(vrs, VReplicaSetReconcileStep::Error))
+        spec.entails(lift_state(at_step_state_pred(vrs, VReplicaSetReconcileStep::AfterUpdateDegradedStatus))
             .leads_to(lift_state(|s: VRSCluster| !s.ongoing_reconciles().contains_key(vrs.object_ref())))),
     ensures
         spec.entails(lift_state(after_create_pod_rank(vrs, 0))
@@ -222,13 +244,13 @@ proof fn lemma_from_after_create_or_delete_pod_rank_zero_to_reconcile_idle(
             .leads_to(lift_state(|s: VRSCluster| !s.ongoing_reconciles().contains_key(vrs.object_ref())))),
 {
     let state_after_create_or_delete = |s: VReplicaSetReconcileState| {
-        s.reconcile_step == VReplicaSetReconcileStep::Done
-        || s.reconcile_step == VReplicaSetReconcileStep::Error
+        s.reconcile_step == VReplicaSetReconcileStep::AfterUpdateStatus
+        || s.reconcile_step == VReplicaSetReconcileStep::AfterUpdateDegradedStatus
     };
     or_leads_to_combine_and_equality!(
         spec, lift_state(VRSCluster::at_expected_reconcile_states(vrs.object_ref(), state_after_create_or_delete)),
-        lift_state(at_step_state_pred(vrs, VReplicaSetReconcileStep::Done)),
-        lift_state(at_step_state_pred(vrs, VReplicaSetReconcileStep::Error));
+        lift_state(at_step_state_pred(vrs, VReplicaSetReconcileStep::AfterUpdateStatus)),
+        lift_state(at_step_state_pred(vrs, VReplicaSetReconcileStep::AfterUpdateDegradedStatus));
         lift_state(|s: VRSCluster| { !s.ongoing_reconciles().contains_key(vrs.object_ref()) })
     );
 
@@ -238,13 +260,13 @@ proof fn lemma_from_after_create_or_delete_pod_rank_zero_to_reconcile_idle(
     or_leads_to_combine_and_equality!(
         spec, lift_state(after_create_pod_rank(vrs, 0)),
         lift_state(at_step_state_pred(vrs, VReplicaSetReconcileStep::AfterCreatePod(0))),
-        lift_state(at_step_state_pred(vrs, VReplicaSetReconcileStep::Error));
+        lift_state(at_step_state_pred(vrs, VReplicaSetReconcileStep::AfterUpdateDegradedStatus));
         lift_state(|s: VRSCluster| { !s.ongoing_reconciles().contains_key(vrs.object_ref()) })
     );
     or_leads_to_combine_and_equality!(
         spec, lift_state(after_delete_pod_rank(vrs, 0)),
         lift_state(at_step_state_pred(vrs, VReplicaSetReconcileStep::AfterDeletePod(0))),
-        lift_state(at_step_state_pred(vrs, VReplicaSetReconcileStep::Error));
+        lift_state(at_step_state_pred(vrs, VReplicaSetReconcileStep::AfterUpdateDegradedStatus));
         lift_state(|s: VRSCluster| { !s.ongoing_reconciles().contains_key(vrs.object_ref()) })
     );
 }
@@ -271,12 +293,12 @@ proof fn lemma_from_after_create_pod_rank_n_to_create_pod_rank_n_minus_1(
 {
     let state_after_create = |s: VReplicaSetReconcileState| {
         s.reconcile_step == VReplicaSetReconcileStep::AfterCreatePod((n - 1) as usize)
-        || s.reconcile_step == VReplicaSetReconcileStep::Error
+        || s.reconcile_step == VReplicaSetReconcileStep::AfterUpdateDegradedStatus
     };
 
     entails_implies_leads_to(
         spec,
-        lift_state(at_step_state_pred(vrs, VReplicaSetReconcileStep::Error)),
+        lift_state(at_step_state_pred(vrs, VReplicaSetReconcileStep::AfterUpdateDegradedStatus)),
         lift_state(after_create_pod_rank(vrs, (n - 1) as usize))
     );
 
@@ -297,7 +319,7 @@ proof fn lemma_from_after_create_pod_rank_n_to_create_pod_rank_n_minus_1(
     or_leads_to_combine_and_equality!(
         spec, lift_state(after_create_pod_rank(vrs, n)),
         lift_state(at_step_state_pred(vrs, VReplicaSetReconcileStep::AfterCreatePod(n))),
-        lift_state(at_step_state_pred(vrs, VReplicaSetReconcileStep::Error));
+        lift_state(at_step_state_pred(vrs, VReplicaSetReconcileStep::AfterUpdateDegradedStatus));
         lift_state(after_create_pod_rank(vrs, (n - 1) as usize))
     );
 }
@@ -324,12 +346,12 @@ proof fn lemma_from_after_delete_pod_rank_n_to_delete_pod_rank_n_minus_1(
 {
     let state_after_delete = |s: VReplicaSetReconcileState| {
         s.reconcile_step == VReplicaSetReconcileStep::AfterDeletePod((n - 1) as usize)
-        || s.reconcile_step == VReplicaSetReconcileStep::Error
+        || s.reconcile_step == VReplicaSetReconcileStep::AfterUpdateDegradedStatus
     };
 
     entails_implies_leads_to(
         spec,
-        lift_state(at_step_state_pred(vrs, VReplicaSetReconcileStep::Error)),
+        lift_state(at_step_state_pred(vrs, VReplicaSetReconcileStep::AfterUpdateDegradedStatus)),
         lift_state(after_delete_pod_rank(vrs, (n - 1) as usize))
     );
 
@@ -350,7 +372,7 @@ proof fn lemma_from_after_delete_pod_rank_n_to_delete_pod_rank_n_minus_1(
     or_leads_to_combine_and_equality!(
         spec, lift_state(after_delete_pod_rank(vrs, n)),
         lift_state(at_step_state_pred(vrs, VReplicaSetReconcileStep::AfterDeletePod(n))),
-        lift_state(at_step_state_pred(vrs, VReplicaSetReconcileStep::Error));
+        lift_state(at_step_state_pred(vrs, VReplicaSetReconcileStep::AfterUpdateDegradedStatus));
         lift_state(after_delete_pod_rank(vrs, (n - 1) as usize))
     );
 }
@@ -377,9 +399,9 @@ proof fn lemma_from_after_list_pods_to_reconcile_idle(
         forall |n: usize| #![auto]
             spec.entails(lift_state(at_step_state_pred(vrs, VReplicaSetReconcileStep::AfterDeletePod(n)))
                 .leads_to(lift_state(|s: VRSCluster| !s.ongoing_reconciles().contains_key(vrs.object_ref())))),
-        spec.entails(lift_state(at_step_state_pred(vrs, VReplicaSetReconcileStep::Done))
+        spec.entails(lift_state(at_step_state_pred(vrs, VReplicaSetReconcileStep::AfterUpdateStatus))
             .leads_to(lift_state(|s: VRSCluster| !s.ongoing_reconciles().contains_key(vrs.object_ref())))),
-        spec.entails(lift_state(at_step_state_pred(vrs, VReplicaSetReconcileStep::Error))
+        spec.entails(lift_state(at_step_state_pred(vrs, VReplicaSetReconcileStep::AfterUpdateDegradedStatus))
             .leads_to(lift_state(|s: VRSCluster| !s.ongoing_reconciles().contains_key(vrs.object_ref())))),
     ensures
         spec.entails(lift_state(at_step_state_pred(vrs, VReplicaSetReconcileStep::AfterListPods))
@@ -388,8 +410,8 @@ proof fn lemma_from_after_list_pods_to_reconcile_idle(
     let state_after_list_pods = |s: VReplicaSetReconcileState| {
         ||| exists |n: usize| s.reconcile_step == VReplicaSetReconcileStep::AfterCreatePod(n)
         ||| exists |n: usize| s.reconcile_step == VReplicaSetReconcileStep::AfterDeletePod(n)
-        ||| s.reconcile_step == VReplicaSetReconcileStep::Done
-        ||| s.reconcile_step == VReplicaSetReconcileStep::Error
+        ||| s.reconcile_step == VReplicaSetReconcileStep::AfterUpdateStatus
+        ||| s.reconcile_step == VReplicaSetReconcileStep::AfterUpdateDegradedStatus
     };
     leads_to_exists_intro(
         spec,
@@ -454,8 +476,8 @@ proof fn lemma_from_after_list_pods_to_reconcile_idle(
         spec, lift_state(VRSCluster::at_expected_reconcile_states(vrs.object_ref(), state_after_list_pods)),
         tla_exists(at_after_create_pod),
         tla_exists(at_after_delete_pod),
-        lift_state(at_step_state_pred(vrs, VReplicaSetReconcileStep::Done)),
-        lift_state(at_step_state_pred(vrs, VReplicaSetReconcileStep::Error));
+        lift_state(at_step_state_pred(vrs, VReplicaSetReconcileStep::AfterUpdateStatus)),
+        lift_state(at_step_state_pred(vrs, VReplicaSetReconcileStep::AfterUpdateDegradedStatus));
         lift_state(|s: VRSCluster| { !s.ongoing_reconciles().contains_key(vrs.object_ref()) })
     );
 
@@ -477,6 +499,8 @@ proof fn lemma_true_equal_to_reconcile_idle_or_at_any_state(vrs: VReplicaSetView
                     .or(lift_state(at_step_state_pred(vrs, VReplicaSetReconcileStep::AfterListPods)))
                     .or(tla_exists(|n| lift_state(at_step_state_pred(vrs, VReplicaSetReconcileStep::AfterCreatePod(n)))))
                     .or(tla_exists(|n| lift_state(at_step_state_pred(vrs, VReplicaSetReconcileStep::AfterDeletePod(n)))))
+                    .or(lift_state(at_step_state_pred(vrs, VReplicaSetReconcileStep::AfterUpdateStatus)))
+                    .or(lift_state(at_step_state_pred(vrs, VReplicaSetReconcileStep::AfterUpdateDegradedStatus)))
                     .or(lift_state(at_step_state_pred(vrs, VReplicaSetReconcileStep::Done)))
                     .or(lift_state(at_step_state_pred(vrs, VReplicaSetReconcileStep::Error)))
 {
@@ -485,6 +509,8 @@ proof fn lemma_true_equal_to_reconcile_idle_or_at_any_state(vrs: VReplicaSetView
                 .or(lift_state(at_step_state_pred(vrs, VReplicaSetReconcileStep::AfterListPods)))
                 .or(tla_exists(|n| lift_state(at_step_state_pred(vrs, VReplicaSetReconcileStep::AfterCreatePod(n)))))
                 .or(tla_exists(|n| lift_state(at_step_state_pred(vrs, VReplicaSetReconcileStep::AfterDeletePod(n)))))
+                .or(lift_state(at_step_state_pred(vrs, VReplicaSetReconcileStep::AfterUpdateStatus)))
+                .or(lift_state(at_step_state_pred(vrs, VReplicaSetReconcileStep::AfterUpdateDegradedStatus)))
                 .or(lift_state(at_step_state_pred(vrs, VReplicaSetReconcileStep::Done)))
                 .or(lift_state(at_step_state_pred(vrs, VReplicaSetReconcileStep::Error)));
 
@@ -515,6 +541,7 @@ proof fn lemma_true_equal_to_reconcile_idle_or_at_any_state(vrs: VReplicaSetView
             .or(lift_state(at_step_state_pred(vrs, VReplicaSetReconcileStep::AfterListPods)))
             .or(tla_exists(|n| lift_state(at_step_state_pred(vrs, VReplicaSetReconcileStep::AfterCreatePod(n)))))
             .or(tla_exists(|n| lift_state(at_step_state_pred(vrs, VReplicaSetReconcileStep::AfterDeletePod(n)))))
+            .or(lift_state(at_step_state_pred(vrs, VReplicaSetReconcileStep::AfterUpdateStatus)))
             .or(lift_state(at_step_state_pred(vrs, VReplicaSetReconcileStep::Done)))
             .or(lift_state(at_step_state_pred(vrs, VReplicaSetReconcileStep::Error)))
     );
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: MIT
 use crate::kubernetes_api_objects::error::UnmarshalError;
 use crate::kubernetes_api_objects::exec::{
-    api_resource::*, label_selector::*, pod_template_spec::*, prelude::*,
+    api_resource::*, condition::*, label_selector::*, pod_template_spec::*, prelude::*,
 };
 use crate::kubernetes_api_objects::spec::resource::*;
 use crate::v_replica_set_controller::trusted::{spec_types, step::*};
@@ -60,6 +60,13 @@ impl View for VReplicaSet {
 }
 
 impl VReplicaSet {
+    #[verifier(external_body)]
+    pub fn clone(&self) -> (vrs: Self)
+        ensures vrs@ == self@,
+    {
+        VReplicaSet { inner: self.inner.clone() }
+    }
+
     #[verifier(external_body)]
     pub fn metadata(&self) -> (metadata: ObjectMeta)
         ensures metadata@ == self@.metadata,
@@ -74,6 +81,18 @@ impl VReplicaSet {
         VReplicaSetSpec { inner: self.inner.spec.clone() }
     }
 
+    #[verifier(external_body)]
+    pub fn set_status(&mut self, status: VReplicaSetStatus)
+        ensures self@ == old(self)@.set_status(status@),
+    {
+        let mut new_status = status.into_kube();
+        match (&mut new_status.conditions, self.inner.status.as_ref().and_then(|s| s.conditions.as_ref())) {
+            (Some(conditions), Some(old_conditions)) => preserve_transition_times(conditions, old_conditions),
+            _ => {},
+        }
+        self.inner.status = Some(new_status);
+    }
+
     #[verifier(external_body)]
     pub fn api_resource() -> (res: ApiResource)
         ensures res@.kind == spec_types::VReplicaSetView::kind(),
@@ -158,4 +177,30 @@ impl VReplicaSetSpec {
     }
 }
 
+#[verifier(external_body)]
+pub struct VReplicaSetStatus {
+    inner: deps_hack::VReplicaSetStatus,
+}
+
+impl VReplicaSetStatus {
+    pub spec fn view(&self) -> spec_types::VReplicaSetStatusView;
+
+    #[verifier(external_body)]
+    pub fn default() -> (status: VReplicaSetStatus)
+        ensures status@ == spec_types::VReplicaSetStatusView::default(),
+    {
+        VReplicaSetStatus { inner: deps_hack::VReplicaSetStatus::default() }
+    }
+
+    #[verifier(external_body)]
+    pub fn set_conditions(&mut self, conditions: Vec<Condition>)
+        ensures self@ == old(self)@.set_conditions(conditions@.map_values(|c: Condition| c@)),
+    {
+        self.inner.conditions = Some(into_kube_conditions(conditions))
+    }
+
+    #[verifier(external)]
+    fn into_kube(self) -> deps_hack::VReplicaSetStatus { self.inner }
+}
+
 }
@@ -3,7 +3,7 @@
 use crate::external_api::spec::{EmptyAPI, EmptyTypeView};
 use crate::kubernetes_api_objects::error::*;
 use crate::kubernetes_api_objects::spec::{
-    api_resource::*, label_selector::*, pod_template_spec::*, prelude::*,
+    api_resource::*, condition::*, label_selector::*, pod_template_spec::*, prelude::*,
 };
 use crate::kubernetes_cluster::spec::{cluster::*, cluster_state_machine::*, message::*};
 use crate::v_replica_set_controller::trusted::step::*;
@@ -31,8 +31,6 @@ pub struct VReplicaSetView {
     pub status: Option<VReplicaSetStatusView>,
 }
 
-pub type VReplicaSetStatusView = EmptyStatusView;
-
 impl VReplicaSetView {
     pub open spec fn well_formed(self) -> bool {
         &&& self.metadata.name.is_Some()
@@ -53,6 +51,13 @@ impl VReplicaSetView {
             uid: self.metadata.uid.get_Some_0(),
         }
     }
+
+    pub open spec fn set_status(self, status: VReplicaSetStatusView) -> VReplicaSetView {
+        VReplicaSetView {
+            status: Some(status),
+            ..self
+        }
+    }
 }
 
 impl ResourceView for VReplicaSetView {
@@ -148,6 +153,8 @@ impl ResourceView for VReplicaSetView {
 impl CustomResourceView for VReplicaSetView {
     proof fn kind_is_custom_resource() {}
 
+    open spec fn is_namespaced() -> bool { true }
+
     open spec fn spec_status_validation(obj_spec: Self::Spec, obj_status: Self::Status) -> bool {
         obj_spec.replicas.is_Some() ==> obj_spec.replicas.get_Some_0() >= 0
     }
@@ -163,4 +170,23 @@ pub struct VReplicaSetSpecView {
     pub template: Option<PodTemplateSpecView>,
 }
 
+pub struct VReplicaSetStatusView {
+    pub conditions: Option<Seq<ConditionView>>,
+}
+
+impl VReplicaSetStatusView {
+    pub open spec fn default() -> VReplicaSetStatusView {
+        VReplicaSetStatusView {
+            conditions: None,
+        }
+    }
+
+    pub open spec fn set_conditions(self, conditions: Seq<ConditionView>) -> VReplicaSetStatusView {
+        VReplicaSetStatusView {
+            conditions: Some(conditions),
+            ..self
+        }
+    }
+}
+
 }
@@ -11,6 +11,8 @@ pub enum VReplicaSetReconcileStep {
     AfterListPods,
     AfterCreatePod(usize),
     AfterDeletePod(usize),
+    AfterUpdateStatus,
+    AfterUpdateDegradedStatus,
     Done,
     Error,
 }
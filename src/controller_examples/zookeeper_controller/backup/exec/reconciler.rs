@@ -0,0 +1,400 @@
+// Copyright 2022 VMware, Inc.
+// SPDX-License-Identifier: MIT
+#![allow(unused_imports)]
+use crate::external_api::exec::*;
+use crate::kubernetes_api_objects::exec::{
+    condition::*, container::*, pod_template_spec::*, prelude::*, resource::ResourceWrapper,
+    volume::*,
+};
+use crate::reconciler::exec::{io::*, reconciler::*};
+use crate::vstd_ext::{string_map::StringMap, string_view::*};
+use crate::zookeeper_controller::backup::model::reconciler as model_reconciler;
+use crate::zookeeper_controller::backup::trusted::{exec_types::*, spec_types, step::*};
+use crate::zookeeper_controller::exec::resource::make_data_pvc_name;
+use crate::zookeeper_controller::trusted::exec_types::ZookeeperCluster;
+use vstd::{prelude::*, seq_lib::*, string::*};
+
+verus! {
+
+pub struct ZookeeperBackupReconciler {}
+
+impl Reconciler for ZookeeperBackupReconciler {
+    type R = ZookeeperBackup;
+    type T = ZookeeperBackupReconcileState;
+    type ExternalAPIType = EmptyAPIShimLayer;
+
+    open spec fn well_formed(backup: &ZookeeperBackup) -> bool { backup@.well_formed() }
+
+    fn reconcile_init_state() -> ZookeeperBackupReconcileState {
+        reconcile_init_state()
+    }
+
+    fn reconcile_core(backup: &ZookeeperBackup, resp_o: Option<Response<EmptyType>>, state: ZookeeperBackupReconcileState)
+    -> (ZookeeperBackupReconcileState, Option<Request<EmptyType>>) {
+        reconcile_core(backup, resp_o, state)
+    }
+
+    fn reconcile_done(state: &ZookeeperBackupReconcileState) -> bool {
+        reconcile_done(state)
+    }
+
+    fn reconcile_error(state: &ZookeeperBackupReconcileState) -> bool {
+        reconcile_error(state)
+    }
+}
+
+pub fn reconcile_init_state() -> (state: ZookeeperBackupReconcileState)
+    ensures state@ == model_reconciler::reconcile_init_state(),
+{
+    ZookeeperBackupReconcileState {
+        reconcile_step: ZookeeperBackupReconcileStep::Init,
+        data_pod_node_name_opt: None,
+    }
+}
+
+pub fn reconcile_done(state: &ZookeeperBackupReconcileState) -> (res: bool)
+    ensures res == model_reconciler::reconcile_done(state@),
+{
+    match state.reconcile_step {
+        ZookeeperBackupReconcileStep::Done => true,
+        _ => false,
+    }
+}
+
+pub fn reconcile_error(state: &ZookeeperBackupReconcileState) -> (res: bool)
+    ensures res == model_reconciler::reconcile_error(state@),
+{
+    match state.reconcile_step {
+        ZookeeperBackupReconcileStep::Error => true,
+        _ => false,
+    }
+}
+
+pub fn reconcile_core(backup: &ZookeeperBackup, resp_o: Option<Response<EmptyType>>, state: ZookeeperBackupReconcileState) -> (res: (ZookeeperBackupReconcileState, Option<Request<EmptyType>>))
+    requires backup@.well_formed(),
+    ensures (res.0@, opt_request_to_view(&res.1)) == model_reconciler::reconcile_core(backup@, opt_response_to_view(&resp_o), state@),
+{
+    let step = state.reconcile_step;
+    match step {
+        ZookeeperBackupReconcileStep::Init => {
+            let req_o = KubeAPIRequest::GetRequest(KubeGetRequest {
+                api_resource: Job::api_resource(),
+                name: make_backup_job_name(backup),
+                namespace: backup.metadata().namespace().unwrap(),
+            });
+            let state_prime = ZookeeperBackupReconcileState {
+                reconcile_step: ZookeeperBackupReconcileStep::AfterKRequestStep(ActionKind::Get, SubResource::BackupJob),
+                ..state
+            };
+            return (state_prime, Some(Request::KRequest(req_o)));
+        },
+        ZookeeperBackupReconcileStep::AfterKRequestStep(ActionKind::Get, SubResource::BackupJob) => {
+            if resp_o.is_some() && resp_o.as_ref().unwrap().is_k_response()
+            && resp_o.as_ref().unwrap().as_k_response_ref().is_get_response() {
+                let get_resp = resp_o.unwrap().into_k_response().into_get_response().res;
+                if get_resp.is_ok() {
+                    let job = Job::unmarshal(get_resp.unwrap());
+                    if job.is_ok() {
+                        let req_o = KubeAPIRequest::UpdateStatusRequest(KubeUpdateStatusRequest {
+                            api_resource: ZookeeperBackup::api_resource(),
+                            name: backup.metadata().name().unwrap(),
+                            namespace: backup.metadata().namespace().unwrap(),
+                            obj: update_backup_status(backup, job.unwrap().succeeded()).marshal(),
+                        });
+                        let state_prime = ZookeeperBackupReconcileState {
+                            reconcile_step: ZookeeperBackupReconcileStep::AfterUpdateStatus,
+                            ..state
+                        };
+                        return (state_prime, Some(Request::KRequest(req_o)));
+                    }
+                } else if get_resp.unwrap_err().is_object_not_found() {
+                    let req_o = KubeAPIRequest::GetRequest(KubeGetRequest {
+                        api_resource: Pod::api_resource(),
+                        name: make_data_pod_name(backup),
+                        namespace: backup.metadata().namespace().unwrap(),
+                    });
+                    let state_prime = ZookeeperBackupReconcileState {
+                        reconcile_step: ZookeeperBackupReconcileStep::AfterKRequestStep(ActionKind::Get, SubResource::DataPod),
+                        ..state
+                    };
+                    return (state_prime, Some(Request::KRequest(req_o)));
+                }
+            }
+            return report_error(backup, state);
+        },
+        ZookeeperBackupReconcileStep::AfterKRequestStep(ActionKind::Get, SubResource::DataPod) => {
+            if resp_o.is_some() && resp_o.as_ref().unwrap().is_k_response()
+            && resp_o.as_ref().unwrap().as_k_response_ref().is_get_response()
+            && resp_o.as_ref().unwrap().as_k_response_ref().as_get_response_ref().res.is_ok() {
+                let pod = Pod::unmarshal(resp_o.unwrap().into_k_response().into_get_response().res.unwrap());
+                if pod.is_ok() {
+                    let pod_spec = pod.unwrap().spec();
+                    if pod_spec.is_some() && pod_spec.as_ref().unwrap().node_name().is_some() {
+                        let req_o = KubeAPIRequest::GetRequest(KubeGetRequest {
+                            api_resource: ZookeeperCluster::api_resource(),
+                            name: backup.spec().zookeeper_cluster_reference().name(),
+                            namespace: backup.metadata().namespace().unwrap(),
+                        });
+                        let state_prime = ZookeeperBackupReconcileState {
+                            reconcile_step: ZookeeperBackupReconcileStep::AfterKRequestStep(ActionKind::Get, SubResource::ZookeeperCluster),
+                            data_pod_node_name_opt: pod_spec.unwrap().node_name(),
+                        };
+                        return (state_prime, Some(Request::KRequest(req_o)));
+                    }
+                }
+            }
+            return report_error(backup, state);
+        },
+        ZookeeperBackupReconcileStep::AfterKRequestStep(ActionKind::Get, SubResource::ZookeeperCluster) => {
+            if resp_o.is_some() && resp_o.as_ref().unwrap().is_k_response()
+            && resp_o.as_ref().unwrap().as_k_response_ref().is_get_response()
+            && resp_o.as_ref().unwrap().as_k_response_ref().as_get_response_ref().res.is_ok() {
+                let zk = ZookeeperCluster::unmarshal(resp_o.unwrap().into_k_response().into_get_response().res.unwrap());
+                if zk.is_ok() {
+                    let zk = zk.unwrap();
+                    if zk.metadata().name().is_some() && zk.spec().persistence().enabled() && state.data_pod_node_name_opt.is_some() {
+                        let req_o = KubeAPIRequest::CreateRequest(KubeCreateRequest {
+                            api_resource: Job::api_resource(),
+                            namespace: backup.metadata().namespace().unwrap(),
+                            obj: make_backup_job(backup, &zk, state.data_pod_node_name_opt.as_ref().unwrap()).marshal(),
+                        });
+                        let state_prime = ZookeeperBackupReconcileState {
+                            reconcile_step: ZookeeperBackupReconcileStep::AfterKRequestStep(ActionKind::Create, SubResource::BackupJob),
+                            ..state
+                        };
+                        return (state_prime, Some(Request::KRequest(req_o)));
+                    }
+                }
+            }
+            return report_error(backup, state);
+        },
+        ZookeeperBackupReconcileStep::AfterKRequestStep(ActionKind::Create, SubResource::BackupJob) => {
+            if resp_o.is_some() && resp_o.as_ref().unwrap().is_k_response()
+            && resp_o.as_ref().unwrap().as_k_response_ref().is_create_response()
+            && resp_o.as_ref().unwrap().as_k_response_ref().as_create_response_ref().res.is_ok() {
+                let req_o = KubeAPIRequest::UpdateStatusRequest(KubeUpdateStatusRequest {
+                    api_resource: ZookeeperBackup::api_resource(),
+                    name: backup.metadata().name().unwrap(),
+                    namespace: backup.metadata().namespace().unwrap(),
+                    obj: update_backup_status(backup, false).marshal(),
+                });
+                let state_prime = ZookeeperBackupReconcileState {
+                    reconcile_step: ZookeeperBackupReconcileStep::AfterUpdateStatus,
+                    ..state
+                };
+                return (state_prime, Some(Request::KRequest(req_o)));
+            }
+            return report_error(backup, state);
+        },
+        ZookeeperBackupReconcileStep::AfterUpdateStatus => {
+            if resp_o.is_some() && resp_o.as_ref().unwrap().is_k_response()
+            && resp_o.as_ref().unwrap().as_k_response_ref().is_update_status_response() {
+                let update_status_resp = resp_o.unwrap().into_k_response().into_update_status_response().res;
+                if update_status_resp.is_ok() {
+                    let state_prime = ZookeeperBackupReconcileState {
+                        reconcile_step: ZookeeperBackupReconcileStep::Done,
+                        ..state
+                    };
+                    return (state_prime, None);
+                }
+            }
+            let state_prime = ZookeeperBackupReconcileState {
+                reconcile_step: ZookeeperBackupReconcileStep::Error,
+                ..state
+            };
+            return (state_prime, None);
+        },
+        ZookeeperBackupReconcileStep::AfterUpdateDegradedStatus => {
+            let state_prime = ZookeeperBackupReconcileState {
+                reconcile_step: ZookeeperBackupReconcileStep::Error,
+                ..state
+            };
+            return (state_prime, None);
+        },
+        _ => {
+            let state_prime = ZookeeperBackupReconcileState {
+                reconcile_step: step,
+                ..state
+            };
+            return (state_prime, None);
+        }
+    }
+}
+
+pub fn make_data_pod_name(backup: &ZookeeperBackup) -> (name: String)
+    requires backup@.well_formed(),
+    ensures name@ == model_reconciler::make_data_pod_name(backup@),
+{
+    backup.spec().zookeeper_cluster_reference().name().concat("-0")
+}
+
+pub fn make_backup_job_name(backup: &ZookeeperBackup) -> (name: String)
+    requires backup@.well_formed(),
+    ensures name@ == model_reconciler::make_backup_job_name(backup@),
+{
+    backup.metadata().name().unwrap().concat("-backup")
+}
+
+pub fn make_backup_command(backup: &ZookeeperBackup) -> (command: String)
+    requires backup@.well_formed(),
+    ensures command@ == model_reconciler::make_backup_command(backup@),
+{
+    let dir = "/backup/".to_string().concat(backup.metadata().name().unwrap().as_str());
+    "rm -rf ".to_string().concat(dir.as_str()).concat(" && mkdir -p ").concat(dir.as_str()).concat(" && cp -a /data/. ").concat(dir.as_str()).concat("/")
+}
+
+pub fn make_backup_job(backup: &ZookeeperBackup, zk: &ZookeeperCluster, node_name: &String) -> (job: Job)
+    requires
+        backup@.well_formed(),
+        zk@.metadata.name.is_Some(),
+    ensures job@ == model_reconciler::make_backup_job(backup@, zk@, node_name@),
+{
+    let mut job = Job::default();
+    job.set_metadata({
+        let mut metadata = ObjectMeta::default();
+        metadata.set_name(make_backup_job_name(backup));
+        metadata
+    });
+    job.set_spec({
+        let mut job_spec = JobSpec::default();
+        job_spec.set_backoff_limit(3);
+        job_spec.set_template({
+            let mut pod_template_spec = PodTemplateSpec::default();
+            pod_template_spec.set_spec({
+                let mut pod_spec = PodSpec::default();
+                pod_spec.set_restart_policy("OnFailure".to_string());
+                pod_spec.set_node_name(node_name.clone());
+                pod_spec.set_containers({
+                    let mut containers = Vec::new();
+                    containers.push({
+                        let mut container = Container::default();
+                        container.set_name("backup".to_string());
+                        container.set_image(zk.spec().image());
+                        container.set_command({
+                            let mut command = Vec::new();
+                            command.push("/bin/sh".to_string());
+                            command.push("-c".to_string());
+                            command.push(make_backup_command(backup));
+                            proof {
+                                assert_seqs_equal!(
+                                    command@.map_values(|s: String| s@),
+                                    seq!["/bin/sh"@, "-c"@, model_reconciler::make_backup_command(backup@)]
+                                );
+                            }
+                            command
+                        });
+                        container.set_volume_mounts({
+                            let mut volume_mounts = Vec::new();
+                            volume_mounts.push({
+                                let mut data_volume_mount = VolumeMount::default();
+                                data_volume_mount.set_name("data".to_string());
+                                data_volume_mount.set_mount_path("/data".to_string());
+                                data_volume_mount.set_read_only(true);
+                                data_volume_mount
+                            });
+                            volume_mounts.push({
+                                let mut backup_volume_mount = VolumeMount::default();
+                                backup_volume_mount.set_name("backup".to_string());
+                                backup_volume_mount.set_mount_path("/backup".to_string());
+                                backup_volume_mount
+                            });
+                            proof {
+                                assert_seqs_equal!(
+                                    volume_mounts@.map_values(|volume_mount: VolumeMount| volume_mount@),
+                                    model_reconciler::make_backup_job(backup@, zk@, node_name@).spec.get_Some_0().template.spec.get_Some_0().containers[0].volume_mounts.get_Some_0()
+                                );
+                            }
+                            volume_mounts
+                        });
+                        container
+                    });
+                    proof {
+                        assert_seqs_equal!(
+                            containers@.map_values(|container: Container| container@),
+                            model_reconciler::make_backup_job(backup@, zk@, node_name@).spec.get_Some_0().template.spec.get_Some_0().containers
+                        );
+                    }
+                    containers
+                });
+                pod_spec.set_volumes({
+                    let mut volumes = Vec::new();
+                    volumes.push({
+                        let mut data_volume = Volume::default();
+                        data_volume.set_name("data".to_string());
+                        data_volume.set_persistent_volume_claim({
+                            let mut pvc_source = PersistentVolumeClaimVolumeSource::default();
+                            pvc_source.set_claim_name(make_data_pvc_name(zk));
+                            pvc_source.set_read_only(true);
+                            pvc_source
+                        });
+                        data_volume
+                    });
+                    volumes.push({
+                        let mut backup_volume = Volume::default();
+                        backup_volume.set_name("backup".to_string());
+                        backup_volume.set_persistent_volume_claim({
+                            let mut pvc_source = PersistentVolumeClaimVolumeSource::default();
+                            pvc_source.set_claim_name(backup.spec().persistent_volume_claim());
+                            pvc_source
+                        });
+                        backup_volume
+                    });
+                    proof {
+                        assert_seqs_equal!(
+                            volumes@.map_values(|vol: Volume| vol@),
+                            model_reconciler::make_backup_job(backup@, zk@, node_name@).spec.get_Some_0().template.spec.get_Some_0().volumes.get_Some_0()
+                        );
+                    }
+                    volumes
+                });
+                pod_spec
+            });
+            pod_template_spec
+        });
+        job_spec
+    });
+    job
+}
+
+pub fn update_backup_status(backup: &ZookeeperBackup, ready: bool) -> (updated_backup: ZookeeperBackup)
+    ensures updated_backup@ == model_reconciler::update_backup_status(backup@, ready),
+{
+    let mut updated_backup = backup.clone();
+    updated_backup.set_status({
+        let mut status = ZookeeperBackupStatus::default();
+        status.set_conditions(make_conditions(ready, false, backup.metadata().generation()));
+        status
+    });
+    updated_backup
+}
+
+pub fn update_backup_status_to_degraded(backup: &ZookeeperBackup) -> (updated_backup: ZookeeperBackup)
+    ensures updated_backup@ == model_reconciler::update_backup_status_to_degraded(backup@),
+{
+    let mut updated_backup = backup.clone();
+    updated_backup.set_status({
+        let mut status = ZookeeperBackupStatus::default();
+        status.set_conditions(make_conditions(false, true, backup.metadata().generation()));
+        status
+    });
+    updated_backup
+}
+
+pub fn report_error(backup: &ZookeeperBackup, state: ZookeeperBackupReconcileState) -> (res: (ZookeeperBackupReconcileState, Option<Request<EmptyType>>))
+    requires backup@.well_formed(),
+    ensures (res.0@, opt_request_to_view(&res.1)) == model_reconciler::report_error(backup@, state@),
+{
+    let req_o = KubeAPIRequest::UpdateStatusRequest(KubeUpdateStatusRequest {
+        api_resource: ZookeeperBackup::api_resource(),
+        name: backup.metadata().name().unwrap(),
+        namespace: backup.metadata().namespace().unwrap(),
+        obj: update_backup_status_to_degraded(backup).marshal(),
+    });
+    let state_prime = ZookeeperBackupReconcileState {
+        reconcile_step: ZookeeperBackupReconcileStep::AfterUpdateDegradedStatus,
+        ..state
+    };
+    (state_prime, Some(Request::KRequest(req_o)))
+}
+
+}
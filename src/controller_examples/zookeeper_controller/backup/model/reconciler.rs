@@ -0,0 +1,304 @@
+// Copyright 2022 VMware, Inc.
+// SPDX-License-Identifier: MIT
+#![allow(unused_imports)]
+use crate::external_api::spec::*;
+use crate::kubernetes_api_objects::spec::{
+    condition::*, container::*, pod_template_spec::*, prelude::*, volume::*,
+};
+use crate::reconciler::spec::{io::*, reconciler::*};
+use crate::vstd_ext::string_view::*;
+use crate::zookeeper_controller::backup::trusted::{spec_types::*, step::*};
+use crate::zookeeper_controller::model::resource::make_data_pvc_name;
+use crate::zookeeper_controller::trusted::spec_types::ZookeeperClusterView;
+use vstd::{prelude::*, string::*};
+
+verus! {
+
+impl Reconciler<ZookeeperBackupView, EmptyAPI> for ZookeeperBackupReconciler {
+    type T = ZookeeperBackupReconcileState;
+
+    open spec fn reconcile_init_state() -> ZookeeperBackupReconcileState {
+        reconcile_init_state()
+    }
+
+    open spec fn reconcile_core(backup: ZookeeperBackupView, resp_o: Option<ResponseView<EmptyTypeView>>, state: ZookeeperBackupReconcileState)
+    -> (ZookeeperBackupReconcileState, Option<RequestView<EmptyTypeView>>) {
+        reconcile_core(backup, resp_o, state)
+    }
+
+    open spec fn reconcile_done(state: ZookeeperBackupReconcileState) -> bool {
+        reconcile_done(state)
+    }
+
+    open spec fn reconcile_error(state: ZookeeperBackupReconcileState) -> bool {
+        reconcile_error(state)
+    }
+
+    open spec fn expect_from_user(obj: DynamicObjectView) -> bool {
+        false /* Don't expect anything from the user except the cr object */
+    }
+}
+
+pub open spec fn reconcile_init_state() -> ZookeeperBackupReconcileState {
+    ZookeeperBackupReconcileState {
+        reconcile_step: ZookeeperBackupReconcileStep::Init,
+        data_pod_node_name_opt: None,
+    }
+}
+
+pub open spec fn reconcile_done(state: ZookeeperBackupReconcileState) -> bool {
+    match state.reconcile_step {
+        ZookeeperBackupReconcileStep::Done => true,
+        _ => false,
+    }
+}
+
+pub open spec fn reconcile_error(state: ZookeeperBackupReconcileState) -> bool {
+    match state.reconcile_step {
+        ZookeeperBackupReconcileStep::Error => true,
+        _ => false,
+    }
+}
+
+// The backup job is looked up before the zookeeper cluster so that a finished backup keeps
+// its status even if the cluster is deleted later.
+// The data volume of the first replica is ReadWriteOnce, so the backup job has to run on the node
+// of the first replica; the pod is looked up before the cluster to find that node.
+pub open spec fn reconcile_core(
+    backup: ZookeeperBackupView, resp_o: Option<ResponseView<EmptyTypeView>>, state: ZookeeperBackupReconcileState
+) -> (ZookeeperBackupReconcileState, Option<RequestView<EmptyTypeView>>) {
+    let step = state.reconcile_step;
+    let resp = resp_o.get_Some_0();
+    match step {
+        ZookeeperBackupReconcileStep::Init => {
+            let req_o = APIRequest::GetRequest(GetRequest { key: make_backup_job_key(backup) });
+            let state_prime = ZookeeperBackupReconcileState {
+                reconcile_step: ZookeeperBackupReconcileStep::AfterKRequestStep(ActionKind::Get, SubResource::BackupJob),
+                ..state
+            };
+            (state_prime, Some(RequestView::KRequest(req_o)))
+        },
+        ZookeeperBackupReconcileStep::AfterKRequestStep(ActionKind::Get, SubResource::BackupJob) => {
+            let get_resp = resp.get_KResponse_0().get_GetResponse_0().res;
+            if resp_o.is_Some() && resp.is_KResponse() && resp.get_KResponse_0().is_GetResponse() {
+                if get_resp.is_Ok() && JobView::unmarshal(get_resp.get_Ok_0()).is_Ok() {
+                    let job = JobView::unmarshal(get_resp.get_Ok_0()).get_Ok_0();
+                    let req_o = APIRequest::UpdateStatusRequest(UpdateStatusRequest {
+                        namespace: backup.metadata.namespace.get_Some_0(),
+                        name: backup.metadata.name.get_Some_0(),
+                        obj: update_backup_status(backup, job.succeeded()).marshal(),
+                    });
+                    let state_prime = ZookeeperBackupReconcileState {
+                        reconcile_step: ZookeeperBackupReconcileStep::AfterUpdateStatus,
+                        ..state
+                    };
+                    (state_prime, Some(RequestView::KRequest(req_o)))
+                } else if get_resp.is_Err() && get_resp.get_Err_0().is_ObjectNotFound() {
+                    let req_o = APIRequest::GetRequest(GetRequest { key: make_data_pod_key(backup) });
+                    let state_prime = ZookeeperBackupReconcileState {
+                        reconcile_step: ZookeeperBackupReconcileStep::AfterKRequestStep(ActionKind::Get, SubResource::DataPod),
+                        ..state
+                    };
+                    (state_prime, Some(RequestView::KRequest(req_o)))
+                } else {
+                    report_error(backup, state)
+                }
+            } else {
+                report_error(backup, state)
+            }
+        },
+        ZookeeperBackupReconcileStep::AfterKRequestStep(ActionKind::Get, SubResource::DataPod) => {
+            let get_resp = resp.get_KResponse_0().get_GetResponse_0().res;
+            let pod = PodView::unmarshal(get_resp.get_Ok_0()).get_Ok_0();
+            if resp_o.is_Some() && resp.is_KResponse() && resp.get_KResponse_0().is_GetResponse()
+            && get_resp.is_Ok() && PodView::unmarshal(get_resp.get_Ok_0()).is_Ok()
+            && pod.spec.is_Some() && pod.spec.get_Some_0().node_name.is_Some() {
+                let req_o = APIRequest::GetRequest(GetRequest { key: make_zookeeper_cluster_key(backup) });
+                let state_prime = ZookeeperBackupReconcileState {
+                    reconcile_step: ZookeeperBackupReconcileStep::AfterKRequestStep(ActionKind::Get, SubResource::ZookeeperCluster),
+                    data_pod_node_name_opt: pod.spec.get_Some_0().node_name,
+                    ..state
+                };
+                (state_prime, Some(RequestView::KRequest(req_o)))
+            } else {
+                // The first replica is not scheduled yet, so there is no node to run the backup on.
+                report_error(backup, state)
+            }
+        },
+        ZookeeperBackupReconcileStep::AfterKRequestStep(ActionKind::Get, SubResource::ZookeeperCluster) => {
+            let get_resp = resp.get_KResponse_0().get_GetResponse_0().res;
+            let zk = ZookeeperClusterView::unmarshal(get_resp.get_Ok_0()).get_Ok_0();
+            if resp_o.is_Some() && resp.is_KResponse() && resp.get_KResponse_0().is_GetResponse()
+            && get_resp.is_Ok() && ZookeeperClusterView::unmarshal(get_resp.get_Ok_0()).is_Ok()
+            && zk.metadata.name.is_Some() && zk.spec.persistence.enabled && state.data_pod_node_name_opt.is_Some() {
+                let req_o = APIRequest::CreateRequest(CreateRequest {
+                    namespace: backup.metadata.namespace.get_Some_0(),
+                    obj: make_backup_job(backup, zk, state.data_pod_node_name_opt.get_Some_0()).marshal(),
+                });
+                let state_prime = ZookeeperBackupReconcileState {
+                    reconcile_step: ZookeeperBackupReconcileStep::AfterKRequestStep(ActionKind::Create, SubResource::BackupJob),
+                    ..state
+                };
+                (state_prime, Some(RequestView::KRequest(req_o)))
+            } else {
+                // Without persistence the data lives in an emptyDir that no job can mount.
+                report_error(backup, state)
+            }
+        },
+        ZookeeperBackupReconcileStep::AfterKRequestStep(ActionKind::Create, SubResource::BackupJob) => {
+            let create_resp = resp.get_KResponse_0().get_CreateResponse_0().res;
+            if resp_o.is_Some() && resp.is_KResponse() && resp.get_KResponse_0().is_CreateResponse()
+            && create_resp.is_Ok() {
+                let req_o = APIRequest::UpdateStatusRequest(UpdateStatusRequest {
+                    namespace: backup.metadata.namespace.get_Some_0(),
+                    name: backup.metadata.name.get_Some_0(),
+                    obj: update_backup_status(backup, false).marshal(),
+                });
+                let state_prime = ZookeeperBackupReconcileState {
+                    reconcile_step: ZookeeperBackupReconcileStep::AfterUpdateStatus,
+                    ..state
+                };
+                (state_prime, Some(RequestView::KRequest(req_o)))
+            } else {
+                report_error(backup, state)
+            }
+        },
+        ZookeeperBackupReconcileStep::AfterUpdateStatus => {
+            let update_status_resp = resp.get_KResponse_0().get_UpdateStatusResponse_0().res;
+            if resp_o.is_Some() && resp.is_KResponse() && resp.get_KResponse_0().is_UpdateStatusResponse()
+            && update_status_resp.is_Ok() {
+                let state_prime = ZookeeperBackupReconcileState {
+                    reconcile_step: ZookeeperBackupReconcileStep::Done,
+                    ..state
+                };
+                (state_prime, None)
+            } else {
+                let state_prime = ZookeeperBackupReconcileState {
+                    reconcile_step: ZookeeperBackupReconcileStep::Error,
+                    ..state
+                };
+                (state_prime, None)
+            }
+        },
+        ZookeeperBackupReconcileStep::AfterUpdateDegradedStatus => {
+            let state_prime = ZookeeperBackupReconcileState {
+                reconcile_step: ZookeeperBackupReconcileStep::Error,
+                ..state
+            };
+            (state_prime, None)
+        },
+        _ => {
+            let state_prime = ZookeeperBackupReconcileState {
+                reconcile_step: step,
+                ..state
+            };
+            (state_prime, None)
+        }
+    }
+}
+
+pub open spec fn make_zookeeper_cluster_key(backup: ZookeeperBackupView) -> ObjectRef {
+    ObjectRef {
+        kind: ZookeeperClusterView::kind(),
+        name: backup.spec.zookeeper_cluster_reference.name,
+        namespace: backup.metadata.namespace.get_Some_0(),
+    }
+}
+
+// The stateful set is named after the zookeeper cluster, so the first replica is "<cluster>-0".
+pub open spec fn make_data_pod_name(backup: ZookeeperBackupView) -> StringView {
+    backup.spec.zookeeper_cluster_reference.name + "-0"@
+}
+
+pub open spec fn make_data_pod_key(backup: ZookeeperBackupView) -> ObjectRef {
+    ObjectRef {
+        kind: PodView::kind(),
+        name: make_data_pod_name(backup),
+        namespace: backup.metadata.namespace.get_Some_0(),
+    }
+}
+
+pub open spec fn make_backup_job_name(backup: ZookeeperBackupView) -> StringView {
+    backup.metadata.name.get_Some_0() + "-backup"@
+}
+
+pub open spec fn make_backup_job_key(backup: ZookeeperBackupView) -> ObjectRef {
+    ObjectRef {
+        kind: JobView::kind(),
+        name: make_backup_job_name(backup),
+        namespace: backup.metadata.namespace.get_Some_0(),
+    }
+}
+
+// Each backup is copied to its own directory in the backup claim, named after the ZookeeperBackup object,
+// so that a ZookeeperRestore can refer to it by name.
+pub open spec fn make_backup_command(backup: ZookeeperBackupView) -> StringView {
+    let dir = "/backup/"@ + backup.metadata.name.get_Some_0();
+    "rm -rf "@ + dir + " && mkdir -p "@ + dir + " && cp -a /data/. "@ + dir + "/"@
+}
+
+// The job mounts the data volume of the first replica read-only, using the zookeeper image
+// which is known to ship a shell. The volume is ReadWriteOnce, so the job is pinned to node_name,
+// the node that runs the first replica.
+// Like the backup directory it fills, the job is not owned by the backup: it is kept after the backup
+// is deleted because its status records whether the directory holds a complete copy.
+pub open spec fn make_backup_job(backup: ZookeeperBackupView, zk: ZookeeperClusterView, node_name: StringView) -> JobView {
+    JobView::default()
+        .set_metadata(ObjectMetaView::default()
+            .set_name(make_backup_job_name(backup))
+        )
+        .set_spec(JobSpecView::default()
+            .set_backoff_limit(3)
+            .set_template(PodTemplateSpecView::default()
+                .set_spec(PodSpecView::default()
+                    .set_restart_policy("OnFailure"@)
+                    .set_node_name(node_name)
+                    .set_containers(seq![
+                        ContainerView::default()
+                            .set_name("backup"@)
+                            .set_image(zk.spec.image)
+                            .set_command(seq!["/bin/sh"@, "-c"@, make_backup_command(backup)])
+                            .set_volume_mounts(seq![
+                                VolumeMountView::default().set_name("data"@).set_mount_path("/data"@).set_read_only(true),
+                                VolumeMountView::default().set_name("backup"@).set_mount_path("/backup"@),
+                            ])
+                    ])
+                    .set_volumes(seq![
+                        VolumeView::default().set_name("data"@).set_persistent_volume_claim(
+                            PersistentVolumeClaimVolumeSourceView::default().set_claim_name(make_data_pvc_name(zk)).set_read_only(true)
+                        ),
+                        VolumeView::default().set_name("backup"@).set_persistent_volume_claim(
+                            PersistentVolumeClaimVolumeSourceView::default().set_claim_name(backup.spec.persistent_volume_claim)
+                        ),
+                    ])
+                )
+            )
+        )
+}
+
+// The backup is ready once the job has copied the data.
+pub open spec fn update_backup_status(backup: ZookeeperBackupView, ready: bool) -> ZookeeperBackupView {
+    backup.set_status(ZookeeperBackupStatusView::default().set_conditions(make_conditions(ready, false, backup.metadata.generation)))
+}
+
+pub open spec fn update_backup_status_to_degraded(backup: ZookeeperBackupView) -> ZookeeperBackupView {
+    backup.set_status(ZookeeperBackupStatusView::default().set_conditions(make_conditions(false, true, backup.metadata.generation)))
+}
+
+// report_error marks the backup as degraded before the reconcile ends in error.
+pub open spec fn report_error(
+    backup: ZookeeperBackupView, state: ZookeeperBackupReconcileState
+) -> (ZookeeperBackupReconcileState, Option<RequestView<EmptyTypeView>>) {
+    let req_o = APIRequest::UpdateStatusRequest(UpdateStatusRequest {
+        namespace: backup.metadata.namespace.get_Some_0(),
+        name: backup.metadata.name.get_Some_0(),
+        obj: update_backup_status_to_degraded(backup).marshal(),
+    });
+    let state_prime = ZookeeperBackupReconcileState {
+        reconcile_step: ZookeeperBackupReconcileStep::AfterUpdateDegradedStatus,
+        ..state
+    };
+    (state_prime, Some(RequestView::KRequest(req_o)))
+}
+
+}
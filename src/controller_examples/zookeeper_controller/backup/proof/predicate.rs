@@ -0,0 +1,107 @@
+// Copyright 2022 VMware, Inc.
+// SPDX-License-Identifier: MIT
+#![allow(unused_imports)]
+use crate::kubernetes_api_objects::spec::prelude::*;
+use crate::kubernetes_cluster::spec::{cluster::*, message::*};
+use crate::temporal_logic::defs::*;
+use crate::zookeeper_controller::backup::{
+    model::reconciler::*,
+    trusted::{liveness_theorem::*, spec_types::*, step::*},
+};
+use vstd::prelude::*;
+
+verus! {
+
+pub open spec fn at_backup_step(key: ObjectRef, step: ZookeeperBackupReconcileStep) -> StatePred<BackupCluster>
+    recommends
+        key.kind.is_CustomResourceKind()
+{
+    |s: BackupCluster| {
+        &&& s.ongoing_reconciles().contains_key(key)
+        &&& s.ongoing_reconciles()[key].local_state.reconcile_step == step
+    }
+}
+
+pub open spec fn job_succeeded(key: ObjectRef) -> StatePred<BackupCluster> {
+    |s: BackupCluster| {
+        &&& s.resources().contains_key(key)
+        &&& JobView::unmarshal(s.resources()[key]).is_Ok()
+        &&& JobView::unmarshal(s.resources()[key]).get_Ok_0().succeeded()
+    }
+}
+
+// Same as backup_ready, but only looks at the status so that it also applies to the object in an update status request.
+pub open spec fn status_is_ready(status: Value) -> bool {
+    let status_view = ZookeeperBackupView::unmarshal_status(status).get_Ok_0();
+    &&& ZookeeperBackupView::unmarshal_status(status).is_Ok()
+    &&& status_view.is_Some()
+    &&& status_view.get_Some_0().conditions.is_Some()
+    &&& status_view.get_Some_0().conditions.get_Some_0().len() > 0
+    &&& status_view.get_Some_0().conditions.get_Some_0()[0].type_ == "Ready"@
+    &&& status_view.get_Some_0().conditions.get_Some_0()[0].status == "True"@
+}
+
+// Jobs are never owned by anything in this cluster: the backup reconciler creates them without owner references and
+// nobody else creates or updates them. So the garbage collector never deletes them.
+pub open spec fn job_has_no_owner_references() -> StatePred<BackupCluster> {
+    |s: BackupCluster| {
+        &&& forall |key: ObjectRef|
+                #[trigger] s.resources().contains_key(key)
+                && key.kind == JobView::kind()
+                ==> s.resources()[key].metadata.owner_references.is_None()
+        &&& forall |msg: BackupMessage|
+                #[trigger] s.in_flight().contains(msg)
+                && msg.content.is_create_request()
+                && msg.content.get_create_request().obj.kind == JobView::kind()
+                ==> msg.content.get_create_request().obj.metadata.owner_references.is_None()
+        &&& forall |msg: BackupMessage|
+                #[trigger] s.in_flight().contains(msg)
+                && msg.content.is_update_request()
+                ==> msg.content.get_update_request().key().kind != JobView::kind()
+    }
+}
+
+pub open spec fn no_delete_request_msg_for_job() -> StatePred<BackupCluster> {
+    |s: BackupCluster| {
+        forall |msg: BackupMessage|
+            #[trigger] s.in_flight().contains(msg)
+            && msg.content.is_delete_request()
+            ==> msg.content.get_delete_request().key.kind != JobView::kind()
+    }
+}
+
+// A job read by the reconciler is as good as the one in etcd: once it has succeeded it stays so.
+pub open spec fn every_ok_get_resp_of_succeeded_job_is_in_etcd() -> StatePred<BackupCluster> {
+    |s: BackupCluster| {
+        forall |msg: BackupMessage|
+            #[trigger] s.in_flight().contains(msg)
+            && BackupCluster::is_ok_get_response_msg()(msg)
+            ==> {
+                let obj = msg.content.get_get_response().res.get_Ok_0();
+                obj.kind == JobView::kind()
+                && JobView::unmarshal(obj).is_Ok()
+                && JobView::unmarshal(obj).get_Ok_0().succeeded()
+                ==> job_succeeded(obj.object_ref())(s)
+            }
+    }
+}
+
+pub open spec fn request_at_after_get_backup_job_step_is_get_request(key: ObjectRef) -> StatePred<BackupCluster> {
+    |s: BackupCluster| {
+        at_backup_step(key, ZookeeperBackupReconcileStep::AfterKRequestStep(ActionKind::Get, SubResource::BackupJob))(s)
+        ==> s.ongoing_reconciles()[key].pending_req_msg.is_Some()
+            && resource_get_request_msg(make_backup_job_key(s.ongoing_reconciles()[key].triggering_cr))(s.ongoing_reconciles()[key].pending_req_msg.get_Some_0())
+    }
+}
+
+pub open spec fn every_ready_status_update_request_implies_job_succeeded(backup: ZookeeperBackupView) -> StatePred<BackupCluster> {
+    |s: BackupCluster| {
+        forall |msg: BackupMessage|
+            #[trigger] s.in_flight().contains(msg)
+            && resource_update_status_request_msg(backup.object_ref())(msg)
+            && status_is_ready(msg.content.get_update_status_request().obj.status)
+            ==> backup_job_succeeded(backup)(s)
+    }
+}
+
+}
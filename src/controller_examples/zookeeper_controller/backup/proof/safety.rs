@@ -0,0 +1,392 @@
+// Copyright 2022 VMware, Inc.
+// SPDX-License-Identifier: MIT
+#![allow(unused_imports)]
+use crate::external_api::spec::*;
+use crate::kubernetes_api_objects::spec::prelude::*;
+use crate::kubernetes_cluster::spec::{
+    cluster::*,
+    cluster_state_machine::Step,
+    controller::types::{ControllerActionInput, ControllerStep},
+    message::*,
+};
+use crate::temporal_logic::{defs::*, rules::*};
+use crate::zookeeper_controller::backup::{
+    model::reconciler::*,
+    proof::predicate::*,
+    trusted::{liveness_theorem::*, spec_types::*, step::*},
+};
+use vstd::{prelude::*, string::*};
+
+verus! {
+
+proof fn safety_proof_forall_backup()
+    ensures safety_theorem(),
+{
+    assert forall |backup: ZookeeperBackupView| #[trigger] cluster_spec_without_wf().entails(safety(backup)) by {
+        safety_proof(backup);
+    };
+    spec_entails_tla_forall(cluster_spec_without_wf(), |backup: ZookeeperBackupView| safety(backup));
+}
+
+proof fn safety_proof(backup: ZookeeperBackupView)
+    ensures cluster_spec_without_wf().entails(safety(backup)),
+{
+    lemma_backup_reported_ready_only_after_job_succeeded(cluster_spec_without_wf(), backup);
+}
+
+// This invariant is exactly the high-level property. A create request resets the status to the default one and an update
+// request keeps the status, so the backup can only turn ready when the api server handles an update status request with
+// a ready status. every_ready_status_update_request_implies_job_succeeded shows that every such request in flight is
+// sent after the job has succeeded.
+proof fn lemma_backup_reported_ready_only_after_job_succeeded(spec: TempPred<BackupCluster>, backup: ZookeeperBackupView)
+    requires
+        spec.entails(lift_state(BackupCluster::init())),
+        spec.entails(always(lift_action(BackupCluster::next()))),
+    ensures spec.entails(always(lift_action(backup_reported_ready_only_after_job_succeeded(backup)))),
+{
+    let inv = backup_reported_ready_only_after_job_succeeded(backup);
+    let next = |s, s_prime| {
+        &&& BackupCluster::next()(s, s_prime)
+        &&& BackupCluster::each_object_in_etcd_is_well_formed()(s)
+        &&& every_ready_status_update_request_implies_job_succeeded(backup)(s)
+    };
+    BackupCluster::lemma_always_each_object_in_etcd_is_well_formed(spec);
+    lemma_always_every_ready_status_update_request_implies_job_succeeded(spec, backup);
+    combine_spec_entails_always_n!(
+        spec, lift_action(next), lift_action(BackupCluster::next()),
+        lift_state(BackupCluster::each_object_in_etcd_is_well_formed()),
+        lift_state(every_ready_status_update_request_implies_job_succeeded(backup))
+    );
+    assert forall |s, s_prime| #[trigger] next(s, s_prime) implies inv(s, s_prime) by {
+        let key = backup.object_ref();
+        if !(s.resources().contains_key(key) && backup_ready(s.resources()[key]))
+        && s_prime.resources().contains_key(key) && backup_ready(s_prime.resources()[key]) {
+            let step = choose |step| BackupCluster::next_step(s, s_prime, step);
+            let req = step.get_ApiServerStep_0().get_Some_0();
+            ZookeeperBackupView::marshal_status_preserves_integrity();
+            if req.content.is_create_request() {
+                assert(ZookeeperBackupView::default().status.is_None());
+                assert(!backup_ready(s_prime.resources()[key]));
+            } else if req.content.is_update_status_request() {
+                assert(s_prime.resources()[key].status == req.content.get_update_status_request().obj.status);
+                assert(resource_update_status_request_msg(key)(req));
+                assert(status_is_ready(req.content.get_update_status_request().obj.status));
+            } else {
+                // Update requests and deletion with finalizers keep the status.
+                assert(s.resources().contains_key(key));
+                assert(s_prime.resources()[key].status == s.resources()[key].status);
+                assert(backup_ready(s.resources()[key]));
+            }
+        }
+    }
+    invariant_n!(
+        spec, lift_action(next), lift_action(inv),
+        lift_action(BackupCluster::next()),
+        lift_state(BackupCluster::each_object_in_etcd_is_well_formed()),
+        lift_state(every_ready_status_update_request_implies_job_succeeded(backup))
+    );
+}
+
+// A ready status is only sent at AfterKRequestStep(Get, BackupJob) when the job in the response has succeeded. The job
+// in the response is the backup job (request_at_after_get_backup_job_step_is_get_request) and it has still succeeded in
+// etcd (every_ok_get_resp_of_succeeded_job_is_in_etcd). This is stable, so it still holds while the request is in flight.
+proof fn lemma_always_every_ready_status_update_request_implies_job_succeeded(spec: TempPred<BackupCluster>, backup: ZookeeperBackupView)
+    requires
+        spec.entails(lift_state(BackupCluster::init())),
+        spec.entails(always(lift_action(BackupCluster::next()))),
+    ensures spec.entails(always(lift_state(every_ready_status_update_request_implies_job_succeeded(backup)))),
+{
+    let inv = every_ready_status_update_request_implies_job_succeeded(backup);
+    let key = backup.object_ref();
+    let next = |s, s_prime| {
+        &&& BackupCluster::next()(s, s_prime)
+        &&& BackupCluster::each_object_in_etcd_is_well_formed()(s)
+        &&& BackupCluster::each_object_in_etcd_is_well_formed()(s_prime)
+        &&& BackupCluster::each_object_in_reconcile_has_consistent_key_and_valid_metadata()(s)
+        &&& BackupCluster::key_of_object_in_matched_ok_get_resp_message_is_same_as_key_of_pending_req(key)(s)
+        &&& no_delete_request_msg_for_job()(s)
+        &&& every_ok_get_resp_of_succeeded_job_is_in_etcd()(s)
+        &&& request_at_after_get_backup_job_step_is_get_request(key)(s)
+    };
+    BackupCluster::lemma_always_each_object_in_etcd_is_well_formed(spec);
+    always_to_always_later(spec, lift_state(BackupCluster::each_object_in_etcd_is_well_formed()));
+    BackupCluster::lemma_always_each_object_in_reconcile_has_consistent_key_and_valid_metadata(spec);
+    BackupCluster::lemma_always_key_of_object_in_matched_ok_get_resp_message_is_same_as_key_of_pending_req(spec, key);
+    lemma_always_no_delete_request_msg_for_job(spec);
+    lemma_always_every_ok_get_resp_of_succeeded_job_is_in_etcd(spec);
+    lemma_always_request_at_after_get_backup_job_step_is_get_request(spec, key);
+    combine_spec_entails_always_n!(
+        spec, lift_action(next), lift_action(BackupCluster::next()),
+        lift_state(BackupCluster::each_object_in_etcd_is_well_formed()),
+        later(lift_state(BackupCluster::each_object_in_etcd_is_well_formed())),
+        lift_state(BackupCluster::each_object_in_reconcile_has_consistent_key_and_valid_metadata()),
+        lift_state(BackupCluster::key_of_object_in_matched_ok_get_resp_message_is_same_as_key_of_pending_req(key)),
+        lift_state(no_delete_request_msg_for_job()),
+        lift_state(every_ok_get_resp_of_succeeded_job_is_in_etcd()),
+        lift_state(request_at_after_get_backup_job_step_is_get_request(key))
+    );
+    assert forall |s, s_prime| inv(s) && #[trigger] next(s, s_prime) implies inv(s_prime) by {
+        assert forall |msg| #[trigger] s_prime.in_flight().contains(msg) && resource_update_status_request_msg(key)(msg)
+        && status_is_ready(msg.content.get_update_status_request().obj.status) implies backup_job_succeeded(backup)(s_prime) by {
+            if s.in_flight().contains(msg) {
+                lemma_job_succeeded_is_stable(backup_job_key(backup), s, s_prime);
+            } else {
+                lemma_ready_status_update_request_is_sent_after_job_succeeded(backup, s, s_prime, msg);
+            }
+        }
+    }
+    init_invariant(spec, BackupCluster::init(), next, inv);
+}
+
+// The update status request carries the key of the triggering backup, so it is sent by the reconcile of
+// backup.object_ref(), and the only ready status it sends is the one computed from a succeeded job.
+proof fn lemma_ready_status_update_request_is_sent_after_job_succeeded(
+    backup: ZookeeperBackupView, s: BackupCluster, s_prime: BackupCluster, msg: BackupMessage
+)
+    requires
+        BackupCluster::next()(s, s_prime),
+        BackupCluster::each_object_in_reconcile_has_consistent_key_and_valid_metadata()(s),
+        BackupCluster::key_of_object_in_matched_ok_get_resp_message_is_same_as_key_of_pending_req(backup.object_ref())(s),
+        every_ok_get_resp_of_succeeded_job_is_in_etcd()(s),
+        request_at_after_get_backup_job_step_is_get_request(backup.object_ref())(s),
+        !s.in_flight().contains(msg),
+        s_prime.in_flight().contains(msg),
+        resource_update_status_request_msg(backup.object_ref())(msg),
+        status_is_ready(msg.content.get_update_status_request().obj.status),
+    ensures backup_job_succeeded(backup)(s_prime),
+{
+    let step = choose |step| BackupCluster::next_step(s, s_prime, step);
+    ZookeeperBackupView::marshal_status_preserves_integrity();
+    reveal_strlit("True");
+    reveal_strlit("False");
+    match step {
+        Step::ControllerStep(input) => {
+            let cr_key = input.1.get_Some_0();
+            let cr = s.ongoing_reconciles()[cr_key].triggering_cr;
+            let local_step = s.ongoing_reconciles()[cr_key].local_state.reconcile_step;
+            assert(cr_key == backup.object_ref());
+            assert(make_backup_job_key(cr) == backup_job_key(backup));
+            // Every other update status request of the reconciler carries a Ready condition that is false.
+            assert(local_step == ZookeeperBackupReconcileStep::AfterKRequestStep(ActionKind::Get, SubResource::BackupJob));
+            let resp = input.0.get_Some_0();
+            let job = JobView::unmarshal(resp.content.get_get_response().res.get_Ok_0()).get_Ok_0();
+            assert(msg.content.get_update_status_request().obj == update_backup_status(cr, job.succeeded()).marshal());
+            assert(job.succeeded());
+            assert(s.in_flight().contains(resp));
+            assert(BackupCluster::is_ok_get_response_msg()(resp));
+            assert(resp.content.get_get_response().res.get_Ok_0().object_ref() == backup_job_key(backup));
+            assert(job_succeeded(backup_job_key(backup))(s));
+            assert(s.resources() == s_prime.resources());
+        },
+        Step::BuiltinControllersStep(input) => {
+            assert(msg.content.get_update_status_request().obj.kind != ZookeeperBackupView::kind());
+        },
+        Step::PodEventStep() => {
+            assert(msg.content.get_update_status_request().obj.kind == PodView::kind());
+        },
+        _ => {
+            assert(!msg.content.is_update_status_request());
+        },
+    }
+}
+
+proof fn lemma_always_request_at_after_get_backup_job_step_is_get_request(spec: TempPred<BackupCluster>, key: ObjectRef)
+    requires
+        spec.entails(lift_state(BackupCluster::init())),
+        spec.entails(always(lift_action(BackupCluster::next()))),
+    ensures spec.entails(always(lift_state(request_at_after_get_backup_job_step_is_get_request(key)))),
+{
+    let inv = request_at_after_get_backup_job_step_is_get_request(key);
+    let next = |s, s_prime| {
+        &&& BackupCluster::next()(s, s_prime)
+        &&& BackupCluster::each_object_in_reconcile_has_consistent_key_and_valid_metadata()(s)
+    };
+    BackupCluster::lemma_always_each_object_in_reconcile_has_consistent_key_and_valid_metadata(spec);
+    combine_spec_entails_always_n!(
+        spec, lift_action(next), lift_action(BackupCluster::next()),
+        lift_state(BackupCluster::each_object_in_reconcile_has_consistent_key_and_valid_metadata())
+    );
+    assert forall |s: BackupCluster, s_prime: BackupCluster| inv(s) && #[trigger] next(s, s_prime) implies inv(s_prime) by {
+        if at_backup_step(key, ZookeeperBackupReconcileStep::AfterKRequestStep(ActionKind::Get, SubResource::BackupJob))(s_prime) {
+            let step = choose |step| BackupCluster::next_step(s, s_prime, step);
+            match step {
+                Step::ControllerStep(input) => {
+                    let cr_key = input.1.get_Some_0();
+                    if cr_key != key {
+                        assert(s_prime.ongoing_reconciles()[key] == s.ongoing_reconciles()[key]);
+                    }
+                },
+                Step::RestartController() => {
+                    assert(!s_prime.ongoing_reconciles().contains_key(key));
+                },
+                _ => {
+                    assert(s_prime.ongoing_reconciles()[key] == s.ongoing_reconciles()[key]);
+                }
+            }
+        }
+    }
+    init_invariant(spec, BackupCluster::init(), next, inv);
+}
+
+proof fn lemma_always_every_ok_get_resp_of_succeeded_job_is_in_etcd(spec: TempPred<BackupCluster>)
+    requires
+        spec.entails(lift_state(BackupCluster::init())),
+        spec.entails(always(lift_action(BackupCluster::next()))),
+    ensures spec.entails(always(lift_state(every_ok_get_resp_of_succeeded_job_is_in_etcd()))),
+{
+    let inv = every_ok_get_resp_of_succeeded_job_is_in_etcd();
+    let next = |s, s_prime| {
+        &&& BackupCluster::next()(s, s_prime)
+        &&& BackupCluster::each_object_in_etcd_is_well_formed()(s)
+        &&& BackupCluster::each_object_in_etcd_is_well_formed()(s_prime)
+        &&& no_delete_request_msg_for_job()(s)
+    };
+    BackupCluster::lemma_always_each_object_in_etcd_is_well_formed(spec);
+    always_to_always_later(spec, lift_state(BackupCluster::each_object_in_etcd_is_well_formed()));
+    lemma_always_no_delete_request_msg_for_job(spec);
+    combine_spec_entails_always_n!(
+        spec, lift_action(next), lift_action(BackupCluster::next()),
+        lift_state(BackupCluster::each_object_in_etcd_is_well_formed()),
+        later(lift_state(BackupCluster::each_object_in_etcd_is_well_formed())),
+        lift_state(no_delete_request_msg_for_job())
+    );
+    assert forall |s, s_prime| inv(s) && #[trigger] next(s, s_prime) implies inv(s_prime) by {
+        assert forall |msg| #[trigger] s_prime.in_flight().contains(msg) && BackupCluster::is_ok_get_response_msg()(msg) implies {
+            let obj = msg.content.get_get_response().res.get_Ok_0();
+            obj.kind == JobView::kind() && JobView::unmarshal(obj).is_Ok() && JobView::unmarshal(obj).get_Ok_0().succeeded()
+            ==> job_succeeded(obj.object_ref())(s_prime)
+        } by {
+            let obj = msg.content.get_get_response().res.get_Ok_0();
+            if s.in_flight().contains(msg) {
+                lemma_job_succeeded_is_stable(obj.object_ref(), s, s_prime);
+            } else {
+                // The response is generated by the api server in this step from the object in etcd, which is not
+                // changed by a get request.
+                let step = choose |step| BackupCluster::next_step(s, s_prime, step);
+                let req = step.get_ApiServerStep_0().get_Some_0();
+                assert(req.content.is_get_request());
+                let req_key = req.content.get_get_request().key;
+                assert(s.resources().contains_key(req_key) && obj == s.resources()[req_key]);
+                assert(obj.object_ref() == req_key);
+                assert(s.resources() == s_prime.resources());
+            }
+        }
+    }
+    init_invariant(spec, BackupCluster::init(), next, inv);
+}
+
+// Once a job has succeeded it stays so: it is never deleted, and any update of it has to pass transition_validation.
+proof fn lemma_job_succeeded_is_stable(key: ObjectRef, s: BackupCluster, s_prime: BackupCluster)
+    requires
+        BackupCluster::next()(s, s_prime),
+        BackupCluster::each_object_in_etcd_is_well_formed()(s),
+        BackupCluster::each_object_in_etcd_is_well_formed()(s_prime),
+        no_delete_request_msg_for_job()(s),
+    ensures job_succeeded(key)(s) ==> job_succeeded(key)(s_prime),
+{
+    if job_succeeded(key)(s) {
+        let step = choose |step| BackupCluster::next_step(s, s_prime, step);
+        match step {
+            Step::ApiServerStep(input) => {
+                let req = input.get_Some_0();
+                if req.content.is_delete_request() {
+                    assert(req.content.get_delete_request().key != key);
+                    assert(s_prime.resources().contains_key(key) && s_prime.resources()[key] == s.resources()[key]);
+                } else if s_prime.resources()[key] != s.resources()[key] {
+                    assert(req.content.is_update_request() || req.content.is_update_status_request());
+                    assert(JobView::unmarshal(s_prime.resources()[key]).get_Ok_0().transition_validation(JobView::unmarshal(s.resources()[key]).get_Ok_0()));
+                }
+            },
+            _ => {
+                assert(s_prime.resources() == s.resources());
+            },
+        }
+    }
+}
+
+// The garbage collector only deletes objects with owner references, so it never deletes a job.
+// The client only deletes backups and the pod events only delete pods.
+proof fn lemma_always_no_delete_request_msg_for_job(spec: TempPred<BackupCluster>)
+    requires
+        spec.entails(lift_state(BackupCluster::init())),
+        spec.entails(always(lift_action(BackupCluster::next()))),
+    ensures spec.entails(always(lift_state(no_delete_request_msg_for_job()))),
+{
+    let inv = no_delete_request_msg_for_job();
+    let next = |s, s_prime| {
+        &&& BackupCluster::next()(s, s_prime)
+        &&& job_has_no_owner_references()(s)
+    };
+    lemma_always_job_has_no_owner_references(spec);
+    combine_spec_entails_always_n!(
+        spec, lift_action(next), lift_action(BackupCluster::next()),
+        lift_state(job_has_no_owner_references())
+    );
+    assert forall |s, s_prime| inv(s) && #[trigger] next(s, s_prime) implies inv(s_prime) by {
+        assert forall |msg| #[trigger] s_prime.in_flight().contains(msg) && msg.content.is_delete_request()
+        implies msg.content.get_delete_request().key.kind != JobView::kind() by {
+            if !s.in_flight().contains(msg) {
+                let step = choose |step| BackupCluster::next_step(s, s_prime, step);
+                match step {
+                    Step::BuiltinControllersStep(input) => {
+                        let key = input.1;
+                        assert(msg.content.get_delete_request().key == key);
+                        assert(s.resources()[key].metadata.owner_references.is_Some());
+                    },
+                    Step::ClientStep() => {
+                        assert(msg.content.get_delete_request().key.kind == ZookeeperBackupView::kind());
+                    },
+                    Step::PodEventStep() => {
+                        assert(msg.content.get_delete_request().key.kind == PodView::kind());
+                    },
+                    _ => {},
+                }
+            }
+        }
+    }
+    init_invariant(spec, BackupCluster::init(), next, inv);
+}
+
+proof fn lemma_always_job_has_no_owner_references(spec: TempPred<BackupCluster>)
+    requires
+        spec.entails(lift_state(BackupCluster::init())),
+        spec.entails(always(lift_action(BackupCluster::next()))),
+    ensures spec.entails(always(lift_state(job_has_no_owner_references()))),
+{
+    let inv = job_has_no_owner_references();
+    assert forall |s, s_prime| inv(s) && #[trigger] BackupCluster::next()(s, s_prime) implies inv(s_prime) by {
+        let step = choose |step| BackupCluster::next_step(s, s_prime, step);
+        assert forall |key| #[trigger] s_prime.resources().contains_key(key) && key.kind == JobView::kind()
+        implies s_prime.resources()[key].metadata.owner_references.is_None() by {
+            if !s.resources().contains_key(key) || s_prime.resources()[key] != s.resources()[key] {
+                // Update status keeps the metadata, and no update request for jobs is in flight.
+                let req = step.get_ApiServerStep_0().get_Some_0();
+                assert(s.in_flight().contains(req));
+            }
+        }
+        assert forall |msg| #[trigger] s_prime.in_flight().contains(msg) && !s.in_flight().contains(msg) implies
+            (msg.content.is_create_request() && msg.content.get_create_request().obj.kind == JobView::kind()
+            ==> msg.content.get_create_request().obj.metadata.owner_references.is_None())
+            && (msg.content.is_update_request() ==> msg.content.get_update_request().key().kind != JobView::kind()) by {
+            match step {
+                Step::ControllerStep(input) => {
+                    // The backup reconciler creates the job without owner references, and never updates anything.
+                    let cr_key = input.1.get_Some_0();
+                    let cr = s.ongoing_reconciles()[cr_key].triggering_cr;
+                    assert(forall |zk, node_name| #[trigger] make_backup_job(cr, zk, node_name).metadata.owner_references.is_None());
+                    assert(!msg.content.is_update_request());
+                },
+                Step::ClientStep() => {
+                    assert(ZookeeperBackupView::kind() != JobView::kind());
+                },
+                Step::PodEventStep() => {
+                    assert(PodView::kind() != JobView::kind());
+                },
+                _ => {},
+            }
+        }
+    }
+    init_invariant(spec, BackupCluster::init(), BackupCluster::next(), inv);
+}
+
+}
@@ -0,0 +1,213 @@
+// Copyright 2022 VMware, Inc.
+// SPDX-License-Identifier: MIT
+use crate::kubernetes_api_objects::error::UnmarshalError;
+use crate::kubernetes_api_objects::exec::{
+    api_resource::*, condition::*, dynamic::*, object_meta::*, owner_reference::*, resource::*,
+};
+use crate::kubernetes_api_objects::spec::resource::*;
+use crate::vstd_ext::string_view::*;
+use crate::zookeeper_controller::backup::trusted::{
+    spec_types, spec_types::ZookeeperBackupView, step::*,
+};
+use deps_hack::kube::Resource;
+use vstd::prelude::*;
+
+verus! {
+
+pub struct ZookeeperBackupReconcileState {
+    pub reconcile_step: ZookeeperBackupReconcileStep,
+    // data_pod_node_name_opt records the node running the first replica,
+    // which is where the backup job has to run to mount its data volume.
+    pub data_pod_node_name_opt: Option<String>,
+}
+
+impl std::clone::Clone for ZookeeperBackupReconcileState {
+    #[verifier(external_body)]
+    fn clone(&self) -> (result: ZookeeperBackupReconcileState)
+        ensures result == self
+    {
+        ZookeeperBackupReconcileState {
+            reconcile_step: self.reconcile_step,
+            data_pod_node_name_opt:
+                match &self.data_pod_node_name_opt {
+                    Some(n) => Some(n.clone()),
+                    None => None,
+                },
+        }
+    }
+}
+
+impl View for ZookeeperBackupReconcileState {
+    type V = spec_types::ZookeeperBackupReconcileState;
+    open spec fn view(&self) -> spec_types::ZookeeperBackupReconcileState {
+        spec_types::ZookeeperBackupReconcileState {
+            reconcile_step: self.reconcile_step,
+            data_pod_node_name_opt:
+                match self.data_pod_node_name_opt {
+                    Some(s) => Some(s@),
+                    None => None,
+                },
+        }
+    }
+}
+
+#[verifier(external_body)]
+pub struct ZookeeperBackup {
+    inner: deps_hack::ZookeeperBackup
+}
+
+impl View for ZookeeperBackup {
+    type V = spec_types::ZookeeperBackupView;
+
+    spec fn view(&self) -> spec_types::ZookeeperBackupView;
+}
+
+impl ZookeeperBackup {
+    #[verifier(external_body)]
+    pub fn clone(&self) -> (backup: Self)
+        ensures backup@ == self@,
+    {
+        ZookeeperBackup { inner: self.inner.clone() }
+    }
+
+    #[verifier(external_body)]
+    pub fn metadata(&self) -> (metadata: ObjectMeta)
+        ensures metadata@ == self@.metadata,
+    {
+        ObjectMeta::from_kube(self.inner.metadata.clone())
+    }
+
+    #[verifier(external_body)]
+    pub fn spec(&self) -> (spec: ZookeeperBackupSpec)
+        ensures spec@ == self@.spec,
+    {
+        ZookeeperBackupSpec { inner: self.inner.spec.clone() }
+    }
+
+    #[verifier(external_body)]
+    pub fn set_status(&mut self, status: ZookeeperBackupStatus)
+        ensures self@ == old(self)@.set_status(status@),
+    {
+        let mut new_status = status.into_kube();
+        match (&mut new_status.conditions, self.inner.status.as_ref().and_then(|s| s.conditions.as_ref())) {
+            (Some(conditions), Some(old_conditions)) => preserve_transition_times(conditions, old_conditions),
+            _ => {},
+        }
+        self.inner.status = Some(new_status);
+    }
+
+    #[verifier(external_body)]
+    pub fn controller_owner_ref(&self) -> (owner_reference: OwnerReference)
+        ensures owner_reference@ == self@.controller_owner_ref(),
+    {
+        OwnerReference::from_kube(
+            // We can safely unwrap here because the trait method implementation always returns a Some(...)
+            self.inner.controller_owner_ref(&()).unwrap()
+        )
+    }
+
+    #[verifier(external_body)]
+    pub fn api_resource() -> (res: ApiResource)
+        ensures res@.kind == ZookeeperBackupView::kind(),
+    {
+        ApiResource::from_kube(deps_hack::kube::api::ApiResource::erase::<deps_hack::ZookeeperBackup>(&()))
+    }
+
+    // NOTE: This function assumes serde_json::to_string won't fail!
+    #[verifier(external_body)]
+    pub fn marshal(self) -> (obj: DynamicObject)
+        ensures obj@ == self@.marshal(),
+    {
+        // TODO: this might be unnecessarily slow
+        DynamicObject::from_kube(deps_hack::k8s_openapi::serde_json::from_str(&deps_hack::k8s_openapi::serde_json::to_string(&self.inner).unwrap()).unwrap())
+    }
+
+    #[verifier(external_body)]
+    pub fn unmarshal(obj: DynamicObject) -> (res: Result<ZookeeperBackup, UnmarshalError>)
+        ensures
+            res.is_Ok() == ZookeeperBackupView::unmarshal(obj@).is_Ok(),
+            res.is_Ok() ==> res.get_Ok_0()@ == ZookeeperBackupView::unmarshal(obj@).get_Ok_0(),
+    {
+        let parse_result = obj.into_kube().try_parse::<deps_hack::ZookeeperBackup>();
+        if parse_result.is_ok() {
+            let res = ZookeeperBackup { inner: parse_result.unwrap() };
+            Ok(res)
+        } else {
+            Err(())
+        }
+    }
+}
+
+#[verifier(external)]
+impl ResourceWrapper<deps_hack::ZookeeperBackup> for ZookeeperBackup {
+    fn from_kube(inner: deps_hack::ZookeeperBackup) -> ZookeeperBackup { ZookeeperBackup { inner: inner } }
+
+    fn into_kube(self) -> deps_hack::ZookeeperBackup { self.inner }
+}
+
+#[verifier(external_body)]
+pub struct ZookeeperBackupSpec {
+    inner: deps_hack::ZookeeperBackupSpec,
+}
+
+impl ZookeeperBackupSpec {
+    pub spec fn view(&self) -> spec_types::ZookeeperBackupSpecView;
+
+    #[verifier(external_body)]
+    pub fn zookeeper_cluster_reference(&self) -> (zookeeper_cluster_reference: ZookeeperClusterReference)
+        ensures zookeeper_cluster_reference@ == self@.zookeeper_cluster_reference,
+    {
+        ZookeeperClusterReference { inner: self.inner.zookeeper_cluster_reference.clone() }
+    }
+
+    #[verifier(external_body)]
+    pub fn persistent_volume_claim(&self) -> (persistent_volume_claim: String)
+        ensures persistent_volume_claim@ == self@.persistent_volume_claim,
+    {
+        self.inner.persistent_volume_claim.clone()
+    }
+}
+
+#[verifier(external_body)]
+pub struct ZookeeperClusterReference {
+    inner: deps_hack::ZookeeperClusterReference,
+}
+
+impl ZookeeperClusterReference {
+    pub spec fn view(&self) -> spec_types::ZookeeperClusterReferenceView;
+
+    #[verifier(external_body)]
+    pub fn name(&self) -> (name: String)
+        ensures name@ == self@.name,
+    {
+        self.inner.name.clone()
+    }
+}
+
+#[verifier(external_body)]
+pub struct ZookeeperBackupStatus {
+    inner: deps_hack::ZookeeperBackupStatus,
+}
+
+impl ZookeeperBackupStatus {
+    pub spec fn view(&self) -> spec_types::ZookeeperBackupStatusView;
+
+    #[verifier(external_body)]
+    pub fn default() -> (status: ZookeeperBackupStatus)
+        ensures status@ == spec_types::ZookeeperBackupStatusView::default(),
+    {
+        ZookeeperBackupStatus { inner: deps_hack::ZookeeperBackupStatus::default() }
+    }
+
+    #[verifier(external_body)]
+    pub fn set_conditions(&mut self, conditions: Vec<Condition>)
+        ensures self@ == old(self)@.set_conditions(conditions@.map_values(|c: Condition| c@)),
+    {
+        self.inner.conditions = Some(into_kube_conditions(conditions))
+    }
+
+    #[verifier(external)]
+    fn into_kube(self) -> deps_hack::ZookeeperBackupStatus { self.inner }
+}
+
+}
@@ -0,0 +1,68 @@
+// Copyright 2022 VMware, Inc.
+// SPDX-License-Identifier: MIT
+#![allow(unused_imports)]
+use crate::kubernetes_api_objects::spec::prelude::*;
+use crate::kubernetes_cluster::spec::{cluster::*, cluster_state_machine::Step, message::*};
+use crate::temporal_logic::defs::*;
+use crate::zookeeper_controller::backup::trusted::{spec_types::*, step::*};
+use vstd::prelude::*;
+
+verus! {
+
+pub open spec fn safety_theorem() -> bool {
+    cluster_spec_without_wf().entails(tla_forall(|backup: ZookeeperBackupView| safety(backup)))
+}
+
+pub open spec fn cluster_spec_without_wf() -> TempPred<BackupCluster> {
+    lift_state(BackupCluster::init()).and(always(lift_action(BackupCluster::next())))
+}
+
+pub open spec fn safety(backup: ZookeeperBackupView) -> TempPred<BackupCluster> {
+    always(lift_action(backup_reported_ready_only_after_job_succeeded(backup)))
+}
+
+// A ZookeeperRestore trusts a ready backup to hold a complete copy of the data, so the backup may only turn ready once
+// the backup job has finished copying.
+//
+// Like the safety property of the restore, the property talks about the previous and current state: the backup turns
+// ready in this step if it is not ready before and is ready after. The job is looked up by name, so the property also
+// covers a backup object that replaces an earlier one with the same name.
+pub open spec fn backup_reported_ready_only_after_job_succeeded(backup: ZookeeperBackupView) -> ActionPred<BackupCluster> {
+    |s: BackupCluster, s_prime: BackupCluster| {
+        let key = backup.object_ref();
+        !(s.resources().contains_key(key) && backup_ready(s.resources()[key]))
+        && s_prime.resources().contains_key(key)
+        && backup_ready(s_prime.resources()[key])
+        ==> backup_job_succeeded(backup)(s)
+    }
+}
+
+// The first condition published by the backup reconciler is the Ready condition.
+pub open spec fn backup_ready(obj: DynamicObjectView) -> bool {
+    let status = ZookeeperBackupView::unmarshal(obj).get_Ok_0().status;
+    &&& ZookeeperBackupView::unmarshal(obj).is_Ok()
+    &&& status.is_Some()
+    &&& status.get_Some_0().conditions.is_Some()
+    &&& status.get_Some_0().conditions.get_Some_0().len() > 0
+    &&& status.get_Some_0().conditions.get_Some_0()[0].type_ == "Ready"@
+    &&& status.get_Some_0().conditions.get_Some_0()[0].status == "True"@
+}
+
+pub open spec fn backup_job_succeeded(backup: ZookeeperBackupView) -> StatePred<BackupCluster> {
+    |s: BackupCluster| {
+        let obj = s.resources()[backup_job_key(backup)];
+        &&& s.resources().contains_key(backup_job_key(backup))
+        &&& JobView::unmarshal(obj).is_Ok()
+        &&& JobView::unmarshal(obj).get_Ok_0().succeeded()
+    }
+}
+
+pub open spec fn backup_job_key(backup: ZookeeperBackupView) -> ObjectRef {
+    ObjectRef {
+        kind: JobView::kind(),
+        name: backup.metadata.name.get_Some_0() + "-backup"@,
+        namespace: backup.metadata.namespace.get_Some_0(),
+    }
+}
+
+}
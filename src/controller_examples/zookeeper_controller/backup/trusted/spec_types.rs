@@ -0,0 +1,190 @@
+// Copyright 2022 VMware, Inc.
+// SPDX-License-Identifier: MIT
+use crate::external_api::spec::{EmptyAPI, EmptyTypeView};
+use crate::kubernetes_api_objects::error::UnmarshalError;
+use crate::kubernetes_api_objects::spec::{
+    common::*, condition::*, dynamic::*, object_meta::*, owner_reference::*, resource::*,
+};
+use crate::kubernetes_cluster::spec::{cluster::*, cluster_state_machine::*, message::*};
+use crate::vstd_ext::string_view::*;
+use crate::zookeeper_controller::backup::trusted::step::*;
+use vstd::prelude::*;
+
+verus! {
+
+pub type BackupStep = Step<BackupMessage>;
+
+pub type BackupCluster = Cluster<ZookeeperBackupView, EmptyAPI, ZookeeperBackupReconciler>;
+
+pub type BackupMessage = Message<EmptyTypeView, EmptyTypeView>;
+
+pub struct ZookeeperBackupReconciler {}
+
+pub struct ZookeeperBackupReconcileState {
+    pub reconcile_step: ZookeeperBackupReconcileStep,
+    pub data_pod_node_name_opt: Option<StringView>,
+}
+
+pub struct ZookeeperBackupView {
+    pub metadata: ObjectMetaView,
+    pub spec: ZookeeperBackupSpecView,
+    pub status: Option<ZookeeperBackupStatusView>,
+}
+
+impl ZookeeperBackupView {
+    pub open spec fn well_formed(self) -> bool {
+        &&& self.metadata.name.is_Some()
+        &&& self.metadata.namespace.is_Some()
+        &&& self.metadata.uid.is_Some()
+    }
+
+    pub open spec fn controller_owner_ref(self) -> OwnerReferenceView {
+        OwnerReferenceView {
+            block_owner_deletion: None,
+            controller: Some(true),
+            kind: Self::kind(),
+            name: self.metadata.name.get_Some_0(),
+            uid: self.metadata.uid.get_Some_0(),
+        }
+    }
+
+    pub open spec fn set_status(self, status: ZookeeperBackupStatusView) -> ZookeeperBackupView {
+        ZookeeperBackupView {
+            status: Some(status),
+            ..self
+        }
+    }
+}
+
+impl ResourceView for ZookeeperBackupView {
+    type Spec = ZookeeperBackupSpecView;
+    type Status = Option<ZookeeperBackupStatusView>;
+
+    open spec fn default() -> ZookeeperBackupView {
+        ZookeeperBackupView {
+            metadata: ObjectMetaView::default(),
+            spec: arbitrary(), // TODO: specify the default value for spec
+            status: None,
+        }
+    }
+
+    open spec fn metadata(self) -> ObjectMetaView { self.metadata }
+
+    open spec fn kind() -> Kind { Kind::CustomResourceKind("zookeeperbackup"@) }
+
+    open spec fn object_ref(self) -> ObjectRef {
+        ObjectRef {
+            kind: Self::kind(),
+            name: self.metadata.name.get_Some_0(),
+            namespace: self.metadata.namespace.get_Some_0(),
+        }
+    }
+
+    proof fn object_ref_is_well_formed() {}
+
+    open spec fn spec(self) -> ZookeeperBackupSpecView { self.spec }
+
+    open spec fn status(self) -> Option<ZookeeperBackupStatusView> { self.status }
+
+    open spec fn marshal(self) -> DynamicObjectView {
+        DynamicObjectView {
+            kind: Self::kind(),
+            metadata: self.metadata,
+            spec: ZookeeperBackupView::marshal_spec(self.spec),
+            status: ZookeeperBackupView::marshal_status(self.status),
+        }
+    }
+
+    open spec fn unmarshal(obj: DynamicObjectView) -> Result<ZookeeperBackupView, UnmarshalError> {
+        if obj.kind != Self::kind() {
+            Err(())
+        } else if !ZookeeperBackupView::unmarshal_spec(obj.spec).is_Ok() {
+            Err(())
+        } else if !ZookeeperBackupView::unmarshal_status(obj.status).is_Ok() {
+            Err(())
+        } else {
+            Ok(ZookeeperBackupView {
+                metadata: obj.metadata,
+                spec: ZookeeperBackupView::unmarshal_spec(obj.spec).get_Ok_0(),
+                status: ZookeeperBackupView::unmarshal_status(obj.status).get_Ok_0(),
+            })
+        }
+    }
+
+    proof fn marshal_preserves_integrity() {
+        ZookeeperBackupView::marshal_spec_preserves_integrity();
+        ZookeeperBackupView::marshal_status_preserves_integrity();
+    }
+
+    proof fn marshal_preserves_metadata() {}
+
+    proof fn marshal_preserves_kind() {}
+
+    closed spec fn marshal_spec(s: ZookeeperBackupSpecView) -> Value;
+
+    closed spec fn unmarshal_spec(v: Value) -> Result<ZookeeperBackupSpecView, UnmarshalError>;
+
+    closed spec fn marshal_status(s: Option<ZookeeperBackupStatusView>) -> Value;
+
+    closed spec fn unmarshal_status(v: Value) -> Result<Option<ZookeeperBackupStatusView>, UnmarshalError>;
+
+    #[verifier(external_body)]
+    proof fn marshal_spec_preserves_integrity() {}
+
+    #[verifier(external_body)]
+    proof fn marshal_status_preserves_integrity() {}
+
+    proof fn unmarshal_result_determined_by_unmarshal_spec_and_status() {}
+
+    open spec fn state_validation(self) -> bool { true }
+
+    // A backup is a snapshot taken once, so pointing it to another cluster or claim later
+    // would not be reflected in the data that is already copied.
+    open spec fn transition_validation(self, old_obj: ZookeeperBackupView) -> bool {
+        self.spec == old_obj.spec
+    }
+}
+
+impl CustomResourceView for ZookeeperBackupView {
+    proof fn kind_is_custom_resource() {}
+
+    open spec fn is_namespaced() -> bool { true }
+
+    open spec fn spec_status_validation(obj_spec: Self::Spec, obj_status: Self::Status) -> bool { true }
+
+    proof fn validation_result_determined_by_spec_and_status()
+        ensures forall |obj: Self| #[trigger] obj.state_validation() == Self::spec_status_validation(obj.spec(), obj.status())
+    {}
+}
+
+pub struct ZookeeperBackupSpecView {
+    pub zookeeper_cluster_reference: ZookeeperClusterReferenceView,
+    pub persistent_volume_claim: StringView,
+}
+
+// ZookeeperClusterReferenceView names the zookeeper cluster, in the same namespace as the backup,
+// whose data is backed up.
+pub struct ZookeeperClusterReferenceView {
+    pub name: StringView,
+}
+
+pub struct ZookeeperBackupStatusView {
+    pub conditions: Option<Seq<ConditionView>>,
+}
+
+impl ZookeeperBackupStatusView {
+    pub open spec fn default() -> ZookeeperBackupStatusView {
+        ZookeeperBackupStatusView {
+            conditions: None,
+        }
+    }
+
+    pub open spec fn set_conditions(self, conditions: Seq<ConditionView>) -> ZookeeperBackupStatusView {
+        ZookeeperBackupStatusView {
+            conditions: Some(conditions),
+            ..self
+        }
+    }
+}
+
+}
@@ -0,0 +1,58 @@
+// Copyright 2022 VMware, Inc.
+// SPDX-License-Identifier: MIT
+#![allow(unused_imports)]
+use vstd::prelude::*;
+
+verus! {
+
+#[is_variant]
+pub enum ZookeeperBackupReconcileStep {
+    Init,
+    AfterKRequestStep(ActionKind, SubResource),
+    AfterUpdateStatus,
+    AfterUpdateDegradedStatus,
+    Done,
+    Error,
+}
+
+impl std::marker::Copy for ZookeeperBackupReconcileStep {}
+
+impl std::clone::Clone for ZookeeperBackupReconcileStep {
+    #[verifier(external_body)]
+    fn clone(&self) -> (result: Self)
+        ensures result == self
+    { *self }
+}
+
+pub enum SubResource {
+    BackupJob,
+    DataPod,
+    ZookeeperCluster,
+}
+
+impl std::marker::Copy for SubResource {}
+
+impl std::clone::Clone for SubResource {
+    #[verifier(external_body)]
+    fn clone(&self) -> (result: Self)
+        ensures result == self
+    { *self }
+}
+
+// The backup job is never updated: each ZookeeperBackup object takes exactly one snapshot.
+#[is_variant]
+pub enum ActionKind {
+    Get,
+    Create,
+}
+
+impl std::marker::Copy for ActionKind {}
+
+impl std::clone::Clone for ActionKind {
+    #[verifier(external_body)]
+    fn clone(&self) -> (result: Self)
+        ensures result == self
+    { *self }
+}
+
+}
@@ -99,6 +99,7 @@ pub fn reconcile_core(
                 SubResource::HeadlessService => reconcile_helper::<model_resource::HeadlessServiceBuilder, HeadlessServiceBuilder>(zk, resp_o, state),
                 SubResource::ClientService => reconcile_helper::<model_resource::ClientServiceBuilder, ClientServiceBuilder>(zk, resp_o, state),
                 SubResource::AdminServerService => reconcile_helper::<model_resource::AdminServerServiceBuilder, AdminServerServiceBuilder>(zk, resp_o, state),
+                SubResource::PodDisruptionBudget => reconcile_helper::<model_resource::PodDisruptionBudgetBuilder, PodDisruptionBudgetBuilder>(zk, resp_o, state),
                 SubResource::ConfigMap => reconcile_helper::<model_resource::ConfigMapBuilder, ConfigMapBuilder>(zk, resp_o, state),
                 SubResource::StatefulSet => reconcile_helper::<model_resource::StatefulSetBuilder, StatefulSetBuilder>(zk, resp_o, state),
             }
@@ -133,11 +134,7 @@ pub fn reconcile_core(
                     return (state_prime, Some(Request::KRequest(req_o)));
                 }
             }
-            let state_prime = ZookeeperReconcileState {
-                reconcile_step: ZookeeperReconcileStep::Error,
-                ..state
-            };
-            return (state_prime, None);
+            return report_error(zk, state);
         },
         ZookeeperReconcileStep::AfterExistsZKNode => {
             if resp_o.is_some() && resp_o.as_ref().unwrap().is_external_response()
@@ -150,7 +147,7 @@ pub fn reconcile_core(
                         let node_path = zk_node_path(zk);
                         let data = zk_node_data(zk);
                         let ext_req = ZKAPIInput::SetDataRequest(
-                            zk.metadata().name().unwrap(), zk.metadata().namespace().unwrap(), zk.spec().ports().client(), node_path, data, version
+                            zk.metadata().name().unwrap(), zk.metadata().namespace().unwrap(), zk.spec().ports().client(), node_path, data, version, zk_auth_secret_name(zk)
                         );
                         let state_prime = ZookeeperReconcileState {
                             reconcile_step: ZookeeperReconcileStep::AfterUpdateZKNode,
@@ -161,7 +158,7 @@ pub fn reconcile_core(
                         let node_path = zk_parent_node_path(zk);
                         let data = "".to_string();
                         let ext_req = ZKAPIInput::CreateRequest(
-                            zk.metadata().name().unwrap(), zk.metadata().namespace().unwrap(), zk.spec().ports().client(), node_path, data
+                            zk.metadata().name().unwrap(), zk.metadata().namespace().unwrap(), zk.spec().ports().client(), node_path, data, zk_auth_secret_name(zk)
                         );
                         let state_prime = ZookeeperReconcileState {
                             reconcile_step: ZookeeperReconcileStep::AfterCreateZKParentNode,
@@ -171,11 +168,7 @@ pub fn reconcile_core(
                     }
                 }
             }
-            let state_prime = ZookeeperReconcileState {
-                reconcile_step: ZookeeperReconcileStep::Error,
-                ..state
-            };
-            return (state_prime, None);
+            return report_error(zk, state);
         },
         ZookeeperReconcileStep::AfterCreateZKParentNode => {
             if resp_o.is_some() && resp_o.as_ref().unwrap().is_external_response()
@@ -185,7 +178,7 @@ pub fn reconcile_core(
                     let node_path = zk_node_path(zk);
                     let data = zk_node_data(zk);
                     let ext_req = ZKAPIInput::CreateRequest(
-                        zk.metadata().name().unwrap(), zk.metadata().namespace().unwrap(), zk.spec().ports().client(), node_path, data
+                        zk.metadata().name().unwrap(), zk.metadata().namespace().unwrap(), zk.spec().ports().client(), node_path, data, zk_auth_secret_name(zk)
                     );
                     let state_prime = ZookeeperReconcileState {
                         reconcile_step: ZookeeperReconcileStep::AfterCreateZKNode,
@@ -194,33 +187,42 @@ pub fn reconcile_core(
                     return (state_prime, Some(Request::ExternalRequest(ext_req)));
                 }
             }
-            let state_prime = ZookeeperReconcileState {
-                reconcile_step: ZookeeperReconcileStep::Error,
-                ..state
-            };
-            return (state_prime, None);
+            return report_error(zk, state);
         },
         ZookeeperReconcileStep::AfterCreateZKNode => {
             if resp_o.is_some() && resp_o.as_ref().unwrap().is_external_response()
             && resp_o.as_ref().unwrap().as_external_response_ref().is_create_response()
             && resp_o.unwrap().into_external_response().unwrap_create_response().res.is_ok() {
-                let req_o = KubeAPIRequest::GetRequest(StatefulSetBuilder::get_request(zk));
+                let ext_req = ZKAPIInput::ReconfigRequest(
+                    zk.metadata().name().unwrap(), zk.metadata().namespace().unwrap(), zk.spec().ports().client(), zk.spec().replicas(), zk_auth_secret_name(zk)
+                );
                 let state_prime = ZookeeperReconcileState {
-                    reconcile_step: ZookeeperReconcileStep::AfterKRequestStep(ActionKind::Get, SubResource::StatefulSet),
+                    reconcile_step: ZookeeperReconcileStep::AfterReconfigZK,
                     ..state
                 };
-                return (state_prime, Some(Request::KRequest(req_o)));
+                return (state_prime, Some(Request::ExternalRequest(ext_req)));
             }
-            let state_prime = ZookeeperReconcileState {
-                reconcile_step: ZookeeperReconcileStep::Error,
-                ..state
-            };
-            return (state_prime, None);
+            return report_error(zk, state);
         },
         ZookeeperReconcileStep::AfterUpdateZKNode => {
             if resp_o.is_some() && resp_o.as_ref().unwrap().is_external_response()
             && resp_o.as_ref().unwrap().as_external_response_ref().is_set_data_response()
             && resp_o.unwrap().into_external_response().unwrap_set_data_response().res.is_ok() {
+                let ext_req = ZKAPIInput::ReconfigRequest(
+                    zk.metadata().name().unwrap(), zk.metadata().namespace().unwrap(), zk.spec().ports().client(), zk.spec().replicas(), zk_auth_secret_name(zk)
+                );
+                let state_prime = ZookeeperReconcileState {
+                    reconcile_step: ZookeeperReconcileStep::AfterReconfigZK,
+                    ..state
+                };
+                return (state_prime, Some(Request::ExternalRequest(ext_req)));
+            }
+            return report_error(zk, state);
+        },
+        ZookeeperReconcileStep::AfterReconfigZK => {
+            if resp_o.is_some() && resp_o.as_ref().unwrap().is_external_response()
+            && resp_o.as_ref().unwrap().as_external_response_ref().is_reconfig_response()
+            && resp_o.unwrap().into_external_response().unwrap_reconfig_response().res.is_ok() {
                 let req_o = KubeAPIRequest::GetRequest(StatefulSetBuilder::get_request(zk));
                 let state_prime = ZookeeperReconcileState {
                     reconcile_step: ZookeeperReconcileStep::AfterKRequestStep(ActionKind::Get, SubResource::StatefulSet),
@@ -228,11 +230,7 @@ pub fn reconcile_core(
                 };
                 return (state_prime, Some(Request::KRequest(req_o)));
             }
-            let state_prime = ZookeeperReconcileState {
-                reconcile_step: ZookeeperReconcileStep::Error,
-                ..state
-            };
-            return (state_prime, None);
+            return report_error(zk, state);
         },
         ZookeeperReconcileStep::AfterUpdateStatus => {
             if resp_o.is_some() && resp_o.as_ref().unwrap().is_k_response()
@@ -252,6 +250,13 @@ pub fn reconcile_core(
             };
             return (state_prime, None);
         },
+        ZookeeperReconcileStep::AfterUpdateDegradedStatus => {
+            let state_prime = ZookeeperReconcileState {
+                reconcile_step: ZookeeperReconcileStep::Error,
+                ..state
+            };
+            return (state_prime, None);
+        },
         _ => {
             let state_prime = ZookeeperReconcileState {
                 reconcile_step: step,
@@ -262,6 +267,23 @@ pub fn reconcile_core(
     }
 }
 
+pub fn report_error(zk: &ZookeeperCluster, state: ZookeeperReconcileState) -> (res: (ZookeeperReconcileState, Option<Request<ZKAPIInput>>))
+    requires zk@.well_formed(),
+    ensures (res.0@, opt_request_to_view(&res.1)) == model_reconciler::report_error(zk@, state@),
+{
+    let req_o = KubeAPIRequest::UpdateStatusRequest(KubeUpdateStatusRequest {
+        api_resource: ZookeeperCluster::api_resource(),
+        name: zk.metadata().name().unwrap(),
+        namespace: zk.metadata().namespace().unwrap(),
+        obj: update_zk_status_to_degraded(zk).marshal(),
+    });
+    let state_prime = ZookeeperReconcileState {
+        reconcile_step: ZookeeperReconcileStep::AfterUpdateDegradedStatus,
+        ..state
+    };
+    (state_prime, Some(Request::KRequest(req_o)))
+}
+
 pub fn reconcile_helper<
     SpecBuilder: SpecResourceBuilder<spec_types::ZookeeperClusterView, spec_types::ZookeeperReconcileState>,
     Builder: ResourceBuilder<ZookeeperCluster, ZookeeperReconcileState, SpecBuilder>
@@ -317,12 +339,7 @@ pub fn reconcile_helper<
                         }
                     }
                     // return error state
-                    let state_prime = ZookeeperReconcileState {
-                        reconcile_step: ZookeeperReconcileStep::Error,
-                        ..state
-                    };
-                    let req_o = None;
-                    return (state_prime, req_o);
+                    return report_error(zk, state);
                 },
                 ActionKind::Create => {
                     if resp_o.is_some() && resp_o.as_ref().unwrap().is_k_response()
@@ -339,12 +356,7 @@ pub fn reconcile_helper<
                             return (state_prime, req_o);
                         }
                     }
-                    let state_prime = ZookeeperReconcileState {
-                        reconcile_step: ZookeeperReconcileStep::Error,
-                        ..state
-                    };
-                    let req_o = None;
-                    return (state_prime, req_o);
+                    return report_error(zk, state);
                 },
                 ActionKind::Update => {
                     if resp_o.is_some() && resp_o.as_ref().unwrap().is_k_response()
@@ -361,12 +373,7 @@ pub fn reconcile_helper<
                             return (state_prime, req_o);
                         }
                     }
-                    let state_prime = ZookeeperReconcileState {
-                        reconcile_step: ZookeeperReconcileStep::Error,
-                        ..state
-                    };
-                    let req_o = None;
-                    return (state_prime, req_o);
+                    return report_error(zk, state);
                 },
             }
         },
@@ -401,6 +408,16 @@ fn zk_parent_node_path(zk: &ZookeeperCluster) -> (path: Vec<String>)
     path
 }
 
+fn zk_auth_secret_name(zk: &ZookeeperCluster) -> (secret_name: Option<String>)
+    ensures opt_string_to_view(&secret_name) == model_reconciler::zk_auth_secret_name(zk@),
+{
+    if zk.spec().auth().is_some() {
+        Some(zk.spec().auth().unwrap().secret_name())
+    } else {
+        None
+    }
+}
+
 fn zk_node_data(zk: &ZookeeperCluster) -> (data: String)
     requires zk@.well_formed(),
     ensures data@ == model_reconciler::zk_node_data(zk@),
@@ -465,6 +482,25 @@ impl ZKAPIOutput {
             _ => unreached(),
         }
     }
+
+    pub fn is_reconfig_response(&self) -> (res: bool)
+        ensures res == self.is_ReconfigResponse(),
+    {
+        match self {
+            ZKAPIOutput::ReconfigResponse(_) => true,
+            _ => false,
+        }
+    }
+
+    pub fn unwrap_reconfig_response(self) -> (result: ZKAPIReconfigResult)
+        requires self.is_ReconfigResponse(),
+        ensures result == self.get_ReconfigResponse_0(),
+    {
+        match self {
+            ZKAPIOutput::ReconfigResponse(result) => result,
+            _ => unreached(),
+        }
+    }
 }
 
 
@@ -8,7 +8,7 @@ use crate::kubernetes_api_objects::exec::{
 };
 use crate::reconciler::exec::{io::*, reconciler::*, resource_builder::*};
 use crate::vstd_ext::{string_map::StringMap, string_view::*};
-use crate::zookeeper_controller::exec::resource::{common::*, config_map::ConfigMapBuilder};
+use crate::zookeeper_controller::exec::resource::{common::*, pod_disruption_budget::PodDisruptionBudgetBuilder};
 use crate::zookeeper_controller::model::resource as model_resource;
 use crate::zookeeper_controller::trusted::{
     exec_types::*, spec_types::ZookeeperClusterView, step::*,
@@ -51,10 +51,10 @@ impl ResourceBuilder<ZookeeperCluster, ZookeeperReconcileState, model_resource::
         let service = Service::unmarshal(obj);
         if service.is_ok() {
             let state_prime = ZookeeperReconcileState {
-                reconcile_step: ZookeeperReconcileStep::AfterKRequestStep(ActionKind::Get, SubResource::ConfigMap),
+                reconcile_step: ZookeeperReconcileStep::AfterKRequestStep(ActionKind::Get, SubResource::PodDisruptionBudget),
                 ..state
             };
-            let req = KubeAPIRequest::GetRequest(ConfigMapBuilder::get_request(zk));
+            let req = KubeAPIRequest::GetRequest(PodDisruptionBudgetBuilder::get_request(zk));
             Ok((state_prime, Some(req)))
         } else {
             Err(())
@@ -65,10 +65,10 @@ impl ResourceBuilder<ZookeeperCluster, ZookeeperReconcileState, model_resource::
         let service = Service::unmarshal(obj);
         if service.is_ok() {
             let state_prime = ZookeeperReconcileState {
-                reconcile_step: ZookeeperReconcileStep::AfterKRequestStep(ActionKind::Get, SubResource::ConfigMap),
+                reconcile_step: ZookeeperReconcileStep::AfterKRequestStep(ActionKind::Get, SubResource::PodDisruptionBudget),
                 ..state
             };
-            let req = KubeAPIRequest::GetRequest(ConfigMapBuilder::get_request(zk));
+            let req = KubeAPIRequest::GetRequest(PodDisruptionBudgetBuilder::get_request(zk));
             Ok((state_prime, Some(req)))
         } else {
             Err(())
@@ -119,6 +119,9 @@ pub fn make_client_service(zk: &ZookeeperCluster) -> (service: Service)
     let mut ports = Vec::new();
 
     ports.push(ServicePort::new_with("tcp-client".to_string(), zk.spec().ports().client()));
+    if zk.spec().tls().is_some() {
+        ports.push(ServicePort::new_with("tls-client".to_string(), zk.spec().tls().unwrap().secure_client_port()));
+    }
 
     proof {
         assert_seqs_equal!(
@@ -134,7 +134,6 @@ pub fn make_zk_config(zk: &ZookeeperCluster) -> (s: String)
     dataDir=/data\n\
     standaloneEnabled=false\n\
     reconfigEnabled=true\n\
-    skipACL=yes\n\
     metricsProvider.className=org.apache.zookeeper.metrics.prometheus.PrometheusMetricsProvider\n\
     metricsProvider.httpPort=".to_string().concat(i32_to_string(zk.spec().ports().metrics()).as_str()).concat("\n\
     metricsProvider.exportJvmInfo=true\n\
@@ -155,6 +154,40 @@ pub fn make_zk_config(zk: &ZookeeperCluster) -> (s: String)
     quorumListenOnAllIPs=").concat(bool_to_string(zk.spec().conf().quorum_listen_on_all_ips()).as_str()).concat("\n\
     admin.serverPort=").concat(i32_to_string(zk.spec().ports().admin_server()).as_str()).concat("\n\
     dynamicConfigFile=/data/zoo.cfg.dynamic\n")
+    .concat(make_zk_auth_config(zk).as_str())
+    .concat(make_zk_tls_config(zk).as_str())
+}
+
+pub fn make_zk_auth_config(zk: &ZookeeperCluster) -> (s: String)
+    ensures s@ == config_map::make_zk_auth_config(zk@),
+{
+    if zk.spec().auth().is_none() {
+        "skipACL=yes\n".to_string()
+    } else if zk.spec().auth().unwrap().sasl() {
+        "skipACL=no\n\
+        authProvider.sasl=org.apache.zookeeper.server.auth.SASLAuthenticationProvider\n".to_string()
+    } else {
+        "skipACL=no\n".to_string()
+    }
+}
+
+pub fn make_zk_tls_config(zk: &ZookeeperCluster) -> (s: String)
+    ensures s@ == config_map::make_zk_tls_config(zk@),
+{
+    if zk.spec().tls().is_none() {
+        return "".to_string();
+    }
+    let tls = zk.spec().tls().unwrap();
+    let mut tls_config = "serverCnxnFactory=org.apache.zookeeper.server.NettyServerCnxnFactory\n\
+    secureClientPort=".to_string().concat(i32_to_string(tls.secure_client_port()).as_str()).concat("\n\
+    ssl.keyStore.location=/tls/keystore.jks\n\
+    ssl.trustStore.location=/tls/truststore.jks\n");
+    if tls.quorum_tls() {
+        tls_config.append("sslQuorum=true\n\
+        ssl.quorum.keyStore.location=/tls/keystore.jks\n\
+        ssl.quorum.trustStore.location=/tls/truststore.jks\n");
+    }
+    tls_config
 }
 
 pub fn make_log4j_config() -> (s: String)
@@ -199,6 +232,30 @@ pub fn make_env_config(zk: &ZookeeperCluster) -> (s: String)
     ADMIN_SERVER_HOST=").concat(name.as_str()).concat("-admin-server\n\
     ADMIN_SERVER_PORT=").concat(admin_port.as_str()).concat("\n\
     CLUSTER_NAME=").concat(name.as_str()).concat("\n")
+    .concat(make_jvm_flags_config(zk).as_str())
+}
+
+pub fn make_jvm_flags_config(zk: &ZookeeperCluster) -> (s: String)
+    ensures s@ == model_resource::make_jvm_flags_config(zk@),
+{
+    let sasl = zk.spec().auth().is_some() && zk.spec().auth().unwrap().sasl();
+    if zk.spec().tls().is_none() && !sasl {
+        return "".to_string();
+    }
+    let mut jvm_flags_config = "".to_string();
+    if zk.spec().tls().is_some() {
+        jvm_flags_config.append("SERVER_JVMFLAGS=\"$SERVER_JVMFLAGS -Dzookeeper.ssl.keyStore.password=$(cat /tls/password) \
+        -Dzookeeper.ssl.trustStore.password=$(cat /tls/password)\"\n");
+        if zk.spec().tls().unwrap().quorum_tls() {
+            jvm_flags_config.append("SERVER_JVMFLAGS=\"$SERVER_JVMFLAGS -Dzookeeper.ssl.quorum.keyStore.password=$(cat /tls/password) \
+            -Dzookeeper.ssl.quorum.trustStore.password=$(cat /tls/password)\"\n");
+        }
+    }
+    if sasl {
+        jvm_flags_config.append("SERVER_JVMFLAGS=\"$SERVER_JVMFLAGS -Djava.security.auth.login.config=/auth/jaas.conf\"\n");
+    }
+    jvm_flags_config.append("export SERVER_JVMFLAGS\n");
+    jvm_flags_config
 }
 
 }
@@ -3,6 +3,7 @@ pub mod client_service;
 pub mod common;
 pub mod config_map;
 pub mod headless_service;
+pub mod pod_disruption_budget;
 pub mod stateful_set;
 
 pub use admin_server_service::*;
@@ -10,4 +11,5 @@ pub use client_service::*;
 pub use common::*;
 pub use config_map::*;
 pub use headless_service::*;
+pub use pod_disruption_budget::*;
 pub use stateful_set::*;
@@ -0,0 +1,145 @@
+// Copyright 2022 VMware, Inc.
+// SPDX-License-Identifier: MIT
+#![allow(unused_imports)]
+use crate::external_api::exec::*;
+use crate::kubernetes_api_objects::exec::{
+    container::*, label_selector::*, pod_template_spec::*, prelude::*, resource_requirements::*,
+    volume::*,
+};
+use crate::reconciler::exec::{io::*, reconciler::*, resource_builder::*};
+use crate::vstd_ext::{string_map::StringMap, string_view::*};
+use crate::zookeeper_controller::exec::resource::{common::*, config_map::ConfigMapBuilder};
+use crate::zookeeper_controller::model::resource as model_resource;
+use crate::zookeeper_controller::trusted::{
+    exec_types::*, spec_types::ZookeeperClusterView, step::*,
+};
+use vstd::prelude::*;
+use vstd::seq_lib::*;
+use vstd::string::*;
+
+verus! {
+
+pub struct PodDisruptionBudgetBuilder {}
+
+impl ResourceBuilder<ZookeeperCluster, ZookeeperReconcileState, model_resource::PodDisruptionBudgetBuilder> for PodDisruptionBudgetBuilder {
+    open spec fn requirements(zk: ZookeeperClusterView) -> bool { zk.well_formed() }
+
+    fn get_request(zk: &ZookeeperCluster) -> KubeGetRequest {
+        KubeGetRequest {
+            api_resource: PodDisruptionBudget::api_resource(),
+            name: make_pod_disruption_budget_name(zk),
+            namespace: zk.metadata().namespace().unwrap(),
+        }
+    }
+
+    fn make(zk: &ZookeeperCluster, state: &ZookeeperReconcileState) -> Result<DynamicObject, ()> {
+        Ok(make_pod_disruption_budget(zk).marshal())
+    }
+
+    fn update(zk: &ZookeeperCluster, state: &ZookeeperReconcileState, obj: DynamicObject) -> Result<DynamicObject, ()> {
+        let pdb = PodDisruptionBudget::unmarshal(obj);
+        if pdb.is_ok() {
+            return Ok(update_pod_disruption_budget(zk, &pdb.unwrap()).marshal());
+        }
+        return Err(());
+    }
+
+    fn state_after_create(zk: &ZookeeperCluster, obj: DynamicObject, state: ZookeeperReconcileState) -> (res: Result<(ZookeeperReconcileState, Option<KubeAPIRequest>), ()>) {
+        let pdb = PodDisruptionBudget::unmarshal(obj);
+        if pdb.is_ok() {
+            let state_prime = ZookeeperReconcileState {
+                reconcile_step: ZookeeperReconcileStep::AfterKRequestStep(ActionKind::Get, SubResource::ConfigMap),
+                ..state
+            };
+            let req = KubeAPIRequest::GetRequest(ConfigMapBuilder::get_request(zk));
+            Ok((state_prime, Some(req)))
+        } else {
+            Err(())
+        }
+    }
+
+    fn state_after_update(zk: &ZookeeperCluster, obj: DynamicObject, state: ZookeeperReconcileState) -> (res: Result<(ZookeeperReconcileState, Option<KubeAPIRequest>), ()>) {
+        let pdb = PodDisruptionBudget::unmarshal(obj);
+        if pdb.is_ok() {
+            let state_prime = ZookeeperReconcileState {
+                reconcile_step: ZookeeperReconcileStep::AfterKRequestStep(ActionKind::Get, SubResource::ConfigMap),
+                ..state
+            };
+            let req = KubeAPIRequest::GetRequest(ConfigMapBuilder::get_request(zk));
+            Ok((state_prime, Some(req)))
+        } else {
+            Err(())
+        }
+    }
+}
+
+pub fn make_pod_disruption_budget_name(zk: &ZookeeperCluster) -> (name: String)
+    requires zk@.well_formed(),
+    ensures name@ == model_resource::make_pod_disruption_budget_name(zk@),
+{
+    zk.metadata().name().unwrap().concat("-pdb")
+}
+
+pub fn make_quorum_size(zk: &ZookeeperCluster) -> (quorum_size: i32)
+    ensures quorum_size as int == model_resource::make_quorum_size(zk@),
+{
+    let replicas = zk.spec().replicas();
+    if replicas > 0 { replicas / 2 + 1 } else { 0 }
+}
+
+pub fn make_min_available(zk: &ZookeeperCluster) -> (min_available: i32)
+    ensures min_available as int == model_resource::make_min_available(zk@),
+{
+    match zk.spec().pod_disruption_budget() {
+        Some(pdb_spec) => pdb_spec.min_available(),
+        None => make_quorum_size(zk),
+    }
+}
+
+pub fn update_pod_disruption_budget(zk: &ZookeeperCluster, found_pdb: &PodDisruptionBudget) -> (pdb: PodDisruptionBudget)
+    requires zk@.well_formed(),
+    ensures pdb@ == model_resource::update_pod_disruption_budget(zk@, found_pdb@),
+{
+    let mut pdb = found_pdb.clone();
+    let made_pdb = make_pod_disruption_budget(zk);
+    pdb.set_metadata({
+        let mut metadata = found_pdb.metadata();
+        metadata.set_owner_references(make_owner_references(zk));
+        metadata.unset_finalizers();
+        metadata.set_labels(made_pdb.metadata().labels().unwrap());
+        metadata.set_annotations(made_pdb.metadata().annotations().unwrap());
+        metadata
+    });
+    pdb.set_spec(made_pdb.spec().unwrap());
+    pdb
+}
+
+// The PodDisruptionBudget keeps a quorum of zookeeper servers running during voluntary disruptions,
+// unless spec.podDisruptionBudget overrides minAvailable
+pub fn make_pod_disruption_budget(zk: &ZookeeperCluster) -> (pdb: PodDisruptionBudget)
+    requires zk@.well_formed(),
+    ensures pdb@ == model_resource::make_pod_disruption_budget(zk@),
+{
+    let mut pdb = PodDisruptionBudget::default();
+    pdb.set_metadata({
+        let mut metadata = ObjectMeta::default();
+        metadata.set_name(make_pod_disruption_budget_name(zk));
+        metadata.set_labels(make_labels(zk));
+        metadata.set_annotations(zk.spec().annotations());
+        metadata.set_owner_references(make_owner_references(zk));
+        metadata
+    });
+    pdb.set_spec({
+        let mut pdb_spec = PodDisruptionBudgetSpec::default();
+        pdb_spec.set_min_available(make_min_available(zk));
+        pdb_spec.set_selector({
+            let mut selector = LabelSelector::default();
+            selector.set_match_labels(make_base_labels(zk));
+            selector
+        });
+        pdb_spec
+    });
+    pdb
+}
+
+}
@@ -3,8 +3,8 @@
 #![allow(unused_imports)]
 use crate::external_api::exec::*;
 use crate::kubernetes_api_objects::exec::{
-    container::*, label_selector::*, pod_template_spec::*, prelude::*, resource_requirements::*,
-    volume::*, volume_resource_requirements::*,
+    condition::*, container::*, label_selector::*, pod_template_spec::*, prelude::*,
+    resource_requirements::*, volume::*, volume_resource_requirements::*,
 };
 use crate::reconciler::exec::{io::*, reconciler::*, resource_builder::*};
 use crate::vstd_ext::{string_map::StringMap, string_view::*};
@@ -108,6 +108,13 @@ pub fn make_stateful_set_name(zk: &ZookeeperCluster) -> (name: String)
     zk.metadata().name().unwrap()
 }
 
+pub fn make_data_pvc_name(zk: &ZookeeperCluster) -> (name: String)
+    requires zk@.metadata.name.is_Some(),
+    ensures name@ == model_resource::make_data_pvc_name(zk@),
+{
+    "data-".to_string().concat(zk.metadata().name().unwrap().as_str()).concat("-0")
+}
+
 pub fn update_stateful_set(zk: &ZookeeperCluster, found_stateful_set: &StatefulSet, rv: &String) -> (stateful_set: StatefulSet)
     requires
         zk@.well_formed(),
@@ -316,6 +323,24 @@ pub fn make_zk_pod_spec(zk: &ZookeeperCluster) -> (pod_spec: PodSpec)
                     conf_volume_mount.set_mount_path("/conf".to_string());
                     conf_volume_mount
                 });
+                if zk.spec().tls().is_some() {
+                    volume_mounts.push({
+                        let mut tls_volume_mount = VolumeMount::default();
+                        tls_volume_mount.set_name("tls".to_string());
+                        tls_volume_mount.set_mount_path("/tls".to_string());
+                        tls_volume_mount.set_read_only(true);
+                        tls_volume_mount
+                    });
+                }
+                if zk.spec().auth().is_some() && zk.spec().auth().unwrap().sasl() {
+                    volume_mounts.push({
+                        let mut auth_volume_mount = VolumeMount::default();
+                        auth_volume_mount.set_name("auth".to_string());
+                        auth_volume_mount.set_mount_path("/auth".to_string());
+                        auth_volume_mount.set_read_only(true);
+                        auth_volume_mount
+                    });
+                }
 
                 proof {
                     assert_seqs_equal!(
@@ -333,6 +358,9 @@ pub fn make_zk_pod_spec(zk: &ZookeeperCluster) -> (pod_spec: PodSpec)
                 ports.push(ContainerPort::new_with("leader-election".to_string(), zk.spec().ports().leader_election()));
                 ports.push(ContainerPort::new_with("metrics".to_string(), zk.spec().ports().metrics()));
                 ports.push(ContainerPort::new_with("admin-server".to_string(), zk.spec().ports().admin_server()));
+                if zk.spec().tls().is_some() {
+                    ports.push(ContainerPort::new_with("secure-client".to_string(), zk.spec().tls().unwrap().secure_client_port()));
+                }
 
                 proof {
                     assert_seqs_equal!(
@@ -427,6 +455,30 @@ pub fn make_zk_pod_spec(zk: &ZookeeperCluster) -> (pod_spec: PodSpec)
                 volume
             });
         }
+        if zk.spec().tls().is_some() {
+            volumes.push({
+                let mut volume = Volume::default();
+                volume.set_name("tls".to_string());
+                volume.set_secret({
+                    let mut secret = SecretVolumeSource::default();
+                    secret.set_secret_name(zk.spec().tls().unwrap().secret_name());
+                    secret
+                });
+                volume
+            });
+        }
+        if zk.spec().auth().is_some() && zk.spec().auth().unwrap().sasl() {
+            volumes.push({
+                let mut volume = Volume::default();
+                volume.set_name("auth".to_string());
+                volume.set_secret({
+                    let mut secret = SecretVolumeSource::default();
+                    secret.set_secret_name(zk.spec().auth().unwrap().secret_name());
+                    secret
+                });
+                volume
+            });
+        }
 
         proof {
             assert_seqs_equal!(
@@ -441,6 +493,14 @@ pub fn make_zk_pod_spec(zk: &ZookeeperCluster) -> (pod_spec: PodSpec)
     if zk_tolerations.is_some() {
         pod_spec.set_tolerations(zk_tolerations.unwrap());
     }
+    let zk_topology_spread_constraints = zk.spec().topology_spread_constraints();
+    if zk_topology_spread_constraints.is_some() {
+        pod_spec.set_topology_spread_constraints(zk_topology_spread_constraints.unwrap());
+    }
+    let zk_priority_class_name = zk.spec().priority_class_name();
+    if zk_priority_class_name.is_some() {
+        pod_spec.set_priority_class_name(zk_priority_class_name.unwrap());
+    }
     pod_spec.set_node_selector(zk.spec().node_selector());
 
     pod_spec
@@ -453,6 +513,20 @@ pub fn update_zk_status(zk: &ZookeeperCluster, ready_replicas: i32) -> (updated_
     updated_zk.set_status({
         let mut status = ZookeeperClusterStatus::default();
         status.set_ready_replicas(ready_replicas);
+        status.set_conditions(make_conditions(ready_replicas == zk.spec().replicas(), false, zk.metadata().generation()));
+        status
+    });
+    updated_zk
+}
+
+pub fn update_zk_status_to_degraded(zk: &ZookeeperCluster) -> (updated_zk: ZookeeperCluster)
+    ensures updated_zk@ == model_resource::update_zk_status_to_degraded(zk@),
+{
+    let mut updated_zk = zk.clone();
+    updated_zk.set_status({
+        let status_o = zk.status();
+        let mut status = if status_o.is_some() { status_o.unwrap() } else { ZookeeperClusterStatus::default() };
+        status.set_conditions(make_conditions(false, true, zk.metadata().generation()));
         status
     });
     updated_zk
@@ -1,6 +1,8 @@
 // Copyright 2022 VMware, Inc.
 // SPDX-License-Identifier: MIT
+pub mod backup;
 pub mod exec;
 pub mod model;
 pub mod proof;
+pub mod restore;
 pub mod trusted;
@@ -84,6 +84,7 @@ pub open spec fn reconcile_core(zk: ZookeeperClusterView, resp_o: Option<Respons
                 SubResource::HeadlessService => { reconcile_helper::<HeadlessServiceBuilder>(zk, resp_o, state) },
                 SubResource::ClientService => { reconcile_helper::<ClientServiceBuilder>(zk, resp_o, state) },
                 SubResource::AdminServerService => { reconcile_helper::<AdminServerServiceBuilder>(zk, resp_o, state) },
+                SubResource::PodDisruptionBudget => { reconcile_helper::<PodDisruptionBudgetBuilder>(zk, resp_o, state) },
                 SubResource::ConfigMap => { reconcile_helper::<ConfigMapBuilder>(zk, resp_o, state) },
                 SubResource::StatefulSet => { reconcile_helper::<StatefulSetBuilder>(zk, resp_o, state) },
             }
@@ -105,18 +106,10 @@ pub open spec fn reconcile_core(zk: ZookeeperClusterView, resp_o: Option<Respons
                     };
                     (state_prime, Some(RequestView::KRequest(req_o)))
                 } else {
-                    let state_prime = ZookeeperReconcileState {
-                        reconcile_step: ZookeeperReconcileStep::Error,
-                        ..state
-                    };
-                    (state_prime, None)
+                    report_error(zk, state)
                 }
             } else {
-                let state_prime = ZookeeperReconcileState {
-                    reconcile_step: ZookeeperReconcileStep::Error,
-                    ..state
-                };
-                (state_prime, None)
+                report_error(zk, state)
             }
         },
         ZookeeperReconcileStep::AfterExistsZKNode => {
@@ -137,11 +130,7 @@ pub open spec fn reconcile_core(zk: ZookeeperClusterView, resp_o: Option<Respons
                     (state_prime, Some(RequestView::ExternalRequest(zk_create_parent_node_request(zk))))
                 }
             } else {
-                let state_prime = ZookeeperReconcileState {
-                    reconcile_step: ZookeeperReconcileStep::Error,
-                    ..state
-                };
-                (state_prime, None)
+                report_error(zk, state)
             }
         },
         ZookeeperReconcileStep::AfterCreateZKParentNode => {
@@ -154,45 +143,46 @@ pub open spec fn reconcile_core(zk: ZookeeperClusterView, resp_o: Option<Respons
                 };
                 (state_prime, Some(RequestView::ExternalRequest(zk_create_node_request(zk))))
             } else {
-                let state_prime = ZookeeperReconcileState {
-                    reconcile_step: ZookeeperReconcileStep::Error,
-                    ..state
-                };
-                (state_prime, None)
+                report_error(zk, state)
             }
         },
         ZookeeperReconcileStep::AfterCreateZKNode => {
             if resp_o.is_Some() && resp.is_ExternalResponse() && resp.get_ExternalResponse_0().is_CreateResponse()
             && resp.get_ExternalResponse_0().get_CreateResponse_0().res.is_Ok() {
-                let req_o = APIRequest::GetRequest(StatefulSetBuilder::get_request(zk));
                 let state_prime = ZookeeperReconcileState {
-                    reconcile_step: ZookeeperReconcileStep::AfterKRequestStep(ActionKind::Get, SubResource::StatefulSet),
+                    reconcile_step: ZookeeperReconcileStep::AfterReconfigZK,
                     ..state
                 };
-                (state_prime, Some(RequestView::KRequest(req_o)))
+                (state_prime, Some(RequestView::ExternalRequest(zk_reconfig_request(zk))))
             } else {
-                let state_prime = ZookeeperReconcileState {
-                    reconcile_step: ZookeeperReconcileStep::Error,
-                    ..state
-                };
-                (state_prime, None)
+                report_error(zk, state)
             }
         },
         ZookeeperReconcileStep::AfterUpdateZKNode => {
             if resp_o.is_Some() && resp.is_ExternalResponse() && resp.get_ExternalResponse_0().is_SetDataResponse()
             && resp.get_ExternalResponse_0().get_SetDataResponse_0().res.is_Ok() {
-                let req_o = APIRequest::GetRequest(StatefulSetBuilder::get_request(zk));
                 let state_prime = ZookeeperReconcileState {
-                    reconcile_step: ZookeeperReconcileStep::AfterKRequestStep(ActionKind::Get, SubResource::StatefulSet),
+                    reconcile_step: ZookeeperReconcileStep::AfterReconfigZK,
                     ..state
                 };
-                (state_prime, Some(RequestView::KRequest(req_o)))
+                (state_prime, Some(RequestView::ExternalRequest(zk_reconfig_request(zk))))
             } else {
+                report_error(zk, state)
+            }
+        },
+        // The ensemble is reconfigured before the stateful set is scaled,
+        // so that the servers to be removed have left the ensemble by the time their pods are deleted.
+        ZookeeperReconcileStep::AfterReconfigZK => {
+            if resp_o.is_Some() && resp.is_ExternalResponse() && resp.get_ExternalResponse_0().is_ReconfigResponse()
+            && resp.get_ExternalResponse_0().get_ReconfigResponse_0().res.is_Ok() {
+                let req_o = APIRequest::GetRequest(StatefulSetBuilder::get_request(zk));
                 let state_prime = ZookeeperReconcileState {
-                    reconcile_step: ZookeeperReconcileStep::Error,
+                    reconcile_step: ZookeeperReconcileStep::AfterKRequestStep(ActionKind::Get, SubResource::StatefulSet),
                     ..state
                 };
-                (state_prime, None)
+                (state_prime, Some(RequestView::KRequest(req_o)))
+            } else {
+                report_error(zk, state)
             }
         },
         ZookeeperReconcileStep::AfterUpdateStatus => {
@@ -212,6 +202,13 @@ pub open spec fn reconcile_core(zk: ZookeeperClusterView, resp_o: Option<Respons
                 (state_prime, None)
             }
         },
+        ZookeeperReconcileStep::AfterUpdateDegradedStatus => {
+            let state_prime = ZookeeperReconcileState {
+                reconcile_step: ZookeeperReconcileStep::Error,
+                ..state
+            };
+            (state_prime, None)
+        },
         _ => {
             let state_prime = ZookeeperReconcileState {
                 reconcile_step: step,
@@ -222,6 +219,20 @@ pub open spec fn reconcile_core(zk: ZookeeperClusterView, resp_o: Option<Respons
     }
 }
 
+// report_error marks the zookeeper cluster as degraded before the reconcile ends in error.
+pub open spec fn report_error(zk: ZookeeperClusterView, state: ZookeeperReconcileState) -> (ZookeeperReconcileState, Option<RequestView<ZKAPIInputView>>) {
+    let req_o = APIRequest::UpdateStatusRequest(UpdateStatusRequest {
+        namespace: zk.metadata.namespace.get_Some_0(),
+        name: zk.metadata.name.get_Some_0(),
+        obj: update_zk_status_to_degraded(zk).marshal(),
+    });
+    let state_prime = ZookeeperReconcileState {
+        reconcile_step: ZookeeperReconcileStep::AfterUpdateDegradedStatus,
+        ..state
+    };
+    (state_prime, Some(RequestView::KRequest(req_o)))
+}
+
 pub open spec fn zk_node_path(zk: ZookeeperClusterView) -> Seq<StringView> {
     seq!["zookeeper-operator"@, zk.metadata.name.get_Some_0()]
 }
@@ -234,6 +245,14 @@ pub open spec fn zk_node_data(zk: ZookeeperClusterView) -> StringView {
     "CLUSTER_SIZE="@ + int_to_string_view(zk.spec.replicas)
 }
 
+pub open spec fn zk_auth_secret_name(zk: ZookeeperClusterView) -> Option<StringView> {
+    if zk.spec.auth.is_Some() {
+        Some(zk.spec.auth.get_Some_0().secret_name)
+    } else {
+        None
+    }
+}
+
 pub open spec fn zk_exists_request(zk: ZookeeperClusterView) -> ZKAPIInputView {
     let zk_name = zk.metadata.name.get_Some_0();
     let zk_namespace = zk.metadata.namespace.get_Some_0();
@@ -248,7 +267,7 @@ pub open spec fn zk_set_data_request(zk: ZookeeperClusterView, version: int) ->
     let client_port = zk.spec.ports.client;
     let node_path = zk_node_path(zk);
     let data = zk_node_data(zk);
-    ZKAPIInputView::SetDataRequest(zk_name, zk_namespace, client_port, node_path, data, version)
+    ZKAPIInputView::SetDataRequest(zk_name, zk_namespace, client_port, node_path, data, version, zk_auth_secret_name(zk))
 }
 
 pub open spec fn zk_create_parent_node_request(zk: ZookeeperClusterView) -> ZKAPIInputView {
@@ -257,7 +276,7 @@ pub open spec fn zk_create_parent_node_request(zk: ZookeeperClusterView) -> ZKAP
     let client_port = zk.spec.ports.client;
     let node_path = zk_parent_node_path(zk);
     let data = ""@;
-    ZKAPIInputView::CreateRequest(zk_name, zk_namespace, client_port, node_path, data)
+    ZKAPIInputView::CreateRequest(zk_name, zk_namespace, client_port, node_path, data, zk_auth_secret_name(zk))
 }
 
 pub open spec fn zk_create_node_request(zk: ZookeeperClusterView) -> ZKAPIInputView {
@@ -266,7 +285,14 @@ pub open spec fn zk_create_node_request(zk: ZookeeperClusterView) -> ZKAPIInputV
     let client_port = zk.spec.ports.client;
     let node_path = zk_node_path(zk);
     let data = zk_node_data(zk);
-    ZKAPIInputView::CreateRequest(zk_name, zk_namespace, client_port, node_path, data)
+    ZKAPIInputView::CreateRequest(zk_name, zk_namespace, client_port, node_path, data, zk_auth_secret_name(zk))
+}
+
+pub open spec fn zk_reconfig_request(zk: ZookeeperClusterView) -> ZKAPIInputView {
+    let zk_name = zk.metadata.name.get_Some_0();
+    let zk_namespace = zk.metadata.namespace.get_Some_0();
+    let client_port = zk.spec.ports.client;
+    ZKAPIInputView::ReconfigRequest(zk_name, zk_namespace, client_port, zk.spec.replicas, zk_auth_secret_name(zk))
 }
 
 pub open spec fn reconcile_helper<Builder: ResourceBuilder<ZookeeperClusterView, ZookeeperReconcileState>>(
@@ -295,11 +321,7 @@ pub open spec fn reconcile_helper<Builder: ResourceBuilder<ZookeeperClusterView,
                                 };
                                 (state_prime, Some(RequestView::KRequest(req_o)))
                             } else {
-                                let state_prime = ZookeeperReconcileState {
-                                    reconcile_step: ZookeeperReconcileStep::Error,
-                                    ..state
-                                };
-                                (state_prime, None)
+                                report_error(zk, state)
                             }
                         } else if get_resp.get_Err_0().is_ObjectNotFound() {
                             let new_obj = Builder::make(zk, state);
@@ -314,26 +336,14 @@ pub open spec fn reconcile_helper<Builder: ResourceBuilder<ZookeeperClusterView,
                                 };
                                 (state_prime, Some(RequestView::KRequest(req_o)))
                             } else {
-                                let state_prime = ZookeeperReconcileState {
-                                    reconcile_step: ZookeeperReconcileStep::Error,
-                                    ..state
-                                };
-                                (state_prime, None)
+                                report_error(zk, state)
                             }
                         } else {
-                            let state_prime = ZookeeperReconcileState {
-                                reconcile_step: ZookeeperReconcileStep::Error,
-                                ..state
-                            };
-                            (state_prime, None)
+                            report_error(zk, state)
                         }
                     } else {
                         // return error state
-                        let state_prime = ZookeeperReconcileState {
-                            reconcile_step: ZookeeperReconcileStep::Error,
-                            ..state
-                        };
-                        (state_prime, None)
+                        report_error(zk, state)
                     }
                 },
                 ActionKind::Create => {
@@ -346,19 +356,11 @@ pub open spec fn reconcile_helper<Builder: ResourceBuilder<ZookeeperClusterView,
                             let req_o = if req.is_Some() { Some(RequestView::KRequest(req.get_Some_0())) } else { None };
                             (state_prime, req_o)
                         } else {
-                            let state_prime = ZookeeperReconcileState {
-                                reconcile_step: ZookeeperReconcileStep::Error,
-                                ..state
-                            };
-                            (state_prime, None)
+                            report_error(zk, state)
                         }
                     } else {
                         // return error state
-                        let state_prime = ZookeeperReconcileState {
-                            reconcile_step: ZookeeperReconcileStep::Error,
-                            ..state
-                        };
-                        (state_prime, None)
+                        report_error(zk, state)
                     }
                 },
                 ActionKind::Update => {
@@ -371,19 +373,11 @@ pub open spec fn reconcile_helper<Builder: ResourceBuilder<ZookeeperClusterView,
                             let req_o = if req.is_Some() { Some(RequestView::KRequest(req.get_Some_0())) } else { None };
                             (state_prime, req_o)
                         } else {
-                            let state_prime = ZookeeperReconcileState {
-                                reconcile_step: ZookeeperReconcileStep::Error,
-                                ..state
-                            };
-                            (state_prime, None)
+                            report_error(zk, state)
                         }
                     } else {
                         // return error state
-                        let state_prime = ZookeeperReconcileState {
-                            reconcile_step: ZookeeperReconcileStep::Error,
-                            ..state
-                        };
-                        (state_prime, None)
+                        report_error(zk, state)
                     }
                 },
             }
@@ -407,6 +401,8 @@ impl Maker for ZookeeperMaker {
 
     open spec fn make_admin_server_service_key(zookeeper: ZookeeperClusterView) -> ObjectRef { make_admin_server_service_key(zookeeper) }
 
+    open spec fn make_pod_disruption_budget_key(zookeeper: ZookeeperClusterView) -> ObjectRef { make_pod_disruption_budget_key(zookeeper) }
+
     open spec fn make_config_map_key(zookeeper: ZookeeperClusterView) -> ObjectRef { make_config_map_key(zookeeper) }
 
     open spec fn make_stateful_set_key(zookeeper: ZookeeperClusterView) -> ObjectRef { make_stateful_set_key(zookeeper) }
@@ -417,6 +413,8 @@ impl Maker for ZookeeperMaker {
 
     open spec fn make_admin_server_service(zookeeper: ZookeeperClusterView) -> ServiceView { make_admin_server_service(zookeeper) }
 
+    open spec fn make_pod_disruption_budget(zookeeper: ZookeeperClusterView) -> PodDisruptionBudgetView { make_pod_disruption_budget(zookeeper) }
+
     open spec fn make_config_map(zookeeper: ZookeeperClusterView) -> ConfigMapView { make_config_map(zookeeper) }
 
     open spec fn make_stateful_set(zookeeper: ZookeeperClusterView, config_map_rv: StringView) -> StatefulSetView { make_stateful_set(zookeeper, config_map_rv) }
@@ -10,7 +10,7 @@ use crate::reconciler::spec::{io::*, reconciler::*, resource_builder::*};
 use crate::state_machine::{action::*, state_machine::*};
 use crate::temporal_logic::defs::*;
 use crate::vstd_ext::string_view::*;
-use crate::zookeeper_controller::model::resource::{common::*, config_map::ConfigMapBuilder};
+use crate::zookeeper_controller::model::resource::{common::*, pod_disruption_budget::PodDisruptionBudgetBuilder};
 use crate::zookeeper_controller::trusted::{spec_types::*, step::*};
 use vstd::prelude::*;
 use vstd::string::*;
@@ -41,10 +41,10 @@ impl ResourceBuilder<ZookeeperClusterView, ZookeeperReconcileState> for AdminSer
         let service = ServiceView::unmarshal(obj);
         if service.is_Ok() {
             let state_prime = ZookeeperReconcileState {
-                reconcile_step: ZookeeperReconcileStep::AfterKRequestStep(ActionKind::Get, SubResource::ConfigMap),
+                reconcile_step: ZookeeperReconcileStep::AfterKRequestStep(ActionKind::Get, SubResource::PodDisruptionBudget),
                 ..state
             };
-            let req = APIRequest::GetRequest(ConfigMapBuilder::get_request(zk));
+            let req = APIRequest::GetRequest(PodDisruptionBudgetBuilder::get_request(zk));
             Ok((state_prime, Some(req)))
         } else {
             Err(())
@@ -55,10 +55,10 @@ impl ResourceBuilder<ZookeeperClusterView, ZookeeperReconcileState> for AdminSer
         let service = ServiceView::unmarshal(obj);
         if service.is_Ok() {
             let state_prime = ZookeeperReconcileState {
-                reconcile_step: ZookeeperReconcileStep::AfterKRequestStep(ActionKind::Get, SubResource::ConfigMap),
+                reconcile_step: ZookeeperReconcileStep::AfterKRequestStep(ActionKind::Get, SubResource::PodDisruptionBudget),
                 ..state
             };
-            let req = APIRequest::GetRequest(ConfigMapBuilder::get_request(zk));
+            let req = APIRequest::GetRequest(PodDisruptionBudgetBuilder::get_request(zk));
             Ok((state_prime, Some(req)))
         } else {
             Err(())
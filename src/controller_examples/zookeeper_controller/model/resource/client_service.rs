@@ -99,6 +99,11 @@ pub open spec fn update_client_service(zk: ZookeeperClusterView, found_client_se
 
 pub open spec fn make_client_service(zk: ZookeeperClusterView) -> ServiceView {
     let ports = seq![ServicePortView::default().set_name("tcp-client"@).set_port(zk.spec.ports.client)];
+    let ports = if zk.spec.tls.is_Some() {
+        ports.push(ServicePortView::default().set_name("tls-client"@).set_port(zk.spec.tls.get_Some_0().secure_client_port))
+    } else {
+        ports
+    };
 
     make_service(zk, make_client_service_name(zk), ports, true)
 }
@@ -146,6 +146,40 @@ pub open spec fn make_env_config(zk: ZookeeperClusterView) -> StringView {
     ADMIN_SERVER_HOST="@ + name + "-admin-server\n\
     ADMIN_SERVER_PORT="@ + admin_server_port + "\n\
     CLUSTER_NAME="@ + name + "\n"@
+    + make_jvm_flags_config(zk)
+}
+
+// zookeeperStart.sh sources env.sh before launching the server, so the exported flags reach the server JVM.
+// The store password is read from the mounted TLS secret when the pod starts.
+pub open spec fn make_jvm_flags_config(zk: ZookeeperClusterView) -> StringView {
+    if zk.spec.tls.is_None() && (zk.spec.auth.is_None() || !zk.spec.auth.get_Some_0().sasl) {
+        ""@
+    } else {
+        {
+            if zk.spec.tls.is_Some() {
+                "SERVER_JVMFLAGS=\"$SERVER_JVMFLAGS -Dzookeeper.ssl.keyStore.password=$(cat /tls/password) \
+                -Dzookeeper.ssl.trustStore.password=$(cat /tls/password)\"\n"@
+                + {
+                    if zk.spec.tls.get_Some_0().quorum_tls {
+                        "SERVER_JVMFLAGS=\"$SERVER_JVMFLAGS -Dzookeeper.ssl.quorum.keyStore.password=$(cat /tls/password) \
+                        -Dzookeeper.ssl.quorum.trustStore.password=$(cat /tls/password)\"\n"@
+                    } else {
+                        ""@
+                    }
+                }
+            } else {
+                ""@
+            }
+        }
+        + {
+            if zk.spec.auth.is_Some() && zk.spec.auth.get_Some_0().sasl {
+                "SERVER_JVMFLAGS=\"$SERVER_JVMFLAGS -Djava.security.auth.login.config=/auth/jaas.conf\"\n"@
+            } else {
+                ""@
+            }
+        }
+        + "export SERVER_JVMFLAGS\n"@
+    }
 }
 
 }
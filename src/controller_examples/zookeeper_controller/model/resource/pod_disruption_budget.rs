@@ -0,0 +1,123 @@
+// Copyright 2022 VMware, Inc.
+// SPDX-License-Identifier: MIT
+#![allow(unused_imports)]
+use crate::kubernetes_api_objects::spec::{
+    container::*, label_selector::*, pod_template_spec::*, prelude::*, resource_requirements::*,
+    volume::*,
+};
+use crate::kubernetes_cluster::spec::message::*;
+use crate::reconciler::spec::{io::*, reconciler::*, resource_builder::*};
+use crate::state_machine::{action::*, state_machine::*};
+use crate::temporal_logic::defs::*;
+use crate::vstd_ext::string_view::*;
+use crate::zookeeper_controller::model::resource::{common::*, config_map::ConfigMapBuilder};
+use crate::zookeeper_controller::trusted::{spec_types::*, step::*};
+use vstd::prelude::*;
+use vstd::string::*;
+
+verus! {
+
+pub struct PodDisruptionBudgetBuilder {}
+
+impl ResourceBuilder<ZookeeperClusterView, ZookeeperReconcileState> for PodDisruptionBudgetBuilder {
+    open spec fn get_request(zk: ZookeeperClusterView) -> GetRequest {
+        GetRequest { key: make_pod_disruption_budget_key(zk) }
+    }
+
+    open spec fn make(zk: ZookeeperClusterView, state: ZookeeperReconcileState) -> Result<DynamicObjectView, ()> {
+        Ok(make_pod_disruption_budget(zk).marshal())
+    }
+
+    open spec fn update(zk: ZookeeperClusterView, state: ZookeeperReconcileState, obj: DynamicObjectView) -> Result<DynamicObjectView, ()> {
+        let pdb = PodDisruptionBudgetView::unmarshal(obj);
+        if pdb.is_Ok() {
+            Ok(update_pod_disruption_budget(zk, pdb.get_Ok_0()).marshal())
+        } else {
+            Err(())
+        }
+    }
+
+    open spec fn state_after_create(zk: ZookeeperClusterView, obj: DynamicObjectView, state: ZookeeperReconcileState) -> (res: Result<(ZookeeperReconcileState, Option<APIRequest>), ()>) {
+        let pdb = PodDisruptionBudgetView::unmarshal(obj);
+        if pdb.is_Ok() {
+            let state_prime = ZookeeperReconcileState {
+                reconcile_step: ZookeeperReconcileStep::AfterKRequestStep(ActionKind::Get, SubResource::ConfigMap),
+                ..state
+            };
+            let req = APIRequest::GetRequest(ConfigMapBuilder::get_request(zk));
+            Ok((state_prime, Some(req)))
+        } else {
+            Err(())
+        }
+    }
+
+    open spec fn state_after_update(zk: ZookeeperClusterView, obj: DynamicObjectView, state: ZookeeperReconcileState) -> (res: Result<(ZookeeperReconcileState, Option<APIRequest>), ()>) {
+        let pdb = PodDisruptionBudgetView::unmarshal(obj);
+        if pdb.is_Ok() {
+            let state_prime = ZookeeperReconcileState {
+                reconcile_step: ZookeeperReconcileStep::AfterKRequestStep(ActionKind::Get, SubResource::ConfigMap),
+                ..state
+            };
+            let req = APIRequest::GetRequest(ConfigMapBuilder::get_request(zk));
+            Ok((state_prime, Some(req)))
+        } else {
+            Err(())
+        }
+    }
+}
+
+pub open spec fn make_pod_disruption_budget_key(zk: ZookeeperClusterView) -> ObjectRef {
+    ObjectRef {
+        kind: PodDisruptionBudgetView::kind(),
+        name: make_pod_disruption_budget_name(zk),
+        namespace: zk.metadata.namespace.get_Some_0(),
+    }
+}
+
+pub open spec fn make_pod_disruption_budget_name(zk: ZookeeperClusterView) -> StringView { zk.metadata.name.get_Some_0() + "-pdb"@ }
+
+// A voluntary disruption (e.g., a node drain) is only allowed to evict a server
+// if the remaining servers still form a quorum.
+// The validation of ZookeeperCluster ensures replicas >= 3; the check only keeps the division on non-negative numbers.
+pub open spec fn make_quorum_size(zk: ZookeeperClusterView) -> int {
+    if zk.spec.replicas > 0 { zk.spec.replicas / 2 + 1 } else { 0 }
+}
+
+// The quorum size can be overridden through spec.podDisruptionBudget; setting minAvailable to 0 disables the budget.
+pub open spec fn make_min_available(zk: ZookeeperClusterView) -> int {
+    if zk.spec.pod_disruption_budget.is_Some() {
+        zk.spec.pod_disruption_budget.get_Some_0().min_available
+    } else {
+        make_quorum_size(zk)
+    }
+}
+
+pub open spec fn update_pod_disruption_budget(zk: ZookeeperClusterView, found_pdb: PodDisruptionBudgetView) -> PodDisruptionBudgetView {
+    PodDisruptionBudgetView {
+        metadata: ObjectMetaView {
+            owner_references: Some(make_owner_references(zk)),
+            finalizers: None,
+            labels: make_pod_disruption_budget(zk).metadata.labels,
+            annotations: make_pod_disruption_budget(zk).metadata.annotations,
+            ..found_pdb.metadata
+        },
+        spec: make_pod_disruption_budget(zk).spec,
+        ..found_pdb
+    }
+}
+
+pub open spec fn make_pod_disruption_budget(zk: ZookeeperClusterView) -> PodDisruptionBudgetView {
+    PodDisruptionBudgetView::default()
+        .set_metadata(ObjectMetaView::default()
+            .set_name(make_pod_disruption_budget_name(zk))
+            .set_labels(make_labels(zk))
+            .set_annotations(zk.spec.annotations)
+            .set_owner_references(make_owner_references(zk))
+        )
+        .set_spec(PodDisruptionBudgetSpecView::default()
+            .set_min_available(make_min_available(zk))
+            .set_selector(LabelSelectorView::default().set_match_labels(make_base_labels(zk)))
+        )
+}
+
+}
@@ -2,8 +2,8 @@
 // SPDX-License-Identifier: MIT
 #![allow(unused_imports)]
 use crate::kubernetes_api_objects::spec::{
-    container::*, label_selector::*, pod_template_spec::*, prelude::*, resource_requirements::*,
-    volume::*, volume_resource_requirements::*,
+    condition::*, container::*, label_selector::*, pod_template_spec::*, prelude::*,
+    resource_requirements::*, volume::*, volume_resource_requirements::*,
 };
 use crate::kubernetes_cluster::spec::message::*;
 use crate::reconciler::spec::{io::*, reconciler::*, resource_builder::*};
@@ -96,6 +96,11 @@ pub open spec fn make_stateful_set_key(zk: ZookeeperClusterView) -> ObjectRef {
 
 pub open spec fn make_stateful_set_name(zk: ZookeeperClusterView) -> StringView { zk.metadata.name.get_Some_0() }
 
+// The data volume of the first replica, created by the stateful set from the "data" volume claim template.
+// Backups are taken from and restored into this volume; the other replicas sync from the leader,
+// which is the replica with the latest transaction.
+pub open spec fn make_data_pvc_name(zk: ZookeeperClusterView) -> StringView { "data-"@ + make_stateful_set_name(zk) + "-0"@ }
+
 pub open spec fn update_stateful_set(zk: ZookeeperClusterView, found_stateful_set: StatefulSetView, rv: StringView) -> StatefulSetView {
     StatefulSetView {
         metadata: ObjectMetaView {
@@ -191,14 +196,20 @@ pub open spec fn make_zk_pod_spec(zk: ZookeeperClusterView) -> PodSpecView {
                     VolumeMountView::default()
                         .set_name("conf"@)
                         .set_mount_path("/conf"@),
-                ]),
+                ] + make_secret_volume_mounts(zk)),
                 ports: Some(seq![
                     ContainerPortView::default().set_name("client"@).set_container_port(zk.spec.ports.client),
                     ContainerPortView::default().set_name("quorum"@).set_container_port(zk.spec.ports.quorum),
                     ContainerPortView::default().set_name("leader-election"@).set_container_port(zk.spec.ports.leader_election),
                     ContainerPortView::default().set_name("metrics"@).set_container_port(zk.spec.ports.metrics),
                     ContainerPortView::default().set_name("admin-server"@).set_container_port(zk.spec.ports.admin_server)
-                ]),
+                ] + {
+                    if zk.spec.tls.is_Some() {
+                        seq![ContainerPortView::default().set_name("secure-client"@).set_container_port(zk.spec.tls.get_Some_0().secure_client_port)]
+                    } else {
+                        seq![]
+                    }
+                }),
                 readiness_probe: Some(ProbeView::default()
                     .set_exec(
                         ExecActionView::default()
@@ -235,20 +246,65 @@ pub open spec fn make_zk_pod_spec(zk: ZookeeperClusterView) -> PodSpecView {
             } else {
                 volumes.push(VolumeView::default().set_name("data"@).set_empty_dir(EmptyDirVolumeSourceView::default()))
             }
-        }),
+        } + make_secret_volumes(zk)),
         tolerations: zk.spec.tolerations,
+        topology_spread_constraints: zk.spec.topology_spread_constraints,
+        priority_class_name: zk.spec.priority_class_name,
         node_selector: Some(zk.spec.node_selector),
         ..PodSpecView::default()
     }
 }
 
+// The TLS secret holds the key and trust stores referred to by zoo.cfg (see make_zk_tls_config),
+// and the auth secret holds jaas.conf for the SASL clients.
+pub open spec fn make_secret_volumes(zk: ZookeeperClusterView) -> Seq<VolumeView> {
+    let tls_volumes = if zk.spec.tls.is_Some() {
+        seq![
+            VolumeView::default()
+                .set_name("tls"@)
+                .set_secret(SecretVolumeSourceView::default().set_secret_name(zk.spec.tls.get_Some_0().secret_name))
+        ]
+    } else {
+        seq![]
+    };
+    if zk.spec.auth.is_Some() && zk.spec.auth.get_Some_0().sasl {
+        tls_volumes.push(
+            VolumeView::default()
+                .set_name("auth"@)
+                .set_secret(SecretVolumeSourceView::default().set_secret_name(zk.spec.auth.get_Some_0().secret_name))
+        )
+    } else {
+        tls_volumes
+    }
+}
+
+pub open spec fn make_secret_volume_mounts(zk: ZookeeperClusterView) -> Seq<VolumeMountView> {
+    let tls_volume_mounts = if zk.spec.tls.is_Some() {
+        seq![VolumeMountView::default().set_name("tls"@).set_mount_path("/tls"@).set_read_only(true)]
+    } else {
+        seq![]
+    };
+    if zk.spec.auth.is_Some() && zk.spec.auth.get_Some_0().sasl {
+        tls_volume_mounts.push(VolumeMountView::default().set_name("auth"@).set_mount_path("/auth"@).set_read_only(true))
+    } else {
+        tls_volume_mounts
+    }
+}
+
 pub open spec fn update_zk_status(zk: ZookeeperClusterView, ready_replicas: int) -> ZookeeperClusterView {
     ZookeeperClusterView {
         status: Some(ZookeeperClusterStatusView {
             ready_replicas: ready_replicas,
+            conditions: Some(make_conditions(ready_replicas == zk.spec.replicas, false, zk.metadata.generation)),
         }),
         ..zk
     }
 }
 
+// The degraded status keeps the ready replicas reported by the last successful reconcile.
+pub open spec fn update_zk_status_to_degraded(zk: ZookeeperClusterView) -> ZookeeperClusterView {
+    let status = if zk.status.is_Some() { zk.status.get_Some_0() } else { ZookeeperClusterStatusView::default() };
+    zk.set_status(status.set_conditions(make_conditions(false, true, zk.metadata.generation)))
+}
+
 }
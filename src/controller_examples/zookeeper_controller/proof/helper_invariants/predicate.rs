@@ -282,7 +282,7 @@ pub open spec fn cm_rv_is_the_same_as_etcd_server_cm_if_cm_updated(zookeeper: Zo
                     _ => true,
                 }
             }
-            ZookeeperReconcileStep::AfterExistsStatefulSet | ZookeeperReconcileStep::AfterExistsZKNode | ZookeeperReconcileStep::AfterCreateZKParentNode | ZookeeperReconcileStep::AfterCreateZKNode | ZookeeperReconcileStep::AfterUpdateZKNode => {
+            ZookeeperReconcileStep::AfterExistsStatefulSet | ZookeeperReconcileStep::AfterExistsZKNode | ZookeeperReconcileStep::AfterCreateZKParentNode | ZookeeperReconcileStep::AfterCreateZKNode | ZookeeperReconcileStep::AfterUpdateZKNode | ZookeeperReconcileStep::AfterReconfigZK => {
                 let cm_key = get_request(SubResource::ConfigMap, zookeeper).key;
                 &&& s.resources().contains_key(cm_key)
                 &&& s.resources()[cm_key].metadata.resource_version.is_Some()
@@ -890,6 +890,7 @@ pub proof fn lemma_always_no_update_status_request_msg_in_flight_of_except_state
                                                 SubResource::HeadlessService => {},
                                                 SubResource::ClientService => {},
                                                 SubResource::AdminServerService => {},
+                                                SubResource::PodDisruptionBudget => {},
                                                 SubResource::ConfigMap => {},
                                                 SubResource::StatefulSet => {},
                                             }
@@ -956,6 +957,7 @@ pub proof fn lemma_always_no_update_status_request_msg_not_from_bc_in_flight_of_
                                             SubResource::HeadlessService => {},
                                             SubResource::ClientService => {},
                                             SubResource::AdminServerService => {},
+                                            SubResource::PodDisruptionBudget => {},
                                             SubResource::ConfigMap => {},
                                             SubResource::StatefulSet => {},
                                         }
@@ -1269,6 +1271,9 @@ pub proof fn lemma_resource_create_or_update_request_msg_implies_key_in_reconcil
                         );
                         seq_lib::seq_equal_preserved_by_add(key.name, cr_key.name, "-admin-server"@);
                     },
+                    SubResource::PodDisruptionBudget => {
+                        seq_lib::seq_equal_preserved_by_add(key.name, cr_key.name, "-pdb"@);
+                    },
                     SubResource::ConfigMap => {
                         // Then we show that only if cr_key.name equals key.name, can this message be created in this step.
                         seq_lib::seq_equal_preserved_by_add(key.name, cr_key.name, "-configmap"@);
@@ -1465,7 +1470,7 @@ pub proof fn leads_to_always_tla_forall_subresource(spec: TempPred<ZKCluster>, p
     leads_to_always_tla_forall(
         spec, p, a_to_p,
         set![SubResource::HeadlessService, SubResource::ClientService, SubResource::AdminServerService,
-        SubResource::ConfigMap, SubResource::StatefulSet]
+        SubResource::PodDisruptionBudget, SubResource::ConfigMap, SubResource::StatefulSet]
     );
 }
 
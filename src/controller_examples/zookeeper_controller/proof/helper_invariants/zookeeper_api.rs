@@ -182,6 +182,76 @@ pub proof fn lemma_eventually_always_every_zk_create_node_request_implies_at_aft
     );
 }
 
+// every_zk_reconfig_request_is_made_from_zookeeper says every reconfig request of the zookeeper cluster carries
+// the desired replicas, so that handling any of them keeps the ensemble within the desired replicas.
+pub open spec fn every_zk_reconfig_request_is_made_from_zookeeper(zookeeper: ZookeeperClusterView) -> StatePred<ZKCluster> {
+    |s: ZKCluster| {
+        forall |msg: ZKMessage| {
+            &&& #[trigger] s.network_state.in_flight.contains(msg)
+            &&& zk_reconfig_request_msg(zookeeper)(msg)
+        } ==> msg.content.get_ExternalAPIRequest_0() == zk_reconfig_request(zookeeper)
+    }
+}
+
+#[verifier(spinoff_prover)]
+pub proof fn lemma_eventually_always_every_zk_reconfig_request_is_made_from_zookeeper(spec: TempPred<ZKCluster>, zookeeper: ZookeeperClusterView)
+    requires
+        spec.entails(always(lift_action(ZKCluster::next()))),
+        spec.entails(tla_forall(|i| ZKCluster::kubernetes_api_next().weak_fairness(i))),
+        spec.entails(tla_forall(|i| ZKCluster::external_api_next().weak_fairness(i))),
+        spec.entails(always(lift_state(ZKCluster::every_in_flight_msg_has_lower_id_than_allocator()))),
+        spec.entails(always(lift_state(ZKCluster::crash_disabled()))),
+        spec.entails(always(lift_state(ZKCluster::busy_disabled()))),
+        spec.entails(always(lift_state(ZKCluster::each_object_in_reconcile_has_consistent_key_and_valid_metadata()))),
+        spec.entails(always(lift_state(ZKCluster::every_in_flight_msg_has_unique_id()))),
+        spec.entails(always(lift_state(ZKCluster::the_object_in_reconcile_has_spec_and_uid_as(zookeeper)))),
+        spec.entails(always(lift_state(ZKCluster::object_in_ok_get_response_has_smaller_rv_than_etcd()))),
+        spec.entails(always(lift_state(ZKCluster::each_object_in_etcd_is_well_formed()))),
+    ensures spec.entails(true_pred().leads_to(always(lift_state(every_zk_reconfig_request_is_made_from_zookeeper(zookeeper))))),
+{
+    let key = zookeeper.object_ref();
+    let requirements = |msg: ZKMessage, s: ZKCluster| {
+        zk_reconfig_request_msg(zookeeper)(msg) ==> msg.content.get_ExternalAPIRequest_0() == zk_reconfig_request(zookeeper)
+    };
+    let stronger_next = |s: ZKCluster, s_prime: ZKCluster| {
+        &&& ZKCluster::next()(s, s_prime)
+        &&& ZKCluster::crash_disabled()(s)
+        &&& ZKCluster::busy_disabled()(s)
+        &&& ZKCluster::each_object_in_reconcile_has_consistent_key_and_valid_metadata()(s)
+        &&& ZKCluster::every_in_flight_msg_has_unique_id()(s)
+        &&& ZKCluster::the_object_in_reconcile_has_spec_and_uid_as(zookeeper)(s)
+    };
+    assert forall |s, s_prime| #[trigger] stronger_next(s, s_prime)
+    implies ZKCluster::every_new_req_msg_if_in_flight_then_satisfies(requirements)(s, s_prime) by {
+        assert forall |msg: ZKMessage| (!s.in_flight().contains(msg) || requirements(msg, s)) && #[trigger] s_prime.in_flight().contains(msg)
+        implies requirements(msg, s_prime) by {
+            if zk_reconfig_request_msg(zookeeper)(msg) && !s.in_flight().contains(msg) {
+                let step = choose |step| ZKCluster::next_step(s, s_prime, step);
+                lemma_zk_request_implies_step_helper(zookeeper, s, s_prime, msg, step);
+                let cr = s.ongoing_reconciles()[key].triggering_cr;
+                assert(cr.metadata.name == zookeeper.metadata.name);
+                assert(cr.metadata.namespace == zookeeper.metadata.namespace);
+                assert(cr.spec == zookeeper.spec);
+                assert(zk_reconfig_request(cr) == zk_reconfig_request(zookeeper));
+            }
+        }
+    }
+    invariant_n!(
+        spec, lift_action(stronger_next), lift_action(ZKCluster::every_new_req_msg_if_in_flight_then_satisfies(requirements)),
+        lift_action(ZKCluster::next()), lift_state(ZKCluster::crash_disabled()), lift_state(ZKCluster::busy_disabled()),
+        lift_state(ZKCluster::each_object_in_reconcile_has_consistent_key_and_valid_metadata()),
+        lift_state(ZKCluster::every_in_flight_msg_has_unique_id()),
+        lift_state(ZKCluster::the_object_in_reconcile_has_spec_and_uid_as(zookeeper))
+    );
+
+    ZKCluster::lemma_true_leads_to_always_every_in_flight_req_msg_satisfies(spec, requirements);
+
+    temp_pred_equality(
+        lift_state(every_zk_reconfig_request_is_made_from_zookeeper(zookeeper)),
+        lift_state(ZKCluster::every_in_flight_req_msg_satisfies(requirements))
+    );
+}
+
 #[verifier(spinoff_prover)]
 pub proof fn lemma_zk_request_implies_step_helper(zookeeper: ZookeeperClusterView, s: ZKCluster, s_prime: ZKCluster, msg: ZKMessage, step: ZKStep)
     requires
@@ -199,6 +269,10 @@ pub proof fn lemma_zk_request_implies_step_helper(zookeeper: ZookeeperClusterVie
             && at_zk_step(zookeeper.object_ref(), ZookeeperReconcileStep::AfterCreateZKParentNode)(s)
             && at_zk_step(zookeeper.object_ref(), ZookeeperReconcileStep::AfterCreateZKNode)(s_prime)
             && ZKCluster::pending_req_msg_is(s_prime, zookeeper.object_ref(), msg),
+        zk_reconfig_request_msg(zookeeper)(msg)
+        ==> step.is_ControllerStep() && step.get_ControllerStep_0().1.get_Some_0() == zookeeper.object_ref()
+            && s.ongoing_reconciles().contains_key(zookeeper.object_ref())
+            && msg.content.get_ExternalAPIRequest_0() == zk_reconfig_request(s.ongoing_reconciles()[zookeeper.object_ref()].triggering_cr),
 {
     let cr_key = step.get_ControllerStep_0().1.get_Some_0();
     let key = zookeeper.object_ref();
@@ -222,6 +296,15 @@ pub proof fn lemma_zk_request_implies_step_helper(zookeeper: ZookeeperClusterVie
         assert(!(zk_node_path(zookeeper) =~= zk_parent_node_path(zookeeper)));
         assert(local_step_prime.is_AfterCreateZKNode());
         assert(local_step.is_AfterCreateZKParentNode());
+    } else if zk_reconfig_request_msg(zookeeper)(msg) {
+        assert(step.is_ControllerStep());
+        assert(s.ongoing_reconciles().contains_key(cr_key));
+        let local_step = s.ongoing_reconciles()[cr_key].local_state.reconcile_step;
+        let local_step_prime = s_prime.ongoing_reconciles()[cr_key].local_state.reconcile_step;
+        assert(cr_key == zookeeper.object_ref());
+        assert(ZKCluster::pending_req_msg_is(s_prime, cr_key, msg));
+        assert(local_step_prime.is_AfterReconfigZK());
+        assert(local_step.is_AfterCreateZKNode() || local_step.is_AfterUpdateZKNode());
     }
 }
 
@@ -26,7 +26,12 @@ use crate::zookeeper_controller::{
                 lemma_stateful_set_is_stable,
             },
             terminate,
-            zookeeper_api::lemma_from_after_exists_stateful_set_step_to_after_get_stateful_set_step,
+            zookeeper_api::{
+                lemma_ensemble_is_stable,
+                lemma_from_after_exists_stateful_set_step_and_key_exists_to_after_reconfig_zk_step,
+                lemma_from_after_exists_stateful_set_step_to_after_get_stateful_set_step,
+                lemma_from_after_reconfig_zk_step_pending_to_ensemble_matches,
+            },
         },
         predicate::*,
         resource::*,
@@ -93,17 +98,98 @@ proof fn lemma_true_leads_to_always_current_state_matches(zookeeper: ZookeeperCl
 {
     let spec = assumption_and_invariants_of_all_phases(zookeeper);
     lemma_true_leads_to_always_state_matches_for_all_resources(zookeeper);
+    lemma_true_leads_to_always_ensemble_matches(zookeeper);
     let a_to_p = |res: SubResource| lift_state(sub_resource_state_matches(res, zookeeper));
     helper_invariants::leads_to_always_tla_forall_subresource(spec, true_pred(), a_to_p);
-    assert forall |ex| #[trigger] tla_forall(a_to_p).satisfied_by(ex) implies lift_state(current_state_matches::<ZookeeperMaker>(zookeeper)).satisfied_by(ex) by {
+    leads_to_always_combine(spec, true_pred(), tla_forall(a_to_p), lift_state(zk_ensemble_matches(zookeeper)));
+    assert forall |ex| #[trigger] tla_forall(a_to_p).and(lift_state(zk_ensemble_matches(zookeeper))).satisfied_by(ex) implies lift_state(current_state_matches::<ZookeeperMaker>(zookeeper)).satisfied_by(ex) by {
         let s = ex.head();
         assert forall |res: SubResource| #[trigger] resource_state_matches::<ZookeeperMaker>(res, zookeeper, s.resources()) by {
             tla_forall_apply(a_to_p, res);
             assert(a_to_p(res).satisfied_by(ex));
             assert(sub_resource_state_matches(res, zookeeper)(s));
         }
+        assert(zk_ensemble_matches(zookeeper)(s));
     }
-    temp_pred_equality(tla_forall(|res: SubResource| lift_state(sub_resource_state_matches(res, zookeeper))), lift_state(current_state_matches::<ZookeeperMaker>(zookeeper)));
+    temp_pred_equality(tla_forall(a_to_p).and(lift_state(zk_ensemble_matches(zookeeper))), lift_state(current_state_matches::<ZookeeperMaker>(zookeeper)));
+}
+
+// The ensemble is reconfigured every time the reconciler goes through the reconfig step, and once the stateful set matches
+// (so it always exists and its uid no longer changes) the reconfig always hits the ensemble of the current stateful set.
+proof fn lemma_true_leads_to_always_ensemble_matches(zookeeper: ZookeeperClusterView)
+    requires assumption_and_invariants_of_all_phases(zookeeper).entails(true_pred().leads_to(always(lift_state(sub_resource_state_matches(SubResource::StatefulSet, zookeeper))))),
+    ensures assumption_and_invariants_of_all_phases(zookeeper).entails(true_pred().leads_to(always(lift_state(zk_ensemble_matches(zookeeper))))),
+{
+    let spec1 = assumption_and_invariants_of_all_phases(zookeeper);
+    let sts_matches = lift_state(sub_resource_state_matches(SubResource::StatefulSet, zookeeper));
+    let spec = spec1.and(always(sts_matches));
+    always_tla_forall_apply_for_sub_resource(spec, SubResource::ConfigMap, zookeeper);
+    always_tla_forall_apply_for_sub_resource(spec, SubResource::StatefulSet, zookeeper);
+
+    assert_by(valid(stable(spec)), {
+        assumption_and_invariants_of_all_phases_is_stable(zookeeper);
+        always_p_is_stable(sts_matches);
+        stable_and_n!(spec1, always(sts_matches));
+    });
+
+    assert_by(spec.entails(true_pred().leads_to(always(lift_state(zk_ensemble_matches(zookeeper))))), {
+        // We first prove true ~> pending_req_in_flight_at_after_exists_stateful_set_step(zookeeper)
+        terminate::reconcile_eventually_terminates(spec, zookeeper);
+        lemma_from_reconcile_idle_to_scheduled(spec, zookeeper);
+        lemma_from_scheduled_to_init_step(spec, zookeeper);
+        lemma_from_init_step_to_after_create_headless_service_step(spec, zookeeper);
+        assert forall |sub_resource: SubResource| sub_resource != SubResource::StatefulSet && sub_resource != SubResource::ConfigMap implies
+        spec.entails(
+            lift_state(#[trigger] pending_req_in_flight_at_after_get_resource_step(sub_resource, zookeeper))
+                .leads_to(lift_state(pending_req_in_flight_at_after_get_resource_step(next_resource_after(sub_resource).get_AfterKRequestStep_1(), zookeeper)))
+        ) by {
+            always_tla_forall_apply_for_sub_resource(spec, sub_resource, zookeeper);
+            lemma_from_after_get_resource_step_to_resource_matches(spec, zookeeper, sub_resource);
+        }
+        lemma_from_after_get_resource_step_to_resource_matches(spec, zookeeper, SubResource::ConfigMap);
+
+        // The stateful set always exists, so the reconciler goes through the reconfig step
+        let key_exists_and_after_exists_stateful_set_step = lift_state(|s: ZKCluster| {
+            &&& s.resources().contains_key(get_request(SubResource::StatefulSet, zookeeper).key)
+            &&& pending_req_in_flight_at_after_exists_stateful_set_step(zookeeper)(s)
+        });
+        assert_by(spec.entails(lift_state(pending_req_in_flight_at_after_exists_stateful_set_step(zookeeper)).leads_to(key_exists_and_after_exists_stateful_set_step)), {
+            assert forall |ex| #[trigger] lift_state(pending_req_in_flight_at_after_exists_stateful_set_step(zookeeper)).and(sts_matches).satisfied_by(ex)
+            implies key_exists_and_after_exists_stateful_set_step.satisfied_by(ex) by {
+                assert(sub_resource_state_matches(SubResource::StatefulSet, zookeeper)(ex.head()));
+            }
+            entails_implies_leads_to(spec, lift_state(pending_req_in_flight_at_after_exists_stateful_set_step(zookeeper)).and(sts_matches), key_exists_and_after_exists_stateful_set_step);
+            leads_to_by_borrowing_inv(spec, lift_state(pending_req_in_flight_at_after_exists_stateful_set_step(zookeeper)), key_exists_and_after_exists_stateful_set_step, sts_matches);
+        });
+        lemma_from_after_exists_stateful_set_step_and_key_exists_to_after_reconfig_zk_step(spec, zookeeper);
+        lemma_from_after_reconfig_zk_step_pending_to_ensemble_matches(spec, zookeeper);
+        leads_to_trans_n!(
+            spec, true_pred(), lift_state(|s: ZKCluster| { !s.ongoing_reconciles().contains_key(zookeeper.object_ref()) }),
+            lift_state(|s: ZKCluster| { !s.ongoing_reconciles().contains_key(zookeeper.object_ref()) && s.scheduled_reconciles().contains_key(zookeeper.object_ref())}),
+            lift_state(no_pending_req_at_zookeeper_step_with_zookeeper(zookeeper, ZookeeperReconcileStep::Init)),
+            lift_state(pending_req_in_flight_at_after_get_resource_step(SubResource::HeadlessService, zookeeper)),
+            lift_state(pending_req_in_flight_at_after_get_resource_step(SubResource::ClientService, zookeeper)),
+            lift_state(pending_req_in_flight_at_after_get_resource_step(SubResource::AdminServerService, zookeeper)),
+            lift_state(pending_req_in_flight_at_after_get_resource_step(SubResource::PodDisruptionBudget, zookeeper)),
+            lift_state(pending_req_in_flight_at_after_get_resource_step(SubResource::ConfigMap, zookeeper)),
+            lift_state(pending_req_in_flight_at_after_exists_stateful_set_step(zookeeper)),
+            key_exists_and_after_exists_stateful_set_step,
+            lift_state(|s: ZKCluster| {
+                &&& s.resources().contains_key(get_request(SubResource::StatefulSet, zookeeper).key)
+                &&& pending_req_in_flight_at_after_reconfig_zk_step(zookeeper)(s)
+            }),
+            lift_state(zk_ensemble_matches(zookeeper))
+        );
+
+        // Finally we prove stability
+        lemma_ensemble_is_stable(spec, zookeeper, true_pred());
+    });
+
+    assert_by(spec1.entails(true_pred().leads_to(always(lift_state(zk_ensemble_matches(zookeeper))))), {
+        unpack_conditions_from_spec(spec1, always(sts_matches), true_pred(), always(lift_state(zk_ensemble_matches(zookeeper))));
+        temp_pred_equality(always(sts_matches), true_pred().and(always(sts_matches)));
+        leads_to_trans(spec1, true_pred(), always(sts_matches), always(lift_state(zk_ensemble_matches(zookeeper))));
+    });
 }
 
 proof fn lemma_true_leads_to_always_state_matches_for_all_resources(zookeeper: ZookeeperClusterView)
@@ -161,6 +247,7 @@ proof fn lemma_true_leads_to_always_state_matches_for_all_but_stateful_set(zooke
         lift_state(pending_req_in_flight_at_after_get_resource_step(SubResource::HeadlessService, zookeeper)),
         lift_state(pending_req_in_flight_at_after_get_resource_step(SubResource::ClientService, zookeeper)),
         lift_state(pending_req_in_flight_at_after_get_resource_step(SubResource::AdminServerService, zookeeper)),
+        lift_state(pending_req_in_flight_at_after_get_resource_step(SubResource::PodDisruptionBudget, zookeeper)),
         lift_state(pending_req_in_flight_at_after_get_resource_step(SubResource::ConfigMap, zookeeper))
     );
 
@@ -244,6 +331,7 @@ proof fn lemma_true_leads_to_always_state_matches_for_stateful_set(zookeeper: Zo
             lift_state(pending_req_in_flight_at_after_get_resource_step(SubResource::HeadlessService, zookeeper)),
             lift_state(pending_req_in_flight_at_after_get_resource_step(SubResource::ClientService, zookeeper)),
             lift_state(pending_req_in_flight_at_after_get_resource_step(SubResource::AdminServerService, zookeeper)),
+            lift_state(pending_req_in_flight_at_after_get_resource_step(SubResource::PodDisruptionBudget, zookeeper)),
             lift_state(pending_req_in_flight_at_after_get_resource_step(SubResource::ConfigMap, zookeeper)),
             lift_state(pending_req_in_flight_at_after_exists_stateful_set_step(zookeeper)),
             lift_state(pending_req_in_flight_at_after_get_resource_step(SubResource::StatefulSet, zookeeper))
@@ -680,6 +680,7 @@ proof fn lemma_resource_state_matches_at_after_create_resource_step(
             SubResource::HeadlessService => ServiceView::marshal_preserves_integrity(),
             SubResource::ClientService => ServiceView::marshal_preserves_integrity(),
             SubResource::AdminServerService => ServiceView::marshal_preserves_integrity(),
+            SubResource::PodDisruptionBudget => PodDisruptionBudgetView::marshal_preserves_integrity(),
             SubResource::ConfigMap => ConfigMapView::marshal_preserves_integrity(),
             SubResource::StatefulSet => StatefulSetView::marshal_preserves_integrity(),
         }
@@ -854,6 +855,7 @@ proof fn lemma_resource_state_matches_at_after_update_resource_step(spec: TempPr
             SubResource::HeadlessService => ServiceView::marshal_preserves_integrity(),
             SubResource::ClientService => ServiceView::marshal_preserves_integrity(),
             SubResource::AdminServerService => ServiceView::marshal_preserves_integrity(),
+            SubResource::PodDisruptionBudget => PodDisruptionBudgetView::marshal_preserves_integrity(),
             SubResource::ConfigMap => ConfigMapView::marshal_preserves_integrity(),
             _ => {}
         }
@@ -1000,6 +1002,7 @@ pub proof fn lemma_resource_object_is_stable(spec: TempPred<ZKCluster>, sub_reso
             SubResource::HeadlessService => ServiceView::marshal_preserves_integrity(),
             SubResource::ClientService => ServiceView::marshal_preserves_integrity(),
             SubResource::AdminServerService => ServiceView::marshal_preserves_integrity(),
+            SubResource::PodDisruptionBudget => PodDisruptionBudgetView::marshal_preserves_integrity(),
             SubResource::ConfigMap => ConfigMapView::marshal_preserves_integrity(),
             _ => {}
         }
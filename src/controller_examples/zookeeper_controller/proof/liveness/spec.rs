@@ -98,10 +98,12 @@ pub proof fn spec_of_previous_phases_entails_eventually_new_invariants(i: nat, z
             let a_to_p_2 = |sub_resource: SubResource| lift_state(helper_invariants::object_in_every_resource_update_request_only_has_owner_references_pointing_to_current_cr(sub_resource, zookeeper));
             helper_invariants::lemma_eventually_always_every_zk_set_data_request_implies_at_after_update_zk_node_step(spec, zookeeper);
             helper_invariants::lemma_eventually_always_every_zk_create_node_request_implies_at_after_create_zk_node_step(spec, zookeeper);
+            helper_invariants::lemma_eventually_always_every_zk_reconfig_request_is_made_from_zookeeper(spec, zookeeper);
             leads_to_always_combine_n!(
                 spec, true_pred(), tla_forall(a_to_p_1), tla_forall(a_to_p_2),
                 lift_state(helper_invariants::every_zk_set_data_request_implies_at_after_update_zk_node_step(zookeeper)),
-                lift_state(helper_invariants::every_zk_create_node_request_implies_at_after_create_zk_node_step(zookeeper))
+                lift_state(helper_invariants::every_zk_create_node_request_implies_at_after_create_zk_node_step(zookeeper)),
+                lift_state(helper_invariants::every_zk_reconfig_request_is_made_from_zookeeper(zookeeper))
             );
         } else if i == 4 {
             helper_invariants::lemma_eventually_always_resource_object_only_has_owner_reference_pointing_to_current_cr_forall(spec, zookeeper);
@@ -218,7 +220,9 @@ pub open spec fn derived_invariants_since_beginning(zookeeper: ZookeeperClusterV
     .and(always(lift_state(ZKCluster::pending_req_in_flight_or_resp_in_flight_at_reconcile_state(zookeeper.object_ref(), at_step_closure(ZookeeperReconcileStep::AfterCreateZKParentNode)))))
     .and(always(lift_state(ZKCluster::pending_req_in_flight_or_resp_in_flight_at_reconcile_state(zookeeper.object_ref(), at_step_closure(ZookeeperReconcileStep::AfterCreateZKNode)))))
     .and(always(lift_state(ZKCluster::pending_req_in_flight_or_resp_in_flight_at_reconcile_state(zookeeper.object_ref(), at_step_closure(ZookeeperReconcileStep::AfterUpdateZKNode)))))
+    .and(always(lift_state(ZKCluster::pending_req_in_flight_or_resp_in_flight_at_reconcile_state(zookeeper.object_ref(), at_step_closure(ZookeeperReconcileStep::AfterReconfigZK)))))
     .and(always(lift_state(ZKCluster::pending_req_in_flight_or_resp_in_flight_at_reconcile_state(zookeeper.object_ref(), at_step_closure(ZookeeperReconcileStep::AfterUpdateStatus)))))
+    .and(always(lift_state(ZKCluster::pending_req_in_flight_or_resp_in_flight_at_reconcile_state(zookeeper.object_ref(), at_step_closure(ZookeeperReconcileStep::AfterUpdateDegradedStatus)))))
     .and(always(tla_forall(|step: (ActionKind, SubResource)| lift_state(ZKCluster::pending_req_in_flight_or_resp_in_flight_at_reconcile_state(zookeeper.object_ref(), at_step_closure(ZookeeperReconcileStep::AfterKRequestStep(step.0, step.1)))))))
     .and(always(tla_forall(|res: SubResource| lift_state(helper_invariants::no_update_status_request_msg_in_flight_of_except_stateful_set(res, zookeeper)))))
     .and(always(lift_state(helper_invariants::no_update_status_request_msg_not_from_bc_in_flight_of_stateful_set(zookeeper))))
@@ -259,7 +263,9 @@ pub proof fn derived_invariants_since_beginning_is_stable(zookeeper: ZookeeperCl
         lift_state(ZKCluster::pending_req_in_flight_or_resp_in_flight_at_reconcile_state(zookeeper.object_ref(), at_step_closure(ZookeeperReconcileStep::AfterCreateZKParentNode))),
         lift_state(ZKCluster::pending_req_in_flight_or_resp_in_flight_at_reconcile_state(zookeeper.object_ref(), at_step_closure(ZookeeperReconcileStep::AfterCreateZKNode))),
         lift_state(ZKCluster::pending_req_in_flight_or_resp_in_flight_at_reconcile_state(zookeeper.object_ref(), at_step_closure(ZookeeperReconcileStep::AfterUpdateZKNode))),
+        lift_state(ZKCluster::pending_req_in_flight_or_resp_in_flight_at_reconcile_state(zookeeper.object_ref(), at_step_closure(ZookeeperReconcileStep::AfterReconfigZK))),
         lift_state(ZKCluster::pending_req_in_flight_or_resp_in_flight_at_reconcile_state(zookeeper.object_ref(), at_step_closure(ZookeeperReconcileStep::AfterUpdateStatus))),
+        lift_state(ZKCluster::pending_req_in_flight_or_resp_in_flight_at_reconcile_state(zookeeper.object_ref(), at_step_closure(ZookeeperReconcileStep::AfterUpdateDegradedStatus))),
         tla_forall(a_to_p_2),
         tla_forall(a_to_p_3),
         lift_state(helper_invariants::no_update_status_request_msg_not_from_bc_in_flight_of_stateful_set(zookeeper)),
@@ -316,6 +322,7 @@ pub open spec fn invariants_since_phase_iii(zookeeper: ZookeeperClusterView) ->
     .and(always(tla_forall(|sub_resource: SubResource| lift_state(helper_invariants::object_in_every_resource_update_request_only_has_owner_references_pointing_to_current_cr(sub_resource, zookeeper)))))
     .and(always(lift_state(helper_invariants::every_zk_set_data_request_implies_at_after_update_zk_node_step(zookeeper))))
     .and(always(lift_state(helper_invariants::every_zk_create_node_request_implies_at_after_create_zk_node_step(zookeeper))))
+    .and(always(lift_state(helper_invariants::every_zk_reconfig_request_is_made_from_zookeeper(zookeeper))))
 }
 
 pub proof fn invariants_since_phase_iii_is_stable(zookeeper: ZookeeperClusterView)
@@ -325,7 +332,8 @@ pub proof fn invariants_since_phase_iii_is_stable(zookeeper: ZookeeperClusterVie
     let a_to_p_2 = |sub_resource: SubResource| lift_state(helper_invariants::object_in_every_resource_update_request_only_has_owner_references_pointing_to_current_cr(sub_resource, zookeeper));
     stable_and_always_n!(tla_forall(a_to_p_1), tla_forall(a_to_p_2),
         lift_state(helper_invariants::every_zk_set_data_request_implies_at_after_update_zk_node_step(zookeeper)),
-        lift_state(helper_invariants::every_zk_create_node_request_implies_at_after_create_zk_node_step(zookeeper))
+        lift_state(helper_invariants::every_zk_create_node_request_implies_at_after_create_zk_node_step(zookeeper)),
+        lift_state(helper_invariants::every_zk_reconfig_request_is_made_from_zookeeper(zookeeper))
     );
 }
 
@@ -456,6 +464,16 @@ pub proof fn lemma_always_for_after_update_zk_node_step_pending_req_in_flight_or
     ZKCluster::lemma_always_pending_req_in_flight_or_resp_in_flight_at_reconcile_state(spec, zookeeper.object_ref(), at_step_closure(ZookeeperReconcileStep::AfterUpdateZKNode));
 }
 
+pub proof fn lemma_always_for_after_reconfig_zk_step_pending_req_in_flight_or_resp_in_flight_at_reconcile_state(spec: TempPred<ZKCluster>, zookeeper: ZookeeperClusterView)
+    requires
+        spec.entails(lift_state(ZKCluster::init())),
+        spec.entails(always(lift_action(ZKCluster::next()))),
+        spec.entails(always(lift_state(ZKCluster::pending_req_of_key_is_unique_with_unique_id(zookeeper.object_ref())))),
+    ensures spec.entails(always(lift_state(ZKCluster::pending_req_in_flight_or_resp_in_flight_at_reconcile_state(zookeeper.object_ref(), at_step_closure(ZookeeperReconcileStep::AfterReconfigZK))))),
+{
+    ZKCluster::lemma_always_pending_req_in_flight_or_resp_in_flight_at_reconcile_state(spec, zookeeper.object_ref(), at_step_closure(ZookeeperReconcileStep::AfterReconfigZK));
+}
+
 pub proof fn lemma_always_for_after_update_status_step_pending_req_in_flight_or_resp_in_flight_at_reconcile_state(spec: TempPred<ZKCluster>, zookeeper: ZookeeperClusterView)
     requires
         spec.entails(lift_state(ZKCluster::init())),
@@ -466,6 +484,16 @@ pub proof fn lemma_always_for_after_update_status_step_pending_req_in_flight_or_
     ZKCluster::lemma_always_pending_req_in_flight_or_resp_in_flight_at_reconcile_state(spec, zookeeper.object_ref(), at_step_closure(ZookeeperReconcileStep::AfterUpdateStatus));
 }
 
+pub proof fn lemma_always_for_after_update_degraded_status_step_pending_req_in_flight_or_resp_in_flight_at_reconcile_state(spec: TempPred<ZKCluster>, zookeeper: ZookeeperClusterView)
+    requires
+        spec.entails(lift_state(ZKCluster::init())),
+        spec.entails(always(lift_action(ZKCluster::next()))),
+        spec.entails(always(lift_state(ZKCluster::pending_req_of_key_is_unique_with_unique_id(zookeeper.object_ref())))),
+    ensures spec.entails(always(lift_state(ZKCluster::pending_req_in_flight_or_resp_in_flight_at_reconcile_state(zookeeper.object_ref(), at_step_closure(ZookeeperReconcileStep::AfterUpdateDegradedStatus))))),
+{
+    ZKCluster::lemma_always_pending_req_in_flight_or_resp_in_flight_at_reconcile_state(spec, zookeeper.object_ref(), at_step_closure(ZookeeperReconcileStep::AfterUpdateDegradedStatus));
+}
+
 pub proof fn sm_spec_entails_all_invariants(zookeeper: ZookeeperClusterView)
     ensures cluster_spec().entails(derived_invariants_since_beginning(zookeeper)),
 {
@@ -502,7 +530,9 @@ pub proof fn sm_spec_entails_all_invariants(zookeeper: ZookeeperClusterView)
     lemma_always_for_after_create_zk_parent_node_step_pending_req_in_flight_or_resp_in_flight_at_reconcile_state(spec, zookeeper);
     lemma_always_for_after_create_zk_node_step_pending_req_in_flight_or_resp_in_flight_at_reconcile_state(spec, zookeeper);
     lemma_always_for_after_update_zk_node_step_pending_req_in_flight_or_resp_in_flight_at_reconcile_state(spec, zookeeper);
+    lemma_always_for_after_reconfig_zk_step_pending_req_in_flight_or_resp_in_flight_at_reconcile_state(spec, zookeeper);
     lemma_always_for_after_update_status_step_pending_req_in_flight_or_resp_in_flight_at_reconcile_state(spec, zookeeper);
+    lemma_always_for_after_update_degraded_status_step_pending_req_in_flight_or_resp_in_flight_at_reconcile_state(spec, zookeeper);
 
     let a_to_p_3 = |res: SubResource| lift_state(helper_invariants::no_update_status_request_msg_in_flight_of_except_stateful_set(res, zookeeper));
     assert_by(spec.entails(always(tla_forall(a_to_p_3))), {
@@ -563,7 +593,9 @@ pub proof fn sm_spec_entails_all_invariants(zookeeper: ZookeeperClusterView)
         lift_state(ZKCluster::pending_req_in_flight_or_resp_in_flight_at_reconcile_state(zookeeper.object_ref(), at_step_closure(ZookeeperReconcileStep::AfterCreateZKParentNode))),
         lift_state(ZKCluster::pending_req_in_flight_or_resp_in_flight_at_reconcile_state(zookeeper.object_ref(), at_step_closure(ZookeeperReconcileStep::AfterCreateZKNode))),
         lift_state(ZKCluster::pending_req_in_flight_or_resp_in_flight_at_reconcile_state(zookeeper.object_ref(), at_step_closure(ZookeeperReconcileStep::AfterUpdateZKNode))),
+        lift_state(ZKCluster::pending_req_in_flight_or_resp_in_flight_at_reconcile_state(zookeeper.object_ref(), at_step_closure(ZookeeperReconcileStep::AfterReconfigZK))),
         lift_state(ZKCluster::pending_req_in_flight_or_resp_in_flight_at_reconcile_state(zookeeper.object_ref(), at_step_closure(ZookeeperReconcileStep::AfterUpdateStatus))),
+        lift_state(ZKCluster::pending_req_in_flight_or_resp_in_flight_at_reconcile_state(zookeeper.object_ref(), at_step_closure(ZookeeperReconcileStep::AfterUpdateDegradedStatus))),
         tla_forall(a_to_p_2),
         tla_forall(a_to_p_3),
         lift_state(helper_invariants::no_update_status_request_msg_not_from_bc_in_flight_of_stateful_set(zookeeper)),
@@ -37,7 +37,9 @@ pub proof fn reconcile_eventually_terminates(spec: TempPred<ZKCluster>, zookeepe
         spec.entails(always(lift_state(ZKCluster::pending_req_in_flight_or_resp_in_flight_at_reconcile_state(zookeeper.object_ref(), at_step_closure(ZookeeperReconcileStep::AfterCreateZKParentNode))))),
         spec.entails(always(lift_state(ZKCluster::pending_req_in_flight_or_resp_in_flight_at_reconcile_state(zookeeper.object_ref(), at_step_closure(ZookeeperReconcileStep::AfterCreateZKNode))))),
         spec.entails(always(lift_state(ZKCluster::pending_req_in_flight_or_resp_in_flight_at_reconcile_state(zookeeper.object_ref(), at_step_closure(ZookeeperReconcileStep::AfterUpdateZKNode))))),
+        spec.entails(always(lift_state(ZKCluster::pending_req_in_flight_or_resp_in_flight_at_reconcile_state(zookeeper.object_ref(), at_step_closure(ZookeeperReconcileStep::AfterReconfigZK))))),
         spec.entails(always(lift_state(ZKCluster::pending_req_in_flight_or_resp_in_flight_at_reconcile_state(zookeeper.object_ref(), at_step_closure(ZookeeperReconcileStep::AfterUpdateStatus))))),
+        spec.entails(always(lift_state(ZKCluster::pending_req_in_flight_or_resp_in_flight_at_reconcile_state(zookeeper.object_ref(), at_step_closure(ZookeeperReconcileStep::AfterUpdateDegradedStatus))))),
         spec.entails(always(tla_forall(|step: (ActionKind, SubResource)| lift_state(ZKCluster::pending_req_in_flight_or_resp_in_flight_at_reconcile_state(
             zookeeper.object_ref(), at_step_closure(ZookeeperReconcileStep::AfterKRequestStep(step.0, step.1))
         ))))),
@@ -72,60 +74,69 @@ pub proof fn reconcile_eventually_terminates(spec: TempPred<ZKCluster>, zookeepe
         spec, zookeeper, at_step_closure(ZookeeperReconcileStep::AfterUpdateStatus),
         at_step1_or_step2_closure(ZookeeperReconcileStep::Done, ZookeeperReconcileStep::Error)
     );
+    // Reporting the degraded status always ends the reconcile in error.
+    ZKCluster::lemma_from_some_state_to_arbitrary_next_state_to_reconcile_idle(
+        spec, zookeeper, at_step_closure(ZookeeperReconcileStep::AfterUpdateDegradedStatus), at_step_closure(ZookeeperReconcileStep::Error)
+    );
     lemma_from_after_get_resource_step_to_after_get_next_resource_step_to_reconcile_idle(spec, zookeeper, SubResource::StatefulSet, ZookeeperReconcileStep::AfterUpdateStatus);
 
     or_leads_to_combine_and_equality!(spec,
-        lift_state(at_step1_or_step2_state_pred(zookeeper, after_get_k_request_step(SubResource::StatefulSet), ZookeeperReconcileStep::Error)),
-        lift_state(at_step_state_pred(zookeeper, after_get_k_request_step(SubResource::StatefulSet))), lift_state(at_step_state_pred(zookeeper, ZookeeperReconcileStep::Error));
+        lift_state(at_step1_or_step2_state_pred(zookeeper, after_get_k_request_step(SubResource::StatefulSet), ZookeeperReconcileStep::AfterUpdateDegradedStatus)),
+        lift_state(at_step_state_pred(zookeeper, after_get_k_request_step(SubResource::StatefulSet))), lift_state(at_step_state_pred(zookeeper, ZookeeperReconcileStep::AfterUpdateDegradedStatus));
         lift_state(reconcile_idle)
     );
     ZKCluster::lemma_from_some_state_to_arbitrary_next_state_to_reconcile_idle(
-        spec, zookeeper, at_step_closure(ZookeeperReconcileStep::AfterUpdateZKNode),
-        at_step1_or_step2_closure(after_get_k_request_step(SubResource::StatefulSet), ZookeeperReconcileStep::Error)
+        spec, zookeeper, at_step_closure(ZookeeperReconcileStep::AfterReconfigZK),
+        at_step1_or_step2_closure(after_get_k_request_step(SubResource::StatefulSet), ZookeeperReconcileStep::AfterUpdateDegradedStatus)
     );
 
     or_leads_to_combine_and_equality!(spec,
-        lift_state(at_step1_or_step2_state_pred(zookeeper, after_get_k_request_step(SubResource::StatefulSet), ZookeeperReconcileStep::Error)),
-        lift_state(at_step_state_pred(zookeeper, after_get_k_request_step(SubResource::StatefulSet))), lift_state(at_step_state_pred(zookeeper, ZookeeperReconcileStep::Error));
+        lift_state(at_step1_or_step2_state_pred(zookeeper, ZookeeperReconcileStep::AfterReconfigZK, ZookeeperReconcileStep::AfterUpdateDegradedStatus)),
+        lift_state(at_step_state_pred(zookeeper, ZookeeperReconcileStep::AfterReconfigZK)), lift_state(at_step_state_pred(zookeeper, ZookeeperReconcileStep::AfterUpdateDegradedStatus));
         lift_state(reconcile_idle)
     );
+    ZKCluster::lemma_from_some_state_to_arbitrary_next_state_to_reconcile_idle(
+        spec, zookeeper, at_step_closure(ZookeeperReconcileStep::AfterUpdateZKNode),
+        at_step1_or_step2_closure(ZookeeperReconcileStep::AfterReconfigZK, ZookeeperReconcileStep::AfterUpdateDegradedStatus)
+    );
     ZKCluster::lemma_from_some_state_to_arbitrary_next_state_to_reconcile_idle(
         spec, zookeeper, at_step_closure(ZookeeperReconcileStep::AfterCreateZKNode),
-        at_step1_or_step2_closure(after_get_k_request_step(SubResource::StatefulSet), ZookeeperReconcileStep::Error)
+        at_step1_or_step2_closure(ZookeeperReconcileStep::AfterReconfigZK, ZookeeperReconcileStep::AfterUpdateDegradedStatus)
     );
 
     or_leads_to_combine_and_equality!(spec,
-        lift_state(at_step1_or_step2_state_pred(zookeeper, ZookeeperReconcileStep::AfterCreateZKNode, ZookeeperReconcileStep::Error)),
-        lift_state(at_step_state_pred(zookeeper, ZookeeperReconcileStep::AfterCreateZKNode)), lift_state(at_step_state_pred(zookeeper, ZookeeperReconcileStep::Error));
+        lift_state(at_step1_or_step2_state_pred(zookeeper, ZookeeperReconcileStep::AfterCreateZKNode, ZookeeperReconcileStep::AfterUpdateDegradedStatus)),
+        lift_state(at_step_state_pred(zookeeper, ZookeeperReconcileStep::AfterCreateZKNode)), lift_state(at_step_state_pred(zookeeper, ZookeeperReconcileStep::AfterUpdateDegradedStatus));
         lift_state(reconcile_idle)
     );
     ZKCluster::lemma_from_some_state_to_arbitrary_next_state_to_reconcile_idle(
         spec, zookeeper, at_step_closure(ZookeeperReconcileStep::AfterCreateZKParentNode),
-        at_step1_or_step2_closure(ZookeeperReconcileStep::AfterCreateZKNode, ZookeeperReconcileStep::Error)
+        at_step1_or_step2_closure(ZookeeperReconcileStep::AfterCreateZKNode, ZookeeperReconcileStep::AfterUpdateDegradedStatus)
     );
 
     or_leads_to_combine_and_equality!(spec,
-        lift_state(at_step1_or_step2_or_step3_state_pred(zookeeper, ZookeeperReconcileStep::AfterUpdateZKNode, ZookeeperReconcileStep::AfterCreateZKParentNode, ZookeeperReconcileStep::Error)),
-        lift_state(at_step_state_pred(zookeeper, ZookeeperReconcileStep::AfterUpdateZKNode)), lift_state(at_step_state_pred(zookeeper, ZookeeperReconcileStep::AfterCreateZKParentNode)), lift_state(at_step_state_pred(zookeeper, ZookeeperReconcileStep::Error));
+        lift_state(at_step1_or_step2_or_step3_state_pred(zookeeper, ZookeeperReconcileStep::AfterUpdateZKNode, ZookeeperReconcileStep::AfterCreateZKParentNode, ZookeeperReconcileStep::AfterUpdateDegradedStatus)),
+        lift_state(at_step_state_pred(zookeeper, ZookeeperReconcileStep::AfterUpdateZKNode)), lift_state(at_step_state_pred(zookeeper, ZookeeperReconcileStep::AfterCreateZKParentNode)), lift_state(at_step_state_pred(zookeeper, ZookeeperReconcileStep::AfterUpdateDegradedStatus));
         lift_state(reconcile_idle)
     );
     ZKCluster::lemma_from_some_state_to_arbitrary_next_state_to_reconcile_idle(
         spec, zookeeper, at_step_closure(ZookeeperReconcileStep::AfterExistsZKNode),
-        at_step1_or_step2_or_step3_closure(ZookeeperReconcileStep::AfterUpdateZKNode, ZookeeperReconcileStep::AfterCreateZKParentNode, ZookeeperReconcileStep::Error)
+        at_step1_or_step2_or_step3_closure(ZookeeperReconcileStep::AfterUpdateZKNode, ZookeeperReconcileStep::AfterCreateZKParentNode, ZookeeperReconcileStep::AfterUpdateDegradedStatus)
     );
 
     or_leads_to_combine_and_equality!(spec,
-        lift_state(at_step1_or_step2_or_step3_state_pred(zookeeper, ZookeeperReconcileStep::AfterExistsZKNode, after_get_k_request_step(SubResource::StatefulSet), ZookeeperReconcileStep::Error)),
-        lift_state(at_step_state_pred(zookeeper, ZookeeperReconcileStep::AfterExistsZKNode)), lift_state(at_step_state_pred(zookeeper, after_get_k_request_step(SubResource::StatefulSet))), lift_state(at_step_state_pred(zookeeper, ZookeeperReconcileStep::Error));
+        lift_state(at_step1_or_step2_or_step3_state_pred(zookeeper, ZookeeperReconcileStep::AfterExistsZKNode, after_get_k_request_step(SubResource::StatefulSet), ZookeeperReconcileStep::AfterUpdateDegradedStatus)),
+        lift_state(at_step_state_pred(zookeeper, ZookeeperReconcileStep::AfterExistsZKNode)), lift_state(at_step_state_pred(zookeeper, after_get_k_request_step(SubResource::StatefulSet))), lift_state(at_step_state_pred(zookeeper, ZookeeperReconcileStep::AfterUpdateDegradedStatus));
         lift_state(reconcile_idle)
     );
     ZKCluster::lemma_from_some_state_to_arbitrary_next_state_to_reconcile_idle(
         spec, zookeeper, at_step_closure(ZookeeperReconcileStep::AfterExistsStatefulSet),
-        at_step1_or_step2_or_step3_closure(ZookeeperReconcileStep::AfterExistsZKNode, after_get_k_request_step(SubResource::StatefulSet), ZookeeperReconcileStep::Error)
+        at_step1_or_step2_or_step3_closure(ZookeeperReconcileStep::AfterExistsZKNode, after_get_k_request_step(SubResource::StatefulSet), ZookeeperReconcileStep::AfterUpdateDegradedStatus)
     );
 
     lemma_from_after_get_resource_step_to_after_get_next_resource_step_to_reconcile_idle(spec, zookeeper, SubResource::ConfigMap, ZookeeperReconcileStep::AfterExistsStatefulSet);
-    lemma_from_after_get_resource_step_to_after_get_next_resource_step_to_reconcile_idle(spec, zookeeper, SubResource::AdminServerService, after_get_k_request_step(SubResource::ConfigMap));
+    lemma_from_after_get_resource_step_to_after_get_next_resource_step_to_reconcile_idle(spec, zookeeper, SubResource::PodDisruptionBudget, after_get_k_request_step(SubResource::ConfigMap));
+    lemma_from_after_get_resource_step_to_after_get_next_resource_step_to_reconcile_idle(spec, zookeeper, SubResource::AdminServerService, after_get_k_request_step(SubResource::PodDisruptionBudget));
     lemma_from_after_get_resource_step_to_after_get_next_resource_step_to_reconcile_idle(spec, zookeeper, SubResource::ClientService, after_get_k_request_step(SubResource::AdminServerService));
     lemma_from_after_get_resource_step_to_after_get_next_resource_step_to_reconcile_idle(spec, zookeeper, SubResource::HeadlessService, after_get_k_request_step(SubResource::ClientService));
 
@@ -140,14 +151,17 @@ pub proof fn reconcile_eventually_terminates(spec: TempPred<ZKCluster>, zookeepe
         lift_state(state_pred_regarding_sub_resource(zookeeper, SubResource::HeadlessService)),
         lift_state(state_pred_regarding_sub_resource(zookeeper, SubResource::ClientService)),
         lift_state(state_pred_regarding_sub_resource(zookeeper, SubResource::AdminServerService)),
+        lift_state(state_pred_regarding_sub_resource(zookeeper, SubResource::PodDisruptionBudget)),
         lift_state(state_pred_regarding_sub_resource(zookeeper, SubResource::ConfigMap)),
         lift_state(at_step_state_pred(zookeeper, ZookeeperReconcileStep::AfterExistsStatefulSet)),
         lift_state(at_step_state_pred(zookeeper, ZookeeperReconcileStep::AfterExistsZKNode)),
         lift_state(at_step_state_pred(zookeeper, ZookeeperReconcileStep::AfterCreateZKParentNode)),
         lift_state(at_step_state_pred(zookeeper, ZookeeperReconcileStep::AfterCreateZKNode)),
         lift_state(at_step_state_pred(zookeeper, ZookeeperReconcileStep::AfterUpdateZKNode)),
+        lift_state(at_step_state_pred(zookeeper, ZookeeperReconcileStep::AfterReconfigZK)),
         lift_state(state_pred_regarding_sub_resource(zookeeper, SubResource::StatefulSet)),
         lift_state(at_step_state_pred(zookeeper, ZookeeperReconcileStep::AfterUpdateStatus)),
+        lift_state(at_step_state_pred(zookeeper, ZookeeperReconcileStep::AfterUpdateDegradedStatus)),
         lift_state(at_step_state_pred(zookeeper, ZookeeperReconcileStep::Done)),
         lift_state(at_step_state_pred(zookeeper, ZookeeperReconcileStep::Error));
         lift_state(reconcile_idle)
@@ -204,7 +218,7 @@ proof fn lemma_from_after_get_resource_step_to_after_get_next_resource_step_to_r
         next_resource_after(sub_resource) == next_step,
         spec.entails(lift_state(at_step_state_pred(zookeeper, next_step))
             .leads_to(lift_state(|s: ZKCluster| !s.ongoing_reconciles().contains_key(zookeeper.object_ref())))),
-        spec.entails(lift_state(at_step_state_pred(zookeeper, ZookeeperReconcileStep::Error))
+        spec.entails(lift_state(at_step_state_pred(zookeeper, ZookeeperReconcileStep::AfterUpdateDegradedStatus))
             .leads_to(lift_state(|s: ZKCluster| !s.ongoing_reconciles().contains_key(zookeeper.object_ref())))),
     ensures
         spec.entails(lift_state(at_step_state_pred(zookeeper, after_get_k_request_step(sub_resource)))
@@ -214,12 +228,12 @@ proof fn lemma_from_after_get_resource_step_to_after_get_next_resource_step_to_r
 {
     let state_after_create_or_update = |s: ZookeeperReconcileState| {
         s.reconcile_step == next_step
-        || s.reconcile_step == ZookeeperReconcileStep::Error
+        || s.reconcile_step == ZookeeperReconcileStep::AfterUpdateDegradedStatus
     };
     or_leads_to_combine_and_equality!(
         spec, lift_state(ZKCluster::at_expected_reconcile_states(zookeeper.object_ref(), state_after_create_or_update)),
         lift_state(at_step_state_pred(zookeeper, next_step)),
-        lift_state(at_step_state_pred(zookeeper, ZookeeperReconcileStep::Error));
+        lift_state(at_step_state_pred(zookeeper, ZookeeperReconcileStep::AfterUpdateDegradedStatus));
         lift_state(|s: ZKCluster| { !s.ongoing_reconciles().contains_key(zookeeper.object_ref()) })
     );
     ZKCluster::lemma_from_some_state_to_arbitrary_next_state_to_reconcile_idle(spec, zookeeper, at_step_closure(after_create_k_request_step(sub_resource)), state_after_create_or_update);
@@ -228,13 +242,13 @@ proof fn lemma_from_after_get_resource_step_to_after_get_next_resource_step_to_r
     let state_after_get = |s: ZookeeperReconcileState| {
         s.reconcile_step == after_create_k_request_step(sub_resource)
         || s.reconcile_step == after_update_k_request_step(sub_resource)
-        || s.reconcile_step == ZookeeperReconcileStep::Error
+        || s.reconcile_step == ZookeeperReconcileStep::AfterUpdateDegradedStatus
     };
     or_leads_to_combine_and_equality!(
         spec, lift_state(ZKCluster::at_expected_reconcile_states(zookeeper.object_ref(), state_after_get)),
         lift_state(at_step_state_pred(zookeeper, after_create_k_request_step(sub_resource))),
         lift_state(at_step_state_pred(zookeeper, after_update_k_request_step(sub_resource))),
-        lift_state(at_step_state_pred(zookeeper, ZookeeperReconcileStep::Error));
+        lift_state(at_step_state_pred(zookeeper, ZookeeperReconcileStep::AfterUpdateDegradedStatus));
         lift_state(|s: ZKCluster| { !s.ongoing_reconciles().contains_key(zookeeper.object_ref()) })
     );
     ZKCluster::lemma_from_some_state_to_arbitrary_next_state_to_reconcile_idle(spec, zookeeper, at_step_closure(after_get_k_request_step(sub_resource)), state_after_get);
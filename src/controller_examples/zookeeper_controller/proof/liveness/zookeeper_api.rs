@@ -56,7 +56,12 @@ pub proof fn lemma_from_after_exists_stateful_set_step_to_after_get_stateful_set
         ),
 {
     lemma_from_after_exists_stateful_set_step_and_key_not_exists_to_after_get_stateful_set_step(spec, zookeeper);
-    lemma_from_after_exists_stateful_set_step_and_key_exists_to_after_get_stateful_set_step(spec, zookeeper);
+    lemma_from_after_exists_stateful_set_step_and_key_exists_to_after_reconfig_zk_step(spec, zookeeper);
+    lemma_from_after_reconfig_zk_step_pending_to_after_get_stateful_set_step(spec, zookeeper);
+    let after_reconfig_zk_step_pending = lift_state(|s: ZKCluster| {
+        &&& s.resources().contains_key(get_request(SubResource::StatefulSet, zookeeper).key)
+        &&& pending_req_in_flight_at_after_reconfig_zk_step(zookeeper)(s)
+    });
     let key_not_exists = lift_state(|s: ZKCluster| {
         &&& !s.resources().contains_key(get_request(SubResource::StatefulSet, zookeeper).key)
         &&& pending_req_in_flight_at_after_exists_stateful_set_step(zookeeper)(s)
@@ -65,6 +70,7 @@ pub proof fn lemma_from_after_exists_stateful_set_step_to_after_get_stateful_set
         &&& s.resources().contains_key(get_request(SubResource::StatefulSet, zookeeper).key)
         &&& pending_req_in_flight_at_after_exists_stateful_set_step(zookeeper)(s)
     });
+    leads_to_trans(spec, key_exists, after_reconfig_zk_step_pending, lift_state(pending_req_in_flight_at_after_get_resource_step(SubResource::StatefulSet, zookeeper)));
     or_leads_to_combine(spec, key_not_exists, key_exists, lift_state(pending_req_in_flight_at_after_get_resource_step(SubResource::StatefulSet, zookeeper)));
     temp_pred_equality(key_not_exists.or(key_exists), lift_state(pending_req_in_flight_at_after_exists_stateful_set_step(zookeeper)));
 }
@@ -146,7 +152,7 @@ proof fn lemma_from_after_exists_stateful_set_step_and_key_not_exists_to_after_g
     });
 }
 
-proof fn lemma_from_after_exists_stateful_set_step_and_key_exists_to_after_get_stateful_set_step(spec: TempPred<ZKCluster>, zookeeper: ZookeeperClusterView)
+pub proof fn lemma_from_after_exists_stateful_set_step_and_key_exists_to_after_reconfig_zk_step(spec: TempPred<ZKCluster>, zookeeper: ZookeeperClusterView)
     requires
         spec.entails(always(lift_action(ZKCluster::next()))),
         spec.entails(tla_forall(|i| ZKCluster::controller_next().weak_fairness(i))),
@@ -175,14 +181,20 @@ proof fn lemma_from_after_exists_stateful_set_step_and_key_exists_to_after_get_s
             lift_state(|s: ZKCluster| {
                 &&& s.resources().contains_key(get_request(SubResource::StatefulSet, zookeeper).key)
                 &&& pending_req_in_flight_at_after_exists_stateful_set_step(zookeeper)(s)
-            }).leads_to(lift_state(pending_req_in_flight_at_after_get_resource_step(SubResource::StatefulSet, zookeeper)))
+            }).leads_to(lift_state(|s: ZKCluster| {
+                &&& s.resources().contains_key(get_request(SubResource::StatefulSet, zookeeper).key)
+                &&& pending_req_in_flight_at_after_reconfig_zk_step(zookeeper)(s)
+            }))
         ),
 {
     let pre = lift_state(|s: ZKCluster| {
         &&& s.resources().contains_key(get_request(SubResource::StatefulSet, zookeeper).key)
         &&& pending_req_in_flight_at_after_exists_stateful_set_step(zookeeper)(s)
     });
-    let post = lift_state(pending_req_in_flight_at_after_get_resource_step(SubResource::StatefulSet, zookeeper));
+    let post = lift_state(|s: ZKCluster| {
+        &&& s.resources().contains_key(get_request(SubResource::StatefulSet, zookeeper).key)
+        &&& pending_req_in_flight_at_after_reconfig_zk_step(zookeeper)(s)
+    });
 
     let after_exists_zk_node_step_pending = lift_state(|s: ZKCluster| {
         &&& s.resources().contains_key(get_request(SubResource::StatefulSet, zookeeper).key)
@@ -234,6 +246,7 @@ proof fn lemma_from_after_exists_stateful_set_step_and_key_exists_to_after_get_s
     });
 
     assert_by(spec.entails(after_exists_zk_node_step_pending.leads_to(post)) , {
+
         let addr_exists_and_after_exists_zk_node_step_pending = lift_state(|s: ZKCluster| {
             &&& s.resources().contains_key(get_request(SubResource::StatefulSet, zookeeper).key)
             &&& s.external_state().data.contains_key(zk_node_addr(s, zookeeper))
@@ -300,7 +313,10 @@ proof fn lemma_from_after_exists_stateful_set_step_and_key_exists_to_after_get_s
                 &&& s.external_state().data.contains_key(zk_node_addr(s, zookeeper))
                 &&& req_msg_is_the_in_flight_pending_req_at_after_update_zk_node_step(zookeeper, req_msg)(s)
             });
-            let after_update_zk_node_step_waiting = lift_state(at_after_update_zk_node_step_and_exists_ok_resp_in_flight(zookeeper));
+            let after_update_zk_node_step_waiting = lift_state(|s: ZKCluster| {
+                &&& s.resources().contains_key(get_request(SubResource::StatefulSet, zookeeper).key)
+                &&& at_after_update_zk_node_step_and_exists_ok_resp_in_flight(zookeeper)(s)
+            });
             assert forall |req_msg| spec.entails(#[trigger] after_update_zk_node_step_req_msg(req_msg).leads_to(after_update_zk_node_step_waiting)) by {
                 lemma_from_pending_req_to_receives_ok_resp_at_after_update_zk_node_step(spec, zookeeper, req_msg);
             }
@@ -313,9 +329,12 @@ proof fn lemma_from_after_exists_stateful_set_step_and_key_exists_to_after_get_s
                 temp_pred_equality(tla_exists(after_update_zk_node_step_req_msg), after_update_zk_node_step_pending);
             });
 
-            let after_update_zk_node_step_resp_msg = |resp_msg: ZKMessage| lift_state(resp_msg_is_the_in_flight_ok_resp_at_after_update_zk_node_step(zookeeper, resp_msg));
+            let after_update_zk_node_step_resp_msg = |resp_msg: ZKMessage| lift_state(|s: ZKCluster| {
+                &&& s.resources().contains_key(get_request(SubResource::StatefulSet, zookeeper).key)
+                &&& resp_msg_is_the_in_flight_ok_resp_at_after_update_zk_node_step(zookeeper, resp_msg)(s)
+            });
             assert forall |resp_msg| spec.entails(#[trigger] after_update_zk_node_step_resp_msg(resp_msg).leads_to(post)) by {
-                lemma_from_after_update_zk_node_step_to_after_get_stateful_set_step(spec, zookeeper, resp_msg);
+                lemma_from_after_update_zk_node_step_to_after_reconfig_zk_step(spec, zookeeper, resp_msg);
             }
             leads_to_exists_intro(spec, after_update_zk_node_step_resp_msg, post);
             assert_by(tla_exists(after_update_zk_node_step_resp_msg) == after_update_zk_node_step_waiting, {
@@ -445,7 +464,10 @@ proof fn lemma_from_after_exists_stateful_set_step_and_key_exists_to_after_get_s
                 &&& s.external_state().data.contains_key(zk_parent_node_addr(s, zookeeper))
                 &&& req_msg_is_the_in_flight_pending_req_at_after_create_zk_node_step(zookeeper, req_msg)(s)
             });
-            let after_create_zk_node_step_waiting = lift_state(at_after_create_zk_node_step_and_exists_ok_resp_in_flight(zookeeper));
+            let after_create_zk_node_step_waiting = lift_state(|s: ZKCluster| {
+                &&& s.resources().contains_key(get_request(SubResource::StatefulSet, zookeeper).key)
+                &&& at_after_create_zk_node_step_and_exists_ok_resp_in_flight(zookeeper)(s)
+            });
             assert forall |req_msg| spec.entails(#[trigger] after_create_zk_node_step_req_msg(req_msg).leads_to(after_create_zk_node_step_waiting)) by {
                 lemma_from_pending_req_to_receives_ok_resp_at_after_create_zk_node_step(spec, zookeeper, req_msg);
             }
@@ -458,9 +480,12 @@ proof fn lemma_from_after_exists_stateful_set_step_and_key_exists_to_after_get_s
                 temp_pred_equality(tla_exists(after_create_zk_node_step_req_msg), after_create_zk_node_step_pending);
             });
 
-            let after_create_zk_node_step_resp_msg = |resp_msg: ZKMessage| lift_state(resp_msg_is_the_in_flight_ok_resp_at_after_create_zk_node_step(zookeeper, resp_msg));
+            let after_create_zk_node_step_resp_msg = |resp_msg: ZKMessage| lift_state(|s: ZKCluster| {
+                &&& s.resources().contains_key(get_request(SubResource::StatefulSet, zookeeper).key)
+                &&& resp_msg_is_the_in_flight_ok_resp_at_after_create_zk_node_step(zookeeper, resp_msg)(s)
+            });
             assert forall |resp_msg| spec.entails(#[trigger] after_create_zk_node_step_resp_msg(resp_msg).leads_to(post)) by {
-                lemma_from_after_create_zk_node_step_to_after_get_stateful_set_step(spec, zookeeper, resp_msg);
+                lemma_from_after_create_zk_node_step_to_after_reconfig_zk_step(spec, zookeeper, resp_msg);
             }
             leads_to_exists_intro(spec, after_create_zk_node_step_resp_msg, post);
             assert_by(tla_exists(after_create_zk_node_step_resp_msg) == after_create_zk_node_step_waiting, {
@@ -1000,7 +1025,10 @@ proof fn lemma_from_pending_req_to_receives_ok_resp_at_after_update_zk_node_step
                 &&& s.external_state().data.contains_key(zk_node_addr(s, zookeeper))
                 &&& req_msg_is_the_in_flight_pending_req_at_after_update_zk_node_step(zookeeper, req_msg)(s)
             })
-                .leads_to(lift_state(at_after_update_zk_node_step_and_exists_ok_resp_in_flight(zookeeper)))
+                .leads_to(lift_state(|s: ZKCluster| {
+                    &&& s.resources().contains_key(get_request(SubResource::StatefulSet, zookeeper).key)
+                    &&& at_after_update_zk_node_step_and_exists_ok_resp_in_flight(zookeeper)(s)
+                }))
         ),
 {
     let pre = |s: ZKCluster| {
@@ -1008,7 +1036,10 @@ proof fn lemma_from_pending_req_to_receives_ok_resp_at_after_update_zk_node_step
         &&& s.external_state().data.contains_key(zk_node_addr(s, zookeeper))
         &&& req_msg_is_the_in_flight_pending_req_at_after_update_zk_node_step(zookeeper, req_msg)(s)
     };
-    let post = at_after_update_zk_node_step_and_exists_ok_resp_in_flight(zookeeper);
+    let post = |s: ZKCluster| {
+        &&& s.resources().contains_key(get_request(SubResource::StatefulSet, zookeeper).key)
+        &&& at_after_update_zk_node_step_and_exists_ok_resp_in_flight(zookeeper)(s)
+    };
     let resource_key = get_request(SubResource::StatefulSet, zookeeper).key;
     let input = Some(req_msg);
     let stronger_next = |s, s_prime: ZKCluster| {
@@ -1077,7 +1108,7 @@ proof fn lemma_from_pending_req_to_receives_ok_resp_at_after_update_zk_node_step
     ZKCluster::lemma_pre_leads_to_post_by_external_api(spec, input, stronger_next, ZKCluster::handle_external_request(), pre, post);
 }
 
-proof fn lemma_from_after_update_zk_node_step_to_after_get_stateful_set_step(spec: TempPred<ZKCluster>, zookeeper: ZookeeperClusterView, resp_msg: ZKMessage)
+proof fn lemma_from_after_update_zk_node_step_to_after_reconfig_zk_step(spec: TempPred<ZKCluster>, zookeeper: ZookeeperClusterView, resp_msg: ZKMessage)
     requires
         spec.entails(always(lift_action(ZKCluster::next()))),
         spec.entails(tla_forall(|i| ZKCluster::controller_next().weak_fairness(i))),
@@ -1091,12 +1122,24 @@ proof fn lemma_from_after_update_zk_node_step_to_after_get_stateful_set_step(spe
         spec.entails(always(lift_state(helper_invariants::no_delete_resource_request_msg_in_flight(SubResource::StatefulSet, zookeeper)))),
     ensures
         spec.entails(
-            lift_state(resp_msg_is_the_in_flight_ok_resp_at_after_update_zk_node_step(zookeeper, resp_msg))
-                .leads_to(lift_state(pending_req_in_flight_at_after_get_resource_step(SubResource::StatefulSet, zookeeper)))
+            lift_state(|s: ZKCluster| {
+                &&& s.resources().contains_key(get_request(SubResource::StatefulSet, zookeeper).key)
+                &&& resp_msg_is_the_in_flight_ok_resp_at_after_update_zk_node_step(zookeeper, resp_msg)(s)
+            })
+                .leads_to(lift_state(|s: ZKCluster| {
+                    &&& s.resources().contains_key(get_request(SubResource::StatefulSet, zookeeper).key)
+                    &&& pending_req_in_flight_at_after_reconfig_zk_step(zookeeper)(s)
+                }))
         ),
 {
-    let pre = resp_msg_is_the_in_flight_ok_resp_at_after_update_zk_node_step(zookeeper, resp_msg);
-    let post = pending_req_in_flight_at_after_get_resource_step(SubResource::StatefulSet, zookeeper);
+    let pre = |s: ZKCluster| {
+        &&& s.resources().contains_key(get_request(SubResource::StatefulSet, zookeeper).key)
+        &&& resp_msg_is_the_in_flight_ok_resp_at_after_update_zk_node_step(zookeeper, resp_msg)(s)
+    };
+    let post = |s: ZKCluster| {
+        &&& s.resources().contains_key(get_request(SubResource::StatefulSet, zookeeper).key)
+        &&& pending_req_in_flight_at_after_reconfig_zk_step(zookeeper)(s)
+    };
     let input = (Some(resp_msg), Some(zookeeper.object_ref()));
     let stronger_next = |s, s_prime: ZKCluster| {
         &&& ZKCluster::next()(s, s_prime)
@@ -1123,6 +1166,18 @@ proof fn lemma_from_after_update_zk_node_step_to_after_get_stateful_set_step(spe
         lift_state(helper_invariants::no_delete_resource_request_msg_in_flight(SubResource::StatefulSet, zookeeper))
     );
 
+    assert forall |s, s_prime| pre(s) && #[trigger] stronger_next(s, s_prime) implies pre(s_prime) || post(s_prime) by {
+        let step = choose |step| ZKCluster::next_step(s, s_prime, step);
+        let sts_key = get_request(SubResource::StatefulSet, zookeeper).key;
+        match step {
+            Step::ApiServerStep(input) => {
+                assert(!resource_delete_request_msg(sts_key)(input.get_Some_0()));
+                assert(!resource_update_request_msg(sts_key)(input.get_Some_0()));
+            },
+            _ => {}
+        }
+    }
+
     ZKCluster::lemma_pre_leads_to_post_by_controller(spec, input, stronger_next, ZKCluster::continue_reconcile(), pre, post);
 }
 
@@ -1522,7 +1577,10 @@ proof fn lemma_from_pending_req_to_receives_ok_resp_at_after_create_zk_node_step
                 &&& !s.external_state().data.contains_key(zk_node_addr(s, zookeeper))
                 &&& s.external_state().data.contains_key(zk_parent_node_addr(s, zookeeper))
                 &&& req_msg_is_the_in_flight_pending_req_at_after_create_zk_node_step(zookeeper, req_msg)(s)
-            }).leads_to(lift_state(at_after_create_zk_node_step_and_exists_ok_resp_in_flight(zookeeper)))
+            }).leads_to(lift_state(|s: ZKCluster| {
+                &&& s.resources().contains_key(get_request(SubResource::StatefulSet, zookeeper).key)
+                &&& at_after_create_zk_node_step_and_exists_ok_resp_in_flight(zookeeper)(s)
+            }))
         ),
 {
     let pre = |s: ZKCluster| {
@@ -1531,7 +1589,10 @@ proof fn lemma_from_pending_req_to_receives_ok_resp_at_after_create_zk_node_step
         &&& s.external_state().data.contains_key(zk_parent_node_addr(s, zookeeper))
         &&& req_msg_is_the_in_flight_pending_req_at_after_create_zk_node_step(zookeeper, req_msg)(s)
     };
-    let post = at_after_create_zk_node_step_and_exists_ok_resp_in_flight(zookeeper);
+    let post = |s: ZKCluster| {
+        &&& s.resources().contains_key(get_request(SubResource::StatefulSet, zookeeper).key)
+        &&& at_after_create_zk_node_step_and_exists_ok_resp_in_flight(zookeeper)(s)
+    };
     let input = Some(req_msg);
     let stronger_next = |s, s_prime: ZKCluster| {
         &&& ZKCluster::next()(s, s_prime)
@@ -1599,7 +1660,235 @@ proof fn lemma_from_pending_req_to_receives_ok_resp_at_after_create_zk_node_step
     ZKCluster::lemma_pre_leads_to_post_by_external_api(spec, input, stronger_next, ZKCluster::handle_external_request(), pre, post);
 }
 
-proof fn lemma_from_after_create_zk_node_step_to_after_get_stateful_set_step(spec: TempPred<ZKCluster>, zookeeper: ZookeeperClusterView, resp_msg: ZKMessage)
+proof fn lemma_from_after_create_zk_node_step_to_after_reconfig_zk_step(spec: TempPred<ZKCluster>, zookeeper: ZookeeperClusterView, resp_msg: ZKMessage)
+    requires
+        spec.entails(always(lift_action(ZKCluster::next()))),
+        spec.entails(tla_forall(|i| ZKCluster::controller_next().weak_fairness(i))),
+        spec.entails(always(lift_state(ZKCluster::crash_disabled()))),
+        spec.entails(always(lift_state(ZKCluster::busy_disabled()))),
+        spec.entails(always(lift_state(ZKCluster::every_in_flight_req_msg_has_different_id_from_pending_req_msg_of(zookeeper.object_ref())))),
+        spec.entails(always(lift_state(ZKCluster::pending_req_of_key_is_unique_with_unique_id(zookeeper.object_ref())))),
+        spec.entails(always(lift_state(ZKCluster::each_object_in_etcd_is_well_formed()))),
+        spec.entails(always(lift_state(ZKCluster::every_in_flight_msg_has_unique_id()))),
+        spec.entails(always(lift_state(helper_invariants::every_resource_update_request_implies_at_after_update_resource_step(SubResource::StatefulSet, zookeeper)))),
+        spec.entails(always(lift_state(helper_invariants::no_delete_resource_request_msg_in_flight(SubResource::StatefulSet, zookeeper)))),
+    ensures
+        spec.entails(
+            lift_state(|s: ZKCluster| {
+                &&& s.resources().contains_key(get_request(SubResource::StatefulSet, zookeeper).key)
+                &&& resp_msg_is_the_in_flight_ok_resp_at_after_create_zk_node_step(zookeeper, resp_msg)(s)
+            })
+                .leads_to(lift_state(|s: ZKCluster| {
+                    &&& s.resources().contains_key(get_request(SubResource::StatefulSet, zookeeper).key)
+                    &&& pending_req_in_flight_at_after_reconfig_zk_step(zookeeper)(s)
+                }))
+        ),
+{
+    let pre = |s: ZKCluster| {
+        &&& s.resources().contains_key(get_request(SubResource::StatefulSet, zookeeper).key)
+        &&& resp_msg_is_the_in_flight_ok_resp_at_after_create_zk_node_step(zookeeper, resp_msg)(s)
+    };
+    let post = |s: ZKCluster| {
+        &&& s.resources().contains_key(get_request(SubResource::StatefulSet, zookeeper).key)
+        &&& pending_req_in_flight_at_after_reconfig_zk_step(zookeeper)(s)
+    };
+    let input = (Some(resp_msg), Some(zookeeper.object_ref()));
+    let stronger_next = |s, s_prime: ZKCluster| {
+        &&& ZKCluster::next()(s, s_prime)
+        &&& ZKCluster::crash_disabled()(s)
+        &&& ZKCluster::busy_disabled()(s)
+        &&& ZKCluster::every_in_flight_req_msg_has_different_id_from_pending_req_msg_of(zookeeper.object_ref())(s)
+        &&& ZKCluster::pending_req_of_key_is_unique_with_unique_id(zookeeper.object_ref())(s)
+        &&& ZKCluster::each_object_in_etcd_is_well_formed()(s)
+        &&& ZKCluster::every_in_flight_msg_has_unique_id()(s)
+        &&& helper_invariants::every_resource_update_request_implies_at_after_update_resource_step(SubResource::StatefulSet, zookeeper)(s)
+        &&& helper_invariants::no_delete_resource_request_msg_in_flight(SubResource::StatefulSet, zookeeper)(s)
+    };
+
+    combine_spec_entails_always_n!(
+        spec, lift_action(stronger_next),
+        lift_action(ZKCluster::next()),
+        lift_state(ZKCluster::crash_disabled()),
+        lift_state(ZKCluster::busy_disabled()),
+        lift_state(ZKCluster::every_in_flight_req_msg_has_different_id_from_pending_req_msg_of(zookeeper.object_ref())),
+        lift_state(ZKCluster::pending_req_of_key_is_unique_with_unique_id(zookeeper.object_ref())),
+        lift_state(ZKCluster::each_object_in_etcd_is_well_formed()),
+        lift_state(ZKCluster::every_in_flight_msg_has_unique_id()),
+        lift_state(helper_invariants::every_resource_update_request_implies_at_after_update_resource_step(SubResource::StatefulSet, zookeeper)),
+        lift_state(helper_invariants::no_delete_resource_request_msg_in_flight(SubResource::StatefulSet, zookeeper))
+    );
+
+    assert forall |s, s_prime| pre(s) && #[trigger] stronger_next(s, s_prime) implies pre(s_prime) || post(s_prime) by {
+        let step = choose |step| ZKCluster::next_step(s, s_prime, step);
+        let sts_key = get_request(SubResource::StatefulSet, zookeeper).key;
+        match step {
+            Step::ApiServerStep(input) => {
+                assert(!resource_delete_request_msg(sts_key)(input.get_Some_0()));
+                assert(!resource_update_request_msg(sts_key)(input.get_Some_0()));
+            },
+            _ => {}
+        }
+    }
+
+    ZKCluster::lemma_pre_leads_to_post_by_controller(spec, input, stronger_next, ZKCluster::continue_reconcile(), pre, post);
+}
+
+proof fn lemma_from_after_reconfig_zk_step_pending_to_after_get_stateful_set_step(spec: TempPred<ZKCluster>, zookeeper: ZookeeperClusterView)
+    requires
+        spec.entails(always(lift_action(ZKCluster::next()))),
+        spec.entails(tla_forall(|i| ZKCluster::controller_next().weak_fairness(i))),
+        spec.entails(tla_forall(|i| ZKCluster::external_api_next().weak_fairness(i))),
+        spec.entails(always(lift_state(ZKCluster::crash_disabled()))),
+        spec.entails(always(lift_state(ZKCluster::busy_disabled()))),
+        spec.entails(always(lift_state(ZKCluster::every_in_flight_req_msg_has_different_id_from_pending_req_msg_of(zookeeper.object_ref())))),
+        spec.entails(always(lift_state(ZKCluster::pending_req_of_key_is_unique_with_unique_id(zookeeper.object_ref())))),
+        spec.entails(always(lift_state(ZKCluster::each_object_in_etcd_is_well_formed()))),
+        spec.entails(always(lift_state(ZKCluster::every_in_flight_msg_has_unique_id()))),
+        spec.entails(always(lift_state(helper_invariants::every_resource_update_request_implies_at_after_update_resource_step(SubResource::StatefulSet, zookeeper)))),
+        spec.entails(always(lift_state(helper_invariants::no_delete_resource_request_msg_in_flight(SubResource::StatefulSet, zookeeper)))),
+        spec.entails(always(lift_state(helper_invariants::object_in_etcd_satisfies_unchangeable(SubResource::StatefulSet, zookeeper)))),
+        spec.entails(always(lift_state(helper_invariants::cm_rv_is_the_same_as_etcd_server_cm_if_cm_updated(zookeeper)))),
+        spec.entails(always(lift_state(helper_invariants::object_in_etcd_satisfies_unchangeable(SubResource::ConfigMap, zookeeper)))),
+    ensures
+        spec.entails(
+            lift_state(|s: ZKCluster| {
+                &&& s.resources().contains_key(get_request(SubResource::StatefulSet, zookeeper).key)
+                &&& pending_req_in_flight_at_after_reconfig_zk_step(zookeeper)(s)
+            }).leads_to(lift_state(pending_req_in_flight_at_after_get_resource_step(SubResource::StatefulSet, zookeeper)))
+        ),
+{
+    let pre = lift_state(|s: ZKCluster| {
+        &&& s.resources().contains_key(get_request(SubResource::StatefulSet, zookeeper).key)
+        &&& pending_req_in_flight_at_after_reconfig_zk_step(zookeeper)(s)
+    });
+    let post = lift_state(pending_req_in_flight_at_after_get_resource_step(SubResource::StatefulSet, zookeeper));
+
+    let after_reconfig_zk_step_req_msg = |req_msg: ZKMessage| lift_state(|s: ZKCluster| {
+        &&& s.resources().contains_key(get_request(SubResource::StatefulSet, zookeeper).key)
+        &&& req_msg_is_the_in_flight_pending_req_at_after_reconfig_zk_step(zookeeper, req_msg)(s)
+    });
+    let after_reconfig_zk_step_waiting = lift_state(at_after_reconfig_zk_step_and_exists_ok_resp_in_flight(zookeeper));
+    assert forall |req_msg| spec.entails(#[trigger] after_reconfig_zk_step_req_msg(req_msg).leads_to(after_reconfig_zk_step_waiting)) by {
+        lemma_from_pending_req_to_receives_ok_resp_at_after_reconfig_zk_step(spec, zookeeper, req_msg);
+    }
+    leads_to_exists_intro(spec, after_reconfig_zk_step_req_msg, after_reconfig_zk_step_waiting);
+    assert_by(tla_exists(after_reconfig_zk_step_req_msg) == pre, {
+        assert forall |ex| #[trigger] pre.satisfied_by(ex) implies tla_exists(after_reconfig_zk_step_req_msg).satisfied_by(ex) by {
+            let req_msg = ex.head().ongoing_reconciles()[zookeeper.object_ref()].pending_req_msg.get_Some_0();
+            assert(after_reconfig_zk_step_req_msg(req_msg).satisfied_by(ex));
+        }
+        temp_pred_equality(tla_exists(after_reconfig_zk_step_req_msg), pre);
+    });
+
+    let after_reconfig_zk_step_resp_msg = |resp_msg: ZKMessage| lift_state(resp_msg_is_the_in_flight_ok_resp_at_after_reconfig_zk_step(zookeeper, resp_msg));
+    assert forall |resp_msg| spec.entails(#[trigger] after_reconfig_zk_step_resp_msg(resp_msg).leads_to(post)) by {
+        lemma_from_after_reconfig_zk_step_to_after_get_stateful_set_step(spec, zookeeper, resp_msg);
+    }
+    leads_to_exists_intro(spec, after_reconfig_zk_step_resp_msg, post);
+    assert_by(tla_exists(after_reconfig_zk_step_resp_msg) == after_reconfig_zk_step_waiting, {
+        assert forall |ex| #[trigger] after_reconfig_zk_step_waiting.satisfied_by(ex) implies tla_exists(after_reconfig_zk_step_resp_msg).satisfied_by(ex) by {
+            let resp_msg = choose |resp_msg| {
+                &&& #[trigger] ex.head().in_flight().contains(resp_msg)
+                &&& Message::resp_msg_matches_req_msg(resp_msg, ex.head().ongoing_reconciles()[zookeeper.object_ref()].pending_req_msg.get_Some_0())
+                &&& resp_msg.content.get_ExternalAPIResponse_0() == ZKAPIOutputView::ReconfigResponse(ZKAPIReconfigResultView{res: Ok(())})
+            };
+            assert(after_reconfig_zk_step_resp_msg(resp_msg).satisfied_by(ex));
+        }
+        temp_pred_equality(tla_exists(after_reconfig_zk_step_resp_msg), after_reconfig_zk_step_waiting);
+    });
+
+    leads_to_trans(spec, pre, after_reconfig_zk_step_waiting, post);
+}
+
+proof fn lemma_from_pending_req_to_receives_ok_resp_at_after_reconfig_zk_step(spec: TempPred<ZKCluster>, zookeeper: ZookeeperClusterView, req_msg: ZKMessage)
+    requires
+        spec.entails(always(lift_action(ZKCluster::next()))),
+        spec.entails(tla_forall(|i| ZKCluster::external_api_next().weak_fairness(i))),
+        spec.entails(always(lift_state(ZKCluster::crash_disabled()))),
+        spec.entails(always(lift_state(ZKCluster::busy_disabled()))),
+        spec.entails(always(lift_state(ZKCluster::every_in_flight_msg_has_unique_id()))),
+        spec.entails(always(lift_state(helper_invariants::every_resource_update_request_implies_at_after_update_resource_step(SubResource::StatefulSet, zookeeper)))),
+        spec.entails(always(lift_state(helper_invariants::no_delete_resource_request_msg_in_flight(SubResource::StatefulSet, zookeeper)))),
+        spec.entails(always(lift_state(helper_invariants::object_in_etcd_satisfies_unchangeable(SubResource::StatefulSet, zookeeper)))),
+        spec.entails(always(lift_state(helper_invariants::cm_rv_is_the_same_as_etcd_server_cm_if_cm_updated(zookeeper)))),
+        spec.entails(always(lift_state(helper_invariants::object_in_etcd_satisfies_unchangeable(SubResource::ConfigMap, zookeeper)))),
+    ensures
+        spec.entails(
+            lift_state(|s: ZKCluster| {
+                &&& s.resources().contains_key(get_request(SubResource::StatefulSet, zookeeper).key)
+                &&& req_msg_is_the_in_flight_pending_req_at_after_reconfig_zk_step(zookeeper, req_msg)(s)
+            }).leads_to(lift_state(at_after_reconfig_zk_step_and_exists_ok_resp_in_flight(zookeeper)))
+        ),
+{
+    let pre = |s: ZKCluster| {
+        &&& s.resources().contains_key(get_request(SubResource::StatefulSet, zookeeper).key)
+        &&& req_msg_is_the_in_flight_pending_req_at_after_reconfig_zk_step(zookeeper, req_msg)(s)
+    };
+    let post = at_after_reconfig_zk_step_and_exists_ok_resp_in_flight(zookeeper);
+    let input = Some(req_msg);
+    let stronger_next = |s, s_prime: ZKCluster| {
+        &&& ZKCluster::next()(s, s_prime)
+        &&& ZKCluster::crash_disabled()(s)
+        &&& ZKCluster::busy_disabled()(s)
+        &&& ZKCluster::every_in_flight_msg_has_unique_id()(s)
+        &&& helper_invariants::every_resource_update_request_implies_at_after_update_resource_step(SubResource::StatefulSet, zookeeper)(s)
+        &&& helper_invariants::no_delete_resource_request_msg_in_flight(SubResource::StatefulSet, zookeeper)(s)
+        &&& helper_invariants::object_in_etcd_satisfies_unchangeable(SubResource::StatefulSet, zookeeper)(s)
+        &&& helper_invariants::cm_rv_is_the_same_as_etcd_server_cm_if_cm_updated(zookeeper)(s)
+        &&& helper_invariants::object_in_etcd_satisfies_unchangeable(SubResource::ConfigMap, zookeeper)(s)
+    };
+    combine_spec_entails_always_n!(
+        spec, lift_action(stronger_next),
+        lift_action(ZKCluster::next()),
+        lift_state(ZKCluster::crash_disabled()),
+        lift_state(ZKCluster::busy_disabled()),
+        lift_state(ZKCluster::every_in_flight_msg_has_unique_id()),
+        lift_state(helper_invariants::every_resource_update_request_implies_at_after_update_resource_step(SubResource::StatefulSet, zookeeper)),
+        lift_state(helper_invariants::no_delete_resource_request_msg_in_flight(SubResource::StatefulSet, zookeeper)),
+        lift_state(helper_invariants::object_in_etcd_satisfies_unchangeable(SubResource::StatefulSet, zookeeper)),
+        lift_state(helper_invariants::cm_rv_is_the_same_as_etcd_server_cm_if_cm_updated(zookeeper)),
+        lift_state(helper_invariants::object_in_etcd_satisfies_unchangeable(SubResource::ConfigMap, zookeeper))
+    );
+
+    assert forall |s, s_prime| pre(s) && #[trigger] stronger_next(s, s_prime) implies pre(s_prime) || post(s_prime) by {
+        let step = choose |step| ZKCluster::next_step(s, s_prime, step);
+        let sts_key = get_request(SubResource::StatefulSet, zookeeper).key;
+        match step {
+            Step::ExternalAPIStep(input) => {
+                if input.get_Some_0() == req_msg {
+                    let resp_msg = ZKCluster::handle_external_request_helper(req_msg, s.external_api_state, s.resources()).1;
+                    assert({
+                        &&& s_prime.in_flight().contains(resp_msg)
+                        &&& Message::resp_msg_matches_req_msg(resp_msg, req_msg)
+                        &&& resp_msg.content.is_ExternalAPIResponse()
+                        &&& resp_msg.content.get_ExternalAPIResponse_0() == ZKAPIOutputView::ReconfigResponse(ZKAPIReconfigResultView{res: Ok(())})
+                    });
+                    assert(post(s_prime));
+                }
+            },
+            Step::ApiServerStep(input) => {
+                assert(!resource_delete_request_msg(sts_key)(input.get_Some_0()));
+                assert(!resource_update_request_msg(sts_key)(input.get_Some_0()));
+            },
+            _ => {}
+        }
+    }
+
+    assert forall |s, s_prime| pre(s) && #[trigger] stronger_next(s, s_prime) && ZKCluster::external_api_next().forward(input)(s, s_prime)
+    implies post(s_prime) by {
+        let resp_msg = ZKCluster::handle_external_request_helper(req_msg, s.external_api_state, s.resources()).1;
+        assert({
+            &&& s_prime.in_flight().contains(resp_msg)
+            &&& Message::resp_msg_matches_req_msg(resp_msg, req_msg)
+            &&& resp_msg.content.is_ExternalAPIResponse()
+            &&& resp_msg.content.get_ExternalAPIResponse_0() == ZKAPIOutputView::ReconfigResponse(ZKAPIReconfigResultView{res: Ok(())})
+        });
+    }
+
+    ZKCluster::lemma_pre_leads_to_post_by_external_api(spec, input, stronger_next, ZKCluster::handle_external_request(), pre, post);
+}
+
+proof fn lemma_from_after_reconfig_zk_step_to_after_get_stateful_set_step(spec: TempPred<ZKCluster>, zookeeper: ZookeeperClusterView, resp_msg: ZKMessage)
     requires
         spec.entails(always(lift_action(ZKCluster::next()))),
         spec.entails(tla_forall(|i| ZKCluster::controller_next().weak_fairness(i))),
@@ -1611,11 +1900,11 @@ proof fn lemma_from_after_create_zk_node_step_to_after_get_stateful_set_step(spe
         spec.entails(always(lift_state(ZKCluster::every_in_flight_msg_has_unique_id()))),
     ensures
         spec.entails(
-            lift_state(resp_msg_is_the_in_flight_ok_resp_at_after_create_zk_node_step(zookeeper, resp_msg))
+            lift_state(resp_msg_is_the_in_flight_ok_resp_at_after_reconfig_zk_step(zookeeper, resp_msg))
                 .leads_to(lift_state(pending_req_in_flight_at_after_get_resource_step(SubResource::StatefulSet, zookeeper)))
         ),
 {
-    let pre = resp_msg_is_the_in_flight_ok_resp_at_after_create_zk_node_step(zookeeper, resp_msg);
+    let pre = resp_msg_is_the_in_flight_ok_resp_at_after_reconfig_zk_step(zookeeper, resp_msg);
     let post = pending_req_in_flight_at_after_get_resource_step(SubResource::StatefulSet, zookeeper);
     let input = (Some(resp_msg), Some(zookeeper.object_ref()));
     let stronger_next = |s, s_prime: ZKCluster| {
@@ -1642,5 +1931,196 @@ proof fn lemma_from_after_create_zk_node_step_to_after_get_stateful_set_step(spe
     ZKCluster::lemma_pre_leads_to_post_by_controller(spec, input, stronger_next, ZKCluster::continue_reconcile(), pre, post);
 }
 
+pub proof fn lemma_from_after_reconfig_zk_step_pending_to_ensemble_matches(spec: TempPred<ZKCluster>, zookeeper: ZookeeperClusterView)
+    requires
+        spec.entails(always(lift_action(ZKCluster::next()))),
+        spec.entails(tla_forall(|i| ZKCluster::external_api_next().weak_fairness(i))),
+        spec.entails(always(lift_state(ZKCluster::crash_disabled()))),
+        spec.entails(always(lift_state(ZKCluster::busy_disabled()))),
+        spec.entails(always(lift_state(ZKCluster::every_in_flight_msg_has_unique_id()))),
+        spec.entails(always(lift_state(helper_invariants::every_resource_update_request_implies_at_after_update_resource_step(SubResource::StatefulSet, zookeeper)))),
+        spec.entails(always(lift_state(helper_invariants::no_delete_resource_request_msg_in_flight(SubResource::StatefulSet, zookeeper)))),
+        spec.entails(always(lift_state(helper_invariants::object_in_etcd_satisfies_unchangeable(SubResource::StatefulSet, zookeeper)))),
+        spec.entails(always(lift_state(helper_invariants::cm_rv_is_the_same_as_etcd_server_cm_if_cm_updated(zookeeper)))),
+        spec.entails(always(lift_state(helper_invariants::object_in_etcd_satisfies_unchangeable(SubResource::ConfigMap, zookeeper)))),
+    ensures
+        spec.entails(
+            lift_state(|s: ZKCluster| {
+                &&& s.resources().contains_key(get_request(SubResource::StatefulSet, zookeeper).key)
+                &&& pending_req_in_flight_at_after_reconfig_zk_step(zookeeper)(s)
+            }).leads_to(lift_state(zk_ensemble_matches(zookeeper)))
+        ),
+{
+    let pre = lift_state(|s: ZKCluster| {
+        &&& s.resources().contains_key(get_request(SubResource::StatefulSet, zookeeper).key)
+        &&& pending_req_in_flight_at_after_reconfig_zk_step(zookeeper)(s)
+    });
+    let post = lift_state(zk_ensemble_matches(zookeeper));
+    let after_reconfig_zk_step_req_msg = |req_msg: ZKMessage| lift_state(|s: ZKCluster| {
+        &&& s.resources().contains_key(get_request(SubResource::StatefulSet, zookeeper).key)
+        &&& req_msg_is_the_in_flight_pending_req_at_after_reconfig_zk_step(zookeeper, req_msg)(s)
+    });
+    assert forall |req_msg| spec.entails(#[trigger] after_reconfig_zk_step_req_msg(req_msg).leads_to(post)) by {
+        lemma_from_pending_req_at_after_reconfig_zk_step_to_ensemble_matches(spec, zookeeper, req_msg);
+    }
+    leads_to_exists_intro(spec, after_reconfig_zk_step_req_msg, post);
+    assert_by(tla_exists(after_reconfig_zk_step_req_msg) == pre, {
+        assert forall |ex| #[trigger] pre.satisfied_by(ex) implies tla_exists(after_reconfig_zk_step_req_msg).satisfied_by(ex) by {
+            let req_msg = ex.head().ongoing_reconciles()[zookeeper.object_ref()].pending_req_msg.get_Some_0();
+            assert(after_reconfig_zk_step_req_msg(req_msg).satisfied_by(ex));
+        }
+        temp_pred_equality(tla_exists(after_reconfig_zk_step_req_msg), pre);
+    });
+}
+
+proof fn lemma_from_pending_req_at_after_reconfig_zk_step_to_ensemble_matches(spec: TempPred<ZKCluster>, zookeeper: ZookeeperClusterView, req_msg: ZKMessage)
+    requires
+        spec.entails(always(lift_action(ZKCluster::next()))),
+        spec.entails(tla_forall(|i| ZKCluster::external_api_next().weak_fairness(i))),
+        spec.entails(always(lift_state(ZKCluster::crash_disabled()))),
+        spec.entails(always(lift_state(ZKCluster::busy_disabled()))),
+        spec.entails(always(lift_state(ZKCluster::every_in_flight_msg_has_unique_id()))),
+        spec.entails(always(lift_state(helper_invariants::every_resource_update_request_implies_at_after_update_resource_step(SubResource::StatefulSet, zookeeper)))),
+        spec.entails(always(lift_state(helper_invariants::no_delete_resource_request_msg_in_flight(SubResource::StatefulSet, zookeeper)))),
+        spec.entails(always(lift_state(helper_invariants::object_in_etcd_satisfies_unchangeable(SubResource::StatefulSet, zookeeper)))),
+        spec.entails(always(lift_state(helper_invariants::cm_rv_is_the_same_as_etcd_server_cm_if_cm_updated(zookeeper)))),
+        spec.entails(always(lift_state(helper_invariants::object_in_etcd_satisfies_unchangeable(SubResource::ConfigMap, zookeeper)))),
+    ensures
+        spec.entails(
+            lift_state(|s: ZKCluster| {
+                &&& s.resources().contains_key(get_request(SubResource::StatefulSet, zookeeper).key)
+                &&& req_msg_is_the_in_flight_pending_req_at_after_reconfig_zk_step(zookeeper, req_msg)(s)
+            }).leads_to(lift_state(zk_ensemble_matches(zookeeper)))
+        ),
+{
+    let pre = |s: ZKCluster| {
+        &&& s.resources().contains_key(get_request(SubResource::StatefulSet, zookeeper).key)
+        &&& req_msg_is_the_in_flight_pending_req_at_after_reconfig_zk_step(zookeeper, req_msg)(s)
+    };
+    let post = zk_ensemble_matches(zookeeper);
+    let input = Some(req_msg);
+    let stronger_next = |s, s_prime: ZKCluster| {
+        &&& ZKCluster::next()(s, s_prime)
+        &&& ZKCluster::crash_disabled()(s)
+        &&& ZKCluster::busy_disabled()(s)
+        &&& ZKCluster::every_in_flight_msg_has_unique_id()(s)
+        &&& helper_invariants::every_resource_update_request_implies_at_after_update_resource_step(SubResource::StatefulSet, zookeeper)(s)
+        &&& helper_invariants::no_delete_resource_request_msg_in_flight(SubResource::StatefulSet, zookeeper)(s)
+        &&& helper_invariants::object_in_etcd_satisfies_unchangeable(SubResource::StatefulSet, zookeeper)(s)
+        &&& helper_invariants::cm_rv_is_the_same_as_etcd_server_cm_if_cm_updated(zookeeper)(s)
+        &&& helper_invariants::object_in_etcd_satisfies_unchangeable(SubResource::ConfigMap, zookeeper)(s)
+    };
+    combine_spec_entails_always_n!(
+        spec, lift_action(stronger_next),
+        lift_action(ZKCluster::next()),
+        lift_state(ZKCluster::crash_disabled()),
+        lift_state(ZKCluster::busy_disabled()),
+        lift_state(ZKCluster::every_in_flight_msg_has_unique_id()),
+        lift_state(helper_invariants::every_resource_update_request_implies_at_after_update_resource_step(SubResource::StatefulSet, zookeeper)),
+        lift_state(helper_invariants::no_delete_resource_request_msg_in_flight(SubResource::StatefulSet, zookeeper)),
+        lift_state(helper_invariants::object_in_etcd_satisfies_unchangeable(SubResource::StatefulSet, zookeeper)),
+        lift_state(helper_invariants::cm_rv_is_the_same_as_etcd_server_cm_if_cm_updated(zookeeper)),
+        lift_state(helper_invariants::object_in_etcd_satisfies_unchangeable(SubResource::ConfigMap, zookeeper))
+    );
+
+    assert forall |s, s_prime| pre(s) && #[trigger] stronger_next(s, s_prime) implies pre(s_prime) || post(s_prime) by {
+        let step = choose |step| ZKCluster::next_step(s, s_prime, step);
+        let sts_key = get_request(SubResource::StatefulSet, zookeeper).key;
+        match step {
+            Step::ExternalAPIStep(input) => {
+                if input.get_Some_0() == req_msg {
+                    lemma_reconfig_request_makes_ensemble_match(zookeeper, s, s_prime, req_msg);
+                }
+            },
+            Step::ApiServerStep(input) => {
+                assert(!resource_delete_request_msg(sts_key)(input.get_Some_0()));
+                assert(!resource_update_request_msg(sts_key)(input.get_Some_0()));
+            },
+            _ => {}
+        }
+    }
+
+    assert forall |s, s_prime| pre(s) && #[trigger] stronger_next(s, s_prime) && ZKCluster::external_api_next().forward(input)(s, s_prime)
+    implies post(s_prime) by {
+        lemma_reconfig_request_makes_ensemble_match(zookeeper, s, s_prime, req_msg);
+    }
+
+    ZKCluster::lemma_pre_leads_to_post_by_external_api(spec, input, stronger_next, ZKCluster::handle_external_request(), pre, post);
+}
+
+// Handling the reconfig request of zookeeper removes the servers beyond the desired replicas from the ensemble
+// of the zookeeper cluster hosted by the current stateful set object.
+proof fn lemma_reconfig_request_makes_ensemble_match(zookeeper: ZookeeperClusterView, s: ZKCluster, s_prime: ZKCluster, req_msg: ZKMessage)
+    requires
+        s.resources().contains_key(get_request(SubResource::StatefulSet, zookeeper).key),
+        req_msg_is_the_in_flight_pending_req_at_after_reconfig_zk_step(zookeeper, req_msg)(s),
+        helper_invariants::object_in_etcd_satisfies_unchangeable(SubResource::StatefulSet, zookeeper)(s),
+        helper_invariants::cm_rv_is_the_same_as_etcd_server_cm_if_cm_updated(zookeeper)(s),
+        helper_invariants::object_in_etcd_satisfies_unchangeable(SubResource::ConfigMap, zookeeper)(s),
+        ZKCluster::next_step(s, s_prime, Step::ExternalAPIStep(Some(req_msg))),
+    ensures zk_ensemble_matches(zookeeper)(s_prime),
+{
+    let sts_key = get_request(SubResource::StatefulSet, zookeeper).key;
+    let resp_msg = ZKCluster::handle_external_request_helper(req_msg, s.external_api_state, s.resources()).1;
+    assert(resp_msg.content.get_ExternalAPIResponse_0() == ZKAPIOutputView::ReconfigResponse(ZKAPIReconfigResultView{res: Ok(())}));
+    assert(s_prime.resources() == s.resources());
+    let addr = ZKNodeAddr::new(zookeeper.metadata.name.get_Some_0(), zookeeper.metadata.namespace.get_Some_0(), s.resources()[sts_key].metadata.uid.get_Some_0(), zk_config_path());
+    assert(s_prime.external_state().ensemble.contains_key(addr));
+    assert forall |id: int| #[trigger] s_prime.external_state().ensemble[addr].contains(id) implies 1 <= id <= zookeeper.spec.replicas by {
+        assert(s_prime.external_state().ensemble[addr].contains(id));
+    }
+}
+
+pub proof fn lemma_ensemble_is_stable(spec: TempPred<ZKCluster>, zookeeper: ZookeeperClusterView, p: TempPred<ZKCluster>)
+    requires
+        spec.entails(p.leads_to(lift_state(zk_ensemble_matches(zookeeper)))),
+        spec.entails(always(lift_action(ZKCluster::next()))),
+        spec.entails(always(lift_state(sub_resource_state_matches(SubResource::StatefulSet, zookeeper)))),
+        spec.entails(always(lift_state(helper_invariants::no_delete_resource_request_msg_in_flight(SubResource::StatefulSet, zookeeper)))),
+        spec.entails(always(lift_state(helper_invariants::every_zk_reconfig_request_is_made_from_zookeeper(zookeeper)))),
+    ensures spec.entails(p.leads_to(always(lift_state(zk_ensemble_matches(zookeeper))))),
+{
+    let post = zk_ensemble_matches(zookeeper);
+    let sts_key = get_request(SubResource::StatefulSet, zookeeper).key;
+    let stronger_next = |s, s_prime: ZKCluster| {
+        &&& ZKCluster::next()(s, s_prime)
+        &&& sub_resource_state_matches(SubResource::StatefulSet, zookeeper)(s_prime)
+        &&& helper_invariants::no_delete_resource_request_msg_in_flight(SubResource::StatefulSet, zookeeper)(s)
+        &&& helper_invariants::every_zk_reconfig_request_is_made_from_zookeeper(zookeeper)(s)
+    };
+    always_to_always_later(spec, lift_state(sub_resource_state_matches(SubResource::StatefulSet, zookeeper)));
+    combine_spec_entails_always_n!(
+        spec, lift_action(stronger_next),
+        lift_action(ZKCluster::next()),
+        later(lift_state(sub_resource_state_matches(SubResource::StatefulSet, zookeeper))),
+        lift_state(helper_invariants::no_delete_resource_request_msg_in_flight(SubResource::StatefulSet, zookeeper)),
+        lift_state(helper_invariants::every_zk_reconfig_request_is_made_from_zookeeper(zookeeper))
+    );
+
+    assert forall |s, s_prime: ZKCluster| post(s) && #[trigger] stronger_next(s, s_prime) implies post(s_prime) by {
+        let step = choose |step| ZKCluster::next_step(s, s_prime, step);
+        // The stateful set object is never deleted, and creating or updating it never changes its uid,
+        // so the ensemble that ensemble_matches talks about stays the same one.
+        match step {
+            Step::ApiServerStep(input) => {
+                let req = input.get_Some_0();
+                assert(!resource_delete_request_msg(sts_key)(req));
+                if resource_update_request_msg(sts_key)(req) {} else {}
+                if resource_update_status_request_msg(sts_key)(req) {} else {}
+                assert(s.resources()[sts_key].metadata.uid == s_prime.resources()[sts_key].metadata.uid);
+            },
+            Step::ExternalAPIStep(input) => {
+                let req = input.get_Some_0();
+                if zk_reconfig_request_msg(zookeeper)(req) {
+                    assert(req.content.get_ExternalAPIRequest_0() == zk_reconfig_request(zookeeper));
+                }
+                assert(s.resources() == s_prime.resources());
+            },
+            _ => {}
+        }
+    }
+
+    leads_to_stable(spec, lift_action(stronger_next), p, lift_state(post));
+}
 
 }
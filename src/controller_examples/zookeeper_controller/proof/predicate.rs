@@ -27,6 +27,12 @@ pub open spec fn sub_resource_state_matches(sub_resource: SubResource, zk: Zooke
     }
 }
 
+pub open spec fn zk_ensemble_matches(zk: ZookeeperClusterView) -> StatePred<ZKCluster> {
+    |s: ZKCluster| {
+        ensemble_matches::<ZookeeperMaker>(zk, s.resources(), s.external_state())
+    }
+}
+
 pub open spec fn at_step_closure(step: ZookeeperReconcileStep) -> spec_fn(ZookeeperReconcileState) -> bool {
     |s: ZookeeperReconcileState| s.reconcile_step == step
 }
@@ -74,7 +80,8 @@ pub open spec fn next_resource_after(sub_resource: SubResource) -> ZookeeperReco
     match sub_resource {
         SubResource::HeadlessService => after_get_k_request_step(SubResource::ClientService),
         SubResource::ClientService => after_get_k_request_step(SubResource::AdminServerService),
-        SubResource::AdminServerService => after_get_k_request_step(SubResource::ConfigMap),
+        SubResource::AdminServerService => after_get_k_request_step(SubResource::PodDisruptionBudget),
+        SubResource::PodDisruptionBudget => after_get_k_request_step(SubResource::ConfigMap),
         SubResource::ConfigMap => ZookeeperReconcileStep::AfterExistsStatefulSet,
         SubResource::StatefulSet => ZookeeperReconcileStep::AfterUpdateStatus,
     }
@@ -352,6 +359,15 @@ pub open spec fn zk_create_node_request_msg(zk: ZookeeperClusterView) -> spec_fn
         && msg.content.get_ExternalAPIRequest_0().get_CreateRequest_3() == zk_node_path(zk)
 }
 
+pub open spec fn zk_reconfig_request_msg(zk: ZookeeperClusterView) -> spec_fn(ZKMessage) -> bool {
+    |msg: ZKMessage|
+        msg.dst.is_ExternalAPI()
+        && msg.content.is_ExternalAPIRequest()
+        && msg.content.get_ExternalAPIRequest_0().is_ReconfigRequest()
+        && msg.content.get_ExternalAPIRequest_0().get_ReconfigRequest_0() == zk.metadata.name.get_Some_0()
+        && msg.content.get_ExternalAPIRequest_0().get_ReconfigRequest_1() == zk.metadata.namespace.get_Some_0()
+}
+
 pub open spec fn pending_req_in_flight_at_after_create_resource_step(
     sub_resource: SubResource, zk: ZookeeperClusterView
 ) -> StatePred<ZKCluster> {
@@ -798,5 +814,67 @@ pub open spec fn resp_msg_is_the_in_flight_ok_resp_at_after_create_zk_node_step(
     }
 }
 
+pub open spec fn pending_req_in_flight_at_after_reconfig_zk_step(zk: ZookeeperClusterView) -> StatePred<ZKCluster> {
+    |s: ZKCluster| {
+        let step = ZookeeperReconcileStep::AfterReconfigZK;
+        let msg = s.ongoing_reconciles()[zk.object_ref()].pending_req_msg.get_Some_0();
+        let request = msg.content.get_ExternalAPIRequest_0();
+        &&& at_zk_step_with_zk(zk, step)(s)
+        &&& s.ongoing_reconciles()[zk.object_ref()].pending_req_msg.is_Some()
+        &&& s.in_flight().contains(msg)
+        &&& msg.src == HostId::CustomController
+        &&& msg.dst == HostId::ExternalAPI
+        &&& msg.content.is_ExternalAPIRequest()
+        &&& request == zk_reconfig_request(zk)
+    }
+}
+
+pub open spec fn req_msg_is_the_in_flight_pending_req_at_after_reconfig_zk_step(zk: ZookeeperClusterView, req_msg: ZKMessage) -> StatePred<ZKCluster> {
+    |s: ZKCluster| {
+        let step = ZookeeperReconcileStep::AfterReconfigZK;
+        let request = req_msg.content.get_ExternalAPIRequest_0();
+        &&& at_zk_step_with_zk(zk, step)(s)
+        &&& ZKCluster::pending_req_msg_is(s, zk.object_ref(), req_msg)
+        &&& s.in_flight().contains(req_msg)
+        &&& req_msg.src == HostId::CustomController
+        &&& req_msg.dst == HostId::ExternalAPI
+        &&& req_msg.content.is_ExternalAPIRequest()
+        &&& request == zk_reconfig_request(zk)
+    }
+}
+
+pub open spec fn at_after_reconfig_zk_step_and_exists_ok_resp_in_flight(zk: ZookeeperClusterView) -> StatePred<ZKCluster> {
+    |s: ZKCluster| {
+        let step = ZookeeperReconcileStep::AfterReconfigZK;
+        let msg = s.ongoing_reconciles()[zk.object_ref()].pending_req_msg.get_Some_0();
+        &&& at_zk_step_with_zk(zk, step)(s)
+        &&& s.ongoing_reconciles()[zk.object_ref()].pending_req_msg.is_Some()
+        &&& msg.src == HostId::CustomController
+        &&& msg.dst == HostId::ExternalAPI
+        &&& msg.content.is_ExternalAPIRequest()
+        &&& exists |resp_msg: ZKMessage| {
+            let resp = resp_msg.content.get_ExternalAPIResponse_0();
+            &&& #[trigger] s.in_flight().contains(resp_msg)
+            &&& Message::resp_msg_matches_req_msg(resp_msg, msg)
+            &&& resp == ZKAPIOutputView::ReconfigResponse(ZKAPIReconfigResultView{res: Ok(())})
+        }
+    }
+}
+
+pub open spec fn resp_msg_is_the_in_flight_ok_resp_at_after_reconfig_zk_step(zk: ZookeeperClusterView, resp_msg: ZKMessage) -> StatePred<ZKCluster> {
+    |s: ZKCluster| {
+        let step = ZookeeperReconcileStep::AfterReconfigZK;
+        let msg = s.ongoing_reconciles()[zk.object_ref()].pending_req_msg.get_Some_0();
+        let resp = resp_msg.content.get_ExternalAPIResponse_0();
+        &&& at_zk_step_with_zk(zk, step)(s)
+        &&& s.ongoing_reconciles()[zk.object_ref()].pending_req_msg.is_Some()
+        &&& msg.src == HostId::CustomController
+        &&& msg.dst == HostId::ExternalAPI
+        &&& msg.content.is_ExternalAPIRequest()
+        &&& s.in_flight().contains(resp_msg)
+        &&& Message::resp_msg_matches_req_msg(resp_msg, msg)
+        &&& resp == ZKAPIOutputView::ReconfigResponse(ZKAPIReconfigResultView{res: Ok(())})
+    }
+}
 
 }
@@ -24,6 +24,7 @@ pub open spec fn get_request(sub_resource: SubResource, zookeeper: ZookeeperClus
         SubResource::HeadlessService => HeadlessServiceBuilder::get_request(zookeeper),
         SubResource::ClientService => ClientServiceBuilder::get_request(zookeeper),
         SubResource::AdminServerService => AdminServerServiceBuilder::get_request(zookeeper),
+        SubResource::PodDisruptionBudget => PodDisruptionBudgetBuilder::get_request(zookeeper),
         SubResource::ConfigMap => ConfigMapBuilder::get_request(zookeeper),
         SubResource::StatefulSet => StatefulSetBuilder::get_request(zookeeper),
     }
@@ -34,6 +35,7 @@ pub open spec fn make(sub_resource: SubResource, zookeeper: ZookeeperClusterView
         SubResource::HeadlessService => HeadlessServiceBuilder::make(zookeeper, state),
         SubResource::ClientService => ClientServiceBuilder::make(zookeeper, state),
         SubResource::AdminServerService => AdminServerServiceBuilder::make(zookeeper, state),
+        SubResource::PodDisruptionBudget => PodDisruptionBudgetBuilder::make(zookeeper, state),
         SubResource::ConfigMap => ConfigMapBuilder::make(zookeeper, state),
         SubResource::StatefulSet => StatefulSetBuilder::make(zookeeper, state),
     }
@@ -44,6 +46,7 @@ pub open spec fn update(sub_resource: SubResource, zookeeper: ZookeeperClusterVi
         SubResource::HeadlessService => HeadlessServiceBuilder::update(zookeeper, state, obj),
         SubResource::ClientService => ClientServiceBuilder::update(zookeeper, state, obj),
         SubResource::AdminServerService => AdminServerServiceBuilder::update(zookeeper, state, obj),
+        SubResource::PodDisruptionBudget => PodDisruptionBudgetBuilder::update(zookeeper, state, obj),
         SubResource::ConfigMap => ConfigMapBuilder::update(zookeeper, state, obj),
         SubResource::StatefulSet => StatefulSetBuilder::update(zookeeper, state, obj),
     }
@@ -56,6 +59,7 @@ pub open spec fn state_after_create(
         SubResource::HeadlessService => HeadlessServiceBuilder::state_after_create(zookeeper, obj, state),
         SubResource::ClientService => ClientServiceBuilder::state_after_create(zookeeper, obj, state),
         SubResource::AdminServerService => AdminServerServiceBuilder::state_after_create(zookeeper, obj, state),
+        SubResource::PodDisruptionBudget => PodDisruptionBudgetBuilder::state_after_create(zookeeper, obj, state),
         SubResource::ConfigMap => ConfigMapBuilder::state_after_create(zookeeper, obj, state),
         SubResource::StatefulSet => StatefulSetBuilder::state_after_create(zookeeper, obj, state),
     }
@@ -68,6 +72,7 @@ pub open spec fn state_after_update(
         SubResource::HeadlessService => HeadlessServiceBuilder::state_after_update(zookeeper, obj, state),
         SubResource::ClientService => ClientServiceBuilder::state_after_update(zookeeper, obj, state),
         SubResource::AdminServerService => AdminServerServiceBuilder::state_after_update(zookeeper, obj, state),
+        SubResource::PodDisruptionBudget => PodDisruptionBudgetBuilder::state_after_update(zookeeper, obj, state),
         SubResource::ConfigMap => ConfigMapBuilder::state_after_update(zookeeper, obj, state),
         SubResource::StatefulSet => StatefulSetBuilder::state_after_update(zookeeper, obj, state),
     }
@@ -0,0 +1,534 @@
+// Copyright 2022 VMware, Inc.
+// SPDX-License-Identifier: MIT
+#![allow(unused_imports)]
+use crate::external_api::exec::*;
+use crate::kubernetes_api_objects::exec::{
+    condition::*, container::*, pod_template_spec::*, prelude::*, resource::ResourceWrapper,
+    volume::*, volume_resource_requirements::*,
+};
+use crate::reconciler::exec::{io::*, reconciler::*};
+use crate::vstd_ext::{string_map::StringMap, string_view::*};
+use crate::zookeeper_controller::exec::resource::make_data_pvc_name;
+use crate::zookeeper_controller::model::resource as model_resource;
+use crate::zookeeper_controller::restore::model::reconciler as model_reconciler;
+use crate::zookeeper_controller::restore::trusted::{exec_types::*, spec_types, step::*};
+use crate::zookeeper_controller::trusted::exec_types::ZookeeperCluster;
+use vstd::{prelude::*, seq_lib::*, string::*};
+
+verus! {
+
+pub struct ZookeeperRestoreReconciler {}
+
+impl Reconciler for ZookeeperRestoreReconciler {
+    type R = ZookeeperRestore;
+    type T = ZookeeperRestoreReconcileState;
+    type ExternalAPIType = EmptyAPIShimLayer;
+
+    open spec fn well_formed(restore: &ZookeeperRestore) -> bool { restore@.well_formed() }
+
+    fn reconcile_init_state() -> ZookeeperRestoreReconcileState {
+        reconcile_init_state()
+    }
+
+    fn reconcile_core(restore: &ZookeeperRestore, resp_o: Option<Response<EmptyType>>, state: ZookeeperRestoreReconcileState)
+    -> (ZookeeperRestoreReconcileState, Option<Request<EmptyType>>) {
+        reconcile_core(restore, resp_o, state)
+    }
+
+    fn reconcile_done(state: &ZookeeperRestoreReconcileState) -> bool {
+        reconcile_done(state)
+    }
+
+    fn reconcile_error(state: &ZookeeperRestoreReconcileState) -> bool {
+        reconcile_error(state)
+    }
+}
+
+pub fn reconcile_init_state() -> (state: ZookeeperRestoreReconcileState)
+    ensures state@ == model_reconciler::reconcile_init_state(),
+{
+    ZookeeperRestoreReconcileState { reconcile_step: ZookeeperRestoreReconcileStep::Init }
+}
+
+pub fn reconcile_done(state: &ZookeeperRestoreReconcileState) -> (res: bool)
+    ensures res == model_reconciler::reconcile_done(state@),
+{
+    match state.reconcile_step {
+        ZookeeperRestoreReconcileStep::Done => true,
+        _ => false,
+    }
+}
+
+pub fn reconcile_error(state: &ZookeeperRestoreReconcileState) -> (res: bool)
+    ensures res == model_reconciler::reconcile_error(state@),
+{
+    match state.reconcile_step {
+        ZookeeperRestoreReconcileStep::Error => true,
+        _ => false,
+    }
+}
+
+pub fn reconcile_core(restore: &ZookeeperRestore, resp_o: Option<Response<EmptyType>>, state: ZookeeperRestoreReconcileState) -> (res: (ZookeeperRestoreReconcileState, Option<Request<EmptyType>>))
+    requires restore@.well_formed(),
+    ensures (res.0@, opt_request_to_view(&res.1)) == model_reconciler::reconcile_core(restore@, opt_response_to_view(&resp_o), state@),
+{
+    let step = state.reconcile_step;
+    match step {
+        ZookeeperRestoreReconcileStep::Init => {
+            let req_o = KubeAPIRequest::GetRequest(KubeGetRequest {
+                api_resource: ZookeeperCluster::api_resource(),
+                name: restore.spec().zookeeper_cluster().name(),
+                namespace: restore.metadata().namespace().unwrap(),
+            });
+            let state_prime = ZookeeperRestoreReconcileState {
+                reconcile_step: ZookeeperRestoreReconcileStep::AfterKRequestStep(ActionKind::Get, SubResource::ZookeeperCluster),
+                ..state
+            };
+            return (state_prime, Some(Request::KRequest(req_o)));
+        },
+        ZookeeperRestoreReconcileStep::AfterKRequestStep(ActionKind::Get, SubResource::ZookeeperCluster) => {
+            if resp_o.is_some() && resp_o.as_ref().unwrap().is_k_response()
+            && resp_o.as_ref().unwrap().as_k_response_ref().is_get_response() {
+                let get_resp = resp_o.unwrap().into_k_response().into_get_response().res;
+                if get_resp.is_ok() {
+                    if is_restored_cluster(restore, get_resp.unwrap()) {
+                        let req_o = KubeAPIRequest::UpdateStatusRequest(KubeUpdateStatusRequest {
+                            api_resource: ZookeeperRestore::api_resource(),
+                            name: restore.metadata().name().unwrap(),
+                            namespace: restore.metadata().namespace().unwrap(),
+                            obj: update_restore_status(restore, true).marshal(),
+                        });
+                        let state_prime = ZookeeperRestoreReconcileState {
+                            reconcile_step: ZookeeperRestoreReconcileStep::AfterUpdateStatus,
+                            ..state
+                        };
+                        return (state_prime, Some(Request::KRequest(req_o)));
+                    }
+                } else if get_resp.unwrap_err().is_object_not_found() {
+                    let req_o = KubeAPIRequest::GetRequest(KubeGetRequest {
+                        api_resource: Job::api_resource(),
+                        name: make_restore_job_name(restore),
+                        namespace: restore.metadata().namespace().unwrap(),
+                    });
+                    let state_prime = ZookeeperRestoreReconcileState {
+                        reconcile_step: ZookeeperRestoreReconcileStep::AfterKRequestStep(ActionKind::Get, SubResource::RestoreJob),
+                        ..state
+                    };
+                    return (state_prime, Some(Request::KRequest(req_o)));
+                }
+            }
+            return report_error(restore, state);
+        },
+        ZookeeperRestoreReconcileStep::AfterKRequestStep(ActionKind::Get, SubResource::RestoreJob) => {
+            if resp_o.is_some() && resp_o.as_ref().unwrap().is_k_response()
+            && resp_o.as_ref().unwrap().as_k_response_ref().is_get_response() {
+                let get_resp = resp_o.unwrap().into_k_response().into_get_response().res;
+                if get_resp.is_ok() {
+                    let job = Job::unmarshal(get_resp.unwrap());
+                    if job.is_ok() {
+                        if job.unwrap().succeeded() {
+                            let req_o = KubeAPIRequest::GetRequest(KubeGetRequest {
+                                api_resource: PersistentVolumeClaim::api_resource(),
+                                name: make_data_pvc_name(&make_zookeeper_cluster(restore)),
+                                namespace: restore.metadata().namespace().unwrap(),
+                            });
+                            let state_prime = ZookeeperRestoreReconcileState {
+                                reconcile_step: ZookeeperRestoreReconcileStep::AfterKRequestStep(ActionKind::Get, SubResource::DataPVC),
+                                ..state
+                            };
+                            return (state_prime, Some(Request::KRequest(req_o)));
+                        } else {
+                            let req_o = KubeAPIRequest::UpdateStatusRequest(KubeUpdateStatusRequest {
+                                api_resource: ZookeeperRestore::api_resource(),
+                                name: restore.metadata().name().unwrap(),
+                                namespace: restore.metadata().namespace().unwrap(),
+                                obj: update_restore_status(restore, false).marshal(),
+                            });
+                            let state_prime = ZookeeperRestoreReconcileState {
+                                reconcile_step: ZookeeperRestoreReconcileStep::AfterUpdateStatus,
+                                ..state
+                            };
+                            return (state_prime, Some(Request::KRequest(req_o)));
+                        }
+                    }
+                } else if get_resp.unwrap_err().is_object_not_found() {
+                    let req_o = KubeAPIRequest::CreateRequest(KubeCreateRequest {
+                        api_resource: PersistentVolumeClaim::api_resource(),
+                        namespace: restore.metadata().namespace().unwrap(),
+                        obj: make_data_pvc(restore).marshal(),
+                    });
+                    let state_prime = ZookeeperRestoreReconcileState {
+                        reconcile_step: ZookeeperRestoreReconcileStep::AfterKRequestStep(ActionKind::Create, SubResource::DataPVC),
+                        ..state
+                    };
+                    return (state_prime, Some(Request::KRequest(req_o)));
+                }
+            }
+            return report_error(restore, state);
+        },
+        ZookeeperRestoreReconcileStep::AfterKRequestStep(ActionKind::Get, SubResource::DataPVC) => {
+            if resp_o.is_some() && resp_o.as_ref().unwrap().is_k_response()
+            && resp_o.as_ref().unwrap().as_k_response_ref().is_get_response() {
+                let get_resp = resp_o.unwrap().into_k_response().into_get_response().res;
+                if get_resp.is_ok() {
+                    let pvc = PersistentVolumeClaim::unmarshal(get_resp.unwrap());
+                    if pvc.is_ok() {
+                        if pvc.unwrap().bound() {
+                            let req_o = KubeAPIRequest::CreateRequest(KubeCreateRequest {
+                                api_resource: ZookeeperCluster::api_resource(),
+                                namespace: restore.metadata().namespace().unwrap(),
+                                obj: make_zookeeper_cluster(restore).marshal(),
+                            });
+                            let state_prime = ZookeeperRestoreReconcileState {
+                                reconcile_step: ZookeeperRestoreReconcileStep::AfterKRequestStep(ActionKind::Create, SubResource::ZookeeperCluster),
+                                ..state
+                            };
+                            return (state_prime, Some(Request::KRequest(req_o)));
+                        } else {
+                            let req_o = KubeAPIRequest::UpdateStatusRequest(KubeUpdateStatusRequest {
+                                api_resource: ZookeeperRestore::api_resource(),
+                                name: restore.metadata().name().unwrap(),
+                                namespace: restore.metadata().namespace().unwrap(),
+                                obj: update_restore_status(restore, false).marshal(),
+                            });
+                            let state_prime = ZookeeperRestoreReconcileState {
+                                reconcile_step: ZookeeperRestoreReconcileStep::AfterUpdateStatus,
+                                ..state
+                            };
+                            return (state_prime, Some(Request::KRequest(req_o)));
+                        }
+                    }
+                }
+            }
+            return report_error(restore, state);
+        },
+        ZookeeperRestoreReconcileStep::AfterKRequestStep(ActionKind::Create, SubResource::DataPVC) => {
+            if resp_o.is_some() && resp_o.as_ref().unwrap().is_k_response()
+            && resp_o.as_ref().unwrap().as_k_response_ref().is_create_response() {
+                let create_resp = resp_o.unwrap().into_k_response().into_create_response().res;
+                if create_resp.is_ok() || create_resp.unwrap_err().is_object_already_exists() {
+                    let req_o = KubeAPIRequest::CreateRequest(KubeCreateRequest {
+                        api_resource: Job::api_resource(),
+                        namespace: restore.metadata().namespace().unwrap(),
+                        obj: make_restore_job(restore).marshal(),
+                    });
+                    let state_prime = ZookeeperRestoreReconcileState {
+                        reconcile_step: ZookeeperRestoreReconcileStep::AfterKRequestStep(ActionKind::Create, SubResource::RestoreJob),
+                        ..state
+                    };
+                    return (state_prime, Some(Request::KRequest(req_o)));
+                }
+            }
+            return report_error(restore, state);
+        },
+        ZookeeperRestoreReconcileStep::AfterKRequestStep(ActionKind::Create, sub_resource) => {
+            if resp_o.is_some() && resp_o.as_ref().unwrap().is_k_response()
+            && resp_o.as_ref().unwrap().as_k_response_ref().is_create_response()
+            && resp_o.as_ref().unwrap().as_k_response_ref().as_create_response_ref().res.is_ok() {
+                let ready = match sub_resource {
+                    SubResource::ZookeeperCluster => true,
+                    _ => false,
+                };
+                let req_o = KubeAPIRequest::UpdateStatusRequest(KubeUpdateStatusRequest {
+                    api_resource: ZookeeperRestore::api_resource(),
+                    name: restore.metadata().name().unwrap(),
+                    namespace: restore.metadata().namespace().unwrap(),
+                    obj: update_restore_status(restore, ready).marshal(),
+                });
+                let state_prime = ZookeeperRestoreReconcileState {
+                    reconcile_step: ZookeeperRestoreReconcileStep::AfterUpdateStatus,
+                    ..state
+                };
+                return (state_prime, Some(Request::KRequest(req_o)));
+            }
+            return report_error(restore, state);
+        },
+        ZookeeperRestoreReconcileStep::AfterUpdateStatus => {
+            if resp_o.is_some() && resp_o.as_ref().unwrap().is_k_response()
+            && resp_o.as_ref().unwrap().as_k_response_ref().is_update_status_response() {
+                let update_status_resp = resp_o.unwrap().into_k_response().into_update_status_response().res;
+                if update_status_resp.is_ok() {
+                    let state_prime = ZookeeperRestoreReconcileState {
+                        reconcile_step: ZookeeperRestoreReconcileStep::Done,
+                        ..state
+                    };
+                    return (state_prime, None);
+                }
+            }
+            let state_prime = ZookeeperRestoreReconcileState {
+                reconcile_step: ZookeeperRestoreReconcileStep::Error,
+                ..state
+            };
+            return (state_prime, None);
+        },
+        ZookeeperRestoreReconcileStep::AfterUpdateDegradedStatus => {
+            let state_prime = ZookeeperRestoreReconcileState {
+                reconcile_step: ZookeeperRestoreReconcileStep::Error,
+                ..state
+            };
+            return (state_prime, None);
+        },
+        _ => {
+            let state_prime = ZookeeperRestoreReconcileState {
+                reconcile_step: step,
+                ..state
+            };
+            return (state_prime, None);
+        }
+    }
+}
+
+pub fn make_zookeeper_cluster(restore: &ZookeeperRestore) -> (zk: ZookeeperCluster)
+    requires restore@.well_formed(),
+    ensures zk@ == model_reconciler::make_zookeeper_cluster(restore@),
+{
+    let mut metadata = ObjectMeta::default();
+    metadata.set_name(restore.spec().zookeeper_cluster().name());
+    metadata.set_annotations({
+        let mut annotations = StringMap::empty();
+        annotations.insert("anvil.dev/restored-from".to_string(), restore.metadata().name().unwrap());
+        annotations
+    });
+    ZookeeperCluster::new(metadata, restore.spec().zookeeper_cluster().spec())
+}
+
+pub fn is_restored_cluster(restore: &ZookeeperRestore, obj: DynamicObject) -> (res: bool)
+    requires restore@.well_formed(),
+    ensures res == model_reconciler::is_restored_cluster(restore@, obj@),
+{
+    let annotations = obj.metadata().annotations();
+    if ZookeeperCluster::unmarshal(obj).is_ok() && annotations.is_some() {
+        let restored_from = annotations.unwrap().get(&"anvil.dev/restored-from".to_string());
+        restored_from.is_some() && restored_from.unwrap().eq(&restore.metadata().name().unwrap())
+    } else {
+        false
+    }
+}
+
+pub fn make_data_pvc(restore: &ZookeeperRestore) -> (pvc: PersistentVolumeClaim)
+    requires restore@.well_formed(),
+    ensures pvc@ == model_reconciler::make_data_pvc(restore@),
+{
+    let zk = make_zookeeper_cluster(restore);
+    let mut pvc = PersistentVolumeClaim::default();
+    pvc.set_metadata({
+        let mut metadata = ObjectMeta::default();
+        metadata.set_name(make_data_pvc_name(&zk));
+        metadata.set_labels({
+            let mut labels = StringMap::empty();
+            labels.insert("app".to_string(), zk.metadata().name().unwrap());
+            proof { assert_maps_equal!(labels@ == model_resource::make_base_labels(zk@)); }
+            labels
+        });
+        metadata
+    });
+    pvc.set_spec({
+        let mut pvc_spec = PersistentVolumeClaimSpec::default();
+        pvc_spec.set_access_modes({
+            let mut access_modes = Vec::new();
+            access_modes.push("ReadWriteOnce".to_string());
+            proof {
+                assert_seqs_equal!(
+                    access_modes@.map_values(|mode: String| mode@),
+                    model_reconciler::make_data_pvc(restore@).spec.get_Some_0().access_modes.get_Some_0()
+                );
+            }
+            access_modes
+        });
+        pvc_spec.set_resources({
+            let mut resources = VolumeResourceRequirements::default();
+            resources.set_requests({
+                let mut requests = StringMap::empty();
+                requests.insert("storage".to_string(), zk.spec().persistence().storage_size());
+                requests
+            });
+            resources
+        });
+        pvc_spec.set_storage_class_name(zk.spec().persistence().storage_class_name());
+        pvc_spec
+    });
+    pvc
+}
+
+pub fn make_restore_job_name(restore: &ZookeeperRestore) -> (name: String)
+    requires restore@.well_formed(),
+    ensures name@ == model_reconciler::make_restore_job_name(restore@),
+{
+    restore.metadata().name().unwrap().concat("-restore")
+}
+
+pub fn make_restore_command() -> (command: String)
+    ensures command@ == model_reconciler::make_restore_command(),
+{
+    "cp -a \"/backup/${BACKUP_NAME}/.\" /data/".to_string()
+}
+
+pub fn make_restore_env(restore: &ZookeeperRestore) -> (env_vars: Vec<EnvVar>)
+    ensures env_vars@.map_values(|v: EnvVar| v@) == model_reconciler::make_restore_env(restore@),
+{
+    let mut env_vars = Vec::new();
+    env_vars.push({
+        let mut env_var = EnvVar::default();
+        env_var.set_name("BACKUP_NAME".to_string());
+        env_var.set_value(restore.spec().backup_name());
+        env_var
+    });
+    proof {
+        assert_seqs_equal!(env_vars@.map_values(|v: EnvVar| v@), model_reconciler::make_restore_env(restore@));
+    }
+    env_vars
+}
+
+pub fn make_restore_job(restore: &ZookeeperRestore) -> (job: Job)
+    requires restore@.well_formed(),
+    ensures job@ == model_reconciler::make_restore_job(restore@),
+{
+    let mut job = Job::default();
+    job.set_metadata({
+        let mut metadata = ObjectMeta::default();
+        metadata.set_name(make_restore_job_name(restore));
+        metadata
+    });
+    job.set_spec({
+        let mut job_spec = JobSpec::default();
+        job_spec.set_backoff_limit(3);
+        job_spec.set_template({
+            let mut pod_template_spec = PodTemplateSpec::default();
+            pod_template_spec.set_spec({
+                let mut pod_spec = PodSpec::default();
+                pod_spec.set_restart_policy("OnFailure".to_string());
+                pod_spec.set_containers({
+                    let mut containers = Vec::new();
+                    containers.push({
+                        let mut container = Container::default();
+                        container.set_name("restore".to_string());
+                        container.set_image(restore.spec().zookeeper_cluster().spec().image());
+                        container.set_command({
+                            let mut command = Vec::new();
+                            command.push("/bin/sh".to_string());
+                            command.push("-c".to_string());
+                            command.push(make_restore_command());
+                            proof {
+                                assert_seqs_equal!(
+                                    command@.map_values(|s: String| s@),
+                                    seq!["/bin/sh"@, "-c"@, model_reconciler::make_restore_command()]
+                                );
+                            }
+                            command
+                        });
+                        container.set_env(make_restore_env(restore));
+                        container.set_volume_mounts({
+                            let mut volume_mounts = Vec::new();
+                            volume_mounts.push({
+                                let mut data_volume_mount = VolumeMount::default();
+                                data_volume_mount.set_name("data".to_string());
+                                data_volume_mount.set_mount_path("/data".to_string());
+                                data_volume_mount
+                            });
+                            volume_mounts.push({
+                                let mut backup_volume_mount = VolumeMount::default();
+                                backup_volume_mount.set_name("backup".to_string());
+                                backup_volume_mount.set_mount_path("/backup".to_string());
+                                backup_volume_mount.set_read_only(true);
+                                backup_volume_mount
+                            });
+                            proof {
+                                assert_seqs_equal!(
+                                    volume_mounts@.map_values(|volume_mount: VolumeMount| volume_mount@),
+                                    model_reconciler::make_restore_job(restore@).spec.get_Some_0().template.spec.get_Some_0().containers[0].volume_mounts.get_Some_0()
+                                );
+                            }
+                            volume_mounts
+                        });
+                        container
+                    });
+                    proof {
+                        assert_seqs_equal!(
+                            containers@.map_values(|container: Container| container@),
+                            model_reconciler::make_restore_job(restore@).spec.get_Some_0().template.spec.get_Some_0().containers
+                        );
+                    }
+                    containers
+                });
+                pod_spec.set_volumes({
+                    let mut volumes = Vec::new();
+                    volumes.push({
+                        let mut data_volume = Volume::default();
+                        data_volume.set_name("data".to_string());
+                        data_volume.set_persistent_volume_claim({
+                            let mut pvc_source = PersistentVolumeClaimVolumeSource::default();
+                            pvc_source.set_claim_name(make_data_pvc_name(&make_zookeeper_cluster(restore)));
+                            pvc_source
+                        });
+                        data_volume
+                    });
+                    volumes.push({
+                        let mut backup_volume = Volume::default();
+                        backup_volume.set_name("backup".to_string());
+                        backup_volume.set_persistent_volume_claim({
+                            let mut pvc_source = PersistentVolumeClaimVolumeSource::default();
+                            pvc_source.set_claim_name(restore.spec().persistent_volume_claim());
+                            pvc_source.set_read_only(true);
+                            pvc_source
+                        });
+                        backup_volume
+                    });
+                    proof {
+                        assert_seqs_equal!(
+                            volumes@.map_values(|vol: Volume| vol@),
+                            model_reconciler::make_restore_job(restore@).spec.get_Some_0().template.spec.get_Some_0().volumes.get_Some_0()
+                        );
+                    }
+                    volumes
+                });
+                pod_spec
+            });
+            pod_template_spec
+        });
+        job_spec
+    });
+    job
+}
+
+pub fn update_restore_status(restore: &ZookeeperRestore, ready: bool) -> (updated_restore: ZookeeperRestore)
+    ensures updated_restore@ == model_reconciler::update_restore_status(restore@, ready),
+{
+    let mut updated_restore = restore.clone();
+    updated_restore.set_status({
+        let mut status = ZookeeperRestoreStatus::default();
+        status.set_conditions(make_conditions(ready, false, restore.metadata().generation()));
+        status
+    });
+    updated_restore
+}
+
+pub fn update_restore_status_to_degraded(restore: &ZookeeperRestore) -> (updated_restore: ZookeeperRestore)
+    ensures updated_restore@ == model_reconciler::update_restore_status_to_degraded(restore@),
+{
+    let mut updated_restore = restore.clone();
+    updated_restore.set_status({
+        let mut status = ZookeeperRestoreStatus::default();
+        status.set_conditions(make_conditions(false, true, restore.metadata().generation()));
+        status
+    });
+    updated_restore
+}
+
+pub fn report_error(restore: &ZookeeperRestore, state: ZookeeperRestoreReconcileState) -> (res: (ZookeeperRestoreReconcileState, Option<Request<EmptyType>>))
+    requires restore@.well_formed(),
+    ensures (res.0@, opt_request_to_view(&res.1)) == model_reconciler::report_error(restore@, state@),
+{
+    let req_o = KubeAPIRequest::UpdateStatusRequest(KubeUpdateStatusRequest {
+        api_resource: ZookeeperRestore::api_resource(),
+        name: restore.metadata().name().unwrap(),
+        namespace: restore.metadata().namespace().unwrap(),
+        obj: update_restore_status_to_degraded(restore).marshal(),
+    });
+    let state_prime = ZookeeperRestoreReconcileState {
+        reconcile_step: ZookeeperRestoreReconcileStep::AfterUpdateDegradedStatus,
+        ..state
+    };
+    (state_prime, Some(Request::KRequest(req_o)))
+}
+
+}
@@ -0,0 +1,388 @@
+// Copyright 2022 VMware, Inc.
+// SPDX-License-Identifier: MIT
+#![allow(unused_imports)]
+use crate::external_api::spec::*;
+use crate::kubernetes_api_objects::spec::{
+    condition::*, container::*, pod_template_spec::*, prelude::*, volume::*,
+    volume_resource_requirements::*,
+};
+use crate::reconciler::spec::{io::*, reconciler::*};
+use crate::vstd_ext::string_view::*;
+use crate::zookeeper_controller::model::resource::{make_base_labels, make_data_pvc_name};
+use crate::zookeeper_controller::restore::trusted::{spec_types::*, step::*};
+use crate::zookeeper_controller::trusted::spec_types::ZookeeperClusterView;
+use vstd::{prelude::*, string::*};
+
+verus! {
+
+impl Reconciler<ZookeeperRestoreView, EmptyAPI> for ZookeeperRestoreReconciler {
+    type T = ZookeeperRestoreReconcileState;
+
+    open spec fn reconcile_init_state() -> ZookeeperRestoreReconcileState {
+        reconcile_init_state()
+    }
+
+    open spec fn reconcile_core(restore: ZookeeperRestoreView, resp_o: Option<ResponseView<EmptyTypeView>>, state: ZookeeperRestoreReconcileState)
+    -> (ZookeeperRestoreReconcileState, Option<RequestView<EmptyTypeView>>) {
+        reconcile_core(restore, resp_o, state)
+    }
+
+    open spec fn reconcile_done(state: ZookeeperRestoreReconcileState) -> bool {
+        reconcile_done(state)
+    }
+
+    open spec fn reconcile_error(state: ZookeeperRestoreReconcileState) -> bool {
+        reconcile_error(state)
+    }
+
+    open spec fn expect_from_user(obj: DynamicObjectView) -> bool {
+        false /* Don't expect anything from the user except the cr object */
+    }
+}
+
+pub open spec fn reconcile_init_state() -> ZookeeperRestoreReconcileState { ZookeeperRestoreReconcileState { reconcile_step: ZookeeperRestoreReconcileStep::Init } }
+
+pub open spec fn reconcile_done(state: ZookeeperRestoreReconcileState) -> bool {
+    match state.reconcile_step {
+        ZookeeperRestoreReconcileStep::Done => true,
+        _ => false,
+    }
+}
+
+pub open spec fn reconcile_error(state: ZookeeperRestoreReconcileState) -> bool {
+    match state.reconcile_step {
+        ZookeeperRestoreReconcileStep::Error => true,
+        _ => false,
+    }
+}
+
+// The restore runs before the new cluster exists: the data volume of the first replica is created
+// and filled by the restore job, and only once the job has succeeded and the volume is bound
+// the ZookeeperCluster object is created, so that the stateful set adopts the restored volume
+// and no server starts with an empty data directory.
+pub open spec fn reconcile_core(
+    restore: ZookeeperRestoreView, resp_o: Option<ResponseView<EmptyTypeView>>, state: ZookeeperRestoreReconcileState
+) -> (ZookeeperRestoreReconcileState, Option<RequestView<EmptyTypeView>>) {
+    let step = state.reconcile_step;
+    let resp = resp_o.get_Some_0();
+    match step {
+        ZookeeperRestoreReconcileStep::Init => {
+            let req_o = APIRequest::GetRequest(GetRequest { key: make_zookeeper_cluster_key(restore) });
+            let state_prime = ZookeeperRestoreReconcileState {
+                reconcile_step: ZookeeperRestoreReconcileStep::AfterKRequestStep(ActionKind::Get, SubResource::ZookeeperCluster),
+                ..state
+            };
+            (state_prime, Some(RequestView::KRequest(req_o)))
+        },
+        ZookeeperRestoreReconcileStep::AfterKRequestStep(ActionKind::Get, SubResource::ZookeeperCluster) => {
+            let get_resp = resp.get_KResponse_0().get_GetResponse_0().res;
+            if resp_o.is_Some() && resp.is_KResponse() && resp.get_KResponse_0().is_GetResponse() {
+                if get_resp.is_Ok() && is_restored_cluster(restore, get_resp.get_Ok_0()) {
+                    let req_o = APIRequest::UpdateStatusRequest(UpdateStatusRequest {
+                        namespace: restore.metadata.namespace.get_Some_0(),
+                        name: restore.metadata.name.get_Some_0(),
+                        obj: update_restore_status(restore, true).marshal(),
+                    });
+                    let state_prime = ZookeeperRestoreReconcileState {
+                        reconcile_step: ZookeeperRestoreReconcileStep::AfterUpdateStatus,
+                        ..state
+                    };
+                    (state_prime, Some(RequestView::KRequest(req_o)))
+                } else if get_resp.is_Err() && get_resp.get_Err_0().is_ObjectNotFound() {
+                    let req_o = APIRequest::GetRequest(GetRequest { key: make_restore_job_key(restore) });
+                    let state_prime = ZookeeperRestoreReconcileState {
+                        reconcile_step: ZookeeperRestoreReconcileStep::AfterKRequestStep(ActionKind::Get, SubResource::RestoreJob),
+                        ..state
+                    };
+                    (state_prime, Some(RequestView::KRequest(req_o)))
+                } else {
+                    // A cluster with the same name that is not created by this restore is already serving,
+                    // so restoring into its volume would overwrite live data.
+                    report_error(restore, state)
+                }
+            } else {
+                report_error(restore, state)
+            }
+        },
+        ZookeeperRestoreReconcileStep::AfterKRequestStep(ActionKind::Get, SubResource::RestoreJob) => {
+            let get_resp = resp.get_KResponse_0().get_GetResponse_0().res;
+            if resp_o.is_Some() && resp.is_KResponse() && resp.get_KResponse_0().is_GetResponse() {
+                if get_resp.is_Ok() && JobView::unmarshal(get_resp.get_Ok_0()).is_Ok() {
+                    if JobView::unmarshal(get_resp.get_Ok_0()).get_Ok_0().succeeded() {
+                        let req_o = APIRequest::GetRequest(GetRequest { key: make_data_pvc_key(restore) });
+                        let state_prime = ZookeeperRestoreReconcileState {
+                            reconcile_step: ZookeeperRestoreReconcileStep::AfterKRequestStep(ActionKind::Get, SubResource::DataPVC),
+                            ..state
+                        };
+                        (state_prime, Some(RequestView::KRequest(req_o)))
+                    } else {
+                        let req_o = APIRequest::UpdateStatusRequest(UpdateStatusRequest {
+                            namespace: restore.metadata.namespace.get_Some_0(),
+                            name: restore.metadata.name.get_Some_0(),
+                            obj: update_restore_status(restore, false).marshal(),
+                        });
+                        let state_prime = ZookeeperRestoreReconcileState {
+                            reconcile_step: ZookeeperRestoreReconcileStep::AfterUpdateStatus,
+                            ..state
+                        };
+                        (state_prime, Some(RequestView::KRequest(req_o)))
+                    }
+                } else if get_resp.is_Err() && get_resp.get_Err_0().is_ObjectNotFound() {
+                    let req_o = APIRequest::CreateRequest(CreateRequest {
+                        namespace: restore.metadata.namespace.get_Some_0(),
+                        obj: make_data_pvc(restore).marshal(),
+                    });
+                    let state_prime = ZookeeperRestoreReconcileState {
+                        reconcile_step: ZookeeperRestoreReconcileStep::AfterKRequestStep(ActionKind::Create, SubResource::DataPVC),
+                        ..state
+                    };
+                    (state_prime, Some(RequestView::KRequest(req_o)))
+                } else {
+                    report_error(restore, state)
+                }
+            } else {
+                report_error(restore, state)
+            }
+        },
+        ZookeeperRestoreReconcileStep::AfterKRequestStep(ActionKind::Get, SubResource::DataPVC) => {
+            let get_resp = resp.get_KResponse_0().get_GetResponse_0().res;
+            if resp_o.is_Some() && resp.is_KResponse() && resp.get_KResponse_0().is_GetResponse()
+            && get_resp.is_Ok() && PersistentVolumeClaimView::unmarshal(get_resp.get_Ok_0()).is_Ok() {
+                // The stateful set only adopts the restored volume if the claim is still bound to it.
+                if PersistentVolumeClaimView::unmarshal(get_resp.get_Ok_0()).get_Ok_0().bound() {
+                    let req_o = APIRequest::CreateRequest(CreateRequest {
+                        namespace: restore.metadata.namespace.get_Some_0(),
+                        obj: make_zookeeper_cluster(restore).marshal(),
+                    });
+                    let state_prime = ZookeeperRestoreReconcileState {
+                        reconcile_step: ZookeeperRestoreReconcileStep::AfterKRequestStep(ActionKind::Create, SubResource::ZookeeperCluster),
+                        ..state
+                    };
+                    (state_prime, Some(RequestView::KRequest(req_o)))
+                } else {
+                    let req_o = APIRequest::UpdateStatusRequest(UpdateStatusRequest {
+                        namespace: restore.metadata.namespace.get_Some_0(),
+                        name: restore.metadata.name.get_Some_0(),
+                        obj: update_restore_status(restore, false).marshal(),
+                    });
+                    let state_prime = ZookeeperRestoreReconcileState {
+                        reconcile_step: ZookeeperRestoreReconcileStep::AfterUpdateStatus,
+                        ..state
+                    };
+                    (state_prime, Some(RequestView::KRequest(req_o)))
+                }
+            } else {
+                report_error(restore, state)
+            }
+        },
+        ZookeeperRestoreReconcileStep::AfterKRequestStep(ActionKind::Create, SubResource::DataPVC) => {
+            let create_resp = resp.get_KResponse_0().get_CreateResponse_0().res;
+            // The volume might be left from an earlier attempt that failed to create the job.
+            if resp_o.is_Some() && resp.is_KResponse() && resp.get_KResponse_0().is_CreateResponse()
+            && (create_resp.is_Ok() || create_resp.get_Err_0().is_ObjectAlreadyExists()) {
+                let req_o = APIRequest::CreateRequest(CreateRequest {
+                    namespace: restore.metadata.namespace.get_Some_0(),
+                    obj: make_restore_job(restore).marshal(),
+                });
+                let state_prime = ZookeeperRestoreReconcileState {
+                    reconcile_step: ZookeeperRestoreReconcileStep::AfterKRequestStep(ActionKind::Create, SubResource::RestoreJob),
+                    ..state
+                };
+                (state_prime, Some(RequestView::KRequest(req_o)))
+            } else {
+                report_error(restore, state)
+            }
+        },
+        ZookeeperRestoreReconcileStep::AfterKRequestStep(ActionKind::Create, sub_resource) => {
+            let create_resp = resp.get_KResponse_0().get_CreateResponse_0().res;
+            if resp_o.is_Some() && resp.is_KResponse() && resp.get_KResponse_0().is_CreateResponse()
+            && create_resp.is_Ok() {
+                // The restore is ready once the new cluster is created, not when the job finishes.
+                let req_o = APIRequest::UpdateStatusRequest(UpdateStatusRequest {
+                    namespace: restore.metadata.namespace.get_Some_0(),
+                    name: restore.metadata.name.get_Some_0(),
+                    obj: update_restore_status(restore, sub_resource.is_ZookeeperCluster()).marshal(),
+                });
+                let state_prime = ZookeeperRestoreReconcileState {
+                    reconcile_step: ZookeeperRestoreReconcileStep::AfterUpdateStatus,
+                    ..state
+                };
+                (state_prime, Some(RequestView::KRequest(req_o)))
+            } else {
+                report_error(restore, state)
+            }
+        },
+        ZookeeperRestoreReconcileStep::AfterUpdateStatus => {
+            let update_status_resp = resp.get_KResponse_0().get_UpdateStatusResponse_0().res;
+            if resp_o.is_Some() && resp.is_KResponse() && resp.get_KResponse_0().is_UpdateStatusResponse()
+            && update_status_resp.is_Ok() {
+                let state_prime = ZookeeperRestoreReconcileState {
+                    reconcile_step: ZookeeperRestoreReconcileStep::Done,
+                    ..state
+                };
+                (state_prime, None)
+            } else {
+                let state_prime = ZookeeperRestoreReconcileState {
+                    reconcile_step: ZookeeperRestoreReconcileStep::Error,
+                    ..state
+                };
+                (state_prime, None)
+            }
+        },
+        ZookeeperRestoreReconcileStep::AfterUpdateDegradedStatus => {
+            let state_prime = ZookeeperRestoreReconcileState {
+                reconcile_step: ZookeeperRestoreReconcileStep::Error,
+                ..state
+            };
+            (state_prime, None)
+        },
+        _ => {
+            let state_prime = ZookeeperRestoreReconcileState {
+                reconcile_step: step,
+                ..state
+            };
+            (state_prime, None)
+        }
+    }
+}
+
+pub open spec fn make_zookeeper_cluster_key(restore: ZookeeperRestoreView) -> ObjectRef {
+    ObjectRef {
+        kind: ZookeeperClusterView::kind(),
+        name: restore.spec.zookeeper_cluster.name,
+        namespace: restore.metadata.namespace.get_Some_0(),
+    }
+}
+
+// The new cluster is not owned by the restore, so deleting the restore leaves the cluster running.
+// The annotation records which restore created the cluster.
+pub open spec fn make_zookeeper_cluster(restore: ZookeeperRestoreView) -> ZookeeperClusterView {
+    ZookeeperClusterView::default()
+        .set_metadata(ObjectMetaView::default()
+            .set_name(restore.spec.zookeeper_cluster.name)
+            .set_annotations(Map::empty().insert("anvil.dev/restored-from"@, restore.metadata.name.get_Some_0()))
+        )
+        .set_spec(restore.spec.zookeeper_cluster.spec)
+}
+
+pub open spec fn is_restored_cluster(restore: ZookeeperRestoreView, obj: DynamicObjectView) -> bool {
+    let annotations = obj.metadata.annotations;
+    &&& ZookeeperClusterView::unmarshal(obj).is_Ok()
+    &&& annotations.is_Some()
+    &&& annotations.get_Some_0().contains_key("anvil.dev/restored-from"@)
+    &&& annotations.get_Some_0()["anvil.dev/restored-from"@] == restore.metadata.name.get_Some_0()
+}
+
+// The data volume matches the volume claim template of the stateful set, so the stateful set
+// adopts it as the volume of the first replica. Like the volumes created by the stateful set,
+// it is not owned by the restore.
+pub open spec fn make_data_pvc(restore: ZookeeperRestoreView) -> PersistentVolumeClaimView {
+    let zk = make_zookeeper_cluster(restore);
+    PersistentVolumeClaimView::default()
+        .set_metadata(ObjectMetaView::default()
+            .set_name(make_data_pvc_name(zk))
+            .set_labels(make_base_labels(zk))
+        )
+        .set_spec(PersistentVolumeClaimSpecView::default()
+            .set_access_modes(seq!["ReadWriteOnce"@])
+            .set_resources(VolumeResourceRequirementsView::default()
+                .set_requests(Map::empty()
+                    .insert("storage"@, zk.spec.persistence.storage_size)
+                )
+            )
+            .set_storage_class_name(zk.spec.persistence.storage_class_name)
+        )
+}
+
+pub open spec fn make_data_pvc_key(restore: ZookeeperRestoreView) -> ObjectRef {
+    ObjectRef {
+        kind: PersistentVolumeClaimView::kind(),
+        name: make_data_pvc_name(make_zookeeper_cluster(restore)),
+        namespace: restore.metadata.namespace.get_Some_0(),
+    }
+}
+
+pub open spec fn make_restore_job_name(restore: ZookeeperRestoreView) -> StringView {
+    restore.metadata.name.get_Some_0() + "-restore"@
+}
+
+pub open spec fn make_restore_job_key(restore: ZookeeperRestoreView) -> ObjectRef {
+    ObjectRef {
+        kind: JobView::kind(),
+        name: make_restore_job_name(restore),
+        namespace: restore.metadata.namespace.get_Some_0(),
+    }
+}
+
+// The backup is read from the directory that the ZookeeperBackup job copied the data to.
+// The backup name is passed through the BACKUP_NAME environment variable instead of being pasted
+// into the shell command, so it is never interpreted by the shell.
+pub open spec fn make_restore_command() -> StringView {
+    "cp -a \"/backup/${BACKUP_NAME}/.\" /data/"@
+}
+
+pub open spec fn make_restore_env(restore: ZookeeperRestoreView) -> Seq<EnvVarView> {
+    seq![EnvVarView::default().set_name("BACKUP_NAME"@).set_value(restore.spec.backup_name)]
+}
+
+// Like the data volume, the job is not owned by the restore: it is kept after the restore is deleted
+// because its status records that the data volume has been restored.
+pub open spec fn make_restore_job(restore: ZookeeperRestoreView) -> JobView {
+    JobView::default()
+        .set_metadata(ObjectMetaView::default()
+            .set_name(make_restore_job_name(restore))
+        )
+        .set_spec(JobSpecView::default()
+            .set_backoff_limit(3)
+            .set_template(PodTemplateSpecView::default()
+                .set_spec(PodSpecView::default()
+                    .set_restart_policy("OnFailure"@)
+                    .set_containers(seq![
+                        ContainerView::default()
+                            .set_name("restore"@)
+                            .set_image(restore.spec.zookeeper_cluster.spec.image)
+                            .set_command(seq!["/bin/sh"@, "-c"@, make_restore_command()])
+                            .set_env(make_restore_env(restore))
+                            .set_volume_mounts(seq![
+                                VolumeMountView::default().set_name("data"@).set_mount_path("/data"@),
+                                VolumeMountView::default().set_name("backup"@).set_mount_path("/backup"@).set_read_only(true),
+                            ])
+                    ])
+                    .set_volumes(seq![
+                        VolumeView::default().set_name("data"@).set_persistent_volume_claim(
+                            PersistentVolumeClaimVolumeSourceView::default().set_claim_name(make_data_pvc_name(make_zookeeper_cluster(restore)))
+                        ),
+                        VolumeView::default().set_name("backup"@).set_persistent_volume_claim(
+                            PersistentVolumeClaimVolumeSourceView::default().set_claim_name(restore.spec.persistent_volume_claim).set_read_only(true)
+                        ),
+                    ])
+                )
+            )
+        )
+}
+
+pub open spec fn update_restore_status(restore: ZookeeperRestoreView, ready: bool) -> ZookeeperRestoreView {
+    restore.set_status(ZookeeperRestoreStatusView::default().set_conditions(make_conditions(ready, false, restore.metadata.generation)))
+}
+
+pub open spec fn update_restore_status_to_degraded(restore: ZookeeperRestoreView) -> ZookeeperRestoreView {
+    restore.set_status(ZookeeperRestoreStatusView::default().set_conditions(make_conditions(false, true, restore.metadata.generation)))
+}
+
+// report_error marks the restore as degraded before the reconcile ends in error.
+pub open spec fn report_error(
+    restore: ZookeeperRestoreView, state: ZookeeperRestoreReconcileState
+) -> (ZookeeperRestoreReconcileState, Option<RequestView<EmptyTypeView>>) {
+    let req_o = APIRequest::UpdateStatusRequest(UpdateStatusRequest {
+        namespace: restore.metadata.namespace.get_Some_0(),
+        name: restore.metadata.name.get_Some_0(),
+        obj: update_restore_status_to_degraded(restore).marshal(),
+    });
+    let state_prime = ZookeeperRestoreReconcileState {
+        reconcile_step: ZookeeperRestoreReconcileStep::AfterUpdateDegradedStatus,
+        ..state
+    };
+    (state_prime, Some(RequestView::KRequest(req_o)))
+}
+
+}
@@ -0,0 +1,136 @@
+// Copyright 2022 VMware, Inc.
+// SPDX-License-Identifier: MIT
+#![allow(unused_imports)]
+use crate::kubernetes_api_objects::spec::prelude::*;
+use crate::kubernetes_cluster::spec::{cluster::*, message::*};
+use crate::temporal_logic::defs::*;
+use crate::zookeeper_controller::restore::{
+    model::reconciler::*,
+    trusted::{liveness_theorem::*, spec_types::*, step::*},
+};
+use crate::zookeeper_controller::trusted::spec_types::ZookeeperClusterView;
+use vstd::prelude::*;
+
+verus! {
+
+pub open spec fn at_restore_step(key: ObjectRef, step: ZookeeperRestoreReconcileStep) -> StatePred<RestoreCluster>
+    recommends
+        key.kind.is_CustomResourceKind()
+{
+    |s: RestoreCluster| {
+        &&& s.ongoing_reconciles().contains_key(key)
+        &&& s.ongoing_reconciles()[key].local_state.reconcile_step == step
+    }
+}
+
+pub open spec fn job_succeeded(key: ObjectRef) -> StatePred<RestoreCluster> {
+    |s: RestoreCluster| {
+        &&& s.resources().contains_key(key)
+        &&& JobView::unmarshal(s.resources()[key]).is_Ok()
+        &&& JobView::unmarshal(s.resources()[key]).get_Ok_0().succeeded()
+    }
+}
+
+pub open spec fn pvc_bound(key: ObjectRef) -> StatePred<RestoreCluster> {
+    |s: RestoreCluster| {
+        &&& s.resources().contains_key(key)
+        &&& PersistentVolumeClaimView::unmarshal(s.resources()[key]).is_Ok()
+        &&& PersistentVolumeClaimView::unmarshal(s.resources()[key]).get_Ok_0().bound()
+    }
+}
+
+pub open spec fn is_job_or_pvc(kind: Kind) -> bool {
+    kind == JobView::kind() || kind == PersistentVolumeClaimView::kind()
+}
+
+// Jobs and volume claims are never owned by anything in this cluster: the restore reconciler creates them without owner
+// references and nobody else creates or updates them. So the garbage collector never deletes them.
+pub open spec fn job_and_pvc_have_no_owner_references() -> StatePred<RestoreCluster> {
+    |s: RestoreCluster| {
+        &&& forall |key: ObjectRef|
+                #[trigger] s.resources().contains_key(key)
+                && is_job_or_pvc(key.kind)
+                ==> s.resources()[key].metadata.owner_references.is_None()
+        &&& forall |msg: RestoreMessage|
+                #[trigger] s.in_flight().contains(msg)
+                && msg.content.is_create_request()
+                && is_job_or_pvc(msg.content.get_create_request().obj.kind)
+                ==> msg.content.get_create_request().obj.metadata.owner_references.is_None()
+        &&& forall |msg: RestoreMessage|
+                #[trigger] s.in_flight().contains(msg)
+                && msg.content.is_update_request()
+                ==> !is_job_or_pvc(msg.content.get_update_request().key().kind)
+    }
+}
+
+pub open spec fn no_delete_request_msg_for_job_or_pvc() -> StatePred<RestoreCluster> {
+    |s: RestoreCluster| {
+        forall |msg: RestoreMessage|
+            #[trigger] s.in_flight().contains(msg)
+            && msg.content.is_delete_request()
+            ==> !is_job_or_pvc(msg.content.get_delete_request().key.kind)
+    }
+}
+
+// A job or a volume claim read by the reconciler is as good as the one in etcd: once it has succeeded or is bound it stays so.
+pub open spec fn every_ok_get_resp_of_succeeded_job_or_bound_pvc_is_in_etcd() -> StatePred<RestoreCluster> {
+    |s: RestoreCluster| {
+        forall |msg: RestoreMessage|
+            #[trigger] s.in_flight().contains(msg)
+            && RestoreCluster::is_ok_get_response_msg()(msg)
+            ==> {
+                let obj = msg.content.get_get_response().res.get_Ok_0();
+                &&& obj.kind == JobView::kind()
+                    && JobView::unmarshal(obj).is_Ok()
+                    && JobView::unmarshal(obj).get_Ok_0().succeeded()
+                    ==> job_succeeded(obj.object_ref())(s)
+                &&& obj.kind == PersistentVolumeClaimView::kind()
+                    && PersistentVolumeClaimView::unmarshal(obj).is_Ok()
+                    && PersistentVolumeClaimView::unmarshal(obj).get_Ok_0().bound()
+                    ==> pvc_bound(obj.object_ref())(s)
+            }
+    }
+}
+
+pub open spec fn request_at_after_get_step_is_get_request(key: ObjectRef) -> StatePred<RestoreCluster> {
+    |s: RestoreCluster| {
+        let restore = s.ongoing_reconciles()[key].triggering_cr;
+        let pending_req = s.ongoing_reconciles()[key].pending_req_msg.get_Some_0();
+        &&& at_restore_step(key, ZookeeperRestoreReconcileStep::AfterKRequestStep(ActionKind::Get, SubResource::RestoreJob))(s)
+            ==> s.ongoing_reconciles()[key].pending_req_msg.is_Some()
+                && resource_get_request_msg(make_restore_job_key(restore))(pending_req)
+        &&& at_restore_step(key, ZookeeperRestoreReconcileStep::AfterKRequestStep(ActionKind::Get, SubResource::DataPVC))(s)
+            ==> s.ongoing_reconciles()[key].pending_req_msg.is_Some()
+                && resource_get_request_msg(make_data_pvc_key(restore))(pending_req)
+    }
+}
+
+pub open spec fn restore_job_succeeded_at_after_get_data_pvc_step(key: ObjectRef) -> StatePred<RestoreCluster> {
+    |s: RestoreCluster| {
+        at_restore_step(key, ZookeeperRestoreReconcileStep::AfterKRequestStep(ActionKind::Get, SubResource::DataPVC))(s)
+        ==> job_succeeded(make_restore_job_key(s.ongoing_reconciles()[key].triggering_cr))(s)
+    }
+}
+
+pub open spec fn restored_zookeeper_create_request_msg(restore: ZookeeperRestoreView) -> spec_fn(RestoreMessage) -> bool {
+    |msg: RestoreMessage| {
+        &&& msg.dst.is_ApiServer()
+        &&& msg.content.is_create_request()
+        &&& msg.content.get_create_request().namespace == restore.metadata.namespace.get_Some_0()
+        &&& msg.content.get_create_request().obj.kind == ZookeeperClusterView::kind()
+        &&& restored_from(msg.content.get_create_request().obj, restore)
+    }
+}
+
+// Create requests are used instead of resource_create_request_msg because the property holds no matter how the cluster
+// is named by the request.
+pub open spec fn every_restored_zookeeper_create_request_implies_restore_finished(restore: ZookeeperRestoreView) -> StatePred<RestoreCluster> {
+    |s: RestoreCluster| {
+        forall |msg: RestoreMessage|
+            #[trigger] s.in_flight().contains(msg)
+            && restored_zookeeper_create_request_msg(restore)(msg)
+            ==> restore_job_succeeded(restore)(s) && data_pvc_bound(restore)(s)
+    }
+}
+
+}
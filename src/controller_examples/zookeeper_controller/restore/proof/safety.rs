@@ -0,0 +1,467 @@
+// Copyright 2022 VMware, Inc.
+// SPDX-License-Identifier: MIT
+#![allow(unused_imports)]
+use crate::external_api::spec::*;
+use crate::kubernetes_api_objects::spec::prelude::*;
+use crate::kubernetes_cluster::spec::{
+    cluster::*,
+    cluster_state_machine::Step,
+    controller::types::{ControllerActionInput, ControllerStep},
+    message::*,
+};
+use crate::temporal_logic::{defs::*, rules::*};
+use crate::zookeeper_controller::restore::{
+    model::reconciler::*,
+    proof::predicate::*,
+    trusted::{liveness_theorem::*, spec_types::*, step::*},
+};
+use crate::zookeeper_controller::trusted::spec_types::ZookeeperClusterView;
+use vstd::prelude::*;
+
+verus! {
+
+proof fn safety_proof_forall_restore()
+    ensures safety_theorem(),
+{
+    assert forall |restore: ZookeeperRestoreView| #[trigger] cluster_spec_without_wf().entails(safety(restore)) by {
+        safety_proof(restore);
+    };
+    spec_entails_tla_forall(cluster_spec_without_wf(), |restore: ZookeeperRestoreView| safety(restore));
+}
+
+proof fn safety_proof(restore: ZookeeperRestoreView)
+    ensures cluster_spec_without_wf().entails(safety(restore)),
+{
+    lemma_zookeeper_cluster_created_only_after_restore(cluster_spec_without_wf(), restore);
+}
+
+// This invariant is exactly the high-level property. The cluster can only appear in etcd when the api server handles a
+// create request, and the create request keeps the annotations of the object. So it is enough to show that every such
+// create request in flight is sent after the restore job has succeeded and the volume is bound, which is
+// every_restored_zookeeper_create_request_implies_restore_finished.
+proof fn lemma_zookeeper_cluster_created_only_after_restore(spec: TempPred<RestoreCluster>, restore: ZookeeperRestoreView)
+    requires
+        spec.entails(lift_state(RestoreCluster::init())),
+        spec.entails(always(lift_action(RestoreCluster::next()))),
+    ensures spec.entails(always(lift_action(zookeeper_cluster_created_only_after_restore(restore)))),
+{
+    let inv = zookeeper_cluster_created_only_after_restore(restore);
+    let next = |s, s_prime| {
+        &&& RestoreCluster::next()(s, s_prime)
+        &&& every_restored_zookeeper_create_request_implies_restore_finished(restore)(s)
+    };
+    lemma_always_every_restored_zookeeper_create_request_implies_restore_finished(spec, restore);
+    combine_spec_entails_always_n!(
+        spec, lift_action(next), lift_action(RestoreCluster::next()),
+        lift_state(every_restored_zookeeper_create_request_implies_restore_finished(restore))
+    );
+    assert forall |s, s_prime| #[trigger] next(s, s_prime) implies inv(s, s_prime) by {
+        let key = restored_zookeeper_key(restore);
+        if !s.resources().contains_key(key) && s_prime.resources().contains_key(key) && restored_from(s_prime.resources()[key], restore) {
+            let step = choose |step| RestoreCluster::next_step(s, s_prime, step);
+            let msg = step.get_ApiServerStep_0().get_Some_0();
+            assert(msg.content.is_create_request());
+            assert(s_prime.resources()[key].metadata.annotations == msg.content.get_create_request().obj.metadata.annotations);
+            assert(restored_zookeeper_create_request_msg(restore)(msg));
+        }
+    }
+    invariant_n!(
+        spec, lift_action(next), lift_action(inv),
+        lift_action(RestoreCluster::next()),
+        lift_state(every_restored_zookeeper_create_request_implies_restore_finished(restore))
+    );
+}
+
+// A create request for the restored cluster is only sent at AfterKRequestStep(Get, DataPVC), after receiving a bound
+// volume claim. The claim in the response is the data volume of the restore (request_at_after_get_step_is_get_request)
+// and it is still bound in etcd (every_ok_get_resp_of_succeeded_job_or_bound_pvc_is_in_etcd). The restore job has
+// succeeded before the reconciler moves to AfterKRequestStep(Get, DataPVC) (restore_job_succeeded_at_after_get_data_pvc_step).
+// Both facts are stable, so they still hold while the request is in flight.
+proof fn lemma_always_every_restored_zookeeper_create_request_implies_restore_finished(spec: TempPred<RestoreCluster>, restore: ZookeeperRestoreView)
+    requires
+        spec.entails(lift_state(RestoreCluster::init())),
+        spec.entails(always(lift_action(RestoreCluster::next()))),
+    ensures spec.entails(always(lift_state(every_restored_zookeeper_create_request_implies_restore_finished(restore)))),
+{
+    let inv = every_restored_zookeeper_create_request_implies_restore_finished(restore);
+    let key = restore.object_ref();
+    let next = |s, s_prime| {
+        &&& RestoreCluster::next()(s, s_prime)
+        &&& RestoreCluster::each_object_in_etcd_is_well_formed()(s)
+        &&& RestoreCluster::each_object_in_etcd_is_well_formed()(s_prime)
+        &&& RestoreCluster::each_object_in_reconcile_has_consistent_key_and_valid_metadata()(s)
+        &&& RestoreCluster::key_of_object_in_matched_ok_get_resp_message_is_same_as_key_of_pending_req(key)(s)
+        &&& job_and_pvc_have_no_owner_references()(s)
+        &&& no_delete_request_msg_for_job_or_pvc()(s)
+        &&& every_ok_get_resp_of_succeeded_job_or_bound_pvc_is_in_etcd()(s)
+        &&& request_at_after_get_step_is_get_request(key)(s)
+        &&& restore_job_succeeded_at_after_get_data_pvc_step(key)(s)
+    };
+    RestoreCluster::lemma_always_each_object_in_etcd_is_well_formed(spec);
+    always_to_always_later(spec, lift_state(RestoreCluster::each_object_in_etcd_is_well_formed()));
+    RestoreCluster::lemma_always_each_object_in_reconcile_has_consistent_key_and_valid_metadata(spec);
+    RestoreCluster::lemma_always_key_of_object_in_matched_ok_get_resp_message_is_same_as_key_of_pending_req(spec, key);
+    lemma_always_job_and_pvc_have_no_owner_references(spec);
+    lemma_always_no_delete_request_msg_for_job_or_pvc(spec);
+    lemma_always_every_ok_get_resp_of_succeeded_job_or_bound_pvc_is_in_etcd(spec);
+    lemma_always_request_at_after_get_step_is_get_request(spec, key);
+    lemma_always_restore_job_succeeded_at_after_get_data_pvc_step(spec, key);
+    combine_spec_entails_always_n!(
+        spec, lift_action(next), lift_action(RestoreCluster::next()),
+        lift_state(RestoreCluster::each_object_in_etcd_is_well_formed()),
+        later(lift_state(RestoreCluster::each_object_in_etcd_is_well_formed())),
+        lift_state(RestoreCluster::each_object_in_reconcile_has_consistent_key_and_valid_metadata()),
+        lift_state(RestoreCluster::key_of_object_in_matched_ok_get_resp_message_is_same_as_key_of_pending_req(key)),
+        lift_state(job_and_pvc_have_no_owner_references()),
+        lift_state(no_delete_request_msg_for_job_or_pvc()),
+        lift_state(every_ok_get_resp_of_succeeded_job_or_bound_pvc_is_in_etcd()),
+        lift_state(request_at_after_get_step_is_get_request(key)),
+        lift_state(restore_job_succeeded_at_after_get_data_pvc_step(key))
+    );
+    assert forall |s, s_prime| inv(s) && #[trigger] next(s, s_prime) implies inv(s_prime) by {
+        assert forall |msg| #[trigger] s_prime.in_flight().contains(msg) && restored_zookeeper_create_request_msg(restore)(msg)
+        implies restore_job_succeeded(restore)(s_prime) && data_pvc_bound(restore)(s_prime) by {
+            if s.in_flight().contains(msg) {
+                lemma_job_succeeded_and_pvc_bound_are_stable(restore_job_key(restore), s, s_prime);
+                lemma_job_succeeded_and_pvc_bound_are_stable(data_pvc_key(restore), s, s_prime);
+            } else {
+                lemma_restored_zookeeper_create_request_is_sent_after_restore(restore, s, s_prime, msg);
+            }
+        }
+    }
+    init_invariant(spec, RestoreCluster::init(), next, inv);
+}
+
+// Only the restore reconciler creates ZookeeperCluster objects, and the annotation pins the triggering restore to the
+// name of restore, so the request is sent by the reconcile of restore.object_ref().
+proof fn lemma_restored_zookeeper_create_request_is_sent_after_restore(
+    restore: ZookeeperRestoreView, s: RestoreCluster, s_prime: RestoreCluster, msg: RestoreMessage
+)
+    requires
+        RestoreCluster::next()(s, s_prime),
+        RestoreCluster::each_object_in_reconcile_has_consistent_key_and_valid_metadata()(s),
+        RestoreCluster::key_of_object_in_matched_ok_get_resp_message_is_same_as_key_of_pending_req(restore.object_ref())(s),
+        every_ok_get_resp_of_succeeded_job_or_bound_pvc_is_in_etcd()(s),
+        request_at_after_get_step_is_get_request(restore.object_ref())(s),
+        restore_job_succeeded_at_after_get_data_pvc_step(restore.object_ref())(s),
+        !s.in_flight().contains(msg),
+        s_prime.in_flight().contains(msg),
+        restored_zookeeper_create_request_msg(restore)(msg),
+    ensures restore_job_succeeded(restore)(s_prime) && data_pvc_bound(restore)(s_prime),
+{
+    let step = choose |step| RestoreCluster::next_step(s, s_prime, step);
+    match step {
+        Step::ControllerStep(input) => {
+            let cr_key = input.1.get_Some_0();
+            let cr = s.ongoing_reconciles()[cr_key].triggering_cr;
+            let local_step = s.ongoing_reconciles()[cr_key].local_state.reconcile_step;
+            // The restore reconciler only creates the data volume and the job everywhere else.
+            assert(local_step == ZookeeperRestoreReconcileStep::AfterKRequestStep(ActionKind::Get, SubResource::DataPVC));
+            assert(msg.content.get_create_request().obj == make_zookeeper_cluster(cr).marshal());
+            assert(cr.metadata.name.get_Some_0() == restore.metadata.name.get_Some_0());
+            assert(cr.metadata.namespace.get_Some_0() == restore.metadata.namespace.get_Some_0());
+            assert(cr_key == restore.object_ref());
+            assert(make_restore_job_key(cr) == restore_job_key(restore));
+            assert(make_data_pvc_key(cr) == data_pvc_key(restore));
+
+            let resp = input.0.get_Some_0();
+            assert(s.in_flight().contains(resp));
+            assert(RestoreCluster::is_ok_get_response_msg()(resp));
+            assert(Message::resp_msg_matches_req_msg(resp, s.ongoing_reconciles()[cr_key].pending_req_msg.get_Some_0()));
+            assert(resp.content.get_get_response().res.get_Ok_0().object_ref() == data_pvc_key(restore));
+            assert(pvc_bound(data_pvc_key(restore))(s));
+            assert(job_succeeded(restore_job_key(restore))(s));
+            assert(s.resources() == s_prime.resources());
+        },
+        Step::ClientStep() => {
+            assert(msg.content.get_create_request().obj.kind == ZookeeperRestoreView::kind());
+        },
+        _ => {
+            assert(!restored_zookeeper_create_request_msg(restore)(msg));
+        },
+    }
+}
+
+// The restore reconciler reaches AfterKRequestStep(Get, DataPVC) only when the job it reads has succeeded.
+proof fn lemma_always_restore_job_succeeded_at_after_get_data_pvc_step(spec: TempPred<RestoreCluster>, key: ObjectRef)
+    requires
+        key.kind.is_CustomResourceKind(),
+        spec.entails(lift_state(RestoreCluster::init())),
+        spec.entails(always(lift_action(RestoreCluster::next()))),
+    ensures spec.entails(always(lift_state(restore_job_succeeded_at_after_get_data_pvc_step(key)))),
+{
+    let inv = restore_job_succeeded_at_after_get_data_pvc_step(key);
+    let next = |s, s_prime| {
+        &&& RestoreCluster::next()(s, s_prime)
+        &&& RestoreCluster::each_object_in_etcd_is_well_formed()(s)
+        &&& RestoreCluster::each_object_in_etcd_is_well_formed()(s_prime)
+        &&& RestoreCluster::key_of_object_in_matched_ok_get_resp_message_is_same_as_key_of_pending_req(key)(s)
+        &&& job_and_pvc_have_no_owner_references()(s)
+        &&& no_delete_request_msg_for_job_or_pvc()(s)
+        &&& every_ok_get_resp_of_succeeded_job_or_bound_pvc_is_in_etcd()(s)
+        &&& request_at_after_get_step_is_get_request(key)(s)
+    };
+    RestoreCluster::lemma_always_each_object_in_etcd_is_well_formed(spec);
+    always_to_always_later(spec, lift_state(RestoreCluster::each_object_in_etcd_is_well_formed()));
+    RestoreCluster::lemma_always_key_of_object_in_matched_ok_get_resp_message_is_same_as_key_of_pending_req(spec, key);
+    lemma_always_job_and_pvc_have_no_owner_references(spec);
+    lemma_always_no_delete_request_msg_for_job_or_pvc(spec);
+    lemma_always_every_ok_get_resp_of_succeeded_job_or_bound_pvc_is_in_etcd(spec);
+    lemma_always_request_at_after_get_step_is_get_request(spec, key);
+    combine_spec_entails_always_n!(
+        spec, lift_action(next), lift_action(RestoreCluster::next()),
+        lift_state(RestoreCluster::each_object_in_etcd_is_well_formed()),
+        later(lift_state(RestoreCluster::each_object_in_etcd_is_well_formed())),
+        lift_state(RestoreCluster::key_of_object_in_matched_ok_get_resp_message_is_same_as_key_of_pending_req(key)),
+        lift_state(job_and_pvc_have_no_owner_references()),
+        lift_state(no_delete_request_msg_for_job_or_pvc()),
+        lift_state(every_ok_get_resp_of_succeeded_job_or_bound_pvc_is_in_etcd()),
+        lift_state(request_at_after_get_step_is_get_request(key))
+    );
+    assert forall |s, s_prime| inv(s) && #[trigger] next(s, s_prime) implies inv(s_prime) by {
+        if at_restore_step(key, ZookeeperRestoreReconcileStep::AfterKRequestStep(ActionKind::Get, SubResource::DataPVC))(s_prime) {
+            let job_key = make_restore_job_key(s_prime.ongoing_reconciles()[key].triggering_cr);
+            let step = choose |step| RestoreCluster::next_step(s, s_prime, step);
+            match step {
+                Step::ControllerStep(input) => {
+                    let cr_key = input.1.get_Some_0();
+                    if cr_key == key {
+                        // The only way in is from AfterKRequestStep(Get, RestoreJob) with a succeeded job in the response.
+                        assert(at_restore_step(key, ZookeeperRestoreReconcileStep::AfterKRequestStep(ActionKind::Get, SubResource::RestoreJob))(s));
+                        let resp = input.0.get_Some_0();
+                        assert(s.in_flight().contains(resp));
+                        assert(RestoreCluster::is_ok_get_response_msg()(resp));
+                        assert(resp.content.get_get_response().res.get_Ok_0().object_ref() == job_key);
+                        assert(job_succeeded(job_key)(s));
+                    } else {
+                        assert(s_prime.ongoing_reconciles()[key] == s.ongoing_reconciles()[key]);
+                    }
+                    assert(s_prime.resources() == s.resources());
+                },
+                Step::RestartController() => {
+                    assert(!s_prime.ongoing_reconciles().contains_key(key));
+                },
+                _ => {
+                    assert(s_prime.ongoing_reconciles()[key] == s.ongoing_reconciles()[key]);
+                    lemma_job_succeeded_and_pvc_bound_are_stable(job_key, s, s_prime);
+                }
+            }
+        }
+    }
+    init_invariant(spec, RestoreCluster::init(), next, inv);
+}
+
+proof fn lemma_always_request_at_after_get_step_is_get_request(spec: TempPred<RestoreCluster>, key: ObjectRef)
+    requires
+        spec.entails(lift_state(RestoreCluster::init())),
+        spec.entails(always(lift_action(RestoreCluster::next()))),
+    ensures spec.entails(always(lift_state(request_at_after_get_step_is_get_request(key)))),
+{
+    let inv = request_at_after_get_step_is_get_request(key);
+    let next = |s, s_prime| {
+        &&& RestoreCluster::next()(s, s_prime)
+        &&& RestoreCluster::each_object_in_reconcile_has_consistent_key_and_valid_metadata()(s)
+    };
+    RestoreCluster::lemma_always_each_object_in_reconcile_has_consistent_key_and_valid_metadata(spec);
+    combine_spec_entails_always_n!(
+        spec, lift_action(next), lift_action(RestoreCluster::next()),
+        lift_state(RestoreCluster::each_object_in_reconcile_has_consistent_key_and_valid_metadata())
+    );
+    assert forall |s: RestoreCluster, s_prime: RestoreCluster| inv(s) && #[trigger] next(s, s_prime) implies inv(s_prime) by {
+        let step = choose |step| RestoreCluster::next_step(s, s_prime, step);
+        match step {
+            Step::ControllerStep(input) => {
+                let cr_key = input.1.get_Some_0();
+                if cr_key != key {
+                    assert(s_prime.ongoing_reconciles()[key] == s.ongoing_reconciles()[key]);
+                }
+            },
+            Step::RestartController() => {
+                assert(!s_prime.ongoing_reconciles().contains_key(key));
+            },
+            _ => {
+                assert(s_prime.ongoing_reconciles()[key] == s.ongoing_reconciles()[key]);
+            }
+        }
+    }
+    init_invariant(spec, RestoreCluster::init(), next, inv);
+}
+
+proof fn lemma_always_every_ok_get_resp_of_succeeded_job_or_bound_pvc_is_in_etcd(spec: TempPred<RestoreCluster>)
+    requires
+        spec.entails(lift_state(RestoreCluster::init())),
+        spec.entails(always(lift_action(RestoreCluster::next()))),
+    ensures spec.entails(always(lift_state(every_ok_get_resp_of_succeeded_job_or_bound_pvc_is_in_etcd()))),
+{
+    let inv = every_ok_get_resp_of_succeeded_job_or_bound_pvc_is_in_etcd();
+    let next = |s, s_prime| {
+        &&& RestoreCluster::next()(s, s_prime)
+        &&& RestoreCluster::each_object_in_etcd_is_well_formed()(s)
+        &&& RestoreCluster::each_object_in_etcd_is_well_formed()(s_prime)
+        &&& job_and_pvc_have_no_owner_references()(s)
+        &&& no_delete_request_msg_for_job_or_pvc()(s)
+    };
+    RestoreCluster::lemma_always_each_object_in_etcd_is_well_formed(spec);
+    always_to_always_later(spec, lift_state(RestoreCluster::each_object_in_etcd_is_well_formed()));
+    lemma_always_job_and_pvc_have_no_owner_references(spec);
+    lemma_always_no_delete_request_msg_for_job_or_pvc(spec);
+    combine_spec_entails_always_n!(
+        spec, lift_action(next), lift_action(RestoreCluster::next()),
+        lift_state(RestoreCluster::each_object_in_etcd_is_well_formed()),
+        later(lift_state(RestoreCluster::each_object_in_etcd_is_well_formed())),
+        lift_state(job_and_pvc_have_no_owner_references()),
+        lift_state(no_delete_request_msg_for_job_or_pvc())
+    );
+    assert forall |s, s_prime| inv(s) && #[trigger] next(s, s_prime) implies inv(s_prime) by {
+        assert forall |msg| #[trigger] s_prime.in_flight().contains(msg) && RestoreCluster::is_ok_get_response_msg()(msg) implies {
+            let obj = msg.content.get_get_response().res.get_Ok_0();
+            &&& obj.kind == JobView::kind() && JobView::unmarshal(obj).is_Ok() && JobView::unmarshal(obj).get_Ok_0().succeeded()
+                ==> job_succeeded(obj.object_ref())(s_prime)
+            &&& obj.kind == PersistentVolumeClaimView::kind() && PersistentVolumeClaimView::unmarshal(obj).is_Ok()
+                && PersistentVolumeClaimView::unmarshal(obj).get_Ok_0().bound()
+                ==> pvc_bound(obj.object_ref())(s_prime)
+        } by {
+            let obj = msg.content.get_get_response().res.get_Ok_0();
+            if s.in_flight().contains(msg) {
+                lemma_job_succeeded_and_pvc_bound_are_stable(obj.object_ref(), s, s_prime);
+            } else {
+                // The response is generated by the api server in this step from the object in etcd, which is not
+                // changed by a get request.
+                let step = choose |step| RestoreCluster::next_step(s, s_prime, step);
+                let req = step.get_ApiServerStep_0().get_Some_0();
+                assert(req.content.is_get_request());
+                let req_key = req.content.get_get_request().key;
+                assert(s.resources().contains_key(req_key) && obj == s.resources()[req_key]);
+                assert(obj.object_ref() == req_key);
+                assert(s.resources() == s_prime.resources());
+            }
+        }
+    }
+    init_invariant(spec, RestoreCluster::init(), next, inv);
+}
+
+// Once a job has succeeded or a volume claim is bound, it stays so: neither is ever deleted, and any update of them has
+// to pass transition_validation.
+proof fn lemma_job_succeeded_and_pvc_bound_are_stable(key: ObjectRef, s: RestoreCluster, s_prime: RestoreCluster)
+    requires
+        RestoreCluster::next()(s, s_prime),
+        RestoreCluster::each_object_in_etcd_is_well_formed()(s),
+        RestoreCluster::each_object_in_etcd_is_well_formed()(s_prime),
+        no_delete_request_msg_for_job_or_pvc()(s),
+    ensures
+        job_succeeded(key)(s) ==> job_succeeded(key)(s_prime),
+        pvc_bound(key)(s) ==> pvc_bound(key)(s_prime),
+{
+    if job_succeeded(key)(s) || pvc_bound(key)(s) {
+        assert(is_job_or_pvc(key.kind));
+        let step = choose |step| RestoreCluster::next_step(s, s_prime, step);
+        match step {
+            Step::ApiServerStep(input) => {
+                let req = input.get_Some_0();
+                if req.content.is_delete_request() {
+                    assert(req.content.get_delete_request().key != key);
+                    assert(s_prime.resources().contains_key(key) && s_prime.resources()[key] == s.resources()[key]);
+                } else if s_prime.resources()[key] != s.resources()[key] {
+                    assert(req.content.is_update_request() || req.content.is_update_status_request());
+                    if key.kind == JobView::kind() {
+                        assert(JobView::unmarshal(s_prime.resources()[key]).get_Ok_0().transition_validation(JobView::unmarshal(s.resources()[key]).get_Ok_0()));
+                    } else {
+                        assert(PersistentVolumeClaimView::unmarshal(s_prime.resources()[key]).get_Ok_0().transition_validation(PersistentVolumeClaimView::unmarshal(s.resources()[key]).get_Ok_0()));
+                    }
+                }
+            },
+            _ => {
+                assert(s_prime.resources() == s.resources());
+            },
+        }
+    }
+}
+
+// The garbage collector only deletes objects with owner references, so it never deletes a job or a volume claim.
+// The client only deletes restores and the pod events only delete pods.
+proof fn lemma_always_no_delete_request_msg_for_job_or_pvc(spec: TempPred<RestoreCluster>)
+    requires
+        spec.entails(lift_state(RestoreCluster::init())),
+        spec.entails(always(lift_action(RestoreCluster::next()))),
+    ensures spec.entails(always(lift_state(no_delete_request_msg_for_job_or_pvc()))),
+{
+    let inv = no_delete_request_msg_for_job_or_pvc();
+    let next = |s, s_prime| {
+        &&& RestoreCluster::next()(s, s_prime)
+        &&& job_and_pvc_have_no_owner_references()(s)
+    };
+    lemma_always_job_and_pvc_have_no_owner_references(spec);
+    combine_spec_entails_always_n!(
+        spec, lift_action(next), lift_action(RestoreCluster::next()),
+        lift_state(job_and_pvc_have_no_owner_references())
+    );
+    assert forall |s, s_prime| inv(s) && #[trigger] next(s, s_prime) implies inv(s_prime) by {
+        assert forall |msg| #[trigger] s_prime.in_flight().contains(msg) && msg.content.is_delete_request()
+        implies !is_job_or_pvc(msg.content.get_delete_request().key.kind) by {
+            if !s.in_flight().contains(msg) {
+                let step = choose |step| RestoreCluster::next_step(s, s_prime, step);
+                match step {
+                    Step::BuiltinControllersStep(input) => {
+                        let key = input.1;
+                        assert(msg.content.get_delete_request().key == key);
+                        assert(s.resources()[key].metadata.owner_references.is_Some());
+                    },
+                    Step::ClientStep() => {
+                        assert(msg.content.get_delete_request().key.kind == ZookeeperRestoreView::kind());
+                    },
+                    Step::PodEventStep() => {
+                        assert(msg.content.get_delete_request().key.kind == PodView::kind());
+                    },
+                    _ => {},
+                }
+            }
+        }
+    }
+    init_invariant(spec, RestoreCluster::init(), next, inv);
+}
+
+proof fn lemma_always_job_and_pvc_have_no_owner_references(spec: TempPred<RestoreCluster>)
+    requires
+        spec.entails(lift_state(RestoreCluster::init())),
+        spec.entails(always(lift_action(RestoreCluster::next()))),
+    ensures spec.entails(always(lift_state(job_and_pvc_have_no_owner_references()))),
+{
+    let inv = job_and_pvc_have_no_owner_references();
+    assert forall |s, s_prime| inv(s) && #[trigger] RestoreCluster::next()(s, s_prime) implies inv(s_prime) by {
+        let step = choose |step| RestoreCluster::next_step(s, s_prime, step);
+        assert forall |key| #[trigger] s_prime.resources().contains_key(key) && is_job_or_pvc(key.kind)
+        implies s_prime.resources()[key].metadata.owner_references.is_None() by {
+            if !s.resources().contains_key(key) || s_prime.resources()[key] != s.resources()[key] {
+                // Update status keeps the metadata, and no update request for jobs or volume claims is in flight.
+                let req = step.get_ApiServerStep_0().get_Some_0();
+                assert(s.in_flight().contains(req));
+            }
+        }
+        assert forall |msg| #[trigger] s_prime.in_flight().contains(msg) && !s.in_flight().contains(msg) implies
+            (msg.content.is_create_request() && is_job_or_pvc(msg.content.get_create_request().obj.kind)
+            ==> msg.content.get_create_request().obj.metadata.owner_references.is_None())
+            && (msg.content.is_update_request() ==> !is_job_or_pvc(msg.content.get_update_request().key().kind)) by {
+            match step {
+                Step::ControllerStep(input) => {
+                    // The restore reconciler creates the data volume and the job without owner references, and never
+                    // updates anything.
+                    let cr_key = input.1.get_Some_0();
+                    let cr = s.ongoing_reconciles()[cr_key].triggering_cr;
+                    assert(make_data_pvc(cr).metadata.owner_references.is_None());
+                    assert(make_restore_job(cr).metadata.owner_references.is_None());
+                    assert(!msg.content.is_update_request());
+                },
+                Step::ClientStep() => {
+                    assert(!is_job_or_pvc(ZookeeperRestoreView::kind()));
+                },
+                Step::PodEventStep() => {
+                    assert(!is_job_or_pvc(PodView::kind()));
+                },
+                _ => {},
+            }
+        }
+    }
+    init_invariant(spec, RestoreCluster::init(), RestoreCluster::next(), inv);
+}
+
+}
@@ -0,0 +1,215 @@
+// Copyright 2022 VMware, Inc.
+// SPDX-License-Identifier: MIT
+use crate::kubernetes_api_objects::error::UnmarshalError;
+use crate::kubernetes_api_objects::exec::{
+    api_resource::*, condition::*, dynamic::*, object_meta::*, owner_reference::*, resource::*,
+};
+use crate::kubernetes_api_objects::spec::resource::*;
+use crate::vstd_ext::string_view::*;
+use crate::zookeeper_controller::restore::trusted::{
+    spec_types, spec_types::ZookeeperRestoreView, step::*,
+};
+use crate::zookeeper_controller::trusted::exec_types::ZookeeperClusterSpec;
+use deps_hack::kube::Resource;
+use vstd::prelude::*;
+
+verus! {
+
+pub struct ZookeeperRestoreReconcileState {
+    pub reconcile_step: ZookeeperRestoreReconcileStep,
+}
+
+impl std::clone::Clone for ZookeeperRestoreReconcileState {
+    #[verifier(external_body)]
+    fn clone(&self) -> (result: ZookeeperRestoreReconcileState)
+        ensures result == self
+    {
+        ZookeeperRestoreReconcileState {
+            reconcile_step: self.reconcile_step,
+        }
+    }
+}
+
+impl View for ZookeeperRestoreReconcileState {
+    type V = spec_types::ZookeeperRestoreReconcileState;
+    open spec fn view(&self) -> spec_types::ZookeeperRestoreReconcileState {
+        spec_types::ZookeeperRestoreReconcileState {
+            reconcile_step: self.reconcile_step,
+        }
+    }
+}
+
+#[verifier(external_body)]
+pub struct ZookeeperRestore {
+    inner: deps_hack::ZookeeperRestore
+}
+
+impl View for ZookeeperRestore {
+    type V = spec_types::ZookeeperRestoreView;
+
+    spec fn view(&self) -> spec_types::ZookeeperRestoreView;
+}
+
+impl ZookeeperRestore {
+    #[verifier(external_body)]
+    pub fn clone(&self) -> (restore: Self)
+        ensures restore@ == self@,
+    {
+        ZookeeperRestore { inner: self.inner.clone() }
+    }
+
+    #[verifier(external_body)]
+    pub fn metadata(&self) -> (metadata: ObjectMeta)
+        ensures metadata@ == self@.metadata,
+    {
+        ObjectMeta::from_kube(self.inner.metadata.clone())
+    }
+
+    #[verifier(external_body)]
+    pub fn spec(&self) -> (spec: ZookeeperRestoreSpec)
+        ensures spec@ == self@.spec,
+    {
+        ZookeeperRestoreSpec { inner: self.inner.spec.clone() }
+    }
+
+    #[verifier(external_body)]
+    pub fn set_status(&mut self, status: ZookeeperRestoreStatus)
+        ensures self@ == old(self)@.set_status(status@),
+    {
+        let mut new_status = status.into_kube();
+        match (&mut new_status.conditions, self.inner.status.as_ref().and_then(|s| s.conditions.as_ref())) {
+            (Some(conditions), Some(old_conditions)) => preserve_transition_times(conditions, old_conditions),
+            _ => {},
+        }
+        self.inner.status = Some(new_status);
+    }
+
+    #[verifier(external_body)]
+    pub fn controller_owner_ref(&self) -> (owner_reference: OwnerReference)
+        ensures owner_reference@ == self@.controller_owner_ref(),
+    {
+        OwnerReference::from_kube(
+            // We can safely unwrap here because the trait method implementation always returns a Some(...)
+            self.inner.controller_owner_ref(&()).unwrap()
+        )
+    }
+
+    #[verifier(external_body)]
+    pub fn api_resource() -> (res: ApiResource)
+        ensures res@.kind == ZookeeperRestoreView::kind(),
+    {
+        ApiResource::from_kube(deps_hack::kube::api::ApiResource::erase::<deps_hack::ZookeeperRestore>(&()))
+    }
+
+    // NOTE: This function assumes serde_json::to_string won't fail!
+    #[verifier(external_body)]
+    pub fn marshal(self) -> (obj: DynamicObject)
+        ensures obj@ == self@.marshal(),
+    {
+        // TODO: this might be unnecessarily slow
+        DynamicObject::from_kube(deps_hack::k8s_openapi::serde_json::from_str(&deps_hack::k8s_openapi::serde_json::to_string(&self.inner).unwrap()).unwrap())
+    }
+
+    #[verifier(external_body)]
+    pub fn unmarshal(obj: DynamicObject) -> (res: Result<ZookeeperRestore, UnmarshalError>)
+        ensures
+            res.is_Ok() == ZookeeperRestoreView::unmarshal(obj@).is_Ok(),
+            res.is_Ok() ==> res.get_Ok_0()@ == ZookeeperRestoreView::unmarshal(obj@).get_Ok_0(),
+    {
+        let parse_result = obj.into_kube().try_parse::<deps_hack::ZookeeperRestore>();
+        if parse_result.is_ok() {
+            let res = ZookeeperRestore { inner: parse_result.unwrap() };
+            Ok(res)
+        } else {
+            Err(())
+        }
+    }
+}
+
+#[verifier(external)]
+impl ResourceWrapper<deps_hack::ZookeeperRestore> for ZookeeperRestore {
+    fn from_kube(inner: deps_hack::ZookeeperRestore) -> ZookeeperRestore { ZookeeperRestore { inner: inner } }
+
+    fn into_kube(self) -> deps_hack::ZookeeperRestore { self.inner }
+}
+
+#[verifier(external_body)]
+pub struct ZookeeperRestoreSpec {
+    inner: deps_hack::ZookeeperRestoreSpec,
+}
+
+impl ZookeeperRestoreSpec {
+    pub spec fn view(&self) -> spec_types::ZookeeperRestoreSpecView;
+
+    #[verifier(external_body)]
+    pub fn backup_name(&self) -> (backup_name: String)
+        ensures backup_name@ == self@.backup_name,
+    {
+        self.inner.backup_name.clone()
+    }
+
+    #[verifier(external_body)]
+    pub fn persistent_volume_claim(&self) -> (persistent_volume_claim: String)
+        ensures persistent_volume_claim@ == self@.persistent_volume_claim,
+    {
+        self.inner.persistent_volume_claim.clone()
+    }
+
+    #[verifier(external_body)]
+    pub fn zookeeper_cluster(&self) -> (zookeeper_cluster: ZookeeperClusterTemplate)
+        ensures zookeeper_cluster@ == self@.zookeeper_cluster,
+    {
+        ZookeeperClusterTemplate { inner: self.inner.zookeeper_cluster.clone() }
+    }
+}
+
+#[verifier(external_body)]
+pub struct ZookeeperClusterTemplate {
+    inner: deps_hack::ZookeeperClusterTemplate,
+}
+
+impl ZookeeperClusterTemplate {
+    pub spec fn view(&self) -> spec_types::ZookeeperClusterTemplateView;
+
+    #[verifier(external_body)]
+    pub fn name(&self) -> (name: String)
+        ensures name@ == self@.name,
+    {
+        self.inner.name.clone()
+    }
+
+    #[verifier(external_body)]
+    pub fn spec(&self) -> (spec: ZookeeperClusterSpec)
+        ensures spec@ == self@.spec,
+    {
+        ZookeeperClusterSpec::from_kube(self.inner.spec.clone())
+    }
+}
+
+#[verifier(external_body)]
+pub struct ZookeeperRestoreStatus {
+    inner: deps_hack::ZookeeperRestoreStatus,
+}
+
+impl ZookeeperRestoreStatus {
+    pub spec fn view(&self) -> spec_types::ZookeeperRestoreStatusView;
+
+    #[verifier(external_body)]
+    pub fn default() -> (status: ZookeeperRestoreStatus)
+        ensures status@ == spec_types::ZookeeperRestoreStatusView::default(),
+    {
+        ZookeeperRestoreStatus { inner: deps_hack::ZookeeperRestoreStatus::default() }
+    }
+
+    #[verifier(external_body)]
+    pub fn set_conditions(&mut self, conditions: Vec<Condition>)
+        ensures self@ == old(self)@.set_conditions(conditions@.map_values(|c: Condition| c@)),
+    {
+        self.inner.conditions = Some(into_kube_conditions(conditions))
+    }
+
+    #[verifier(external)]
+    fn into_kube(self) -> deps_hack::ZookeeperRestoreStatus { self.inner }
+}
+
+}
@@ -0,0 +1,93 @@
+// Copyright 2022 VMware, Inc.
+// SPDX-License-Identifier: MIT
+#![allow(unused_imports)]
+use crate::kubernetes_api_objects::spec::prelude::*;
+use crate::kubernetes_cluster::spec::{cluster::*, cluster_state_machine::Step, message::*};
+use crate::temporal_logic::defs::*;
+use crate::zookeeper_controller::restore::trusted::{spec_types::*, step::*};
+use crate::zookeeper_controller::trusted::spec_types::ZookeeperClusterView;
+use vstd::prelude::*;
+
+verus! {
+
+pub open spec fn safety_theorem() -> bool {
+    cluster_spec_without_wf().entails(tla_forall(|restore: ZookeeperRestoreView| safety(restore)))
+}
+
+pub open spec fn cluster_spec_without_wf() -> TempPred<RestoreCluster> {
+    lift_state(RestoreCluster::init()).and(always(lift_action(RestoreCluster::next())))
+}
+
+pub open spec fn safety(restore: ZookeeperRestoreView) -> TempPred<RestoreCluster> {
+    always(lift_action(zookeeper_cluster_created_only_after_restore(restore)))
+}
+
+// The restored cluster must not start serving on an empty data directory. Its stateful set adopts the data volume
+// of the first replica, so the ZookeeperCluster object may only be created once the restore job has copied the backup
+// into that volume and the volume is bound to the claim.
+//
+// Like the safety property of the rabbitmq controller, the property talks about the previous and current state
+// instead of the create request: the cluster is created in this step if it does not exist before and exists after.
+// The restore job and the data volume are looked up by name, so the property also covers a cluster created on behalf
+// of an earlier restore object with the same name.
+pub open spec fn zookeeper_cluster_created_only_after_restore(restore: ZookeeperRestoreView) -> ActionPred<RestoreCluster> {
+    |s: RestoreCluster, s_prime: RestoreCluster| {
+        let key = restored_zookeeper_key(restore);
+        !s.resources().contains_key(key)
+        && s_prime.resources().contains_key(key)
+        && restored_from(s_prime.resources()[key], restore)
+        ==> restore_job_succeeded(restore)(s) && data_pvc_bound(restore)(s)
+    }
+}
+
+pub open spec fn restored_from(obj: DynamicObjectView, restore: ZookeeperRestoreView) -> bool {
+    &&& obj.metadata.annotations.is_Some()
+    &&& obj.metadata.annotations.get_Some_0().contains_key("anvil.dev/restored-from"@)
+    &&& obj.metadata.annotations.get_Some_0()["anvil.dev/restored-from"@] == restore.metadata.name.get_Some_0()
+}
+
+pub open spec fn restore_job_succeeded(restore: ZookeeperRestoreView) -> StatePred<RestoreCluster> {
+    |s: RestoreCluster| {
+        let obj = s.resources()[restore_job_key(restore)];
+        &&& s.resources().contains_key(restore_job_key(restore))
+        &&& JobView::unmarshal(obj).is_Ok()
+        &&& JobView::unmarshal(obj).get_Ok_0().succeeded()
+    }
+}
+
+pub open spec fn data_pvc_bound(restore: ZookeeperRestoreView) -> StatePred<RestoreCluster> {
+    |s: RestoreCluster| {
+        let obj = s.resources()[data_pvc_key(restore)];
+        &&& s.resources().contains_key(data_pvc_key(restore))
+        &&& PersistentVolumeClaimView::unmarshal(obj).is_Ok()
+        &&& PersistentVolumeClaimView::unmarshal(obj).get_Ok_0().bound()
+    }
+}
+
+pub open spec fn restored_zookeeper_key(restore: ZookeeperRestoreView) -> ObjectRef {
+    ObjectRef {
+        kind: ZookeeperClusterView::kind(),
+        name: restore.spec.zookeeper_cluster.name,
+        namespace: restore.metadata.namespace.get_Some_0(),
+    }
+}
+
+pub open spec fn restore_job_key(restore: ZookeeperRestoreView) -> ObjectRef {
+    ObjectRef {
+        kind: JobView::kind(),
+        name: restore.metadata.name.get_Some_0() + "-restore"@,
+        namespace: restore.metadata.namespace.get_Some_0(),
+    }
+}
+
+// The stateful set of the restored cluster is named after the cluster, and its first replica uses
+// the volume claim "data-<stateful set>-0".
+pub open spec fn data_pvc_key(restore: ZookeeperRestoreView) -> ObjectRef {
+    ObjectRef {
+        kind: PersistentVolumeClaimView::kind(),
+        name: "data-"@ + restore.spec.zookeeper_cluster.name + "-0"@,
+        namespace: restore.metadata.namespace.get_Some_0(),
+    }
+}
+
+}
@@ -0,0 +1,6 @@
+// Copyright 2022 VMware, Inc.
+// SPDX-License-Identifier: MIT
+pub mod exec_types;
+pub mod liveness_theorem;
+pub mod spec_types;
+pub mod step;
@@ -0,0 +1,214 @@
+// Copyright 2022 VMware, Inc.
+// SPDX-License-Identifier: MIT
+use crate::external_api::spec::{EmptyAPI, EmptyTypeView};
+use crate::kubernetes_api_objects::error::UnmarshalError;
+use crate::kubernetes_api_objects::spec::{
+    common::*, condition::*, dynamic::*, object_meta::*, owner_reference::*, resource::*,
+};
+use crate::kubernetes_cluster::spec::{cluster::*, cluster_state_machine::*, message::*};
+use crate::vstd_ext::string_view::*;
+use crate::zookeeper_controller::restore::trusted::step::*;
+use crate::zookeeper_controller::trusted::spec_types::{ZookeeperClusterSpecView, ZookeeperClusterView};
+use vstd::prelude::*;
+
+verus! {
+
+pub type RestoreStep = Step<RestoreMessage>;
+
+pub type RestoreCluster = Cluster<ZookeeperRestoreView, EmptyAPI, ZookeeperRestoreReconciler>;
+
+pub type RestoreMessage = Message<EmptyTypeView, EmptyTypeView>;
+
+pub struct ZookeeperRestoreReconciler {}
+
+pub struct ZookeeperRestoreReconcileState {
+    pub reconcile_step: ZookeeperRestoreReconcileStep,
+}
+
+pub struct ZookeeperRestoreView {
+    pub metadata: ObjectMetaView,
+    pub spec: ZookeeperRestoreSpecView,
+    pub status: Option<ZookeeperRestoreStatusView>,
+}
+
+impl ZookeeperRestoreView {
+    pub open spec fn well_formed(self) -> bool {
+        &&& self.metadata.name.is_Some()
+        &&& self.metadata.namespace.is_Some()
+        &&& self.metadata.uid.is_Some()
+    }
+
+    pub open spec fn controller_owner_ref(self) -> OwnerReferenceView {
+        OwnerReferenceView {
+            block_owner_deletion: None,
+            controller: Some(true),
+            kind: Self::kind(),
+            name: self.metadata.name.get_Some_0(),
+            uid: self.metadata.uid.get_Some_0(),
+        }
+    }
+
+    pub open spec fn set_status(self, status: ZookeeperRestoreStatusView) -> ZookeeperRestoreView {
+        ZookeeperRestoreView {
+            status: Some(status),
+            ..self
+        }
+    }
+}
+
+impl ResourceView for ZookeeperRestoreView {
+    type Spec = ZookeeperRestoreSpecView;
+    type Status = Option<ZookeeperRestoreStatusView>;
+
+    open spec fn default() -> ZookeeperRestoreView {
+        ZookeeperRestoreView {
+            metadata: ObjectMetaView::default(),
+            spec: arbitrary(), // TODO: specify the default value for spec
+            status: None,
+        }
+    }
+
+    open spec fn metadata(self) -> ObjectMetaView { self.metadata }
+
+    open spec fn kind() -> Kind { Kind::CustomResourceKind("zookeeperrestore"@) }
+
+    open spec fn object_ref(self) -> ObjectRef {
+        ObjectRef {
+            kind: Self::kind(),
+            name: self.metadata.name.get_Some_0(),
+            namespace: self.metadata.namespace.get_Some_0(),
+        }
+    }
+
+    proof fn object_ref_is_well_formed() {}
+
+    open spec fn spec(self) -> ZookeeperRestoreSpecView { self.spec }
+
+    open spec fn status(self) -> Option<ZookeeperRestoreStatusView> { self.status }
+
+    open spec fn marshal(self) -> DynamicObjectView {
+        DynamicObjectView {
+            kind: Self::kind(),
+            metadata: self.metadata,
+            spec: ZookeeperRestoreView::marshal_spec(self.spec),
+            status: ZookeeperRestoreView::marshal_status(self.status),
+        }
+    }
+
+    open spec fn unmarshal(obj: DynamicObjectView) -> Result<ZookeeperRestoreView, UnmarshalError> {
+        if obj.kind != Self::kind() {
+            Err(())
+        } else if !ZookeeperRestoreView::unmarshal_spec(obj.spec).is_Ok() {
+            Err(())
+        } else if !ZookeeperRestoreView::unmarshal_status(obj.status).is_Ok() {
+            Err(())
+        } else {
+            Ok(ZookeeperRestoreView {
+                metadata: obj.metadata,
+                spec: ZookeeperRestoreView::unmarshal_spec(obj.spec).get_Ok_0(),
+                status: ZookeeperRestoreView::unmarshal_status(obj.status).get_Ok_0(),
+            })
+        }
+    }
+
+    proof fn marshal_preserves_integrity() {
+        ZookeeperRestoreView::marshal_spec_preserves_integrity();
+        ZookeeperRestoreView::marshal_status_preserves_integrity();
+    }
+
+    proof fn marshal_preserves_metadata() {}
+
+    proof fn marshal_preserves_kind() {}
+
+    closed spec fn marshal_spec(s: ZookeeperRestoreSpecView) -> Value;
+
+    closed spec fn unmarshal_spec(v: Value) -> Result<ZookeeperRestoreSpecView, UnmarshalError>;
+
+    closed spec fn marshal_status(s: Option<ZookeeperRestoreStatusView>) -> Value;
+
+    closed spec fn unmarshal_status(v: Value) -> Result<Option<ZookeeperRestoreStatusView>, UnmarshalError>;
+
+    #[verifier(external_body)]
+    proof fn marshal_spec_preserves_integrity() {}
+
+    #[verifier(external_body)]
+    proof fn marshal_status_preserves_integrity() {}
+
+    proof fn unmarshal_result_determined_by_unmarshal_spec_and_status() {}
+
+    // The data can only be restored into a cluster with persistent storage,
+    // and the new cluster must itself be valid.
+    open spec fn state_validation(self) -> bool {
+        &&& self.spec.zookeeper_cluster.spec.persistence.enabled
+        &&& ZookeeperClusterView::spec_status_validation(self.spec.zookeeper_cluster.spec, None)
+    }
+
+    // The restore happens once, before the new cluster starts, so later changes would not take effect.
+    // The new cluster is changed through the ZookeeperCluster object instead.
+    open spec fn transition_validation(self, old_obj: ZookeeperRestoreView) -> bool {
+        self.spec == old_obj.spec
+    }
+}
+
+impl CustomResourceView for ZookeeperRestoreView {
+    proof fn kind_is_custom_resource() {}
+
+    open spec fn is_namespaced() -> bool { true }
+
+    open spec fn spec_status_validation(obj_spec: Self::Spec, obj_status: Self::Status) -> bool {
+        &&& is_dns_1123_label(obj_spec.backup_name)
+        &&& obj_spec.zookeeper_cluster.spec.persistence.enabled
+        &&& ZookeeperClusterView::spec_status_validation(obj_spec.zookeeper_cluster.spec, None)
+    }
+
+    proof fn validation_result_determined_by_spec_and_status()
+        ensures forall |obj: Self| #[trigger] obj.state_validation() == Self::spec_status_validation(obj.spec(), obj.status())
+    {}
+}
+
+// backup_name is the name of a ZookeeperBackup object and names its directory in the backup claim,
+// so it is restricted to a DNS-1123 label: lowercase alphanumerics and '-', starting and ending
+// with an alphanumeric. This rules out '/', '.' and any shell metacharacter.
+pub open spec fn is_dns_1123_label(s: StringView) -> bool {
+    &&& 0 < s.len() <= 63
+    &&& forall |i| 0 <= i < s.len() ==> {
+        let c = #[trigger] s[i];
+        ||| ('a' <= c && c <= 'z')
+        ||| ('0' <= c && c <= '9')
+        ||| (c == '-' && 0 < i < s.len() - 1)
+    }
+}
+
+pub struct ZookeeperRestoreSpecView {
+    pub backup_name: StringView,
+    pub persistent_volume_claim: StringView,
+    pub zookeeper_cluster: ZookeeperClusterTemplateView,
+}
+
+// ZookeeperClusterTemplateView describes the new zookeeper cluster, in the same namespace as the restore,
+// that is created once the data is restored.
+pub struct ZookeeperClusterTemplateView {
+    pub name: StringView,
+    pub spec: ZookeeperClusterSpecView,
+}
+
+pub struct ZookeeperRestoreStatusView {
+    pub conditions: Option<Seq<ConditionView>>,
+}
+
+impl ZookeeperRestoreStatusView {
+    pub open spec fn default() -> ZookeeperRestoreStatusView {
+        ZookeeperRestoreStatusView {
+            conditions: None,
+        }
+    }
+
+    pub open spec fn set_conditions(self, conditions: Seq<ConditionView>) -> ZookeeperRestoreStatusView {
+        ZookeeperRestoreStatusView {
+            conditions: Some(conditions),
+            ..self
+        }
+    }
+}
+
+}
@@ -0,0 +1,58 @@
+// Copyright 2022 VMware, Inc.
+// SPDX-License-Identifier: MIT
+#![allow(unused_imports)]
+use vstd::prelude::*;
+
+verus! {
+
+#[is_variant]
+pub enum ZookeeperRestoreReconcileStep {
+    Init,
+    AfterKRequestStep(ActionKind, SubResource),
+    AfterUpdateStatus,
+    AfterUpdateDegradedStatus,
+    Done,
+    Error,
+}
+
+impl std::marker::Copy for ZookeeperRestoreReconcileStep {}
+
+impl std::clone::Clone for ZookeeperRestoreReconcileStep {
+    #[verifier(external_body)]
+    fn clone(&self) -> (result: Self)
+        ensures result == self
+    { *self }
+}
+
+pub enum SubResource {
+    ZookeeperCluster,
+    RestoreJob,
+    DataPVC,
+}
+
+impl std::marker::Copy for SubResource {}
+
+impl std::clone::Clone for SubResource {
+    #[verifier(external_body)]
+    fn clone(&self) -> (result: Self)
+        ensures result == self
+    { *self }
+}
+
+// Nothing is ever updated: the data volume, the restore job and the new cluster are each created once.
+#[is_variant]
+pub enum ActionKind {
+    Get,
+    Create,
+}
+
+impl std::marker::Copy for ActionKind {}
+
+impl std::clone::Clone for ActionKind {
+    #[verifier(external_body)]
+    fn clone(&self) -> (result: Self)
+        ensures result == self
+    { *self }
+}
+
+}
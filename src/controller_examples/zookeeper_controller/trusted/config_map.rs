@@ -11,7 +11,6 @@ pub open spec fn make_zk_config(zk: ZookeeperClusterView) -> StringView {
     dataDir=/data\n\
     standaloneEnabled=false\n\
     reconfigEnabled=true\n\
-    skipACL=yes\n\
     metricsProvider.className=org.apache.zookeeper.metrics.prometheus.PrometheusMetricsProvider\n\
     metricsProvider.httpPort="@ + int_to_string_view(zk.spec.ports.metrics) + "\n\
     metricsProvider.exportJvmInfo=true\n\
@@ -32,6 +31,43 @@ pub open spec fn make_zk_config(zk: ZookeeperClusterView) -> StringView {
     quorumListenOnAllIPs="@ + bool_to_string_view(zk.spec.conf.quorum_listen_on_all_ips) + "\n\
     admin.serverPort="@ + int_to_string_view(zk.spec.ports.admin_server) + "\n\
     dynamicConfigFile=/data/zoo.cfg.dynamic\n"@
+    + make_zk_auth_config(zk)
+    + make_zk_tls_config(zk)
+}
+
+// ACLs are only checked when authentication is configured, otherwise every client can write every znode.
+pub open spec fn make_zk_auth_config(zk: ZookeeperClusterView) -> StringView {
+    if zk.spec.auth.is_None() {
+        "skipACL=yes\n"@
+    } else if zk.spec.auth.get_Some_0().sasl {
+        "skipACL=no\n\
+        authProvider.sasl=org.apache.zookeeper.server.auth.SASLAuthenticationProvider\n"@
+    } else {
+        "skipACL=no\n"@
+    }
+}
+
+// The TLS secret is mounted by the stateful set at /tls, and the store password is passed
+// through SERVER_JVMFLAGS in env.sh so that it does not show up in the config map.
+pub open spec fn make_zk_tls_config(zk: ZookeeperClusterView) -> StringView {
+    if zk.spec.tls.is_None() {
+        ""@
+    } else {
+        let tls = zk.spec.tls.get_Some_0();
+        "serverCnxnFactory=org.apache.zookeeper.server.NettyServerCnxnFactory\n\
+        secureClientPort="@ + int_to_string_view(tls.secure_client_port) + "\n\
+        ssl.keyStore.location=/tls/keystore.jks\n\
+        ssl.trustStore.location=/tls/truststore.jks\n"@
+        + {
+            if tls.quorum_tls {
+                "sslQuorum=true\n\
+                ssl.quorum.keyStore.location=/tls/keystore.jks\n\
+                ssl.quorum.trustStore.location=/tls/truststore.jks\n"@
+            } else {
+                ""@
+            }
+        }
+    }
 }
 
 }
@@ -2,8 +2,8 @@
 // SPDX-License-Identifier: MIT
 use crate::kubernetes_api_objects::error::*;
 use crate::kubernetes_api_objects::exec::{
-    affinity::*, api_resource::*, dynamic::*, object_meta::*, owner_reference::*, resource::*,
-    resource_requirements::*, toleration::*,
+    affinity::*, api_resource::*, condition::*, dynamic::*, object_meta::*, owner_reference::*,
+    resource::*, resource_requirements::*, toleration::*, topology_spread_constraint::*,
 };
 use crate::kubernetes_api_objects::spec::resource::*;
 use crate::vstd_ext::{string_map::*, string_view::*};
@@ -64,6 +64,19 @@ impl View for ZookeeperCluster {
 }
 
 impl ZookeeperCluster {
+    #[verifier(external_body)]
+    pub fn new(metadata: ObjectMeta, spec: ZookeeperClusterSpec) -> (zk: Self)
+        ensures zk@ == spec_types::ZookeeperClusterView::default().set_metadata(metadata@).set_spec(spec@),
+    {
+        ZookeeperCluster {
+            inner: deps_hack::ZookeeperCluster {
+                metadata: metadata.into_kube(),
+                spec: spec.inner,
+                status: None,
+            }
+        }
+    }
+
     #[verifier(external_body)]
     pub fn clone(&self) -> (zk: Self)
         ensures zk@ == self@,
@@ -85,11 +98,29 @@ impl ZookeeperCluster {
         ZookeeperClusterSpec { inner: self.inner.spec.clone() }
     }
 
+    #[verifier(external_body)]
+    pub fn status(&self) -> (status: Option<ZookeeperClusterStatus>)
+        ensures
+            self@.status.is_Some() == status.is_Some(),
+            status.is_Some() ==> status.get_Some_0()@ == self@.status.get_Some_0(),
+    {
+        if self.inner.status.is_none() {
+            None
+        } else {
+            Some(ZookeeperClusterStatus { inner: self.inner.status.as_ref().unwrap().clone() })
+        }
+    }
+
     #[verifier(external_body)]
     pub fn set_status(&mut self, status: ZookeeperClusterStatus)
         ensures self@ == old(self)@.set_status(status@),
     {
-        self.inner.status = Some(status.into_kube());
+        let mut new_status = status.into_kube();
+        match (&mut new_status.conditions, self.inner.status.as_ref().and_then(|s| s.conditions.as_ref())) {
+            (Some(conditions), Some(old_conditions)) => preserve_transition_times(conditions, old_conditions),
+            _ => {},
+        }
+        self.inner.status = Some(new_status);
     }
 
     #[verifier(external_body)]
@@ -149,6 +180,13 @@ pub struct ZookeeperClusterSpec {
 impl ZookeeperClusterSpec {
     pub spec fn view(&self) -> spec_types::ZookeeperClusterSpecView;
 
+    #[verifier(external_body)]
+    pub fn clone(&self) -> (spec: Self)
+        ensures spec@ == self@,
+    {
+        ZookeeperClusterSpec { inner: self.inner.clone() }
+    }
+
     #[verifier(external_body)]
     pub fn replicas(&self) -> (replicas: i32)
         ensures replicas as int == self@.replicas,
@@ -220,6 +258,25 @@ impl ZookeeperClusterSpec {
         }
     }
 
+    #[verifier(external_body)]
+    pub fn topology_spread_constraints(&self) -> (topology_spread_constraints: Option<Vec<TopologySpreadConstraint>>)
+        ensures
+            self@.topology_spread_constraints.is_Some() == topology_spread_constraints.is_Some(),
+            topology_spread_constraints.is_Some() ==> topology_spread_constraints.get_Some_0()@.map_values(|c: TopologySpreadConstraint| c@) == self@.topology_spread_constraints.get_Some_0(),
+    {
+        match &self.inner.topology_spread_constraints {
+            Some(cons) => Some(cons.clone().into_iter().map(|c: deps_hack::k8s_openapi::api::core::v1::TopologySpreadConstraint| TopologySpreadConstraint::from_kube(c)).collect()),
+            None => None,
+        }
+    }
+
+    #[verifier(external_body)]
+    pub fn priority_class_name(&self) -> (priority_class_name: Option<String>)
+        ensures opt_string_to_view(&priority_class_name) == self@.priority_class_name,
+    {
+        self.inner.priority_class_name.clone()
+    }
+
     #[verifier(external_body)]
     pub fn node_selector(&self) -> (node_selector: StringMap)
         ensures node_selector@ == self@.node_selector,
@@ -240,6 +297,49 @@ impl ZookeeperClusterSpec {
     {
         StringMap::from_rust_map(self.inner.annotations.clone())
     }
+
+    #[verifier(external_body)]
+    pub fn tls(&self) -> (tls: Option<ZookeeperTLSSpec>)
+        ensures
+            self@.tls.is_Some() == tls.is_Some(),
+            tls.is_Some() ==> tls.get_Some_0()@ == self@.tls.get_Some_0(),
+    {
+        match &self.inner.tls {
+            Some(t) => Some(ZookeeperTLSSpec { inner: t.clone() }),
+            None => None,
+        }
+    }
+
+    #[verifier(external_body)]
+    pub fn auth(&self) -> (auth: Option<ZookeeperAuthSpec>)
+        ensures
+            self@.auth.is_Some() == auth.is_Some(),
+            auth.is_Some() ==> auth.get_Some_0()@ == self@.auth.get_Some_0(),
+    {
+        match &self.inner.auth {
+            Some(a) => Some(ZookeeperAuthSpec { inner: a.clone() }),
+            None => None,
+        }
+    }
+
+    #[verifier(external_body)]
+    pub fn pod_disruption_budget(&self) -> (pod_disruption_budget: Option<ZookeeperPodDisruptionBudgetSpec>)
+        ensures
+            self@.pod_disruption_budget.is_Some() == pod_disruption_budget.is_Some(),
+            pod_disruption_budget.is_Some() ==> pod_disruption_budget.get_Some_0()@ == self@.pod_disruption_budget.get_Some_0(),
+    {
+        match &self.inner.pod_disruption_budget {
+            Some(p) => Some(ZookeeperPodDisruptionBudgetSpec { inner: p.clone() }),
+            None => None,
+        }
+    }
+}
+
+#[verifier(external)]
+impl ResourceWrapper<deps_hack::ZookeeperClusterSpec> for ZookeeperClusterSpec {
+    fn from_kube(inner: deps_hack::ZookeeperClusterSpec) -> ZookeeperClusterSpec { ZookeeperClusterSpec { inner: inner } }
+
+    fn into_kube(self) -> deps_hack::ZookeeperClusterSpec { self.inner }
 }
 
 #[verifier(external_body)]
@@ -430,6 +530,82 @@ impl ZookeeperPersistence {
     }
 }
 
+#[verifier(external_body)]
+pub struct ZookeeperTLSSpec {
+    inner: deps_hack::ZookeeperTLSSpec,
+}
+
+impl ZookeeperTLSSpec {
+    pub spec fn view(&self) -> spec_types::ZookeeperTLSSpecView;
+
+    #[verifier(external_body)]
+    pub fn secret_name(&self) -> (secret_name: String)
+        ensures secret_name@ == self@.secret_name,
+    {
+        self.inner.secret_name.clone()
+    }
+
+    #[verifier(external_body)]
+    pub fn secure_client_port(&self) -> (secure_client_port: i32)
+        ensures secure_client_port as int == self@.secure_client_port,
+    {
+        self.inner.secure_client_port
+    }
+
+    #[verifier(external_body)]
+    pub fn quorum_tls(&self) -> (quorum_tls: bool)
+        ensures quorum_tls == self@.quorum_tls,
+    {
+        self.inner.quorum_tls
+    }
+
+    #[verifier(external_body)]
+    pub fn plain_port_enabled(&self) -> (plain_port_enabled: bool)
+        ensures plain_port_enabled == self@.plain_port_enabled,
+    {
+        self.inner.plain_port_enabled
+    }
+}
+
+#[verifier(external_body)]
+pub struct ZookeeperAuthSpec {
+    inner: deps_hack::ZookeeperAuthSpec,
+}
+
+impl ZookeeperAuthSpec {
+    pub spec fn view(&self) -> spec_types::ZookeeperAuthSpecView;
+
+    #[verifier(external_body)]
+    pub fn secret_name(&self) -> (secret_name: String)
+        ensures secret_name@ == self@.secret_name,
+    {
+        self.inner.secret_name.clone()
+    }
+
+    #[verifier(external_body)]
+    pub fn sasl(&self) -> (sasl: bool)
+        ensures sasl == self@.sasl,
+    {
+        self.inner.sasl
+    }
+}
+
+#[verifier(external_body)]
+pub struct ZookeeperPodDisruptionBudgetSpec {
+    inner: deps_hack::ZookeeperPodDisruptionBudgetSpec,
+}
+
+impl ZookeeperPodDisruptionBudgetSpec {
+    pub spec fn view(&self) -> spec_types::ZookeeperPodDisruptionBudgetSpecView;
+
+    #[verifier(external_body)]
+    pub fn min_available(&self) -> (min_available: i32)
+        ensures min_available as int == self@.min_available,
+    {
+        self.inner.min_available
+    }
+}
+
 #[verifier(external_body)]
 pub struct ZookeeperClusterStatus {
     inner: deps_hack::ZookeeperClusterStatus,
@@ -452,6 +628,13 @@ impl ZookeeperClusterStatus {
         self.inner.ready_replicas = ready_replicas
     }
 
+    #[verifier(external_body)]
+    pub fn set_conditions(&mut self, conditions: Vec<Condition>)
+        ensures self@ == old(self)@.set_conditions(conditions@.map_values(|c: Condition| c@)),
+    {
+        self.inner.conditions = Some(into_kube_conditions(conditions))
+    }
+
     #[verifier(external)]
     fn into_kube(self) -> deps_hack::ZookeeperClusterStatus { self.inner }
 }
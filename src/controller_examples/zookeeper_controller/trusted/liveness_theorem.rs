@@ -5,7 +5,7 @@ use crate::kubernetes_api_objects::spec::prelude::*;
 use crate::kubernetes_cluster::spec::{cluster::*, cluster_state_machine::Step, message::*};
 use crate::temporal_logic::defs::*;
 use crate::vstd_ext::string_view::int_to_string_view;
-use crate::zookeeper_controller::trusted::{maker::*, spec_types::*, step::*};
+use crate::zookeeper_controller::trusted::{maker::*, spec_types::*, step::*, zookeeper_api_spec::*};
 use vstd::prelude::*;
 
 verus! {
@@ -22,10 +22,23 @@ pub open spec fn desired_state_is(zookeeper: ZookeeperClusterView) -> StatePred<
 
 pub open spec fn current_state_matches<M: Maker>(zookeeper: ZookeeperClusterView) -> StatePred<ZKCluster> {
     |s: ZKCluster| {
-        forall |sub_resource: SubResource| #[trigger] resource_state_matches::<M>(sub_resource, zookeeper, s.resources())
+        &&& forall |sub_resource: SubResource| #[trigger] resource_state_matches::<M>(sub_resource, zookeeper, s.resources())
+        &&& ensemble_matches::<M>(zookeeper, s.resources(), s.external_state())
     }
 }
 
+// ensemble_matches says the ensemble of the zookeeper cluster hosted by the current stateful set object
+// has been reconfigured and has no server beyond the desired replicas.
+// It does not require every desired server to be in the ensemble: the servers that are not up during
+// the reconfig join the ensemble by themselves when they start, which happens outside the controller.
+pub open spec fn ensemble_matches<M: Maker>(zookeeper: ZookeeperClusterView, resources: StoredState, state: ZKState) -> bool {
+    let key = M::make_stateful_set_key(zookeeper);
+    let addr = ZKNodeAddr::new(zookeeper.metadata.name.get_Some_0(), zookeeper.metadata.namespace.get_Some_0(), resources[key].metadata.uid.get_Some_0(), zk_config_path());
+    &&& resources.contains_key(key)
+    &&& state.ensemble.contains_key(addr)
+    &&& forall |id: int| #[trigger] state.ensemble[addr].contains(id) ==> 1 <= id <= zookeeper.spec.replicas
+}
+
 pub open spec fn resource_state_matches<M: Maker>(sub_resource: SubResource, zookeeper: ZookeeperClusterView, resources: StoredState) -> bool {
     match sub_resource {
         SubResource::HeadlessService => {
@@ -73,6 +86,15 @@ pub open spec fn resource_state_matches<M: Maker>(sub_resource: SubResource, zoo
             &&& obj.metadata.labels == M::make_admin_server_service(zookeeper).metadata.labels
             &&& obj.metadata.annotations == M::make_admin_server_service(zookeeper).metadata.annotations
         },
+        SubResource::PodDisruptionBudget => {
+            let key = M::make_pod_disruption_budget_key(zookeeper);
+            let obj = resources[key];
+            &&& resources.contains_key(key)
+            &&& PodDisruptionBudgetView::unmarshal(obj).is_Ok()
+            &&& PodDisruptionBudgetView::unmarshal(obj).get_Ok_0().spec == M::make_pod_disruption_budget(zookeeper).spec
+            &&& obj.metadata.labels == M::make_pod_disruption_budget(zookeeper).metadata.labels
+            &&& obj.metadata.annotations == M::make_pod_disruption_budget(zookeeper).metadata.annotations
+        },
         SubResource::ConfigMap => {
             let key = M::make_config_map_key(zookeeper);
             let obj = resources[key];
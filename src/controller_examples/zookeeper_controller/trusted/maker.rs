@@ -12,12 +12,14 @@ pub trait Maker {
     spec fn make_headless_service_key(zookeeper: ZookeeperClusterView) -> ObjectRef;
     spec fn make_client_service_key(zookeeper: ZookeeperClusterView) -> ObjectRef;
     spec fn make_admin_server_service_key(zookeeper: ZookeeperClusterView) -> ObjectRef;
+    spec fn make_pod_disruption_budget_key(zookeeper: ZookeeperClusterView) -> ObjectRef;
     spec fn make_config_map_key(zookeeper: ZookeeperClusterView) -> ObjectRef;
     spec fn make_stateful_set_key(zookeeper: ZookeeperClusterView) -> ObjectRef;
 
     spec fn make_headless_service(zookeeper: ZookeeperClusterView) -> ServiceView;
     spec fn make_client_service(zookeeper: ZookeeperClusterView) -> ServiceView;
     spec fn make_admin_server_service(zookeeper: ZookeeperClusterView) -> ServiceView;
+    spec fn make_pod_disruption_budget(zookeeper: ZookeeperClusterView) -> PodDisruptionBudgetView;
     spec fn make_config_map(zookeeper: ZookeeperClusterView) -> ConfigMapView;
     spec fn make_stateful_set(zookeeper: ZookeeperClusterView, config_map_rv: StringView) -> StatefulSetView;
 }
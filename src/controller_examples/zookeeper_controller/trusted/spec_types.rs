@@ -2,8 +2,9 @@
 // SPDX-License-Identifier: MIT
 use crate::kubernetes_api_objects::error::*;
 use crate::kubernetes_api_objects::spec::{
-    affinity::*, api_resource::*, common::*, dynamic::*, object_meta::*, owner_reference::*,
-    resource::*, resource_requirements::*, toleration::*,
+    affinity::*, api_resource::*, common::*, condition::*, dynamic::*, object_meta::*,
+    owner_reference::*, resource::*, resource_requirements::*, toleration::*,
+    topology_spread_constraint::*,
 };
 use crate::kubernetes_cluster::spec::{cluster::*, cluster_state_machine::*, message::*};
 use crate::vstd_ext::string_view::*;
@@ -48,6 +49,20 @@ impl ZookeeperClusterView {
         }
     }
 
+    pub open spec fn set_metadata(self, metadata: ObjectMetaView) -> ZookeeperClusterView {
+        ZookeeperClusterView {
+            metadata: metadata,
+            ..self
+        }
+    }
+
+    pub open spec fn set_spec(self, spec: ZookeeperClusterSpecView) -> ZookeeperClusterView {
+        ZookeeperClusterView {
+            spec: spec,
+            ..self
+        }
+    }
+
     pub open spec fn set_status(self, status: ZookeeperClusterStatusView) -> ZookeeperClusterView {
         ZookeeperClusterView {
             status: Some(status),
@@ -140,6 +155,10 @@ impl ResourceView for ZookeeperClusterView {
         &&& self.spec.replicas >= 3
         &&& self.spec.conf.sync_limit >= 1
         &&& self.spec.conf.min_session_timeout <= self.spec.conf.max_session_timeout
+        &&& self.spec.pod_disruption_budget.is_Some() ==> self.spec.pod_disruption_budget.get_Some_0().min_available >= 0
+        // The controller only connects through the plain client port (see set_up_zk_client),
+        // so a cluster with TLS cannot close it.
+        &&& self.spec.tls.is_Some() ==> self.spec.tls.get_Some_0().plain_port_enabled
     }
 
     open spec fn transition_validation(self, old_obj: ZookeeperClusterView) -> bool {
@@ -147,16 +166,23 @@ impl ResourceView for ZookeeperClusterView {
         &&& self.spec.persistence.enabled == old_obj.spec.persistence.enabled
         &&& self.spec.persistence.storage_size == old_obj.spec.persistence.storage_size
         &&& self.spec.persistence.storage_class_name == old_obj.spec.persistence.storage_class_name
+        // The znodes created with authentication are only writable by the same identity,
+        // so the authentication cannot be turned off or switched to another secret later.
+        &&& self.spec.auth == old_obj.spec.auth
     }
 }
 
 impl CustomResourceView for ZookeeperClusterView {
     proof fn kind_is_custom_resource() {}
 
+    open spec fn is_namespaced() -> bool { true }
+
     open spec fn spec_status_validation(obj_spec: Self::Spec, obj_status: Self::Status) -> bool {
         &&& obj_spec.replicas >= 3
         &&& obj_spec.conf.sync_limit >= 1
         &&& obj_spec.conf.min_session_timeout <= obj_spec.conf.max_session_timeout
+        &&& obj_spec.pod_disruption_budget.is_Some() ==> obj_spec.pod_disruption_budget.get_Some_0().min_available >= 0
+        &&& obj_spec.tls.is_Some() ==> obj_spec.tls.get_Some_0().plain_port_enabled
     }
 
     proof fn validation_result_determined_by_spec_and_status()
@@ -173,9 +199,14 @@ pub struct ZookeeperClusterSpecView {
     pub resources: Option<ResourceRequirementsView>,
     pub affinity: Option<AffinityView>,
     pub tolerations: Option<Seq<TolerationView>>,
+    pub topology_spread_constraints: Option<Seq<TopologySpreadConstraintView>>,
+    pub priority_class_name: Option<StringView>,
     pub node_selector: Map<StringView, StringView>,
     pub labels: Map<StringView, StringView>,
     pub annotations: Map<StringView, StringView>,
+    pub tls: Option<ZookeeperTLSSpecView>,
+    pub auth: Option<ZookeeperAuthSpecView>,
+    pub pod_disruption_budget: Option<ZookeeperPodDisruptionBudgetSpecView>,
 }
 
 pub struct ZookeeperPortsView {
@@ -210,14 +241,32 @@ pub struct ZookeeperPersistenceView {
     pub storage_class_name: StringView,
 }
 
+pub struct ZookeeperTLSSpecView {
+    pub secret_name: StringView,
+    pub secure_client_port: int,
+    pub quorum_tls: bool,
+    pub plain_port_enabled: bool,
+}
+
+pub struct ZookeeperAuthSpecView {
+    pub secret_name: StringView,
+    pub sasl: bool,
+}
+
+pub struct ZookeeperPodDisruptionBudgetSpecView {
+    pub min_available: int,
+}
+
 pub struct ZookeeperClusterStatusView {
     pub ready_replicas: int,
+    pub conditions: Option<Seq<ConditionView>>,
 }
 
 impl ZookeeperClusterStatusView {
     pub open spec fn default() -> ZookeeperClusterStatusView {
         ZookeeperClusterStatusView {
             ready_replicas: 0,
+            conditions: None,
         }
     }
 
@@ -227,6 +276,13 @@ impl ZookeeperClusterStatusView {
             ..self
         }
     }
+
+    pub open spec fn set_conditions(self, conditions: Seq<ConditionView>) -> ZookeeperClusterStatusView {
+        ZookeeperClusterStatusView {
+            conditions: Some(conditions),
+            ..self
+        }
+    }
 }
 
 
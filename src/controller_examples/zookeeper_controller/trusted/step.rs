@@ -14,7 +14,9 @@ pub enum ZookeeperReconcileStep {
     AfterCreateZKParentNode,
     AfterCreateZKNode,
     AfterUpdateZKNode,
+    AfterReconfigZK,
     AfterUpdateStatus,
+    AfterUpdateDegradedStatus,
     Done,
     Error,
 }
@@ -40,6 +42,7 @@ pub enum SubResource {
     HeadlessService,
     ClientService,
     AdminServerService,
+    PodDisruptionBudget,
     ConfigMap,
     StatefulSet,
 }
@@ -50,6 +53,9 @@ pub enum ZKAPIError {
     ZKNodeCreateAlreadyExists,
     ZKNodeCreateFailed,
     ZKNodeSetDataFailed,
+    ZKReconfigFailed,
+    // The zookeeper cluster did not answer within the per-call timeout.
+    ZKTimeout,
 }
 
 impl ZKAPIError {
@@ -71,6 +77,8 @@ impl std::fmt::Debug for ZKAPIError {
             ZKAPIError::ZKNodeCreateAlreadyExists => write!(f, "ZKNodeCreateAlreadyExists"),
             ZKAPIError::ZKNodeCreateFailed => write!(f, "ZKNodeCreateFailed"),
             ZKAPIError::ZKNodeSetDataFailed => write!(f, "ZKNodeSetDataFailed"),
+            ZKAPIError::ZKReconfigFailed => write!(f, "ZKReconfigFailed"),
+            ZKAPIError::ZKTimeout => write!(f, "ZKTimeout"),
         }
     }
 }
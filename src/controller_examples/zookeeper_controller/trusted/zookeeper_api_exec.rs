@@ -1,16 +1,28 @@
 // Copyright 2022 VMware, Inc.
 // SPDX-License-Identifier: MIT
 use crate::external_api::exec::*;
+use crate::vstd_ext::string_view::*;
 use crate::zookeeper_controller::trusted::{
     step::*,
     zookeeper_api_spec::{
         ZKAPICreateResultView, ZKAPIExistsResultView, ZKAPIInputView, ZKAPIOutputView,
-        ZKAPISetDataResultView,
+        ZKAPIReconfigResultView, ZKAPISetDataResultView,
     },
 };
 use core::time::Duration;
-use deps_hack::tracing::info;
-use deps_hack::zookeeper::{Acl, CreateMode, WatchedEvent, Watcher, ZkError, ZkResult, ZooKeeper};
+use deps_hack::futures::future::BoxFuture;
+use deps_hack::k8s_openapi::api::core::v1::{Pod, Secret};
+use deps_hack::kube::{api::AttachParams, Api, Client};
+use deps_hack::tokio::io::{AsyncReadExt, AsyncWriteExt};
+use deps_hack::tokio::net::TcpStream;
+use deps_hack::tokio::time::timeout;
+use deps_hack::tracing::{error, info};
+use deps_hack::zookeeper::{
+    Acl, CreateMode, KeeperState, WatchedEvent, Watcher, ZkError, ZkResult, ZooKeeper,
+};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
 use vstd::pervasive::unreached;
 use vstd::{prelude::*, string::*, view::*};
 
@@ -51,11 +63,21 @@ impl View for ZKAPISetDataResult {
     open spec fn view(&self) -> ZKAPISetDataResultView { ZKAPISetDataResultView {res: self.res} }
 }
 
+pub struct ZKAPIReconfigResult {
+    pub res: Result<(), ZKAPIError>,
+}
+
+impl View for ZKAPIReconfigResult {
+    type V = ZKAPIReconfigResultView;
+    open spec fn view(&self) -> ZKAPIReconfigResultView { ZKAPIReconfigResultView {res: self.res} }
+}
+
 #[is_variant]
 pub enum ZKAPIInput {
     ExistsRequest(String, String, i32, Vec<String>),
-    CreateRequest(String, String, i32, Vec<String>, String),
-    SetDataRequest(String, String, i32, Vec<String>, String, i32),
+    CreateRequest(String, String, i32, Vec<String>, String, Option<String>),
+    SetDataRequest(String, String, i32, Vec<String>, String, i32, Option<String>),
+    ReconfigRequest(String, String, i32, i32, Option<String>),
 }
 
 #[is_variant]
@@ -63,6 +85,7 @@ pub enum ZKAPIOutput {
     ExistsResponse(ZKAPIExistsResult),
     CreateResponse(ZKAPICreateResult),
     SetDataResponse(ZKAPISetDataResult),
+    ReconfigResponse(ZKAPIReconfigResult),
 }
 
 impl View for ZKAPIInput {
@@ -71,10 +94,12 @@ impl View for ZKAPIInput {
         match self {
             ZKAPIInput::ExistsRequest(name, namespace, port, path)
                 => ZKAPIInputView::ExistsRequest(name@, namespace@, *port as int, path@.map_values(|s: String| s@)),
-            ZKAPIInput::CreateRequest(name, namespace, port, path, data)
-                => ZKAPIInputView::CreateRequest(name@, namespace@, *port as int, path@.map_values(|s: String| s@), data@),
-            ZKAPIInput::SetDataRequest(name, namespace, port, path, data, version)
-                => ZKAPIInputView::SetDataRequest(name@, namespace@, *port as int, path@.map_values(|s: String| s@), data@, *version as int),
+            ZKAPIInput::CreateRequest(name, namespace, port, path, data, auth)
+                => ZKAPIInputView::CreateRequest(name@, namespace@, *port as int, path@.map_values(|s: String| s@), data@, opt_string_to_view(auth)),
+            ZKAPIInput::SetDataRequest(name, namespace, port, path, data, version, auth)
+                => ZKAPIInputView::SetDataRequest(name@, namespace@, *port as int, path@.map_values(|s: String| s@), data@, *version as int, opt_string_to_view(auth)),
+            ZKAPIInput::ReconfigRequest(name, namespace, port, replicas, auth)
+                => ZKAPIInputView::ReconfigRequest(name@, namespace@, *port as int, *replicas as int, opt_string_to_view(auth)),
         }
     }
 }
@@ -86,6 +111,7 @@ impl View for ZKAPIOutput {
             ZKAPIOutput::ExistsResponse(result) => ZKAPIOutputView::ExistsResponse(result@),
             ZKAPIOutput::CreateResponse(result) => ZKAPIOutputView::CreateResponse(result@),
             ZKAPIOutput::SetDataResponse(result) => ZKAPIOutputView::SetDataResponse(result@),
+            ZKAPIOutput::ReconfigResponse(result) => ZKAPIOutputView::ReconfigResponse(result@),
         }
     }
 }
@@ -97,48 +123,317 @@ pub struct ZKAPIShimLayer {}
 impl ExternalAPIShimLayer for ZKAPIShimLayer {
     type Input = ZKAPIInput;
     type Output = ZKAPIOutput;
+    // The controller runtime uses call_external_api_async, so this only blocks the current thread on it.
     fn call_external_api(input: ZKAPIInput) -> ZKAPIOutput {
-        match input {
-            ZKAPIInput::ExistsRequest(zk_name, zk_namespace, port, path) => {
-                ZKAPIOutput::ExistsResponse(zk_exists(zk_name, zk_namespace, port, path))
-            }
-            ZKAPIInput::CreateRequest(zk_name, zk_namespace, port, path, data) => {
-                ZKAPIOutput::CreateResponse(zk_create(zk_name, zk_namespace, port, path, data))
+        deps_hack::tokio::task::block_in_place(|| {
+            deps_hack::tokio::runtime::Handle::current()
+                .block_on(Self::call_external_api_async(input))
+        })
+    }
+}
+
+impl AsyncExternalAPIShimLayer for ZKAPIShimLayer {
+    fn call_external_api_async(input: ZKAPIInput) -> BoxFuture<'static, ZKAPIOutput> {
+        Box::pin(async move {
+            match input {
+                ZKAPIInput::ExistsRequest(zk_name, zk_namespace, port, path) => {
+                    ZKAPIOutput::ExistsResponse(zk_exists(zk_name, zk_namespace, port, path).await)
+                }
+                ZKAPIInput::CreateRequest(zk_name, zk_namespace, port, path, data, auth) => {
+                    ZKAPIOutput::CreateResponse(
+                        zk_create(zk_name, zk_namespace, port, path, data, auth).await,
+                    )
+                }
+                ZKAPIInput::SetDataRequest(zk_name, zk_namespace, port, path, data, version, auth) => {
+                    ZKAPIOutput::SetDataResponse(
+                        zk_set_data(zk_name, zk_namespace, port, path, data, version, auth).await,
+                    )
+                }
+                ZKAPIInput::ReconfigRequest(zk_name, zk_namespace, port, replicas, auth) => {
+                    ZKAPIOutput::ReconfigResponse(
+                        zk_reconfig(zk_name, zk_namespace, port, replicas, auth).await,
+                    )
+                }
             }
-            ZKAPIInput::SetDataRequest(zk_name, zk_namespace, port, path, data, version) => {
-                ZKAPIOutput::SetDataResponse(zk_set_data(
-                    zk_name,
-                    zk_namespace,
-                    port,
-                    path,
-                    data,
-                    version,
-                ))
+        })
+    }
+
+    // Creating a node that already exists is expected when the reconcile is retried, so it is not reported.
+    fn error_of(output: &ZKAPIOutput) -> Option<String> {
+        let err = match output {
+            ZKAPIOutput::ExistsResponse(result) => result.res.as_ref().err(),
+            ZKAPIOutput::CreateResponse(result) => result.res.as_ref().err(),
+            ZKAPIOutput::SetDataResponse(result) => result.res.as_ref().err(),
+            ZKAPIOutput::ReconfigResponse(result) => result.res.as_ref().err(),
+        }?;
+        match err {
+            ZKAPIError::ZKNodeExistsFailed => Some("Checking the ZK node failed".to_string()),
+            ZKAPIError::ZKNodeCreateAlreadyExists => None,
+            ZKAPIError::ZKNodeCreateFailed => Some("Creating the ZK node failed".to_string()),
+            ZKAPIError::ZKNodeSetDataFailed => Some("Updating the ZK node failed".to_string()),
+            ZKAPIError::ZKReconfigFailed => Some("Reconfiguring the ZK cluster failed".to_string()),
+            ZKAPIError::ZKTimeout => Some("The ZK cluster did not answer in time".to_string()),
+        }
+    }
+}
+
+// Each call to the zookeeper cluster, including connecting to it if there is no pooled session,
+// fails with ZKAPIError::ZKTimeout if it does not finish within ZK_CALL_TIMEOUT,
+// so that the reconcile does not stall when the cluster is unreachable.
+const ZK_CALL_TIMEOUT: Duration = Duration::from_secs(10);
+
+const ZK_SESSION_TIMEOUT: Duration = Duration::from_secs(10);
+
+// Reconfig runs zkCli.sh in a pod, which takes longer than a single call.
+const ZK_RECONFIG_TIMEOUT: Duration = Duration::from_secs(60);
+
+// A session is identified by the cluster and the credentials secret it authenticated with.
+#[derive(Clone, PartialEq, Eq, Hash)]
+struct ZKSessionKey {
+    name: String,
+    namespace: String,
+    port: i32,
+    auth: Option<String>,
+}
+
+impl ZKSessionKey {
+    fn new(name: &str, namespace: &str, port: i32, auth: &Option<String>) -> ZKSessionKey {
+        ZKSessionKey {
+            name: name.to_string(),
+            namespace: namespace.to_string(),
+            port,
+            auth: auth.clone(),
+        }
+    }
+}
+
+// ZKClient is what the session pool needs from a client, so that the pool can be tested without a zookeeper server.
+trait ZKClient: Send + Sync + 'static {
+    fn close_session(&self);
+}
+
+impl ZKClient for ZooKeeper {
+    fn close_session(&self) {
+        let _ = self.close();
+    }
+}
+
+struct ZKSession<C> {
+    client: Arc<C>,
+    // Set by the watcher once the server expires the session, after which the client never reconnects.
+    expired: Arc<AtomicBool>,
+}
+
+struct SessionWatcher {
+    expired: Arc<AtomicBool>,
+}
+
+impl Watcher for SessionWatcher {
+    fn handle(&self, e: WatchedEvent) {
+        if matches!(e.keeper_state, KeeperState::Expired) {
+            self.expired.store(true, Ordering::SeqCst);
+        }
+    }
+}
+
+// The sessions are pooled per cluster so that each call does not connect (and authenticate) again.
+struct ZKSessionPool<C> {
+    sessions: Mutex<HashMap<ZKSessionKey, ZKSession<C>>>,
+}
+
+impl<C: ZKClient> ZKSessionPool<C> {
+    fn new() -> ZKSessionPool<C> {
+        ZKSessionPool {
+            sessions: Mutex::new(HashMap::new()),
+        }
+    }
+
+    // get returns the pooled client, or None if there is no session or the session expired,
+    // in which case the caller connects again.
+    fn get(&self, key: &ZKSessionKey) -> Option<Arc<C>> {
+        let mut sessions = self.sessions.lock().unwrap();
+        match sessions.get(key) {
+            Some(session) if !session.expired.load(Ordering::SeqCst) => Some(session.client.clone()),
+            Some(_) => {
+                info!("The zk session to {}/{} expired, reconnecting ...", key.namespace, key.name);
+                sessions.remove(key);
+                None
             }
+            None => None,
+        }
+    }
+
+    fn insert(&self, key: ZKSessionKey, client: Arc<C>, expired: Arc<AtomicBool>) {
+        self.sessions
+            .lock()
+            .unwrap()
+            .insert(key, ZKSession { client, expired });
+    }
+
+    // evict drops the pooled session so that the next call reconnects.
+    // Closing the session waits for the server, so it happens on the blocking thread pool.
+    fn evict(&self, key: &ZKSessionKey) {
+        if let Some(session) = self.sessions.lock().unwrap().remove(key) {
+            deps_hack::tokio::task::spawn_blocking(move || session.client.close_session());
         }
     }
 }
 
-struct NoopWatcher;
+fn zk_sessions() -> &'static ZKSessionPool<ZooKeeper> {
+    static SESSIONS: OnceLock<ZKSessionPool<ZooKeeper>> = OnceLock::new();
+    SESSIONS.get_or_init(ZKSessionPool::new)
+}
 
-impl Watcher for NoopWatcher {
-    fn handle(&self, _e: WatchedEvent) {}
+async fn get_zk_client(key: &ZKSessionKey) -> Result<Arc<ZooKeeper>, String> {
+    if let Some(zk_client) = zk_sessions().get(key) {
+        return Ok(zk_client);
+    }
+    let credentials = match &key.auth {
+        Some(secret_name) => Some(get_digest_credentials(&key.namespace, secret_name).await?),
+        None => None,
+    };
+    let uri = format!("{}-client.{}.svc.cluster.local:{}", key.name, key.namespace, key.port);
+    let expired = Arc::new(AtomicBool::new(false));
+    let watcher = SessionWatcher {
+        expired: expired.clone(),
+    };
+    let zk_client = Arc::new(
+        deps_hack::tokio::task::spawn_blocking(move || set_up_zk_client(&uri, watcher, credentials))
+            .await
+            .map_err(|e| e.to_string())??,
+    );
+    zk_sessions().insert(key.clone(), zk_client.clone(), expired);
+    Ok(zk_client)
 }
 
-pub fn set_up_zk_client(name: &String, namespace: &String, port: i32) -> ZkResult<ZooKeeper> {
-    let uri = &format!("{}-client.{}.svc.cluster.local:{}", name, namespace, port);
+// set_up_zk_client connects to the plain client port of the zookeeper cluster.
+// If there are credentials, the client also authenticates with the digest scheme,
+// so that it can write the nodes it created before.
+//
+// Note that the zookeeper client library does not support TLS or SASL,
+// so the controller keeps using the plain client port even if the cluster enables TLS.
+// The validation of ZookeeperCluster rejects TLS with tls.plainPortEnabled set to false,
+// so the plain client port is always open.
+fn set_up_zk_client(
+    uri: &str,
+    watcher: SessionWatcher,
+    credentials: Option<String>,
+) -> Result<ZooKeeper, String> {
     info!("Connecting to zk uri {} ...", uri);
-    ZooKeeper::connect(uri, Duration::from_secs(10), NoopWatcher)
+    let zk_client =
+        ZooKeeper::connect(uri, ZK_SESSION_TIMEOUT, watcher).map_err(|e| e.to_string())?;
+    if let Some(credentials) = credentials {
+        if let Err(e) = zk_client.add_auth("digest", credentials.into_bytes()) {
+            error!("Authenticating to zk uri {} failed: {}", uri, e);
+            let _ = zk_client.close();
+            return Err(e.to_string());
+        }
+    }
+    Ok(zk_client)
+}
+
+// get_digest_credentials reads the username and password from the secret and returns them as "username:password".
+async fn get_digest_credentials(namespace: &str, secret_name: &str) -> Result<String, String> {
+    let client = Client::try_default().await.map_err(|e| e.to_string())?;
+    let secret = Api::<Secret>::namespaced(client, namespace)
+        .get(secret_name)
+        .await
+        .map_err(|e| e.to_string())?;
+    let data = secret.data.unwrap_or_default();
+    let field = |key: &str| {
+        data.get(key)
+            .map(|value| String::from_utf8_lossy(&value.0).to_string())
+            .ok_or(format!("secret {} has no {}", secret_name, key))
+    };
+    Ok(format!("{}:{}", field("username")?, field("password")?))
+}
+
+enum ZKCallError {
+    Timeout,
+    Session(String),
+    Zk(ZkError),
+}
+
+impl ZKCallError {
+    // into_api_error reports the timeout as ZKTimeout and any other error as failed.
+    fn into_api_error(self, failed: ZKAPIError) -> ZKAPIError {
+        match self {
+            ZKCallError::Timeout => {
+                error!("The zk call timed out after {:?}", ZK_CALL_TIMEOUT);
+                ZKAPIError::ZKTimeout
+            }
+            ZKCallError::Session(e) => {
+                error!("Setting up the zk session failed: {}", e);
+                failed
+            }
+            ZKCallError::Zk(e) => {
+                error!("The zk call failed: {:?}", e);
+                failed
+            }
+        }
+    }
+}
+
+// zk_call runs op with the pooled session of the cluster.
+// The zookeeper client library is synchronous, so op runs on the blocking thread pool.
+async fn zk_call<T, F>(key: ZKSessionKey, op: F) -> Result<T, ZKCallError>
+where
+    T: Send + 'static,
+    F: FnOnce(&ZooKeeper) -> ZkResult<T> + Send + 'static,
+{
+    let call = async {
+        let zk_client = get_zk_client(&key).await.map_err(ZKCallError::Session)?;
+        deps_hack::tokio::task::spawn_blocking(move || op(&zk_client))
+            .await
+            .map_err(|e| ZKCallError::Session(e.to_string()))?
+            .map_err(ZKCallError::Zk)
+    };
+    run_zk_call(zk_sessions(), &key, ZK_CALL_TIMEOUT, call).await
+}
+
+// run_zk_call bounds the call by call_timeout.
+// If the call times out or the session is lost, the session is evicted so that the next call reconnects.
+async fn run_zk_call<C, T, Fut>(
+    pool: &ZKSessionPool<C>,
+    key: &ZKSessionKey,
+    call_timeout: Duration,
+    call: Fut,
+) -> Result<T, ZKCallError>
+where
+    C: ZKClient,
+    Fut: std::future::Future<Output = Result<T, ZKCallError>>,
+{
+    let result = match timeout(call_timeout, call).await {
+        Err(_) => Err(ZKCallError::Timeout),
+        Ok(result) => result,
+    };
+    match result {
+        Err(ZKCallError::Timeout)
+        | Err(ZKCallError::Zk(ZkError::SessionExpired))
+        | Err(ZKCallError::Zk(ZkError::ConnectionLoss)) => pool.evict(key),
+        _ => {}
+    }
+    result
+}
+
+// Nodes created with authentication can only be written by the creator, but everyone can read them,
+// since the zookeeper pods read the cluster size from the node when they start.
+fn make_acl(auth: &Option<String>) -> Vec<Acl> {
+    if auth.is_some() {
+        [Acl::creator_all().as_slice(), Acl::read_unsafe().as_slice()].concat()
+    } else {
+        Acl::open_unsafe().to_vec()
+    }
 }
 
-pub fn zk_exists(
+pub async fn zk_exists(
     name: String,
     namespace: String,
     port: i32,
     path: Vec<String>,
 ) -> ZKAPIExistsResult {
     let result = ZKAPIExistsResult {
-        res: zk_exists_internal(name, namespace, port, path),
+        res: zk_exists_internal(name, namespace, port, path).await,
     };
     match result.res {
         Err(_) => info!("Checking existence of zk node failed"),
@@ -153,20 +448,17 @@ pub fn zk_exists(
     result
 }
 
-pub fn zk_exists_internal(
+pub async fn zk_exists_internal(
     name: String,
     namespace: String,
     port: i32,
     path: Vec<String>,
 ) -> Result<Option<i32>, ZKAPIError> {
-    let zk_client =
-        set_up_zk_client(&name, &namespace, port).map_err(|_e| ZKAPIError::ZKNodeExistsFailed)?;
     let path_as_string = format!("/{}", path.join("/"));
     info!("Checking existence of {} ...", &path_as_string);
-    let exist_result = zk_client.exists(path_as_string.as_str(), false);
-    let _ = zk_client.close();
-    match exist_result {
-        Err(e) => Err(ZKAPIError::ZKNodeExistsFailed),
+    let key = ZKSessionKey::new(&name, &namespace, port, &None);
+    match zk_call(key, move |zk_client| zk_client.exists(path_as_string.as_str(), false)).await {
+        Err(e) => Err(e.into_api_error(ZKAPIError::ZKNodeExistsFailed)),
         Ok(o) => match o {
             Some(stat) => Ok(Some(stat.version)),
             None => Ok(None),
@@ -174,15 +466,16 @@ pub fn zk_exists_internal(
     }
 }
 
-pub fn zk_create(
+pub async fn zk_create(
     name: String,
     namespace: String,
     port: i32,
     path: Vec<String>,
     data: String,
+    auth: Option<String>,
 ) -> ZKAPICreateResult {
     let result = ZKAPICreateResult {
-        res: zk_create_internal(name, namespace, port, path, data),
+        res: zk_create_internal(name, namespace, port, path, data, auth).await,
     };
     match result.res {
         Err(_) => info!("Create zk node failed"),
@@ -191,44 +484,46 @@ pub fn zk_create(
     result
 }
 
-pub fn zk_create_internal(
+pub async fn zk_create_internal(
     name: String,
     namespace: String,
     port: i32,
     path: Vec<String>,
     data: String,
+    auth: Option<String>,
 ) -> Result<(), ZKAPIError> {
-    let zk_client =
-        set_up_zk_client(&name, &namespace, port).map_err(|_e| ZKAPIError::ZKNodeCreateFailed)?;
     let path_as_string = format!("/{}", path.join("/"));
     let data_as_string = data;
     info!("Creating {} {} ...", &path_as_string, &data_as_string);
-    let create_result = zk_client.create(
-        path_as_string.as_str(),
-        data_as_string.as_str().as_bytes().to_vec(),
-        Acl::open_unsafe().to_vec(),
-        CreateMode::Persistent,
-    );
-    let _ = zk_client.close();
+    let acl = make_acl(&auth);
+    let key = ZKSessionKey::new(&name, &namespace, port, &auth);
+    let create_result = zk_call(key, move |zk_client| {
+        zk_client.create(
+            path_as_string.as_str(),
+            data_as_string.as_str().as_bytes().to_vec(),
+            acl,
+            CreateMode::Persistent,
+        )
+    })
+    .await;
     match create_result {
-        Err(e) => match e {
-            ZkError::NodeExists => Err(ZKAPIError::ZKNodeCreateAlreadyExists),
-            _ => Err(ZKAPIError::ZKNodeCreateFailed),
-        },
+        Err(ZKCallError::Zk(ZkError::NodeExists)) => Err(ZKAPIError::ZKNodeCreateAlreadyExists),
+        Err(e) => Err(e.into_api_error(ZKAPIError::ZKNodeCreateFailed)),
         Ok(_) => Ok(()),
     }
 }
 
-pub fn zk_set_data(
+pub async fn zk_set_data(
     name: String,
     namespace: String,
     port: i32,
     path: Vec<String>,
     data: String,
     version: i32,
+    auth: Option<String>,
 ) -> ZKAPISetDataResult {
     let result = ZKAPISetDataResult {
-        res: zk_set_data_internal(name, namespace, port, path, data, version),
+        res: zk_set_data_internal(name, namespace, port, path, data, version, auth).await,
     };
     match result.res {
         Err(_) => info!("Set zk node failed"),
@@ -237,30 +532,317 @@ pub fn zk_set_data(
     result
 }
 
-pub fn zk_set_data_internal(
+pub async fn zk_set_data_internal(
     name: String,
     namespace: String,
     port: i32,
     path: Vec<String>,
     data: String,
     version: i32,
+    auth: Option<String>,
 ) -> Result<(), ZKAPIError> {
-    let zk_client =
-        set_up_zk_client(&name, &namespace, port).map_err(|_e| ZKAPIError::ZKNodeSetDataFailed)?;
     let path_as_string = format!("/{}", path.join("/"));
     let data_as_string = data;
     info!(
         "Setting {} {} {} ...",
         &path_as_string, &data_as_string, version
     );
-    let set_result = zk_client.set_data(
-        path_as_string.as_str(),
-        data_as_string.as_str().as_bytes().to_vec(),
-        Some(version),
-    );
-    let _ = zk_client.close();
+    let key = ZKSessionKey::new(&name, &namespace, port, &auth);
+    let set_result = zk_call(key, move |zk_client| {
+        zk_client.set_data(
+            path_as_string.as_str(),
+            data_as_string.as_str().as_bytes().to_vec(),
+            Some(version),
+        )
+    })
+    .await;
     match set_result {
-        Err(_) => Err(ZKAPIError::ZKNodeSetDataFailed),
+        Err(e) => Err(e.into_api_error(ZKAPIError::ZKNodeSetDataFailed)),
         Ok(_) => Ok(()),
     }
 }
+
+pub async fn zk_reconfig(
+    name: String,
+    namespace: String,
+    port: i32,
+    replicas: i32,
+    auth: Option<String>,
+) -> ZKAPIReconfigResult {
+    let result = ZKAPIReconfigResult {
+        res: zk_reconfig_internal(name, namespace, port, replicas, auth).await,
+    };
+    match result.res {
+        Err(_) => info!("Reconfig zk ensemble failed"),
+        Ok(_) => info!("Reconfig zk ensemble successfully"),
+    }
+    result
+}
+
+// zk_reconfig_internal implements handle_reconfig in zookeeper_api_spec.rs.
+// It reads the current dynamic configuration, removes the servers beyond replicas,
+// and adds the missing servers that are already up. The missing servers that are not up yet
+// join the ensemble by themselves (in zookeeperStart.sh) once the stateful set creates them,
+// since adding a server that is down would leave the new configuration without a quorum.
+// Whether a server is up is modeled by zk_server_is_up.
+pub async fn zk_reconfig_internal(
+    name: String,
+    namespace: String,
+    port: i32,
+    replicas: i32,
+    auth: Option<String>,
+) -> Result<(), ZKAPIError> {
+    let key = ZKSessionKey::new(&name, &namespace, port, &None);
+    let config = match zk_call(key, |zk_client| zk_client.get_data("/zookeeper/config", false)).await {
+        Err(e) => return Err(e.into_api_error(ZKAPIError::ZKReconfigFailed)),
+        Ok((data, _)) => String::from_utf8_lossy(&data).to_string(),
+    };
+    let servers = parse_dynamic_config(&config);
+    let leaving = servers
+        .iter()
+        .filter(|(id, _)| *id > replicas)
+        .map(|(id, _)| id.to_string())
+        .collect::<Vec<_>>();
+    // All the servers listen on the same quorum and leader election ports,
+    // so the joining servers reuse the ports of a current member.
+    let mut joining = Vec::new();
+    if let Some((_, member)) = servers.first() {
+        for id in 1..=replicas {
+            if servers.iter().all(|(member_id, _)| *member_id != id)
+                && server_is_up(&name, &namespace, port, id).await
+            {
+                joining.push(make_server_config(&name, &namespace, id, member));
+            }
+        }
+    }
+    if leaving.is_empty() && joining.is_empty() {
+        info!("The zk ensemble already has {} servers", replicas);
+        return Ok(());
+    }
+    info!("Reconfig zk ensemble: add {:?} remove {:?} ...", &joining, &leaving);
+    match timeout(
+        ZK_RECONFIG_TIMEOUT,
+        run_reconfig(&name, &namespace, port, &joining, &leaving, &auth),
+    )
+    .await
+    {
+        Err(_) => {
+            error!("Reconfig timed out after {:?}", ZK_RECONFIG_TIMEOUT);
+            Err(ZKAPIError::ZKTimeout)
+        }
+        Ok(Err(e)) => {
+            error!("Reconfig failed: {}", e);
+            Err(ZKAPIError::ZKReconfigFailed)
+        }
+        Ok(Ok(())) => Ok(()),
+    }
+}
+
+// parse_dynamic_config returns the id and the address of each server in the dynamic configuration,
+// which looks like "server.1=zk-0.zk-headless.default.svc.cluster.local:2888:3888:participant;0.0.0.0:2181".
+fn parse_dynamic_config(config: &str) -> Vec<(i32, String)> {
+    config
+        .lines()
+        .filter_map(|line| {
+            let (key, value) = line.trim().split_once('=')?;
+            let id = key.strip_prefix("server.")?.parse::<i32>().ok()?;
+            Some((id, value.to_string()))
+        })
+        .collect()
+}
+
+// The server with id i is hosted by the pod with ordinal i - 1.
+fn server_host(name: &str, namespace: &str, id: i32) -> String {
+    format!("{}-{}.{}-headless.{}.svc.cluster.local", name, id - 1, name, namespace)
+}
+
+fn make_server_config(name: &str, namespace: &str, id: i32, member: &str) -> String {
+    let ports = member.split_once(':').map(|(_, ports)| ports).unwrap_or("");
+    format!("server.{}={}:{}", id, server_host(name, namespace, id), ports)
+}
+
+// server_is_up sends the "ruok" four letter word to the server, which answers "imok" if it is running.
+async fn server_is_up(name: &str, namespace: &str, port: i32, id: i32) -> bool {
+    let addr = format!("{}:{}", server_host(name, namespace, id), port);
+    let ask = async {
+        let mut stream = TcpStream::connect(addr.as_str()).await?;
+        stream.write_all(b"ruok").await?;
+        let mut answer = String::new();
+        stream.read_to_string(&mut answer).await?;
+        Ok::<String, std::io::Error>(answer)
+    };
+    matches!(timeout(Duration::from_secs(5), ask).await, Ok(Ok(answer)) if answer == "imok")
+}
+
+// run_reconfig issues the reconfig command with zkCli.sh inside the first zookeeper pod,
+// which outlives every scale down since there are always at least three replicas.
+// The commands are written to the standard input so that the credentials do not show up in the exec request.
+// With authentication, the credentials need to be allowed to write /zookeeper/config (e.g., be the super user).
+async fn run_reconfig(
+    name: &str,
+    namespace: &str,
+    port: i32,
+    joining: &[String],
+    leaving: &[String],
+    auth: &Option<String>,
+) -> Result<(), String> {
+    let mut commands = String::new();
+    if let Some(secret_name) = auth {
+        commands.push_str(&format!(
+            "addauth digest {}\n",
+            get_digest_credentials(namespace, secret_name).await?
+        ));
+    }
+    commands.push_str("reconfig");
+    if !joining.is_empty() {
+        commands.push_str(&format!(" -add {}", joining.join(",")));
+    }
+    if !leaving.is_empty() {
+        commands.push_str(&format!(" -remove {}", leaving.join(",")));
+    }
+    commands.push_str("\nquit\n");
+    let server = format!("localhost:{}", port);
+    let client = Client::try_default().await.map_err(|e| e.to_string())?;
+    let mut attached = Api::<Pod>::namespaced(client, namespace)
+        .exec(
+            &format!("{}-0", name),
+            vec!["zkCli.sh", "-server", server.as_str()],
+            &AttachParams::default().stdin(true).stderr(false),
+        )
+        .await
+        .map_err(|e| e.to_string())?;
+    let mut stdin = attached.stdin().ok_or("no stdin".to_string())?;
+    stdin.write_all(commands.as_bytes()).await.map_err(|e| e.to_string())?;
+    drop(stdin);
+    let mut output = String::new();
+    if let Some(mut stdout) = attached.stdout() {
+        stdout.read_to_string(&mut output).await.map_err(|e| e.to_string())?;
+    }
+    attached.join().await.map_err(|e| e.to_string())?;
+    // zkCli.sh does not exit with an error when a command fails, so check what it prints instead.
+    if output.contains("Committed new configuration") {
+        Ok(())
+    } else {
+        Err(output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Default)]
+    struct FakeClient {
+        closed: AtomicBool,
+    }
+
+    impl ZKClient for FakeClient {
+        fn close_session(&self) {
+            self.closed.store(true, Ordering::SeqCst);
+        }
+    }
+
+    fn session_key() -> ZKSessionKey {
+        ZKSessionKey::new("zk", "default", 2181, &None)
+    }
+
+    // Returns the pool with one session for session_key and the client of the session.
+    fn pool_with_session() -> (ZKSessionPool<FakeClient>, Arc<FakeClient>, Arc<AtomicBool>) {
+        let pool = ZKSessionPool::new();
+        let client = Arc::new(FakeClient::default());
+        let expired = Arc::new(AtomicBool::new(false));
+        pool.insert(session_key(), client.clone(), expired.clone());
+        (pool, client, expired)
+    }
+
+    // The evicted session is closed on the blocking thread pool, so wait for it.
+    async fn wait_until_closed(client: &FakeClient) {
+        let closed = async {
+            while !client.closed.load(Ordering::SeqCst) {
+                deps_hack::tokio::task::yield_now().await;
+            }
+        };
+        timeout(Duration::from_secs(5), closed).await.unwrap();
+    }
+
+    #[test]
+    fn test_pool_reuses_session() {
+        let (pool, client, _) = pool_with_session();
+        assert!(Arc::ptr_eq(&pool.get(&session_key()).unwrap(), &client));
+        assert!(Arc::ptr_eq(&pool.get(&session_key()).unwrap(), &client));
+        // A session is not shared with the clients using other credentials
+        let other_key = ZKSessionKey::new("zk", "default", 2181, &Some("zk-auth".to_string()));
+        assert!(pool.get(&other_key).is_none());
+    }
+
+    #[test]
+    fn test_pool_reconnects_after_expiry() {
+        let (pool, _, expired) = pool_with_session();
+        expired.store(true, Ordering::SeqCst);
+        assert!(pool.get(&session_key()).is_none());
+        let new_client = Arc::new(FakeClient::default());
+        pool.insert(session_key(), new_client.clone(), Arc::new(AtomicBool::new(false)));
+        assert!(Arc::ptr_eq(&pool.get(&session_key()).unwrap(), &new_client));
+    }
+
+    #[deps_hack::tokio::test(crate = "deps_hack::tokio")]
+    async fn test_run_zk_call_evicts_session_on_connection_loss() {
+        let (pool, client, _) = pool_with_session();
+        let result = run_zk_call(&pool, &session_key(), Duration::from_secs(5), async {
+            Err::<(), _>(ZKCallError::Zk(ZkError::ConnectionLoss))
+        })
+        .await;
+        assert!(matches!(result, Err(ZKCallError::Zk(ZkError::ConnectionLoss))));
+        assert!(pool.get(&session_key()).is_none());
+        wait_until_closed(&client).await;
+    }
+
+    #[deps_hack::tokio::test(crate = "deps_hack::tokio")]
+    async fn test_run_zk_call_keeps_session_on_other_errors() {
+        let (pool, client, _) = pool_with_session();
+        let result = run_zk_call(&pool, &session_key(), Duration::from_secs(5), async {
+            Err::<(), _>(ZKCallError::Zk(ZkError::NoNode))
+        })
+        .await;
+        assert!(matches!(result, Err(ZKCallError::Zk(ZkError::NoNode))));
+        let result = run_zk_call(&pool, &session_key(), Duration::from_secs(5), async {
+            Ok::<i32, ZKCallError>(1)
+        })
+        .await;
+        assert!(matches!(result, Ok(1)));
+        assert!(Arc::ptr_eq(&pool.get(&session_key()).unwrap(), &client));
+    }
+
+    #[deps_hack::tokio::test(crate = "deps_hack::tokio")]
+    async fn test_run_zk_call_times_out() {
+        let (pool, client, _) = pool_with_session();
+        let result = run_zk_call(
+            &pool,
+            &session_key(),
+            Duration::from_millis(10),
+            deps_hack::futures::future::pending::<Result<(), ZKCallError>>(),
+        )
+        .await;
+        match result {
+            Err(e) => assert!(matches!(
+                e.into_api_error(ZKAPIError::ZKNodeExistsFailed),
+                ZKAPIError::ZKTimeout
+            )),
+            Ok(_) => panic!("the call should time out"),
+        }
+        assert!(pool.get(&session_key()).is_none());
+        wait_until_closed(&client).await;
+    }
+
+    #[test]
+    fn test_error_of() {
+        let set_data_failed = ZKAPIOutput::SetDataResponse(ZKAPISetDataResult { res: Err(ZKAPIError::ZKNodeSetDataFailed) });
+        assert_eq!(ZKAPIShimLayer::error_of(&set_data_failed), Some("Updating the ZK node failed".to_string()));
+        let timeout = ZKAPIOutput::ExistsResponse(ZKAPIExistsResult { res: Err(ZKAPIError::ZKTimeout) });
+        assert_eq!(ZKAPIShimLayer::error_of(&timeout), Some("The ZK cluster did not answer in time".to_string()));
+        let already_exists = ZKAPIOutput::CreateResponse(ZKAPICreateResult { res: Err(ZKAPIError::ZKNodeCreateAlreadyExists) });
+        assert_eq!(ZKAPIShimLayer::error_of(&already_exists), None);
+        let exists = ZKAPIOutput::ExistsResponse(ZKAPIExistsResult { res: Ok(Some(1)) });
+        assert_eq!(ZKAPIShimLayer::error_of(&exists), None);
+    }
+}
@@ -58,12 +58,21 @@ pub type ZKNodeVersion = int;
 
 // ZKState is basically a map from the key (the id of the zookeeper cluster and the node path)
 // to the value, and the stat data associated with the node (i.e., version number).
+// It also records the ensemble of each zookeeper cluster, i.e., the ids of the
+// servers that are voting members in the dynamic configuration stored at /zookeeper/config.
+//
+// The ACLs of the nodes are not modeled: see validate for why the ACL check never fails.
 pub struct ZKState {
     pub data: Map<ZKNodeAddr, (ZKNodeValue, ZKNodeVersion)>,
+    pub ensemble: Map<ZKNodeAddr, Set<int>>,
 }
 
 impl ZKState {
-    pub open spec fn init() -> ZKState { ZKState { data: Map::empty() } }
+    pub open spec fn init() -> ZKState { ZKState { data: Map::empty(), ensemble: Map::empty() } }
+}
+
+pub open spec fn zk_config_path() -> Seq<StringView> {
+    seq!["zookeeper"@, "config"@]
 }
 
 pub struct ZKAPIExistsResultView {
@@ -78,11 +87,19 @@ pub struct ZKAPISetDataResultView {
     pub res: Result<(), ZKAPIError>,
 }
 
+pub struct ZKAPIReconfigResultView {
+    pub res: Result<(), ZKAPIError>,
+}
+
+// The create, set data and reconfig requests end with the name of the secret holding the credentials
+// that the client authenticates with before writing, or None if the cluster does not use authentication.
+// The reconfig request carries the number of servers that should be in the ensemble.
 #[is_variant]
 pub enum ZKAPIInputView {
     ExistsRequest(StringView, StringView, int, Seq<StringView>),
-    CreateRequest(StringView, StringView, int, Seq<StringView>, ZKNodeValue),
-    SetDataRequest(StringView, StringView, int, Seq<StringView>, ZKNodeValue, ZKNodeVersion),
+    CreateRequest(StringView, StringView, int, Seq<StringView>, ZKNodeValue, Option<StringView>),
+    SetDataRequest(StringView, StringView, int, Seq<StringView>, ZKNodeValue, ZKNodeVersion, Option<StringView>),
+    ReconfigRequest(StringView, StringView, int, int, Option<StringView>),
 }
 
 #[is_variant]
@@ -90,6 +107,7 @@ pub enum ZKAPIOutputView {
     ExistsResponse(ZKAPIExistsResultView),
     CreateResponse(ZKAPICreateResultView),
     SetDataResponse(ZKAPISetDataResultView),
+    ReconfigResponse(ZKAPIReconfigResultView),
 }
 
 impl ExternalAPI for ZKAPI {
@@ -104,14 +122,18 @@ impl ExternalAPI for ZKAPI {
                 let (s_prime, res) = handle_exists(name, namespace, port, path, resources, state);
                 (s_prime, ZKAPIOutputView::ExistsResponse(res))
             },
-            ZKAPIInputView::CreateRequest(name, namespace, port, path, data) => {
-                let (s_prime, res) = handle_create(name, namespace, port, path, data, resources, state);
+            ZKAPIInputView::CreateRequest(name, namespace, port, path, data, auth) => {
+                let (s_prime, res) = handle_create(name, namespace, port, path, data, auth, resources, state);
                 (s_prime, ZKAPIOutputView::CreateResponse(res))
             },
-            ZKAPIInputView::SetDataRequest(name, namespace, port, path, data, version) => {
-                let (s_prime, res) = handle_set_data(name, namespace, port, path, data, version, resources, state);
+            ZKAPIInputView::SetDataRequest(name, namespace, port, path, data, version, auth) => {
+                let (s_prime, res) = handle_set_data(name, namespace, port, path, data, version, auth, resources, state);
                 (s_prime, ZKAPIOutputView::SetDataResponse(res))
             },
+            ZKAPIInputView::ReconfigRequest(name, namespace, port, replicas, auth) => {
+                let (s_prime, res) = handle_reconfig(name, namespace, port, replicas, auth, resources, state);
+                (s_prime, ZKAPIOutputView::ReconfigResponse(res))
+            },
         }
     }
 
@@ -163,6 +185,15 @@ pub open spec fn validate_stateful_set(name: StringView, namespace: StringView,
 // such as checking the existence of the service object as well,
 // checking whether the stateful set is really ready,
 // and checking whether the port number is correct.
+//
+// Like the connection to the server, reading the credentials secret is assumed to succeed.
+// A node created with authentication gets an ACL that only lets the creator write it (everyone can still read it).
+// transition_validation keeps the auth section of a zookeeper cluster unchanged, so the controller
+// always authenticates as the identity that created its nodes and the ACL check does not fail.
+// That is why the ACLs are not part of ZKState and the auth of the requests does not affect the transitions.
+// The same holds for reconfig, which additionally requires that identity to be allowed to write /zookeeper/config.
+// The calls are also assumed to finish within the per-call timeout of the exec implementation,
+// so the transition never returns ZKAPIError::ZKTimeout.
 pub open spec fn validate(name: StringView, namespace: StringView, port: int, path: Seq<StringView>, resources: StoredState) -> bool {
     &&& path.len() > 0
     &&& validate_stateful_set(name, namespace, resources)
@@ -190,7 +221,7 @@ pub open spec fn handle_exists(name: StringView, namespace: StringView, port: in
 
 // handle_create models the behavior of the zookeeper server handling the create request.
 // The creation succeeds only when (1) the node does not exist yet and (2) the parent node exists.
-pub open spec fn handle_create(name: StringView, namespace: StringView, port: int, path: Seq<StringView>, data: ZKNodeValue, resources: StoredState, state: ZKState) -> (ZKState, ZKAPICreateResultView) {
+pub open spec fn handle_create(name: StringView, namespace: StringView, port: int, path: Seq<StringView>, data: ZKNodeValue, auth: Option<StringView>, resources: StoredState, state: ZKState) -> (ZKState, ZKAPICreateResultView) {
     let key = ObjectRef { kind: Kind::StatefulSetKind, namespace: namespace, name: name };
     if !validate(name, namespace, port, path, resources) {
         (state, ZKAPICreateResultView{res: Err(ZKAPIError::ZKNodeCreateFailed)})
@@ -200,7 +231,7 @@ pub open spec fn handle_create(name: StringView, namespace: StringView, port: in
             if path.len() > 1 && !state.data.contains_key(addr.parent_addr()) {
                 (state, ZKAPICreateResultView{res: Err(ZKAPIError::ZKNodeCreateFailed)})
             } else {
-                let state_prime = ZKState { data: state.data.insert(addr, (data, 0)) };
+                let state_prime = ZKState { data: state.data.insert(addr, (data, 0)), ..state };
                 (state_prime, ZKAPICreateResultView{res: Ok(())})
             }
         } else {
@@ -211,7 +242,7 @@ pub open spec fn handle_create(name: StringView, namespace: StringView, port: in
 
 // handle_set_data models the behavior of the zookeeper server handling the set data request.
 // To set the data, the node needs to exist and the provided version number must match the current version of the node.
-pub open spec fn handle_set_data(name: StringView, namespace: StringView, port: int, path: Seq<StringView>, data: ZKNodeValue, version: ZKNodeVersion, resources: StoredState, state: ZKState) -> (ZKState, ZKAPISetDataResultView) {
+pub open spec fn handle_set_data(name: StringView, namespace: StringView, port: int, path: Seq<StringView>, data: ZKNodeValue, version: ZKNodeVersion, auth: Option<StringView>, resources: StoredState, state: ZKState) -> (ZKState, ZKAPISetDataResultView) {
     let key = ObjectRef { kind: Kind::StatefulSetKind, namespace: namespace, name: name };
     if !validate(name, namespace, port, path, resources) {
         (state, ZKAPISetDataResultView{res: Err(ZKAPIError::ZKNodeSetDataFailed)})
@@ -224,11 +255,42 @@ pub open spec fn handle_set_data(name: StringView, namespace: StringView, port:
             if current_version != version {
                 (state, ZKAPISetDataResultView{res: Err(ZKAPIError::ZKNodeSetDataFailed)})
             } else {
-                let state_prime = ZKState { data: state.data.insert(addr, (data, current_version + 1)) };
+                let state_prime = ZKState { data: state.data.insert(addr, (data, current_version + 1)), ..state };
                 (state_prime, ZKAPISetDataResultView{res: Ok(())})
             }
         }
     }
 }
 
+// zk_server_is_up tells whether the server with the given id is up and answers "imok" to "ruok",
+// so that it can join the ensemble.
+// Whether a server is up depends on the pods and the processes in them instead of the kubernetes objects,
+// so we leave it as a closed function instead of modeling the pods.
+pub closed spec fn zk_server_is_up(name: StringView, namespace: StringView, id: int) -> bool;
+
+// reconfigured_ensemble is the ensemble after a reconfig to replicas servers:
+// the servers beyond replicas leave the ensemble, and the missing servers that are up join it
+// (the server id is the pod ordinal plus one).
+pub open spec fn reconfigured_ensemble(name: StringView, namespace: StringView, replicas: int, ensemble: Set<int>) -> Set<int> {
+    Set::new(|id: int| 1 <= id <= replicas && (ensemble.contains(id) || zk_server_is_up(name, namespace, id)))
+}
+
+// handle_reconfig models the behavior of the zookeeper server handling the reconfig request.
+// The servers beyond replicas leave the ensemble, but only the missing servers that are up join it,
+// because a configuration with a server that is down might not form a quorum.
+// This is what the exec implementation does: it checks each missing server with "ruok" and leaves
+// the ones that are not up yet to join by themselves when they start (see zookeeperStart.sh),
+// so the reconfig succeeds even if some servers are missing.
+pub open spec fn handle_reconfig(name: StringView, namespace: StringView, port: int, replicas: int, auth: Option<StringView>, resources: StoredState, state: ZKState) -> (ZKState, ZKAPIReconfigResultView) {
+    let key = ObjectRef { kind: Kind::StatefulSetKind, namespace: namespace, name: name };
+    if !validate(name, namespace, port, zk_config_path(), resources) {
+        (state, ZKAPIReconfigResultView{res: Err(ZKAPIError::ZKReconfigFailed)})
+    } else {
+        let addr = ZKNodeAddr::new(name, namespace, resources[key].metadata.uid.get_Some_0(), zk_config_path());
+        let ensemble = if state.ensemble.contains_key(addr) { state.ensemble[addr] } else { Set::empty() };
+        let state_prime = ZKState { ensemble: state.ensemble.insert(addr, reconfigured_ensemble(name, namespace, replicas, ensemble)), ..state };
+        (state_prime, ZKAPIReconfigResultView{res: Ok(())})
+    }
+}
+
 }
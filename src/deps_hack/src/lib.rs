@@ -2,6 +2,7 @@ pub use anyhow;
 pub use base64;
 pub use chrono;
 pub use futures;
+pub use http;
 pub use k8s_openapi;
 pub use kube;
 pub use kube_client;
@@ -15,6 +16,7 @@ pub use serde_json;
 pub use serde_yaml;
 pub use thiserror;
 pub use tokio;
+pub use tower;
 pub use tracing;
 pub use tracing_subscriber;
 pub use zookeeper;
@@ -25,6 +27,8 @@ pub enum Error {
     ShimLayerError(String),
     #[error("ReconcileCoreError")]
     ReconcileCoreError,
+    #[error("Throttled: retry after {0:?}")]
+    Throttled(std::time::Duration),
 }
 
 #[derive(
@@ -42,17 +46,46 @@ pub struct ZookeeperClusterSpec {
     pub resources: Option<k8s_openapi::api::core::v1::ResourceRequirements>,
     pub affinity: Option<k8s_openapi::api::core::v1::Affinity>,
     pub tolerations: Option<Vec<k8s_openapi::api::core::v1::Toleration>>,
+    #[serde(rename = "topologySpreadConstraints")]
+    pub topology_spread_constraints:
+        Option<Vec<k8s_openapi::api::core::v1::TopologySpreadConstraint>>,
+    #[serde(rename = "priorityClassName")]
+    pub priority_class_name: Option<String>,
     #[serde(default, rename = "nodeSelector")]
     pub node_selector: std::collections::BTreeMap<String, String>,
     #[serde(default)]
     pub labels: std::collections::BTreeMap<String, String>,
     #[serde(default)]
     pub annotations: std::collections::BTreeMap<String, String>,
+    pub tls: Option<ZookeeperTLSSpec>,
+    pub auth: Option<ZookeeperAuthSpec>,
+    /// PodDisruptionBudget overrides the budget created for the servers.
+    /// By default, minAvailable is a quorum of the ensemble.
+    #[serde(rename = "podDisruptionBudget")]
+    pub pod_disruption_budget: Option<ZookeeperPodDisruptionBudgetSpec>,
 }
 
 #[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
 pub struct ZookeeperClusterStatus {
     pub ready_replicas: i32,
+    #[serde(default)]
+    pub conditions: Option<Vec<Condition>>,
+}
+
+/// Condition mirrors metav1.Condition, except that lastTransitionTime is optional.
+#[derive(
+    Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize, schemars::JsonSchema,
+)]
+pub struct Condition {
+    #[serde(rename = "type")]
+    pub type_: String,
+    pub status: String,
+    pub reason: Option<String>,
+    pub message: Option<String>,
+    #[serde(rename = "lastTransitionTime")]
+    pub last_transition_time: Option<k8s_openapi::apimachinery::pkg::apis::meta::v1::Time>,
+    #[serde(rename = "observedGeneration")]
+    pub observed_generation: Option<i64>,
 }
 
 #[derive(Debug, Clone, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
@@ -103,6 +136,52 @@ pub struct ZookeeperConfig {
     pub quorum_listen_on_all_ips: bool,
 }
 
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+pub struct ZookeeperTLSSpec {
+    /// Name of a secret in the same namespace holding keystore.jks, truststore.jks and
+    /// the password of both stores in password.
+    #[serde(rename = "secretName")]
+    pub secret_name: String,
+    /// The port serving TLS client connections, next to the plain client port.
+    #[serde(rename = "secureClientPort", default = "default_secure_client_port")]
+    pub secure_client_port: i32,
+    /// When true, the servers also talk to each other over TLS.
+    #[serde(rename = "quorumTLS", default)]
+    pub quorum_tls: bool,
+    /// Whether the plain client port stays open next to the secure client port. It must be true:
+    /// the controller writes its znodes through the plain client port, authenticated with the
+    /// digest credentials of spec.auth, because its zookeeper client library does not support TLS.
+    #[serde(rename = "plainPortEnabled", default = "default_plain_port_enabled")]
+    pub plain_port_enabled: bool,
+}
+
+pub fn default_secure_client_port() -> i32 {
+    2281
+}
+
+pub fn default_plain_port_enabled() -> bool {
+    true
+}
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+pub struct ZookeeperAuthSpec {
+    /// Name of a secret in the same namespace holding the username and password
+    /// that the controller authenticates with (digest scheme) before writing its znodes.
+    #[serde(rename = "secretName")]
+    pub secret_name: String,
+    /// When true, the servers accept SASL clients listed in the jaas.conf key of the same secret.
+    #[serde(default)]
+    pub sasl: bool,
+}
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+pub struct ZookeeperPodDisruptionBudgetSpec {
+    /// Number of servers that must stay available during voluntary disruptions such as node drains.
+    /// Setting it to 0 effectively disables the budget.
+    #[serde(rename = "minAvailable")]
+    pub min_available: i32,
+}
+
 #[derive(Debug, Clone, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
 pub struct ZookeeperPersistence {
     pub enabled: bool,
@@ -112,11 +191,70 @@ pub struct ZookeeperPersistence {
     pub storage_class_name: String,
 }
 
+/// ZookeeperClusterReference points to the ZookeeperCluster in the same namespace.
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+pub struct ZookeeperClusterReference {
+    pub name: String,
+}
+
+#[derive(
+    kube::CustomResource, Debug, Clone, serde::Deserialize, serde::Serialize, schemars::JsonSchema,
+)]
+#[kube(group = "anvil.dev", version = "v1", kind = "ZookeeperBackup")]
+#[kube(shortname = "zkbackup", namespaced)]
+#[kube(status = "ZookeeperBackupStatus")]
+pub struct ZookeeperBackupSpec {
+    #[serde(rename = "zookeeperClusterReference")]
+    pub zookeeper_cluster_reference: ZookeeperClusterReference,
+    /// PersistentVolumeClaim is the claim that stores the backups.
+    /// Each backup is copied to the directory named after the ZookeeperBackup object.
+    #[serde(rename = "persistentVolumeClaim")]
+    pub persistent_volume_claim: String,
+}
+
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+pub struct ZookeeperBackupStatus {
+    #[serde(default)]
+    pub conditions: Option<Vec<Condition>>,
+}
+
+#[derive(
+    kube::CustomResource, Debug, Clone, serde::Deserialize, serde::Serialize, schemars::JsonSchema,
+)]
+#[kube(group = "anvil.dev", version = "v1", kind = "ZookeeperRestore")]
+#[kube(shortname = "zkrestore", namespaced)]
+#[kube(status = "ZookeeperRestoreStatus")]
+pub struct ZookeeperRestoreSpec {
+    /// BackupName is the name of the ZookeeperBackup to restore from.
+    #[serde(rename = "backupName")]
+    #[schemars(length(max = 63), regex(pattern = r"^[a-z0-9]([-a-z0-9]*[a-z0-9])?$"))]
+    pub backup_name: String,
+    /// PersistentVolumeClaim is the claim that stores the backups.
+    #[serde(rename = "persistentVolumeClaim")]
+    pub persistent_volume_claim: String,
+    /// ZookeeperCluster is the new cluster that is created once the data is restored.
+    #[serde(rename = "zookeeperCluster")]
+    pub zookeeper_cluster: ZookeeperClusterTemplate,
+}
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+pub struct ZookeeperClusterTemplate {
+    pub name: String,
+    pub spec: ZookeeperClusterSpec,
+}
+
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+pub struct ZookeeperRestoreStatus {
+    #[serde(default)]
+    pub conditions: Option<Vec<Condition>>,
+}
+
 #[derive(
     kube::CustomResource, Debug, Clone, serde::Deserialize, serde::Serialize, schemars::JsonSchema,
 )]
 #[kube(group = "anvil.dev", version = "v1", kind = "RabbitmqCluster")]
 #[kube(shortname = "rbmq", namespaced)]
+#[kube(status = "RabbitmqClusterStatus")]
 pub struct RabbitmqClusterSpec {
     pub replicas: i32,
     /// Image is the name of the RabbitMQ docker image to use for RabbitMQ nodes in the RabbitmqCluster.
@@ -127,6 +265,11 @@ pub struct RabbitmqClusterSpec {
     pub rabbitmq_config: Option<RabbitmqConfig>,
     pub affinity: Option<k8s_openapi::api::core::v1::Affinity>,
     pub tolerations: Option<Vec<k8s_openapi::api::core::v1::Toleration>>,
+    #[serde(rename = "topologySpreadConstraints")]
+    pub topology_spread_constraints:
+        Option<Vec<k8s_openapi::api::core::v1::TopologySpreadConstraint>>,
+    #[serde(rename = "priorityClassName")]
+    pub priority_class_name: Option<String>,
     #[serde(default)]
     pub labels: std::collections::BTreeMap<String, String>,
     #[serde(default)]
@@ -148,6 +291,32 @@ pub struct RabbitmqClusterSpec {
     #[serde(rename = "persistentVolumeClaimRetentionPolicy")]
     pub persistent_volume_claim_retention_policy:
         Option<k8s_openapi::api::apps::v1::StatefulSetPersistentVolumeClaimRetentionPolicy>,
+    /// AdditionalPlugins is the list of plugins to enable on top of the default ones.
+    /// Changes are applied to running nodes by running rabbitmq-plugins set in each server pod, without a rolling restart.
+    #[serde(rename = "additionalPlugins", default)]
+    pub additional_plugins: Vec<String>,
+    pub tls: Option<RabbitmqTLSSpec>,
+    /// AllowScaleDown lets replicas be decreased. Before the StatefulSet is scaled down,
+    /// the controller checks that no quorum queue would lose its majority and drains the removed nodes.
+    /// It cannot be unset once set.
+    #[serde(rename = "allowScaleDown", default)]
+    pub allow_scale_down: bool,
+    /// ClusterRoleName is the name of a pre-existing ClusterRole to bind the server's service account to,
+    /// instead of the Role created by the controller. It cannot be changed once set.
+    /// The controller must be granted the bind verb on this ClusterRole.
+    #[serde(rename = "clusterRoleName")]
+    pub cluster_role_name: Option<String>,
+    /// PodDisruptionBudget overrides the budget created for the nodes.
+    /// By default, minAvailable is a majority of the cluster, which with a single replica
+    /// blocks every node drain; set minAvailable to 0 to allow draining a single-node cluster.
+    #[serde(rename = "podDisruptionBudget")]
+    pub pod_disruption_budget: Option<RabbitmqPodDisruptionBudgetSpec>,
+}
+
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+pub struct RabbitmqClusterStatus {
+    #[serde(default)]
+    pub conditions: Option<Vec<Condition>>,
 }
 
 pub fn default_pod_management_policy() -> String {
@@ -171,6 +340,27 @@ pub struct RabbitmqConfig {
     pub env_config: Option<String>,
 }
 
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+pub struct RabbitmqTLSSpec {
+    /// Name of a secret in the same namespace holding tls.crt and tls.key.
+    #[serde(rename = "secretName")]
+    pub secret_name: String,
+    /// Name of a secret in the same namespace holding ca.crt, used to verify peers.
+    #[serde(rename = "caSecretName")]
+    pub ca_secret_name: Option<String>,
+    /// When true, only the TLS listeners are opened.
+    #[serde(rename = "disableNonTLSListeners", default)]
+    pub disable_non_tls_listeners: bool,
+}
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+pub struct RabbitmqPodDisruptionBudgetSpec {
+    /// Number of nodes that must stay available during voluntary disruptions such as node drains.
+    /// Setting it to 0 effectively disables the budget.
+    #[serde(rename = "minAvailable")]
+    pub min_available: i32,
+}
+
 pub fn default_storage_class_name() -> String {
     "standard".to_string()
 }
@@ -187,11 +377,94 @@ pub struct RabbitmqClusterPersistenceSpec {
     pub storage: k8s_openapi::apimachinery::pkg::api::resource::Quantity,
 }
 
+/// RabbitmqClusterReference points to the RabbitmqCluster in the same namespace
+/// that hosts the users, vhosts and permissions.
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+pub struct RabbitmqClusterReference {
+    pub name: String,
+}
+
+#[derive(
+    kube::CustomResource, Debug, Clone, serde::Deserialize, serde::Serialize, schemars::JsonSchema,
+)]
+#[kube(group = "anvil.dev", version = "v1", kind = "RabbitmqUser")]
+#[kube(shortname = "rmquser", namespaced)]
+#[kube(status = "RabbitmqUserStatus")]
+pub struct RabbitmqUserSpec {
+    #[serde(rename = "rabbitmqClusterReference")]
+    pub rabbitmq_cluster_reference: RabbitmqClusterReference,
+    /// Tags are the user tags such as "management" or "administrator".
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+pub struct RabbitmqUserStatus {
+    #[serde(default)]
+    pub conditions: Option<Vec<Condition>>,
+}
+
+#[derive(
+    kube::CustomResource, Debug, Clone, serde::Deserialize, serde::Serialize, schemars::JsonSchema,
+)]
+#[kube(group = "anvil.dev", version = "v1", kind = "RabbitmqVhost")]
+#[kube(shortname = "rmqvhost", namespaced)]
+#[kube(status = "RabbitmqVhostStatus")]
+pub struct RabbitmqVhostSpec {
+    /// Name is the name of the vhost in the broker.
+    pub name: String,
+    #[serde(rename = "rabbitmqClusterReference")]
+    pub rabbitmq_cluster_reference: RabbitmqClusterReference,
+    #[serde(default)]
+    pub tracing: bool,
+}
+
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+pub struct RabbitmqVhostStatus {
+    #[serde(default)]
+    pub conditions: Option<Vec<Condition>>,
+}
+
+#[derive(
+    kube::CustomResource, Debug, Clone, serde::Deserialize, serde::Serialize, schemars::JsonSchema,
+)]
+#[kube(group = "anvil.dev", version = "v1", kind = "RabbitmqPermission")]
+#[kube(shortname = "rmqperm", namespaced)]
+#[kube(status = "RabbitmqPermissionStatus")]
+pub struct RabbitmqPermissionSpec {
+    /// User is the name of the user in the broker, which is the name of its RabbitmqUser object.
+    pub user: String,
+    /// Vhost is the name of the vhost in the broker.
+    pub vhost: String,
+    pub permissions: RabbitmqPermissionRules,
+    #[serde(rename = "rabbitmqClusterReference")]
+    pub rabbitmq_cluster_reference: RabbitmqClusterReference,
+}
+
+/// RabbitmqPermissionRules holds the regular expressions matching the resources
+/// the user can configure, write to and read from.
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+pub struct RabbitmqPermissionRules {
+    #[serde(default)]
+    pub configure: String,
+    #[serde(default)]
+    pub write: String,
+    #[serde(default)]
+    pub read: String,
+}
+
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+pub struct RabbitmqPermissionStatus {
+    #[serde(default)]
+    pub conditions: Option<Vec<Condition>>,
+}
+
 #[derive(
     kube::CustomResource, Debug, Clone, serde::Deserialize, serde::Serialize, schemars::JsonSchema,
 )]
 #[kube(group = "anvil.dev", version = "v1", kind = "FluentBit")]
 #[kube(shortname = "fb", namespaced)]
+#[kube(status = "FluentBitStatus")]
 pub struct FluentBitSpec {
     #[serde(rename = "fluentBitConfigName")]
     pub fluentbit_config_name: String,
@@ -258,11 +531,26 @@ pub struct FluentBitSpec {
     pub ports: Option<Vec<k8s_openapi::api::core::v1::ContainerPort>>,
 }
 
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+pub struct FluentBitStatus {
+    #[serde(rename = "desiredNumberScheduled", default)]
+    pub desired_number_scheduled: i32,
+    #[serde(rename = "numberReady", default)]
+    pub number_ready: i32,
+    #[serde(rename = "updatedNumberScheduled", default)]
+    pub updated_number_scheduled: i32,
+    #[serde(rename = "configHash")]
+    pub config_hash: Option<String>,
+    #[serde(default)]
+    pub conditions: Option<Vec<Condition>>,
+}
+
 #[derive(
     kube::CustomResource, Debug, Clone, serde::Deserialize, serde::Serialize, schemars::JsonSchema,
 )]
 #[kube(group = "anvil.dev", version = "v1", kind = "FluentBitConfig")]
 #[kube(shortname = "fbc", namespaced)]
+#[kube(status = "FluentBitConfigStatus")]
 pub struct FluentBitConfigSpec {
     #[serde(rename = "fluentBitConfig")]
     pub fluentbit_config: String,
@@ -270,28 +558,48 @@ pub struct FluentBitConfigSpec {
     pub parsers_config: String,
 }
 
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+pub struct FluentBitConfigStatus {
+    #[serde(default)]
+    pub conditions: Option<Vec<Condition>>,
+}
+
 #[derive(
     kube::CustomResource, Debug, Clone, serde::Deserialize, serde::Serialize, schemars::JsonSchema,
 )]
 #[kube(group = "anvil.dev", version = "v1", kind = "VReplicaSet")]
 #[kube(shortname = "vrs", namespaced)]
+#[kube(status = "VReplicaSetStatus")]
 pub struct VReplicaSetSpec {
     pub replicas: Option<i32>,
     pub selector: k8s_openapi::apimachinery::pkg::apis::meta::v1::LabelSelector,
     pub template: Option<k8s_openapi::api::core::v1::PodTemplateSpec>,
 }
 
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+pub struct VReplicaSetStatus {
+    #[serde(default)]
+    pub conditions: Option<Vec<Condition>>,
+}
+
 #[derive(
     kube::CustomResource, Debug, Clone, serde::Deserialize, serde::Serialize, schemars::JsonSchema,
 )]
 #[kube(group = "anvil.dev", version = "v1", kind = "VDeployment")]
 #[kube(shortname = "vrs", namespaced)]
+#[kube(status = "VDeploymentStatus")]
 pub struct VDeploymentSpec {
     pub replicas: Option<i32>,
     pub selector: k8s_openapi::apimachinery::pkg::apis::meta::v1::LabelSelector,
     pub template: Option<k8s_openapi::api::core::v1::PodTemplateSpec>,
 }
 
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+pub struct VDeploymentStatus {
+    #[serde(default)]
+    pub conditions: Option<Vec<Condition>>,
+}
+
 #[derive(
     kube::CustomResource, Debug, Clone, serde::Deserialize, serde::Serialize, schemars::JsonSchema,
 )]
@@ -69,16 +69,24 @@ fn valid_object(obj: &DynamicObject) -> (ret: bool)
     ensures ret == model::valid_object::<K::V>(obj@)
 {
     match obj.kind() {
+        KindExec::ClusterRoleKind => ClusterRole::unmarshal(obj.clone()).unwrap().state_validation(),
+        KindExec::ClusterRoleBindingKind => ClusterRoleBinding::unmarshal(obj.clone()).unwrap().state_validation(),
         KindExec::ConfigMapKind => ConfigMap::unmarshal(obj.clone()).unwrap().state_validation(),
         KindExec::DaemonSetKind => DaemonSet::unmarshal(obj.clone()).unwrap().state_validation(),
+        KindExec::EventKind => Event::unmarshal(obj.clone()).unwrap().state_validation(),
+        KindExec::JobKind => Job::unmarshal(obj.clone()).unwrap().state_validation(),
+        KindExec::NamespaceKind => Namespace::unmarshal(obj.clone()).unwrap().state_validation(),
+        KindExec::PersistentVolumeKind => PersistentVolume::unmarshal(obj.clone()).unwrap().state_validation(),
         KindExec::PersistentVolumeClaimKind => PersistentVolumeClaim::unmarshal(obj.clone()).unwrap().state_validation(),
         KindExec::PodKind => Pod::unmarshal(obj.clone()).unwrap().state_validation(),
+        KindExec::PodDisruptionBudgetKind => PodDisruptionBudget::unmarshal(obj.clone()).unwrap().state_validation(),
         KindExec::RoleBindingKind => RoleBinding::unmarshal(obj.clone()).unwrap().state_validation(),
         KindExec::RoleKind => Role::unmarshal(obj.clone()).unwrap().state_validation(),
         KindExec::SecretKind => Secret::unmarshal(obj.clone()).unwrap().state_validation(),
         KindExec::ServiceKind => Service::unmarshal(obj.clone()).unwrap().state_validation(),
         KindExec::StatefulSetKind => StatefulSet::unmarshal(obj.clone()).unwrap().state_validation(),
         KindExec::ServiceAccountKind => ServiceAccount::unmarshal(obj.clone()).unwrap().state_validation(),
+        KindExec::StorageClassKind => StorageClass::unmarshal(obj.clone()).unwrap().state_validation(),
         KindExec::CustomResourceKind(_) => {
             proof {
                 K::V::unmarshal_result_determined_by_unmarshal_spec_and_status();
@@ -113,16 +121,24 @@ fn valid_transition(obj: &DynamicObject, old_obj: &DynamicObject) -> (ret: bool)
     ensures ret == model::valid_transition::<K::V>(obj@, old_obj@)
 {
     match obj.kind() {
+        KindExec::ClusterRoleKind => ClusterRole::unmarshal(obj.clone()).unwrap().transition_validation(&ClusterRole::unmarshal(old_obj.clone()).unwrap()),
+        KindExec::ClusterRoleBindingKind => ClusterRoleBinding::unmarshal(obj.clone()).unwrap().transition_validation(&ClusterRoleBinding::unmarshal(old_obj.clone()).unwrap()),
         KindExec::ConfigMapKind => ConfigMap::unmarshal(obj.clone()).unwrap().transition_validation(&ConfigMap::unmarshal(old_obj.clone()).unwrap()),
         KindExec::DaemonSetKind => DaemonSet::unmarshal(obj.clone()).unwrap().transition_validation(&DaemonSet::unmarshal(old_obj.clone()).unwrap()),
+        KindExec::EventKind => Event::unmarshal(obj.clone()).unwrap().transition_validation(&Event::unmarshal(old_obj.clone()).unwrap()),
+        KindExec::JobKind => Job::unmarshal(obj.clone()).unwrap().transition_validation(&Job::unmarshal(old_obj.clone()).unwrap()),
+        KindExec::NamespaceKind => Namespace::unmarshal(obj.clone()).unwrap().transition_validation(&Namespace::unmarshal(old_obj.clone()).unwrap()),
+        KindExec::PersistentVolumeKind => PersistentVolume::unmarshal(obj.clone()).unwrap().transition_validation(&PersistentVolume::unmarshal(old_obj.clone()).unwrap()),
         KindExec::PersistentVolumeClaimKind => PersistentVolumeClaim::unmarshal(obj.clone()).unwrap().transition_validation(&PersistentVolumeClaim::unmarshal(old_obj.clone()).unwrap()),
         KindExec::PodKind => Pod::unmarshal(obj.clone()).unwrap().transition_validation(&Pod::unmarshal(old_obj.clone()).unwrap()),
+        KindExec::PodDisruptionBudgetKind => PodDisruptionBudget::unmarshal(obj.clone()).unwrap().transition_validation(&PodDisruptionBudget::unmarshal(old_obj.clone()).unwrap()),
         KindExec::RoleBindingKind => RoleBinding::unmarshal(obj.clone()).unwrap().transition_validation(&RoleBinding::unmarshal(old_obj.clone()).unwrap()),
         KindExec::RoleKind => Role::unmarshal(obj.clone()).unwrap().transition_validation(&Role::unmarshal(old_obj.clone()).unwrap()),
         KindExec::SecretKind => Secret::unmarshal(obj.clone()).unwrap().transition_validation(&Secret::unmarshal(old_obj.clone()).unwrap()),
         KindExec::ServiceKind => Service::unmarshal(obj.clone()).unwrap().transition_validation(&Service::unmarshal(old_obj.clone()).unwrap()),
         KindExec::StatefulSetKind => StatefulSet::unmarshal(obj.clone()).unwrap().transition_validation(&StatefulSet::unmarshal(old_obj.clone()).unwrap()),
         KindExec::ServiceAccountKind => ServiceAccount::unmarshal(obj.clone()).unwrap().transition_validation(&ServiceAccount::unmarshal(old_obj.clone()).unwrap()),
+        KindExec::StorageClassKind => StorageClass::unmarshal(obj.clone()).unwrap().transition_validation(&StorageClass::unmarshal(old_obj.clone()).unwrap()),
         KindExec::CustomResourceKind(_) => {
             proof {
                 K::V::unmarshal_result_determined_by_unmarshal_spec_and_status();
@@ -150,6 +166,15 @@ fn object_transition_validity_check(obj: &DynamicObject, old_obj: &DynamicObject
     }
 }
 
+fn kind_is_namespaced(kind: &KindExec) -> (ret: bool)
+    ensures ret == model::kind_is_namespaced::<K::V>(kind@)
+{
+    match kind {
+        KindExec::CustomResourceKind(_) => K::is_namespaced(),
+        _ => kind.is_namespaced(),
+    }
+}
+
 pub fn handle_get_request(req: &KubeGetRequest, s: &ApiServerState) -> (ret: KubeGetResponse)
     ensures ret@ == model::handle_get_request(req@, s@)
 {
@@ -172,6 +197,8 @@ fn create_request_admission_check(req: &KubeCreateRequest, s: &ApiServerState) -
         Some(APIError::Invalid)
     } else if req.obj.metadata().namespace().is_some() && !req.namespace.eq(&req.obj.metadata().namespace().unwrap()) {
         Some(APIError::BadRequest)
+    } else if !Self::kind_is_namespaced(&req.obj.kind()) && !req.namespace.eq(&"".to_string()) {
+        Some(APIError::BadRequest)
     } else if !Self::unmarshallable_object(&req.obj) {
         Some(APIError::BadRequest)
     } else if req.obj.metadata().name().is_some() && s.resources.contains_key(&KubeObjectRef {
@@ -230,6 +257,7 @@ pub fn handle_create_request(req: &KubeCreateRequest, s: &mut ApiServerState) ->
         created_obj.set_resource_version(s.resource_version_counter);
         created_obj.set_uid(s.uid_counter);
         created_obj.unset_deletion_timestamp();
+        created_obj.merge_string_data();
         created_obj.set_default_status::<K::V>();
         let object_check_error = Self::created_object_validity_check(&created_obj);
         if s.resources.contains_key(&created_obj.object_ref()) {
@@ -331,6 +359,7 @@ fn updated_object(req: &KubeUpdateRequest, old_obj: &DynamicObject) -> (ret: Dyn
 {
     let mut updated_obj = req.obj.clone();
     updated_obj.set_namespace(req.namespace.clone());
+    updated_obj.merge_string_data();
     updated_obj.set_resource_version_from(old_obj);
     updated_obj.set_uid_from(old_obj);
     updated_obj.set_deletion_timestamp_from(old_obj);
@@ -68,16 +68,24 @@ impl ApiResource {
         ensures kind@ == self@.kind,
     {
         match self.as_kube_ref().kind.as_str() {
+            "ClusterRole" => KindExec::ClusterRoleKind,
+            "ClusterRoleBinding" => KindExec::ClusterRoleBindingKind,
             "ConfigMap" => KindExec::ConfigMapKind,
             "DaemonSet" => KindExec::DaemonSetKind,
+            "Event" => KindExec::EventKind,
+            "Job" => KindExec::JobKind,
+            "Namespace" => KindExec::NamespaceKind,
+            "PersistentVolume" => KindExec::PersistentVolumeKind,
             "PersistentVolumeClaim" => KindExec::PersistentVolumeClaimKind,
             "Pod" => KindExec::PodKind,
+            "PodDisruptionBudget" => KindExec::PodDisruptionBudgetKind,
             "Role" => KindExec::RoleKind,
             "RoleBinding" => KindExec::RoleBindingKind,
             "StatefulSet" => KindExec::StatefulSetKind,
             "Service" => KindExec::ServiceKind,
             "ServiceAccount" => KindExec::ServiceAccountKind,
             "Secret" => KindExec::SecretKind,
+            "StorageClass" => KindExec::StorageClassKind,
             _ => panic!(), // We assume the DynamicObject won't be a custom object
         }
     }
@@ -161,16 +169,24 @@ impl DynamicObject {
             panic!();
         }
         match self.as_kube_ref().types.as_ref().unwrap().kind.as_str() {
+            "ClusterRole" => KindExec::ClusterRoleKind,
+            "ClusterRoleBinding" => KindExec::ClusterRoleBindingKind,
             "ConfigMap" => KindExec::ConfigMapKind,
             "DaemonSet" => KindExec::DaemonSetKind,
+            "Event" => KindExec::EventKind,
+            "Job" => KindExec::JobKind,
+            "Namespace" => KindExec::NamespaceKind,
+            "PersistentVolume" => KindExec::PersistentVolumeKind,
             "PersistentVolumeClaim" => KindExec::PersistentVolumeClaimKind,
             "Pod" => KindExec::PodKind,
+            "PodDisruptionBudget" => KindExec::PodDisruptionBudgetKind,
             "Role" => KindExec::RoleKind,
             "RoleBinding" => KindExec::RoleBindingKind,
             "StatefulSet" => KindExec::StatefulSetKind,
             "Service" => KindExec::ServiceKind,
             "ServiceAccount" => KindExec::ServiceAccountKind,
             "Secret" => KindExec::SecretKind,
+            "StorageClass" => KindExec::StorageClassKind,
             _ => panic!(), // We assume the DynamicObject won't be a custom object
         }
     }
@@ -292,6 +308,25 @@ impl DynamicObject {
         ensures self@ == old(self)@.set_status(other@.status)
     {}
 
+    #[verifier(external_body)]
+    pub fn merge_string_data(&mut self)
+        ensures self@ == old(self)@.set_spec(model::merged_spec(old(self)@.kind, old(self)@.spec)),
+    {
+        if self.as_kube_ref().types.as_ref().map_or(false, |t| t.kind == "Secret") {
+            let data = &mut self.as_kube_mut_ref().data;
+            if let Some(deps_hack::serde_json::Value::Object(string_data)) = data.as_object_mut().and_then(|o| o.remove("stringData")) {
+                if !data["data"].is_object() {
+                    data["data"] = deps_hack::serde_json::json!({});
+                }
+                for (key, value) in string_data {
+                    if let Some(v) = value.as_str() {
+                        data["data"][key] = deps_hack::serde_json::Value::String(deps_hack::base64::encode(v));
+                    }
+                }
+            }
+        }
+    }
+
     #[verifier(external_body)]
     pub fn set_default_status<K: CustomResourceView>(&mut self)
         ensures
@@ -329,6 +364,57 @@ impl DaemonSet {
     }
 }
 
+impl Event {
+    pub fn state_validation(&self) -> (ret: bool)
+        ensures ret == self@.state_validation()
+    {
+        if self.type_().is_some() {
+            let type_ = self.type_().unwrap();
+            type_.eq(&"Normal".to_string()) || type_.eq(&"Warning".to_string())
+        } else {
+            true
+        }
+    }
+
+    pub fn transition_validation(&self, old_obj: &Event) -> (ret: bool)
+        ensures ret == self@.transition_validation(old_obj@)
+    {
+        self.involved_object().eq(&old_obj.involved_object())
+    }
+}
+
+impl Job {
+    pub fn state_validation(&self) -> (ret: bool)
+        ensures ret == self@.state_validation()
+    {
+        if self.spec().is_some() {
+            let backoff_limit = self.spec().unwrap().backoff_limit();
+            backoff_limit.is_none() || backoff_limit.unwrap() >= 0
+        } else {
+            false
+        }
+    }
+
+    pub fn transition_validation(&self, old_obj: &Job) -> (ret: bool)
+        requires
+            self@.state_validation(),
+            old_obj@.state_validation(),
+        ensures ret == self@.transition_validation(old_obj@)
+    {
+        self.spec().unwrap().template().eq(&old_obj.spec().unwrap().template())
+    }
+}
+
+impl Namespace {
+    pub fn state_validation(&self) -> (ret: bool)
+        ensures ret == self@.state_validation()
+    { true }
+
+    pub fn transition_validation(&self, old_obj: &Namespace) -> (ret: bool)
+        ensures ret == self@.transition_validation(old_obj@)
+    { true }
+}
+
 impl Pod {
     pub fn state_validation(&self) -> (ret: bool)
         ensures ret == self@.state_validation()
@@ -339,6 +425,26 @@ impl Pod {
     { true }
 }
 
+impl PodDisruptionBudget {
+    pub fn state_validation(&self) -> (ret: bool)
+        ensures ret == self@.state_validation()
+    {
+        if self.spec().is_some() {
+            let min_available = self.spec().unwrap().min_available();
+            let max_unavailable = self.spec().unwrap().max_unavailable();
+            !(min_available.is_some() && max_unavailable.is_some())
+            && (min_available.is_none() || min_available.unwrap() >= 0)
+            && (max_unavailable.is_none() || max_unavailable.unwrap() >= 0)
+        } else {
+            false
+        }
+    }
+
+    pub fn transition_validation(&self, old_obj: &PodDisruptionBudget) -> (ret: bool)
+        ensures ret == self@.transition_validation(old_obj@)
+    { true }
+}
+
 impl PersistentVolumeClaim {
     pub fn state_validation(&self) -> (ret: bool)
         ensures ret == self@.state_validation()
@@ -349,6 +455,16 @@ impl PersistentVolumeClaim {
     { true }
 }
 
+impl PersistentVolume {
+    pub fn state_validation(&self) -> (ret: bool)
+        ensures ret == self@.state_validation()
+    { self.spec().is_some() }
+
+    pub fn transition_validation(&self, old_obj: &PersistentVolume) -> (ret: bool)
+        ensures ret == self@.transition_validation(old_obj@)
+    { true }
+}
+
 impl PolicyRule {
     pub fn state_validation(&self) -> (ret: bool)
         ensures ret == self@.state_validation()
@@ -388,6 +504,44 @@ impl Role {
     { true }
 }
 
+impl Subject {
+    pub fn state_validation(&self, is_namespaced: bool) -> (ret: bool)
+        ensures ret == self@.state_validation(is_namespaced)
+    {
+        if self.kind().eq(&"ServiceAccount".to_string()) {
+            (self.api_group().is_none() || self.api_group().unwrap().eq(&"".to_string()))
+            && (is_namespaced || (self.namespace().is_some() && self.namespace().unwrap().unicode_len() > 0))
+        } else if self.kind().eq(&"User".to_string()) || self.kind().eq(&"Group".to_string()) {
+            self.api_group().is_some() && self.api_group().unwrap().eq(&"rbac.authorization.k8s.io".to_string())
+        } else {
+            false
+        }
+    }
+}
+
+fn subjects_state_validation(subjects: Option<Vec<Subject>>, is_namespaced: bool) -> (ret: bool)
+    ensures
+        ret == (subjects.is_Some()
+            ==> forall |i| 0 <= i < subjects.get_Some_0().len() ==> #[trigger] subjects.get_Some_0()@.map_values(|s: Subject| s@)[i].state_validation(is_namespaced)),
+{
+    if subjects.is_some() {
+        let subjects = subjects.unwrap();
+        let mut all_valid = true;
+        let mut i = 0;
+        while i < subjects.len()
+            invariant
+                all_valid == (forall |j| #![trigger subjects[j]] 0 <= j < i ==> subjects@.map_values(|s: Subject| s@)[j].state_validation(is_namespaced)),
+                i <= subjects.len(),
+        {
+            all_valid = all_valid && subjects[i].state_validation(is_namespaced);
+            i += 1;
+        }
+        all_valid
+    } else {
+        true
+    }
+}
+
 impl RoleBinding {
     pub fn state_validation(&self) -> (ret: bool)
         ensures ret == self@.state_validation()
@@ -395,6 +549,7 @@ impl RoleBinding {
         self.role_ref().api_group().eq(&"rbac.authorization.k8s.io".to_string())
         && (self.role_ref().kind().eq(&"Role".to_string())
             || self.role_ref().kind().eq(&"ClusterRole".to_string()))
+        && subjects_state_validation(self.subjects(), true)
     }
 
     pub fn transition_validation(&self, old_obj: &RoleBinding) -> (ret: bool)
@@ -404,6 +559,49 @@ impl RoleBinding {
     }
 }
 
+impl ClusterRole {
+    pub fn state_validation(&self) -> (ret: bool)
+        ensures ret == self@.state_validation()
+    {
+        if self.rules().is_some() {
+            let policy_rules = self.rules().unwrap();
+            let mut all_valid = true;
+            let mut i = 0;
+            while i < policy_rules.len()
+                invariant
+                    all_valid == (forall |j| #![trigger policy_rules[j]] 0 <= j < i ==> policy_rules@.map_values(|policy_rule: PolicyRule| policy_rule@)[j].state_validation()),
+                    i <= policy_rules.len(),
+            {
+                all_valid = all_valid && policy_rules[i].state_validation();
+                i += 1;
+            }
+            all_valid
+        } else {
+            true
+        }
+    }
+
+    pub fn transition_validation(&self, old_obj: &ClusterRole) -> (ret: bool)
+        ensures ret == self@.transition_validation(old_obj@)
+    { true }
+}
+
+impl ClusterRoleBinding {
+    pub fn state_validation(&self) -> (ret: bool)
+        ensures ret == self@.state_validation()
+    {
+        self.role_ref().api_group().eq(&"rbac.authorization.k8s.io".to_string())
+        && self.role_ref().kind().eq(&"ClusterRole".to_string())
+        && subjects_state_validation(self.subjects(), false)
+    }
+
+    pub fn transition_validation(&self, old_obj: &ClusterRoleBinding) -> (ret: bool)
+        ensures ret == self@.transition_validation(old_obj@)
+    {
+        self.role_ref().eq(&old_obj.role_ref())
+    }
+}
+
 impl Secret {
     pub fn state_validation(&self) -> (ret: bool)
         ensures ret == self@.state_validation()
@@ -435,6 +633,23 @@ impl ServiceAccount {
     { true }
 }
 
+impl StorageClass {
+    pub fn state_validation(&self) -> (ret: bool)
+        ensures ret == self@.state_validation()
+    { self.provisioner().unicode_len() > 0 }
+
+    pub fn transition_validation(&self, old_obj: &StorageClass) -> (ret: bool)
+        ensures ret == self@.transition_validation(old_obj@)
+    {
+        self.provisioner().eq(&old_obj.provisioner())
+        && match (self.parameters(), old_obj.parameters()) {
+            (Some(parameters), Some(old_parameters)) => parameters.eq(&old_parameters),
+            (None, None) => true,
+            _ => false,
+        }
+    }
+}
+
 impl StatefulSet {
     pub fn state_validation(&self) -> (ret: bool)
         ensures ret == self@.state_validation()
@@ -510,6 +725,9 @@ where Self::V: CustomResourceView, Self: std::marker::Sized
 
     fn transition_validation(&self, old_obj: &Self) -> (ret: bool)
         ensures ret == self@.transition_validation(old_obj@);
+
+    fn is_namespaced() -> (ret: bool)
+        ensures ret == Self::V::is_namespaced();
 }
 
 // SimpleCRView and SimpleCR are types only used for instantiating the executable API server model,
@@ -613,6 +831,8 @@ impl ResourceView for SimpleCRView {
 impl CustomResourceView for SimpleCRView {
     proof fn kind_is_custom_resource() {}
 
+    open spec fn is_namespaced() -> bool { true }
+
     open spec fn spec_status_validation(obj_spec: Self::Spec, obj_status: Self::Status) -> bool { true }
 
     proof fn validation_result_determined_by_spec_and_status()
@@ -650,6 +870,12 @@ impl CustomResource for SimpleCR {
     {
         true
     }
+
+    fn is_namespaced() -> (ret: bool)
+        ensures ret == SimpleCRView::is_namespaced()
+    {
+        true
+    }
 }
 
 #[verifier(external_body)]
@@ -1,6 +1,7 @@
 // Copyright 2022 VMware, Inc.
 // SPDX-License-Identifier: MIT
 use crate::external_api::spec::EmptyTypeView;
+use deps_hack::futures::future::BoxFuture;
 use vstd::{prelude::*, view::*};
 
 verus! {
@@ -38,3 +39,45 @@ impl View for EmptyType {
 }
 
 }
+
+// The controller runtime calls the external api from the async reconcile loop.
+// A shim layer that talks to a slow or unreachable external system should implement call_external_api_async
+// natively (e.g., with timeouts), so that it does not hold a runtime worker while waiting.
+// The spec of the async call is the same as call_external_api: the output is what transition returns.
+pub trait AsyncExternalAPIShimLayer: ExternalAPIShimLayer {
+    fn call_external_api_async(input: Self::Input) -> BoxFuture<'static, Self::Output>;
+
+    // error_of describes the failure carried by the output of a call, if any,
+    // so that the controller runtime can record it as a Warning Event.
+    fn error_of(_output: &Self::Output) -> Option<String> {
+        None
+    }
+}
+
+// call_external_api_blocking runs the synchronous call_external_api on the blocking thread pool,
+// for the shim layers that do not need a native async implementation.
+pub fn call_external_api_blocking<T>(input: T::Input) -> BoxFuture<'static, T::Output>
+where
+    T: ExternalAPIShimLayer,
+    T::Input: Send + 'static,
+    T::Output: Send + 'static,
+{
+    Box::pin(async move {
+        deps_hack::tokio::task::spawn_blocking(move || T::call_external_api(input))
+            .await
+            .expect("external api call panicked")
+    })
+}
+
+// block_on runs an async call (e.g., with the kubernetes client) from the blocking thread that call_external_api runs on.
+pub fn block_on<F: std::future::Future>(future: F) -> F::Output {
+    deps_hack::tokio::task::block_in_place(|| {
+        deps_hack::tokio::runtime::Handle::current().block_on(future)
+    })
+}
+
+impl AsyncExternalAPIShimLayer for EmptyAPIShimLayer {
+    fn call_external_api_async(input: EmptyType) -> BoxFuture<'static, EmptyType> {
+        Box::pin(async move { Self::call_external_api(input) })
+    }
+}
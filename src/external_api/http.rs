@@ -0,0 +1,187 @@
+// Copyright 2022 VMware, Inc.
+// SPDX-License-Identifier: MIT
+//! A minimal HTTP/1.1 client for the external API shims that talk to management endpoints
+//! (e.g., the RabbitMQ management API and the fluent-bit HTTP server). It sends one request per connection and reads the whole response.
+use core::time::Duration;
+use deps_hack::k8s_openapi::api::core::v1::Secret;
+use deps_hack::kube::{Api, Client};
+use std::io::{Read, Write};
+use std::net::TcpStream;
+
+// http_request sends one request with basic authentication and returns the status code and the body of the response.
+pub fn http_request(
+    method: &str,
+    addr: &str,
+    path: &str,
+    credentials: (&str, &str),
+    body: &str,
+) -> std::io::Result<(u16, String)> {
+    let (username, password) = credentials;
+    let credentials = deps_hack::base64::encode(format!("{}:{}", username, password));
+    send_http_request(
+        method,
+        addr,
+        path,
+        &format!("Authorization: Basic {}\r\n", credentials),
+        body,
+    )
+}
+
+// http_request_without_auth is http_request for endpoints that do not authenticate the caller,
+// such as the HTTP server of fluent-bit.
+pub fn http_request_without_auth(
+    method: &str,
+    addr: &str,
+    path: &str,
+    body: &str,
+) -> std::io::Result<(u16, String)> {
+    send_http_request(method, addr, path, "", body)
+}
+
+fn send_http_request(
+    method: &str,
+    addr: &str,
+    path: &str,
+    auth_header: &str,
+    body: &str,
+) -> std::io::Result<(u16, String)> {
+    let mut stream = TcpStream::connect(addr)?;
+    stream.set_read_timeout(Some(Duration::from_secs(10)))?;
+    stream.set_write_timeout(Some(Duration::from_secs(10)))?;
+    let request = format!(
+        "{} {} HTTP/1.1\r\nHost: {}\r\n{}Content-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        method,
+        path,
+        addr,
+        auth_header,
+        body.len(),
+        body
+    );
+    stream.write_all(request.as_bytes())?;
+    let mut response = String::new();
+    stream.read_to_string(&mut response)?;
+    parse_http_response(&response).ok_or(std::io::Error::new(
+        std::io::ErrorKind::InvalidData,
+        "malformed HTTP response",
+    ))
+}
+
+// get_basic_auth_credentials reads the credentials for http_request from the "username" and "password" of the secret,
+// so the shims use whatever credentials the managed cluster was set up with.
+pub async fn get_basic_auth_credentials(
+    secret_name: &str,
+    namespace: &str,
+) -> Result<(String, String), String> {
+    let client = Client::try_default().await.map_err(|e| e.to_string())?;
+    let secret = Api::<Secret>::namespaced(client, namespace)
+        .get(secret_name)
+        .await
+        .map_err(|e| e.to_string())?;
+    basic_auth_credentials(&secret).ok_or(format!("secret {} has no credentials", secret_name))
+}
+
+pub fn basic_auth_credentials(secret: &Secret) -> Option<(String, String)> {
+    let data = secret.data.as_ref()?;
+    let field = |key: &str| {
+        data.get(key)
+            .map(|value| String::from_utf8_lossy(&value.0).to_string())
+    };
+    Some((field("username")?, field("password")?))
+}
+
+pub fn is_success(status: u16) -> bool {
+    (200..300).contains(&status)
+}
+
+// percent_encode escapes a path segment, so names such as the default vhost "/" stay in one segment.
+pub fn percent_encode(segment: &str) -> String {
+    segment
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                (b as char).to_string()
+            }
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
+
+fn parse_http_response(response: &str) -> Option<(u16, String)> {
+    let (head, body) = response.split_once("\r\n\r\n").unwrap_or((response, ""));
+    let mut lines = head.lines();
+    // The status line looks like "HTTP/1.1 204 No Content"
+    let status = lines
+        .next()?
+        .split_whitespace()
+        .nth(1)?
+        .parse::<u16>()
+        .ok()?;
+    let chunked = lines.any(|line| {
+        let line = line.to_ascii_lowercase();
+        line.starts_with("transfer-encoding:") && line.contains("chunked")
+    });
+    if chunked {
+        Some((status, decode_chunked(body)?))
+    } else {
+        Some((status, body.to_string()))
+    }
+}
+
+// decode_chunked joins the chunks of a response sent with "Transfer-Encoding: chunked".
+fn decode_chunked(mut body: &str) -> Option<String> {
+    let mut decoded = String::new();
+    loop {
+        let (size_line, rest) = body.split_once("\r\n")?;
+        let size = usize::from_str_radix(size_line.split(';').next()?.trim(), 16).ok()?;
+        if size == 0 {
+            return Some(decoded);
+        }
+        decoded.push_str(rest.get(..size)?);
+        body = rest.get(size..)?.strip_prefix("\r\n")?;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_http_response_chunked() {
+        let response =
+            "HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n4\r\n[{}]\r\n3\r\n,{}\r\n0\r\n\r\n";
+        assert_eq!(
+            parse_http_response(response),
+            Some((200, "[{}],{}".to_string()))
+        );
+        assert_eq!(parse_http_response("garbage"), None);
+    }
+
+    #[test]
+    fn test_basic_auth_credentials() {
+        use deps_hack::k8s_openapi::ByteString;
+        let mut secret = Secret::default();
+        assert_eq!(basic_auth_credentials(&secret), None);
+        secret.data = Some(
+            vec![("username".to_string(), ByteString(b"admin".to_vec()))]
+                .into_iter()
+                .collect(),
+        );
+        assert_eq!(basic_auth_credentials(&secret), None);
+        secret
+            .data
+            .as_mut()
+            .unwrap()
+            .insert("password".to_string(), ByteString(b"secret".to_vec()));
+        assert_eq!(
+            basic_auth_credentials(&secret),
+            Some(("admin".to_string(), "secret".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_percent_encode() {
+        assert_eq!(percent_encode("/"), "%2F");
+        assert_eq!(percent_encode("my-vhost_1"), "my-vhost_1");
+        assert_eq!(percent_encode("rabbit@node 0"), "rabbit%40node%200");
+    }
+}
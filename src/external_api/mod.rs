@@ -1,4 +1,5 @@
 // Copyright 2022 VMware, Inc.
 // SPDX-License-Identifier: MIT
 pub mod exec;
-pub mod spec;
\ No newline at end of file
+pub mod http;
+pub mod spec;
@@ -25,6 +25,7 @@ use deps_hack::tokio;
 use deps_hack::tracing::{error, info};
 use deps_hack::tracing_subscriber;
 use shim_layer::controller_runtime::run_controller;
+use shim_layer::watch_scope::WatchScope;
 use std::env;
 
 #[tokio::main]
@@ -32,6 +33,8 @@ async fn main() -> Result<()> {
     tracing_subscriber::fmt::init();
     let args: Vec<String> = env::args().collect();
     let cmd = args[1].clone();
+    // Flags such as --namespaces and --selector follow the command
+    let flags = &args[2..];
 
     if cmd == String::from("export") {
         println!("{}", serde_yaml::to_string(&deps_hack::FluentBit::crd())?);
@@ -41,20 +44,22 @@ async fn main() -> Result<()> {
         );
     } else if cmd == String::from("run") {
         info!("running fluent-controller");
+        let scope = WatchScope::from_args(flags)?;
         let fluentbit_controller_fut =
-            run_controller::<deps_hack::FluentBit, FluentBitReconciler>(false);
+            run_controller::<deps_hack::FluentBit, FluentBitReconciler>(false, scope.clone());
         let fluentbit_config_controller_fut =
-            run_controller::<deps_hack::FluentBitConfig, FluentBitConfigReconciler>(false);
+            run_controller::<deps_hack::FluentBitConfig, FluentBitConfigReconciler>(false, scope);
         futures::try_join!(fluentbit_controller_fut, fluentbit_config_controller_fut)?;
     } else if cmd == String::from("crash") {
         info!("running fluent-controller in crash-testing mode");
+        let scope = WatchScope::from_args(flags)?;
         let fluentbit_controller_fut =
-            run_controller::<deps_hack::FluentBit, FluentBitReconciler>(true);
+            run_controller::<deps_hack::FluentBit, FluentBitReconciler>(true, scope.clone());
         let fluentbit_config_controller_fut =
-            run_controller::<deps_hack::FluentBitConfig, FluentBitConfigReconciler>(true);
+            run_controller::<deps_hack::FluentBitConfig, FluentBitConfigReconciler>(true, scope);
         futures::try_join!(fluentbit_controller_fut, fluentbit_config_controller_fut)?;
     } else {
-        error!("wrong command; please use \"export\", \"run\" or \"crash\"");
+        error!("wrong command; please use \"export\", \"run\" or \"crash\" (\"run\" and \"crash\" accept \"--namespaces\" and \"--selector\")");
     }
     Ok(())
 }
@@ -4,7 +4,6 @@ use vstd::prelude::*;
 
 verus! {
 
-// TODO: implement other error types
 #[is_variant]
 pub enum APIError {
     BadRequest,
@@ -17,6 +16,14 @@ pub enum APIError {
     InternalError,
     Timeout,
     ServerTimeout,
+    TooManyRequests,
+    Gone,
+    Unauthorized,
+    ServiceUnavailable,
+    RequestEntityTooLarge,
+    // Transport is returned when the request never got a response from the API server,
+    // e.g., the connection is refused or the TLS handshake fails.
+    Transport,
     Other
 }
 
@@ -34,12 +41,28 @@ impl std::fmt::Debug for APIError {
             APIError::InternalError => write!(f, "InternalError"),
             APIError::Timeout => write!(f, "Timeout"),
             APIError::ServerTimeout => write!(f, "ServerTimeout"),
+            APIError::TooManyRequests => write!(f, "TooManyRequests"),
+            APIError::Gone => write!(f, "Gone"),
+            APIError::Unauthorized => write!(f, "Unauthorized"),
+            APIError::ServiceUnavailable => write!(f, "ServiceUnavailable"),
+            APIError::RequestEntityTooLarge => write!(f, "RequestEntityTooLarge"),
+            APIError::Transport => write!(f, "Transport"),
             APIError::Other => write!(f, "Other"),
         }
     }
 }
 
 impl APIError {
+    // A transient error is caused by the API server or the network rather than the request itself,
+    // so the same request might succeed if the controller retries it later.
+    pub open spec fn is_transient(self) -> bool {
+        ||| self.is_Timeout()
+        ||| self.is_ServerTimeout()
+        ||| self.is_TooManyRequests()
+        ||| self.is_ServiceUnavailable()
+        ||| self.is_Transport()
+    }
+
     pub fn is_object_not_found(&self) -> (res: bool)
         ensures res <==> self.is_ObjectNotFound(),
     {
@@ -48,6 +71,28 @@ impl APIError {
             _ => false,
         }
     }
+
+    pub fn is_object_already_exists(&self) -> (res: bool)
+        ensures res <==> self.is_ObjectAlreadyExists(),
+    {
+        match self {
+            APIError::ObjectAlreadyExists => true,
+            _ => false,
+        }
+    }
+
+    pub fn is_transient_error(&self) -> (res: bool)
+        ensures res <==> self.is_transient(),
+    {
+        match self {
+            APIError::Timeout => true,
+            APIError::ServerTimeout => true,
+            APIError::TooManyRequests => true,
+            APIError::ServiceUnavailable => true,
+            APIError::Transport => true,
+            _ => false,
+        }
+    }
 }
 
 pub type UnmarshalError = ();
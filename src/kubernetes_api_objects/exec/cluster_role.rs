@@ -0,0 +1,117 @@
+// Copyright 2022 VMware, Inc.
+// SPDX-License-Identifier: MIT
+use crate::kubernetes_api_objects::error::UnmarshalError;
+use crate::kubernetes_api_objects::exec::{
+    api_resource::*, dynamic::*, object_meta::*, resource::*, role::*,
+};
+use crate::kubernetes_api_objects::spec::{cluster_role::*, resource::*};
+use vstd::prelude::*;
+
+verus! {
+
+// This definition is a wrapper of ClusterRole defined at
+// https://github.com/Arnavion/k8s-openapi/blob/v0.17.0/src/v1_26/api/rbac/v1/cluster_role.rs.
+// It is supposed to be used in exec controller code.
+//
+// More detailed information: https://kubernetes.io/docs/reference/access-authn-authz/rbac/.
+
+#[verifier(external_body)]
+pub struct ClusterRole {
+    inner: deps_hack::k8s_openapi::api::rbac::v1::ClusterRole,
+}
+
+impl View for ClusterRole {
+    type V = ClusterRoleView;
+
+    spec fn view(&self) -> ClusterRoleView;
+}
+
+impl ClusterRole {
+    #[verifier(external_body)]
+    pub fn default() -> (cluster_role: ClusterRole)
+        ensures cluster_role@ == ClusterRoleView::default(),
+    {
+        ClusterRole {
+            inner: deps_hack::k8s_openapi::api::rbac::v1::ClusterRole::default(),
+        }
+    }
+
+    #[verifier(external_body)]
+    pub fn metadata(&self) -> (metadata: ObjectMeta)
+        ensures metadata@ == self@.metadata,
+    {
+        ObjectMeta::from_kube(self.inner.metadata.clone())
+    }
+
+    #[verifier(external_body)]
+    pub fn rules(&self) -> (policy_rules: Option<Vec<PolicyRule>>)
+        ensures
+            self@.policy_rules.is_Some() == policy_rules.is_Some(),
+            policy_rules.is_Some() ==> policy_rules.get_Some_0()@.map_values(|policy_rule: PolicyRule| policy_rule@) == self@.policy_rules.get_Some_0()
+    {
+        match &self.inner.rules {
+            Some(p) => Some(p.into_iter().map(|item| PolicyRule::from_kube(item.clone())).collect()),
+            None => None,
+        }
+    }
+
+    #[verifier(external_body)]
+    pub fn set_metadata(&mut self, metadata: ObjectMeta)
+        ensures self@ == old(self)@.set_metadata(metadata@),
+    {
+        self.inner.metadata = metadata.into_kube();
+    }
+
+    #[verifier(external_body)]
+    pub fn set_rules(&mut self, policy_rules: Vec<PolicyRule>)
+        ensures self@ == old(self)@.set_rules(policy_rules@.map_values(|policy_rule: PolicyRule| policy_rule@)),
+    {
+        self.inner.rules = Some(
+            policy_rules.into_iter().map(|p| p.into_kube()).collect()
+        )
+    }
+
+    #[verifier(external_body)]
+    pub fn clone(&self) -> (c: Self)
+        ensures c@ == self@,
+    {
+        ClusterRole { inner: self.inner.clone() }
+    }
+
+    #[verifier(external)]
+    pub fn into_kube(self) -> deps_hack::k8s_openapi::api::rbac::v1::ClusterRole { self.inner }
+
+    #[verifier(external)]
+    pub fn from_kube(inner: deps_hack::k8s_openapi::api::rbac::v1::ClusterRole) -> ClusterRole { ClusterRole { inner: inner } }
+
+    #[verifier(external_body)]
+    pub fn api_resource() -> (res: ApiResource)
+        ensures res@.kind == ClusterRoleView::kind(),
+    {
+        ApiResource::from_kube(deps_hack::kube::api::ApiResource::erase::<deps_hack::k8s_openapi::api::rbac::v1::ClusterRole>(&()))
+    }
+
+    #[verifier(external_body)]
+    pub fn marshal(self) -> (obj: DynamicObject)
+        ensures obj@ == self@.marshal(),
+    {
+        DynamicObject::from_kube(deps_hack::k8s_openapi::serde_json::from_str(&deps_hack::k8s_openapi::serde_json::to_string(&self.inner).unwrap()).unwrap())
+    }
+
+    #[verifier(external_body)]
+    pub fn unmarshal(obj: DynamicObject) -> (res: Result<ClusterRole, UnmarshalError>)
+        ensures
+            res.is_Ok() == ClusterRoleView::unmarshal(obj@).is_Ok(),
+            res.is_Ok() ==> res.get_Ok_0()@ == ClusterRoleView::unmarshal(obj@).get_Ok_0(),
+    {
+        let parse_result = obj.into_kube().try_parse::<deps_hack::k8s_openapi::api::rbac::v1::ClusterRole>();
+        if parse_result.is_ok() {
+            let res = ClusterRole { inner: parse_result.unwrap() };
+            Ok(res)
+        } else {
+            Err(())
+        }
+    }
+}
+
+}
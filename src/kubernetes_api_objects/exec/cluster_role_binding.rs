@@ -0,0 +1,132 @@
+// Copyright 2022 VMware, Inc.
+// SPDX-License-Identifier: MIT
+use crate::kubernetes_api_objects::error::UnmarshalError;
+use crate::kubernetes_api_objects::exec::{
+    api_resource::*, dynamic::*, object_meta::*, resource::*, role_binding::*,
+};
+use crate::kubernetes_api_objects::spec::{cluster_role_binding::*, resource::*};
+use vstd::prelude::*;
+
+verus! {
+
+
+// This definition is a wrapper of ClusterRoleBinding defined at
+// https://github.com/Arnavion/k8s-openapi/blob/v0.17.0/src/v1_26/api/rbac/v1/cluster_role_binding.rs.
+// It is supposed to be used in exec controller code.
+//
+// More detailed information: https://kubernetes.io/docs/reference/access-authn-authz/rbac/.
+
+#[verifier(external_body)]
+pub struct ClusterRoleBinding {
+    inner: deps_hack::k8s_openapi::api::rbac::v1::ClusterRoleBinding,
+}
+
+impl View for ClusterRoleBinding {
+    type V = ClusterRoleBindingView;
+
+    spec fn view(&self) -> ClusterRoleBindingView;
+}
+
+impl ClusterRoleBinding {
+    #[verifier(external_body)]
+    pub fn default() -> (cluster_role_binding: ClusterRoleBinding)
+        ensures cluster_role_binding@ == ClusterRoleBindingView::default(),
+    {
+        ClusterRoleBinding {
+            inner: deps_hack::k8s_openapi::api::rbac::v1::ClusterRoleBinding::default(),
+        }
+    }
+
+    #[verifier(external_body)]
+    pub fn metadata(&self) -> (metadata: ObjectMeta)
+        ensures metadata@ == self@.metadata,
+    {
+        ObjectMeta::from_kube(self.inner.metadata.clone())
+    }
+
+    #[verifier(external_body)]
+    pub fn role_ref(&self) -> (role_ref: RoleRef)
+        ensures role_ref@ == self@.role_ref,
+    {
+        RoleRef::from_kube(self.inner.role_ref.clone())
+    }
+
+    #[verifier(external_body)]
+    pub fn subjects(&self) -> (subjects: Option<Vec<Subject>>)
+        ensures
+            self@.subjects.is_Some() == subjects.is_Some(),
+            subjects.is_Some() ==> subjects.get_Some_0()@.map_values(|s: Subject| s@) == self@.subjects.get_Some_0(),
+    {
+        match &self.inner.subjects {
+            Some(s) => Some(s.into_iter().map(|item| Subject::from_kube(item.clone())).collect()),
+            None => None,
+        }
+    }
+
+    #[verifier(external_body)]
+    pub fn set_metadata(&mut self, metadata: ObjectMeta)
+        ensures self@ == old(self)@.set_metadata(metadata@),
+    {
+        self.inner.metadata = metadata.into_kube();
+    }
+
+    #[verifier(external_body)]
+    pub fn set_role_ref(&mut self, role_ref: RoleRef)
+        ensures self@ == old(self)@.set_role_ref(role_ref@),
+    {
+        self.inner.role_ref = role_ref.into_kube();
+    }
+
+    #[verifier(external_body)]
+    pub fn set_subjects(&mut self, subjects: Vec<Subject>)
+        ensures self@ == old(self)@.set_subjects(subjects@.map_values(|s: Subject| s@)),
+    {
+        self.inner.subjects = Some(
+            subjects.into_iter().map(|s: Subject| s.into_kube()).collect()
+        );
+    }
+
+    #[verifier(external_body)]
+    pub fn clone(&self) -> (c: Self)
+        ensures c@ == self@,
+    {
+        ClusterRoleBinding { inner: self.inner.clone() }
+    }
+
+    #[verifier(external)]
+    pub fn into_kube(self) -> deps_hack::k8s_openapi::api::rbac::v1::ClusterRoleBinding { self.inner }
+
+    #[verifier(external)]
+    pub fn from_kube(inner: deps_hack::k8s_openapi::api::rbac::v1::ClusterRoleBinding) -> ClusterRoleBinding { ClusterRoleBinding { inner: inner } }
+
+    #[verifier(external_body)]
+    pub fn api_resource() -> (res: ApiResource)
+        ensures res@.kind == ClusterRoleBindingView::kind(),
+    {
+        ApiResource::from_kube(deps_hack::kube::api::ApiResource::erase::<deps_hack::k8s_openapi::api::rbac::v1::ClusterRoleBinding>(&()))
+    }
+
+    #[verifier(external_body)]
+    pub fn marshal(self) -> (obj: DynamicObject)
+        ensures obj@ == self@.marshal(),
+    {
+        DynamicObject::from_kube(deps_hack::k8s_openapi::serde_json::from_str(&deps_hack::k8s_openapi::serde_json::to_string(&self.inner).unwrap()).unwrap())
+    }
+
+    #[verifier(external_body)]
+    pub fn unmarshal(obj: DynamicObject) -> (res: Result<ClusterRoleBinding, UnmarshalError>)
+        ensures
+            res.is_Ok() == ClusterRoleBindingView::unmarshal(obj@).is_Ok(),
+            res.is_Ok() ==> res.get_Ok_0()@ == ClusterRoleBindingView::unmarshal(obj@).get_Ok_0(),
+    {
+        let parse_result = obj.into_kube().try_parse::<deps_hack::k8s_openapi::api::rbac::v1::ClusterRoleBinding>();
+        if parse_result.is_ok() {
+            let res = ClusterRoleBinding { inner: parse_result.unwrap() };
+            Ok(res)
+        } else {
+            Err(())
+        }
+    }
+}
+
+}
@@ -7,17 +7,25 @@ verus! {
 
 #[derive(PartialEq, Eq, PartialOrd, Ord, Debug)]
 pub enum KindExec {
+    ClusterRoleKind,
+    ClusterRoleBindingKind,
     ConfigMapKind,
     CustomResourceKind(String),
     DaemonSetKind,
+    EventKind,
+    JobKind,
+    NamespaceKind,
+    PersistentVolumeKind,
     PersistentVolumeClaimKind,
     PodKind,
+    PodDisruptionBudgetKind,
     RoleKind,
     RoleBindingKind,
     StatefulSetKind,
     ServiceKind,
     ServiceAccountKind,
     SecretKind,
+    StorageClassKind,
 }
 
 impl View for KindExec {
@@ -25,37 +33,68 @@ impl View for KindExec {
 
     open spec fn view(&self) -> Self::V {
         match self {
+            KindExec::ClusterRoleKind => Kind::ClusterRoleKind,
+            KindExec::ClusterRoleBindingKind => Kind::ClusterRoleBindingKind,
             KindExec::ConfigMapKind => Kind::ConfigMapKind,
             KindExec::DaemonSetKind => Kind::DaemonSetKind,
+            KindExec::EventKind => Kind::EventKind,
+            KindExec::JobKind => Kind::JobKind,
+            KindExec::NamespaceKind => Kind::NamespaceKind,
+            KindExec::PersistentVolumeKind => Kind::PersistentVolumeKind,
             KindExec::PersistentVolumeClaimKind => Kind::PersistentVolumeClaimKind,
             KindExec::PodKind => Kind::PodKind,
+            KindExec::PodDisruptionBudgetKind => Kind::PodDisruptionBudgetKind,
             KindExec::RoleBindingKind => Kind::RoleBindingKind,
             KindExec::RoleKind => Kind::RoleKind,
             KindExec::SecretKind => Kind::SecretKind,
             KindExec::ServiceKind => Kind::ServiceKind,
             KindExec::StatefulSetKind => Kind::StatefulSetKind,
             KindExec::ServiceAccountKind => Kind::ServiceAccountKind,
+            KindExec::StorageClassKind => Kind::StorageClassKind,
             KindExec::CustomResourceKind(s) => Kind::CustomResourceKind(s@),
         }
     }
 }
 
+impl KindExec {
+    pub fn is_namespaced(&self) -> (b: bool)
+        ensures b == self@.is_namespaced(),
+    {
+        match self {
+            KindExec::ClusterRoleKind => false,
+            KindExec::ClusterRoleBindingKind => false,
+            KindExec::NamespaceKind => false,
+            KindExec::PersistentVolumeKind => false,
+            KindExec::StorageClassKind => false,
+            _ => true,
+        }
+    }
+}
+
 impl std::clone::Clone for KindExec {
     #[verifier(external_body)]
     fn clone(&self) -> (result: Self)
         ensures result == self
     {
         match self {
+            KindExec::ClusterRoleKind => KindExec::ClusterRoleKind,
+            KindExec::ClusterRoleBindingKind => KindExec::ClusterRoleBindingKind,
             KindExec::ConfigMapKind => KindExec::ConfigMapKind,
             KindExec::DaemonSetKind => KindExec::DaemonSetKind,
+            KindExec::EventKind => KindExec::EventKind,
+            KindExec::JobKind => KindExec::JobKind,
+            KindExec::NamespaceKind => KindExec::NamespaceKind,
+            KindExec::PersistentVolumeKind => KindExec::PersistentVolumeKind,
             KindExec::PersistentVolumeClaimKind => KindExec::PersistentVolumeClaimKind,
             KindExec::PodKind => KindExec::PodKind,
+            KindExec::PodDisruptionBudgetKind => KindExec::PodDisruptionBudgetKind,
             KindExec::RoleBindingKind => KindExec::RoleBindingKind,
             KindExec::RoleKind => KindExec::RoleKind,
             KindExec::SecretKind => KindExec::SecretKind,
             KindExec::ServiceKind => KindExec::ServiceKind,
             KindExec::StatefulSetKind => KindExec::StatefulSetKind,
             KindExec::ServiceAccountKind => KindExec::ServiceAccountKind,
+            KindExec::StorageClassKind => KindExec::StorageClassKind,
             KindExec::CustomResourceKind(s) => KindExec::CustomResourceKind(s.clone()),
         }
     }
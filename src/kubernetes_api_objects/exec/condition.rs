@@ -0,0 +1,180 @@
+// Copyright 2022 VMware, Inc.
+// SPDX-License-Identifier: MIT
+use crate::kubernetes_api_objects::exec::resource::*;
+use crate::kubernetes_api_objects::spec::condition::*;
+use vstd::prelude::*;
+use vstd::seq_lib::*;
+
+verus! {
+
+// Condition describes one aspect of the current state of a custom resource managed by our controllers.
+//
+// This definition is a wrapper of Condition defined in deps_hack, which mirrors metav1.Condition
+// (https://github.com/Arnavion/k8s-openapi/blob/v0.22.0/src/v1_30/apimachinery/pkg/apis/meta/v1/condition.rs)
+// except that lastTransitionTime is optional so that a condition can be built without a clock.
+// It is supposed to be used in exec controller code.
+//
+// More detailed information: https://github.com/kubernetes/community/blob/master/contributors/devel/sig-architecture/api-conventions.md#typical-status-properties.
+
+#[verifier(external_body)]
+pub struct Condition {
+    inner: deps_hack::Condition,
+}
+
+impl Condition {
+    pub spec fn view(&self) -> ConditionView;
+
+    #[verifier(external_body)]
+    pub fn default() -> (condition: Condition)
+        ensures condition@ == ConditionView::default(),
+    {
+        Condition { inner: deps_hack::Condition::default() }
+    }
+
+    #[verifier(external_body)]
+    pub fn clone(&self) -> (c: Self)
+        ensures c@ == self@,
+    {
+        Condition { inner: self.inner.clone() }
+    }
+
+    #[verifier(external_body)]
+    pub fn set_type(&mut self, type_: String)
+        ensures self@ == old(self)@.set_type(type_@),
+    {
+        self.inner.type_ = type_;
+    }
+
+    #[verifier(external_body)]
+    pub fn set_status(&mut self, status: String)
+        ensures self@ == old(self)@.set_status(status@),
+    {
+        self.inner.status = status;
+    }
+
+    #[verifier(external_body)]
+    pub fn set_reason(&mut self, reason: String)
+        ensures self@ == old(self)@.set_reason(reason@),
+    {
+        self.inner.reason = Some(reason);
+    }
+
+    #[verifier(external_body)]
+    pub fn set_message(&mut self, message: String)
+        ensures self@ == old(self)@.set_message(message@),
+    {
+        self.inner.message = Some(message);
+    }
+
+    #[verifier(external_body)]
+    pub fn set_observed_generation(&mut self, observed_generation: i64)
+        ensures self@ == old(self)@.set_observed_generation(observed_generation as int),
+    {
+        self.inner.observed_generation = Some(observed_generation);
+    }
+}
+
+#[verifier(external)]
+impl ResourceWrapper<deps_hack::Condition> for Condition {
+    fn from_kube(inner: deps_hack::Condition) -> Condition { Condition { inner: inner } }
+
+    fn into_kube(self) -> deps_hack::Condition { self.inner }
+}
+
+pub fn condition_status(b: bool) -> (status: String)
+    ensures status@ == crate::kubernetes_api_objects::spec::condition::condition_status(b),
+{
+    if b { "True".to_string() } else { "False".to_string() }
+}
+
+pub fn make_ready_condition(ready: bool) -> (condition: Condition)
+    ensures condition@ == crate::kubernetes_api_objects::spec::condition::make_ready_condition(ready),
+{
+    let mut condition = Condition::default();
+    condition.set_type("Ready".to_string());
+    condition.set_status(condition_status(ready));
+    condition.set_reason(if ready { "AllReplicasReady".to_string() } else { "ReplicasNotReady".to_string() });
+    condition
+}
+
+pub fn make_progressing_condition(ready: bool) -> (condition: Condition)
+    ensures condition@ == crate::kubernetes_api_objects::spec::condition::make_progressing_condition(ready),
+{
+    let mut condition = Condition::default();
+    condition.set_type("Progressing".to_string());
+    condition.set_status(condition_status(!ready));
+    condition.set_reason(if ready { "ReconcileComplete".to_string() } else { "Reconciling".to_string() });
+    condition
+}
+
+pub fn make_degraded_condition(degraded: bool) -> (condition: Condition)
+    ensures condition@ == crate::kubernetes_api_objects::spec::condition::make_degraded_condition(degraded),
+{
+    let mut condition = Condition::default();
+    condition.set_type("Degraded".to_string());
+    condition.set_status(condition_status(degraded));
+    condition.set_reason(if degraded { "ReconcileFailed".to_string() } else { "ReconcileSucceeded".to_string() });
+    condition
+}
+
+pub fn set_observed_generation_of(condition: Condition, generation: Option<i64>) -> (result: Condition)
+    ensures
+        result@ == crate::kubernetes_api_objects::spec::condition::set_observed_generation_of(
+            condition@, if generation.is_Some() { Some(generation.get_Some_0() as int) } else { None }
+        ),
+{
+    let mut result = condition;
+    if generation.is_some() {
+        result.set_observed_generation(generation.unwrap());
+    }
+    result
+}
+
+pub fn make_conditions(ready: bool, degraded: bool, generation: Option<i64>) -> (conditions: Vec<Condition>)
+    ensures
+        conditions@.map_values(|c: Condition| c@) == crate::kubernetes_api_objects::spec::condition::make_conditions(
+            ready, degraded, if generation.is_Some() { Some(generation.get_Some_0() as int) } else { None }
+        ),
+{
+    let mut conditions = Vec::new();
+    conditions.push(set_observed_generation_of(make_ready_condition(ready), generation));
+    conditions.push(set_observed_generation_of(make_progressing_condition(ready), generation));
+    conditions.push(set_observed_generation_of(make_degraded_condition(degraded), generation));
+    proof {
+        assert_seqs_equal!(
+            conditions@.map_values(|c: Condition| c@),
+            crate::kubernetes_api_objects::spec::condition::make_conditions(
+                ready, degraded, if generation.is_Some() { Some(generation.get_Some_0() as int) } else { None }
+            )
+        );
+    }
+    conditions
+}
+
+// into_kube_conditions converts the conditions into the form stored in a custom resource's status.
+// Since lastTransitionTime is not modeled, every condition is stamped with the current time here;
+// preserve_transition_times later restores the timestamps of the conditions that did not change.
+#[verifier(external)]
+pub fn into_kube_conditions(conditions: Vec<Condition>) -> Vec<deps_hack::Condition> {
+    let now = deps_hack::k8s_openapi::apimachinery::pkg::apis::meta::v1::Time(deps_hack::chrono::Utc::now());
+    conditions.into_iter().map(|c| {
+        let mut c = c.into_kube();
+        c.last_transition_time = Some(now.clone());
+        c
+    }).collect()
+}
+
+// preserve_transition_times makes each condition keep the lastTransitionTime of the condition of the same type
+// in old_conditions if its status is unchanged, so that the timestamp only moves when the status transitions.
+#[verifier(external)]
+pub fn preserve_transition_times(conditions: &mut Vec<deps_hack::Condition>, old_conditions: &Vec<deps_hack::Condition>) {
+    for c in conditions.iter_mut() {
+        if let Some(old) = old_conditions.iter().find(|o| o.type_ == c.type_) {
+            if old.status == c.status && old.last_transition_time.is_some() {
+                c.last_transition_time = old.last_transition_time.clone();
+            }
+        }
+    }
+}
+
+}
@@ -623,6 +623,76 @@ pub struct SecurityContext {
 impl SecurityContext {
     pub spec fn view(&self) -> SecurityContextView;
 
+    #[verifier(external_body)]
+    pub fn default() -> (security_context: SecurityContext)
+        ensures security_context@ == SecurityContextView::default(),
+    {
+        SecurityContext { inner: deps_hack::k8s_openapi::api::core::v1::SecurityContext::default() }
+    }
+
+    #[verifier(external_body)]
+    pub fn clone(&self) -> (security_context: SecurityContext)
+        ensures security_context@ == self@,
+    {
+        SecurityContext { inner: self.inner.clone() }
+    }
+
+    #[verifier(external_body)]
+    pub fn set_allow_privilege_escalation(&mut self, allow_privilege_escalation: bool)
+        ensures self@ == old(self)@.set_allow_privilege_escalation(allow_privilege_escalation),
+    {
+        self.inner.allow_privilege_escalation = Some(allow_privilege_escalation)
+    }
+
+    #[verifier(external_body)]
+    pub fn set_capabilities(&mut self, capabilities: Capabilities)
+        ensures self@ == old(self)@.set_capabilities(capabilities@),
+    {
+        self.inner.capabilities = Some(capabilities.into_kube())
+    }
+
+    #[verifier(external_body)]
+    pub fn set_privileged(&mut self, privileged: bool)
+        ensures self@ == old(self)@.set_privileged(privileged),
+    {
+        self.inner.privileged = Some(privileged)
+    }
+
+    #[verifier(external_body)]
+    pub fn set_read_only_root_filesystem(&mut self, read_only_root_filesystem: bool)
+        ensures self@ == old(self)@.set_read_only_root_filesystem(read_only_root_filesystem),
+    {
+        self.inner.read_only_root_filesystem = Some(read_only_root_filesystem)
+    }
+
+    #[verifier(external_body)]
+    pub fn set_run_as_group(&mut self, run_as_group: i64)
+        ensures self@ == old(self)@.set_run_as_group(run_as_group as int),
+    {
+        self.inner.run_as_group = Some(run_as_group)
+    }
+
+    #[verifier(external_body)]
+    pub fn set_run_as_non_root(&mut self, run_as_non_root: bool)
+        ensures self@ == old(self)@.set_run_as_non_root(run_as_non_root),
+    {
+        self.inner.run_as_non_root = Some(run_as_non_root)
+    }
+
+    #[verifier(external_body)]
+    pub fn set_run_as_user(&mut self, run_as_user: i64)
+        ensures self@ == old(self)@.set_run_as_user(run_as_user as int),
+    {
+        self.inner.run_as_user = Some(run_as_user)
+    }
+
+    #[verifier(external_body)]
+    pub fn set_seccomp_profile(&mut self, seccomp_profile: SeccompProfile)
+        ensures self@ == old(self)@.set_seccomp_profile(seccomp_profile@),
+    {
+        self.inner.seccomp_profile = Some(seccomp_profile.into_kube())
+    }
+
     #[verifier(external)]
     pub fn from_kube(inner: deps_hack::k8s_openapi::api::core::v1::SecurityContext) -> SecurityContext { SecurityContext { inner: inner } }
 
@@ -630,4 +700,90 @@ impl SecurityContext {
     pub fn into_kube(self) -> deps_hack::k8s_openapi::api::core::v1::SecurityContext { self.inner }
 }
 
+#[verifier(external_body)]
+pub struct Capabilities {
+    inner: deps_hack::k8s_openapi::api::core::v1::Capabilities,
+}
+
+impl Capabilities {
+    pub spec fn view(&self) -> CapabilitiesView;
+
+    #[verifier(external_body)]
+    pub fn default() -> (capabilities: Capabilities)
+        ensures capabilities@ == CapabilitiesView::default(),
+    {
+        Capabilities { inner: deps_hack::k8s_openapi::api::core::v1::Capabilities::default() }
+    }
+
+    #[verifier(external_body)]
+    pub fn clone(&self) -> (capabilities: Capabilities)
+        ensures capabilities@ == self@,
+    {
+        Capabilities { inner: self.inner.clone() }
+    }
+
+    #[verifier(external_body)]
+    pub fn set_add(&mut self, add: Vec<String>)
+        ensures self@ == old(self)@.set_add(add@.map_values(|capability: String| capability@)),
+    {
+        self.inner.add = Some(add)
+    }
+
+    #[verifier(external_body)]
+    pub fn set_drop(&mut self, drop: Vec<String>)
+        ensures self@ == old(self)@.set_drop(drop@.map_values(|capability: String| capability@)),
+    {
+        self.inner.drop = Some(drop)
+    }
+
+    #[verifier(external)]
+    pub fn from_kube(inner: deps_hack::k8s_openapi::api::core::v1::Capabilities) -> Capabilities { Capabilities { inner: inner } }
+
+    #[verifier(external)]
+    pub fn into_kube(self) -> deps_hack::k8s_openapi::api::core::v1::Capabilities { self.inner }
+}
+
+#[verifier(external_body)]
+pub struct SeccompProfile {
+    inner: deps_hack::k8s_openapi::api::core::v1::SeccompProfile,
+}
+
+impl SeccompProfile {
+    pub spec fn view(&self) -> SeccompProfileView;
+
+    #[verifier(external_body)]
+    pub fn default() -> (seccomp_profile: SeccompProfile)
+        ensures seccomp_profile@ == SeccompProfileView::default(),
+    {
+        SeccompProfile { inner: deps_hack::k8s_openapi::api::core::v1::SeccompProfile::default() }
+    }
+
+    #[verifier(external_body)]
+    pub fn clone(&self) -> (seccomp_profile: SeccompProfile)
+        ensures seccomp_profile@ == self@,
+    {
+        SeccompProfile { inner: self.inner.clone() }
+    }
+
+    #[verifier(external_body)]
+    pub fn set_localhost_profile(&mut self, localhost_profile: String)
+        ensures self@ == old(self)@.set_localhost_profile(localhost_profile@),
+    {
+        self.inner.localhost_profile = Some(localhost_profile)
+    }
+
+    #[verifier(external_body)]
+    pub fn set_type(&mut self, type_: String)
+        ensures self@ == old(self)@.set_type(type_@),
+    {
+        self.inner.type_ = type_
+    }
+
+    #[verifier(external)]
+    pub fn from_kube(inner: deps_hack::k8s_openapi::api::core::v1::SeccompProfile) -> SeccompProfile { SeccompProfile { inner: inner } }
+
+    #[verifier(external)]
+    pub fn into_kube(self) -> deps_hack::k8s_openapi::api::core::v1::SeccompProfile { self.inner }
+}
+
 }
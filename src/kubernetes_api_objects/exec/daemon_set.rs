@@ -65,6 +65,15 @@ impl DaemonSet {
         if self.inner.spec.is_none() { None } else { Some(DaemonSetSpec::from_kube(self.inner.spec.as_ref().unwrap().clone())) }
     }
 
+    #[verifier(external_body)]
+    pub fn status(&self) -> (status: Option<DaemonSetStatus>)
+        ensures
+            self@.status.is_Some() == status.is_Some(),
+            status.is_Some() ==> status.get_Some_0()@ == self@.status.get_Some_0(),
+    {
+        if self.inner.status.is_none() { None } else { Some(DaemonSetStatus::from_kube(self.inner.status.as_ref().unwrap().clone())) }
+    }
+
     #[verifier(external_body)]
     pub fn set_metadata(&mut self, metadata: ObjectMeta)
         ensures self@ == old(self)@.set_metadata(metadata@),
@@ -190,6 +199,20 @@ impl DaemonSetStatus {
     {
         self.inner.number_ready
     }
+
+    #[verifier(external_body)]
+    pub fn desired_number_scheduled(&self) -> (desired_number_scheduled: i32)
+        ensures self@.desired_number_scheduled == desired_number_scheduled as int,
+    {
+        self.inner.desired_number_scheduled
+    }
+
+    #[verifier(external_body)]
+    pub fn updated_number_scheduled(&self) -> (updated_number_scheduled: i32)
+        ensures self@.updated_number_scheduled == updated_number_scheduled as int,
+    {
+        self.inner.updated_number_scheduled.unwrap_or(0)
+    }
 }
 
 #[verifier(external)]
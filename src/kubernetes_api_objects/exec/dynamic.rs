@@ -1,6 +1,6 @@
 // Copyright 2022 VMware, Inc.
 // SPDX-License-Identifier: MIT
-use crate::kubernetes_api_objects::exec::{object_meta::*, resource::*};
+use crate::kubernetes_api_objects::exec::{json_value::*, object_meta::*, resource::*};
 use crate::kubernetes_api_objects::spec::dynamic::*;
 use vstd::prelude::*;
 
@@ -43,6 +43,74 @@ impl DynamicObject {
     {
         ObjectMeta::from_kube(self.inner.metadata.clone())
     }
+
+    // The data of a kube DynamicObject has all the fields of the object other than apiVersion, kind and metadata.
+    // Most kinds keep their spec and status in the "spec" and "status" fields of the data,
+    // while the kinds without a spec (see spec_is_top_level) keep what the model marshals as their spec
+    // (e.g., "data" and "stringData" of a Secret) at the top level, so their spec is the data without "status".
+
+    #[verifier(external_body)]
+    pub fn spec(&self) -> (spec: JsonValueExec)
+        ensures spec@ == self@.spec,
+    {
+        if self.spec_is_top_level() {
+            let mut data = self.inner.data.clone();
+            if let Some(o) = data.as_object_mut() {
+                o.remove("status");
+            }
+            JsonValueExec::from_kube(data)
+        } else {
+            JsonValueExec::from_kube(self.inner.data.get("spec").cloned().unwrap_or(deps_hack::serde_json::Value::Null))
+        }
+    }
+
+    #[verifier(external_body)]
+    pub fn status(&self) -> (status: JsonValueExec)
+        ensures status@ == self@.status,
+    {
+        JsonValueExec::from_kube(self.inner.data.get("status").cloned().unwrap_or(deps_hack::serde_json::Value::Null))
+    }
+
+    #[verifier(external_body)]
+    pub fn set_spec(&mut self, spec: JsonValueExec)
+        ensures self@ == old(self)@.set_spec(spec@),
+    {
+        let status = self.inner.data.get("status").cloned();
+        if self.spec_is_top_level() {
+            self.inner.data = spec.into_kube();
+            if let (Some(status), Some(o)) = (status, self.inner.data.as_object_mut()) {
+                o.insert("status".to_string(), status);
+            }
+        } else {
+            self.set_data_field("spec", spec.into_kube());
+        }
+    }
+
+    #[verifier(external_body)]
+    pub fn set_status(&mut self, status: JsonValueExec)
+        ensures self@ == old(self)@.set_status(status@),
+    {
+        self.set_data_field("status", status.into_kube());
+    }
+
+    #[verifier(external)]
+    fn spec_is_top_level(&self) -> bool {
+        match self.inner.types.as_ref() {
+            Some(types) => matches!(
+                types.kind.as_str(),
+                "ConfigMap" | "Secret" | "ServiceAccount" | "Role" | "RoleBinding" | "ClusterRole" | "ClusterRoleBinding" | "Event"
+            ),
+            None => false,
+        }
+    }
+
+    #[verifier(external)]
+    fn set_data_field(&mut self, key: &str, value: deps_hack::serde_json::Value) {
+        if !self.inner.data.is_object() {
+            self.inner.data = deps_hack::serde_json::Value::Object(deps_hack::serde_json::Map::new());
+        }
+        self.inner.data.as_object_mut().unwrap().insert(key.to_string(), value);
+    }
 }
 
 #[verifier(external)]
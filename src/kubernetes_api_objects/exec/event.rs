@@ -0,0 +1,205 @@
+// Copyright 2022 VMware, Inc.
+// SPDX-License-Identifier: MIT
+use crate::kubernetes_api_objects::error::UnmarshalError;
+use crate::kubernetes_api_objects::exec::{
+    api_resource::*, dynamic::*, object_meta::*, resource::*,
+};
+use crate::kubernetes_api_objects::spec::{event::*, resource::*};
+use vstd::prelude::*;
+
+verus! {
+
+// An event is a report of something that happened to an object (e.g., a reconcile failure),
+// which shows up in `kubectl describe` of the involved object.
+//
+// This definition is a wrapper of Event defined at
+// https://github.com/Arnavion/k8s-openapi/blob/v0.22.0/src/v1_30/api/core/v1/event.rs.
+// It is supposed to be used in exec controller code.
+//
+// More detailed information: https://kubernetes.io/docs/reference/kubernetes-api/cluster-resources/event-v1/.
+
+#[verifier(external_body)]
+pub struct Event {
+    inner: deps_hack::k8s_openapi::api::core::v1::Event,
+}
+
+impl View for Event {
+    type V = EventView;
+
+    spec fn view(&self) -> EventView;
+}
+
+impl Event {
+    #[verifier(external_body)]
+    pub fn default() -> (event: Event)
+        ensures event@ == EventView::default(),
+    {
+        Event {
+            inner: deps_hack::k8s_openapi::api::core::v1::Event::default(),
+        }
+    }
+
+    #[verifier(external_body)]
+    pub fn metadata(&self) -> (metadata: ObjectMeta)
+        ensures metadata@ == self@.metadata,
+    {
+        ObjectMeta::from_kube(self.inner.metadata.clone())
+    }
+
+    #[verifier(external_body)]
+    pub fn involved_object(&self) -> (involved_object: ObjectReference)
+        ensures involved_object@ == self@.involved_object,
+    {
+        ObjectReference::from_kube(self.inner.involved_object.clone())
+    }
+
+    #[verifier(external_body)]
+    pub fn type_(&self) -> (type_: Option<String>)
+        ensures
+            self@.type_.is_Some() == type_.is_Some(),
+            type_.is_Some() ==> type_.get_Some_0()@ == self@.type_.get_Some_0(),
+    {
+        self.inner.type_.clone()
+    }
+
+    #[verifier(external_body)]
+    pub fn set_metadata(&mut self, metadata: ObjectMeta)
+        ensures self@ == old(self)@.set_metadata(metadata@),
+    {
+        self.inner.metadata = metadata.into_kube();
+    }
+
+    #[verifier(external_body)]
+    pub fn set_involved_object(&mut self, involved_object: ObjectReference)
+        ensures self@ == old(self)@.set_involved_object(involved_object@),
+    {
+        self.inner.involved_object = involved_object.into_kube();
+    }
+
+    #[verifier(external_body)]
+    pub fn set_reason(&mut self, reason: String)
+        ensures self@ == old(self)@.set_reason(reason@),
+    {
+        self.inner.reason = Some(reason);
+    }
+
+    #[verifier(external_body)]
+    pub fn set_message(&mut self, message: String)
+        ensures self@ == old(self)@.set_message(message@),
+    {
+        self.inner.message = Some(message);
+    }
+
+    #[verifier(external_body)]
+    pub fn set_type(&mut self, type_: String)
+        ensures self@ == old(self)@.set_type(type_@),
+    {
+        self.inner.type_ = Some(type_);
+    }
+
+    #[verifier(external_body)]
+    pub fn clone(&self) -> (c: Self)
+        ensures c@ == self@,
+    {
+        Event { inner: self.inner.clone() }
+    }
+
+    #[verifier(external)]
+    pub fn into_kube(self) -> deps_hack::k8s_openapi::api::core::v1::Event { self.inner }
+
+    #[verifier(external)]
+    pub fn from_kube(inner: deps_hack::k8s_openapi::api::core::v1::Event) -> Event { Event { inner: inner } }
+
+    #[verifier(external_body)]
+    pub fn api_resource() -> (res: ApiResource)
+        ensures res@.kind == EventView::kind(),
+    {
+        ApiResource::from_kube(deps_hack::kube::api::ApiResource::erase::<deps_hack::k8s_openapi::api::core::v1::Event>(&()))
+    }
+
+    #[verifier(external_body)]
+    pub fn marshal(self) -> (obj: DynamicObject)
+        ensures obj@ == self@.marshal(),
+    {
+        DynamicObject::from_kube(deps_hack::k8s_openapi::serde_json::from_str(&deps_hack::k8s_openapi::serde_json::to_string(&self.inner).unwrap()).unwrap())
+    }
+
+    #[verifier(external_body)]
+    pub fn unmarshal(obj: DynamicObject) -> (res: Result<Event, UnmarshalError>)
+        ensures
+            res.is_Ok() == EventView::unmarshal(obj@).is_Ok(),
+            res.is_Ok() ==> res.get_Ok_0()@ == EventView::unmarshal(obj@).get_Ok_0(),
+    {
+        let parse_result = obj.into_kube().try_parse::<deps_hack::k8s_openapi::api::core::v1::Event>();
+        if parse_result.is_ok() {
+            let res = Event { inner: parse_result.unwrap() };
+            Ok(res)
+        } else {
+            Err(())
+        }
+    }
+}
+
+#[verifier(external_body)]
+pub struct ObjectReference {
+    inner: deps_hack::k8s_openapi::api::core::v1::ObjectReference,
+}
+
+impl ObjectReference {
+    pub spec fn view(&self) -> ObjectReferenceView;
+
+    #[verifier(external_body)]
+    pub fn default() -> (object_reference: ObjectReference)
+        ensures object_reference@ == ObjectReferenceView::default(),
+    {
+        ObjectReference {
+            inner: deps_hack::k8s_openapi::api::core::v1::ObjectReference::default(),
+        }
+    }
+
+    // Only kind, name and namespace are compared since they are the only fields in the view.
+    #[verifier(external_body)]
+    pub fn eq(&self, other: &Self) -> (b: bool)
+        ensures b == (self.view() == other.view())
+    {
+        self.inner.kind == other.inner.kind
+        && self.inner.name == other.inner.name
+        && self.inner.namespace == other.inner.namespace
+    }
+
+    #[verifier(external_body)]
+    pub fn clone(&self) -> (c: Self)
+        ensures c@ == self@,
+    {
+        ObjectReference { inner: self.inner.clone() }
+    }
+
+    #[verifier(external_body)]
+    pub fn set_kind(&mut self, kind: String)
+        ensures self@ == old(self)@.set_kind(kind@),
+    {
+        self.inner.kind = Some(kind);
+    }
+
+    #[verifier(external_body)]
+    pub fn set_name(&mut self, name: String)
+        ensures self@ == old(self)@.set_name(name@),
+    {
+        self.inner.name = Some(name);
+    }
+
+    #[verifier(external_body)]
+    pub fn set_namespace(&mut self, namespace: String)
+        ensures self@ == old(self)@.set_namespace(namespace@),
+    {
+        self.inner.namespace = Some(namespace);
+    }
+
+    #[verifier(external)]
+    pub fn into_kube(self) -> deps_hack::k8s_openapi::api::core::v1::ObjectReference { self.inner }
+
+    #[verifier(external)]
+    pub fn from_kube(inner: deps_hack::k8s_openapi::api::core::v1::ObjectReference) -> ObjectReference { ObjectReference { inner: inner } }
+}
+
+}
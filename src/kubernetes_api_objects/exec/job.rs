@@ -0,0 +1,232 @@
+// Copyright 2022 VMware, Inc.
+// SPDX-License-Identifier: MIT
+use crate::kubernetes_api_objects::error::UnmarshalError;
+use crate::kubernetes_api_objects::exec::{
+    api_resource::*, dynamic::*, object_meta::*, pod_template_spec::*, resource::*,
+};
+use crate::kubernetes_api_objects::spec::{job::*, resource::*};
+use vstd::prelude::*;
+
+verus! {
+
+// Job is a type of API object used for running a task to completion,
+// by creating Pods from the template until one of them terminates successfully.
+//
+// This definition is a wrapper of Job defined at
+// https://github.com/Arnavion/k8s-openapi/blob/v0.17.0/src/v1_26/api/batch/v1/job.rs.
+// It is supposed to be used in exec controller code.
+//
+// More detailed information: https://kubernetes.io/docs/concepts/workloads/controllers/job/.
+
+#[verifier(external_body)]
+pub struct Job {
+    inner: deps_hack::k8s_openapi::api::batch::v1::Job,
+}
+
+
+impl View for Job {
+    type V = JobView;
+
+    spec fn view(&self) -> JobView;
+}
+
+impl Job {
+    #[verifier(external_body)]
+    pub fn default() -> (job: Job)
+        ensures job@ == JobView::default(),
+    {
+        Job { inner: deps_hack::k8s_openapi::api::batch::v1::Job::default() }
+    }
+
+    #[verifier(external_body)]
+    pub fn clone(&self) -> (s: Self)
+        ensures s@ == self@,
+    {
+        Job { inner: self.inner.clone() }
+    }
+
+    #[verifier(external_body)]
+    pub fn metadata(&self) -> (metadata: ObjectMeta)
+        ensures metadata@ == self@.metadata,
+    {
+        ObjectMeta::from_kube(self.inner.metadata.clone())
+    }
+
+    #[verifier(external_body)]
+    pub fn spec(&self) -> (spec: Option<JobSpec>)
+        ensures
+            self@.spec.is_Some() == spec.is_Some(),
+            spec.is_Some() ==> spec.get_Some_0()@ == self@.spec.get_Some_0(),
+    {
+        if self.inner.spec.is_none() { None } else { Some(JobSpec::from_kube(self.inner.spec.as_ref().unwrap().clone())) }
+    }
+
+    #[verifier(external_body)]
+    pub fn status(&self) -> (status: Option<JobStatus>)
+        ensures
+            self@.status.is_Some() == status.is_Some(),
+            status.is_Some() ==> status.get_Some_0()@ == self@.status.get_Some_0(),
+    {
+        if self.inner.status.is_none() { None } else { Some(JobStatus::from_kube(self.inner.status.as_ref().unwrap().clone())) }
+    }
+
+    pub fn succeeded(&self) -> (succeeded: bool)
+        ensures succeeded == self@.succeeded(),
+    {
+        match self.status() {
+            Some(status) => match status.succeeded() {
+                Some(succeeded) => succeeded > 0,
+                None => false,
+            },
+            None => false,
+        }
+    }
+
+    #[verifier(external_body)]
+    pub fn set_metadata(&mut self, metadata: ObjectMeta)
+        ensures self@ == old(self)@.set_metadata(metadata@),
+    {
+        self.inner.metadata = metadata.into_kube();
+    }
+
+    #[verifier(external_body)]
+    pub fn set_spec(&mut self, spec: JobSpec)
+        ensures self@ == old(self)@.set_spec(spec@),
+    {
+        self.inner.spec = Some(spec.into_kube());
+    }
+
+    #[verifier(external_body)]
+    pub fn api_resource() -> (res: ApiResource)
+        ensures res@.kind == JobView::kind(),
+    {
+        ApiResource::from_kube(deps_hack::kube::api::ApiResource::erase::<deps_hack::k8s_openapi::api::batch::v1::Job>(&()))
+    }
+
+    // NOTE: This function assumes serde_json::to_string won't fail!
+    #[verifier(external_body)]
+    pub fn marshal(self) -> (obj: DynamicObject)
+        ensures obj@ == self@.marshal(),
+    {
+        DynamicObject::from_kube(deps_hack::k8s_openapi::serde_json::from_str(&deps_hack::k8s_openapi::serde_json::to_string(&self.inner).unwrap()).unwrap())
+    }
+
+    // Convert a DynamicObject to a Job
+    #[verifier(external_body)]
+    pub fn unmarshal(obj: DynamicObject) -> (res: Result<Job, UnmarshalError>)
+        ensures
+            res.is_Ok() == JobView::unmarshal(obj@).is_Ok(),
+            res.is_Ok() ==> res.get_Ok_0()@ == JobView::unmarshal(obj@).get_Ok_0(),
+    {
+        let parse_result = obj.into_kube().try_parse::<deps_hack::k8s_openapi::api::batch::v1::Job>();
+        if parse_result.is_ok() {
+            let res = Job { inner: parse_result.unwrap() };
+            Ok(res)
+        } else {
+            Err(())
+        }
+    }
+}
+
+#[verifier(external)]
+impl ResourceWrapper<deps_hack::k8s_openapi::api::batch::v1::Job> for Job {
+    fn from_kube(inner: deps_hack::k8s_openapi::api::batch::v1::Job) -> Job { Job { inner: inner } }
+
+    fn into_kube(self) -> deps_hack::k8s_openapi::api::batch::v1::Job { self.inner }
+}
+
+#[verifier(external_body)]
+pub struct JobSpec {
+    inner: deps_hack::k8s_openapi::api::batch::v1::JobSpec,
+}
+
+impl JobSpec {
+    pub spec fn view(&self) -> JobSpecView;
+
+    #[verifier(external_body)]
+    pub fn default() -> (job_spec: JobSpec)
+        ensures job_spec@ == JobSpecView::default(),
+    {
+        JobSpec { inner: deps_hack::k8s_openapi::api::batch::v1::JobSpec::default() }
+    }
+
+    #[verifier(external_body)]
+    pub fn clone(&self) -> (s: Self)
+        ensures s@ == self@,
+    {
+        JobSpec { inner: self.inner.clone() }
+    }
+
+    #[verifier(external_body)]
+    pub fn set_template(&mut self, template: PodTemplateSpec)
+        ensures self@ == old(self)@.set_template(template@),
+    {
+        self.inner.template = template.into_kube()
+    }
+
+    #[verifier(external_body)]
+    pub fn set_backoff_limit(&mut self, backoff_limit: i32)
+        ensures self@ == old(self)@.set_backoff_limit(backoff_limit as int),
+    {
+        self.inner.backoff_limit = Some(backoff_limit)
+    }
+
+    #[verifier(external_body)]
+    pub fn template(&self) -> (template: PodTemplateSpec)
+        ensures template@ == self@.template,
+    {
+        PodTemplateSpec::from_kube(self.inner.template.clone())
+    }
+
+    #[verifier(external_body)]
+    pub fn backoff_limit(&self) -> (backoff_limit: Option<i32>)
+        ensures
+            self@.backoff_limit.is_Some() == backoff_limit.is_Some(),
+            backoff_limit.is_Some() ==> backoff_limit.get_Some_0() as int == self@.backoff_limit.get_Some_0(),
+    {
+        self.inner.backoff_limit
+    }
+}
+
+#[verifier(external)]
+impl ResourceWrapper<deps_hack::k8s_openapi::api::batch::v1::JobSpec> for JobSpec {
+    fn from_kube(inner: deps_hack::k8s_openapi::api::batch::v1::JobSpec) -> JobSpec { JobSpec { inner: inner } }
+
+    fn into_kube(self) -> deps_hack::k8s_openapi::api::batch::v1::JobSpec { self.inner }
+}
+
+#[verifier(external_body)]
+pub struct JobStatus {
+    inner: deps_hack::k8s_openapi::api::batch::v1::JobStatus,
+}
+
+impl JobStatus {
+    pub spec fn view(&self) -> JobStatusView;
+
+    #[verifier(external_body)]
+    pub fn succeeded(&self) -> (succeeded: Option<i32>)
+        ensures
+            self@.succeeded.is_Some() == succeeded.is_Some(),
+            succeeded.is_Some() ==> succeeded.get_Some_0() as int == self@.succeeded.get_Some_0(),
+    {
+        self.inner.succeeded
+    }
+
+    #[verifier(external_body)]
+    pub fn failed(&self) -> (failed: Option<i32>)
+        ensures
+            self@.failed.is_Some() == failed.is_Some(),
+            failed.is_Some() ==> failed.get_Some_0() as int == self@.failed.get_Some_0(),
+    {
+        self.inner.failed
+    }
+}
+
+#[verifier(external)]
+impl ResourceWrapper<deps_hack::k8s_openapi::api::batch::v1::JobStatus> for JobStatus {
+    fn from_kube(inner: deps_hack::k8s_openapi::api::batch::v1::JobStatus) -> JobStatus { JobStatus { inner: inner } }
+
+    fn into_kube(self) -> deps_hack::k8s_openapi::api::batch::v1::JobStatus { self.inner }
+}
+
+}
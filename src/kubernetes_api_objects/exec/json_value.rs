@@ -0,0 +1,147 @@
+// Copyright 2022 VMware, Inc.
+// SPDX-License-Identifier: MIT
+use crate::kubernetes_api_objects::exec::resource::*;
+use crate::kubernetes_api_objects::spec::json_value::*;
+use crate::vstd_ext::option_lib::*;
+use vstd::prelude::*;
+
+verus! {
+
+// JsonValueExec is the exec counterpart of JsonValue.
+//
+// This definition is a wrapper of serde_json::Value, which is what the data of
+// a kube::api::DynamicObject is made of.
+
+#[verifier(external_body)]
+pub struct JsonValueExec {
+    inner: deps_hack::serde_json::Value,
+}
+
+impl View for JsonValueExec {
+    type V = JsonValue;
+
+    spec fn view(&self) -> JsonValue;
+}
+
+impl JsonValueExec {
+    #[verifier(external_body)]
+    pub fn null() -> (v: JsonValueExec)
+        ensures v@ == JsonValue::Null,
+    {
+        JsonValueExec { inner: deps_hack::serde_json::Value::Null }
+    }
+
+    #[verifier(external_body)]
+    pub fn empty_object() -> (v: JsonValueExec)
+        ensures v@ == JsonValue::empty_object(),
+    {
+        JsonValueExec { inner: deps_hack::serde_json::Value::Object(deps_hack::serde_json::Map::new()) }
+    }
+
+    #[verifier(external_body)]
+    pub fn from_bool(b: bool) -> (v: JsonValueExec)
+        ensures v@ == JsonValue::Bool(b),
+    {
+        JsonValueExec { inner: deps_hack::serde_json::Value::Bool(b) }
+    }
+
+    #[verifier(external_body)]
+    pub fn from_i64(i: i64) -> (v: JsonValueExec)
+        ensures v@ == JsonValue::Number(i as int),
+    {
+        JsonValueExec { inner: deps_hack::serde_json::Value::from(i) }
+    }
+
+    #[verifier(external_body)]
+    pub fn from_string(s: String) -> (v: JsonValueExec)
+        ensures v@ == JsonValue::String(s@),
+    {
+        JsonValueExec { inner: deps_hack::serde_json::Value::String(s) }
+    }
+
+    #[verifier(external_body)]
+    pub fn from_vec(vec: Vec<JsonValueExec>) -> (v: JsonValueExec)
+        ensures v@ == JsonValue::Array(vec@.map_values(|e: JsonValueExec| e@)),
+    {
+        JsonValueExec { inner: deps_hack::serde_json::Value::Array(vec.into_iter().map(|e| e.inner).collect()) }
+    }
+
+    #[verifier(external_body)]
+    pub fn is_null(&self) -> (b: bool)
+        ensures b == self@.is_Null(),
+    {
+        self.inner.is_null()
+    }
+
+    #[verifier(external_body)]
+    pub fn is_object(&self) -> (b: bool)
+        ensures b == self@.is_Object(),
+    {
+        self.inner.is_object()
+    }
+
+    #[verifier(external_body)]
+    pub fn as_bool(&self) -> (b: Option<bool>)
+        ensures
+            self@.is_Bool() == b.is_Some(),
+            b.is_Some() ==> b.get_Some_0() == self@.get_Bool_0(),
+    {
+        self.inner.as_bool()
+    }
+
+    #[verifier(external_body)]
+    pub fn as_string(&self) -> (s: Option<String>)
+        ensures
+            self@.is_String() == s.is_Some(),
+            s.is_Some() ==> s.get_Some_0()@ == self@.get_String_0(),
+    {
+        self.inner.as_str().map(|s| s.to_string())
+    }
+
+    #[verifier(external_body)]
+    pub fn field(&self, key: &String) -> (v: Option<JsonValueExec>)
+        ensures option_view(v) == self@.field(key@),
+    {
+        self.inner.as_object().and_then(|o| o.get(key)).map(|v| JsonValueExec { inner: v.clone() })
+    }
+
+    #[verifier(external_body)]
+    pub fn set_field(&mut self, key: String, value: JsonValueExec)
+        ensures self@ == old(self)@.set_field(key@, value@),
+    {
+        if !self.inner.is_object() {
+            self.inner = deps_hack::serde_json::Value::Object(deps_hack::serde_json::Map::new());
+        }
+        self.inner.as_object_mut().unwrap().insert(key, value.inner);
+    }
+
+    #[verifier(external_body)]
+    pub fn remove_field(&mut self, key: &String)
+        ensures self@ == old(self)@.remove_field(key@),
+    {
+        if let Some(o) = self.inner.as_object_mut() {
+            o.remove(key);
+        }
+    }
+}
+
+#[verifier(external)]
+impl ResourceWrapper<deps_hack::serde_json::Value> for JsonValueExec {
+    fn from_kube(inner: deps_hack::serde_json::Value) -> JsonValueExec { JsonValueExec { inner: inner } }
+
+    fn into_kube(self) -> deps_hack::serde_json::Value { self.inner }
+}
+
+impl std::clone::Clone for JsonValueExec {
+    #[verifier(external_body)]
+    fn clone(&self) -> (result: Self)
+        ensures result@ == self@
+    { JsonValueExec { inner: self.inner.clone() } }
+}
+
+#[verifier(external)]
+impl std::fmt::Debug for JsonValueExec {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result { self.inner.fmt(f) }
+}
+
+}
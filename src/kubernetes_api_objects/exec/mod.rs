@@ -3,16 +3,25 @@
 pub mod affinity;
 pub mod api_method;
 pub mod api_resource;
+pub mod cluster_role;
+pub mod cluster_role_binding;
 pub mod common;
+pub mod condition;
 pub mod config_map;
 pub mod container;
 pub mod daemon_set;
 pub mod dynamic;
+pub mod event;
+pub mod job;
+pub mod json_value;
 pub mod label_selector;
+pub mod namespace;
 pub mod object_meta;
 pub mod owner_reference;
+pub mod persistent_volume;
 pub mod persistent_volume_claim;
 pub mod pod;
+pub mod pod_disruption_budget;
 pub mod pod_template_spec;
 pub mod preconditions;
 pub mod prelude;
@@ -24,6 +33,8 @@ pub mod secret;
 pub mod service;
 pub mod service_account;
 pub mod stateful_set;
+pub mod storage_class;
 pub mod toleration;
+pub mod topology_spread_constraint;
 pub mod volume;
 pub mod volume_resource_requirements;
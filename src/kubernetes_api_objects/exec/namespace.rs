@@ -0,0 +1,100 @@
+// Copyright 2022 VMware, Inc.
+// SPDX-License-Identifier: MIT
+use crate::kubernetes_api_objects::error::UnmarshalError;
+use crate::kubernetes_api_objects::exec::{
+    api_resource::*, dynamic::*, object_meta::*, resource::*,
+};
+use crate::kubernetes_api_objects::spec::{namespace::*, resource::*};
+use vstd::prelude::*;
+
+verus! {
+
+// Namespace provides a mechanism for isolating groups of resources within a single cluster.
+// Namespace objects are cluster-scoped, so their metadata does not carry a namespace.
+//
+// This definition is a wrapper of Namespace defined at
+// https://github.com/Arnavion/k8s-openapi/blob/v0.17.0/src/v1_26/api/core/v1/namespace.rs.
+// It is supposed to be used in exec controller code.
+//
+// More detailed information: https://kubernetes.io/docs/concepts/overview/working-with-objects/namespaces/.
+
+#[verifier(external_body)]
+pub struct Namespace {
+    inner: deps_hack::k8s_openapi::api::core::v1::Namespace,
+}
+
+impl View for Namespace {
+    type V = NamespaceView;
+
+    spec fn view(&self) -> NamespaceView;
+}
+
+impl Namespace {
+    #[verifier(external_body)]
+    pub fn default() -> (namespace: Namespace)
+        ensures namespace@ == NamespaceView::default(),
+    {
+        Namespace {
+            inner: deps_hack::k8s_openapi::api::core::v1::Namespace::default(),
+        }
+    }
+
+    #[verifier(external_body)]
+    pub fn metadata(&self) -> (metadata: ObjectMeta)
+        ensures metadata@ == self@.metadata,
+    {
+        ObjectMeta::from_kube(self.inner.metadata.clone())
+    }
+
+    #[verifier(external_body)]
+    pub fn set_metadata(&mut self, metadata: ObjectMeta)
+        ensures self@ == old(self)@.set_metadata(metadata@),
+    {
+        self.inner.metadata = metadata.into_kube();
+    }
+
+    #[verifier(external_body)]
+    pub fn clone(&self) -> (c: Self)
+        ensures c@ == self@,
+    {
+        Namespace { inner: self.inner.clone() }
+    }
+
+    #[verifier(external_body)]
+    pub fn api_resource() -> (res: ApiResource)
+        ensures res@.kind == NamespaceView::kind(),
+    {
+        ApiResource::from_kube(deps_hack::kube::api::ApiResource::erase::<deps_hack::k8s_openapi::api::core::v1::Namespace>(&()))
+    }
+
+    #[verifier(external_body)]
+    pub fn marshal(self) -> (obj: DynamicObject)
+        ensures obj@ == self@.marshal(),
+    {
+        DynamicObject::from_kube(deps_hack::k8s_openapi::serde_json::from_str(&deps_hack::k8s_openapi::serde_json::to_string(&self.inner).unwrap()).unwrap())
+    }
+
+    #[verifier(external_body)]
+    pub fn unmarshal(obj: DynamicObject) -> (res: Result<Namespace, UnmarshalError>)
+        ensures
+            res.is_Ok() == NamespaceView::unmarshal(obj@).is_Ok(),
+            res.is_Ok() ==> res.get_Ok_0()@ == NamespaceView::unmarshal(obj@).get_Ok_0(),
+    {
+        let parse_result = obj.into_kube().try_parse::<deps_hack::k8s_openapi::api::core::v1::Namespace>();
+        if parse_result.is_ok() {
+            let res = Namespace { inner: parse_result.unwrap() };
+            Ok(res)
+        } else {
+            Err(())
+        }
+    }
+}
+
+#[verifier(external)]
+impl ResourceWrapper<deps_hack::k8s_openapi::api::core::v1::Namespace> for Namespace {
+    fn from_kube(inner: deps_hack::k8s_openapi::api::core::v1::Namespace) -> Namespace { Namespace { inner: inner } }
+
+    fn into_kube(self) -> deps_hack::k8s_openapi::api::core::v1::Namespace { self.inner }
+}
+
+}
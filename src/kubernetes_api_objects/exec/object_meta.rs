@@ -141,6 +141,15 @@ impl ObjectMeta {
         self.inner.resource_version.clone()
     }
 
+    #[verifier(external_body)]
+    pub fn generation(&self) -> (generation: Option<i64>)
+        ensures
+            self@.generation.is_Some() == generation.is_Some(),
+            generation.is_Some() ==> generation.get_Some_0() as int == self@.generation.get_Some_0(),
+    {
+        self.inner.generation
+    }
+
     #[verifier(external_body)]
     pub fn has_some_resource_version(&self) -> (b: bool)
         ensures
@@ -0,0 +1,175 @@
+// Copyright 2022 VMware, Inc.
+// SPDX-License-Identifier: MIT
+use crate::kubernetes_api_objects::error::UnmarshalError;
+use crate::kubernetes_api_objects::exec::{
+    api_resource::*, dynamic::*, object_meta::*, resource::*,
+};
+use crate::kubernetes_api_objects::spec::{persistent_volume::*, resource::*};
+use crate::vstd_ext::string_map::*;
+use vstd::prelude::*;
+
+verus! {
+
+// PersistentVolume is a piece of storage in the cluster that has been provisioned by an administrator
+// or dynamically provisioned using a StorageClass. PersistentVolume objects are cluster-scoped.
+//
+// This definition is a wrapper of PersistentVolume defined at
+// https://github.com/Arnavion/k8s-openapi/blob/v0.17.0/src/v1_26/api/core/v1/persistent_volume.rs.
+// It is supposed to be used in exec controller code.
+//
+// More detailed information: https://kubernetes.io/docs/concepts/storage/persistent-volumes/.
+
+#[verifier(external_body)]
+pub struct PersistentVolume {
+    inner: deps_hack::k8s_openapi::api::core::v1::PersistentVolume,
+}
+
+impl View for PersistentVolume {
+    type V = PersistentVolumeView;
+
+    spec fn view(&self) -> PersistentVolumeView;
+}
+
+impl PersistentVolume {
+    #[verifier(external_body)]
+    pub fn default() -> (pv: PersistentVolume)
+        ensures pv@ == PersistentVolumeView::default(),
+    {
+        PersistentVolume {
+            inner: deps_hack::k8s_openapi::api::core::v1::PersistentVolume::default(),
+        }
+    }
+
+    #[verifier(external_body)]
+    pub fn metadata(&self) -> (metadata: ObjectMeta)
+        ensures metadata@ == self@.metadata,
+    {
+        ObjectMeta::from_kube(self.inner.metadata.clone())
+    }
+
+    #[verifier(external_body)]
+    pub fn spec(&self) -> (spec: Option<PersistentVolumeSpec>)
+        ensures
+            self@.spec.is_Some() == spec.is_Some(),
+            spec.is_Some() ==> spec.get_Some_0()@ == self@.spec.get_Some_0(),
+    {
+        match &self.inner.spec {
+            Some(s) => Some(PersistentVolumeSpec::from_kube(s.clone())),
+            None => None,
+        }
+    }
+
+    #[verifier(external_body)]
+    pub fn set_metadata(&mut self, metadata: ObjectMeta)
+        ensures self@ == old(self)@.set_metadata(metadata@),
+    {
+        self.inner.metadata = metadata.into_kube();
+    }
+
+    #[verifier(external_body)]
+    pub fn set_spec(&mut self, spec: PersistentVolumeSpec)
+        ensures self@ == old(self)@.set_spec(spec@),
+    {
+        self.inner.spec = Some(spec.into_kube());
+    }
+
+    #[verifier(external_body)]
+    pub fn api_resource() -> (res: ApiResource)
+        ensures res@.kind == PersistentVolumeView::kind(),
+    {
+        ApiResource::from_kube(deps_hack::kube::api::ApiResource::erase::<deps_hack::k8s_openapi::api::core::v1::PersistentVolume>(&()))
+    }
+
+    #[verifier(external_body)]
+    pub fn marshal(self) -> (obj: DynamicObject)
+        ensures obj@ == self@.marshal(),
+    {
+        DynamicObject::from_kube(deps_hack::k8s_openapi::serde_json::from_str(&deps_hack::k8s_openapi::serde_json::to_string(&self.inner).unwrap()).unwrap())
+    }
+
+    #[verifier(external_body)]
+    pub fn unmarshal(obj: DynamicObject) -> (res: Result<PersistentVolume, UnmarshalError>)
+        ensures
+            res.is_Ok() == PersistentVolumeView::unmarshal(obj@).is_Ok(),
+            res.is_Ok() ==> res.get_Ok_0()@ == PersistentVolumeView::unmarshal(obj@).get_Ok_0(),
+    {
+        let parse_result = obj.into_kube().try_parse::<deps_hack::k8s_openapi::api::core::v1::PersistentVolume>();
+        if parse_result.is_ok() {
+            let res = PersistentVolume { inner: parse_result.unwrap() };
+            Ok(res)
+        } else {
+            Err(())
+        }
+    }
+}
+
+#[verifier(external)]
+impl ResourceWrapper<deps_hack::k8s_openapi::api::core::v1::PersistentVolume> for PersistentVolume {
+    fn from_kube(inner: deps_hack::k8s_openapi::api::core::v1::PersistentVolume) -> PersistentVolume { PersistentVolume { inner: inner } }
+
+    fn into_kube(self) -> deps_hack::k8s_openapi::api::core::v1::PersistentVolume { self.inner }
+}
+
+#[verifier(external_body)]
+pub struct PersistentVolumeSpec {
+    inner: deps_hack::k8s_openapi::api::core::v1::PersistentVolumeSpec,
+}
+
+impl PersistentVolumeSpec {
+    pub spec fn view(&self) -> PersistentVolumeSpecView;
+
+    #[verifier(external_body)]
+    pub fn default() -> (pv_spec: PersistentVolumeSpec)
+        ensures pv_spec@ == PersistentVolumeSpecView::default(),
+    {
+        PersistentVolumeSpec {
+            inner: deps_hack::k8s_openapi::api::core::v1::PersistentVolumeSpec::default(),
+        }
+    }
+
+    #[verifier(external_body)]
+    pub fn clone(&self) -> (pv_spec: PersistentVolumeSpec)
+        ensures pv_spec@ == self@,
+    {
+        PersistentVolumeSpec { inner: self.inner.clone() }
+    }
+
+    #[verifier(external_body)]
+    pub fn set_access_modes(&mut self, access_modes: Vec<String>)
+        ensures self@ == old(self)@.set_access_modes(access_modes@.map_values(|mode: String| mode@)),
+    {
+        self.inner.access_modes = Some(access_modes);
+    }
+
+    #[verifier(external_body)]
+    pub fn set_capacity(&mut self, capacity: StringMap)
+        ensures self@ == old(self)@.set_capacity(capacity@),
+    {
+        self.inner.capacity = Some(capacity.into_rust_map().into_iter().map(|(k, v)| (k, deps_hack::k8s_openapi::apimachinery::pkg::api::resource::Quantity(v))).collect());
+    }
+
+    #[verifier(external_body)]
+    pub fn set_persistent_volume_reclaim_policy(&mut self, persistent_volume_reclaim_policy: String)
+        ensures self@ == old(self)@.set_persistent_volume_reclaim_policy(persistent_volume_reclaim_policy@),
+    {
+        self.inner.persistent_volume_reclaim_policy = Some(persistent_volume_reclaim_policy);
+    }
+
+    #[verifier(external_body)]
+    pub fn set_storage_class_name(&mut self, storage_class_name: String)
+        ensures self@ == old(self)@.set_storage_class_name(storage_class_name@),
+    {
+        self.inner.storage_class_name = Some(storage_class_name);
+    }
+}
+
+#[verifier(external)]
+impl ResourceWrapper<deps_hack::k8s_openapi::api::core::v1::PersistentVolumeSpec> for PersistentVolumeSpec {
+    fn from_kube(inner: deps_hack::k8s_openapi::api::core::v1::PersistentVolumeSpec) -> PersistentVolumeSpec {
+        PersistentVolumeSpec { inner: inner }
+    }
+
+    fn into_kube(self) -> deps_hack::k8s_openapi::api::core::v1::PersistentVolumeSpec { self.inner }
+}
+
+}
@@ -65,6 +65,30 @@ impl PersistentVolumeClaim {
         }
     }
 
+    #[verifier(external_body)]
+    pub fn status(&self) -> (status: Option<PersistentVolumeClaimStatus>)
+        ensures
+            self@.status.is_Some() == status.is_Some(),
+            status.is_Some() ==> status.get_Some_0()@ == self@.status.get_Some_0(),
+    {
+        match &self.inner.status {
+            Some(s) => Some(PersistentVolumeClaimStatus::from_kube(s.clone())),
+            None => None,
+        }
+    }
+
+    pub fn bound(&self) -> (bound: bool)
+        ensures bound == self@.bound(),
+    {
+        match self.status() {
+            Some(status) => match status.phase() {
+                Some(phase) => phase.eq(&"Bound".to_string()),
+                None => false,
+            },
+            None => false,
+        }
+    }
+
     #[verifier(external_body)]
     pub fn set_metadata(&mut self, metadata: ObjectMeta)
         ensures
@@ -172,4 +196,29 @@ impl ResourceWrapper<deps_hack::k8s_openapi::api::core::v1::PersistentVolumeClai
     fn into_kube(self) -> deps_hack::k8s_openapi::api::core::v1::PersistentVolumeClaimSpec { self.inner }
 }
 
+#[verifier(external_body)]
+pub struct PersistentVolumeClaimStatus {
+    inner: deps_hack::k8s_openapi::api::core::v1::PersistentVolumeClaimStatus,
+}
+
+impl PersistentVolumeClaimStatus {
+    pub spec fn view(&self) -> PersistentVolumeClaimStatusView;
+
+    #[verifier(external_body)]
+    pub fn phase(&self) -> (phase: Option<String>)
+        ensures
+            self@.phase.is_Some() == phase.is_Some(),
+            phase.is_Some() ==> phase.get_Some_0()@ == self@.phase.get_Some_0(),
+    {
+        self.inner.phase.clone()
+    }
+}
+
+#[verifier(external)]
+impl ResourceWrapper<deps_hack::k8s_openapi::api::core::v1::PersistentVolumeClaimStatus> for PersistentVolumeClaimStatus {
+    fn from_kube(inner: deps_hack::k8s_openapi::api::core::v1::PersistentVolumeClaimStatus) -> PersistentVolumeClaimStatus { PersistentVolumeClaimStatus { inner: inner } }
+
+    fn into_kube(self) -> deps_hack::k8s_openapi::api::core::v1::PersistentVolumeClaimStatus { self.inner }
+}
+
 }
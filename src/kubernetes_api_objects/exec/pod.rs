@@ -3,7 +3,7 @@
 use crate::kubernetes_api_objects::error::UnmarshalError;
 use crate::kubernetes_api_objects::exec::{
     affinity::*, api_resource::*, container::*, dynamic::*, object_meta::*, resource::*,
-    toleration::*, volume::*,
+    toleration::*, topology_spread_constraint::*, volume::*,
 };
 use crate::kubernetes_api_objects::spec::{pod::*, resource::*};
 use crate::vstd_ext::string_map::*;
@@ -190,6 +190,13 @@ impl PodSpec {
         self.inner.tolerations = Some(tolerations.into_iter().map(|toleration: Toleration| toleration.into_kube()).collect())
     }
 
+    #[verifier(external_body)]
+    pub fn set_topology_spread_constraints(&mut self, topology_spread_constraints: Vec<TopologySpreadConstraint>)
+        ensures self@ == old(self)@.set_topology_spread_constraints(topology_spread_constraints@.map_values(|constraint: TopologySpreadConstraint| constraint@)),
+    {
+        self.inner.topology_spread_constraints = Some(topology_spread_constraints.into_iter().map(|constraint: TopologySpreadConstraint| constraint.into_kube()).collect())
+    }
+
     #[verifier(external_body)]
     pub fn set_node_selector(&mut self, node_selector: StringMap)
         ensures self@ == old(self)@.set_node_selector(node_selector@),
@@ -253,6 +260,29 @@ impl PodSpec {
         self.inner.image_pull_secrets = Some(image_pull_secrets.into_iter().map(|r: LocalObjectReference| r.into_kube()).collect())
     }
 
+    #[verifier(external_body)]
+    pub fn set_restart_policy(&mut self, restart_policy: String)
+        ensures self@ == old(self)@.set_restart_policy(restart_policy@),
+    {
+        self.inner.restart_policy = Some(restart_policy)
+    }
+
+    #[verifier(external_body)]
+    pub fn node_name(&self) -> (node_name: Option<String>)
+        ensures
+            self@.node_name.is_Some() == node_name.is_Some(),
+            node_name.is_Some() ==> node_name.get_Some_0()@ == self@.node_name.get_Some_0(),
+    {
+        self.inner.node_name.clone()
+    }
+
+    #[verifier(external_body)]
+    pub fn set_node_name(&mut self, node_name: String)
+        ensures self@ == old(self)@.set_node_name(node_name@),
+    {
+        self.inner.node_name = Some(node_name)
+    }
+
     #[verifier(external)]
     pub fn from_kube(inner: deps_hack::k8s_openapi::api::core::v1::PodSpec) -> PodSpec { PodSpec { inner: inner } }
 
@@ -271,6 +301,57 @@ impl View for PodSecurityContext {
     spec fn view(&self) -> PodSecurityContextView;
 }
 
+impl PodSecurityContext {
+    #[verifier(external_body)]
+    pub fn default() -> (pod_security_context: PodSecurityContext)
+        ensures pod_security_context@ == PodSecurityContextView::default(),
+    {
+        PodSecurityContext { inner: deps_hack::k8s_openapi::api::core::v1::PodSecurityContext::default() }
+    }
+
+    #[verifier(external_body)]
+    pub fn clone(&self) -> (pod_security_context: PodSecurityContext)
+        ensures pod_security_context@ == self@,
+    {
+        PodSecurityContext { inner: self.inner.clone() }
+    }
+
+    #[verifier(external_body)]
+    pub fn set_fs_group(&mut self, fs_group: i64)
+        ensures self@ == old(self)@.set_fs_group(fs_group as int),
+    {
+        self.inner.fs_group = Some(fs_group)
+    }
+
+    #[verifier(external_body)]
+    pub fn set_run_as_group(&mut self, run_as_group: i64)
+        ensures self@ == old(self)@.set_run_as_group(run_as_group as int),
+    {
+        self.inner.run_as_group = Some(run_as_group)
+    }
+
+    #[verifier(external_body)]
+    pub fn set_run_as_non_root(&mut self, run_as_non_root: bool)
+        ensures self@ == old(self)@.set_run_as_non_root(run_as_non_root),
+    {
+        self.inner.run_as_non_root = Some(run_as_non_root)
+    }
+
+    #[verifier(external_body)]
+    pub fn set_run_as_user(&mut self, run_as_user: i64)
+        ensures self@ == old(self)@.set_run_as_user(run_as_user as int),
+    {
+        self.inner.run_as_user = Some(run_as_user)
+    }
+
+    #[verifier(external_body)]
+    pub fn set_seccomp_profile(&mut self, seccomp_profile: SeccompProfile)
+        ensures self@ == old(self)@.set_seccomp_profile(seccomp_profile@),
+    {
+        self.inner.seccomp_profile = Some(seccomp_profile.into_kube())
+    }
+}
+
 #[verifier(external)]
 impl ResourceWrapper<deps_hack::k8s_openapi::api::core::v1::PodSecurityContext> for PodSecurityContext {
     fn from_kube(inner: deps_hack::k8s_openapi::api::core::v1::PodSecurityContext) -> PodSecurityContext { PodSecurityContext { inner: inner } }
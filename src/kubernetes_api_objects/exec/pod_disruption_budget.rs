@@ -0,0 +1,205 @@
+// Copyright 2022 VMware, Inc.
+// SPDX-License-Identifier: MIT
+use crate::kubernetes_api_objects::error::UnmarshalError;
+use crate::kubernetes_api_objects::exec::{
+    api_resource::*, dynamic::*, label_selector::*, object_meta::*, resource::*,
+};
+use crate::kubernetes_api_objects::spec::{pod_disruption_budget::*, resource::*};
+use vstd::prelude::*;
+
+verus! {
+
+// PodDisruptionBudget limits the number of pods of a replicated application that are down simultaneously
+// from voluntary disruptions, such as node drains.
+//
+// This definition is a wrapper of PodDisruptionBudget defined at
+// https://github.com/Arnavion/k8s-openapi/blob/v0.17.0/src/v1_26/api/policy/v1/pod_disruption_budget.rs.
+// It is supposed to be used in exec controller code.
+//
+// More detailed information: https://kubernetes.io/docs/concepts/workloads/pods/disruptions/.
+
+#[verifier(external_body)]
+pub struct PodDisruptionBudget {
+    inner: deps_hack::k8s_openapi::api::policy::v1::PodDisruptionBudget,
+}
+
+impl View for PodDisruptionBudget {
+    type V = PodDisruptionBudgetView;
+
+    spec fn view(&self) -> PodDisruptionBudgetView;
+}
+
+impl PodDisruptionBudget {
+    #[verifier(external_body)]
+    pub fn default() -> (pdb: PodDisruptionBudget)
+        ensures pdb@ == PodDisruptionBudgetView::default(),
+    {
+        PodDisruptionBudget {
+            inner: deps_hack::k8s_openapi::api::policy::v1::PodDisruptionBudget::default(),
+        }
+    }
+
+    #[verifier(external_body)]
+    pub fn clone(&self) -> (pdb: PodDisruptionBudget)
+        ensures pdb@ == self@,
+    {
+        PodDisruptionBudget { inner: self.inner.clone() }
+    }
+
+    #[verifier(external_body)]
+    pub fn metadata(&self) -> (metadata: ObjectMeta)
+        ensures metadata@ == self@.metadata,
+    {
+        ObjectMeta::from_kube(self.inner.metadata.clone())
+    }
+
+    #[verifier(external_body)]
+    pub fn spec(&self) -> (spec: Option<PodDisruptionBudgetSpec>)
+        ensures
+            self@.spec.is_Some() == spec.is_Some(),
+            spec.is_Some() ==> spec.get_Some_0()@ == self@.spec.get_Some_0(),
+    {
+        match &self.inner.spec {
+            Some(s) => Some(PodDisruptionBudgetSpec::from_kube(s.clone())),
+            None => None,
+        }
+    }
+
+    #[verifier(external_body)]
+    pub fn set_metadata(&mut self, metadata: ObjectMeta)
+        ensures self@ == old(self)@.set_metadata(metadata@),
+    {
+        self.inner.metadata = metadata.into_kube();
+    }
+
+    #[verifier(external_body)]
+    pub fn set_spec(&mut self, spec: PodDisruptionBudgetSpec)
+        ensures self@ == old(self)@.set_spec(spec@),
+    {
+        self.inner.spec = Some(spec.into_kube());
+    }
+
+    #[verifier(external_body)]
+    pub fn api_resource() -> (res: ApiResource)
+        ensures res@.kind == PodDisruptionBudgetView::kind(),
+    {
+        ApiResource::from_kube(deps_hack::kube::api::ApiResource::erase::<deps_hack::k8s_openapi::api::policy::v1::PodDisruptionBudget>(&()))
+    }
+
+    #[verifier(external_body)]
+    pub fn marshal(self) -> (obj: DynamicObject)
+        ensures obj@ == self@.marshal(),
+    {
+        DynamicObject::from_kube(deps_hack::k8s_openapi::serde_json::from_str(&deps_hack::k8s_openapi::serde_json::to_string(&self.inner).unwrap()).unwrap())
+    }
+
+    #[verifier(external_body)]
+    pub fn unmarshal(obj: DynamicObject) -> (res: Result<PodDisruptionBudget, UnmarshalError>)
+        ensures
+            res.is_Ok() == PodDisruptionBudgetView::unmarshal(obj@).is_Ok(),
+            res.is_Ok() ==> res.get_Ok_0()@ == PodDisruptionBudgetView::unmarshal(obj@).get_Ok_0(),
+    {
+        let parse_result = obj.into_kube().try_parse::<deps_hack::k8s_openapi::api::policy::v1::PodDisruptionBudget>();
+        if parse_result.is_ok() {
+            let res = PodDisruptionBudget { inner: parse_result.unwrap() };
+            Ok(res)
+        } else {
+            Err(())
+        }
+    }
+}
+
+#[verifier(external)]
+impl ResourceWrapper<deps_hack::k8s_openapi::api::policy::v1::PodDisruptionBudget> for PodDisruptionBudget {
+    fn from_kube(inner: deps_hack::k8s_openapi::api::policy::v1::PodDisruptionBudget) -> PodDisruptionBudget { PodDisruptionBudget { inner: inner } }
+
+    fn into_kube(self) -> deps_hack::k8s_openapi::api::policy::v1::PodDisruptionBudget { self.inner }
+}
+
+#[verifier(external_body)]
+pub struct PodDisruptionBudgetSpec {
+    inner: deps_hack::k8s_openapi::api::policy::v1::PodDisruptionBudgetSpec,
+}
+
+impl PodDisruptionBudgetSpec {
+    pub spec fn view(&self) -> PodDisruptionBudgetSpecView;
+
+    #[verifier(external_body)]
+    pub fn default() -> (pdb_spec: PodDisruptionBudgetSpec)
+        ensures pdb_spec@ == PodDisruptionBudgetSpecView::default(),
+    {
+        PodDisruptionBudgetSpec {
+            inner: deps_hack::k8s_openapi::api::policy::v1::PodDisruptionBudgetSpec::default(),
+        }
+    }
+
+    #[verifier(external_body)]
+    pub fn clone(&self) -> (pdb_spec: PodDisruptionBudgetSpec)
+        ensures pdb_spec@ == self@,
+    {
+        PodDisruptionBudgetSpec { inner: self.inner.clone() }
+    }
+
+    #[verifier(external_body)]
+    pub fn eq(&self, other: &Self) -> (b: bool)
+        ensures b == (self.view() == other.view())
+    {
+        self.inner == other.inner
+    }
+
+    #[verifier(external_body)]
+    pub fn min_available(&self) -> (min_available: Option<i32>)
+        ensures
+            self@.min_available.is_Some() == min_available.is_Some(),
+            min_available.is_Some() ==> min_available.get_Some_0() as int == self@.min_available.get_Some_0(),
+    {
+        match &self.inner.min_available {
+            Some(deps_hack::k8s_openapi::apimachinery::pkg::util::intstr::IntOrString::Int(i)) => Some(*i),
+            _ => None,
+        }
+    }
+
+    #[verifier(external_body)]
+    pub fn max_unavailable(&self) -> (max_unavailable: Option<i32>)
+        ensures
+            self@.max_unavailable.is_Some() == max_unavailable.is_Some(),
+            max_unavailable.is_Some() ==> max_unavailable.get_Some_0() as int == self@.max_unavailable.get_Some_0(),
+    {
+        match &self.inner.max_unavailable {
+            Some(deps_hack::k8s_openapi::apimachinery::pkg::util::intstr::IntOrString::Int(i)) => Some(*i),
+            _ => None,
+        }
+    }
+
+    #[verifier(external_body)]
+    pub fn set_min_available(&mut self, min_available: i32)
+        ensures self@ == old(self)@.set_min_available(min_available as int),
+    {
+        self.inner.min_available = Some(deps_hack::k8s_openapi::apimachinery::pkg::util::intstr::IntOrString::Int(min_available));
+    }
+
+    #[verifier(external_body)]
+    pub fn set_max_unavailable(&mut self, max_unavailable: i32)
+        ensures self@ == old(self)@.set_max_unavailable(max_unavailable as int),
+    {
+        self.inner.max_unavailable = Some(deps_hack::k8s_openapi::apimachinery::pkg::util::intstr::IntOrString::Int(max_unavailable));
+    }
+
+    #[verifier(external_body)]
+    pub fn set_selector(&mut self, selector: LabelSelector)
+        ensures self@ == old(self)@.set_selector(selector@),
+    {
+        self.inner.selector = Some(selector.into_kube());
+    }
+}
+
+#[verifier(external)]
+impl ResourceWrapper<deps_hack::k8s_openapi::api::policy::v1::PodDisruptionBudgetSpec> for PodDisruptionBudgetSpec {
+    fn from_kube(inner: deps_hack::k8s_openapi::api::policy::v1::PodDisruptionBudgetSpec) -> PodDisruptionBudgetSpec {
+        PodDisruptionBudgetSpec { inner: inner }
+    }
+
+    fn into_kube(self) -> deps_hack::k8s_openapi::api::policy::v1::PodDisruptionBudgetSpec { self.inner }
+}
+
+}
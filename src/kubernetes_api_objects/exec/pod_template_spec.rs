@@ -23,6 +23,13 @@ impl PodTemplateSpec {
         }
     }
 
+    #[verifier(external_body)]
+    pub fn eq(&self, other: &Self) -> (b: bool)
+        ensures b == (self.view() == other.view())
+    {
+        self.inner == other.inner
+    }
+
     #[verifier(external_body)]
     pub fn clone(&self) -> (pod_template_spec: PodTemplateSpec)
         ensures pod_template_spec@ == self@,
@@ -1,14 +1,22 @@
 // Copyright 2022 VMware, Inc.
 // SPDX-License-Identifier: MIT
 pub use crate::kubernetes_api_objects::exec::api_method::*;
+pub use crate::kubernetes_api_objects::exec::cluster_role::*;
+pub use crate::kubernetes_api_objects::exec::cluster_role_binding::*;
 pub use crate::kubernetes_api_objects::exec::common::*;
 pub use crate::kubernetes_api_objects::exec::config_map::*;
 pub use crate::kubernetes_api_objects::exec::daemon_set::*;
 pub use crate::kubernetes_api_objects::exec::dynamic::*;
+pub use crate::kubernetes_api_objects::exec::event::*;
+pub use crate::kubernetes_api_objects::exec::job::*;
+pub use crate::kubernetes_api_objects::exec::json_value::*;
+pub use crate::kubernetes_api_objects::exec::namespace::*;
 pub use crate::kubernetes_api_objects::exec::object_meta::*;
 pub use crate::kubernetes_api_objects::exec::owner_reference::*;
+pub use crate::kubernetes_api_objects::exec::persistent_volume::*;
 pub use crate::kubernetes_api_objects::exec::persistent_volume_claim::*;
 pub use crate::kubernetes_api_objects::exec::pod::*;
+pub use crate::kubernetes_api_objects::exec::pod_disruption_budget::*;
 pub use crate::kubernetes_api_objects::exec::preconditions::*;
 pub use crate::kubernetes_api_objects::exec::resource::*;
 pub use crate::kubernetes_api_objects::exec::role::*;
@@ -17,3 +25,4 @@ pub use crate::kubernetes_api_objects::exec::secret::*;
 pub use crate::kubernetes_api_objects::exec::service::*;
 pub use crate::kubernetes_api_objects::exec::service_account::*;
 pub use crate::kubernetes_api_objects::exec::stateful_set::*;
+pub use crate::kubernetes_api_objects::exec::storage_class::*;
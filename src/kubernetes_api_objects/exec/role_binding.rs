@@ -51,6 +51,18 @@ impl RoleBinding {
         RoleRef::from_kube(self.inner.role_ref.clone())
     }
 
+    #[verifier(external_body)]
+    pub fn subjects(&self) -> (subjects: Option<Vec<Subject>>)
+        ensures
+            self@.subjects.is_Some() == subjects.is_Some(),
+            subjects.is_Some() ==> subjects.get_Some_0()@.map_values(|s: Subject| s@) == self@.subjects.get_Some_0(),
+    {
+        match &self.inner.subjects {
+            Some(s) => Some(s.into_iter().map(|item| Subject::from_kube(item.clone())).collect()),
+            None => None,
+        }
+    }
+
     #[verifier(external_body)]
     pub fn set_metadata(&mut self, metadata: ObjectMeta)
         ensures self@ == old(self)@.set_metadata(metadata@),
@@ -206,6 +218,52 @@ impl Subject {
         }
     }
 
+    #[verifier(external_body)]
+    pub fn clone(&self) -> (c: Self)
+        ensures c@ == self@,
+    {
+        Subject { inner: self.inner.clone() }
+    }
+
+    #[verifier(external_body)]
+    pub fn api_group(&self) -> (api_group: Option<String>)
+        ensures
+            self@.api_group.is_Some() == api_group.is_Some(),
+            api_group.is_Some() ==> api_group.get_Some_0()@ == self@.api_group.get_Some_0(),
+    {
+        self.inner.api_group.clone()
+    }
+
+    #[verifier(external_body)]
+    pub fn kind(&self) -> (kind: String)
+        ensures kind@ == self@.kind,
+    {
+        self.inner.kind.clone()
+    }
+
+    #[verifier(external_body)]
+    pub fn name(&self) -> (name: String)
+        ensures name@ == self@.name,
+    {
+        self.inner.name.clone()
+    }
+
+    #[verifier(external_body)]
+    pub fn namespace(&self) -> (namespace: Option<String>)
+        ensures
+            self@.namespace.is_Some() == namespace.is_Some(),
+            namespace.is_Some() ==> namespace.get_Some_0()@ == self@.namespace.get_Some_0(),
+    {
+        self.inner.namespace.clone()
+    }
+
+    #[verifier(external_body)]
+    pub fn set_api_group(&mut self, api_group: String)
+        ensures self@ == old(self)@.set_api_group(api_group@),
+    {
+        self.inner.api_group = Some(api_group);
+    }
+
     #[verifier(external_body)]
     pub fn set_kind(&mut self, kind: String)
         ensures self@ == old(self)@.set_kind(kind@),
@@ -5,7 +5,7 @@ use crate::kubernetes_api_objects::exec::{
     api_resource::*, dynamic::*, object_meta::*, resource::*,
 };
 use crate::kubernetes_api_objects::spec::{resource::*, secret::*};
-use crate::vstd_ext::string_map::*;
+use crate::vstd_ext::{byte_string_map::*, string_map::*};
 use vstd::prelude::*;
 
 verus! {
@@ -49,21 +49,25 @@ impl Secret {
     }
 
     #[verifier(external_body)]
-    pub fn data(&self) -> (data: Option<StringMap>)
+    pub fn data(&self) -> (data: Option<ByteStringMap>)
         ensures
             self@.data.is_Some() == data.is_Some(),
             data.is_Some() ==> data.get_Some_0()@ == self@.data.get_Some_0(),
     {
         match &self.inner.data {
-            Some(d) => {
-                let binary_map = d.clone();
-                let mut string_map = std::collections::BTreeMap::new();
-                for (key, value) in binary_map {
-                    // NOTE: unwrap might panic here if value.0 is invalid utf8 sequence!
-                    string_map.insert(key, std::string::String::from_utf8(value.0).unwrap());
-                }
-                Some(StringMap::from_rust_map(string_map))
-            },
+            Some(d) => Some(ByteStringMap::from_rust_map(d.iter().map(|(k, v)| (k.clone(), v.0.clone())).collect())),
+            None => None,
+        }
+    }
+
+    #[verifier(external_body)]
+    pub fn string_data(&self) -> (string_data: Option<StringMap>)
+        ensures
+            self@.string_data.is_Some() == string_data.is_Some(),
+            string_data.is_Some() ==> string_data.get_Some_0()@ == self@.string_data.get_Some_0(),
+    {
+        match &self.inner.string_data {
+            Some(d) => Some(StringMap::from_rust_map(d.clone())),
             None => None,
         }
     }
@@ -75,17 +79,33 @@ impl Secret {
         self.inner.metadata = metadata.into_kube();
     }
 
-    // TODO: data is a map of string to bytestring. May support it in the future.
+    // Stores the UTF-8 encoding of each value of data.
     #[verifier(external_body)]
     pub fn set_data(&mut self, data: StringMap)
         ensures self@ == old(self)@.set_data(data@),
     {
-        let string_map = data.into_rust_map();
-        let mut binary_map = std::collections::BTreeMap::new();
-        for (key, value) in string_map {
-            binary_map.insert(key, deps_hack::k8s_openapi::ByteString(value.into_bytes()));
-        }
-        self.inner.data = Some(binary_map)
+        self.set_binary_data(ByteStringMap::from_string_map(data))
+    }
+
+    #[verifier(external_body)]
+    pub fn set_binary_data(&mut self, data: ByteStringMap)
+        ensures self@ == old(self)@.set_binary_data(data@),
+    {
+        self.inner.data = Some(data.into_rust_map().into_iter().map(|(k, v)| (k, deps_hack::k8s_openapi::ByteString(v))).collect())
+    }
+
+    #[verifier(external_body)]
+    pub fn set_string_data(&mut self, string_data: StringMap)
+        ensures self@ == old(self)@.set_string_data(string_data@),
+    {
+        self.inner.string_data = Some(string_data.into_rust_map())
+    }
+
+    #[verifier(external_body)]
+    pub fn unset_string_data(&mut self)
+        ensures self@ == old(self)@.unset_string_data(),
+    {
+        self.inner.string_data = None
     }
 
     #[verifier(external_body)]
@@ -108,6 +128,8 @@ impl Secret {
         ApiResource::from_kube(deps_hack::kube::api::ApiResource::erase::<deps_hack::k8s_openapi::api::core::v1::Secret>(&()))
     }
 
+    // The values of data are base64 encoded by the serializer of ByteString,
+    // which is what base64_encode in the spec of marshal_spec stands for.
     #[verifier(external_body)]
     pub fn marshal(self) -> (obj: DynamicObject)
         ensures obj@ == self@.marshal(),
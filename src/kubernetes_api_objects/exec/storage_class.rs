@@ -0,0 +1,155 @@
+// Copyright 2022 VMware, Inc.
+// SPDX-License-Identifier: MIT
+use crate::kubernetes_api_objects::error::UnmarshalError;
+use crate::kubernetes_api_objects::exec::{
+    api_resource::*, dynamic::*, object_meta::*, resource::*,
+};
+use crate::kubernetes_api_objects::spec::{resource::*, storage_class::*};
+use crate::vstd_ext::string_map::*;
+use vstd::prelude::*;
+
+verus! {
+
+// StorageClass describes the parameters for a class of storage for which PersistentVolumes can be dynamically provisioned.
+// StorageClass objects are cluster-scoped.
+//
+// This definition is a wrapper of StorageClass defined at
+// https://github.com/Arnavion/k8s-openapi/blob/v0.17.0/src/v1_26/api/storage/v1/storage_class.rs.
+// It is supposed to be used in exec controller code.
+//
+// More detailed information: https://kubernetes.io/docs/concepts/storage/storage-classes/.
+
+#[verifier(external_body)]
+pub struct StorageClass {
+    inner: deps_hack::k8s_openapi::api::storage::v1::StorageClass,
+}
+
+impl View for StorageClass {
+    type V = StorageClassView;
+
+    spec fn view(&self) -> StorageClassView;
+}
+
+impl StorageClass {
+    #[verifier(external_body)]
+    pub fn default() -> (storage_class: StorageClass)
+        ensures storage_class@ == StorageClassView::default(),
+    {
+        StorageClass {
+            inner: deps_hack::k8s_openapi::api::storage::v1::StorageClass::default(),
+        }
+    }
+
+    #[verifier(external_body)]
+    pub fn clone(&self) -> (c: Self)
+        ensures c@ == self@,
+    {
+        StorageClass { inner: self.inner.clone() }
+    }
+
+    #[verifier(external_body)]
+    pub fn metadata(&self) -> (metadata: ObjectMeta)
+        ensures metadata@ == self@.metadata,
+    {
+        ObjectMeta::from_kube(self.inner.metadata.clone())
+    }
+
+    #[verifier(external_body)]
+    pub fn provisioner(&self) -> (provisioner: String)
+        ensures provisioner@ == self@.provisioner,
+    {
+        self.inner.provisioner.clone()
+    }
+
+    #[verifier(external_body)]
+    pub fn parameters(&self) -> (parameters: Option<StringMap>)
+        ensures
+            self@.parameters.is_Some() == parameters.is_Some(),
+            parameters.is_Some() ==> parameters.get_Some_0()@ == self@.parameters.get_Some_0(),
+    {
+        match &self.inner.parameters {
+            Some(p) => Some(StringMap::from_rust_map(p.clone())),
+            None => None,
+        }
+    }
+
+    #[verifier(external_body)]
+    pub fn set_metadata(&mut self, metadata: ObjectMeta)
+        ensures self@ == old(self)@.set_metadata(metadata@),
+    {
+        self.inner.metadata = metadata.into_kube();
+    }
+
+    #[verifier(external_body)]
+    pub fn set_provisioner(&mut self, provisioner: String)
+        ensures self@ == old(self)@.set_provisioner(provisioner@),
+    {
+        self.inner.provisioner = provisioner;
+    }
+
+    #[verifier(external_body)]
+    pub fn set_parameters(&mut self, parameters: StringMap)
+        ensures self@ == old(self)@.set_parameters(parameters@),
+    {
+        self.inner.parameters = Some(parameters.into_rust_map());
+    }
+
+    #[verifier(external_body)]
+    pub fn set_reclaim_policy(&mut self, reclaim_policy: String)
+        ensures self@ == old(self)@.set_reclaim_policy(reclaim_policy@),
+    {
+        self.inner.reclaim_policy = Some(reclaim_policy);
+    }
+
+    #[verifier(external_body)]
+    pub fn set_volume_binding_mode(&mut self, volume_binding_mode: String)
+        ensures self@ == old(self)@.set_volume_binding_mode(volume_binding_mode@),
+    {
+        self.inner.volume_binding_mode = Some(volume_binding_mode);
+    }
+
+    #[verifier(external_body)]
+    pub fn set_allow_volume_expansion(&mut self, allow_volume_expansion: bool)
+        ensures self@ == old(self)@.set_allow_volume_expansion(allow_volume_expansion),
+    {
+        self.inner.allow_volume_expansion = Some(allow_volume_expansion);
+    }
+
+    #[verifier(external_body)]
+    pub fn api_resource() -> (res: ApiResource)
+        ensures res@.kind == StorageClassView::kind(),
+    {
+        ApiResource::from_kube(deps_hack::kube::api::ApiResource::erase::<deps_hack::k8s_openapi::api::storage::v1::StorageClass>(&()))
+    }
+
+    #[verifier(external_body)]
+    pub fn marshal(self) -> (obj: DynamicObject)
+        ensures obj@ == self@.marshal(),
+    {
+        DynamicObject::from_kube(deps_hack::k8s_openapi::serde_json::from_str(&deps_hack::k8s_openapi::serde_json::to_string(&self.inner).unwrap()).unwrap())
+    }
+
+    #[verifier(external_body)]
+    pub fn unmarshal(obj: DynamicObject) -> (res: Result<StorageClass, UnmarshalError>)
+        ensures
+            res.is_Ok() == StorageClassView::unmarshal(obj@).is_Ok(),
+            res.is_Ok() ==> res.get_Ok_0()@ == StorageClassView::unmarshal(obj@).get_Ok_0(),
+    {
+        let parse_result = obj.into_kube().try_parse::<deps_hack::k8s_openapi::api::storage::v1::StorageClass>();
+        if parse_result.is_ok() {
+            let res = StorageClass { inner: parse_result.unwrap() };
+            Ok(res)
+        } else {
+            Err(())
+        }
+    }
+}
+
+#[verifier(external)]
+impl ResourceWrapper<deps_hack::k8s_openapi::api::storage::v1::StorageClass> for StorageClass {
+    fn from_kube(inner: deps_hack::k8s_openapi::api::storage::v1::StorageClass) -> StorageClass { StorageClass { inner: inner } }
+
+    fn into_kube(self) -> deps_hack::k8s_openapi::api::storage::v1::StorageClass { self.inner }
+}
+
+}
@@ -0,0 +1,80 @@
+// Copyright 2022 VMware, Inc.
+// SPDX-License-Identifier: MIT
+use crate::kubernetes_api_objects::exec::{label_selector::*, resource::*};
+use crate::kubernetes_api_objects::spec::topology_spread_constraint::*;
+use vstd::prelude::*;
+
+verus! {
+
+// TopologySpreadConstraint specifies how to spread matching pods among the given topology,
+// such as zones or nodes, with at most max_skew difference in the number of matching pods.
+//
+// This definition is a wrapper of TopologySpreadConstraint defined at
+// https://github.com/Arnavion/k8s-openapi/blob/v0.17.0/src/v1_26/api/core/v1/topology_spread_constraint.rs.
+// It is supposed to be used in exec controller code.
+//
+// More detailed information: https://kubernetes.io/docs/concepts/scheduling-eviction/topology-spread-constraints/.
+
+#[verifier(external_body)]
+pub struct TopologySpreadConstraint {
+    inner: deps_hack::k8s_openapi::api::core::v1::TopologySpreadConstraint,
+}
+
+impl TopologySpreadConstraint {
+    pub spec fn view(&self) -> TopologySpreadConstraintView;
+
+    #[verifier(external_body)]
+    pub fn default() -> (topology_spread_constraint: TopologySpreadConstraint)
+        ensures topology_spread_constraint@ == TopologySpreadConstraintView::default(),
+    {
+        TopologySpreadConstraint {
+            inner: deps_hack::k8s_openapi::api::core::v1::TopologySpreadConstraint::default(),
+        }
+    }
+
+    #[verifier(external_body)]
+    pub fn clone(&self) -> (topology_spread_constraint: TopologySpreadConstraint)
+        ensures topology_spread_constraint@ == self@,
+    {
+        TopologySpreadConstraint { inner: self.inner.clone() }
+    }
+
+    #[verifier(external_body)]
+    pub fn set_max_skew(&mut self, max_skew: i32)
+        ensures self@ == old(self)@.set_max_skew(max_skew as int),
+    {
+        self.inner.max_skew = max_skew;
+    }
+
+    #[verifier(external_body)]
+    pub fn set_topology_key(&mut self, topology_key: String)
+        ensures self@ == old(self)@.set_topology_key(topology_key@),
+    {
+        self.inner.topology_key = topology_key;
+    }
+
+    #[verifier(external_body)]
+    pub fn set_when_unsatisfiable(&mut self, when_unsatisfiable: String)
+        ensures self@ == old(self)@.set_when_unsatisfiable(when_unsatisfiable@),
+    {
+        self.inner.when_unsatisfiable = when_unsatisfiable;
+    }
+
+    #[verifier(external_body)]
+    pub fn set_label_selector(&mut self, label_selector: LabelSelector)
+        ensures self@ == old(self)@.set_label_selector(label_selector@),
+    {
+        self.inner.label_selector = Some(label_selector.into_kube());
+    }
+}
+
+#[verifier(external)]
+impl ResourceWrapper<deps_hack::k8s_openapi::api::core::v1::TopologySpreadConstraint> for TopologySpreadConstraint {
+    fn from_kube(inner: deps_hack::k8s_openapi::api::core::v1::TopologySpreadConstraint) -> TopologySpreadConstraint {
+        TopologySpreadConstraint { inner: inner }
+    }
+
+    fn into_kube(self) -> deps_hack::k8s_openapi::api::core::v1::TopologySpreadConstraint { self.inner }
+}
+
+}
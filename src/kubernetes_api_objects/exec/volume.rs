@@ -71,6 +71,13 @@ impl Volume {
         self.inner.downward_api = Some(downward_api.into_kube());
     }
 
+    #[verifier(external_body)]
+    pub fn set_persistent_volume_claim(&mut self, persistent_volume_claim: PersistentVolumeClaimVolumeSource)
+        ensures self@ == old(self)@.set_persistent_volume_claim(persistent_volume_claim@),
+    {
+        self.inner.persistent_volume_claim = Some(persistent_volume_claim.into_kube());
+    }
+
     #[verifier(external)]
     pub fn into_kube(self) -> deps_hack::k8s_openapi::api::core::v1::Volume { self.inner }
 
@@ -154,6 +161,49 @@ impl HostPathVolumeSource {
     pub fn from_kube(inner: deps_hack::k8s_openapi::api::core::v1::HostPathVolumeSource) -> HostPathVolumeSource { HostPathVolumeSource { inner } }
 }
 
+#[verifier(external_body)]
+pub struct PersistentVolumeClaimVolumeSource {
+    inner: deps_hack::k8s_openapi::api::core::v1::PersistentVolumeClaimVolumeSource,
+}
+
+impl PersistentVolumeClaimVolumeSource {
+    pub spec fn view(&self) -> PersistentVolumeClaimVolumeSourceView;
+
+    #[verifier(external_body)]
+    pub fn default() -> (persistent_volume_claim_volume_source: PersistentVolumeClaimVolumeSource)
+        ensures persistent_volume_claim_volume_source@ == PersistentVolumeClaimVolumeSourceView::default(),
+    {
+        PersistentVolumeClaimVolumeSource { inner: deps_hack::k8s_openapi::api::core::v1::PersistentVolumeClaimVolumeSource::default() }
+    }
+
+    #[verifier(external_body)]
+    pub fn clone(&self) -> (persistent_volume_claim_volume_source: PersistentVolumeClaimVolumeSource)
+        ensures persistent_volume_claim_volume_source@ == self@,
+    {
+        PersistentVolumeClaimVolumeSource { inner: self.inner.clone() }
+    }
+
+    #[verifier(external_body)]
+    pub fn set_claim_name(&mut self, claim_name: String)
+        ensures self@ == old(self)@.set_claim_name(claim_name@),
+    {
+        self.inner.claim_name = claim_name;
+    }
+
+    #[verifier(external_body)]
+    pub fn set_read_only(&mut self, read_only: bool)
+        ensures self@ == old(self)@.set_read_only(read_only),
+    {
+        self.inner.read_only = Some(read_only);
+    }
+
+    #[verifier(external)]
+    pub fn into_kube(self) -> deps_hack::k8s_openapi::api::core::v1::PersistentVolumeClaimVolumeSource { self.inner }
+
+    #[verifier(external)]
+    pub fn from_kube(inner: deps_hack::k8s_openapi::api::core::v1::PersistentVolumeClaimVolumeSource) -> PersistentVolumeClaimVolumeSource { PersistentVolumeClaimVolumeSource { inner } }
+}
+
 #[verifier(external_body)]
 pub struct ConfigMapVolumeSource {
     inner: deps_hack::k8s_openapi::api::core::v1::ConfigMapVolumeSource,
@@ -28,6 +28,20 @@ pub enum APIRequest {
     UpdateStatusRequest(UpdateStatusRequest),
 }
 
+impl APIRequest {
+    // Whether the request writes an Event. Events are only for observability,
+    // so such writes do not touch any object managed by controllers.
+    pub open spec fn is_event_write_request(self) -> bool {
+        match self {
+            APIRequest::CreateRequest(req) => req.obj.kind == Kind::EventKind,
+            APIRequest::DeleteRequest(req) => req.key.kind == Kind::EventKind,
+            APIRequest::UpdateRequest(req) => req.obj.kind == Kind::EventKind,
+            APIRequest::UpdateStatusRequest(req) => req.obj.kind == Kind::EventKind,
+            _ => false,
+        }
+    }
+}
+
 // GetRequest gets an object with the key (kind, name and namespace).
 
 pub struct GetRequest {
@@ -0,0 +1,140 @@
+// Copyright 2022 VMware, Inc.
+// SPDX-License-Identifier: MIT
+use crate::kubernetes_api_objects::error::*;
+use crate::kubernetes_api_objects::spec::{common::*, dynamic::*, object_meta::*, resource::*, role::*};
+use vstd::prelude::*;
+
+verus! {
+
+// ClusterRoleView is the ghost type of ClusterRole.
+// A ClusterRole is the cluster-scoped counterpart of Role and shares the same PolicyRuleView.
+
+
+pub struct ClusterRoleView {
+    pub metadata: ObjectMetaView,
+    pub policy_rules: Option<Seq<PolicyRuleView>>,
+}
+
+type ClusterRoleSpecView = Option<Seq<PolicyRuleView>>;
+
+impl ClusterRoleView {
+    pub open spec fn set_metadata(self, metadata: ObjectMetaView) -> ClusterRoleView {
+        ClusterRoleView {
+            metadata: metadata,
+            ..self
+        }
+    }
+
+    pub open spec fn set_rules(self, policy_rules: Seq<PolicyRuleView>) -> ClusterRoleView {
+        ClusterRoleView {
+            policy_rules: Some(policy_rules),
+            ..self
+        }
+    }
+}
+
+impl ResourceView for ClusterRoleView {
+    type Spec = ClusterRoleSpecView;
+    type Status = EmptyStatusView;
+
+    open spec fn default() -> ClusterRoleView {
+        ClusterRoleView {
+            metadata: ObjectMetaView::default(),
+            policy_rules: None,
+        }
+    }
+
+    open spec fn metadata(self) -> ObjectMetaView {
+        self.metadata
+    }
+
+    open spec fn kind() -> Kind {
+        Kind::ClusterRoleKind
+    }
+
+    open spec fn object_ref(self) -> ObjectRef {
+        ObjectRef {
+            kind: Self::kind(),
+            name: self.metadata.name.get_Some_0(),
+            namespace: self.metadata.namespace.get_Some_0(),
+        }
+    }
+
+    proof fn object_ref_is_well_formed() {}
+
+    open spec fn spec(self) -> ClusterRoleSpecView {
+        self.policy_rules
+    }
+
+    open spec fn status(self) -> EmptyStatusView {
+        empty_status()
+    }
+
+    open spec fn marshal(self) -> DynamicObjectView {
+        DynamicObjectView {
+            kind: Self::kind(),
+            metadata: self.metadata,
+            spec: ClusterRoleView::marshal_spec(self.policy_rules),
+            status: ClusterRoleView::marshal_status(empty_status()),
+        }
+    }
+
+    open spec fn unmarshal(obj: DynamicObjectView) -> Result<ClusterRoleView, UnmarshalError> {
+        if obj.kind != Self::kind() {
+            Err(())
+        } else if !ClusterRoleView::unmarshal_spec(obj.spec).is_Ok() {
+            Err(())
+        } else if !ClusterRoleView::unmarshal_status(obj.status).is_Ok() {
+            Err(())
+        } else {
+            Ok(ClusterRoleView {
+                metadata: obj.metadata,
+                policy_rules: ClusterRoleView::unmarshal_spec(obj.spec).get_Ok_0(),
+            })
+        }
+    }
+
+    proof fn marshal_preserves_integrity() {
+        ClusterRoleView::marshal_spec_preserves_integrity();
+        ClusterRoleView::marshal_status_preserves_integrity();
+    }
+
+    proof fn marshal_preserves_metadata() {}
+
+    proof fn marshal_preserves_kind() {}
+
+    open spec fn marshal_spec(s: ClusterRoleSpecView) -> Value {
+        RoleView::marshal_spec(s)
+    }
+
+    open spec fn unmarshal_spec(v: Value) -> Result<ClusterRoleSpecView, UnmarshalError> {
+        RoleView::unmarshal_spec(v)
+    }
+
+    open spec fn marshal_status(s: EmptyStatusView) -> Value {
+        marshal_empty_status(s)
+    }
+
+    open spec fn unmarshal_status(v: Value) -> Result<EmptyStatusView, UnmarshalError> {
+        unmarshal_empty_status(v)
+    }
+
+    proof fn marshal_spec_preserves_integrity() {
+        RoleView::marshal_spec_preserves_integrity();
+    }
+
+    proof fn marshal_status_preserves_integrity() {}
+
+    proof fn unmarshal_result_determined_by_unmarshal_spec_and_status() {}
+
+    open spec fn state_validation(self) -> bool {
+        &&& self.policy_rules.is_Some()
+            ==> (forall |i| 0 <= i < self.policy_rules.get_Some_0().len() ==> #[trigger] self.policy_rules.get_Some_0()[i].state_validation())
+    }
+
+    open spec fn transition_validation(self, old_obj: ClusterRoleView) -> bool {
+        true
+    }
+}
+
+}
@@ -0,0 +1,152 @@
+// Copyright 2022 VMware, Inc.
+// SPDX-License-Identifier: MIT
+use crate::kubernetes_api_objects::error::*;
+use crate::kubernetes_api_objects::spec::{common::*, dynamic::*, object_meta::*, resource::*, role_binding::*};
+use vstd::prelude::*;
+
+verus! {
+
+// ClusterRoleBindingView is the ghost type of ClusterRoleBinding.
+// A ClusterRoleBinding is the cluster-scoped counterpart of RoleBinding and can only refer to a ClusterRole.
+
+
+pub struct ClusterRoleBindingView {
+    pub metadata: ObjectMetaView,
+    pub role_ref: RoleRefView,
+    pub subjects: Option<Seq<SubjectView>>,
+}
+
+type ClusterRoleBindingSpecView = (RoleRefView, Option<Seq<SubjectView>>);
+
+impl ClusterRoleBindingView {
+    pub open spec fn set_metadata(self, metadata: ObjectMetaView) -> ClusterRoleBindingView {
+        ClusterRoleBindingView {
+            metadata: metadata,
+            ..self
+        }
+    }
+
+    pub open spec fn set_role_ref(self, role_ref: RoleRefView) -> ClusterRoleBindingView {
+        ClusterRoleBindingView {
+            role_ref: role_ref,
+            ..self
+        }
+    }
+
+    pub open spec fn set_subjects(self, subjects: Seq<SubjectView>) -> ClusterRoleBindingView {
+        ClusterRoleBindingView {
+            subjects: Some(subjects),
+            ..self
+        }
+    }
+}
+
+impl ResourceView for ClusterRoleBindingView {
+    type Spec = ClusterRoleBindingSpecView;
+    type Status = EmptyStatusView;
+
+    open spec fn default() -> ClusterRoleBindingView {
+        ClusterRoleBindingView {
+            metadata: ObjectMetaView::default(),
+            role_ref: RoleRefView::default(),
+            subjects: None,
+        }
+    }
+
+    open spec fn metadata(self) -> ObjectMetaView {
+        self.metadata
+    }
+
+    open spec fn kind() -> Kind {
+        Kind::ClusterRoleBindingKind
+    }
+
+    open spec fn object_ref(self) -> ObjectRef {
+        ObjectRef {
+            kind: Self::kind(),
+            name: self.metadata.name.get_Some_0(),
+            namespace: self.metadata.namespace.get_Some_0(),
+        }
+    }
+
+    proof fn object_ref_is_well_formed() {}
+
+    open spec fn spec(self) -> ClusterRoleBindingSpecView {
+        (self.role_ref, self.subjects)
+    }
+
+    open spec fn status(self) -> EmptyStatusView {
+        empty_status()
+    }
+
+    open spec fn marshal(self) -> DynamicObjectView {
+        DynamicObjectView {
+            kind: Self::kind(),
+            metadata: self.metadata,
+            spec: ClusterRoleBindingView::marshal_spec((self.role_ref, self.subjects)),
+            status: ClusterRoleBindingView::marshal_status(empty_status()),
+        }
+    }
+
+    open spec fn unmarshal(obj: DynamicObjectView) -> Result<ClusterRoleBindingView, UnmarshalError> {
+        if obj.kind != Self::kind() {
+            Err(())
+        } else if !ClusterRoleBindingView::unmarshal_spec(obj.spec).is_Ok() {
+            Err(())
+        } else if !ClusterRoleBindingView::unmarshal_status(obj.status).is_Ok() {
+            Err(())
+        } else {
+            Ok(ClusterRoleBindingView {
+                metadata: obj.metadata,
+                role_ref: ClusterRoleBindingView::unmarshal_spec(obj.spec).get_Ok_0().0,
+                subjects: ClusterRoleBindingView::unmarshal_spec(obj.spec).get_Ok_0().1,
+            })
+        }
+    }
+
+    proof fn marshal_preserves_integrity() {
+        ClusterRoleBindingView::marshal_spec_preserves_integrity();
+        ClusterRoleBindingView::marshal_status_preserves_integrity();
+    }
+
+    proof fn marshal_preserves_metadata() {}
+
+    proof fn marshal_preserves_kind() {}
+
+    open spec fn marshal_spec(s: ClusterRoleBindingSpecView) -> Value {
+        RoleBindingView::marshal_spec(s)
+    }
+
+    open spec fn unmarshal_spec(v: Value) -> Result<ClusterRoleBindingSpecView, UnmarshalError> {
+        RoleBindingView::unmarshal_spec(v)
+    }
+
+    open spec fn marshal_status(s: EmptyStatusView) -> Value {
+        marshal_empty_status(s)
+    }
+
+    open spec fn unmarshal_status(v: Value) -> Result<EmptyStatusView, UnmarshalError> {
+        unmarshal_empty_status(v)
+    }
+
+    proof fn marshal_spec_preserves_integrity() {
+        RoleBindingView::marshal_spec_preserves_integrity();
+    }
+
+    proof fn marshal_status_preserves_integrity() {}
+
+    proof fn unmarshal_result_determined_by_unmarshal_spec_and_status() {}
+
+    open spec fn state_validation(self) -> bool {
+        &&& self.role_ref.api_group == "rbac.authorization.k8s.io"@
+        &&& self.role_ref.kind == "ClusterRole"@
+        &&& self.subjects.is_Some()
+            ==> forall |i| 0 <= i < self.subjects.get_Some_0().len() ==> #[trigger] self.subjects.get_Some_0()[i].state_validation(false)
+    }
+
+    open spec fn transition_validation(self, old_obj: ClusterRoleBindingView) -> bool {
+        &&& old_obj.role_ref == self.role_ref // role_ref is immutable
+    }
+}
+
+}
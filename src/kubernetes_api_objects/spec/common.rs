@@ -20,19 +20,45 @@ pub type GenerateNameCounter = int;
 // can differentiate between different custom resources
 #[is_variant]
 pub enum Kind {
+    ClusterRoleKind,
+    ClusterRoleBindingKind,
     ConfigMapKind,
     CustomResourceKind(StringView),
     DaemonSetKind,
+    EventKind,
+    JobKind,
+    NamespaceKind,
+    PersistentVolumeKind,
     PersistentVolumeClaimKind,
     PodKind,
+    PodDisruptionBudgetKind,
     RoleKind,
     RoleBindingKind,
     StatefulSetKind,
     ServiceKind,
     ServiceAccountKind,
     SecretKind,
+    StorageClassKind,
 }
 
+impl Kind {
+    // Whether objects of this kind live in a namespace.
+    // Cluster-scoped objects are keyed by the empty namespace in the API server model.
+    // The scope of a custom resource is decided by its CRD (see CustomResourceView::is_namespaced),
+    // so CustomResourceKind is treated as namespaced here.
+    pub open spec fn is_namespaced(self) -> bool {
+        match self {
+            Kind::ClusterRoleKind => false,
+            Kind::ClusterRoleBindingKind => false,
+            Kind::NamespaceKind => false,
+            Kind::PersistentVolumeKind => false,
+            Kind::StorageClassKind => false,
+            _ => true,
+        }
+    }
+}
+
+// For a cluster-scoped object, namespace is the empty string
 pub struct ObjectRef {
     pub kind: Kind,
     pub name: StringView,
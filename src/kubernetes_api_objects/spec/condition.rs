@@ -0,0 +1,114 @@
+// Copyright 2022 VMware, Inc.
+// SPDX-License-Identifier: MIT
+use crate::vstd_ext::string_view::*;
+use vstd::prelude::*;
+
+verus! {
+
+// ConditionView is the ghost type of Condition.
+//
+// lastTransitionTime is intentionally not part of the view: the model has no notion of wall-clock time,
+// so the exec wrapper stamps it when the status of a condition changes (see exec::condition).
+
+pub struct ConditionView {
+    pub type_: StringView,
+    pub status: StringView,
+    pub reason: Option<StringView>,
+    pub message: Option<StringView>,
+    pub observed_generation: Option<int>,
+}
+
+impl ConditionView {
+    pub open spec fn default() -> ConditionView {
+        ConditionView {
+            type_: ""@,
+            status: ""@,
+            reason: None,
+            message: None,
+            observed_generation: None,
+        }
+    }
+
+    pub open spec fn set_type(self, type_: StringView) -> ConditionView {
+        ConditionView {
+            type_: type_,
+            ..self
+        }
+    }
+
+    pub open spec fn set_status(self, status: StringView) -> ConditionView {
+        ConditionView {
+            status: status,
+            ..self
+        }
+    }
+
+    pub open spec fn set_reason(self, reason: StringView) -> ConditionView {
+        ConditionView {
+            reason: Some(reason),
+            ..self
+        }
+    }
+
+    pub open spec fn set_message(self, message: StringView) -> ConditionView {
+        ConditionView {
+            message: Some(message),
+            ..self
+        }
+    }
+
+    pub open spec fn set_observed_generation(self, observed_generation: int) -> ConditionView {
+        ConditionView {
+            observed_generation: Some(observed_generation),
+            ..self
+        }
+    }
+}
+
+pub open spec fn condition_status(b: bool) -> StringView {
+    if b { "True"@ } else { "False"@ }
+}
+
+// make_ready_condition, make_progressing_condition and make_degraded_condition are the
+// three conditions that every controller publishes in the status of its custom resource.
+// ready means the managed workload has caught up with the desired state described by the custom resource.
+// degraded means the last reconcile ended in error.
+
+pub open spec fn make_ready_condition(ready: bool) -> ConditionView {
+    ConditionView::default()
+        .set_type("Ready"@)
+        .set_status(condition_status(ready))
+        .set_reason(if ready { "AllReplicasReady"@ } else { "ReplicasNotReady"@ })
+}
+
+pub open spec fn make_progressing_condition(ready: bool) -> ConditionView {
+    ConditionView::default()
+        .set_type("Progressing"@)
+        .set_status(condition_status(!ready))
+        .set_reason(if ready { "ReconcileComplete"@ } else { "Reconciling"@ })
+}
+
+pub open spec fn make_degraded_condition(degraded: bool) -> ConditionView {
+    ConditionView::default()
+        .set_type("Degraded"@)
+        .set_status(condition_status(degraded))
+        .set_reason(if degraded { "ReconcileFailed"@ } else { "ReconcileSucceeded"@ })
+}
+
+// generation is the metadata.generation of the custom resource whose spec the conditions were computed from.
+pub open spec fn set_observed_generation_of(condition: ConditionView, generation: Option<int>) -> ConditionView {
+    match generation {
+        Some(generation) => condition.set_observed_generation(generation),
+        None => condition,
+    }
+}
+
+pub open spec fn make_conditions(ready: bool, degraded: bool, generation: Option<int>) -> Seq<ConditionView> {
+    seq![
+        set_observed_generation_of(make_ready_condition(ready), generation),
+        set_observed_generation_of(make_progressing_condition(ready), generation),
+        set_observed_generation_of(make_degraded_condition(degraded), generation)
+    ]
+}
+
+}
@@ -446,6 +446,142 @@ impl EnvVarSourceView {
     }
 }
 
-pub struct SecurityContextView {}
+pub struct SecurityContextView {
+    pub allow_privilege_escalation: Option<bool>,
+    pub capabilities: Option<CapabilitiesView>,
+    pub privileged: Option<bool>,
+    pub read_only_root_filesystem: Option<bool>,
+    pub run_as_group: Option<int>,
+    pub run_as_non_root: Option<bool>,
+    pub run_as_user: Option<int>,
+    pub seccomp_profile: Option<SeccompProfileView>,
+}
+
+impl SecurityContextView {
+    pub open spec fn default() -> SecurityContextView {
+        SecurityContextView {
+            allow_privilege_escalation: None,
+            capabilities: None,
+            privileged: None,
+            read_only_root_filesystem: None,
+            run_as_group: None,
+            run_as_non_root: None,
+            run_as_user: None,
+            seccomp_profile: None,
+        }
+    }
+
+    pub open spec fn set_allow_privilege_escalation(self, allow_privilege_escalation: bool) -> SecurityContextView {
+        SecurityContextView {
+            allow_privilege_escalation: Some(allow_privilege_escalation),
+            ..self
+        }
+    }
+
+    pub open spec fn set_capabilities(self, capabilities: CapabilitiesView) -> SecurityContextView {
+        SecurityContextView {
+            capabilities: Some(capabilities),
+            ..self
+        }
+    }
+
+    pub open spec fn set_privileged(self, privileged: bool) -> SecurityContextView {
+        SecurityContextView {
+            privileged: Some(privileged),
+            ..self
+        }
+    }
+
+    pub open spec fn set_read_only_root_filesystem(self, read_only_root_filesystem: bool) -> SecurityContextView {
+        SecurityContextView {
+            read_only_root_filesystem: Some(read_only_root_filesystem),
+            ..self
+        }
+    }
+
+    pub open spec fn set_run_as_group(self, run_as_group: int) -> SecurityContextView {
+        SecurityContextView {
+            run_as_group: Some(run_as_group),
+            ..self
+        }
+    }
+
+    pub open spec fn set_run_as_non_root(self, run_as_non_root: bool) -> SecurityContextView {
+        SecurityContextView {
+            run_as_non_root: Some(run_as_non_root),
+            ..self
+        }
+    }
+
+    pub open spec fn set_run_as_user(self, run_as_user: int) -> SecurityContextView {
+        SecurityContextView {
+            run_as_user: Some(run_as_user),
+            ..self
+        }
+    }
+
+    pub open spec fn set_seccomp_profile(self, seccomp_profile: SeccompProfileView) -> SecurityContextView {
+        SecurityContextView {
+            seccomp_profile: Some(seccomp_profile),
+            ..self
+        }
+    }
+}
+
+pub struct CapabilitiesView {
+    pub add: Option<Seq<StringView>>,
+    pub drop: Option<Seq<StringView>>,
+}
+
+impl CapabilitiesView {
+    pub open spec fn default() -> CapabilitiesView {
+        CapabilitiesView {
+            add: None,
+            drop: None,
+        }
+    }
+
+    pub open spec fn set_add(self, add: Seq<StringView>) -> CapabilitiesView {
+        CapabilitiesView {
+            add: Some(add),
+            ..self
+        }
+    }
+
+    pub open spec fn set_drop(self, drop: Seq<StringView>) -> CapabilitiesView {
+        CapabilitiesView {
+            drop: Some(drop),
+            ..self
+        }
+    }
+}
+
+pub struct SeccompProfileView {
+    pub localhost_profile: Option<StringView>,
+    pub type_: StringView,
+}
+
+impl SeccompProfileView {
+    pub open spec fn default() -> SeccompProfileView {
+        SeccompProfileView {
+            localhost_profile: None,
+            type_: ""@,
+        }
+    }
+
+    pub open spec fn set_localhost_profile(self, localhost_profile: StringView) -> SeccompProfileView {
+        SeccompProfileView {
+            localhost_profile: Some(localhost_profile),
+            ..self
+        }
+    }
+
+    pub open spec fn set_type(self, type_: StringView) -> SeccompProfileView {
+        SeccompProfileView {
+            type_: type_,
+            ..self
+        }
+    }
+}
 
 }
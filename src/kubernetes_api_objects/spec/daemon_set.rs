@@ -162,6 +162,8 @@ impl DaemonSetSpecView {
 
 pub struct DaemonSetStatusView {
     pub number_ready: int,
+    pub desired_number_scheduled: int,
+    pub updated_number_scheduled: int,
 }
 
 }
@@ -85,6 +85,20 @@ impl DynamicObjectView {
             ..self
         }
     }
+
+    pub open spec fn set_spec(self, spec: Value) -> DynamicObjectView {
+        DynamicObjectView {
+            spec: spec,
+            ..self
+        }
+    }
+
+    pub open spec fn set_status(self, status: Value) -> DynamicObjectView {
+        DynamicObjectView {
+            status: status,
+            ..self
+        }
+    }
 }
 
 // This data type represents the entire cluster state that consists of
@@ -0,0 +1,270 @@
+// Copyright 2022 VMware, Inc.
+// SPDX-License-Identifier: MIT
+use crate::kubernetes_api_objects::error::*;
+use crate::kubernetes_api_objects::spec::{common::*, dynamic::*, json_value::*, object_meta::*, resource::*};
+use crate::vstd_ext::string_view::StringView;
+use vstd::{prelude::*, string::*};
+
+verus! {
+
+// EventView is the ghost type of Event.
+//
+// Events are only written for observability (e.g., kubectl describe), so no controller
+// reads them back and no controller-owned object depends on them.
+
+pub struct EventView {
+    pub metadata: ObjectMetaView,
+    pub involved_object: ObjectReferenceView,
+    pub reason: Option<StringView>,
+    pub message: Option<StringView>,
+    pub type_: Option<StringView>,
+}
+
+type EventSpecView = (ObjectReferenceView, Option<StringView>, Option<StringView>, Option<StringView>);
+
+impl EventView {
+    pub open spec fn set_metadata(self, metadata: ObjectMetaView) -> EventView {
+        EventView {
+            metadata: metadata,
+            ..self
+        }
+    }
+
+    pub open spec fn set_involved_object(self, involved_object: ObjectReferenceView) -> EventView {
+        EventView {
+            involved_object: involved_object,
+            ..self
+        }
+    }
+
+    pub open spec fn set_reason(self, reason: StringView) -> EventView {
+        EventView {
+            reason: Some(reason),
+            ..self
+        }
+    }
+
+    pub open spec fn set_message(self, message: StringView) -> EventView {
+        EventView {
+            message: Some(message),
+            ..self
+        }
+    }
+
+    pub open spec fn set_type(self, type_: StringView) -> EventView {
+        EventView {
+            type_: Some(type_),
+            ..self
+        }
+    }
+}
+
+impl ResourceView for EventView {
+    type Spec = EventSpecView;
+    type Status = EmptyStatusView;
+
+    open spec fn default() -> EventView {
+        EventView {
+            metadata: ObjectMetaView::default(),
+            involved_object: ObjectReferenceView::default(),
+            reason: None,
+            message: None,
+            type_: None,
+        }
+    }
+
+    open spec fn metadata(self) -> ObjectMetaView {
+        self.metadata
+    }
+
+    open spec fn kind() -> Kind {
+        Kind::EventKind
+    }
+
+    open spec fn object_ref(self) -> ObjectRef {
+        ObjectRef {
+            kind: Self::kind(),
+            name: self.metadata.name.get_Some_0(),
+            namespace: self.metadata.namespace.get_Some_0(),
+        }
+    }
+
+    proof fn object_ref_is_well_formed() {}
+
+    open spec fn spec(self) -> EventSpecView {
+        (self.involved_object, self.reason, self.message, self.type_)
+    }
+
+    open spec fn status(self) -> EmptyStatusView {
+        empty_status()
+    }
+
+    open spec fn marshal(self) -> DynamicObjectView {
+        DynamicObjectView {
+            kind: Self::kind(),
+            metadata: self.metadata,
+            spec: EventView::marshal_spec((self.involved_object, self.reason, self.message, self.type_)),
+            status: EventView::marshal_status(empty_status()),
+        }
+    }
+
+    open spec fn unmarshal(obj: DynamicObjectView) -> Result<EventView, UnmarshalError> {
+        if obj.kind != Self::kind() {
+            Err(())
+        } else if !EventView::unmarshal_spec(obj.spec).is_Ok() {
+            Err(())
+        } else if !EventView::unmarshal_status(obj.status).is_Ok() {
+            Err(())
+        } else {
+            Ok(EventView {
+                metadata: obj.metadata,
+                involved_object: EventView::unmarshal_spec(obj.spec).get_Ok_0().0,
+                reason: EventView::unmarshal_spec(obj.spec).get_Ok_0().1,
+                message: EventView::unmarshal_spec(obj.spec).get_Ok_0().2,
+                type_: EventView::unmarshal_spec(obj.spec).get_Ok_0().3,
+            })
+        }
+    }
+
+    proof fn marshal_preserves_integrity() {
+        EventView::marshal_spec_preserves_integrity();
+        EventView::marshal_status_preserves_integrity();
+    }
+
+    proof fn marshal_preserves_metadata() {}
+
+    proof fn marshal_preserves_kind() {}
+
+    open spec fn marshal_spec(s: EventSpecView) -> Value {
+        JsonValue::empty_object()
+            .set_field("involvedObject"@, s.0.marshal())
+            .set_optional_field("reason"@, marshal_optional(s.1, |s: StringView| marshal_string(s)))
+            .set_optional_field("message"@, marshal_optional(s.2, |s: StringView| marshal_string(s)))
+            .set_optional_field("type"@, marshal_optional(s.3, |s: StringView| marshal_string(s)))
+    }
+
+    open spec fn unmarshal_spec(v: Value) -> Result<EventSpecView, UnmarshalError> {
+        let involved_object = unmarshal_field(v.field("involvedObject"@), |j: JsonValue| ObjectReferenceView::unmarshal(j));
+        let reason = unmarshal_optional(v.field("reason"@), |j: JsonValue| unmarshal_string(j));
+        let message = unmarshal_optional(v.field("message"@), |j: JsonValue| unmarshal_string(j));
+        let type_ = unmarshal_optional(v.field("type"@), |j: JsonValue| unmarshal_string(j));
+        if involved_object.is_Ok() && reason.is_Ok() && message.is_Ok() && type_.is_Ok() {
+            Ok((involved_object.get_Ok_0(), reason.get_Ok_0(), message.get_Ok_0(), type_.get_Ok_0()))
+        } else {
+            Err(())
+        }
+    }
+
+    open spec fn marshal_status(s: EmptyStatusView) -> Value {
+        marshal_empty_status(s)
+    }
+
+    open spec fn unmarshal_status(v: Value) -> Result<EmptyStatusView, UnmarshalError> {
+        unmarshal_empty_status(v)
+    }
+
+    proof fn marshal_spec_preserves_integrity() {
+        reveal_strlit("involvedObject");
+        reveal_strlit("reason");
+        reveal_strlit("message");
+        reveal_strlit("type");
+        assert("reason"@[0] != "message"@[0]);
+        ObjectReferenceView::marshal_preserves_integrity();
+        string_round_trips();
+        assert forall |s: EventSpecView| #[trigger] Self::unmarshal_spec(Self::marshal_spec(s)).is_Ok() && s == Self::unmarshal_spec(Self::marshal_spec(s)).get_Ok_0() by {
+            unmarshal_field_after_marshal(s.0, |r: ObjectReferenceView| r.marshal(), |j: JsonValue| ObjectReferenceView::unmarshal(j));
+            marshal_optional_preserves_integrity(s.1, |s: StringView| marshal_string(s), |j: JsonValue| unmarshal_string(j));
+            marshal_optional_preserves_integrity(s.2, |s: StringView| marshal_string(s), |j: JsonValue| unmarshal_string(j));
+            marshal_optional_preserves_integrity(s.3, |s: StringView| marshal_string(s), |j: JsonValue| unmarshal_string(j));
+        }
+    }
+
+    proof fn marshal_status_preserves_integrity() {}
+
+    proof fn unmarshal_result_determined_by_unmarshal_spec_and_status() {}
+
+    open spec fn state_validation(self) -> bool {
+        self.type_.is_Some() ==> (self.type_.get_Some_0() == "Normal"@ || self.type_.get_Some_0() == "Warning"@)
+    }
+
+    open spec fn transition_validation(self, old_obj: EventView) -> bool {
+        &&& old_obj.involved_object == self.involved_object // involved_object is immutable
+    }
+}
+
+pub struct ObjectReferenceView {
+    pub kind: Option<StringView>,
+    pub name: Option<StringView>,
+    pub namespace: Option<StringView>,
+}
+
+impl ObjectReferenceView {
+    pub open spec fn default() -> ObjectReferenceView {
+        ObjectReferenceView {
+            kind: None,
+            name: None,
+            namespace: None,
+        }
+    }
+
+    pub open spec fn set_kind(self, kind: StringView) -> ObjectReferenceView {
+        ObjectReferenceView {
+            kind: Some(kind),
+            ..self
+        }
+    }
+
+    pub open spec fn set_name(self, name: StringView) -> ObjectReferenceView {
+        ObjectReferenceView {
+            name: Some(name),
+            ..self
+        }
+    }
+
+    pub open spec fn set_namespace(self, namespace: StringView) -> ObjectReferenceView {
+        ObjectReferenceView {
+            namespace: Some(namespace),
+            ..self
+        }
+    }
+
+    pub open spec fn marshal(self) -> JsonValue {
+        JsonValue::empty_object()
+            .set_optional_field("kind"@, marshal_optional(self.kind, |s: StringView| marshal_string(s)))
+            .set_optional_field("name"@, marshal_optional(self.name, |s: StringView| marshal_string(s)))
+            .set_optional_field("namespace"@, marshal_optional(self.namespace, |s: StringView| marshal_string(s)))
+    }
+
+    pub open spec fn unmarshal(v: JsonValue) -> Result<ObjectReferenceView, UnmarshalError> {
+        let kind = unmarshal_optional(v.field("kind"@), |j: JsonValue| unmarshal_string(j));
+        let name = unmarshal_optional(v.field("name"@), |j: JsonValue| unmarshal_string(j));
+        let namespace = unmarshal_optional(v.field("namespace"@), |j: JsonValue| unmarshal_string(j));
+        if v.is_Object() && kind.is_Ok() && name.is_Ok() && namespace.is_Ok() {
+            Ok(ObjectReferenceView {
+                kind: kind.get_Ok_0(),
+                name: name.get_Ok_0(),
+                namespace: namespace.get_Ok_0(),
+            })
+        } else {
+            Err(())
+        }
+    }
+
+    pub proof fn marshal_preserves_integrity()
+        ensures round_trips(|r: ObjectReferenceView| r.marshal(), |v: JsonValue| ObjectReferenceView::unmarshal(v)),
+    {
+        reveal_strlit("kind");
+        reveal_strlit("name");
+        reveal_strlit("namespace");
+        assert("kind"@[0] != "name"@[0]);
+        assert("name"@.len() != "namespace"@.len());
+        string_round_trips();
+        assert forall |r: ObjectReferenceView| #[trigger] ObjectReferenceView::unmarshal(r.marshal()).is_Ok() && ObjectReferenceView::unmarshal(r.marshal()).get_Ok_0() == r by {
+            marshal_optional_preserves_integrity(r.kind, |s: StringView| marshal_string(s), |j: JsonValue| unmarshal_string(j));
+            marshal_optional_preserves_integrity(r.name, |s: StringView| marshal_string(s), |j: JsonValue| unmarshal_string(j));
+            marshal_optional_preserves_integrity(r.namespace, |s: StringView| marshal_string(s), |j: JsonValue| unmarshal_string(j));
+        }
+    }
+}
+
+}
@@ -0,0 +1,179 @@
+// Copyright 2022 VMware, Inc.
+// SPDX-License-Identifier: MIT
+use crate::kubernetes_api_objects::error::*;
+use crate::kubernetes_api_objects::spec::{
+    common::*, dynamic::*, object_meta::*, pod_template_spec::*, resource::*,
+};
+use vstd::prelude::*;
+
+verus! {
+
+// JobView is the ghost type of Job.
+
+
+pub struct JobView {
+    pub metadata: ObjectMetaView,
+    pub spec: Option<JobSpecView>,
+    pub status: Option<JobStatusView>,
+}
+
+impl JobView {
+    pub open spec fn set_metadata(self, metadata: ObjectMetaView) -> JobView {
+        JobView {
+            metadata: metadata,
+            ..self
+        }
+    }
+
+    pub open spec fn set_spec(self, spec: JobSpecView) -> JobView {
+        JobView {
+            spec: Some(spec),
+            ..self
+        }
+    }
+
+    // A job succeeds once one of its pods completes successfully, and Kubernetes never
+    // starts a new pod after that (the default completions is one).
+    pub open spec fn succeeded(self) -> bool {
+        &&& self.status.is_Some()
+        &&& self.status.get_Some_0().succeeded.is_Some()
+        &&& self.status.get_Some_0().succeeded.get_Some_0() > 0
+    }
+}
+
+impl ResourceView for JobView {
+    type Spec = Option<JobSpecView>;
+    type Status = Option<JobStatusView>;
+
+    open spec fn default() -> JobView {
+        JobView {
+            metadata: ObjectMetaView::default(),
+            spec: None,
+            status: None,
+        }
+    }
+
+    open spec fn metadata(self) -> ObjectMetaView {
+        self.metadata
+    }
+
+    open spec fn kind() -> Kind {
+        Kind::JobKind
+    }
+
+    open spec fn object_ref(self) -> ObjectRef {
+        ObjectRef {
+            kind: Self::kind(),
+            name: self.metadata.name.get_Some_0(),
+            namespace: self.metadata.namespace.get_Some_0(),
+        }
+    }
+
+    proof fn object_ref_is_well_formed() {}
+
+    open spec fn spec(self) -> Option<JobSpecView> {
+        self.spec
+    }
+
+    open spec fn status(self) -> Option<JobStatusView> {
+        self.status
+    }
+
+    open spec fn marshal(self) -> DynamicObjectView {
+        DynamicObjectView {
+            kind: Self::kind(),
+            metadata: self.metadata,
+            spec: JobView::marshal_spec(self.spec),
+            status: JobView::marshal_status(self.status),
+        }
+    }
+
+    open spec fn unmarshal(obj: DynamicObjectView) -> Result<JobView, UnmarshalError> {
+        if obj.kind != Self::kind() {
+            Err(())
+        } else if !JobView::unmarshal_spec(obj.spec).is_Ok() {
+            Err(())
+        } else if !JobView::unmarshal_status(obj.status).is_Ok() {
+            Err(())
+        } else {
+            Ok(JobView {
+                metadata: obj.metadata,
+                spec: JobView::unmarshal_spec(obj.spec).get_Ok_0(),
+                status: JobView::unmarshal_status(obj.status).get_Ok_0(),
+            })
+        }
+    }
+
+    proof fn marshal_preserves_integrity() {
+        JobView::marshal_spec_preserves_integrity();
+        JobView::marshal_status_preserves_integrity();
+    }
+
+    proof fn marshal_preserves_metadata() {}
+
+    proof fn marshal_preserves_kind() {}
+
+    closed spec fn marshal_spec(s: Option<JobSpecView>) -> Value;
+
+    closed spec fn unmarshal_spec(v: Value) -> Result<Option<JobSpecView>, UnmarshalError>;
+
+    closed spec fn marshal_status(s: Option<JobStatusView>) -> Value;
+
+    closed spec fn unmarshal_status(v: Value) -> Result<Option<JobStatusView>, UnmarshalError>;
+
+    #[verifier(external_body)]
+    proof fn marshal_spec_preserves_integrity() {}
+
+    #[verifier(external_body)]
+    proof fn marshal_status_preserves_integrity() {}
+
+    proof fn unmarshal_result_determined_by_unmarshal_spec_and_status() {}
+
+    open spec fn state_validation(self) -> bool {
+        &&& self.spec.is_Some()
+        &&& self.spec.get_Some_0().backoff_limit.is_Some() ==> self.spec.get_Some_0().backoff_limit.get_Some_0() >= 0
+    }
+
+    // The pod template of a job is immutable, and a job that has succeeded stays succeeded.
+    open spec fn transition_validation(self, old_obj: JobView) -> bool {
+        let old_spec = old_obj.spec.get_Some_0();
+        let new_spec = self.spec.get_Some_0();
+        &&& old_spec.template == new_spec.template
+        &&& old_obj.succeeded() ==> self.succeeded()
+    }
+}
+
+pub struct JobSpecView {
+    pub template: PodTemplateSpecView,
+    pub backoff_limit: Option<int>,
+}
+
+impl JobSpecView {
+    pub open spec fn default() -> JobSpecView {
+        JobSpecView {
+            template: PodTemplateSpecView::default(),
+            backoff_limit: None,
+        }
+    }
+
+    pub open spec fn set_template(self, template: PodTemplateSpecView) -> JobSpecView {
+        JobSpecView {
+            template: template,
+            ..self
+        }
+    }
+
+    pub open spec fn set_backoff_limit(self, backoff_limit: int) -> JobSpecView {
+        JobSpecView {
+            backoff_limit: Some(backoff_limit),
+            ..self
+        }
+    }
+}
+
+pub struct JobStatusView {
+    pub succeeded: Option<int>,
+    pub failed: Option<int>,
+}
+
+}
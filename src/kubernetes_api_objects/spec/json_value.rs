@@ -0,0 +1,282 @@
+// Copyright 2022 VMware, Inc.
+// SPDX-License-Identifier: MIT
+use crate::kubernetes_api_objects::error::*;
+use crate::vstd_ext::string_view::*;
+use vstd::prelude::*;
+
+verus! {
+
+// JsonValue is the ghost type of a JSON document (serde_json::Value).
+// It is what the spec and status of a DynamicObjectView are made of.
+// Only ConfigMap, Secret, ServiceAccount, Role, RoleBinding, ClusterRole, ClusterRoleBinding,
+// Namespace and Event marshal their fields into it one by one, so only for these kinds can the model
+// talk about individual fields (e.g., an update that only touches some fields preserves the others).
+// The other kinds marshal through uninterpreted functions and their JsonValue stays opaque (see Value).
+//
+// Numbers are modeled as integers because none of the fields we currently marshal are floats.
+
+#[is_variant]
+pub enum JsonValue {
+    Null,
+    Bool(bool),
+    Number(int),
+    String(StringView),
+    Array(Seq<JsonValue>),
+    Object(Map<StringView, JsonValue>),
+}
+
+impl JsonValue {
+    pub open spec fn empty_object() -> JsonValue {
+        JsonValue::Object(Map::empty())
+    }
+
+    // Returns the value of the field key if self is an object that has this field
+    pub open spec fn field(self, key: StringView) -> Option<JsonValue> {
+        match self {
+            JsonValue::Object(o) => if o.contains_key(key) { Some(o[key]) } else { None },
+            _ => None,
+        }
+    }
+
+    // Sets the field key to value, and keeps all the other fields untouched.
+    // If self is not an object, it is replaced by an object that only has this field.
+    pub open spec fn set_field(self, key: StringView, value: JsonValue) -> JsonValue {
+        match self {
+            JsonValue::Object(o) => JsonValue::Object(o.insert(key, value)),
+            _ => JsonValue::Object(Map::empty().insert(key, value)),
+        }
+    }
+
+    pub open spec fn set_optional_field(self, key: StringView, value: Option<JsonValue>) -> JsonValue {
+        match value {
+            Some(v) => self.set_field(key, v),
+            None => self,
+        }
+    }
+
+    pub open spec fn remove_field(self, key: StringView) -> JsonValue {
+        match self {
+            JsonValue::Object(o) => JsonValue::Object(o.remove(key)),
+            _ => self,
+        }
+    }
+}
+
+pub proof fn set_field_sets_the_field(v: JsonValue, key: StringView, value: JsonValue)
+    ensures v.set_field(key, value).field(key) == Some(value),
+{}
+
+pub proof fn set_field_preserves_other_fields(v: JsonValue, key: StringView, value: JsonValue, other: StringView)
+    requires key != other,
+    ensures v.set_field(key, value).field(other) == v.field(other),
+{}
+
+pub proof fn remove_field_preserves_other_fields(v: JsonValue, key: StringView, other: StringView)
+    requires key != other,
+    ensures v.remove_field(key).field(other) == v.field(other),
+{}
+
+// The following functions are the building blocks for marshalling the spec and status of each kind.
+// Each marshal function comes with an unmarshal function and a lemma showing that unmarshal
+// recovers what was marshalled.
+//
+// Unmarshalling an object only looks at the fields it knows and ignores the others,
+// which is how the API server and the controllers treat unknown fields.
+
+pub open spec fn round_trips<T>(marshal: spec_fn(T) -> JsonValue, unmarshal: spec_fn(JsonValue) -> Result<T, UnmarshalError>) -> bool {
+    forall |t: T| #[trigger] unmarshal(marshal(t)).is_Ok() && unmarshal(marshal(t)).get_Ok_0() == t
+}
+
+pub open spec fn marshal_bool(b: bool) -> JsonValue {
+    JsonValue::Bool(b)
+}
+
+pub open spec fn unmarshal_bool(v: JsonValue) -> Result<bool, UnmarshalError> {
+    match v {
+        JsonValue::Bool(b) => Ok(b),
+        _ => Err(()),
+    }
+}
+
+pub open spec fn marshal_string(s: StringView) -> JsonValue {
+    JsonValue::String(s)
+}
+
+pub open spec fn unmarshal_string(v: JsonValue) -> Result<StringView, UnmarshalError> {
+    match v {
+        JsonValue::String(s) => Ok(s),
+        _ => Err(()),
+    }
+}
+
+pub open spec fn marshal_optional<T>(o: Option<T>, marshal: spec_fn(T) -> JsonValue) -> Option<JsonValue> {
+    match o {
+        Some(t) => Some(marshal(t)),
+        None => None,
+    }
+}
+
+// An absent field is unmarshalled to None
+pub open spec fn unmarshal_optional<T>(o: Option<JsonValue>, unmarshal: spec_fn(JsonValue) -> Result<T, UnmarshalError>) -> Result<Option<T>, UnmarshalError> {
+    match o {
+        Some(v) => if unmarshal(v).is_Ok() { Ok(Some(unmarshal(v).get_Ok_0())) } else { Err(()) },
+        None => Ok(None),
+    }
+}
+
+// A required field that is absent fails unmarshalling
+pub open spec fn unmarshal_field<T>(o: Option<JsonValue>, unmarshal: spec_fn(JsonValue) -> Result<T, UnmarshalError>) -> Result<T, UnmarshalError> {
+    match o {
+        Some(v) => unmarshal(v),
+        None => Err(()),
+    }
+}
+
+pub open spec fn marshal_seq<T>(s: Seq<T>, marshal: spec_fn(T) -> JsonValue) -> JsonValue {
+    JsonValue::Array(s.map_values(marshal))
+}
+
+pub open spec fn unmarshal_seq<T>(v: JsonValue, unmarshal: spec_fn(JsonValue) -> Result<T, UnmarshalError>) -> Result<Seq<T>, UnmarshalError> {
+    match v {
+        JsonValue::Array(a) => if forall |i| 0 <= i < a.len() ==> #[trigger] unmarshal(a[i]).is_Ok() {
+            Ok(a.map_values(|e: JsonValue| unmarshal(e).get_Ok_0()))
+        } else {
+            Err(())
+        },
+        _ => Err(()),
+    }
+}
+
+pub open spec fn marshal_string_seq(s: Seq<StringView>) -> JsonValue {
+    marshal_seq(s, |e: StringView| marshal_string(e))
+}
+
+pub open spec fn unmarshal_string_seq(v: JsonValue) -> Result<Seq<StringView>, UnmarshalError> {
+    unmarshal_seq(v, |e: JsonValue| unmarshal_string(e))
+}
+
+pub open spec fn marshal_string_map(m: Map<StringView, StringView>) -> JsonValue {
+    JsonValue::Object(m.map_values(|s: StringView| JsonValue::String(s)))
+}
+
+pub open spec fn unmarshal_string_map(v: JsonValue) -> Result<Map<StringView, StringView>, UnmarshalError> {
+    match v {
+        JsonValue::Object(o) => if forall |k| #[trigger] o.contains_key(k) ==> o[k].is_String() {
+            Ok(o.map_values(|e: JsonValue| e.get_String_0()))
+        } else {
+            Err(())
+        },
+        _ => Err(()),
+    }
+}
+
+pub open spec fn marshal_byte_string_map(m: Map<StringView, ByteStringView>) -> JsonValue {
+    JsonValue::Object(m.map_values(|b: ByteStringView| JsonValue::String(base64_encode(b))))
+}
+
+pub open spec fn unmarshal_byte_string_map(v: JsonValue) -> Result<Map<StringView, ByteStringView>, UnmarshalError> {
+    match v {
+        JsonValue::Object(o) => if forall |k| #[trigger] o.contains_key(k) ==> o[k].is_String() && base64_decode(o[k].get_String_0()).is_Some() {
+            Ok(o.map_values(|e: JsonValue| base64_decode(e.get_String_0()).get_Some_0()))
+        } else {
+            Err(())
+        },
+        _ => Err(()),
+    }
+}
+
+pub proof fn bool_round_trips()
+    ensures round_trips(|b: bool| marshal_bool(b), |v: JsonValue| unmarshal_bool(v)),
+{}
+
+pub proof fn string_round_trips()
+    ensures round_trips(|s: StringView| marshal_string(s), |v: JsonValue| unmarshal_string(v)),
+{}
+
+pub proof fn marshal_optional_preserves_integrity<T>(o: Option<T>, marshal: spec_fn(T) -> JsonValue, unmarshal: spec_fn(JsonValue) -> Result<T, UnmarshalError>)
+    requires round_trips(marshal, unmarshal),
+    ensures
+        unmarshal_optional(marshal_optional(o, marshal), unmarshal).is_Ok(),
+        unmarshal_optional(marshal_optional(o, marshal), unmarshal).get_Ok_0() == o,
+{
+    if o.is_Some() {
+        assert(unmarshal(marshal(o.get_Some_0())).is_Ok());
+    }
+}
+
+pub proof fn unmarshal_field_after_marshal<T>(t: T, marshal: spec_fn(T) -> JsonValue, unmarshal: spec_fn(JsonValue) -> Result<T, UnmarshalError>)
+    requires round_trips(marshal, unmarshal),
+    ensures
+        unmarshal_field(Some(marshal(t)), unmarshal).is_Ok(),
+        unmarshal_field(Some(marshal(t)), unmarshal).get_Ok_0() == t,
+{
+    assert(unmarshal(marshal(t)).is_Ok());
+}
+
+pub proof fn marshal_seq_preserves_integrity<T>(s: Seq<T>, marshal: spec_fn(T) -> JsonValue, unmarshal: spec_fn(JsonValue) -> Result<T, UnmarshalError>)
+    requires round_trips(marshal, unmarshal),
+    ensures
+        unmarshal_seq(marshal_seq(s, marshal), unmarshal).is_Ok(),
+        unmarshal_seq(marshal_seq(s, marshal), unmarshal).get_Ok_0() == s,
+{
+    let a = s.map_values(marshal);
+    assert forall |i| 0 <= i < a.len() implies #[trigger] unmarshal(a[i]).is_Ok() by {
+        assert(a[i] == marshal(s[i]));
+        assert(unmarshal(marshal(s[i])).is_Ok());
+    }
+    assert(a.map_values(|e: JsonValue| unmarshal(e).get_Ok_0()) =~= s) by {
+        assert forall |i| 0 <= i < s.len() implies #[trigger] a.map_values(|e: JsonValue| unmarshal(e).get_Ok_0())[i] == s[i] by {
+            assert(a[i] == marshal(s[i]));
+            assert(unmarshal(marshal(s[i])).get_Ok_0() == s[i]);
+        }
+    }
+}
+
+pub proof fn marshal_string_map_preserves_integrity(m: Map<StringView, StringView>)
+    ensures
+        unmarshal_string_map(marshal_string_map(m)).is_Ok(),
+        unmarshal_string_map(marshal_string_map(m)).get_Ok_0() == m,
+{
+    let o = m.map_values(|s: StringView| JsonValue::String(s));
+    assert(o.map_values(|e: JsonValue| e.get_String_0()) =~= m);
+}
+
+pub proof fn string_map_round_trips()
+    ensures round_trips(|m: Map<StringView, StringView>| marshal_string_map(m), |v: JsonValue| unmarshal_string_map(v)),
+{
+    assert forall |m: Map<StringView, StringView>| #[trigger] unmarshal_string_map(marshal_string_map(m)).is_Ok()
+    && unmarshal_string_map(marshal_string_map(m)).get_Ok_0() == m by {
+        marshal_string_map_preserves_integrity(m);
+    }
+}
+
+pub proof fn marshal_byte_string_map_preserves_integrity(m: Map<StringView, ByteStringView>)
+    ensures
+        unmarshal_byte_string_map(marshal_byte_string_map(m)).is_Ok(),
+        unmarshal_byte_string_map(marshal_byte_string_map(m)).get_Ok_0() == m,
+{
+    base64_decode_after_encode();
+    let o = m.map_values(|b: ByteStringView| JsonValue::String(base64_encode(b)));
+    assert(o.map_values(|e: JsonValue| base64_decode(e.get_String_0()).get_Some_0()) =~= m);
+}
+
+pub proof fn byte_string_map_round_trips()
+    ensures round_trips(|m: Map<StringView, ByteStringView>| marshal_byte_string_map(m), |v: JsonValue| unmarshal_byte_string_map(v)),
+{
+    assert forall |m: Map<StringView, ByteStringView>| #[trigger] unmarshal_byte_string_map(marshal_byte_string_map(m)).is_Ok()
+    && unmarshal_byte_string_map(marshal_byte_string_map(m)).get_Ok_0() == m by {
+        marshal_byte_string_map_preserves_integrity(m);
+    }
+}
+
+pub proof fn string_seq_round_trips()
+    ensures round_trips(|s: Seq<StringView>| marshal_string_seq(s), |v: JsonValue| unmarshal_string_seq(v)),
+{
+    assert forall |s: Seq<StringView>| #[trigger] unmarshal_string_seq(marshal_string_seq(s)).is_Ok()
+    && unmarshal_string_seq(marshal_string_seq(s)).get_Ok_0() == s by {
+        string_round_trips();
+        marshal_seq_preserves_integrity(s, |e: StringView| marshal_string(e), |e: JsonValue| unmarshal_string(e));
+    }
+}
+
+}
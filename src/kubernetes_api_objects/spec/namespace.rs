@@ -0,0 +1,124 @@
+// Copyright 2022 VMware, Inc.
+// SPDX-License-Identifier: MIT
+use crate::kubernetes_api_objects::error::*;
+use crate::kubernetes_api_objects::spec::{common::*, dynamic::*, object_meta::*, resource::*};
+use vstd::prelude::*;
+
+verus! {
+
+// NamespaceView is the ghost type of Namespace.
+// A Namespace is cluster-scoped, so its metadata.namespace is None and it is keyed by the empty namespace.
+
+
+pub struct NamespaceView {
+    pub metadata: ObjectMetaView,
+}
+
+type NamespaceSpecView = ();
+
+impl NamespaceView {
+    pub open spec fn set_metadata(self, metadata: ObjectMetaView) -> NamespaceView {
+        NamespaceView {
+            metadata: metadata,
+            ..self
+        }
+    }
+}
+
+impl ResourceView for NamespaceView {
+    type Spec = NamespaceSpecView;
+    type Status = EmptyStatusView;
+
+    open spec fn default() -> NamespaceView {
+        NamespaceView {
+            metadata: ObjectMetaView::default(),
+        }
+    }
+
+    open spec fn metadata(self) -> ObjectMetaView {
+        self.metadata
+    }
+
+    open spec fn kind() -> Kind {
+        Kind::NamespaceKind
+    }
+
+    open spec fn object_ref(self) -> ObjectRef {
+        ObjectRef {
+            kind: Self::kind(),
+            name: self.metadata.name.get_Some_0(),
+            namespace: self.metadata.namespace.get_Some_0(),
+        }
+    }
+
+    proof fn object_ref_is_well_formed() {}
+
+    open spec fn spec(self) -> NamespaceSpecView {
+        ()
+    }
+
+    open spec fn status(self) -> EmptyStatusView {
+        empty_status()
+    }
+
+    open spec fn marshal(self) -> DynamicObjectView {
+        DynamicObjectView {
+            kind: Self::kind(),
+            metadata: self.metadata,
+            spec: NamespaceView::marshal_spec(()),
+            status: NamespaceView::marshal_status(empty_status()),
+        }
+    }
+
+    open spec fn unmarshal(obj: DynamicObjectView) -> Result<NamespaceView, UnmarshalError> {
+        if obj.kind != Self::kind() {
+            Err(())
+        } else if !NamespaceView::unmarshal_spec(obj.spec).is_Ok() {
+            Err(())
+        } else if !NamespaceView::unmarshal_status(obj.status).is_Ok() {
+            Err(())
+        } else {
+            Ok(NamespaceView {
+                metadata: obj.metadata,
+            })
+        }
+    }
+
+    proof fn marshal_preserves_integrity() {}
+
+    proof fn marshal_preserves_metadata() {}
+
+    proof fn marshal_preserves_kind() {}
+
+    open spec fn marshal_spec(s: NamespaceSpecView) -> Value {
+        JsonValue::empty_object()
+    }
+
+    open spec fn unmarshal_spec(v: Value) -> Result<NamespaceSpecView, UnmarshalError> {
+        Ok(())
+    }
+
+    open spec fn marshal_status(s: EmptyStatusView) -> Value {
+        marshal_empty_status(s)
+    }
+
+    open spec fn unmarshal_status(v: Value) -> Result<EmptyStatusView, UnmarshalError> {
+        unmarshal_empty_status(v)
+    }
+
+    proof fn marshal_spec_preserves_integrity() {}
+
+    proof fn marshal_status_preserves_integrity() {}
+
+    proof fn unmarshal_result_determined_by_unmarshal_spec_and_status() {}
+
+    open spec fn state_validation(self) -> bool {
+        true
+    }
+
+    open spec fn transition_validation(self, old_obj: NamespaceView) -> bool {
+        true
+    }
+}
+
+}
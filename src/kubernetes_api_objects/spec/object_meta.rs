@@ -14,6 +14,9 @@ pub struct ObjectMetaView {
     pub generate_name: Option<StringView>,
     pub namespace: Option<StringView>,
     pub resource_version: Option<ResourceVersion>,
+    // generation is bumped by the API server when the spec changes; the model does not track it,
+    // and controllers only report it back as the observedGeneration of their status.
+    pub generation: Option<int>,
     pub uid: Option<Uid>,
     pub labels: Option<Map<StringView, StringView>>,
     pub annotations: Option<Map<StringView, StringView>>,
@@ -29,6 +32,7 @@ impl ObjectMetaView {
             generate_name: None,
             namespace: None,
             resource_version: None,
+            generation: None,
             uid: None,
             labels: None,
             annotations: None,
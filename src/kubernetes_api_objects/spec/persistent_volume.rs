@@ -0,0 +1,181 @@
+// Copyright 2022 VMware, Inc.
+// SPDX-License-Identifier: MIT
+use crate::kubernetes_api_objects::error::*;
+use crate::kubernetes_api_objects::spec::{common::*, dynamic::*, object_meta::*, resource::*};
+use crate::vstd_ext::string_view::*;
+use vstd::prelude::*;
+
+verus! {
+
+// PersistentVolumeView is the ghost type of PersistentVolume.
+// A PersistentVolume is cluster-scoped, so its metadata.namespace is None and it is keyed by the empty namespace.
+
+
+pub struct PersistentVolumeView {
+    pub metadata: ObjectMetaView,
+    pub spec: Option<PersistentVolumeSpecView>,
+    pub status: Option<PersistentVolumeStatusView>,
+}
+
+pub type PersistentVolumeStatusView = EmptyStatusView;
+
+impl PersistentVolumeView {
+    pub open spec fn set_metadata(self, metadata: ObjectMetaView) -> PersistentVolumeView {
+        PersistentVolumeView {
+            metadata: metadata,
+            ..self
+        }
+    }
+
+    pub open spec fn set_spec(self, spec: PersistentVolumeSpecView) -> PersistentVolumeView {
+        PersistentVolumeView {
+            spec: Some(spec),
+            ..self
+        }
+    }
+}
+
+impl ResourceView for PersistentVolumeView {
+    type Spec = Option<PersistentVolumeSpecView>;
+    type Status = Option<PersistentVolumeStatusView>;
+
+    open spec fn default() -> PersistentVolumeView {
+        PersistentVolumeView {
+            metadata: ObjectMetaView::default(),
+            spec: None,
+            status: None,
+        }
+    }
+
+    open spec fn metadata(self) -> ObjectMetaView {
+        self.metadata
+    }
+
+    open spec fn kind() -> Kind {
+        Kind::PersistentVolumeKind
+    }
+
+    open spec fn object_ref(self) -> ObjectRef {
+        ObjectRef {
+            kind: Self::kind(),
+            name: self.metadata.name.get_Some_0(),
+            namespace: self.metadata.namespace.get_Some_0(),
+        }
+    }
+
+    proof fn object_ref_is_well_formed() {}
+
+    open spec fn spec(self) -> Option<PersistentVolumeSpecView> {
+        self.spec
+    }
+
+    open spec fn status(self) -> Option<PersistentVolumeStatusView> {
+        self.status
+    }
+
+    open spec fn marshal(self) -> DynamicObjectView {
+        DynamicObjectView {
+            kind: Self::kind(),
+            metadata: self.metadata,
+            spec: PersistentVolumeView::marshal_spec(self.spec),
+            status: PersistentVolumeView::marshal_status(self.status),
+        }
+    }
+
+    open spec fn unmarshal(obj: DynamicObjectView) -> Result<PersistentVolumeView, UnmarshalError> {
+        if obj.kind != Self::kind() {
+            Err(())
+        } else if !PersistentVolumeView::unmarshal_spec(obj.spec).is_Ok() {
+            Err(())
+        } else if !PersistentVolumeView::unmarshal_status(obj.status).is_Ok() {
+            Err(())
+        } else {
+            Ok(PersistentVolumeView {
+                metadata: obj.metadata,
+                spec: PersistentVolumeView::unmarshal_spec(obj.spec).get_Ok_0(),
+                status: PersistentVolumeView::unmarshal_status(obj.status).get_Ok_0(),
+            })
+        }
+    }
+
+    proof fn marshal_preserves_integrity() {
+        PersistentVolumeView::marshal_spec_preserves_integrity();
+        PersistentVolumeView::marshal_status_preserves_integrity();
+    }
+
+    proof fn marshal_preserves_metadata() {}
+
+    proof fn marshal_preserves_kind() {}
+
+    closed spec fn marshal_spec(s: Option<PersistentVolumeSpecView>) -> Value;
+
+    closed spec fn unmarshal_spec(v: Value) -> Result<Option<PersistentVolumeSpecView>, UnmarshalError>;
+
+    closed spec fn marshal_status(s: Option<PersistentVolumeStatusView>) -> Value;
+
+    closed spec fn unmarshal_status(v: Value) -> Result<Option<PersistentVolumeStatusView>, UnmarshalError>;
+
+    #[verifier(external_body)]
+    proof fn marshal_spec_preserves_integrity() {}
+
+    #[verifier(external_body)]
+    proof fn marshal_status_preserves_integrity() {}
+
+    proof fn unmarshal_result_determined_by_unmarshal_spec_and_status() {}
+
+    open spec fn state_validation(self) -> bool {
+        &&& self.spec.is_Some()
+    }
+
+    open spec fn transition_validation(self, old_obj: PersistentVolumeView) -> bool {
+        true
+    }
+}
+
+pub struct PersistentVolumeSpecView {
+    pub access_modes: Option<Seq<StringView>>,
+    pub capacity: Option<Map<StringView, StringView>>,
+    pub persistent_volume_reclaim_policy: Option<StringView>,
+    pub storage_class_name: Option<StringView>,
+}
+
+impl PersistentVolumeSpecView {
+    pub open spec fn default() -> PersistentVolumeSpecView {
+        PersistentVolumeSpecView {
+            access_modes: None,
+            capacity: None,
+            persistent_volume_reclaim_policy: None,
+            storage_class_name: None,
+        }
+    }
+
+    pub open spec fn set_access_modes(self, access_modes: Seq<StringView>) -> PersistentVolumeSpecView {
+        PersistentVolumeSpecView {
+            access_modes: Some(access_modes),
+            ..self
+        }
+    }
+
+    pub open spec fn set_capacity(self, capacity: Map<StringView, StringView>) -> PersistentVolumeSpecView {
+        PersistentVolumeSpecView {
+            capacity: Some(capacity),
+            ..self
+        }
+    }
+
+    pub open spec fn set_persistent_volume_reclaim_policy(self, persistent_volume_reclaim_policy: StringView) -> PersistentVolumeSpecView {
+        PersistentVolumeSpecView {
+            persistent_volume_reclaim_policy: Some(persistent_volume_reclaim_policy),
+            ..self
+        }
+    }
+
+    pub open spec fn set_storage_class_name(self, storage_class_name: StringView) -> PersistentVolumeSpecView {
+        PersistentVolumeSpecView {
+            storage_class_name: Some(storage_class_name),
+            ..self
+        }
+    }
+}
+
+}
@@ -18,7 +18,6 @@ pub struct PersistentVolumeClaimView {
     pub status: Option<PersistentVolumeClaimStatusView>,
 }
 
-pub type PersistentVolumeClaimStatusView = EmptyStatusView;
 
 impl PersistentVolumeClaimView {
     pub open spec fn set_metadata(self, metadata: ObjectMetaView) -> PersistentVolumeClaimView {
@@ -34,6 +33,11 @@ impl PersistentVolumeClaimView {
             ..self
         }
     }
+
+    pub open spec fn bound(self) -> bool {
+        &&& self.status.is_Some()
+        &&& self.status.get_Some_0().phase == Some("Bound"@)
+    }
 }
 
 impl ResourceView for PersistentVolumeClaimView {
@@ -128,8 +132,9 @@ impl ResourceView for PersistentVolumeClaimView {
         &&& self.spec.is_Some()
     }
 
+    // Persistent volumes are not modeled, so a bound claim never loses its volume.
     open spec fn transition_validation(self, old_obj: PersistentVolumeClaimView) -> bool {
-        true
+        old_obj.bound() ==> self.bound()
     }
 }
 
@@ -170,4 +175,8 @@ impl PersistentVolumeClaimSpecView {
     }
 }
 
+pub struct PersistentVolumeClaimStatusView {
+    pub phase: Option<StringView>,
+}
+
 }
@@ -3,7 +3,7 @@
 use crate::kubernetes_api_objects::error::*;
 use crate::kubernetes_api_objects::spec::{
     affinity::*, common::*, container::*, dynamic::*, object_meta::*, resource::*, toleration::*,
-    volume::*,
+    topology_spread_constraint::*, volume::*,
 };
 use crate::vstd_ext::string_view::*;
 use vstd::prelude::*;
@@ -141,6 +141,7 @@ pub struct PodSpecView {
     pub init_containers: Option<Seq<ContainerView>>,
     pub service_account_name: Option<StringView>,
     pub tolerations: Option<Seq<TolerationView>>,
+    pub topology_spread_constraints: Option<Seq<TopologySpreadConstraintView>>,
     pub node_selector: Option<Map<StringView, StringView>>,
     pub runtime_class_name: Option<StringView>,
     pub dns_policy: Option<StringView>,
@@ -150,6 +151,8 @@ pub struct PodSpecView {
     pub host_network: Option<bool>,
     pub termination_grace_period_seconds: Option<int>,
     pub image_pull_secrets: Option<Seq<LocalObjectReferenceView>>,
+    pub restart_policy: Option<StringView>,
+    pub node_name: Option<StringView>,
 }
 
 impl PodSpecView {
@@ -161,6 +164,7 @@ impl PodSpecView {
             init_containers: None,
             service_account_name: None,
             tolerations: None,
+            topology_spread_constraints: None,
             node_selector: None,
             runtime_class_name: None,
             dns_policy: None,
@@ -170,6 +174,8 @@ impl PodSpecView {
             host_network: None,
             termination_grace_period_seconds: None,
             image_pull_secrets: None,
+            restart_policy: None,
+            node_name: None,
         }
     }
 
@@ -229,6 +235,13 @@ impl PodSpecView {
         }
     }
 
+    pub open spec fn set_topology_spread_constraints(self, topology_spread_constraints: Seq<TopologySpreadConstraintView>) -> PodSpecView {
+        PodSpecView {
+            topology_spread_constraints: Some(topology_spread_constraints),
+            ..self
+        }
+    }
+
     pub open spec fn set_node_selector(self, node_selector: Map<StringView, StringView>) -> PodSpecView {
         PodSpecView {
             node_selector: Some(node_selector),
@@ -291,9 +304,129 @@ impl PodSpecView {
             ..self
         }
     }
+
+    pub open spec fn set_restart_policy(self, restart_policy: StringView) -> PodSpecView {
+        PodSpecView {
+            restart_policy: Some(restart_policy),
+            ..self
+        }
+    }
+
+    pub open spec fn set_node_name(self, node_name: StringView) -> PodSpecView {
+        PodSpecView {
+            node_name: Some(node_name),
+            ..self
+        }
+    }
+}
+
+pub struct PodSecurityContextView {
+    pub fs_group: Option<int>,
+    pub run_as_group: Option<int>,
+    pub run_as_non_root: Option<bool>,
+    pub run_as_user: Option<int>,
+    pub seccomp_profile: Option<SeccompProfileView>,
 }
 
-pub struct PodSecurityContextView {}
+impl PodSecurityContextView {
+    pub open spec fn default() -> PodSecurityContextView {
+        PodSecurityContextView {
+            fs_group: None,
+            run_as_group: None,
+            run_as_non_root: None,
+            run_as_user: None,
+            seccomp_profile: None,
+        }
+    }
+
+    pub open spec fn set_fs_group(self, fs_group: int) -> PodSecurityContextView {
+        PodSecurityContextView {
+            fs_group: Some(fs_group),
+            ..self
+        }
+    }
+
+    pub open spec fn set_run_as_group(self, run_as_group: int) -> PodSecurityContextView {
+        PodSecurityContextView {
+            run_as_group: Some(run_as_group),
+            ..self
+        }
+    }
+
+    pub open spec fn set_run_as_non_root(self, run_as_non_root: bool) -> PodSecurityContextView {
+        PodSecurityContextView {
+            run_as_non_root: Some(run_as_non_root),
+            ..self
+        }
+    }
+
+    pub open spec fn set_run_as_user(self, run_as_user: int) -> PodSecurityContextView {
+        PodSecurityContextView {
+            run_as_user: Some(run_as_user),
+            ..self
+        }
+    }
+
+    pub open spec fn set_seccomp_profile(self, seccomp_profile: SeccompProfileView) -> PodSecurityContextView {
+        PodSecurityContextView {
+            seccomp_profile: Some(seccomp_profile),
+            ..self
+        }
+    }
+}
+
+// The following predicates encode the "restricted" profile of the Pod Security Standards
+// (https://kubernetes.io/docs/concepts/security/pod-security-standards/#restricted)
+// over the fields modeled in PodSpecView, so that a controller can prove the pod templates it creates
+// would be admitted to a namespace enforcing pod-security.kubernetes.io/enforce=restricted.
+// As in the real admission plugin, a field set in the container's security context overrides
+// the same field set in the pod's security context.
+
+pub open spec fn seccomp_profile_is_restricted(seccomp_profile: SeccompProfileView) -> bool {
+    seccomp_profile.type_ == "RuntimeDefault"@ || seccomp_profile.type_ == "Localhost"@
+}
+
+pub open spec fn container_satisfies_restricted_pod_security_standard(container: ContainerView, pod_security_context: Option<PodSecurityContextView>) -> bool {
+    let security_context = container.security_context.get_Some_0();
+    let capabilities = security_context.capabilities.get_Some_0();
+    &&& container.security_context.is_Some()
+    // Privileged containers are disallowed.
+    &&& security_context.privileged != Some(true)
+    // Privilege escalation must be explicitly disallowed.
+    &&& security_context.allow_privilege_escalation == Some(false)
+    // Containers must run as non-root users and must not set runAsUser to 0.
+    &&& match security_context.run_as_non_root {
+        Some(run_as_non_root) => run_as_non_root,
+        None => pod_security_context.is_Some() && pod_security_context.get_Some_0().run_as_non_root == Some(true),
+    }
+    &&& security_context.run_as_user != Some(0int)
+    // The seccomp profile must be RuntimeDefault or Localhost.
+    &&& match security_context.seccomp_profile {
+        Some(seccomp_profile) => seccomp_profile_is_restricted(seccomp_profile),
+        None => pod_security_context.is_Some() && pod_security_context.get_Some_0().seccomp_profile.is_Some()
+            && seccomp_profile_is_restricted(pod_security_context.get_Some_0().seccomp_profile.get_Some_0()),
+    }
+    // Containers must drop ALL capabilities and are only allowed to add back NET_BIND_SERVICE.
+    &&& security_context.capabilities.is_Some()
+    &&& capabilities.drop.is_Some() && capabilities.drop.get_Some_0().contains("ALL"@)
+    &&& capabilities.add.is_Some() ==> forall |i| #![trigger capabilities.add.get_Some_0()[i]] 0 <= i < capabilities.add.get_Some_0().len()
+        ==> capabilities.add.get_Some_0()[i] == "NET_BIND_SERVICE"@
+}
+
+impl PodSpecView {
+    pub open spec fn satisfies_restricted_pod_security_standard(self) -> bool {
+        // Sharing the host namespaces is disallowed.
+        &&& self.host_network != Some(true)
+        // HostPath volumes are disallowed.
+        &&& self.volumes.is_Some() ==> forall |i| #![trigger self.volumes.get_Some_0()[i]] 0 <= i < self.volumes.get_Some_0().len()
+            ==> self.volumes.get_Some_0()[i].host_path.is_None()
+        &&& self.security_context.is_Some() ==> self.security_context.get_Some_0().run_as_user != Some(0int)
+        &&& forall |i| #![trigger self.containers[i]] 0 <= i < self.containers.len()
+            ==> container_satisfies_restricted_pod_security_standard(self.containers[i], self.security_context)
+        &&& self.init_containers.is_Some() ==> forall |i| #![trigger self.init_containers.get_Some_0()[i]] 0 <= i < self.init_containers.get_Some_0().len()
+            ==> container_satisfies_restricted_pod_security_standard(self.init_containers.get_Some_0()[i], self.security_context)
+    }
+}
 
 pub struct LocalObjectReferenceView {}
 
@@ -0,0 +1,177 @@
+// Copyright 2022 VMware, Inc.
+// SPDX-License-Identifier: MIT
+use crate::kubernetes_api_objects::error::*;
+use crate::kubernetes_api_objects::spec::{
+    common::*, dynamic::*, label_selector::*, object_meta::*, resource::*,
+};
+use crate::vstd_ext::string_view::*;
+use vstd::prelude::*;
+
+verus! {
+
+// PodDisruptionBudgetView is the ghost type of PodDisruptionBudget.
+// Only the integer form of minAvailable and maxUnavailable is modeled; the percentage form is not supported.
+
+pub struct PodDisruptionBudgetView {
+    pub metadata: ObjectMetaView,
+    pub spec: Option<PodDisruptionBudgetSpecView>,
+    pub status: Option<PodDisruptionBudgetStatusView>,
+}
+
+pub type PodDisruptionBudgetStatusView = EmptyStatusView;
+
+impl PodDisruptionBudgetView {
+    pub open spec fn set_metadata(self, metadata: ObjectMetaView) -> PodDisruptionBudgetView {
+        PodDisruptionBudgetView {
+            metadata: metadata,
+            ..self
+        }
+    }
+
+    pub open spec fn set_spec(self, spec: PodDisruptionBudgetSpecView) -> PodDisruptionBudgetView {
+        PodDisruptionBudgetView {
+            spec: Some(spec),
+            ..self
+        }
+    }
+}
+
+impl ResourceView for PodDisruptionBudgetView {
+    type Spec = Option<PodDisruptionBudgetSpecView>;
+    type Status = Option<PodDisruptionBudgetStatusView>;
+
+    open spec fn default() -> PodDisruptionBudgetView {
+        PodDisruptionBudgetView {
+            metadata: ObjectMetaView::default(),
+            spec: None,
+            status: None,
+        }
+    }
+
+    open spec fn metadata(self) -> ObjectMetaView {
+        self.metadata
+    }
+
+    open spec fn kind() -> Kind {
+        Kind::PodDisruptionBudgetKind
+    }
+
+    open spec fn object_ref(self) -> ObjectRef {
+        ObjectRef {
+            kind: Self::kind(),
+            name: self.metadata.name.get_Some_0(),
+            namespace: self.metadata.namespace.get_Some_0(),
+        }
+    }
+
+    proof fn object_ref_is_well_formed() {}
+
+    open spec fn spec(self) -> Option<PodDisruptionBudgetSpecView> {
+        self.spec
+    }
+
+    open spec fn status(self) -> Option<PodDisruptionBudgetStatusView> {
+        self.status
+    }
+
+    open spec fn marshal(self) -> DynamicObjectView {
+        DynamicObjectView {
+            kind: Self::kind(),
+            metadata: self.metadata,
+            spec: PodDisruptionBudgetView::marshal_spec(self.spec),
+            status: PodDisruptionBudgetView::marshal_status(self.status),
+        }
+    }
+
+    open spec fn unmarshal(obj: DynamicObjectView) -> Result<PodDisruptionBudgetView, UnmarshalError> {
+        if obj.kind != Self::kind() {
+            Err(())
+        } else if !PodDisruptionBudgetView::unmarshal_spec(obj.spec).is_Ok() {
+            Err(())
+        } else if !PodDisruptionBudgetView::unmarshal_status(obj.status).is_Ok() {
+            Err(())
+        } else {
+            Ok(PodDisruptionBudgetView {
+                metadata: obj.metadata,
+                spec: PodDisruptionBudgetView::unmarshal_spec(obj.spec).get_Ok_0(),
+                status: PodDisruptionBudgetView::unmarshal_status(obj.status).get_Ok_0(),
+            })
+        }
+    }
+
+    proof fn marshal_preserves_integrity() {
+        PodDisruptionBudgetView::marshal_spec_preserves_integrity();
+        PodDisruptionBudgetView::marshal_status_preserves_integrity();
+    }
+
+    proof fn marshal_preserves_metadata() {}
+
+    proof fn marshal_preserves_kind() {}
+
+    closed spec fn marshal_spec(s: Option<PodDisruptionBudgetSpecView>) -> Value;
+
+    closed spec fn unmarshal_spec(v: Value) -> Result<Option<PodDisruptionBudgetSpecView>, UnmarshalError>;
+
+    closed spec fn marshal_status(s: Option<PodDisruptionBudgetStatusView>) -> Value;
+
+    closed spec fn unmarshal_status(v: Value) -> Result<Option<PodDisruptionBudgetStatusView>, UnmarshalError>;
+
+    #[verifier(external_body)]
+    proof fn marshal_spec_preserves_integrity() {}
+
+    #[verifier(external_body)]
+    proof fn marshal_status_preserves_integrity() {}
+
+    proof fn unmarshal_result_determined_by_unmarshal_spec_and_status() {}
+
+    // minAvailable and maxUnavailable are mutually exclusive, and neither can be negative.
+    open spec fn state_validation(self) -> bool {
+        &&& self.spec.is_Some()
+        &&& !(self.spec.get_Some_0().min_available.is_Some() && self.spec.get_Some_0().max_unavailable.is_Some())
+        &&& self.spec.get_Some_0().min_available.is_Some() ==> self.spec.get_Some_0().min_available.get_Some_0() >= 0
+        &&& self.spec.get_Some_0().max_unavailable.is_Some() ==> self.spec.get_Some_0().max_unavailable.get_Some_0() >= 0
+    }
+
+    open spec fn transition_validation(self, old_obj: PodDisruptionBudgetView) -> bool {
+        true
+    }
+}
+
+pub struct PodDisruptionBudgetSpecView {
+    pub min_available: Option<int>,
+    pub max_unavailable: Option<int>,
+    pub selector: Option<LabelSelectorView>,
+}
+
+impl PodDisruptionBudgetSpecView {
+    pub open spec fn default() -> PodDisruptionBudgetSpecView {
+        PodDisruptionBudgetSpecView {
+            min_available: None,
+            max_unavailable: None,
+            selector: None,
+        }
+    }
+
+    pub open spec fn set_min_available(self, min_available: int) -> PodDisruptionBudgetSpecView {
+        PodDisruptionBudgetSpecView {
+            min_available: Some(min_available),
+            ..self
+        }
+    }
+
+    pub open spec fn set_max_unavailable(self, max_unavailable: int) -> PodDisruptionBudgetSpecView {
+        PodDisruptionBudgetSpecView {
+            max_unavailable: Some(max_unavailable),
+            ..self
+        }
+    }
+
+    pub open spec fn set_selector(self, selector: LabelSelectorView) -> PodDisruptionBudgetSpecView {
+        PodDisruptionBudgetSpecView {
+            selector: Some(selector),
+            ..self
+        }
+    }
+}
+
+}
@@ -1,14 +1,22 @@
 // Copyright 2022 VMware, Inc.
 // SPDX-License-Identifier: MIT
 pub use crate::kubernetes_api_objects::spec::api_method::*;
+pub use crate::kubernetes_api_objects::spec::cluster_role::*;
+pub use crate::kubernetes_api_objects::spec::cluster_role_binding::*;
 pub use crate::kubernetes_api_objects::spec::common::*;
 pub use crate::kubernetes_api_objects::spec::config_map::*;
 pub use crate::kubernetes_api_objects::spec::daemon_set::*;
 pub use crate::kubernetes_api_objects::spec::dynamic::*;
+pub use crate::kubernetes_api_objects::spec::event::*;
+pub use crate::kubernetes_api_objects::spec::job::*;
+pub use crate::kubernetes_api_objects::spec::json_value::*;
+pub use crate::kubernetes_api_objects::spec::namespace::*;
 pub use crate::kubernetes_api_objects::spec::object_meta::*;
 pub use crate::kubernetes_api_objects::spec::owner_reference::*;
+pub use crate::kubernetes_api_objects::spec::persistent_volume::*;
 pub use crate::kubernetes_api_objects::spec::persistent_volume_claim::*;
 pub use crate::kubernetes_api_objects::spec::pod::*;
+pub use crate::kubernetes_api_objects::spec::pod_disruption_budget::*;
 pub use crate::kubernetes_api_objects::spec::preconditions::*;
 pub use crate::kubernetes_api_objects::spec::resource::*;
 pub use crate::kubernetes_api_objects::spec::role::*;
@@ -17,3 +25,4 @@ pub use crate::kubernetes_api_objects::spec::secret::*;
 pub use crate::kubernetes_api_objects::spec::service::*;
 pub use crate::kubernetes_api_objects::spec::service_account::*;
 pub use crate::kubernetes_api_objects::spec::stateful_set::*;
+pub use crate::kubernetes_api_objects::spec::storage_class::*;
@@ -1,13 +1,19 @@
 // Copyright 2022 VMware, Inc.
 // SPDX-License-Identifier: MIT
 use crate::kubernetes_api_objects::error::*;
-use crate::kubernetes_api_objects::spec::{common::*, dynamic::*, object_meta::*};
+use crate::kubernetes_api_objects::spec::{common::*, dynamic::*, json_value::*, object_meta::*};
 use crate::vstd_ext::string_view::*;
 use vstd::prelude::*;
 
 verus! {
 
-pub type Value = StringView;
+// Value is what the spec and status of a DynamicObjectView are made of,
+// and DynamicObject::spec/status return its exec counterpart JsonValueExec.
+// ConfigMap, Secret, ServiceAccount, Role, RoleBinding, ClusterRole, ClusterRoleBinding, Namespace and Event
+// marshal their spec and status to JsonValue field by field.
+// The other kinds (e.g., Pod, StatefulSet and the custom resources) still use uninterpreted marshal functions,
+// so the model can only say that their marshalled spec and status round-trip.
+pub type Value = JsonValue;
 
 pub trait Marshallable: Sized {
     spec fn marshal(self) -> Value;
@@ -113,10 +119,25 @@ pub open spec fn empty_status() -> EmptyStatusView {
     ()
 }
 
+// Kinds without a status are marshalled to an empty status object,
+// and whatever is stored as their status is ignored when unmarshalling.
+
+pub open spec fn marshal_empty_status(s: EmptyStatusView) -> Value {
+    JsonValue::empty_object()
+}
+
+pub open spec fn unmarshal_empty_status(v: Value) -> Result<EmptyStatusView, UnmarshalError> {
+    Ok(())
+}
+
 pub trait CustomResourceView: ResourceView {
     proof fn kind_is_custom_resource()
         ensures Self::kind().is_CustomResourceKind();
 
+    // Whether the custom resource is namespaced or cluster-scoped, as the scope of its CRD says.
+    // Cluster-scoped custom resources are keyed by the empty namespace in the API server model.
+    spec fn is_namespaced() -> bool;
+
     // The following spec and proof state that validation is only determined by spec and status.
     // That is, validation is not affected by the metadata.
     // TODO: promote this to ResourceView.
@@ -1,9 +1,9 @@
 // Copyright 2022 VMware, Inc.
 // SPDX-License-Identifier: MIT
 use crate::kubernetes_api_objects::error::*;
-use crate::kubernetes_api_objects::spec::{common::*, dynamic::*, object_meta::*, resource::*};
+use crate::kubernetes_api_objects::spec::{common::*, dynamic::*, json_value::*, object_meta::*, resource::*};
 use crate::vstd_ext::string_view::StringView;
-use vstd::prelude::*;
+use vstd::{prelude::*, string::*};
 
 verus! {
 
@@ -103,18 +103,42 @@ impl ResourceView for RoleView {
 
     proof fn marshal_preserves_kind() {}
 
-    closed spec fn marshal_spec(s: RoleSpecView) -> Value;
+    open spec fn marshal_spec(s: RoleSpecView) -> Value {
+        JsonValue::empty_object()
+            .set_optional_field("rules"@, marshal_optional(s, |rules: Seq<PolicyRuleView>| marshal_seq(rules, |r: PolicyRuleView| r.marshal())))
+    }
 
-    closed spec fn unmarshal_spec(v: Value) -> Result<RoleSpecView, UnmarshalError>;
+    open spec fn unmarshal_spec(v: Value) -> Result<RoleSpecView, UnmarshalError> {
+        if v.is_Object() {
+            unmarshal_optional(v.field("rules"@), |j: JsonValue| unmarshal_seq(j, |r: JsonValue| PolicyRuleView::unmarshal(r)))
+        } else {
+            Err(())
+        }
+    }
 
-    closed spec fn marshal_status(s: EmptyStatusView) -> Value;
+    open spec fn marshal_status(s: EmptyStatusView) -> Value {
+        marshal_empty_status(s)
+    }
 
-    closed spec fn unmarshal_status(v: Value) -> Result<EmptyStatusView, UnmarshalError>;
+    open spec fn unmarshal_status(v: Value) -> Result<EmptyStatusView, UnmarshalError> {
+        unmarshal_empty_status(v)
+    }
 
-    #[verifier(external_body)]
-    proof fn marshal_spec_preserves_integrity() {}
+    proof fn marshal_spec_preserves_integrity() {
+        assert forall |s: RoleSpecView| #[trigger] Self::unmarshal_spec(Self::marshal_spec(s)).is_Ok() && s == Self::unmarshal_spec(Self::marshal_spec(s)).get_Ok_0() by {
+            PolicyRuleView::marshal_preserves_integrity();
+            assert forall |rules: Seq<PolicyRuleView>| #[trigger] unmarshal_seq(marshal_seq(rules, |r: PolicyRuleView| r.marshal()), |r: JsonValue| PolicyRuleView::unmarshal(r)).is_Ok()
+            && unmarshal_seq(marshal_seq(rules, |r: PolicyRuleView| r.marshal()), |r: JsonValue| PolicyRuleView::unmarshal(r)).get_Ok_0() == rules by {
+                marshal_seq_preserves_integrity(rules, |r: PolicyRuleView| r.marshal(), |r: JsonValue| PolicyRuleView::unmarshal(r));
+            }
+            marshal_optional_preserves_integrity(
+                s,
+                |rules: Seq<PolicyRuleView>| marshal_seq(rules, |r: PolicyRuleView| r.marshal()),
+                |j: JsonValue| unmarshal_seq(j, |r: JsonValue| PolicyRuleView::unmarshal(r))
+            );
+        }
+    }
 
-    #[verifier(external_body)]
     proof fn marshal_status_preserves_integrity() {}
 
     proof fn unmarshal_result_determined_by_unmarshal_spec_and_status() {}
@@ -172,6 +196,43 @@ impl PolicyRuleView {
             ..self
         }
     }
+
+    pub open spec fn marshal(self) -> JsonValue {
+        JsonValue::empty_object()
+            .set_optional_field("apiGroups"@, marshal_optional(self.api_groups, |s: Seq<StringView>| marshal_string_seq(s)))
+            .set_optional_field("resources"@, marshal_optional(self.resources, |s: Seq<StringView>| marshal_string_seq(s)))
+            .set_field("verbs"@, marshal_string_seq(self.verbs))
+    }
+
+    pub open spec fn unmarshal(v: JsonValue) -> Result<PolicyRuleView, UnmarshalError> {
+        let api_groups = unmarshal_optional(v.field("apiGroups"@), |j: JsonValue| unmarshal_string_seq(j));
+        let resources = unmarshal_optional(v.field("resources"@), |j: JsonValue| unmarshal_string_seq(j));
+        let verbs = unmarshal_field(v.field("verbs"@), |j: JsonValue| unmarshal_string_seq(j));
+        if api_groups.is_Ok() && resources.is_Ok() && verbs.is_Ok() {
+            Ok(PolicyRuleView {
+                api_groups: api_groups.get_Ok_0(),
+                resources: resources.get_Ok_0(),
+                verbs: verbs.get_Ok_0(),
+            })
+        } else {
+            Err(())
+        }
+    }
+
+    pub proof fn marshal_preserves_integrity()
+        ensures round_trips(|r: PolicyRuleView| r.marshal(), |v: JsonValue| PolicyRuleView::unmarshal(v)),
+    {
+        reveal_strlit("apiGroups");
+        reveal_strlit("resources");
+        reveal_strlit("verbs");
+        assert("apiGroups"@[0] != "resources"@[0]);
+        string_seq_round_trips();
+        assert forall |r: PolicyRuleView| #[trigger] PolicyRuleView::unmarshal(r.marshal()).is_Ok() && PolicyRuleView::unmarshal(r.marshal()).get_Ok_0() == r by {
+            marshal_optional_preserves_integrity(r.api_groups, |s: Seq<StringView>| marshal_string_seq(s), |j: JsonValue| unmarshal_string_seq(j));
+            marshal_optional_preserves_integrity(r.resources, |s: Seq<StringView>| marshal_string_seq(s), |j: JsonValue| unmarshal_string_seq(j));
+            unmarshal_field_after_marshal(r.verbs, |s: Seq<StringView>| marshal_string_seq(s), |j: JsonValue| unmarshal_string_seq(j));
+        }
+    }
 }
 
 }
@@ -1,9 +1,9 @@
 // Copyright 2022 VMware, Inc.
 // SPDX-License-Identifier: MIT
 use crate::kubernetes_api_objects::error::*;
-use crate::kubernetes_api_objects::spec::{common::*, dynamic::*, object_meta::*, resource::*};
+use crate::kubernetes_api_objects::spec::{common::*, dynamic::*, json_value::*, object_meta::*, resource::*};
 use crate::vstd_ext::string_view::StringView;
-use vstd::prelude::*;
+use vstd::{prelude::*, string::*};
 
 verus! {
 
@@ -113,18 +113,49 @@ impl ResourceView for RoleBindingView {
 
     proof fn marshal_preserves_kind() {}
 
-    closed spec fn marshal_spec(s: RoleBindingSpecView) -> Value;
+    open spec fn marshal_spec(s: RoleBindingSpecView) -> Value {
+        JsonValue::empty_object()
+            .set_field("roleRef"@, s.0.marshal())
+            .set_optional_field("subjects"@, marshal_optional(s.1, |subjects: Seq<SubjectView>| marshal_seq(subjects, |s: SubjectView| s.marshal())))
+    }
 
-    closed spec fn unmarshal_spec(v: Value) -> Result<RoleBindingSpecView, UnmarshalError>;
+    open spec fn unmarshal_spec(v: Value) -> Result<RoleBindingSpecView, UnmarshalError> {
+        let role_ref = unmarshal_field(v.field("roleRef"@), |j: JsonValue| RoleRefView::unmarshal(j));
+        let subjects = unmarshal_optional(v.field("subjects"@), |j: JsonValue| unmarshal_seq(j, |s: JsonValue| SubjectView::unmarshal(s)));
+        if role_ref.is_Ok() && subjects.is_Ok() {
+            Ok((role_ref.get_Ok_0(), subjects.get_Ok_0()))
+        } else {
+            Err(())
+        }
+    }
 
-    closed spec fn marshal_status(s: EmptyStatusView) -> Value;
+    open spec fn marshal_status(s: EmptyStatusView) -> Value {
+        marshal_empty_status(s)
+    }
 
-    closed spec fn unmarshal_status(v: Value) -> Result<EmptyStatusView, UnmarshalError>;
+    open spec fn unmarshal_status(v: Value) -> Result<EmptyStatusView, UnmarshalError> {
+        unmarshal_empty_status(v)
+    }
 
-    #[verifier(external_body)]
-    proof fn marshal_spec_preserves_integrity() {}
+    proof fn marshal_spec_preserves_integrity() {
+        reveal_strlit("roleRef");
+        reveal_strlit("subjects");
+        RoleRefView::marshal_preserves_integrity();
+        SubjectView::marshal_preserves_integrity();
+        assert forall |s: RoleBindingSpecView| #[trigger] Self::unmarshal_spec(Self::marshal_spec(s)).is_Ok() && s == Self::unmarshal_spec(Self::marshal_spec(s)).get_Ok_0() by {
+            assert forall |subjects: Seq<SubjectView>| #[trigger] unmarshal_seq(marshal_seq(subjects, |s: SubjectView| s.marshal()), |s: JsonValue| SubjectView::unmarshal(s)).is_Ok()
+            && unmarshal_seq(marshal_seq(subjects, |s: SubjectView| s.marshal()), |s: JsonValue| SubjectView::unmarshal(s)).get_Ok_0() == subjects by {
+                marshal_seq_preserves_integrity(subjects, |s: SubjectView| s.marshal(), |s: JsonValue| SubjectView::unmarshal(s));
+            }
+            unmarshal_field_after_marshal(s.0, |r: RoleRefView| r.marshal(), |j: JsonValue| RoleRefView::unmarshal(j));
+            marshal_optional_preserves_integrity(
+                s.1,
+                |subjects: Seq<SubjectView>| marshal_seq(subjects, |s: SubjectView| s.marshal()),
+                |j: JsonValue| unmarshal_seq(j, |s: JsonValue| SubjectView::unmarshal(s))
+            );
+        }
+    }
 
-    #[verifier(external_body)]
     proof fn marshal_status_preserves_integrity() {}
 
     proof fn unmarshal_result_determined_by_unmarshal_spec_and_status() {}
@@ -133,8 +164,8 @@ impl ResourceView for RoleBindingView {
         &&& self.role_ref.api_group == "rbac.authorization.k8s.io"@
         &&& (self.role_ref.kind == "Role"@ || self.role_ref.kind == "ClusterRole"@)
         // &&& self.role_ref.name.len() > 0
-        // &&& self.subjects.is_Some()
-        //     ==> forall |i| 0 <= i < self.subjects.get_Some_0().len() ==> #[trigger] self.subjects.get_Some_0()[i].state_validation(true)
+        &&& self.subjects.is_Some()
+            ==> forall |i| 0 <= i < self.subjects.get_Some_0().len() ==> #[trigger] self.subjects.get_Some_0()[i].state_validation(true)
     }
 
     open spec fn transition_validation(self, old_obj: RoleBindingView) -> bool {
@@ -177,9 +208,47 @@ impl RoleRefView {
             ..self
         }
     }
+
+    pub open spec fn marshal(self) -> JsonValue {
+        JsonValue::empty_object()
+            .set_field("apiGroup"@, marshal_string(self.api_group))
+            .set_field("kind"@, marshal_string(self.kind))
+            .set_field("name"@, marshal_string(self.name))
+    }
+
+    pub open spec fn unmarshal(v: JsonValue) -> Result<RoleRefView, UnmarshalError> {
+        let api_group = unmarshal_field(v.field("apiGroup"@), |j: JsonValue| unmarshal_string(j));
+        let kind = unmarshal_field(v.field("kind"@), |j: JsonValue| unmarshal_string(j));
+        let name = unmarshal_field(v.field("name"@), |j: JsonValue| unmarshal_string(j));
+        if api_group.is_Ok() && kind.is_Ok() && name.is_Ok() {
+            Ok(RoleRefView {
+                api_group: api_group.get_Ok_0(),
+                kind: kind.get_Ok_0(),
+                name: name.get_Ok_0(),
+            })
+        } else {
+            Err(())
+        }
+    }
+
+    pub proof fn marshal_preserves_integrity()
+        ensures round_trips(|r: RoleRefView| r.marshal(), |v: JsonValue| RoleRefView::unmarshal(v)),
+    {
+        reveal_strlit("apiGroup");
+        reveal_strlit("kind");
+        reveal_strlit("name");
+        assert("kind"@[0] != "name"@[0]);
+        string_round_trips();
+        assert forall |r: RoleRefView| #[trigger] RoleRefView::unmarshal(r.marshal()).is_Ok() && RoleRefView::unmarshal(r.marshal()).get_Ok_0() == r by {
+            unmarshal_field_after_marshal(r.api_group, |s: StringView| marshal_string(s), |j: JsonValue| unmarshal_string(j));
+            unmarshal_field_after_marshal(r.kind, |s: StringView| marshal_string(s), |j: JsonValue| unmarshal_string(j));
+            unmarshal_field_after_marshal(r.name, |s: StringView| marshal_string(s), |j: JsonValue| unmarshal_string(j));
+        }
+    }
 }
 
 pub struct SubjectView {
+    pub api_group: Option<StringView>,
     pub kind: StringView,
     pub name: StringView,
     pub namespace: Option<StringView>,
@@ -188,15 +257,34 @@ pub struct SubjectView {
 impl SubjectView {
     pub open spec fn default() -> SubjectView {
         SubjectView {
+            api_group: None,
             kind: ""@,
             name: ""@,
             namespace: None,
         }
     }
 
+    // is_namespaced tells whether the subject belongs to a RoleBinding (namespaced)
+    // or a ClusterRoleBinding (cluster-scoped).
+    // A ServiceAccount subject can omit its namespace only in a RoleBinding,
+    // where it defaults to the namespace of the RoleBinding.
     pub open spec fn state_validation(self, is_namespaced: bool) -> bool {
-        &&& self.kind == "ServiceAccount"@ // TODO: support User and Group as kind here
-        &&& is_namespaced ==> self.namespace.is_Some() && self.namespace.get_Some_0().len() > 0
+        ||| {
+            &&& self.kind == "ServiceAccount"@
+            &&& self.api_group.is_None() || self.api_group.get_Some_0() == ""@
+            &&& !is_namespaced ==> self.namespace.is_Some() && self.namespace.get_Some_0().len() > 0
+        }
+        ||| {
+            &&& (self.kind == "User"@ || self.kind == "Group"@)
+            &&& self.api_group == Some("rbac.authorization.k8s.io"@)
+        }
+    }
+
+    pub open spec fn set_api_group(self, api_group: StringView) -> SubjectView {
+        SubjectView {
+            api_group: Some(api_group),
+            ..self
+        }
     }
 
     pub open spec fn set_kind(self, kind: StringView) -> SubjectView {
@@ -219,6 +307,49 @@ impl SubjectView {
             ..self
         }
     }
+
+    pub open spec fn marshal(self) -> JsonValue {
+        JsonValue::empty_object()
+            .set_optional_field("apiGroup"@, marshal_optional(self.api_group, |s: StringView| marshal_string(s)))
+            .set_field("kind"@, marshal_string(self.kind))
+            .set_field("name"@, marshal_string(self.name))
+            .set_optional_field("namespace"@, marshal_optional(self.namespace, |s: StringView| marshal_string(s)))
+    }
+
+    pub open spec fn unmarshal(v: JsonValue) -> Result<SubjectView, UnmarshalError> {
+        let api_group = unmarshal_optional(v.field("apiGroup"@), |j: JsonValue| unmarshal_string(j));
+        let kind = unmarshal_field(v.field("kind"@), |j: JsonValue| unmarshal_string(j));
+        let name = unmarshal_field(v.field("name"@), |j: JsonValue| unmarshal_string(j));
+        let namespace = unmarshal_optional(v.field("namespace"@), |j: JsonValue| unmarshal_string(j));
+        if api_group.is_Ok() && kind.is_Ok() && name.is_Ok() && namespace.is_Ok() {
+            Ok(SubjectView {
+                api_group: api_group.get_Ok_0(),
+                kind: kind.get_Ok_0(),
+                name: name.get_Ok_0(),
+                namespace: namespace.get_Ok_0(),
+            })
+        } else {
+            Err(())
+        }
+    }
+
+    pub proof fn marshal_preserves_integrity()
+        ensures round_trips(|s: SubjectView| s.marshal(), |v: JsonValue| SubjectView::unmarshal(v)),
+    {
+        reveal_strlit("apiGroup");
+        reveal_strlit("kind");
+        reveal_strlit("name");
+        reveal_strlit("namespace");
+        assert("kind"@[0] != "name"@[0]);
+        assert("name"@.len() != "namespace"@.len());
+        string_round_trips();
+        assert forall |s: SubjectView| #[trigger] SubjectView::unmarshal(s.marshal()).is_Ok() && SubjectView::unmarshal(s.marshal()).get_Ok_0() == s by {
+            marshal_optional_preserves_integrity(s.api_group, |s: StringView| marshal_string(s), |j: JsonValue| unmarshal_string(j));
+            unmarshal_field_after_marshal(s.kind, |s: StringView| marshal_string(s), |j: JsonValue| unmarshal_string(j));
+            unmarshal_field_after_marshal(s.name, |s: StringView| marshal_string(s), |j: JsonValue| unmarshal_string(j));
+            marshal_optional_preserves_integrity(s.namespace, |s: StringView| marshal_string(s), |j: JsonValue| unmarshal_string(j));
+        }
+    }
 }
 
 }
@@ -1,18 +1,21 @@
 // Copyright 2022 VMware, Inc.
 // SPDX-License-Identifier: MIT
 use crate::kubernetes_api_objects::error::*;
-use crate::kubernetes_api_objects::spec::{common::*, dynamic::*, object_meta::*, resource::*};
+use crate::kubernetes_api_objects::spec::{common::*, dynamic::*, json_value::*, object_meta::*, resource::*};
 use crate::vstd_ext::string_view::*;
-use vstd::prelude::*;
+use vstd::{prelude::*, string::*};
 
 verus! {
 
 pub struct SecretView {
     pub metadata: ObjectMetaView,
-    pub data: Option<Map<StringView, StringView>>, // For view, <String, String> map is used instead of <String, Bytestring> map for now.
+    pub data: Option<Map<StringView, ByteStringView>>,
+    // string_data is write-only: the API server merges it into data
+    // when the secret is created or updated, so a stored secret never has string_data.
+    pub string_data: Option<Map<StringView, StringView>>,
 }
 
-type SecretSpecView = Option<Map<StringView, StringView>>;
+type SecretSpecView = (Option<Map<StringView, ByteStringView>>, Option<Map<StringView, StringView>>);
 
 impl SecretView {
     pub open spec fn set_metadata(self, metadata: ObjectMetaView) -> SecretView {
@@ -22,12 +25,70 @@ impl SecretView {
         }
     }
 
+    // Stores the UTF-8 encoding of each value of data
     pub open spec fn set_data(self, data: Map<StringView, StringView>) -> SecretView {
+        SecretView {
+            data: Some(string_map_to_byte_string_map(data)),
+            ..self
+        }
+    }
+
+    pub open spec fn set_binary_data(self, data: Map<StringView, ByteStringView>) -> SecretView {
         SecretView {
             data: Some(data),
             ..self
         }
     }
+
+    pub open spec fn set_string_data(self, string_data: Map<StringView, StringView>) -> SecretView {
+        SecretView {
+            string_data: Some(string_data),
+            ..self
+        }
+    }
+
+    pub open spec fn unset_string_data(self) -> SecretView {
+        SecretView {
+            string_data: None,
+            ..self
+        }
+    }
+
+    // This is what the API server does to the spec of a secret before storing it:
+    // each entry of string_data is encoded and overrides the entry of data with the same key.
+    pub open spec fn merge_string_data(s: SecretSpecView) -> SecretSpecView {
+        match s.1 {
+            Some(string_data) => {
+                let data = match s.0 {
+                    Some(data) => data,
+                    None => Map::empty(),
+                };
+                (Some(data.union_prefer_right(string_map_to_byte_string_map(string_data))), None)
+            },
+            None => s,
+        }
+    }
+
+    // merge_string_data applied to a marshalled spec. Only data and stringData are touched,
+    // and the other fields of the spec are kept as they are.
+    pub open spec fn merge_string_data_in_spec(v: Value) -> Value {
+        let s = SecretView::unmarshal_spec(v);
+        if s.is_Ok() && s.get_Ok_0().1.is_Some() {
+            v.remove_field("stringData"@)
+                .set_optional_field("data"@, marshal_optional(SecretView::merge_string_data(s.get_Ok_0()).0, |m: Map<StringView, ByteStringView>| marshal_byte_string_map(m)))
+        } else {
+            v
+        }
+    }
+
+    // A secret without string_data is stored as it is, so a controller that never sets string_data
+    // finds in etcd exactly the secret it created or updated.
+    pub proof fn lemma_merge_string_data_in_spec_preserves_spec_without_string_data(s: SecretView)
+        requires s.string_data.is_None(),
+        ensures SecretView::merge_string_data_in_spec(SecretView::marshal_spec(s.spec())) == SecretView::marshal_spec(s.spec()),
+    {
+        SecretView::marshal_spec_preserves_integrity();
+    }
 }
 
 impl ResourceView for SecretView {
@@ -38,6 +99,7 @@ impl ResourceView for SecretView {
         SecretView {
             metadata: ObjectMetaView::default(),
             data: None,
+            string_data: None,
         }
     }
 
@@ -58,7 +120,7 @@ impl ResourceView for SecretView {
     }
 
     open spec fn spec(self) -> SecretSpecView {
-        self.data
+        (self.data, self.string_data)
     }
 
     open spec fn status(self) -> EmptyStatusView {
@@ -71,7 +133,7 @@ impl ResourceView for SecretView {
         DynamicObjectView {
             kind: Self::kind(),
             metadata: self.metadata,
-            spec: SecretView::marshal_spec(self.data),
+            spec: SecretView::marshal_spec((self.data, self.string_data)),
             status: SecretView::marshal_status(empty_status()),
         }
     }
@@ -86,7 +148,8 @@ impl ResourceView for SecretView {
         } else {
             Ok(SecretView {
                 metadata: obj.metadata,
-                data: SecretView::unmarshal_spec(obj.spec).get_Ok_0(),
+                data: SecretView::unmarshal_spec(obj.spec).get_Ok_0().0,
+                string_data: SecretView::unmarshal_spec(obj.spec).get_Ok_0().1,
             })
         }
     }
@@ -100,18 +163,41 @@ impl ResourceView for SecretView {
 
     proof fn marshal_preserves_kind() {}
 
-    open spec fn marshal_spec(s: SecretSpecView) -> Value;
+    open spec fn marshal_spec(s: SecretSpecView) -> Value {
+        JsonValue::empty_object()
+            .set_optional_field("data"@, marshal_optional(s.0, |m: Map<StringView, ByteStringView>| marshal_byte_string_map(m)))
+            .set_optional_field("stringData"@, marshal_optional(s.1, |m: Map<StringView, StringView>| marshal_string_map(m)))
+    }
 
-    open spec fn unmarshal_spec(v: Value) -> Result<SecretSpecView, UnmarshalError>;
+    open spec fn unmarshal_spec(v: Value) -> Result<SecretSpecView, UnmarshalError> {
+        let data = unmarshal_optional(v.field("data"@), |j: JsonValue| unmarshal_byte_string_map(j));
+        let string_data = unmarshal_optional(v.field("stringData"@), |j: JsonValue| unmarshal_string_map(j));
+        if v.is_Object() && data.is_Ok() && string_data.is_Ok() {
+            Ok((data.get_Ok_0(), string_data.get_Ok_0()))
+        } else {
+            Err(())
+        }
+    }
 
-    closed spec fn marshal_status(s: EmptyStatusView) -> Value;
+    open spec fn marshal_status(s: EmptyStatusView) -> Value {
+        marshal_empty_status(s)
+    }
 
-    closed spec fn unmarshal_status(v: Value) -> Result<EmptyStatusView, UnmarshalError>;
+    open spec fn unmarshal_status(v: Value) -> Result<EmptyStatusView, UnmarshalError> {
+        unmarshal_empty_status(v)
+    }
 
-    #[verifier(external_body)]
-    proof fn marshal_spec_preserves_integrity() {}
+    proof fn marshal_spec_preserves_integrity() {
+        reveal_strlit("data");
+        reveal_strlit("stringData");
+        byte_string_map_round_trips();
+        string_map_round_trips();
+        assert forall |s: SecretSpecView| #[trigger] Self::unmarshal_spec(Self::marshal_spec(s)).is_Ok() && s == Self::unmarshal_spec(Self::marshal_spec(s)).get_Ok_0() by {
+            marshal_optional_preserves_integrity(s.0, |m: Map<StringView, ByteStringView>| marshal_byte_string_map(m), |j: JsonValue| unmarshal_byte_string_map(j));
+            marshal_optional_preserves_integrity(s.1, |m: Map<StringView, StringView>| marshal_string_map(m), |j: JsonValue| unmarshal_string_map(j));
+        }
+    }
 
-    #[verifier(external_body)]
     proof fn marshal_status_preserves_integrity() {}
 
     proof fn unmarshal_result_determined_by_unmarshal_spec_and_status() {}
@@ -1,7 +1,7 @@
 // Copyright 2022 VMware, Inc.
 // SPDX-License-Identifier: MIT
 use crate::kubernetes_api_objects::error::*;
-use crate::kubernetes_api_objects::spec::{common::*, dynamic::*, object_meta::*, resource::*};
+use crate::kubernetes_api_objects::spec::{common::*, dynamic::*, json_value::*, object_meta::*, resource::*};
 use vstd::prelude::*;
 
 verus! {
@@ -95,18 +95,34 @@ impl ResourceView for ServiceAccountView {
 
     proof fn marshal_preserves_kind() {}
 
-    closed spec fn marshal_spec(s: ServiceAccountSpecView) -> Value;
+    open spec fn marshal_spec(s: ServiceAccountSpecView) -> Value {
+        JsonValue::empty_object()
+            .set_optional_field("automountServiceAccountToken"@, marshal_optional(s, |b: bool| marshal_bool(b)))
+    }
 
-    closed spec fn unmarshal_spec(v: Value) -> Result<ServiceAccountSpecView, UnmarshalError>;
+    open spec fn unmarshal_spec(v: Value) -> Result<ServiceAccountSpecView, UnmarshalError> {
+        if v.is_Object() {
+            unmarshal_optional(v.field("automountServiceAccountToken"@), |j: JsonValue| unmarshal_bool(j))
+        } else {
+            Err(())
+        }
+    }
 
-    closed spec fn marshal_status(s: EmptyStatusView) -> Value;
+    open spec fn marshal_status(s: EmptyStatusView) -> Value {
+        marshal_empty_status(s)
+    }
 
-    closed spec fn unmarshal_status(v: Value) -> Result<EmptyStatusView, UnmarshalError>;
+    open spec fn unmarshal_status(v: Value) -> Result<EmptyStatusView, UnmarshalError> {
+        unmarshal_empty_status(v)
+    }
 
-    #[verifier(external_body)]
-    proof fn marshal_spec_preserves_integrity() {}
+    proof fn marshal_spec_preserves_integrity() {
+        assert forall |s: ServiceAccountSpecView| #[trigger] Self::unmarshal_spec(Self::marshal_spec(s)).is_Ok() && s == Self::unmarshal_spec(Self::marshal_spec(s)).get_Ok_0() by {
+            bool_round_trips();
+            marshal_optional_preserves_integrity(s, |b: bool| marshal_bool(b), |j: JsonValue| unmarshal_bool(j));
+        }
+    }
 
-    #[verifier(external_body)]
     proof fn marshal_status_preserves_integrity() {}
 
     proof fn unmarshal_result_determined_by_unmarshal_spec_and_status() {}
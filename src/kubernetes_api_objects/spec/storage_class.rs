@@ -0,0 +1,177 @@
+// Copyright 2022 VMware, Inc.
+// SPDX-License-Identifier: MIT
+use crate::kubernetes_api_objects::error::*;
+use crate::kubernetes_api_objects::spec::{common::*, dynamic::*, object_meta::*, resource::*};
+use crate::vstd_ext::string_view::*;
+use vstd::prelude::*;
+
+verus! {
+
+// StorageClassView is the ghost type of StorageClass.
+// A StorageClass is cluster-scoped, so its metadata.namespace is None and it is keyed by the empty namespace.
+
+
+pub struct StorageClassView {
+    pub metadata: ObjectMetaView,
+    pub provisioner: StringView,
+    pub parameters: Option<Map<StringView, StringView>>,
+    pub reclaim_policy: Option<StringView>,
+    pub volume_binding_mode: Option<StringView>,
+    pub allow_volume_expansion: Option<bool>,
+}
+
+type StorageClassSpecView = (StringView, Option<Map<StringView, StringView>>, Option<StringView>, Option<StringView>, Option<bool>);
+
+impl StorageClassView {
+    pub open spec fn set_metadata(self, metadata: ObjectMetaView) -> StorageClassView {
+        StorageClassView {
+            metadata: metadata,
+            ..self
+        }
+    }
+
+    pub open spec fn set_provisioner(self, provisioner: StringView) -> StorageClassView {
+        StorageClassView {
+            provisioner: provisioner,
+            ..self
+        }
+    }
+
+    pub open spec fn set_parameters(self, parameters: Map<StringView, StringView>) -> StorageClassView {
+        StorageClassView {
+            parameters: Some(parameters),
+            ..self
+        }
+    }
+
+    pub open spec fn set_reclaim_policy(self, reclaim_policy: StringView) -> StorageClassView {
+        StorageClassView {
+            reclaim_policy: Some(reclaim_policy),
+            ..self
+        }
+    }
+
+    pub open spec fn set_volume_binding_mode(self, volume_binding_mode: StringView) -> StorageClassView {
+        StorageClassView {
+            volume_binding_mode: Some(volume_binding_mode),
+            ..self
+        }
+    }
+
+    pub open spec fn set_allow_volume_expansion(self, allow_volume_expansion: bool) -> StorageClassView {
+        StorageClassView {
+            allow_volume_expansion: Some(allow_volume_expansion),
+            ..self
+        }
+    }
+}
+
+impl ResourceView for StorageClassView {
+    type Spec = StorageClassSpecView;
+    type Status = EmptyStatusView;
+
+    open spec fn default() -> StorageClassView {
+        StorageClassView {
+            metadata: ObjectMetaView::default(),
+            provisioner: ""@,
+            parameters: None,
+            reclaim_policy: None,
+            volume_binding_mode: None,
+            allow_volume_expansion: None,
+        }
+    }
+
+    open spec fn metadata(self) -> ObjectMetaView {
+        self.metadata
+    }
+
+    open spec fn kind() -> Kind {
+        Kind::StorageClassKind
+    }
+
+    open spec fn object_ref(self) -> ObjectRef {
+        ObjectRef {
+            kind: Self::kind(),
+            name: self.metadata.name.get_Some_0(),
+            namespace: self.metadata.namespace.get_Some_0(),
+        }
+    }
+
+    proof fn object_ref_is_well_formed() {}
+
+    open spec fn spec(self) -> StorageClassSpecView {
+        (self.provisioner, self.parameters, self.reclaim_policy, self.volume_binding_mode, self.allow_volume_expansion)
+    }
+
+    open spec fn status(self) -> EmptyStatusView {
+        empty_status()
+    }
+
+    open spec fn marshal(self) -> DynamicObjectView {
+        DynamicObjectView {
+            kind: Self::kind(),
+            metadata: self.metadata,
+            spec: StorageClassView::marshal_spec((self.provisioner, self.parameters, self.reclaim_policy, self.volume_binding_mode, self.allow_volume_expansion)),
+            status: StorageClassView::marshal_status(empty_status()),
+        }
+    }
+
+    open spec fn unmarshal(obj: DynamicObjectView) -> Result<StorageClassView, UnmarshalError> {
+        if obj.kind != Self::kind() {
+            Err(())
+        } else if !StorageClassView::unmarshal_spec(obj.spec).is_Ok() {
+            Err(())
+        } else if !StorageClassView::unmarshal_status(obj.status).is_Ok() {
+            Err(())
+        } else {
+            Ok(StorageClassView {
+                metadata: obj.metadata,
+                provisioner: StorageClassView::unmarshal_spec(obj.spec).get_Ok_0().0,
+                parameters: StorageClassView::unmarshal_spec(obj.spec).get_Ok_0().1,
+                reclaim_policy: StorageClassView::unmarshal_spec(obj.spec).get_Ok_0().2,
+                volume_binding_mode: StorageClassView::unmarshal_spec(obj.spec).get_Ok_0().3,
+                allow_volume_expansion: StorageClassView::unmarshal_spec(obj.spec).get_Ok_0().4,
+            })
+        }
+    }
+
+    proof fn marshal_preserves_integrity() {
+        StorageClassView::marshal_spec_preserves_integrity();
+        StorageClassView::marshal_status_preserves_integrity();
+    }
+
+    proof fn marshal_preserves_metadata() {}
+
+    proof fn marshal_preserves_kind() {}
+
+    closed spec fn marshal_spec(s: StorageClassSpecView) -> Value;
+
+    closed spec fn unmarshal_spec(v: Value) -> Result<StorageClassSpecView, UnmarshalError>;
+
+    open spec fn marshal_status(s: EmptyStatusView) -> Value {
+        marshal_empty_status(s)
+    }
+
+    open spec fn unmarshal_status(v: Value) -> Result<EmptyStatusView, UnmarshalError> {
+        unmarshal_empty_status(v)
+    }
+
+    #[verifier(external_body)]
+    proof fn marshal_spec_preserves_integrity() {}
+
+    proof fn marshal_status_preserves_integrity() {}
+
+    proof fn unmarshal_result_determined_by_unmarshal_spec_and_status() {}
+
+    // The provisioner is required and, like the parameters, it cannot be changed once created.
+    open spec fn state_validation(self) -> bool {
+        self.provisioner.len() > 0
+    }
+
+    open spec fn transition_validation(self, old_obj: StorageClassView) -> bool {
+        &&& self.provisioner == old_obj.provisioner
+        &&& self.parameters == old_obj.parameters
+    }
+}
+
+}
@@ -0,0 +1,57 @@
+// Copyright 2022 VMware, Inc.
+// SPDX-License-Identifier: MIT
+use crate::kubernetes_api_objects::spec::label_selector::*;
+use crate::vstd_ext::string_view::*;
+use vstd::prelude::*;
+
+verus! {
+
+// TopologySpreadConstraintView is the ghost type of TopologySpreadConstraint.
+
+pub struct TopologySpreadConstraintView {
+    pub max_skew: int,
+    pub topology_key: StringView,
+    pub when_unsatisfiable: StringView,
+    pub label_selector: Option<LabelSelectorView>,
+}
+
+impl TopologySpreadConstraintView {
+    pub open spec fn default() -> TopologySpreadConstraintView {
+        TopologySpreadConstraintView {
+            max_skew: 0,
+            topology_key: ""@,
+            when_unsatisfiable: ""@,
+            label_selector: None,
+        }
+    }
+
+    pub open spec fn set_max_skew(self, max_skew: int) -> TopologySpreadConstraintView {
+        TopologySpreadConstraintView {
+            max_skew: max_skew,
+            ..self
+        }
+    }
+
+    pub open spec fn set_topology_key(self, topology_key: StringView) -> TopologySpreadConstraintView {
+        TopologySpreadConstraintView {
+            topology_key: topology_key,
+            ..self
+        }
+    }
+
+    pub open spec fn set_when_unsatisfiable(self, when_unsatisfiable: StringView) -> TopologySpreadConstraintView {
+        TopologySpreadConstraintView {
+            when_unsatisfiable: when_unsatisfiable,
+            ..self
+        }
+    }
+
+    pub open spec fn set_label_selector(self, label_selector: LabelSelectorView) -> TopologySpreadConstraintView {
+        TopologySpreadConstraintView {
+            label_selector: Some(label_selector),
+            ..self
+        }
+    }
+}
+
+}
@@ -13,6 +13,7 @@ pub struct VolumeView {
     pub secret: Option<SecretVolumeSourceView>,
     pub downward_api: Option<DownwardAPIVolumeSourceView>,
     pub empty_dir: Option<EmptyDirVolumeSourceView>,
+    pub persistent_volume_claim: Option<PersistentVolumeClaimVolumeSourceView>,
 }
 
 impl VolumeView {
@@ -25,6 +26,7 @@ impl VolumeView {
             secret: None,
             downward_api: None,
             empty_dir: None,
+            persistent_volume_claim: None,
         }
     }
 
@@ -76,6 +78,13 @@ impl VolumeView {
             ..self
         }
     }
+
+    pub open spec fn set_persistent_volume_claim(self, persistent_volume_claim: PersistentVolumeClaimVolumeSourceView) -> VolumeView {
+        VolumeView {
+            persistent_volume_claim: Some(persistent_volume_claim),
+            ..self
+        }
+    }
 }
 
 pub struct EmptyDirVolumeSourceView {
@@ -111,6 +120,34 @@ impl HostPathVolumeSourceView {
     }
 }
 
+pub struct PersistentVolumeClaimVolumeSourceView {
+    pub claim_name: StringView,
+    pub read_only: Option<bool>,
+}
+
+impl PersistentVolumeClaimVolumeSourceView {
+    pub open spec fn default() -> PersistentVolumeClaimVolumeSourceView {
+        PersistentVolumeClaimVolumeSourceView {
+            claim_name: ""@,
+            read_only: None,
+        }
+    }
+
+    pub open spec fn set_claim_name(self, claim_name: StringView) -> PersistentVolumeClaimVolumeSourceView {
+        PersistentVolumeClaimVolumeSourceView {
+            claim_name: claim_name,
+            ..self
+        }
+    }
+
+    pub open spec fn set_read_only(self, read_only: bool) -> PersistentVolumeClaimVolumeSourceView {
+        PersistentVolumeClaimVolumeSourceView {
+            read_only: Some(read_only),
+            ..self
+        }
+    }
+}
+
 pub struct ConfigMapVolumeSourceView {
     pub name: Option<StringView>,
 }
@@ -3,9 +3,29 @@
 #![allow(unused_imports)]
 use crate::external_api::spec::ExternalAPI;
 use crate::kubernetes_api_objects::spec::prelude::*;
-use crate::kubernetes_cluster::spec::{cluster::*, cluster_state_machine::Step, message::*};
+use crate::kubernetes_cluster::spec::{
+    api_server::types::*, cluster::*, cluster_state_machine::Step, message::*,
+};
 use crate::reconciler::spec::reconciler::Reconciler;
 use crate::temporal_logic::{defs::*, rules::*};
 use vstd::prelude::*;
 
-verus! {}
+verus! {
+
+impl <K: CustomResourceView, E: ExternalAPI, R: Reconciler<K, E>> Cluster<K, E, R> {
+
+// Handling a request that writes an Event never changes any object of another kind,
+// so recording Events does not interfere with the objects that controllers manage.
+pub proof fn lemma_event_write_request_preserves_objects_of_other_kinds(msg: MsgType<E>, s: ApiServerState, key: ObjectRef)
+    requires
+        msg.content.is_APIRequest(),
+        msg.content.get_APIRequest_0().is_event_write_request(),
+        key.kind != Kind::EventKind,
+    ensures
+        Self::transition_by_etcd(msg, s).0.resources.contains_key(key) == s.resources.contains_key(key),
+        s.resources.contains_key(key) ==> Self::transition_by_etcd(msg, s).0.resources[key] == s.resources[key],
+{}
+
+}
+
+}
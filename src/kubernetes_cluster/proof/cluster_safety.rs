@@ -28,16 +28,22 @@ pub proof fn lemma_always_has_rest_id_counter_no_smaller_than(
 }
 
 pub open spec fn valid_object_except_k(obj: DynamicObjectView) -> bool {
-    if obj.kind == ConfigMapView::kind() { ConfigMapView::unmarshal(obj).get_Ok_0().state_validation() }
+    if obj.kind == ClusterRoleView::kind() { ClusterRoleView::unmarshal(obj).get_Ok_0().state_validation() }
+    else if obj.kind == ClusterRoleBindingView::kind() { ClusterRoleBindingView::unmarshal(obj).get_Ok_0().state_validation() }
+    else if obj.kind == ConfigMapView::kind() { ConfigMapView::unmarshal(obj).get_Ok_0().state_validation() }
     else if obj.kind == DaemonSetView::kind() { DaemonSetView::unmarshal(obj).get_Ok_0().state_validation() }
+    else if obj.kind == NamespaceView::kind() { NamespaceView::unmarshal(obj).get_Ok_0().state_validation() }
+    else if obj.kind == PersistentVolumeView::kind() { PersistentVolumeView::unmarshal(obj).get_Ok_0().state_validation() }
     else if obj.kind == PersistentVolumeClaimView::kind() { PersistentVolumeClaimView::unmarshal(obj).get_Ok_0().state_validation() }
     else if obj.kind == PodView::kind() { PodView::unmarshal(obj).get_Ok_0().state_validation() }
+    else if obj.kind == PodDisruptionBudgetView::kind() { PodDisruptionBudgetView::unmarshal(obj).get_Ok_0().state_validation() }
     else if obj.kind == RoleBindingView::kind() { RoleBindingView::unmarshal(obj).get_Ok_0().state_validation() }
     else if obj.kind == RoleView::kind() { RoleView::unmarshal(obj).get_Ok_0().state_validation() }
     else if obj.kind == SecretView::kind() { SecretView::unmarshal(obj).get_Ok_0().state_validation() }
     else if obj.kind == ServiceView::kind() { ServiceView::unmarshal(obj).get_Ok_0().state_validation() }
     else if obj.kind == StatefulSetView::kind() { StatefulSetView::unmarshal(obj).get_Ok_0().state_validation() }
     else if obj.kind == ServiceAccountView::kind() { ServiceAccountView::unmarshal(obj).get_Ok_0().state_validation() }
+    else if obj.kind == StorageClassView::kind() { StorageClassView::unmarshal(obj).get_Ok_0().state_validation() }
     else { true }
 }
 
@@ -74,16 +80,22 @@ pub proof fn lemma_always_each_object_in_etcd_is_well_formed(spec: TempPred<Self
     implies invariant(s_prime) by {
         assert forall |key: ObjectRef| #[trigger] s_prime.resources().contains_key(key)
         implies Self::etcd_object_is_well_formed(key)(s_prime) by {
+            ClusterRoleView::marshal_status_preserves_integrity();
+            ClusterRoleBindingView::marshal_status_preserves_integrity();
             ConfigMapView::marshal_status_preserves_integrity();
             DaemonSetView::marshal_status_preserves_integrity();
+            NamespaceView::marshal_status_preserves_integrity();
+            PersistentVolumeView::marshal_status_preserves_integrity();
             PersistentVolumeClaimView::marshal_status_preserves_integrity();
             PodView::marshal_status_preserves_integrity();
+            PodDisruptionBudgetView::marshal_status_preserves_integrity();
             RoleBindingView::marshal_status_preserves_integrity();
             RoleView::marshal_status_preserves_integrity();
             SecretView::marshal_status_preserves_integrity();
             ServiceView::marshal_status_preserves_integrity();
             StatefulSetView::marshal_status_preserves_integrity();
             ServiceAccountView::marshal_status_preserves_integrity();
+            StorageClassView::marshal_status_preserves_integrity();
             K::marshal_status_preserves_integrity();
             if s.resources().contains_key(key) {
                 let step = choose |step| Self::next_step(s, s_prime, step);
@@ -127,6 +127,12 @@ pub proof fn exists_next_builtin_controllers_step(action: BuiltinControllersActi
     } else if action == Self::run_daemon_set_controller() {
         let step = BuiltinControllersStep::RunDaemonSetController;
         assert(((Self::builtin_controllers().step_to_action)(step).precondition)(input, s));
+    } else if action == Self::run_job_controller() {
+        let step = BuiltinControllersStep::RunJobController;
+        assert(((Self::builtin_controllers().step_to_action)(step).precondition)(input, s));
+    } else if action == Self::run_persistent_volume_controller() {
+        let step = BuiltinControllersStep::RunPersistentVolumeController;
+        assert(((Self::builtin_controllers().step_to_action)(step).precondition)(input, s));
     } else {
         let step = BuiltinControllersStep::RunStabilizer;
         assert(((Self::builtin_controllers().step_to_action)(step).precondition)(input, s));
@@ -25,16 +25,24 @@ verus! {
 #[verifier(inline)]
 pub open spec fn unmarshallable_spec<K: CustomResourceView>(obj: DynamicObjectView) -> bool {
     match obj.kind {
+        Kind::ClusterRoleKind => ClusterRoleView::unmarshal_spec(obj.spec).is_Ok(),
+        Kind::ClusterRoleBindingKind => ClusterRoleBindingView::unmarshal_spec(obj.spec).is_Ok(),
         Kind::ConfigMapKind => ConfigMapView::unmarshal_spec(obj.spec).is_Ok(),
         Kind::DaemonSetKind => DaemonSetView::unmarshal_spec(obj.spec).is_Ok(),
+        Kind::EventKind => EventView::unmarshal_spec(obj.spec).is_Ok(),
+        Kind::JobKind => JobView::unmarshal_spec(obj.spec).is_Ok(),
+        Kind::NamespaceKind => NamespaceView::unmarshal_spec(obj.spec).is_Ok(),
+        Kind::PersistentVolumeKind => PersistentVolumeView::unmarshal_spec(obj.spec).is_Ok(),
         Kind::PersistentVolumeClaimKind => PersistentVolumeClaimView::unmarshal_spec(obj.spec).is_Ok(),
         Kind::PodKind => PodView::unmarshal_spec(obj.spec).is_Ok(),
+        Kind::PodDisruptionBudgetKind => PodDisruptionBudgetView::unmarshal_spec(obj.spec).is_Ok(),
         Kind::RoleBindingKind => RoleBindingView::unmarshal_spec(obj.spec).is_Ok(),
         Kind::RoleKind => RoleView::unmarshal_spec(obj.spec).is_Ok(),
         Kind::SecretKind => SecretView::unmarshal_spec(obj.spec).is_Ok(),
         Kind::ServiceKind => ServiceView::unmarshal_spec(obj.spec).is_Ok(),
         Kind::StatefulSetKind => StatefulSetView::unmarshal_spec(obj.spec).is_Ok(),
         Kind::ServiceAccountKind => ServiceAccountView::unmarshal_spec(obj.spec).is_Ok(),
+        Kind::StorageClassKind => StorageClassView::unmarshal_spec(obj.spec).is_Ok(),
         Kind::CustomResourceKind(_) => K::unmarshal_spec(obj.spec).is_Ok(),
     }
 }
@@ -42,16 +50,24 @@ pub open spec fn unmarshallable_spec<K: CustomResourceView>(obj: DynamicObjectVi
 #[verifier(inline)]
 pub open spec fn unmarshallable_status<K: CustomResourceView>(obj: DynamicObjectView) -> bool {
     match obj.kind {
+        Kind::ClusterRoleKind => ClusterRoleView::unmarshal_status(obj.status).is_Ok(),
+        Kind::ClusterRoleBindingKind => ClusterRoleBindingView::unmarshal_status(obj.status).is_Ok(),
         Kind::ConfigMapKind => ConfigMapView::unmarshal_status(obj.status).is_Ok(),
         Kind::DaemonSetKind => DaemonSetView::unmarshal_status(obj.status).is_Ok(),
+        Kind::EventKind => EventView::unmarshal_status(obj.status).is_Ok(),
+        Kind::JobKind => JobView::unmarshal_status(obj.status).is_Ok(),
+        Kind::NamespaceKind => NamespaceView::unmarshal_status(obj.status).is_Ok(),
+        Kind::PersistentVolumeKind => PersistentVolumeView::unmarshal_status(obj.status).is_Ok(),
         Kind::PersistentVolumeClaimKind => PersistentVolumeClaimView::unmarshal_status(obj.status).is_Ok(),
         Kind::PodKind => PodView::unmarshal_status(obj.status).is_Ok(),
+        Kind::PodDisruptionBudgetKind => PodDisruptionBudgetView::unmarshal_status(obj.status).is_Ok(),
         Kind::RoleBindingKind => RoleBindingView::unmarshal_status(obj.status).is_Ok(),
         Kind::RoleKind => RoleView::unmarshal_status(obj.status).is_Ok(),
         Kind::SecretKind => SecretView::unmarshal_status(obj.status).is_Ok(),
         Kind::ServiceKind => ServiceView::unmarshal_status(obj.status).is_Ok(),
         Kind::StatefulSetKind => StatefulSetView::unmarshal_status(obj.status).is_Ok(),
         Kind::ServiceAccountKind => ServiceAccountView::unmarshal_status(obj.status).is_Ok(),
+        Kind::StorageClassKind => StorageClassView::unmarshal_status(obj.status).is_Ok(),
         Kind::CustomResourceKind(_) => K::unmarshal_status(obj.status).is_Ok(),
     }
 }
@@ -82,16 +98,24 @@ pub open spec fn metadata_transition_validity_check(obj: DynamicObjectView, old_
 
 pub open spec fn valid_object<K: CustomResourceView>(obj: DynamicObjectView) -> bool {
     match obj.kind {
+        Kind::ClusterRoleKind => ClusterRoleView::unmarshal(obj).get_Ok_0().state_validation(),
+        Kind::ClusterRoleBindingKind => ClusterRoleBindingView::unmarshal(obj).get_Ok_0().state_validation(),
         Kind::ConfigMapKind => ConfigMapView::unmarshal(obj).get_Ok_0().state_validation(),
         Kind::DaemonSetKind => DaemonSetView::unmarshal(obj).get_Ok_0().state_validation(),
+        Kind::EventKind => EventView::unmarshal(obj).get_Ok_0().state_validation(),
+        Kind::JobKind => JobView::unmarshal(obj).get_Ok_0().state_validation(),
+        Kind::NamespaceKind => NamespaceView::unmarshal(obj).get_Ok_0().state_validation(),
+        Kind::PersistentVolumeKind => PersistentVolumeView::unmarshal(obj).get_Ok_0().state_validation(),
         Kind::PersistentVolumeClaimKind => PersistentVolumeClaimView::unmarshal(obj).get_Ok_0().state_validation(),
         Kind::PodKind => PodView::unmarshal(obj).get_Ok_0().state_validation(),
+        Kind::PodDisruptionBudgetKind => PodDisruptionBudgetView::unmarshal(obj).get_Ok_0().state_validation(),
         Kind::RoleBindingKind => RoleBindingView::unmarshal(obj).get_Ok_0().state_validation(),
         Kind::RoleKind => RoleView::unmarshal(obj).get_Ok_0().state_validation(),
         Kind::SecretKind => SecretView::unmarshal(obj).get_Ok_0().state_validation(),
         Kind::ServiceKind => ServiceView::unmarshal(obj).get_Ok_0().state_validation(),
         Kind::StatefulSetKind => StatefulSetView::unmarshal(obj).get_Ok_0().state_validation(),
         Kind::ServiceAccountKind => ServiceAccountView::unmarshal(obj).get_Ok_0().state_validation(),
+        Kind::StorageClassKind => StorageClassView::unmarshal(obj).get_Ok_0().state_validation(),
         Kind::CustomResourceKind(_) => K::unmarshal(obj).get_Ok_0().state_validation(),
     }
 }
@@ -106,16 +130,24 @@ pub open spec fn object_validity_check<K: CustomResourceView>(obj: DynamicObject
 
 pub open spec fn valid_transition<K: CustomResourceView>(obj: DynamicObjectView, old_obj: DynamicObjectView) -> bool {
     match obj.kind {
+        Kind::ClusterRoleKind => ClusterRoleView::unmarshal(obj).get_Ok_0().transition_validation(ClusterRoleView::unmarshal(old_obj).get_Ok_0()),
+        Kind::ClusterRoleBindingKind => ClusterRoleBindingView::unmarshal(obj).get_Ok_0().transition_validation(ClusterRoleBindingView::unmarshal(old_obj).get_Ok_0()),
         Kind::ConfigMapKind => ConfigMapView::unmarshal(obj).get_Ok_0().transition_validation(ConfigMapView::unmarshal(old_obj).get_Ok_0()),
         Kind::DaemonSetKind => DaemonSetView::unmarshal(obj).get_Ok_0().transition_validation(DaemonSetView::unmarshal(old_obj).get_Ok_0()),
+        Kind::EventKind => EventView::unmarshal(obj).get_Ok_0().transition_validation(EventView::unmarshal(old_obj).get_Ok_0()),
+        Kind::JobKind => JobView::unmarshal(obj).get_Ok_0().transition_validation(JobView::unmarshal(old_obj).get_Ok_0()),
+        Kind::NamespaceKind => NamespaceView::unmarshal(obj).get_Ok_0().transition_validation(NamespaceView::unmarshal(old_obj).get_Ok_0()),
+        Kind::PersistentVolumeKind => PersistentVolumeView::unmarshal(obj).get_Ok_0().transition_validation(PersistentVolumeView::unmarshal(old_obj).get_Ok_0()),
         Kind::PersistentVolumeClaimKind => PersistentVolumeClaimView::unmarshal(obj).get_Ok_0().transition_validation(PersistentVolumeClaimView::unmarshal(old_obj).get_Ok_0()),
         Kind::PodKind => PodView::unmarshal(obj).get_Ok_0().transition_validation(PodView::unmarshal(old_obj).get_Ok_0()),
+        Kind::PodDisruptionBudgetKind => PodDisruptionBudgetView::unmarshal(obj).get_Ok_0().transition_validation(PodDisruptionBudgetView::unmarshal(old_obj).get_Ok_0()),
         Kind::RoleBindingKind => RoleBindingView::unmarshal(obj).get_Ok_0().transition_validation(RoleBindingView::unmarshal(old_obj).get_Ok_0()),
         Kind::RoleKind => RoleView::unmarshal(obj).get_Ok_0().transition_validation(RoleView::unmarshal(old_obj).get_Ok_0()),
         Kind::SecretKind => SecretView::unmarshal(obj).get_Ok_0().transition_validation(SecretView::unmarshal(old_obj).get_Ok_0()),
         Kind::ServiceKind => ServiceView::unmarshal(obj).get_Ok_0().transition_validation(ServiceView::unmarshal(old_obj).get_Ok_0()),
         Kind::StatefulSetKind => StatefulSetView::unmarshal(obj).get_Ok_0().transition_validation(StatefulSetView::unmarshal(old_obj).get_Ok_0()),
         Kind::ServiceAccountKind => ServiceAccountView::unmarshal(obj).get_Ok_0().transition_validation(ServiceAccountView::unmarshal(old_obj).get_Ok_0()),
+        Kind::StorageClassKind => StorageClassView::unmarshal(obj).get_Ok_0().transition_validation(StorageClassView::unmarshal(old_obj).get_Ok_0()),
         Kind::CustomResourceKind(_) => K::unmarshal(obj).get_Ok_0().transition_validation(K::unmarshal(old_obj).get_Ok_0()),
     }
 }
@@ -130,20 +162,46 @@ pub open spec fn object_transition_validity_check<K: CustomResourceView>(obj: Dy
 
 pub open spec fn marshalled_default_status<K: CustomResourceView>(kind: Kind) -> Value {
     match kind {
+        Kind::ClusterRoleKind => ClusterRoleView::marshal_status(ClusterRoleView::default().status()),
+        Kind::ClusterRoleBindingKind => ClusterRoleBindingView::marshal_status(ClusterRoleBindingView::default().status()),
         Kind::ConfigMapKind => ConfigMapView::marshal_status(ConfigMapView::default().status()),
         Kind::DaemonSetKind => DaemonSetView::marshal_status(DaemonSetView::default().status()),
+        Kind::EventKind => EventView::marshal_status(EventView::default().status()),
+        Kind::JobKind => JobView::marshal_status(JobView::default().status()),
+        Kind::NamespaceKind => NamespaceView::marshal_status(NamespaceView::default().status()),
+        Kind::PersistentVolumeKind => PersistentVolumeView::marshal_status(PersistentVolumeView::default().status()),
         Kind::PersistentVolumeClaimKind => PersistentVolumeClaimView::marshal_status(PersistentVolumeClaimView::default().status()),
         Kind::PodKind => PodView::marshal_status(PodView::default().status()),
+        Kind::PodDisruptionBudgetKind => PodDisruptionBudgetView::marshal_status(PodDisruptionBudgetView::default().status()),
         Kind::RoleBindingKind => RoleBindingView::marshal_status(RoleBindingView::default().status()),
         Kind::RoleKind => RoleView::marshal_status(RoleView::default().status()),
         Kind::SecretKind => SecretView::marshal_status(SecretView::default().status()),
         Kind::ServiceKind => ServiceView::marshal_status(ServiceView::default().status()),
         Kind::StatefulSetKind => StatefulSetView::marshal_status(StatefulSetView::default().status()),
         Kind::ServiceAccountKind => ServiceAccountView::marshal_status(ServiceAccountView::default().status()),
+        Kind::StorageClassKind => StorageClassView::marshal_status(StorageClassView::default().status()),
         Kind::CustomResourceKind(_) => K::marshal_status(K::default().status()),
     }
 }
 
+// Whether objects of the kind live in a namespace.
+// The only custom resource known by the API server model is K, whose scope is given by its CRD.
+pub open spec fn kind_is_namespaced<K: CustomResourceView>(kind: Kind) -> bool {
+    match kind {
+        Kind::CustomResourceKind(_) => K::is_namespaced(),
+        _ => kind.is_namespaced(),
+    }
+}
+
+// The API server merges the write-only string_data of a secret into its data before storing it.
+pub open spec fn merged_spec(kind: Kind, spec: Value) -> Value {
+    if kind == Kind::SecretKind {
+        SecretView::merge_string_data_in_spec(spec)
+    } else {
+        spec
+    }
+}
+
 #[verifier(inline)]
 pub open spec fn handle_get_request(req: GetRequest, s: ApiServerState) -> GetResponse {
     if !s.resources.contains_key(req.key) {
@@ -172,6 +230,9 @@ pub open spec fn create_request_admission_check<K: CustomResourceView>(req: Crea
     } else if req.obj.metadata.namespace.is_Some() && req.namespace != req.obj.metadata.namespace.get_Some_0() {
         // Creation fails because the namespace of the provided object does not match the namespace sent on the request
         Some(APIError::BadRequest)
+    } else if !kind_is_namespaced::<K>(req.obj.kind) && req.namespace != ""@ {
+        // Creation fails because a cluster-scoped object is created in a namespace
+        Some(APIError::BadRequest)
     } else if !unmarshallable_object::<K>(req.obj) {
         // Creation fails because the provided object is not well formed
         Some(APIError::BadRequest) // TODO: should the error be BadRequest?
@@ -223,7 +284,7 @@ pub open spec fn handle_create_request<K: CustomResourceView>(req: CreateRequest
                 deletion_timestamp: None, // Unset deletion timestamp for new object
                 ..req.obj.metadata
             },
-            spec: req.obj.spec,
+            spec: merged_spec(req.obj.kind, req.obj.spec), // Merge string_data into data if it is a secret
             status: marshalled_default_status::<K>(req.obj.kind), // Overwrite the status with the default one
         };
         if s.resources.contains_key(created_obj.object_ref()) {
@@ -372,7 +433,7 @@ pub open spec fn updated_object(req: UpdateRequest, old_obj: DynamicObjectView)
             deletion_timestamp: old_obj.metadata.deletion_timestamp, // Ignore any change to deletion_timestamp
             ..req.obj.metadata
         },
-        spec: req.obj.spec,
+        spec: merged_spec(req.obj.kind, req.obj.spec), // Merge string_data into data if it is a secret
         status: old_obj.status, // Ignore any change to status
     };
     updated_obj
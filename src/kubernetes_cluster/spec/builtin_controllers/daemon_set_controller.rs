@@ -35,10 +35,14 @@ pub open spec fn run_daemon_set_controller() -> BuiltinControllersAction<E::Inpu
             let resources = s.resources;
             let key = input.key;
             let number_ready = input.choice.get_DaemonSetController_number_ready();
+            let desired_number_scheduled = input.choice.get_DaemonSetController_desired_number_scheduled();
+            let updated_number_scheduled = input.choice.get_DaemonSetController_updated_number_scheduled();
             let old_daemon_set = DaemonSetView::unmarshal(resources[key]).get_Ok_0();
             let new_daemon_set = DaemonSetView {
                 status: Some(DaemonSetStatusView {
                     number_ready: number_ready,
+                    desired_number_scheduled: desired_number_scheduled,
+                    updated_number_scheduled: updated_number_scheduled,
                 }),
                 ..old_daemon_set
             };
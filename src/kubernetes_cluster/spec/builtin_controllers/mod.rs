@@ -2,6 +2,8 @@
 // SPDX-License-Identifier: MIT
 pub mod daemon_set_controller;
 pub mod garbage_collector;
+pub mod job_controller;
+pub mod persistent_volume_controller;
 pub mod stabilizer;
 pub mod state_machine;
 pub mod stateful_set_controller;
@@ -0,0 +1,61 @@
+// Copyright 2022 VMware, Inc.
+// SPDX-License-Identifier: MIT
+#![allow(unused_imports)]
+use crate::external_api::spec::*;
+use crate::kubernetes_api_objects::spec::prelude::*;
+use crate::kubernetes_cluster::spec::{
+    api_server::types::ApiServerState, builtin_controllers::types::*, cluster::Cluster, message::*,
+};
+use crate::reconciler::spec::reconciler::Reconciler;
+use crate::state_machine::action::*;
+use crate::state_machine::state_machine::*;
+use crate::temporal_logic::defs::*;
+use vstd::{multiset::*, prelude::*};
+
+verus! {
+
+impl <K: CustomResourceView, E: ExternalAPI, R: Reconciler<K, E>> Cluster<K, E, R> {
+
+// The persistent volume controller binds a claim to a volume and reports it in the phase of the claim.
+// Persistent volumes are not modeled, so the phase is chosen by the top level state machine.
+pub open spec fn run_persistent_volume_controller() -> BuiltinControllersAction<E::Input, E::Output> {
+    Action {
+        precondition: |input: BuiltinControllersActionInput, s: ApiServerState| {
+            let resources = s.resources;
+            let key = input.key;
+            // The persistent volume controller is chosen by the top level state machine
+            &&& input.choice.is_PersistentVolumeController()
+            // The object exists in the cluster state
+            &&& resources.contains_key(key)
+            // and it is a persistent volume claim
+            &&& key.kind == PersistentVolumeClaimView::kind()
+            // and it is not stable yet
+            &&& !s.stable_resources.contains(key)
+        },
+        transition: |input: BuiltinControllersActionInput, s: ApiServerState| {
+            let resources = s.resources;
+            let key = input.key;
+            let phase = input.choice.get_PersistentVolumeController_phase();
+            let old_pvc = PersistentVolumeClaimView::unmarshal(resources[key]).get_Ok_0();
+            let new_pvc = PersistentVolumeClaimView {
+                status: Some(PersistentVolumeClaimStatusView {
+                    phase: Some(phase),
+                }),
+                ..old_pvc
+            };
+            let update_status_req_msg = Message::built_in_controller_req_msg(Message::update_status_req_msg_content(
+                input.key.namespace, input.key.name, new_pvc.marshal(), input.rest_id_allocator.allocate().1
+            ));
+            let s_prime = s;
+            let output = BuiltinControllersActionOutput {
+                send: Multiset::singleton(update_status_req_msg),
+                rest_id_allocator: input.rest_id_allocator.allocate().0,
+            };
+            (s_prime, output)
+        },
+    }
+}
+
+}
+
+}
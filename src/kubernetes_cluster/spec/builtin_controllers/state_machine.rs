@@ -30,6 +30,8 @@ pub open spec fn builtin_controllers() -> BuiltinControllersStateMachine<E::Inpu
             Self::run_garbage_collector(),
             Self::run_stateful_set_controller(),
             Self::run_daemon_set_controller(),
+            Self::run_job_controller(),
+            Self::run_persistent_volume_controller(),
             Self::run_stabilizer()
         ],
         step_to_action: |step: BuiltinControllersStep| {
@@ -37,6 +39,8 @@ pub open spec fn builtin_controllers() -> BuiltinControllersStateMachine<E::Inpu
                 BuiltinControllersStep::RunGarbageCollector => Self::run_garbage_collector(),
                 BuiltinControllersStep::RunStatefulSetController => Self::run_stateful_set_controller(),
                 BuiltinControllersStep::RunDaemonSetController => Self::run_daemon_set_controller(),
+                BuiltinControllersStep::RunJobController => Self::run_job_controller(),
+                BuiltinControllersStep::RunPersistentVolumeController => Self::run_persistent_volume_controller(),
                 BuiltinControllersStep::RunStabilizer => Self::run_stabilizer(),
             }
         },
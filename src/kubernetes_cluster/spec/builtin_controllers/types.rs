@@ -7,6 +7,7 @@ use crate::kubernetes_cluster::spec::message::*;
 use crate::state_machine::action::*;
 use crate::state_machine::state_machine::*;
 use crate::temporal_logic::defs::*;
+use crate::vstd_ext::string_view::*;
 use vstd::{multiset::*, prelude::*};
 
 verus! {
@@ -18,6 +19,8 @@ pub enum BuiltinControllersStep {
     RunGarbageCollector,
     RunStatefulSetController,
     RunDaemonSetController,
+    RunJobController,
+    RunPersistentVolumeController,
     RunStabilizer,
 }
 
@@ -25,7 +28,9 @@ pub enum BuiltinControllersStep {
 pub enum BuiltinControllerChoice {
     GarbageCollector,
     StatefulSetController{ready_replicas: int},
-    DaemonSetController{number_ready: int},
+    DaemonSetController{number_ready: int, desired_number_scheduled: int, updated_number_scheduled: int},
+    JobController{succeeded: int, failed: int},
+    PersistentVolumeController{phase: StringView},
     Stabilizer,
 }
 
@@ -276,7 +276,8 @@ pub open spec fn disable_crash() -> Action<Self, (), ()> {
 }
 
 // This action fails a request sent to the Kubernetes API in a transient way:
-// the request fails with timeout error or conflict error (caused by resource version conflicts).
+// the request fails with a transient error (e.g., timeout, or throttled by API priority and fairness)
+// or conflict error (caused by resource version conflicts).
 pub open spec fn fail_request_transiently() -> Action<Self, (MsgType<E>, APIError), ()> {
     let result = |input: (MsgType<E>, APIError), s: Self| {
         let req_msg = input.0;
@@ -296,7 +297,7 @@ pub open spec fn fail_request_transiently() -> Action<Self, (MsgType<E>, APIErro
             &&& s.transient_failure_enabled
             &&& req_msg.dst.is_ApiServer()
             &&& req_msg.content.is_APIRequest()
-            &&& (api_err.is_Timeout() || api_err.is_ServerTimeout() || api_err.is_Conflict())
+            &&& (api_err.is_transient() || api_err.is_Conflict())
             &&& result(input, s).is_Enabled()
         },
         transition: |input: (MsgType<E>, APIError), s: Self| {
@@ -21,6 +21,7 @@ use deps_hack::tokio;
 use deps_hack::tracing::{error, info};
 use deps_hack::tracing_subscriber;
 use shim_layer::controller_runtime::run_controller;
+use shim_layer::watch_scope::WatchScope;
 use std::env;
 
 #[tokio::main]
@@ -28,6 +29,8 @@ async fn main() -> Result<()> {
     tracing_subscriber::fmt::init();
     let args: Vec<String> = env::args().collect();
     let cmd = args[1].clone();
+    // Flags such as --namespaces and --selector follow the command
+    let flags = &args[2..];
 
     if cmd == String::from("export") {
         println!(
@@ -36,12 +39,20 @@ async fn main() -> Result<()> {
         );
     } else if cmd == String::from("run") {
         info!("running rabbitmq-controller");
-        run_controller::<deps_hack::RabbitmqCluster, RabbitmqReconciler>(false).await?;
+        run_controller::<deps_hack::RabbitmqCluster, RabbitmqReconciler>(
+            false,
+            WatchScope::from_args(flags)?,
+        )
+        .await?;
     } else if cmd == String::from("crash") {
         info!("running rabbitmq-controller in crash-testing mode");
-        run_controller::<deps_hack::RabbitmqCluster, RabbitmqReconciler>(true).await?;
+        run_controller::<deps_hack::RabbitmqCluster, RabbitmqReconciler>(
+            true,
+            WatchScope::from_args(flags)?,
+        )
+        .await?;
     } else {
-        error!("wrong command; please use \"export\", \"run\" or \"crash\"");
+        error!("wrong command; please use \"export\", \"run\" or \"crash\" (\"run\" and \"crash\" accept \"--namespaces\" and \"--selector\")");
     }
     Ok(())
 }
@@ -0,0 +1,89 @@
+// Copyright 2022 VMware, Inc.
+// SPDX-License-Identifier: MIT
+#![allow(unused_imports)]
+
+pub mod external_api;
+pub mod kubernetes_api_objects;
+pub mod kubernetes_cluster;
+#[path = "unverified_controllers/rabbitmq_topology_controller/mod.rs"]
+pub mod rabbitmq_topology_controller;
+pub mod reconciler;
+pub mod shim_layer;
+pub mod state_machine;
+pub mod temporal_logic;
+pub mod vstd_ext;
+
+use crate::rabbitmq_topology_controller::{
+    permission::exec::reconciler::RabbitmqPermissionReconciler,
+    user::exec::reconciler::RabbitmqUserReconciler,
+    vhost::exec::reconciler::RabbitmqVhostReconciler,
+};
+use deps_hack::anyhow::Result;
+use deps_hack::futures;
+use deps_hack::kube::CustomResourceExt;
+use deps_hack::serde_yaml;
+use deps_hack::tokio;
+use deps_hack::tracing::{error, info};
+use deps_hack::tracing_subscriber;
+use shim_layer::controller_runtime::run_controller;
+use shim_layer::watch_scope::WatchScope;
+use std::env;
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    tracing_subscriber::fmt::init();
+    let args: Vec<String> = env::args().collect();
+    let cmd = args[1].clone();
+    // Flags such as --namespaces and --selector follow the command
+    let flags = &args[2..];
+
+    if cmd == String::from("export") {
+        println!(
+            "{}",
+            serde_yaml::to_string(&deps_hack::RabbitmqUser::crd())?
+        );
+        println!(
+            "{}",
+            serde_yaml::to_string(&deps_hack::RabbitmqVhost::crd())?
+        );
+        println!(
+            "{}",
+            serde_yaml::to_string(&deps_hack::RabbitmqPermission::crd())?
+        );
+    } else if cmd == String::from("run") {
+        info!("running rabbitmq-topology-controller");
+        let scope = WatchScope::from_args(flags)?;
+        let user_controller_fut =
+            run_controller::<deps_hack::RabbitmqUser, RabbitmqUserReconciler>(false, scope.clone());
+        let vhost_controller_fut =
+            run_controller::<deps_hack::RabbitmqVhost, RabbitmqVhostReconciler>(false, scope.clone());
+        let permission_controller_fut = run_controller::<
+            deps_hack::RabbitmqPermission,
+            RabbitmqPermissionReconciler,
+        >(false, scope);
+        futures::try_join!(
+            user_controller_fut,
+            vhost_controller_fut,
+            permission_controller_fut
+        )?;
+    } else if cmd == String::from("crash") {
+        info!("running rabbitmq-topology-controller in crash-testing mode");
+        let scope = WatchScope::from_args(flags)?;
+        let user_controller_fut =
+            run_controller::<deps_hack::RabbitmqUser, RabbitmqUserReconciler>(true, scope.clone());
+        let vhost_controller_fut =
+            run_controller::<deps_hack::RabbitmqVhost, RabbitmqVhostReconciler>(true, scope.clone());
+        let permission_controller_fut = run_controller::<
+            deps_hack::RabbitmqPermission,
+            RabbitmqPermissionReconciler,
+        >(true, scope);
+        futures::try_join!(
+            user_controller_fut,
+            vhost_controller_fut,
+            permission_controller_fut
+        )?;
+    } else {
+        error!("wrong command; please use \"export\", \"run\" or \"crash\" (\"run\" and \"crash\" accept \"--namespaces\" and \"--selector\")");
+    }
+    Ok(())
+}
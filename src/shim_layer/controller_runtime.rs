@@ -5,7 +5,7 @@ use crate::external_api::exec::*;
 use crate::kubernetes_api_objects::error::*;
 use crate::kubernetes_api_objects::exec::{api_method::*, dynamic::*, resource::*};
 use crate::reconciler::exec::{io::*, reconciler::*};
-use crate::shim_layer::fault_injection::*;
+use crate::shim_layer::{event_recorder::*, fault_injection::*, retry_after::*, watch_scope::*};
 use builtin::*;
 use builtin_macros::*;
 use core::fmt::Debug;
@@ -13,14 +13,14 @@ use core::hash::Hash;
 use deps_hack::anyhow::Result;
 use deps_hack::futures::{Future, Stream, StreamExt, TryFuture};
 use deps_hack::kube::{
-    api::{Api, DeleteParams, ListParams, ObjectMeta, PostParams, Resource},
+    api::{Api, ApiResource, DeleteParams, ListParams, ObjectMeta, PostParams, Resource},
     runtime::{
         controller::{self, Action, Controller},
         reflector, watcher,
     },
     Client, CustomResource, CustomResourceExt,
 };
-use deps_hack::kube_core::{ErrorResponse, NamespaceResourceScope};
+use deps_hack::kube_core::ErrorResponse;
 use deps_hack::serde::{de::DeserializeOwned, Serialize};
 use deps_hack::tracing::{error, info, warn};
 use deps_hack::Error;
@@ -36,10 +36,13 @@ use vstd::{string::*, view::*};
 // run_controller prepares and runs the controller. It requires:
 // K: the custom resource type
 // ReconcilerType: the reconciler type
-pub async fn run_controller<K, ReconcilerType>(fault_injection: bool) -> Result<()>
+pub async fn run_controller<K, ReconcilerType>(
+    fault_injection: bool,
+    scope: WatchScope,
+) -> Result<()>
 where
     K: Clone
-        + Resource<Scope = NamespaceResourceScope>
+        + Resource
         + CustomResourceExt
         + DeserializeOwned
         + Debug
@@ -49,31 +52,64 @@ where
         + 'static,
     K::DynamicType: Default + Eq + Hash + Clone + Debug + Unpin,
     ReconcilerType: Reconciler + Send + Sync,
+    ReconcilerType::ExternalAPIType: AsyncExternalAPIShimLayer,
     ReconcilerType::R: ResourceWrapper<K> + Send,
     ReconcilerType::T: Send,
     <ReconcilerType::ExternalAPIType as ExternalAPIShimLayer>::Input: Send,
     <ReconcilerType::ExternalAPIType as ExternalAPIShimLayer>::Output: Send,
 {
-    let client = Client::try_default().await?;
-    let crs = Api::<K>::all(client.clone());
+    let client = make_client().await?;
+    // The custom resource is watched as a DynamicObject so that the same code path
+    // works for both namespaced and cluster-scoped custom resources
+    // (kube-rs only allows Api::namespaced for types with NamespaceResourceScope).
+    let cr_api_resource = ApiResource::erase::<K>(&K::DynamicType::default());
+    let cr_is_namespaced = cr_is_namespaced::<K>();
+    if !cr_is_namespaced && !scope.namespaces.is_empty() {
+        warn!(
+            "{} is cluster-scoped, ignoring namespaces {:?} in the scope",
+            cr_api_resource.kind, scope.namespaces
+        );
+    }
+    // One Api (and hence one watch) per namespace in scope,
+    // so that the controller does not need to list or watch cluster-wide.
+    let cr_apis: Vec<Api<deps_hack::kube::api::DynamicObject>> =
+        if !cr_is_namespaced || scope.namespaces.is_empty() {
+            vec![Api::all_with(client.clone(), &cr_api_resource)]
+        } else {
+            scope
+                .namespaces
+                .iter()
+                .map(|namespace| Api::namespaced_with(client.clone(), namespace, &cr_api_resource))
+                .collect()
+        };
 
     // Build the async closure on top of reconcile_with
-    let reconcile = |cr: Arc<K>, ctx: Arc<Data>| async move {
-        return reconcile_with::<K, ReconcilerType>(cr, ctx, fault_injection).await;
+    let reconcile = |cr: Arc<deps_hack::kube::api::DynamicObject>, ctx: Arc<Data>| async move {
+        return with_retry_after_scope(reconcile_with::<K, ReconcilerType>(
+            cr,
+            ctx,
+            fault_injection,
+        ))
+        .await;
     };
 
-    info!("starting controller");
+    info!("starting controller with scope {:?}", scope);
     // TODO: the controller should also listen to the owned resources
-    Controller::new(crs, watcher::Config::default()) // The controller's reconcile is triggered when a CR is created/updated
-        .shutdown_on_signal()
-        .run(reconcile, error_policy, Arc::new(Data { client })) // The reconcile function is registered
-        .for_each(|res| async move {
-            match res {
-                Ok(o) => info!("reconciled {:?}", o),
-                Err(e) => info!("reconcile failed: {}", e),
-            }
-        })
-        .await;
+    let controllers = cr_apis.into_iter().map(|crs| {
+        let data = Arc::new(Data {
+            client: client.clone(),
+        });
+        Controller::new_with(crs, scope.watcher_config(), cr_api_resource.clone()) // The controller's reconcile is triggered when a CR is created/updated
+            .shutdown_on_signal()
+            .run(reconcile, error_policy, data) // The reconcile function is registered
+            .for_each(|res| async move {
+                match res {
+                    Ok(o) => info!("reconciled {:?}", o),
+                    Err(e) => info!("reconcile failed: {}", e),
+                }
+            })
+    });
+    deps_hack::futures::future::join_all(controllers).await;
     info!("controller terminated");
     Ok(())
 }
@@ -87,19 +123,15 @@ where
 // It ends the loop when the ReconcilerType reports the reconcile is done (ReconcilerType::reconcile_done)
 // or encounters error (ReconcilerType::reconcile_error).
 pub async fn reconcile_with<K, ReconcilerType>(
-    cr: Arc<K>,
+    cr: Arc<deps_hack::kube::api::DynamicObject>,
     ctx: Arc<Data>,
     fault_injection: bool,
 ) -> Result<Action, Error>
 where
-    K: Clone
-        + Resource<Scope = NamespaceResourceScope>
-        + CustomResourceExt
-        + DeserializeOwned
-        + Debug
-        + Serialize,
+    K: Clone + Resource + CustomResourceExt + DeserializeOwned + Debug + Serialize,
     K::DynamicType: Default + Clone + Debug,
     ReconcilerType: Reconciler,
+    ReconcilerType::ExternalAPIType: AsyncExternalAPIShimLayer,
     ReconcilerType::R: ResourceWrapper<K>,
 {
     let client = &ctx.client;
@@ -107,15 +139,15 @@ where
     let cr_name = cr.meta().name.as_ref().ok_or_else(|| {
         Error::ShimLayerError("Custom resource misses \".metadata.name\"".to_string())
     })?;
-    let cr_namespace = cr.meta().namespace.as_ref().ok_or_else(|| {
-        Error::ShimLayerError("Custom resources misses \".metadata.namespace\"".to_string())
-    })?;
+    // A cluster-scoped custom resource has no namespace and is keyed by the empty namespace
+    let cr_namespace = cr.meta().namespace.clone().unwrap_or_default();
     let cr_kind = K::kind(&K::DynamicType::default()).to_string();
 
     let cr_key = format!("{}/{}/{}", cr_kind, cr_namespace, cr_name);
     let log_header = format!("Reconciling {}:", cr_key);
 
-    let cr_api = Api::<K>::namespaced(client.clone(), &cr_namespace);
+    let cr_api_resource = ApiResource::erase::<K>(&K::DynamicType::default());
+    let cr_api = dynamic_api(client, &cr_namespace, &cr_api_resource);
     // Get the custom resource by a quorum read to Kubernetes' storage (etcd) to get the most updated custom resource
     let get_cr_resp = cr_api.get(&cr_name).await;
     match get_cr_resp {
@@ -133,18 +165,35 @@ where
                 "{} Get custom resource {} failed with error: {}, will retry reconcile",
                 log_header, cr_name, err
             );
-            return Ok(Action::requeue(Duration::from_secs(60)));
+            return Ok(Action::requeue(
+                retry_after_of(&err).unwrap_or(Duration::from_secs(60)),
+            ));
         }
         _ => {}
     }
+    let cr = match get_cr_resp.unwrap().try_parse::<K>() {
+        Err(err) => {
+            warn!(
+                "{} Parse custom resource {} failed with error: {}, will retry reconcile",
+                log_header, cr_name, err
+            );
+            return Ok(Action::requeue(Duration::from_secs(60)));
+        }
+        Ok(cr) => cr,
+    };
     // Wrap the custom resource with Verus-friendly wrapper type (which has a ghost version, i.e., view)
-    let cr = get_cr_resp.unwrap();
     info!(
         "{} Get cr {}",
         log_header,
         deps_hack::k8s_openapi::serde_json::to_string(&cr).unwrap()
     );
 
+    let recorder = EventRecorder::new(
+        client.clone(),
+        format!("{}-controller", cr_kind.to_lowercase()),
+        cr.object_ref(&K::DynamicType::default()),
+        &log_header,
+    );
     let cr_wrapper = ReconcilerType::R::from_kube(cr);
     let mut state = ReconcilerType::reconcile_init_state();
     let mut resp_option: Option<
@@ -153,6 +202,9 @@ where
     // check_fault_timing is only set to true right after the controller issues any create, update or delete request,
     // or external request
     let mut check_fault_timing: bool;
+    // retry_after is set when the last request is throttled by the API server,
+    // so that the next reconcile is delayed as the API server asks
+    let mut retry_after: Option<Duration> = None;
 
     // Call reconcile_core in a loop
     loop {
@@ -164,6 +216,12 @@ where
         }
         if ReconcilerType::reconcile_error(&state) {
             warn!("{} error", log_header);
+            recorder
+                .warning("ReconcileFailed", "Reconcile ended with an error".to_string())
+                .await;
+            if let Some(duration) = retry_after {
+                return Err(Error::Throttled(duration));
+            }
             return Err(Error::ReconcileCoreError);
         }
         // Feed the current reconcile state and get the new state and the pending request
@@ -174,16 +232,18 @@ where
             Some(request) => match request {
                 Request::KRequest(req) => {
                     let kube_resp: KubeAPIResponse;
+                    retry_after = None;
                     match req {
                         KubeAPIRequest::GetRequest(get_req) => {
-                            let api = Api::<deps_hack::kube::api::DynamicObject>::namespaced_with(
-                                client.clone(),
+                            let api = dynamic_api(
+                                client,
                                 &get_req.namespace,
                                 get_req.api_resource.as_kube_ref(),
                             );
                             let key = get_req.key();
                             match api.get(&get_req.name).await {
                                 Err(err) => {
+                                    retry_after = retry_after_of(&err);
                                     kube_resp = KubeAPIResponse::GetResponse(KubeGetResponse {
                                         res: Err(kube_error_to_ghost(&err)),
                                     });
@@ -198,8 +258,8 @@ where
                             }
                         }
                         KubeAPIRequest::ListRequest(list_req) => {
-                            let api = Api::<deps_hack::kube::api::DynamicObject>::namespaced_with(
-                                client.clone(),
+                            let api = dynamic_api(
+                                client,
                                 &list_req.namespace,
                                 list_req.api_resource.as_kube_ref(),
                             );
@@ -207,6 +267,7 @@ where
                             let lp = ListParams::default();
                             match api.list(&lp).await {
                                 Err(err) => {
+                                    retry_after = retry_after_of(&err);
                                     kube_resp = KubeAPIResponse::ListResponse(KubeListResponse {
                                         res: Err(kube_error_to_ghost(&err)),
                                     });
@@ -226,8 +287,8 @@ where
                         }
                         KubeAPIRequest::CreateRequest(create_req) => {
                             check_fault_timing = true;
-                            let api = Api::<deps_hack::kube::api::DynamicObject>::namespaced_with(
-                                client.clone(),
+                            let api = dynamic_api(
+                                client,
                                 &create_req.namespace,
                                 create_req.api_resource.as_kube_ref(),
                             );
@@ -236,6 +297,7 @@ where
                             let obj_to_create = create_req.obj.into_kube();
                             match api.create(&pp, &obj_to_create).await {
                                 Err(err) => {
+                                    retry_after = retry_after_of(&err);
                                     kube_resp =
                                         KubeAPIResponse::CreateResponse(KubeCreateResponse {
                                             res: Err(kube_error_to_ghost(&err)),
@@ -244,6 +306,9 @@ where
                                         "{} Create {} failed with error: {}",
                                         log_header, key, err
                                     );
+                                    recorder
+                                        .warning("CreateFailed", format!("Create {} failed: {}", key, err))
+                                        .await;
                                 }
                                 Ok(obj) => {
                                     kube_resp =
@@ -251,13 +316,16 @@ where
                                             res: Ok(DynamicObject::from_kube(obj)),
                                         });
                                     info!("{} Create {} done", log_header, key);
+                                    recorder
+                                        .normal("Created", format!("Created {}", key))
+                                        .await;
                                 }
                             }
                         }
                         KubeAPIRequest::DeleteRequest(delete_req) => {
                             check_fault_timing = true;
-                            let api = Api::<deps_hack::kube::api::DynamicObject>::namespaced_with(
-                                client.clone(),
+                            let api = dynamic_api(
+                                client,
                                 &delete_req.namespace,
                                 delete_req.api_resource.as_kube_ref(),
                             );
@@ -270,6 +338,7 @@ where
                             let key = delete_req.key();
                             match api.delete(&delete_req.name, &dp).await {
                                 Err(err) => {
+                                    retry_after = retry_after_of(&err);
                                     kube_resp =
                                         KubeAPIResponse::DeleteResponse(KubeDeleteResponse {
                                             res: Err(kube_error_to_ghost(&err)),
@@ -278,6 +347,9 @@ where
                                         "{} Delete {} failed with error: {}",
                                         log_header, key, err
                                     );
+                                    recorder
+                                        .warning("DeleteFailed", format!("Delete {} failed: {}", key, err))
+                                        .await;
                                 }
                                 Ok(_) => {
                                     kube_resp =
@@ -285,13 +357,16 @@ where
                                             res: Ok(()),
                                         });
                                     info!("{} Delete {} done", log_header, key);
+                                    recorder
+                                        .normal("Deleted", format!("Deleted {}", key))
+                                        .await;
                                 }
                             }
                         }
                         KubeAPIRequest::UpdateRequest(update_req) => {
                             check_fault_timing = true;
-                            let api = Api::<deps_hack::kube::api::DynamicObject>::namespaced_with(
-                                client.clone(),
+                            let api = dynamic_api(
+                                client,
                                 &update_req.namespace,
                                 update_req.api_resource.as_kube_ref(),
                             );
@@ -300,6 +375,7 @@ where
                             let obj_to_update = update_req.obj.into_kube();
                             match api.replace(&update_req.name, &pp, &obj_to_update).await {
                                 Err(err) => {
+                                    retry_after = retry_after_of(&err);
                                     kube_resp =
                                         KubeAPIResponse::UpdateResponse(KubeUpdateResponse {
                                             res: Err(kube_error_to_ghost(&err)),
@@ -308,6 +384,9 @@ where
                                         "{} Update {} failed with error: {}",
                                         log_header, key, err
                                     );
+                                    recorder
+                                        .warning("UpdateFailed", format!("Update {} failed: {}", key, err))
+                                        .await;
                                 }
                                 Ok(obj) => {
                                     kube_resp =
@@ -315,13 +394,14 @@ where
                                             res: Ok(DynamicObject::from_kube(obj)),
                                         });
                                     info!("{} Update {} done", log_header, key);
+                                    recorder.record(updated_event(&key)).await;
                                 }
                             }
                         }
                         KubeAPIRequest::UpdateStatusRequest(update_status_req) => {
                             check_fault_timing = true;
-                            let api = Api::<deps_hack::kube::api::DynamicObject>::namespaced_with(
-                                client.clone(),
+                            let api = dynamic_api(
+                                client,
                                 &update_status_req.namespace,
                                 update_status_req.api_resource.as_kube_ref(),
                             );
@@ -339,6 +419,7 @@ where
                                 .await
                             {
                                 Err(err) => {
+                                    retry_after = retry_after_of(&err);
                                     kube_resp = KubeAPIResponse::UpdateStatusResponse(
                                         KubeUpdateStatusResponse {
                                             res: Err(kube_error_to_ghost(&err)),
@@ -348,6 +429,9 @@ where
                                         "{} UpdateStatus {} failed with error: {}",
                                         log_header, key, err
                                     );
+                                    recorder
+                                        .warning("UpdateStatusFailed", format!("UpdateStatus {} failed: {}", key, err))
+                                        .await;
                                 }
                                 Ok(obj) => {
                                     kube_resp = KubeAPIResponse::UpdateStatusResponse(
@@ -364,7 +448,13 @@ where
                 }
                 Request::ExternalRequest(req) => {
                     check_fault_timing = true;
-                    let external_resp = ReconcilerType::ExternalAPIType::call_external_api(req);
+                    let external_resp =
+                        ReconcilerType::ExternalAPIType::call_external_api_async(req).await;
+                    if let Some(event) = external_api_failed_event(
+                        ReconcilerType::ExternalAPIType::error_of(&external_resp),
+                    ) {
+                        recorder.record(event).await;
+                    }
                     resp_option = Some(Response::ExternalResponse(external_resp));
                 }
             },
@@ -388,13 +478,38 @@ where
     return Ok(Action::requeue(Duration::from_secs(60)));
 }
 
+// cr_is_namespaced returns whether the custom resource K is namespaced, according to its CRD.
+pub fn cr_is_namespaced<K>() -> bool
+where
+    K: CustomResourceExt,
+{
+    K::crd().spec.scope == "Namespaced"
+}
+
+// dynamic_api returns the API handle for the objects of api_resource in the given namespace.
+// The empty namespace is used for cluster-scoped objects, for which the handle is not namespaced.
+pub fn dynamic_api(
+    client: &Client,
+    namespace: &String,
+    api_resource: &ApiResource,
+) -> Api<deps_hack::kube::api::DynamicObject> {
+    if namespace.is_empty() {
+        Api::all_with(client.clone(), api_resource)
+    } else {
+        Api::namespaced_with(client.clone(), namespace, api_resource)
+    }
+}
+
 // error_policy defines the controller's behavior when the reconcile ends with an error.
-pub fn error_policy<K>(_object: Arc<K>, _error: &Error, _ctx: Arc<Data>) -> Action
+pub fn error_policy<K>(_object: Arc<K>, error: &Error, _ctx: Arc<Data>) -> Action
 where
     K: Clone + Resource + DeserializeOwned + Debug + Send + Sync + 'static,
     K::DynamicType: Eq + Hash + Clone + Debug + Unpin,
 {
-    Action::requeue(Duration::from_secs(10))
+    match error {
+        Error::Throttled(duration) => Action::requeue(*duration),
+        _ => Action::requeue(Duration::from_secs(10)),
+    }
 }
 
 // Data is passed to reconcile_with.
@@ -405,8 +520,6 @@ pub struct Data {
 
 // kube_error_to_ghost translates the API error from kube-rs APIs
 // to the form that can be processed by reconcile_core.
-
-// TODO: match more error types.
 pub fn kube_error_to_ghost(error: &deps_hack::kube::Error) -> APIError {
     match error {
         deps_hack::kube::Error::Api(error_resp) => {
@@ -418,18 +531,54 @@ pub fn kube_error_to_ghost(error: &deps_hack::kube::Error) -> APIError {
                 APIError::BadRequest
             } else if &error_resp.reason == "Conflict" {
                 APIError::Conflict
+            } else if &error_resp.reason == "Forbidden" {
+                APIError::Forbidden
             } else if &error_resp.reason == "Invalid" {
                 APIError::Invalid
+            } else if &error_resp.reason == "MethodNotAllowed" {
+                APIError::NotSupported
             } else if &error_resp.reason == "InternalError" {
                 APIError::InternalError
             } else if &error_resp.reason == "Timeout" {
                 APIError::Timeout
             } else if &error_resp.reason == "ServerTimeout" {
                 APIError::ServerTimeout
+            } else if &error_resp.reason == "TooManyRequests" {
+                APIError::TooManyRequests
+            } else if &error_resp.reason == "Gone" || &error_resp.reason == "Expired" {
+                APIError::Gone
+            } else if &error_resp.reason == "Unauthorized" {
+                APIError::Unauthorized
+            } else if &error_resp.reason == "ServiceUnavailable" {
+                APIError::ServiceUnavailable
+            } else if &error_resp.reason == "RequestEntityTooLarge" {
+                APIError::RequestEntityTooLarge
             } else {
-                APIError::Other
+                // Some errors (e.g., the ones returned by aggregated API servers or proxies)
+                // come with an empty or unknown reason, so fall back to the HTTP status code.
+                match error_resp.code {
+                    400 => APIError::BadRequest,
+                    401 => APIError::Unauthorized,
+                    403 => APIError::Forbidden,
+                    404 => APIError::ObjectNotFound,
+                    405 => APIError::NotSupported,
+                    409 => APIError::Conflict,
+                    410 => APIError::Gone,
+                    413 => APIError::RequestEntityTooLarge,
+                    422 => APIError::Invalid,
+                    429 => APIError::TooManyRequests,
+                    500 => APIError::InternalError,
+                    503 => APIError::ServiceUnavailable,
+                    504 => APIError::Timeout,
+                    _ => APIError::Other,
+                }
             }
         }
+        deps_hack::kube::Error::HyperError(_)
+        | deps_hack::kube::Error::Service(_)
+        | deps_hack::kube::Error::ReadEvents(_)
+        | deps_hack::kube::Error::OpensslTls(_)
+        | deps_hack::kube::Error::TlsRequired => APIError::Transport,
         _ => APIError::Other,
     }
 }
@@ -0,0 +1,146 @@
+// Copyright 2022 VMware, Inc.
+// SPDX-License-Identifier: MIT
+use crate::kubernetes_api_objects::error::*;
+use crate::shim_layer::controller_runtime::kube_error_to_ghost;
+use deps_hack::anyhow::Result;
+use deps_hack::http::{header::RETRY_AFTER, HeaderValue, Response, StatusCode};
+use deps_hack::kube::{client::ClientBuilder, Client, Config};
+use deps_hack::tower::util::MapResponseLayer;
+use std::cell::Cell;
+use std::future::Future;
+use std::time::Duration;
+
+// The API server tells the client how long to back off (in seconds) in the Retry-After header
+// when it rejects a request with 429 or 503 (e.g., throttled by API priority and fairness).
+// kube-rs drops the response headers when it turns such a response into an error,
+// so the client built by make_client records the header before kube-rs handles the response,
+// and retry_after_of reads it back when the error reaches the shim layer.
+//
+// The header is recorded in a task local so that each reconcile (see with_retry_after_scope)
+// only sees the header of its own requests. The requests of one reconcile are sent one by one,
+// so the recorded header always belongs to the last failed request.
+deps_hack::tokio::task_local! {
+    static LAST_RETRY_AFTER: Cell<Option<Duration>>;
+}
+
+// The API server uses 1 second when it does not set the Retry-After header for a throttled request.
+const DEFAULT_RETRY_AFTER: Duration = Duration::from_secs(1);
+
+// make_client creates a client in the same way as Client::try_default,
+// with a layer that records the Retry-After header of each throttled response.
+pub async fn make_client() -> Result<Client> {
+    let config = Config::infer().await?;
+    let client = ClientBuilder::try_from(config)?
+        .with_layer(&MapResponseLayer::new(record_retry_after))
+        .build();
+    Ok(client)
+}
+
+// with_retry_after_scope runs the reconcile so that retry_after_of can see the Retry-After header
+// recorded for the requests sent by the reconcile.
+pub async fn with_retry_after_scope<F: Future>(reconcile: F) -> F::Output {
+    LAST_RETRY_AFTER.scope(Cell::new(None), reconcile).await
+}
+
+// retry_after_of returns how long to wait before retrying if the request is rejected
+// because the API server is overloaded, following the Retry-After header of the rejection.
+pub fn retry_after_of(error: &deps_hack::kube::Error) -> Option<Duration> {
+    match kube_error_to_ghost(error) {
+        APIError::TooManyRequests | APIError::ServiceUnavailable => Some(
+            LAST_RETRY_AFTER
+                .try_with(|retry_after| retry_after.take())
+                .ok()
+                .flatten()
+                .unwrap_or(DEFAULT_RETRY_AFTER),
+        ),
+        _ => None,
+    }
+}
+
+fn record_retry_after<B>(response: Response<B>) -> Response<B> {
+    if response.status() == StatusCode::TOO_MANY_REQUESTS
+        || response.status() == StatusCode::SERVICE_UNAVAILABLE
+    {
+        let retry_after = response
+            .headers()
+            .get(RETRY_AFTER)
+            .and_then(parse_retry_after);
+        // Responses to the requests sent outside any reconcile (e.g., by the watchers) are not recorded
+        let _ = LAST_RETRY_AFTER.try_with(|last| last.set(retry_after));
+    }
+    response
+}
+
+// parse_retry_after parses the Retry-After header set by the API server, which is always in seconds.
+// The HTTP-date form is not used by the API server and is ignored.
+fn parse_retry_after(value: &HeaderValue) -> Option<Duration> {
+    let seconds = value.to_str().ok()?.trim().parse::<u64>().ok()?;
+    Some(Duration::from_secs(seconds))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use deps_hack::http::Request;
+    use deps_hack::k8s_openapi::api::core::v1::ConfigMap;
+    use deps_hack::kube::{client::Body, Api};
+    use deps_hack::tower::{service_fn, ServiceBuilder};
+
+    fn client_responding_with(status: u16, retry_after: Option<&'static str>) -> Client {
+        let service = ServiceBuilder::new()
+            .layer(MapResponseLayer::new(record_retry_after))
+            .service(service_fn(move |_req: Request<Body>| async move {
+                let status_body = format!(
+                    "{{\"kind\":\"Status\",\"apiVersion\":\"v1\",\"status\":\"Failure\",\"message\":\"throttled\",\"reason\":\"{}\",\"code\":{}}}",
+                    if status == 429 { "TooManyRequests" } else { "NotFound" },
+                    status
+                );
+                let mut response = Response::builder().status(status);
+                if let Some(retry_after) = retry_after {
+                    response = response.header(RETRY_AFTER, retry_after);
+                }
+                Ok::<_, std::convert::Infallible>(
+                    response.body(Body::from(status_body.into_bytes())).unwrap(),
+                )
+            }));
+        Client::new(service, "default")
+    }
+
+    async fn retry_after_of_get(client: Client) -> Option<Duration> {
+        let api: Api<ConfigMap> = Api::namespaced(client, "default");
+        with_retry_after_scope(async { retry_after_of(&api.get("foo").await.unwrap_err()) }).await
+    }
+
+    #[test]
+    fn test_parse_retry_after() {
+        assert_eq!(
+            parse_retry_after(&HeaderValue::from_static("5")),
+            Some(Duration::from_secs(5))
+        );
+        assert_eq!(
+            parse_retry_after(&HeaderValue::from_static("Wed, 21 Oct 2015 07:28:00 GMT")),
+            None
+        );
+        assert_eq!(parse_retry_after(&HeaderValue::from_static("-1")), None);
+    }
+
+    #[deps_hack::tokio::test(crate = "deps_hack::tokio")]
+    async fn test_retry_after_of_follows_header() {
+        assert_eq!(
+            retry_after_of_get(client_responding_with(429, Some("7"))).await,
+            Some(Duration::from_secs(7))
+        );
+    }
+
+    #[deps_hack::tokio::test(crate = "deps_hack::tokio")]
+    async fn test_retry_after_of_without_header() {
+        assert_eq!(
+            retry_after_of_get(client_responding_with(429, None)).await,
+            Some(DEFAULT_RETRY_AFTER)
+        );
+        assert_eq!(
+            retry_after_of_get(client_responding_with(404, Some("7"))).await,
+            None
+        );
+    }
+}
@@ -0,0 +1,89 @@
+// Copyright 2022 VMware, Inc.
+// SPDX-License-Identifier: MIT
+use deps_hack::anyhow::{anyhow, Result};
+use deps_hack::kube::runtime::watcher;
+
+// WatchScope decides which custom resources the controller watches and reconciles.
+// By default the controller watches the custom resources in all namespaces, which
+// requires cluster-wide RBAC (ClusterRole). Restricting the scope to a set of namespaces
+// allows the controller to run with namespaced RBAC (Role) only, so that one controller
+// can be deployed per tenant namespace.
+#[derive(Clone, Debug, Default)]
+pub struct WatchScope {
+    // The namespaces to watch. Empty means all namespaces.
+    pub namespaces: Vec<String>,
+    // The label selector (e.g., "tenant=foo") that the watched custom resources should match.
+    pub label_selector: Option<String>,
+}
+
+impl WatchScope {
+    // from_args parses the command line flags that follow the "run" or "crash" command:
+    // --namespaces <ns1,ns2,...> and --selector <label-selector>.
+    pub fn from_args(args: &[String]) -> Result<WatchScope> {
+        let mut scope = WatchScope::default();
+        let mut iter = args.iter();
+        while let Some(arg) = iter.next() {
+            let value = iter
+                .next()
+                .ok_or_else(|| anyhow!("missing value for flag {}", arg))?;
+            if arg == "--namespaces" {
+                scope.namespaces = value
+                    .split(',')
+                    .filter(|ns| !ns.is_empty())
+                    .map(|ns| ns.to_string())
+                    .collect();
+            } else if arg == "--selector" {
+                scope.label_selector = Some(value.clone());
+            } else {
+                return Err(anyhow!(
+                    "unknown flag {}; please use \"--namespaces\" or \"--selector\"",
+                    arg
+                ));
+            }
+        }
+        Ok(scope)
+    }
+
+    pub fn watcher_config(&self) -> watcher::Config {
+        match &self.label_selector {
+            Some(selector) => watcher::Config::default().labels(selector),
+            None => watcher::Config::default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(flags: &[&str]) -> Vec<String> {
+        flags.iter().map(|flag| flag.to_string()).collect()
+    }
+
+    #[test]
+    fn test_from_args_without_flags() {
+        let scope = WatchScope::from_args(&args(&[])).unwrap();
+        assert!(scope.namespaces.is_empty());
+        assert_eq!(scope.label_selector, None);
+    }
+
+    #[test]
+    fn test_from_args_with_namespaces() {
+        let scope = WatchScope::from_args(&args(&["--namespaces", "foo,,bar"])).unwrap();
+        assert_eq!(scope.namespaces, vec!["foo".to_string(), "bar".to_string()]);
+        assert_eq!(scope.label_selector, None);
+
+        let scope =
+            WatchScope::from_args(&args(&["--selector", "tenant=foo", "--namespaces", "foo"]))
+                .unwrap();
+        assert_eq!(scope.namespaces, vec!["foo".to_string()]);
+        assert_eq!(scope.label_selector, Some("tenant=foo".to_string()));
+    }
+
+    #[test]
+    fn test_from_args_with_invalid_input() {
+        assert!(WatchScope::from_args(&args(&["--namespaces"])).is_err());
+        assert!(WatchScope::from_args(&args(&["--namespace", "foo"])).is_err());
+        assert!(WatchScope::from_args(&args(&["foo"])).is_err());
+    }
+}
@@ -50,6 +50,32 @@ impl<State, Input, Output> Action<State, Input, Output> {
         leads_to_weaken::<State>(spec, always(lift_state(self.pre(input))), lift_action(self.forward(input)), always(lift_state(pre)), lift_action(self.forward(input)));
         wf1_variant_temp::<State>(spec, lift_action(next), lift_action(self.forward(input)), lift_state(pre), lift_state(post));
     }
+
+    // `strong_fairness` assumption says that,
+    // it is always true that, if `pre` is infinitely often true, `forward` eventually becomes true
+    pub open spec fn strong_fairness(self, input: Input) -> TempPred<State> {
+        always(eventually(lift_state(self.pre(input)))).leads_to(lift_action(self.forward(input)))
+    }
+
+    // `sf1` is a specialized version of temporal_logic_rules::sf1 for Action
+    pub proof fn sf1(self, input: Input, spec: TempPred<State>, next: ActionPred<State>, pre: StatePred<State>, post: StatePred<State>)
+        requires
+            forall |s, s_prime: State| pre(s) && #[trigger] next(s, s_prime) ==> pre(s_prime) || post(s_prime),
+            forall |s, s_prime: State| pre(s) && #[trigger] next(s, s_prime) && self.forward(input)(s, s_prime) ==> post(s_prime),
+            spec.entails(always(lift_action(next))),
+            spec.entails(always(lift_state(pre)).leads_to(lift_state(self.pre(input)))),
+            spec.entails(self.strong_fairness(input)),
+        ensures spec.entails(lift_state(pre).leads_to(lift_state(post))),
+    {
+        sf1_variant_temp::<State>(spec, lift_action(next), lift_action(self.forward(input)), lift_state(self.pre(input)), lift_state(pre), lift_state(post));
+    }
+
+    pub proof fn strong_fairness_implies_weak_fairness(self, input: Input, spec: TempPred<State>)
+        requires spec.entails(self.strong_fairness(input)),
+        ensures spec.entails(self.weak_fairness(input)),
+    {
+        always_eventually_leads_to_implies_always_leads_to::<State>(spec, lift_state(self.pre(input)), lift_action(self.forward(input)));
+    }
 }
 
 #[is_variant]
@@ -2,3 +2,4 @@
 // SPDX-License-Identifier: MIT
 pub mod state_machine;
 pub mod action;
+pub mod refinement;
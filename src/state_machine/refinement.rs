@@ -0,0 +1,179 @@
+// Copyright 2022 VMware, Inc.
+// SPDX-License-Identifier: MIT
+#![allow(unused_imports)]
+use crate::temporal_logic::defs::*;
+use crate::temporal_logic::rules::*;
+use vstd::prelude::*;
+
+verus! {
+
+// A refinement from a concrete state machine (with state C) to an abstract state machine (with state A)
+// is given by an abstraction function `abs` that maps each concrete state to an abstract state.
+//
+// To prove the refinement, one shows that
+// (1) each initial concrete state maps to an initial abstract state, and
+// (2) each concrete step maps to an abstract step (the abstract next should allow stuttering
+//     if some concrete steps are invisible in the abstract state machine),
+// optionally under some invariant of the concrete state machine.
+// Then every execution of the concrete state machine maps to an execution of the abstract one,
+// and any temporal property proved for the abstract state machine holds for the mapped concrete executions.
+// For liveness, the fairness assumptions of the abstract state machine also need to be implied
+// by the ones of the concrete state machine (see refined_weak_fairness).
+
+// Maps each state of the concrete execution to the abstract state.
+pub open spec fn abstract_execution<C, A>(abs: spec_fn(C) -> A, ex: Execution<C>) -> Execution<A> {
+    Execution {
+        nat_to_state: |i: nat| abs((ex.nat_to_state)(i)),
+    }
+}
+
+// Returns a temporal predicate of the concrete state machine that is satisfied
+// iff `temp_pred` is satisfied by the abstract execution.
+pub open spec fn refined<C, A>(abs: spec_fn(C) -> A, temp_pred: TempPred<A>) -> TempPred<C> {
+    TempPred::new(|ex: Execution<C>| temp_pred.satisfied_by(abstract_execution(abs, ex)))
+}
+
+pub open spec fn init_refines<C, A>(c_init: StatePred<C>, a_init: StatePred<A>, abs: spec_fn(C) -> A) -> bool {
+    forall |c: C| #[trigger] c_init(c) ==> a_init(abs(c))
+}
+
+pub open spec fn next_refines<C, A>(c_next: ActionPred<C>, a_next: ActionPred<A>, inv: StatePred<C>, abs: spec_fn(C) -> A) -> bool {
+    forall |c: C, c_prime: C| inv(c) && #[trigger] c_next(c, c_prime) ==> a_next(abs(c), abs(c_prime))
+}
+
+pub proof fn abstract_execution_suffix<C, A>(abs: spec_fn(C) -> A, ex: Execution<C>, i: nat)
+    ensures abstract_execution(abs, ex).suffix(i) == abstract_execution(abs, ex.suffix(i)),
+{
+    execution_equality::<A>(abstract_execution(abs, ex).suffix(i), abstract_execution(abs, ex.suffix(i)));
+}
+
+// The step simulation theorem: the concrete executions map to executions of the abstract state machine.
+pub proof fn refinement_entails_abstract_spec<C, A>(
+    spec: TempPred<C>, c_init: StatePred<C>, c_next: ActionPred<C>, inv: StatePred<C>,
+    a_init: StatePred<A>, a_next: ActionPred<A>, abs: spec_fn(C) -> A
+)
+    requires
+        init_refines(c_init, a_init, abs),
+        next_refines(c_next, a_next, inv, abs),
+        spec.entails(lift_state(c_init)),
+        spec.entails(always(lift_action(c_next))),
+        spec.entails(always(lift_state(inv))),
+    ensures spec.entails(refined(abs, lift_state(a_init).and(always(lift_action(a_next))))),
+{
+    assert forall |ex| #[trigger] spec.satisfied_by(ex) implies refined(abs, lift_state(a_init).and(always(lift_action(a_next)))).satisfied_by(ex) by {
+        let a_ex = abstract_execution(abs, ex);
+        assert(spec.implies(lift_state(c_init)).satisfied_by(ex));
+        assert(spec.implies(always(lift_action(c_next))).satisfied_by(ex));
+        assert(spec.implies(always(lift_state(inv))).satisfied_by(ex));
+        assert(c_init(ex.head()));
+        assert(lift_state(a_init).satisfied_by(a_ex));
+        assert forall |i: nat| #[trigger] lift_action(a_next).satisfied_by(a_ex.suffix(i)) by {
+            assert(lift_action(c_next).satisfied_by(ex.suffix(i)));
+            assert(lift_state(inv).satisfied_by(ex.suffix(i)));
+            abstract_execution_suffix(abs, ex, i);
+        };
+    };
+}
+
+// Any property entailed by the abstract spec also holds for the concrete state machine (through abs).
+pub proof fn refinement_transfers_property<C, A>(spec: TempPred<C>, abs: spec_fn(C) -> A, a_spec: TempPred<A>, p: TempPred<A>)
+    requires
+        spec.entails(refined(abs, a_spec)),
+        a_spec.entails(p),
+    ensures spec.entails(refined(abs, p)),
+{
+    assert forall |ex| #[trigger] spec.satisfied_by(ex) implies refined(abs, p).satisfied_by(ex) by {
+        assert(spec.implies(refined(abs, a_spec)).satisfied_by(ex));
+        assert(a_spec.implies(p).satisfied_by(abstract_execution(abs, ex)));
+    };
+}
+
+pub proof fn entails_refined_and<C, A>(spec: TempPred<C>, abs: spec_fn(C) -> A, p: TempPred<A>, q: TempPred<A>)
+    requires
+        spec.entails(refined(abs, p)),
+        spec.entails(refined(abs, q)),
+    ensures spec.entails(refined(abs, p.and(q))),
+{
+    assert forall |ex| #[trigger] spec.satisfied_by(ex) implies refined(abs, p.and(q)).satisfied_by(ex) by {
+        assert(spec.implies(refined(abs, p)).satisfied_by(ex));
+        assert(spec.implies(refined(abs, q)).satisfied_by(ex));
+    };
+}
+
+// Weak fairness of an abstract action follows from weak fairness of a concrete action if
+// the concrete action is enabled whenever the abstract one is, and it maps to the abstract action.
+pub proof fn refined_weak_fairness<C, A>(spec: TempPred<C>, abs: spec_fn(C) -> A, c_forward: ActionPred<C>, a_forward: ActionPred<A>)
+    requires
+        forall |c: C| #[trigger] enabled(a_forward)(abs(c)) ==> enabled(c_forward)(c),
+        forall |c: C, c_prime: C| #[trigger] c_forward(c, c_prime) ==> a_forward(abs(c), abs(c_prime)),
+        spec.entails(weak_fairness(c_forward)),
+    ensures spec.entails(refined(abs, weak_fairness(a_forward))),
+{
+    assert forall |ex| #[trigger] spec.satisfied_by(ex) implies refined(abs, weak_fairness(a_forward)).satisfied_by(ex) by {
+        let a_ex = abstract_execution(abs, ex);
+        assert(spec.implies(weak_fairness(c_forward)).satisfied_by(ex));
+        assert forall |i: nat| #[trigger] always(lift_state(enabled(a_forward))).satisfied_by(a_ex.suffix(i))
+        implies eventually(lift_action(a_forward)).satisfied_by(a_ex.suffix(i)) by {
+            abstract_execution_suffix(abs, ex, i);
+            assert forall |j: nat| #[trigger] lift_state(enabled(c_forward)).satisfied_by(ex.suffix(i).suffix(j)) by {
+                assert(lift_state(enabled(a_forward)).satisfied_by(a_ex.suffix(i).suffix(j)));
+                abstract_execution_suffix(abs, ex.suffix(i), j);
+            };
+            assert(always(lift_state(enabled(c_forward))).implies(eventually(lift_action(c_forward))).satisfied_by(ex.suffix(i)));
+            let k = choose |k: nat| #[trigger] lift_action(c_forward).satisfied_by(ex.suffix(i).suffix(k));
+            abstract_execution_suffix(abs, ex.suffix(i), k);
+            assert(lift_action(a_forward).satisfied_by(a_ex.suffix(i).suffix(k)));
+        };
+    };
+}
+
+// Safety properties over abstract states become safety properties over concrete states.
+pub proof fn refined_always_lift_state<C, A>(abs: spec_fn(C) -> A, p: StatePred<A>)
+    ensures refined(abs, always(lift_state(p))) == always(lift_state(|c: C| p(abs(c)))),
+{
+    let c_p = |c: C| p(abs(c));
+    assert forall |ex| #[trigger] refined(abs, always(lift_state(p))).satisfied_by(ex) implies always(lift_state(c_p)).satisfied_by(ex) by {
+        assert forall |i: nat| #[trigger] lift_state(c_p).satisfied_by(ex.suffix(i)) by {
+            assert(lift_state(p).satisfied_by(abstract_execution(abs, ex).suffix(i)));
+        };
+    };
+    assert forall |ex| #[trigger] always(lift_state(c_p)).satisfied_by(ex) implies refined(abs, always(lift_state(p))).satisfied_by(ex) by {
+        assert forall |i: nat| #[trigger] lift_state(p).satisfied_by(abstract_execution(abs, ex).suffix(i)) by {
+            assert(lift_state(c_p).satisfied_by(ex.suffix(i)));
+        };
+    };
+    temp_pred_equality::<C>(refined(abs, always(lift_state(p))), always(lift_state(c_p)));
+}
+
+// Liveness properties over abstract states become liveness properties over concrete states.
+pub proof fn refined_leads_to_lift_state<C, A>(abs: spec_fn(C) -> A, p: StatePred<A>, q: StatePred<A>)
+    ensures refined(abs, lift_state(p).leads_to(lift_state(q))) == lift_state(|c: C| p(abs(c))).leads_to(lift_state(|c: C| q(abs(c)))),
+{
+    let c_p = |c: C| p(abs(c));
+    let c_q = |c: C| q(abs(c));
+    assert forall |ex| #[trigger] refined(abs, lift_state(p).leads_to(lift_state(q))).satisfied_by(ex)
+    implies lift_state(c_p).leads_to(lift_state(c_q)).satisfied_by(ex) by {
+        let a_ex = abstract_execution(abs, ex);
+        assert forall |i: nat| #[trigger] lift_state(c_p).satisfied_by(ex.suffix(i)) implies eventually(lift_state(c_q)).satisfied_by(ex.suffix(i)) by {
+            assert(lift_state(p).implies(eventually(lift_state(q))).satisfied_by(a_ex.suffix(i)));
+            let k = choose |k: nat| #[trigger] lift_state(q).satisfied_by(a_ex.suffix(i).suffix(k));
+            abstract_execution_suffix(abs, ex, i);
+            abstract_execution_suffix(abs, ex.suffix(i), k);
+            assert(lift_state(c_q).satisfied_by(ex.suffix(i).suffix(k)));
+        };
+    };
+    assert forall |ex| #[trigger] lift_state(c_p).leads_to(lift_state(c_q)).satisfied_by(ex)
+    implies refined(abs, lift_state(p).leads_to(lift_state(q))).satisfied_by(ex) by {
+        let a_ex = abstract_execution(abs, ex);
+        assert forall |i: nat| #[trigger] lift_state(p).satisfied_by(a_ex.suffix(i)) implies eventually(lift_state(q)).satisfied_by(a_ex.suffix(i)) by {
+            assert(lift_state(c_p).implies(eventually(lift_state(c_q))).satisfied_by(ex.suffix(i)));
+            let k = choose |k: nat| #[trigger] lift_state(c_q).satisfied_by(ex.suffix(i).suffix(k));
+            abstract_execution_suffix(abs, ex, i);
+            abstract_execution_suffix(abs, ex.suffix(i), k);
+            assert(lift_state(q).satisfied_by(a_ex.suffix(i).suffix(k)));
+        };
+    };
+    temp_pred_equality::<C>(refined(abs, lift_state(p).leads_to(lift_state(q))), lift_state(c_p).leads_to(lift_state(c_q)));
+}
+
+}
@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: MIT
 #![allow(unused_imports)]
 use vstd::function::*;
+use vstd::multiset::*;
 use vstd::prelude::*;
 
 verus! {
@@ -112,6 +113,19 @@ pub open spec fn later<T>(temp_pred: TempPred<T>) -> TempPred<T> {
     TempPred::new(|ex: Execution<T>| temp_pred.satisfied_by(ex.suffix(1)))
 }
 
+// `U` (until) for temporal predicates in LTL.
+// Returns a temporal predicate that is satisfied iff `q` is satisfied on some suffix of the execution
+// and `p` is satisfied on every suffix before that.
+pub open spec fn until<T>(p: TempPred<T>, q: TempPred<T>) -> TempPred<T> {
+    TempPred::new(|ex: Execution<T>| exists |i: nat| #[trigger] q.satisfied_by(ex.suffix(i)) && forall |j: nat| j < i ==> #[trigger] p.satisfied_by(ex.suffix(j)))
+}
+
+// `W` (weak until) for temporal predicates in LTL.
+// Same as until except that `q` does not have to happen if `p` is always satisfied.
+pub open spec fn weak_until<T>(p: TempPred<T>, q: TempPred<T>) -> TempPred<T> {
+    until(p, q).or(always(p))
+}
+
 // `~` for temporal predicates in TLA+ (i.e., `!` in Verus).
 pub open spec fn not<T>(temp_pred: TempPred<T>) -> TempPred<T> {
     TempPred::new(|ex: Execution<T>| !temp_pred.satisfied_by(ex))
@@ -160,6 +174,20 @@ pub open spec fn weak_fairness<T>(action_pred: ActionPred<T>) -> TempPred<T> {
     always(lift_state(enabled(action_pred))).leads_to(lift_action(action_pred))
 }
 
+// Returns a temporal predicate that is satisfied
+// iff `always(eventually(lift_state(enabled(action_pred))))` getting satisfied leads to `lift_action(action_pred)` getting satisfied.
+//
+// It says whether it is *always* the case that if the action is *infinitely often* enabled, the action *eventually* happens.
+// Unlike weak_fairness, the action does not need to stay enabled continuously,
+// so strong_fairness is a stronger assumption (see strong_fairness_implies_weak_fairness).
+//
+// Defined in 5.3 in a different form:
+//    []<>E(A) ~> A
+// == []<>A \/ <>[]~E(A)
+pub open spec fn strong_fairness<T>(action_pred: ActionPred<T>) -> TempPred<T> {
+    always(eventually(lift_state(enabled(action_pred)))).leads_to(lift_action(action_pred))
+}
+
 // `|=` for temporal predicates in TLA+.
 // Returns true iff `temp_pred` is satisfied by all possible executions (behaviors).
 //
@@ -176,4 +204,39 @@ pub open spec fn false_pred<T>() -> TempPred<T> {
     not(true_pred())
 }
 
+// Returns true iff `lt` is a well-founded relation, i.e.,
+// every nonempty set (described by `p`) has a minimal element with respect to `lt`.
+//
+// Well-founded relations are used as ranks in leads_to induction (see leads_to_rank_well_founded).
+pub open spec fn well_founded<A>(lt: spec_fn(A, A) -> bool) -> bool {
+    forall |p: spec_fn(A) -> bool, a: A| #[trigger] p(a)
+        ==> exists |m: A| #[trigger] p(m) && forall |b: A| #[trigger] p(b) ==> !lt(b, m)
+}
+
+pub open spec fn nat_lt() -> spec_fn(nat, nat) -> bool {
+    |a: nat, b: nat| a < b
+}
+
+// The lexicographic order over pairs built from `lt_a` and `lt_b`.
+pub open spec fn lex_lt<A, B>(lt_a: spec_fn(A, A) -> bool, lt_b: spec_fn(B, B) -> bool) -> spec_fn((A, B), (A, B)) -> bool {
+    |x: (A, B), y: (A, B)| lt_a(x.0, y.0) || (x.0 == y.0 && lt_b(x.1, y.1))
+}
+
+// The lexicographic order over pairs of nats, e.g., (pods to delete, pods to create).
+pub open spec fn nat_pair_lt() -> spec_fn((nat, nat), (nat, nat)) -> bool {
+    lex_lt(nat_lt(), nat_lt())
+}
+
+// The (Dershowitz-Manna) multiset ordering that only looks at the elements smaller than `bound`:
+// m1 is smaller than m2 iff they are different, and whenever m1 has more copies of some y than m2,
+// m2 has more copies of some x larger than y.
+// In other words, m1 is obtained from m2 by replacing some elements with any number of smaller ones.
+pub open spec fn multiset_lt(bound: nat) -> spec_fn(Multiset<nat>, Multiset<nat>) -> bool {
+    |m1: Multiset<nat>, m2: Multiset<nat>| {
+        &&& exists |y: nat| y < bound && #[trigger] m1.count(y) != m2.count(y)
+        &&& forall |y: nat| y < bound && #[trigger] m1.count(y) > m2.count(y)
+            ==> exists |x: nat| y < x < bound && #[trigger] m2.count(x) > m1.count(x)
+    }
+}
+
 }
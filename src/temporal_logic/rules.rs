@@ -4,6 +4,7 @@
 use crate::temporal_logic::defs::*;
 use vstd::function::*;
 use vstd::map_lib::*;
+use vstd::multiset::*;
 use vstd::prelude::*;
 
 verus! {
@@ -47,6 +48,13 @@ proof fn weak_fairness_unfold<T>(ex: Execution<T>, p: ActionPred<T>)
     leads_to_unfold::<T>(ex, always(lift_state(enabled(p))), lift_action(p));
 }
 
+proof fn strong_fairness_unfold<T>(ex: Execution<T>, p: ActionPred<T>)
+    requires strong_fairness(p).satisfied_by(ex),
+    ensures forall |i| always(eventually(lift_state(enabled(p)))).implies(eventually(lift_action(p))).satisfied_by(#[trigger] ex.suffix(i)),
+{
+    leads_to_unfold::<T>(ex, always(eventually(lift_state(enabled(p)))), lift_action(p));
+}
+
 proof fn always_lift_state_unfold<T>(ex: Execution<T>, p: StatePred<T>)
     requires always(lift_state(p)).satisfied_by(ex),
     ensures forall |i| p(#[trigger] ex.suffix(i).head()),
@@ -1223,6 +1231,203 @@ pub proof fn wf1<T>(spec: TempPred<T>, next: ActionPred<T>, forward: ActionPred<
     wf1_variant_temp::<T>(spec, lift_action(next), lift_action(forward), lift_state(p), lift_state(q));
 }
 
+// Get the initial leads_to with strong fairness, which only requires forward to be enabled infinitely often.
+// pre:
+//     spec |= [](p /\ next => p' \/ q')
+//     spec |= [](p /\ next /\ forward => q')
+//     spec |= []next
+//     spec |= []p ~> e
+//     spec |= []<>e ~> forward
+// post:
+//     spec |= p ~> q
+pub proof fn sf1_variant_temp<T>(spec: TempPred<T>, next: TempPred<T>, forward: TempPred<T>, e: TempPred<T>, p: TempPred<T>, q: TempPred<T>)
+    requires
+        spec.entails(always(p.and(next).implies(later(p).or(later(q))))),
+        spec.entails(always(p.and(next).and(forward).implies(later(q)))),
+        spec.entails(always(next)),
+        spec.entails(always(p).leads_to(e)),
+        spec.entails(always(eventually(e)).leads_to(forward)),
+    ensures spec.entails(p.leads_to(q)),
+{
+    assert forall |ex| #[trigger] spec.satisfied_by(ex) implies always(p).leads_to(forward).satisfied_by(ex) by {
+        assert forall |i| #[trigger] always(p).satisfied_by(ex.suffix(i)) implies eventually(forward).satisfied_by(ex.suffix(i)) by {
+            implies_apply::<T>(ex, spec, always(p).leads_to(e));
+            leads_to_unfold::<T>(ex, always(p), e);
+            // Since p holds from now on, e keeps showing up in the future.
+            assert forall |j| #[trigger] eventually(e).satisfied_by(ex.suffix(i).suffix(j)) by {
+                always_propagate_forwards::<T>(ex.suffix(i), p, j);
+                execution_equality::<T>(ex.suffix(i).suffix(j), ex.suffix(i + j));
+                implies_apply::<T>(ex.suffix(i + j), always(p), eventually(e));
+            };
+            implies_apply::<T>(ex, spec, always(eventually(e)).leads_to(forward));
+            leads_to_unfold::<T>(ex, always(eventually(e)), forward);
+            implies_apply::<T>(ex.suffix(i), always(eventually(e)), eventually(forward));
+        };
+    };
+    wf1_variant_temp::<T>(spec, next, forward, p, q);
+}
+
+// Get the initial leads_to with strong fairness.
+// Compared to wf1, forward only needs to be enabled from time to time when p holds (e.g., it can be
+// disabled temporarily by other writers), instead of being enabled whenever p holds.
+// pre:
+//     |= p /\ next => p' \/ q'
+//     |= p /\ next /\ forward => q'
+//     spec |= []p ~> enabled(forward)
+//     spec |= []next
+//     spec |= sf(forward)
+// post:
+//     spec |= p ~> q
+pub proof fn sf1<T>(spec: TempPred<T>, next: ActionPred<T>, forward: ActionPred<T>, p: StatePred<T>, q: StatePred<T>)
+    requires
+        forall |s, s_prime: T| p(s) && #[trigger] next(s, s_prime) ==> p(s_prime) || q(s_prime),
+        forall |s, s_prime: T| p(s) && #[trigger] next(s, s_prime) && forward(s, s_prime) ==> q(s_prime),
+        spec.entails(always(lift_state(p)).leads_to(lift_state(enabled(forward)))),
+        spec.entails(always(lift_action(next))),
+        spec.entails(strong_fairness(forward)),
+    ensures spec.entails(lift_state(p).leads_to(lift_state(q))),
+{
+    sf1_variant_temp::<T>(spec, lift_action(next), lift_action(forward), lift_state(enabled(forward)), lift_state(p), lift_state(q));
+}
+
+// If q happens whenever p holds infinitely often, q also happens whenever p holds continuously.
+// pre:
+//     spec |= []<>p ~> q
+// post:
+//     spec |= []p ~> q
+pub proof fn always_eventually_leads_to_implies_always_leads_to<T>(spec: TempPred<T>, p: TempPred<T>, q: TempPred<T>)
+    requires spec.entails(always(eventually(p)).leads_to(q)),
+    ensures spec.entails(always(p).leads_to(q)),
+{
+    assert forall |ex| #[trigger] spec.satisfied_by(ex) implies always(p).leads_to(q).satisfied_by(ex) by {
+        assert forall |i| #[trigger] always(p).satisfied_by(ex.suffix(i)) implies eventually(q).satisfied_by(ex.suffix(i)) by {
+            assert forall |j| #[trigger] eventually(p).satisfied_by(ex.suffix(i).suffix(j)) by {
+                always_to_future::<T>(ex.suffix(i), p, j);
+                execution_equality::<T>(ex.suffix(i).suffix(j), ex.suffix(i).suffix(j).suffix(0));
+                eventually_proved_by_witness::<T>(ex.suffix(i).suffix(j), p, 0);
+            };
+            implies_apply::<T>(ex, spec, always(eventually(p)).leads_to(q));
+            leads_to_unfold::<T>(ex, always(eventually(p)), q);
+            implies_apply::<T>(ex.suffix(i), always(eventually(p)), eventually(q));
+        };
+    };
+}
+
+// Strong fairness is stronger than weak fairness.
+// pre:
+//     spec |= sf(forward)
+// post:
+//     spec |= wf(forward)
+pub proof fn strong_fairness_implies_weak_fairness<T>(spec: TempPred<T>, forward: ActionPred<T>)
+    requires spec.entails(strong_fairness(forward)),
+    ensures spec.entails(weak_fairness(forward)),
+{
+    always_eventually_leads_to_implies_always_leads_to::<T>(spec, lift_state(enabled(forward)), lift_action(forward));
+}
+
+// q eventually happens if p holds until q.
+// pre:
+//     spec |= p U q
+// post:
+//     spec |= <>q
+pub proof fn until_implies_eventually<T>(spec: TempPred<T>, p: TempPred<T>, q: TempPred<T>)
+    requires spec.entails(until(p, q)),
+    ensures spec.entails(eventually(q)),
+{
+    assert forall |ex| #[trigger] spec.satisfied_by(ex) implies eventually(q).satisfied_by(ex) by {
+        implies_apply::<T>(ex, spec, until(p, q));
+        let i = choose |i: nat| #[trigger] q.satisfied_by(ex.suffix(i)) && forall |j: nat| j < i ==> #[trigger] p.satisfied_by(ex.suffix(j));
+        eventually_proved_by_witness::<T>(ex, q, i);
+    };
+}
+
+// Weak until becomes until once q is known to eventually happen.
+// pre:
+//     spec |= p W q
+//     spec |= <>q
+// post:
+//     spec |= p U q
+pub proof fn weak_until_and_eventually_implies_until<T>(spec: TempPred<T>, p: TempPred<T>, q: TempPred<T>)
+    requires
+        spec.entails(weak_until(p, q)),
+        spec.entails(eventually(q)),
+    ensures spec.entails(until(p, q)),
+{
+    assert forall |ex| #[trigger] spec.satisfied_by(ex) implies until(p, q).satisfied_by(ex) by {
+        implies_apply::<T>(ex, spec, weak_until(p, q));
+        if !until(p, q).satisfied_by(ex) {
+            implies_apply::<T>(ex, spec, eventually(q));
+            let i = eventually_choose_witness::<T>(ex, q);
+            always_unfold::<T>(ex, p);
+            assert(q.satisfied_by(ex.suffix(i)) && forall |j: nat| j < i ==> #[trigger] p.satisfied_by(ex.suffix(j)));
+        }
+    };
+}
+
+proof fn weak_until_intro_rec<T>(ex: Execution<T>, next: ActionPred<T>, p: StatePred<T>, q: StatePred<T>, i: nat)
+    requires
+        forall |s, s_prime: T| p(s) && !q(s) && #[trigger] next(s, s_prime) ==> p(s_prime) || q(s_prime),
+        forall |idx: nat| next(#[trigger] ex.suffix(idx).head(), ex.suffix(idx).head_next()),
+        forall |idx: nat| idx < i ==> !q(#[trigger] ex.suffix(idx).head()),
+        p(ex.head()),
+    ensures p(ex.suffix(i).head()),
+    decreases i,
+{
+    if i == 0 {
+        execution_equality::<T>(ex, ex.suffix(0));
+    } else {
+        let prev = (i - 1) as nat;
+        weak_until_intro_rec::<T>(ex, next, p, q, prev);
+        assert(!q(ex.suffix(prev).head()));
+        assert(next(ex.suffix(prev).head(), ex.suffix(prev).head_next()));
+        assert(ex.suffix(prev).head_next() == ex.suffix(i).head());
+    }
+}
+
+// Get p W q from a safety argument: p keeps holding until q happens.
+// pre:
+//     |= p /\ ~q /\ next => p' \/ q'
+//     spec |= []next
+// post:
+//     spec |= [](p => p W q)
+pub proof fn weak_until_intro<T>(spec: TempPred<T>, next: ActionPred<T>, p: StatePred<T>, q: StatePred<T>)
+    requires
+        forall |s, s_prime: T| p(s) && !q(s) && #[trigger] next(s, s_prime) ==> p(s_prime) || q(s_prime),
+        spec.entails(always(lift_action(next))),
+    ensures spec.entails(always(lift_state(p).implies(weak_until(lift_state(p), lift_state(q))))),
+{
+    assert forall |ex| #[trigger] spec.satisfied_by(ex) implies always(lift_state(p).implies(weak_until(lift_state(p), lift_state(q)))).satisfied_by(ex) by {
+        implies_apply::<T>(ex, spec, always(lift_action(next)));
+        assert forall |i| #[trigger] lift_state(p).satisfied_by(ex.suffix(i)) implies weak_until(lift_state(p), lift_state(q)).satisfied_by(ex.suffix(i)) by {
+            let ex_i = ex.suffix(i);
+            always_propagate_forwards::<T>(ex, lift_action(next), i);
+            always_lift_action_unfold::<T>(ex_i, next);
+            let q_at = |k: nat| q(ex_i.suffix(k).head());
+            if exists |k: nat| #[trigger] q_at(k) {
+                // p holds until the first time q happens.
+                let k0 = choose |k: nat| #[trigger] q_at(k);
+                lemma_nat_has_min(q_at, k0);
+                let k = choose |m: nat| #[trigger] q_at(m) && forall |b: nat| #[trigger] q_at(b) ==> !(b < m);
+                assert forall |j: nat| j < k implies #[trigger] lift_state(p).satisfied_by(ex_i.suffix(j)) by {
+                    assert forall |idx: nat| idx < j implies !q(#[trigger] ex_i.suffix(idx).head()) by {
+                        assert(!q_at(idx));
+                    };
+                    weak_until_intro_rec::<T>(ex_i, next, p, q, j);
+                };
+                assert(lift_state(q).satisfied_by(ex_i.suffix(k)));
+            } else {
+                // q never happens, so p always holds.
+                assert forall |j: nat| #[trigger] lift_state(p).satisfied_by(ex_i.suffix(j)) by {
+                    assert forall |idx: nat| idx < j implies !q(#[trigger] ex_i.suffix(idx).head()) by {
+                        assert(!q_at(idx));
+                    };
+                    weak_until_intro_rec::<T>(ex_i, next, p, q, j);
+                };
+            }
+        };
+    };
+}
+
 // Connects two valid implies.
 // pre:
 //     p |= q
@@ -1820,6 +2025,159 @@ proof fn leads_to_rank_step_one_usize_help<T>(spec: TempPred<T>, p: spec_fn(usiz
     }
 }
 
+// The temporal predicate saying that p(b) holds for some b ranked lower than a.
+pub open spec fn lower_rank<T, A>(lt: spec_fn(A, A) -> bool, p: spec_fn(A) -> TempPred<T>, a: A) -> TempPred<T> {
+    TempPred::new(|ex: Execution<T>| exists |b: A| #[trigger] lt(b, a) && p(b).satisfied_by(ex))
+}
+
+// Leads-to induction over any well-founded rank.
+// pre:
+//     lt is well-founded
+//     for all a, spec |= p(a) ~> q \/ (exists b such that lt(b, a) /\ p(b))
+// post:
+//     for all a, spec |= p(a) ~> q
+pub proof fn leads_to_rank_well_founded<T, A>(spec: TempPred<T>, lt: spec_fn(A, A) -> bool, p: spec_fn(A) -> TempPred<T>, q: TempPred<T>)
+    requires
+        well_founded(lt),
+        forall |a: A| #[trigger] spec.entails(p(a).leads_to(q.or(lower_rank(lt, p, a)))),
+    ensures forall |a: A| #[trigger] spec.entails(p(a).leads_to(q)),
+{
+    let bad = |a: A| !spec.entails(p(a).leads_to(q));
+    assert forall |a: A| #[trigger] spec.entails(p(a).leads_to(q)) by {
+        if bad(a) {
+            // Pick the minimal rank m from which q is not reached.
+            let m = choose |m: A| #[trigger] bad(m) && forall |b: A| #[trigger] bad(b) ==> !lt(b, m);
+            // Every rank lower than m reaches q, so lower_rank(m) ~> q.
+            assert forall |ex| #[trigger] spec.satisfied_by(ex) implies lower_rank(lt, p, m).leads_to(q).satisfied_by(ex) by {
+                assert forall |i| #[trigger] lower_rank(lt, p, m).satisfied_by(ex.suffix(i)) implies eventually(q).satisfied_by(ex.suffix(i)) by {
+                    let b = choose |b: A| #[trigger] lt(b, m) && p(b).satisfied_by(ex.suffix(i));
+                    assert(!bad(b));
+                    implies_apply::<T>(ex, spec, p(b).leads_to(q));
+                    leads_to_unfold::<T>(ex, p(b), q);
+                    implies_apply::<T>(ex.suffix(i), p(b), eventually(q));
+                };
+            };
+            entails_implies_leads_to::<T>(spec, q, q);
+            or_leads_to_combine::<T>(spec, q, lower_rank(lt, p, m), q);
+            leads_to_trans::<T>(spec, p(m), q.or(lower_rank(lt, p, m)), q);
+            assert(false);
+        }
+    };
+}
+
+proof fn lemma_nat_has_min(p: spec_fn(nat) -> bool, a: nat)
+    requires p(a),
+    ensures exists |m: nat| #[trigger] p(m) && forall |b: nat| #[trigger] p(b) ==> !(b < m),
+    decreases a,
+{
+    if exists |b: nat| #[trigger] p(b) && b < a {
+        let b = choose |b: nat| #[trigger] p(b) && b < a;
+        lemma_nat_has_min(p, b);
+    } else {
+        assert(forall |b: nat| #[trigger] p(b) ==> !(b < a));
+    }
+}
+
+pub proof fn nat_lt_is_well_founded()
+    ensures well_founded(nat_lt()),
+{
+    assert forall |p: spec_fn(nat) -> bool, a: nat| #[trigger] p(a)
+    implies exists |m: nat| #[trigger] p(m) && forall |b: nat| #[trigger] p(b) ==> !nat_lt()(b, m) by {
+        lemma_nat_has_min(p, a);
+    };
+}
+
+pub proof fn lex_lt_is_well_founded<A, B>(lt_a: spec_fn(A, A) -> bool, lt_b: spec_fn(B, B) -> bool)
+    requires
+        well_founded(lt_a),
+        well_founded(lt_b),
+    ensures well_founded(lex_lt(lt_a, lt_b)),
+{
+    assert forall |p: spec_fn((A, B)) -> bool, a: (A, B)| #[trigger] p(a)
+    implies exists |m: (A, B)| #[trigger] p(m) && forall |b: (A, B)| #[trigger] p(b) ==> !lex_lt(lt_a, lt_b)(b, m) by {
+        // First find the minimal first component among p, then the minimal second component with that first component.
+        let p_a = |x: A| exists |y: B| #[trigger] p((x, y));
+        assert(p((a.0, a.1)));
+        assert(p_a(a.0));
+        let m_a = choose |m: A| #[trigger] p_a(m) && forall |b: A| #[trigger] p_a(b) ==> !lt_a(b, m);
+        let y = choose |y: B| #[trigger] p((m_a, y));
+        let p_b = |y: B| p((m_a, y));
+        assert(p_b(y));
+        let m_b = choose |m: B| #[trigger] p_b(m) && forall |b: B| #[trigger] p_b(b) ==> !lt_b(b, m);
+        assert forall |b: (A, B)| #[trigger] p(b) implies !lex_lt(lt_a, lt_b)(b, (m_a, m_b)) by {
+            assert(p((b.0, b.1)));
+            assert(p_a(b.0));
+            if b.0 == m_a {
+                assert(p_b(b.1));
+            }
+        };
+        assert(p((m_a, m_b)));
+    };
+}
+
+pub proof fn nat_pair_lt_is_well_founded()
+    ensures well_founded(nat_pair_lt()),
+{
+    nat_lt_is_well_founded();
+    lex_lt_is_well_founded(nat_lt(), nat_lt());
+}
+
+// If lt_a can be mapped into a well-founded lt_b by f, lt_a is also well-founded.
+pub proof fn well_founded_by_mapping<A, B>(lt_a: spec_fn(A, A) -> bool, lt_b: spec_fn(B, B) -> bool, f: spec_fn(A) -> B)
+    requires
+        well_founded(lt_b),
+        forall |x: A, y: A| #[trigger] lt_a(x, y) ==> lt_b(f(x), f(y)),
+    ensures well_founded(lt_a),
+{
+    assert forall |p: spec_fn(A) -> bool, a: A| #[trigger] p(a)
+    implies exists |m: A| #[trigger] p(m) && forall |b: A| #[trigger] p(b) ==> !lt_a(b, m) by {
+        let p_b = |y: B| exists |x: A| #[trigger] p(x) && f(x) == y;
+        assert(p_b(f(a)));
+        let m_b = choose |m: B| #[trigger] p_b(m) && forall |b: B| #[trigger] p_b(b) ==> !lt_b(b, m);
+        let m = choose |x: A| #[trigger] p(x) && f(x) == m_b;
+        assert forall |b: A| #[trigger] p(b) implies !lt_a(b, m) by {
+            assert(p_b(f(b)));
+        };
+    };
+}
+
+pub proof fn multiset_lt_is_well_founded(bound: nat)
+    ensures well_founded(multiset_lt(bound)),
+    decreases bound,
+{
+    if bound == 0 {
+        // Nothing is smaller than anything.
+        assert forall |p: spec_fn(Multiset<nat>) -> bool, a: Multiset<nat>| #[trigger] p(a)
+        implies exists |m: Multiset<nat>| #[trigger] p(m) && forall |b: Multiset<nat>| #[trigger] p(b) ==> !multiset_lt(bound)(b, m) by {
+            assert(p(a) && forall |b: Multiset<nat>| #[trigger] p(b) ==> !multiset_lt(bound)(b, a));
+        };
+    } else {
+        // Compare the copies of the largest element (bound - 1) first, and then the rest.
+        let k = (bound - 1) as nat;
+        multiset_lt_is_well_founded(k);
+        nat_lt_is_well_founded();
+        lex_lt_is_well_founded(nat_lt(), multiset_lt(k));
+        let f = |m: Multiset<nat>| (m.count(k), m);
+        assert forall |x: Multiset<nat>, y: Multiset<nat>| #[trigger] multiset_lt(bound)(x, y)
+        implies lex_lt(nat_lt(), multiset_lt(k))(f(x), f(y)) by {
+            if x.count(k) > y.count(k) {
+                // There is no element larger than k below the bound.
+                assert(k < bound && x.count(k) > y.count(k));
+            } else if x.count(k) == y.count(k) {
+                let y0 = choose |y0: nat| y0 < bound && #[trigger] x.count(y0) != y.count(y0);
+                assert(y0 < k && x.count(y0) != y.count(y0));
+                assert forall |z: nat| z < k && #[trigger] x.count(z) > y.count(z)
+                implies exists |w: nat| z < w < k && #[trigger] y.count(w) > x.count(w) by {
+                    assert(z < bound && x.count(z) > y.count(z));
+                    let w = choose |w: nat| z < w < bound && #[trigger] y.count(w) > x.count(w);
+                    assert(w != k);
+                };
+            }
+        };
+        well_founded_by_mapping(multiset_lt(bound), lex_lt(nat_lt(), multiset_lt(k)), f);
+    }
+}
+
 // Proving p leads to q vacuously.
 // pre:
 //     spec |= []r
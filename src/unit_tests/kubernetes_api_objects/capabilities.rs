@@ -0,0 +1,55 @@
+// Copyright 2022 VMware, Inc.
+// SPDX-License-Identifier: MIT
+use crate::kubernetes_api_objects::exec::container::*;
+use vstd::prelude::*;
+use vstd::string::*;
+
+#[test]
+pub fn test_default() {
+    let capabilities = Capabilities::default();
+    assert_eq!(
+        capabilities.into_kube(),
+        deps_hack::k8s_openapi::api::core::v1::Capabilities::default()
+    );
+}
+
+#[test]
+pub fn test_set_add() {
+    let mut capabilities = Capabilities::default();
+    capabilities.set_add(vec!["NET_BIND_SERVICE".to_string()]);
+    assert_eq!(
+        vec!["NET_BIND_SERVICE".to_string()],
+        capabilities.into_kube().add.unwrap()
+    );
+}
+
+#[test]
+pub fn test_set_drop() {
+    let mut capabilities = Capabilities::default();
+    capabilities.set_drop(vec!["ALL".to_string()]);
+    assert_eq!(
+        vec!["ALL".to_string()],
+        capabilities.into_kube().drop.unwrap()
+    );
+}
+
+#[test]
+pub fn test_clone() {
+    let mut capabilities = Capabilities::default();
+    capabilities.set_add(vec!["NET_BIND_SERVICE".to_string()]);
+    capabilities.set_drop(vec!["ALL".to_string()]);
+    let capabilities_clone = capabilities.clone();
+    assert_eq!(capabilities.into_kube(), capabilities_clone.into_kube());
+}
+
+#[test]
+pub fn test_kube() {
+    let kube_capabilities = deps_hack::k8s_openapi::api::core::v1::Capabilities {
+        add: Some(vec!["NET_BIND_SERVICE".to_string()]),
+        drop: Some(vec!["ALL".to_string()]),
+    };
+
+    let capabilities = Capabilities::from_kube(kube_capabilities.clone());
+
+    assert_eq!(capabilities.into_kube(), kube_capabilities);
+}
@@ -0,0 +1,227 @@
+// Copyright 2022 VMware, Inc.
+// SPDX-License-Identifier: MIT
+use crate::kubernetes_api_objects::exec::cluster_role::*;
+use crate::kubernetes_api_objects::exec::object_meta::*;
+use crate::kubernetes_api_objects::exec::resource::*;
+use crate::kubernetes_api_objects::exec::role::*;
+use crate::vstd_ext::string_map::*;
+use vstd::prelude::*;
+use vstd::string::*;
+
+#[test]
+pub fn test_default() {
+    let cluster_role = ClusterRole::default();
+    assert_eq!(
+        cluster_role.into_kube(),
+        deps_hack::k8s_openapi::api::rbac::v1::ClusterRole::default()
+    );
+}
+
+#[test]
+pub fn test_set_metadata() {
+    let mut cluster_role = ClusterRole::default();
+    let mut object_meta = ObjectMeta::default();
+    object_meta.set_name("name".to_string());
+    cluster_role.set_metadata(object_meta.clone());
+    assert_eq!(object_meta.into_kube(), cluster_role.into_kube().metadata);
+}
+
+#[test]
+pub fn test_metadata() {
+    let mut cluster_role = ClusterRole::default();
+    let mut object_meta = ObjectMeta::default();
+    object_meta.set_name("name".to_string());
+    cluster_role.set_metadata(object_meta.clone());
+    assert_eq!(object_meta.into_kube(), cluster_role.metadata().into_kube());
+}
+
+#[test]
+pub fn test_set_rules() {
+    let mut cluster_role = ClusterRole::default();
+    let policy_rule_gen = || {
+        let mut policy_rule_1 = PolicyRule::default();
+        let mut policy_rule_2 = PolicyRule::default();
+        let mut policy_rules = Vec::new();
+        let api_groups_gen = |suffix: &str| {
+            let api_groups_1 = format!("api_groups_{}_1", suffix);
+            let api_groups_2 = format!("api_groups_{}_2", suffix);
+            let mut api_groups = Vec::new();
+            api_groups.push(api_groups_1);
+            api_groups.push(api_groups_2);
+            api_groups
+        };
+        let resources_gen = |suffix: &str| {
+            let resources_1 = format!("resources_{}_1", suffix);
+            let resources_2 = format!("resources_{}_2", suffix);
+            let mut resources = Vec::new();
+            resources.push(resources_1);
+            resources.push(resources_2);
+            resources
+        };
+        let verbs_gen = |suffix: &str| {
+            let verbs_1 = format!("verbs_{}_1", suffix);
+            let verbs_2 = format!("verbs_{}_2", suffix);
+            let mut verbs = Vec::new();
+            verbs.push(verbs_1);
+            verbs.push(verbs_2);
+            verbs
+        };
+        policy_rule_1.set_api_groups(api_groups_gen("1"));
+        policy_rule_1.set_resources(resources_gen("1"));
+        policy_rule_1.set_verbs(verbs_gen("1"));
+        policy_rule_2.set_api_groups(api_groups_gen("2"));
+        policy_rule_2.set_resources(resources_gen("2"));
+        policy_rule_2.set_verbs(verbs_gen("2"));
+        policy_rules.push(policy_rule_1);
+        policy_rules.push(policy_rule_2);
+        policy_rules
+    };
+    cluster_role.set_rules(policy_rule_gen());
+    assert_eq!(
+        policy_rule_gen()
+            .into_iter()
+            .map(|s: PolicyRule| s.into_kube())
+            .collect::<Vec<_>>(),
+        cluster_role.into_kube().rules.unwrap()
+    );
+}
+
+#[test]
+pub fn test_clone() {
+    let mut cluster_role = ClusterRole::default();
+    let mut object_meta = ObjectMeta::default();
+    let policy_rule_gen = || {
+        let mut policy_rule_1 = PolicyRule::default();
+        let mut policy_rule_2 = PolicyRule::default();
+        let mut policy_rules = Vec::new();
+        let api_groups_gen = |suffix: &str| {
+            let api_groups_1 = format!("api_groups_{}_1", suffix);
+            let api_groups_2 = format!("api_groups_{}_2", suffix);
+            let mut api_groups = Vec::new();
+            api_groups.push(api_groups_1);
+            api_groups.push(api_groups_2);
+            api_groups
+        };
+        let resources_gen = |suffix: &str| {
+            let resources_1 = format!("resources_{}_1", suffix);
+            let resources_2 = format!("resources_{}_2", suffix);
+            let mut resources = Vec::new();
+            resources.push(resources_1);
+            resources.push(resources_2);
+            resources
+        };
+        let verbs_gen = |suffix: &str| {
+            let verbs_1 = format!("verbs_{}_1", suffix);
+            let verbs_2 = format!("verbs_{}_2", suffix);
+            let mut verbs = Vec::new();
+            verbs.push(verbs_1);
+            verbs.push(verbs_2);
+            verbs
+        };
+        policy_rule_1.set_api_groups(api_groups_gen("1"));
+        policy_rule_1.set_resources(resources_gen("1"));
+        policy_rule_1.set_verbs(verbs_gen("1"));
+        policy_rule_2.set_api_groups(api_groups_gen("2"));
+        policy_rule_2.set_resources(resources_gen("2"));
+        policy_rule_2.set_verbs(verbs_gen("2"));
+        policy_rules.push(policy_rule_1);
+        policy_rules.push(policy_rule_2);
+        policy_rules
+    };
+    object_meta.set_name("name".to_string());
+    cluster_role.set_metadata(object_meta.clone());
+    cluster_role.set_rules(policy_rule_gen());
+    let cluster_role_clone = cluster_role.clone();
+    assert_eq!(cluster_role.into_kube(), cluster_role_clone.into_kube());
+}
+
+#[test]
+pub fn test_api_resource() {
+    let api_resource = ClusterRole::api_resource();
+    assert_eq!(api_resource.into_kube().kind, "ClusterRole");
+}
+
+#[test]
+pub fn test_kube() {
+    let kube_cluster_role = deps_hack::k8s_openapi::api::rbac::v1::ClusterRole {
+        metadata: deps_hack::k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta {
+            name: Some("name".to_string()),
+            ..Default::default()
+        },
+        rules: Some(vec![
+            deps_hack::k8s_openapi::api::rbac::v1::PolicyRule {
+                api_groups: Some(vec![
+                    "api_groups_1_1".to_string(),
+                    "api_groups_1_2".to_string(),
+                ]),
+                resources: Some(vec![
+                    "resources_1_1".to_string(),
+                    "resources_1_2".to_string(),
+                ]),
+                verbs: vec!["verbs_1_1".to_string(), "verbs_1_2".to_string()],
+                ..Default::default()
+            },
+            deps_hack::k8s_openapi::api::rbac::v1::PolicyRule {
+                api_groups: Some(vec![
+                    "api_groups_2_1".to_string(),
+                    "api_groups_2_2".to_string(),
+                ]),
+                resources: Some(vec![
+                    "resources_2_1".to_string(),
+                    "resources_2_2".to_string(),
+                ]),
+                verbs: vec!["verbs_2_1".to_string(), "verbs_2_2".to_string()],
+                ..Default::default()
+            },
+        ]),
+        ..Default::default()
+    };
+
+    let cluster_role = ClusterRole::from_kube(kube_cluster_role.clone());
+
+    assert_eq!(cluster_role.into_kube(), kube_cluster_role);
+}
+
+#[test]
+pub fn test_marshal() {
+    let kube_cluster_role = deps_hack::k8s_openapi::api::rbac::v1::ClusterRole {
+        metadata: deps_hack::k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta {
+            name: Some("name".to_string()),
+            ..Default::default()
+        },
+        rules: Some(vec![
+            deps_hack::k8s_openapi::api::rbac::v1::PolicyRule {
+                api_groups: Some(vec![
+                    "api_groups_1_1".to_string(),
+                    "api_groups_1_2".to_string(),
+                ]),
+                resources: Some(vec![
+                    "resources_1_1".to_string(),
+                    "resources_1_2".to_string(),
+                ]),
+                verbs: vec!["verbs_1_1".to_string(), "verbs_1_2".to_string()],
+                ..Default::default()
+            },
+            deps_hack::k8s_openapi::api::rbac::v1::PolicyRule {
+                api_groups: Some(vec![
+                    "api_groups_2_1".to_string(),
+                    "api_groups_2_2".to_string(),
+                ]),
+                resources: Some(vec![
+                    "resources_2_1".to_string(),
+                    "resources_2_2".to_string(),
+                ]),
+                verbs: vec!["verbs_2_1".to_string(), "verbs_2_2".to_string()],
+                ..Default::default()
+            },
+        ]),
+        ..Default::default()
+    };
+
+    let cluster_role = ClusterRole::from_kube(kube_cluster_role.clone());
+
+    assert_eq!(
+        kube_cluster_role,
+        ClusterRole::unmarshal(cluster_role.marshal()).unwrap().into_kube()
+    );
+}
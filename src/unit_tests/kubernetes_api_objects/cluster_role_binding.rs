@@ -0,0 +1,205 @@
+// Copyright 2022 VMware, Inc.
+// SPDX-License-Identifier: MIT
+use crate::kubernetes_api_objects::exec::cluster_role_binding::*;
+use crate::kubernetes_api_objects::exec::object_meta::*;
+use crate::kubernetes_api_objects::exec::resource::*;
+use crate::kubernetes_api_objects::exec::role_binding::*;
+use crate::vstd_ext::string_map::*;
+use vstd::prelude::*;
+use vstd::string::*;
+
+#[test]
+pub fn test_default() {
+    let cluster_role_binding = ClusterRoleBinding::default();
+    assert_eq!(
+        cluster_role_binding.into_kube(),
+        deps_hack::k8s_openapi::api::rbac::v1::ClusterRoleBinding::default()
+    );
+}
+
+#[test]
+pub fn test_set_metadata() {
+    let mut cluster_role_binding = ClusterRoleBinding::default();
+    let mut object_meta = ObjectMeta::default();
+    object_meta.set_name("name".to_string());
+    cluster_role_binding.set_metadata(object_meta.clone());
+    assert_eq!(object_meta.into_kube(), cluster_role_binding.into_kube().metadata);
+}
+
+#[test]
+pub fn test_metadata() {
+    let mut cluster_role_binding = ClusterRoleBinding::default();
+    let mut object_meta = ObjectMeta::default();
+    object_meta.set_name("name".to_string());
+    cluster_role_binding.set_metadata(object_meta.clone());
+    assert_eq!(object_meta.into_kube(), cluster_role_binding.metadata().into_kube());
+}
+
+#[test]
+pub fn test_set_role_ref() {
+    let mut cluster_role_binding = ClusterRoleBinding::default();
+    let mut role_ref = RoleRef::default();
+    role_ref.set_api_group("api_group".to_string());
+    role_ref.set_kind("kind".to_string());
+    role_ref.set_name("name".to_string());
+    cluster_role_binding.set_role_ref(role_ref.clone());
+    assert_eq!(role_ref.into_kube(), cluster_role_binding.into_kube().role_ref);
+}
+
+#[test]
+pub fn test_set_subjects() {
+    let mut cluster_role_binding = ClusterRoleBinding::default();
+    let subject_gen = || {
+        let mut subject_1 = Subject::default();
+        let mut subject_2 = Subject::default();
+        let mut subjects = Vec::new();
+        subject_1.set_kind("kind1".to_string());
+        subject_1.set_name("name1".to_string());
+        subject_1.set_namespace("namespace1".to_string());
+        subject_2.set_kind("kind2".to_string());
+        subject_2.set_name("name2".to_string());
+        subject_2.set_namespace("namespace2".to_string());
+        subjects.push(subject_1);
+        subjects.push(subject_2);
+        subjects
+    };
+    cluster_role_binding.set_subjects(subject_gen());
+    assert_eq!(
+        subject_gen()
+            .into_iter()
+            .map(|s: Subject| s.into_kube())
+            .collect::<Vec<_>>(),
+        cluster_role_binding.into_kube().subjects.unwrap()
+    );
+}
+
+#[test]
+pub fn test_subjects() {
+    let mut cluster_role_binding = ClusterRoleBinding::default();
+    assert_eq!(cluster_role_binding.subjects().is_none(), true);
+    let mut subject = Subject::default();
+    subject.set_api_group("rbac.authorization.k8s.io".to_string());
+    subject.set_kind("Group".to_string());
+    subject.set_name("name".to_string());
+    cluster_role_binding.set_subjects(vec![subject.clone()]);
+    assert_eq!(
+        vec![subject.into_kube()],
+        cluster_role_binding
+            .subjects()
+            .unwrap()
+            .into_iter()
+            .map(|s: Subject| s.into_kube())
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+pub fn test_clone() {
+    let mut cluster_role_binding = ClusterRoleBinding::default();
+    let mut object_meta = ObjectMeta::default();
+    let mut role_ref = RoleRef::default();
+    let subject_gen = || {
+        let mut subject_1 = Subject::default();
+        let mut subject_2 = Subject::default();
+        let mut subjects = Vec::new();
+        subject_1.set_kind("kind1".to_string());
+        subject_1.set_name("name1".to_string());
+        subject_1.set_namespace("namespace1".to_string());
+        subject_2.set_kind("kind2".to_string());
+        subject_2.set_name("name2".to_string());
+        subject_2.set_namespace("namespace2".to_string());
+        subjects.push(subject_1);
+        subjects.push(subject_2);
+        subjects
+    };
+    object_meta.set_name("name".to_string());
+    role_ref.set_api_group("api_group".to_string());
+    role_ref.set_kind("kind".to_string());
+    role_ref.set_name("name".to_string());
+    cluster_role_binding.set_metadata(object_meta.clone());
+    cluster_role_binding.set_role_ref(role_ref.clone());
+    cluster_role_binding.set_subjects(subject_gen());
+    let cluster_role_binding_clone = cluster_role_binding.clone();
+    assert_eq!(cluster_role_binding.into_kube(), cluster_role_binding_clone.into_kube());
+}
+
+#[test]
+pub fn test_api_resource() {
+    let api_resource = ClusterRoleBinding::api_resource();
+    assert_eq!(api_resource.into_kube().kind, "ClusterRoleBinding");
+}
+
+#[test]
+pub fn test_kube() {
+    let kube_cluster_role_binding = deps_hack::k8s_openapi::api::rbac::v1::ClusterRoleBinding {
+        metadata: deps_hack::k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta {
+            name: Some("name".to_string()),
+            ..Default::default()
+        },
+        role_ref: deps_hack::k8s_openapi::api::rbac::v1::RoleRef {
+            api_group: "api_group".to_string(),
+            kind: "kind".to_string(),
+            name: "name".to_string(),
+            ..Default::default()
+        },
+        subjects: Some(vec![
+            deps_hack::k8s_openapi::api::rbac::v1::Subject {
+                kind: "kind1".to_string(),
+                name: "name1".to_string(),
+                namespace: Some("namespace1".to_string()),
+                ..Default::default()
+            },
+            deps_hack::k8s_openapi::api::rbac::v1::Subject {
+                kind: "kind2".to_string(),
+                name: "name2".to_string(),
+                namespace: Some("namespace2".to_string()),
+                ..Default::default()
+            },
+        ]),
+        ..Default::default()
+    };
+
+    let cluster_role_binding = ClusterRoleBinding::from_kube(kube_cluster_role_binding.clone());
+
+    assert_eq!(cluster_role_binding.into_kube(), kube_cluster_role_binding);
+}
+
+#[test]
+pub fn test_marshal() {
+    let kube_cluster_role_binding = deps_hack::k8s_openapi::api::rbac::v1::ClusterRoleBinding {
+        metadata: deps_hack::k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta {
+            name: Some("name".to_string()),
+            ..Default::default()
+        },
+        role_ref: deps_hack::k8s_openapi::api::rbac::v1::RoleRef {
+            api_group: "api_group".to_string(),
+            kind: "kind".to_string(),
+            name: "name".to_string(),
+            ..Default::default()
+        },
+        subjects: Some(vec![
+            deps_hack::k8s_openapi::api::rbac::v1::Subject {
+                kind: "kind1".to_string(),
+                name: "name1".to_string(),
+                namespace: Some("namespace1".to_string()),
+                ..Default::default()
+            },
+            deps_hack::k8s_openapi::api::rbac::v1::Subject {
+                kind: "kind2".to_string(),
+                name: "name2".to_string(),
+                namespace: Some("namespace2".to_string()),
+                ..Default::default()
+            },
+        ]),
+        ..Default::default()
+    };
+
+    let cluster_role_binding = ClusterRoleBinding::from_kube(kube_cluster_role_binding.clone());
+
+    assert_eq!(
+        kube_cluster_role_binding,
+        ClusterRoleBinding::unmarshal(cluster_role_binding.marshal())
+            .unwrap()
+            .into_kube()
+    );
+}
@@ -0,0 +1,153 @@
+// Copyright 2022 VMware, Inc.
+// SPDX-License-Identifier: MIT
+use crate::kubernetes_api_objects::exec::condition::*;
+use crate::kubernetes_api_objects::exec::resource::*;
+use deps_hack::chrono::{DateTime, Utc};
+use deps_hack::k8s_openapi::apimachinery::pkg::apis::meta::v1::Time;
+use vstd::prelude::*;
+use vstd::string::*;
+
+#[test]
+pub fn test_default() {
+    let condition = Condition::default();
+    assert_eq!(condition.into_kube(), deps_hack::Condition::default());
+}
+
+#[test]
+pub fn test_clone() {
+    let mut condition = Condition::default();
+    condition.set_type("Ready".to_string());
+    condition.set_status("True".to_string());
+    let condition_clone = condition.clone();
+    assert_eq!(condition.into_kube(), condition_clone.into_kube());
+}
+
+#[test]
+pub fn test_set_type() {
+    let mut condition = Condition::default();
+    condition.set_type("Ready".to_string());
+    assert_eq!("Ready".to_string(), condition.into_kube().type_);
+}
+
+#[test]
+pub fn test_set_status() {
+    let mut condition = Condition::default();
+    condition.set_status("False".to_string());
+    assert_eq!("False".to_string(), condition.into_kube().status);
+}
+
+#[test]
+pub fn test_set_reason() {
+    let mut condition = Condition::default();
+    condition.set_reason("reason".to_string());
+    assert_eq!("reason".to_string(), condition.into_kube().reason.unwrap());
+}
+
+#[test]
+pub fn test_set_message() {
+    let mut condition = Condition::default();
+    condition.set_message("message".to_string());
+    assert_eq!("message".to_string(), condition.into_kube().message.unwrap());
+}
+
+#[test]
+pub fn test_set_observed_generation() {
+    let mut condition = Condition::default();
+    condition.set_observed_generation(3);
+    assert_eq!(3, condition.into_kube().observed_generation.unwrap());
+}
+
+#[test]
+pub fn test_make_conditions() {
+    let conditions: Vec<deps_hack::Condition> = make_conditions(true, false, None)
+        .into_iter()
+        .map(|c| c.into_kube())
+        .collect();
+    assert_eq!(conditions.len(), 3);
+    assert_eq!(conditions[0].type_, "Ready".to_string());
+    assert_eq!(conditions[0].status, "True".to_string());
+    assert_eq!(conditions[1].type_, "Progressing".to_string());
+    assert_eq!(conditions[1].status, "False".to_string());
+    assert_eq!(conditions[2].type_, "Degraded".to_string());
+    assert_eq!(conditions[2].status, "False".to_string());
+}
+
+#[test]
+pub fn test_make_degraded_conditions() {
+    let conditions: Vec<deps_hack::Condition> = make_conditions(false, true, Some(4))
+        .into_iter()
+        .map(|c| c.into_kube())
+        .collect();
+    assert_eq!(conditions[0].status, "False".to_string());
+    assert_eq!(conditions[1].status, "True".to_string());
+    assert_eq!(conditions[2].type_, "Degraded".to_string());
+    assert_eq!(conditions[2].status, "True".to_string());
+    assert_eq!(conditions[2].reason, Some("ReconcileFailed".to_string()));
+    assert!(conditions.iter().all(|c| c.observed_generation == Some(4)));
+}
+
+#[test]
+pub fn test_into_kube_conditions() {
+    let conditions = into_kube_conditions(make_conditions(true, false, None));
+    assert_eq!(conditions.len(), 3);
+    assert!(conditions
+        .iter()
+        .all(|c| c.last_transition_time.is_some()));
+}
+
+#[test]
+pub fn test_preserve_transition_times() {
+    let old_time = Time(
+        DateTime::parse_from_rfc3339("2020-01-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc),
+    );
+    let old_conditions = vec![
+        deps_hack::Condition {
+            type_: "Ready".to_string(),
+            status: "True".to_string(),
+            last_transition_time: Some(old_time.clone()),
+            ..Default::default()
+        },
+        deps_hack::Condition {
+            type_: "Progressing".to_string(),
+            status: "False".to_string(),
+            last_transition_time: Some(old_time.clone()),
+            ..Default::default()
+        },
+    ];
+
+    let mut conditions = into_kube_conditions(make_conditions(false, false, None));
+    preserve_transition_times(&mut conditions, &old_conditions);
+    // Ready and Progressing both flipped, so they get new timestamps
+    assert_ne!(conditions[0].last_transition_time, Some(old_time.clone()));
+    assert_ne!(conditions[1].last_transition_time, Some(old_time.clone()));
+    // Degraded did not exist before
+    assert!(conditions[2].last_transition_time.is_some());
+
+    let mut conditions = into_kube_conditions(make_conditions(true, false, None));
+    preserve_transition_times(&mut conditions, &old_conditions);
+    // Neither Ready nor Progressing changed, so they keep the old timestamps
+    assert_eq!(conditions[0].last_transition_time, Some(old_time.clone()));
+    assert_eq!(conditions[1].last_transition_time, Some(old_time));
+}
+
+#[test]
+pub fn test_kube() {
+    let kube_condition = deps_hack::Condition {
+        type_: "Ready".to_string(),
+        status: "True".to_string(),
+        reason: Some("reason".to_string()),
+        message: Some("message".to_string()),
+        last_transition_time: Some(Time(
+            DateTime::parse_from_rfc3339("2020-01-01T00:00:00Z")
+                .unwrap()
+                .with_timezone(&Utc),
+        )),
+        observed_generation: Some(1),
+    };
+
+    let condition = Condition::from_kube(kube_condition.clone());
+
+    assert_eq!(condition.into_kube(), kube_condition);
+}
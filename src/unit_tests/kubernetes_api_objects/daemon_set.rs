@@ -92,6 +92,38 @@ pub fn test_clone() {
     assert_eq!(daemon_set.into_kube(), daemon_set_clone.into_kube());
 }
 
+#[test]
+pub fn test_status() {
+    let daemon_set = DaemonSet::default();
+    let temp = daemon_set.status();
+    if !temp.is_none() {
+        panic!("DaemonSet status should be None, but it's not.");
+    }
+    let daemon_set_status =
+        DaemonSetStatus::from_kube(deps_hack::k8s_openapi::api::apps::v1::DaemonSetStatus {
+            number_ready: 3,
+            desired_number_scheduled: 4,
+            updated_number_scheduled: Some(2),
+            ..Default::default()
+        });
+    let daemon_set = DaemonSet::from_kube(deps_hack::k8s_openapi::api::apps::v1::DaemonSet {
+        status: Some(deps_hack::k8s_openapi::api::apps::v1::DaemonSetStatus {
+            number_ready: 3,
+            desired_number_scheduled: 4,
+            updated_number_scheduled: Some(2),
+            ..Default::default()
+        }),
+        ..Default::default()
+    });
+    assert_eq!(
+        daemon_set_status.into_kube(),
+        daemon_set.status().unwrap().into_kube()
+    );
+    assert_eq!(daemon_set.status().unwrap().number_ready(), 3);
+    assert_eq!(daemon_set.status().unwrap().desired_number_scheduled(), 4);
+    assert_eq!(daemon_set.status().unwrap().updated_number_scheduled(), 2);
+}
+
 #[test]
 pub fn test_kube() {
     let kube_daemon_set = deps_hack::k8s_openapi::api::apps::v1::DaemonSet {
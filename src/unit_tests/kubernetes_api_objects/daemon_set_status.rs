@@ -31,3 +31,16 @@ pub fn test_number_ready() {
         });
     assert_eq!(1, daemon_set_status.into_kube().number_ready);
 }
+
+#[test]
+pub fn test_updated_number_scheduled() {
+    let daemon_set_status =
+        DaemonSetStatus::from_kube(deps_hack::k8s_openapi::api::apps::v1::DaemonSetStatus {
+            updated_number_scheduled: Some(2),
+            ..Default::default()
+        });
+    assert_eq!(2, daemon_set_status.updated_number_scheduled());
+    let daemon_set_status =
+        DaemonSetStatus::from_kube(deps_hack::k8s_openapi::api::apps::v1::DaemonSetStatus::default());
+    assert_eq!(0, daemon_set_status.updated_number_scheduled());
+}
@@ -1,6 +1,7 @@
 // Copyright 2022 VMware, Inc.
 // SPDX-License-Identifier: MIT
 use crate::kubernetes_api_objects::exec::dynamic::*;
+use crate::kubernetes_api_objects::exec::json_value::*;
 use crate::kubernetes_api_objects::exec::object_meta::*;
 use crate::kubernetes_api_objects::exec::resource::*;
 use crate::vstd_ext::string_map::*;
@@ -127,3 +128,74 @@ pub fn test_fmt() {
         format!("{:?}", dynamic_object.into_kube())
     );
 }
+
+#[test]
+pub fn test_spec_and_status() {
+    let dynamic_object = DynamicObject::from_kube(deps_hack::kube::api::DynamicObject {
+        metadata: deps_hack::kube::api::ObjectMeta::default(),
+        types: Some(deps_hack::kube::api::TypeMeta {
+            api_version: "apps/v1".to_string(),
+            kind: "StatefulSet".to_string(),
+        }),
+        data: deps_hack::serde_json::json!({
+            "spec": {"replicas": 3},
+            "status": {"readyReplicas": 1},
+        }),
+    });
+    assert_eq!(dynamic_object.spec().into_kube(), deps_hack::serde_json::json!({"replicas": 3}));
+    assert_eq!(dynamic_object.status().into_kube(), deps_hack::serde_json::json!({"readyReplicas": 1}));
+
+    let dynamic_object = DynamicObject::from_kube(deps_hack::kube::api::DynamicObject {
+        metadata: deps_hack::kube::api::ObjectMeta::default(),
+        types: Some(deps_hack::kube::api::TypeMeta {
+            api_version: "v1".to_string(),
+            kind: "ConfigMap".to_string(),
+        }),
+        data: deps_hack::serde_json::json!({
+            "data": {"key": "value"},
+        }),
+    });
+    assert_eq!(dynamic_object.spec().into_kube(), deps_hack::serde_json::json!({"data": {"key": "value"}}));
+    assert_eq!(dynamic_object.status().into_kube(), deps_hack::serde_json::json!(null));
+}
+
+#[test]
+pub fn test_set_spec_and_status() {
+    let mut dynamic_object = DynamicObject::from_kube(deps_hack::kube::api::DynamicObject {
+        metadata: deps_hack::kube::api::ObjectMeta::default(),
+        types: Some(deps_hack::kube::api::TypeMeta {
+            api_version: "apps/v1".to_string(),
+            kind: "StatefulSet".to_string(),
+        }),
+        data: deps_hack::serde_json::json!({
+            "spec": {"replicas": 3},
+            "status": {"readyReplicas": 1},
+        }),
+    });
+    dynamic_object.set_spec(JsonValueExec::from_kube(deps_hack::serde_json::json!({"replicas": 5})));
+    dynamic_object.set_status(JsonValueExec::from_kube(deps_hack::serde_json::json!({"readyReplicas": 5})));
+    assert_eq!(dynamic_object.into_kube().data, deps_hack::serde_json::json!({
+        "spec": {"replicas": 5},
+        "status": {"readyReplicas": 5},
+    }));
+
+    let mut dynamic_object = DynamicObject::from_kube(deps_hack::kube::api::DynamicObject {
+        metadata: deps_hack::kube::api::ObjectMeta::default(),
+        types: Some(deps_hack::kube::api::TypeMeta {
+            api_version: "v1".to_string(),
+            kind: "Secret".to_string(),
+        }),
+        data: deps_hack::serde_json::json!({
+            "data": {"key": "dmFsdWU="},
+            "type": "Opaque",
+        }),
+    });
+    let mut spec = dynamic_object.spec();
+    spec.set_field("stringData".to_string(), JsonValueExec::from_kube(deps_hack::serde_json::json!({"key": "value"})));
+    dynamic_object.set_spec(spec);
+    assert_eq!(dynamic_object.into_kube().data, deps_hack::serde_json::json!({
+        "data": {"key": "dmFsdWU="},
+        "stringData": {"key": "value"},
+        "type": "Opaque",
+    }));
+}
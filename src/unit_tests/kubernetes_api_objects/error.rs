@@ -32,6 +32,39 @@ pub fn test_apierror_fmt() {
     assert_eq!(format!("{:?}", error), "Timeout");
     let error = APIError::ServerTimeout;
     assert_eq!(format!("{:?}", error), "ServerTimeout");
+    let error = APIError::TooManyRequests;
+    assert_eq!(format!("{:?}", error), "TooManyRequests");
+    let error = APIError::Gone;
+    assert_eq!(format!("{:?}", error), "Gone");
+    let error = APIError::Unauthorized;
+    assert_eq!(format!("{:?}", error), "Unauthorized");
+    let error = APIError::ServiceUnavailable;
+    assert_eq!(format!("{:?}", error), "ServiceUnavailable");
+    let error = APIError::RequestEntityTooLarge;
+    assert_eq!(format!("{:?}", error), "RequestEntityTooLarge");
+    let error = APIError::Transport;
+    assert_eq!(format!("{:?}", error), "Transport");
     let error = APIError::Other;
     assert_eq!(format!("{:?}", error), "Other");
 }
+
+#[test]
+pub fn test_is_transient_error() {
+    assert!(APIError::Timeout.is_transient_error());
+    assert!(APIError::ServerTimeout.is_transient_error());
+    assert!(APIError::TooManyRequests.is_transient_error());
+    assert!(APIError::ServiceUnavailable.is_transient_error());
+    assert!(APIError::Transport.is_transient_error());
+    assert!(!APIError::Gone.is_transient_error());
+    assert!(!APIError::Unauthorized.is_transient_error());
+    assert!(!APIError::RequestEntityTooLarge.is_transient_error());
+    assert!(!APIError::ObjectNotFound.is_transient_error());
+    assert!(!APIError::Other.is_transient_error());
+}
+
+#[test]
+pub fn test_is_object_already_exists() {
+    assert!(APIError::ObjectAlreadyExists.is_object_already_exists());
+    assert!(!APIError::ObjectNotFound.is_object_already_exists());
+    assert!(!APIError::Conflict.is_object_already_exists());
+}
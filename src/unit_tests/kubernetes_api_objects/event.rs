@@ -0,0 +1,127 @@
+// Copyright 2022 VMware, Inc.
+// SPDX-License-Identifier: MIT
+use crate::kubernetes_api_objects::exec::event::*;
+use crate::kubernetes_api_objects::exec::object_meta::*;
+use crate::kubernetes_api_objects::exec::resource::*;
+use vstd::prelude::*;
+use vstd::string::*;
+
+#[test]
+pub fn test_default() {
+    let event = Event::default();
+    assert_eq!(
+        event.into_kube(),
+        deps_hack::k8s_openapi::api::core::v1::Event::default()
+    );
+}
+
+#[test]
+pub fn test_set_metadata() {
+    let mut event = Event::default();
+    let mut metadata = ObjectMeta::default();
+    metadata.set_name("name".to_string());
+    event.set_metadata(metadata.clone());
+    assert_eq!(metadata.into_kube(), event.into_kube().metadata);
+}
+
+#[test]
+pub fn test_metadata() {
+    let mut event = Event::default();
+    let mut metadata = ObjectMeta::default();
+    metadata.set_name("name".to_string());
+    event.set_metadata(metadata.clone());
+    assert_eq!(metadata.into_kube(), event.metadata().into_kube());
+}
+
+#[test]
+pub fn test_set_involved_object() {
+    let mut event = Event::default();
+    let mut involved_object = ObjectReference::default();
+    involved_object.set_kind("ConfigMap".to_string());
+    involved_object.set_name("name".to_string());
+    involved_object.set_namespace("default".to_string());
+    event.set_involved_object(involved_object.clone());
+    assert_eq!(
+        deps_hack::k8s_openapi::api::core::v1::ObjectReference {
+            kind: Some("ConfigMap".to_string()),
+            name: Some("name".to_string()),
+            namespace: Some("default".to_string()),
+            ..Default::default()
+        },
+        event.involved_object().into_kube()
+    );
+}
+
+#[test]
+pub fn test_set_reason() {
+    let mut event = Event::default();
+    event.set_reason("Created".to_string());
+    assert_eq!("Created".to_string(), event.into_kube().reason.unwrap());
+}
+
+#[test]
+pub fn test_set_message() {
+    let mut event = Event::default();
+    event.set_message("message".to_string());
+    assert_eq!("message".to_string(), event.into_kube().message.unwrap());
+}
+
+#[test]
+pub fn test_set_type() {
+    let mut event = Event::default();
+    event.set_type("Warning".to_string());
+    assert_eq!("Warning".to_string(), event.type_().unwrap());
+}
+
+#[test]
+pub fn test_api_resource() {
+    let api_resource = Event::api_resource();
+    assert_eq!(api_resource.into_kube().kind, "Event");
+}
+
+#[test]
+pub fn test_clone() {
+    let mut event = Event::default();
+    let mut metadata = ObjectMeta::default();
+    metadata.set_name("name".to_string());
+    event.set_metadata(metadata.clone());
+    let event_clone = event.clone();
+    assert_eq!(event.into_kube(), event_clone.into_kube());
+}
+
+#[test]
+pub fn test_kube() {
+    let kube_event = deps_hack::k8s_openapi::api::core::v1::Event {
+        metadata: deps_hack::k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta {
+            name: Some("name".to_string()),
+            ..Default::default()
+        },
+        reason: Some("Created".to_string()),
+        type_: Some("Normal".to_string()),
+        ..Default::default()
+    };
+
+    let event = Event::from_kube(kube_event.clone());
+
+    assert_eq!(event.into_kube(), kube_event);
+}
+
+#[test]
+pub fn test_marshal() {
+    let kube_event = deps_hack::k8s_openapi::api::core::v1::Event {
+        metadata: deps_hack::k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta {
+            name: Some("name".to_string()),
+            ..Default::default()
+        },
+        reason: Some("Created".to_string()),
+        type_: Some("Normal".to_string()),
+        ..Default::default()
+    };
+
+    let event = Event::from_kube(kube_event.clone());
+
+    assert_eq!(
+        kube_event,
+        Event::unmarshal(event.marshal()).unwrap().into_kube()
+    );
+}
@@ -0,0 +1,153 @@
+// Copyright 2022 VMware, Inc.
+// SPDX-License-Identifier: MIT
+use crate::kubernetes_api_objects::exec::job::*;
+use crate::kubernetes_api_objects::exec::object_meta::*;
+use crate::kubernetes_api_objects::exec::pod_template_spec::*;
+use crate::kubernetes_api_objects::exec::resource::*;
+use vstd::prelude::*;
+use vstd::string::*;
+
+#[test]
+pub fn test_default() {
+    let job = Job::default();
+    assert_eq!(
+        job.into_kube(),
+        deps_hack::k8s_openapi::api::batch::v1::Job::default()
+    );
+}
+
+#[test]
+pub fn test_set_metadata() {
+    let mut object_meta = ObjectMeta::default();
+    object_meta.set_name("name".to_string());
+    let mut job = Job::default();
+    job.set_metadata(object_meta.clone());
+    assert_eq!(object_meta.into_kube(), job.into_kube().metadata);
+}
+
+#[test]
+pub fn test_metadata() {
+    let mut object_meta = ObjectMeta::default();
+    object_meta.set_name("name".to_string());
+    let mut job = Job::default();
+    job.set_metadata(object_meta.clone());
+    assert_eq!(object_meta.into_kube(), job.metadata().into_kube());
+}
+
+#[test]
+pub fn test_set_spec() {
+    let mut job = Job::default();
+    let mut job_spec = JobSpec::default();
+    job_spec.set_backoff_limit(3);
+    job.set_spec(job_spec.clone());
+    assert_eq!(job_spec.into_kube(), job.into_kube().spec.unwrap());
+}
+
+#[test]
+pub fn test_spec() {
+    let mut job = Job::default();
+    let temp = job.spec();
+    if !temp.is_none() {
+        panic!("spec should be none");
+    }
+    let mut job_spec = JobSpec::default();
+    job_spec.set_backoff_limit(3);
+    job.set_spec(job_spec.clone());
+    assert_eq!(job_spec.into_kube(), job.spec().unwrap().into_kube());
+}
+
+#[test]
+pub fn test_api_resource() {
+    let api_resource = Job::api_resource();
+    assert_eq!(api_resource.into_kube().kind, "Job");
+}
+
+#[test]
+pub fn test_clone() {
+    let mut job = Job::default();
+    let mut job_spec = JobSpec::default();
+    let mut pod_template_spec = PodTemplateSpec::default();
+    let mut object_meta = ObjectMeta::default();
+    object_meta.set_name("name".to_string());
+    pod_template_spec.set_metadata(object_meta.clone());
+    job_spec.set_template(pod_template_spec.clone());
+    job.set_spec(job_spec.clone());
+    let job_clone = job.clone();
+    assert_eq!(job.into_kube(), job_clone.into_kube());
+}
+
+#[test]
+pub fn test_status() {
+    let job = Job::default();
+    let temp = job.status();
+    if !temp.is_none() {
+        panic!("Job status should be None, but it's not.");
+    }
+    let job = Job::from_kube(deps_hack::k8s_openapi::api::batch::v1::Job {
+        status: Some(deps_hack::k8s_openapi::api::batch::v1::JobStatus {
+            succeeded: Some(1),
+            failed: Some(2),
+            ..Default::default()
+        }),
+        ..Default::default()
+    });
+    assert_eq!(job.status().unwrap().succeeded(), Some(1));
+    assert_eq!(job.status().unwrap().failed(), Some(2));
+}
+
+#[test]
+pub fn test_succeeded() {
+    let job = Job::default();
+    assert!(!job.succeeded());
+    let job = Job::from_kube(deps_hack::k8s_openapi::api::batch::v1::Job {
+        status: Some(deps_hack::k8s_openapi::api::batch::v1::JobStatus {
+            failed: Some(1),
+            ..Default::default()
+        }),
+        ..Default::default()
+    });
+    assert!(!job.succeeded());
+    let job = Job::from_kube(deps_hack::k8s_openapi::api::batch::v1::Job {
+        status: Some(deps_hack::k8s_openapi::api::batch::v1::JobStatus {
+            succeeded: Some(1),
+            ..Default::default()
+        }),
+        ..Default::default()
+    });
+    assert!(job.succeeded());
+}
+
+#[test]
+pub fn test_kube() {
+    let kube_job = deps_hack::k8s_openapi::api::batch::v1::Job {
+        metadata: deps_hack::k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta {
+            name: Some("name".to_string()),
+            ..Default::default()
+        },
+        spec: Some(deps_hack::k8s_openapi::api::batch::v1::JobSpec {
+            backoff_limit: Some(3),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+
+    let job = Job::from_kube(kube_job.clone());
+    assert_eq!(job.into_kube(), kube_job);
+}
+
+#[test]
+pub fn test_marshal() {
+    let kube_job = deps_hack::k8s_openapi::api::batch::v1::Job {
+        metadata: deps_hack::k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta {
+            name: Some("name".to_string()),
+            ..Default::default()
+        },
+        spec: Some(deps_hack::k8s_openapi::api::batch::v1::JobSpec {
+            backoff_limit: Some(3),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    let job = Job::from_kube(kube_job.clone());
+    assert_eq!(kube_job, Job::unmarshal(job.marshal()).unwrap().into_kube());
+}
@@ -0,0 +1,84 @@
+// Copyright 2022 VMware, Inc.
+// SPDX-License-Identifier: MIT
+use crate::kubernetes_api_objects::exec::job::*;
+use crate::kubernetes_api_objects::exec::object_meta::*;
+use crate::kubernetes_api_objects::exec::pod_template_spec::*;
+use crate::kubernetes_api_objects::exec::resource::*;
+use vstd::prelude::*;
+use vstd::string::*;
+
+#[test]
+pub fn test_default() {
+    let job_spec = JobSpec::default();
+    assert_eq!(
+        job_spec.into_kube(),
+        deps_hack::k8s_openapi::api::batch::v1::JobSpec::default()
+    );
+}
+
+#[test]
+pub fn test_clone() {
+    let mut job_spec = JobSpec::default();
+    job_spec.set_backoff_limit(3);
+    let job_spec_clone = job_spec.clone();
+    assert_eq!(job_spec.into_kube(), job_spec_clone.into_kube());
+}
+
+#[test]
+pub fn test_set_template() {
+    let mut job_spec = JobSpec::default();
+    let mut pod_template_spec = PodTemplateSpec::default();
+    let mut object_meta = ObjectMeta::default();
+    object_meta.set_name("name".to_string());
+    pod_template_spec.set_metadata(object_meta);
+    job_spec.set_template(pod_template_spec.clone());
+    assert_eq!(pod_template_spec.into_kube(), job_spec.into_kube().template);
+}
+
+#[test]
+pub fn test_template() {
+    let mut job_spec = JobSpec::default();
+    let mut pod_template_spec = PodTemplateSpec::default();
+    let mut object_meta = ObjectMeta::default();
+    object_meta.set_name("name".to_string());
+    pod_template_spec.set_metadata(object_meta);
+    job_spec.set_template(pod_template_spec.clone());
+    assert_eq!(
+        pod_template_spec.into_kube(),
+        job_spec.template().into_kube()
+    );
+}
+
+#[test]
+pub fn test_set_backoff_limit() {
+    let mut job_spec = JobSpec::default();
+    job_spec.set_backoff_limit(3);
+    assert_eq!(Some(3), job_spec.into_kube().backoff_limit);
+}
+
+#[test]
+pub fn test_backoff_limit() {
+    let mut job_spec = JobSpec::default();
+    assert_eq!(None, job_spec.backoff_limit());
+    job_spec.set_backoff_limit(3);
+    assert_eq!(Some(3), job_spec.backoff_limit());
+}
+
+#[test]
+pub fn test_kube() {
+    let kube_job_spec = deps_hack::k8s_openapi::api::batch::v1::JobSpec {
+        backoff_limit: Some(3),
+        template: deps_hack::k8s_openapi::api::core::v1::PodTemplateSpec {
+            metadata: Some(
+                deps_hack::k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta {
+                    name: Some("name".to_string()),
+                    ..Default::default()
+                },
+            ),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let job_spec = JobSpec::from_kube(kube_job_spec.clone());
+    assert_eq!(job_spec.into_kube(), kube_job_spec);
+}
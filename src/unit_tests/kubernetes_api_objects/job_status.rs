@@ -0,0 +1,35 @@
+// Copyright 2022 VMware, Inc.
+// SPDX-License-Identifier: MIT
+use crate::kubernetes_api_objects::exec::job::*;
+use crate::kubernetes_api_objects::exec::resource::*;
+use vstd::prelude::*;
+
+#[test]
+pub fn test_kube() {
+    let kube_job_status = deps_hack::k8s_openapi::api::batch::v1::JobStatus {
+        active: Some(1),
+        succeeded: Some(2),
+        failed: Some(3),
+        ..Default::default()
+    };
+    let job_status = JobStatus::from_kube(kube_job_status.clone());
+    assert_eq!(job_status.into_kube(), kube_job_status);
+}
+
+#[test]
+pub fn test_succeeded() {
+    let job_status = JobStatus::from_kube(deps_hack::k8s_openapi::api::batch::v1::JobStatus {
+        succeeded: Some(1),
+        ..Default::default()
+    });
+    assert_eq!(Some(1), job_status.succeeded());
+}
+
+#[test]
+pub fn test_failed() {
+    let job_status = JobStatus::from_kube(deps_hack::k8s_openapi::api::batch::v1::JobStatus {
+        failed: Some(1),
+        ..Default::default()
+    });
+    assert_eq!(Some(1), job_status.failed());
+}
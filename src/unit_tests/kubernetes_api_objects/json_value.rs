@@ -0,0 +1,76 @@
+// Copyright 2022 VMware, Inc.
+// SPDX-License-Identifier: MIT
+use crate::kubernetes_api_objects::exec::json_value::*;
+use crate::kubernetes_api_objects::exec::resource::*;
+use vstd::prelude::*;
+use vstd::string::*;
+
+#[test]
+pub fn test_scalars() {
+    assert_eq!(JsonValueExec::null().into_kube(), deps_hack::serde_json::json!(null));
+    assert_eq!(JsonValueExec::from_bool(true).into_kube(), deps_hack::serde_json::json!(true));
+    assert_eq!(JsonValueExec::from_i64(3).into_kube(), deps_hack::serde_json::json!(3));
+    assert_eq!(JsonValueExec::from_string("value".to_string()).into_kube(), deps_hack::serde_json::json!("value"));
+}
+
+#[test]
+pub fn test_from_vec() {
+    let v = JsonValueExec::from_vec(vec![JsonValueExec::from_string("a".to_string()), JsonValueExec::from_bool(false)]);
+    assert_eq!(v.into_kube(), deps_hack::serde_json::json!(["a", false]));
+}
+
+#[test]
+pub fn test_as_bool() {
+    assert_eq!(JsonValueExec::from_bool(true).as_bool(), Some(true));
+    assert_eq!(JsonValueExec::null().as_bool(), None);
+}
+
+#[test]
+pub fn test_as_string() {
+    assert_eq!(JsonValueExec::from_string("value".to_string()).as_string(), Some("value".to_string()));
+    assert_eq!(JsonValueExec::from_bool(true).as_string(), None);
+}
+
+#[test]
+pub fn test_field() {
+    let v = JsonValueExec::from_kube(deps_hack::serde_json::json!({
+        "key": "value",
+    }));
+    assert_eq!(v.field(&"key".to_string()).unwrap().into_kube(), deps_hack::serde_json::json!("value"));
+    assert!(v.field(&"other".to_string()).is_none());
+    assert!(JsonValueExec::null().field(&"key".to_string()).is_none());
+}
+
+#[test]
+pub fn test_set_field() {
+    let mut v = JsonValueExec::from_kube(deps_hack::serde_json::json!({
+        "key": "value",
+        "unknown": {"nested": 1},
+    }));
+    v.set_field("key".to_string(), JsonValueExec::from_string("new_value".to_string()));
+    assert_eq!(v.into_kube(), deps_hack::serde_json::json!({
+        "key": "new_value",
+        "unknown": {"nested": 1},
+    }));
+
+    let mut v = JsonValueExec::null();
+    v.set_field("key".to_string(), JsonValueExec::from_bool(true));
+    assert_eq!(v.into_kube(), deps_hack::serde_json::json!({"key": true}));
+}
+
+#[test]
+pub fn test_remove_field() {
+    let mut v = JsonValueExec::from_kube(deps_hack::serde_json::json!({
+        "key": "value",
+        "other": "value",
+    }));
+    v.remove_field(&"key".to_string());
+    assert_eq!(v.into_kube(), deps_hack::serde_json::json!({"other": "value"}));
+}
+
+#[test]
+pub fn test_clone() {
+    let v = JsonValueExec::from_kube(deps_hack::serde_json::json!({"key": ["a", "b"]}));
+    let v_clone = v.clone();
+    assert_eq!(v.into_kube(), v_clone.into_kube());
+}
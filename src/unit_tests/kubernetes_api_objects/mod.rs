@@ -3,6 +3,10 @@
 pub mod affinity;
 pub mod api_method;
 pub mod api_resource;
+pub mod capabilities;
+pub mod cluster_role;
+pub mod cluster_role_binding;
+pub mod condition;
 pub mod config_map;
 pub mod config_map_projection;
 pub mod config_map_volume_source;
@@ -18,19 +22,31 @@ pub mod empty_dir_volume_source;
 pub mod env_var;
 pub mod env_var_source;
 pub mod error;
+pub mod event;
 pub mod exec_action;
 pub mod host_path_volume_source;
+pub mod job;
+pub mod job_spec;
+pub mod job_status;
+pub mod json_value;
 pub mod key_to_path;
 pub mod label_selector;
 pub mod lifecycle;
 pub mod lifecycle_handler;
 pub mod local_object_reference;
+pub mod namespace;
 pub mod object_field_selector;
 pub mod object_meta;
 pub mod owner_reference;
+pub mod persistent_volume;
 pub mod persistent_volume_claim;
 pub mod persistent_volume_claim_spec;
+pub mod persistent_volume_claim_status;
+pub mod persistent_volume_claim_volume_source;
+pub mod persistent_volume_spec;
 pub mod pod;
+pub mod pod_disruption_budget;
+pub mod pod_disruption_budget_spec;
 pub mod pod_security_context;
 pub mod pod_spec;
 pub mod pod_template_spec;
@@ -41,6 +57,7 @@ pub mod resource_requirements;
 pub mod role;
 pub mod role_binding;
 pub mod role_ref;
+pub mod seccomp_profile;
 pub mod secret;
 pub mod secret_projection;
 pub mod secret_volume_source;
@@ -53,9 +70,11 @@ pub mod stateful_set;
 pub mod stateful_set_persistent_volume_claim_retention_policy;
 pub mod stateful_set_spec;
 pub mod stateful_set_status;
+pub mod storage_class;
 pub mod subject;
 pub mod tcp_socket_action;
 pub mod toleration;
+pub mod topology_spread_constraint;
 pub mod volume;
 pub mod volume_mount;
 pub mod volume_projection;
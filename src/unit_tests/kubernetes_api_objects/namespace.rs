@@ -0,0 +1,85 @@
+// Copyright 2022 VMware, Inc.
+// SPDX-License-Identifier: MIT
+use crate::kubernetes_api_objects::exec::namespace::*;
+use crate::kubernetes_api_objects::exec::object_meta::*;
+use crate::kubernetes_api_objects::exec::resource::*;
+use vstd::prelude::*;
+use vstd::string::*;
+
+#[test]
+pub fn test_default() {
+    let namespace = Namespace::default();
+    assert_eq!(
+        namespace.into_kube(),
+        deps_hack::k8s_openapi::api::core::v1::Namespace::default()
+    );
+}
+
+#[test]
+pub fn test_set_metadata() {
+    let mut namespace = Namespace::default();
+    let mut object_meta = ObjectMeta::default();
+    object_meta.set_name("name".to_string());
+    namespace.set_metadata(object_meta.clone());
+    assert_eq!(object_meta.into_kube(), namespace.into_kube().metadata);
+}
+
+#[test]
+pub fn test_metadata() {
+    let mut namespace = Namespace::default();
+    let mut object_meta = ObjectMeta::default();
+    object_meta.set_name("name".to_string());
+    namespace.set_metadata(object_meta.clone());
+    assert_eq!(object_meta.into_kube(), namespace.metadata().into_kube());
+}
+
+#[test]
+pub fn test_clone() {
+    let mut namespace = Namespace::default();
+    let mut object_meta = ObjectMeta::default();
+    object_meta.set_name("name".to_string());
+    namespace.set_metadata(object_meta.clone());
+    let namespace_clone = namespace.clone();
+    assert_eq!(namespace.into_kube(), namespace_clone.into_kube());
+}
+
+#[test]
+pub fn test_api_resource() {
+    let api_resource = Namespace::api_resource();
+    assert_eq!(api_resource.into_kube().kind, "Namespace");
+}
+
+#[test]
+pub fn test_kube() {
+    let kube_namespace = deps_hack::k8s_openapi::api::core::v1::Namespace {
+        metadata: deps_hack::k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta {
+            name: Some("name".to_string()),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    let namespace = Namespace::from_kube(kube_namespace.clone());
+
+    assert_eq!(namespace.into_kube(), kube_namespace);
+}
+
+#[test]
+pub fn test_marshal() {
+    let kube_namespace = deps_hack::k8s_openapi::api::core::v1::Namespace {
+        metadata: deps_hack::k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta {
+            name: Some("name".to_string()),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    let namespace = Namespace::from_kube(kube_namespace.clone());
+
+    assert_eq!(
+        kube_namespace,
+        Namespace::unmarshal(namespace.marshal())
+            .unwrap()
+            .into_kube()
+    );
+}
@@ -121,6 +121,19 @@ pub fn test_resource_version() {
     );
 }
 
+#[test]
+pub fn test_generation() {
+    let object_meta = ObjectMeta::default();
+    assert_eq!(None, object_meta.generation());
+    let object_meta = ObjectMeta::from_kube(
+        deps_hack::k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta {
+            generation: Some(2),
+            ..Default::default()
+        },
+    );
+    assert_eq!(Some(2), object_meta.generation());
+}
+
 #[test]
 pub fn test_has_deletion_timestamp() {
     let object_meta = ObjectMeta::default();
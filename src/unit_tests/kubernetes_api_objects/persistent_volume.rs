@@ -0,0 +1,134 @@
+// Copyright 2022 VMware, Inc.
+// SPDX-License-Identifier: MIT
+use crate::kubernetes_api_objects::exec::object_meta::*;
+use crate::kubernetes_api_objects::exec::persistent_volume::*;
+use crate::kubernetes_api_objects::exec::resource::*;
+use crate::vstd_ext::string_map::*;
+use std::collections::BTreeMap;
+use vstd::prelude::*;
+use vstd::string::*;
+
+#[test]
+pub fn test_default() {
+    let persistent_volume = PersistentVolume::default();
+    assert_eq!(
+        persistent_volume.into_kube(),
+        deps_hack::k8s_openapi::api::core::v1::PersistentVolume::default()
+    );
+}
+
+#[test]
+pub fn test_set_metadata() {
+    let mut persistent_volume = PersistentVolume::default();
+    let mut object_meta = ObjectMeta::default();
+    object_meta.set_name("name".to_string());
+    persistent_volume.set_metadata(object_meta.clone());
+    assert_eq!(
+        object_meta.into_kube(),
+        persistent_volume.into_kube().metadata
+    );
+}
+
+#[test]
+pub fn test_metadata() {
+    let mut persistent_volume = PersistentVolume::default();
+    let mut object_meta = ObjectMeta::default();
+    object_meta.set_name("name".to_string());
+    persistent_volume.set_metadata(object_meta.clone());
+    assert_eq!(
+        object_meta.into_kube(),
+        persistent_volume.metadata().into_kube()
+    );
+}
+
+#[test]
+pub fn test_set_spec() {
+    let mut persistent_volume = PersistentVolume::default();
+    let mut persistent_volume_spec = PersistentVolumeSpec::default();
+    persistent_volume_spec.set_storage_class_name("standard".to_string());
+    persistent_volume.set_spec(persistent_volume_spec.clone());
+    assert_eq!(
+        persistent_volume_spec.into_kube(),
+        persistent_volume.into_kube().spec.unwrap()
+    );
+}
+
+#[test]
+pub fn test_spec() {
+    let mut persistent_volume = PersistentVolume::default();
+    assert_eq!(persistent_volume.spec().is_none(), true);
+    let mut persistent_volume_spec = PersistentVolumeSpec::default();
+    persistent_volume_spec.set_storage_class_name("standard".to_string());
+    persistent_volume.set_spec(persistent_volume_spec.clone());
+    assert_eq!(
+        persistent_volume_spec.into_kube(),
+        persistent_volume.spec().unwrap().into_kube()
+    );
+}
+
+#[test]
+pub fn test_api_resource() {
+    let api_resource = PersistentVolume::api_resource();
+    assert_eq!(api_resource.into_kube().kind, "PersistentVolume");
+}
+
+#[test]
+pub fn test_kube() {
+    let kube_persistent_volume = deps_hack::k8s_openapi::api::core::v1::PersistentVolume {
+        metadata: deps_hack::k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta {
+            name: Some("name".to_string()),
+            ..Default::default()
+        },
+        spec: Some(
+            deps_hack::k8s_openapi::api::core::v1::PersistentVolumeSpec {
+                access_modes: Some(vec!["ReadWriteOnce".to_string()]),
+                capacity: Some(BTreeMap::from([(
+                    "storage".to_string(),
+                    deps_hack::k8s_openapi::apimachinery::pkg::api::resource::Quantity(
+                        "1Gi".to_string(),
+                    ),
+                )])),
+                storage_class_name: Some("standard".to_string()),
+                ..Default::default()
+            },
+        ),
+        ..Default::default()
+    };
+
+    let persistent_volume = PersistentVolume::from_kube(kube_persistent_volume.clone());
+
+    assert_eq!(persistent_volume.into_kube(), kube_persistent_volume);
+}
+
+#[test]
+pub fn test_marshal() {
+    let kube_persistent_volume = deps_hack::k8s_openapi::api::core::v1::PersistentVolume {
+        metadata: deps_hack::k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta {
+            name: Some("name".to_string()),
+            ..Default::default()
+        },
+        spec: Some(
+            deps_hack::k8s_openapi::api::core::v1::PersistentVolumeSpec {
+                access_modes: Some(vec!["ReadWriteOnce".to_string()]),
+                capacity: Some(BTreeMap::from([(
+                    "storage".to_string(),
+                    deps_hack::k8s_openapi::apimachinery::pkg::api::resource::Quantity(
+                        "1Gi".to_string(),
+                    ),
+                )])),
+                storage_class_name: Some("standard".to_string()),
+                ..Default::default()
+            },
+        ),
+        ..Default::default()
+    };
+
+    let persistent_volume = PersistentVolume::from_kube(kube_persistent_volume.clone());
+
+    assert_eq!(
+        kube_persistent_volume,
+        PersistentVolume::unmarshal(persistent_volume.marshal())
+            .unwrap()
+            .into_kube()
+    );
+}
@@ -73,6 +73,57 @@ pub fn test_spec() {
     );
 }
 
+#[test]
+pub fn test_status() {
+    let persistent_volume_claim = PersistentVolumeClaim::default();
+    assert!(persistent_volume_claim.status().is_none());
+    let persistent_volume_claim = PersistentVolumeClaim::from_kube(
+        deps_hack::k8s_openapi::api::core::v1::PersistentVolumeClaim {
+            status: Some(
+                deps_hack::k8s_openapi::api::core::v1::PersistentVolumeClaimStatus {
+                    phase: Some("Pending".to_string()),
+                    ..Default::default()
+                },
+            ),
+            ..Default::default()
+        },
+    );
+    assert_eq!(
+        persistent_volume_claim.status().unwrap().phase(),
+        Some("Pending".to_string())
+    );
+}
+
+#[test]
+pub fn test_bound() {
+    let persistent_volume_claim = PersistentVolumeClaim::default();
+    assert!(!persistent_volume_claim.bound());
+    let persistent_volume_claim = PersistentVolumeClaim::from_kube(
+        deps_hack::k8s_openapi::api::core::v1::PersistentVolumeClaim {
+            status: Some(
+                deps_hack::k8s_openapi::api::core::v1::PersistentVolumeClaimStatus {
+                    phase: Some("Pending".to_string()),
+                    ..Default::default()
+                },
+            ),
+            ..Default::default()
+        },
+    );
+    assert!(!persistent_volume_claim.bound());
+    let persistent_volume_claim = PersistentVolumeClaim::from_kube(
+        deps_hack::k8s_openapi::api::core::v1::PersistentVolumeClaim {
+            status: Some(
+                deps_hack::k8s_openapi::api::core::v1::PersistentVolumeClaimStatus {
+                    phase: Some("Bound".to_string()),
+                    ..Default::default()
+                },
+            ),
+            ..Default::default()
+        },
+    );
+    assert!(persistent_volume_claim.bound());
+}
+
 #[test]
 pub fn test_api_resource() {
     let api_resource = PersistentVolumeClaim::api_resource();
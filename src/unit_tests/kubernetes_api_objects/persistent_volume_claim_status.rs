@@ -0,0 +1,34 @@
+// Copyright 2022 VMware, Inc.
+// SPDX-License-Identifier: MIT
+use crate::kubernetes_api_objects::exec::persistent_volume_claim::*;
+use crate::kubernetes_api_objects::exec::resource::*;
+use vstd::prelude::*;
+
+#[test]
+pub fn test_kube() {
+    let kube_persistent_volume_claim_status =
+        deps_hack::k8s_openapi::api::core::v1::PersistentVolumeClaimStatus {
+            phase: Some("Bound".to_string()),
+            ..Default::default()
+        };
+    let persistent_volume_claim_status =
+        PersistentVolumeClaimStatus::from_kube(kube_persistent_volume_claim_status.clone());
+    assert_eq!(
+        persistent_volume_claim_status.into_kube(),
+        kube_persistent_volume_claim_status
+    );
+}
+
+#[test]
+pub fn test_phase() {
+    let persistent_volume_claim_status = PersistentVolumeClaimStatus::from_kube(
+        deps_hack::k8s_openapi::api::core::v1::PersistentVolumeClaimStatus {
+            phase: Some("Bound".to_string()),
+            ..Default::default()
+        },
+    );
+    assert_eq!(
+        Some("Bound".to_string()),
+        persistent_volume_claim_status.phase()
+    );
+}
@@ -0,0 +1,63 @@
+// Copyright 2022 VMware, Inc.
+// SPDX-License-Identifier: MIT
+use crate::kubernetes_api_objects::exec::resource::*;
+use crate::kubernetes_api_objects::exec::volume::*;
+use vstd::prelude::*;
+use vstd::string::*;
+
+#[test]
+pub fn test_default() {
+    let persistent_volume_claim_volume_source = PersistentVolumeClaimVolumeSource::default();
+    assert_eq!(
+        persistent_volume_claim_volume_source.into_kube(),
+        deps_hack::k8s_openapi::api::core::v1::PersistentVolumeClaimVolumeSource::default()
+    );
+}
+
+#[test]
+pub fn test_set_claim_name() {
+    let mut persistent_volume_claim_volume_source = PersistentVolumeClaimVolumeSource::default();
+    persistent_volume_claim_volume_source.set_claim_name("claim".to_string());
+    assert_eq!(
+        "claim".to_string(),
+        persistent_volume_claim_volume_source.into_kube().claim_name
+    );
+}
+
+#[test]
+pub fn test_set_read_only() {
+    let mut persistent_volume_claim_volume_source = PersistentVolumeClaimVolumeSource::default();
+    persistent_volume_claim_volume_source.set_read_only(true);
+    assert_eq!(
+        true,
+        persistent_volume_claim_volume_source.into_kube().read_only.unwrap()
+    );
+}
+
+#[test]
+pub fn test_clone() {
+    let mut persistent_volume_claim_volume_source = PersistentVolumeClaimVolumeSource::default();
+    persistent_volume_claim_volume_source.set_claim_name("claim".to_string());
+    let persistent_volume_claim_volume_source_clone = persistent_volume_claim_volume_source.clone();
+    assert_eq!(
+        persistent_volume_claim_volume_source.into_kube(),
+        persistent_volume_claim_volume_source_clone.into_kube()
+    );
+}
+
+#[test]
+pub fn test_kube() {
+    let kube_persistent_volume_claim_volume_source =
+        deps_hack::k8s_openapi::api::core::v1::PersistentVolumeClaimVolumeSource {
+            claim_name: "claim".to_string(),
+            read_only: Some(true),
+        };
+
+    let persistent_volume_claim_volume_source =
+        PersistentVolumeClaimVolumeSource::from_kube(kube_persistent_volume_claim_volume_source.clone());
+
+    assert_eq!(
+        persistent_volume_claim_volume_source.into_kube(),
+        kube_persistent_volume_claim_volume_source
+    );
+}
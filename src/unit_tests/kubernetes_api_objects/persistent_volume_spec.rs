@@ -0,0 +1,103 @@
+// Copyright 2022 VMware, Inc.
+// SPDX-License-Identifier: MIT
+use crate::kubernetes_api_objects::exec::persistent_volume::*;
+use crate::kubernetes_api_objects::exec::resource::*;
+use crate::vstd_ext::string_map::*;
+use std::collections::BTreeMap;
+use vstd::prelude::*;
+use vstd::string::*;
+
+#[test]
+pub fn test_default() {
+    let persistent_volume_spec = PersistentVolumeSpec::default();
+    assert_eq!(
+        persistent_volume_spec.into_kube(),
+        deps_hack::k8s_openapi::api::core::v1::PersistentVolumeSpec::default()
+    );
+}
+
+#[test]
+pub fn test_set_access_modes() {
+    let mut persistent_volume_spec = PersistentVolumeSpec::default();
+    let access_modes_gen = || {
+        let mut access_modes = Vec::new();
+        access_modes.push("ReadWriteOnce".to_string());
+        access_modes.push("ReadOnlyMany".to_string());
+        access_modes
+    };
+    persistent_volume_spec.set_access_modes(access_modes_gen());
+    assert_eq!(
+        access_modes_gen(),
+        persistent_volume_spec.into_kube().access_modes.unwrap()
+    );
+}
+
+#[test]
+pub fn test_set_capacity() {
+    let mut persistent_volume_spec = PersistentVolumeSpec::default();
+    let mut capacity = StringMap::new();
+    capacity.insert("storage".to_string(), "1Gi".to_string());
+    persistent_volume_spec.set_capacity(capacity);
+    assert_eq!(
+        BTreeMap::from([(
+            "storage".to_string(),
+            deps_hack::k8s_openapi::apimachinery::pkg::api::resource::Quantity("1Gi".to_string())
+        )]),
+        persistent_volume_spec.into_kube().capacity.unwrap()
+    );
+}
+
+#[test]
+pub fn test_set_persistent_volume_reclaim_policy() {
+    let mut persistent_volume_spec = PersistentVolumeSpec::default();
+    persistent_volume_spec.set_persistent_volume_reclaim_policy("Retain".to_string());
+    assert_eq!(
+        "Retain".to_string(),
+        persistent_volume_spec
+            .into_kube()
+            .persistent_volume_reclaim_policy
+            .unwrap()
+    );
+}
+
+#[test]
+pub fn test_set_storage_class_name() {
+    let mut persistent_volume_spec = PersistentVolumeSpec::default();
+    persistent_volume_spec.set_storage_class_name("standard".to_string());
+    assert_eq!(
+        "standard".to_string(),
+        persistent_volume_spec
+            .into_kube()
+            .storage_class_name
+            .unwrap()
+    );
+}
+
+#[test]
+pub fn test_clone() {
+    let mut persistent_volume_spec = PersistentVolumeSpec::default();
+    persistent_volume_spec.set_storage_class_name("standard".to_string());
+    let persistent_volume_spec_clone = persistent_volume_spec.clone();
+    assert_eq!(
+        persistent_volume_spec.into_kube(),
+        persistent_volume_spec_clone.into_kube()
+    );
+}
+
+#[test]
+pub fn test_kube() {
+    let kube_persistent_volume_spec = deps_hack::k8s_openapi::api::core::v1::PersistentVolumeSpec {
+        access_modes: Some(vec!["ReadWriteOnce".to_string()]),
+        persistent_volume_reclaim_policy: Some("Retain".to_string()),
+        storage_class_name: Some("standard".to_string()),
+        ..Default::default()
+    };
+
+    let persistent_volume_spec =
+        PersistentVolumeSpec::from_kube(kube_persistent_volume_spec.clone());
+
+    assert_eq!(
+        persistent_volume_spec.into_kube(),
+        kube_persistent_volume_spec
+    );
+}
@@ -0,0 +1,140 @@
+// Copyright 2022 VMware, Inc.
+// SPDX-License-Identifier: MIT
+use crate::kubernetes_api_objects::exec::object_meta::*;
+use crate::kubernetes_api_objects::exec::pod_disruption_budget::*;
+use crate::kubernetes_api_objects::exec::resource::*;
+use vstd::prelude::*;
+use vstd::string::*;
+
+#[test]
+pub fn test_default() {
+    let pod_disruption_budget = PodDisruptionBudget::default();
+    assert_eq!(
+        pod_disruption_budget.into_kube(),
+        deps_hack::k8s_openapi::api::policy::v1::PodDisruptionBudget::default()
+    );
+}
+
+#[test]
+pub fn test_set_metadata() {
+    let mut pod_disruption_budget = PodDisruptionBudget::default();
+    let mut object_meta = ObjectMeta::default();
+    object_meta.set_name("name".to_string());
+    pod_disruption_budget.set_metadata(object_meta.clone());
+    assert_eq!(
+        object_meta.into_kube(),
+        pod_disruption_budget.into_kube().metadata
+    );
+}
+
+#[test]
+pub fn test_metadata() {
+    let mut pod_disruption_budget = PodDisruptionBudget::default();
+    let mut object_meta = ObjectMeta::default();
+    object_meta.set_name("name".to_string());
+    pod_disruption_budget.set_metadata(object_meta.clone());
+    assert_eq!(
+        object_meta.into_kube(),
+        pod_disruption_budget.metadata().into_kube()
+    );
+}
+
+#[test]
+pub fn test_set_spec() {
+    let mut pod_disruption_budget = PodDisruptionBudget::default();
+    let mut pod_disruption_budget_spec = PodDisruptionBudgetSpec::default();
+    pod_disruption_budget_spec.set_min_available(2);
+    pod_disruption_budget.set_spec(pod_disruption_budget_spec.clone());
+    assert_eq!(
+        pod_disruption_budget_spec.into_kube(),
+        pod_disruption_budget.into_kube().spec.unwrap()
+    );
+}
+
+#[test]
+pub fn test_spec() {
+    let mut pod_disruption_budget = PodDisruptionBudget::default();
+    assert_eq!(pod_disruption_budget.spec().is_none(), true);
+    let mut pod_disruption_budget_spec = PodDisruptionBudgetSpec::default();
+    pod_disruption_budget_spec.set_min_available(2);
+    pod_disruption_budget.set_spec(pod_disruption_budget_spec.clone());
+    assert_eq!(
+        pod_disruption_budget_spec.into_kube(),
+        pod_disruption_budget.spec().unwrap().into_kube()
+    );
+}
+
+#[test]
+pub fn test_clone() {
+    let mut pod_disruption_budget = PodDisruptionBudget::default();
+    let mut object_meta = ObjectMeta::default();
+    object_meta.set_name("name".to_string());
+    pod_disruption_budget.set_metadata(object_meta);
+    let pod_disruption_budget_clone = pod_disruption_budget.clone();
+    assert_eq!(
+        pod_disruption_budget.into_kube(),
+        pod_disruption_budget_clone.into_kube()
+    );
+}
+
+#[test]
+pub fn test_api_resource() {
+    let api_resource = PodDisruptionBudget::api_resource();
+    assert_eq!(api_resource.into_kube().kind, "PodDisruptionBudget");
+}
+
+#[test]
+pub fn test_kube() {
+    let kube_pod_disruption_budget = deps_hack::k8s_openapi::api::policy::v1::PodDisruptionBudget {
+        metadata: deps_hack::k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta {
+            name: Some("name".to_string()),
+            namespace: Some("namespace".to_string()),
+            ..Default::default()
+        },
+        spec: Some(
+            deps_hack::k8s_openapi::api::policy::v1::PodDisruptionBudgetSpec {
+                min_available: Some(
+                    deps_hack::k8s_openapi::apimachinery::pkg::util::intstr::IntOrString::Int(2),
+                ),
+                ..Default::default()
+            },
+        ),
+        ..Default::default()
+    };
+
+    let pod_disruption_budget = PodDisruptionBudget::from_kube(kube_pod_disruption_budget.clone());
+
+    assert_eq!(
+        pod_disruption_budget.into_kube(),
+        kube_pod_disruption_budget
+    );
+}
+
+#[test]
+pub fn test_marshal() {
+    let kube_pod_disruption_budget = deps_hack::k8s_openapi::api::policy::v1::PodDisruptionBudget {
+        metadata: deps_hack::k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta {
+            name: Some("name".to_string()),
+            namespace: Some("namespace".to_string()),
+            ..Default::default()
+        },
+        spec: Some(
+            deps_hack::k8s_openapi::api::policy::v1::PodDisruptionBudgetSpec {
+                min_available: Some(
+                    deps_hack::k8s_openapi::apimachinery::pkg::util::intstr::IntOrString::Int(2),
+                ),
+                ..Default::default()
+            },
+        ),
+        ..Default::default()
+    };
+
+    let pod_disruption_budget = PodDisruptionBudget::from_kube(kube_pod_disruption_budget.clone());
+
+    assert_eq!(
+        kube_pod_disruption_budget,
+        PodDisruptionBudget::unmarshal(pod_disruption_budget.marshal())
+            .unwrap()
+            .into_kube()
+    );
+}
@@ -0,0 +1,102 @@
+// Copyright 2022 VMware, Inc.
+// SPDX-License-Identifier: MIT
+use crate::kubernetes_api_objects::exec::label_selector::*;
+use crate::kubernetes_api_objects::exec::pod_disruption_budget::*;
+use crate::kubernetes_api_objects::exec::resource::*;
+use crate::vstd_ext::string_map::*;
+use vstd::prelude::*;
+use vstd::string::*;
+
+#[test]
+pub fn test_default() {
+    let pod_disruption_budget_spec = PodDisruptionBudgetSpec::default();
+    assert_eq!(
+        pod_disruption_budget_spec.into_kube(),
+        deps_hack::k8s_openapi::api::policy::v1::PodDisruptionBudgetSpec::default()
+    );
+}
+
+#[test]
+pub fn test_set_min_available() {
+    let mut pod_disruption_budget_spec = PodDisruptionBudgetSpec::default();
+    pod_disruption_budget_spec.set_min_available(2);
+    assert_eq!(
+        deps_hack::k8s_openapi::apimachinery::pkg::util::intstr::IntOrString::Int(2),
+        pod_disruption_budget_spec
+            .into_kube()
+            .min_available
+            .unwrap()
+    );
+}
+
+#[test]
+pub fn test_min_available() {
+    let mut pod_disruption_budget_spec = PodDisruptionBudgetSpec::default();
+    assert_eq!(pod_disruption_budget_spec.min_available(), None);
+    pod_disruption_budget_spec.set_min_available(2);
+    assert_eq!(pod_disruption_budget_spec.min_available(), Some(2));
+}
+
+#[test]
+pub fn test_set_max_unavailable() {
+    let mut pod_disruption_budget_spec = PodDisruptionBudgetSpec::default();
+    pod_disruption_budget_spec.set_max_unavailable(1);
+    assert_eq!(
+        deps_hack::k8s_openapi::apimachinery::pkg::util::intstr::IntOrString::Int(1),
+        pod_disruption_budget_spec
+            .into_kube()
+            .max_unavailable
+            .unwrap()
+    );
+}
+
+#[test]
+pub fn test_max_unavailable() {
+    let mut pod_disruption_budget_spec = PodDisruptionBudgetSpec::default();
+    assert_eq!(pod_disruption_budget_spec.max_unavailable(), None);
+    pod_disruption_budget_spec.set_max_unavailable(1);
+    assert_eq!(pod_disruption_budget_spec.max_unavailable(), Some(1));
+}
+
+#[test]
+pub fn test_set_selector() {
+    let mut pod_disruption_budget_spec = PodDisruptionBudgetSpec::default();
+    let mut label_selector = LabelSelector::default();
+    let mut match_labels = StringMap::new();
+    match_labels.insert("app".to_string(), "zookeeper".to_string());
+    label_selector.set_match_labels(match_labels);
+    pod_disruption_budget_spec.set_selector(label_selector.clone());
+    assert_eq!(
+        label_selector.into_kube(),
+        pod_disruption_budget_spec.into_kube().selector.unwrap()
+    );
+}
+
+#[test]
+pub fn test_eq() {
+    let mut pod_disruption_budget_spec = PodDisruptionBudgetSpec::default();
+    pod_disruption_budget_spec.set_min_available(2);
+    let mut other = PodDisruptionBudgetSpec::default();
+    assert_eq!(pod_disruption_budget_spec.eq(&other), false);
+    other.set_min_available(2);
+    assert_eq!(pod_disruption_budget_spec.eq(&other), true);
+}
+
+#[test]
+pub fn test_kube() {
+    let kube_pod_disruption_budget_spec =
+        deps_hack::k8s_openapi::api::policy::v1::PodDisruptionBudgetSpec {
+            max_unavailable: Some(
+                deps_hack::k8s_openapi::apimachinery::pkg::util::intstr::IntOrString::Int(1),
+            ),
+            ..Default::default()
+        };
+
+    let pod_disruption_budget_spec =
+        PodDisruptionBudgetSpec::from_kube(kube_pod_disruption_budget_spec.clone());
+
+    assert_eq!(
+        pod_disruption_budget_spec.into_kube(),
+        kube_pod_disruption_budget_spec
+    );
+}
@@ -11,6 +11,70 @@ use crate::vstd_ext::string_map::*;
 use vstd::prelude::*;
 use vstd::string::*;
 
+#[test]
+pub fn test_default() {
+    let pod_security_context = PodSecurityContext::default();
+    assert_eq!(
+        pod_security_context.into_kube(),
+        deps_hack::k8s_openapi::api::core::v1::PodSecurityContext::default()
+    );
+}
+
+#[test]
+pub fn test_set_fs_group() {
+    let mut pod_security_context = PodSecurityContext::default();
+    pod_security_context.set_fs_group(1000);
+    assert_eq!(1000, pod_security_context.into_kube().fs_group.unwrap());
+}
+
+#[test]
+pub fn test_set_run_as_group() {
+    let mut pod_security_context = PodSecurityContext::default();
+    pod_security_context.set_run_as_group(1000);
+    assert_eq!(1000, pod_security_context.into_kube().run_as_group.unwrap());
+}
+
+#[test]
+pub fn test_set_run_as_non_root() {
+    let mut pod_security_context = PodSecurityContext::default();
+    pod_security_context.set_run_as_non_root(true);
+    assert_eq!(
+        true,
+        pod_security_context.into_kube().run_as_non_root.unwrap()
+    );
+}
+
+#[test]
+pub fn test_set_run_as_user() {
+    let mut pod_security_context = PodSecurityContext::default();
+    pod_security_context.set_run_as_user(1000);
+    assert_eq!(1000, pod_security_context.into_kube().run_as_user.unwrap());
+}
+
+#[test]
+pub fn test_set_seccomp_profile() {
+    let mut pod_security_context = PodSecurityContext::default();
+    let mut seccomp_profile = SeccompProfile::default();
+    seccomp_profile.set_type("RuntimeDefault".to_string());
+    pod_security_context.set_seccomp_profile(seccomp_profile.clone());
+    assert_eq!(
+        seccomp_profile.into_kube(),
+        pod_security_context.into_kube().seccomp_profile.unwrap()
+    );
+}
+
+#[test]
+pub fn test_clone() {
+    let mut pod_security_context = PodSecurityContext::default();
+    pod_security_context.set_fs_group(1000);
+    pod_security_context.set_run_as_non_root(true);
+    let pod_security_context_clone = pod_security_context.clone();
+    assert_eq!(
+        pod_security_context.into_kube(),
+        pod_security_context_clone.into_kube()
+    );
+}
+
 #[test]
 pub fn test_kube() {
     let kube_pod_security_context = deps_hack::k8s_openapi::api::core::v1::PodSecurityContext {
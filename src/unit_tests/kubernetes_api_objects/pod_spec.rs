@@ -6,6 +6,7 @@ use crate::kubernetes_api_objects::exec::object_meta::*;
 use crate::kubernetes_api_objects::exec::pod::*;
 use crate::kubernetes_api_objects::exec::resource::*;
 use crate::kubernetes_api_objects::exec::toleration::*;
+use crate::kubernetes_api_objects::exec::topology_spread_constraint::*;
 use crate::kubernetes_api_objects::exec::volume::*;
 use crate::vstd_ext::string_map::*;
 use vstd::prelude::*;
@@ -105,6 +106,19 @@ pub fn test_set_tolerations() {
     );
 }
 
+#[test]
+pub fn test_set_topology_spread_constraints() {
+    let mut pod_spec = PodSpec::default();
+    let mut topology_spread_constraint = TopologySpreadConstraint::default();
+    topology_spread_constraint.set_max_skew(1);
+    topology_spread_constraint.set_topology_key("topology.kubernetes.io/zone".to_string());
+    pod_spec.set_topology_spread_constraints(vec![topology_spread_constraint.clone()]);
+    assert_eq!(
+        vec![topology_spread_constraint.into_kube()],
+        pod_spec.into_kube().topology_spread_constraints.unwrap()
+    );
+}
+
 #[test]
 pub fn test_set_node_selector() {
     let mut pod_spec = PodSpec::default();
@@ -143,6 +157,33 @@ pub fn test_set_dns_policy() {
     );
 }
 
+#[test]
+pub fn test_set_restart_policy() {
+    let mut pod_spec = PodSpec::default();
+    if pod_spec.clone().into_kube().restart_policy.is_some() {
+        panic!("restart_policy should be None");
+    };
+    pod_spec.set_restart_policy("OnFailure".to_string());
+    assert_eq!(
+        "OnFailure".to_string(),
+        pod_spec.clone().into_kube().restart_policy.unwrap()
+    );
+}
+
+#[test]
+pub fn test_set_node_name() {
+    let mut pod_spec = PodSpec::default();
+    if pod_spec.node_name().is_some() {
+        panic!("node_name should be None");
+    };
+    pod_spec.set_node_name("node".to_string());
+    assert_eq!("node".to_string(), pod_spec.node_name().unwrap());
+    assert_eq!(
+        "node".to_string(),
+        pod_spec.clone().into_kube().node_name.unwrap()
+    );
+}
+
 #[test]
 pub fn test_set_scheduler_name() {
     let mut pod_spec = PodSpec::default();
@@ -74,6 +74,26 @@ pub fn test_set_subjects() {
     );
 }
 
+#[test]
+pub fn test_subjects() {
+    let mut role_binding = RoleBinding::default();
+    assert_eq!(role_binding.subjects().is_none(), true);
+    let mut subject = Subject::default();
+    subject.set_api_group("rbac.authorization.k8s.io".to_string());
+    subject.set_kind("Group".to_string());
+    subject.set_name("name".to_string());
+    role_binding.set_subjects(vec![subject.clone()]);
+    assert_eq!(
+        vec![subject.into_kube()],
+        role_binding
+            .subjects()
+            .unwrap()
+            .into_iter()
+            .map(|s: Subject| s.into_kube())
+            .collect::<Vec<_>>()
+    );
+}
+
 #[test]
 pub fn test_clone() {
     let mut role_binding = RoleBinding::default();
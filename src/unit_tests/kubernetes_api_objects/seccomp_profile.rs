@@ -0,0 +1,58 @@
+// Copyright 2022 VMware, Inc.
+// SPDX-License-Identifier: MIT
+use crate::kubernetes_api_objects::exec::container::*;
+use vstd::prelude::*;
+use vstd::string::*;
+
+#[test]
+pub fn test_default() {
+    let seccomp_profile = SeccompProfile::default();
+    assert_eq!(
+        seccomp_profile.into_kube(),
+        deps_hack::k8s_openapi::api::core::v1::SeccompProfile::default()
+    );
+}
+
+#[test]
+pub fn test_set_localhost_profile() {
+    let mut seccomp_profile = SeccompProfile::default();
+    seccomp_profile.set_localhost_profile("profiles/audit.json".to_string());
+    assert_eq!(
+        "profiles/audit.json".to_string(),
+        seccomp_profile.into_kube().localhost_profile.unwrap()
+    );
+}
+
+#[test]
+pub fn test_set_type() {
+    let mut seccomp_profile = SeccompProfile::default();
+    seccomp_profile.set_type("RuntimeDefault".to_string());
+    assert_eq!(
+        "RuntimeDefault".to_string(),
+        seccomp_profile.into_kube().type_
+    );
+}
+
+#[test]
+pub fn test_clone() {
+    let mut seccomp_profile = SeccompProfile::default();
+    seccomp_profile.set_type("Localhost".to_string());
+    seccomp_profile.set_localhost_profile("profiles/audit.json".to_string());
+    let seccomp_profile_clone = seccomp_profile.clone();
+    assert_eq!(
+        seccomp_profile.into_kube(),
+        seccomp_profile_clone.into_kube()
+    );
+}
+
+#[test]
+pub fn test_kube() {
+    let kube_seccomp_profile = deps_hack::k8s_openapi::api::core::v1::SeccompProfile {
+        localhost_profile: Some("profiles/audit.json".to_string()),
+        type_: "Localhost".to_string(),
+    };
+
+    let seccomp_profile = SeccompProfile::from_kube(kube_seccomp_profile.clone());
+
+    assert_eq!(seccomp_profile.into_kube(), kube_seccomp_profile);
+}
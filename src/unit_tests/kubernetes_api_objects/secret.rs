@@ -3,6 +3,7 @@
 use crate::kubernetes_api_objects::exec::object_meta::*;
 use crate::kubernetes_api_objects::exec::resource::*;
 use crate::kubernetes_api_objects::exec::secret::*;
+use crate::vstd_ext::byte_string_map::*;
 use crate::vstd_ext::string_map::*;
 use vstd::prelude::*;
 use vstd::string::*;
@@ -59,7 +60,61 @@ pub fn test_data() {
     let mut data = StringMap::new();
     data.insert("key".to_string(), "value".to_string());
     secret.set_data(data.clone());
-    assert_eq!(data.into_rust_map(), secret.data().unwrap().into_rust_map());
+    assert_eq!(
+        ByteStringMap::from_string_map(data).into_rust_map(),
+        secret.data().unwrap().into_rust_map()
+    );
+}
+
+#[test]
+pub fn test_set_binary_data() {
+    let mut secret = Secret::default();
+    let mut data = ByteStringMap::new();
+    data.insert("key".to_string(), vec![0, 159, 146, 150]);
+    secret.set_binary_data(data.clone());
+    assert_eq!(
+        std::collections::BTreeMap::from_iter(vec![(
+            "key".to_string(),
+            deps_hack::k8s_openapi::ByteString(vec![0, 159, 146, 150]),
+        )]),
+        secret.into_kube().data.unwrap()
+    );
+}
+
+#[test]
+pub fn test_string_data() {
+    let mut secret = Secret::default();
+    let temp = secret.string_data();
+    if !temp.is_none() {
+        panic!("string_data should be None");
+    }
+    let mut string_data = StringMap::new();
+    string_data.insert("key".to_string(), "value".to_string());
+    secret.set_string_data(string_data.clone());
+    assert_eq!(string_data.clone().into_rust_map(), secret.string_data().unwrap().into_rust_map());
+    assert_eq!(string_data.into_rust_map(), secret.into_kube().string_data.unwrap());
+}
+
+#[test]
+pub fn test_unset_string_data() {
+    let mut secret = Secret::default();
+    let mut string_data = StringMap::new();
+    string_data.insert("key".to_string(), "value".to_string());
+    secret.set_string_data(string_data);
+    secret.unset_string_data();
+    assert!(secret.string_data().is_none());
+    assert!(secret.into_kube().string_data.is_none());
+}
+
+#[test]
+pub fn test_marshal_binary_data() {
+    let mut secret = Secret::default();
+    let mut data = ByteStringMap::new();
+    data.insert("key".to_string(), vec![0, 159, 146, 150]);
+    secret.set_binary_data(data);
+    let obj = secret.clone().marshal();
+    assert_eq!(obj.as_kube_ref().data["data"]["key"], deps_hack::serde_json::json!("AJ+Slg=="));
+    assert_eq!(secret.into_kube(), Secret::unmarshal(obj).unwrap().into_kube());
 }
 
 #[test]
@@ -8,6 +8,105 @@ use crate::vstd_ext::string_map::*;
 use vstd::prelude::*;
 use vstd::string::*;
 
+#[test]
+pub fn test_default() {
+    let security_context = SecurityContext::default();
+    assert_eq!(
+        security_context.into_kube(),
+        deps_hack::k8s_openapi::api::core::v1::SecurityContext::default()
+    );
+}
+
+#[test]
+pub fn test_set_allow_privilege_escalation() {
+    let mut security_context = SecurityContext::default();
+    security_context.set_allow_privilege_escalation(false);
+    assert_eq!(
+        false,
+        security_context
+            .into_kube()
+            .allow_privilege_escalation
+            .unwrap()
+    );
+}
+
+#[test]
+pub fn test_set_capabilities() {
+    let mut security_context = SecurityContext::default();
+    let mut capabilities = Capabilities::default();
+    capabilities.set_drop(vec!["ALL".to_string()]);
+    security_context.set_capabilities(capabilities.clone());
+    assert_eq!(
+        capabilities.into_kube(),
+        security_context.into_kube().capabilities.unwrap()
+    );
+}
+
+#[test]
+pub fn test_set_privileged() {
+    let mut security_context = SecurityContext::default();
+    security_context.set_privileged(true);
+    assert_eq!(true, security_context.into_kube().privileged.unwrap());
+}
+
+#[test]
+pub fn test_set_read_only_root_filesystem() {
+    let mut security_context = SecurityContext::default();
+    security_context.set_read_only_root_filesystem(true);
+    assert_eq!(
+        true,
+        security_context
+            .into_kube()
+            .read_only_root_filesystem
+            .unwrap()
+    );
+}
+
+#[test]
+pub fn test_set_run_as_group() {
+    let mut security_context = SecurityContext::default();
+    security_context.set_run_as_group(1000);
+    assert_eq!(1000, security_context.into_kube().run_as_group.unwrap());
+}
+
+#[test]
+pub fn test_set_run_as_non_root() {
+    let mut security_context = SecurityContext::default();
+    security_context.set_run_as_non_root(true);
+    assert_eq!(true, security_context.into_kube().run_as_non_root.unwrap());
+}
+
+#[test]
+pub fn test_set_run_as_user() {
+    let mut security_context = SecurityContext::default();
+    security_context.set_run_as_user(1000);
+    assert_eq!(1000, security_context.into_kube().run_as_user.unwrap());
+}
+
+#[test]
+pub fn test_set_seccomp_profile() {
+    let mut security_context = SecurityContext::default();
+    let mut seccomp_profile = SeccompProfile::default();
+    seccomp_profile.set_type("RuntimeDefault".to_string());
+    security_context.set_seccomp_profile(seccomp_profile.clone());
+    assert_eq!(
+        seccomp_profile.into_kube(),
+        security_context.into_kube().seccomp_profile.unwrap()
+    );
+}
+
+#[test]
+pub fn test_clone() {
+    let mut security_context = SecurityContext::default();
+    security_context.set_run_as_user(1000);
+    security_context.set_allow_privilege_escalation(false);
+    let security_context_clone = security_context.clone();
+    assert_eq!(
+        security_context.into_kube(),
+        security_context_clone.into_kube()
+    );
+}
+
 #[test]
 pub fn test_kube() {
     let kube_security_context = deps_hack::k8s_openapi::api::core::v1::SecurityContext {
@@ -0,0 +1,173 @@
+// Copyright 2022 VMware, Inc.
+// SPDX-License-Identifier: MIT
+use crate::kubernetes_api_objects::exec::object_meta::*;
+use crate::kubernetes_api_objects::exec::resource::*;
+use crate::kubernetes_api_objects::exec::storage_class::*;
+use crate::vstd_ext::string_map::*;
+use std::collections::BTreeMap;
+use vstd::prelude::*;
+use vstd::string::*;
+
+#[test]
+pub fn test_default() {
+    let storage_class = StorageClass::default();
+    assert_eq!(
+        storage_class.into_kube(),
+        deps_hack::k8s_openapi::api::storage::v1::StorageClass::default()
+    );
+}
+
+#[test]
+pub fn test_set_metadata() {
+    let mut storage_class = StorageClass::default();
+    let mut object_meta = ObjectMeta::default();
+    object_meta.set_name("name".to_string());
+    storage_class.set_metadata(object_meta.clone());
+    assert_eq!(object_meta.into_kube(), storage_class.into_kube().metadata);
+}
+
+#[test]
+pub fn test_metadata() {
+    let mut storage_class = StorageClass::default();
+    let mut object_meta = ObjectMeta::default();
+    object_meta.set_name("name".to_string());
+    storage_class.set_metadata(object_meta.clone());
+    assert_eq!(
+        object_meta.into_kube(),
+        storage_class.metadata().into_kube()
+    );
+}
+
+#[test]
+pub fn test_set_provisioner() {
+    let mut storage_class = StorageClass::default();
+    storage_class.set_provisioner("kubernetes.io/no-provisioner".to_string());
+    assert_eq!(
+        "kubernetes.io/no-provisioner".to_string(),
+        storage_class.into_kube().provisioner
+    );
+}
+
+#[test]
+pub fn test_provisioner() {
+    let mut storage_class = StorageClass::default();
+    storage_class.set_provisioner("kubernetes.io/no-provisioner".to_string());
+    assert_eq!(
+        "kubernetes.io/no-provisioner".to_string(),
+        storage_class.provisioner()
+    );
+}
+
+#[test]
+pub fn test_set_parameters() {
+    let mut storage_class = StorageClass::default();
+    let mut parameters = StringMap::new();
+    parameters.insert("type".to_string(), "gp3".to_string());
+    storage_class.set_parameters(parameters.clone());
+    assert_eq!(
+        parameters.into_rust_map(),
+        storage_class.into_kube().parameters.unwrap()
+    );
+}
+
+#[test]
+pub fn test_parameters() {
+    let mut storage_class = StorageClass::default();
+    assert_eq!(storage_class.parameters().is_none(), true);
+    let mut parameters = StringMap::new();
+    parameters.insert("type".to_string(), "gp3".to_string());
+    storage_class.set_parameters(parameters.clone());
+    assert_eq!(
+        parameters.into_rust_map(),
+        storage_class.parameters().unwrap().into_rust_map()
+    );
+}
+
+#[test]
+pub fn test_set_reclaim_policy() {
+    let mut storage_class = StorageClass::default();
+    storage_class.set_reclaim_policy("Retain".to_string());
+    assert_eq!(
+        "Retain".to_string(),
+        storage_class.into_kube().reclaim_policy.unwrap()
+    );
+}
+
+#[test]
+pub fn test_set_volume_binding_mode() {
+    let mut storage_class = StorageClass::default();
+    storage_class.set_volume_binding_mode("WaitForFirstConsumer".to_string());
+    assert_eq!(
+        "WaitForFirstConsumer".to_string(),
+        storage_class.into_kube().volume_binding_mode.unwrap()
+    );
+}
+
+#[test]
+pub fn test_set_allow_volume_expansion() {
+    let mut storage_class = StorageClass::default();
+    storage_class.set_allow_volume_expansion(true);
+    assert_eq!(
+        true,
+        storage_class.into_kube().allow_volume_expansion.unwrap()
+    );
+}
+
+#[test]
+pub fn test_clone() {
+    let mut storage_class = StorageClass::default();
+    storage_class.set_provisioner("kubernetes.io/no-provisioner".to_string());
+    let storage_class_clone = storage_class.clone();
+    assert_eq!(storage_class.into_kube(), storage_class_clone.into_kube());
+}
+
+#[test]
+pub fn test_api_resource() {
+    let api_resource = StorageClass::api_resource();
+    assert_eq!(api_resource.into_kube().kind, "StorageClass");
+}
+
+#[test]
+pub fn test_kube() {
+    let kube_storage_class = deps_hack::k8s_openapi::api::storage::v1::StorageClass {
+        metadata: deps_hack::k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta {
+            name: Some("name".to_string()),
+            ..Default::default()
+        },
+        provisioner: "kubernetes.io/no-provisioner".to_string(),
+        parameters: Some(BTreeMap::from([("type".to_string(), "gp3".to_string())])),
+        reclaim_policy: Some("Retain".to_string()),
+        volume_binding_mode: Some("WaitForFirstConsumer".to_string()),
+        allow_volume_expansion: Some(true),
+        ..Default::default()
+    };
+
+    let storage_class = StorageClass::from_kube(kube_storage_class.clone());
+
+    assert_eq!(storage_class.into_kube(), kube_storage_class);
+}
+
+#[test]
+pub fn test_marshal() {
+    let kube_storage_class = deps_hack::k8s_openapi::api::storage::v1::StorageClass {
+        metadata: deps_hack::k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta {
+            name: Some("name".to_string()),
+            ..Default::default()
+        },
+        provisioner: "kubernetes.io/no-provisioner".to_string(),
+        parameters: Some(BTreeMap::from([("type".to_string(), "gp3".to_string())])),
+        reclaim_policy: Some("Retain".to_string()),
+        volume_binding_mode: Some("WaitForFirstConsumer".to_string()),
+        allow_volume_expansion: Some(true),
+        ..Default::default()
+    };
+
+    let storage_class = StorageClass::from_kube(kube_storage_class.clone());
+
+    assert_eq!(
+        kube_storage_class,
+        StorageClass::unmarshal(storage_class.marshal())
+            .unwrap()
+            .into_kube()
+    );
+}
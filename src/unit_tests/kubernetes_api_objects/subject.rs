@@ -40,6 +40,49 @@ pub fn test_set_namespace() {
     );
 }
 
+#[test]
+pub fn test_set_api_group() {
+    let mut subject = Subject::default();
+    subject.set_api_group("rbac.authorization.k8s.io".to_string());
+    assert_eq!(
+        "rbac.authorization.k8s.io".to_string(),
+        subject.into_kube().api_group.unwrap()
+    );
+}
+
+#[test]
+pub fn test_api_group() {
+    let mut subject = Subject::default();
+    assert_eq!(subject.api_group().is_none(), true);
+    subject.set_api_group("rbac.authorization.k8s.io".to_string());
+    assert_eq!(
+        "rbac.authorization.k8s.io".to_string(),
+        subject.api_group().unwrap()
+    );
+}
+
+#[test]
+pub fn test_kind() {
+    let mut subject = Subject::default();
+    subject.set_kind("User".to_string());
+    assert_eq!("User".to_string(), subject.kind());
+}
+
+#[test]
+pub fn test_name() {
+    let mut subject = Subject::default();
+    subject.set_name("name".to_string());
+    assert_eq!("name".to_string(), subject.name());
+}
+
+#[test]
+pub fn test_namespace() {
+    let mut subject = Subject::default();
+    assert_eq!(subject.namespace().is_none(), true);
+    subject.set_namespace("namespace".to_string());
+    assert_eq!("namespace".to_string(), subject.namespace().unwrap());
+}
+
 #[test]
 pub fn test_kube() {
     let kube_subject = deps_hack::k8s_openapi::api::rbac::v1::Subject {
@@ -0,0 +1,92 @@
+// Copyright 2022 VMware, Inc.
+// SPDX-License-Identifier: MIT
+use crate::kubernetes_api_objects::exec::label_selector::*;
+use crate::kubernetes_api_objects::exec::resource::*;
+use crate::kubernetes_api_objects::exec::topology_spread_constraint::*;
+use crate::vstd_ext::string_map::*;
+use vstd::prelude::*;
+use vstd::string::*;
+
+#[test]
+pub fn test_default() {
+    let topology_spread_constraint = TopologySpreadConstraint::default();
+    assert_eq!(
+        topology_spread_constraint.into_kube(),
+        deps_hack::k8s_openapi::api::core::v1::TopologySpreadConstraint::default()
+    );
+}
+
+#[test]
+pub fn test_set_max_skew() {
+    let mut topology_spread_constraint = TopologySpreadConstraint::default();
+    topology_spread_constraint.set_max_skew(1);
+    assert_eq!(1, topology_spread_constraint.into_kube().max_skew);
+}
+
+#[test]
+pub fn test_set_topology_key() {
+    let mut topology_spread_constraint = TopologySpreadConstraint::default();
+    topology_spread_constraint.set_topology_key("topology.kubernetes.io/zone".to_string());
+    assert_eq!(
+        "topology.kubernetes.io/zone".to_string(),
+        topology_spread_constraint.into_kube().topology_key
+    );
+}
+
+#[test]
+pub fn test_set_when_unsatisfiable() {
+    let mut topology_spread_constraint = TopologySpreadConstraint::default();
+    topology_spread_constraint.set_when_unsatisfiable("DoNotSchedule".to_string());
+    assert_eq!(
+        "DoNotSchedule".to_string(),
+        topology_spread_constraint.into_kube().when_unsatisfiable
+    );
+}
+
+#[test]
+pub fn test_set_label_selector() {
+    let mut topology_spread_constraint = TopologySpreadConstraint::default();
+    let mut label_selector = LabelSelector::default();
+    let mut match_labels = StringMap::new();
+    match_labels.insert("app".to_string(), "zookeeper".to_string());
+    label_selector.set_match_labels(match_labels);
+    topology_spread_constraint.set_label_selector(label_selector.clone());
+    assert_eq!(
+        label_selector.into_kube(),
+        topology_spread_constraint
+            .into_kube()
+            .label_selector
+            .unwrap()
+    );
+}
+
+#[test]
+pub fn test_clone() {
+    let mut topology_spread_constraint = TopologySpreadConstraint::default();
+    topology_spread_constraint.set_max_skew(1);
+    topology_spread_constraint.set_topology_key("kubernetes.io/hostname".to_string());
+    let topology_spread_constraint_clone = topology_spread_constraint.clone();
+    assert_eq!(
+        topology_spread_constraint.into_kube(),
+        topology_spread_constraint_clone.into_kube()
+    );
+}
+
+#[test]
+pub fn test_kube() {
+    let kube_topology_spread_constraint =
+        deps_hack::k8s_openapi::api::core::v1::TopologySpreadConstraint {
+            max_skew: 1,
+            topology_key: "topology.kubernetes.io/zone".to_string(),
+            when_unsatisfiable: "ScheduleAnyway".to_string(),
+            ..Default::default()
+        };
+
+    let topology_spread_constraint =
+        TopologySpreadConstraint::from_kube(kube_topology_spread_constraint.clone());
+
+    assert_eq!(
+        topology_spread_constraint.into_kube(),
+        kube_topology_spread_constraint
+    );
+}
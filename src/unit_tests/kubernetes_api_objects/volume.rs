@@ -41,6 +41,18 @@ pub fn test_set_host_path() {
     );
 }
 
+#[test]
+pub fn test_set_persistent_volume_claim() {
+    let mut volume = Volume::default();
+    let mut persistent_volume_claim_volume_source = PersistentVolumeClaimVolumeSource::default();
+    persistent_volume_claim_volume_source.set_claim_name("claim".to_string());
+    volume.set_persistent_volume_claim(persistent_volume_claim_volume_source.clone());
+    assert_eq!(
+        persistent_volume_claim_volume_source.into_kube(),
+        volume.into_kube().persistent_volume_claim.unwrap()
+    );
+}
+
 #[test]
 pub fn test_set_config_map() {
     let mut volume = Volume::default();
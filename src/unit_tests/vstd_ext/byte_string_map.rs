@@ -0,0 +1,33 @@
+// Copyright 2022 VMware, Inc.
+// SPDX-License-Identifier: MIT
+use crate::vstd_ext::byte_string_map::*;
+use crate::vstd_ext::string_map::*;
+use vstd::prelude::*;
+use vstd::string::*;
+
+#[test]
+pub fn test_from_string_map() {
+    let mut m = StringMap::empty();
+    m.insert("key".to_string(), "val".to_string());
+
+    let rust_map = ByteStringMap::from_string_map(m).into_rust_map();
+    assert_eq!(rust_map.get(&"key".to_string()), Some(&b"val".to_vec()));
+}
+
+#[test]
+pub fn test_extend() {
+    let mut m = ByteStringMap::empty();
+    m.insert("key1".to_string(), vec![1, 2]);
+    m.insert("key2".to_string(), vec![3]);
+
+    let mut m2 = ByteStringMap::empty();
+    m2.insert("key1".to_string(), vec![0, 255]);
+    m2.insert("key3".to_string(), vec![]);
+
+    m.extend(m2);
+
+    let rust_map = m.into_rust_map();
+    assert_eq!(rust_map.get(&"key1".to_string()), Some(&vec![0, 255]));
+    assert_eq!(rust_map.get(&"key2".to_string()), Some(&vec![3]));
+    assert_eq!(rust_map.get(&"key3".to_string()), Some(&vec![]));
+}
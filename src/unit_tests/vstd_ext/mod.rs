@@ -1,3 +1,4 @@
 // Copyright 2022 VMware, Inc.
 // SPDX-License-Identifier: MIT
+pub mod byte_string_map;
 pub mod string_map;
@@ -0,0 +1,10 @@
+// Copyright 2022 VMware, Inc.
+// SPDX-License-Identifier: MIT
+// The rabbitmq topology controller manages the users, vhosts and permissions of a rabbitmq cluster
+// through its management API. It is not a verified controller: it has no liveness theorem or proof/ folder,
+// so ESR is not proved, and Verus only checks that each exec reconciler conforms to its model.
+// It lives outside controller_examples/ until its ESR is proved.
+pub mod permission;
+pub mod trusted;
+pub mod user;
+pub mod vhost;
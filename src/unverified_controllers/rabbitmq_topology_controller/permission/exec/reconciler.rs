@@ -0,0 +1,180 @@
+// Copyright 2022 VMware, Inc.
+// SPDX-License-Identifier: MIT
+#![allow(unused_imports)]
+use crate::external_api::exec::*;
+use crate::kubernetes_api_objects::exec::prelude::*;
+use crate::kubernetes_api_objects::exec::{condition::*, resource::ResourceWrapper};
+use crate::rabbitmq_topology_controller::permission::model::reconciler as model_reconciler;
+use crate::rabbitmq_topology_controller::permission::trusted::{exec_types::*, spec_types, step::*};
+use crate::rabbitmq_topology_controller::trusted::rabbitmq_topology_api_exec::*;
+use crate::reconciler::exec::{io::*, reconciler::*};
+use crate::vstd_ext::string_view::*;
+use vstd::{prelude::*, string::*};
+
+verus! {
+
+pub struct RabbitmqPermissionReconciler {}
+
+impl Reconciler for RabbitmqPermissionReconciler {
+    type R = RabbitmqPermission;
+    type T = RabbitmqPermissionReconcileState;
+    type ExternalAPIType = RabbitmqTopologyAPIShimLayer;
+
+    open spec fn well_formed(permission: &RabbitmqPermission) -> bool { permission@.well_formed() }
+
+    fn reconcile_init_state() -> RabbitmqPermissionReconcileState {
+        reconcile_init_state()
+    }
+
+    fn reconcile_core(permission: &RabbitmqPermission, resp_o: Option<Response<RabbitmqTopologyAPIOutput>>, state: RabbitmqPermissionReconcileState)
+    -> (RabbitmqPermissionReconcileState, Option<Request<RabbitmqTopologyAPIInput>>) {
+        reconcile_core(permission, resp_o, state)
+    }
+
+    fn reconcile_done(state: &RabbitmqPermissionReconcileState) -> bool {
+        reconcile_done(state)
+    }
+
+    fn reconcile_error(state: &RabbitmqPermissionReconcileState) -> bool {
+        reconcile_error(state)
+    }
+}
+
+pub fn reconcile_init_state() -> (state: RabbitmqPermissionReconcileState)
+    ensures state@ == model_reconciler::reconcile_init_state(),
+{
+    RabbitmqPermissionReconcileState { reconcile_step: RabbitmqPermissionReconcileStep::Init }
+}
+
+pub fn reconcile_done(state: &RabbitmqPermissionReconcileState) -> (res: bool)
+    ensures res == model_reconciler::reconcile_done(state@),
+{
+    match state.reconcile_step {
+        RabbitmqPermissionReconcileStep::Done => true,
+        _ => false,
+    }
+}
+
+pub fn reconcile_error(state: &RabbitmqPermissionReconcileState) -> (res: bool)
+    ensures res == model_reconciler::reconcile_error(state@),
+{
+    match state.reconcile_step {
+        RabbitmqPermissionReconcileStep::Error => true,
+        _ => false,
+    }
+}
+
+pub fn reconcile_core(permission: &RabbitmqPermission, resp_o: Option<Response<RabbitmqTopologyAPIOutput>>, state: RabbitmqPermissionReconcileState) -> (res: (RabbitmqPermissionReconcileState, Option<Request<RabbitmqTopologyAPIInput>>))
+    requires permission@.well_formed(),
+    ensures (res.0@, opt_request_to_view(&res.1)) == model_reconciler::reconcile_core(permission@, opt_response_to_view(&resp_o), state@),
+{
+    let step = state.reconcile_step;
+    match step {
+        RabbitmqPermissionReconcileStep::Init => {
+            let ext_req = RabbitmqTopologyAPIInput::PutPermissionRequest(
+                permission.spec().rabbitmq_cluster_reference().name(), permission.metadata().namespace().unwrap(),
+                permission.spec().user(), permission.spec().vhost(), permission.spec().permissions()
+            );
+            let state_prime = RabbitmqPermissionReconcileState {
+                reconcile_step: RabbitmqPermissionReconcileStep::AfterPutPermission,
+                ..state
+            };
+            return (state_prime, Some(Request::ExternalRequest(ext_req)));
+        },
+        RabbitmqPermissionReconcileStep::AfterPutPermission => {
+            if resp_o.is_some() && resp_o.as_ref().unwrap().is_external_response()
+            && resp_o.as_ref().unwrap().as_external_response_ref().is_put_permission_response() {
+                let put_permission_resp = resp_o.unwrap().into_external_response().unwrap_put_permission_response().res;
+                if put_permission_resp.is_ok() {
+                    let req_o = KubeAPIRequest::UpdateStatusRequest(KubeUpdateStatusRequest {
+                        api_resource: RabbitmqPermission::api_resource(),
+                        name: permission.metadata().name().unwrap(),
+                        namespace: permission.metadata().namespace().unwrap(),
+                        obj: update_permission_status(permission).marshal(),
+                    });
+                    let state_prime = RabbitmqPermissionReconcileState {
+                        reconcile_step: RabbitmqPermissionReconcileStep::AfterUpdateStatus,
+                        ..state
+                    };
+                    return (state_prime, Some(Request::KRequest(req_o)));
+                }
+            }
+            return report_error(permission, state);
+        },
+        RabbitmqPermissionReconcileStep::AfterUpdateStatus => {
+            if resp_o.is_some() && resp_o.as_ref().unwrap().is_k_response()
+            && resp_o.as_ref().unwrap().as_k_response_ref().is_update_status_response() {
+                let update_status_resp = resp_o.unwrap().into_k_response().into_update_status_response().res;
+                if update_status_resp.is_ok() {
+                    let state_prime = RabbitmqPermissionReconcileState {
+                        reconcile_step: RabbitmqPermissionReconcileStep::Done,
+                        ..state
+                    };
+                    return (state_prime, None);
+                }
+            }
+            let state_prime = RabbitmqPermissionReconcileState {
+                reconcile_step: RabbitmqPermissionReconcileStep::Error,
+                ..state
+            };
+            return (state_prime, None);
+        },
+        RabbitmqPermissionReconcileStep::AfterUpdateDegradedStatus => {
+            let state_prime = RabbitmqPermissionReconcileState {
+                reconcile_step: RabbitmqPermissionReconcileStep::Error,
+                ..state
+            };
+            return (state_prime, None);
+        },
+        _ => {
+            let state_prime = RabbitmqPermissionReconcileState {
+                reconcile_step: step,
+                ..state
+            };
+            return (state_prime, None);
+        }
+    }
+}
+
+pub fn update_permission_status(permission: &RabbitmqPermission) -> (updated_permission: RabbitmqPermission)
+    ensures updated_permission@ == model_reconciler::update_permission_status(permission@),
+{
+    let mut updated_permission = permission.clone();
+    updated_permission.set_status({
+        let mut status = RabbitmqPermissionStatus::default();
+        status.set_conditions(make_conditions(true, false, permission.metadata().generation()));
+        status
+    });
+    updated_permission
+}
+
+pub fn update_permission_status_to_degraded(permission: &RabbitmqPermission) -> (updated_permission: RabbitmqPermission)
+    ensures updated_permission@ == model_reconciler::update_permission_status_to_degraded(permission@),
+{
+    let mut updated_permission = permission.clone();
+    updated_permission.set_status({
+        let mut status = RabbitmqPermissionStatus::default();
+        status.set_conditions(make_conditions(false, true, permission.metadata().generation()));
+        status
+    });
+    updated_permission
+}
+
+pub fn report_error(permission: &RabbitmqPermission, state: RabbitmqPermissionReconcileState) -> (res: (RabbitmqPermissionReconcileState, Option<Request<RabbitmqTopologyAPIInput>>))
+    requires permission@.well_formed(),
+    ensures (res.0@, opt_request_to_view(&res.1)) == model_reconciler::report_error(permission@, state@),
+{
+    let req_o = KubeAPIRequest::UpdateStatusRequest(KubeUpdateStatusRequest {
+        api_resource: RabbitmqPermission::api_resource(),
+        name: permission.metadata().name().unwrap(),
+        namespace: permission.metadata().namespace().unwrap(),
+        obj: update_permission_status_to_degraded(permission).marshal(),
+    });
+    let state_prime = RabbitmqPermissionReconcileState {
+        reconcile_step: RabbitmqPermissionReconcileStep::AfterUpdateDegradedStatus,
+        ..state
+    };
+    (state_prime, Some(Request::KRequest(req_o)))
+}
+
+}
@@ -0,0 +1,150 @@
+// Copyright 2022 VMware, Inc.
+// SPDX-License-Identifier: MIT
+#![allow(unused_imports)]
+use crate::external_api::spec::*;
+use crate::kubernetes_api_objects::spec::{condition::*, prelude::*};
+use crate::rabbitmq_topology_controller::permission::trusted::{spec_types::*, step::*};
+use crate::rabbitmq_topology_controller::trusted::rabbitmq_topology_api_spec::*;
+use crate::reconciler::spec::{io::*, reconciler::*};
+use crate::vstd_ext::string_view::*;
+use vstd::{prelude::*, string::*};
+
+verus! {
+
+impl Reconciler<RabbitmqPermissionView, RabbitmqTopologyAPI> for RabbitmqPermissionReconciler {
+    type T = RabbitmqPermissionReconcileState;
+
+    open spec fn reconcile_init_state() -> RabbitmqPermissionReconcileState {
+        reconcile_init_state()
+    }
+
+    open spec fn reconcile_core(permission: RabbitmqPermissionView, resp_o: Option<ResponseView<RabbitmqTopologyAPIOutputView>>, state: RabbitmqPermissionReconcileState)
+    -> (RabbitmqPermissionReconcileState, Option<RequestView<RabbitmqTopologyAPIInputView>>) {
+        reconcile_core(permission, resp_o, state)
+    }
+
+    open spec fn reconcile_done(state: RabbitmqPermissionReconcileState) -> bool {
+        reconcile_done(state)
+    }
+
+    open spec fn reconcile_error(state: RabbitmqPermissionReconcileState) -> bool {
+        reconcile_error(state)
+    }
+
+    open spec fn expect_from_user(obj: DynamicObjectView) -> bool {
+        false /* Don't expect anything from the user except the cr object */
+    }
+}
+
+pub open spec fn reconcile_init_state() -> RabbitmqPermissionReconcileState { RabbitmqPermissionReconcileState { reconcile_step: RabbitmqPermissionReconcileStep::Init } }
+
+pub open spec fn reconcile_done(state: RabbitmqPermissionReconcileState) -> bool {
+    match state.reconcile_step {
+        RabbitmqPermissionReconcileStep::Done => true,
+        _ => false,
+    }
+}
+
+pub open spec fn reconcile_error(state: RabbitmqPermissionReconcileState) -> bool {
+    match state.reconcile_step {
+        RabbitmqPermissionReconcileStep::Error => true,
+        _ => false,
+    }
+}
+
+pub open spec fn reconcile_core(
+    permission: RabbitmqPermissionView, resp_o: Option<ResponseView<RabbitmqTopologyAPIOutputView>>, state: RabbitmqPermissionReconcileState
+) -> (RabbitmqPermissionReconcileState, Option<RequestView<RabbitmqTopologyAPIInputView>>) {
+    let step = state.reconcile_step;
+    let resp = resp_o.get_Some_0();
+    match step {
+        RabbitmqPermissionReconcileStep::Init => {
+            let state_prime = RabbitmqPermissionReconcileState {
+                reconcile_step: RabbitmqPermissionReconcileStep::AfterPutPermission,
+                ..state
+            };
+            (state_prime, Some(RequestView::ExternalRequest(put_permission_request(permission))))
+        },
+        RabbitmqPermissionReconcileStep::AfterPutPermission => {
+            if resp_o.is_Some() && resp.is_ExternalResponse() && resp.get_ExternalResponse_0().is_PutPermissionResponse()
+            && resp.get_ExternalResponse_0().get_PutPermissionResponse_0().res.is_Ok() {
+                let req_o = APIRequest::UpdateStatusRequest(UpdateStatusRequest {
+                    namespace: permission.metadata.namespace.get_Some_0(),
+                    name: permission.metadata.name.get_Some_0(),
+                    obj: update_permission_status(permission).marshal(),
+                });
+                let state_prime = RabbitmqPermissionReconcileState {
+                    reconcile_step: RabbitmqPermissionReconcileStep::AfterUpdateStatus,
+                    ..state
+                };
+                (state_prime, Some(RequestView::KRequest(req_o)))
+            } else {
+                report_error(permission, state)
+            }
+        },
+        RabbitmqPermissionReconcileStep::AfterUpdateStatus => {
+            let update_status_resp = resp.get_KResponse_0().get_UpdateStatusResponse_0().res;
+            if resp_o.is_Some() && resp.is_KResponse() && resp.get_KResponse_0().is_UpdateStatusResponse()
+            && update_status_resp.is_Ok() {
+                let state_prime = RabbitmqPermissionReconcileState {
+                    reconcile_step: RabbitmqPermissionReconcileStep::Done,
+                    ..state
+                };
+                (state_prime, None)
+            } else {
+                let state_prime = RabbitmqPermissionReconcileState {
+                    reconcile_step: RabbitmqPermissionReconcileStep::Error,
+                    ..state
+                };
+                (state_prime, None)
+            }
+        },
+        RabbitmqPermissionReconcileStep::AfterUpdateDegradedStatus => {
+            let state_prime = RabbitmqPermissionReconcileState {
+                reconcile_step: RabbitmqPermissionReconcileStep::Error,
+                ..state
+            };
+            (state_prime, None)
+        },
+        _ => {
+            let state_prime = RabbitmqPermissionReconcileState {
+                reconcile_step: step,
+                ..state
+            };
+            (state_prime, None)
+        }
+    }
+}
+
+pub open spec fn put_permission_request(permission: RabbitmqPermissionView) -> RabbitmqTopologyAPIInputView {
+    RabbitmqTopologyAPIInputView::PutPermissionRequest(
+        permission.spec.rabbitmq_cluster_reference.name, permission.metadata.namespace.get_Some_0(),
+        permission.spec.user, permission.spec.vhost, permission.spec.permissions
+    )
+}
+
+// The permission is ready as soon as the broker grants it,
+// which fails until both the user and the vhost exist in the broker.
+pub open spec fn update_permission_status(permission: RabbitmqPermissionView) -> RabbitmqPermissionView {
+    permission.set_status(RabbitmqPermissionStatusView::default().set_conditions(make_conditions(true, false, permission.metadata.generation)))
+}
+
+pub open spec fn update_permission_status_to_degraded(permission: RabbitmqPermissionView) -> RabbitmqPermissionView {
+    permission.set_status(RabbitmqPermissionStatusView::default().set_conditions(make_conditions(false, true, permission.metadata.generation)))
+}
+
+// report_error marks the permission as degraded before the reconcile ends in error.
+pub open spec fn report_error(permission: RabbitmqPermissionView, state: RabbitmqPermissionReconcileState) -> (RabbitmqPermissionReconcileState, Option<RequestView<RabbitmqTopologyAPIInputView>>) {
+    let req_o = APIRequest::UpdateStatusRequest(UpdateStatusRequest {
+        namespace: permission.metadata.namespace.get_Some_0(),
+        name: permission.metadata.name.get_Some_0(),
+        obj: update_permission_status_to_degraded(permission).marshal(),
+    });
+    let state_prime = RabbitmqPermissionReconcileState {
+        reconcile_step: RabbitmqPermissionReconcileStep::AfterUpdateDegradedStatus,
+        ..state
+    };
+    (state_prime, Some(RequestView::KRequest(req_o)))
+}
+
+}
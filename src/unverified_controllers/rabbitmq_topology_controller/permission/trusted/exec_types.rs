@@ -0,0 +1,194 @@
+// Copyright 2022 VMware, Inc.
+// SPDX-License-Identifier: MIT
+use crate::kubernetes_api_objects::error::UnmarshalError;
+use crate::kubernetes_api_objects::exec::{
+    api_resource::*, condition::*, dynamic::*, object_meta::*, resource::*,
+};
+use crate::kubernetes_api_objects::spec::resource::*;
+use crate::rabbitmq_topology_controller::permission::trusted::{
+    spec_types, spec_types::RabbitmqPermissionView, step::*,
+};
+use crate::rabbitmq_topology_controller::trusted::{
+    exec_types::RabbitmqClusterReference, rabbitmq_topology_api_exec::BrokerPermission,
+};
+use crate::vstd_ext::string_view::*;
+use vstd::prelude::*;
+
+verus! {
+
+pub struct RabbitmqPermissionReconcileState {
+    pub reconcile_step: RabbitmqPermissionReconcileStep,
+}
+
+impl std::clone::Clone for RabbitmqPermissionReconcileState {
+    #[verifier(external_body)]
+    fn clone(&self) -> (result: RabbitmqPermissionReconcileState)
+        ensures result == self
+    {
+        RabbitmqPermissionReconcileState {
+            reconcile_step: self.reconcile_step,
+        }
+    }
+}
+
+impl View for RabbitmqPermissionReconcileState {
+    type V = spec_types::RabbitmqPermissionReconcileState;
+    open spec fn view(&self) -> spec_types::RabbitmqPermissionReconcileState {
+        spec_types::RabbitmqPermissionReconcileState {
+            reconcile_step: self.reconcile_step,
+        }
+    }
+}
+
+#[verifier(external_body)]
+pub struct RabbitmqPermission {
+    inner: deps_hack::RabbitmqPermission
+}
+
+impl View for RabbitmqPermission {
+    type V = spec_types::RabbitmqPermissionView;
+
+    spec fn view(&self) -> spec_types::RabbitmqPermissionView;
+}
+
+impl RabbitmqPermission {
+    #[verifier(external_body)]
+    pub fn clone(&self) -> (permission: Self)
+        ensures permission@ == self@,
+    {
+        RabbitmqPermission { inner: self.inner.clone() }
+    }
+
+    #[verifier(external_body)]
+    pub fn metadata(&self) -> (metadata: ObjectMeta)
+        ensures metadata@ == self@.metadata,
+    {
+        ObjectMeta::from_kube(self.inner.metadata.clone())
+    }
+
+    #[verifier(external_body)]
+    pub fn spec(&self) -> (spec: RabbitmqPermissionSpec)
+        ensures spec@ == self@.spec,
+    {
+        RabbitmqPermissionSpec { inner: self.inner.spec.clone() }
+    }
+
+    #[verifier(external_body)]
+    pub fn set_status(&mut self, status: RabbitmqPermissionStatus)
+        ensures self@ == old(self)@.set_status(status@),
+    {
+        let mut new_status = status.into_kube();
+        match (&mut new_status.conditions, self.inner.status.as_ref().and_then(|s| s.conditions.as_ref())) {
+            (Some(conditions), Some(old_conditions)) => preserve_transition_times(conditions, old_conditions),
+            _ => {},
+        }
+        self.inner.status = Some(new_status);
+    }
+
+    #[verifier(external_body)]
+    pub fn api_resource() -> (res: ApiResource)
+        ensures res@.kind == RabbitmqPermissionView::kind(),
+    {
+        ApiResource::from_kube(deps_hack::kube::api::ApiResource::erase::<deps_hack::RabbitmqPermission>(&()))
+    }
+
+    // NOTE: This function assumes serde_json::to_string won't fail!
+    #[verifier(external_body)]
+    pub fn marshal(self) -> (obj: DynamicObject)
+        ensures obj@ == self@.marshal(),
+    {
+        // TODO: this might be unnecessarily slow
+        DynamicObject::from_kube(deps_hack::k8s_openapi::serde_json::from_str(&deps_hack::k8s_openapi::serde_json::to_string(&self.inner).unwrap()).unwrap())
+    }
+
+    #[verifier(external_body)]
+    pub fn unmarshal(obj: DynamicObject) -> (res: Result<RabbitmqPermission, UnmarshalError>)
+        ensures
+            res.is_Ok() == RabbitmqPermissionView::unmarshal(obj@).is_Ok(),
+            res.is_Ok() ==> res.get_Ok_0()@ == RabbitmqPermissionView::unmarshal(obj@).get_Ok_0(),
+    {
+        let parse_result = obj.into_kube().try_parse::<deps_hack::RabbitmqPermission>();
+        if parse_result.is_ok() {
+            let res = RabbitmqPermission { inner: parse_result.unwrap() };
+            Ok(res)
+        } else {
+            Err(())
+        }
+    }
+}
+
+#[verifier(external)]
+impl ResourceWrapper<deps_hack::RabbitmqPermission> for RabbitmqPermission {
+    fn from_kube(inner: deps_hack::RabbitmqPermission) -> RabbitmqPermission { RabbitmqPermission { inner: inner } }
+
+    fn into_kube(self) -> deps_hack::RabbitmqPermission { self.inner }
+}
+
+#[verifier(external_body)]
+pub struct RabbitmqPermissionSpec {
+    inner: deps_hack::RabbitmqPermissionSpec,
+}
+
+impl RabbitmqPermissionSpec {
+    pub spec fn view(&self) -> spec_types::RabbitmqPermissionSpecView;
+
+    #[verifier(external_body)]
+    pub fn user(&self) -> (user: String)
+        ensures user@ == self@.user,
+    {
+        self.inner.user.clone()
+    }
+
+    #[verifier(external_body)]
+    pub fn vhost(&self) -> (vhost: String)
+        ensures vhost@ == self@.vhost,
+    {
+        self.inner.vhost.clone()
+    }
+
+    #[verifier(external_body)]
+    pub fn permissions(&self) -> (permissions: BrokerPermission)
+        ensures permissions@ == self@.permissions,
+    {
+        BrokerPermission {
+            configure: self.inner.permissions.configure.clone(),
+            write: self.inner.permissions.write.clone(),
+            read: self.inner.permissions.read.clone(),
+        }
+    }
+
+    #[verifier(external_body)]
+    pub fn rabbitmq_cluster_reference(&self) -> (rabbitmq_cluster_reference: RabbitmqClusterReference)
+        ensures rabbitmq_cluster_reference@ == self@.rabbitmq_cluster_reference,
+    {
+        RabbitmqClusterReference::from_kube(self.inner.rabbitmq_cluster_reference.clone())
+    }
+}
+
+#[verifier(external_body)]
+pub struct RabbitmqPermissionStatus {
+    inner: deps_hack::RabbitmqPermissionStatus,
+}
+
+impl RabbitmqPermissionStatus {
+    pub spec fn view(&self) -> spec_types::RabbitmqPermissionStatusView;
+
+    #[verifier(external_body)]
+    pub fn default() -> (status: RabbitmqPermissionStatus)
+        ensures status@ == spec_types::RabbitmqPermissionStatusView::default(),
+    {
+        RabbitmqPermissionStatus { inner: deps_hack::RabbitmqPermissionStatus::default() }
+    }
+
+    #[verifier(external_body)]
+    pub fn set_conditions(&mut self, conditions: Vec<Condition>)
+        ensures self@ == old(self)@.set_conditions(conditions@.map_values(|c: Condition| c@)),
+    {
+        self.inner.conditions = Some(into_kube_conditions(conditions))
+    }
+
+    #[verifier(external)]
+    fn into_kube(self) -> deps_hack::RabbitmqPermissionStatus { self.inner }
+}
+
+}
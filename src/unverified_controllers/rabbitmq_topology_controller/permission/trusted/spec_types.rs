@@ -0,0 +1,179 @@
+// Copyright 2022 VMware, Inc.
+// SPDX-License-Identifier: MIT
+use crate::kubernetes_api_objects::error::UnmarshalError;
+use crate::kubernetes_api_objects::spec::{
+    common::*, condition::*, dynamic::*, object_meta::*, owner_reference::*, resource::*,
+};
+use crate::kubernetes_cluster::spec::{cluster::*, cluster_state_machine::*, message::*};
+use crate::rabbitmq_topology_controller::permission::trusted::step::*;
+use crate::rabbitmq_topology_controller::trusted::{
+    rabbitmq_topology_api_spec::*, spec_types::RabbitmqClusterReferenceView,
+};
+use crate::vstd_ext::string_view::*;
+use vstd::prelude::*;
+
+verus! {
+
+pub type PermissionStep = Step<PermissionMessage>;
+
+pub type PermissionCluster = Cluster<RabbitmqPermissionView, RabbitmqTopologyAPI, RabbitmqPermissionReconciler>;
+
+pub type PermissionMessage = Message<RabbitmqTopologyAPIInputView, RabbitmqTopologyAPIOutputView>;
+
+pub struct RabbitmqPermissionReconciler {}
+
+pub struct RabbitmqPermissionReconcileState {
+    pub reconcile_step: RabbitmqPermissionReconcileStep,
+}
+
+pub struct RabbitmqPermissionView {
+    pub metadata: ObjectMetaView,
+    pub spec: RabbitmqPermissionSpecView,
+    pub status: Option<RabbitmqPermissionStatusView>,
+}
+
+impl RabbitmqPermissionView {
+    pub open spec fn well_formed(self) -> bool {
+        &&& self.metadata.name.is_Some()
+        &&& self.metadata.namespace.is_Some()
+        &&& self.metadata.uid.is_Some()
+    }
+
+    pub open spec fn set_status(self, status: RabbitmqPermissionStatusView) -> RabbitmqPermissionView {
+        RabbitmqPermissionView {
+            status: Some(status),
+            ..self
+        }
+    }
+}
+
+impl ResourceView for RabbitmqPermissionView {
+    type Spec = RabbitmqPermissionSpecView;
+    type Status = Option<RabbitmqPermissionStatusView>;
+
+    open spec fn default() -> RabbitmqPermissionView {
+        RabbitmqPermissionView {
+            metadata: ObjectMetaView::default(),
+            spec: arbitrary(), // TODO: specify the default value for spec
+            status: None,
+        }
+    }
+
+    open spec fn metadata(self) -> ObjectMetaView { self.metadata }
+
+    open spec fn kind() -> Kind { Kind::CustomResourceKind("rabbitmqpermission"@) }
+
+    open spec fn object_ref(self) -> ObjectRef {
+        ObjectRef {
+            kind: Self::kind(),
+            name: self.metadata.name.get_Some_0(),
+            namespace: self.metadata.namespace.get_Some_0(),
+        }
+    }
+
+    proof fn object_ref_is_well_formed() {}
+
+    open spec fn spec(self) -> RabbitmqPermissionSpecView { self.spec }
+
+    open spec fn status(self) -> Option<RabbitmqPermissionStatusView> { self.status }
+
+    open spec fn marshal(self) -> DynamicObjectView {
+        DynamicObjectView {
+            kind: Self::kind(),
+            metadata: self.metadata,
+            spec: RabbitmqPermissionView::marshal_spec(self.spec),
+            status: RabbitmqPermissionView::marshal_status(self.status),
+        }
+    }
+
+    open spec fn unmarshal(obj: DynamicObjectView) -> Result<RabbitmqPermissionView, UnmarshalError> {
+        if obj.kind != Self::kind() {
+            Err(())
+        } else if !RabbitmqPermissionView::unmarshal_spec(obj.spec).is_Ok() {
+            Err(())
+        } else if !RabbitmqPermissionView::unmarshal_status(obj.status).is_Ok() {
+            Err(())
+        } else {
+            Ok(RabbitmqPermissionView {
+                metadata: obj.metadata,
+                spec: RabbitmqPermissionView::unmarshal_spec(obj.spec).get_Ok_0(),
+                status: RabbitmqPermissionView::unmarshal_status(obj.status).get_Ok_0(),
+            })
+        }
+    }
+
+    proof fn marshal_preserves_integrity() {
+        RabbitmqPermissionView::marshal_spec_preserves_integrity();
+        RabbitmqPermissionView::marshal_status_preserves_integrity();
+    }
+
+    proof fn marshal_preserves_metadata() {}
+
+    proof fn marshal_preserves_kind() {}
+
+    closed spec fn marshal_spec(s: RabbitmqPermissionSpecView) -> Value;
+
+    closed spec fn unmarshal_spec(v: Value) -> Result<RabbitmqPermissionSpecView, UnmarshalError>;
+
+    closed spec fn marshal_status(s: Option<RabbitmqPermissionStatusView>) -> Value;
+
+    closed spec fn unmarshal_status(v: Value) -> Result<Option<RabbitmqPermissionStatusView>, UnmarshalError>;
+
+    #[verifier(external_body)]
+    proof fn marshal_spec_preserves_integrity() {}
+
+    #[verifier(external_body)]
+    proof fn marshal_status_preserves_integrity() {}
+
+    proof fn unmarshal_result_determined_by_unmarshal_spec_and_status() {}
+
+    open spec fn state_validation(self) -> bool { true }
+
+    // The permission is never revoked in the broker, so granting it to another user or in another vhost
+    // would leave the old permission behind.
+    open spec fn transition_validation(self, old_obj: RabbitmqPermissionView) -> bool {
+        &&& self.spec.user == old_obj.spec.user
+        &&& self.spec.vhost == old_obj.spec.vhost
+        &&& self.spec.rabbitmq_cluster_reference == old_obj.spec.rabbitmq_cluster_reference
+    }
+}
+
+impl CustomResourceView for RabbitmqPermissionView {
+    proof fn kind_is_custom_resource() {}
+
+    open spec fn is_namespaced() -> bool { true }
+
+    open spec fn spec_status_validation(obj_spec: Self::Spec, obj_status: Self::Status) -> bool { true }
+
+    proof fn validation_result_determined_by_spec_and_status()
+        ensures forall |obj: Self| #[trigger] obj.state_validation() == Self::spec_status_validation(obj.spec(), obj.status())
+    {}
+}
+
+pub struct RabbitmqPermissionSpecView {
+    pub user: StringView,
+    pub vhost: StringView,
+    pub permissions: BrokerPermissionView,
+    pub rabbitmq_cluster_reference: RabbitmqClusterReferenceView,
+}
+
+pub struct RabbitmqPermissionStatusView {
+    pub conditions: Option<Seq<ConditionView>>,
+}
+
+impl RabbitmqPermissionStatusView {
+    pub open spec fn default() -> RabbitmqPermissionStatusView {
+        RabbitmqPermissionStatusView {
+            conditions: None,
+        }
+    }
+
+    pub open spec fn set_conditions(self, conditions: Seq<ConditionView>) -> RabbitmqPermissionStatusView {
+        RabbitmqPermissionStatusView {
+            conditions: Some(conditions),
+            ..self
+        }
+    }
+}
+
+}
@@ -0,0 +1,27 @@
+// Copyright 2022 VMware, Inc.
+// SPDX-License-Identifier: MIT
+#![allow(unused_imports)]
+use vstd::prelude::*;
+
+verus! {
+
+#[is_variant]
+pub enum RabbitmqPermissionReconcileStep {
+    Init,
+    AfterPutPermission,
+    AfterUpdateStatus,
+    AfterUpdateDegradedStatus,
+    Done,
+    Error,
+}
+
+impl std::marker::Copy for RabbitmqPermissionReconcileStep {}
+
+impl std::clone::Clone for RabbitmqPermissionReconcileStep {
+    #[verifier(external_body)]
+    fn clone(&self) -> (result: Self)
+        ensures result == self
+    { *self }
+}
+
+}
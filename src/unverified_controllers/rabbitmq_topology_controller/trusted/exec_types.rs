@@ -0,0 +1,27 @@
+// Copyright 2022 VMware, Inc.
+// SPDX-License-Identifier: MIT
+use crate::rabbitmq_topology_controller::trusted::spec_types;
+use vstd::prelude::*;
+
+verus! {
+
+#[verifier(external_body)]
+pub struct RabbitmqClusterReference {
+    inner: deps_hack::RabbitmqClusterReference,
+}
+
+impl RabbitmqClusterReference {
+    pub spec fn view(&self) -> spec_types::RabbitmqClusterReferenceView;
+
+    #[verifier(external_body)]
+    pub fn name(&self) -> (name: String)
+        ensures name@ == self@.name,
+    {
+        self.inner.name.clone()
+    }
+
+    #[verifier(external)]
+    pub fn from_kube(inner: deps_hack::RabbitmqClusterReference) -> RabbitmqClusterReference { RabbitmqClusterReference { inner: inner } }
+}
+
+}
@@ -0,0 +1,7 @@
+// Copyright 2022 VMware, Inc.
+// SPDX-License-Identifier: MIT
+pub mod exec_types;
+pub mod rabbitmq_topology_api_exec;
+pub mod rabbitmq_topology_api_spec;
+pub mod spec_types;
+pub mod step;
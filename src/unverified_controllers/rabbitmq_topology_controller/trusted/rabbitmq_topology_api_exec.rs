@@ -0,0 +1,462 @@
+// Copyright 2022 VMware, Inc.
+// SPDX-License-Identifier: MIT
+use crate::external_api::exec::*;
+use crate::external_api::http::{
+    get_basic_auth_credentials, http_request, is_success, percent_encode,
+};
+use crate::rabbitmq_topology_controller::trusted::{
+    rabbitmq_topology_api_spec::{
+        BrokerPermissionView, RabbitmqTopologyAPIInputView, RabbitmqTopologyAPIOutputView,
+        RabbitmqTopologyAPIResultView,
+    },
+    step::*,
+};
+use deps_hack::futures::future::BoxFuture;
+use deps_hack::tracing::info;
+use vstd::{pervasive::unreached, prelude::*, string::*, view::*};
+
+verus! {
+
+pub struct BrokerPermission {
+    pub configure: String,
+    pub write: String,
+    pub read: String,
+}
+
+impl View for BrokerPermission {
+    type V = BrokerPermissionView;
+    open spec fn view(&self) -> BrokerPermissionView {
+        BrokerPermissionView {
+            configure: self.configure@,
+            write: self.write@,
+            read: self.read@,
+        }
+    }
+}
+
+pub struct RabbitmqTopologyAPIResult {
+    pub res: Result<(), RabbitmqTopologyAPIError>,
+}
+
+impl View for RabbitmqTopologyAPIResult {
+    type V = RabbitmqTopologyAPIResultView;
+    open spec fn view(&self) -> RabbitmqTopologyAPIResultView { RabbitmqTopologyAPIResultView {res: self.res} }
+}
+
+#[is_variant]
+pub enum RabbitmqTopologyAPIInput {
+    PutUserRequest(String, String, String, String, Vec<String>),
+    PutVhostRequest(String, String, String, bool),
+    PutPermissionRequest(String, String, String, String, BrokerPermission),
+}
+
+#[is_variant]
+pub enum RabbitmqTopologyAPIOutput {
+    PutUserResponse(RabbitmqTopologyAPIResult),
+    PutVhostResponse(RabbitmqTopologyAPIResult),
+    PutPermissionResponse(RabbitmqTopologyAPIResult),
+}
+
+impl View for RabbitmqTopologyAPIInput {
+    type V = RabbitmqTopologyAPIInputView;
+    open spec fn view(&self) -> RabbitmqTopologyAPIInputView {
+        match self {
+            RabbitmqTopologyAPIInput::PutUserRequest(cluster_name, cluster_namespace, user, password, tags)
+                => RabbitmqTopologyAPIInputView::PutUserRequest(cluster_name@, cluster_namespace@, user@, password@, tags@.map_values(|s: String| s@)),
+            RabbitmqTopologyAPIInput::PutVhostRequest(cluster_name, cluster_namespace, vhost, tracing)
+                => RabbitmqTopologyAPIInputView::PutVhostRequest(cluster_name@, cluster_namespace@, vhost@, *tracing),
+            RabbitmqTopologyAPIInput::PutPermissionRequest(cluster_name, cluster_namespace, user, vhost, permission)
+                => RabbitmqTopologyAPIInputView::PutPermissionRequest(cluster_name@, cluster_namespace@, user@, vhost@, permission@),
+        }
+    }
+}
+
+impl View for RabbitmqTopologyAPIOutput {
+    type V = RabbitmqTopologyAPIOutputView;
+    open spec fn view(&self) -> RabbitmqTopologyAPIOutputView {
+        match self {
+            RabbitmqTopologyAPIOutput::PutUserResponse(result) => RabbitmqTopologyAPIOutputView::PutUserResponse(result@),
+            RabbitmqTopologyAPIOutput::PutVhostResponse(result) => RabbitmqTopologyAPIOutputView::PutVhostResponse(result@),
+            RabbitmqTopologyAPIOutput::PutPermissionResponse(result) => RabbitmqTopologyAPIOutputView::PutPermissionResponse(result@),
+        }
+    }
+}
+
+impl RabbitmqTopologyAPIOutput {
+    pub fn is_put_user_response(&self) -> (res: bool)
+        ensures res == self.is_PutUserResponse(),
+    {
+        match self {
+            RabbitmqTopologyAPIOutput::PutUserResponse(_) => true,
+            _ => false,
+        }
+    }
+
+    pub fn unwrap_put_user_response(self) -> (result: RabbitmqTopologyAPIResult)
+        requires self.is_PutUserResponse(),
+        ensures result == self.get_PutUserResponse_0(),
+    {
+        match self {
+            RabbitmqTopologyAPIOutput::PutUserResponse(result) => result,
+            _ => unreached(),
+        }
+    }
+
+    pub fn is_put_vhost_response(&self) -> (res: bool)
+        ensures res == self.is_PutVhostResponse(),
+    {
+        match self {
+            RabbitmqTopologyAPIOutput::PutVhostResponse(_) => true,
+            _ => false,
+        }
+    }
+
+    pub fn unwrap_put_vhost_response(self) -> (result: RabbitmqTopologyAPIResult)
+        requires self.is_PutVhostResponse(),
+        ensures result == self.get_PutVhostResponse_0(),
+    {
+        match self {
+            RabbitmqTopologyAPIOutput::PutVhostResponse(result) => result,
+            _ => unreached(),
+        }
+    }
+
+    pub fn is_put_permission_response(&self) -> (res: bool)
+        ensures res == self.is_PutPermissionResponse(),
+    {
+        match self {
+            RabbitmqTopologyAPIOutput::PutPermissionResponse(_) => true,
+            _ => false,
+        }
+    }
+
+    pub fn unwrap_put_permission_response(self) -> (result: RabbitmqTopologyAPIResult)
+        requires self.is_PutPermissionResponse(),
+        ensures result == self.get_PutPermissionResponse_0(),
+    {
+        match self {
+            RabbitmqTopologyAPIOutput::PutPermissionResponse(result) => result,
+            _ => unreached(),
+        }
+    }
+}
+
+pub struct RabbitmqTopologyAPIShimLayer {}
+
+}
+
+impl ExternalAPIShimLayer for RabbitmqTopologyAPIShimLayer {
+    type Input = RabbitmqTopologyAPIInput;
+    type Output = RabbitmqTopologyAPIOutput;
+    fn call_external_api(input: RabbitmqTopologyAPIInput) -> RabbitmqTopologyAPIOutput {
+        match input {
+            RabbitmqTopologyAPIInput::PutUserRequest(
+                cluster_name,
+                cluster_namespace,
+                user,
+                password,
+                tags,
+            ) => RabbitmqTopologyAPIOutput::PutUserResponse(log_result(
+                "Put rabbitmq user",
+                call_management_api(
+                    &cluster_name,
+                    &cluster_namespace,
+                    RabbitmqTopologyAPIError::PutUserFailed,
+                    |addr, credentials| put_user(addr, credentials, &user, &password, &tags),
+                ),
+            )),
+            RabbitmqTopologyAPIInput::PutVhostRequest(
+                cluster_name,
+                cluster_namespace,
+                vhost,
+                tracing,
+            ) => RabbitmqTopologyAPIOutput::PutVhostResponse(log_result(
+                "Put rabbitmq vhost",
+                call_management_api(
+                    &cluster_name,
+                    &cluster_namespace,
+                    RabbitmqTopologyAPIError::PutVhostFailed,
+                    |addr, credentials| put_vhost(addr, credentials, &vhost, tracing),
+                ),
+            )),
+            RabbitmqTopologyAPIInput::PutPermissionRequest(
+                cluster_name,
+                cluster_namespace,
+                user,
+                vhost,
+                permission,
+            ) => RabbitmqTopologyAPIOutput::PutPermissionResponse(log_result(
+                "Put rabbitmq permission",
+                call_management_api(
+                    &cluster_name,
+                    &cluster_namespace,
+                    RabbitmqTopologyAPIError::PutPermissionFailed,
+                    |addr, credentials| {
+                        put_permission(addr, credentials, &user, &vhost, &permission)
+                    },
+                ),
+            )),
+        }
+    }
+}
+
+// The management API calls use blocking sockets, so they run on the blocking thread pool.
+impl AsyncExternalAPIShimLayer for RabbitmqTopologyAPIShimLayer {
+    fn call_external_api_async(input: RabbitmqTopologyAPIInput) -> BoxFuture<'static, RabbitmqTopologyAPIOutput> {
+        call_external_api_blocking::<Self>(input)
+    }
+
+    fn error_of(output: &RabbitmqTopologyAPIOutput) -> Option<String> {
+        match output {
+            RabbitmqTopologyAPIOutput::PutUserResponse(result) => result.res.as_ref().err(),
+            RabbitmqTopologyAPIOutput::PutVhostResponse(result) => result.res.as_ref().err(),
+            RabbitmqTopologyAPIOutput::PutPermissionResponse(result) => result.res.as_ref().err(),
+        }
+        .map(|err| format!("{:?}", err))
+    }
+}
+
+const MANAGEMENT_PORT: i32 = 15672;
+
+// management_addr points to the client service of the rabbitmq cluster, which exposes the management API.
+pub fn management_addr(cluster_name: &String, cluster_namespace: &String) -> String {
+    format!(
+        "{}-client.{}.svc.cluster.local:{}",
+        cluster_name, cluster_namespace, MANAGEMENT_PORT
+    )
+}
+
+// call_management_api calls the management API of the rabbitmq cluster as its default user,
+// whose credentials the rabbitmq controller keeps in the default user secret of the cluster.
+fn call_management_api(
+    cluster_name: &String,
+    cluster_namespace: &String,
+    error: RabbitmqTopologyAPIError,
+    call: impl FnOnce(&str, (&str, &str)) -> Result<(), RabbitmqTopologyAPIError>,
+) -> Result<(), RabbitmqTopologyAPIError> {
+    let secret_name = format!("{}-default-user", cluster_name);
+    let (username, password) =
+        block_on(get_basic_auth_credentials(&secret_name, cluster_namespace)).map_err(|e| {
+            info!("Reading the default user credentials failed: {}", e);
+            error
+        })?;
+    call(
+        &management_addr(cluster_name, cluster_namespace),
+        (username.as_str(), password.as_str()),
+    )
+}
+
+fn log_result(
+    action: &str,
+    res: Result<(), RabbitmqTopologyAPIError>,
+) -> RabbitmqTopologyAPIResult {
+    match res {
+        Err(_) => info!("{} failed", action),
+        Ok(_) => info!("{} successfully", action),
+    }
+    RabbitmqTopologyAPIResult { res }
+}
+
+// put_user creates the user or overwrites its password and tags.
+pub fn put_user(
+    addr: &str,
+    credentials: (&str, &str),
+    user: &str,
+    password: &str,
+    tags: &[String],
+) -> Result<(), RabbitmqTopologyAPIError> {
+    let path = format!("/api/users/{}", percent_encode(user));
+    // The management API takes the tags as one comma-separated string
+    let body =
+        deps_hack::serde_json::json!({ "password": password, "tags": tags.join(",") }).to_string();
+    put(addr, credentials, &path, &body).map_err(|_e| RabbitmqTopologyAPIError::PutUserFailed)
+}
+
+// put_vhost creates the vhost or overwrites its settings.
+pub fn put_vhost(
+    addr: &str,
+    credentials: (&str, &str),
+    vhost: &str,
+    tracing: bool,
+) -> Result<(), RabbitmqTopologyAPIError> {
+    let path = format!("/api/vhosts/{}", percent_encode(vhost));
+    let body = deps_hack::serde_json::json!({ "tracing": tracing }).to_string();
+    put(addr, credentials, &path, &body).map_err(|_e| RabbitmqTopologyAPIError::PutVhostFailed)
+}
+
+// put_permission grants the user the permissions in the vhost,
+// which fails if either of them does not exist in the broker yet.
+pub fn put_permission(
+    addr: &str,
+    credentials: (&str, &str),
+    user: &str,
+    vhost: &str,
+    permission: &BrokerPermission,
+) -> Result<(), RabbitmqTopologyAPIError> {
+    let path = format!(
+        "/api/permissions/{}/{}",
+        percent_encode(vhost),
+        percent_encode(user)
+    );
+    let body = deps_hack::serde_json::json!({
+        "configure": permission.configure,
+        "write": permission.write,
+        "read": permission.read,
+    })
+    .to_string();
+    put(addr, credentials, &path, &body).map_err(|_e| RabbitmqTopologyAPIError::PutPermissionFailed)
+}
+
+fn put(addr: &str, credentials: (&str, &str), path: &str, body: &str) -> Result<(), ()> {
+    let (status, _) = http_request("PUT", addr, path, credentials, body).map_err(|e| {
+        info!("Management API on {} is unreachable: {}", addr, e);
+    })?;
+    if is_success(status) {
+        Ok(())
+    } else {
+        info!("Management API on {} returned status {} for {}", addr, status, path);
+        Err(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    use std::io::{Read, Write};
+    use std::net::TcpListener;
+    use std::thread;
+
+    const CREDENTIALS: (&str, &str) = ("admin", "s3cret");
+
+    // StandInBroker records what a management API would store for the requests it accepted.
+    #[derive(Default)]
+    struct StandInBroker {
+        users: HashMap<String, deps_hack::serde_json::Value>,
+        vhosts: HashMap<String, deps_hack::serde_json::Value>,
+        permissions: HashMap<(String, String), deps_hack::serde_json::Value>,
+    }
+
+    impl StandInBroker {
+        // handle mimics the management API: users and vhosts are upserted,
+        // and permissions are rejected unless both the user and the vhost exist.
+        fn handle(&mut self, method: &str, path: &str, body: &str) -> &'static str {
+            let value = deps_hack::serde_json::from_str(body).unwrap_or_default();
+            let segments = path.split('/').skip(2).collect::<Vec<&str>>();
+            match (method, segments.as_slice()) {
+                ("PUT", ["users", user]) => {
+                    self.users.insert(user.to_string(), value);
+                    "204 No Content"
+                }
+                ("PUT", ["vhosts", vhost]) => {
+                    self.vhosts.insert(vhost.to_string(), value);
+                    "201 Created"
+                }
+                ("PUT", ["permissions", vhost, user]) => {
+                    if self.users.contains_key(*user) && self.vhosts.contains_key(*vhost) {
+                        self.permissions
+                            .insert((user.to_string(), vhost.to_string()), value);
+                        "201 Created"
+                    } else {
+                        "400 Bad Request"
+                    }
+                }
+                _ => "405 Method Not Allowed",
+            }
+        }
+    }
+
+    // Runs a stand-in broker for the given number of requests and returns its state afterwards.
+    fn serve(requests: usize) -> (String, thread::JoinHandle<StandInBroker>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap().to_string();
+        let handle = thread::spawn(move || {
+            let mut broker = StandInBroker::default();
+            for _ in 0..requests {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut buf = [0; 4096];
+                let n = stream.read(&mut buf).unwrap();
+                let request = String::from_utf8_lossy(&buf[..n]).to_string();
+                let authorization = format!(
+                    "Authorization: Basic {}\r\n",
+                    deps_hack::base64::encode(format!("{}:{}", CREDENTIALS.0, CREDENTIALS.1))
+                );
+                assert!(request.contains(&authorization));
+                let (head, body) = request.split_once("\r\n\r\n").unwrap();
+                let mut request_line = head.lines().next().unwrap().split_whitespace();
+                let method = request_line.next().unwrap();
+                let path = request_line.next().unwrap();
+                let status = broker.handle(method, path, body);
+                let response = format!("HTTP/1.1 {}\r\nContent-Length: 0\r\n\r\n", status);
+                stream.write_all(response.as_bytes()).unwrap();
+            }
+            broker
+        });
+        (addr, handle)
+    }
+
+    fn permission(configure: &str, write: &str, read: &str) -> BrokerPermission {
+        BrokerPermission {
+            configure: configure.to_string(),
+            write: write.to_string(),
+            read: read.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_put_user() {
+        let (addr, handle) = serve(1);
+        let tags = vec!["management".to_string(), "policymaker".to_string()];
+        assert!(put_user(&addr, CREDENTIALS, "alice", "secret", &tags).is_ok());
+        let broker = handle.join().unwrap();
+        assert_eq!(broker.users["alice"]["password"], "secret");
+        assert_eq!(broker.users["alice"]["tags"], "management,policymaker");
+    }
+
+    #[test]
+    fn test_put_vhost_escapes_name() {
+        let (addr, handle) = serve(1);
+        assert!(put_vhost(&addr, CREDENTIALS, "/", true).is_ok());
+        let broker = handle.join().unwrap();
+        assert_eq!(broker.vhosts["%2F"]["tracing"], true);
+    }
+
+    #[test]
+    fn test_put_permission_requires_user_and_vhost() {
+        let (addr, handle) = serve(4);
+        let rules = permission(".*", ".*", "^amq\\.");
+        assert!(matches!(
+            put_permission(&addr, CREDENTIALS, "alice", "orders", &rules),
+            Err(RabbitmqTopologyAPIError::PutPermissionFailed)
+        ));
+        assert!(put_user(&addr, CREDENTIALS, "alice", "secret", &[]).is_ok());
+        assert!(put_vhost(&addr, CREDENTIALS, "orders", false).is_ok());
+        assert!(put_permission(&addr, CREDENTIALS, "alice", "orders", &rules).is_ok());
+        let broker = handle.join().unwrap();
+        let granted = &broker.permissions[&("alice".to_string(), "orders".to_string())];
+        assert_eq!(granted["configure"], ".*");
+        assert_eq!(granted["read"], "^amq\\.");
+    }
+
+    #[test]
+    fn test_put_user_unreachable() {
+        // Bind then drop the listener so that nothing listens on the port
+        let addr = TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap()
+            .to_string();
+        assert!(matches!(
+            put_user(&addr, CREDENTIALS, "alice", "secret", &[]),
+            Err(RabbitmqTopologyAPIError::PutUserFailed)
+        ));
+    }
+
+    #[test]
+    fn test_management_addr() {
+        assert_eq!(
+            management_addr(&"rabbitmq".to_string(), &"default".to_string()),
+            "rabbitmq-client.default.svc.cluster.local:15672"
+        );
+    }
+}
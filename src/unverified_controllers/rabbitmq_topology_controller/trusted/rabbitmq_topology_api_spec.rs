@@ -0,0 +1,177 @@
+// Copyright 2022 VMware, Inc.
+// SPDX-License-Identifier: MIT
+use crate::external_api::spec::*;
+use crate::kubernetes_api_objects::spec::{common::*, dynamic::*, resource::*, stateful_set::*};
+use crate::rabbitmq_topology_controller::trusted::step::*;
+use vstd::{prelude::*, string::*};
+
+verus! {
+
+// BrokerObjectKey identifies a user or a vhost inside the broker.
+// Users and vhosts live in the rabbitmq cluster referenced by the custom resource,
+// so the same name in two different rabbitmq clusters refers to two different objects.
+pub struct BrokerObjectKey {
+    pub cluster_name: StringView,
+    pub cluster_namespace: StringView,
+    pub name: StringView,
+}
+
+impl BrokerObjectKey {
+    pub open spec fn new(cluster_name: StringView, cluster_namespace: StringView, name: StringView) -> Self {
+        BrokerObjectKey {
+            cluster_name: cluster_name,
+            cluster_namespace: cluster_namespace,
+            name: name,
+        }
+    }
+}
+
+pub struct BrokerUserView {
+    pub password: StringView,
+    pub tags: Seq<StringView>,
+}
+
+pub struct BrokerVhostView {
+    pub tracing: bool,
+}
+
+pub struct BrokerPermissionView {
+    pub configure: StringView,
+    pub write: StringView,
+    pub read: StringView,
+}
+
+pub struct RabbitmqTopologyAPI {}
+
+// RabbitmqTopologyAPIState maps the users and vhosts to their settings in the broker,
+// and maps each (user, vhost) pair to the permissions the user has in the vhost.
+pub struct RabbitmqTopologyAPIState {
+    pub users: Map<BrokerObjectKey, BrokerUserView>,
+    pub vhosts: Map<BrokerObjectKey, BrokerVhostView>,
+    pub permissions: Map<(BrokerObjectKey, StringView), BrokerPermissionView>,
+}
+
+impl RabbitmqTopologyAPIState {
+    pub open spec fn init() -> RabbitmqTopologyAPIState {
+        RabbitmqTopologyAPIState {
+            users: Map::empty(),
+            vhosts: Map::empty(),
+            permissions: Map::empty(),
+        }
+    }
+}
+
+pub struct RabbitmqTopologyAPIResultView {
+    pub res: Result<(), RabbitmqTopologyAPIError>,
+}
+
+// Each request carries the name and namespace of the referenced rabbitmq cluster first.
+#[is_variant]
+pub enum RabbitmqTopologyAPIInputView {
+    PutUserRequest(StringView, StringView, StringView, StringView, Seq<StringView>),
+    PutVhostRequest(StringView, StringView, StringView, bool),
+    PutPermissionRequest(StringView, StringView, StringView, StringView, BrokerPermissionView),
+}
+
+#[is_variant]
+pub enum RabbitmqTopologyAPIOutputView {
+    PutUserResponse(RabbitmqTopologyAPIResultView),
+    PutVhostResponse(RabbitmqTopologyAPIResultView),
+    PutPermissionResponse(RabbitmqTopologyAPIResultView),
+}
+
+impl ExternalAPI for RabbitmqTopologyAPI {
+
+    type Input = RabbitmqTopologyAPIInputView;
+    type Output = RabbitmqTopologyAPIOutputView;
+    type State = RabbitmqTopologyAPIState;
+
+    open spec fn transition(input: RabbitmqTopologyAPIInputView, resources: StoredState, state: RabbitmqTopologyAPIState) -> (RabbitmqTopologyAPIState, RabbitmqTopologyAPIOutputView) {
+        match input {
+            RabbitmqTopologyAPIInputView::PutUserRequest(cluster_name, cluster_namespace, user, password, tags) => {
+                let (s_prime, res) = handle_put_user(cluster_name, cluster_namespace, user, password, tags, resources, state);
+                (s_prime, RabbitmqTopologyAPIOutputView::PutUserResponse(res))
+            },
+            RabbitmqTopologyAPIInputView::PutVhostRequest(cluster_name, cluster_namespace, vhost, tracing) => {
+                let (s_prime, res) = handle_put_vhost(cluster_name, cluster_namespace, vhost, tracing, resources, state);
+                (s_prime, RabbitmqTopologyAPIOutputView::PutVhostResponse(res))
+            },
+            RabbitmqTopologyAPIInputView::PutPermissionRequest(cluster_name, cluster_namespace, user, vhost, permission) => {
+                let (s_prime, res) = handle_put_permission(cluster_name, cluster_namespace, user, vhost, permission, resources, state);
+                (s_prime, RabbitmqTopologyAPIOutputView::PutPermissionResponse(res))
+            },
+        }
+    }
+
+    open spec fn init_state() -> RabbitmqTopologyAPIState { RabbitmqTopologyAPIState::init() }
+}
+
+// validate checks that the stateful set object hosting the rabbitmq nodes of the referenced cluster exists.
+//
+// TODO: more validation check could be implemented,
+// such as checking whether the pods are really ready.
+pub open spec fn validate(cluster_name: StringView, cluster_namespace: StringView, resources: StoredState) -> bool {
+    let sts_key = ObjectRef {
+        kind: Kind::StatefulSetKind,
+        namespace: cluster_namespace,
+        name: cluster_name + "-server"@,
+    };
+    &&& resources.contains_key(sts_key)
+    &&& StatefulSetView::unmarshal(resources[sts_key]).is_Ok()
+}
+
+// handle_put_user models the management API creating the user or overwriting its password and tags.
+pub open spec fn handle_put_user(
+    cluster_name: StringView, cluster_namespace: StringView, user: StringView, password: StringView, tags: Seq<StringView>,
+    resources: StoredState, state: RabbitmqTopologyAPIState
+) -> (RabbitmqTopologyAPIState, RabbitmqTopologyAPIResultView) {
+    if !validate(cluster_name, cluster_namespace, resources) {
+        (state, RabbitmqTopologyAPIResultView{res: Err(RabbitmqTopologyAPIError::PutUserFailed)})
+    } else {
+        let key = BrokerObjectKey::new(cluster_name, cluster_namespace, user);
+        let state_prime = RabbitmqTopologyAPIState {
+            users: state.users.insert(key, BrokerUserView { password: password, tags: tags }),
+            ..state
+        };
+        (state_prime, RabbitmqTopologyAPIResultView{res: Ok(())})
+    }
+}
+
+// handle_put_vhost models the management API creating the vhost or overwriting its settings.
+pub open spec fn handle_put_vhost(
+    cluster_name: StringView, cluster_namespace: StringView, vhost: StringView, tracing: bool,
+    resources: StoredState, state: RabbitmqTopologyAPIState
+) -> (RabbitmqTopologyAPIState, RabbitmqTopologyAPIResultView) {
+    if !validate(cluster_name, cluster_namespace, resources) {
+        (state, RabbitmqTopologyAPIResultView{res: Err(RabbitmqTopologyAPIError::PutVhostFailed)})
+    } else {
+        let key = BrokerObjectKey::new(cluster_name, cluster_namespace, vhost);
+        let state_prime = RabbitmqTopologyAPIState {
+            vhosts: state.vhosts.insert(key, BrokerVhostView { tracing: tracing }),
+            ..state
+        };
+        (state_prime, RabbitmqTopologyAPIResultView{res: Ok(())})
+    }
+}
+
+// handle_put_permission models the management API granting the user the permissions in the vhost.
+// The management API rejects the request if either the user or the vhost does not exist yet.
+pub open spec fn handle_put_permission(
+    cluster_name: StringView, cluster_namespace: StringView, user: StringView, vhost: StringView, permission: BrokerPermissionView,
+    resources: StoredState, state: RabbitmqTopologyAPIState
+) -> (RabbitmqTopologyAPIState, RabbitmqTopologyAPIResultView) {
+    let user_key = BrokerObjectKey::new(cluster_name, cluster_namespace, user);
+    let vhost_key = BrokerObjectKey::new(cluster_name, cluster_namespace, vhost);
+    if !validate(cluster_name, cluster_namespace, resources)
+    || !state.users.contains_key(user_key) || !state.vhosts.contains_key(vhost_key) {
+        (state, RabbitmqTopologyAPIResultView{res: Err(RabbitmqTopologyAPIError::PutPermissionFailed)})
+    } else {
+        let state_prime = RabbitmqTopologyAPIState {
+            permissions: state.permissions.insert((user_key, vhost), permission),
+            ..state
+        };
+        (state_prime, RabbitmqTopologyAPIResultView{res: Ok(())})
+    }
+}
+
+}
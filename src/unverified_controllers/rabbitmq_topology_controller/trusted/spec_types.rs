@@ -0,0 +1,14 @@
+// Copyright 2022 VMware, Inc.
+// SPDX-License-Identifier: MIT
+use crate::vstd_ext::string_view::*;
+use vstd::prelude::*;
+
+verus! {
+
+// RabbitmqClusterReferenceView names the rabbitmq cluster, in the same namespace as the referencing object,
+// that hosts the users, vhosts and permissions.
+pub struct RabbitmqClusterReferenceView {
+    pub name: StringView,
+}
+
+}
@@ -0,0 +1,26 @@
+// Copyright 2022 VMware, Inc.
+// SPDX-License-Identifier: MIT
+#![allow(unused_imports)]
+use vstd::prelude::*;
+
+verus! {
+
+#[is_variant]
+pub enum RabbitmqTopologyAPIError {
+    PutUserFailed,
+    PutVhostFailed,
+    PutPermissionFailed,
+}
+
+#[verifier(external)]
+impl std::fmt::Debug for RabbitmqTopologyAPIError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            RabbitmqTopologyAPIError::PutUserFailed => write!(f, "PutUserFailed"),
+            RabbitmqTopologyAPIError::PutVhostFailed => write!(f, "PutVhostFailed"),
+            RabbitmqTopologyAPIError::PutPermissionFailed => write!(f, "PutPermissionFailed"),
+        }
+    }
+}
+
+}
@@ -0,0 +1,288 @@
+// Copyright 2022 VMware, Inc.
+// SPDX-License-Identifier: MIT
+#![allow(unused_imports)]
+use crate::external_api::exec::*;
+use crate::kubernetes_api_objects::exec::prelude::*;
+use crate::kubernetes_api_objects::exec::{condition::*, resource::ResourceWrapper};
+use crate::rabbitmq_topology_controller::trusted::rabbitmq_topology_api_exec::*;
+use crate::rabbitmq_topology_controller::user::model::reconciler as model_reconciler;
+use crate::rabbitmq_topology_controller::user::trusted::{exec_types::*, spec_types, step::*};
+use crate::reconciler::exec::{io::*, reconciler::*};
+use crate::vstd_ext::{string_map::StringMap, string_view::*};
+use vstd::{prelude::*, seq_lib::*, string::*};
+
+verus! {
+
+pub struct RabbitmqUserReconciler {}
+
+impl Reconciler for RabbitmqUserReconciler {
+    type R = RabbitmqUser;
+    type T = RabbitmqUserReconcileState;
+    type ExternalAPIType = RabbitmqTopologyAPIShimLayer;
+
+    open spec fn well_formed(user: &RabbitmqUser) -> bool { user@.well_formed() }
+
+    fn reconcile_init_state() -> RabbitmqUserReconcileState {
+        reconcile_init_state()
+    }
+
+    fn reconcile_core(user: &RabbitmqUser, resp_o: Option<Response<RabbitmqTopologyAPIOutput>>, state: RabbitmqUserReconcileState)
+    -> (RabbitmqUserReconcileState, Option<Request<RabbitmqTopologyAPIInput>>) {
+        reconcile_core(user, resp_o, state)
+    }
+
+    fn reconcile_done(state: &RabbitmqUserReconcileState) -> bool {
+        reconcile_done(state)
+    }
+
+    fn reconcile_error(state: &RabbitmqUserReconcileState) -> bool {
+        reconcile_error(state)
+    }
+}
+
+pub fn reconcile_init_state() -> (state: RabbitmqUserReconcileState)
+    ensures state@ == model_reconciler::reconcile_init_state(),
+{
+    RabbitmqUserReconcileState { reconcile_step: RabbitmqUserReconcileStep::Init }
+}
+
+pub fn reconcile_done(state: &RabbitmqUserReconcileState) -> (res: bool)
+    ensures res == model_reconciler::reconcile_done(state@),
+{
+    match state.reconcile_step {
+        RabbitmqUserReconcileStep::Done => true,
+        _ => false,
+    }
+}
+
+pub fn reconcile_error(state: &RabbitmqUserReconcileState) -> (res: bool)
+    ensures res == model_reconciler::reconcile_error(state@),
+{
+    match state.reconcile_step {
+        RabbitmqUserReconcileStep::Error => true,
+        _ => false,
+    }
+}
+
+pub fn reconcile_core(user: &RabbitmqUser, resp_o: Option<Response<RabbitmqTopologyAPIOutput>>, state: RabbitmqUserReconcileState) -> (res: (RabbitmqUserReconcileState, Option<Request<RabbitmqTopologyAPIInput>>))
+    requires user@.well_formed(),
+    ensures (res.0@, opt_request_to_view(&res.1)) == model_reconciler::reconcile_core(user@, opt_response_to_view(&resp_o), state@),
+{
+    let step = state.reconcile_step;
+    match step {
+        RabbitmqUserReconcileStep::Init => {
+            let req_o = KubeAPIRequest::GetRequest(KubeGetRequest {
+                api_resource: Secret::api_resource(),
+                name: make_credentials_secret_name(user),
+                namespace: user.metadata().namespace().unwrap(),
+            });
+            let state_prime = RabbitmqUserReconcileState {
+                reconcile_step: RabbitmqUserReconcileStep::AfterKRequestStep(ActionKind::Get, SubResource::CredentialsSecret),
+                ..state
+            };
+            return (state_prime, Some(Request::KRequest(req_o)));
+        },
+        RabbitmqUserReconcileStep::AfterKRequestStep(ActionKind::Get, _) => {
+            if resp_o.is_some() && resp_o.as_ref().unwrap().is_k_response()
+            && resp_o.as_ref().unwrap().as_k_response_ref().is_get_response() {
+                let get_resp = resp_o.unwrap().into_k_response().into_get_response().res;
+                if get_resp.is_ok() {
+                    let password = found_password(get_resp.unwrap());
+                    if password.is_some() {
+                        let state_prime = RabbitmqUserReconcileState {
+                            reconcile_step: RabbitmqUserReconcileStep::AfterPutUser,
+                            ..state
+                        };
+                        return (state_prime, Some(Request::ExternalRequest(put_user_request(user, password.unwrap()))));
+                    }
+                } else if get_resp.unwrap_err().is_object_not_found() {
+                    let req_o = KubeAPIRequest::CreateRequest(KubeCreateRequest {
+                        api_resource: Secret::api_resource(),
+                        namespace: user.metadata().namespace().unwrap(),
+                        obj: make_credentials_secret(user).marshal(),
+                    });
+                    let state_prime = RabbitmqUserReconcileState {
+                        reconcile_step: RabbitmqUserReconcileStep::AfterKRequestStep(ActionKind::Create, SubResource::CredentialsSecret),
+                        ..state
+                    };
+                    return (state_prime, Some(Request::KRequest(req_o)));
+                }
+            }
+            return report_error(user, state);
+        },
+        RabbitmqUserReconcileStep::AfterKRequestStep(ActionKind::Create, _) => {
+            if resp_o.is_some() && resp_o.as_ref().unwrap().is_k_response()
+            && resp_o.as_ref().unwrap().as_k_response_ref().is_create_response()
+            && resp_o.as_ref().unwrap().as_k_response_ref().as_create_response_ref().res.is_ok() {
+                let password = found_password(resp_o.unwrap().into_k_response().into_create_response().res.unwrap());
+                if password.is_some() {
+                    let state_prime = RabbitmqUserReconcileState {
+                        reconcile_step: RabbitmqUserReconcileStep::AfterPutUser,
+                        ..state
+                    };
+                    return (state_prime, Some(Request::ExternalRequest(put_user_request(user, password.unwrap()))));
+                }
+            }
+            return report_error(user, state);
+        },
+        RabbitmqUserReconcileStep::AfterPutUser => {
+            if resp_o.is_some() && resp_o.as_ref().unwrap().is_external_response()
+            && resp_o.as_ref().unwrap().as_external_response_ref().is_put_user_response() {
+                let put_user_resp = resp_o.unwrap().into_external_response().unwrap_put_user_response().res;
+                if put_user_resp.is_ok() {
+                    let req_o = KubeAPIRequest::UpdateStatusRequest(KubeUpdateStatusRequest {
+                        api_resource: RabbitmqUser::api_resource(),
+                        name: user.metadata().name().unwrap(),
+                        namespace: user.metadata().namespace().unwrap(),
+                        obj: update_user_status(user).marshal(),
+                    });
+                    let state_prime = RabbitmqUserReconcileState {
+                        reconcile_step: RabbitmqUserReconcileStep::AfterUpdateStatus,
+                        ..state
+                    };
+                    return (state_prime, Some(Request::KRequest(req_o)));
+                }
+            }
+            return report_error(user, state);
+        },
+        RabbitmqUserReconcileStep::AfterUpdateStatus => {
+            if resp_o.is_some() && resp_o.as_ref().unwrap().is_k_response()
+            && resp_o.as_ref().unwrap().as_k_response_ref().is_update_status_response() {
+                let update_status_resp = resp_o.unwrap().into_k_response().into_update_status_response().res;
+                if update_status_resp.is_ok() {
+                    let state_prime = RabbitmqUserReconcileState {
+                        reconcile_step: RabbitmqUserReconcileStep::Done,
+                        ..state
+                    };
+                    return (state_prime, None);
+                }
+            }
+            let state_prime = RabbitmqUserReconcileState {
+                reconcile_step: RabbitmqUserReconcileStep::Error,
+                ..state
+            };
+            return (state_prime, None);
+        },
+        RabbitmqUserReconcileStep::AfterUpdateDegradedStatus => {
+            let state_prime = RabbitmqUserReconcileState {
+                reconcile_step: RabbitmqUserReconcileStep::Error,
+                ..state
+            };
+            return (state_prime, None);
+        },
+        _ => {
+            let state_prime = RabbitmqUserReconcileState {
+                reconcile_step: step,
+                ..state
+            };
+            return (state_prime, None);
+        }
+    }
+}
+
+pub fn put_user_request(user: &RabbitmqUser, password: String) -> (req: RabbitmqTopologyAPIInput)
+    requires user@.well_formed(),
+    ensures req@ == model_reconciler::put_user_request(user@, password@),
+{
+    RabbitmqTopologyAPIInput::PutUserRequest(
+        user.spec().rabbitmq_cluster_reference().name(), user.metadata().namespace().unwrap(), user.metadata().name().unwrap(), password, user.spec().tags()
+    )
+}
+
+pub fn found_password(obj: DynamicObject) -> (password: Option<String>)
+    ensures
+        password.is_Some() == model_reconciler::found_password(obj@).is_Some(),
+        password.is_Some() ==> password.get_Some_0()@ == model_reconciler::found_password(obj@).get_Some_0(),
+{
+    let secret = Secret::unmarshal(obj);
+    if secret.is_ok() {
+        let data = secret.unwrap().data();
+        if data.is_some() {
+            let password = data.unwrap().get(&"password".to_string());
+            if password.is_some() {
+                return byte_string_to_string(password.unwrap());
+            }
+        }
+    }
+    None
+}
+
+pub fn make_credentials_secret_name(user: &RabbitmqUser) -> (name: String)
+    requires user@.well_formed(),
+    ensures name@ == model_reconciler::make_credentials_secret_name(user@),
+{
+    user.metadata().name().unwrap().concat("-user-credentials")
+}
+
+pub fn make_credentials_secret(user: &RabbitmqUser) -> (secret: Secret)
+    requires user@.well_formed(),
+    ensures secret@ == model_reconciler::make_credentials_secret(user@),
+{
+    let mut secret = Secret::default();
+    secret.set_metadata({
+        let mut metadata = ObjectMeta::default();
+        metadata.set_name(make_credentials_secret_name(user));
+        metadata.set_owner_references({
+            let mut owner_references = Vec::new();
+            owner_references.push(user.controller_owner_ref());
+            proof {
+                assert_seqs_equal!(
+                    owner_references@.map_values(|owner_ref: OwnerReference| owner_ref@),
+                    seq![user@.controller_owner_ref()]
+                );
+            }
+            owner_references
+        });
+        metadata
+    });
+    secret.set_data({
+        let mut data = StringMap::empty();
+        data.insert("username".to_string(), user.metadata().name().unwrap());
+        data.insert("password".to_string(), random_encoded_string(24));
+        data
+    });
+    secret
+}
+
+pub fn update_user_status(user: &RabbitmqUser) -> (updated_user: RabbitmqUser)
+    ensures updated_user@ == model_reconciler::update_user_status(user@),
+{
+    let mut updated_user = user.clone();
+    updated_user.set_status({
+        let mut status = RabbitmqUserStatus::default();
+        status.set_conditions(make_conditions(true, false, user.metadata().generation()));
+        status
+    });
+    updated_user
+}
+
+pub fn update_user_status_to_degraded(user: &RabbitmqUser) -> (updated_user: RabbitmqUser)
+    ensures updated_user@ == model_reconciler::update_user_status_to_degraded(user@),
+{
+    let mut updated_user = user.clone();
+    updated_user.set_status({
+        let mut status = RabbitmqUserStatus::default();
+        status.set_conditions(make_conditions(false, true, user.metadata().generation()));
+        status
+    });
+    updated_user
+}
+
+pub fn report_error(user: &RabbitmqUser, state: RabbitmqUserReconcileState) -> (res: (RabbitmqUserReconcileState, Option<Request<RabbitmqTopologyAPIInput>>))
+    requires user@.well_formed(),
+    ensures (res.0@, opt_request_to_view(&res.1)) == model_reconciler::report_error(user@, state@),
+{
+    let req_o = KubeAPIRequest::UpdateStatusRequest(KubeUpdateStatusRequest {
+        api_resource: RabbitmqUser::api_resource(),
+        name: user.metadata().name().unwrap(),
+        namespace: user.metadata().namespace().unwrap(),
+        obj: update_user_status_to_degraded(user).marshal(),
+    });
+    let state_prime = RabbitmqUserReconcileState {
+        reconcile_step: RabbitmqUserReconcileStep::AfterUpdateDegradedStatus,
+        ..state
+    };
+    (state_prime, Some(Request::KRequest(req_o)))
+}
+
+}
@@ -0,0 +1,225 @@
+// Copyright 2022 VMware, Inc.
+// SPDX-License-Identifier: MIT
+#![allow(unused_imports)]
+use crate::external_api::spec::*;
+use crate::kubernetes_api_objects::spec::{condition::*, prelude::*};
+use crate::rabbitmq_topology_controller::trusted::rabbitmq_topology_api_spec::*;
+use crate::rabbitmq_topology_controller::user::trusted::{spec_types::*, step::*};
+use crate::reconciler::spec::{io::*, reconciler::*};
+use crate::vstd_ext::string_view::*;
+use vstd::{prelude::*, string::*};
+
+verus! {
+
+impl Reconciler<RabbitmqUserView, RabbitmqTopologyAPI> for RabbitmqUserReconciler {
+    type T = RabbitmqUserReconcileState;
+
+    open spec fn reconcile_init_state() -> RabbitmqUserReconcileState {
+        reconcile_init_state()
+    }
+
+    open spec fn reconcile_core(user: RabbitmqUserView, resp_o: Option<ResponseView<RabbitmqTopologyAPIOutputView>>, state: RabbitmqUserReconcileState)
+    -> (RabbitmqUserReconcileState, Option<RequestView<RabbitmqTopologyAPIInputView>>) {
+        reconcile_core(user, resp_o, state)
+    }
+
+    open spec fn reconcile_done(state: RabbitmqUserReconcileState) -> bool {
+        reconcile_done(state)
+    }
+
+    open spec fn reconcile_error(state: RabbitmqUserReconcileState) -> bool {
+        reconcile_error(state)
+    }
+
+    open spec fn expect_from_user(obj: DynamicObjectView) -> bool {
+        false /* Don't expect anything from the user except the cr object */
+    }
+}
+
+pub open spec fn reconcile_init_state() -> RabbitmqUserReconcileState { RabbitmqUserReconcileState { reconcile_step: RabbitmqUserReconcileStep::Init } }
+
+pub open spec fn reconcile_done(state: RabbitmqUserReconcileState) -> bool {
+    match state.reconcile_step {
+        RabbitmqUserReconcileStep::Done => true,
+        _ => false,
+    }
+}
+
+pub open spec fn reconcile_error(state: RabbitmqUserReconcileState) -> bool {
+    match state.reconcile_step {
+        RabbitmqUserReconcileStep::Error => true,
+        _ => false,
+    }
+}
+
+pub open spec fn reconcile_core(
+    user: RabbitmqUserView, resp_o: Option<ResponseView<RabbitmqTopologyAPIOutputView>>, state: RabbitmqUserReconcileState
+) -> (RabbitmqUserReconcileState, Option<RequestView<RabbitmqTopologyAPIInputView>>) {
+    let step = state.reconcile_step;
+    let resp = resp_o.get_Some_0();
+    match step {
+        RabbitmqUserReconcileStep::Init => {
+            let req_o = APIRequest::GetRequest(GetRequest { key: make_credentials_secret_key(user) });
+            let state_prime = RabbitmqUserReconcileState {
+                reconcile_step: RabbitmqUserReconcileStep::AfterKRequestStep(ActionKind::Get, SubResource::CredentialsSecret),
+                ..state
+            };
+            (state_prime, Some(RequestView::KRequest(req_o)))
+        },
+        RabbitmqUserReconcileStep::AfterKRequestStep(ActionKind::Get, _) => {
+            let get_resp = resp.get_KResponse_0().get_GetResponse_0().res;
+            if resp_o.is_Some() && resp.is_KResponse() && resp.get_KResponse_0().is_GetResponse() {
+                if get_resp.is_Ok() && found_password(get_resp.get_Ok_0()).is_Some() {
+                    // The password is generated only once, so the existing secret is the source of truth
+                    let state_prime = RabbitmqUserReconcileState {
+                        reconcile_step: RabbitmqUserReconcileStep::AfterPutUser,
+                        ..state
+                    };
+                    (state_prime, Some(RequestView::ExternalRequest(put_user_request(user, found_password(get_resp.get_Ok_0()).get_Some_0()))))
+                } else if get_resp.is_Err() && get_resp.get_Err_0().is_ObjectNotFound() {
+                    let req_o = APIRequest::CreateRequest(CreateRequest {
+                        namespace: user.metadata.namespace.get_Some_0(),
+                        obj: make_credentials_secret(user).marshal(),
+                    });
+                    let state_prime = RabbitmqUserReconcileState {
+                        reconcile_step: RabbitmqUserReconcileStep::AfterKRequestStep(ActionKind::Create, SubResource::CredentialsSecret),
+                        ..state
+                    };
+                    (state_prime, Some(RequestView::KRequest(req_o)))
+                } else {
+                    report_error(user, state)
+                }
+            } else {
+                report_error(user, state)
+            }
+        },
+        RabbitmqUserReconcileStep::AfterKRequestStep(ActionKind::Create, _) => {
+            let create_resp = resp.get_KResponse_0().get_CreateResponse_0().res;
+            if resp_o.is_Some() && resp.is_KResponse() && resp.get_KResponse_0().is_CreateResponse()
+            && create_resp.is_Ok() && found_password(create_resp.get_Ok_0()).is_Some() {
+                let state_prime = RabbitmqUserReconcileState {
+                    reconcile_step: RabbitmqUserReconcileStep::AfterPutUser,
+                    ..state
+                };
+                (state_prime, Some(RequestView::ExternalRequest(put_user_request(user, found_password(create_resp.get_Ok_0()).get_Some_0()))))
+            } else {
+                report_error(user, state)
+            }
+        },
+        RabbitmqUserReconcileStep::AfterPutUser => {
+            if resp_o.is_Some() && resp.is_ExternalResponse() && resp.get_ExternalResponse_0().is_PutUserResponse()
+            && resp.get_ExternalResponse_0().get_PutUserResponse_0().res.is_Ok() {
+                let req_o = APIRequest::UpdateStatusRequest(UpdateStatusRequest {
+                    namespace: user.metadata.namespace.get_Some_0(),
+                    name: user.metadata.name.get_Some_0(),
+                    obj: update_user_status(user).marshal(),
+                });
+                let state_prime = RabbitmqUserReconcileState {
+                    reconcile_step: RabbitmqUserReconcileStep::AfterUpdateStatus,
+                    ..state
+                };
+                (state_prime, Some(RequestView::KRequest(req_o)))
+            } else {
+                report_error(user, state)
+            }
+        },
+        RabbitmqUserReconcileStep::AfterUpdateStatus => {
+            let update_status_resp = resp.get_KResponse_0().get_UpdateStatusResponse_0().res;
+            if resp_o.is_Some() && resp.is_KResponse() && resp.get_KResponse_0().is_UpdateStatusResponse()
+            && update_status_resp.is_Ok() {
+                let state_prime = RabbitmqUserReconcileState {
+                    reconcile_step: RabbitmqUserReconcileStep::Done,
+                    ..state
+                };
+                (state_prime, None)
+            } else {
+                let state_prime = RabbitmqUserReconcileState {
+                    reconcile_step: RabbitmqUserReconcileStep::Error,
+                    ..state
+                };
+                (state_prime, None)
+            }
+        },
+        RabbitmqUserReconcileStep::AfterUpdateDegradedStatus => {
+            let state_prime = RabbitmqUserReconcileState {
+                reconcile_step: RabbitmqUserReconcileStep::Error,
+                ..state
+            };
+            (state_prime, None)
+        },
+        _ => {
+            let state_prime = RabbitmqUserReconcileState {
+                reconcile_step: step,
+                ..state
+            };
+            (state_prime, None)
+        }
+    }
+}
+
+// The name of the user in the broker is the name of the RabbitmqUser object.
+pub open spec fn put_user_request(user: RabbitmqUserView, password: StringView) -> RabbitmqTopologyAPIInputView {
+    RabbitmqTopologyAPIInputView::PutUserRequest(
+        user.spec.rabbitmq_cluster_reference.name, user.metadata.namespace.get_Some_0(), user.metadata.name.get_Some_0(), password, user.spec.tags
+    )
+}
+
+// found_password returns the password stored in the credentials secret.
+// A secret created beforehand by someone else is also accepted, which allows importing existing credentials.
+pub open spec fn found_password(obj: DynamicObjectView) -> Option<StringView> {
+    let secret = SecretView::unmarshal(obj);
+    let data = secret.get_Ok_0().data;
+    if secret.is_Ok() && data.is_Some() && data.get_Some_0().contains_key("password"@) {
+        byte_string_view_to_string_view(data.get_Some_0()["password"@])
+    } else {
+        None
+    }
+}
+
+pub open spec fn make_credentials_secret_name(user: RabbitmqUserView) -> StringView {
+    user.metadata.name.get_Some_0() + "-user-credentials"@
+}
+
+pub open spec fn make_credentials_secret_key(user: RabbitmqUserView) -> ObjectRef {
+    ObjectRef {
+        kind: SecretView::kind(),
+        name: make_credentials_secret_name(user),
+        namespace: user.metadata.namespace.get_Some_0(),
+    }
+}
+
+pub open spec fn make_credentials_secret(user: RabbitmqUserView) -> SecretView {
+    SecretView::default()
+        .set_metadata(ObjectMetaView::default()
+            .set_name(make_credentials_secret_name(user))
+            .set_owner_references(seq![user.controller_owner_ref()])
+        ).set_data(Map::empty()
+            .insert("username"@, user.metadata.name.get_Some_0())
+            .insert("password"@, random_encoded_string_view(24))
+        )
+}
+
+// The user is ready as soon as the broker accepts it.
+pub open spec fn update_user_status(user: RabbitmqUserView) -> RabbitmqUserView {
+    user.set_status(RabbitmqUserStatusView::default().set_conditions(make_conditions(true, false, user.metadata.generation)))
+}
+
+pub open spec fn update_user_status_to_degraded(user: RabbitmqUserView) -> RabbitmqUserView {
+    user.set_status(RabbitmqUserStatusView::default().set_conditions(make_conditions(false, true, user.metadata.generation)))
+}
+
+// report_error marks the user as degraded before the reconcile ends in error.
+pub open spec fn report_error(user: RabbitmqUserView, state: RabbitmqUserReconcileState) -> (RabbitmqUserReconcileState, Option<RequestView<RabbitmqTopologyAPIInputView>>) {
+    let req_o = APIRequest::UpdateStatusRequest(UpdateStatusRequest {
+        namespace: user.metadata.namespace.get_Some_0(),
+        name: user.metadata.name.get_Some_0(),
+        obj: update_user_status_to_degraded(user).marshal(),
+    });
+    let state_prime = RabbitmqUserReconcileState {
+        reconcile_step: RabbitmqUserReconcileStep::AfterUpdateDegradedStatus,
+        ..state
+    };
+    (state_prime, Some(RequestView::KRequest(req_o)))
+}
+
+}
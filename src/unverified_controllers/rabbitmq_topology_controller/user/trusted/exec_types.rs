@@ -0,0 +1,185 @@
+// Copyright 2022 VMware, Inc.
+// SPDX-License-Identifier: MIT
+use crate::kubernetes_api_objects::error::UnmarshalError;
+use crate::kubernetes_api_objects::exec::{
+    api_resource::*, condition::*, dynamic::*, object_meta::*, owner_reference::*, resource::*,
+};
+use crate::kubernetes_api_objects::spec::resource::*;
+use crate::rabbitmq_topology_controller::trusted::exec_types::RabbitmqClusterReference;
+use crate::rabbitmq_topology_controller::user::trusted::{
+    spec_types, spec_types::RabbitmqUserView, step::*,
+};
+use crate::vstd_ext::string_view::*;
+use deps_hack::kube::Resource;
+use vstd::prelude::*;
+
+verus! {
+
+pub struct RabbitmqUserReconcileState {
+    pub reconcile_step: RabbitmqUserReconcileStep,
+}
+
+impl std::clone::Clone for RabbitmqUserReconcileState {
+    #[verifier(external_body)]
+    fn clone(&self) -> (result: RabbitmqUserReconcileState)
+        ensures result == self
+    {
+        RabbitmqUserReconcileState {
+            reconcile_step: self.reconcile_step,
+        }
+    }
+}
+
+impl View for RabbitmqUserReconcileState {
+    type V = spec_types::RabbitmqUserReconcileState;
+    open spec fn view(&self) -> spec_types::RabbitmqUserReconcileState {
+        spec_types::RabbitmqUserReconcileState {
+            reconcile_step: self.reconcile_step,
+        }
+    }
+}
+
+#[verifier(external_body)]
+pub struct RabbitmqUser {
+    inner: deps_hack::RabbitmqUser
+}
+
+impl View for RabbitmqUser {
+    type V = spec_types::RabbitmqUserView;
+
+    spec fn view(&self) -> spec_types::RabbitmqUserView;
+}
+
+impl RabbitmqUser {
+    #[verifier(external_body)]
+    pub fn clone(&self) -> (user: Self)
+        ensures user@ == self@,
+    {
+        RabbitmqUser { inner: self.inner.clone() }
+    }
+
+    #[verifier(external_body)]
+    pub fn metadata(&self) -> (metadata: ObjectMeta)
+        ensures metadata@ == self@.metadata,
+    {
+        ObjectMeta::from_kube(self.inner.metadata.clone())
+    }
+
+    #[verifier(external_body)]
+    pub fn spec(&self) -> (spec: RabbitmqUserSpec)
+        ensures spec@ == self@.spec,
+    {
+        RabbitmqUserSpec { inner: self.inner.spec.clone() }
+    }
+
+    #[verifier(external_body)]
+    pub fn set_status(&mut self, status: RabbitmqUserStatus)
+        ensures self@ == old(self)@.set_status(status@),
+    {
+        let mut new_status = status.into_kube();
+        match (&mut new_status.conditions, self.inner.status.as_ref().and_then(|s| s.conditions.as_ref())) {
+            (Some(conditions), Some(old_conditions)) => preserve_transition_times(conditions, old_conditions),
+            _ => {},
+        }
+        self.inner.status = Some(new_status);
+    }
+
+    #[verifier(external_body)]
+    pub fn controller_owner_ref(&self) -> (owner_reference: OwnerReference)
+        ensures owner_reference@ == self@.controller_owner_ref(),
+    {
+        OwnerReference::from_kube(
+            // We can safely unwrap here because the trait method implementation always returns a Some(...)
+            self.inner.controller_owner_ref(&()).unwrap()
+        )
+    }
+
+    #[verifier(external_body)]
+    pub fn api_resource() -> (res: ApiResource)
+        ensures res@.kind == RabbitmqUserView::kind(),
+    {
+        ApiResource::from_kube(deps_hack::kube::api::ApiResource::erase::<deps_hack::RabbitmqUser>(&()))
+    }
+
+    // NOTE: This function assumes serde_json::to_string won't fail!
+    #[verifier(external_body)]
+    pub fn marshal(self) -> (obj: DynamicObject)
+        ensures obj@ == self@.marshal(),
+    {
+        // TODO: this might be unnecessarily slow
+        DynamicObject::from_kube(deps_hack::k8s_openapi::serde_json::from_str(&deps_hack::k8s_openapi::serde_json::to_string(&self.inner).unwrap()).unwrap())
+    }
+
+    #[verifier(external_body)]
+    pub fn unmarshal(obj: DynamicObject) -> (res: Result<RabbitmqUser, UnmarshalError>)
+        ensures
+            res.is_Ok() == RabbitmqUserView::unmarshal(obj@).is_Ok(),
+            res.is_Ok() ==> res.get_Ok_0()@ == RabbitmqUserView::unmarshal(obj@).get_Ok_0(),
+    {
+        let parse_result = obj.into_kube().try_parse::<deps_hack::RabbitmqUser>();
+        if parse_result.is_ok() {
+            let res = RabbitmqUser { inner: parse_result.unwrap() };
+            Ok(res)
+        } else {
+            Err(())
+        }
+    }
+}
+
+#[verifier(external)]
+impl ResourceWrapper<deps_hack::RabbitmqUser> for RabbitmqUser {
+    fn from_kube(inner: deps_hack::RabbitmqUser) -> RabbitmqUser { RabbitmqUser { inner: inner } }
+
+    fn into_kube(self) -> deps_hack::RabbitmqUser { self.inner }
+}
+
+#[verifier(external_body)]
+pub struct RabbitmqUserSpec {
+    inner: deps_hack::RabbitmqUserSpec,
+}
+
+impl RabbitmqUserSpec {
+    pub spec fn view(&self) -> spec_types::RabbitmqUserSpecView;
+
+    #[verifier(external_body)]
+    pub fn rabbitmq_cluster_reference(&self) -> (rabbitmq_cluster_reference: RabbitmqClusterReference)
+        ensures rabbitmq_cluster_reference@ == self@.rabbitmq_cluster_reference,
+    {
+        RabbitmqClusterReference::from_kube(self.inner.rabbitmq_cluster_reference.clone())
+    }
+
+    #[verifier(external_body)]
+    pub fn tags(&self) -> (tags: Vec<String>)
+        ensures tags@.map_values(|s: String| s@) == self@.tags,
+    {
+        self.inner.tags.clone()
+    }
+}
+
+#[verifier(external_body)]
+pub struct RabbitmqUserStatus {
+    inner: deps_hack::RabbitmqUserStatus,
+}
+
+impl RabbitmqUserStatus {
+    pub spec fn view(&self) -> spec_types::RabbitmqUserStatusView;
+
+    #[verifier(external_body)]
+    pub fn default() -> (status: RabbitmqUserStatus)
+        ensures status@ == spec_types::RabbitmqUserStatusView::default(),
+    {
+        RabbitmqUserStatus { inner: deps_hack::RabbitmqUserStatus::default() }
+    }
+
+    #[verifier(external_body)]
+    pub fn set_conditions(&mut self, conditions: Vec<Condition>)
+        ensures self@ == old(self)@.set_conditions(conditions@.map_values(|c: Condition| c@)),
+    {
+        self.inner.conditions = Some(into_kube_conditions(conditions))
+    }
+
+    #[verifier(external)]
+    fn into_kube(self) -> deps_hack::RabbitmqUserStatus { self.inner }
+}
+
+}
@@ -0,0 +1,184 @@
+// Copyright 2022 VMware, Inc.
+// SPDX-License-Identifier: MIT
+use crate::kubernetes_api_objects::error::UnmarshalError;
+use crate::kubernetes_api_objects::spec::{
+    common::*, condition::*, dynamic::*, object_meta::*, owner_reference::*, resource::*,
+};
+use crate::kubernetes_cluster::spec::{cluster::*, cluster_state_machine::*, message::*};
+use crate::rabbitmq_topology_controller::trusted::{
+    rabbitmq_topology_api_spec::*, spec_types::RabbitmqClusterReferenceView,
+};
+use crate::rabbitmq_topology_controller::user::trusted::step::*;
+use crate::vstd_ext::string_view::*;
+use vstd::prelude::*;
+
+verus! {
+
+pub type UserStep = Step<UserMessage>;
+
+pub type UserCluster = Cluster<RabbitmqUserView, RabbitmqTopologyAPI, RabbitmqUserReconciler>;
+
+pub type UserMessage = Message<RabbitmqTopologyAPIInputView, RabbitmqTopologyAPIOutputView>;
+
+pub struct RabbitmqUserReconciler {}
+
+pub struct RabbitmqUserReconcileState {
+    pub reconcile_step: RabbitmqUserReconcileStep,
+}
+
+pub struct RabbitmqUserView {
+    pub metadata: ObjectMetaView,
+    pub spec: RabbitmqUserSpecView,
+    pub status: Option<RabbitmqUserStatusView>,
+}
+
+impl RabbitmqUserView {
+    pub open spec fn well_formed(self) -> bool {
+        &&& self.metadata.name.is_Some()
+        &&& self.metadata.namespace.is_Some()
+        &&& self.metadata.uid.is_Some()
+    }
+
+    pub open spec fn controller_owner_ref(self) -> OwnerReferenceView {
+        OwnerReferenceView {
+            block_owner_deletion: None,
+            controller: Some(true),
+            kind: Self::kind(),
+            name: self.metadata.name.get_Some_0(),
+            uid: self.metadata.uid.get_Some_0(),
+        }
+    }
+
+    pub open spec fn set_status(self, status: RabbitmqUserStatusView) -> RabbitmqUserView {
+        RabbitmqUserView {
+            status: Some(status),
+            ..self
+        }
+    }
+}
+
+impl ResourceView for RabbitmqUserView {
+    type Spec = RabbitmqUserSpecView;
+    type Status = Option<RabbitmqUserStatusView>;
+
+    open spec fn default() -> RabbitmqUserView {
+        RabbitmqUserView {
+            metadata: ObjectMetaView::default(),
+            spec: arbitrary(), // TODO: specify the default value for spec
+            status: None,
+        }
+    }
+
+    open spec fn metadata(self) -> ObjectMetaView { self.metadata }
+
+    open spec fn kind() -> Kind { Kind::CustomResourceKind("rabbitmquser"@) }
+
+    open spec fn object_ref(self) -> ObjectRef {
+        ObjectRef {
+            kind: Self::kind(),
+            name: self.metadata.name.get_Some_0(),
+            namespace: self.metadata.namespace.get_Some_0(),
+        }
+    }
+
+    proof fn object_ref_is_well_formed() {}
+
+    open spec fn spec(self) -> RabbitmqUserSpecView { self.spec }
+
+    open spec fn status(self) -> Option<RabbitmqUserStatusView> { self.status }
+
+    open spec fn marshal(self) -> DynamicObjectView {
+        DynamicObjectView {
+            kind: Self::kind(),
+            metadata: self.metadata,
+            spec: RabbitmqUserView::marshal_spec(self.spec),
+            status: RabbitmqUserView::marshal_status(self.status),
+        }
+    }
+
+    open spec fn unmarshal(obj: DynamicObjectView) -> Result<RabbitmqUserView, UnmarshalError> {
+        if obj.kind != Self::kind() {
+            Err(())
+        } else if !RabbitmqUserView::unmarshal_spec(obj.spec).is_Ok() {
+            Err(())
+        } else if !RabbitmqUserView::unmarshal_status(obj.status).is_Ok() {
+            Err(())
+        } else {
+            Ok(RabbitmqUserView {
+                metadata: obj.metadata,
+                spec: RabbitmqUserView::unmarshal_spec(obj.spec).get_Ok_0(),
+                status: RabbitmqUserView::unmarshal_status(obj.status).get_Ok_0(),
+            })
+        }
+    }
+
+    proof fn marshal_preserves_integrity() {
+        RabbitmqUserView::marshal_spec_preserves_integrity();
+        RabbitmqUserView::marshal_status_preserves_integrity();
+    }
+
+    proof fn marshal_preserves_metadata() {}
+
+    proof fn marshal_preserves_kind() {}
+
+    closed spec fn marshal_spec(s: RabbitmqUserSpecView) -> Value;
+
+    closed spec fn unmarshal_spec(v: Value) -> Result<RabbitmqUserSpecView, UnmarshalError>;
+
+    closed spec fn marshal_status(s: Option<RabbitmqUserStatusView>) -> Value;
+
+    closed spec fn unmarshal_status(v: Value) -> Result<Option<RabbitmqUserStatusView>, UnmarshalError>;
+
+    #[verifier(external_body)]
+    proof fn marshal_spec_preserves_integrity() {}
+
+    #[verifier(external_body)]
+    proof fn marshal_status_preserves_integrity() {}
+
+    proof fn unmarshal_result_determined_by_unmarshal_spec_and_status() {}
+
+    open spec fn state_validation(self) -> bool { true }
+
+    // The user is never deleted from the broker, so moving it to another rabbitmq cluster would leave the old user behind.
+    open spec fn transition_validation(self, old_obj: RabbitmqUserView) -> bool {
+        self.spec.rabbitmq_cluster_reference == old_obj.spec.rabbitmq_cluster_reference
+    }
+}
+
+impl CustomResourceView for RabbitmqUserView {
+    proof fn kind_is_custom_resource() {}
+
+    open spec fn is_namespaced() -> bool { true }
+
+    open spec fn spec_status_validation(obj_spec: Self::Spec, obj_status: Self::Status) -> bool { true }
+
+    proof fn validation_result_determined_by_spec_and_status()
+        ensures forall |obj: Self| #[trigger] obj.state_validation() == Self::spec_status_validation(obj.spec(), obj.status())
+    {}
+}
+
+pub struct RabbitmqUserSpecView {
+    pub rabbitmq_cluster_reference: RabbitmqClusterReferenceView,
+    pub tags: Seq<StringView>,
+}
+
+pub struct RabbitmqUserStatusView {
+    pub conditions: Option<Seq<ConditionView>>,
+}
+
+impl RabbitmqUserStatusView {
+    pub open spec fn default() -> RabbitmqUserStatusView {
+        RabbitmqUserStatusView {
+            conditions: None,
+        }
+    }
+
+    pub open spec fn set_conditions(self, conditions: Seq<ConditionView>) -> RabbitmqUserStatusView {
+        RabbitmqUserStatusView {
+            conditions: Some(conditions),
+            ..self
+        }
+    }
+}
+
+}
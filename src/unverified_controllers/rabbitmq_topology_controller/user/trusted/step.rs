@@ -0,0 +1,57 @@
+// Copyright 2022 VMware, Inc.
+// SPDX-License-Identifier: MIT
+#![allow(unused_imports)]
+use vstd::prelude::*;
+
+verus! {
+
+#[is_variant]
+pub enum RabbitmqUserReconcileStep {
+    Init,
+    AfterKRequestStep(ActionKind, SubResource),
+    AfterPutUser,
+    AfterUpdateStatus,
+    AfterUpdateDegradedStatus,
+    Done,
+    Error,
+}
+
+impl std::marker::Copy for RabbitmqUserReconcileStep {}
+
+impl std::clone::Clone for RabbitmqUserReconcileStep {
+    #[verifier(external_body)]
+    fn clone(&self) -> (result: Self)
+        ensures result == self
+    { *self }
+}
+
+pub enum SubResource {
+    CredentialsSecret,
+}
+
+impl std::marker::Copy for SubResource {}
+
+impl std::clone::Clone for SubResource {
+    #[verifier(external_body)]
+    fn clone(&self) -> (result: Self)
+        ensures result == self
+    { *self }
+}
+
+// The credentials secret is never updated: the password is generated once and kept as long as the secret exists.
+#[is_variant]
+pub enum ActionKind {
+    Get,
+    Create,
+}
+
+impl std::marker::Copy for ActionKind {}
+
+impl std::clone::Clone for ActionKind {
+    #[verifier(external_body)]
+    fn clone(&self) -> (result: Self)
+        ensures result == self
+    { *self }
+}
+
+}
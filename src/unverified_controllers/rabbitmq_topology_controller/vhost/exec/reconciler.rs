@@ -0,0 +1,179 @@
+// Copyright 2022 VMware, Inc.
+// SPDX-License-Identifier: MIT
+#![allow(unused_imports)]
+use crate::external_api::exec::*;
+use crate::kubernetes_api_objects::exec::prelude::*;
+use crate::kubernetes_api_objects::exec::{condition::*, resource::ResourceWrapper};
+use crate::rabbitmq_topology_controller::trusted::rabbitmq_topology_api_exec::*;
+use crate::rabbitmq_topology_controller::vhost::model::reconciler as model_reconciler;
+use crate::rabbitmq_topology_controller::vhost::trusted::{exec_types::*, spec_types, step::*};
+use crate::reconciler::exec::{io::*, reconciler::*};
+use crate::vstd_ext::string_view::*;
+use vstd::{prelude::*, string::*};
+
+verus! {
+
+pub struct RabbitmqVhostReconciler {}
+
+impl Reconciler for RabbitmqVhostReconciler {
+    type R = RabbitmqVhost;
+    type T = RabbitmqVhostReconcileState;
+    type ExternalAPIType = RabbitmqTopologyAPIShimLayer;
+
+    open spec fn well_formed(vhost: &RabbitmqVhost) -> bool { vhost@.well_formed() }
+
+    fn reconcile_init_state() -> RabbitmqVhostReconcileState {
+        reconcile_init_state()
+    }
+
+    fn reconcile_core(vhost: &RabbitmqVhost, resp_o: Option<Response<RabbitmqTopologyAPIOutput>>, state: RabbitmqVhostReconcileState)
+    -> (RabbitmqVhostReconcileState, Option<Request<RabbitmqTopologyAPIInput>>) {
+        reconcile_core(vhost, resp_o, state)
+    }
+
+    fn reconcile_done(state: &RabbitmqVhostReconcileState) -> bool {
+        reconcile_done(state)
+    }
+
+    fn reconcile_error(state: &RabbitmqVhostReconcileState) -> bool {
+        reconcile_error(state)
+    }
+}
+
+pub fn reconcile_init_state() -> (state: RabbitmqVhostReconcileState)
+    ensures state@ == model_reconciler::reconcile_init_state(),
+{
+    RabbitmqVhostReconcileState { reconcile_step: RabbitmqVhostReconcileStep::Init }
+}
+
+pub fn reconcile_done(state: &RabbitmqVhostReconcileState) -> (res: bool)
+    ensures res == model_reconciler::reconcile_done(state@),
+{
+    match state.reconcile_step {
+        RabbitmqVhostReconcileStep::Done => true,
+        _ => false,
+    }
+}
+
+pub fn reconcile_error(state: &RabbitmqVhostReconcileState) -> (res: bool)
+    ensures res == model_reconciler::reconcile_error(state@),
+{
+    match state.reconcile_step {
+        RabbitmqVhostReconcileStep::Error => true,
+        _ => false,
+    }
+}
+
+pub fn reconcile_core(vhost: &RabbitmqVhost, resp_o: Option<Response<RabbitmqTopologyAPIOutput>>, state: RabbitmqVhostReconcileState) -> (res: (RabbitmqVhostReconcileState, Option<Request<RabbitmqTopologyAPIInput>>))
+    requires vhost@.well_formed(),
+    ensures (res.0@, opt_request_to_view(&res.1)) == model_reconciler::reconcile_core(vhost@, opt_response_to_view(&resp_o), state@),
+{
+    let step = state.reconcile_step;
+    match step {
+        RabbitmqVhostReconcileStep::Init => {
+            let ext_req = RabbitmqTopologyAPIInput::PutVhostRequest(
+                vhost.spec().rabbitmq_cluster_reference().name(), vhost.metadata().namespace().unwrap(), vhost.spec().name(), vhost.spec().tracing()
+            );
+            let state_prime = RabbitmqVhostReconcileState {
+                reconcile_step: RabbitmqVhostReconcileStep::AfterPutVhost,
+                ..state
+            };
+            return (state_prime, Some(Request::ExternalRequest(ext_req)));
+        },
+        RabbitmqVhostReconcileStep::AfterPutVhost => {
+            if resp_o.is_some() && resp_o.as_ref().unwrap().is_external_response()
+            && resp_o.as_ref().unwrap().as_external_response_ref().is_put_vhost_response() {
+                let put_vhost_resp = resp_o.unwrap().into_external_response().unwrap_put_vhost_response().res;
+                if put_vhost_resp.is_ok() {
+                    let req_o = KubeAPIRequest::UpdateStatusRequest(KubeUpdateStatusRequest {
+                        api_resource: RabbitmqVhost::api_resource(),
+                        name: vhost.metadata().name().unwrap(),
+                        namespace: vhost.metadata().namespace().unwrap(),
+                        obj: update_vhost_status(vhost).marshal(),
+                    });
+                    let state_prime = RabbitmqVhostReconcileState {
+                        reconcile_step: RabbitmqVhostReconcileStep::AfterUpdateStatus,
+                        ..state
+                    };
+                    return (state_prime, Some(Request::KRequest(req_o)));
+                }
+            }
+            return report_error(vhost, state);
+        },
+        RabbitmqVhostReconcileStep::AfterUpdateStatus => {
+            if resp_o.is_some() && resp_o.as_ref().unwrap().is_k_response()
+            && resp_o.as_ref().unwrap().as_k_response_ref().is_update_status_response() {
+                let update_status_resp = resp_o.unwrap().into_k_response().into_update_status_response().res;
+                if update_status_resp.is_ok() {
+                    let state_prime = RabbitmqVhostReconcileState {
+                        reconcile_step: RabbitmqVhostReconcileStep::Done,
+                        ..state
+                    };
+                    return (state_prime, None);
+                }
+            }
+            let state_prime = RabbitmqVhostReconcileState {
+                reconcile_step: RabbitmqVhostReconcileStep::Error,
+                ..state
+            };
+            return (state_prime, None);
+        },
+        RabbitmqVhostReconcileStep::AfterUpdateDegradedStatus => {
+            let state_prime = RabbitmqVhostReconcileState {
+                reconcile_step: RabbitmqVhostReconcileStep::Error,
+                ..state
+            };
+            return (state_prime, None);
+        },
+        _ => {
+            let state_prime = RabbitmqVhostReconcileState {
+                reconcile_step: step,
+                ..state
+            };
+            return (state_prime, None);
+        }
+    }
+}
+
+pub fn update_vhost_status(vhost: &RabbitmqVhost) -> (updated_vhost: RabbitmqVhost)
+    ensures updated_vhost@ == model_reconciler::update_vhost_status(vhost@),
+{
+    let mut updated_vhost = vhost.clone();
+    updated_vhost.set_status({
+        let mut status = RabbitmqVhostStatus::default();
+        status.set_conditions(make_conditions(true, false, vhost.metadata().generation()));
+        status
+    });
+    updated_vhost
+}
+
+pub fn update_vhost_status_to_degraded(vhost: &RabbitmqVhost) -> (updated_vhost: RabbitmqVhost)
+    ensures updated_vhost@ == model_reconciler::update_vhost_status_to_degraded(vhost@),
+{
+    let mut updated_vhost = vhost.clone();
+    updated_vhost.set_status({
+        let mut status = RabbitmqVhostStatus::default();
+        status.set_conditions(make_conditions(false, true, vhost.metadata().generation()));
+        status
+    });
+    updated_vhost
+}
+
+pub fn report_error(vhost: &RabbitmqVhost, state: RabbitmqVhostReconcileState) -> (res: (RabbitmqVhostReconcileState, Option<Request<RabbitmqTopologyAPIInput>>))
+    requires vhost@.well_formed(),
+    ensures (res.0@, opt_request_to_view(&res.1)) == model_reconciler::report_error(vhost@, state@),
+{
+    let req_o = KubeAPIRequest::UpdateStatusRequest(KubeUpdateStatusRequest {
+        api_resource: RabbitmqVhost::api_resource(),
+        name: vhost.metadata().name().unwrap(),
+        namespace: vhost.metadata().namespace().unwrap(),
+        obj: update_vhost_status_to_degraded(vhost).marshal(),
+    });
+    let state_prime = RabbitmqVhostReconcileState {
+        reconcile_step: RabbitmqVhostReconcileStep::AfterUpdateDegradedStatus,
+        ..state
+    };
+    (state_prime, Some(Request::KRequest(req_o)))
+}
+
+}
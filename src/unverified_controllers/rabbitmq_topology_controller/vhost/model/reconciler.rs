@@ -0,0 +1,148 @@
+// Copyright 2022 VMware, Inc.
+// SPDX-License-Identifier: MIT
+#![allow(unused_imports)]
+use crate::external_api::spec::*;
+use crate::kubernetes_api_objects::spec::{condition::*, prelude::*};
+use crate::rabbitmq_topology_controller::trusted::rabbitmq_topology_api_spec::*;
+use crate::rabbitmq_topology_controller::vhost::trusted::{spec_types::*, step::*};
+use crate::reconciler::spec::{io::*, reconciler::*};
+use crate::vstd_ext::string_view::*;
+use vstd::{prelude::*, string::*};
+
+verus! {
+
+impl Reconciler<RabbitmqVhostView, RabbitmqTopologyAPI> for RabbitmqVhostReconciler {
+    type T = RabbitmqVhostReconcileState;
+
+    open spec fn reconcile_init_state() -> RabbitmqVhostReconcileState {
+        reconcile_init_state()
+    }
+
+    open spec fn reconcile_core(vhost: RabbitmqVhostView, resp_o: Option<ResponseView<RabbitmqTopologyAPIOutputView>>, state: RabbitmqVhostReconcileState)
+    -> (RabbitmqVhostReconcileState, Option<RequestView<RabbitmqTopologyAPIInputView>>) {
+        reconcile_core(vhost, resp_o, state)
+    }
+
+    open spec fn reconcile_done(state: RabbitmqVhostReconcileState) -> bool {
+        reconcile_done(state)
+    }
+
+    open spec fn reconcile_error(state: RabbitmqVhostReconcileState) -> bool {
+        reconcile_error(state)
+    }
+
+    open spec fn expect_from_user(obj: DynamicObjectView) -> bool {
+        false /* Don't expect anything from the user except the cr object */
+    }
+}
+
+pub open spec fn reconcile_init_state() -> RabbitmqVhostReconcileState { RabbitmqVhostReconcileState { reconcile_step: RabbitmqVhostReconcileStep::Init } }
+
+pub open spec fn reconcile_done(state: RabbitmqVhostReconcileState) -> bool {
+    match state.reconcile_step {
+        RabbitmqVhostReconcileStep::Done => true,
+        _ => false,
+    }
+}
+
+pub open spec fn reconcile_error(state: RabbitmqVhostReconcileState) -> bool {
+    match state.reconcile_step {
+        RabbitmqVhostReconcileStep::Error => true,
+        _ => false,
+    }
+}
+
+pub open spec fn reconcile_core(
+    vhost: RabbitmqVhostView, resp_o: Option<ResponseView<RabbitmqTopologyAPIOutputView>>, state: RabbitmqVhostReconcileState
+) -> (RabbitmqVhostReconcileState, Option<RequestView<RabbitmqTopologyAPIInputView>>) {
+    let step = state.reconcile_step;
+    let resp = resp_o.get_Some_0();
+    match step {
+        RabbitmqVhostReconcileStep::Init => {
+            let state_prime = RabbitmqVhostReconcileState {
+                reconcile_step: RabbitmqVhostReconcileStep::AfterPutVhost,
+                ..state
+            };
+            (state_prime, Some(RequestView::ExternalRequest(put_vhost_request(vhost))))
+        },
+        RabbitmqVhostReconcileStep::AfterPutVhost => {
+            if resp_o.is_Some() && resp.is_ExternalResponse() && resp.get_ExternalResponse_0().is_PutVhostResponse()
+            && resp.get_ExternalResponse_0().get_PutVhostResponse_0().res.is_Ok() {
+                let req_o = APIRequest::UpdateStatusRequest(UpdateStatusRequest {
+                    namespace: vhost.metadata.namespace.get_Some_0(),
+                    name: vhost.metadata.name.get_Some_0(),
+                    obj: update_vhost_status(vhost).marshal(),
+                });
+                let state_prime = RabbitmqVhostReconcileState {
+                    reconcile_step: RabbitmqVhostReconcileStep::AfterUpdateStatus,
+                    ..state
+                };
+                (state_prime, Some(RequestView::KRequest(req_o)))
+            } else {
+                report_error(vhost, state)
+            }
+        },
+        RabbitmqVhostReconcileStep::AfterUpdateStatus => {
+            let update_status_resp = resp.get_KResponse_0().get_UpdateStatusResponse_0().res;
+            if resp_o.is_Some() && resp.is_KResponse() && resp.get_KResponse_0().is_UpdateStatusResponse()
+            && update_status_resp.is_Ok() {
+                let state_prime = RabbitmqVhostReconcileState {
+                    reconcile_step: RabbitmqVhostReconcileStep::Done,
+                    ..state
+                };
+                (state_prime, None)
+            } else {
+                let state_prime = RabbitmqVhostReconcileState {
+                    reconcile_step: RabbitmqVhostReconcileStep::Error,
+                    ..state
+                };
+                (state_prime, None)
+            }
+        },
+        RabbitmqVhostReconcileStep::AfterUpdateDegradedStatus => {
+            let state_prime = RabbitmqVhostReconcileState {
+                reconcile_step: RabbitmqVhostReconcileStep::Error,
+                ..state
+            };
+            (state_prime, None)
+        },
+        _ => {
+            let state_prime = RabbitmqVhostReconcileState {
+                reconcile_step: step,
+                ..state
+            };
+            (state_prime, None)
+        }
+    }
+}
+
+pub open spec fn put_vhost_request(vhost: RabbitmqVhostView) -> RabbitmqTopologyAPIInputView {
+    RabbitmqTopologyAPIInputView::PutVhostRequest(
+        vhost.spec.rabbitmq_cluster_reference.name, vhost.metadata.namespace.get_Some_0(), vhost.spec.name, vhost.spec.tracing
+    )
+}
+
+// The vhost is ready as soon as the broker accepts it.
+pub open spec fn update_vhost_status(vhost: RabbitmqVhostView) -> RabbitmqVhostView {
+    vhost.set_status(RabbitmqVhostStatusView::default().set_conditions(make_conditions(true, false, vhost.metadata.generation)))
+}
+
+pub open spec fn update_vhost_status_to_degraded(vhost: RabbitmqVhostView) -> RabbitmqVhostView {
+    vhost.set_status(RabbitmqVhostStatusView::default().set_conditions(make_conditions(false, true, vhost.metadata.generation)))
+}
+
+// report_error marks the vhost as degraded before the reconcile ends in error.
+pub open spec fn report_error(vhost: RabbitmqVhostView, state: RabbitmqVhostReconcileState) -> (RabbitmqVhostReconcileState, Option<RequestView<RabbitmqTopologyAPIInputView>>) {
+    let req_o = APIRequest::UpdateStatusRequest(UpdateStatusRequest {
+        namespace: vhost.metadata.namespace.get_Some_0(),
+        name: vhost.metadata.name.get_Some_0(),
+        obj: update_vhost_status_to_degraded(vhost).marshal(),
+    });
+    let state_prime = RabbitmqVhostReconcileState {
+        reconcile_step: RabbitmqVhostReconcileStep::AfterUpdateDegradedStatus,
+        ..state
+    };
+    (state_prime, Some(RequestView::KRequest(req_o)))
+}
+
+}
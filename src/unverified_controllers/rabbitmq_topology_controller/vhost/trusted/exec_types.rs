@@ -0,0 +1,181 @@
+// Copyright 2022 VMware, Inc.
+// SPDX-License-Identifier: MIT
+use crate::kubernetes_api_objects::error::UnmarshalError;
+use crate::kubernetes_api_objects::exec::{
+    api_resource::*, condition::*, dynamic::*, object_meta::*, resource::*,
+};
+use crate::kubernetes_api_objects::spec::resource::*;
+use crate::rabbitmq_topology_controller::trusted::exec_types::RabbitmqClusterReference;
+use crate::rabbitmq_topology_controller::vhost::trusted::{
+    spec_types, spec_types::RabbitmqVhostView, step::*,
+};
+use crate::vstd_ext::string_view::*;
+use vstd::prelude::*;
+
+verus! {
+
+pub struct RabbitmqVhostReconcileState {
+    pub reconcile_step: RabbitmqVhostReconcileStep,
+}
+
+impl std::clone::Clone for RabbitmqVhostReconcileState {
+    #[verifier(external_body)]
+    fn clone(&self) -> (result: RabbitmqVhostReconcileState)
+        ensures result == self
+    {
+        RabbitmqVhostReconcileState {
+            reconcile_step: self.reconcile_step,
+        }
+    }
+}
+
+impl View for RabbitmqVhostReconcileState {
+    type V = spec_types::RabbitmqVhostReconcileState;
+    open spec fn view(&self) -> spec_types::RabbitmqVhostReconcileState {
+        spec_types::RabbitmqVhostReconcileState {
+            reconcile_step: self.reconcile_step,
+        }
+    }
+}
+
+#[verifier(external_body)]
+pub struct RabbitmqVhost {
+    inner: deps_hack::RabbitmqVhost
+}
+
+impl View for RabbitmqVhost {
+    type V = spec_types::RabbitmqVhostView;
+
+    spec fn view(&self) -> spec_types::RabbitmqVhostView;
+}
+
+impl RabbitmqVhost {
+    #[verifier(external_body)]
+    pub fn clone(&self) -> (vhost: Self)
+        ensures vhost@ == self@,
+    {
+        RabbitmqVhost { inner: self.inner.clone() }
+    }
+
+    #[verifier(external_body)]
+    pub fn metadata(&self) -> (metadata: ObjectMeta)
+        ensures metadata@ == self@.metadata,
+    {
+        ObjectMeta::from_kube(self.inner.metadata.clone())
+    }
+
+    #[verifier(external_body)]
+    pub fn spec(&self) -> (spec: RabbitmqVhostSpec)
+        ensures spec@ == self@.spec,
+    {
+        RabbitmqVhostSpec { inner: self.inner.spec.clone() }
+    }
+
+    #[verifier(external_body)]
+    pub fn set_status(&mut self, status: RabbitmqVhostStatus)
+        ensures self@ == old(self)@.set_status(status@),
+    {
+        let mut new_status = status.into_kube();
+        match (&mut new_status.conditions, self.inner.status.as_ref().and_then(|s| s.conditions.as_ref())) {
+            (Some(conditions), Some(old_conditions)) => preserve_transition_times(conditions, old_conditions),
+            _ => {},
+        }
+        self.inner.status = Some(new_status);
+    }
+
+    #[verifier(external_body)]
+    pub fn api_resource() -> (res: ApiResource)
+        ensures res@.kind == RabbitmqVhostView::kind(),
+    {
+        ApiResource::from_kube(deps_hack::kube::api::ApiResource::erase::<deps_hack::RabbitmqVhost>(&()))
+    }
+
+    // NOTE: This function assumes serde_json::to_string won't fail!
+    #[verifier(external_body)]
+    pub fn marshal(self) -> (obj: DynamicObject)
+        ensures obj@ == self@.marshal(),
+    {
+        // TODO: this might be unnecessarily slow
+        DynamicObject::from_kube(deps_hack::k8s_openapi::serde_json::from_str(&deps_hack::k8s_openapi::serde_json::to_string(&self.inner).unwrap()).unwrap())
+    }
+
+    #[verifier(external_body)]
+    pub fn unmarshal(obj: DynamicObject) -> (res: Result<RabbitmqVhost, UnmarshalError>)
+        ensures
+            res.is_Ok() == RabbitmqVhostView::unmarshal(obj@).is_Ok(),
+            res.is_Ok() ==> res.get_Ok_0()@ == RabbitmqVhostView::unmarshal(obj@).get_Ok_0(),
+    {
+        let parse_result = obj.into_kube().try_parse::<deps_hack::RabbitmqVhost>();
+        if parse_result.is_ok() {
+            let res = RabbitmqVhost { inner: parse_result.unwrap() };
+            Ok(res)
+        } else {
+            Err(())
+        }
+    }
+}
+
+#[verifier(external)]
+impl ResourceWrapper<deps_hack::RabbitmqVhost> for RabbitmqVhost {
+    fn from_kube(inner: deps_hack::RabbitmqVhost) -> RabbitmqVhost { RabbitmqVhost { inner: inner } }
+
+    fn into_kube(self) -> deps_hack::RabbitmqVhost { self.inner }
+}
+
+#[verifier(external_body)]
+pub struct RabbitmqVhostSpec {
+    inner: deps_hack::RabbitmqVhostSpec,
+}
+
+impl RabbitmqVhostSpec {
+    pub spec fn view(&self) -> spec_types::RabbitmqVhostSpecView;
+
+    #[verifier(external_body)]
+    pub fn name(&self) -> (name: String)
+        ensures name@ == self@.name,
+    {
+        self.inner.name.clone()
+    }
+
+    #[verifier(external_body)]
+    pub fn rabbitmq_cluster_reference(&self) -> (rabbitmq_cluster_reference: RabbitmqClusterReference)
+        ensures rabbitmq_cluster_reference@ == self@.rabbitmq_cluster_reference,
+    {
+        RabbitmqClusterReference::from_kube(self.inner.rabbitmq_cluster_reference.clone())
+    }
+
+    #[verifier(external_body)]
+    pub fn tracing(&self) -> (tracing: bool)
+        ensures tracing == self@.tracing,
+    {
+        self.inner.tracing
+    }
+}
+
+#[verifier(external_body)]
+pub struct RabbitmqVhostStatus {
+    inner: deps_hack::RabbitmqVhostStatus,
+}
+
+impl RabbitmqVhostStatus {
+    pub spec fn view(&self) -> spec_types::RabbitmqVhostStatusView;
+
+    #[verifier(external_body)]
+    pub fn default() -> (status: RabbitmqVhostStatus)
+        ensures status@ == spec_types::RabbitmqVhostStatusView::default(),
+    {
+        RabbitmqVhostStatus { inner: deps_hack::RabbitmqVhostStatus::default() }
+    }
+
+    #[verifier(external_body)]
+    pub fn set_conditions(&mut self, conditions: Vec<Condition>)
+        ensures self@ == old(self)@.set_conditions(conditions@.map_values(|c: Condition| c@)),
+    {
+        self.inner.conditions = Some(into_kube_conditions(conditions))
+    }
+
+    #[verifier(external)]
+    fn into_kube(self) -> deps_hack::RabbitmqVhostStatus { self.inner }
+}
+
+}
@@ -0,0 +1,177 @@
+// Copyright 2022 VMware, Inc.
+// SPDX-License-Identifier: MIT
+use crate::kubernetes_api_objects::error::UnmarshalError;
+use crate::kubernetes_api_objects::spec::{
+    common::*, condition::*, dynamic::*, object_meta::*, owner_reference::*, resource::*,
+};
+use crate::kubernetes_cluster::spec::{cluster::*, cluster_state_machine::*, message::*};
+use crate::rabbitmq_topology_controller::trusted::{
+    rabbitmq_topology_api_spec::*, spec_types::RabbitmqClusterReferenceView,
+};
+use crate::rabbitmq_topology_controller::vhost::trusted::step::*;
+use crate::vstd_ext::string_view::*;
+use vstd::prelude::*;
+
+verus! {
+
+pub type VhostStep = Step<VhostMessage>;
+
+pub type VhostCluster = Cluster<RabbitmqVhostView, RabbitmqTopologyAPI, RabbitmqVhostReconciler>;
+
+pub type VhostMessage = Message<RabbitmqTopologyAPIInputView, RabbitmqTopologyAPIOutputView>;
+
+pub struct RabbitmqVhostReconciler {}
+
+pub struct RabbitmqVhostReconcileState {
+    pub reconcile_step: RabbitmqVhostReconcileStep,
+}
+
+pub struct RabbitmqVhostView {
+    pub metadata: ObjectMetaView,
+    pub spec: RabbitmqVhostSpecView,
+    pub status: Option<RabbitmqVhostStatusView>,
+}
+
+impl RabbitmqVhostView {
+    pub open spec fn well_formed(self) -> bool {
+        &&& self.metadata.name.is_Some()
+        &&& self.metadata.namespace.is_Some()
+        &&& self.metadata.uid.is_Some()
+    }
+
+    pub open spec fn set_status(self, status: RabbitmqVhostStatusView) -> RabbitmqVhostView {
+        RabbitmqVhostView {
+            status: Some(status),
+            ..self
+        }
+    }
+}
+
+impl ResourceView for RabbitmqVhostView {
+    type Spec = RabbitmqVhostSpecView;
+    type Status = Option<RabbitmqVhostStatusView>;
+
+    open spec fn default() -> RabbitmqVhostView {
+        RabbitmqVhostView {
+            metadata: ObjectMetaView::default(),
+            spec: arbitrary(), // TODO: specify the default value for spec
+            status: None,
+        }
+    }
+
+    open spec fn metadata(self) -> ObjectMetaView { self.metadata }
+
+    open spec fn kind() -> Kind { Kind::CustomResourceKind("rabbitmqvhost"@) }
+
+    open spec fn object_ref(self) -> ObjectRef {
+        ObjectRef {
+            kind: Self::kind(),
+            name: self.metadata.name.get_Some_0(),
+            namespace: self.metadata.namespace.get_Some_0(),
+        }
+    }
+
+    proof fn object_ref_is_well_formed() {}
+
+    open spec fn spec(self) -> RabbitmqVhostSpecView { self.spec }
+
+    open spec fn status(self) -> Option<RabbitmqVhostStatusView> { self.status }
+
+    open spec fn marshal(self) -> DynamicObjectView {
+        DynamicObjectView {
+            kind: Self::kind(),
+            metadata: self.metadata,
+            spec: RabbitmqVhostView::marshal_spec(self.spec),
+            status: RabbitmqVhostView::marshal_status(self.status),
+        }
+    }
+
+    open spec fn unmarshal(obj: DynamicObjectView) -> Result<RabbitmqVhostView, UnmarshalError> {
+        if obj.kind != Self::kind() {
+            Err(())
+        } else if !RabbitmqVhostView::unmarshal_spec(obj.spec).is_Ok() {
+            Err(())
+        } else if !RabbitmqVhostView::unmarshal_status(obj.status).is_Ok() {
+            Err(())
+        } else {
+            Ok(RabbitmqVhostView {
+                metadata: obj.metadata,
+                spec: RabbitmqVhostView::unmarshal_spec(obj.spec).get_Ok_0(),
+                status: RabbitmqVhostView::unmarshal_status(obj.status).get_Ok_0(),
+            })
+        }
+    }
+
+    proof fn marshal_preserves_integrity() {
+        RabbitmqVhostView::marshal_spec_preserves_integrity();
+        RabbitmqVhostView::marshal_status_preserves_integrity();
+    }
+
+    proof fn marshal_preserves_metadata() {}
+
+    proof fn marshal_preserves_kind() {}
+
+    closed spec fn marshal_spec(s: RabbitmqVhostSpecView) -> Value;
+
+    closed spec fn unmarshal_spec(v: Value) -> Result<RabbitmqVhostSpecView, UnmarshalError>;
+
+    closed spec fn marshal_status(s: Option<RabbitmqVhostStatusView>) -> Value;
+
+    closed spec fn unmarshal_status(v: Value) -> Result<Option<RabbitmqVhostStatusView>, UnmarshalError>;
+
+    #[verifier(external_body)]
+    proof fn marshal_spec_preserves_integrity() {}
+
+    #[verifier(external_body)]
+    proof fn marshal_status_preserves_integrity() {}
+
+    proof fn unmarshal_result_determined_by_unmarshal_spec_and_status() {}
+
+    open spec fn state_validation(self) -> bool { true }
+
+    // The vhost is never deleted from the broker, so renaming the vhost or moving it to another
+    // rabbitmq cluster would leave the old vhost behind.
+    open spec fn transition_validation(self, old_obj: RabbitmqVhostView) -> bool {
+        &&& self.spec.name == old_obj.spec.name
+        &&& self.spec.rabbitmq_cluster_reference == old_obj.spec.rabbitmq_cluster_reference
+    }
+}
+
+impl CustomResourceView for RabbitmqVhostView {
+    proof fn kind_is_custom_resource() {}
+
+    open spec fn is_namespaced() -> bool { true }
+
+    open spec fn spec_status_validation(obj_spec: Self::Spec, obj_status: Self::Status) -> bool { true }
+
+    proof fn validation_result_determined_by_spec_and_status()
+        ensures forall |obj: Self| #[trigger] obj.state_validation() == Self::spec_status_validation(obj.spec(), obj.status())
+    {}
+}
+
+pub struct RabbitmqVhostSpecView {
+    pub name: StringView,
+    pub rabbitmq_cluster_reference: RabbitmqClusterReferenceView,
+    pub tracing: bool,
+}
+
+pub struct RabbitmqVhostStatusView {
+    pub conditions: Option<Seq<ConditionView>>,
+}
+
+impl RabbitmqVhostStatusView {
+    pub open spec fn default() -> RabbitmqVhostStatusView {
+        RabbitmqVhostStatusView {
+            conditions: None,
+        }
+    }
+
+    pub open spec fn set_conditions(self, conditions: Seq<ConditionView>) -> RabbitmqVhostStatusView {
+        RabbitmqVhostStatusView {
+            conditions: Some(conditions),
+            ..self
+        }
+    }
+}
+
+}
@@ -0,0 +1,27 @@
+// Copyright 2022 VMware, Inc.
+// SPDX-License-Identifier: MIT
+#![allow(unused_imports)]
+use vstd::prelude::*;
+
+verus! {
+
+#[is_variant]
+pub enum RabbitmqVhostReconcileStep {
+    Init,
+    AfterPutVhost,
+    AfterUpdateStatus,
+    AfterUpdateDegradedStatus,
+    Done,
+    Error,
+}
+
+impl std::marker::Copy for RabbitmqVhostReconcileStep {}
+
+impl std::clone::Clone for RabbitmqVhostReconcileStep {
+    #[verifier(external_body)]
+    fn clone(&self) -> (result: Self)
+        ensures result == self
+    { *self }
+}
+
+}
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: MIT
 use crate::kubernetes_api_objects::error::UnmarshalError;
 use crate::kubernetes_api_objects::exec::{
-    api_resource::*, label_selector::*, pod_template_spec::*, prelude::*,
+    api_resource::*, condition::*, label_selector::*, pod_template_spec::*, prelude::*,
 };
 use crate::kubernetes_api_objects::spec::resource::*;
 use crate::vdeployment_controller::trusted::{spec_types, step::*};
@@ -24,6 +24,13 @@ impl View for VDeployment {
 }
 
 impl VDeployment {
+    #[verifier(external_body)]
+    pub fn clone(&self) -> (vd: Self)
+        ensures vd@ == self@,
+    {
+        VDeployment { inner: self.inner.clone() }
+    }
+
     #[verifier(external_body)]
     pub fn metadata(&self) -> (metadata: ObjectMeta)
         ensures metadata@ == self@.metadata,
@@ -38,6 +45,18 @@ impl VDeployment {
         VDeploymentSpec { inner: self.inner.spec.clone() }
     }
 
+    #[verifier(external_body)]
+    pub fn set_status(&mut self, status: VDeploymentStatus)
+        ensures self@ == old(self)@.set_status(status@),
+    {
+        let mut new_status = status.into_kube();
+        match (&mut new_status.conditions, self.inner.status.as_ref().and_then(|s| s.conditions.as_ref())) {
+            (Some(conditions), Some(old_conditions)) => preserve_transition_times(conditions, old_conditions),
+            _ => {},
+        }
+        self.inner.status = Some(new_status);
+    }
+
     #[verifier(external_body)]
     pub fn api_resource() -> (res: ApiResource)
         ensures res@.kind == spec_types::VDeploymentView::kind(),
@@ -122,4 +141,30 @@ impl VDeploymentSpec {
     }
 }
 
+#[verifier(external_body)]
+pub struct VDeploymentStatus {
+    inner: deps_hack::VDeploymentStatus,
+}
+
+impl VDeploymentStatus {
+    pub spec fn view(&self) -> spec_types::VDeploymentStatusView;
+
+    #[verifier(external_body)]
+    pub fn default() -> (status: VDeploymentStatus)
+        ensures status@ == spec_types::VDeploymentStatusView::default(),
+    {
+        VDeploymentStatus { inner: deps_hack::VDeploymentStatus::default() }
+    }
+
+    #[verifier(external_body)]
+    pub fn set_conditions(&mut self, conditions: Vec<Condition>)
+        ensures self@ == old(self)@.set_conditions(conditions@.map_values(|c: Condition| c@)),
+    {
+        self.inner.conditions = Some(into_kube_conditions(conditions))
+    }
+
+    #[verifier(external)]
+    fn into_kube(self) -> deps_hack::VDeploymentStatus { self.inner }
+}
+
 }
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: MIT
 use crate::kubernetes_api_objects::error::*;
 use crate::kubernetes_api_objects::spec::{
-    api_resource::*, label_selector::*, pod_template_spec::*, prelude::*,
+    api_resource::*, condition::*, label_selector::*, pod_template_spec::*, prelude::*,
 };
 use crate::kubernetes_cluster::spec::{cluster::*, message::*};
 use crate::vdeployment_controller::trusted::step::*;
@@ -17,8 +17,6 @@ pub struct VDeploymentView {
     pub status: Option<VDeploymentStatusView>,
 }
 
-pub type VDeploymentStatusView = EmptyStatusView;
-
 impl VDeploymentView {
     pub open spec fn well_formed(self) -> bool {
         &&& self.metadata.well_formed()
@@ -34,6 +32,13 @@ impl VDeploymentView {
             uid: self.metadata.uid.get_Some_0(),
         }
     }
+
+    pub open spec fn set_status(self, status: VDeploymentStatusView) -> VDeploymentView {
+        VDeploymentView {
+            status: Some(status),
+            ..self
+        }
+    }
 }
 
 impl ResourceView for VDeploymentView {
@@ -140,6 +145,8 @@ impl ResourceView for VDeploymentView {
 impl CustomResourceView for VDeploymentView {
     proof fn kind_is_custom_resource() {}
 
+    open spec fn is_namespaced() -> bool { true }
+
     open spec fn spec_status_validation(obj_spec: Self::Spec, obj_status: Self::Status) -> bool {
         VDeploymentView {
             metadata: arbitrary(),
@@ -157,4 +164,23 @@ pub struct VDeploymentSpecView {
     pub template: Option<PodTemplateSpecView>,
 }
 
+pub struct VDeploymentStatusView {
+    pub conditions: Option<Seq<ConditionView>>,
+}
+
+impl VDeploymentStatusView {
+    pub open spec fn default() -> VDeploymentStatusView {
+        VDeploymentStatusView {
+            conditions: None,
+        }
+    }
+
+    pub open spec fn set_conditions(self, conditions: Seq<ConditionView>) -> VDeploymentStatusView {
+        VDeploymentStatusView {
+            conditions: Some(conditions),
+            ..self
+        }
+    }
+}
+
 }
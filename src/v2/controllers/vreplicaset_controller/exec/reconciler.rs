@@ -1,6 +1,6 @@
 // Copyright 2022 VMware, Inc.
 // SPDX-License-Identifier: MIT
-use crate::kubernetes_api_objects::exec::prelude::*;
+use crate::kubernetes_api_objects::exec::{condition::*, prelude::*};
 use crate::kubernetes_api_objects::spec::prelude::*;
 use crate::reconciler::exec::{io::*, reconciler::*};
 use crate::vreplicaset_controller::model::reconciler as model_reconciler;
@@ -113,26 +113,22 @@ pub fn reconcile_core(v_replica_set: &VReplicaSet, resp_o: Option<Response<VoidE
             if !(resp_o.is_some() && resp_o.as_ref().unwrap().is_k_response()
             && resp_o.as_ref().unwrap().as_k_response_ref().is_list_response()
             && resp_o.as_ref().unwrap().as_k_response_ref().as_list_response_ref().res.is_ok()) {
-                return (error_state(state), None);
+                return report_error(v_replica_set, state);
             }
             let objs = resp_o.unwrap().into_k_response().into_list_response().res.unwrap();
             let pods_or_none = objects_to_pods(objs);
             if pods_or_none.is_none() {
-                return (error_state(state), None);
+                return report_error(v_replica_set, state);
             }
             let pods = pods_or_none.unwrap();
             let filtered_pods = filter_pods(pods, v_replica_set);
             let replicas = v_replica_set.spec().replicas().unwrap_or(0);
             if replicas < 0 {
-                return (error_state(state), None);
+                return report_error(v_replica_set, state);
             }
             let desired_replicas: usize = replicas as usize;
             if filtered_pods.len() == desired_replicas {
-                let state_prime = VReplicaSetReconcileState {
-                    reconcile_step: VReplicaSetReconcileStep::Done,
-                    ..state
-                };
-                return (state_prime, None);
+                return update_status(v_replica_set, state, true);
             } else if filtered_pods.len() < desired_replicas {
                 let diff =  desired_replicas - filtered_pods.len();
                 let pod = make_pod(v_replica_set);
@@ -150,7 +146,7 @@ pub fn reconcile_core(v_replica_set: &VReplicaSet, resp_o: Option<Response<VoidE
                 let diff = filtered_pods.len() - desired_replicas;
                 let pod_name_or_none = filtered_pods[diff - 1].metadata().name();
                 if pod_name_or_none.is_none() {
-                    return (error_state(state), None);
+                    return report_error(v_replica_set, state);
                 }
                 let req = KubeAPIRequest::DeleteRequest(KubeDeleteRequest {
                     api_resource: Pod::api_resource(),
@@ -175,14 +171,11 @@ pub fn reconcile_core(v_replica_set: &VReplicaSet, resp_o: Option<Response<VoidE
             if !(resp_o.is_some() && resp_o.as_ref().unwrap().is_k_response()
             && resp_o.as_ref().unwrap().as_k_response_ref().is_create_response()
             && resp_o.as_ref().unwrap().as_k_response_ref().as_create_response_ref().res.is_ok()) {
-                return (error_state(state), None);
+                return report_error(v_replica_set, state);
             }
             if diff == 0 {
-                let state_prime = VReplicaSetReconcileState {
-                    reconcile_step: VReplicaSetReconcileStep::Done,
-                    ..state
-                };
-                return (state_prime, None);
+                // The pods are created/deleted but not yet observed, so the status reports progressing.
+                return update_status(v_replica_set, state, false);
             } else {
                 let pod = make_pod(v_replica_set);
                 let req = KubeAPIRequest::CreateRequest(KubeCreateRequest {
@@ -202,24 +195,21 @@ pub fn reconcile_core(v_replica_set: &VReplicaSet, resp_o: Option<Response<VoidE
             if !(resp_o.is_some() && resp_o.as_ref().unwrap().is_k_response()
             && resp_o.as_ref().unwrap().as_k_response_ref().is_delete_response()
             && resp_o.as_ref().unwrap().as_k_response_ref().as_delete_response_ref().res.is_ok()) {
-                return (error_state(state), None);
+                return report_error(v_replica_set, state);
             }
             if diff == 0 {
-                let state_prime = VReplicaSetReconcileState {
-                    reconcile_step: VReplicaSetReconcileStep::Done,
-                    ..state
-                };
-                return (state_prime, None);
+                // The pods are created/deleted but not yet observed, so the status reports progressing.
+                return update_status(v_replica_set, state, false);
             } else {
                 if state.filtered_pods.is_none() {
-                    return (error_state(state), None);
+                    return report_error(v_replica_set, state);
                 }
                 if diff > state.filtered_pods.as_ref().unwrap().len() {
-                    return (error_state(state), None);
+                    return report_error(v_replica_set, state);
                 }
                 let pod_name_or_none = state.filtered_pods.as_ref().unwrap()[diff - 1].metadata().name();
                 if pod_name_or_none.is_none() {
-                    return (error_state(state), None);
+                    return report_error(v_replica_set, state);
                 }
                 let req = KubeAPIRequest::DeleteRequest(KubeDeleteRequest {
                     api_resource: Pod::api_resource(),
@@ -240,6 +230,21 @@ pub fn reconcile_core(v_replica_set: &VReplicaSet, resp_o: Option<Response<VoidE
                 return (state_prime, Some(Request::KRequest(req)));
             }
         },
+        VReplicaSetReconcileStep::AfterUpdateStatus => {
+            if !(resp_o.is_some() && resp_o.as_ref().unwrap().is_k_response()
+            && resp_o.as_ref().unwrap().as_k_response_ref().is_update_status_response()
+            && resp_o.as_ref().unwrap().as_k_response_ref().as_update_status_response_ref().res.is_ok()) {
+                return (error_state(state), None);
+            }
+            let state_prime = VReplicaSetReconcileState {
+                reconcile_step: VReplicaSetReconcileStep::Done,
+                ..state
+            };
+            return (state_prime, None);
+        },
+        VReplicaSetReconcileStep::AfterUpdateDegradedStatus => {
+            return (error_state(state), None);
+        },
         _ => {
             return (state, None);
         }
@@ -255,6 +260,52 @@ pub fn error_state(state: VReplicaSetReconcileState) -> (state_prime: VReplicaSe
     }
 }
 
+pub fn update_status(v_replica_set: &VReplicaSet, state: VReplicaSetReconcileState, ready: bool) -> (res: (VReplicaSetReconcileState, Option<Request<VoidEReq>>))
+    requires v_replica_set@.well_formed(),
+    ensures (res.0@, option_view(res.1)) == model_reconciler::update_status(v_replica_set@, state@, ready),
+{
+    let req = KubeAPIRequest::UpdateStatusRequest(KubeUpdateStatusRequest {
+        api_resource: VReplicaSet::api_resource(),
+        name: v_replica_set.metadata().name().unwrap(),
+        namespace: v_replica_set.metadata().namespace().unwrap(),
+        obj: update_vrs_status(v_replica_set, ready, false).marshal(),
+    });
+    let state_prime = VReplicaSetReconcileState {
+        reconcile_step: VReplicaSetReconcileStep::AfterUpdateStatus,
+        ..state
+    };
+    (state_prime, Some(Request::KRequest(req)))
+}
+
+pub fn report_error(v_replica_set: &VReplicaSet, state: VReplicaSetReconcileState) -> (res: (VReplicaSetReconcileState, Option<Request<VoidEReq>>))
+    requires v_replica_set@.well_formed(),
+    ensures (res.0@, option_view(res.1)) == model_reconciler::report_error(v_replica_set@, state@),
+{
+    let req = KubeAPIRequest::UpdateStatusRequest(KubeUpdateStatusRequest {
+        api_resource: VReplicaSet::api_resource(),
+        name: v_replica_set.metadata().name().unwrap(),
+        namespace: v_replica_set.metadata().namespace().unwrap(),
+        obj: update_vrs_status(v_replica_set, false, true).marshal(),
+    });
+    let state_prime = VReplicaSetReconcileState {
+        reconcile_step: VReplicaSetReconcileStep::AfterUpdateDegradedStatus,
+        ..state
+    };
+    (state_prime, Some(Request::KRequest(req)))
+}
+
+pub fn update_vrs_status(v_replica_set: &VReplicaSet, ready: bool, degraded: bool) -> (updated_vrs: VReplicaSet)
+    ensures updated_vrs@ == model_reconciler::update_vrs_status(v_replica_set@, ready, degraded),
+{
+    let mut updated_vrs = v_replica_set.clone();
+    updated_vrs.set_status({
+        let mut status = VReplicaSetStatus::default();
+        status.set_conditions(make_conditions(ready, degraded, v_replica_set.metadata().generation()));
+        status
+    });
+    updated_vrs
+}
+
 pub fn make_owner_references(v_replica_set: &VReplicaSet) -> (owner_references: Vec<OwnerReference>)
     requires v_replica_set@.well_formed(),
     ensures owner_references@.map_values(|or: OwnerReference| or@) ==  model_reconciler::make_owner_references(v_replica_set@),
@@ -1,4 +1,4 @@
-use crate::kubernetes_api_objects::spec::prelude::*;
+use crate::kubernetes_api_objects::spec::{condition::*, prelude::*};
 use crate::reconciler::spec::{io::*, reconciler::*};
 use crate::vreplicaset_controller::trusted::{spec_types::*, step::*};
 use vstd::prelude::*;
@@ -69,26 +69,22 @@ pub open spec fn reconcile_core(v_replica_set: VReplicaSetView, resp_o: Option<R
             if !(resp_o.is_Some() && resp_o.get_Some_0().is_KResponse()
             && resp_o.get_Some_0().get_KResponse_0().is_ListResponse()
             && resp_o.get_Some_0().get_KResponse_0().get_ListResponse_0().res.is_ok()) {
-                (error_state(state), None)
+                report_error(v_replica_set, state)
             } else {
                 let objs = resp_o.unwrap().get_KResponse_0().get_ListResponse_0().res.unwrap();
                 let pods_or_none = objects_to_pods(objs);
                 if pods_or_none.is_none() {
-                    (error_state(state), None)
+                    report_error(v_replica_set, state)
                 } else {
                     let pods = pods_or_none.unwrap();
                     let filtered_pods = filter_pods(pods, v_replica_set);
                     let replicas = v_replica_set.spec.replicas.unwrap_or(0);
                     if replicas < 0 {
-                        (error_state(state), None)
+                        report_error(v_replica_set, state)
                     } else {
                         let desired_replicas: usize = replicas as usize;
                         if filtered_pods.len() == desired_replicas {
-                            let state_prime = VReplicaSetReconcileState {
-                                reconcile_step: VReplicaSetReconcileStep::Done,
-                                ..state
-                            };
-                            (state_prime, None)
+                            update_status(v_replica_set, state, true)
                         } else if filtered_pods.len() < desired_replicas {
                             let diff =  desired_replicas - filtered_pods.len();
                             let pod = make_pod(v_replica_set);
@@ -105,7 +101,7 @@ pub open spec fn reconcile_core(v_replica_set: VReplicaSetView, resp_o: Option<R
                             let diff = filtered_pods.len() - desired_replicas;
                             let pod_name_or_none = filtered_pods[diff - 1].metadata.name;
                             if pod_name_or_none.is_none() {
-                                (error_state(state), None)
+                                report_error(v_replica_set, state)
                             } else {
                                 let req = APIRequest::DeleteRequest(DeleteRequest {
                                     key: ObjectRef {
@@ -135,13 +131,10 @@ pub open spec fn reconcile_core(v_replica_set: VReplicaSetView, resp_o: Option<R
             if !(resp_o.is_Some() && resp_o.get_Some_0().is_KResponse()
             && resp_o.get_Some_0().get_KResponse_0().is_CreateResponse()
             && resp_o.get_Some_0().get_KResponse_0().get_CreateResponse_0().res.is_ok()) {
-                (error_state(state), None)
+                report_error(v_replica_set, state)
             } else if diff == 0 {
-                let state_prime = VReplicaSetReconcileState {
-                    reconcile_step: VReplicaSetReconcileStep::Done,
-                    ..state
-                };
-                (state_prime, None)
+                // The pods are created/deleted but not yet observed, so the status reports progressing.
+                update_status(v_replica_set, state, false)
             } else {
                 let pod = make_pod(v_replica_set);
                 let req = APIRequest::CreateRequest(CreateRequest {
@@ -160,22 +153,19 @@ pub open spec fn reconcile_core(v_replica_set: VReplicaSetView, resp_o: Option<R
             if !(resp_o.is_Some() && resp_o.get_Some_0().is_KResponse()
             && resp_o.get_Some_0().get_KResponse_0().is_DeleteResponse()
             && resp_o.get_Some_0().get_KResponse_0().get_DeleteResponse_0().res.is_ok()) {
-                (error_state(state), None)
+                report_error(v_replica_set, state)
             } else if diff == 0 {
-                let state_prime = VReplicaSetReconcileState {
-                    reconcile_step: VReplicaSetReconcileStep::Done,
-                    ..state
-                };
-                (state_prime, None)
+                // The pods are created/deleted but not yet observed, so the status reports progressing.
+                update_status(v_replica_set, state, false)
             } else {
                 if state.filtered_pods.is_none() {
-                    (error_state(state), None)
+                    report_error(v_replica_set, state)
                 } else if diff > state.filtered_pods.unwrap().len() {
-                    (error_state(state), None)
+                    report_error(v_replica_set, state)
                 } else {
                     let pod_name_or_none = state.filtered_pods.unwrap()[diff - 1].metadata.name;
                     if pod_name_or_none.is_none() {
-                        (error_state(state), None)
+                        report_error(v_replica_set, state)
                     } else {
                         let req = APIRequest::DeleteRequest(DeleteRequest {
                             key: ObjectRef {
@@ -197,6 +187,22 @@ pub open spec fn reconcile_core(v_replica_set: VReplicaSetView, resp_o: Option<R
                 }
             }
         },
+        VReplicaSetReconcileStep::AfterUpdateStatus => {
+            if !(resp_o.is_Some() && resp_o.get_Some_0().is_KResponse()
+            && resp_o.get_Some_0().get_KResponse_0().is_UpdateStatusResponse()
+            && resp_o.get_Some_0().get_KResponse_0().get_UpdateStatusResponse_0().res.is_ok()) {
+                (error_state(state), None)
+            } else {
+                let state_prime = VReplicaSetReconcileState {
+                    reconcile_step: VReplicaSetReconcileStep::Done,
+                    ..state
+                };
+                (state_prime, None)
+            }
+        },
+        VReplicaSetReconcileStep::AfterUpdateDegradedStatus => {
+            (error_state(state), None)
+        },
         _ => {
             (state, None)
         }
@@ -211,6 +217,43 @@ pub open spec fn error_state(state: VReplicaSetReconcileState) -> (state_prime:
     }
 }
 
+pub open spec fn update_status(
+    v_replica_set: VReplicaSetView, state: VReplicaSetReconcileState, ready: bool
+) -> (VReplicaSetReconcileState, Option<RequestView<VoidEReqView>>) {
+    let req = APIRequest::UpdateStatusRequest(UpdateStatusRequest {
+        namespace: v_replica_set.metadata.namespace.unwrap(),
+        name: v_replica_set.metadata.name.unwrap(),
+        obj: update_vrs_status(v_replica_set, ready, false).marshal(),
+    });
+    let state_prime = VReplicaSetReconcileState {
+        reconcile_step: VReplicaSetReconcileStep::AfterUpdateStatus,
+        ..state
+    };
+    (state_prime, Some(RequestView::KRequest(req)))
+}
+
+// report_error marks the v_replica_set as degraded before the reconcile ends in error.
+pub open spec fn report_error(
+    v_replica_set: VReplicaSetView, state: VReplicaSetReconcileState
+) -> (VReplicaSetReconcileState, Option<RequestView<VoidEReqView>>) {
+    let req = APIRequest::UpdateStatusRequest(UpdateStatusRequest {
+        namespace: v_replica_set.metadata.namespace.unwrap(),
+        name: v_replica_set.metadata.name.unwrap(),
+        obj: update_vrs_status(v_replica_set, false, true).marshal(),
+    });
+    let state_prime = VReplicaSetReconcileState {
+        reconcile_step: VReplicaSetReconcileStep::AfterUpdateDegradedStatus,
+        ..state
+    };
+    (state_prime, Some(RequestView::KRequest(req)))
+}
+
+pub open spec fn update_vrs_status(v_replica_set: VReplicaSetView, ready: bool, degraded: bool) -> VReplicaSetView {
+    v_replica_set.set_status(VReplicaSetStatusView::default().set_conditions(
+        make_conditions(ready, degraded, v_replica_set.metadata.generation)
+    ))
+}
+
 pub open spec fn objects_to_pods(objs: Seq<DynamicObjectView>) -> (pods_or_none: Option<Seq<PodView>>) {
     if objs.filter(|o: DynamicObjectView| PodView::unmarshal(o).is_err()).len() != 0 {
         None
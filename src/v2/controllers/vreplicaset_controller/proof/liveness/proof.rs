@@ -26,6 +26,9 @@ proof fn eventually_stable_reconciliation_holds(spec: TempPred<ClusterState>, cl
         spec.entails(tla_forall(|i| cluster.builtin_controllers_next().weak_fairness(i))),
         // The fairness condition of scheduling controller reconcile.
         spec.entails(tla_forall(|i| cluster.schedule_controller_reconcile().weak_fairness((controller_id, i)))),
+        // The token bucket of the vrs controller is eventually refilled whenever it runs out of tokens
+        // (an assumption on the environment); throttling stays enabled.
+        spec.entails(cluster.tokens_are_eventually_refilled(HostId::Controller(controller_id))),
         // No other controllers interfere with the vrs controller.
         forall |other_id| cluster.controller_models.remove(controller_id).contains_key(other_id)
             ==> spec.entails(always(lift_state(#[trigger] vrs_not_interfered_by(other_id)))),
@@ -1,7 +1,9 @@
 // Copyright 2022 VMware, Inc.
 // SPDX-License-Identifier: MIT
 use crate::kubernetes_api_objects::error::*;
-use crate::kubernetes_api_objects::spec::{label_selector::*, pod_template_spec::*, prelude::*};
+use crate::kubernetes_api_objects::spec::{
+    condition::*, label_selector::*, pod_template_spec::*, prelude::*,
+};
 use vstd::prelude::*;
 
 verus! {
@@ -12,8 +14,6 @@ pub struct VReplicaSetView {
     pub status: Option<VReplicaSetStatusView>,
 }
 
-pub type VReplicaSetStatusView = EmptyStatusView;
-
 impl VReplicaSetView {
     pub open spec fn well_formed(self) -> bool {
         &&& self.metadata.well_formed()
@@ -29,6 +29,13 @@ impl VReplicaSetView {
             uid: self.metadata.uid.get_Some_0(),
         }
     }
+
+    pub open spec fn set_status(self, status: VReplicaSetStatusView) -> VReplicaSetView {
+        VReplicaSetView {
+            status: Some(status),
+            ..self
+        }
+    }
 }
 
 impl ResourceView for VReplicaSetView {
@@ -135,6 +142,8 @@ impl ResourceView for VReplicaSetView {
 impl CustomResourceView for VReplicaSetView {
     proof fn kind_is_custom_resource() {}
 
+    open spec fn is_namespaced() -> bool { true }
+
     open spec fn spec_status_validation(obj_spec: Self::Spec, obj_status: Self::Status) -> bool {
         VReplicaSetView {
             metadata: arbitrary(),
@@ -152,4 +161,23 @@ pub struct VReplicaSetSpecView {
     pub template: Option<PodTemplateSpecView>,
 }
 
+pub struct VReplicaSetStatusView {
+    pub conditions: Option<Seq<ConditionView>>,
+}
+
+impl VReplicaSetStatusView {
+    pub open spec fn default() -> VReplicaSetStatusView {
+        VReplicaSetStatusView {
+            conditions: None,
+        }
+    }
+
+    pub open spec fn set_conditions(self, conditions: Seq<ConditionView>) -> VReplicaSetStatusView {
+        VReplicaSetStatusView {
+            conditions: Some(conditions),
+            ..self
+        }
+    }
+}
+
 }
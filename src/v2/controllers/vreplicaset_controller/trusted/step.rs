@@ -8,6 +8,8 @@ pub enum VReplicaSetReconcileStep {
     AfterListPods,
     AfterCreatePod(usize),
     AfterDeletePod(usize),
+    AfterUpdateStatus,
+    AfterUpdateDegradedStatus,
     Done,
     Error,
 }
@@ -10,6 +10,12 @@ use crate::reconciler::exec::io::{VoidEReq, VoidEResp};
 // Note that we can encapsulate all the required libraries here, so each reconciler only has one ExternalAPI type.
 pub trait ExternalShimLayer<EReq, EResp> {
     fn external_call(req: EReq) -> EResp;
+
+    // error_of describes the failure carried by the response of a call, if any,
+    // so that the controller runtime can record it as a Warning Event.
+    fn error_of(_resp: &EResp) -> Option<String> {
+        None
+    }
 }
 
 // An empty library that implements External Library.
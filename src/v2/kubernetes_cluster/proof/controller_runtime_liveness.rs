@@ -524,6 +524,15 @@ pub proof fn lemma_from_pending_req_in_flight_at_some_state_to_in_flight_resp_ma
                             assert(pre_1(s_prime));
                         }
                     }
+                    // A throttled request still gets a (TooManyRequests) response,
+                    // so the reconcile keeps making progress under throttling.
+                    Step::ThrottleReqStep(input) => {
+                        if input == req_msg {
+                            assert(post_1(s_prime));
+                        } else {
+                            assert(pre_1(s_prime));
+                        }
+                    }
                     Step::ControllerStep(input) => { assert(pre_1(s_prime)); },
                     _ => { assert(pre_1(s_prime)); }
                 }
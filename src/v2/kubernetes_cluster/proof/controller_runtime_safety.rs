@@ -1,3 +1,4 @@
+use crate::kubernetes_api_objects::error::*;
 use crate::kubernetes_api_objects::spec::prelude::*;
 use crate::kubernetes_cluster::spec::{
     api_server::state_machine::transition_by_etcd, cluster::*, controller::types::*,
@@ -141,6 +142,16 @@ pub proof fn lemma_always_pending_req_in_flight_or_resp_in_flight_at_reconcile_s
                         }
                     }
                 }
+                Step::ThrottleReqStep(input) => {
+                    if input == pending_req_msg {
+                        let resp_msg = form_matched_err_resp_msg(pending_req_msg, APIError::TooManyRequests);
+                        assert(s_prime.in_flight().contains(resp_msg));
+                    } else {
+                        if !s.in_flight().contains(pending_req_msg) {
+                            assert(s_prime.in_flight().contains(resp));
+                        }
+                    }
+                }
                 Step::ControllerStep(input) => {
                     let input_controller_id = input.0;
                     let input_cr_key = input.2.get_Some_0();
@@ -0,0 +1,22 @@
+use crate::kubernetes_api_objects::spec::prelude::*;
+use crate::kubernetes_cluster::spec::{
+    api_server::{state_machine::*, types::*},
+    message::*,
+};
+use vstd::prelude::*;
+
+verus! {
+
+// Handling a request that writes an Event never changes any object of another kind,
+// so recording Events does not interfere with the objects that controllers manage.
+pub proof fn lemma_event_write_request_preserves_objects_of_other_kinds(installed_types: InstalledTypes, msg: Message, s: APIServerState, key: ObjectRef)
+    requires
+        msg.content.is_APIRequest(),
+        msg.content.get_APIRequest_0().is_event_write_request(),
+        key.kind != Kind::EventKind,
+    ensures
+        transition_by_etcd(installed_types, msg, s).0.resources.contains_key(key) == s.resources.contains_key(key),
+        s.resources.contains_key(key) ==> transition_by_etcd(installed_types, msg, s).0.resources[key] == s.resources[key],
+{}
+
+}
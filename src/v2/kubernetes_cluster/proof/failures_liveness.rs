@@ -1,4 +1,9 @@
-use crate::kubernetes_cluster::spec::cluster::*;
+use crate::kubernetes_api_objects::error::*;
+use crate::kubernetes_cluster::spec::{
+    api_server::{state_machine::{out_of_tokens, transition_by_etcd}, types::*},
+    cluster::*,
+    message::*,
+};
 use crate::temporal_logic::{defs::*, rules::*};
 use vstd::prelude::*;
 
@@ -47,6 +52,164 @@ pub proof fn lemma_true_leads_to_req_drop_always_disabled(self, spec: TempPred<C
     leads_to_stable(spec, lift_action(self.next()), true_pred(), lift_state(Self::req_drop_disabled()));
 }
 
+pub open spec fn client_has_tokens(client: HostId) -> StatePred<ClusterState> {
+    |s: ClusterState| !out_of_tokens(s.api_server.flow_control, client)
+}
+
+// Throttling does not stop a reconcile from making progress since a throttled request
+// still gets a response (see lemma_from_pending_req_in_flight_at_some_state_to_in_flight_resp_matches_pending_req_at_some_state).
+// To show that the cluster eventually converges while throttling stays enabled, the requests from the controller
+// need to eventually get through. The bucket of the controller eventually has tokens under tokens_are_eventually_refilled,
+// and it keeps them until the API server handles a request from the controller,
+// so the next request from the controller is handled instead of being throttled
+// (see lemma_req_in_flight_and_client_has_tokens_leads_to_req_handled).
+pub proof fn lemma_true_leads_to_client_has_tokens(self, spec: TempPred<ClusterState>, client: HostId)
+    requires
+        spec.entails(always(lift_action(self.next()))),
+        spec.entails(self.tokens_are_eventually_refilled(client)),
+    ensures spec.entails(true_pred().leads_to(lift_state(Self::client_has_tokens(client)))),
+{
+    let no_tokens = |s: ClusterState| out_of_tokens(s.api_server.flow_control, client);
+    let has_tokens = Self::client_has_tokens(client);
+    let input = (client, 1nat);
+    assert forall |ex| #[trigger] always(lift_state(no_tokens)).satisfied_by(ex)
+    implies lift_state(self.refill_tokens().pre(input)).satisfied_by(ex) by {
+        assert(lift_state(no_tokens).satisfied_by(ex.suffix(0)));
+        execution_equality::<ClusterState>(ex, ex.suffix(0));
+    };
+    entails_implies_leads_to(spec, always(lift_state(no_tokens)), lift_state(self.refill_tokens().pre(input)));
+    self.refill_tokens().sf1(input, spec, self.next(), no_tokens, has_tokens);
+    leads_to_self_temp(lift_state(has_tokens));
+    or_leads_to_combine_and_equality!(spec, true_pred(), lift_state(no_tokens), lift_state(has_tokens); lift_state(has_tokens));
+}
+
+pub open spec fn api_request_msg_from(client: HostId, msg: Message) -> bool {
+    &&& msg.src == client
+    &&& msg.dst.is_APIServer()
+    &&& msg.content.is_APIRequest()
+}
+
+// This holds for a controller that sends at most one request at a time to the API server,
+// e.g., a controller that reconciles one object at a time.
+pub open spec fn at_most_one_api_request_msg_in_flight_from(client: HostId) -> StatePred<ClusterState> {
+    |s: ClusterState| {
+        forall |msg1: Message, msg2: Message|
+            #[trigger] s.in_flight().contains(msg1)
+            && #[trigger] s.in_flight().contains(msg2)
+            && Self::api_request_msg_from(client, msg1)
+            && Self::api_request_msg_from(client, msg2)
+            ==> msg1 == msg2
+    }
+}
+
+pub open spec fn req_msg_in_flight_and_client_has_tokens(client: HostId, req_msg: Message) -> StatePred<ClusterState> {
+    |s: ClusterState| {
+        &&& s.in_flight().contains(req_msg)
+        &&& Self::api_request_msg_from(client, req_msg)
+        &&& Self::client_has_tokens(client)(s)
+    }
+}
+
+// The response is not TooManyRequests, i.e., the request is handled by the API server instead of being throttled.
+pub open spec fn req_msg_is_handled_by_api_server(req_msg: Message) -> StatePred<ClusterState> {
+    |s: ClusterState| {
+        exists |resp_msg: Message| {
+            &&& #[trigger] s.in_flight().contains(resp_msg)
+            &&& resp_msg_matches_req_msg(resp_msg, req_msg)
+            &&& resp_msg != form_matched_err_resp_msg(req_msg, APIError::TooManyRequests)
+        }
+    }
+}
+
+// A request sent while the bucket of its sender has tokens cannot be throttled: the bucket is not refilled
+// while it has tokens, and only the API server handling a request from the same client takes a token from it,
+// which does not happen before the request itself is handled if the client has no other request in flight.
+pub proof fn lemma_req_in_flight_and_client_has_tokens_leads_to_req_handled(self, spec: TempPred<ClusterState>, client: HostId, req_msg: Message)
+    requires
+        spec.entails(always(lift_action(self.next()))),
+        spec.entails(tla_forall(|i| self.api_server_next().weak_fairness(i))),
+        spec.entails(always(lift_state(Self::req_drop_disabled()))),
+        spec.entails(always(lift_state(Self::at_most_one_api_request_msg_in_flight_from(client)))),
+    ensures
+        spec.entails(lift_state(Self::req_msg_in_flight_and_client_has_tokens(client, req_msg))
+            .leads_to(lift_state(Self::req_msg_is_handled_by_api_server(req_msg)))),
+{
+    let pre = Self::req_msg_in_flight_and_client_has_tokens(client, req_msg);
+    let post = Self::req_msg_is_handled_by_api_server(req_msg);
+    let input = Some(req_msg);
+    let stronger_next = |s, s_prime| {
+        &&& self.next()(s, s_prime)
+        &&& Self::req_drop_disabled()(s)
+        &&& Self::at_most_one_api_request_msg_in_flight_from(client)(s)
+    };
+    combine_spec_entails_always_n!(
+        spec, lift_action(stronger_next),
+        lift_action(self.next()),
+        lift_state(Self::req_drop_disabled()),
+        lift_state(Self::at_most_one_api_request_msg_in_flight_from(client))
+    );
+
+    assert forall |s, s_prime| pre(s) && #[trigger] stronger_next(s, s_prime)
+    && self.api_server_next().forward(input)(s, s_prime) implies post(s_prime) by {
+        let resp_msg = transition_by_etcd(self.installed_types, req_msg, s.api_server).1;
+        assert({
+            &&& s_prime.in_flight().contains(resp_msg)
+            &&& resp_msg_matches_req_msg(resp_msg, req_msg)
+            &&& resp_msg != form_matched_err_resp_msg(req_msg, APIError::TooManyRequests)
+        });
+    };
+    assert forall |s, s_prime| pre(s) && #[trigger] stronger_next(s, s_prime)
+    implies pre(s_prime) || post(s_prime) by {
+        let step = choose |step| self.next_step(s, s_prime, step);
+        match step {
+            Step::APIServerStep(input) => {
+                let msg = input.get_Some_0();
+                if msg == req_msg {
+                    assert(post(s_prime));
+                } else {
+                    // The handled request is not from the client, so the bucket of the client stays the same.
+                    assert(!Self::api_request_msg_from(client, msg));
+                    assert(pre(s_prime));
+                }
+            }
+            Step::ThrottleReqStep(input) => {
+                // The client has tokens, so none of its requests can be throttled.
+                assert(input.src != client);
+                assert(pre(s_prime));
+            }
+            Step::RefillTokensStep(input) => {
+                // The bucket of the client is not empty, so it cannot be refilled.
+                assert(input.0 != client);
+                assert(pre(s_prime));
+            }
+            _ => { assert(pre(s_prime)); }
+        }
+    };
+    self.lemma_pre_leads_to_post_by_api_server(spec, input, stronger_next, APIServerStep::HandleRequest, pre, post);
+}
+
+// A client that always has tokens left is never throttled: the requests it sends
+// are either handled by the API server or dropped by drop_req.
+pub proof fn lemma_always_request_not_throttled_if_client_has_tokens(self, spec: TempPred<ClusterState>, client: HostId)
+    requires
+        spec.entails(always(lift_state(Self::client_has_tokens(client)))),
+    ensures
+        spec.entails(always(lift_action(|s: ClusterState, s_prime: ClusterState| {
+            forall |req_msg: Message| req_msg.src == client ==> !#[trigger] self.throttle_req().forward(req_msg)(s, s_prime)
+        }))),
+{
+    let has_tokens = Self::client_has_tokens(client);
+    let not_throttled = |s: ClusterState, s_prime: ClusterState| {
+        forall |req_msg: Message| req_msg.src == client ==> !#[trigger] self.throttle_req().forward(req_msg)(s, s_prime)
+    };
+    assert forall |ex| #[trigger] lift_state(has_tokens).satisfied_by(ex)
+    implies lift_action(not_throttled).satisfied_by(ex) by {
+        assert forall |req_msg: Message| req_msg.src == client
+        implies !#[trigger] self.throttle_req().forward(req_msg)(ex.head(), ex.head_next()) by {}
+    };
+    always_weaken(spec, lift_state(has_tokens), lift_action(not_throttled));
+}
+
 }
 
 }
@@ -61,6 +61,10 @@ pub proof fn lemma_always_every_in_flight_msg_has_lower_id_than_allocator(self,
                         assert(s.in_flight().contains(input.0));
                         assert(msg.rpc_id == input.0.rpc_id);
                     },
+                    Step::ThrottleReqStep(input) => {
+                        assert(s.in_flight().contains(input));
+                        assert(msg.rpc_id == input.rpc_id);
+                    },
                     Step::ExternalStep(_) => {},
                     _ => {},
                 }
@@ -314,6 +318,16 @@ pub proof fn lemma_always_every_in_flight_msg_has_no_replicas_and_has_unique_id(
                                 assert(s_prime.in_flight().count(msg) == 1);
                             }
                         },
+                        Step::ThrottleReqStep(input) => {
+                            let req = input;
+                            assert(s.in_flight().contains(req));
+                            if s.in_flight().contains(msg) {
+                                assert(s.in_flight().count(msg) == 1);
+                                assert(s_prime.in_flight().count(msg) == 1);
+                            } else {
+                                assert(s_prime.in_flight().count(msg) == 1);
+                            }
+                        },
                         _ => {
                             if s.in_flight().contains(msg) {
                                 assert(s.in_flight().count(msg) == 1);
@@ -365,6 +379,11 @@ proof fn newly_added_msg_have_different_id_from_existing_ones(self, s: ClusterSt
                 assert(s.network.in_flight.count(req_msg) <= 1);
                 assert(msg_1.rpc_id != msg_2.rpc_id);
             }
+            Step::ThrottleReqStep(input) => {
+                let req_msg = input;
+                assert(s.network.in_flight.count(req_msg) <= 1);
+                assert(msg_1.rpc_id != msg_2.rpc_id);
+            }
             _ => assert(false),
         }
     } else if msg_2.content.is_ExternalResponse() {
@@ -393,6 +393,13 @@ proof fn pending_requests_num_decreases(self, spec: TempPred<ClusterState>, rpc_
                     assert(pending_req_multiset =~= pending_req_multiset_prime);
                 }
             },
+            Step::ThrottleReqStep(input) => {
+                if pending_req_multiset.count(input) > 0 {
+                    assert(pending_req_multiset.remove(input) =~= pending_req_multiset_prime);
+                } else {
+                    assert(pending_req_multiset =~= pending_req_multiset_prime);
+                }
+            },
             Step::BuiltinControllersStep(input) => {
                 assert(pending_req_multiset =~= pending_req_multiset_prime);
             },
@@ -100,16 +100,22 @@ pub proof fn lemma_always_each_builtin_object_in_etcd_is_well_formed(self, spec:
                             APIRequest::GetRequest(_) => {}
                             APIRequest::ListRequest(_) => {}
                             APIRequest::CreateRequest(_) => {
+                                ClusterRoleView::marshal_status_preserves_integrity();
+                                ClusterRoleBindingView::marshal_status_preserves_integrity();
                                 ConfigMapView::marshal_status_preserves_integrity();
                                 DaemonSetView::marshal_status_preserves_integrity();
+                                NamespaceView::marshal_status_preserves_integrity();
+                                PersistentVolumeView::marshal_status_preserves_integrity();
                                 PersistentVolumeClaimView::marshal_status_preserves_integrity();
                                 PodView::marshal_status_preserves_integrity();
+                                PodDisruptionBudgetView::marshal_status_preserves_integrity();
                                 RoleBindingView::marshal_status_preserves_integrity();
                                 RoleView::marshal_status_preserves_integrity();
                                 SecretView::marshal_status_preserves_integrity();
                                 ServiceView::marshal_status_preserves_integrity();
                                 StatefulSetView::marshal_status_preserves_integrity();
                                 ServiceAccountView::marshal_status_preserves_integrity();
+                                StorageClassView::marshal_status_preserves_integrity();
                             }
                             APIRequest::DeleteRequest(_) => {}
                             APIRequest::UpdateRequest(_) => {}
@@ -221,6 +221,15 @@ pub proof fn lemma_always_key_of_object_in_matched_ok_get_resp_message_is_same_a
                     }
                     assert(is_ok_get_response_msg_and_matches_key(req_key)(msg));
                 },
+                Step::ThrottleReqStep(input) => {
+                    assert(s.ongoing_reconciles(controller_id)[key] == s_prime.ongoing_reconciles(controller_id)[key]);
+                    if !s.in_flight().contains(msg) {
+                        assert(msg.src.is_APIServer());
+                        assert(msg.content.is_get_response());
+                        assert(msg.content.get_get_response().res.is_Err());
+                    }
+                    assert(is_ok_get_response_msg_and_matches_key(req_key)(msg));
+                },
                 Step::ExternalStep(input) => {
                     assert(input.1.get_Some_0() != msg);
                     assert(s.in_flight().contains(msg));
@@ -326,6 +335,15 @@ pub proof fn lemma_always_key_of_object_in_matched_ok_update_resp_message_is_sam
                     }
                     assert(is_ok_update_response_msg_and_matches_key(req_key)(msg));
                 },
+                Step::ThrottleReqStep(input) => {
+                    assert(s.ongoing_reconciles(controller_id)[key] == s_prime.ongoing_reconciles(controller_id)[key]);
+                    if !s.in_flight().contains(msg) {
+                        assert(msg.src.is_APIServer());
+                        assert(msg.content.is_update_response());
+                        assert(msg.content.get_update_response().res.is_Err());
+                    }
+                    assert(is_ok_update_response_msg_and_matches_key(req_key)(msg));
+                },
                 Step::ExternalStep(input) => {
                     assert(input.1.get_Some_0() != msg);
                     assert(s.in_flight().contains(msg));
@@ -433,6 +451,15 @@ pub proof fn lemma_always_key_of_object_in_matched_ok_create_resp_message_is_sam
                     }
                     assert(is_ok_create_response_msg_and_matches_key(req_key)(msg));
                 },
+                Step::ThrottleReqStep(input) => {
+                    assert(s.ongoing_reconciles(controller_id)[key] == s_prime.ongoing_reconciles(controller_id)[key]);
+                    if !s.in_flight().contains(msg) {
+                        assert(msg.src.is_APIServer());
+                        assert(msg.content.is_create_response());
+                        assert(msg.content.get_create_response().res.is_Err());
+                    }
+                    assert(is_ok_create_response_msg_and_matches_key(req_key)(msg));
+                },
                 Step::ExternalStep(input) => {
                     assert(input.1.get_Some_0() != msg);
                     assert(s.in_flight().contains(msg));
@@ -1,5 +1,5 @@
 use crate::kubernetes_cluster::spec::{cluster::*, retentive_cluster::*};
-use crate::temporal_logic::defs::*;
+use crate::temporal_logic::{defs::*, rules::*};
 use vstd::prelude::*;
 
 verus! {
@@ -94,6 +94,103 @@ pub proof fn transfer_invariant_from_cluster(self, spec: TempPred<ClusterHistory
     }
 }
 
+// Taking a step keeps the history and appends the current state to the past.
+pub proof fn lemma_next_preserves_history(self, h: ClusterHistory, h_prime: ClusterHistory)
+    requires
+        self.next()(h, h_prime),
+    ensures
+        h_prime.past.len() == h.past.len() + 1,
+        forall |i: int| 0 <= i <= h.past.len() ==> #[trigger] h_prime.state_at(i) == h.state_at(i),
+        h_prime.state_at(h_prime.past.len() as int) == h_prime.current,
+{
+    assert forall |i: int| 0 <= i <= h.past.len() implies #[trigger] h_prime.state_at(i) == h.state_at(i) by {
+        if i < h.past.len() {
+            assert(h_prime.past[i] == h.past[i]);
+        } else {
+            assert(h_prime.past[i] == h.current);
+        }
+    }
+}
+
+pub proof fn lemma_previously_is_current_before_next(self, p: StatePred<ClusterState>, h: ClusterHistory, h_prime: ClusterHistory)
+    requires
+        self.next()(h, h_prime),
+    ensures
+        previously(p)(h_prime) == p(h.current),
+{
+    assert(h_prime.past.last() == h.current);
+}
+
+pub proof fn lemma_once_is_preserved_by_next(self, p: StatePred<ClusterState>, h: ClusterHistory, h_prime: ClusterHistory)
+    requires
+        self.next()(h, h_prime),
+        once(p)(h),
+    ensures
+        once(p)(h_prime),
+{
+    self.lemma_next_preserves_history(h, h_prime);
+    let i = choose |i: int| 0 <= i <= h.past.len() && #[trigger] p(h.state_at(i));
+    assert(p(h_prime.state_at(i)));
+}
+
+pub proof fn lemma_since_is_preserved_by_next(self, p: StatePred<ClusterState>, q: StatePred<ClusterState>, h: ClusterHistory, h_prime: ClusterHistory)
+    requires
+        self.next()(h, h_prime),
+        since(p, q)(h) || q(h_prime.current),
+        p(h_prime.current),
+    ensures
+        since(p, q)(h_prime),
+{
+    self.lemma_next_preserves_history(h, h_prime);
+    if q(h_prime.current) {
+        assert(q(h_prime.state_at(h_prime.past.len() as int)));
+    } else {
+        let i = choose |i: int| 0 <= i <= h.past.len() && #[trigger] q(h.state_at(i))
+            && forall |j: int| i < j <= h.past.len() ==> #[trigger] p(h.state_at(j));
+        assert(q(h_prime.state_at(i)));
+        assert forall |j: int| i < j <= h_prime.past.len() implies #[trigger] p(h_prime.state_at(j)) by {
+            if j <= h.past.len() {
+                assert(p(h.state_at(j)));
+            }
+        }
+    }
+}
+
+// Proves safety properties of the form "r only holds if p once held",
+// e.g., "a pod is never deleted unless the CR once requested fewer replicas".
+// It is enough to show that whenever r starts to hold, p holds either before or after the step.
+pub proof fn lemma_always_implies_once(self, spec: TempPred<ClusterHistory>, r: StatePred<ClusterState>, p: StatePred<ClusterState>)
+    requires
+        forall |s: ClusterState| #[trigger] self.to_cluster().init()(s) && r(s) ==> p(s),
+        forall |s: ClusterState, s_prime: ClusterState| #[trigger] self.to_cluster().next()(s, s_prime) && !r(s) && r(s_prime) ==> p(s) || p(s_prime),
+        spec.entails(lift_state(self.init())),
+        spec.entails(always(lift_action(self.next()))),
+    ensures
+        spec.entails(always(lift_state(|h: ClusterHistory| r(h.current) ==> once(p)(h)))),
+{
+    let inv = |h: ClusterHistory| r(h.current) ==> once(p)(h);
+    assert forall |h: ClusterHistory| #[trigger] self.init()(h) implies inv(h) by {
+        if r(h.current) {
+            assert(self.to_cluster().init()(h.current));
+            assert(p(h.state_at(0)));
+        }
+    }
+    assert forall |h: ClusterHistory, h_prime: ClusterHistory| inv(h) && #[trigger] self.next()(h, h_prime) implies inv(h_prime) by {
+        if r(h_prime.current) {
+            self.lemma_next_preserves_history(h, h_prime);
+            assert(self.to_cluster().next()(h.current, h_prime.current));
+            if r(h.current) {
+                self.lemma_once_is_preserved_by_next(p, h, h_prime);
+            } else if p(h.current) {
+                assert(p(h_prime.state_at(h.past.len() as int)));
+            } else {
+                assert(p(h_prime.state_at(h_prime.past.len() as int)));
+            }
+        }
+    }
+    init_invariant(spec, self.init(), self.next(), inv);
+}
+
 pub open spec fn construct_history_from_state(i: nat, ex: Execution<ClusterState>) -> ClusterHistory
     decreases i
 {
@@ -66,8 +66,6 @@ verus! {
 //
 // + Model foreground and orphan deletion options
 //
-// + Support cluster-wide state objects (the ones that don't belong to a namespace)
-//
 // + Keep the error code consistent with the real API Server
 //
 // + Document intended mismatch between the model and the real API server
@@ -75,16 +73,24 @@ verus! {
 #[verifier(inline)]
 pub open spec fn unmarshallable_spec(obj: DynamicObjectView, installed_types: InstalledTypes) -> bool {
     match obj.kind {
+        Kind::ClusterRoleKind => ClusterRoleView::unmarshal_spec(obj.spec).is_Ok(),
+        Kind::ClusterRoleBindingKind => ClusterRoleBindingView::unmarshal_spec(obj.spec).is_Ok(),
         Kind::ConfigMapKind => ConfigMapView::unmarshal_spec(obj.spec).is_Ok(),
         Kind::DaemonSetKind => DaemonSetView::unmarshal_spec(obj.spec).is_Ok(),
+        Kind::EventKind => EventView::unmarshal_spec(obj.spec).is_Ok(),
+        Kind::JobKind => JobView::unmarshal_spec(obj.spec).is_Ok(),
+        Kind::NamespaceKind => NamespaceView::unmarshal_spec(obj.spec).is_Ok(),
+        Kind::PersistentVolumeKind => PersistentVolumeView::unmarshal_spec(obj.spec).is_Ok(),
         Kind::PersistentVolumeClaimKind => PersistentVolumeClaimView::unmarshal_spec(obj.spec).is_Ok(),
         Kind::PodKind => PodView::unmarshal_spec(obj.spec).is_Ok(),
+        Kind::PodDisruptionBudgetKind => PodDisruptionBudgetView::unmarshal_spec(obj.spec).is_Ok(),
         Kind::RoleBindingKind => RoleBindingView::unmarshal_spec(obj.spec).is_Ok(),
         Kind::RoleKind => RoleView::unmarshal_spec(obj.spec).is_Ok(),
         Kind::SecretKind => SecretView::unmarshal_spec(obj.spec).is_Ok(),
         Kind::ServiceKind => ServiceView::unmarshal_spec(obj.spec).is_Ok(),
         Kind::StatefulSetKind => StatefulSetView::unmarshal_spec(obj.spec).is_Ok(),
         Kind::ServiceAccountKind => ServiceAccountView::unmarshal_spec(obj.spec).is_Ok(),
+        Kind::StorageClassKind => StorageClassView::unmarshal_spec(obj.spec).is_Ok(),
         Kind::CustomResourceKind(string) => (installed_types[string].unmarshallable_spec)(obj.spec),
     }
 }
@@ -92,16 +98,24 @@ pub open spec fn unmarshallable_spec(obj: DynamicObjectView, installed_types: In
 #[verifier(inline)]
 pub open spec fn unmarshallable_status(obj: DynamicObjectView, installed_types: InstalledTypes) -> bool {
     match obj.kind {
+        Kind::ClusterRoleKind => ClusterRoleView::unmarshal_status(obj.status).is_Ok(),
+        Kind::ClusterRoleBindingKind => ClusterRoleBindingView::unmarshal_status(obj.status).is_Ok(),
         Kind::ConfigMapKind => ConfigMapView::unmarshal_status(obj.status).is_Ok(),
         Kind::DaemonSetKind => DaemonSetView::unmarshal_status(obj.status).is_Ok(),
+        Kind::EventKind => EventView::unmarshal_status(obj.status).is_Ok(),
+        Kind::JobKind => JobView::unmarshal_status(obj.status).is_Ok(),
+        Kind::NamespaceKind => NamespaceView::unmarshal_status(obj.status).is_Ok(),
+        Kind::PersistentVolumeKind => PersistentVolumeView::unmarshal_status(obj.status).is_Ok(),
         Kind::PersistentVolumeClaimKind => PersistentVolumeClaimView::unmarshal_status(obj.status).is_Ok(),
         Kind::PodKind => PodView::unmarshal_status(obj.status).is_Ok(),
+        Kind::PodDisruptionBudgetKind => PodDisruptionBudgetView::unmarshal_status(obj.status).is_Ok(),
         Kind::RoleBindingKind => RoleBindingView::unmarshal_status(obj.status).is_Ok(),
         Kind::RoleKind => RoleView::unmarshal_status(obj.status).is_Ok(),
         Kind::SecretKind => SecretView::unmarshal_status(obj.status).is_Ok(),
         Kind::ServiceKind => ServiceView::unmarshal_status(obj.status).is_Ok(),
         Kind::StatefulSetKind => StatefulSetView::unmarshal_status(obj.status).is_Ok(),
         Kind::ServiceAccountKind => ServiceAccountView::unmarshal_status(obj.status).is_Ok(),
+        Kind::StorageClassKind => StorageClassView::unmarshal_status(obj.status).is_Ok(),
         Kind::CustomResourceKind(string) => (installed_types[string].unmarshallable_status)(obj.status),
     }
 }
@@ -132,16 +146,24 @@ pub open spec fn metadata_transition_validity_check(obj: DynamicObjectView, old_
 
 pub open spec fn valid_object(obj: DynamicObjectView, installed_types: InstalledTypes) -> bool {
     match obj.kind {
+        Kind::ClusterRoleKind => ClusterRoleView::unmarshal(obj).get_Ok_0().state_validation(),
+        Kind::ClusterRoleBindingKind => ClusterRoleBindingView::unmarshal(obj).get_Ok_0().state_validation(),
         Kind::ConfigMapKind => ConfigMapView::unmarshal(obj).get_Ok_0().state_validation(),
         Kind::DaemonSetKind => DaemonSetView::unmarshal(obj).get_Ok_0().state_validation(),
+        Kind::EventKind => EventView::unmarshal(obj).get_Ok_0().state_validation(),
+        Kind::JobKind => JobView::unmarshal(obj).get_Ok_0().state_validation(),
+        Kind::NamespaceKind => NamespaceView::unmarshal(obj).get_Ok_0().state_validation(),
+        Kind::PersistentVolumeKind => PersistentVolumeView::unmarshal(obj).get_Ok_0().state_validation(),
         Kind::PersistentVolumeClaimKind => PersistentVolumeClaimView::unmarshal(obj).get_Ok_0().state_validation(),
         Kind::PodKind => PodView::unmarshal(obj).get_Ok_0().state_validation(),
+        Kind::PodDisruptionBudgetKind => PodDisruptionBudgetView::unmarshal(obj).get_Ok_0().state_validation(),
         Kind::RoleBindingKind => RoleBindingView::unmarshal(obj).get_Ok_0().state_validation(),
         Kind::RoleKind => RoleView::unmarshal(obj).get_Ok_0().state_validation(),
         Kind::SecretKind => SecretView::unmarshal(obj).get_Ok_0().state_validation(),
         Kind::ServiceKind => ServiceView::unmarshal(obj).get_Ok_0().state_validation(),
         Kind::StatefulSetKind => StatefulSetView::unmarshal(obj).get_Ok_0().state_validation(),
         Kind::ServiceAccountKind => ServiceAccountView::unmarshal(obj).get_Ok_0().state_validation(),
+        Kind::StorageClassKind => StorageClassView::unmarshal(obj).get_Ok_0().state_validation(),
         Kind::CustomResourceKind(string) => (installed_types[string].valid_object)(obj),
     }
 }
@@ -156,16 +178,24 @@ pub open spec fn object_validity_check(obj: DynamicObjectView, installed_types:
 
 pub open spec fn valid_transition(obj: DynamicObjectView, old_obj: DynamicObjectView, installed_types: InstalledTypes) -> bool {
     match obj.kind {
+        Kind::ClusterRoleKind => ClusterRoleView::unmarshal(obj).get_Ok_0().transition_validation(ClusterRoleView::unmarshal(old_obj).get_Ok_0()),
+        Kind::ClusterRoleBindingKind => ClusterRoleBindingView::unmarshal(obj).get_Ok_0().transition_validation(ClusterRoleBindingView::unmarshal(old_obj).get_Ok_0()),
         Kind::ConfigMapKind => ConfigMapView::unmarshal(obj).get_Ok_0().transition_validation(ConfigMapView::unmarshal(old_obj).get_Ok_0()),
         Kind::DaemonSetKind => DaemonSetView::unmarshal(obj).get_Ok_0().transition_validation(DaemonSetView::unmarshal(old_obj).get_Ok_0()),
+        Kind::EventKind => EventView::unmarshal(obj).get_Ok_0().transition_validation(EventView::unmarshal(old_obj).get_Ok_0()),
+        Kind::JobKind => JobView::unmarshal(obj).get_Ok_0().transition_validation(JobView::unmarshal(old_obj).get_Ok_0()),
+        Kind::NamespaceKind => NamespaceView::unmarshal(obj).get_Ok_0().transition_validation(NamespaceView::unmarshal(old_obj).get_Ok_0()),
+        Kind::PersistentVolumeKind => PersistentVolumeView::unmarshal(obj).get_Ok_0().transition_validation(PersistentVolumeView::unmarshal(old_obj).get_Ok_0()),
         Kind::PersistentVolumeClaimKind => PersistentVolumeClaimView::unmarshal(obj).get_Ok_0().transition_validation(PersistentVolumeClaimView::unmarshal(old_obj).get_Ok_0()),
         Kind::PodKind => PodView::unmarshal(obj).get_Ok_0().transition_validation(PodView::unmarshal(old_obj).get_Ok_0()),
+        Kind::PodDisruptionBudgetKind => PodDisruptionBudgetView::unmarshal(obj).get_Ok_0().transition_validation(PodDisruptionBudgetView::unmarshal(old_obj).get_Ok_0()),
         Kind::RoleBindingKind => RoleBindingView::unmarshal(obj).get_Ok_0().transition_validation(RoleBindingView::unmarshal(old_obj).get_Ok_0()),
         Kind::RoleKind => RoleView::unmarshal(obj).get_Ok_0().transition_validation(RoleView::unmarshal(old_obj).get_Ok_0()),
         Kind::SecretKind => SecretView::unmarshal(obj).get_Ok_0().transition_validation(SecretView::unmarshal(old_obj).get_Ok_0()),
         Kind::ServiceKind => ServiceView::unmarshal(obj).get_Ok_0().transition_validation(ServiceView::unmarshal(old_obj).get_Ok_0()),
         Kind::StatefulSetKind => StatefulSetView::unmarshal(obj).get_Ok_0().transition_validation(StatefulSetView::unmarshal(old_obj).get_Ok_0()),
         Kind::ServiceAccountKind => ServiceAccountView::unmarshal(obj).get_Ok_0().transition_validation(ServiceAccountView::unmarshal(old_obj).get_Ok_0()),
+        Kind::StorageClassKind => StorageClassView::unmarshal(obj).get_Ok_0().transition_validation(StorageClassView::unmarshal(old_obj).get_Ok_0()),
         Kind::CustomResourceKind(string) => (installed_types[string].valid_transition)(obj, old_obj),
     }
 }
@@ -180,20 +210,46 @@ pub open spec fn object_transition_validity_check(obj: DynamicObjectView, old_ob
 
 pub open spec fn marshalled_default_status(kind: Kind, installed_types: InstalledTypes) -> Value {
     match kind {
+        Kind::ClusterRoleKind => ClusterRoleView::marshal_status(ClusterRoleView::default().status()),
+        Kind::ClusterRoleBindingKind => ClusterRoleBindingView::marshal_status(ClusterRoleBindingView::default().status()),
         Kind::ConfigMapKind => ConfigMapView::marshal_status(ConfigMapView::default().status()),
         Kind::DaemonSetKind => DaemonSetView::marshal_status(DaemonSetView::default().status()),
+        Kind::EventKind => EventView::marshal_status(EventView::default().status()),
+        Kind::JobKind => JobView::marshal_status(JobView::default().status()),
+        Kind::NamespaceKind => NamespaceView::marshal_status(NamespaceView::default().status()),
+        Kind::PersistentVolumeKind => PersistentVolumeView::marshal_status(PersistentVolumeView::default().status()),
         Kind::PersistentVolumeClaimKind => PersistentVolumeClaimView::marshal_status(PersistentVolumeClaimView::default().status()),
         Kind::PodKind => PodView::marshal_status(PodView::default().status()),
+        Kind::PodDisruptionBudgetKind => PodDisruptionBudgetView::marshal_status(PodDisruptionBudgetView::default().status()),
         Kind::RoleBindingKind => RoleBindingView::marshal_status(RoleBindingView::default().status()),
         Kind::RoleKind => RoleView::marshal_status(RoleView::default().status()),
         Kind::SecretKind => SecretView::marshal_status(SecretView::default().status()),
         Kind::ServiceKind => ServiceView::marshal_status(ServiceView::default().status()),
         Kind::StatefulSetKind => StatefulSetView::marshal_status(StatefulSetView::default().status()),
         Kind::ServiceAccountKind => ServiceAccountView::marshal_status(ServiceAccountView::default().status()),
+        Kind::StorageClassKind => StorageClassView::marshal_status(StorageClassView::default().status()),
         Kind::CustomResourceKind(string) => (installed_types[string].marshalled_default_status)(),
     }
 }
 
+// Whether objects of the kind live in a namespace.
+// The scope of a custom resource is recorded when its type is installed.
+pub open spec fn kind_is_namespaced(kind: Kind, installed_types: InstalledTypes) -> bool {
+    match kind {
+        Kind::CustomResourceKind(string) => installed_types.contains_key(string) ==> installed_types[string].namespaced,
+        _ => kind.is_namespaced(),
+    }
+}
+
+// The API server merges the write-only string_data of a secret into its data before storing it.
+pub open spec fn merged_spec(kind: Kind, spec: Value) -> Value {
+    if kind == Kind::SecretKind {
+        SecretView::merge_string_data_in_spec(spec)
+    } else {
+        spec
+    }
+}
+
 #[verifier(inline)]
 pub open spec fn handle_get_request(req: GetRequest, s: APIServerState) -> GetResponse {
     if !s.resources.contains_key(req.key) {
@@ -221,6 +277,9 @@ pub open spec fn create_request_admission_check(installed_types: InstalledTypes,
     } else if req.obj.metadata.namespace.is_Some() && req.namespace != req.obj.metadata.namespace.get_Some_0() {
         // Creation fails because the namespace of the provided object does not match the namespace sent on the request
         Some(APIError::BadRequest)
+    } else if !kind_is_namespaced(req.obj.kind, installed_types) && req.namespace != ""@ {
+        // Creation fails because a cluster-scoped object is created in a namespace
+        Some(APIError::BadRequest)
     } else if !unmarshallable_object(req.obj, installed_types) {
         // Creation fails because the provided object is not well formed
         Some(APIError::BadRequest) // TODO: should the error be BadRequest?
@@ -276,7 +335,7 @@ pub open spec fn handle_create_request(installed_types: InstalledTypes, req: Cre
                 deletion_timestamp: None, // Unset deletion timestamp for new object
                 ..req.obj.metadata
             },
-            spec: req.obj.spec,
+            spec: merged_spec(req.obj.kind, req.obj.spec), // Merge string_data into data if it is a secret
             status: marshalled_default_status(req.obj.kind, installed_types), // Overwrite the status with the default one
         };
         if s.resources.contains_key(created_obj.object_ref()) {
@@ -465,7 +524,7 @@ pub open spec fn updated_object(req: UpdateRequest, old_obj: DynamicObjectView)
             deletion_timestamp: old_obj.metadata.deletion_timestamp, // Ignore any change to deletion_timestamp
             ..req.obj.metadata
         },
-        spec: req.obj.spec,
+        spec: merged_spec(req.obj.kind, req.obj.spec), // Merge string_data into data if it is a secret
         status: old_obj.status, // Ignore any change to status
     };
     updated_obj
@@ -665,6 +724,30 @@ pub open spec fn transition_by_etcd(installed_types: InstalledTypes, msg: Messag
     }
 }
 
+pub open spec fn out_of_tokens(flow_control: FlowControlState, client: HostId) -> bool {
+    !flow_control.tokens.contains_key(client) || flow_control.tokens[client] == 0
+}
+
+// Each handled request takes one token from the sender's bucket. A request is handled
+// even if the sender is out of tokens, since throttling is nondeterministic in API priority
+// and fairness (it depends on the seats and queues available when the request arrives);
+// such a request might instead be rejected by Cluster::throttle_req.
+pub open spec fn consume_token(flow_control: FlowControlState, client: HostId) -> FlowControlState {
+    if out_of_tokens(flow_control, client) {
+        flow_control
+    } else {
+        FlowControlState {
+            tokens: flow_control.tokens.insert(client, (flow_control.tokens[client] - 1) as nat),
+        }
+    }
+}
+
+pub open spec fn refill_token_bucket(flow_control: FlowControlState, client: HostId, tokens: nat) -> FlowControlState {
+    FlowControlState {
+        tokens: flow_control.tokens.insert(client, tokens),
+    }
+}
+
 pub open spec fn handle_request(installed_types: InstalledTypes) -> APIServerAction {
     Action {
         precondition: |input: APIServerActionInput, s: APIServerState| {
@@ -673,7 +756,10 @@ pub open spec fn handle_request(installed_types: InstalledTypes) -> APIServerAct
         },
         transition: |input: APIServerActionInput, s: APIServerState| {
             let (s_prime, etcd_resp) = transition_by_etcd(installed_types, input.recv.get_Some_0(), s);
-            (s_prime, APIServerActionOutput {
+            (APIServerState {
+                flow_control: consume_token(s.flow_control, input.recv.get_Some_0().src),
+                ..s_prime
+            }, APIServerActionOutput {
                 send: Multiset::singleton(etcd_resp)
             })
         },
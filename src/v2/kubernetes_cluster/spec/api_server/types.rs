@@ -11,6 +11,18 @@ pub struct APIServerState {
     pub resources: StoredState,
     pub uid_counter: Uid,
     pub resource_version_counter: ResourceVersion,
+    pub flow_control: FlowControlState,
+}
+
+// FlowControlState models API priority and fairness as a token bucket for each client
+// (identified by the host that sends the request). Each request handled by the API server
+// takes one token from the bucket of its sender, and a client that runs out of tokens might
+// get its requests rejected with TooManyRequests until its bucket is refilled.
+//
+// The refill rate and the bucket capacity are abstracted away: an empty bucket can be refilled
+// with any positive number of tokens at any time.
+pub struct FlowControlState {
+    pub tokens: Map<HostId, nat>,
 }
 
 pub type InstalledTypes = Map<StringView, InstalledType>;
@@ -21,6 +33,7 @@ pub struct InstalledType {
     pub valid_object: spec_fn(DynamicObjectView) -> bool,
     pub valid_transition: spec_fn(DynamicObjectView, DynamicObjectView) -> bool,
     pub marshalled_default_status: spec_fn() -> Value,
+    pub namespaced: bool,
 }
 
 pub enum APIServerStep {
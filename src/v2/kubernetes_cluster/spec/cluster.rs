@@ -1,7 +1,7 @@
 use crate::kubernetes_api_objects::error::*;
 use crate::kubernetes_api_objects::spec::prelude::*;
 use crate::kubernetes_cluster::spec::{
-    api_server::state_machine::api_server, api_server::types::*,
+    api_server::state_machine::{api_server, out_of_tokens, refill_token_bucket}, api_server::types::*,
     builtin_controllers::state_machine::builtin_controllers, builtin_controllers::types::*,
     controller::state_machine::controller, controller::types::*, external::state_machine::external,
     external::types::*, message::*, network::state_machine::network, network::types::*,
@@ -17,13 +17,15 @@ verus! {
 // the states of each controller running in the cluster (and the associated external system if exists),
 // the state of the network (the pending messages).
 // It also has a global rpc_id_allocator that assign a unique id to each RPC call,
-// and a req_drop_enabled to enable/disable network message drop.
+// and a req_drop_enabled to enable/disable network message drop,
+// and a throttling_enabled to enable/disable API priority and fairness throttling.
 pub struct ClusterState {
     pub api_server: APIServerState,
     pub controller_and_externals: Map<int, ControllerAndExternalState>,
     pub network: NetworkState,
     pub rpc_id_allocator: RPCIdAllocator,
     pub req_drop_enabled: bool,
+    pub throttling_enabled: bool,
     pub pod_monkey_enabled: bool,
 }
 
@@ -72,6 +74,8 @@ pub enum Step {
     DisableCrashStep(int),
     DropReqStep((Message, APIError)),
     DisableReqDropStep,
+    ThrottleReqStep(Message),
+    RefillTokensStep((HostId, nat)),
     PodMonkeyStep(PodView),
     DisablePodMonkeyStep,
     ExternalStep((int, Option<Message>)),
@@ -108,6 +112,8 @@ impl Cluster {
             &&& (network().init)(s.network)
             // and message drop is enabled...
             &&& s.req_drop_enabled
+            // and throttling is enabled...
+            &&& s.throttling_enabled
             // and pod monkey is enabled...
             &&& s.pod_monkey_enabled
             // and for each controller...
@@ -151,6 +157,8 @@ impl Cluster {
             Step::DisableCrashStep(input) => self.disable_crash().forward(input)(s, s_prime),
             Step::DropReqStep(input) => self.drop_req().forward(input)(s, s_prime),
             Step::DisableReqDropStep => self.disable_req_drop().forward(())(s, s_prime),
+            Step::ThrottleReqStep(input) => self.throttle_req().forward(input)(s, s_prime),
+            Step::RefillTokensStep(input) => self.refill_tokens().forward(input)(s, s_prime),
             Step::PodMonkeyStep(input) => self.pod_monkey_next().forward(input)(s, s_prime),
             Step::DisablePodMonkeyStep => self.disable_pod_monkey().forward(())(s, s_prime),
             Step::ExternalStep(input) => self.external_next().forward(input)(s, s_prime),
@@ -415,8 +423,11 @@ impl Cluster {
     // to the sending controller. This is used to model different types of transient
     // failures, including:
     // * Network failures that drop the request, where the error might be Timeout.
+    // * Network failures that never reach the API server, where the error might
+    //   be Transport.
     // * API server is running busy and cannot take more requests, where the error
-    //   might be ServerTimeout.
+    //   might be ServerTimeout, ServiceUnavailable, or TooManyRequests when the
+    //   request is throttled by API priority and fairness.
     // * Non-deterministic requests get rejected by API server. For example, when
     //   creating an object using a generate_name, API server will try to create
     //   the object using a randomly generated name, and retry if it fails. If all
@@ -469,6 +480,73 @@ impl Cluster {
         }
     }
 
+    // The throttle_req rejects a request sent to the API server with TooManyRequests
+    // when the sender has run out of tokens. This models API priority and fairness
+    // (https://kubernetes.io/docs/concepts/cluster-administration/flow-control/), which
+    // protects the API server from being overloaded by a single client. Unlike drop_req,
+    // throttling only affects the clients that have used up their own share.
+    pub open spec fn throttle_req(self) -> Action<ClusterState, Message, ()> {
+        let result = |input: Message, s: ClusterState| {
+            let req_msg = input;
+            let resp = form_matched_err_resp_msg(req_msg, APIError::TooManyRequests);
+            let msg_ops = MessageOps {
+                recv: Some(req_msg),
+                send: Multiset::singleton(resp),
+            };
+            let result = network().next_result(msg_ops, s.network);
+            result
+        };
+        Action {
+            precondition: |input: Message, s: ClusterState| {
+                let req_msg = input;
+                &&& s.throttling_enabled
+                &&& req_msg.dst.is_APIServer()
+                &&& req_msg.content.is_APIRequest()
+                &&& out_of_tokens(s.api_server.flow_control, req_msg.src)
+                &&& result(input, s).is_Enabled()
+            },
+            transition: |input: Message, s: ClusterState| {
+                (ClusterState {
+                    network: result(input, s).get_Enabled_0(),
+                    ..s
+                }, ())
+            }
+        }
+    }
+
+    // The refill_tokens refills the token bucket of a client that has run out of tokens,
+    // which models the tokens regenerated over time. A refill always gives at least one
+    // token, so the bucket of a client is only drained by the requests the client sends.
+    pub open spec fn refill_tokens(self) -> Action<ClusterState, (HostId, nat), ()> {
+        Action {
+            precondition: |input: (HostId, nat), s: ClusterState| {
+                let client = input.0;
+                let tokens = input.1;
+                &&& out_of_tokens(s.api_server.flow_control, client)
+                &&& tokens > 0
+            },
+            transition: |input: (HostId, nat), s: ClusterState| {
+                let client = input.0;
+                let tokens = input.1;
+                (ClusterState {
+                    api_server: APIServerState {
+                        flow_control: refill_token_bucket(s.api_server.flow_control, client, tokens),
+                        ..s.api_server
+                    },
+                    ..s
+                }, ())
+            }
+        }
+    }
+
+    // The tokens_are_eventually_refilled is an assumption on the environment: if the client runs out of
+    // tokens infinitely often, its bucket is refilled infinitely often, so the client has tokens infinitely often.
+    // Throttling stays enabled: the client can still be throttled whenever its bucket is empty,
+    // and this says nothing about the buckets of the other clients.
+    pub open spec fn tokens_are_eventually_refilled(self, client: HostId) -> TempPred<ClusterState> {
+        self.refill_tokens().strong_fairness((client, 1))
+    }
+
     // The pod_monkey_next randomly creates, updates, or deletes a pod. This is used
     // to model any event that affects pods but not issued by controllers, including:
     // app container failures that cause pod termination (deletion), node failures or
@@ -18,6 +18,7 @@ pub open spec fn installed_type<T: CustomResourceView>() -> InstalledType {
         valid_object: |obj: DynamicObjectView| T::unmarshal(obj).get_Ok_0().state_validation(),
         valid_transition: |obj, old_obj: DynamicObjectView| T::unmarshal(obj).get_Ok_0().transition_validation(T::unmarshal(old_obj).get_Ok_0()),
         marshalled_default_status: || T::marshal_status(T::default().status()),
+        namespaced: T::is_namespaced(),
     }
 }
 
@@ -10,6 +10,34 @@ pub struct ClusterHistory {
     pub past: Seq<ClusterState>,
 }
 
+impl ClusterHistory {
+    // Returns the i-th state in the history, where the current state is the last one (i == past.len()).
+    pub open spec fn state_at(self, i: int) -> ClusterState {
+        if i < self.past.len() { self.past[i] } else { self.current }
+    }
+}
+
+// Past-time temporal operators evaluated over the history.
+// Since the history is part of the state, they are state predicates of the retentive state machine
+// and can be used in invariants like "r only holds if p once held".
+
+// Returns a state predicate that is satisfied iff `p` was satisfied by the previous state.
+pub open spec fn previously(p: StatePred<ClusterState>) -> StatePred<ClusterHistory> {
+    |h: ClusterHistory| h.past.len() > 0 && p(h.past.last())
+}
+
+// Returns a state predicate that is satisfied iff `p` is satisfied by the current state or some past state.
+pub open spec fn once(p: StatePred<ClusterState>) -> StatePred<ClusterHistory> {
+    |h: ClusterHistory| exists |i: int| 0 <= i <= h.past.len() && #[trigger] p(h.state_at(i))
+}
+
+// Returns a state predicate that is satisfied iff `q` is satisfied by the current state or some past state
+// and `p` is satisfied by every state after that.
+pub open spec fn since(p: StatePred<ClusterState>, q: StatePred<ClusterState>) -> StatePred<ClusterHistory> {
+    |h: ClusterHistory| exists |i: int| 0 <= i <= h.past.len() && #[trigger] q(h.state_at(i))
+        && forall |j: int| i < j <= h.past.len() ==> #[trigger] p(h.state_at(j))
+}
+
 pub struct RetentiveCluster {
     pub installed_types: InstalledTypes,
     pub controller_models: Map<int, ControllerModel>,
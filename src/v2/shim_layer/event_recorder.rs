@@ -0,0 +1,189 @@
+use deps_hack::k8s_openapi::api::core::v1::ObjectReference;
+use deps_hack::kube::{
+    runtime::events::{Event, EventType, Recorder, Reporter},
+    Client,
+};
+use deps_hack::tracing::warn;
+
+// EventRecorder publishes Events about the custom resource under reconciliation,
+// so that `kubectl describe` shows what the controller did (e.g., "Created StatefulSet/default/zk")
+// and why a reconcile failed.
+//
+// Events are published by the shim layer, outside of reconcile_core, so they never show up
+// in the requests of the verified reconciler. A reconciler that wants to report something
+// more specific can still create an Event object with a regular create request.
+pub struct EventRecorder {
+    recorder: Recorder,
+    log_header: String,
+}
+
+impl EventRecorder {
+    pub fn new(
+        client: Client,
+        controller: String,
+        cr_ref: ObjectReference,
+        log_header: &String,
+    ) -> EventRecorder {
+        let reporter = Reporter {
+            controller: controller,
+            instance: std::env::var("HOSTNAME").ok(),
+        };
+        EventRecorder {
+            recorder: Recorder::new(client, reporter, cr_ref),
+            log_header: log_header.clone(),
+        }
+    }
+
+    pub async fn normal(&self, reason: &str, note: String) {
+        self.publish(EventType::Normal, reason, note).await
+    }
+
+    pub async fn warning(&self, reason: &str, note: String) {
+        self.publish(EventType::Warning, reason, note).await
+    }
+
+    pub async fn record(&self, event: EventNote) {
+        self.publish(event.type_, event.reason, event.note).await
+    }
+
+    // Failing to publish an Event should not fail the reconcile, so the error is only logged.
+    async fn publish(&self, type_: EventType, reason: &str, note: String) {
+        let event = Event {
+            type_: type_,
+            reason: reason.to_string(),
+            note: Some(note),
+            action: "Reconcile".to_string(),
+            secondary: None,
+        };
+        if let Err(err) = self.recorder.publish(event).await {
+            warn!(
+                "{} Publish event {} failed with error: {}",
+                self.log_header, reason, err
+            );
+        }
+    }
+}
+
+// EventNote is an Event chosen by one of the functions below, to be published by EventRecorder::record.
+#[derive(Debug, PartialEq)]
+pub struct EventNote {
+    pub type_: EventType,
+    pub reason: &'static str,
+    pub note: String,
+}
+
+// updated_event is the Event recorded once an update request succeeds,
+// like the ones recorded for create and delete requests.
+pub fn updated_event(key: &str) -> EventNote {
+    EventNote {
+        type_: EventType::Normal,
+        reason: "Updated",
+        note: format!("Updated {}", key),
+    }
+}
+
+// external_api_failed_event is the Event recorded when an external call fails (e.g., "ZK node update failed").
+// error is the failure that the external shim layer reports for the output of the call, if any.
+pub fn external_api_failed_event(error: Option<String>) -> Option<EventNote> {
+    error.map(|error| EventNote {
+        type_: EventType::Warning,
+        reason: "ExternalAPIFailed",
+        note: error,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use deps_hack::http::{Request, Response};
+    use deps_hack::k8s_openapi::api::core::v1::ObjectReference;
+    use deps_hack::kube::client::Body;
+    use deps_hack::serde_json::Value;
+    use deps_hack::tower::service_fn;
+    use std::sync::{Arc, Mutex};
+
+    // Returns a recorder whose client stands in for the API server: it keeps the body of each request
+    // and echoes it back as the created Event.
+    fn recorder_with_stand_in() -> (EventRecorder, Arc<Mutex<Vec<Value>>>) {
+        let published = Arc::new(Mutex::new(Vec::new()));
+        let bodies = published.clone();
+        let service = service_fn(move |req: Request<Body>| {
+            let bodies = bodies.clone();
+            async move {
+                let body = req.into_body().collect_bytes().await.unwrap();
+                bodies
+                    .lock()
+                    .unwrap()
+                    .push(deps_hack::serde_json::from_slice(&body).unwrap());
+                Ok::<_, std::convert::Infallible>(
+                    Response::builder()
+                        .status(201)
+                        .body(Body::from(body.to_vec()))
+                        .unwrap(),
+                )
+            }
+        });
+        let cr_ref = ObjectReference {
+            kind: Some("ZookeeperCluster".to_string()),
+            name: Some("zk".to_string()),
+            namespace: Some("default".to_string()),
+            ..ObjectReference::default()
+        };
+        let recorder = EventRecorder::new(
+            Client::new(service, "default"),
+            "zookeepercluster-controller".to_string(),
+            cr_ref,
+            &"test".to_string(),
+        );
+        (recorder, published)
+    }
+
+    #[test]
+    fn test_updated_event() {
+        assert_eq!(
+            updated_event("StatefulSet/default/zk"),
+            EventNote {
+                type_: EventType::Normal,
+                reason: "Updated",
+                note: "Updated StatefulSet/default/zk".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_external_api_failed_event() {
+        assert_eq!(external_api_failed_event(None), None);
+        assert_eq!(
+            external_api_failed_event(Some("ZK node update failed".to_string())),
+            Some(EventNote {
+                type_: EventType::Warning,
+                reason: "ExternalAPIFailed",
+                note: "ZK node update failed".to_string(),
+            })
+        );
+    }
+
+    #[deps_hack::tokio::test(crate = "deps_hack::tokio")]
+    async fn test_record_updated_event() {
+        let (recorder, published) = recorder_with_stand_in();
+        recorder.record(updated_event("StatefulSet/default/zk")).await;
+        let published = published.lock().unwrap();
+        assert_eq!(published.len(), 1);
+        assert_eq!(published[0]["type"], "Normal");
+        assert_eq!(published[0]["reason"], "Updated");
+        assert_eq!(published[0]["note"], "Updated StatefulSet/default/zk");
+        assert_eq!(published[0]["regarding"]["name"], "zk");
+    }
+
+    #[deps_hack::tokio::test(crate = "deps_hack::tokio")]
+    async fn test_record_external_api_failed_event() {
+        let (recorder, published) = recorder_with_stand_in();
+        let event = external_api_failed_event(Some("ZK node update failed".to_string())).unwrap();
+        recorder.record(event).await;
+        let published = published.lock().unwrap();
+        assert_eq!(published.len(), 1);
+        assert_eq!(published[0]["type"], "Warning");
+        assert_eq!(published[0]["reason"], "ExternalAPIFailed");
+        assert_eq!(published[0]["note"], "ZK node update failed");
+    }
+}
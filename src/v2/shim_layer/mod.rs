@@ -1,4 +1,7 @@
 // Copyright 2022 VMware, Inc.
 // SPDX-License-Identifier: MIT
 pub mod controller_runtime;
+pub mod event_recorder;
 pub mod fault_injection;
+pub mod retry_after;
+pub mod watch_scope;
@@ -36,16 +36,18 @@ async fn main() -> Result<()> {
     //     info!("running vdeployment-controller");
     //     run_controller::<deps_hack::VDeployment, VDeploymentReconciler, VoidExternalShimLayer>(
     //         false,
+    //         WatchScope::from_args(&args[2..])?,
     //     )
     //     .await?;
     // } else if cmd == String::from("crash") {
     //     info!("running vdeployment-controller in crash-testing mode");
     //     run_controller::<deps_hack::VDeployment, VDeploymentReconciler, VoidExternalShimLayer>(
     //         true,
+    //         WatchScope::from_args(&args[2..])?,
     //     )
     //     .await?;
     } else {
-        error!("wrong command; please use \"export\", \"run\" or \"crash\"");
+        error!("wrong command; please use \"export\", \"run\" or \"crash\" (\"run\" and \"crash\" accept \"--namespaces\" and \"--selector\")");
     }
     Ok(())
 }
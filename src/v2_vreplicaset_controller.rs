@@ -22,6 +22,7 @@ use deps_hack::tokio;
 use deps_hack::tracing::{error, info};
 use deps_hack::tracing_subscriber;
 use shim_layer::controller_runtime::run_controller;
+use shim_layer::watch_scope::WatchScope;
 use std::env;
 
 #[tokio::main]
@@ -29,6 +30,8 @@ async fn main() -> Result<()> {
     tracing_subscriber::fmt::init();
     let args: Vec<String> = env::args().collect();
     let cmd = args[1].clone();
+    // Flags such as --namespaces and --selector follow the command
+    let flags = &args[2..];
 
     if cmd == String::from("export") {
         println!("{}", serde_yaml::to_string(&deps_hack::VReplicaSet::crd())?);
@@ -36,16 +39,18 @@ async fn main() -> Result<()> {
         info!("running vreplicaset-controller");
         run_controller::<deps_hack::VReplicaSet, VReplicaSetReconciler, VoidExternalShimLayer>(
             false,
+            WatchScope::from_args(flags)?,
         )
         .await?;
     } else if cmd == String::from("crash") {
         info!("running vreplicaset-controller in crash-testing mode");
         run_controller::<deps_hack::VReplicaSet, VReplicaSetReconciler, VoidExternalShimLayer>(
             true,
+            WatchScope::from_args(flags)?,
         )
         .await?;
     } else {
-        error!("wrong command; please use \"export\", \"run\" or \"crash\"");
+        error!("wrong command; please use \"export\", \"run\" or \"crash\" (\"run\" and \"crash\" accept \"--namespaces\" and \"--selector\")");
     }
     Ok(())
 }
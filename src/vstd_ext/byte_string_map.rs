@@ -0,0 +1,92 @@
+// Copyright 2022 VMware, Inc.
+// SPDX-License-Identifier: MIT
+use crate::vstd_ext::string_map::*;
+use crate::vstd_ext::string_view::*;
+use vstd::prelude::*;
+
+verus! {
+
+#[verifier(external_body)]
+pub struct ByteStringMap {
+    inner: std::collections::BTreeMap<std::string::String, std::vec::Vec<u8>>,
+}
+
+impl ByteStringMap {
+    pub spec fn view(&self) -> Map<Seq<char>, Seq<u8>>;
+
+    #[verifier(external_body)]
+    pub fn new() -> (m: Self)
+        ensures m@ == Map::<Seq<char>, Seq<u8>>::empty(),
+    {
+        ByteStringMap { inner: std::collections::BTreeMap::new() }
+    }
+
+    pub fn empty() -> (m: Self)
+        ensures m@ == Map::<Seq<char>, Seq<u8>>::empty(),
+    {
+        ByteStringMap::new()
+    }
+
+    // Encodes each value of the string map in UTF-8
+    #[verifier(external_body)]
+    pub fn from_string_map(m: StringMap) -> (res: Self)
+        ensures res@ == string_map_to_byte_string_map(m@),
+    {
+        ByteStringMap { inner: m.into_rust_map().into_iter().map(|(k, v)| (k, v.into_bytes())).collect() }
+    }
+
+    #[verifier(external_body)]
+    pub fn len(&self) -> (len: usize)
+        ensures len == self@.len(),
+    {
+        self.inner.len()
+    }
+
+    #[verifier(external_body)]
+    pub fn clone(&self) -> (m: Self)
+        ensures m@ == self@,
+    {
+        ByteStringMap { inner: self.inner.clone() }
+    }
+
+    #[verifier(external_body)]
+    pub fn insert(&mut self, key: String, value: Vec<u8>) -> (old_v: Option<Vec<u8>>)
+        ensures
+            self@ == old(self)@.insert(key@, value@),
+            old(self)@.contains_key(key@) == old_v.is_Some(),
+            old_v.is_Some() ==> old_v.get_Some_0()@ == old(self)@[key@],
+    {
+        self.inner.insert(key, value)
+    }
+
+    #[verifier(external_body)]
+    pub fn get(&self, key: &String) -> (v: Option<Vec<u8>>)
+        ensures
+            self@.contains_key(key@) == v.is_Some(),
+            v.is_Some() ==> v.get_Some_0()@ == self@[key@],
+    {
+        self.inner.get(key).cloned()
+    }
+
+    #[verifier(external_body)]
+    pub fn extend(&mut self, m2: ByteStringMap)
+        ensures self@ == old(self)@.union_prefer_right(m2@),
+    {
+        self.inner.extend(m2.into_rust_map())
+    }
+
+    #[verifier(external_body)]
+    pub fn keys(&self) -> (keys: Vec<String>)
+        ensures keys@.map_values(|k: String| k@) == self@.dom().to_seq(),
+    {
+        self.inner.keys().cloned().collect()
+    }
+
+    #[verifier(external)]
+    pub fn from_rust_map(inner: std::collections::BTreeMap<std::string::String, std::vec::Vec<u8>>) -> ByteStringMap { ByteStringMap { inner: inner } }
+
+    #[verifier(external)]
+    pub fn into_rust_map(self) -> std::collections::BTreeMap<std::string::String, std::vec::Vec<u8>> { self.inner }
+}
+
+}
@@ -1,5 +1,6 @@
 // Copyright 2022 VMware, Inc.
 // SPDX-License-Identifier: MIT
+pub mod byte_string_map;
 pub mod map_lib;
 pub mod multiset_lib;
 pub mod option_lib;
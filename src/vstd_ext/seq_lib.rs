@@ -113,4 +113,13 @@ pub proof fn seq_pred_false_on_all_elements_implies_empty_filter<A>(s: Seq<A>, p
 // If `pred` is false on every element, filter will return an empty sequence.
 //
 
+pub proof fn seq_filter_push<A>(s: Seq<A>, pred: spec_fn(A) -> bool, e: A)
+    ensures
+        pred(e) ==> s.push(e).filter(pred) == s.filter(pred).push(e),
+        !pred(e) ==> s.push(e).filter(pred) == s.filter(pred),
+{
+    reveal(Seq::filter);
+    assert(s.push(e).drop_last() == s);
+}
+
 }
@@ -30,6 +30,13 @@ impl StringMap {
         self.inner.len()
     }
 
+    #[verifier(external_body)]
+    pub fn eq(&self, other: &Self) -> (b: bool)
+        ensures b == (self@ == other@),
+    {
+        self.inner == other.inner
+    }
+
     #[verifier(external_body)]
     pub fn clone(&self) -> (m: Self)
         ensures m@ == self@,
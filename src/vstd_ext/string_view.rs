@@ -7,6 +7,8 @@ verus! {
 
 pub type StringView = Seq<char>;
 
+pub type ByteStringView = Seq<u8>;
+
 #[verifier(external_body)]
 pub fn i32_to_string(i: i32) -> (s: String)
     ensures s@ == int_to_string_view(i as int),
@@ -42,4 +44,54 @@ pub open spec fn opt_string_to_view(s: &Option<String>) -> Option<StringView> {
     }
 }
 
+// UTF-8 encoding of a string
+pub closed spec fn string_view_to_byte_string_view(s: StringView) -> ByteStringView;
+
+#[verifier(external_body)]
+pub proof fn string_view_to_byte_string_view_injectivity()
+    ensures forall |s1: StringView, s2: StringView| string_view_to_byte_string_view(s1) == string_view_to_byte_string_view(s2) ==> s1 == s2,
+{}
+
+// UTF-8 decoding of a byte string, which fails if the bytes are not valid UTF-8
+pub closed spec fn byte_string_view_to_string_view(b: ByteStringView) -> Option<StringView>;
+
+#[verifier(external_body)]
+pub proof fn byte_string_view_to_string_view_after_encode()
+    ensures forall |s: StringView| #[trigger] byte_string_view_to_string_view(string_view_to_byte_string_view(s)) == Some(s),
+{}
+
+#[verifier(external_body)]
+pub fn byte_string_to_string(b: Vec<u8>) -> (s: Option<String>)
+    ensures
+        s.is_Some() == byte_string_view_to_string_view(b@).is_Some(),
+        s.is_Some() ==> s.get_Some_0()@ == byte_string_view_to_string_view(b@).get_Some_0(),
+{
+    String::from_utf8(b).ok()
+}
+
+pub open spec fn string_map_to_byte_string_map(m: Map<StringView, StringView>) -> Map<StringView, ByteStringView> {
+    m.map_values(|v: StringView| string_view_to_byte_string_view(v))
+}
+
+// Binary data is carried as base64 strings in JSON
+pub closed spec fn base64_encode(b: ByteStringView) -> StringView;
+
+pub closed spec fn base64_decode(s: StringView) -> Option<ByteStringView>;
+
+#[verifier(external_body)]
+pub proof fn base64_decode_after_encode()
+    ensures forall |b: ByteStringView| #[trigger] base64_decode(base64_encode(b)) == Some(b),
+{}
+
+// A random base64 string encoding length random bytes, used for generated secrets (e.g., cookies and passwords)
+#[verifier(external_body)]
+pub fn random_encoded_string(length: usize) -> (s: String)
+    ensures s@ == random_encoded_string_view(length),
+{
+    let random_bytes: std::vec::Vec<std::primitive::u8> = (0..length).map(|_| deps_hack::rand::random::<std::primitive::u8>()).collect();
+    deps_hack::base64::encode(random_bytes)
+}
+
+pub closed spec fn random_encoded_string_view(length: usize) -> StringView;
+
 }
@@ -13,14 +13,18 @@ pub mod vstd_ext;
 #[path = "controller_examples/zookeeper_controller/mod.rs"]
 pub mod zookeeper_controller;
 
+use crate::zookeeper_controller::backup::exec::reconciler::ZookeeperBackupReconciler;
 use crate::zookeeper_controller::exec::reconciler::ZookeeperReconciler;
+use crate::zookeeper_controller::restore::exec::reconciler::ZookeeperRestoreReconciler;
 use deps_hack::anyhow::Result;
+use deps_hack::futures;
 use deps_hack::kube::CustomResourceExt;
 use deps_hack::serde_yaml;
 use deps_hack::tokio;
 use deps_hack::tracing::{error, info};
 use deps_hack::tracing_subscriber;
 use shim_layer::controller_runtime::run_controller;
+use shim_layer::watch_scope::WatchScope;
 use std::env;
 
 #[tokio::main]
@@ -28,20 +32,52 @@ async fn main() -> Result<()> {
     tracing_subscriber::fmt::init();
     let args: Vec<String> = env::args().collect();
     let cmd = args[1].clone();
+    // Flags such as --namespaces and --selector follow the command
+    let flags = &args[2..];
 
     if cmd == String::from("export") {
         println!(
             "{}",
             serde_yaml::to_string(&deps_hack::ZookeeperCluster::crd())?
         );
+        println!(
+            "{}",
+            serde_yaml::to_string(&deps_hack::ZookeeperBackup::crd())?
+        );
+        println!(
+            "{}",
+            serde_yaml::to_string(&deps_hack::ZookeeperRestore::crd())?
+        );
     } else if cmd == String::from("run") {
         info!("running zookeeper-controller");
-        run_controller::<deps_hack::ZookeeperCluster, ZookeeperReconciler>(false).await?;
+        let scope = WatchScope::from_args(flags)?;
+        let zookeeper_controller_fut =
+            run_controller::<deps_hack::ZookeeperCluster, ZookeeperReconciler>(false, scope.clone());
+        let backup_controller_fut =
+            run_controller::<deps_hack::ZookeeperBackup, ZookeeperBackupReconciler>(false, scope.clone());
+        let restore_controller_fut =
+            run_controller::<deps_hack::ZookeeperRestore, ZookeeperRestoreReconciler>(false, scope);
+        futures::try_join!(
+            zookeeper_controller_fut,
+            backup_controller_fut,
+            restore_controller_fut
+        )?;
     } else if cmd == String::from("crash") {
         info!("running zookeeper-controller in crash-testing mode");
-        run_controller::<deps_hack::ZookeeperCluster, ZookeeperReconciler>(true).await?;
+        let scope = WatchScope::from_args(flags)?;
+        let zookeeper_controller_fut =
+            run_controller::<deps_hack::ZookeeperCluster, ZookeeperReconciler>(true, scope.clone());
+        let backup_controller_fut =
+            run_controller::<deps_hack::ZookeeperBackup, ZookeeperBackupReconciler>(true, scope.clone());
+        let restore_controller_fut =
+            run_controller::<deps_hack::ZookeeperRestore, ZookeeperRestoreReconciler>(true, scope);
+        futures::try_join!(
+            zookeeper_controller_fut,
+            backup_controller_fut,
+            restore_controller_fut
+        )?;
     } else {
-        error!("wrong command; please use \"export\", \"run\" or \"crash\"");
+        error!("wrong command; please use \"export\", \"run\" or \"crash\" (\"run\" and \"crash\" accept \"--namespaces\" and \"--selector\")");
     }
     Ok(())
 }